@@ -0,0 +1,581 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Typed model of the Atlassian Document Format (ADF).
+//!
+//! ADF is the structured JSON format JIRA uses in `fields.description` and
+//! comment bodies. This module parses the raw JSON into an [`AdfNode`] tree
+//! once, and walks that tree on behalf of an [`AdfWriter`], so the HTML
+//! renderer in bugview-service and the terminal renderer in bugview-cli share
+//! a single parser.
+//!
+//! Parsing is lenient: JIRA adds node types over time, so anything this model
+//! does not recognize becomes [`AdfNode::Unknown`] with its children preserved,
+//! and writers render those children as if the wrapper were not there.
+//! Malformed nodes (e.g. a mention without attributes) are dropped rather than
+//! failing the whole document.
+
+use serde_json::Value;
+
+/// A node in an ADF document
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdfNode {
+    /// `paragraph`
+    Paragraph(Vec<AdfNode>),
+    /// `text`, with its formatting marks
+    Text { text: String, marks: Vec<Mark> },
+    /// `hardBreak`
+    HardBreak,
+    /// `rule` (horizontal line)
+    Rule,
+    /// `heading`; `level` is clamped to 1..=6
+    Heading { level: u8, content: Vec<AdfNode> },
+    /// `bulletList`; children are normally [`AdfNode::ListItem`]
+    BulletList(Vec<AdfNode>),
+    /// `orderedList`; `start` is the number of the first item
+    OrderedList { start: u64, items: Vec<AdfNode> },
+    /// `listItem`
+    ListItem(Vec<AdfNode>),
+    /// `codeBlock`
+    CodeBlock {
+        language: Option<String>,
+        content: Vec<AdfNode>,
+    },
+    /// `blockquote`
+    Blockquote(Vec<AdfNode>),
+    /// `panel`
+    Panel {
+        panel_type: PanelType,
+        content: Vec<AdfNode>,
+    },
+    /// `expand` / `nestedExpand` (a collapsible section)
+    Expand {
+        title: Option<String>,
+        content: Vec<AdfNode>,
+    },
+    /// `table`; children are normally [`AdfNode::TableRow`]
+    Table(Vec<AdfNode>),
+    /// `tableRow`; children are normally [`AdfNode::TableCell`]
+    TableRow(Vec<AdfNode>),
+    /// `tableCell` or `tableHeader`
+    TableCell { header: bool, content: Vec<AdfNode> },
+    /// `mediaSingle` / `mediaGroup`; children are normally [`AdfNode::Media`]
+    MediaGroup(Vec<AdfNode>),
+    /// `media` (an attachment or external image)
+    Media(Media),
+    /// `mention` of a user; `name` is the display name without the leading
+    /// `@`, or the account ID when JIRA did not supply one. `id` is the
+    /// account ID, which is absent once the document has been scrubbed with
+    /// [`jira_api::sanitize`].
+    Mention { id: Option<String>, name: String },
+    /// `emoji`
+    Emoji {
+        short_name: String,
+        text: Option<String>,
+    },
+    /// `inlineCard` (a smart link rendered inline)
+    InlineCard { url: String },
+    /// `blockCard` / `embedCard` (a smart link rendered as a block)
+    BlockCard { url: String },
+    /// `status` lozenge
+    Status { text: String },
+    /// `date`, as milliseconds since the Unix epoch
+    Date { timestamp: String },
+    /// Any node type not modeled above
+    Unknown {
+        node_type: String,
+        content: Vec<AdfNode>,
+    },
+}
+
+/// Formatting applied to a text node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mark {
+    Strong,
+    Em,
+    Code,
+    Strike,
+    Underline,
+    Link {
+        href: String,
+    },
+    /// Any mark not modeled above (e.g. `textColor`); writers ignore these
+    Unknown(String),
+}
+
+/// Visual style of a panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PanelType {
+    #[default]
+    Info,
+    Note,
+    Tip,
+    Success,
+    Warning,
+    Error,
+    /// Any panel type not modeled above
+    Unknown,
+}
+
+/// An attachment or external image referenced by a `media` node
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Media {
+    /// `file` for JIRA attachments, `external` for images hosted elsewhere
+    pub media_type: String,
+    /// Attachment identifier (attachments only)
+    pub id: Option<String>,
+    /// Image URL (external media only)
+    pub url: Option<String>,
+    /// Alternative text, usually the attachment's file name
+    pub alt: Option<String>,
+}
+
+impl Media {
+    /// Human-readable label for the media, for writers that cannot display it
+    pub fn label(&self) -> &str {
+        self.alt
+            .as_deref()
+            .or(self.url.as_deref())
+            .or(self.id.as_deref())
+            .unwrap_or("attachment")
+    }
+}
+
+impl AdfNode {
+    /// Parse a single ADF node, returning `None` if it is malformed.
+    pub fn from_value(value: &Value) -> Option<AdfNode> {
+        let obj = value.as_object()?;
+        let node_type = obj.get("type").and_then(Value::as_str).unwrap_or("");
+        let attrs = obj.get("attrs");
+        let attr_str = |name: &str| {
+            attrs
+                .and_then(|a| a.get(name))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        let content = || obj.get("content").map(parse_content).unwrap_or_default();
+
+        let node = match node_type {
+            "paragraph" => AdfNode::Paragraph(content()),
+            "text" => AdfNode::Text {
+                text: obj.get("text").and_then(Value::as_str)?.to_string(),
+                marks: obj
+                    .get("marks")
+                    .and_then(Value::as_array)
+                    .map(|marks| marks.iter().filter_map(Mark::from_value).collect())
+                    .unwrap_or_default(),
+            },
+            "hardBreak" => AdfNode::HardBreak,
+            "rule" => AdfNode::Rule,
+            "heading" => AdfNode::Heading {
+                level: attrs
+                    .and_then(|a| a.get("level"))
+                    .and_then(Value::as_u64)
+                    .unwrap_or(1)
+                    .clamp(1, 6) as u8,
+                content: content(),
+            },
+            "bulletList" => AdfNode::BulletList(content()),
+            "orderedList" => AdfNode::OrderedList {
+                start: attrs
+                    .and_then(|a| a.get("order"))
+                    .and_then(Value::as_u64)
+                    .unwrap_or(1),
+                items: content(),
+            },
+            "listItem" => AdfNode::ListItem(content()),
+            "codeBlock" => AdfNode::CodeBlock {
+                language: attr_str("language"),
+                content: content(),
+            },
+            "blockquote" => AdfNode::Blockquote(content()),
+            "panel" => AdfNode::Panel {
+                panel_type: attrs
+                    .and_then(|a| a.get("panelType"))
+                    .and_then(Value::as_str)
+                    .map(PanelType::from_name)
+                    .unwrap_or_default(),
+                content: content(),
+            },
+            "expand" | "nestedExpand" => AdfNode::Expand {
+                title: attr_str("title").filter(|t| !t.is_empty()),
+                content: content(),
+            },
+            "table" => AdfNode::Table(content()),
+            "tableRow" => AdfNode::TableRow(content()),
+            "tableCell" | "tableHeader" => AdfNode::TableCell {
+                header: node_type == "tableHeader",
+                content: content(),
+            },
+            "mediaSingle" | "mediaGroup" => AdfNode::MediaGroup(content()),
+            "media" => AdfNode::Media(Media {
+                media_type: attr_str("type").unwrap_or_else(|| "file".to_string()),
+                id: attr_str("id"),
+                url: attr_str("url"),
+                alt: attr_str("alt").filter(|a| !a.is_empty()),
+            }),
+            "mention" => {
+                let id = attr_str("id");
+                let name = match attr_str("text") {
+                    Some(text) => text.strip_prefix('@').unwrap_or(&text).to_string(),
+                    None => id.clone()?,
+                };
+                AdfNode::Mention { id, name }
+            }
+            "emoji" => AdfNode::Emoji {
+                short_name: attr_str("shortName")?,
+                text: attr_str("text"),
+            },
+            "inlineCard" => AdfNode::InlineCard {
+                url: attr_str("url")?,
+            },
+            "blockCard" | "embedCard" => AdfNode::BlockCard {
+                url: attr_str("url")?,
+            },
+            "status" => AdfNode::Status {
+                text: attr_str("text")?,
+            },
+            "date" => AdfNode::Date {
+                timestamp: attr_str("timestamp")?,
+            },
+            other => AdfNode::Unknown {
+                node_type: other.to_string(),
+                content: content(),
+            },
+        };
+
+        Some(node)
+    }
+
+    /// Child nodes that a writer should render between `start_node` and
+    /// `end_node`.
+    pub fn children(&self) -> &[AdfNode] {
+        match self {
+            AdfNode::Paragraph(content)
+            | AdfNode::BulletList(content)
+            | AdfNode::ListItem(content)
+            | AdfNode::Blockquote(content)
+            | AdfNode::Table(content)
+            | AdfNode::TableRow(content)
+            | AdfNode::MediaGroup(content)
+            | AdfNode::Heading { content, .. }
+            | AdfNode::OrderedList { items: content, .. }
+            | AdfNode::CodeBlock { content, .. }
+            | AdfNode::Panel { content, .. }
+            | AdfNode::Expand { content, .. }
+            | AdfNode::TableCell { content, .. }
+            | AdfNode::Unknown { content, .. } => content,
+            AdfNode::Text { .. }
+            | AdfNode::HardBreak
+            | AdfNode::Rule
+            | AdfNode::Media(_)
+            | AdfNode::Mention { .. }
+            | AdfNode::Emoji { .. }
+            | AdfNode::InlineCard { .. }
+            | AdfNode::BlockCard { .. }
+            | AdfNode::Status { .. }
+            | AdfNode::Date { .. } => &[],
+        }
+    }
+}
+
+impl Mark {
+    /// Parse a single mark, returning `None` if it is malformed.
+    pub fn from_value(value: &Value) -> Option<Mark> {
+        let mark_type = value.get("type").and_then(Value::as_str)?;
+        let mark = match mark_type {
+            "strong" => Mark::Strong,
+            "em" => Mark::Em,
+            "code" => Mark::Code,
+            "strike" => Mark::Strike,
+            "underline" => Mark::Underline,
+            "link" => Mark::Link {
+                href: value
+                    .get("attrs")
+                    .and_then(|a| a.get("href"))
+                    .and_then(Value::as_str)?
+                    .to_string(),
+            },
+            other => Mark::Unknown(other.to_string()),
+        };
+        Some(mark)
+    }
+}
+
+impl PanelType {
+    fn from_name(name: &str) -> PanelType {
+        match name {
+            "info" => PanelType::Info,
+            "note" => PanelType::Note,
+            "tip" => PanelType::Tip,
+            "success" => PanelType::Success,
+            "warning" => PanelType::Warning,
+            "error" => PanelType::Error,
+            _ => PanelType::Unknown,
+        }
+    }
+}
+
+/// Formatting of a run of text, flattened from its marks.
+///
+/// Writers apply formatting in a fixed nesting order regardless of the order
+/// the marks appear in the document, so this is usually more convenient than
+/// the marks themselves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStyle<'a> {
+    pub strong: bool,
+    pub em: bool,
+    pub code: bool,
+    pub strike: bool,
+    pub underline: bool,
+    pub link: Option<&'a str>,
+}
+
+impl<'a> TextStyle<'a> {
+    pub fn from_marks(marks: &'a [Mark]) -> Self {
+        let mut style = TextStyle::default();
+        for mark in marks {
+            match mark {
+                Mark::Strong => style.strong = true,
+                Mark::Em => style.em = true,
+                Mark::Code => style.code = true,
+                Mark::Strike => style.strike = true,
+                Mark::Underline => style.underline = true,
+                Mark::Link { href } => style.link = Some(href),
+                Mark::Unknown(_) => {}
+            }
+        }
+        style
+    }
+}
+
+/// Short label for a smart link: the last path segment of its URL, which for
+/// JIRA links is the issue key.
+pub fn card_label(url: &str) -> &str {
+    url.rsplit('/').next().unwrap_or(url)
+}
+
+/// Parse the `content` array of an ADF document or node.
+///
+/// Anything other than an array yields no nodes; malformed entries are
+/// skipped.
+pub fn parse_content(content: &Value) -> Vec<AdfNode> {
+    content
+        .as_array()
+        .map(|nodes| nodes.iter().filter_map(AdfNode::from_value).collect())
+        .unwrap_or_default()
+}
+
+/// Receives the nodes of an ADF document in document order.
+///
+/// [`render`] calls `start_node`, renders the node's
+/// [`children`](AdfNode::children), then calls `end_node`, except for text
+/// nodes which are delivered through `write_text` instead. Leaf nodes (e.g.
+/// mentions) should be rendered entirely in `start_node`. Writers should treat
+/// [`AdfNode::Unknown`] as transparent so its children still appear.
+pub trait AdfWriter {
+    /// Write a run of text with the given formatting.
+    fn write_text(&mut self, text: &str, style: TextStyle<'_>);
+
+    /// Called before a node's children are rendered.
+    fn start_node(&mut self, node: &AdfNode);
+
+    /// Called after a node's children are rendered.
+    fn end_node(&mut self, node: &AdfNode);
+}
+
+/// Walk `nodes`, delivering each to `writer`.
+pub fn render<W: AdfWriter + ?Sized>(nodes: &[AdfNode], writer: &mut W) {
+    for node in nodes {
+        if let AdfNode::Text { text, marks } = node {
+            writer.write_text(text, TextStyle::from_marks(marks));
+            continue;
+        }
+        writer.start_node(node);
+        render(node.children(), writer);
+        writer.end_node(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Writer that records the traversal, for checking `render`.
+    #[derive(Default)]
+    struct TraceWriter(Vec<String>);
+
+    impl AdfWriter for TraceWriter {
+        fn write_text(&mut self, text: &str, style: TextStyle<'_>) {
+            self.0.push(format!("text:{}:{}", text, style.strong));
+        }
+
+        fn start_node(&mut self, node: &AdfNode) {
+            self.0.push(format!("start:{}", node_name(node)));
+        }
+
+        fn end_node(&mut self, node: &AdfNode) {
+            self.0.push(format!("end:{}", node_name(node)));
+        }
+    }
+
+    fn node_name(node: &AdfNode) -> &str {
+        match node {
+            AdfNode::Paragraph(_) => "paragraph",
+            AdfNode::Unknown { node_type, .. } => node_type,
+            _ => "other",
+        }
+    }
+
+    #[test]
+    fn parses_block_and_inline_nodes() {
+        let nodes = parse_content(&json!([
+            {"type": "heading", "attrs": {"level": 9}, "content": [
+                {"type": "text", "text": "Title"}
+            ]},
+            {"type": "panel", "attrs": {"panelType": "warning"}, "content": []},
+            {"type": "orderedList", "attrs": {"order": 3}, "content": []},
+            {"type": "mention", "attrs": {"id": "abc", "text": "@Jane"}},
+            {"type": "tableHeader", "content": []},
+            {"type": "media", "attrs": {"type": "file", "id": "x", "alt": "log.txt"}}
+        ]));
+
+        assert_eq!(
+            nodes,
+            vec![
+                AdfNode::Heading {
+                    level: 6,
+                    content: vec![AdfNode::Text {
+                        text: "Title".to_string(),
+                        marks: vec![],
+                    }],
+                },
+                AdfNode::Panel {
+                    panel_type: PanelType::Warning,
+                    content: vec![],
+                },
+                AdfNode::OrderedList {
+                    start: 3,
+                    items: vec![],
+                },
+                AdfNode::Mention {
+                    id: Some("abc".to_string()),
+                    name: "Jane".to_string(),
+                },
+                AdfNode::TableCell {
+                    header: true,
+                    content: vec![],
+                },
+                AdfNode::Media(Media {
+                    media_type: "file".to_string(),
+                    id: Some("x".to_string()),
+                    url: None,
+                    alt: Some("log.txt".to_string()),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_marks() {
+        let nodes = parse_content(&json!([{
+            "type": "text",
+            "text": "x",
+            "marks": [
+                {"type": "strong"},
+                {"type": "link", "attrs": {"href": "https://example.com"}},
+                {"type": "textColor", "attrs": {"color": "#ff0000"}},
+                {"type": "link"}
+            ]
+        }]));
+
+        let AdfNode::Text { marks, .. } = &nodes[0] else {
+            panic!("expected text node: {:?}", nodes);
+        };
+        assert_eq!(
+            marks,
+            &vec![
+                Mark::Strong,
+                Mark::Link {
+                    href: "https://example.com".to_string()
+                },
+                Mark::Unknown("textColor".to_string()),
+            ]
+        );
+
+        let style = TextStyle::from_marks(marks);
+        assert!(style.strong);
+        assert_eq!(style.link, Some("https://example.com"));
+    }
+
+    #[test]
+    fn mention_without_text_uses_id() {
+        let nodes = parse_content(&json!([{"type": "mention", "attrs": {"id": "abc"}}]));
+        assert_eq!(
+            nodes,
+            vec![AdfNode::Mention {
+                id: Some("abc".to_string()),
+                name: "abc".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn scrubbed_mention_keeps_name() {
+        let nodes = parse_content(&json!([{"type": "mention", "attrs": {"text": "@Jane"}}]));
+        assert_eq!(
+            nodes,
+            vec![AdfNode::Mention {
+                id: None,
+                name: "Jane".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn card_label_is_last_path_segment() {
+        assert_eq!(card_label("https://example.com/browse/OS-1234"), "OS-1234");
+        assert_eq!(card_label("OS-1234"), "OS-1234");
+    }
+
+    #[test]
+    fn malformed_nodes_are_skipped() {
+        let nodes = parse_content(&json!([
+            "not an object",
+            {"type": "text"},
+            {"type": "mention", "attrs": {}},
+            {"type": "inlineCard"},
+            {"type": "hardBreak"}
+        ]));
+        assert_eq!(nodes, vec![AdfNode::HardBreak]);
+        assert!(parse_content(&json!({"type": "doc"})).is_empty());
+    }
+
+    #[test]
+    fn unknown_nodes_keep_their_children() {
+        let nodes = parse_content(&json!([
+            {"type": "layoutSection", "content": [
+                {"type": "paragraph", "content": [
+                    {"type": "text", "text": "inside", "marks": [{"type": "strong"}]}
+                ]}
+            ]}
+        ]));
+
+        let mut writer = TraceWriter::default();
+        render(&nodes, &mut writer);
+        assert_eq!(
+            writer.0,
+            vec![
+                "start:layoutSection",
+                "start:paragraph",
+                "text:inside:true",
+                "end:paragraph",
+                "end:layoutSection",
+            ]
+        );
+    }
+}
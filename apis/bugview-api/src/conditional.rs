@@ -0,0 +1,267 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Conditional request support (`ETag` / `Last-Modified`) for JSON endpoints.
+//!
+//! Clients polling `index.json` would otherwise re-download identical content
+//! on every request. JSON endpoints return [`HttpResponseConditional`], which
+//! carries the cache validators for the response and turns into a
+//! `304 Not Modified` when the request's `If-None-Match` (or, failing that,
+//! `If-Modified-Since`) header shows the client already has the current
+//! representation.
+//!
+//! The response metadata is identical to `HttpResponseOk<T>`, so the OpenAPI
+//! document and the generated clients are unaffected.
+
+use chrono::{DateTime, Utc};
+use dropshot::{Body, HttpCodedResponse, HttpError, HttpResponse, HttpResponseOk};
+use http::header::{ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use http::{Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Number of hex digits of the SHA-256 content digest used in the ETag.
+/// 128 bits is far more than needed to distinguish page revisions.
+const ETAG_DIGEST_HEX_LEN: usize = 32;
+
+/// Validators describing the current representation of a resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheValidators {
+    /// Strong entity tag, including the surrounding double quotes
+    pub etag: String,
+    /// Time the underlying content was last modified, if known
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl CacheValidators {
+    /// Compute validators from the content that determines the response.
+    ///
+    /// `content` should only include data that is stable across identical
+    /// requests. For example, the issue index hashes the issues themselves
+    /// rather than the whole response, because the pagination token handed
+    /// out is freshly generated on every request.
+    pub fn from_content<C: Serialize + ?Sized>(
+        content: &C,
+        last_modified: Option<DateTime<Utc>>,
+    ) -> Result<Self, serde_json::Error> {
+        let bytes = serde_json::to_vec(content)?;
+        let digest = Sha256::digest(&bytes);
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+
+        Ok(Self {
+            etag: format!("\"{}\"", &hex[..ETAG_DIGEST_HEX_LEN]),
+            // HTTP dates have one-second resolution; truncate so that a
+            // round-tripped If-Modified-Since compares equal.
+            last_modified: last_modified.and_then(|t| DateTime::from_timestamp(t.timestamp(), 0)),
+        })
+    }
+
+    /// Format `last_modified` as an HTTP-date (RFC 9110 IMF-fixdate).
+    pub fn last_modified_http_date(&self) -> Option<String> {
+        self.last_modified
+            .map(|t| t.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+    }
+
+    /// Returns true if the request headers show the client's cached copy is
+    /// still current.
+    ///
+    /// Per RFC 9110 section 13.1.3, `If-None-Match` takes precedence: when it
+    /// is present, `If-Modified-Since` is ignored. Entity tags are compared
+    /// weakly, as required for `If-None-Match`.
+    pub fn is_fresh(&self, headers: &HeaderMap) -> bool {
+        let if_none_match: Vec<&str> = headers
+            .get_all(IF_NONE_MATCH)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .collect();
+
+        if !if_none_match.is_empty() {
+            let ours = strip_weak_prefix(&self.etag);
+            return if_none_match
+                .iter()
+                .flat_map(|v| v.split(','))
+                .map(str::trim)
+                .any(|tag| tag == "*" || strip_weak_prefix(tag) == ours);
+        }
+
+        let Some(last_modified) = self.last_modified else {
+            return false;
+        };
+
+        headers
+            .get(IF_MODIFIED_SINCE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok())
+            .is_some_and(|since| last_modified.timestamp() <= since.timestamp())
+    }
+
+    /// Add the `ETag` and `Last-Modified` headers to a response.
+    fn apply(&self, headers: &mut HeaderMap) -> Result<(), HttpError> {
+        let etag = HeaderValue::from_str(&self.etag)
+            .map_err(|e| HttpError::for_internal_error(format!("Invalid ETag: {}", e)))?;
+        headers.insert(ETAG, etag);
+
+        if let Some(date) = self.last_modified_http_date() {
+            let date = HeaderValue::from_str(&date).map_err(|e| {
+                HttpError::for_internal_error(format!("Invalid Last-Modified: {}", e))
+            })?;
+            headers.insert(LAST_MODIFIED, date);
+        }
+
+        Ok(())
+    }
+}
+
+/// Strip the weak validator prefix (`W/`) from an entity tag.
+fn strip_weak_prefix(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+/// A `200 OK` JSON response that becomes `304 Not Modified` when the client's
+/// cached copy is current.
+#[derive(Debug)]
+pub enum HttpResponseConditional<T> {
+    /// The client has no current copy: send the full body
+    Modified {
+        body: T,
+        validators: CacheValidators,
+    },
+    /// The client's copy matches: send headers only
+    NotModified(CacheValidators),
+}
+
+impl<T> HttpResponseConditional<T> {
+    /// Build the response for `body`, checking the request headers against
+    /// `validators`.
+    pub fn new(request_headers: &HeaderMap, body: T, validators: CacheValidators) -> Self {
+        if validators.is_fresh(request_headers) {
+            HttpResponseConditional::NotModified(validators)
+        } else {
+            HttpResponseConditional::Modified { body, validators }
+        }
+    }
+
+    /// The validators attached to this response.
+    pub fn validators(&self) -> &CacheValidators {
+        match self {
+            HttpResponseConditional::Modified { validators, .. } => validators,
+            HttpResponseConditional::NotModified(validators) => validators,
+        }
+    }
+
+    /// Returns true if this response will be sent as `304 Not Modified`.
+    pub fn is_not_modified(&self) -> bool {
+        matches!(self, HttpResponseConditional::NotModified(_))
+    }
+}
+
+impl<T> HttpCodedResponse for HttpResponseConditional<T>
+where
+    T: JsonSchema + Serialize + Send + Sync + 'static,
+{
+    type Body = T;
+    const STATUS_CODE: StatusCode = StatusCode::OK;
+    const DESCRIPTION: &'static str = "successful operation";
+}
+
+impl<T> From<HttpResponseConditional<T>> for Result<Response<Body>, HttpError>
+where
+    T: JsonSchema + Serialize + Send + Sync + 'static,
+{
+    fn from(response: HttpResponseConditional<T>) -> Self {
+        match response {
+            HttpResponseConditional::Modified { body, validators } => {
+                let mut response = HttpResponseOk(body).to_result()?;
+                validators.apply(response.headers_mut())?;
+                Ok(response)
+            }
+            HttpResponseConditional::NotModified(validators) => {
+                let mut response = Response::builder()
+                    .status(StatusCode::NOT_MODIFIED)
+                    .body(Body::empty())
+                    .map_err(|e| {
+                        HttpError::for_internal_error(format!("Failed to build response: {}", e))
+                    })?;
+                validators.apply(response.headers_mut())?;
+                Ok(response)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validators() -> CacheValidators {
+        let modified = DateTime::parse_from_rfc3339("2023-10-02T04:00:00.250Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        CacheValidators::from_content(&["PROJ-1", "PROJ-2"], Some(modified)).unwrap()
+    }
+
+    fn headers(name: http::header::HeaderName, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn etag_is_stable_and_content_dependent() {
+        let a = CacheValidators::from_content(&["PROJ-1"], None).unwrap();
+        let b = CacheValidators::from_content(&["PROJ-1"], None).unwrap();
+        let c = CacheValidators::from_content(&["PROJ-2"], None).unwrap();
+
+        assert_eq!(a.etag, b.etag);
+        assert_ne!(a.etag, c.etag);
+        assert!(a.etag.starts_with('"') && a.etag.ends_with('"'));
+        assert_eq!(a.etag.len(), ETAG_DIGEST_HEX_LEN + 2);
+    }
+
+    #[test]
+    fn last_modified_is_http_date() {
+        assert_eq!(
+            validators().last_modified_http_date().as_deref(),
+            Some("Mon, 02 Oct 2023 04:00:00 GMT")
+        );
+    }
+
+    #[test]
+    fn if_none_match_matches_current_etag() {
+        let v = validators();
+        assert!(v.is_fresh(&headers(IF_NONE_MATCH, &v.etag)));
+        assert!(v.is_fresh(&headers(IF_NONE_MATCH, &format!("W/{}", v.etag))));
+        assert!(v.is_fresh(&headers(IF_NONE_MATCH, &format!("\"stale\", {}", v.etag))));
+        assert!(v.is_fresh(&headers(IF_NONE_MATCH, "*")));
+        assert!(!v.is_fresh(&headers(IF_NONE_MATCH, "\"stale\"")));
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence_over_if_modified_since() {
+        let v = validators();
+        let mut h = headers(IF_NONE_MATCH, "\"stale\"");
+        h.insert(
+            IF_MODIFIED_SINCE,
+            HeaderValue::from_static("Mon, 02 Oct 2023 04:00:00 GMT"),
+        );
+        assert!(!v.is_fresh(&h));
+    }
+
+    #[test]
+    fn if_modified_since_compares_dates() {
+        let v = validators();
+        assert!(v.is_fresh(&headers(IF_MODIFIED_SINCE, "Mon, 02 Oct 2023 04:00:00 GMT")));
+        assert!(v.is_fresh(&headers(IF_MODIFIED_SINCE, "Tue, 03 Oct 2023 00:00:00 GMT")));
+        assert!(!v.is_fresh(&headers(IF_MODIFIED_SINCE, "Mon, 02 Oct 2023 03:59:59 GMT")));
+        assert!(!v.is_fresh(&headers(IF_MODIFIED_SINCE, "not a date")));
+    }
+
+    #[test]
+    fn unconditional_request_is_not_fresh() {
+        assert!(!validators().is_fresh(&HeaderMap::new()));
+    }
+}
@@ -9,6 +9,13 @@ use http::Response;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+pub mod adf;
+mod conditional;
+mod negotiate;
+
+pub use conditional::{CacheValidators, HttpResponseConditional};
+pub use negotiate::{ResponseFormat, VARY_NEGOTIATED};
+
 // ============================================================================
 // Request/Response Types
 // ============================================================================
@@ -44,11 +51,67 @@ pub struct IssueListQuery {
     /// Next page token for pagination (token-based, not offset)
     #[serde(default)]
     pub next_page_token: Option<String>,
+    /// Page number (1-based). Ignored when `next_page_token` is given.
+    #[serde(default)]
+    pub page: Option<u32>,
     /// Sort field (key, created, or updated). Defaults to "updated" if omitted.
     #[serde(default)]
     pub sort: Option<IssueSort>,
 }
 
+/// Issue status filter for the JSON issue index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum IssueStatusFilter {
+    /// Issues without a resolution
+    Open,
+    /// Issues with a resolution (fixed, won't fix, duplicate, ...)
+    Resolved,
+}
+
+/// Query parameters for the JSON issue index
+///
+/// Pagination and sorting work as in [`IssueListQuery`]. Each filter that is
+/// given narrows the results further; filters are validated strictly and an
+/// invalid value is a 400 error rather than being ignored.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct IssueIndexQuery {
+    /// Next page token for pagination (token-based, not offset). Tokens are
+    /// only valid with the same filters as the request that returned them.
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+    /// Page number (1-based). Ignored when `next_page_token` is given.
+    #[serde(default)]
+    pub page: Option<u32>,
+    /// Sort field (key, created, or updated). Defaults to "updated" if omitted.
+    #[serde(default)]
+    pub sort: Option<IssueSort>,
+    /// Only include issues that are open (unresolved) or resolved
+    #[serde(default)]
+    pub status: Option<IssueStatusFilter>,
+    /// Only include issues updated on or after this date (ISO 8601 calendar
+    /// date, `YYYY-MM-DD`, in the JIRA server's timezone)
+    #[serde(default)]
+    pub updated_since: Option<String>,
+    /// Only include issues from this JIRA project, given as its key (e.g.
+    /// "OS"): an uppercase letter followed by uppercase letters, digits or
+    /// underscores
+    #[serde(default)]
+    pub project: Option<String>,
+}
+
+impl IssueIndexQuery {
+    /// The pagination and sort parameters, without the filters
+    pub fn list_query(&self) -> IssueListQuery {
+        IssueListQuery {
+            next_page_token: self.next_page_token.clone(),
+            page: self.page,
+            sort: self.sort,
+        }
+    }
+}
+
 /// Path parameter for label-specific queries
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct LabelPath {
@@ -56,6 +119,22 @@ pub struct LabelPath {
     pub key: String,
 }
 
+/// Path parameter for project index pages
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjectPath {
+    /// JIRA project key (e.g., "OS")
+    pub project: String,
+}
+
+/// Path parameters for label-specific project index pages
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjectLabelPath {
+    /// JIRA project key (e.g., "OS")
+    pub project: String,
+    /// Label key
+    pub key: String,
+}
+
 /// Path parameter for issue-specific queries
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct IssuePath {
@@ -63,6 +142,56 @@ pub struct IssuePath {
     pub key: String,
 }
 
+/// Query parameters for the full issue endpoint, and for the issue page when
+/// JSON is negotiated
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct IssueFullQuery {
+    /// Response format version: 1 (default) passes JIRA's issue fields
+    /// through, 2 returns a stable typed subset of them
+    #[serde(default)]
+    pub v: Option<u32>,
+}
+
+/// Path parameter for static asset requests
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StaticAssetPath {
+    /// Asset file name (e.g., "bugview.css")
+    pub asset: String,
+}
+
+/// Name of the cookie holding the visitor's color theme preference
+pub const THEME_COOKIE: &str = "bugview_theme";
+
+/// Color theme of the HTML pages
+///
+/// Stored in the [`THEME_COOKIE`] cookie by the theme toggle on every page.
+/// Without the cookie, pages follow the browser's `prefers-color-scheme`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Theme {
+    /// Follow the browser's color scheme preference (default)
+    #[default]
+    Auto,
+    /// Always use the light theme
+    Light,
+    /// Always use the dark theme
+    Dark,
+}
+
+impl Theme {
+    /// Read the theme preference from a `Cookie` request header value
+    ///
+    /// Falls back to [`Theme::Auto`] if the cookie is missing or unrecognized.
+    pub fn from_cookie_header(header: &str) -> Self {
+        header
+            .split(';')
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(name, _)| *name == THEME_COOKIE)
+            .and_then(|(_, value)| value.trim().parse().ok())
+            .unwrap_or_default()
+    }
+}
+
 /// Simplified issue information for list views
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct IssueListItem {
@@ -78,6 +207,35 @@ pub struct IssueListItem {
     pub updated: String,
     /// Creation timestamp
     pub created: String,
+    /// `updated` formatted for display (None if it could not be parsed)
+    #[serde(default)]
+    pub updated_display: Option<DisplayTimestamp>,
+    /// `created` formatted for display (None if it could not be parsed)
+    #[serde(default)]
+    pub created_display: Option<DisplayTimestamp>,
+    /// Public labels on the issue
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Issue type (e.g., "Bug", "Improvement")
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    /// Priority (e.g., "2 - Critical")
+    #[serde(default)]
+    pub priority: Option<String>,
+}
+
+/// A timestamp in machine-readable and human-readable forms
+///
+/// Both forms use the service's configured display timezone, so the HTML
+/// pages and JSON consumers show the same thing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DisplayTimestamp {
+    /// RFC 3339 timestamp (e.g., "2023-10-04T14:27:22+00:00")
+    pub iso: String,
+    /// Calendar date (e.g., "2023-10-04")
+    pub date: String,
+    /// Time relative to when the response was generated (e.g., "3 days ago")
+    pub relative: String,
 }
 
 /// Legacy issue summary format (for backwards compatibility with Node.js bugview)
@@ -102,6 +260,15 @@ pub struct IssueListResponse {
     pub next_page_token: Option<String>,
     /// True if this is the last page
     pub is_last: bool,
+    /// Number of this page (None when the page was requested by token)
+    #[serde(default)]
+    pub page: Option<u32>,
+    /// Number of the previous page (None on the first page)
+    #[serde(default)]
+    pub prev_page: Option<u32>,
+    /// Number of the next page (None if this is the last page)
+    #[serde(default)]
+    pub next_page: Option<u32>,
 }
 
 impl IssueListResponse {
@@ -116,8 +283,23 @@ impl IssueListResponse {
             issues,
             is_last: next_page_token.is_none(),
             next_page_token,
+            page: None,
+            prev_page: None,
+            next_page: None,
         }
     }
+
+    /// Set the number of this page, deriving `prev_page` and `next_page`.
+    ///
+    /// `next_page` is only set when there is a next page to go to.
+    pub fn with_page(mut self, page: Option<u32>) -> Self {
+        self.page = page;
+        self.prev_page = page.and_then(|p| p.checked_sub(1)).filter(|&p| p > 0);
+        self.next_page = page
+            .filter(|_| !self.is_last)
+            .and_then(|p| p.checked_add(1));
+        self
+    }
 }
 
 /// Full issue details (matches original Node.js bugview format)
@@ -131,6 +313,93 @@ pub struct IssueDetails {
     pub fields: serde_json::Value,
     /// Remote links (filtered by allowed domains)
     pub remotelinks: Vec<RemoteLink>,
+    /// Changes to status, resolution and public labels, oldest first
+    #[serde(default)]
+    pub history: Vec<IssueHistoryEntry>,
+}
+
+/// Full issue details, in the format version requested with `?v=`
+///
+/// Version 1 ([`IssueDetails`]) is the default. Version 2
+/// ([`IssueDetailsV2`]) is always tagged with `"version": 2`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum IssueFullResponse {
+    IssueDetailsV2(IssueDetailsV2),
+    IssueDetails(IssueDetails),
+}
+
+/// Full issue details, format version 2
+///
+/// Unlike [`IssueDetails`], which passes JIRA's issue fields through as they
+/// are, this is a fixed set of typed fields that does not change shape when
+/// JIRA's fields do.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct IssueDetailsV2 {
+    /// Response format version (always 2)
+    pub version: u32,
+    /// JIRA internal issue ID
+    pub id: String,
+    /// Issue key (e.g., "OS-1234")
+    pub key: jira_api::IssueKey,
+    /// Issue summary/title
+    pub summary: String,
+    /// Description rendered as HTML (None if the issue has no description)
+    pub description_html: Option<String>,
+    /// Issue status (e.g., "Open", "Resolved", "Closed")
+    pub status: String,
+    /// Resolution status (if resolved)
+    pub resolution: Option<String>,
+    /// Public labels
+    pub labels: Vec<String>,
+    /// Names of the versions the issue is fixed in
+    pub fix_versions: Vec<String>,
+    /// Creation timestamp
+    pub created: String,
+    /// Last updated timestamp
+    pub updated: String,
+    /// Remote links (filtered by allowed domains)
+    pub links: Vec<RemoteLink>,
+    /// Public comments, oldest first
+    pub comments: Vec<IssueComment>,
+    /// Changes to status, resolution and public labels, oldest first
+    pub history: Vec<IssueHistoryEntry>,
+}
+
+/// A public comment on an issue
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueComment {
+    /// Display name of the author
+    pub author: String,
+    /// When the comment was made
+    pub created: String,
+    /// When the comment was last edited (None if it never was)
+    pub edited: Option<String>,
+    /// Comment body rendered as HTML
+    pub body_html: String,
+}
+
+/// One entry in an issue's public change history
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueHistoryEntry {
+    /// When the change was made
+    pub created: String,
+    /// `created` formatted for display (None if it could not be parsed)
+    #[serde(default)]
+    pub created_display: Option<DisplayTimestamp>,
+    /// Changes to public fields made at this time
+    pub changes: Vec<IssueFieldChange>,
+}
+
+/// A change to a single issue field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct IssueFieldChange {
+    /// Field that changed ("status", "resolution" or "labels")
+    pub field: String,
+    /// Value before the change (None if the field was empty)
+    pub from: Option<String>,
+    /// Value after the change (None if the field was cleared)
+    pub to: Option<String>,
 }
 
 /// Remote link information
@@ -142,6 +411,97 @@ pub struct RemoteLink {
     pub title: String,
 }
 
+/// Query parameters for the access statistics endpoint
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StatsQuery {
+    /// Maximum number of issues and labels listed per window (default 20)
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+/// View count for a single issue
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueViews {
+    /// Issue key (e.g., "OS-1234")
+    pub key: String,
+    /// Number of views in the window
+    pub views: u64,
+}
+
+/// View count for a label, summed over the views of issues carrying it
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LabelViews {
+    /// Label name
+    pub label: String,
+    /// Number of views in the window
+    pub views: u64,
+}
+
+/// Access statistics for one time window
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StatsWindow {
+    /// Window name ("24h", "7d" or "30d")
+    pub window: String,
+    /// Start of the window (RFC3339, rounded down to the hour)
+    pub since: String,
+    /// Total issue views in the window
+    pub total_views: u64,
+    /// Most viewed issues, most views first
+    pub issues: Vec<IssueViews>,
+    /// Most viewed labels, most views first
+    pub labels: Vec<LabelViews>,
+}
+
+/// Issue cache counters since the service started
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct IssueCacheStats {
+    /// Issues currently cached
+    pub entries: u64,
+    /// Requests served from a fresh cached copy
+    pub hits: u64,
+    /// Requests that had to fetch the issue from JIRA
+    pub misses: u64,
+    /// Requests served from a cached copy past its TTL
+    pub stale: u64,
+    /// Background refreshes that failed to reach JIRA
+    pub refresh_failures: u64,
+}
+
+/// Response for the access statistics endpoint
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct StatsResponse {
+    /// Time the statistics were generated (RFC3339)
+    pub generated: String,
+    /// Statistics per time window, shortest window first
+    pub windows: Vec<StatsWindow>,
+    /// Issue cache counters
+    pub cache: IssueCacheStats,
+}
+
+/// Response for the configuration reload endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ConfigReloadResponse {
+    /// Whether the reloaded configuration differs from the one it replaced
+    pub changed: bool,
+    /// Label that marks issues as public
+    pub default_label: String,
+    /// Additional labels issues can be filtered by
+    pub allowed_labels: Vec<String>,
+    /// Keys of the projects with public labels of their own
+    pub projects: Vec<String>,
+    /// Domains that remote links may point to
+    pub allowed_domains: Vec<String>,
+}
+
+/// Response for the cache purge endpoint
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CachePurgeResponse {
+    /// Cached issues dropped
+    pub issues: u64,
+    /// Cached index searches dropped
+    pub searches: u64,
+}
+
 /// Bugview API Trait
 ///
 /// This API provides public read-only access to JIRA issues that have been
@@ -153,7 +513,9 @@ pub trait BugviewApi {
 
     /// Get issue index as JSON
     ///
-    /// Returns a paginated list of public issues.
+    /// Returns a paginated list of public issues, optionally filtered by
+    /// status, last update date and project. Supports conditional requests
+    /// via `If-None-Match` / `If-Modified-Since`.
     #[endpoint {
         method = GET,
         path = "/bugview/index.json",
@@ -161,13 +523,14 @@ pub trait BugviewApi {
     }]
     async fn get_issue_index_json(
         rqctx: RequestContext<Self::Context>,
-        query: Query<IssueListQuery>,
-    ) -> Result<HttpResponseOk<IssueListResponse>, HttpError>;
+        query: Query<IssueIndexQuery>,
+    ) -> Result<HttpResponseConditional<IssueListResponse>, HttpError>;
 
     /// Get issue summary as JSON (legacy format)
     ///
     /// Returns issue key, summary, and web URL. This endpoint maintains
     /// backwards compatibility with the original Node.js bugview service.
+    /// Supports conditional requests.
     #[endpoint {
         method = GET,
         path = "/bugview/json/{key}",
@@ -176,11 +539,13 @@ pub trait BugviewApi {
     async fn get_issue_json(
         rqctx: RequestContext<Self::Context>,
         path: Path<IssuePath>,
-    ) -> Result<HttpResponseOk<IssueSummary>, HttpError>;
+    ) -> Result<HttpResponseConditional<IssueSummary>, HttpError>;
 
     /// Get full issue details as JSON
     ///
-    /// Returns complete issue information including all fields.
+    /// Returns complete issue information including all fields. With `v=2`,
+    /// returns a stable typed subset of the fields instead of passing JIRA's
+    /// fields through. Supports conditional requests.
     #[endpoint {
         method = GET,
         path = "/bugview/fulljson/{key}",
@@ -189,7 +554,57 @@ pub trait BugviewApi {
     async fn get_issue_full_json(
         rqctx: RequestContext<Self::Context>,
         path: Path<IssuePath>,
-    ) -> Result<HttpResponseOk<IssueDetails>, HttpError>;
+        query: Query<IssueFullQuery>,
+    ) -> Result<HttpResponseConditional<IssueFullResponse>, HttpError>;
+
+    // ========================================================================
+    // Admin Endpoints
+    // ========================================================================
+
+    /// Get issue access statistics
+    ///
+    /// Returns the most viewed issues and labels over the last 24 hours,
+    /// 7 days and 30 days. Requires the admin bearer token configured on the
+    /// service; returns 404 when no token is configured.
+    #[endpoint {
+        method = GET,
+        path = "/bugview/stats.json",
+        tags = ["admin"],
+    }]
+    async fn get_stats_json(
+        rqctx: RequestContext<Self::Context>,
+        query: Query<StatsQuery>,
+    ) -> Result<HttpResponseOk<StatsResponse>, HttpError>;
+
+    /// Reload the service configuration
+    ///
+    /// Re-reads the public labels, projects, allowed domains and the other
+    /// reloadable settings, without restarting the service. Requires the
+    /// admin bearer token configured on the service; returns 404 when no
+    /// token is configured. An invalid configuration is a 400 error and
+    /// leaves the running configuration in place.
+    #[endpoint {
+        method = POST,
+        path = "/bugview/admin/reload",
+        tags = ["admin"],
+    }]
+    async fn reload_config(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<ConfigReloadResponse>, HttpError>;
+
+    /// Purge the issue and index caches
+    ///
+    /// Drops every cached issue and index search, so that the next request
+    /// for each goes to JIRA. Requires the admin bearer token configured on
+    /// the service; returns 404 when no token is configured.
+    #[endpoint {
+        method = POST,
+        path = "/bugview/admin/cache/purge",
+        tags = ["admin"],
+    }]
+    async fn purge_caches(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<CachePurgeResponse>, HttpError>;
 
     // ========================================================================
     // HTML Endpoints
@@ -222,9 +637,44 @@ pub trait BugviewApi {
         query: Query<IssueListQuery>,
     ) -> Result<Response<Body>, HttpError>;
 
+    /// Get a project's issue index as HTML
+    ///
+    /// Returns a paginated HTML view of the public issues in one project.
+    /// Projects configured with a public label of their own use it;
+    /// other projects use the service's default label.
+    #[endpoint {
+        method = GET,
+        path = "/bugview/project/{project}/index.html",
+        tags = ["html"],
+    }]
+    async fn get_project_index_html(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<ProjectPath>,
+        query: Query<IssueListQuery>,
+    ) -> Result<Response<Body>, HttpError>;
+
+    /// Get a project's issues for a specific label as HTML
+    ///
+    /// Returns a paginated HTML view of the public issues in one project
+    /// with the specified label, which must be one of the project's
+    /// allowed labels.
+    #[endpoint {
+        method = GET,
+        path = "/bugview/project/{project}/label/{key}",
+        tags = ["html"],
+    }]
+    async fn get_project_label_index_html(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<ProjectLabelPath>,
+        query: Query<IssueListQuery>,
+    ) -> Result<Response<Body>, HttpError>;
+
     /// Get issue details as HTML
     ///
-    /// Returns an HTML view of a single issue with full details.
+    /// Returns an HTML view of a single issue with full details. Clients
+    /// that prefer `application/json` in their `Accept` header get the same
+    /// response as `/bugview/fulljson/{key}` instead, including its `v`
+    /// parameter and conditional request support.
     #[endpoint {
         method = GET,
         path = "/bugview/issue/{key}",
@@ -233,11 +683,34 @@ pub trait BugviewApi {
     async fn get_issue_html(
         rqctx: RequestContext<Self::Context>,
         path: Path<IssuePath>,
+        query: Query<IssueFullQuery>,
+    ) -> Result<Response<Body>, HttpError>;
+
+    /// Get a static asset
+    ///
+    /// Serves the stylesheet and script used by the HTML pages. The assets
+    /// are embedded in the service, so the pages load nothing from third-party
+    /// hosts.
+    #[endpoint {
+        method = GET,
+        path = "/bugview/static/{asset}",
+        tags = ["html"],
+    }]
+    async fn get_static_asset(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<StaticAssetPath>,
     ) -> Result<Response<Body>, HttpError>;
 
     // ========================================================================
     // Redirects
     // ========================================================================
+    //
+    // The Node.js bugview served issues at /bugview/{key}. That route cannot
+    // be represented here: Dropshot does not allow a variable segment
+    // (/bugview/{key}) at the same path depth as literal segments
+    // (/bugview/index.html, /bugview/label/{key}, ...). Legacy issue URLs are
+    // redirected to /bugview/issue/{key} by the reverse proxy instead; see
+    // the bugview-service README.
 
     /// Redirect /bugview to /bugview/index.html
     #[endpoint {
@@ -0,0 +1,197 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Content negotiation for endpoints that serve both HTML and JSON.
+//!
+//! `/bugview/issue/{key}` is the canonical URL of an issue. Browsers get the
+//! HTML page there, while clients that send `Accept: application/json` get
+//! the same JSON as `/bugview/fulljson/{key}`, so that one URL can be linked
+//! to from anywhere. The dedicated JSON endpoints remain for compatibility.
+
+use http::header::{ACCEPT, HeaderMap};
+
+/// Value of the `Vary` header on negotiated responses, so that caches keep
+/// the HTML and JSON representations apart.
+pub const VARY_NEGOTIATED: &str = "Accept";
+
+/// Representation chosen for a negotiated response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// `text/html` (the default)
+    Html,
+    /// `application/json`
+    Json,
+}
+
+impl ResponseFormat {
+    /// Choose a representation from the request's `Accept` headers.
+    ///
+    /// Each format gets the quality of the most specific media range that
+    /// matches it (`type/subtype` over `type/*` over `*/*`). JSON is only
+    /// chosen when it is strictly preferred, so requests without an `Accept`
+    /// header, with `*/*`, or with a browser's usual header get HTML.
+    pub fn from_accept(headers: &HeaderMap) -> Self {
+        let ranges: Vec<MediaRange> = headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .filter_map(MediaRange::parse)
+            .collect();
+
+        let html = quality(&ranges, "text", "html");
+        let json = quality(&ranges, "application", "json");
+        if json > html {
+            ResponseFormat::Json
+        } else {
+            ResponseFormat::Html
+        }
+    }
+}
+
+/// One media range of an `Accept` header, e.g. `text/*;q=0.8`
+struct MediaRange<'a> {
+    type_: &'a str,
+    subtype: &'a str,
+    /// Quality in thousandths, as the header allows at most three decimals
+    quality: u16,
+}
+
+impl<'a> MediaRange<'a> {
+    fn parse(s: &'a str) -> Option<Self> {
+        let mut params = s.split(';');
+        let (type_, subtype) = params.next()?.trim().split_once('/')?;
+
+        let mut quality = 1000;
+        for param in params {
+            if let Some((name, value)) = param.trim().split_once('=')
+                && name.trim().eq_ignore_ascii_case("q")
+            {
+                quality = parse_quality(value.trim())?;
+            }
+        }
+
+        Some(Self {
+            type_: type_.trim(),
+            subtype: subtype.trim(),
+            quality,
+        })
+    }
+
+    /// How specifically this range matches `type_/subtype`, if it does
+    fn specificity(&self, type_: &str, subtype: &str) -> Option<u8> {
+        if self.type_ == "*" {
+            return (self.subtype == "*").then_some(0);
+        }
+        if !self.type_.eq_ignore_ascii_case(type_) {
+            return None;
+        }
+        if self.subtype == "*" {
+            return Some(1);
+        }
+        self.subtype.eq_ignore_ascii_case(subtype).then_some(2)
+    }
+}
+
+/// Parse a quality value (`0` to `1` with up to three decimals) into
+/// thousandths
+fn parse_quality(s: &str) -> Option<u16> {
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if fraction.len() > 3 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let whole: u16 = match whole {
+        "0" => 0,
+        "1" => 1000,
+        _ => return None,
+    };
+    let fraction: u16 = format!("{:0<3}", fraction).parse().ok()?;
+    let quality = whole + fraction;
+    (quality <= 1000).then_some(quality)
+}
+
+/// Quality the ranges give `type_/subtype`: that of the most specific range
+/// matching it, or 0 if none does
+fn quality(ranges: &[MediaRange<'_>], type_: &str, subtype: &str) -> u16 {
+    ranges
+        .iter()
+        .filter_map(|r| r.specificity(type_, subtype).map(|s| (s, r.quality)))
+        .max_by_key(|(specificity, _)| *specificity)
+        .map_or(0, |(_, quality)| quality)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::header::HeaderValue;
+
+    fn format(accept: &str) -> ResponseFormat {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_str(accept).unwrap());
+        ResponseFormat::from_accept(&headers)
+    }
+
+    #[test]
+    fn defaults_to_html() {
+        assert_eq!(
+            ResponseFormat::from_accept(&HeaderMap::new()),
+            ResponseFormat::Html
+        );
+        assert_eq!(format("*/*"), ResponseFormat::Html);
+        assert_eq!(format("text/plain"), ResponseFormat::Html);
+        assert_eq!(format("not a media type"), ResponseFormat::Html);
+        // Firefox
+        assert_eq!(
+            format("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"),
+            ResponseFormat::Html
+        );
+    }
+
+    #[test]
+    fn json_when_preferred() {
+        assert_eq!(format("application/json"), ResponseFormat::Json);
+        assert_eq!(format("Application/JSON"), ResponseFormat::Json);
+        assert_eq!(format("application/*"), ResponseFormat::Json);
+        assert_eq!(
+            format("application/json, text/html;q=0.5"),
+            ResponseFormat::Json
+        );
+        assert_eq!(format("application/json, */*;q=0.1"), ResponseFormat::Json);
+    }
+
+    #[test]
+    fn quality_decides() {
+        assert_eq!(
+            format("text/html;q=0.5, application/json;q=0.9"),
+            ResponseFormat::Json
+        );
+        assert_eq!(
+            format("text/html, application/json;q=0.9"),
+            ResponseFormat::Html
+        );
+        // Ties go to HTML
+        assert_eq!(format("text/html, application/json"), ResponseFormat::Html);
+        // The most specific range wins: JSON is explicitly refused here
+        assert_eq!(
+            format("application/*, application/json;q=0"),
+            ResponseFormat::Html
+        );
+        // An invalid quality discards the range
+        assert_eq!(format("application/json;q=2"), ResponseFormat::Html);
+    }
+
+    #[test]
+    fn parses_quality() {
+        assert_eq!(parse_quality("1"), Some(1000));
+        assert_eq!(parse_quality("1.000"), Some(1000));
+        assert_eq!(parse_quality("0.5"), Some(500));
+        assert_eq!(parse_quality("0.125"), Some(125));
+        assert_eq!(parse_quality("0"), Some(0));
+        assert_eq!(parse_quality("1.5"), None);
+        assert_eq!(parse_quality("0.1234"), None);
+        assert_eq!(parse_quality("high"), None);
+    }
+}
@@ -23,6 +23,15 @@
 //! To ensure the generated `cloudapi-client` can correctly deserialize errors from
 //! the real CloudAPI, the `openapi-manager` crate applies a post-generation transform
 //! to patch the Error schema. See `openapi-manager/src/transforms.rs` for details.
+//!
+//! # Health Endpoints
+//!
+//! The unauthenticated `/--ping` and `/--version` endpoints used by load
+//! balancers live in a separate [`CloudApiHealth`] trait. Dropshot does not
+//! allow literal segments (e.g. `/--ping`) and variable segments (e.g.
+//! `/{account}`) at the same path depth, so the two traits must be registered
+//! with separate `ApiDescription`s. Servers route `/--*` paths to the health
+//! API and everything else to [`CloudApi`].
 
 use dropshot::{
     Body, HttpError, HttpResponseAccepted, HttpResponseCreated, HttpResponseDeleted,
@@ -47,7 +56,7 @@ pub use vmapi_api::MigrationPhase;
 pub use vmapi_api::SnapshotState;
 
 /// URL for CloudAPI documentation
-// The Node.js CloudAPI has endpoints at /, /docs, /favicon.ico, and /--ping
+// The Node.js CloudAPI has endpoints at /, /docs, and /favicon.ico
 // that cannot be represented in the Dropshot API trait because they conflict
 // with /{account} variable path routing. Dropshot does not allow both literal
 // segments (e.g., /docs) and variable segments (e.g., /{account}) at the same
@@ -78,10 +87,10 @@ pub const FAVICON_URL: &str = "http://apidocs.tritondatacenter.com/favicon.ico";
 /// - Datacenters
 /// - Services
 /// - Migrations
-// Note: Endpoints /, /docs, /favicon.ico, and /--ping from the Node.js
-// CloudAPI cannot be included due to Dropshot routing limitations.
-// See DOCS_URL constant for details. These should be handled at the
-// reverse proxy or HTTP server level.
+// Note: Endpoints /, /docs, and /favicon.ico from the Node.js CloudAPI
+// cannot be included due to Dropshot routing limitations. See DOCS_URL
+// constant for details. These should be handled at the reverse proxy or
+// HTTP server level. /--ping and /--version are modeled by CloudApiHealth.
 #[dropshot::api_description]
 pub trait CloudApi {
     /// Context type for request handlers
@@ -176,6 +185,41 @@ pub trait CloudApi {
         query: Query<ListMachinesQuery>,
     ) -> Result<Response<Body>, HttpError>;
 
+    /// Summarize machines
+    ///
+    /// Counts the account's machines by state, brand and package, for
+    /// dashboards that do not need the machines themselves. This is not a
+    /// CloudAPI endpoint, and would be `/{account}/machines/summary`, but
+    /// Dropshot does not allow a literal segment at the same path depth as
+    /// `/{account}/machines/{machine}`.
+    #[endpoint {
+        method = GET,
+        path = "/{account}/machinesummary",
+        tags = ["machines"],
+    }]
+    async fn get_machine_summary(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<AccountPath>,
+    ) -> Result<HttpResponseOk<MachineSummary>, HttpError>;
+
+    /// Export machines
+    ///
+    /// Streams the account's machines as CSV or newline-delimited JSON with
+    /// the requested columns, for inventories too large to list as one JSON
+    /// array. The `Content-Type` is `text/csv` or `application/x-ndjson`.
+    /// Like `machinesummary`, this is not a CloudAPI endpoint, and is not
+    /// under `/{account}/machines/` for the same reason.
+    #[endpoint {
+        method = GET,
+        path = "/{account}/machineexport",
+        tags = ["machines"],
+    }]
+    async fn export_machines(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<AccountPath>,
+        query: Query<MachineExportQuery>,
+    ) -> Result<Response<Body>, HttpError>;
+
     /// Get a machine
     #[endpoint {
         method = GET,
@@ -2060,8 +2104,10 @@ pub trait CloudApi {
 
     /// Perform migration action
     ///
-    /// Performs a migration action on a machine. The `action` field in the
-    /// request body specifies the operation:
+    /// Performs a migration action on a machine. The action can be specified
+    /// either in the request body (`{"action": "begin"}`) or as a query
+    /// parameter (`?action=begin`). Body takes precedence over the query
+    /// parameter. The action is one of:
     ///
     /// - `begin`: Start a new migration. Optionally accepts `affinity` rules.
     /// - `sync`: Sync data to the target server.
@@ -2081,13 +2127,15 @@ pub trait CloudApi {
     async fn migrate(
         rqctx: RequestContext<Self::Context>,
         path: Path<MachinePath>,
+        query: Query<MigrationActionQuery>,
         body: TypedBody<MigrateRequest>,
     ) -> Result<HttpResponseOk<Migration>, HttpError>;
 
     /// Watch migration progress
     ///
-    /// Streams real-time migration progress updates via WebSocket.
-    /// Returns a stream of JSON objects with progress information.
+    /// Streams real-time migration progress updates via WebSocket. Each
+    /// message is a JSON-encoded `MigrationWatchEvent`: `progress` events
+    /// while the current action runs, then a final `end` event.
     #[channel {
         protocol = WEBSOCKETS,
         path = "/{account}/migrations/{machine}/watch",
@@ -2428,3 +2476,57 @@ pub trait CloudApi {
         body: TypedBody<ReplaceRoleTagsRequest>,
     ) -> Result<HttpResponseOk<RoleTagsResponse>, HttpError>;
 }
+
+// ============================================================================
+// CloudApiHealth — unauthenticated service health endpoints
+// ============================================================================
+
+/// CloudAPI service health endpoints
+///
+/// These are unauthenticated and sit outside the `/{account}` namespace, so
+/// they are modeled separately from [`CloudApi`] (see the crate docs).
+#[dropshot::api_description]
+pub trait CloudApiHealth {
+    /// Context type for request handlers
+    type Context: Send + Sync + 'static;
+
+    /// Ping the service
+    ///
+    /// Reports the API versions the server supports.
+    #[endpoint {
+        method = GET,
+        path = "/--ping",
+        tags = ["health"],
+    }]
+    async fn ping(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<PingResponse>, HttpError>;
+
+    /// Head ping
+    #[endpoint {
+        method = HEAD,
+        path = "/--ping",
+        tags = ["health"],
+    }]
+    async fn head_ping(rqctx: RequestContext<Self::Context>) -> Result<Response<Body>, HttpError>;
+
+    /// Get the server version
+    #[endpoint {
+        method = GET,
+        path = "/--version",
+        tags = ["health"],
+    }]
+    async fn get_version(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<VersionResponse>, HttpError>;
+
+    /// Head server version
+    #[endpoint {
+        method = HEAD,
+        path = "/--version",
+        tags = ["health"],
+    }]
+    async fn head_version(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<Response<Body>, HttpError>;
+}
@@ -96,6 +96,34 @@ pub struct UpdateAccountRequest {
     pub triton_cns_enabled: Option<bool>,
 }
 
+/// Dimension a provisioning limit counts
+///
+/// Units for a limit's `value` and `used`:
+/// - `machines` → count of VMs
+/// - `ram` → MiB
+/// - `quota` → GiB
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitBy {
+    #[default]
+    Machines,
+    Ram,
+    Quota,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Kind of filter restricting which provisions a limit applies to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitCheck {
+    Brand,
+    Image,
+    Os,
+    #[serde(other)]
+    Unknown,
+}
+
 /// A single provisioning limit entry.
 ///
 /// Each limit constrains a specific dimension (VM count, RAM, or disk quota),
@@ -103,10 +131,7 @@ pub struct UpdateAccountRequest {
 /// matching provisions; `0` means unlimited (filtered out before the response
 /// reaches the client).
 ///
-/// Units for `value` and `used` depend on `by`:
-/// - absent / `"machines"` → count of VMs
-/// - `"ram"` → MiB
-/// - `"quota"` → GiB
+/// Units for `value` and `used` depend on `by`, see [`LimitBy`].
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProvisioningLimit {
     /// The limit value (threshold).
@@ -114,13 +139,14 @@ pub struct ProvisioningLimit {
     /// Current usage against this limit.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub used: Option<i64>,
-    /// What dimension the limit counts: `"ram"`, `"quota"`, or `"machines"`.
-    /// When absent, defaults to counting VMs.
+    /// What dimension the limit counts. When absent, defaults to counting
+    /// VMs.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub by: Option<String>,
-    /// Type of filter applied: `"brand"`, `"image"`, or `"os"`.
+    pub by: Option<LimitBy>,
+    /// Type of filter applied. When absent, the limit applies to every
+    /// provision.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub check: Option<String>,
+    pub check: Option<LimitCheck>,
     /// Brand filter value (when `check` is `"brand"`).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub brand: Option<String>,
@@ -132,6 +158,91 @@ pub struct ProvisioningLimit {
     pub os: Option<String>,
 }
 
+impl ProvisioningLimit {
+    /// The dimension this limit counts, `Machines` when not given
+    pub fn by(&self) -> LimitBy {
+        self.by.unwrap_or_default()
+    }
+
+    /// The `check` filter and the value it matches (e.g. `brand` and
+    /// `"bhyve"`), if both are present
+    pub fn filter(&self) -> Option<(LimitCheck, &str)> {
+        let value = match self.check? {
+            LimitCheck::Brand => self.brand.as_deref(),
+            LimitCheck::Image => self.image.as_deref(),
+            LimitCheck::Os => self.os.as_deref(),
+            LimitCheck::Unknown => None,
+        };
+        Some((self.check?, value?))
+    }
+
+    /// Whether this limit applies to `candidate`
+    ///
+    /// A limit without a `check` applies to every provision. A limit whose
+    /// filter cannot be interpreted, or whose filtered attribute the
+    /// candidate does not know, does not apply.
+    pub fn applies_to(&self, candidate: &ProvisionCandidate) -> bool {
+        if self.check.is_none() {
+            return true;
+        }
+        let Some((check, value)) = self.filter() else {
+            return false;
+        };
+        let attribute = match check {
+            LimitCheck::Brand => candidate.brand.as_deref(),
+            LimitCheck::Image => candidate.image.as_deref(),
+            LimitCheck::Os => candidate.os.as_deref(),
+            LimitCheck::Unknown => None,
+        };
+        attribute == Some(value)
+    }
+
+    /// Whether provisioning `candidate` would go over this limit
+    ///
+    /// Limits that do not apply to the candidate, or count an unknown
+    /// dimension, are never exceeded.
+    pub fn exceeded_by(&self, candidate: &ProvisionCandidate) -> bool {
+        if !self.applies_to(candidate) {
+            return false;
+        }
+        if self.value < 0 {
+            return true;
+        }
+        if self.value == 0 {
+            return false;
+        }
+
+        let used = i128::from(self.used.unwrap_or(0));
+        let value = i128::from(self.value);
+        match self.by() {
+            LimitBy::Machines => used + 1 > value,
+            LimitBy::Ram => used + i128::from(candidate.ram_mib) > value,
+            // Compare in MiB so that partial GiB of disk are not rounded away
+            LimitBy::Quota => (used * 1024) + i128::from(candidate.disk_mib) > value * 1024,
+            LimitBy::Unknown => false,
+        }
+    }
+}
+
+/// A provision to evaluate against [`ProvisioningLimits`] locally
+///
+/// Built from the package (RAM and disk) and the image (name and OS) the
+/// instance would be created with. Attributes left as `None` never match a
+/// limit that filters on them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvisionCandidate {
+    /// Brand the instance would have (e.g. `"bhyve"`)
+    pub brand: Option<String>,
+    /// Name of the image the instance would be created from
+    pub image: Option<String>,
+    /// OS of the image the instance would be created from
+    pub os: Option<String>,
+    /// Package memory in MiB
+    pub ram_mib: u64,
+    /// Package disk in MiB
+    pub disk_mib: u64,
+}
+
 /// Provisioning limits for an account — an array of limit entries.
 ///
 /// Newtype wrapper rather than a type alias so the generated OpenAPI
@@ -141,6 +252,25 @@ pub struct ProvisioningLimit {
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct ProvisioningLimits(pub Vec<ProvisioningLimit>);
 
+impl ProvisioningLimits {
+    /// The limits that provisioning `candidate` would go over
+    ///
+    /// This is an advisory, local check against limits fetched earlier:
+    /// usage may have changed since, and CloudAPI remains the authority when
+    /// the provision is actually requested.
+    pub fn exceeded_by(&self, candidate: &ProvisionCandidate) -> Vec<&ProvisioningLimit> {
+        self.iter()
+            .filter(|limit| limit.exceeded_by(candidate))
+            .collect()
+    }
+
+    /// Whether `candidate` fits within every limit, see
+    /// [`ProvisioningLimits::exceeded_by`]
+    pub fn allow(&self, candidate: &ProvisionCandidate) -> bool {
+        self.iter().all(|limit| !limit.exceeded_by(candidate))
+    }
+}
+
 impl std::ops::Deref for ProvisioningLimits {
     type Target = Vec<ProvisioningLimit>;
     fn deref(&self) -> &Self::Target {
@@ -0,0 +1,787 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Aperture policy rule language
+//!
+//! Policy rules are sentences in the Aperture language, stored and sent as
+//! plain strings:
+//!
+//! ```text
+//! CAN listmachines, getmachine AND rebootmachine
+//! CAN rebootmachine IF requesttime::time > 07:30:00 AND requesttime::day IN (Mon, Tue, Wed)
+//! Fred CANNOT deletemachine *
+//! ```
+//!
+//! A rule is an optional list of principals, `CAN` or `CANNOT`, a list of
+//! actions, an optional list of resources, and optional conditions after
+//! `IF` (or `WHEN`). List items are separated by commas or `AND`. Conditions
+//! compare an attribute, optionally typed as `name::type`, with `=`, `<`,
+//! `<=`, `>`, `>=`, `IN (list)` or `LIKE /regex/`, and combine with `AND`,
+//! `OR`, `NOT` and parentheses. Keywords are case-insensitive.
+//!
+//! [`PolicyRule`] parses a rule so clients can reject a malformed one before
+//! sending it, and formats it canonically (upper-case keywords, single
+//! spaces, comma-separated lists) so that rules written by hand and rules
+//! read back from CloudAPI compare equal. Only syntax is checked: action
+//! names and attribute types are not, as they depend on the server.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Whether a rule grants or denies its actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleEffect {
+    /// `CAN`
+    Allow,
+    /// `CANNOT`
+    Deny,
+}
+
+/// Comparison operator in a rule condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionOp {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+    Like,
+}
+
+impl ConditionOp {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::In => "IN",
+            Self::Like => "LIKE",
+        }
+    }
+}
+
+/// Right-hand side of a rule condition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConditionValue {
+    /// A bare word, such as `10.0.0.0/8` or `07:30:00`
+    Word(String),
+    /// A quoted string, unescaped
+    Quoted(String),
+    /// A parenthesized list, for `IN`
+    List(Vec<ConditionValue>),
+    /// A `/regex/flags` literal, for `LIKE`, as written
+    Regex(String),
+}
+
+/// Condition under which a rule applies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleCondition {
+    /// `name[::type] op value`
+    Compare {
+        name: String,
+        type_name: Option<String>,
+        op: ConditionOp,
+        value: ConditionValue,
+    },
+    Not(Box<RuleCondition>),
+    And(Vec<RuleCondition>),
+    Or(Vec<RuleCondition>),
+}
+
+/// A parsed Aperture policy rule
+///
+/// Parse with [`str::parse`]; [`Display`](fmt::Display) gives the canonical
+/// form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyRule {
+    /// Principals the rule applies to; empty for the role's members
+    pub principals: Vec<String>,
+    pub effect: RuleEffect,
+    pub actions: Vec<String>,
+    /// Resources the actions apply to; empty for any
+    pub resources: Vec<String>,
+    pub condition: Option<RuleCondition>,
+}
+
+/// Reason a policy rule could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPolicyRule {
+    /// The rule as given
+    pub rule: String,
+    /// Byte offset in `rule` where the problem was found
+    pub offset: usize,
+    /// What was wrong
+    pub message: String,
+}
+
+impl fmt::Display for InvalidPolicyRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = self.rule[..self.offset.min(self.rule.len())]
+            .chars()
+            .count()
+            + 1;
+        write!(
+            f,
+            "invalid policy rule '{}': {} at column {}",
+            self.rule, self.message, column
+        )
+    }
+}
+
+impl std::error::Error for InvalidPolicyRule {}
+
+impl FromStr for PolicyRule {
+    type Err = InvalidPolicyRule;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        Parser::new(rule)
+            .rule()
+            .map_err(|(offset, message)| InvalidPolicyRule {
+                rule: rule.to_string(),
+                offset,
+                message,
+            })
+    }
+}
+
+impl fmt::Display for PolicyRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.principals.is_empty() {
+            write!(f, "{} ", join_items(&self.principals))?;
+        }
+        let effect = match self.effect {
+            RuleEffect::Allow => "CAN",
+            RuleEffect::Deny => "CANNOT",
+        };
+        write!(f, "{} {}", effect, join_items(&self.actions))?;
+        if !self.resources.is_empty() {
+            write!(f, " {}", join_items(&self.resources))?;
+        }
+        if let Some(condition) = &self.condition {
+            write!(f, " IF {}", condition)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RuleCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compare {
+                name,
+                type_name,
+                op,
+                value,
+            } => {
+                write!(f, "{}", name)?;
+                if let Some(type_name) = type_name {
+                    write!(f, "::{}", type_name)?;
+                }
+                write!(f, " {} {}", op.as_str(), value)
+            }
+            Self::Not(inner) => match inner.as_ref() {
+                Self::And(_) | Self::Or(_) => write!(f, "NOT ({})", inner),
+                _ => write!(f, "NOT {}", inner),
+            },
+            Self::And(terms) => write_terms(f, terms, " AND ", |t| matches!(t, Self::Or(_))),
+            // AND binds tighter than OR, so OR terms never need parentheses
+            Self::Or(terms) => write_terms(f, terms, " OR ", |_| false),
+        }
+    }
+}
+
+impl fmt::Display for ConditionValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Word(word) | Self::Regex(word) => write!(f, "{}", word),
+            Self::Quoted(s) => write!(f, "{}", quote(s)),
+            Self::List(values) => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                write!(f, "({})", values.join(", "))
+            }
+        }
+    }
+}
+
+fn write_terms(
+    f: &mut fmt::Formatter<'_>,
+    terms: &[RuleCondition],
+    separator: &str,
+    needs_parens: impl Fn(&RuleCondition) -> bool,
+) -> fmt::Result {
+    for (i, term) in terms.iter().enumerate() {
+        if i > 0 {
+            f.write_str(separator)?;
+        }
+        if needs_parens(term) {
+            write!(f, "({})", term)?;
+        } else {
+            write!(f, "{}", term)?;
+        }
+    }
+    Ok(())
+}
+
+/// Join principal, action or resource names, quoting any that would not
+/// parse back as a single bare word
+fn join_items(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| {
+            if is_bare_word(item) {
+                item.clone()
+            } else {
+                quote(item)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn is_bare_word(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(is_word_char) && Keyword::from_word(s).is_none()
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !matches!(c, ',' | '(' | ')' | '"' | '\'' | '=' | '<' | '>')
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    Can,
+    Cannot,
+    If,
+    And,
+    Or,
+    Not,
+    In,
+    Like,
+}
+
+impl Keyword {
+    fn from_word(word: &str) -> Option<Self> {
+        [
+            ("can", Self::Can),
+            ("cannot", Self::Cannot),
+            ("if", Self::If),
+            ("when", Self::If),
+            ("and", Self::And),
+            ("or", Self::Or),
+            ("not", Self::Not),
+            ("in", Self::In),
+            ("like", Self::Like),
+        ]
+        .into_iter()
+        .find(|(name, _)| word.eq_ignore_ascii_case(name))
+        .map(|(_, keyword)| keyword)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Regex(String),
+    Keyword(Keyword),
+    Op(ConditionOp),
+    Comma,
+    Open,
+    Close,
+    End,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => format!("'{}'", word),
+            Self::Quoted(s) => quote(s),
+            Self::Regex(regex) => regex.clone(),
+            Self::Keyword(keyword) => format!("{:?}", keyword).to_uppercase(),
+            Self::Op(op) => format!("'{}'", op.as_str()),
+            Self::Comma => "','".to_string(),
+            Self::Open => "'('".to_string(),
+            Self::Close => "')'".to_string(),
+            Self::End => "end of rule".to_string(),
+        }
+    }
+}
+
+type ParseResult<T> = Result<T, (usize, String)>;
+
+/// Recursive-descent parser over a lazily tokenized rule
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The next token and its offset, once peeked
+    peeked: Option<(usize, Token)>,
+    /// Whether the last token was `LIKE`, after which `/` starts a regex
+    after_like: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            peeked: None,
+            after_like: false,
+        }
+    }
+
+    fn rule(mut self) -> ParseResult<PolicyRule> {
+        let principals = match self.peek()? {
+            Token::Word(_) | Token::Quoted(_) => self.items("a principal")?,
+            _ => Vec::new(),
+        };
+        let effect = match self.next()? {
+            (_, Token::Keyword(Keyword::Can)) => RuleEffect::Allow,
+            (_, Token::Keyword(Keyword::Cannot)) => RuleEffect::Deny,
+            (offset, other) => return Err(unexpected(offset, &other, "CAN or CANNOT")),
+        };
+
+        let actions = self.items("an action")?;
+        let resources = match self.peek()? {
+            Token::Word(_) | Token::Quoted(_) => self.items("a resource")?,
+            _ => Vec::new(),
+        };
+
+        let condition = match self.next()? {
+            (_, Token::Keyword(Keyword::If)) => Some(self.or()?),
+            (_, Token::End) => None,
+            (offset, other) => return Err(unexpected(offset, &other, "IF or end of rule")),
+        };
+        match self.next()? {
+            (_, Token::End) => {}
+            (offset, other) => return Err(unexpected(offset, &other, "end of rule")),
+        }
+
+        Ok(PolicyRule {
+            principals,
+            effect,
+            actions,
+            resources,
+            condition,
+        })
+    }
+
+    /// A non-empty list of names separated by commas or `AND`
+    fn items(&mut self, what: &str) -> ParseResult<Vec<String>> {
+        let mut items = Vec::new();
+        loop {
+            match self.next()? {
+                (_, Token::Word(name) | Token::Quoted(name)) => items.push(name),
+                (offset, other) => return Err(unexpected(offset, &other, what)),
+            }
+            if !self.list_separator()? {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Consume a comma or `AND` if one is next
+    fn list_separator(&mut self) -> ParseResult<bool> {
+        if matches!(self.peek()?, Token::Comma | Token::Keyword(Keyword::And)) {
+            self.next()?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn or(&mut self) -> ParseResult<RuleCondition> {
+        let mut terms = vec![self.and()?];
+        while self.peek()? == &Token::Keyword(Keyword::Or) {
+            self.next()?;
+            terms.push(self.and()?);
+        }
+        Ok(flatten(terms, RuleCondition::Or))
+    }
+
+    fn and(&mut self) -> ParseResult<RuleCondition> {
+        let mut terms = vec![self.unary()?];
+        while self.peek()? == &Token::Keyword(Keyword::And) {
+            self.next()?;
+            terms.push(self.unary()?);
+        }
+        Ok(flatten(terms, RuleCondition::And))
+    }
+
+    fn unary(&mut self) -> ParseResult<RuleCondition> {
+        match self.next()? {
+            (_, Token::Keyword(Keyword::Not)) => Ok(RuleCondition::Not(Box::new(self.unary()?))),
+            (_, Token::Open) => {
+                let condition = self.or()?;
+                match self.next()? {
+                    (_, Token::Close) => Ok(condition),
+                    (offset, other) => Err(unexpected(offset, &other, "')'")),
+                }
+            }
+            (offset, Token::Word(attribute)) => self.comparison(offset, attribute),
+            (offset, other) => Err(unexpected(offset, &other, "a condition")),
+        }
+    }
+
+    fn comparison(&mut self, offset: usize, attribute: String) -> ParseResult<RuleCondition> {
+        let (name, type_name) = match attribute.split_once("::") {
+            Some((name, type_name)) => (name.to_string(), Some(type_name.to_string())),
+            None => (attribute, None),
+        };
+        if name.is_empty() || type_name.as_deref().is_some_and(str::is_empty) {
+            return Err((
+                offset,
+                "attribute name and type must not be empty".to_string(),
+            ));
+        }
+
+        let op = match self.next()? {
+            (_, Token::Op(op)) => op,
+            (_, Token::Keyword(Keyword::In)) => ConditionOp::In,
+            (_, Token::Keyword(Keyword::Like)) => ConditionOp::Like,
+            (offset, other) => return Err(unexpected(offset, &other, "a comparison operator")),
+        };
+
+        let value = match (op, self.next()?) {
+            (ConditionOp::In, (_, Token::Open)) => {
+                let mut values = Vec::new();
+                loop {
+                    values.push(self.scalar()?);
+                    match self.next()? {
+                        (_, Token::Comma) => {}
+                        (_, Token::Close) => break,
+                        (offset, other) => return Err(unexpected(offset, &other, "',' or ')'")),
+                    }
+                }
+                ConditionValue::List(values)
+            }
+            (ConditionOp::In, (offset, other)) => return Err(unexpected(offset, &other, "'('")),
+            (ConditionOp::Like, (_, Token::Regex(regex))) => ConditionValue::Regex(regex),
+            (ConditionOp::Like, (_, Token::Quoted(s))) => ConditionValue::Quoted(s),
+            (ConditionOp::Like, (offset, other)) => {
+                return Err(unexpected(offset, &other, "a /regex/"));
+            }
+            (_, (_, Token::Word(word))) => ConditionValue::Word(word),
+            (_, (_, Token::Quoted(s))) => ConditionValue::Quoted(s),
+            (_, (offset, other)) => return Err(unexpected(offset, &other, "a value")),
+        };
+
+        Ok(RuleCondition::Compare {
+            name,
+            type_name,
+            op,
+            value,
+        })
+    }
+
+    /// A single word or quoted value, as in an `IN` list
+    fn scalar(&mut self) -> ParseResult<ConditionValue> {
+        match self.next()? {
+            (_, Token::Word(word)) => Ok(ConditionValue::Word(word)),
+            (_, Token::Quoted(s)) => Ok(ConditionValue::Quoted(s)),
+            (offset, other) => Err(unexpected(offset, &other, "a value")),
+        }
+    }
+
+    fn peek(&mut self) -> ParseResult<&Token> {
+        let peeked = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.lex()?,
+        };
+        Ok(&self.peeked.insert(peeked).1)
+    }
+
+    fn next(&mut self) -> ParseResult<(usize, Token)> {
+        let (offset, token) = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.lex()?,
+        };
+        self.after_like = token == Token::Keyword(Keyword::Like);
+        Ok((offset, token))
+    }
+
+    fn lex(&mut self) -> ParseResult<(usize, Token)> {
+        let rest = &self.input[self.pos..];
+        let trimmed = rest.trim_start();
+        let start = self.pos + (rest.len() - trimmed.len());
+        self.pos = start;
+
+        let Some(c) = trimmed.chars().next() else {
+            return Ok((start, Token::End));
+        };
+        let token = match c {
+            ',' => self.punct(1, Token::Comma),
+            '(' => self.punct(1, Token::Open),
+            ')' => self.punct(1, Token::Close),
+            '=' => self.punct(1, Token::Op(ConditionOp::Eq)),
+            '<' | '>' => {
+                let or_equal = trimmed[1..].starts_with('=');
+                let op = match (c, or_equal) {
+                    ('<', false) => ConditionOp::Lt,
+                    ('<', true) => ConditionOp::Le,
+                    (_, false) => ConditionOp::Gt,
+                    (_, true) => ConditionOp::Ge,
+                };
+                self.punct(if or_equal { 2 } else { 1 }, Token::Op(op))
+            }
+            '"' | '\'' => self.quoted(start, c)?,
+            '/' if self.after_like => self.regex(start)?,
+            _ => {
+                let len = trimmed
+                    .find(|c: char| !is_word_char(c))
+                    .unwrap_or(trimmed.len());
+                let word = &trimmed[..len];
+                self.pos += len;
+                match Keyword::from_word(word) {
+                    Some(keyword) => Token::Keyword(keyword),
+                    None => Token::Word(word.to_string()),
+                }
+            }
+        };
+        Ok((start, token))
+    }
+
+    fn punct(&mut self, len: usize, token: Token) -> Token {
+        self.pos += len;
+        token
+    }
+
+    fn quoted(&mut self, start: usize, quote: char) -> ParseResult<Token> {
+        let mut value = String::new();
+        let mut chars = self.input[start + 1..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => value.push(escaped),
+                    None => break,
+                },
+                c if c == quote => {
+                    self.pos = start + 1 + i + 1;
+                    return Ok(Token::Quoted(value));
+                }
+                c => value.push(c),
+            }
+        }
+        Err((start, "unterminated string".to_string()))
+    }
+
+    fn regex(&mut self, start: usize) -> ParseResult<Token> {
+        let body = &self.input[start + 1..];
+        let mut escaped = false;
+        for (i, c) in body.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '/' => {
+                    let after = &body[i + 1..];
+                    let flags = after
+                        .find(|c: char| !c.is_ascii_alphabetic())
+                        .unwrap_or(after.len());
+                    let end = start + 1 + i + 1 + flags;
+                    self.pos = end;
+                    return Ok(Token::Regex(self.input[start..end].to_string()));
+                }
+                _ => {}
+            }
+        }
+        Err((start, "unterminated regular expression".to_string()))
+    }
+}
+
+/// A single term stands alone; several are joined with `join`
+fn flatten(
+    mut terms: Vec<RuleCondition>,
+    join: fn(Vec<RuleCondition>) -> RuleCondition,
+) -> RuleCondition {
+    if terms.len() == 1 {
+        terms.remove(0)
+    } else {
+        join(terms)
+    }
+}
+
+fn unexpected(offset: usize, found: &Token, expected: &str) -> (usize, String) {
+    (
+        offset,
+        format!("expected {}, found {}", expected, found.describe()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(rule: &str) -> String {
+        match rule.parse::<PolicyRule>() {
+            Ok(parsed) => parsed.to_string(),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn error(rule: &str) -> InvalidPolicyRule {
+        rule.parse::<PolicyRule>()
+            .expect_err("rule should not parse")
+    }
+
+    #[test]
+    fn test_simple_rules() {
+        let rule: PolicyRule = "can listmachines, getmachine and rebootmachine"
+            .parse()
+            .unwrap();
+        assert!(rule.principals.is_empty());
+        assert_eq!(rule.effect, RuleEffect::Allow);
+        assert_eq!(
+            rule.actions,
+            vec!["listmachines", "getmachine", "rebootmachine"]
+        );
+        assert!(rule.resources.is_empty());
+        assert!(rule.condition.is_none());
+        assert_eq!(
+            rule.to_string(),
+            "CAN listmachines, getmachine, rebootmachine"
+        );
+
+        assert_eq!(canonical("CAN *"), "CAN *");
+        assert_eq!(
+            canonical("Fred and  Bob   cannot deletemachine *"),
+            "Fred, Bob CANNOT deletemachine *"
+        );
+        assert_eq!(
+            canonical("CAN read and write *.js, 'my file'"),
+            "CAN read, write *.js, \"my file\""
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        assert_eq!(
+            canonical(
+                "CAN rebootmachine if requesttime::time > 07:30:00 and \
+                 requesttime::time<18:30:00 AND requesttime::day in (Mon,Tue, Wed)"
+            ),
+            "CAN rebootmachine IF requesttime::time > 07:30:00 AND \
+             requesttime::time < 18:30:00 AND requesttime::day IN (Mon, Tue, Wed)"
+        );
+        assert_eq!(
+            canonical("CAN getmachine WHEN sourceip = 10.0.0.0/8 or sourceip >= 192.168.1.1"),
+            "CAN getmachine IF sourceip = 10.0.0.0/8 OR sourceip >= 192.168.1.1"
+        );
+        assert_eq!(
+            canonical("CAN * IF (a = 1 OR b = 2) AND NOT (c = 3 AND d = \"x y\")"),
+            "CAN * IF (a = 1 OR b = 2) AND NOT (c = 3 AND d = \"x y\")"
+        );
+        assert_eq!(
+            canonical("CAN * IF ((a = 1)) or not b <= 2"),
+            "CAN * IF a = 1 OR NOT b <= 2"
+        );
+        assert_eq!(
+            canonical("CAN getobject IF path like /^\\/public\\/(a|b)/i"),
+            "CAN getobject IF path LIKE /^\\/public\\/(a|b)/i"
+        );
+
+        let rule: PolicyRule = "CAN x IF day::string IN ('Mon', Tue)".parse().unwrap();
+        assert_eq!(
+            rule.condition,
+            Some(RuleCondition::Compare {
+                name: "day".to_string(),
+                type_name: Some("string".to_string()),
+                op: ConditionOp::In,
+                value: ConditionValue::List(vec![
+                    ConditionValue::Quoted("Mon".to_string()),
+                    ConditionValue::Word("Tue".to_string()),
+                ]),
+            })
+        );
+    }
+
+    #[test]
+    fn test_canonical_form_is_stable() {
+        for rule in [
+            "CAN listmachines",
+            "Fred, Bob CANNOT deletemachine *",
+            "CAN * IF (a = 1 OR b = 2) AND NOT (c = 3 AND d = \"x \\\"y\\\"\")",
+            "CAN \"if\" IF a IN (\"and\", b)",
+        ] {
+            assert_eq!(canonical(rule), rule);
+            assert_eq!(canonical(&canonical(rule)), canonical(rule));
+        }
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let err = error("listmachines");
+        assert_eq!(err.message, "expected CAN or CANNOT, found end of rule");
+
+        let err = error("CAN");
+        assert_eq!(err.message, "expected an action, found end of rule");
+
+        let err = error("CAN getmachine IF");
+        assert_eq!(err.message, "expected a condition, found end of rule");
+
+        let err = error("CAN getmachine IF sourceip 10.0.0.1");
+        assert_eq!(err.offset, 27);
+        assert_eq!(
+            err.to_string(),
+            "invalid policy rule 'CAN getmachine IF sourceip 10.0.0.1': \
+             expected a comparison operator, found '10.0.0.1' at column 28"
+        );
+
+        assert_eq!(
+            error("CAN x IF day IN Mon").message,
+            "expected '(', found 'Mon'"
+        );
+        assert_eq!(
+            error("CAN x IF (a = 1").message,
+            "expected ')', found end of rule"
+        );
+        assert_eq!(
+            error("CAN x IF a = 1 b = 2").message,
+            "expected end of rule, found 'b'"
+        );
+        assert_eq!(error("CAN x IF a = 'open").message, "unterminated string");
+        assert_eq!(
+            error("CAN x IF a LIKE /open").message,
+            "unterminated regular expression"
+        );
+        assert_eq!(
+            error("CAN x IF ::string = 1").message,
+            "attribute name and type must not be empty"
+        );
+    }
+
+    #[test]
+    fn test_policy_rules() {
+        use crate::types::{CreatePolicyRequest, PolicyRules};
+
+        let rules = PolicyRules::from(vec![
+            "can listmachines and getmachine".to_string(),
+            "CAN rebootmachine if  sourceip = 10.0.0.0/8".to_string(),
+        ]);
+        assert_eq!(
+            rules.canonical().unwrap(),
+            PolicyRules::from(vec![
+                "CAN listmachines, getmachine".to_string(),
+                "CAN rebootmachine IF sourceip = 10.0.0.0/8".to_string(),
+            ])
+        );
+
+        let request: CreatePolicyRequest = serde_json::from_value(serde_json::json!({
+            "name": "ops",
+            "rules": ["CAN listmachines", "listmachines"]
+        }))
+        .unwrap();
+        let err = request.validate().unwrap_err();
+        assert_eq!(err.rule, "listmachines");
+    }
+}
@@ -44,7 +44,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::Machine;
-use super::common::Uuid;
+use super::common::{Timestamp, Uuid};
 
 /// Resource types supported by the changefeed.
 ///
@@ -138,7 +138,7 @@ pub struct ChangefeedSubscription {
 /// Describes what kind of change occurred.
 ///
 /// Included in each [`ChangefeedMessage`] to indicate what properties changed.
-#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangefeedChangeKind {
     /// The resource type that changed
@@ -148,6 +148,13 @@ pub struct ChangefeedChangeKind {
     pub sub_resources: Vec<ChangefeedSubResource>,
 }
 
+impl ChangefeedChangeKind {
+    /// Whether `sub_resource` is among the properties that changed.
+    pub fn includes(&self, sub_resource: ChangefeedSubResource) -> bool {
+        self.sub_resources.contains(&sub_resource)
+    }
+}
+
 /// Change notification message received from the changefeed.
 ///
 /// The server sends these messages when a subscribed resource changes.
@@ -161,7 +168,7 @@ pub struct ChangefeedChangeKind {
 /// - `resourceState`: Internal machine state from VMAPI
 /// - `changeKind`: Object with `resource` and `subResources` describing the change
 /// - `resourceObject`: Complete machine object with current state
-#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangefeedMessage {
     /// UUID of the changed resource (the machine UUID)
@@ -170,7 +177,8 @@ pub struct ChangefeedMessage {
     /// Timestamp when the change was published.
     ///
     /// This is a Unix timestamp in milliseconds, serialized as a string.
-    /// CloudAPI documentation specifies this as `String(timestamp)`.
+    /// CloudAPI documentation specifies this as `String(timestamp)`; use
+    /// [`ChangefeedMessage::published_at`] for the parsed time.
     pub published: String,
 
     /// The current state of the resource after the change.
@@ -200,6 +208,14 @@ impl ChangefeedMessage {
     pub fn resource_state_typed(&self) -> Option<vmapi_api::VmState> {
         self.resource_state.parse().ok()
     }
+
+    /// Get the time the change was published.
+    ///
+    /// Returns `None` if `published` is not a timestamp. See [`Timestamp`]
+    /// for the formats accepted.
+    pub fn published_at(&self) -> Option<Timestamp> {
+        self.published.parse().ok()
+    }
 }
 
 #[cfg(test)]
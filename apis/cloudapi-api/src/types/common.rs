@@ -77,8 +77,135 @@ pub struct ErrorResponse {
     pub request_id: Option<String>,
 }
 
-/// RFC3339 timestamp
-pub type Timestamp = vmapi_api::Timestamp;
+/// A point in time, as CloudAPI reports it
+///
+/// CloudAPI is not consistent about timestamp formats: most endpoints send
+/// RFC3339 strings, but some pass through Unix timestamps in milliseconds
+/// from the services behind them, as numbers or as strings of digits. All of
+/// these deserialize to a `Timestamp`. It always serializes as RFC3339 in
+/// UTC, with milliseconds when there are any (`2024-01-01T00:00:00.123Z`),
+/// and has the same `date-time` schema as `chrono::DateTime<Utc>`, so the
+/// OpenAPI spec is unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, JsonSchema)]
+#[schemars(transparent)]
+pub struct Timestamp(pub chrono::DateTime<chrono::Utc>);
+
+/// A string that is neither an RFC3339 timestamp nor a Unix timestamp in
+/// milliseconds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTimestamp(pub String);
+
+impl std::fmt::Display for InvalidTimestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid timestamp '{}': expected RFC3339 or milliseconds since the epoch",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidTimestamp {}
+
+impl Timestamp {
+    /// The current time
+    pub fn now() -> Self {
+        Timestamp(chrono::Utc::now())
+    }
+
+    /// The time `millis` milliseconds after the Unix epoch, if it is in
+    /// range
+    pub fn from_millis(millis: i64) -> Option<Self> {
+        chrono::DateTime::from_timestamp_millis(millis).map(Timestamp)
+    }
+}
+
+impl std::str::FromStr for Timestamp {
+    type Err = InvalidTimestamp;
+
+    /// Parse an RFC3339 timestamp in any offset, or a Unix timestamp in
+    /// milliseconds
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if let Ok(time) = chrono::DateTime::parse_from_rfc3339(trimmed) {
+            return Ok(Timestamp(time.with_timezone(&chrono::Utc)));
+        }
+        trimmed
+            .parse::<i64>()
+            .ok()
+            .and_then(Timestamp::from_millis)
+            .ok_or_else(|| InvalidTimestamp(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimestampVisitor;
+
+        impl serde::de::Visitor<'_> for TimestampVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an RFC3339 timestamp or milliseconds since the epoch")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Timestamp, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Timestamp, E> {
+                Timestamp::from_millis(v)
+                    .ok_or_else(|| E::custom(format!("timestamp {} is out of range", v)))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Timestamp, E> {
+                i64::try_from(v)
+                    .ok()
+                    .and_then(Timestamp::from_millis)
+                    .ok_or_else(|| E::custom(format!("timestamp {} is out of range", v)))
+            }
+        }
+
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+}
+
+impl std::ops::Deref for Timestamp {
+    type Target = chrono::DateTime<chrono::Utc>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(time: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp(time)
+    }
+}
+
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(time: Timestamp) -> Self {
+        time.0
+    }
+}
+
+impl PartialEq<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn eq(&self, other: &chrono::DateTime<chrono::Utc>) -> bool {
+        &self.0 == other
+    }
+}
 
 /// Key-value tags (values can be strings, booleans, or numbers)
 pub use vmapi_api::Tags;
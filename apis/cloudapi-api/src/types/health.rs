@@ -0,0 +1,53 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Service health types (`/--ping`, `/--version`)
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// CloudAPI version implemented by this crate
+pub const API_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// API versions a CloudAPI server accepts in `Accept-Version`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiVersions {
+    /// Supported API versions (e.g. "7.3.0", "8.0.0", "9.20.0")
+    pub versions: Vec<String>,
+}
+
+/// Response for `GET /--ping`
+///
+/// Matches the Node.js CloudAPI body: `{"ping": "pong", "cloudapi":
+/// {"versions": [...]}}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PingResponse {
+    /// Always "pong"
+    pub ping: String,
+    /// API versions supported by the server
+    pub cloudapi: ApiVersions,
+}
+
+impl PingResponse {
+    /// A successful ping reporting the given supported API versions
+    pub fn pong(versions: Vec<String>) -> Self {
+        Self {
+            ping: "pong".to_string(),
+            cloudapi: ApiVersions { versions },
+        }
+    }
+}
+
+/// Response for `GET /--version`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct VersionResponse {
+    /// Server name (e.g. "cloudapi")
+    pub name: String,
+    /// Server version (e.g. "9.20.0")
+    pub version: String,
+    /// API versions supported by the server
+    pub versions: Vec<String>,
+}
@@ -105,7 +105,7 @@ pub enum ImageType {
 /// Image requirements
 ///
 /// Specifies hardware/software requirements for an image.
-#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct ImageRequirements {
     /// Minimum RAM in MB
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -128,7 +128,7 @@ pub struct ImageRequirements {
 }
 
 /// Image file information
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageFile {
     /// Compression type (gzip, bzip2, none)
     pub compression: String,
@@ -141,7 +141,7 @@ pub struct ImageFile {
 /// Image error information (for failed image creation)
 // Note: Named `ImageErrorInfo` rather than `ImageError` to distinguish this DTO
 // from Rust error types.
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageErrorInfo {
     /// Error code
     pub code: String,
@@ -151,7 +151,7 @@ pub struct ImageErrorInfo {
 }
 
 /// Image/dataset information
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Image {
     /// Image UUID
     pub id: Uuid,
@@ -352,4 +352,10 @@ pub struct ListImagesQuery {
     /// Filter by type
     #[serde(default, rename = "type")]
     pub image_type: Option<ImageType>,
+    /// Maximum number of images to return
+    #[serde(default)]
+    pub limit: Option<u64>,
+    /// Pagination marker: return the images listed after this image UUID
+    #[serde(default)]
+    pub marker: Option<Uuid>,
 }
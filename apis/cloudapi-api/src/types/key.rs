@@ -40,7 +40,7 @@ pub struct UserAccessKeyPath {
 }
 
 /// SSH key information
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SshKey {
     /// Key name
     pub name: String,
@@ -107,6 +107,9 @@ pub struct AccessKey {
     /// Expiration timestamp (null for permanent keys)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expiration: Option<Timestamp>,
+    /// Time the key was last used to authenticate a request (absent if it has never been used)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lastused: Option<Timestamp>,
 }
 
 /// Request to create access key
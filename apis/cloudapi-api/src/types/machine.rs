@@ -64,6 +64,7 @@ impl<'a> IntoIterator for &'a AffinityRules {
 // Only CreateMachineRequest uses CloudAPI's restrictive Brand to validate provisioning.
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use vmapi_api::Brand as VmapiBrand;
 
 /// Path parameter for machine operations
@@ -653,6 +654,54 @@ impl ListMachinesQuery {
     }
 }
 
+/// Counts of an account's machines by state, brand and package
+///
+/// Returned by `GET /{account}/machinesummary`, so that dashboards can show
+/// how many machines there are without listing them all. Each map only has
+/// entries for values that at least one machine has. Deleted machines are not
+/// counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MachineSummary {
+    /// Number of machines
+    pub total: u64,
+    /// Number of machines in each state
+    pub states: BTreeMap<String, u64>,
+    /// Number of machines of each brand
+    pub brands: BTreeMap<String, u64>,
+    /// Number of machines using each package, by package name
+    pub packages: BTreeMap<String, u64>,
+}
+
+impl MachineSummary {
+    /// Count a machine, unless it has been deleted
+    pub fn add(&mut self, machine: &Machine) {
+        if machine.state == MachineState::Deleted {
+            return;
+        }
+
+        // Brands serialize as plain strings
+        let brand = serde_json::to_value(machine.brand)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| "unknown".to_string());
+
+        self.total += 1;
+        *self.states.entry(machine.state.to_string()).or_default() += 1;
+        *self.brands.entry(brand).or_default() += 1;
+        *self.packages.entry(machine.package.clone()).or_default() += 1;
+    }
+}
+
+impl<'a> FromIterator<&'a Machine> for MachineSummary {
+    fn from_iter<I: IntoIterator<Item = &'a Machine>>(machines: I) -> Self {
+        let mut summary = Self::default();
+        for machine in machines {
+            summary.add(machine);
+        }
+        summary
+    }
+}
+
 /// Success status for an audit entry.
 ///
 /// CloudAPI sends `"yes"` or `"no"` strings on the wire (not booleans).
@@ -665,17 +714,112 @@ pub enum AuditSuccess {
     Unknown,
 }
 
+/// Action recorded in a machine audit entry.
+///
+/// These are the action names Node.js CloudAPI derives from the VMAPI job
+/// behind each change. The list is not exhaustive: names not known here
+/// parse as `Unknown`, and the raw name stays available in
+/// [`AuditEntry::action`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Provision,
+    Start,
+    Stop,
+    Reboot,
+    Resize,
+    Rename,
+    Reprovision,
+    Delete,
+    CreateSnapshot,
+    DeleteSnapshot,
+    RollbackSnapshot,
+    SetMetadata,
+    ReplaceMetadata,
+    RemoveMetadata,
+    SetTags,
+    ReplaceTags,
+    RemoveTags,
+    AddNics,
+    RemoveNics,
+    EnableFirewall,
+    DisableFirewall,
+    EnableDeletionProtection,
+    DisableDeletionProtection,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<&str> for AuditAction {
+    fn from(action: &str) -> Self {
+        Self::deserialize(serde::de::value::StrDeserializer::<serde::de::value::Error>::new(action))
+            .unwrap_or(Self::Unknown)
+    }
+}
+
+/// How the caller of an audited action authenticated
+///
+/// `signature` for HTTP Signature (SSH key) requests, `basic` for HTTP Basic,
+/// `token` for trusted services such as the portal, and `operator` for
+/// changes made through the internal APIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditCallerType {
+    Signature,
+    Basic,
+    Token,
+    Operator,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Caller of an audited action
+///
+/// CloudAPI only includes the fields that apply to the caller type: `keyId`
+/// for signature callers, `ip` for requests that came in over HTTP.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditCaller {
+    /// Authentication type
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub caller_type: Option<AuditCallerType>,
+    /// Client IP address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// Signing key, as `/<login>/keys/<fingerprint>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    /// Login of the caller, when CloudAPI records it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login: Option<String>,
+}
+
 /// Audit entry for a machine
+///
+/// Matches the records returned by Node.js CloudAPI's `MachineAudit`.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AuditEntry {
-    /// Action performed
+    /// Action performed (e.g. "start"); see [`AuditAction`] for known values
     pub action: String,
     /// Timestamp
     pub time: Timestamp,
     /// Caller information
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub caller: Option<serde_json::Value>,
+    pub caller: Option<AuditCaller>,
     /// Success status
     #[serde(default)]
     pub success: Option<AuditSuccess>,
 }
+
+impl AuditEntry {
+    /// The action as an [`AuditAction`], `Unknown` for unrecognized names
+    pub fn action_kind(&self) -> AuditAction {
+        AuditAction::from(self.action.as_str())
+    }
+
+    /// Whether the action is recorded as having succeeded
+    pub fn succeeded(&self) -> bool {
+        self.success == Some(AuditSuccess::Yes)
+    }
+}
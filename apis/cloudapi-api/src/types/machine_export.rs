@@ -0,0 +1,333 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Machine inventory export
+//!
+//! `GET /{account}/machineexport` returns the account's machines as CSV or
+//! newline-delimited JSON rather than as one JSON array, so that a large
+//! inventory can be streamed to a file a row at a time. The caller picks the
+//! [`MachineColumn`]s, and [`MachineExportFormat`] renders the header and
+//! rows, so that servers and clients agree on the output.
+
+use super::common::Uuid;
+use super::machine::{ListMachinesQuery, Machine, MachineState, MachineType};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use vmapi_api::Brand as VmapiBrand;
+
+/// Output format of a machine export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MachineExportFormat {
+    /// Comma-separated values, starting with a header row
+    #[default]
+    Csv,
+    /// Newline-delimited JSON, one object per machine
+    Ndjson,
+}
+
+impl MachineExportFormat {
+    /// The `Content-Type` of an export in this format
+    pub fn content_type(self) -> &'static str {
+        match self {
+            MachineExportFormat::Csv => "text/csv; charset=utf-8",
+            MachineExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    /// The line that starts an export with these columns, if the format has
+    /// one
+    pub fn header(self, columns: &[MachineColumn]) -> Option<String> {
+        match self {
+            MachineExportFormat::Csv => {
+                let names: Vec<&str> = columns.iter().map(|c| c.name()).collect();
+                Some(csv_line(names.iter().map(|name| csv_field(name))))
+            }
+            MachineExportFormat::Ndjson => None,
+        }
+    }
+
+    /// The line for one machine, with its newline
+    pub fn row(self, machine: &Machine, columns: &[MachineColumn]) -> String {
+        match self {
+            MachineExportFormat::Csv => csv_line(
+                columns
+                    .iter()
+                    .map(|c| csv_field(&csv_text(&c.value(machine)))),
+            ),
+            MachineExportFormat::Ndjson => {
+                let fields: Vec<String> = columns
+                    .iter()
+                    .map(|c| format!("{}:{}", Value::from(c.name()), c.value(machine)))
+                    .collect();
+                format!("{{{}}}\n", fields.join(","))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for MachineExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MachineExportFormat::Csv => "csv",
+            MachineExportFormat::Ndjson => "ndjson",
+        })
+    }
+}
+
+/// A column of a machine export
+///
+/// Columns are named as in the JSON form of [`Machine`]. In CSV, lists such
+/// as `ips` are joined with commas, `tags` is written as a JSON object and
+/// fields a machine does not have are left empty; NDJSON keeps the JSON
+/// values, with `null` for fields a machine does not have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MachineColumn {
+    Id,
+    Name,
+    Type,
+    Brand,
+    State,
+    Image,
+    Package,
+    Memory,
+    Disk,
+    PrimaryIp,
+    Ips,
+    ComputeNode,
+    Docker,
+    FirewallEnabled,
+    DeletionProtection,
+    Tags,
+    Created,
+    Updated,
+}
+
+impl MachineColumn {
+    /// Every column, in the order they are listed in documentation
+    pub const ALL: [MachineColumn; 18] = [
+        MachineColumn::Id,
+        MachineColumn::Name,
+        MachineColumn::Type,
+        MachineColumn::Brand,
+        MachineColumn::State,
+        MachineColumn::Image,
+        MachineColumn::Package,
+        MachineColumn::Memory,
+        MachineColumn::Disk,
+        MachineColumn::PrimaryIp,
+        MachineColumn::Ips,
+        MachineColumn::ComputeNode,
+        MachineColumn::Docker,
+        MachineColumn::FirewallEnabled,
+        MachineColumn::DeletionProtection,
+        MachineColumn::Tags,
+        MachineColumn::Created,
+        MachineColumn::Updated,
+    ];
+
+    /// The columns exported when none are requested
+    pub const DEFAULT: [MachineColumn; 9] = [
+        MachineColumn::Id,
+        MachineColumn::Name,
+        MachineColumn::State,
+        MachineColumn::Brand,
+        MachineColumn::Package,
+        MachineColumn::Memory,
+        MachineColumn::Disk,
+        MachineColumn::PrimaryIp,
+        MachineColumn::Created,
+    ];
+
+    /// The name of the column, as used in the `columns` query parameter and
+    /// the CSV header
+    pub fn name(self) -> &'static str {
+        match self {
+            MachineColumn::Id => "id",
+            MachineColumn::Name => "name",
+            MachineColumn::Type => "type",
+            MachineColumn::Brand => "brand",
+            MachineColumn::State => "state",
+            MachineColumn::Image => "image",
+            MachineColumn::Package => "package",
+            MachineColumn::Memory => "memory",
+            MachineColumn::Disk => "disk",
+            MachineColumn::PrimaryIp => "primaryIp",
+            MachineColumn::Ips => "ips",
+            MachineColumn::ComputeNode => "compute_node",
+            MachineColumn::Docker => "docker",
+            MachineColumn::FirewallEnabled => "firewall_enabled",
+            MachineColumn::DeletionProtection => "deletion_protection",
+            MachineColumn::Tags => "tags",
+            MachineColumn::Created => "created",
+            MachineColumn::Updated => "updated",
+        }
+    }
+
+    /// Parse a comma-separated list of column names
+    ///
+    /// Fails on an unknown or repeated column, or an empty list.
+    pub fn parse_list(list: &str) -> Result<Vec<MachineColumn>, String> {
+        let mut columns = Vec::new();
+        for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let column: MachineColumn = name.parse()?;
+            if columns.contains(&column) {
+                return Err(format!("column '{}' is given more than once", name));
+            }
+            columns.push(column);
+        }
+        if columns.is_empty() {
+            return Err("no columns given".to_string());
+        }
+        Ok(columns)
+    }
+
+    /// The value of the column for a machine, as JSON
+    pub fn value(self, machine: &Machine) -> Value {
+        let value = match self {
+            MachineColumn::Id => serde_json::to_value(machine.id),
+            MachineColumn::Name => Ok(Value::from(machine.name.as_str())),
+            MachineColumn::Type => serde_json::to_value(machine.machine_type),
+            MachineColumn::Brand => serde_json::to_value(machine.brand),
+            MachineColumn::State => serde_json::to_value(machine.state),
+            MachineColumn::Image => serde_json::to_value(machine.image),
+            MachineColumn::Package => Ok(Value::from(machine.package.as_str())),
+            MachineColumn::Memory => Ok(Value::from(machine.memory)),
+            MachineColumn::Disk => Ok(Value::from(machine.disk)),
+            MachineColumn::PrimaryIp => Ok(Value::from(machine.primary_ip.clone())),
+            MachineColumn::Ips => Ok(Value::from(machine.ips.clone())),
+            MachineColumn::ComputeNode => serde_json::to_value(machine.compute_node),
+            MachineColumn::Docker => Ok(Value::from(machine.docker)),
+            MachineColumn::FirewallEnabled => Ok(Value::from(machine.firewall_enabled)),
+            MachineColumn::DeletionProtection => Ok(Value::from(machine.deletion_protection)),
+            MachineColumn::Tags => serde_json::to_value(&machine.tags),
+            MachineColumn::Created => serde_json::to_value(machine.created),
+            MachineColumn::Updated => serde_json::to_value(machine.updated),
+        };
+        value.unwrap_or(Value::Null)
+    }
+}
+
+impl std::fmt::Display for MachineColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for MachineColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MachineColumn::ALL
+            .into_iter()
+            .find(|c| c.name() == s)
+            .ok_or_else(|| format!("unknown column '{}'", s))
+    }
+}
+
+/// Query parameters for exporting machines
+///
+/// The filters are those of [`ListMachinesQuery`], without pagination: an
+/// export always covers every matching machine.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct MachineExportQuery {
+    /// Output format (default `csv`)
+    #[serde(default)]
+    pub format: Option<MachineExportFormat>,
+    /// Comma-separated columns to export, in order (default
+    /// `id,name,state,brand,package,memory,disk,primaryIp,created`)
+    #[serde(default)]
+    pub columns: Option<String>,
+    /// Filter by machine name
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Filter by image UUID
+    #[serde(default)]
+    pub image: Option<Uuid>,
+    /// Filter by state
+    #[serde(default)]
+    pub state: Option<MachineState>,
+    /// Filter by memory (MB)
+    #[serde(default)]
+    pub memory: Option<u64>,
+    /// Filter by machine type (smartmachine or virtualmachine)
+    #[serde(default, rename = "type")]
+    pub machine_type: Option<MachineType>,
+    /// Filter by brand (accepts any brand including internal-only brands)
+    #[serde(default)]
+    pub brand: Option<VmapiBrand>,
+    /// Filter by tag (key=value)
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Filter by docker flag
+    #[serde(default)]
+    pub docker: Option<bool>,
+    /// Include destroyed/tombstone machines
+    #[serde(default)]
+    pub tombstone: Option<bool>,
+}
+
+impl MachineExportQuery {
+    /// The requested format
+    pub fn format(&self) -> MachineExportFormat {
+        self.format.unwrap_or_default()
+    }
+
+    /// The requested columns, or [`MachineColumn::DEFAULT`]
+    pub fn columns(&self) -> Result<Vec<MachineColumn>, String> {
+        match &self.columns {
+            Some(list) => MachineColumn::parse_list(list),
+            None => Ok(MachineColumn::DEFAULT.to_vec()),
+        }
+    }
+
+    /// The filters as a query for listing the machines
+    pub fn list_query(&self) -> ListMachinesQuery {
+        ListMachinesQuery {
+            name: self.name.clone(),
+            image: self.image,
+            state: self.state,
+            memory: self.memory,
+            machine_type: self.machine_type,
+            brand: self.brand,
+            offset: None,
+            limit: None,
+            tag: self.tag.clone(),
+            docker: self.docker,
+            credentials: None,
+            tombstone: self.tombstone,
+            extra: std::collections::HashMap::new(),
+        }
+    }
+}
+
+/// The text of a JSON value in a CSV field
+fn csv_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.iter().all(|v| !v.is_array() && !v.is_object()) => {
+            items.iter().map(csv_text).collect::<Vec<_>>().join(",")
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Quote a CSV field if it holds a comma, quote or line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    let mut line = fields.collect::<Vec<_>>().join(",");
+    line.push('\n');
+    line
+}
@@ -9,6 +9,8 @@
 use super::common::{Metadata, Tags, Timestamp, Uuid};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 /// Path parameter for snapshot operations
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -161,12 +163,508 @@ pub struct ResizeDiskRequest {
     pub dangerous_allow_shrink: Option<bool>,
 }
 
+/// Maximum length of a metadata key, in bytes
+pub const MAX_METADATA_KEY_LEN: usize = 256;
+
+/// Maximum length of a metadata value, in bytes
+///
+/// Booleans and numbers are measured by the length of their string form.
+pub const MAX_METADATA_VALUE_LEN: usize = 1024 * 1024;
+
+/// Metadata key prefixes that customers may not set
+///
+/// `triton.` keys are reserved for Triton itself, and `sdc:` keys are served
+/// by the metadata agent from the machine's own properties.
+pub const RESERVED_METADATA_PREFIXES: &[&str] = &["triton.", "sdc:"];
+
+/// Machine metadata that has been checked against CloudAPI's constraints
+///
+/// CloudAPI only enforces these server side; validating them up front lets a
+/// client reject bad metadata with a clear error before making a request.
+/// Keys must be non-empty, at most [`MAX_METADATA_KEY_LEN`] bytes, consist of
+/// ASCII letters, digits, `-`, `_`, `.` and `:`, and must not start with one
+/// of the [`RESERVED_METADATA_PREFIXES`]. Values must be strings, booleans or
+/// numbers no longer than [`MAX_METADATA_VALUE_LEN`] bytes.
+///
+/// This type validates on deserialization, so a request with invalid metadata
+/// is rejected before it reaches the handler.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct MachineMetadata(HashMap<String, Value>);
+
+impl<'de> Deserialize<'de> for MachineMetadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let map = HashMap::<String, Value>::deserialize(deserializer)?;
+        MachineMetadata::new(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl MachineMetadata {
+    /// Create new metadata, validating every key and value
+    pub fn new(map: impl Into<Metadata>) -> Result<Self, InvalidMetadata> {
+        let map = map.into().0;
+        for (key, value) in &map {
+            validate_metadata_key(key)?;
+            validate_metadata_value(key, value)?;
+        }
+        Ok(Self(map))
+    }
+
+    /// Create without validation (for trusted sources like CloudAPI responses)
+    pub fn new_unchecked(map: impl Into<Metadata>) -> Self {
+        Self(map.into().0)
+    }
+
+    /// Return the validated key-value pairs
+    pub fn into_inner(self) -> HashMap<String, Value> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for MachineMetadata {
+    type Target = HashMap<String, Value>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<Metadata> for MachineMetadata {
+    type Error = InvalidMetadata;
+    fn try_from(map: Metadata) -> Result<Self, Self::Error> {
+        MachineMetadata::new(map)
+    }
+}
+
+impl TryFrom<serde_json::Map<String, Value>> for MachineMetadata {
+    type Error = InvalidMetadata;
+    fn try_from(map: serde_json::Map<String, Value>) -> Result<Self, Self::Error> {
+        MachineMetadata::new(map)
+    }
+}
+
+impl From<MachineMetadata> for Metadata {
+    fn from(metadata: MachineMetadata) -> Self {
+        Metadata(metadata.0)
+    }
+}
+
+fn validate_metadata_key(key: &str) -> Result<(), InvalidMetadata> {
+    if key.is_empty() {
+        return Err(InvalidMetadata::EmptyKey);
+    }
+    if key.len() > MAX_METADATA_KEY_LEN {
+        return Err(InvalidMetadata::KeyTooLong(key.to_string()));
+    }
+    if let Some(c) = key
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':')))
+    {
+        return Err(InvalidMetadata::InvalidKeyCharacter {
+            key: key.to_string(),
+            character: c,
+        });
+    }
+    if let Some(prefix) = RESERVED_METADATA_PREFIXES
+        .iter()
+        .copied()
+        .find(|p| key.starts_with(p))
+    {
+        return Err(InvalidMetadata::ReservedKey {
+            key: key.to_string(),
+            prefix,
+        });
+    }
+    Ok(())
+}
+
+fn validate_metadata_value(key: &str, value: &Value) -> Result<(), InvalidMetadata> {
+    let len = match value {
+        Value::String(s) => s.len(),
+        Value::Bool(b) => b.to_string().len(),
+        Value::Number(n) => n.to_string().len(),
+        Value::Null | Value::Array(_) | Value::Object(_) => {
+            return Err(InvalidMetadata::UnsupportedValue(key.to_string()));
+        }
+    };
+    if len > MAX_METADATA_VALUE_LEN {
+        return Err(InvalidMetadata::ValueTooLong {
+            key: key.to_string(),
+            len,
+        });
+    }
+    Ok(())
+}
+
+/// Reason a metadata key or value was rejected by [`MachineMetadata::new`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidMetadata {
+    /// A key was the empty string
+    EmptyKey,
+    /// A key exceeded [`MAX_METADATA_KEY_LEN`]
+    KeyTooLong(String),
+    /// A key contained a character outside the allowed set
+    InvalidKeyCharacter { key: String, character: char },
+    /// A key started with one of the [`RESERVED_METADATA_PREFIXES`]
+    ReservedKey { key: String, prefix: &'static str },
+    /// A value was null, an array or an object
+    UnsupportedValue(String),
+    /// A value exceeded [`MAX_METADATA_VALUE_LEN`]
+    ValueTooLong { key: String, len: usize },
+}
+
+impl std::fmt::Display for InvalidMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyKey => write!(f, "metadata key must not be empty"),
+            Self::KeyTooLong(key) => write!(
+                f,
+                "metadata key '{}...' is longer than {} bytes",
+                key.chars().take(32).collect::<String>(),
+                MAX_METADATA_KEY_LEN
+            ),
+            Self::InvalidKeyCharacter { key, character } => write!(
+                f,
+                "metadata key '{}' contains invalid character {:?} \
+                 (allowed: letters, digits, '-', '_', '.', ':')",
+                key, character
+            ),
+            Self::ReservedKey { key, prefix } => write!(
+                f,
+                "metadata key '{}' is reserved: keys starting with '{}' cannot be set",
+                key, prefix
+            ),
+            Self::UnsupportedValue(key) => write!(
+                f,
+                "metadata value for '{}' must be a string, boolean or number",
+                key
+            ),
+            Self::ValueTooLong { key, len } => write!(
+                f,
+                "metadata value for '{}' is {} bytes, more than the maximum of {}",
+                key, len, MAX_METADATA_VALUE_LEN
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidMetadata {}
+
+/// Maximum length of a tag name, in bytes
+pub const MAX_TAG_NAME_LEN: usize = 256;
+
+/// Maximum length of a tag value, in bytes
+///
+/// Booleans and numbers are measured by the length of their string form.
+pub const MAX_TAG_VALUE_LEN: usize = 1024;
+
+/// Prefix of the tags read by Triton CNS
+///
+/// CNS builds DNS records from these tags and does not reject bad values, so
+/// a malformed one breaks name resolution for the machine instead of failing
+/// the request. [`MachineTags::new`] checks their values as well as their
+/// names.
+pub const CNS_TAG_PREFIX: &str = "triton.cns.";
+
+/// `triton.cns.services`: comma-separated service names the machine is
+/// listed under, each optionally followed by `:port=<port>`
+pub const CNS_SERVICES_TAG: &str = "triton.cns.services";
+
+/// `triton.cns.disable`: `true` to leave the machine out of CNS
+pub const CNS_DISABLE_TAG: &str = "triton.cns.disable";
+
+/// `triton.cns.reverse_ptr`: DNS name used for the machine's PTR records
+pub const CNS_REVERSE_PTR_TAG: &str = "triton.cns.reverse_ptr";
+
+/// Maximum length of a DNS label, and so of a CNS service name
+const MAX_DNS_LABEL_LEN: usize = 63;
+
+/// Maximum length of a DNS name
+const MAX_DNS_NAME_LEN: usize = 253;
+
+/// Machine tags that have been checked against CloudAPI's and CNS's
+/// constraints
+///
+/// Names must be non-empty, at most [`MAX_TAG_NAME_LEN`] bytes, and consist of
+/// ASCII letters, digits, `-`, `_`, `.` and `:`. Values must be strings,
+/// booleans or numbers no longer than [`MAX_TAG_VALUE_LEN`] bytes. Tags under
+/// [`CNS_TAG_PREFIX`] must be one of the tags CNS knows, with a value in the
+/// syntax it expects:
+///
+/// - [`CNS_SERVICES_TAG`]: a string of comma-separated DNS labels, each
+///   optionally followed by `:port=<1-65535>`, e.g. `"web,api:port=8080"`
+/// - [`CNS_DISABLE_TAG`]: `true` or `false`, as a boolean or a string
+/// - [`CNS_REVERSE_PTR_TAG`]: a string holding a DNS name
+///
+/// This type validates on deserialization, so a request with invalid tags is
+/// rejected before it reaches the handler. [`MachineTags::new_unchecked`] is
+/// the escape hatch for tags that are known to be fine, for example those of
+/// a CNS newer than these rules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct MachineTags(HashMap<String, Value>);
+
+impl<'de> Deserialize<'de> for MachineTags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let map = HashMap::<String, Value>::deserialize(deserializer)?;
+        MachineTags::new(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl MachineTags {
+    /// Create new tags, validating every name and value
+    pub fn new(map: impl Into<Tags>) -> Result<Self, InvalidTag> {
+        let map = map.into().0;
+        for (name, value) in &map {
+            validate_tag_name(name)?;
+            validate_tag_value(name, value)?;
+            if name.starts_with(CNS_TAG_PREFIX) {
+                validate_cns_tag(name, value)?;
+            }
+        }
+        Ok(Self(map))
+    }
+
+    /// Create without validation (for trusted sources like CloudAPI
+    /// responses, or to send tags these rules do not know about)
+    pub fn new_unchecked(map: impl Into<Tags>) -> Self {
+        Self(map.into().0)
+    }
+
+    /// Return the validated name-value pairs
+    pub fn into_inner(self) -> HashMap<String, Value> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for MachineTags {
+    type Target = HashMap<String, Value>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TryFrom<Tags> for MachineTags {
+    type Error = InvalidTag;
+    fn try_from(map: Tags) -> Result<Self, Self::Error> {
+        MachineTags::new(map)
+    }
+}
+
+impl TryFrom<serde_json::Map<String, Value>> for MachineTags {
+    type Error = InvalidTag;
+    fn try_from(map: serde_json::Map<String, Value>) -> Result<Self, Self::Error> {
+        MachineTags::new(map)
+    }
+}
+
+impl From<MachineTags> for Tags {
+    fn from(tags: MachineTags) -> Self {
+        Tags(tags.0)
+    }
+}
+
+fn validate_tag_name(name: &str) -> Result<(), InvalidTag> {
+    if name.is_empty() {
+        return Err(InvalidTag::EmptyName);
+    }
+    if name.len() > MAX_TAG_NAME_LEN {
+        return Err(InvalidTag::NameTooLong(name.to_string()));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':')))
+    {
+        return Err(InvalidTag::InvalidNameCharacter {
+            name: name.to_string(),
+            character: c,
+        });
+    }
+    Ok(())
+}
+
+fn validate_tag_value(name: &str, value: &Value) -> Result<(), InvalidTag> {
+    let len = match value {
+        Value::String(s) => s.len(),
+        Value::Bool(b) => b.to_string().len(),
+        Value::Number(n) => n.to_string().len(),
+        Value::Null | Value::Array(_) | Value::Object(_) => {
+            return Err(InvalidTag::UnsupportedValue(name.to_string()));
+        }
+    };
+    if len > MAX_TAG_VALUE_LEN {
+        return Err(InvalidTag::ValueTooLong {
+            name: name.to_string(),
+            len,
+        });
+    }
+    Ok(())
+}
+
+fn validate_cns_tag(name: &str, value: &Value) -> Result<(), InvalidTag> {
+    let invalid = |reason: String| InvalidTag::InvalidCnsValue {
+        name: name.to_string(),
+        reason,
+    };
+    match name {
+        CNS_SERVICES_TAG => {
+            let Value::String(services) = value else {
+                return Err(invalid("expected a string".to_string()));
+            };
+            for service in services.split(',') {
+                validate_cns_service(service).map_err(invalid)?;
+            }
+            Ok(())
+        }
+        CNS_DISABLE_TAG => match value {
+            Value::Bool(_) => Ok(()),
+            Value::String(s) if s == "true" || s == "false" => Ok(()),
+            _ => Err(invalid("expected true or false".to_string())),
+        },
+        CNS_REVERSE_PTR_TAG => match value {
+            Value::String(ptr) => validate_dns_name(ptr).map_err(invalid),
+            _ => Err(invalid("expected a DNS name".to_string())),
+        },
+        _ => Err(InvalidTag::UnknownCnsTag(name.to_string())),
+    }
+}
+
+/// Check one entry of `triton.cns.services`: `name[:port=<port>]`
+fn validate_cns_service(service: &str) -> Result<(), String> {
+    let mut parts = service.split(':');
+    let label = parts.next().unwrap_or_default();
+    validate_dns_label(label).map_err(|e| format!("service {:?}: {}", service, e))?;
+    for property in parts {
+        match property.split_once('=') {
+            Some(("port", port)) => match port.parse::<u16>() {
+                Ok(port) if port > 0 => {}
+                _ => {
+                    return Err(format!(
+                        "service {:?}: port must be a number from 1 to 65535",
+                        service
+                    ));
+                }
+            },
+            _ => {
+                return Err(format!(
+                    "service {:?}: unknown property {:?} (expected port=<port>)",
+                    service, property
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_dns_name(name: &str) -> Result<(), String> {
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if name.len() > MAX_DNS_NAME_LEN {
+        return Err(format!(
+            "DNS name is longer than {} bytes",
+            MAX_DNS_NAME_LEN
+        ));
+    }
+    for label in name.split('.') {
+        validate_dns_label(label).map_err(|e| format!("DNS name {:?}: {}", name, e))?;
+    }
+    Ok(())
+}
+
+fn validate_dns_label(label: &str) -> Result<(), String> {
+    if label.is_empty() {
+        return Err("empty DNS label".to_string());
+    }
+    if label.len() > MAX_DNS_LABEL_LEN {
+        return Err(format!(
+            "DNS label is longer than {} bytes",
+            MAX_DNS_LABEL_LEN
+        ));
+    }
+    if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(format!(
+            "DNS label {:?} may only contain letters, digits and '-'",
+            label
+        ));
+    }
+    if label.starts_with('-') || label.ends_with('-') {
+        return Err(format!(
+            "DNS label {:?} must not start or end with '-'",
+            label
+        ));
+    }
+    Ok(())
+}
+
+/// Reason a tag name or value was rejected by [`MachineTags::new`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidTag {
+    /// A name was the empty string
+    EmptyName,
+    /// A name exceeded [`MAX_TAG_NAME_LEN`]
+    NameTooLong(String),
+    /// A name contained a character outside the allowed set
+    InvalidNameCharacter { name: String, character: char },
+    /// A value was null, an array or an object
+    UnsupportedValue(String),
+    /// A value exceeded [`MAX_TAG_VALUE_LEN`]
+    ValueTooLong { name: String, len: usize },
+    /// A name started with [`CNS_TAG_PREFIX`] but is not a tag CNS reads
+    UnknownCnsTag(String),
+    /// The value of a CNS tag was not in the syntax CNS expects
+    InvalidCnsValue { name: String, reason: String },
+}
+
+impl std::fmt::Display for InvalidTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "tag name must not be empty"),
+            Self::NameTooLong(name) => write!(
+                f,
+                "tag name '{}...' is longer than {} bytes",
+                name.chars().take(32).collect::<String>(),
+                MAX_TAG_NAME_LEN
+            ),
+            Self::InvalidNameCharacter { name, character } => write!(
+                f,
+                "tag name '{}' contains invalid character {:?} \
+                 (allowed: letters, digits, '-', '_', '.', ':')",
+                name, character
+            ),
+            Self::UnsupportedValue(name) => write!(
+                f,
+                "tag value for '{}' must be a string, boolean or number",
+                name
+            ),
+            Self::ValueTooLong { name, len } => write!(
+                f,
+                "tag value for '{}' is {} bytes, more than the maximum of {}",
+                name, len, MAX_TAG_VALUE_LEN
+            ),
+            Self::UnknownCnsTag(name) => write!(
+                f,
+                "unknown CNS tag '{}' (expected one of {}, {}, {})",
+                name, CNS_SERVICES_TAG, CNS_DISABLE_TAG, CNS_REVERSE_PTR_TAG
+            ),
+            Self::InvalidCnsValue { name, reason } => {
+                write!(f, "invalid value for CNS tag '{}': {}", name, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidTag {}
+
 /// Request to add machine metadata
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct AddMetadataRequest {
     /// Metadata key-value pairs
     #[serde(flatten)]
-    pub metadata: Metadata,
+    pub metadata: MachineMetadata,
 }
 
 /// Request to add/replace machine tags
@@ -174,5 +672,5 @@ pub struct AddMetadataRequest {
 pub struct TagsRequest {
     /// Tags key-value pairs
     #[serde(flatten)]
-    pub tags: Tags,
+    pub tags: MachineTags,
 }
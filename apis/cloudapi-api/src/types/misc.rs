@@ -43,7 +43,7 @@ pub enum DiskSize {
 ///
 /// Note: PAPI returns package disk fields in snake_case, and CloudAPI passes
 /// them through directly (packages.js). No camelCase translation.
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PackageDisk {
     /// Disk size in MB or a named value like "remaining"
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -55,7 +55,7 @@ pub struct PackageDisk {
 }
 
 /// Package information
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Package {
     /// Package UUID
     pub id: Uuid,
@@ -102,6 +102,62 @@ pub struct Package {
     pub role_tag: Option<RoleTags>,
 }
 
+/// Translate a PAPI package into the CloudAPI representation
+///
+/// Mirrors `translate()` in CloudAPI's packages.js: resource fields are
+/// renamed (`max_physical_memory` becomes `memory`, `quota` becomes `disk`,
+/// and so on) and PAPI-only fields such as `owner_uuids` and `traits` are
+/// dropped.
+impl From<papi_api::Package> for Package {
+    fn from(pkg: papi_api::Package) -> Self {
+        Self {
+            id: pkg.uuid,
+            name: pkg.name,
+            memory: pkg.max_physical_memory,
+            disk: pkg.quota,
+            swap: pkg.max_swap,
+            vcpus: pkg
+                .vcpus
+                .map_or(0, |vcpus| u32::try_from(vcpus).unwrap_or(u32::MAX)),
+            lwps: Some(u32::try_from(pkg.max_lwps).unwrap_or(u32::MAX)),
+            version: Some(pkg.version),
+            group: pkg.group,
+            description: pkg.description,
+            default: pkg.default.unwrap_or(false),
+            brand: pkg.brand.map(vmapi_brand),
+            flexible_disk: pkg.flexible_disk,
+            disks: pkg
+                .disks
+                .map(|disks| disks.into_iter().map(PackageDisk::from).collect()),
+            role_tag: None,
+        }
+    }
+}
+
+/// Map a PAPI brand onto the VMAPI brand used by CloudAPI output types
+fn vmapi_brand(brand: papi_api::Brand) -> VmapiBrand {
+    match brand {
+        papi_api::Brand::Bhyve => VmapiBrand::Bhyve,
+        papi_api::Brand::Joyent => VmapiBrand::Joyent,
+        papi_api::Brand::JoyentMinimal => VmapiBrand::JoyentMinimal,
+        papi_api::Brand::Kvm => VmapiBrand::Kvm,
+        papi_api::Brand::Lx => VmapiBrand::Lx,
+        papi_api::Brand::Unknown => VmapiBrand::Unknown,
+    }
+}
+
+impl From<papi_api::DiskSpec> for PackageDisk {
+    fn from(disk: papi_api::DiskSpec) -> Self {
+        Self {
+            size: disk.size.map(|size| match size {
+                papi_api::DiskSize::Size(mb) => DiskSize::Megabytes(mb),
+                papi_api::DiskSize::Remaining(_) => DiskSize::Named("remaining".to_string()),
+            }),
+            block_size: None,
+        }
+    }
+}
+
 /// Query parameters for listing packages
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListPackagesQuery {
@@ -144,13 +200,46 @@ pub struct ListPackagesQuery {
 /// ```json
 /// {"us-central-1": "https://us-central-1.api.mnx.io"}
 /// ```
+// The map is the wire format of the Node.js CloudAPI and is kept as is;
+// `entries()` and iteration give typed `Datacenter` entries ordered by name.
+//
 // Newtype rather than a type alias so the OpenAPI spec carries `Datacenters`
 // as a named schema rather than an anonymous map. Type aliases are erased
 // before schema generation, which would produce unnamed map types in
 // downstream-generated clients.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Datacenters(pub HashMap<String, String>);
 
+impl Datacenters {
+    /// CloudAPI URL of the named datacenter
+    pub fn url(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Name of the datacenter served from `url`, ignoring a trailing slash
+    pub fn name_for_url(&self, url: &str) -> Option<&str> {
+        let url = url.trim_end_matches('/');
+        self.0
+            .iter()
+            .find(|(_, u)| u.trim_end_matches('/') == url)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// All datacenters, ordered by name
+    pub fn entries(&self) -> Vec<Datacenter> {
+        let mut entries: Vec<Datacenter> = self
+            .0
+            .iter()
+            .map(|(name, url)| Datacenter {
+                name: name.clone(),
+                url: url.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+}
+
 impl std::ops::Deref for Datacenters {
     type Target = HashMap<String, String>;
     fn deref(&self) -> &Self::Target {
@@ -164,8 +253,28 @@ impl std::ops::DerefMut for Datacenters {
     }
 }
 
+impl From<HashMap<String, String>> for Datacenters {
+    fn from(m: HashMap<String, String>) -> Self {
+        Datacenters(m)
+    }
+}
+
+impl FromIterator<Datacenter> for Datacenters {
+    fn from_iter<I: IntoIterator<Item = Datacenter>>(iter: I) -> Self {
+        Datacenters(iter.into_iter().map(|dc| (dc.name, dc.url)).collect())
+    }
+}
+
+impl IntoIterator for Datacenters {
+    type Item = Datacenter;
+    type IntoIter = std::vec::IntoIter<Datacenter>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries().into_iter()
+    }
+}
+
 /// Datacenter information (used for add_foreign_datacenter response)
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Datacenter {
     /// Datacenter name
     pub name: String,
@@ -189,13 +298,37 @@ pub struct AddForeignDatacenterRequest {
 /// ```json
 /// {"cmon": "https://cmon.example.com:9163", "docker": "tcp://docker.example.com:2376"}
 /// ```
+// The map is the wire format of the Node.js CloudAPI and is kept as is;
+// `entries()` and iteration give typed `Service` entries ordered by name.
+//
 // Newtype rather than a type alias so the OpenAPI spec carries `Services`
 // as a named schema rather than an anonymous map. Type aliases are erased
 // before schema generation, which would produce unnamed map types in
 // downstream-generated clients.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Services(pub HashMap<String, String>);
 
+impl Services {
+    /// Endpoint of the named service
+    pub fn endpoint(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// All services, ordered by name
+    pub fn entries(&self) -> Vec<Service> {
+        let mut entries: Vec<Service> = self
+            .0
+            .iter()
+            .map(|(name, endpoint)| Service {
+                name: name.clone(),
+                endpoint: endpoint.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+}
+
 impl std::ops::Deref for Services {
     type Target = HashMap<String, String>;
     fn deref(&self) -> &Self::Target {
@@ -209,8 +342,32 @@ impl std::ops::DerefMut for Services {
     }
 }
 
-/// Service information (individual service entry, for documentation)
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+impl From<HashMap<String, String>> for Services {
+    fn from(m: HashMap<String, String>) -> Self {
+        Services(m)
+    }
+}
+
+impl FromIterator<Service> for Services {
+    fn from_iter<I: IntoIterator<Item = Service>>(iter: I) -> Self {
+        Services(
+            iter.into_iter()
+                .map(|svc| (svc.name, svc.endpoint))
+                .collect(),
+        )
+    }
+}
+
+impl IntoIterator for Services {
+    type Item = Service;
+    type IntoIter = std::vec::IntoIter<Service>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries().into_iter()
+    }
+}
+
+/// Service information (an entry of [`Services`])
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Service {
     /// Service name
     pub name: String,
@@ -248,7 +405,7 @@ use vmapi_api::MigrationPhase;
 /// - `abort`: Cancel the migration and clean up
 /// - `pause`: Pause an in-progress migration
 /// - `finalize`: Clean up after a successful switch
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MigrationAction {
     /// Start a new migration
@@ -265,6 +422,22 @@ pub enum MigrationAction {
     Pause,
     /// Clean up after switch
     Finalize,
+    /// Unknown action (forward compatibility)
+    #[serde(other)]
+    Unknown,
+}
+
+/// Query parameters for migration actions
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MigrationActionQuery {
+    /// Migration action to perform. Optional in the query string because
+    /// clients may send it in the request body instead. Body takes
+    /// precedence over the query parameter.
+    // Implementation note: matches Restify's mapParams behavior.
+    // Service implementations should check the body first, then fall back
+    // to this query parameter.
+    #[serde(default)]
+    pub action: Option<MigrationAction>,
 }
 
 /// Migration information
@@ -344,7 +517,8 @@ pub struct MigrationEstimate {
 /// Migration request
 ///
 /// Used to perform migration actions on an instance. The `action` field
-/// specifies which migration operation to perform.
+/// specifies which migration operation to perform. It may be omitted when
+/// the action is passed as a query parameter (`?action=begin`) instead.
 ///
 /// # Examples
 ///
@@ -369,8 +543,9 @@ pub struct MigrationEstimate {
 /// ```
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct MigrateRequest {
-    /// Migration action to perform
-    pub action: MigrationAction,
+    /// Migration action to perform (takes precedence over `?action=`)
+    #[serde(default)]
+    pub action: Option<MigrationAction>,
     /// Affinity rules (only valid for "begin" and "automatic" actions)
     ///
     /// These rules influence which server the instance will be migrated to.
@@ -378,6 +553,62 @@ pub struct MigrateRequest {
     pub affinity: Option<AffinityRules>,
 }
 
+/// Migration progress event
+///
+/// Messages sent by the migration watch channel
+/// (`/{account}/migrations/{machine}/watch`), one JSON object per message.
+/// The channel sends `progress` events while the migration runs and a single
+/// `end` event, after which the server closes the connection.
+// Note: Like Migration, event fields are passed through from VMAPI in
+// snake_case.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum MigrationWatchEvent {
+    /// Progress update for the current phase
+    Progress(MigrationProgressEvent),
+    /// The current migration action has finished
+    End(MigrationEndEvent),
+    /// Unknown event type (forward compatibility)
+    #[serde(other)]
+    Unknown,
+}
+
+/// Progress update sent while a migration action is running
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MigrationProgressEvent {
+    /// Phase being reported on
+    pub phase: MigrationPhase,
+    /// State of the phase
+    pub state: MigrationState,
+    /// Current progress value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_progress: Option<u64>,
+    /// Total progress value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_progress: Option<u64>,
+    /// Human-readable progress message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Transfer rate in bytes per second (sync phase only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_bytes_second: Option<u64>,
+    /// Estimated milliseconds remaining (sync phase only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eta_ms: Option<u64>,
+}
+
+/// Final event sent when a migration action finishes
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MigrationEndEvent {
+    /// Phase the migration finished in
+    pub phase: MigrationPhase,
+    /// Resulting migration state
+    pub state: MigrationState,
+    /// Error message if the action failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 /// Path parameter for resource role tag operations
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ResourcePath {
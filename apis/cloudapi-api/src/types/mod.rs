@@ -7,12 +7,15 @@
 //! CloudAPI type definitions
 
 pub mod account;
+pub mod aperture;
 pub mod changefeed;
 pub mod common;
 pub mod firewall;
+pub mod health;
 pub mod image;
 pub mod key;
 pub mod machine;
+pub mod machine_export;
 pub mod machine_resources;
 pub mod misc;
 pub mod network;
@@ -20,12 +23,15 @@ pub mod user;
 pub mod volume;
 
 pub use account::*;
+pub use aperture::*;
 pub use changefeed::*;
 pub use common::*;
 pub use firewall::*;
+pub use health::*;
 pub use image::*;
 pub use key::*;
 pub use machine::*;
+pub use machine_export::*;
 pub use machine_resources::*;
 pub use misc::*;
 pub use network::*;
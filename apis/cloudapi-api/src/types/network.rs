@@ -199,6 +199,13 @@ pub struct Network {
     /// VLAN ID
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vlan_id: Option<u16>,
+    /// NIC tag the network is on. A machine can only be given this network
+    /// on a server that provides the tag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nic_tag: Option<String>,
+    /// MTU
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u32>,
     /// DNS suffixes for CNS
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub suffixes: Option<Vec<String>>,
@@ -208,11 +215,94 @@ pub struct Network {
     /// Routes
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub routes: Option<serde_json::Value>,
+    /// Network pool: provisioning picks one of the pool's networks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool: Option<bool>,
+    /// Networks in the pool (pools only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub networks: Option<NetworkIds>,
+    /// NIC tags of the networks in the pool (pools only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nic_tags_present: Option<Vec<String>>,
     /// Role tags for RBAC
     #[serde(rename = "role-tag", default, skip_serializing_if = "Option::is_none")]
     pub role_tag: Option<RoleTags>,
 }
 
+impl Network {
+    /// Whether this entry is a network pool rather than a single network
+    pub fn is_pool(&self) -> bool {
+        self.pool == Some(true)
+    }
+
+    /// NIC tags a server must provide one of for a machine to be given this
+    /// network (or, for a pool, one of its networks)
+    ///
+    /// Empty when the NIC tag is not known.
+    pub fn nic_tags(&self) -> Vec<&str> {
+        match (&self.nic_tags_present, &self.nic_tag) {
+            (Some(tags), _) if self.is_pool() => tags.iter().map(String::as_str).collect(),
+            (_, Some(tag)) => vec![tag.as_str()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Network pool
+///
+/// A pool groups networks (typically on the same NIC tag) so that
+/// provisioning can pick whichever has free addresses. `list_networks`
+/// returns pools alongside networks, marked with `pool: true`; convert a
+/// pool to its [`Network`] listing entry with `From`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NetworkPool {
+    /// Pool UUID
+    pub id: Uuid,
+    /// Pool name
+    pub name: String,
+    /// Public pool
+    pub public: bool,
+    /// Description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Networks in the pool
+    pub networks: NetworkIds,
+    /// NIC tag shared by the pool's networks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nic_tag: Option<String>,
+    /// NIC tags of the networks in the pool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nic_tags_present: Option<Vec<String>>,
+}
+
+impl From<NetworkPool> for Network {
+    fn from(pool: NetworkPool) -> Self {
+        Network {
+            id: pool.id,
+            name: pool.name,
+            public: pool.public,
+            fabric: None,
+            description: pool.description,
+            gateway: None,
+            internet_nat: None,
+            provision_start_ip: None,
+            provision_end_ip: None,
+            subnet: None,
+            netmask: None,
+            vlan_id: None,
+            nic_tag: pool.nic_tag,
+            mtu: None,
+            suffixes: None,
+            resolvers: None,
+            routes: None,
+            pool: Some(true),
+            networks: Some(pool.networks),
+            nic_tags_present: pool.nic_tags_present,
+            role_tag: None,
+        }
+    }
+}
+
 /// Fabric VLAN information
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct FabricVlan {
@@ -6,15 +6,32 @@
 
 //! User, role, and policy types
 
+use super::aperture::{InvalidPolicyRule, PolicyRule};
 use super::common::{RoleTags, Timestamp, Uuid};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Aperture policy rule strings (shared across Policy, CreatePolicyRequest,
 /// UpdatePolicyRequest so all three reference the same named schema).
+///
+/// The rules are kept as the strings CloudAPI stores; [`PolicyRules::parse`]
+/// and [`PolicyRules::canonical`] check them against the Aperture grammar.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct PolicyRules(pub Vec<String>);
 
+impl PolicyRules {
+    /// Parse every rule, failing on the first that is not valid Aperture
+    pub fn parse(&self) -> Result<Vec<PolicyRule>, InvalidPolicyRule> {
+        self.0.iter().map(|rule| rule.parse()).collect()
+    }
+
+    /// The rules in canonical form, so that the same rule written two ways
+    /// is sent (and compares) the same
+    pub fn canonical(&self) -> Result<PolicyRules, InvalidPolicyRule> {
+        Ok(self.parse()?.iter().map(ToString::to_string).collect())
+    }
+}
+
 impl std::ops::Deref for PolicyRules {
     type Target = Vec<String>;
     fn deref(&self) -> &Self::Target {
@@ -331,6 +348,13 @@ pub struct CreatePolicyRequest {
     pub description: Option<String>,
 }
 
+impl CreatePolicyRequest {
+    /// Check that every rule is valid Aperture
+    pub fn validate(&self) -> Result<(), InvalidPolicyRule> {
+        self.rules.parse().map(|_| ())
+    }
+}
+
 /// Request to update policy
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UpdatePolicyRequest {
@@ -344,3 +368,13 @@ pub struct UpdatePolicyRequest {
     #[serde(default)]
     pub description: Option<String>,
 }
+
+impl UpdatePolicyRequest {
+    /// Check that every rule, if rules are being replaced, is valid Aperture
+    pub fn validate(&self) -> Result<(), InvalidPolicyRule> {
+        match &self.rules {
+            Some(rules) => rules.parse().map(|_| ()),
+            None => Ok(()),
+        }
+    }
+}
@@ -8,12 +8,13 @@
 //!
 //! These tests verify that CloudAPI JSON responses for accounts deserialize
 //! correctly, particularly the snake_case `triton_cns_enabled` field that
-//! differs from the camelCase convention.
+//! differs from the camelCase convention, and that provisioning limits are
+//! evaluated the way CloudAPI evaluates them.
 
 mod common;
 
 use chrono::TimeZone;
-use cloudapi_api::types::Account;
+use cloudapi_api::types::{Account, LimitBy, LimitCheck, ProvisionCandidate, ProvisioningLimits};
 use uuid::Uuid;
 
 #[test]
@@ -118,3 +119,75 @@ fn test_account_triton_cns_enabled_round_trip() {
         "should not serialize as camelCase"
     );
 }
+
+#[test]
+fn test_provisioning_limits_deserialize() {
+    let limits: ProvisioningLimits = common::deserialize_fixture("account", "limits.json");
+
+    assert_eq!(limits.len(), 6);
+    assert_eq!(limits[0].by, None);
+    assert_eq!(limits[0].by(), LimitBy::Machines);
+    assert_eq!(limits[1].by(), LimitBy::Ram);
+    assert_eq!(limits[2].filter(), Some((LimitCheck::Brand, "bhyve")));
+    assert_eq!(limits[3].used, None);
+    assert_eq!(limits[4].filter(), Some((LimitCheck::Image, "base-64-lts")));
+
+    // Unrecognized dimensions and filters are kept rather than rejected
+    assert_eq!(limits[5].by(), LimitBy::Unknown);
+    assert_eq!(limits[5].check, Some(LimitCheck::Unknown));
+    assert_eq!(limits[5].filter(), None);
+}
+
+#[test]
+fn test_provisioning_limits_evaluate() {
+    let limits: ProvisioningLimits = common::deserialize_fixture("account", "limits.json");
+    let candidate = ProvisionCandidate {
+        brand: Some("joyent".to_string()),
+        image: Some("base-64".to_string()),
+        os: Some("smartos".to_string()),
+        ram_mib: 1024,
+        disk_mib: 25600,
+    };
+
+    // One more machine and 1 GiB more RAM fit; the other limits do not apply
+    assert!(limits.allow(&candidate));
+
+    // The RAM limit is exceeded
+    let large = ProvisionCandidate {
+        ram_mib: 4096,
+        ..candidate.clone()
+    };
+    let exceeded = limits.exceeded_by(&large);
+    assert_eq!(exceeded.len(), 1);
+    assert_eq!(exceeded[0].by(), LimitBy::Ram);
+
+    // Quota is counted in GiB but compared without rounding the package disk
+    let bhyve = ProvisionCandidate {
+        brand: Some("bhyve".to_string()),
+        disk_mib: 50 * 1024 + 1,
+        ..candidate.clone()
+    };
+    assert_eq!(limits.exceeded_by(&bhyve).len(), 1);
+    let bhyve = ProvisionCandidate {
+        disk_mib: 50 * 1024,
+        ..bhyve
+    };
+    assert!(limits.allow(&bhyve));
+
+    // A value of -1 blocks every matching provision
+    let windows = ProvisionCandidate {
+        os: Some("windows".to_string()),
+        ..candidate.clone()
+    };
+    assert!(!limits.allow(&windows));
+
+    // Image limits match on the image name
+    let lts = ProvisionCandidate {
+        image: Some("base-64-lts".to_string()),
+        ..candidate
+    };
+    assert_eq!(
+        limits.exceeded_by(&lts)[0].filter(),
+        Some((LimitCheck::Image, "base-64-lts"))
+    );
+}
@@ -22,6 +22,10 @@ use cloudapi_api::types::{
     ResizeDiskRequest, ResizeMachineRequest, StartMachineRequest, StopMachineRequest,
     UpdateVolumeRequest, VolumeAction, VolumeActionQuery,
 };
+use cloudapi_api::types::{
+    MigrateRequest, MigrationAction, MigrationActionQuery, MigrationPhase, MigrationState,
+    MigrationWatchEvent,
+};
 use uuid::Uuid;
 
 // --- MachineAction tests ---
@@ -374,3 +378,93 @@ fn test_update_volume_request() {
     let req: UpdateVolumeRequest = serde_json::from_str(json).unwrap();
     assert!(req.name.is_none());
 }
+
+// --- MigrationAction tests ---
+
+#[test]
+fn test_migration_action_wire_format() {
+    // MigrationAction uses lowercase
+    let cases = [
+        (MigrationAction::Begin, "begin"),
+        (MigrationAction::Sync, "sync"),
+        (MigrationAction::Switch, "switch"),
+        (MigrationAction::Automatic, "automatic"),
+        (MigrationAction::Abort, "abort"),
+        (MigrationAction::Pause, "pause"),
+        (MigrationAction::Finalize, "finalize"),
+    ];
+
+    for (variant, expected_wire) in cases {
+        let serialized = serde_json::to_value(variant).unwrap();
+        assert_eq!(
+            serialized,
+            serde_json::Value::String(expected_wire.to_string()),
+            "MigrationAction::{:?} should serialize to {:?}",
+            variant,
+            expected_wire
+        );
+        let parsed: MigrationAction = serde_json::from_value(serialized).unwrap();
+        assert_eq!(parsed, variant);
+    }
+
+    let parsed: MigrationAction = serde_json::from_str(r#""rollback""#).unwrap();
+    assert_eq!(parsed, MigrationAction::Unknown);
+}
+
+#[test]
+fn test_migration_action_query_deserialize() {
+    let json = r#"{"action": "pause"}"#;
+    let query: MigrationActionQuery = serde_json::from_str(json).unwrap();
+    assert_eq!(query.action, Some(MigrationAction::Pause));
+
+    // Action is optional (clients may send it in the body instead)
+    let json = r#"{}"#;
+    let query: MigrationActionQuery = serde_json::from_str(json).unwrap();
+    assert_eq!(query.action, None);
+}
+
+#[test]
+fn test_migrate_request() {
+    let json = r#"{"action": "begin", "affinity": ["instance!=web-*"]}"#;
+    let req: MigrateRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.action, Some(MigrationAction::Begin));
+    assert!(req.affinity.is_some());
+
+    // Action may be supplied as ?action= instead
+    let req: MigrateRequest = serde_json::from_str("{}").unwrap();
+    assert_eq!(req.action, None);
+    assert!(req.affinity.is_none());
+}
+
+#[test]
+fn test_migration_watch_events() {
+    let json = r#"{
+        "type": "progress",
+        "phase": "sync",
+        "state": "running",
+        "current_progress": 512,
+        "total_progress": 1024,
+        "message": "syncing data",
+        "transfer_bytes_second": 1048576,
+        "eta_ms": 30000
+    }"#;
+    let MigrationWatchEvent::Progress(progress) = serde_json::from_str(json).unwrap() else {
+        panic!("expected progress event");
+    };
+    assert_eq!(progress.phase, MigrationPhase::Sync);
+    assert_eq!(progress.state, MigrationState::Running);
+    assert_eq!(progress.current_progress, Some(512));
+    assert_eq!(progress.eta_ms, Some(30000));
+
+    let json = r#"{"type": "end", "phase": "switch", "state": "successful"}"#;
+    let MigrationWatchEvent::End(end) = serde_json::from_str(json).unwrap() else {
+        panic!("expected end event");
+    };
+    assert_eq!(end.phase, MigrationPhase::Switch);
+    assert_eq!(end.state, MigrationState::Successful);
+    assert!(end.error.is_none());
+
+    let json = r#"{"type": "heartbeat"}"#;
+    let event: MigrationWatchEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(event, MigrationWatchEvent::Unknown));
+}
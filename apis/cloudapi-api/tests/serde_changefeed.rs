@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Serialization tests for changefeed WebSocket messages
+//!
+//! The fixtures are messages as sent by Node.js CloudAPI, so these tests
+//! check that servers and clients using these types agree with it on the
+//! wire format.
+
+mod common;
+
+use cloudapi_api::types::{
+    ChangefeedMessage, ChangefeedResource, ChangefeedSubResource, ChangefeedSubscription,
+    MachineState,
+};
+use uuid::Uuid;
+
+const VM_UUID: &str = "8a5918c3-84a2-4122-9ed5-60d76d7a8525";
+
+#[test]
+fn test_subscription_node_format() {
+    let sub: ChangefeedSubscription =
+        common::deserialize_fixture("changefeed", "subscription.json");
+
+    assert_eq!(sub.resource, ChangefeedResource::Vm);
+    assert_eq!(sub.sub_resources.len(), 8);
+    assert!(
+        sub.sub_resources
+            .contains(&ChangefeedSubResource::CustomerMetadata)
+    );
+    assert_eq!(sub.vms, Some(vec![Uuid::parse_str(VM_UUID).unwrap()]));
+
+    // What we send must be exactly what CloudAPI expects
+    let fixture: serde_json::Value =
+        serde_json::from_str(&common::load_fixture("changefeed", "subscription.json")).unwrap();
+    assert_eq!(serde_json::to_value(&sub).unwrap(), fixture);
+}
+
+#[test]
+fn test_state_change_message() {
+    let msg: ChangefeedMessage = common::deserialize_fixture("changefeed", "state_stopped.json");
+
+    assert_eq!(msg.changed_resource_id, Uuid::parse_str(VM_UUID).unwrap());
+    assert_eq!(msg.change_kind.resource, ChangefeedResource::Vm);
+    assert_eq!(
+        msg.change_kind.sub_resources,
+        vec![
+            ChangefeedSubResource::LastModified,
+            ChangefeedSubResource::State,
+            ChangefeedSubResource::ZoneState,
+        ]
+    );
+    assert!(msg.change_kind.includes(ChangefeedSubResource::State));
+    assert!(!msg.change_kind.includes(ChangefeedSubResource::Tags));
+    assert_eq!(msg.resource_state, "stopped");
+    assert_eq!(
+        msg.resource_state_typed(),
+        Some(vmapi_api::VmState::Stopped)
+    );
+
+    let machine = msg.resource_object.as_ref().expect("resource object");
+    assert_eq!(machine.id, msg.changed_resource_id);
+    assert_eq!(machine.state, MachineState::Stopped);
+    assert_eq!(machine.firewall_enabled, Some(false));
+}
+
+#[test]
+fn test_published_timestamp() {
+    let msg: ChangefeedMessage = common::deserialize_fixture("changefeed", "state_stopped.json");
+
+    let published = msg.published_at().expect("published timestamp");
+    assert_eq!(published.timestamp_millis(), 1_769_463_151_702);
+    assert_eq!(published.to_rfc3339(), "2026-01-26T21:32:31.702+00:00");
+
+    let mut msg = msg;
+    msg.published = "yesterday".to_string();
+    assert!(msg.published_at().is_none());
+}
+
+#[test]
+fn test_destroyed_message() {
+    let msg: ChangefeedMessage = common::deserialize_fixture("changefeed", "destroyed.json");
+
+    assert!(msg.change_kind.includes(ChangefeedSubResource::Destroyed));
+    assert!(
+        msg.resource_state_typed()
+            .is_some_and(|state| state.is_destroyed())
+    );
+    let machine = msg.resource_object.as_ref().expect("resource object");
+    assert_eq!(machine.state, MachineState::Deleted);
+    assert!(machine.ips.is_empty());
+}
+
+#[test]
+fn test_unknown_sub_resources_and_missing_object() {
+    // VMAPI passes through sub-resources that CloudAPI does not list, and
+    // some messages carry no resource object
+    let msg: ChangefeedMessage = common::deserialize_fixture("changefeed", "tags_autoboot.json");
+
+    assert_eq!(
+        msg.change_kind.sub_resources,
+        vec![
+            ChangefeedSubResource::Autoboot,
+            ChangefeedSubResource::Tags,
+            ChangefeedSubResource::Other,
+        ]
+    );
+    assert!(msg.resource_object.is_none());
+}
+
+#[test]
+fn test_message_round_trip() {
+    // A server built on these types must emit what Node CloudAPI emits
+    for name in ["state_stopped.json", "destroyed.json"] {
+        let fixture: serde_json::Value =
+            serde_json::from_str(&common::load_fixture("changefeed", name)).unwrap();
+        let msg: ChangefeedMessage = serde_json::from_value(fixture.clone()).unwrap();
+        let json = serde_json::to_value(&msg).unwrap();
+
+        for field in [
+            "changeKind",
+            "changedResourceId",
+            "published",
+            "resourceState",
+        ] {
+            assert_eq!(json[field], fixture[field], "{}: {}", name, field);
+        }
+        assert_eq!(
+            json["resourceObject"]["id"], fixture["resourceObject"]["id"],
+            "{}",
+            name
+        );
+
+        let reparsed: ChangefeedMessage = serde_json::from_value(json).unwrap();
+        assert_eq!(reparsed.change_kind, msg.change_kind);
+        assert_eq!(reparsed.published, msg.published);
+    }
+}
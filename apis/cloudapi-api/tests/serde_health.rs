@@ -0,0 +1,55 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Serialization tests for the service health endpoints
+
+use cloudapi_api::types::{API_VERSION, PingResponse, VersionResponse};
+
+#[test]
+fn test_ping_response_node_format() {
+    let json = r#"{
+        "ping": "pong",
+        "cloudapi": {"versions": ["7.3.0", "8.0.0", "9.20.0"]}
+    }"#;
+    let ping: PingResponse = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        ping,
+        PingResponse::pong(vec![
+            "7.3.0".to_string(),
+            "8.0.0".to_string(),
+            "9.20.0".to_string()
+        ])
+    );
+}
+
+#[test]
+fn test_version_response_round_trip() {
+    let version = VersionResponse {
+        name: "cloudapi".to_string(),
+        version: API_VERSION.to_string(),
+        versions: vec![API_VERSION.to_string()],
+    };
+    let json = serde_json::to_value(&version).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "name": "cloudapi",
+            "version": "9.20.0",
+            "versions": ["9.20.0"]
+        })
+    );
+    let parsed: VersionResponse = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, version);
+}
+
+#[test]
+fn test_health_api_description_builds() {
+    // The health routes must stay out of the `/{account}` namespace that
+    // CloudApi registers, so they get their own description
+    cloudapi_api::cloud_api_health_mod::stub_api_description().unwrap();
+}
@@ -12,7 +12,7 @@
 
 mod common;
 
-use cloudapi_api::types::{Image, ImageState, ImageType};
+use cloudapi_api::types::{Image, ImageState, ImageType, ListImagesQuery};
 use uuid::Uuid;
 use vmapi_api::Brand as VmapiBrand;
 
@@ -52,11 +52,7 @@ fn test_image_published_at_snake_case() {
     );
     assert_eq!(
         image.published_at,
-        Some(
-            chrono::DateTime::parse_from_rfc3339("2021-04-01T00:42:24Z")
-                .unwrap()
-                .with_timezone(&chrono::Utc),
-        )
+        Some("2021-04-01T00:42:24Z".parse().unwrap())
     );
 }
 
@@ -226,3 +222,43 @@ fn test_image_round_trip() {
     assert_eq!(original.state, deserialized.state);
     assert_eq!(original.published_at, deserialized.published_at);
 }
+
+// --- ListImagesQuery tests ---
+
+/// Every filter uses the query parameter name from the Node.js CloudAPI;
+/// `type` in particular needs a rename because it is a Rust keyword.
+#[test]
+fn test_list_images_query_wire_names() {
+    let json = r#"{
+        "name": "base-64-lts",
+        "os": "smartos",
+        "version": "21.4.0",
+        "public": true,
+        "state": "all",
+        "owner": "930896af-bf8c-48d4-885c-6573a94b1853",
+        "type": "zone-dataset",
+        "limit": 25,
+        "marker": "2b683a82-a066-11e3-97ab-2faa44701c5a"
+    }"#;
+    let query: ListImagesQuery = serde_json::from_str(json).unwrap();
+
+    assert_eq!(query.name.as_deref(), Some("base-64-lts"));
+    assert_eq!(query.os.as_deref(), Some("smartos"));
+    assert_eq!(query.version.as_deref(), Some("21.4.0"));
+    assert_eq!(query.public, Some(true));
+    assert_eq!(query.state, Some(ImageState::All));
+    assert_eq!(
+        query.owner,
+        Some(Uuid::parse_str("930896af-bf8c-48d4-885c-6573a94b1853").unwrap())
+    );
+    assert_eq!(query.image_type, Some(ImageType::ZoneDataset));
+    assert_eq!(query.limit, Some(25));
+    assert_eq!(
+        query.marker,
+        Some(Uuid::parse_str("2b683a82-a066-11e3-97ab-2faa44701c5a").unwrap())
+    );
+
+    // "image_type" is not the wire name
+    let query: ListImagesQuery = serde_json::from_str(r#"{"image_type": "zvol"}"#).unwrap();
+    assert!(query.image_type.is_none());
+}
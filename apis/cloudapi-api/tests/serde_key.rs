@@ -27,6 +27,7 @@ fn test_active_permanent_key() {
     assert_eq!(key.credentialtype, CredentialType::Permanent);
     assert_eq!(key.description.as_deref(), Some("My test key"));
     assert!(key.expiration.is_none());
+    assert!(key.lastused.is_some());
 }
 
 #[test]
@@ -37,6 +38,7 @@ fn test_inactive_key() {
     assert_eq!(key.credentialtype, CredentialType::Permanent);
     assert!(key.description.is_none());
     assert!(key.expiration.is_none());
+    assert!(key.lastused.is_none());
 }
 
 #[test]
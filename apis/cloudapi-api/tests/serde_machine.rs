@@ -11,7 +11,12 @@
 
 mod common;
 
-use cloudapi_api::types::{Machine, MachineState, MachineType, MountMode};
+use cloudapi_api::types::{
+    AddMetadataRequest, AuditAction, AuditCallerType, AuditEntry, AuditSuccess, InvalidMetadata,
+    InvalidTag, MAX_METADATA_VALUE_LEN, Machine, MachineColumn, MachineExportFormat,
+    MachineExportQuery, MachineMetadata, MachineState, MachineSummary, MachineTags, MachineType,
+    MountMode, Tags, TagsRequest,
+};
 use uuid::Uuid;
 
 #[test]
@@ -324,3 +329,289 @@ fn test_mount_mode_unknown_variant() {
     let parsed: MountMode = serde_json::from_str(json).unwrap();
     assert_eq!(parsed, MountMode::Unknown);
 }
+
+/// AddMetadataRequest accepts scalar values and keeps them as sent.
+#[test]
+fn test_add_metadata_request_valid() {
+    let json = r##"{"user-script": "#!/bin/sh", "com.example:enabled": true, "port": 8080}"##;
+    let request: AddMetadataRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(request.metadata.len(), 3);
+    assert_eq!(request.metadata["port"], serde_json::json!(8080));
+}
+
+/// AddMetadataRequest rejects invalid metadata during deserialization.
+#[test]
+fn test_add_metadata_request_invalid() {
+    let cases = [
+        r#"{"": "x"}"#,
+        r#"{"has space": "x"}"#,
+        r#"{"triton.cns.services": "web"}"#,
+        r#"{"sdc:uuid": "x"}"#,
+        r#"{"nested": {"a": 1}}"#,
+        r#"{"nothing": null}"#,
+    ];
+
+    for json in cases {
+        let result: Result<AddMetadataRequest, _> = serde_json::from_str(json);
+        assert!(result.is_err(), "expected {} to be rejected", json);
+    }
+}
+
+/// MachineMetadata::new reports which constraint was violated.
+#[test]
+fn test_machine_metadata_errors() {
+    let reserved: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(r#"{"triton.cns.disable": "true"}"#).unwrap();
+    let err = MachineMetadata::new(reserved).unwrap_err();
+    assert_eq!(
+        err,
+        InvalidMetadata::ReservedKey {
+            key: "triton.cns.disable".to_string(),
+            prefix: "triton.",
+        }
+    );
+    assert!(err.to_string().contains("reserved"));
+
+    let mut too_long = serde_json::Map::new();
+    too_long.insert(
+        "user-data".to_string(),
+        serde_json::Value::String("x".repeat(MAX_METADATA_VALUE_LEN + 1)),
+    );
+    assert!(matches!(
+        MachineMetadata::new(too_long),
+        Err(InvalidMetadata::ValueTooLong { len, .. }) if len == MAX_METADATA_VALUE_LEN + 1
+    ));
+}
+
+/// TagsRequest accepts well-formed tags, including the CNS tags.
+#[test]
+fn test_tags_request_valid() {
+    let json = r#"{
+        "role": "web",
+        "com.example:replicas": 3,
+        "triton.cns.services": "web,api:port=8080",
+        "triton.cns.disable": false,
+        "triton.cns.reverse_ptr": "web1.example.com."
+    }"#;
+    let request: TagsRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(request.tags.len(), 5);
+    assert_eq!(request.tags["com.example:replicas"], serde_json::json!(3));
+
+    let request: TagsRequest = serde_json::from_str(r#"{"triton.cns.disable": "true"}"#).unwrap();
+    assert_eq!(
+        request.tags["triton.cns.disable"],
+        serde_json::json!("true")
+    );
+}
+
+/// TagsRequest rejects bad names and CNS values during deserialization.
+#[test]
+fn test_tags_request_invalid() {
+    let cases = [
+        r#"{"": "x"}"#,
+        r#"{"has space": "x"}"#,
+        r#"{"nested": {"a": 1}}"#,
+        r#"{"triton.cns.services": ""}"#,
+        r#"{"triton.cns.services": "web,,api"}"#,
+        r#"{"triton.cns.services": "web, api"}"#,
+        r#"{"triton.cns.services": "web_app"}"#,
+        r#"{"triton.cns.services": "-web"}"#,
+        r#"{"triton.cns.services": "web:port=0"}"#,
+        r#"{"triton.cns.services": "web:port=http"}"#,
+        r#"{"triton.cns.services": "web:weight=10"}"#,
+        r#"{"triton.cns.services": 123}"#,
+        r#"{"triton.cns.disable": "yes"}"#,
+        r#"{"triton.cns.disable": 1}"#,
+        r#"{"triton.cns.reverse_ptr": "web..example.com"}"#,
+        r#"{"triton.cns.service": "web"}"#,
+    ];
+
+    for json in cases {
+        let result: Result<TagsRequest, _> = serde_json::from_str(json);
+        assert!(result.is_err(), "expected {} to be rejected", json);
+    }
+}
+
+/// MachineTags::new reports which constraint was violated, and
+/// new_unchecked sends tags as given.
+#[test]
+fn test_machine_tags_errors() {
+    let unknown: Tags = [("triton.cns.service", "web")].into_iter().collect();
+    let err = MachineTags::new(unknown.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        InvalidTag::UnknownCnsTag("triton.cns.service".to_string())
+    );
+    assert!(err.to_string().contains("triton.cns.services"));
+
+    let bad_port: Tags = [("triton.cns.services", "web:port=65536")]
+        .into_iter()
+        .collect();
+    let err = MachineTags::new(bad_port).unwrap_err();
+    assert!(matches!(
+        &err,
+        InvalidTag::InvalidCnsValue { name, .. } if name == "triton.cns.services"
+    ));
+    assert!(err.to_string().contains("port"), "{}", err);
+
+    let unchecked = MachineTags::new_unchecked(unknown);
+    assert_eq!(unchecked["triton.cns.service"], serde_json::json!("web"));
+    assert_eq!(
+        serde_json::to_value(&unchecked).unwrap(),
+        serde_json::json!({"triton.cns.service": "web"})
+    );
+}
+
+/// Audit records as returned by Node.js CloudAPI, including an action and
+/// caller type this crate does not know about.
+#[test]
+fn test_audit_entries_deserialize() {
+    let audit: Vec<AuditEntry> = common::deserialize_fixture("machine", "audit.json");
+    assert_eq!(audit.len(), 3);
+
+    let start = &audit[0];
+    assert_eq!(start.action_kind(), AuditAction::Start);
+    assert!(start.succeeded());
+    let caller = start.caller.as_ref().unwrap();
+    assert_eq!(caller.caller_type, Some(AuditCallerType::Signature));
+    assert_eq!(caller.ip.as_deref(), Some("203.0.113.7"));
+    assert_eq!(
+        caller.key_id.as_deref(),
+        Some("/admin/keys/aa:bb:cc:dd:ee:ff:00:11:22:33:44:55:66:77:88:99")
+    );
+
+    let provision = &audit[1];
+    assert_eq!(provision.action_kind(), AuditAction::Provision);
+    assert_eq!(provision.success, Some(AuditSuccess::No));
+    assert!(!provision.succeeded());
+    let caller = provision.caller.as_ref().unwrap();
+    assert_eq!(caller.caller_type, Some(AuditCallerType::Operator));
+    assert_eq!(caller.ip, None);
+
+    // Unknown names stay readable rather than failing the whole list
+    let unknown = &audit[2];
+    assert_eq!(unknown.action, "migrate_estimate");
+    assert_eq!(unknown.action_kind(), AuditAction::Unknown);
+    assert_eq!(unknown.success, None);
+    assert_eq!(
+        unknown.caller.as_ref().unwrap().caller_type,
+        Some(AuditCallerType::Unknown)
+    );
+
+    // The caller round-trips with CloudAPI's field names
+    let json = serde_json::to_value(start).unwrap();
+    assert_eq!(json["caller"]["type"], "signature");
+    assert_eq!(json["caller"]["ip"], "203.0.113.7");
+    assert!(json["caller"]["keyId"].is_string());
+}
+
+#[test]
+fn test_audit_action_names() {
+    assert_eq!(
+        AuditAction::from("create_snapshot"),
+        AuditAction::CreateSnapshot
+    );
+    assert_eq!(
+        AuditAction::from("enable_deletion_protection"),
+        AuditAction::EnableDeletionProtection
+    );
+    assert_eq!(AuditAction::from("Start"), AuditAction::Unknown);
+    assert_eq!(
+        AuditAction::RollbackSnapshot.to_string(),
+        "rollback_snapshot"
+    );
+}
+
+#[test]
+fn test_machine_summary_counts() {
+    let basic: Machine = common::deserialize_fixture("machine", "basic.json");
+    let lx: Machine = common::deserialize_fixture("machine", "instance_get.json");
+    let mut stopped = basic.clone();
+    stopped.state = MachineState::Stopped;
+    let mut deleted = basic.clone();
+    deleted.state = MachineState::Deleted;
+
+    let summary: MachineSummary = [&basic, &lx, &stopped, &deleted].into_iter().collect();
+    assert_eq!(summary.total, 3);
+    assert_eq!(summary.states.get("running"), Some(&2));
+    assert_eq!(summary.states.get("stopped"), Some(&1));
+    assert_eq!(summary.states.get("deleted"), None);
+    assert_eq!(summary.brands.get("joyent"), Some(&2));
+    assert_eq!(summary.brands.get("lx"), Some(&1));
+    assert_eq!(summary.packages.get("g4-highcpu-1G"), Some(&2));
+    assert_eq!(summary.packages.get("g1.micro"), Some(&1));
+
+    let json = serde_json::to_value(&summary).unwrap();
+    assert_eq!(json["total"], 3);
+    assert_eq!(json["states"]["running"], 2);
+    assert_eq!(json["brands"]["lx"], 1);
+
+    let empty = MachineSummary::default();
+    assert_eq!(
+        serde_json::to_value(&empty).unwrap(),
+        serde_json::json!({"total": 0, "states": {}, "brands": {}, "packages": {}})
+    );
+}
+
+#[test]
+fn test_machine_export_rows() {
+    let mut json: serde_json::Value = common::deserialize_fixture("machine", "basic.json");
+    json["name"] = "web \"east\", 1".into();
+    json["ips"] = serde_json::json!(["10.88.88.10", "192.168.1.5"]);
+    json["tags"] = serde_json::json!({"role": "web"});
+    let machine: Machine = serde_json::from_value(json).unwrap();
+
+    let columns = MachineColumn::parse_list("name, ips,tags,memory,primaryIp").unwrap();
+    let csv = MachineExportFormat::Csv;
+    assert_eq!(
+        csv.header(&columns).as_deref(),
+        Some("name,ips,tags,memory,primaryIp\n")
+    );
+    assert_eq!(
+        csv.row(&machine, &columns),
+        "\"web \"\"east\"\", 1\",\"10.88.88.10,192.168.1.5\",\"{\"\"role\"\":\"\"web\"\"}\",1024,\n"
+    );
+
+    let ndjson = MachineExportFormat::Ndjson;
+    assert_eq!(ndjson.header(&columns), None);
+    let row = ndjson.row(&machine, &columns);
+    assert!(row.ends_with('\n'));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&row).unwrap(),
+        serde_json::json!({
+            "name": "web \"east\", 1",
+            "ips": ["10.88.88.10", "192.168.1.5"],
+            "tags": {"role": "web"},
+            "memory": 1024,
+            "primaryIp": null,
+        })
+    );
+}
+
+#[test]
+fn test_machine_export_query() {
+    let query: MachineExportQuery = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert_eq!(query.format(), MachineExportFormat::Csv);
+    assert_eq!(query.columns().unwrap(), MachineColumn::DEFAULT.to_vec());
+
+    let query: MachineExportQuery = serde_json::from_value(serde_json::json!({
+        "format": "ndjson",
+        "columns": "id,state",
+        "state": "running",
+    }))
+    .unwrap();
+    assert_eq!(query.format(), MachineExportFormat::Ndjson);
+    assert_eq!(
+        query.columns().unwrap(),
+        vec![MachineColumn::Id, MachineColumn::State]
+    );
+    assert_eq!(query.list_query().state, Some(MachineState::Running));
+    assert_eq!(query.list_query().limit, None);
+
+    for list in ["", "id,bogus", "id,name,id"] {
+        assert!(MachineColumn::parse_list(list).is_err(), "{}", list);
+    }
+    for column in MachineColumn::ALL {
+        assert_eq!(column.name().parse::<MachineColumn>(), Ok(column));
+    }
+}
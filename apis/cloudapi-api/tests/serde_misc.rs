@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Serialization tests for the datacenter and service maps
+//!
+//! CloudAPI returns both as plain `name -> URL` objects. The typed entries
+//! exposed by `Datacenters` and `Services` must not change that wire format.
+
+use cloudapi_api::types::{Datacenter, Datacenters, Services};
+
+#[test]
+fn test_datacenters_deserialize() {
+    let json = r#"{
+        "us-west-1": "https://us-west-1.api.example.com",
+        "us-east-1": "https://us-east-1.api.example.com/"
+    }"#;
+    let dcs: Datacenters = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        dcs.url("us-west-1"),
+        Some("https://us-west-1.api.example.com")
+    );
+    assert_eq!(dcs.url("eu-1"), None);
+    assert_eq!(
+        dcs.entries(),
+        vec![
+            Datacenter {
+                name: "us-east-1".to_string(),
+                url: "https://us-east-1.api.example.com/".to_string(),
+            },
+            Datacenter {
+                name: "us-west-1".to_string(),
+                url: "https://us-west-1.api.example.com".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_datacenters_name_for_url_ignores_trailing_slash() {
+    let dcs: Datacenters = [Datacenter {
+        name: "us-east-1".to_string(),
+        url: "https://us-east-1.api.example.com/".to_string(),
+    }]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        dcs.name_for_url("https://us-east-1.api.example.com"),
+        Some("us-east-1")
+    );
+    assert_eq!(dcs.name_for_url("https://other.example.com"), None);
+}
+
+#[test]
+fn test_datacenters_serialize_as_map() {
+    let dcs: Datacenters = [Datacenter {
+        name: "us-east-1".to_string(),
+        url: "https://us-east-1.api.example.com".to_string(),
+    }]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        serde_json::to_value(&dcs).unwrap(),
+        serde_json::json!({"us-east-1": "https://us-east-1.api.example.com"})
+    );
+}
+
+#[test]
+fn test_services_round_trip() {
+    let json = serde_json::json!({
+        "docker": "tcp://docker.example.com:2376",
+        "cmon": "https://cmon.example.com:9163"
+    });
+    let services: Services = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(
+        services.endpoint("docker"),
+        Some("tcp://docker.example.com:2376")
+    );
+    assert_eq!(services.endpoint("manta"), None);
+
+    let names: Vec<String> = services.clone().into_iter().map(|s| s.name).collect();
+    assert_eq!(names, vec!["cmon", "docker"]);
+
+    let rebuilt: Services = services.entries().into_iter().collect();
+    assert_eq!(rebuilt, services);
+    assert_eq!(serde_json::to_value(&rebuilt).unwrap(), json);
+}
+
+#[test]
+fn test_services_empty() {
+    let services: Services = serde_json::from_str("{}").unwrap();
+    assert!(services.entries().is_empty());
+    assert_eq!(services, Services::default());
+}
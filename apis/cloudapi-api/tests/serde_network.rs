@@ -13,7 +13,7 @@
 mod common;
 
 use cloudapi_api::types::{
-    CreateFabricNetworkRequest, FabricVlan, Network, NetworkIp, Nic, NicState,
+    CreateFabricNetworkRequest, FabricVlan, Network, NetworkIp, NetworkPool, Nic, NicState,
 };
 use uuid::Uuid;
 
@@ -136,6 +136,49 @@ fn test_network_list_deserialize() {
     assert!(!networks[1].public);
 }
 
+/// Test that pools are marked in a network list and carry their NIC tags.
+#[test]
+fn test_network_list_with_pool() {
+    let json = format!(
+        "[{}, {}]",
+        common::load_fixture("network", "public.json"),
+        common::load_fixture("network", "pool.json")
+    );
+
+    let networks: Vec<Network> = serde_json::from_str(&json).expect("Failed to parse network list");
+    assert!(!networks[0].is_pool());
+
+    let pool = &networks[1];
+    assert!(pool.is_pool());
+    assert_eq!(pool.networks.as_ref().map(|n| n.len()), Some(2));
+    assert_eq!(pool.nic_tag.as_deref(), Some("external"));
+    assert_eq!(pool.nic_tags(), vec!["external", "external_rack2"]);
+}
+
+#[test]
+fn test_network_nic_tags() {
+    let mut network: Network = common::deserialize_fixture("network", "fabric.json");
+    assert!(network.nic_tags().is_empty());
+
+    network.nic_tag = Some("sdc_overlay".to_string());
+    assert_eq!(network.nic_tags(), vec!["sdc_overlay"]);
+}
+
+#[test]
+fn test_network_from_pool() {
+    let pool: NetworkPool = common::deserialize_fixture("network", "pool.json");
+    let id = pool.id;
+    let network = Network::from(pool);
+
+    assert_eq!(network.id, id);
+    assert!(network.is_pool());
+
+    let value = serde_json::to_value(&network).unwrap();
+    assert_eq!(value["pool"], true);
+    assert_eq!(value["networks"][0], "3985900d-15a8-42d8-a997-1f7e8df2d0af");
+    assert!(value.get("subnet").is_none());
+}
+
 /// Test FabricVlan deserialization.
 #[test]
 fn test_fabric_vlan_deserialize() {
@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Conversion tests from PAPI packages to CloudAPI packages
+
+use cloudapi_api::types::Package;
+
+#[test]
+fn test_package_from_papi() {
+    let papi: papi_api::Package = serde_json::from_value(serde_json::json!({
+        "uuid": "7b17343c-94af-6266-e0e8-893a3b9993d0",
+        "name": "sdc_128",
+        "version": "1.0.0",
+        "active": true,
+        "cpu_cap": 20,
+        "max_lwps": 1000,
+        "max_physical_memory": 128,
+        "max_swap": 256,
+        "quota": 10240,
+        "zfs_io_priority": 10,
+        "brand": "joyent",
+        "owner_uuids": ["930896af-bf8c-48d4-885c-6573a94b1853"],
+        "group": "sdc",
+        "description": "Small SmartOS zone",
+        "traits": {"ssd": true},
+        "v": 1
+    }))
+    .unwrap();
+
+    let pkg = Package::from(papi);
+
+    assert_eq!(
+        serde_json::to_value(&pkg).unwrap(),
+        serde_json::json!({
+            "id": "7b17343c-94af-6266-e0e8-893a3b9993d0",
+            "name": "sdc_128",
+            "memory": 128,
+            "disk": 10240,
+            "swap": 256,
+            "vcpus": 0,
+            "lwps": 1000,
+            "version": "1.0.0",
+            "group": "sdc",
+            "description": "Small SmartOS zone",
+            "default": false,
+            "brand": "joyent"
+        })
+    );
+}
+
+#[test]
+fn test_package_from_papi_flexible_disk() {
+    let papi: papi_api::Package = serde_json::from_value(serde_json::json!({
+        "uuid": "0f4d8f3a-2b3c-4f5e-9a1b-2c3d4e5f6a7b",
+        "name": "bhyve-flex-4g",
+        "version": "2.1.0",
+        "active": true,
+        "max_lwps": 4000,
+        "max_physical_memory": 4096,
+        "max_swap": 8192,
+        "quota": 102400,
+        "zfs_io_priority": 100,
+        "vcpus": 2,
+        "default": true,
+        "brand": "bhyve",
+        "flexible_disk": true,
+        "disks": [{"size": 10240}, {"size": "remaining"}, {}]
+    }))
+    .unwrap();
+
+    let pkg = Package::from(papi);
+
+    assert_eq!(pkg.vcpus, 2);
+    assert!(pkg.default);
+    assert_eq!(pkg.flexible_disk, Some(true));
+    assert_eq!(
+        serde_json::to_value(&pkg.disks).unwrap(),
+        serde_json::json!([{"size": 10240}, {"size": "remaining"}, {}])
+    );
+}
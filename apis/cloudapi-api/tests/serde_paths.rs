@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Deserialization tests for path and query parameter types
+//!
+//! Parameters that only ever name a resource by UUID are typed as `Uuid`, so
+//! a malformed ID is rejected while the request is being parsed rather than
+//! in each handler.  Parameters documented as "UUID or login" / "name or
+//! UUID" stay `String`.
+
+use cloudapi_api::types::{
+    DiskPath, FirewallRulePath, ImagePath, ListMachinesQuery, MachinePath, NetworkIpPath,
+    PackagePath, UserPath, VolumePath,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+const MACHINE_UUID: &str = "a1234567-1234-1234-1234-123456789012";
+
+#[test]
+fn test_machine_path_valid_uuid() {
+    let path: MachinePath =
+        serde_json::from_value(json!({"account": "my", "machine": MACHINE_UUID})).unwrap();
+    assert_eq!(path.account, "my");
+    assert_eq!(path.machine, Uuid::parse_str(MACHINE_UUID).unwrap());
+}
+
+#[test]
+fn test_uuid_paths_reject_invalid_uuid() {
+    assert!(
+        serde_json::from_value::<MachinePath>(json!({"account": "my", "machine": "not-a-uuid"}))
+            .is_err()
+    );
+    assert!(
+        serde_json::from_value::<ImagePath>(json!({"account": "my", "dataset": "base-64-lts"}))
+            .is_err()
+    );
+    assert!(serde_json::from_value::<VolumePath>(json!({"account": "my", "id": "1234"})).is_err());
+    assert!(
+        serde_json::from_value::<FirewallRulePath>(json!({"account": "my", "id": ""})).is_err()
+    );
+    assert!(
+        serde_json::from_value::<DiskPath>(
+            json!({"account": "my", "machine": MACHINE_UUID, "disk": "disk0"})
+        )
+        .is_err()
+    );
+    assert!(
+        serde_json::from_value::<NetworkIpPath>(
+            json!({"account": "my", "network": "external", "ip_address": "10.0.0.1"})
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_list_machines_query_image_filter() {
+    let query: ListMachinesQuery = serde_json::from_value(json!({"image": MACHINE_UUID})).unwrap();
+    assert_eq!(query.image, Some(Uuid::parse_str(MACHINE_UUID).unwrap()));
+
+    assert!(serde_json::from_value::<ListMachinesQuery>(json!({"image": "base-64"})).is_err());
+}
+
+/// Paths that accept a login or name as well as a UUID take any string.
+#[test]
+fn test_name_or_uuid_paths_accept_names() {
+    let path: UserPath = serde_json::from_value(json!({"account": "my", "uuid": "alice"})).unwrap();
+    assert_eq!(path.uuid, "alice");
+
+    let path: PackagePath =
+        serde_json::from_value(json!({"account": "my", "package": "g4-highcpu-1G"})).unwrap();
+    assert_eq!(path.package, "g4-highcpu-1G");
+}
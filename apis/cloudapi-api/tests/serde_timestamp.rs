@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Serialization tests for `Timestamp`
+//!
+//! CloudAPI sends timestamps as RFC3339 strings in any offset, and as Unix
+//! timestamps in milliseconds, either as numbers or as strings of digits.
+//! All of them must deserialize to the same instant, which always
+//! serializes back as RFC3339 in UTC.
+
+use chrono::TimeZone;
+use cloudapi_api::types::{Account, Timestamp};
+
+fn parse(value: serde_json::Value) -> Result<Timestamp, serde_json::Error> {
+    serde_json::from_value(value)
+}
+
+#[test]
+fn test_timestamp_formats() {
+    let expected = chrono::Utc
+        .with_ymd_and_hms(2026, 1, 26, 21, 32, 31)
+        .unwrap()
+        + chrono::Duration::milliseconds(702);
+
+    for value in [
+        serde_json::json!("2026-01-26T21:32:31.702Z"),
+        serde_json::json!("2026-01-26T16:32:31.702-05:00"),
+        serde_json::json!(1_769_463_151_702_i64),
+        serde_json::json!("1769463151702"),
+    ] {
+        let timestamp = parse(value.clone()).unwrap_or_else(|e| panic!("{}: {}", value, e));
+        assert_eq!(timestamp, expected, "{}", value);
+    }
+}
+
+#[test]
+fn test_invalid_timestamps() {
+    for value in [
+        serde_json::json!("yesterday"),
+        serde_json::json!("2026-01-26"),
+        serde_json::json!(""),
+        serde_json::json!(true),
+        serde_json::json!(u64::MAX),
+    ] {
+        assert!(parse(value.clone()).is_err(), "{}", value);
+    }
+
+    let err = "yesterday".parse::<Timestamp>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid timestamp 'yesterday': expected RFC3339 or milliseconds since the epoch"
+    );
+}
+
+#[test]
+fn test_timestamp_serialization() {
+    let with_millis = parse(serde_json::json!(1_769_463_151_702_i64)).unwrap();
+    assert_eq!(
+        serde_json::to_value(with_millis).unwrap(),
+        serde_json::json!("2026-01-26T21:32:31.702Z")
+    );
+    assert_eq!(with_millis.to_string(), "2026-01-26T21:32:31.702Z");
+
+    let whole = parse(serde_json::json!("2024-01-01T00:00:00.000+02:00")).unwrap();
+    assert_eq!(
+        serde_json::to_value(whole).unwrap(),
+        serde_json::json!("2023-12-31T22:00:00Z")
+    );
+}
+
+/// Fields typed `Timestamp` accept epoch milliseconds too
+#[test]
+fn test_epoch_millis_field() {
+    let account: Account = serde_json::from_value(serde_json::json!({
+        "id": "9b3f4c5e-6d7a-4e8b-9c0d-1e2f3a4b5c6d",
+        "login": "user",
+        "email": "user@example.com",
+        "created": 1_704_067_200_000_i64,
+        "updated": "2024-06-15T12:00:00.000Z"
+    }))
+    .unwrap();
+    assert_eq!(
+        account.created,
+        chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    );
+    assert!(account.created < account.updated);
+}
+
+/// The schema is that of `chrono::DateTime<Utc>`, so the OpenAPI spec
+/// describes the field as it did before `Timestamp` was a type of its own
+#[test]
+fn test_timestamp_schema() {
+    let schema = schemars::schema_for!(Timestamp);
+    let chrono_schema = schemars::schema_for!(chrono::DateTime<chrono::Utc>);
+    assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        serde_json::to_value(chrono_schema).unwrap()
+    );
+}
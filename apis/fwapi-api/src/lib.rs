@@ -0,0 +1,157 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Triton Firewall API (FWAPI) trait definition.
+//!
+//! FWAPI stores the firewall rules of a Triton datacenter and pushes them to
+//! compute nodes. CloudAPI only exposes an account's own rules; FWAPI also
+//! manages global rules and resolves which rules apply to a given VM.
+
+pub mod types;
+
+pub use types::*;
+
+use dropshot::{
+    HttpError, HttpResponseAccepted, HttpResponseDeleted, HttpResponseOk, Path, Query,
+    RequestContext, TypedBody,
+};
+
+/// Triton Firewall API
+///
+/// Manages firewall rules and resolves the rules that apply to VMs.
+#[dropshot::api_description]
+pub trait FwapiApi {
+    type Context: Send + Sync + 'static;
+
+    // ========================================================================
+    // Health
+    // ========================================================================
+
+    /// Ping the FWAPI service
+    ///
+    /// Returns the health of FWAPI and its backend services.
+    #[endpoint {
+        method = GET,
+        path = "/ping",
+        tags = ["health"],
+    }]
+    async fn ping(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<PingResponse>, HttpError>;
+
+    // ========================================================================
+    // Rules
+    // ========================================================================
+
+    /// List rules
+    ///
+    /// Returns the rules matching the query filters. Filtering by
+    /// `owner_uuid` also returns global rules.
+    #[endpoint {
+        method = GET,
+        path = "/rules",
+        tags = ["rules"],
+    }]
+    async fn list_rules(
+        rqctx: RequestContext<Self::Context>,
+        query: Query<ListRulesQuery>,
+    ) -> Result<HttpResponseOk<Vec<FirewallRule>>, HttpError>;
+
+    /// Create a rule
+    ///
+    /// Validates and stores the rule, then pushes it to the compute nodes of
+    /// the VMs it affects. The push happens asynchronously.
+    #[endpoint {
+        method = POST,
+        path = "/rules",
+        tags = ["rules"],
+    }]
+    async fn create_rule(
+        rqctx: RequestContext<Self::Context>,
+        body: TypedBody<CreateRuleRequest>,
+    ) -> Result<HttpResponseAccepted<FirewallRule>, HttpError>;
+
+    /// Get a rule by UUID
+    #[endpoint {
+        method = GET,
+        path = "/rules/{uuid}",
+        tags = ["rules"],
+    }]
+    async fn get_rule(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<RulePath>,
+        query: Query<OwnerQuery>,
+    ) -> Result<HttpResponseOk<FirewallRule>, HttpError>;
+
+    /// Update a rule
+    ///
+    /// Updates the given fields and pushes the new rule to the compute nodes
+    /// of the VMs affected before or after the update. The push happens
+    /// asynchronously.
+    #[endpoint {
+        method = PUT,
+        path = "/rules/{uuid}",
+        tags = ["rules"],
+    }]
+    async fn update_rule(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<RulePath>,
+        body: TypedBody<UpdateRuleRequest>,
+    ) -> Result<HttpResponseAccepted<FirewallRule>, HttpError>;
+
+    /// Delete a rule
+    ///
+    /// Removes the rule from FWAPI and from the compute nodes of the VMs it
+    /// affects.
+    #[endpoint {
+        method = DELETE,
+        path = "/rules/{uuid}",
+        tags = ["rules"],
+    }]
+    async fn delete_rule(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<RulePath>,
+        query: Query<OwnerQuery>,
+    ) -> Result<HttpResponseDeleted, HttpError>;
+
+    // ========================================================================
+    // Per-VM rules
+    // ========================================================================
+
+    /// List the rules applying to a VM
+    ///
+    /// Returns every rule that affects the VM, including global rules and
+    /// rules that match it by tag or "all vms".
+    #[endpoint {
+        method = GET,
+        path = "/firewalls/vms/{uuid}",
+        tags = ["firewalls"],
+    }]
+    async fn list_vm_rules(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<VmPath>,
+        query: Query<OwnerQuery>,
+    ) -> Result<HttpResponseOk<Vec<FirewallRule>>, HttpError>;
+
+    // ========================================================================
+    // Resolve
+    // ========================================================================
+
+    /// Resolve rules
+    ///
+    /// Returns the rules of an account that apply to the given VMs and tags,
+    /// along with the VMs and tags those rules refer to. Compute nodes use
+    /// this to find the remote VMs they need to know about.
+    #[endpoint {
+        method = POST,
+        path = "/resolve",
+        tags = ["resolve"],
+    }]
+    async fn resolve(
+        rqctx: RequestContext<Self::Context>,
+        body: TypedBody<ResolveRequest>,
+    ) -> Result<HttpResponseOk<ResolveResponse>, HttpError>;
+}
@@ -0,0 +1,195 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Types for the Triton Firewall API (FWAPI).
+//!
+//! Fields use snake_case in the JSON wire format, except `allVMs` in the
+//! resolve response.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// UUID type alias.
+pub type Uuid = uuid::Uuid;
+
+// ============================================================================
+// Health
+// ============================================================================
+
+/// Response from the /ping health check endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PingResponse {
+    /// Whether FWAPI and all of its backend services are healthy.
+    pub healthy: bool,
+    /// Overall status ("OK" when healthy).
+    pub status: String,
+    /// Status of each backend service (e.g. "moray": "online").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub services: Option<HashMap<String, String>>,
+}
+
+// ============================================================================
+// Rules
+// ============================================================================
+
+/// A firewall rule.
+///
+/// `rule` is the rule text in the fwrule language, for example
+/// `FROM any TO tag "www" ALLOW tcp PORT 80`. FWAPI parses and validates it;
+/// clients pass it through as a string.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FirewallRule {
+    /// Rule UUID.
+    pub uuid: Uuid,
+    /// Rule text in the fwrule language.
+    pub rule: String,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// Whether TCP connections matching the rule are logged.
+    #[serde(default)]
+    pub log: bool,
+    /// Whether the rule applies to VMs of all accounts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global: Option<bool>,
+    /// Account that owns the rule (absent for global rules).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<Uuid>,
+    /// Human-readable description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Rule version, changed on every update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Service or user that created the rule (e.g. "fwadm").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+}
+
+/// Path parameter for rule operations.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RulePath {
+    /// Rule UUID.
+    pub uuid: Uuid,
+}
+
+/// Query parameters for listing rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ListRulesQuery {
+    /// Only return rules owned by this account (global rules are included).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<Uuid>,
+    /// Only return global (true) or non-global (false) rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global: Option<bool>,
+    /// Maximum number of rules to return.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    /// Skip this many rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+/// Query parameter restricting an operation to one account's rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct OwnerQuery {
+    /// Account the rule or VM must belong to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<Uuid>,
+}
+
+/// Request body for creating a rule.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateRuleRequest {
+    /// Rule text in the fwrule language.
+    pub rule: String,
+    /// Whether the rule is enabled (defaults to false).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Whether to log TCP connections matching the rule (defaults to false).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<bool>,
+    /// Create a global rule applying to VMs of all accounts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global: Option<bool>,
+    /// Account that owns the rule (required unless `global` is set).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<Uuid>,
+    /// Human-readable description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// UUID for the new rule (generated if absent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<Uuid>,
+}
+
+/// Request body for updating a rule. Absent fields are left unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateRuleRequest {
+    /// Rule text in the fwrule language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// Whether the rule is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Whether to log TCP connections matching the rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<bool>,
+    /// Account that owns the rule; must match the existing owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<Uuid>,
+    /// Human-readable description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+// ============================================================================
+// Per-VM rules
+// ============================================================================
+
+/// Path parameter for per-VM operations.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct VmPath {
+    /// VM UUID.
+    pub uuid: Uuid,
+}
+
+// ============================================================================
+// Resolve
+// ============================================================================
+
+/// Request body for resolving the rules that apply to a set of VMs and tags.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolveRequest {
+    /// Account whose rules to resolve.
+    pub owner_uuid: Uuid,
+    /// VMs to resolve rules for.
+    #[serde(default)]
+    pub vms: Vec<Uuid>,
+    /// Tags to resolve rules for, mapped to a value or `true` for any value.
+    #[serde(default)]
+    pub tags: HashMap<String, serde_json::Value>,
+}
+
+/// The rules that apply to a set of VMs and tags, and what they refer to.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolveResponse {
+    /// Whether any rule refers to "all vms", so every VM of the account is
+    /// affected.
+    #[serde(rename = "allVMs")]
+    pub all_vms: bool,
+    /// Account the rules were resolved for.
+    pub owner_uuid: Uuid,
+    /// Rules that apply.
+    pub rules: Vec<FirewallRule>,
+    /// Tags the rules refer to, mapped to the values they match (or `true`
+    /// for any value).
+    #[serde(default)]
+    pub tags: HashMap<String, serde_json::Value>,
+    /// VMs the rules refer to.
+    #[serde(default)]
+    pub vms: Vec<Uuid>,
+}
@@ -18,13 +18,24 @@
 //! 4. Serve as a real-world example of API trait definitions in this monorepo
 //!
 //! Reference: https://developer.atlassian.com/cloud/jira/platform/rest/v3/
-
-use dropshot::{HttpError, HttpResponseOk, Path, Query, RequestContext};
+//!
+//! # Bulk Fetch
+//!
+//! The bulk issue fetch endpoint (`POST /rest/api/3/issue/bulkfetch`) lives
+//! in a separate [`JiraBulkApi`] trait. Dropshot does not allow a literal
+//! segment (`bulkfetch`) at the same path depth as a variable segment
+//! (`{issue_id_or_key}`), so it cannot be registered alongside
+//! [`JiraApi::get_issue`]. It has an OpenAPI spec of its own, and
+//! jira-client generates a second client for it.
+
+use dropshot::{HttpError, HttpResponseOk, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 
+pub mod sanitize;
+
 // ============================================================================
 // Newtypes
 // ============================================================================
@@ -162,6 +173,61 @@ pub struct Issue {
     /// Rendered (HTML) versions of fields when expand=renderedFields is used
     #[serde(default, rename = "renderedFields")]
     pub rendered_fields: Option<HashMap<String, serde_json::Value>>,
+
+    /// Change history when expand=changelog is used
+    #[serde(default)]
+    pub changelog: Option<Changelog>,
+}
+
+/// Issue change history
+///
+/// JIRA includes at most the 100 most recent history entries when the
+/// changelog is expanded on a single issue.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Changelog {
+    /// History entries, oldest first
+    #[serde(default)]
+    pub histories: Vec<ChangelogHistory>,
+
+    /// Total number of history entries on the issue
+    #[serde(default)]
+    pub total: Option<u32>,
+}
+
+/// A single change to an issue, which may touch several fields
+///
+/// JIRA also reports the author of each change; it is deliberately not
+/// modeled here.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ChangelogHistory {
+    /// History entry ID
+    pub id: String,
+
+    /// When the change was made
+    pub created: String,
+
+    /// Individual field changes
+    #[serde(default)]
+    pub items: Vec<ChangelogItem>,
+}
+
+/// A change to a single field
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ChangelogItem {
+    /// Field name (e.g., "status")
+    pub field: String,
+
+    /// Field type ("jira" for system fields, "custom" for custom fields)
+    #[serde(default)]
+    pub fieldtype: Option<String>,
+
+    /// Display value before the change
+    #[serde(default, rename = "fromString")]
+    pub from_string: Option<String>,
+
+    /// Display value after the change
+    #[serde(default, rename = "toString")]
+    pub to_string: Option<String>,
 }
 
 /// Path parameter for get_issue endpoint
@@ -174,10 +240,55 @@ pub struct IssueIdOrKey {
 /// Query parameters for get_issue endpoint
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct IssueQuery {
-    /// Comma-separated list of expansions (e.g., "renderedFields")
+    /// Comma-separated list of expansions (e.g., "renderedFields,changelog")
     pub expand: Option<String>,
 }
 
+/// Maximum number of issues that can be requested in one bulk fetch
+pub const BULK_FETCH_MAX_ISSUES: usize = 100;
+
+/// Request body for the bulk issue fetch endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkFetchRequest {
+    /// Issue IDs or keys to fetch (at most 100)
+    #[serde(rename = "issueIdsOrKeys")]
+    pub issue_ids_or_keys: Vec<String>,
+
+    /// Fields to include for each issue (default: all navigable fields)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+
+    /// Expansions to apply to each issue (e.g., "changelog")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expand: Option<Vec<String>>,
+}
+
+/// Response from the bulk issue fetch endpoint
+///
+/// Issues that do not exist or cannot be viewed do not fail the request;
+/// they are reported in `issue_errors` instead.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkFetchResponse {
+    /// The issues found, in no particular order
+    #[serde(default)]
+    pub issues: Vec<Issue>,
+
+    /// The requested issues that could not be fetched
+    #[serde(default, rename = "issueErrors")]
+    pub issue_errors: Vec<BulkFetchIssueError>,
+}
+
+/// An issue that could not be returned by a bulk fetch
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BulkFetchIssueError {
+    /// Issue ID or key, as requested
+    pub id: String,
+
+    /// Why the issue could not be fetched
+    #[serde(rename = "errorMessage")]
+    pub error_message: String,
+}
+
 /// Remote link information
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RemoteLink {
@@ -265,6 +376,32 @@ pub trait JiraApi {
     ) -> Result<HttpResponseOk<Vec<RemoteLink>>, HttpError>;
 }
 
+/// JIRA REST API v3 bulk issue fetch
+///
+/// Kept apart from [`JiraApi`] because its path conflicts with
+/// `/rest/api/3/issue/{issue_id_or_key}` in Dropshot (see the crate docs).
+#[dropshot::api_description]
+pub trait JiraBulkApi {
+    /// Context type for request handlers
+    type Context: Send + Sync + 'static;
+
+    /// Get several issues at once
+    ///
+    /// Retrieves up to 100 issues by ID or key in a single request. Issues
+    /// that do not exist or cannot be viewed are listed in issueErrors.
+    ///
+    /// **JIRA API Reference**: POST /rest/api/3/issue/bulkfetch
+    #[endpoint {
+        method = POST,
+        path = "/rest/api/3/issue/bulkfetch",
+        tags = ["issues"],
+    }]
+    async fn bulk_fetch_issues(
+        rqctx: RequestContext<Self::Context>,
+        body: TypedBody<BulkFetchRequest>,
+    ) -> Result<HttpResponseOk<BulkFetchResponse>, HttpError>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,4 +476,36 @@ mod tests {
         assert!(msg.contains("invalid"));
         assert!(msg.contains("PROJECT-123"));
     }
+
+    #[test]
+    fn bulk_fetch_request_serialization() {
+        let request = BulkFetchRequest {
+            issue_ids_or_keys: vec!["PROJECT-1".to_string(), "10002".to_string()],
+            fields: None,
+            expand: Some(vec!["changelog".to_string()]),
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "issueIdsOrKeys": ["PROJECT-1", "10002"],
+                "expand": ["changelog"]
+            })
+        );
+    }
+
+    #[test]
+    fn bulk_fetch_response_deserialization() {
+        let response: BulkFetchResponse = serde_json::from_value(serde_json::json!({
+            "issues": [{"key": "PROJECT-1", "id": "10001", "fields": {}}],
+            "issueErrors": [{"id": "PROJECT-2", "errorMessage": "Issue does not exist"}]
+        }))
+        .unwrap();
+        assert_eq!(response.issues[0].key.as_str(), "PROJECT-1");
+        assert_eq!(response.issue_errors[0].id, "PROJECT-2");
+
+        // Both lists are left out when empty
+        let empty: BulkFetchResponse = serde_json::from_str("{}").unwrap();
+        assert!(empty.issues.is_empty());
+        assert!(empty.issue_errors.is_empty());
+    }
 }
@@ -0,0 +1,215 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Scrubbing of user details from issue fields
+//!
+//! JIRA embeds a full user object wherever an issue refers to a person
+//! (`assignee`, `reporter`, `creator`, comment `author` and `updateAuthor`,
+//! user-picker custom fields, ...). Those objects carry the user's email
+//! address, account ID and avatar URLs. ADF `mention` nodes in descriptions
+//! and comments carry the mentioned user's account ID as well.
+//!
+//! None of that may be shown on a public page, so [`scrub_issue`] walks every
+//! field recursively, whatever its name or nesting, and:
+//!
+//! - reduces each user object to the subfields in [`USER_SAFE_FIELDS`];
+//! - reduces the attributes of each `mention` node to its display text;
+//! - removes the keys in [`SENSITIVE_KEYS`] from every other object.
+//!
+//! This is a whitelist for users and a blacklist for everything else: a field
+//! JIRA adds to user objects later is dropped, while unrelated objects keep
+//! their shape.
+
+use crate::Issue;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Subfields of a user object that are safe to expose
+pub const USER_SAFE_FIELDS: &[&str] = &["displayName"];
+
+/// Keys that identify an object as a JIRA user
+const USER_MARKER_KEYS: &[&str] = &["accountId", "accountType", "emailAddress"];
+
+/// Keys removed from any object, user or not
+pub const SENSITIVE_KEYS: &[&str] = &["accountId", "emailAddress", "avatarUrls"];
+
+/// Attributes of an ADF `mention` node that are safe to expose
+const MENTION_SAFE_ATTRS: &[&str] = &["text"];
+
+/// Scrub user details from an issue's fields and rendered fields
+pub fn scrub_issue(issue: &mut Issue) {
+    scrub_fields(&mut issue.fields);
+    if let Some(rendered) = issue.rendered_fields.as_mut() {
+        scrub_fields(rendered);
+    }
+}
+
+/// Scrub user details from a map of issue fields
+pub fn scrub_fields(fields: &mut HashMap<String, Value>) {
+    fields.values_mut().for_each(scrub_value);
+}
+
+/// Scrub user details from a JSON value, recursively
+pub fn scrub_value(value: &mut Value) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(scrub_value),
+        Value::Object(map) if is_user(map) => {
+            map.retain(|key, _| USER_SAFE_FIELDS.contains(&key.as_str()));
+        }
+        Value::Object(map) => {
+            map.retain(|key, _| !SENSITIVE_KEYS.contains(&key.as_str()));
+            if is_mention(map)
+                && let Some(Value::Object(attrs)) = map.get_mut("attrs")
+            {
+                attrs.retain(|key, _| MENTION_SAFE_ATTRS.contains(&key.as_str()));
+            }
+            map.values_mut().for_each(scrub_value);
+        }
+        _ => {}
+    }
+}
+
+fn is_user(map: &Map<String, Value>) -> bool {
+    USER_MARKER_KEYS.iter().any(|key| map.contains_key(*key))
+}
+
+fn is_mention(map: &Map<String, Value>) -> bool {
+    map.get("type").and_then(Value::as_str) == Some("mention")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user(name: &str) -> Value {
+        json!({
+            "self": "https://jira.example.com/rest/api/3/user?accountId=5b10a2844c20165700ede21g",
+            "accountId": "5b10a2844c20165700ede21g",
+            "emailAddress": format!("{}@example.com", name.to_lowercase()),
+            "avatarUrls": {"48x48": "https://avatar.example.com/48.png"},
+            "displayName": name,
+            "active": true,
+            "timeZone": "UTC",
+            "accountType": "atlassian"
+        })
+    }
+
+    #[test]
+    fn user_fields_reduced_to_display_name() {
+        let mut fields = HashMap::from([
+            ("assignee".to_string(), user("Jane")),
+            ("reporter".to_string(), user("John")),
+            ("customfield_10100".to_string(), json!([user("Ann")])),
+            ("summary".to_string(), json!("Something broke")),
+        ]);
+
+        scrub_fields(&mut fields);
+
+        assert_eq!(fields["assignee"], json!({"displayName": "Jane"}));
+        assert_eq!(fields["reporter"], json!({"displayName": "John"}));
+        assert_eq!(fields["customfield_10100"], json!([{"displayName": "Ann"}]));
+        assert_eq!(fields["summary"], json!("Something broke"));
+    }
+
+    #[test]
+    fn sensitive_keys_removed_from_other_objects() {
+        let mut value = json!({
+            "project": {
+                "key": "OS",
+                "name": "SmartOS",
+                "avatarUrls": {"48x48": "https://avatar.example.com/p.png"}
+            },
+            "watches": {"watchCount": 2, "accountId": "5b10a2844c20165700ede21g"}
+        });
+
+        scrub_value(&mut value);
+
+        assert_eq!(
+            value,
+            json!({
+                "project": {"key": "OS", "name": "SmartOS"},
+                "watches": {"watchCount": 2}
+            })
+        );
+    }
+
+    #[test]
+    fn nested_adf_mentions_scrubbed() {
+        let mut fields = HashMap::from([(
+            "comment".to_string(),
+            json!({
+                "comments": [{
+                    "id": "10001",
+                    "author": user("Jane"),
+                    "updateAuthor": user("Jane"),
+                    "body": {
+                        "type": "doc",
+                        "version": 1,
+                        "content": [{
+                            "type": "bulletList",
+                            "content": [{
+                                "type": "listItem",
+                                "content": [{
+                                    "type": "paragraph",
+                                    "content": [
+                                        {"type": "text", "text": "Ask "},
+                                        {"type": "mention", "attrs": {
+                                            "id": "5b10a2844c20165700ede21g",
+                                            "text": "@John",
+                                            "accessLevel": "CONTAINER",
+                                            "userType": "DEFAULT"
+                                        }}
+                                    ]
+                                }]
+                            }]
+                        }]
+                    }
+                }]
+            }),
+        )]);
+
+        scrub_fields(&mut fields);
+
+        let comment = &fields["comment"]["comments"][0];
+        assert_eq!(comment["id"], json!("10001"));
+        assert_eq!(comment["author"], json!({"displayName": "Jane"}));
+        assert_eq!(comment["updateAuthor"], json!({"displayName": "Jane"}));
+
+        let paragraph = &comment["body"]["content"][0]["content"][0]["content"][0];
+        assert_eq!(
+            paragraph["content"],
+            json!([
+                {"type": "text", "text": "Ask "},
+                {"type": "mention", "attrs": {"text": "@John"}}
+            ])
+        );
+
+        let scrubbed = serde_json::to_string(&fields).unwrap();
+        assert!(!scrubbed.contains("5b10a2844c20165700ede21g"));
+        assert!(!scrubbed.contains("@example.com"));
+        assert!(!scrubbed.contains("avatar.example.com"));
+    }
+
+    #[test]
+    fn scrub_issue_covers_rendered_fields() {
+        let mut issue: Issue = serde_json::from_value(json!({
+            "key": "OS-1",
+            "id": "1",
+            "fields": {"assignee": user("Jane")},
+            "renderedFields": {"creator": user("John")}
+        }))
+        .unwrap();
+
+        scrub_issue(&mut issue);
+
+        assert_eq!(issue.fields["assignee"], json!({"displayName": "Jane"}));
+        assert_eq!(
+            issue.rendered_fields.unwrap()["creator"],
+            json!({"displayName": "John"})
+        );
+    }
+}
@@ -5,6 +5,7 @@
 // Copyright 2025 Edgecast Cloud LLC.
 
 use anyhow::Result;
+use bugview_api::adf::{self, AdfNode, AdfWriter, TextStyle};
 use bugview_client::Client;
 use chrono::DateTime;
 use clap::{Parser, Subcommand};
@@ -32,6 +33,15 @@ enum Commands {
         /// Sort field (key, created, or updated)
         #[arg(long, value_enum)]
         sort: Option<bugview_client::types::IssueSort>,
+        /// Only open or resolved issues
+        #[arg(long, value_enum)]
+        status: Option<bugview_client::types::IssueStatusFilter>,
+        /// Only issues updated on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        updated_since: Option<String>,
+        /// Only issues from this project (e.g., OS)
+        #[arg(long)]
+        project: Option<String>,
     },
     /// Get issue details (terminal-friendly view or raw JSON)
     Get {
@@ -56,235 +66,202 @@ enum Commands {
 
 /// Extract text from ADF (Atlassian Document Format) content for terminal display
 fn extract_adf_text(nodes: &serde_json::Value) -> String {
-    let mut output = String::new();
-    render_adf_to_text(nodes, &mut output, 0);
-    output
+    let mut writer = TextWriter::default();
+    adf::render(&adf::parse_content(nodes), &mut writer);
+    writer.output
 }
 
-fn render_adf_to_text(nodes: &serde_json::Value, output: &mut String, indent_level: usize) {
-    let Some(nodes_array) = nodes.as_array() else {
-        return;
-    };
+/// Width of the box drawn around panels, excluding the corners
+const PANEL_WIDTH: usize = 68;
+
+/// [`AdfWriter`] that produces Markdown-flavored plain text for the terminal
+#[derive(Default)]
+struct TextWriter {
+    output: String,
+    /// Next item number for each enclosing list (`None` for bullet lists)
+    lists: Vec<Option<u64>>,
+    /// Output offsets where enclosing panels and quotes began, and the prefix
+    /// to apply to each of their lines once complete
+    prefixed: Vec<(usize, &'static str)>,
+}
 
-    for node in nodes_array {
-        let Some(node_obj) = node.as_object() else {
-            continue;
-        };
-        let node_type = node_obj.get("type").and_then(|t| t.as_str()).unwrap_or("");
+impl TextWriter {
+    fn start_prefixed(&mut self, prefix: &'static str) {
+        self.prefixed.push((self.output.len(), prefix));
+    }
 
-        match node_type {
-            "paragraph" => {
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_text(content, output, indent_level);
-                }
-                output.push('\n');
-            }
+    fn end_prefixed(&mut self) {
+        let Some((start, prefix)) = self.prefixed.pop() else {
+            return;
+        };
+        let content = self.output[start..].to_string();
+        self.output.truncate(start);
+        for line in content.lines() {
+            self.output.push_str(prefix);
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+    }
+}
 
-            "text" => {
-                if let Some(text) = node_obj.get("text").and_then(|t| t.as_str()) {
-                    let marks = node_obj
-                        .get("marks")
-                        .and_then(|m| m.as_array())
-                        .map(|m| m.as_slice())
-                        .unwrap_or(&[]);
-
-                    let mut has_strong = false;
-                    let mut has_em = false;
-                    let mut has_code = false;
-                    let mut has_strike = false;
-                    let mut link_href: Option<&str> = None;
-
-                    for mark in marks {
-                        if let Some(mark_type) = mark.get("type").and_then(|t| t.as_str()) {
-                            match mark_type {
-                                "strong" => has_strong = true,
-                                "em" => has_em = true,
-                                "code" => has_code = true,
-                                "strike" => has_strike = true,
-                                "link" => {
-                                    link_href = mark
-                                        .get("attrs")
-                                        .and_then(|a| a.get("href"))
-                                        .and_then(|h| h.as_str());
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
+impl AdfWriter for TextWriter {
+    fn write_text(&mut self, text: &str, style: TextStyle<'_>) {
+        if style.link.is_some() {
+            self.output.push('[');
+        }
+        if style.strong {
+            self.output.push_str("**");
+        }
+        if style.em {
+            self.output.push('*');
+        }
+        if style.code {
+            self.output.push('`');
+        }
+        if style.strike {
+            self.output.push_str("~~");
+        }
 
-                    if link_href.is_some() {
-                        output.push('[');
-                    }
-                    if has_strong {
-                        output.push_str("**");
-                    }
-                    if has_em {
-                        output.push('*');
-                    }
-                    if has_code {
-                        output.push('`');
-                    }
-                    if has_strike {
-                        output.push_str("~~");
-                    }
+        self.output.push_str(text);
 
-                    output.push_str(text);
+        if style.strike {
+            self.output.push_str("~~");
+        }
+        if style.code {
+            self.output.push('`');
+        }
+        if style.em {
+            self.output.push('*');
+        }
+        if style.strong {
+            self.output.push_str("**");
+        }
+        if let Some(href) = style.link {
+            self.output.push_str("](");
+            self.output.push_str(href);
+            self.output.push(')');
+        }
+    }
 
-                    if has_strike {
-                        output.push_str("~~");
-                    }
-                    if has_code {
-                        output.push('`');
-                    }
-                    if has_em {
-                        output.push('*');
-                    }
-                    if has_strong {
-                        output.push_str("**");
-                    }
-                    if let Some(href) = link_href {
-                        output.push_str("](");
-                        output.push_str(href);
-                        output.push(')');
+    fn start_node(&mut self, node: &AdfNode) {
+        match node {
+            AdfNode::HardBreak => self.output.push('\n'),
+            AdfNode::Rule => {
+                self.output.push_str(&"─".repeat(PANEL_WIDTH));
+                self.output.push('\n');
+            }
+            AdfNode::Heading { level, .. } => {
+                self.output.push_str(&"#".repeat(usize::from(*level)));
+                self.output.push(' ');
+            }
+            AdfNode::BulletList(_) => self.lists.push(None),
+            AdfNode::OrderedList { start, .. } => self.lists.push(Some(*start)),
+            AdfNode::ListItem(_) => {
+                let depth = self.lists.len().saturating_sub(1);
+                self.output.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        self.output.push_str(&format!("{}. ", number));
+                        *number += 1;
                     }
+                    _ => self.output.push_str("• "),
                 }
             }
-
-            "inlineCard" => {
-                if let Some(attrs) = node_obj.get("attrs")
-                    && let Some(url) = attrs.get("url").and_then(|u| u.as_str())
-                {
-                    let display = url.rsplit('/').next().unwrap_or(url);
-                    output.push_str(&format!("[{}]", display));
-                }
+            AdfNode::CodeBlock { .. } => self.output.push_str("\n```\n"),
+            AdfNode::Blockquote(_) => self.start_prefixed("> "),
+            AdfNode::Panel { .. } => {
+                self.output.push_str("\n┌");
+                self.output.push_str(&"─".repeat(PANEL_WIDTH));
+                self.output.push_str("┐\n");
+                self.start_prefixed("│ ");
             }
-
-            "codeBlock" => {
-                output.push_str("\n```\n");
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_text(content, output, indent_level);
-                }
-                output.push_str("```\n");
+            AdfNode::Expand { title, .. } => {
+                self.output.push_str("▸ ");
+                self.output.push_str(title.as_deref().unwrap_or("Details"));
+                self.output.push('\n');
             }
-
-            "hardBreak" => {
-                output.push('\n');
+            AdfNode::TableCell { .. } => self.output.push_str("| "),
+            AdfNode::Media(media) => {
+                self.output
+                    .push_str(&format!("[attachment: {}]", media.label()));
             }
-
-            "mention" => {
-                if let Some(attrs) = node_obj.get("attrs") {
-                    let display = attrs
-                        .get("text")
-                        .and_then(|t| t.as_str())
-                        .map(|t| t.strip_prefix('@').unwrap_or(t))
-                        .or_else(|| attrs.get("id").and_then(|i| i.as_str()));
-                    if let Some(d) = display {
-                        output.push_str(&format!("@{}", d));
-                    }
-                }
+            AdfNode::Mention { name, .. } => self.output.push_str(&format!("@{}", name)),
+            AdfNode::Emoji { short_name, text } => {
+                self.output.push_str(text.as_deref().unwrap_or(short_name));
             }
-
-            "bulletList" => {
-                if let Some(content) = node_obj.get("content")
-                    && let Some(items) = content.as_array()
-                {
-                    for item in items {
-                        let prefix_indent = if indent_level > 0 {
-                            "  ".repeat(indent_level)
-                        } else {
-                            String::new()
-                        };
-                        output.push_str(&prefix_indent);
-                        output.push_str("• ");
-                        if let Some(item_content) = item.get("content") {
-                            render_adf_to_text(item_content, output, indent_level + 1);
-                        }
-                        // Trim trailing whitespace and ensure newline
-                        while output.ends_with(' ') || output.ends_with('\t') {
-                            output.pop();
-                        }
-                        if !output.ends_with('\n') {
-                            output.push('\n');
-                        }
-                    }
-                }
+            AdfNode::InlineCard { url } => {
+                self.output.push_str(&format!("[{}]", adf::card_label(url)));
             }
-
-            "orderedList" => {
-                if let Some(content) = node_obj.get("content")
-                    && let Some(items) = content.as_array()
-                {
-                    for (i, item) in items.iter().enumerate() {
-                        let prefix_indent = if indent_level > 0 {
-                            "  ".repeat(indent_level)
-                        } else {
-                            String::new()
-                        };
-                        output.push_str(&prefix_indent);
-                        output.push_str(&format!("{}. ", i + 1));
-                        if let Some(item_content) = item.get("content") {
-                            render_adf_to_text(item_content, output, indent_level + 1);
-                        }
-                        while output.ends_with(' ') || output.ends_with('\t') {
-                            output.pop();
-                        }
-                        if !output.ends_with('\n') {
-                            output.push('\n');
-                        }
-                    }
-                }
+            AdfNode::BlockCard { url } => {
+                self.output
+                    .push_str(&format!("[{}]\n", adf::card_label(url)));
             }
-
-            "listItem" => {
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_text(content, output, indent_level);
-                }
+            AdfNode::Status { text } => {
+                self.output.push_str(&format!("[{}]", text.to_uppercase()));
             }
+            AdfNode::Date { timestamp } => self.output.push_str(&format_adf_date(timestamp)),
+            _ => {}
+        }
+    }
 
-            "heading" => {
-                let level = node_obj
-                    .get("attrs")
-                    .and_then(|a| a.get("level"))
-                    .and_then(|l| l.as_u64())
-                    .unwrap_or(1)
-                    .min(6) as usize;
-                output.push_str(&"#".repeat(level));
-                output.push(' ');
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_text(content, output, indent_level);
-                }
-                output.push('\n');
+    fn end_node(&mut self, node: &AdfNode) {
+        match node {
+            AdfNode::Paragraph(_) | AdfNode::Heading { .. } => self.output.push('\n'),
+            AdfNode::BulletList(_) | AdfNode::OrderedList { .. } => {
+                self.lists.pop();
             }
-
-            "panel" => {
-                output.push_str("\n┌");
-                output.push_str(&"─".repeat(68));
-                output.push_str("┐\n");
-                let panel_start = output.len();
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_text(content, output, indent_level);
+            AdfNode::ListItem(_) => {
+                // Trim trailing whitespace and ensure newline
+                while self.output.ends_with(' ') || self.output.ends_with('\t') {
+                    self.output.pop();
                 }
-                // Process panel content to add "│ " prefix to each line
-                let panel_content = output[panel_start..].to_string();
-                output.truncate(panel_start);
-                for line in panel_content.lines() {
-                    output.push_str("│ ");
-                    output.push_str(line);
-                    output.push('\n');
+                if !self.output.ends_with('\n') {
+                    self.output.push('\n');
                 }
-                output.push('└');
-                output.push_str(&"─".repeat(68));
-                output.push_str("┘\n");
             }
-
-            _ => {
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_text(content, output, indent_level);
+            AdfNode::CodeBlock { .. } => self.output.push_str("```\n"),
+            AdfNode::Blockquote(_) => self.end_prefixed(),
+            AdfNode::Panel { .. } => {
+                self.end_prefixed();
+                self.output.push('└');
+                self.output.push_str(&"─".repeat(PANEL_WIDTH));
+                self.output.push_str("┘\n");
+            }
+            AdfNode::TableCell { .. } => {
+                // Cell content is usually a paragraph; keep the row on one line
+                while self.output.ends_with('\n') {
+                    self.output.pop();
                 }
+                self.output.push(' ');
             }
+            AdfNode::TableRow(_) => self.output.push_str("|\n"),
+            _ => {}
         }
     }
 }
+
+/// Unwrap a full issue response requested in format version 1
+fn v1_details(
+    response: bugview_client::types::IssueFullResponse,
+) -> Result<bugview_client::types::IssueDetails> {
+    match response {
+        bugview_client::types::IssueFullResponse::IssueDetails(details) => Ok(details),
+        bugview_client::types::IssueFullResponse::IssueDetailsV2(_) => Err(anyhow::anyhow!(
+            "Expected a version 1 issue response, got version 2"
+        )),
+    }
+}
+
+/// Format an ADF `date` node (milliseconds since the epoch) as `YYYY-MM-DD`
+fn format_adf_date(timestamp: &str) -> String {
+    timestamp
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
 /// Format a timestamp into a human-readable format
 ///
 /// Converts ISO 8601 timestamps like "2023-10-04T10:27:22.826-0400" into
@@ -312,6 +289,9 @@ async fn main() -> Result<()> {
         Commands::List {
             next_page_token,
             sort,
+            status,
+            updated_since,
+            project,
         } => {
             let mut request = client.get_issue_index_json();
 
@@ -323,6 +303,18 @@ async fn main() -> Result<()> {
                 request = request.sort(sort_field);
             }
 
+            if let Some(status) = status {
+                request = request.status(status);
+            }
+
+            if let Some(date) = updated_since {
+                request = request.updated_since(date);
+            }
+
+            if let Some(project) = project {
+                request = request.project(project);
+            }
+
             let response = request.send().await.map_err(|e| {
                 if e.to_string().contains("404") || e.to_string().contains("Not Found") {
                     anyhow::anyhow!("No issues found. The service may be unavailable or there are no public issues.")
@@ -364,6 +356,7 @@ async fn main() -> Result<()> {
             let response = client
                 .get_issue_full_json()
                 .key(key.clone())
+                .v(1)
                 .send()
                 .await
                 .map_err(|e| {
@@ -373,7 +366,7 @@ async fn main() -> Result<()> {
                         anyhow::anyhow!("Failed to fetch issue '{}': {}", key, e)
                     }
                 })?;
-            let issue = response.into_inner();
+            let issue = v1_details(response.into_inner())?;
 
             // Build JIRA-compatible fixture format (drop remotelinks)
             let fixture = serde_json::json!({
@@ -387,7 +380,7 @@ async fn main() -> Result<()> {
         }
 
         Commands::Get { key, raw } => {
-            let response = client.get_issue_full_json().key(key.clone()).send().await.map_err(|e| {
+            let response = client.get_issue_full_json().key(key.clone()).v(1).send().await.map_err(|e| {
                 if e.to_string().contains("404") || e.to_string().contains("Not Found") {
                     anyhow::anyhow!("Issue '{}' not found\n\nThe issue may not exist, may be private, or may have been deleted.\nUse 'bugview list' to see available public issues.", key)
                 } else if e.to_string().contains("connection") || e.to_string().contains("dns") {
@@ -396,7 +389,7 @@ async fn main() -> Result<()> {
                     anyhow::anyhow!("Failed to fetch issue '{}': {}", key, e)
                 }
             })?;
-            let issue = response.into_inner();
+            let issue = v1_details(response.into_inner())?;
 
             if raw {
                 // Raw JSON output
@@ -601,6 +594,59 @@ mod tests {
         assert_eq!(out, "[click here](https://example.com)");
     }
 
+    #[test]
+    fn nested_lists_are_indented_and_numbered() {
+        let adf = serde_json::json!([{
+            "type": "orderedList",
+            "attrs": {"order": 3},
+            "content": [
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "first"}]},
+                    {"type": "bulletList", "content": [
+                        {"type": "listItem", "content": [
+                            {"type": "paragraph", "content": [{"type": "text", "text": "nested"}]}
+                        ]}
+                    ]}
+                ]},
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "second"}]}
+                ]}
+            ]
+        }]);
+        let out = extract_adf_text(&adf);
+        assert_eq!(out, "3. first\n  • nested\n4. second\n");
+    }
+
+    #[test]
+    fn panel_lines_are_boxed() {
+        let adf = serde_json::json!([{
+            "type": "panel",
+            "attrs": {"panelType": "note"},
+            "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "careful"}]}
+            ]
+        }]);
+        let out = extract_adf_text(&adf);
+        assert!(out.contains("│ careful\n"), "output was: {}", out);
+        assert!(out.ends_with("┘\n"), "output was: {}", out);
+    }
+
+    #[test]
+    fn table_rows_render_on_one_line() {
+        let adf = serde_json::json!([{
+            "type": "table",
+            "content": [{"type": "tableRow", "content": [
+                {"type": "tableHeader", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "a"}]}
+                ]},
+                {"type": "tableCell", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "b"}]}
+                ]}
+            ]}]
+        }]);
+        assert_eq!(extract_adf_text(&adf), "| a | b |\n");
+    }
+
     #[test]
     fn link_mark_with_other_formatting() {
         let adf = serde_json::json!([
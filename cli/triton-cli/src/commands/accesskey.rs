@@ -110,7 +110,7 @@ async fn list_access_keys(
         json::print_json_stream(&keys)?;
     } else {
         let mut tbl = TableBuilder::new(&["ACCESSKEYID", "STATUS", "CREDENTIALTYPE", "UPDATED"])
-            .with_long_headers(&["DESCRIPTION", "CREATED", "EXPIRATION"]);
+            .with_long_headers(&["DESCRIPTION", "CREATED", "EXPIRATION", "LASTUSED"]);
         for key in &keys {
             tbl.add_row(vec![
                 key.accesskeyid.clone(),
@@ -120,6 +120,7 @@ async fn list_access_keys(
                 key.description.clone().unwrap_or_default(),
                 key.created.to_rfc3339(),
                 key.expiration.map(|e| e.to_rfc3339()).unwrap_or_default(),
+                key.lastused.map(|t| t.to_rfc3339()).unwrap_or_default(),
             ]);
         }
         tbl.print(&args.table)?;
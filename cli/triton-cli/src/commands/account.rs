@@ -11,7 +11,7 @@ use clap::{Args, Subcommand};
 use std::path::PathBuf;
 use triton_gateway_client::TypedClient;
 
-use crate::output::json;
+use crate::output::{enum_to_display, json};
 
 #[derive(Subcommand, Clone)]
 pub enum AccountCommand {
@@ -157,21 +157,14 @@ async fn get_limits(client: &TypedClient, use_json: bool) -> Result<()> {
         println!("{:<10} {:>7}  {:>5}  CHECK", "BY", "LIMIT", "USED");
 
         for limit in &limits {
-            let by = limit.by.as_deref().unwrap_or("machines");
+            let by = enum_to_display(&limit.by());
             let used = limit
                 .used
                 .map(|u| u.to_string())
                 .unwrap_or_else(|| "-".to_string());
-            let check = match (
-                limit.check.as_deref(),
-                limit.brand.as_deref(),
-                limit.image.as_deref(),
-                limit.os.as_deref(),
-            ) {
-                (Some("brand"), Some(v), _, _) => format!("brand={v}"),
-                (Some("image"), _, Some(v), _) => format!("image={v}"),
-                (Some("os"), _, _, Some(v)) => format!("os={v}"),
-                _ => String::new(),
+            let check = match limit.filter() {
+                Some((check, value)) => format!("{}={value}", enum_to_display(&check)),
+                None => String::new(),
             };
             println!("{:<10} {:>7}  {:>5}  {}", by, limit.value, used, check);
         }
@@ -140,12 +140,8 @@ fn handle_message(text: &str, use_json: bool) -> Result<()> {
         // Parse and format nicely
         let msg: ChangefeedMessage = serde_json::from_str(text)?;
 
-        // Format timestamp (published is a string containing millisecond Unix timestamp)
         let timestamp = msg
-            .published
-            .parse::<i64>()
-            .ok()
-            .and_then(chrono::DateTime::from_timestamp_millis)
+            .published_at()
             .map(|dt| dt.to_rfc3339())
             .unwrap_or_else(|| msg.published.clone());
 
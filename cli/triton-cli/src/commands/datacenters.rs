@@ -8,7 +8,7 @@
 
 use anyhow::Result;
 use clap::Args;
-use triton_gateway_client::TypedClient;
+use triton_gateway_client::{Datacenter, TypedClient};
 
 use crate::define_columns;
 use crate::output::json;
@@ -35,18 +35,13 @@ pub async fn run(args: DatacenterListArgs, client: &TypedClient, use_json: bool)
     if use_json {
         json::print_json(&datacenters)?;
     } else {
-        // Sort by name for consistent output
-        let mut entries: Vec<(&str, &str)> = datacenters
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.as_str()))
-            .collect();
-        entries.sort_by_key(|(name, _)| *name);
-
-        type DcEntry<'a> = (&'a str, &'a str);
+        // entries() is sorted by name for consistent output
+        let entries = datacenters.entries();
+
         define_columns! {
-            DcColumn for DcEntry<'_> {
-                Name("NAME") => |dc| dc.0.to_string(),
-                Url("URL") => |dc| dc.1.to_string(),
+            DcColumn for Datacenter {
+                Name("NAME") => |dc| dc.name.clone(),
+                Url("URL") => |dc| dc.url.clone(),
             }
         }
 
@@ -806,20 +806,24 @@ async fn copy_image(
         .into_inner();
 
     // Validate the destination datacenter exists
-    let dest_url = datacenters.get(dest_dc).ok_or_else(|| {
+    let dest_url = datacenters.url(dest_dc).ok_or_else(|| {
         anyhow::anyhow!(
             "'{}' is not a valid datacenter name (available: {})",
             dest_dc,
-            datacenters.keys().cloned().collect::<Vec<_>>().join(", ")
+            datacenters
+                .entries()
+                .into_iter()
+                .map(|dc| dc.name)
+                .collect::<Vec<_>>()
+                .join(", ")
         )
     })?;
 
     // Determine our current DC name by reverse-looking up the base URL
     let my_url = client.baseurl().trim_end_matches('/');
     let source_dc = datacenters
-        .iter()
-        .find(|(_, url)| url.trim_end_matches('/') == my_url)
-        .map(|(name, _)| name.clone())
+        .name_for_url(my_url)
+        .map(str::to_string)
         .ok_or_else(|| {
             anyhow::anyhow!(
                 "Could not determine current datacenter name from URL {}",
@@ -56,15 +56,13 @@ pub async fn run(args: AuditArgs, client: &TypedClient, use_json: bool) -> Resul
                 },
                 // --- long-only columns below ---
                 Caller("CALLER") => |audit| {
-                    audit.caller.as_ref().map(|c| {
-                        if let Some(obj) = c.as_object()
-                            && let Some(login) = obj.get("login").and_then(|v| v.as_str())
-                        {
-                            return login.to_string();
-                        }
-                        c.to_string()
+                    audit.caller.as_ref().and_then(|c| {
+                        c.login.clone()
+                            .or_else(|| c.key_id.clone())
+                            .or_else(|| c.type_.as_ref().map(enum_to_display))
                     }).unwrap_or_else(|| "-".to_string())
                 },
+                Ip("IP") => |audit| audit.caller_ip().unwrap_or("-").to_string(),
             }
         }
 
@@ -213,6 +213,10 @@ async fn set_metadata(args: MetadataSetArgs, client: &TypedClient) -> Result<()>
         map
     };
 
+    // Check keys and values locally so bad metadata fails with a clear
+    // error instead of a rejected request
+    cloudapi_api::MachineMetadata::new(meta_map.clone())?;
+
     let request = AddMetadataRequest::from(meta_map.clone());
 
     // Capture current state before metadata operation so --wait uses the correct target
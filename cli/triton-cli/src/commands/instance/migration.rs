@@ -214,7 +214,7 @@ async fn begin_migration(
     let id_str = instance_id.to_string();
 
     let request = triton_gateway_client::types::MigrateRequest {
-        action: triton_gateway_client::types::MigrationAction::Begin,
+        action: Some(triton_gateway_client::types::MigrationAction::Begin),
         affinity: args
             .affinity
             .map(triton_gateway_client::AffinityRules::from),
@@ -264,7 +264,7 @@ async fn sync_migration(
     let instance_id = super::get::resolve_instance(&args.instance, client).await?;
 
     let request = triton_gateway_client::types::MigrateRequest {
-        action: triton_gateway_client::types::MigrationAction::Sync,
+        action: Some(triton_gateway_client::types::MigrationAction::Sync),
         affinity: None,
     };
 
@@ -307,7 +307,7 @@ async fn switch_migration(
     let instance_id = super::get::resolve_instance(&args.instance, client).await?;
 
     let request = triton_gateway_client::types::MigrateRequest {
-        action: triton_gateway_client::types::MigrationAction::Switch,
+        action: Some(triton_gateway_client::types::MigrationAction::Switch),
         affinity: None,
     };
 
@@ -459,7 +459,7 @@ async fn abort_migration(
     let id_str = instance_id.to_string();
 
     let request = triton_gateway_client::types::MigrateRequest {
-        action: triton_gateway_client::types::MigrationAction::Abort,
+        action: Some(triton_gateway_client::types::MigrationAction::Abort),
         affinity: None,
     };
 
@@ -437,6 +437,7 @@ mod tests {
             roles: None,
             act_as: None,
             accept_version: None,
+            signed_headers: Default::default(),
         };
         TypedClient::new(
             &format!("http://127.0.0.1:{port}"),
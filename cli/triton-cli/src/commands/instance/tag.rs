@@ -82,6 +82,10 @@ pub struct TagSetArgs {
     /// JSON output (compact, single line)
     #[arg(short = 'j', long = "json")]
     pub json: bool,
+
+    /// Send the tags without checking their names and triton.cns.* values
+    #[arg(long = "no-validate")]
+    pub no_validate: bool,
 }
 
 #[derive(Args, Clone)]
@@ -133,6 +137,10 @@ pub struct TagReplaceAllArgs {
     /// JSON output (compact, single line)
     #[arg(short = 'j', long = "json")]
     pub json: bool,
+
+    /// Send the tags without checking their names and triton.cns.* values
+    #[arg(long = "no-validate")]
+    pub no_validate: bool,
 }
 
 impl TagCommand {
@@ -319,6 +327,12 @@ async fn set_tags(args: TagSetArgs, client: &TypedClient) -> Result<()> {
         return Err(anyhow::anyhow!("No tags specified"));
     }
 
+    // Check names and CNS tag values locally: CloudAPI accepts a malformed
+    // triton.cns.* value, which then breaks DNS for the instance
+    if !args.no_validate {
+        cloudapi_api::MachineTags::new(tag_map.clone())?;
+    }
+
     let expected_tags: Map<String, Value> = tag_map.clone();
     let request = TagsRequest::from(tag_map);
 
@@ -480,6 +494,12 @@ async fn replace_all_tags(args: TagReplaceAllArgs, client: &TypedClient) -> Resu
         return Err(anyhow::anyhow!("no tags were provided"));
     }
 
+    // Check names and CNS tag values locally: CloudAPI accepts a malformed
+    // triton.cns.* value, which then breaks DNS for the instance
+    if !args.no_validate {
+        cloudapi_api::MachineTags::new(tag_map.clone())?;
+    }
+
     let expected_tags: Map<String, Value> = tag_map.clone();
     let request = TagsRequest::from(tag_map);
 
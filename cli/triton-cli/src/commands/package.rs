@@ -140,15 +140,36 @@ async fn list_packages(
     apply_positional_filters(&mut args)?;
 
     let account = client.effective_account();
-    let response = client
-        .inner()
-        .list_packages()
-        .account(account)
-        .send()
-        .await?;
-    let all_packages = response.into_inner();
+    let mut req = client.inner().list_packages().account(account);
+
+    // Exact-match filters are applied by CloudAPI. `name` is a substring
+    // match in node-triton, so it is only applied client-side below.
+    if let Some(memory) = args.memory {
+        req = req.memory(memory);
+    }
+    if let Some(disk) = args.disk {
+        req = req.disk(disk);
+    }
+    if let Some(swap) = args.swap {
+        req = req.swap(swap);
+    }
+    if let Some(lwps) = args.lwps {
+        req = req.lwps(lwps);
+    }
+    if let Some(version) = &args.version {
+        req = req.version(version);
+    }
+    if let Some(vcpus) = args.vcpus {
+        req = req.vcpus(vcpus);
+    }
+    if let Some(group) = &args.group {
+        req = req.group(group);
+    }
+
+    let all_packages = req.send().await?.into_inner();
 
-    // Client-side filtering
+    // Client-side filtering: applies the name filter, and re-checks the
+    // others in case the server ignores a query parameter.
     let packages: Vec<_> = all_packages
         .into_iter()
         .filter(|pkg| {
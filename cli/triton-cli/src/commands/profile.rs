@@ -555,7 +555,7 @@ async fn docker_setup(name: Option<String>, lifetime: u32, yes: bool) -> Result<
         .into_inner();
 
     let docker_url = services
-        .get("docker")
+        .endpoint("docker")
         .ok_or_else(|| anyhow::anyhow!("No Docker service available in this datacenter"))?;
 
     println!("Docker service found: {}", docker_url);
@@ -699,7 +699,7 @@ async fn cmon_certgen(name: Option<String>, lifetime: u32, yes: bool) -> Result<
         .into_inner();
 
     let cmon_url = services
-        .get("cmon")
+        .endpoint("cmon")
         .ok_or_else(|| anyhow::anyhow!("No CMON service available in this datacenter"))?;
 
     println!("CMON service found: {}", cmon_url);
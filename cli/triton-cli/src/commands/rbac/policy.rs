@@ -8,6 +8,7 @@
 
 use anyhow::Result;
 use clap::{Args, Subcommand};
+use cloudapi_api::PolicyRules;
 use serde::Deserialize;
 use triton_gateway_client::TypedClient;
 
@@ -253,6 +254,14 @@ async fn get_policy(args: PolicyGetArgs, client: &TypedClient, use_json: bool) -
     Ok(())
 }
 
+/// Check rules against the Aperture grammar, to catch syntax errors before
+/// CloudAPI does. The rules are sent as written, as node-triton sends them.
+fn checked_rules(rules: Vec<String>) -> Result<PolicyRules, cloudapi_api::InvalidPolicyRule> {
+    let rules = PolicyRules::from(rules);
+    rules.parse()?;
+    Ok(rules)
+}
+
 async fn create_policy(args: PolicyCreateArgs, client: &TypedClient, use_json: bool) -> Result<()> {
     let account = client.effective_account();
 
@@ -262,9 +271,11 @@ async fn create_policy(args: PolicyCreateArgs, client: &TypedClient, use_json: b
         ));
     }
 
+    let rules = checked_rules(args.rule)?;
+
     let request = triton_gateway_client::types::CreatePolicyRequest {
         name: args.name.clone(),
-        rules: args.rule.into(),
+        rules,
         description: args.description,
     };
 
@@ -294,7 +305,7 @@ async fn update_policy(args: PolicyUpdateArgs, client: &TypedClient, use_json: b
         rules: if args.rule.is_empty() {
             None
         } else {
-            Some(args.rule.into())
+            Some(checked_rules(args.rule)?)
         },
         description: args.description,
     };
@@ -456,7 +467,7 @@ async fn add_policy_from_file(
     let account = client.effective_account();
     let request = triton_gateway_client::types::CreatePolicyRequest {
         name: name.clone(),
-        rules: rules.into(),
+        rules: checked_rules(rules)?,
         description,
     };
 
@@ -561,10 +572,22 @@ async fn edit_policy_in_editor(policy_ref: &str, client: &TypedClient) -> Result
                     continue;
                 }
 
+                let rules = match checked_rules(edited.rules) {
+                    Ok(rules) => rules,
+                    Err(e) => {
+                        tracing::error!("{}", e);
+                        if !editor::prompt_retry()? {
+                            anyhow::bail!("Aborted");
+                        }
+                        current_yaml = result.content;
+                        continue;
+                    }
+                };
+
                 // Build update request
                 let request = triton_gateway_client::types::UpdatePolicyRequest {
                     name: Some(edited.name.clone()),
-                    rules: Some(edited.rules.into()),
+                    rules: Some(rules),
                     description: edited.description,
                 };
 
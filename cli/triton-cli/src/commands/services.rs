@@ -8,7 +8,7 @@
 
 use anyhow::Result;
 use clap::Args;
-use triton_gateway_client::TypedClient;
+use triton_gateway_client::{Service, TypedClient};
 
 use crate::define_columns;
 use crate::output::json;
@@ -35,18 +35,13 @@ pub async fn run(args: ServiceListArgs, client: &TypedClient, use_json: bool) ->
     if use_json {
         json::print_json(&services)?;
     } else {
-        // Sort by name for consistent output (matching node-triton)
-        let mut entries: Vec<(&str, &str)> = services
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.as_str()))
-            .collect();
-        entries.sort_by_key(|(name, _)| *name);
-
-        type SvcEntry<'a> = (&'a str, &'a str);
+        // entries() is sorted by name for consistent output (matching node-triton)
+        let entries = services.entries();
+
         define_columns! {
-            SvcColumn for SvcEntry<'_> {
-                Name("NAME") => |svc| svc.0.to_string(),
-                Endpoint("ENDPOINT") => |svc| svc.1.to_string(),
+            SvcColumn for Service {
+                Name("NAME") => |svc| svc.name.clone(),
+                Endpoint("ENDPOINT") => |svc| svc.endpoint.clone(),
             }
         }
 
@@ -496,6 +496,9 @@ impl Cli {
             Err(e) => {
                 // Non-fatal: let the request fail later with a clear error
                 tracing::debug!("Key probe failed: {}", e);
+                if matches!(e, triton_auth::AuthError::KeyNotFound(_)) {
+                    tracing::debug!("{}", triton_auth::diagnose(&final_key_id).await);
+                }
                 key_source
             }
         };
@@ -87,7 +87,8 @@ fn test_inst_tag_set_help() {
         .args(["inst", "tag", "set", "-h"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Usage:"));
+        .stdout(predicate::str::contains("Usage:"))
+        .stdout(predicate::str::contains("--no-validate"));
 }
 
 /// Test `triton inst tag delete -h` shows help
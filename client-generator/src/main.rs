@@ -91,7 +91,7 @@ fn configure_cloudapi(settings: &mut GenerationSettings) {
     settings
         .with_interface(progenitor::InterfaceStyle::Builder)
         .with_tag(progenitor::TagStyle::Merged)
-        .with_inner_type(syn::parse_quote!(triton_auth::AuthConfig))
+        .with_inner_type(syn::parse_quote!(crate::auth::ClientAuth))
         .with_pre_hook_async(syn::parse_quote!(crate::auth::add_auth_headers))
         .with_derive("schemars::JsonSchema")
         // Tags and MetadataObject are now named schemas (see vmapi-api's
@@ -123,10 +123,17 @@ fn configure_cloudapi(settings: &mut GenerationSettings) {
             std::iter::empty(),
         )
         .with_replacement("NetworkIds", "cloudapi_api::NetworkIds", std::iter::empty())
+        .with_replacement(
+            "Datacenters",
+            "cloudapi_api::Datacenters",
+            std::iter::empty(),
+        )
+        .with_replacement("Services", "cloudapi_api::Services", std::iter::empty())
         .with_patch("VmBrand", &value_enum_patch)
         .with_patch("Brand", &value_enum_patch)
         .with_patch("MachineState", &value_enum_patch)
         .with_patch("MachineType", &value_enum_patch)
+        .with_patch("MachineExportFormat", &value_enum_patch)
         .with_patch("ImageState", &value_enum_patch)
         .with_patch("ImageType", &value_enum_patch)
         .with_patch("DiskState", &value_enum_patch)
@@ -182,11 +189,18 @@ fn configure_triton_gateway(settings: &mut GenerationSettings) {
             std::iter::empty(),
         )
         .with_replacement("NetworkIds", "cloudapi_api::NetworkIds", std::iter::empty())
+        .with_replacement(
+            "Datacenters",
+            "cloudapi_api::Datacenters",
+            std::iter::empty(),
+        )
+        .with_replacement("Services", "cloudapi_api::Services", std::iter::empty())
         .with_patch("Brand", &value_enum_patch)
         .with_patch("Brand2", &value_enum_patch)
         .with_patch("VmBrand", &value_enum_patch)
         .with_patch("MachineState", &value_enum_patch)
         .with_patch("MachineType", &value_enum_patch)
+        .with_patch("MachineExportFormat", &value_enum_patch)
         .with_patch("ImageState", &value_enum_patch)
         .with_patch("ImageType", &value_enum_patch)
         .with_patch("DiskState", &value_enum_patch)
@@ -259,6 +273,13 @@ fn configure_jira(settings: &mut GenerationSettings) {
         .with_tag(progenitor::TagStyle::Merged);
 }
 
+fn configure_fwapi(settings: &mut GenerationSettings) {
+    settings
+        .with_interface(progenitor::InterfaceStyle::Builder)
+        .with_tag(progenitor::TagStyle::Merged)
+        .with_derive("schemars::JsonSchema");
+}
+
 fn configure_mahi(settings: &mut GenerationSettings) {
     let value_enum_patch = TypePatch::default().with_derive("clap::ValueEnum").clone();
 
@@ -328,6 +349,12 @@ static CLIENTS: &[ClientConfig] = &[
         output_path: "clients/internal/vmapi-client/src/generated.rs",
         configure: configure_vmapi,
     },
+    ClientConfig {
+        name: "fwapi-client",
+        spec_path: "openapi-specs/patched/fwapi-api.json",
+        output_path: "clients/internal/fwapi-client/src/generated.rs",
+        configure: configure_fwapi,
+    },
     ClientConfig {
         name: "mahi-client",
         spec_path: "openapi-specs/patched/mahi-api.json",
@@ -42,6 +42,33 @@ func (e AccessKeyStatus) Valid() bool {
 	}
 }
 
+// Defines values for AuditCallerType.
+const (
+	AuditCallerTypeBasic     AuditCallerType = "basic"
+	AuditCallerTypeOperator  AuditCallerType = "operator"
+	AuditCallerTypeSignature AuditCallerType = "signature"
+	AuditCallerTypeToken     AuditCallerType = "token"
+	AuditCallerTypeUnknown   AuditCallerType = "unknown"
+)
+
+// Valid indicates whether the value is a known member of the AuditCallerType enum.
+func (e AuditCallerType) Valid() bool {
+	switch e {
+	case AuditCallerTypeBasic:
+		return true
+	case AuditCallerTypeOperator:
+		return true
+	case AuditCallerTypeSignature:
+		return true
+	case AuditCallerTypeToken:
+		return true
+	case AuditCallerTypeUnknown:
+		return true
+	default:
+		return false
+	}
+}
+
 // Defines values for AuditSuccess.
 const (
 	AuditSuccessNo      AuditSuccess = "no"
@@ -1028,13 +1055,37 @@ type AddNicRequest struct {
 // Newtype wrapper rather than a type alias so the generated OpenAPI spec carries a single named `AffinityRules` schema shared between machine provisioning and migration.
 type AffinityRules = []string
 
+// AuditCaller Caller of an audited action
+//
+// CloudAPI only includes the fields that apply to the caller type: `keyId` for signature callers, `ip` for requests that came in over HTTP.
+type AuditCaller struct {
+	// IP Client IP address
+	IP *string `json:"ip,omitempty"`
+
+	// KeyID Signing key, as `/<login>/keys/<fingerprint>`
+	KeyID *string `json:"keyId,omitempty"`
+
+	// Login Login of the caller, when CloudAPI records it
+	Login *string `json:"login,omitempty"`
+
+	// Type Authentication type
+	Type *AuditCallerType `json:"type,omitempty"`
+}
+
+// AuditCallerType How the caller of an audited action authenticated
+//
+// `signature` for HTTP Signature (SSH key) requests, `basic` for HTTP Basic, `token` for trusted services such as the portal, and `operator` for changes made through the internal APIs.
+type AuditCallerType string
+
 // AuditEntry Audit entry for a machine
+//
+// Matches the records returned by Node.js CloudAPI's `MachineAudit`.
 type AuditEntry struct {
-	// Action Action performed
+	// Action Action performed (e.g. "start"); see [`AuditAction`] for known values
 	Action string `json:"action"`
 
 	// Caller Caller information
-	Caller interface{} `json:"caller,omitempty"`
+	Caller *AuditCaller `json:"caller,omitempty"`
 
 	// Success Success status
 	Success *AuditSuccess `json:"success,omitempty"`
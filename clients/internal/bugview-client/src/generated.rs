@@ -37,6 +37,170 @@ pub mod types {
         }
     }
 
+    #[doc = "Response for the cache purge endpoint"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Response for the cache purge endpoint\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"issues\","]
+    #[doc = "    \"searches\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"issues\": {"]
+    #[doc = "      \"description\": \"Cached issues dropped\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"searches\": {"]
+    #[doc = "      \"description\": \"Cached index searches dropped\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct CachePurgeResponse {
+        #[doc = "Cached issues dropped"]
+        pub issues: u64,
+        #[doc = "Cached index searches dropped"]
+        pub searches: u64,
+    }
+
+    impl CachePurgeResponse {
+        pub fn builder() -> builder::CachePurgeResponse {
+            Default::default()
+        }
+    }
+
+    #[doc = "Response for the configuration reload endpoint"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Response for the configuration reload endpoint\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"allowed_domains\","]
+    #[doc = "    \"allowed_labels\","]
+    #[doc = "    \"changed\","]
+    #[doc = "    \"default_label\","]
+    #[doc = "    \"projects\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"allowed_domains\": {"]
+    #[doc = "      \"description\": \"Domains that remote links may point to\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"allowed_labels\": {"]
+    #[doc = "      \"description\": \"Additional labels issues can be filtered by\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"changed\": {"]
+    #[doc = "      \"description\": \"Whether the reloaded configuration differs from the one it replaced\","]
+    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    },"]
+    #[doc = "    \"default_label\": {"]
+    #[doc = "      \"description\": \"Label that marks issues as public\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"projects\": {"]
+    #[doc = "      \"description\": \"Keys of the projects with public labels of their own\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct ConfigReloadResponse {
+        #[doc = "Domains that remote links may point to"]
+        pub allowed_domains: ::std::vec::Vec<::std::string::String>,
+        #[doc = "Additional labels issues can be filtered by"]
+        pub allowed_labels: ::std::vec::Vec<::std::string::String>,
+        #[doc = "Whether the reloaded configuration differs from the one it replaced"]
+        pub changed: bool,
+        #[doc = "Label that marks issues as public"]
+        pub default_label: ::std::string::String,
+        #[doc = "Keys of the projects with public labels of their own"]
+        pub projects: ::std::vec::Vec<::std::string::String>,
+    }
+
+    impl ConfigReloadResponse {
+        pub fn builder() -> builder::ConfigReloadResponse {
+            Default::default()
+        }
+    }
+
+    #[doc = "A timestamp in machine-readable and human-readable forms\n\nBoth forms use the service's configured display timezone, so the HTML pages and JSON consumers show the same thing."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"A timestamp in machine-readable and human-readable forms\\n\\nBoth forms use the service's configured display timezone, so the HTML pages and JSON consumers show the same thing.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"date\","]
+    #[doc = "    \"iso\","]
+    #[doc = "    \"relative\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"date\": {"]
+    #[doc = "      \"description\": \"Calendar date (e.g., \\\"2023-10-04\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"iso\": {"]
+    #[doc = "      \"description\": \"RFC 3339 timestamp (e.g., \\\"2023-10-04T14:27:22+00:00\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"relative\": {"]
+    #[doc = "      \"description\": \"Time relative to when the response was generated (e.g., \\\"3 days ago\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct DisplayTimestamp {
+        #[doc = "Calendar date (e.g., \"2023-10-04\")"]
+        pub date: ::std::string::String,
+        #[doc = "RFC 3339 timestamp (e.g., \"2023-10-04T14:27:22+00:00\")"]
+        pub iso: ::std::string::String,
+        #[doc = "Time relative to when the response was generated (e.g., \"3 days ago\")"]
+        pub relative: ::std::string::String,
+    }
+
+    impl DisplayTimestamp {
+        pub fn builder() -> builder::DisplayTimestamp {
+            Default::default()
+        }
+    }
+
     #[doc = "Error information from a response."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -79,6 +243,136 @@ pub mod types {
         }
     }
 
+    #[doc = "Issue cache counters since the service started"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Issue cache counters since the service started\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"entries\","]
+    #[doc = "    \"hits\","]
+    #[doc = "    \"misses\","]
+    #[doc = "    \"refresh_failures\","]
+    #[doc = "    \"stale\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"entries\": {"]
+    #[doc = "      \"description\": \"Issues currently cached\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"hits\": {"]
+    #[doc = "      \"description\": \"Requests served from a fresh cached copy\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"misses\": {"]
+    #[doc = "      \"description\": \"Requests that had to fetch the issue from JIRA\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"refresh_failures\": {"]
+    #[doc = "      \"description\": \"Background refreshes that failed to reach JIRA\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"stale\": {"]
+    #[doc = "      \"description\": \"Requests served from a cached copy past its TTL\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct IssueCacheStats {
+        #[doc = "Issues currently cached"]
+        pub entries: u64,
+        #[doc = "Requests served from a fresh cached copy"]
+        pub hits: u64,
+        #[doc = "Requests that had to fetch the issue from JIRA"]
+        pub misses: u64,
+        #[doc = "Background refreshes that failed to reach JIRA"]
+        pub refresh_failures: u64,
+        #[doc = "Requests served from a cached copy past its TTL"]
+        pub stale: u64,
+    }
+
+    impl IssueCacheStats {
+        pub fn builder() -> builder::IssueCacheStats {
+            Default::default()
+        }
+    }
+
+    #[doc = "A public comment on an issue"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"A public comment on an issue\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"author\","]
+    #[doc = "    \"body_html\","]
+    #[doc = "    \"created\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"author\": {"]
+    #[doc = "      \"description\": \"Display name of the author\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"body_html\": {"]
+    #[doc = "      \"description\": \"Comment body rendered as HTML\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"created\": {"]
+    #[doc = "      \"description\": \"When the comment was made\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"edited\": {"]
+    #[doc = "      \"description\": \"When the comment was last edited (None if it never was)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct IssueComment {
+        #[doc = "Display name of the author"]
+        pub author: ::std::string::String,
+        #[doc = "Comment body rendered as HTML"]
+        pub body_html: ::std::string::String,
+        #[doc = "When the comment was made"]
+        pub created: ::std::string::String,
+        #[doc = "When the comment was last edited (None if it never was)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub edited: ::std::option::Option<::std::string::String>,
+    }
+
+    impl IssueComment {
+        pub fn builder() -> builder::IssueComment {
+            Default::default()
+        }
+    }
+
     #[doc = "Full issue details (matches original Node.js bugview format)"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -97,6 +391,14 @@ pub mod types {
     #[doc = "    \"fields\": {"]
     #[doc = "      \"description\": \"Issue fields (sanitized for public consumption)\""]
     #[doc = "    },"]
+    #[doc = "    \"history\": {"]
+    #[doc = "      \"description\": \"Changes to status, resolution and public labels, oldest first\","]
+    #[doc = "      \"default\": [],"]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/IssueHistoryEntry\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
     #[doc = "    \"id\": {"]
     #[doc = "      \"description\": \"JIRA internal issue ID\","]
     #[doc = "      \"type\": \"string\""]
@@ -122,6 +424,9 @@ pub mod types {
     pub struct IssueDetails {
         #[doc = "Issue fields (sanitized for public consumption)"]
         pub fields: ::serde_json::Value,
+        #[doc = "Changes to status, resolution and public labels, oldest first"]
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub history: ::std::vec::Vec<IssueHistoryEntry>,
         #[doc = "JIRA internal issue ID"]
         pub id: ::std::string::String,
         #[doc = "Issue key (e.g., \"OS-1234\")"]
@@ -136,30 +441,83 @@ pub mod types {
         }
     }
 
-    #[doc = "Simplified issue information for list views"]
+    #[doc = "Full issue details, format version 2\n\nUnlike [`IssueDetails`], which passes JIRA's issue fields through as they are, this is a fixed set of typed fields that does not change shape when JIRA's fields do."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"Simplified issue information for list views\","]
+    #[doc = "  \"description\": \"Full issue details, format version 2\\n\\nUnlike [`IssueDetails`], which passes JIRA's issue fields through as they are, this is a fixed set of typed fields that does not change shape when JIRA's fields do.\","]
     #[doc = "  \"type\": \"object\","]
     #[doc = "  \"required\": ["]
+    #[doc = "    \"comments\","]
     #[doc = "    \"created\","]
+    #[doc = "    \"fix_versions\","]
+    #[doc = "    \"history\","]
+    #[doc = "    \"id\","]
     #[doc = "    \"key\","]
+    #[doc = "    \"labels\","]
+    #[doc = "    \"links\","]
     #[doc = "    \"status\","]
     #[doc = "    \"summary\","]
-    #[doc = "    \"updated\""]
+    #[doc = "    \"updated\","]
+    #[doc = "    \"version\""]
     #[doc = "  ],"]
     #[doc = "  \"properties\": {"]
+    #[doc = "    \"comments\": {"]
+    #[doc = "      \"description\": \"Public comments, oldest first\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/IssueComment\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
     #[doc = "    \"created\": {"]
     #[doc = "      \"description\": \"Creation timestamp\","]
     #[doc = "      \"type\": \"string\""]
     #[doc = "    },"]
+    #[doc = "    \"description_html\": {"]
+    #[doc = "      \"description\": \"Description rendered as HTML (None if the issue has no description)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"fix_versions\": {"]
+    #[doc = "      \"description\": \"Names of the versions the issue is fixed in\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"history\": {"]
+    #[doc = "      \"description\": \"Changes to status, resolution and public labels, oldest first\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/IssueHistoryEntry\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"id\": {"]
+    #[doc = "      \"description\": \"JIRA internal issue ID\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
     #[doc = "    \"key\": {"]
-    #[doc = "      \"description\": \"Issue key (e.g., \\\"PROJECT-123\\\")\","]
+    #[doc = "      \"description\": \"Issue key (e.g., \\\"OS-1234\\\")\","]
     #[doc = "      \"type\": \"string\""]
     #[doc = "    },"]
+    #[doc = "    \"labels\": {"]
+    #[doc = "      \"description\": \"Public labels\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"links\": {"]
+    #[doc = "      \"description\": \"Remote links (filtered by allowed domains)\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/RemoteLink\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
     #[doc = "    \"resolution\": {"]
     #[doc = "      \"description\": \"Resolution status (if resolved)\","]
     #[doc = "      \"type\": ["]
@@ -178,6 +536,12 @@ pub mod types {
     #[doc = "    \"updated\": {"]
     #[doc = "      \"description\": \"Last updated timestamp\","]
     #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"version\": {"]
+    #[doc = "      \"description\": \"Response format version (always 2)\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint32\","]
+    #[doc = "      \"minimum\": 0.0"]
     #[doc = "    }"]
     #[doc = "  }"]
     #[doc = "}"]
@@ -186,11 +550,26 @@ pub mod types {
     #[derive(
         :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
     )]
-    pub struct IssueListItem {
+    pub struct IssueDetailsV2 {
+        #[doc = "Public comments, oldest first"]
+        pub comments: ::std::vec::Vec<IssueComment>,
         #[doc = "Creation timestamp"]
         pub created: ::std::string::String,
-        #[doc = "Issue key (e.g., \"PROJECT-123\")"]
+        #[doc = "Description rendered as HTML (None if the issue has no description)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub description_html: ::std::option::Option<::std::string::String>,
+        #[doc = "Names of the versions the issue is fixed in"]
+        pub fix_versions: ::std::vec::Vec<::std::string::String>,
+        #[doc = "Changes to status, resolution and public labels, oldest first"]
+        pub history: ::std::vec::Vec<IssueHistoryEntry>,
+        #[doc = "JIRA internal issue ID"]
+        pub id: ::std::string::String,
+        #[doc = "Issue key (e.g., \"OS-1234\")"]
         pub key: ::std::string::String,
+        #[doc = "Public labels"]
+        pub labels: ::std::vec::Vec<::std::string::String>,
+        #[doc = "Remote links (filtered by allowed domains)"]
+        pub links: ::std::vec::Vec<RemoteLink>,
         #[doc = "Resolution status (if resolved)"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub resolution: ::std::option::Option<::std::string::String>,
@@ -200,40 +579,41 @@ pub mod types {
         pub summary: ::std::string::String,
         #[doc = "Last updated timestamp"]
         pub updated: ::std::string::String,
+        #[doc = "Response format version (always 2)"]
+        pub version: u32,
     }
 
-    impl IssueListItem {
-        pub fn builder() -> builder::IssueListItem {
+    impl IssueDetailsV2 {
+        pub fn builder() -> builder::IssueDetailsV2 {
             Default::default()
         }
     }
 
-    #[doc = "Response for issue list endpoints"]
+    #[doc = "A change to a single issue field"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"Response for issue list endpoints\","]
+    #[doc = "  \"description\": \"A change to a single issue field\","]
     #[doc = "  \"type\": \"object\","]
     #[doc = "  \"required\": ["]
-    #[doc = "    \"is_last\","]
-    #[doc = "    \"issues\""]
+    #[doc = "    \"field\""]
     #[doc = "  ],"]
     #[doc = "  \"properties\": {"]
-    #[doc = "    \"is_last\": {"]
-    #[doc = "      \"description\": \"True if this is the last page\","]
-    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    \"field\": {"]
+    #[doc = "      \"description\": \"Field that changed (\\\"status\\\", \\\"resolution\\\" or \\\"labels\\\")\","]
+    #[doc = "      \"type\": \"string\""]
     #[doc = "    },"]
-    #[doc = "    \"issues\": {"]
-    #[doc = "      \"description\": \"Issues in this page (page size determined by service configuration)\","]
-    #[doc = "      \"type\": \"array\","]
-    #[doc = "      \"items\": {"]
-    #[doc = "        \"$ref\": \"#/components/schemas/IssueListItem\""]
-    #[doc = "      }"]
+    #[doc = "    \"from\": {"]
+    #[doc = "      \"description\": \"Value before the change (None if the field was empty)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
     #[doc = "    },"]
-    #[doc = "    \"next_page_token\": {"]
-    #[doc = "      \"description\": \"Token for next page (None if this is the last page)\","]
+    #[doc = "    \"to\": {"]
+    #[doc = "      \"description\": \"Value after the change (None if the field was cleared)\","]
     #[doc = "      \"type\": ["]
     #[doc = "        \"string\","]
     #[doc = "        \"null\""]
@@ -246,57 +626,390 @@ pub mod types {
     #[derive(
         :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
     )]
-    pub struct IssueListResponse {
-        #[doc = "True if this is the last page"]
-        pub is_last: bool,
-        #[doc = "Issues in this page (page size determined by service configuration)"]
-        pub issues: ::std::vec::Vec<IssueListItem>,
-        #[doc = "Token for next page (None if this is the last page)"]
+    pub struct IssueFieldChange {
+        #[doc = "Field that changed (\"status\", \"resolution\" or \"labels\")"]
+        pub field: ::std::string::String,
+        #[doc = "Value before the change (None if the field was empty)"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        pub next_page_token: ::std::option::Option<::std::string::String>,
+        pub from: ::std::option::Option<::std::string::String>,
+        #[doc = "Value after the change (None if the field was cleared)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub to: ::std::option::Option<::std::string::String>,
     }
 
-    impl IssueListResponse {
-        pub fn builder() -> builder::IssueListResponse {
+    impl IssueFieldChange {
+        pub fn builder() -> builder::IssueFieldChange {
             Default::default()
         }
     }
 
-    #[doc = "Sort field for issue lists"]
+    #[doc = "Full issue details, in the format version requested with `?v=`\n\nVersion 1 ([`IssueDetails`]) is the default. Version 2 ([`IssueDetailsV2`]) is always tagged with `\"version\": 2`."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"Sort field for issue lists\","]
-    #[doc = "  \"oneOf\": ["]
-    #[doc = "    {"]
-    #[doc = "      \"description\": \"Sort by issue key (e.g., OS-1234)\","]
-    #[doc = "      \"type\": \"string\","]
-    #[doc = "      \"enum\": ["]
-    #[doc = "        \"key\""]
-    #[doc = "      ]"]
-    #[doc = "    },"]
+    #[doc = "  \"description\": \"Full issue details, in the format version requested with `?v=`\\n\\nVersion 1 ([`IssueDetails`]) is the default. Version 2 ([`IssueDetailsV2`]) is always tagged with `\\\"version\\\": 2`.\","]
+    #[doc = "  \"anyOf\": ["]
     #[doc = "    {"]
-    #[doc = "      \"description\": \"Sort by creation date\","]
-    #[doc = "      \"type\": \"string\","]
-    #[doc = "      \"enum\": ["]
-    #[doc = "        \"created\""]
-    #[doc = "      ]"]
+    #[doc = "      \"$ref\": \"#/components/schemas/IssueDetailsV2\""]
     #[doc = "    },"]
     #[doc = "    {"]
-    #[doc = "      \"description\": \"Sort by last update date (default)\","]
-    #[doc = "      \"type\": \"string\","]
-    #[doc = "      \"enum\": ["]
-    #[doc = "        \"updated\""]
-    #[doc = "      ]"]
+    #[doc = "      \"$ref\": \"#/components/schemas/IssueDetails\""]
     #[doc = "    }"]
     #[doc = "  ]"]
     #[doc = "}"]
     #[doc = r" ```"]
     #[doc = r" </details>"]
     #[derive(
-        :: serde :: Deserialize,
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    #[serde(untagged)]
+    pub enum IssueFullResponse {
+        IssueDetailsV2(IssueDetailsV2),
+        IssueDetails(IssueDetails),
+    }
+
+    impl ::std::convert::From<IssueDetailsV2> for IssueFullResponse {
+        fn from(value: IssueDetailsV2) -> Self {
+            Self::IssueDetailsV2(value)
+        }
+    }
+
+    impl ::std::convert::From<IssueDetails> for IssueFullResponse {
+        fn from(value: IssueDetails) -> Self {
+            Self::IssueDetails(value)
+        }
+    }
+
+    #[doc = "One entry in an issue's public change history"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"One entry in an issue's public change history\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"changes\","]
+    #[doc = "    \"created\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"changes\": {"]
+    #[doc = "      \"description\": \"Changes to public fields made at this time\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/IssueFieldChange\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"created\": {"]
+    #[doc = "      \"description\": \"When the change was made\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"created_display\": {"]
+    #[doc = "      \"description\": \"`created` formatted for display (None if it could not be parsed)\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/DisplayTimestamp\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct IssueHistoryEntry {
+        #[doc = "Changes to public fields made at this time"]
+        pub changes: ::std::vec::Vec<IssueFieldChange>,
+        #[doc = "When the change was made"]
+        pub created: ::std::string::String,
+        #[doc = "`created` formatted for display (None if it could not be parsed)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_display: ::std::option::Option<DisplayTimestamp>,
+    }
+
+    impl IssueHistoryEntry {
+        pub fn builder() -> builder::IssueHistoryEntry {
+            Default::default()
+        }
+    }
+
+    #[doc = "Simplified issue information for list views"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Simplified issue information for list views\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"created\","]
+    #[doc = "    \"key\","]
+    #[doc = "    \"status\","]
+    #[doc = "    \"summary\","]
+    #[doc = "    \"updated\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"created\": {"]
+    #[doc = "      \"description\": \"Creation timestamp\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"created_display\": {"]
+    #[doc = "      \"description\": \"`created` formatted for display (None if it could not be parsed)\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/DisplayTimestamp\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"issue_type\": {"]
+    #[doc = "      \"description\": \"Issue type (e.g., \\\"Bug\\\", \\\"Improvement\\\")\","]
+    #[doc = "      \"default\": null,"]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"key\": {"]
+    #[doc = "      \"description\": \"Issue key (e.g., \\\"PROJECT-123\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"labels\": {"]
+    #[doc = "      \"description\": \"Public labels on the issue\","]
+    #[doc = "      \"default\": [],"]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"priority\": {"]
+    #[doc = "      \"description\": \"Priority (e.g., \\\"2 - Critical\\\")\","]
+    #[doc = "      \"default\": null,"]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"resolution\": {"]
+    #[doc = "      \"description\": \"Resolution status (if resolved)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"status\": {"]
+    #[doc = "      \"description\": \"Issue status (e.g., \\\"Open\\\", \\\"Resolved\\\", \\\"Closed\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"summary\": {"]
+    #[doc = "      \"description\": \"Issue summary/title\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"updated\": {"]
+    #[doc = "      \"description\": \"Last updated timestamp\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"updated_display\": {"]
+    #[doc = "      \"description\": \"`updated` formatted for display (None if it could not be parsed)\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/DisplayTimestamp\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct IssueListItem {
+        #[doc = "Creation timestamp"]
+        pub created: ::std::string::String,
+        #[doc = "`created` formatted for display (None if it could not be parsed)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_display: ::std::option::Option<DisplayTimestamp>,
+        #[doc = "Issue type (e.g., \"Bug\", \"Improvement\")"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub issue_type: ::std::option::Option<::std::string::String>,
+        #[doc = "Issue key (e.g., \"PROJECT-123\")"]
+        pub key: ::std::string::String,
+        #[doc = "Public labels on the issue"]
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub labels: ::std::vec::Vec<::std::string::String>,
+        #[doc = "Priority (e.g., \"2 - Critical\")"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub priority: ::std::option::Option<::std::string::String>,
+        #[doc = "Resolution status (if resolved)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub resolution: ::std::option::Option<::std::string::String>,
+        #[doc = "Issue status (e.g., \"Open\", \"Resolved\", \"Closed\")"]
+        pub status: ::std::string::String,
+        #[doc = "Issue summary/title"]
+        pub summary: ::std::string::String,
+        #[doc = "Last updated timestamp"]
+        pub updated: ::std::string::String,
+        #[doc = "`updated` formatted for display (None if it could not be parsed)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub updated_display: ::std::option::Option<DisplayTimestamp>,
+    }
+
+    impl IssueListItem {
+        pub fn builder() -> builder::IssueListItem {
+            Default::default()
+        }
+    }
+
+    #[doc = "Response for issue list endpoints"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Response for issue list endpoints\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"is_last\","]
+    #[doc = "    \"issues\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"is_last\": {"]
+    #[doc = "      \"description\": \"True if this is the last page\","]
+    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    },"]
+    #[doc = "    \"issues\": {"]
+    #[doc = "      \"description\": \"Issues in this page (page size determined by service configuration)\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/IssueListItem\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"next_page\": {"]
+    #[doc = "      \"description\": \"Number of the next page (None if this is the last page)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"integer\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"format\": \"uint32\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"next_page_token\": {"]
+    #[doc = "      \"description\": \"Token for next page (None if this is the last page)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"page\": {"]
+    #[doc = "      \"description\": \"Number of this page (None when the page was requested by token)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"integer\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"format\": \"uint32\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"prev_page\": {"]
+    #[doc = "      \"description\": \"Number of the previous page (None on the first page)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"integer\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"format\": \"uint32\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct IssueListResponse {
+        #[doc = "True if this is the last page"]
+        pub is_last: bool,
+        #[doc = "Issues in this page (page size determined by service configuration)"]
+        pub issues: ::std::vec::Vec<IssueListItem>,
+        #[doc = "Number of the next page (None if this is the last page)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub next_page: ::std::option::Option<u32>,
+        #[doc = "Token for next page (None if this is the last page)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub next_page_token: ::std::option::Option<::std::string::String>,
+        #[doc = "Number of this page (None when the page was requested by token)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub page: ::std::option::Option<u32>,
+        #[doc = "Number of the previous page (None on the first page)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub prev_page: ::std::option::Option<u32>,
+    }
+
+    impl IssueListResponse {
+        pub fn builder() -> builder::IssueListResponse {
+            Default::default()
+        }
+    }
+
+    #[doc = "Sort field for issue lists"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Sort field for issue lists\","]
+    #[doc = "  \"oneOf\": ["]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Sort by issue key (e.g., OS-1234)\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"key\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Sort by creation date\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"created\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Sort by last update date (default)\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"updated\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  ]"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize,
         :: serde :: Serialize,
         Clone,
         Copy,
@@ -368,25 +1081,119 @@ pub mod types {
         }
     }
 
-    #[doc = "Legacy issue summary format (for backwards compatibility with Node.js bugview)\n\nThis matches the original `/bugview/json/{key}` response format."]
+    #[doc = "Issue status filter for the JSON issue index"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"Legacy issue summary format (for backwards compatibility with Node.js bugview)\\n\\nThis matches the original `/bugview/json/{key}` response format.\","]
-    #[doc = "  \"type\": \"object\","]
-    #[doc = "  \"required\": ["]
-    #[doc = "    \"id\","]
-    #[doc = "    \"summary\","]
-    #[doc = "    \"web_url\""]
-    #[doc = "  ],"]
-    #[doc = "  \"properties\": {"]
-    #[doc = "    \"id\": {"]
-    #[doc = "      \"description\": \"Issue key (named \\\"id\\\" for legacy compatibility)\","]
-    #[doc = "      \"type\": \"string\""]
+    #[doc = "  \"description\": \"Issue status filter for the JSON issue index\","]
+    #[doc = "  \"oneOf\": ["]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Issues without a resolution\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"open\""]
+    #[doc = "      ]"]
     #[doc = "    },"]
-    #[doc = "    \"summary\": {"]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Issues with a resolution (fixed, won't fix, duplicate, ...)\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"resolved\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  ]"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        clap :: ValueEnum,
+        schemars :: JsonSchema,
+    )]
+    pub enum IssueStatusFilter {
+        #[doc = "Issues without a resolution"]
+        #[serde(rename = "open")]
+        Open,
+        #[doc = "Issues with a resolution (fixed, won't fix, duplicate, ...)"]
+        #[serde(rename = "resolved")]
+        Resolved,
+    }
+
+    impl ::std::fmt::Display for IssueStatusFilter {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Open => f.write_str("open"),
+                Self::Resolved => f.write_str("resolved"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for IssueStatusFilter {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "open" => Ok(Self::Open),
+                "resolved" => Ok(Self::Resolved),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for IssueStatusFilter {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for IssueStatusFilter {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for IssueStatusFilter {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    #[doc = "Legacy issue summary format (for backwards compatibility with Node.js bugview)\n\nThis matches the original `/bugview/json/{key}` response format."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Legacy issue summary format (for backwards compatibility with Node.js bugview)\\n\\nThis matches the original `/bugview/json/{key}` response format.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"id\","]
+    #[doc = "    \"summary\","]
+    #[doc = "    \"web_url\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"id\": {"]
+    #[doc = "      \"description\": \"Issue key (named \\\"id\\\" for legacy compatibility)\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"summary\": {"]
     #[doc = "      \"description\": \"Issue summary/title\","]
     #[doc = "      \"type\": \"string\""]
     #[doc = "    },"]
@@ -416,6 +1223,92 @@ pub mod types {
         }
     }
 
+    #[doc = "View count for a single issue"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"View count for a single issue\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"key\","]
+    #[doc = "    \"views\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"key\": {"]
+    #[doc = "      \"description\": \"Issue key (e.g., \\\"OS-1234\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"views\": {"]
+    #[doc = "      \"description\": \"Number of views in the window\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct IssueViews {
+        #[doc = "Issue key (e.g., \"OS-1234\")"]
+        pub key: ::std::string::String,
+        #[doc = "Number of views in the window"]
+        pub views: u64,
+    }
+
+    impl IssueViews {
+        pub fn builder() -> builder::IssueViews {
+            Default::default()
+        }
+    }
+
+    #[doc = "View count for a label, summed over the views of issues carrying it"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"View count for a label, summed over the views of issues carrying it\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"label\","]
+    #[doc = "    \"views\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"label\": {"]
+    #[doc = "      \"description\": \"Label name\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"views\": {"]
+    #[doc = "      \"description\": \"Number of views in the window\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct LabelViews {
+        #[doc = "Label name"]
+        pub label: ::std::string::String,
+        #[doc = "Number of views in the window"]
+        pub views: u64,
+    }
+
+    impl LabelViews {
+        pub fn builder() -> builder::LabelViews {
+            Default::default()
+        }
+    }
+
     #[doc = "Remote link information"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -457,630 +1350,2571 @@ pub mod types {
         }
     }
 
+    #[doc = "Response for the access statistics endpoint"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Response for the access statistics endpoint\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"cache\","]
+    #[doc = "    \"generated\","]
+    #[doc = "    \"windows\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"cache\": {"]
+    #[doc = "      \"description\": \"Issue cache counters\","]
+    #[doc = "      \"allOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"$ref\": \"#/components/schemas/IssueCacheStats\""]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"generated\": {"]
+    #[doc = "      \"description\": \"Time the statistics were generated (RFC3339)\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"windows\": {"]
+    #[doc = "      \"description\": \"Statistics per time window, shortest window first\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/StatsWindow\""]
+    #[doc = "      }"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct StatsResponse {
+        #[doc = "Issue cache counters"]
+        pub cache: IssueCacheStats,
+        #[doc = "Time the statistics were generated (RFC3339)"]
+        pub generated: ::std::string::String,
+        #[doc = "Statistics per time window, shortest window first"]
+        pub windows: ::std::vec::Vec<StatsWindow>,
+    }
+
+    impl StatsResponse {
+        pub fn builder() -> builder::StatsResponse {
+            Default::default()
+        }
+    }
+
+    #[doc = "Access statistics for one time window"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Access statistics for one time window\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"issues\","]
+    #[doc = "    \"labels\","]
+    #[doc = "    \"since\","]
+    #[doc = "    \"total_views\","]
+    #[doc = "    \"window\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"issues\": {"]
+    #[doc = "      \"description\": \"Most viewed issues, most views first\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/IssueViews\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"labels\": {"]
+    #[doc = "      \"description\": \"Most viewed labels, most views first\","]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/LabelViews\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"since\": {"]
+    #[doc = "      \"description\": \"Start of the window (RFC3339, rounded down to the hour)\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"total_views\": {"]
+    #[doc = "      \"description\": \"Total issue views in the window\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
+    #[doc = "    \"window\": {"]
+    #[doc = "      \"description\": \"Window name (\\\"24h\\\", \\\"7d\\\" or \\\"30d\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct StatsWindow {
+        #[doc = "Most viewed issues, most views first"]
+        pub issues: ::std::vec::Vec<IssueViews>,
+        #[doc = "Most viewed labels, most views first"]
+        pub labels: ::std::vec::Vec<LabelViews>,
+        #[doc = "Start of the window (RFC3339, rounded down to the hour)"]
+        pub since: ::std::string::String,
+        #[doc = "Total issue views in the window"]
+        pub total_views: u64,
+        #[doc = "Window name (\"24h\", \"7d\" or \"30d\")"]
+        pub window: ::std::string::String,
+    }
+
+    impl StatsWindow {
+        pub fn builder() -> builder::StatsWindow {
+            Default::default()
+        }
+    }
+
     #[doc = r" Types for composing complex structures."]
     pub mod builder {
         #[derive(Clone, Debug)]
-        pub struct Error {
-            error_code: ::std::result::Result<
-                ::std::option::Option<::std::string::String>,
-                ::std::string::String,
-            >,
-            message: ::std::result::Result<::std::string::String, ::std::string::String>,
-            request_id: ::std::result::Result<::std::string::String, ::std::string::String>,
+        pub struct CachePurgeResponse {
+            issues: ::std::result::Result<u64, ::std::string::String>,
+            searches: ::std::result::Result<u64, ::std::string::String>,
         }
 
-        impl ::std::default::Default for Error {
+        impl ::std::default::Default for CachePurgeResponse {
             fn default() -> Self {
                 Self {
-                    error_code: Ok(Default::default()),
-                    message: Err("no value supplied for message".to_string()),
-                    request_id: Err("no value supplied for request_id".to_string()),
+                    issues: Err("no value supplied for issues".to_string()),
+                    searches: Err("no value supplied for searches".to_string()),
                 }
             }
         }
 
-        impl Error {
-            pub fn error_code<T>(mut self, value: T) -> Self
-            where
-                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
-                T::Error: ::std::fmt::Display,
-            {
-                self.error_code = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for error_code: {e}"));
-                self
-            }
-            pub fn message<T>(mut self, value: T) -> Self
+        impl CachePurgeResponse {
+            pub fn issues<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::string::String>,
+                T: ::std::convert::TryInto<u64>,
                 T::Error: ::std::fmt::Display,
             {
-                self.message = value
+                self.issues = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for message: {e}"));
+                    .map_err(|e| format!("error converting supplied value for issues: {e}"));
                 self
             }
-            pub fn request_id<T>(mut self, value: T) -> Self
+            pub fn searches<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::string::String>,
+                T: ::std::convert::TryInto<u64>,
                 T::Error: ::std::fmt::Display,
             {
-                self.request_id = value
+                self.searches = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for request_id: {e}"));
+                    .map_err(|e| format!("error converting supplied value for searches: {e}"));
                 self
             }
         }
 
-        impl ::std::convert::TryFrom<Error> for super::Error {
+        impl ::std::convert::TryFrom<CachePurgeResponse> for super::CachePurgeResponse {
             type Error = super::error::ConversionError;
             fn try_from(
-                value: Error,
+                value: CachePurgeResponse,
             ) -> ::std::result::Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    error_code: value.error_code?,
-                    message: value.message?,
-                    request_id: value.request_id?,
+                    issues: value.issues?,
+                    searches: value.searches?,
                 })
             }
         }
 
-        impl ::std::convert::From<super::Error> for Error {
-            fn from(value: super::Error) -> Self {
+        impl ::std::convert::From<super::CachePurgeResponse> for CachePurgeResponse {
+            fn from(value: super::CachePurgeResponse) -> Self {
                 Self {
-                    error_code: Ok(value.error_code),
-                    message: Ok(value.message),
-                    request_id: Ok(value.request_id),
+                    issues: Ok(value.issues),
+                    searches: Ok(value.searches),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct IssueDetails {
-            fields: ::std::result::Result<::serde_json::Value, ::std::string::String>,
-            id: ::std::result::Result<::std::string::String, ::std::string::String>,
-            key: ::std::result::Result<::std::string::String, ::std::string::String>,
-            remotelinks:
-                ::std::result::Result<::std::vec::Vec<super::RemoteLink>, ::std::string::String>,
-        }
-
-        impl ::std::default::Default for IssueDetails {
+        pub struct ConfigReloadResponse {
+            allowed_domains: ::std::result::Result<
+                ::std::vec::Vec<::std::string::String>,
+                ::std::string::String,
+            >,
+            allowed_labels: ::std::result::Result<
+                ::std::vec::Vec<::std::string::String>,
+                ::std::string::String,
+            >,
+            changed: ::std::result::Result<bool, ::std::string::String>,
+            default_label: ::std::result::Result<::std::string::String, ::std::string::String>,
+            projects: ::std::result::Result<
+                ::std::vec::Vec<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for ConfigReloadResponse {
             fn default() -> Self {
                 Self {
-                    fields: Err("no value supplied for fields".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    key: Err("no value supplied for key".to_string()),
-                    remotelinks: Err("no value supplied for remotelinks".to_string()),
+                    allowed_domains: Err("no value supplied for allowed_domains".to_string()),
+                    allowed_labels: Err("no value supplied for allowed_labels".to_string()),
+                    changed: Err("no value supplied for changed".to_string()),
+                    default_label: Err("no value supplied for default_label".to_string()),
+                    projects: Err("no value supplied for projects".to_string()),
                 }
             }
         }
 
-        impl IssueDetails {
-            pub fn fields<T>(mut self, value: T) -> Self
+        impl ConfigReloadResponse {
+            pub fn allowed_domains<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::serde_json::Value>,
+                T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
                 T::Error: ::std::fmt::Display,
             {
-                self.fields = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for fields: {e}"));
+                self.allowed_domains = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for allowed_domains: {e}")
+                });
                 self
             }
-            pub fn id<T>(mut self, value: T) -> Self
+            pub fn allowed_labels<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::string::String>,
+                T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
                 T::Error: ::std::fmt::Display,
             {
-                self.id = value
+                self.allowed_labels = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for allowed_labels: {e}")
+                });
+                self
+            }
+            pub fn changed<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.changed = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {e}"));
+                    .map_err(|e| format!("error converting supplied value for changed: {e}"));
                 self
             }
-            pub fn key<T>(mut self, value: T) -> Self
+            pub fn default_label<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.key = value
+                self.default_label = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for key: {e}"));
+                    .map_err(|e| format!("error converting supplied value for default_label: {e}"));
                 self
             }
-            pub fn remotelinks<T>(mut self, value: T) -> Self
+            pub fn projects<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::vec::Vec<super::RemoteLink>>,
+                T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
                 T::Error: ::std::fmt::Display,
             {
-                self.remotelinks = value
+                self.projects = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for remotelinks: {e}"));
+                    .map_err(|e| format!("error converting supplied value for projects: {e}"));
                 self
             }
         }
 
-        impl ::std::convert::TryFrom<IssueDetails> for super::IssueDetails {
+        impl ::std::convert::TryFrom<ConfigReloadResponse> for super::ConfigReloadResponse {
             type Error = super::error::ConversionError;
             fn try_from(
-                value: IssueDetails,
+                value: ConfigReloadResponse,
             ) -> ::std::result::Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    fields: value.fields?,
-                    id: value.id?,
-                    key: value.key?,
-                    remotelinks: value.remotelinks?,
+                    allowed_domains: value.allowed_domains?,
+                    allowed_labels: value.allowed_labels?,
+                    changed: value.changed?,
+                    default_label: value.default_label?,
+                    projects: value.projects?,
                 })
             }
         }
 
-        impl ::std::convert::From<super::IssueDetails> for IssueDetails {
-            fn from(value: super::IssueDetails) -> Self {
+        impl ::std::convert::From<super::ConfigReloadResponse> for ConfigReloadResponse {
+            fn from(value: super::ConfigReloadResponse) -> Self {
                 Self {
-                    fields: Ok(value.fields),
-                    id: Ok(value.id),
-                    key: Ok(value.key),
-                    remotelinks: Ok(value.remotelinks),
+                    allowed_domains: Ok(value.allowed_domains),
+                    allowed_labels: Ok(value.allowed_labels),
+                    changed: Ok(value.changed),
+                    default_label: Ok(value.default_label),
+                    projects: Ok(value.projects),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct IssueListItem {
-            created: ::std::result::Result<::std::string::String, ::std::string::String>,
-            key: ::std::result::Result<::std::string::String, ::std::string::String>,
-            resolution: ::std::result::Result<
-                ::std::option::Option<::std::string::String>,
-                ::std::string::String,
-            >,
-            status: ::std::result::Result<::std::string::String, ::std::string::String>,
-            summary: ::std::result::Result<::std::string::String, ::std::string::String>,
-            updated: ::std::result::Result<::std::string::String, ::std::string::String>,
+        pub struct DisplayTimestamp {
+            date: ::std::result::Result<::std::string::String, ::std::string::String>,
+            iso: ::std::result::Result<::std::string::String, ::std::string::String>,
+            relative: ::std::result::Result<::std::string::String, ::std::string::String>,
         }
 
-        impl ::std::default::Default for IssueListItem {
+        impl ::std::default::Default for DisplayTimestamp {
             fn default() -> Self {
                 Self {
-                    created: Err("no value supplied for created".to_string()),
-                    key: Err("no value supplied for key".to_string()),
-                    resolution: Ok(Default::default()),
-                    status: Err("no value supplied for status".to_string()),
-                    summary: Err("no value supplied for summary".to_string()),
-                    updated: Err("no value supplied for updated".to_string()),
+                    date: Err("no value supplied for date".to_string()),
+                    iso: Err("no value supplied for iso".to_string()),
+                    relative: Err("no value supplied for relative".to_string()),
                 }
             }
         }
 
-        impl IssueListItem {
-            pub fn created<T>(mut self, value: T) -> Self
-            where
-                T: ::std::convert::TryInto<::std::string::String>,
-                T::Error: ::std::fmt::Display,
-            {
-                self.created = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created: {e}"));
-                self
-            }
-            pub fn key<T>(mut self, value: T) -> Self
-            where
-                T: ::std::convert::TryInto<::std::string::String>,
-                T::Error: ::std::fmt::Display,
-            {
-                self.key = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for key: {e}"));
-                self
-            }
-            pub fn resolution<T>(mut self, value: T) -> Self
-            where
-                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
-                T::Error: ::std::fmt::Display,
-            {
-                self.resolution = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for resolution: {e}"));
-                self
-            }
-            pub fn status<T>(mut self, value: T) -> Self
+        impl DisplayTimestamp {
+            pub fn date<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.status = value
+                self.date = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for status: {e}"));
+                    .map_err(|e| format!("error converting supplied value for date: {e}"));
                 self
             }
-            pub fn summary<T>(mut self, value: T) -> Self
+            pub fn iso<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.summary = value
+                self.iso = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for summary: {e}"));
+                    .map_err(|e| format!("error converting supplied value for iso: {e}"));
                 self
             }
-            pub fn updated<T>(mut self, value: T) -> Self
+            pub fn relative<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.updated = value
+                self.relative = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for updated: {e}"));
+                    .map_err(|e| format!("error converting supplied value for relative: {e}"));
                 self
             }
         }
 
-        impl ::std::convert::TryFrom<IssueListItem> for super::IssueListItem {
+        impl ::std::convert::TryFrom<DisplayTimestamp> for super::DisplayTimestamp {
             type Error = super::error::ConversionError;
             fn try_from(
-                value: IssueListItem,
+                value: DisplayTimestamp,
             ) -> ::std::result::Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    created: value.created?,
-                    key: value.key?,
-                    resolution: value.resolution?,
-                    status: value.status?,
-                    summary: value.summary?,
-                    updated: value.updated?,
+                    date: value.date?,
+                    iso: value.iso?,
+                    relative: value.relative?,
                 })
             }
         }
 
-        impl ::std::convert::From<super::IssueListItem> for IssueListItem {
-            fn from(value: super::IssueListItem) -> Self {
+        impl ::std::convert::From<super::DisplayTimestamp> for DisplayTimestamp {
+            fn from(value: super::DisplayTimestamp) -> Self {
                 Self {
-                    created: Ok(value.created),
-                    key: Ok(value.key),
-                    resolution: Ok(value.resolution),
-                    status: Ok(value.status),
-                    summary: Ok(value.summary),
-                    updated: Ok(value.updated),
+                    date: Ok(value.date),
+                    iso: Ok(value.iso),
+                    relative: Ok(value.relative),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct IssueListResponse {
-            is_last: ::std::result::Result<bool, ::std::string::String>,
-            issues:
-                ::std::result::Result<::std::vec::Vec<super::IssueListItem>, ::std::string::String>,
-            next_page_token: ::std::result::Result<
+        pub struct Error {
+            error_code: ::std::result::Result<
                 ::std::option::Option<::std::string::String>,
                 ::std::string::String,
             >,
+            message: ::std::result::Result<::std::string::String, ::std::string::String>,
+            request_id: ::std::result::Result<::std::string::String, ::std::string::String>,
         }
 
-        impl ::std::default::Default for IssueListResponse {
+        impl ::std::default::Default for Error {
             fn default() -> Self {
                 Self {
-                    is_last: Err("no value supplied for is_last".to_string()),
-                    issues: Err("no value supplied for issues".to_string()),
-                    next_page_token: Ok(Default::default()),
+                    error_code: Ok(Default::default()),
+                    message: Err("no value supplied for message".to_string()),
+                    request_id: Err("no value supplied for request_id".to_string()),
                 }
             }
         }
 
-        impl IssueListResponse {
-            pub fn is_last<T>(mut self, value: T) -> Self
+        impl Error {
+            pub fn error_code<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<bool>,
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
                 T::Error: ::std::fmt::Display,
             {
-                self.is_last = value
+                self.error_code = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for is_last: {e}"));
+                    .map_err(|e| format!("error converting supplied value for error_code: {e}"));
                 self
             }
-            pub fn issues<T>(mut self, value: T) -> Self
+            pub fn message<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueListItem>>,
+                T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.issues = value
+                self.message = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for issues: {e}"));
+                    .map_err(|e| format!("error converting supplied value for message: {e}"));
                 self
             }
-            pub fn next_page_token<T>(mut self, value: T) -> Self
+            pub fn request_id<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.next_page_token = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for next_page_token: {e}")
-                });
+                self.request_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for request_id: {e}"));
                 self
             }
         }
 
-        impl ::std::convert::TryFrom<IssueListResponse> for super::IssueListResponse {
+        impl ::std::convert::TryFrom<Error> for super::Error {
             type Error = super::error::ConversionError;
             fn try_from(
-                value: IssueListResponse,
+                value: Error,
             ) -> ::std::result::Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    is_last: value.is_last?,
-                    issues: value.issues?,
-                    next_page_token: value.next_page_token?,
+                    error_code: value.error_code?,
+                    message: value.message?,
+                    request_id: value.request_id?,
                 })
             }
         }
 
-        impl ::std::convert::From<super::IssueListResponse> for IssueListResponse {
-            fn from(value: super::IssueListResponse) -> Self {
+        impl ::std::convert::From<super::Error> for Error {
+            fn from(value: super::Error) -> Self {
                 Self {
-                    is_last: Ok(value.is_last),
-                    issues: Ok(value.issues),
-                    next_page_token: Ok(value.next_page_token),
+                    error_code: Ok(value.error_code),
+                    message: Ok(value.message),
+                    request_id: Ok(value.request_id),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct IssueSummary {
-            id: ::std::result::Result<::std::string::String, ::std::string::String>,
-            summary: ::std::result::Result<::std::string::String, ::std::string::String>,
-            web_url: ::std::result::Result<::std::string::String, ::std::string::String>,
+        pub struct IssueCacheStats {
+            entries: ::std::result::Result<u64, ::std::string::String>,
+            hits: ::std::result::Result<u64, ::std::string::String>,
+            misses: ::std::result::Result<u64, ::std::string::String>,
+            refresh_failures: ::std::result::Result<u64, ::std::string::String>,
+            stale: ::std::result::Result<u64, ::std::string::String>,
         }
 
-        impl ::std::default::Default for IssueSummary {
+        impl ::std::default::Default for IssueCacheStats {
             fn default() -> Self {
                 Self {
-                    id: Err("no value supplied for id".to_string()),
-                    summary: Err("no value supplied for summary".to_string()),
-                    web_url: Err("no value supplied for web_url".to_string()),
+                    entries: Err("no value supplied for entries".to_string()),
+                    hits: Err("no value supplied for hits".to_string()),
+                    misses: Err("no value supplied for misses".to_string()),
+                    refresh_failures: Err("no value supplied for refresh_failures".to_string()),
+                    stale: Err("no value supplied for stale".to_string()),
                 }
             }
         }
 
-        impl IssueSummary {
-            pub fn id<T>(mut self, value: T) -> Self
+        impl IssueCacheStats {
+            pub fn entries<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::string::String>,
+                T: ::std::convert::TryInto<u64>,
                 T::Error: ::std::fmt::Display,
             {
-                self.id = value
+                self.entries = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {e}"));
+                    .map_err(|e| format!("error converting supplied value for entries: {e}"));
                 self
             }
-            pub fn summary<T>(mut self, value: T) -> Self
+            pub fn hits<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::string::String>,
+                T: ::std::convert::TryInto<u64>,
                 T::Error: ::std::fmt::Display,
             {
-                self.summary = value
+                self.hits = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for summary: {e}"));
+                    .map_err(|e| format!("error converting supplied value for hits: {e}"));
                 self
             }
-            pub fn web_url<T>(mut self, value: T) -> Self
+            pub fn misses<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::string::String>,
+                T: ::std::convert::TryInto<u64>,
                 T::Error: ::std::fmt::Display,
             {
-                self.web_url = value
+                self.misses = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for web_url: {e}"));
+                    .map_err(|e| format!("error converting supplied value for misses: {e}"));
+                self
+            }
+            pub fn refresh_failures<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u64>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.refresh_failures = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for refresh_failures: {e}")
+                });
+                self
+            }
+            pub fn stale<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u64>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.stale = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for stale: {e}"));
                 self
             }
         }
 
-        impl ::std::convert::TryFrom<IssueSummary> for super::IssueSummary {
+        impl ::std::convert::TryFrom<IssueCacheStats> for super::IssueCacheStats {
             type Error = super::error::ConversionError;
             fn try_from(
-                value: IssueSummary,
+                value: IssueCacheStats,
             ) -> ::std::result::Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    id: value.id?,
-                    summary: value.summary?,
-                    web_url: value.web_url?,
+                    entries: value.entries?,
+                    hits: value.hits?,
+                    misses: value.misses?,
+                    refresh_failures: value.refresh_failures?,
+                    stale: value.stale?,
                 })
             }
         }
 
-        impl ::std::convert::From<super::IssueSummary> for IssueSummary {
-            fn from(value: super::IssueSummary) -> Self {
+        impl ::std::convert::From<super::IssueCacheStats> for IssueCacheStats {
+            fn from(value: super::IssueCacheStats) -> Self {
                 Self {
-                    id: Ok(value.id),
-                    summary: Ok(value.summary),
-                    web_url: Ok(value.web_url),
+                    entries: Ok(value.entries),
+                    hits: Ok(value.hits),
+                    misses: Ok(value.misses),
+                    refresh_failures: Ok(value.refresh_failures),
+                    stale: Ok(value.stale),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct RemoteLink {
-            title: ::std::result::Result<::std::string::String, ::std::string::String>,
-            url: ::std::result::Result<::std::string::String, ::std::string::String>,
+        pub struct IssueComment {
+            author: ::std::result::Result<::std::string::String, ::std::string::String>,
+            body_html: ::std::result::Result<::std::string::String, ::std::string::String>,
+            created: ::std::result::Result<::std::string::String, ::std::string::String>,
+            edited: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
         }
 
-        impl ::std::default::Default for RemoteLink {
+        impl ::std::default::Default for IssueComment {
             fn default() -> Self {
                 Self {
-                    title: Err("no value supplied for title".to_string()),
-                    url: Err("no value supplied for url".to_string()),
+                    author: Err("no value supplied for author".to_string()),
+                    body_html: Err("no value supplied for body_html".to_string()),
+                    created: Err("no value supplied for created".to_string()),
+                    edited: Ok(Default::default()),
                 }
             }
         }
 
-        impl RemoteLink {
-            pub fn title<T>(mut self, value: T) -> Self
+        impl IssueComment {
+            pub fn author<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.title = value
+                self.author = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {e}"));
+                    .map_err(|e| format!("error converting supplied value for author: {e}"));
                 self
             }
-            pub fn url<T>(mut self, value: T) -> Self
+            pub fn body_html<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
                 T::Error: ::std::fmt::Display,
             {
-                self.url = value
+                self.body_html = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for url: {e}"));
+                    .map_err(|e| format!("error converting supplied value for body_html: {e}"));
                 self
             }
-        }
-
-        impl ::std::convert::TryFrom<RemoteLink> for super::RemoteLink {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: RemoteLink,
-            ) -> ::std::result::Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    title: value.title?,
-                    url: value.url?,
+            pub fn created<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created: {e}"));
+                self
+            }
+            pub fn edited<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.edited = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for edited: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueComment> for super::IssueComment {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueComment,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    author: value.author?,
+                    body_html: value.body_html?,
+                    created: value.created?,
+                    edited: value.edited?,
                 })
             }
         }
 
-        impl ::std::convert::From<super::RemoteLink> for RemoteLink {
-            fn from(value: super::RemoteLink) -> Self {
+        impl ::std::convert::From<super::IssueComment> for IssueComment {
+            fn from(value: super::IssueComment) -> Self {
                 Self {
-                    title: Ok(value.title),
-                    url: Ok(value.url),
+                    author: Ok(value.author),
+                    body_html: Ok(value.body_html),
+                    created: Ok(value.created),
+                    edited: Ok(value.edited),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueDetails {
+            fields: ::std::result::Result<::serde_json::Value, ::std::string::String>,
+            history: ::std::result::Result<
+                ::std::vec::Vec<super::IssueHistoryEntry>,
+                ::std::string::String,
+            >,
+            id: ::std::result::Result<::std::string::String, ::std::string::String>,
+            key: ::std::result::Result<::std::string::String, ::std::string::String>,
+            remotelinks:
+                ::std::result::Result<::std::vec::Vec<super::RemoteLink>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for IssueDetails {
+            fn default() -> Self {
+                Self {
+                    fields: Err("no value supplied for fields".to_string()),
+                    history: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    key: Err("no value supplied for key".to_string()),
+                    remotelinks: Err("no value supplied for remotelinks".to_string()),
+                }
+            }
+        }
+
+        impl IssueDetails {
+            pub fn fields<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::serde_json::Value>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.fields = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for fields: {e}"));
+                self
+            }
+            pub fn history<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueHistoryEntry>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.history = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for history: {e}"));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {e}"));
+                self
+            }
+            pub fn key<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.key = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key: {e}"));
+                self
+            }
+            pub fn remotelinks<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::RemoteLink>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.remotelinks = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for remotelinks: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueDetails> for super::IssueDetails {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueDetails,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    fields: value.fields?,
+                    history: value.history?,
+                    id: value.id?,
+                    key: value.key?,
+                    remotelinks: value.remotelinks?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueDetails> for IssueDetails {
+            fn from(value: super::IssueDetails) -> Self {
+                Self {
+                    fields: Ok(value.fields),
+                    history: Ok(value.history),
+                    id: Ok(value.id),
+                    key: Ok(value.key),
+                    remotelinks: Ok(value.remotelinks),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueDetailsV2 {
+            comments:
+                ::std::result::Result<::std::vec::Vec<super::IssueComment>, ::std::string::String>,
+            created: ::std::result::Result<::std::string::String, ::std::string::String>,
+            description_html: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            fix_versions: ::std::result::Result<
+                ::std::vec::Vec<::std::string::String>,
+                ::std::string::String,
+            >,
+            history: ::std::result::Result<
+                ::std::vec::Vec<super::IssueHistoryEntry>,
+                ::std::string::String,
+            >,
+            id: ::std::result::Result<::std::string::String, ::std::string::String>,
+            key: ::std::result::Result<::std::string::String, ::std::string::String>,
+            labels: ::std::result::Result<
+                ::std::vec::Vec<::std::string::String>,
+                ::std::string::String,
+            >,
+            links: ::std::result::Result<::std::vec::Vec<super::RemoteLink>, ::std::string::String>,
+            resolution: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            status: ::std::result::Result<::std::string::String, ::std::string::String>,
+            summary: ::std::result::Result<::std::string::String, ::std::string::String>,
+            updated: ::std::result::Result<::std::string::String, ::std::string::String>,
+            version: ::std::result::Result<u32, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for IssueDetailsV2 {
+            fn default() -> Self {
+                Self {
+                    comments: Err("no value supplied for comments".to_string()),
+                    created: Err("no value supplied for created".to_string()),
+                    description_html: Ok(Default::default()),
+                    fix_versions: Err("no value supplied for fix_versions".to_string()),
+                    history: Err("no value supplied for history".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    key: Err("no value supplied for key".to_string()),
+                    labels: Err("no value supplied for labels".to_string()),
+                    links: Err("no value supplied for links".to_string()),
+                    resolution: Ok(Default::default()),
+                    status: Err("no value supplied for status".to_string()),
+                    summary: Err("no value supplied for summary".to_string()),
+                    updated: Err("no value supplied for updated".to_string()),
+                    version: Err("no value supplied for version".to_string()),
                 }
             }
         }
+
+        impl IssueDetailsV2 {
+            pub fn comments<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueComment>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.comments = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for comments: {e}"));
+                self
+            }
+            pub fn created<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created: {e}"));
+                self
+            }
+            pub fn description_html<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.description_html = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for description_html: {e}")
+                });
+                self
+            }
+            pub fn fix_versions<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.fix_versions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for fix_versions: {e}"));
+                self
+            }
+            pub fn history<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueHistoryEntry>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.history = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for history: {e}"));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {e}"));
+                self
+            }
+            pub fn key<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.key = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key: {e}"));
+                self
+            }
+            pub fn labels<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.labels = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for labels: {e}"));
+                self
+            }
+            pub fn links<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::RemoteLink>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.links = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for links: {e}"));
+                self
+            }
+            pub fn resolution<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.resolution = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for resolution: {e}"));
+                self
+            }
+            pub fn status<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.status = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for status: {e}"));
+                self
+            }
+            pub fn summary<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.summary = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for summary: {e}"));
+                self
+            }
+            pub fn updated<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.updated = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated: {e}"));
+                self
+            }
+            pub fn version<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u32>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.version = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for version: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueDetailsV2> for super::IssueDetailsV2 {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueDetailsV2,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    comments: value.comments?,
+                    created: value.created?,
+                    description_html: value.description_html?,
+                    fix_versions: value.fix_versions?,
+                    history: value.history?,
+                    id: value.id?,
+                    key: value.key?,
+                    labels: value.labels?,
+                    links: value.links?,
+                    resolution: value.resolution?,
+                    status: value.status?,
+                    summary: value.summary?,
+                    updated: value.updated?,
+                    version: value.version?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueDetailsV2> for IssueDetailsV2 {
+            fn from(value: super::IssueDetailsV2) -> Self {
+                Self {
+                    comments: Ok(value.comments),
+                    created: Ok(value.created),
+                    description_html: Ok(value.description_html),
+                    fix_versions: Ok(value.fix_versions),
+                    history: Ok(value.history),
+                    id: Ok(value.id),
+                    key: Ok(value.key),
+                    labels: Ok(value.labels),
+                    links: Ok(value.links),
+                    resolution: Ok(value.resolution),
+                    status: Ok(value.status),
+                    summary: Ok(value.summary),
+                    updated: Ok(value.updated),
+                    version: Ok(value.version),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueFieldChange {
+            field: ::std::result::Result<::std::string::String, ::std::string::String>,
+            from: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            to: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for IssueFieldChange {
+            fn default() -> Self {
+                Self {
+                    field: Err("no value supplied for field".to_string()),
+                    from: Ok(Default::default()),
+                    to: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl IssueFieldChange {
+            pub fn field<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.field = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for field: {e}"));
+                self
+            }
+            pub fn from<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.from = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for from: {e}"));
+                self
+            }
+            pub fn to<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.to = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for to: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueFieldChange> for super::IssueFieldChange {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueFieldChange,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    field: value.field?,
+                    from: value.from?,
+                    to: value.to?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueFieldChange> for IssueFieldChange {
+            fn from(value: super::IssueFieldChange) -> Self {
+                Self {
+                    field: Ok(value.field),
+                    from: Ok(value.from),
+                    to: Ok(value.to),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueHistoryEntry {
+            changes: ::std::result::Result<
+                ::std::vec::Vec<super::IssueFieldChange>,
+                ::std::string::String,
+            >,
+            created: ::std::result::Result<::std::string::String, ::std::string::String>,
+            created_display: ::std::result::Result<
+                ::std::option::Option<super::DisplayTimestamp>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for IssueHistoryEntry {
+            fn default() -> Self {
+                Self {
+                    changes: Err("no value supplied for changes".to_string()),
+                    created: Err("no value supplied for created".to_string()),
+                    created_display: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl IssueHistoryEntry {
+            pub fn changes<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueFieldChange>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.changes = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for changes: {e}"));
+                self
+            }
+            pub fn created<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created: {e}"));
+                self
+            }
+            pub fn created_display<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<super::DisplayTimestamp>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created_display = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for created_display: {e}")
+                });
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueHistoryEntry> for super::IssueHistoryEntry {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueHistoryEntry,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    changes: value.changes?,
+                    created: value.created?,
+                    created_display: value.created_display?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueHistoryEntry> for IssueHistoryEntry {
+            fn from(value: super::IssueHistoryEntry) -> Self {
+                Self {
+                    changes: Ok(value.changes),
+                    created: Ok(value.created),
+                    created_display: Ok(value.created_display),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueListItem {
+            created: ::std::result::Result<::std::string::String, ::std::string::String>,
+            created_display: ::std::result::Result<
+                ::std::option::Option<super::DisplayTimestamp>,
+                ::std::string::String,
+            >,
+            issue_type: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            key: ::std::result::Result<::std::string::String, ::std::string::String>,
+            labels: ::std::result::Result<
+                ::std::vec::Vec<::std::string::String>,
+                ::std::string::String,
+            >,
+            priority: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            resolution: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            status: ::std::result::Result<::std::string::String, ::std::string::String>,
+            summary: ::std::result::Result<::std::string::String, ::std::string::String>,
+            updated: ::std::result::Result<::std::string::String, ::std::string::String>,
+            updated_display: ::std::result::Result<
+                ::std::option::Option<super::DisplayTimestamp>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for IssueListItem {
+            fn default() -> Self {
+                Self {
+                    created: Err("no value supplied for created".to_string()),
+                    created_display: Ok(Default::default()),
+                    issue_type: Ok(Default::default()),
+                    key: Err("no value supplied for key".to_string()),
+                    labels: Ok(Default::default()),
+                    priority: Ok(Default::default()),
+                    resolution: Ok(Default::default()),
+                    status: Err("no value supplied for status".to_string()),
+                    summary: Err("no value supplied for summary".to_string()),
+                    updated: Err("no value supplied for updated".to_string()),
+                    updated_display: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl IssueListItem {
+            pub fn created<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created: {e}"));
+                self
+            }
+            pub fn created_display<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<super::DisplayTimestamp>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created_display = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for created_display: {e}")
+                });
+                self
+            }
+            pub fn issue_type<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.issue_type = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for issue_type: {e}"));
+                self
+            }
+            pub fn key<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.key = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key: {e}"));
+                self
+            }
+            pub fn labels<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.labels = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for labels: {e}"));
+                self
+            }
+            pub fn priority<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.priority = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for priority: {e}"));
+                self
+            }
+            pub fn resolution<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.resolution = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for resolution: {e}"));
+                self
+            }
+            pub fn status<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.status = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for status: {e}"));
+                self
+            }
+            pub fn summary<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.summary = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for summary: {e}"));
+                self
+            }
+            pub fn updated<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.updated = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated: {e}"));
+                self
+            }
+            pub fn updated_display<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<super::DisplayTimestamp>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.updated_display = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for updated_display: {e}")
+                });
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueListItem> for super::IssueListItem {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueListItem,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created: value.created?,
+                    created_display: value.created_display?,
+                    issue_type: value.issue_type?,
+                    key: value.key?,
+                    labels: value.labels?,
+                    priority: value.priority?,
+                    resolution: value.resolution?,
+                    status: value.status?,
+                    summary: value.summary?,
+                    updated: value.updated?,
+                    updated_display: value.updated_display?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueListItem> for IssueListItem {
+            fn from(value: super::IssueListItem) -> Self {
+                Self {
+                    created: Ok(value.created),
+                    created_display: Ok(value.created_display),
+                    issue_type: Ok(value.issue_type),
+                    key: Ok(value.key),
+                    labels: Ok(value.labels),
+                    priority: Ok(value.priority),
+                    resolution: Ok(value.resolution),
+                    status: Ok(value.status),
+                    summary: Ok(value.summary),
+                    updated: Ok(value.updated),
+                    updated_display: Ok(value.updated_display),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueListResponse {
+            is_last: ::std::result::Result<bool, ::std::string::String>,
+            issues:
+                ::std::result::Result<::std::vec::Vec<super::IssueListItem>, ::std::string::String>,
+            next_page: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+            next_page_token: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            page: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+            prev_page: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for IssueListResponse {
+            fn default() -> Self {
+                Self {
+                    is_last: Err("no value supplied for is_last".to_string()),
+                    issues: Err("no value supplied for issues".to_string()),
+                    next_page: Ok(Default::default()),
+                    next_page_token: Ok(Default::default()),
+                    page: Ok(Default::default()),
+                    prev_page: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl IssueListResponse {
+            pub fn is_last<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.is_last = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for is_last: {e}"));
+                self
+            }
+            pub fn issues<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueListItem>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.issues = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for issues: {e}"));
+                self
+            }
+            pub fn next_page<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<u32>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.next_page = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for next_page: {e}"));
+                self
+            }
+            pub fn next_page_token<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.next_page_token = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for next_page_token: {e}")
+                });
+                self
+            }
+            pub fn page<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<u32>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.page = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for page: {e}"));
+                self
+            }
+            pub fn prev_page<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<u32>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.prev_page = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for prev_page: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueListResponse> for super::IssueListResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueListResponse,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    is_last: value.is_last?,
+                    issues: value.issues?,
+                    next_page: value.next_page?,
+                    next_page_token: value.next_page_token?,
+                    page: value.page?,
+                    prev_page: value.prev_page?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueListResponse> for IssueListResponse {
+            fn from(value: super::IssueListResponse) -> Self {
+                Self {
+                    is_last: Ok(value.is_last),
+                    issues: Ok(value.issues),
+                    next_page: Ok(value.next_page),
+                    next_page_token: Ok(value.next_page_token),
+                    page: Ok(value.page),
+                    prev_page: Ok(value.prev_page),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueSummary {
+            id: ::std::result::Result<::std::string::String, ::std::string::String>,
+            summary: ::std::result::Result<::std::string::String, ::std::string::String>,
+            web_url: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for IssueSummary {
+            fn default() -> Self {
+                Self {
+                    id: Err("no value supplied for id".to_string()),
+                    summary: Err("no value supplied for summary".to_string()),
+                    web_url: Err("no value supplied for web_url".to_string()),
+                }
+            }
+        }
+
+        impl IssueSummary {
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {e}"));
+                self
+            }
+            pub fn summary<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.summary = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for summary: {e}"));
+                self
+            }
+            pub fn web_url<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.web_url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for web_url: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueSummary> for super::IssueSummary {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueSummary,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    id: value.id?,
+                    summary: value.summary?,
+                    web_url: value.web_url?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueSummary> for IssueSummary {
+            fn from(value: super::IssueSummary) -> Self {
+                Self {
+                    id: Ok(value.id),
+                    summary: Ok(value.summary),
+                    web_url: Ok(value.web_url),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct IssueViews {
+            key: ::std::result::Result<::std::string::String, ::std::string::String>,
+            views: ::std::result::Result<u64, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for IssueViews {
+            fn default() -> Self {
+                Self {
+                    key: Err("no value supplied for key".to_string()),
+                    views: Err("no value supplied for views".to_string()),
+                }
+            }
+        }
+
+        impl IssueViews {
+            pub fn key<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.key = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key: {e}"));
+                self
+            }
+            pub fn views<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u64>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.views = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for views: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<IssueViews> for super::IssueViews {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: IssueViews,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    key: value.key?,
+                    views: value.views?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::IssueViews> for IssueViews {
+            fn from(value: super::IssueViews) -> Self {
+                Self {
+                    key: Ok(value.key),
+                    views: Ok(value.views),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct LabelViews {
+            label: ::std::result::Result<::std::string::String, ::std::string::String>,
+            views: ::std::result::Result<u64, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for LabelViews {
+            fn default() -> Self {
+                Self {
+                    label: Err("no value supplied for label".to_string()),
+                    views: Err("no value supplied for views".to_string()),
+                }
+            }
+        }
+
+        impl LabelViews {
+            pub fn label<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.label = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for label: {e}"));
+                self
+            }
+            pub fn views<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u64>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.views = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for views: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<LabelViews> for super::LabelViews {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: LabelViews,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    label: value.label?,
+                    views: value.views?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::LabelViews> for LabelViews {
+            fn from(value: super::LabelViews) -> Self {
+                Self {
+                    label: Ok(value.label),
+                    views: Ok(value.views),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RemoteLink {
+            title: ::std::result::Result<::std::string::String, ::std::string::String>,
+            url: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for RemoteLink {
+            fn default() -> Self {
+                Self {
+                    title: Err("no value supplied for title".to_string()),
+                    url: Err("no value supplied for url".to_string()),
+                }
+            }
+        }
+
+        impl RemoteLink {
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {e}"));
+                self
+            }
+            pub fn url<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<RemoteLink> for super::RemoteLink {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: RemoteLink,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    title: value.title?,
+                    url: value.url?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::RemoteLink> for RemoteLink {
+            fn from(value: super::RemoteLink) -> Self {
+                Self {
+                    title: Ok(value.title),
+                    url: Ok(value.url),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct StatsResponse {
+            cache: ::std::result::Result<super::IssueCacheStats, ::std::string::String>,
+            generated: ::std::result::Result<::std::string::String, ::std::string::String>,
+            windows:
+                ::std::result::Result<::std::vec::Vec<super::StatsWindow>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for StatsResponse {
+            fn default() -> Self {
+                Self {
+                    cache: Err("no value supplied for cache".to_string()),
+                    generated: Err("no value supplied for generated".to_string()),
+                    windows: Err("no value supplied for windows".to_string()),
+                }
+            }
+        }
+
+        impl StatsResponse {
+            pub fn cache<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<super::IssueCacheStats>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.cache = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for cache: {e}"));
+                self
+            }
+            pub fn generated<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.generated = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for generated: {e}"));
+                self
+            }
+            pub fn windows<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::StatsWindow>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.windows = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for windows: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<StatsResponse> for super::StatsResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: StatsResponse,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    cache: value.cache?,
+                    generated: value.generated?,
+                    windows: value.windows?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::StatsResponse> for StatsResponse {
+            fn from(value: super::StatsResponse) -> Self {
+                Self {
+                    cache: Ok(value.cache),
+                    generated: Ok(value.generated),
+                    windows: Ok(value.windows),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct StatsWindow {
+            issues:
+                ::std::result::Result<::std::vec::Vec<super::IssueViews>, ::std::string::String>,
+            labels:
+                ::std::result::Result<::std::vec::Vec<super::LabelViews>, ::std::string::String>,
+            since: ::std::result::Result<::std::string::String, ::std::string::String>,
+            total_views: ::std::result::Result<u64, ::std::string::String>,
+            window: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for StatsWindow {
+            fn default() -> Self {
+                Self {
+                    issues: Err("no value supplied for issues".to_string()),
+                    labels: Err("no value supplied for labels".to_string()),
+                    since: Err("no value supplied for since".to_string()),
+                    total_views: Err("no value supplied for total_views".to_string()),
+                    window: Err("no value supplied for window".to_string()),
+                }
+            }
+        }
+
+        impl StatsWindow {
+            pub fn issues<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::IssueViews>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.issues = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for issues: {e}"));
+                self
+            }
+            pub fn labels<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::LabelViews>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.labels = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for labels: {e}"));
+                self
+            }
+            pub fn since<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.since = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for since: {e}"));
+                self
+            }
+            pub fn total_views<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u64>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.total_views = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for total_views: {e}"));
+                self
+            }
+            pub fn window<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.window = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for window: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<StatsWindow> for super::StatsWindow {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: StatsWindow,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    issues: value.issues?,
+                    labels: value.labels?,
+                    since: value.since?,
+                    total_views: value.total_views?,
+                    window: value.window?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::StatsWindow> for StatsWindow {
+            fn from(value: super::StatsWindow) -> Self {
+                Self {
+                    issues: Ok(value.issues),
+                    labels: Ok(value.labels),
+                    since: Ok(value.since),
+                    total_views: Ok(value.total_views),
+                    window: Ok(value.window),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[doc = "Client for Bugview API\n\nPublic JIRA issue viewer API\n\nVersion: 0.1.0"]
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+}
+
+impl Client {
+    #[doc = r" Create a new client."]
+    #[doc = r""]
+    #[doc = r" `baseurl` is the base URL provided to the internal"]
+    #[doc = r" `reqwest::Client`, and should include a scheme and hostname,"]
+    #[doc = r" as well as port and a path stem if applicable."]
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = ::std::time::Duration::from_secs(15u64);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    #[doc = r" Construct a new client with an existing `reqwest::Client`,"]
+    #[doc = r" allowing more control over its configuration."]
+    #[doc = r""]
+    #[doc = r" `baseurl` is the base URL provided to the internal"]
+    #[doc = r" `reqwest::Client`, and should include a scheme and hostname,"]
+    #[doc = r" as well as port and a path stem if applicable."]
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+        }
+    }
+}
+
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "0.1.0"
+    }
+
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+
+impl ClientHooks<()> for &Client {}
+impl Client {
+    #[doc = "Redirect /bugview to /bugview/index.html\n\nSends a `GET` request to `/bugview`\n\n```ignore\nlet response = client.redirect_bugview_root()\n    .send()\n    .await;\n```"]
+    pub fn redirect_bugview_root(&self) -> builder::RedirectBugviewRoot<'_> {
+        builder::RedirectBugviewRoot::new(self)
+    }
+
+    #[doc = "Purge the issue and index caches\n\nDrops every cached issue and index search, so that the next request for each goes to JIRA. Requires the admin bearer token configured on the service; returns 404 when no token is configured.\n\nSends a `POST` request to `/bugview/admin/cache/purge`\n\n```ignore\nlet response = client.purge_caches()\n    .send()\n    .await;\n```"]
+    pub fn purge_caches(&self) -> builder::PurgeCaches<'_> {
+        builder::PurgeCaches::new(self)
+    }
+
+    #[doc = "Reload the service configuration\n\nRe-reads the public labels, projects, allowed domains and the other reloadable settings, without restarting the service. Requires the admin bearer token configured on the service; returns 404 when no token is configured. An invalid configuration is a 400 error and leaves the running configuration in place.\n\nSends a `POST` request to `/bugview/admin/reload`\n\n```ignore\nlet response = client.reload_config()\n    .send()\n    .await;\n```"]
+    pub fn reload_config(&self) -> builder::ReloadConfig<'_> {
+        builder::ReloadConfig::new(self)
+    }
+
+    #[doc = "Get full issue details as JSON\n\nReturns complete issue information including all fields. With `v=2`, returns a stable typed subset of the fields instead of passing JIRA's fields through. Supports conditional requests.\n\nSends a `GET` request to `/bugview/fulljson/{key}`\n\nArguments:\n- `key`: Issue key (e.g., \"PROJECT-123\")\n- `v`: Response format version: 1 (default) passes JIRA's issue fields through, 2 returns a stable typed subset of them\n```ignore\nlet response = client.get_issue_full_json()\n    .key(key)\n    .v(v)\n    .send()\n    .await;\n```"]
+    pub fn get_issue_full_json(&self) -> builder::GetIssueFullJson<'_> {
+        builder::GetIssueFullJson::new(self)
+    }
+
+    #[doc = "Get issue index as HTML\n\nReturns a paginated HTML view of public issues.\n\nSends a `GET` request to `/bugview/index.html`\n\nArguments:\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `page`: Page number (1-based). Ignored when `next_page_token` is given.\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_issue_index_html()\n    .next_page_token(next_page_token)\n    .page(page)\n    .sort(sort)\n    .send()\n    .await;\n```"]
+    pub fn get_issue_index_html(&self) -> builder::GetIssueIndexHtml<'_> {
+        builder::GetIssueIndexHtml::new(self)
+    }
+
+    #[doc = "Get issue index as JSON\n\nReturns a paginated list of public issues, optionally filtered by status, last update date and project. Supports conditional requests via `If-None-Match` / `If-Modified-Since`.\n\nSends a `GET` request to `/bugview/index.json`\n\nArguments:\n- `next_page_token`: Next page token for pagination (token-based, not offset). Tokens are only valid with the same filters as the request that returned them.\n- `page`: Page number (1-based). Ignored when `next_page_token` is given.\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n- `status`: Only include issues that are open (unresolved) or resolved\n- `updated_since`: Only include issues updated on or after this date (ISO 8601 calendar date, `YYYY-MM-DD`, in the JIRA server's timezone)\n- `project`: Only include issues from this JIRA project, given as its key (e.g. \"OS\"): an uppercase letter followed by uppercase letters, digits or underscores\n```ignore\nlet response = client.get_issue_index_json()\n    .next_page_token(next_page_token)\n    .page(page)\n    .sort(sort)\n    .status(status)\n    .updated_since(updated_since)\n    .project(project)\n    .send()\n    .await;\n```"]
+    pub fn get_issue_index_json(&self) -> builder::GetIssueIndexJson<'_> {
+        builder::GetIssueIndexJson::new(self)
+    }
+
+    #[doc = "Get issue details as HTML\n\nReturns an HTML view of a single issue with full details. Clients that prefer `application/json` in their `Accept` header get the same response as `/bugview/fulljson/{key}` instead, including its `v` parameter and conditional request support.\n\nSends a `GET` request to `/bugview/issue/{key}`\n\nArguments:\n- `key`: Issue key (e.g., \"PROJECT-123\")\n- `v`: Response format version: 1 (default) passes JIRA's issue fields through, 2 returns a stable typed subset of them\n```ignore\nlet response = client.get_issue_html()\n    .key(key)\n    .v(v)\n    .send()\n    .await;\n```"]
+    pub fn get_issue_html(&self) -> builder::GetIssueHtml<'_> {
+        builder::GetIssueHtml::new(self)
+    }
+
+    #[doc = "Get issue summary as JSON (legacy format)\n\nReturns issue key, summary, and web URL. This endpoint maintains backwards compatibility with the original Node.js bugview service.\n\nSends a `GET` request to `/bugview/json/{key}`\n\nArguments:\n- `key`: Issue key (e.g., \"PROJECT-123\")\n```ignore\nlet response = client.get_issue_json()\n    .key(key)\n    .send()\n    .await;\n```"]
+    pub fn get_issue_json(&self) -> builder::GetIssueJson<'_> {
+        builder::GetIssueJson::new(self)
+    }
+
+    #[doc = "Get issues for a specific label as HTML\n\nReturns a paginated HTML view of issues with the specified label.\n\nSends a `GET` request to `/bugview/label/{key}`\n\nArguments:\n- `key`: Label key\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `page`: Page number (1-based). Ignored when `next_page_token` is given.\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_label_index_html()\n    .key(key)\n    .next_page_token(next_page_token)\n    .page(page)\n    .sort(sort)\n    .send()\n    .await;\n```"]
+    pub fn get_label_index_html(&self) -> builder::GetLabelIndexHtml<'_> {
+        builder::GetLabelIndexHtml::new(self)
+    }
+
+    #[doc = "Get a project's issue index as HTML\n\nReturns a paginated HTML view of the public issues in one project. Projects configured with a public label of their own use it; other projects use the service's default label.\n\nSends a `GET` request to `/bugview/project/{project}/index.html`\n\nArguments:\n- `project`: JIRA project key (e.g., \"OS\")\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `page`: Page number (1-based). Ignored when `next_page_token` is given.\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_project_index_html()\n    .project(project)\n    .next_page_token(next_page_token)\n    .page(page)\n    .sort(sort)\n    .send()\n    .await;\n```"]
+    pub fn get_project_index_html(&self) -> builder::GetProjectIndexHtml<'_> {
+        builder::GetProjectIndexHtml::new(self)
+    }
+
+    #[doc = "Get a project's issues for a specific label as HTML\n\nReturns a paginated HTML view of the public issues in one project with the specified label, which must be one of the project's allowed labels.\n\nSends a `GET` request to `/bugview/project/{project}/label/{key}`\n\nArguments:\n- `project`: JIRA project key (e.g., \"OS\")\n- `key`: Label key\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `page`: Page number (1-based). Ignored when `next_page_token` is given.\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_project_label_index_html()\n    .project(project)\n    .key(key)\n    .next_page_token(next_page_token)\n    .page(page)\n    .sort(sort)\n    .send()\n    .await;\n```"]
+    pub fn get_project_label_index_html(&self) -> builder::GetProjectLabelIndexHtml<'_> {
+        builder::GetProjectLabelIndexHtml::new(self)
+    }
+
+    #[doc = "Get a static asset\n\nServes the stylesheet and script used by the HTML pages. The assets are embedded in the service, so the pages load nothing from third-party hosts.\n\nSends a `GET` request to `/bugview/static/{asset}`\n\nArguments:\n- `asset`: Asset file name (e.g., \"bugview.css\")\n```ignore\nlet response = client.get_static_asset()\n    .asset(asset)\n    .send()\n    .await;\n```"]
+    pub fn get_static_asset(&self) -> builder::GetStaticAsset<'_> {
+        builder::GetStaticAsset::new(self)
+    }
+
+    #[doc = "Get issue access statistics\n\nReturns the most viewed issues and labels over the last 24 hours, 7 days and 30 days. Requires the admin bearer token configured on the service; returns 404 when no token is configured.\n\nSends a `GET` request to `/bugview/stats.json`\n\nArguments:\n- `limit`: Maximum number of issues and labels listed per window (default 20)\n```ignore\nlet response = client.get_stats_json()\n    .limit(limit)\n    .send()\n    .await;\n```"]
+    pub fn get_stats_json(&self) -> builder::GetStatsJson<'_> {
+        builder::GetStatsJson::new(self)
+    }
+}
+
+#[doc = r" Types for composing operation parameters."]
+#[allow(clippy::all)]
+pub mod builder {
+    use super::types;
+    #[allow(unused_imports)]
+    use super::{
+        ByteStream, ClientHooks, ClientInfo, Error, OperationInfo, RequestBuilderExt,
+        ResponseValue, encode_path,
+    };
+    #[doc = "Builder for [`Client::redirect_bugview_root`]\n\n[`Client::redirect_bugview_root`]: super::Client::redirect_bugview_root"]
+    #[derive(Debug, Clone)]
+    pub struct RedirectBugviewRoot<'a> {
+        client: &'a super::Client,
+    }
+
+    impl<'a> RedirectBugviewRoot<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+
+        #[doc = "Sends a `GET` request to `/bugview`"]
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+            let Self { client } = self;
+            let url = format!("{}/bugview", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client.client.get(url).headers(header_map).build()?;
+            let info = OperationInfo {
+                operation_id: "redirect_bugview_root",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::purge_caches`]\n\n[`Client::purge_caches`]: super::Client::purge_caches"]
+    #[derive(Debug, Clone)]
+    pub struct PurgeCaches<'a> {
+        client: &'a super::Client,
+    }
+
+    impl<'a> PurgeCaches<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+
+        #[doc = "Sends a `POST` request to `/bugview/admin/cache/purge`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::CachePurgeResponse>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/bugview/admin/cache/purge", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "purge_caches",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::reload_config`]\n\n[`Client::reload_config`]: super::Client::reload_config"]
+    #[derive(Debug, Clone)]
+    pub struct ReloadConfig<'a> {
+        client: &'a super::Client,
+    }
+
+    impl<'a> ReloadConfig<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+
+        #[doc = "Sends a `POST` request to `/bugview/admin/reload`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ConfigReloadResponse>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/bugview/admin/reload", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "reload_config",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::get_issue_full_json`]\n\n[`Client::get_issue_full_json`]: super::Client::get_issue_full_json"]
+    #[derive(Debug, Clone)]
+    pub struct GetIssueFullJson<'a> {
+        client: &'a super::Client,
+        key: Result<::std::string::String, String>,
+        v: Result<Option<u32>, String>,
+    }
+
+    impl<'a> GetIssueFullJson<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                key: Err("key was not initialized".to_string()),
+                v: Ok(None),
+            }
+        }
+
+        pub fn key<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.key = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for key failed".to_string()
+            });
+            self
+        }
+
+        pub fn v<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.v = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for v failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/bugview/fulljson/{key}`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::IssueFullResponse>, Error<types::Error>> {
+            let Self { client, key, v } = self;
+            let key = key.map_err(Error::InvalidRequest)?;
+            let v = v.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bugview/fulljson/{}",
+                client.baseurl,
+                encode_path(&key.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new("v", &v))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "get_issue_full_json",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::get_issue_index_html`]\n\n[`Client::get_issue_index_html`]: super::Client::get_issue_index_html"]
+    #[derive(Debug, Clone)]
+    pub struct GetIssueIndexHtml<'a> {
+        client: &'a super::Client,
+        next_page_token: Result<Option<::std::string::String>, String>,
+        page: Result<Option<u32>, String>,
+        sort: Result<Option<types::IssueSort>, String>,
+    }
+
+    impl<'a> GetIssueIndexHtml<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                next_page_token: Ok(None),
+                page: Ok(None),
+                sort: Ok(None),
+            }
+        }
+
+        pub fn next_page_token<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.next_page_token = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for next_page_token failed".to_string()
+            });
+            self
+        }
+
+        pub fn page<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.page = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for page failed".to_string());
+            self
+        }
+
+        pub fn sort<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::IssueSort>,
+        {
+            self.sort = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `IssueSort` for sort failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/bugview/index.html`"]
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+            let Self {
+                client,
+                next_page_token,
+                page,
+                sort,
+            } = self;
+            let next_page_token = next_page_token.map_err(Error::InvalidRequest)?;
+            let page = page.map_err(Error::InvalidRequest)?;
+            let sort = sort.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/bugview/index.html", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .query(&progenitor_client::QueryParam::new(
+                    "next_page_token",
+                    &next_page_token,
+                ))
+                .query(&progenitor_client::QueryParam::new("page", &page))
+                .query(&progenitor_client::QueryParam::new("sort", &sort))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "get_issue_index_html",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+            }
+        }
     }
-}
-
-#[derive(Clone, Debug)]
-#[doc = "Client for Bugview API\n\nPublic JIRA issue viewer API\n\nVersion: 0.1.0"]
-pub struct Client {
-    pub(crate) baseurl: String,
-    pub(crate) client: reqwest::Client,
-}
 
-impl Client {
-    #[doc = r" Create a new client."]
-    #[doc = r""]
-    #[doc = r" `baseurl` is the base URL provided to the internal"]
-    #[doc = r" `reqwest::Client`, and should include a scheme and hostname,"]
-    #[doc = r" as well as port and a path stem if applicable."]
-    pub fn new(baseurl: &str) -> Self {
-        #[cfg(not(target_arch = "wasm32"))]
-        let client = {
-            let dur = ::std::time::Duration::from_secs(15u64);
-            reqwest::ClientBuilder::new()
-                .connect_timeout(dur)
-                .timeout(dur)
-        };
-        #[cfg(target_arch = "wasm32")]
-        let client = reqwest::ClientBuilder::new();
-        Self::new_with_client(baseurl, client.build().unwrap())
+    #[doc = "Builder for [`Client::get_issue_index_json`]\n\n[`Client::get_issue_index_json`]: super::Client::get_issue_index_json"]
+    #[derive(Debug, Clone)]
+    pub struct GetIssueIndexJson<'a> {
+        client: &'a super::Client,
+        next_page_token: Result<Option<::std::string::String>, String>,
+        page: Result<Option<u32>, String>,
+        sort: Result<Option<types::IssueSort>, String>,
+        status: Result<Option<types::IssueStatusFilter>, String>,
+        updated_since: Result<Option<::std::string::String>, String>,
+        project: Result<Option<::std::string::String>, String>,
     }
 
-    #[doc = r" Construct a new client with an existing `reqwest::Client`,"]
-    #[doc = r" allowing more control over its configuration."]
-    #[doc = r""]
-    #[doc = r" `baseurl` is the base URL provided to the internal"]
-    #[doc = r" `reqwest::Client`, and should include a scheme and hostname,"]
-    #[doc = r" as well as port and a path stem if applicable."]
-    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
-        Self {
-            baseurl: baseurl.to_string(),
-            client,
+    impl<'a> GetIssueIndexJson<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                next_page_token: Ok(None),
+                page: Ok(None),
+                sort: Ok(None),
+                status: Ok(None),
+                updated_since: Ok(None),
+                project: Ok(None),
+            }
         }
-    }
-}
-
-impl ClientInfo<()> for Client {
-    fn api_version() -> &'static str {
-        "0.1.0"
-    }
-
-    fn baseurl(&self) -> &str {
-        self.baseurl.as_str()
-    }
 
-    fn client(&self) -> &reqwest::Client {
-        &self.client
-    }
-
-    fn inner(&self) -> &() {
-        &()
-    }
-}
-
-impl ClientHooks<()> for &Client {}
-impl Client {
-    #[doc = "Redirect /bugview to /bugview/index.html\n\nSends a `GET` request to `/bugview`\n\n```ignore\nlet response = client.redirect_bugview_root()\n    .send()\n    .await;\n```"]
-    pub fn redirect_bugview_root(&self) -> builder::RedirectBugviewRoot<'_> {
-        builder::RedirectBugviewRoot::new(self)
-    }
+        pub fn next_page_token<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.next_page_token = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for next_page_token failed".to_string()
+            });
+            self
+        }
 
-    #[doc = "Get full issue details as JSON\n\nReturns complete issue information including all fields.\n\nSends a `GET` request to `/bugview/fulljson/{key}`\n\nArguments:\n- `key`: Issue key (e.g., \"PROJECT-123\")\n```ignore\nlet response = client.get_issue_full_json()\n    .key(key)\n    .send()\n    .await;\n```"]
-    pub fn get_issue_full_json(&self) -> builder::GetIssueFullJson<'_> {
-        builder::GetIssueFullJson::new(self)
-    }
+        pub fn page<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.page = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for page failed".to_string());
+            self
+        }
 
-    #[doc = "Get issue index as HTML\n\nReturns a paginated HTML view of public issues.\n\nSends a `GET` request to `/bugview/index.html`\n\nArguments:\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_issue_index_html()\n    .next_page_token(next_page_token)\n    .sort(sort)\n    .send()\n    .await;\n```"]
-    pub fn get_issue_index_html(&self) -> builder::GetIssueIndexHtml<'_> {
-        builder::GetIssueIndexHtml::new(self)
-    }
+        pub fn sort<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::IssueSort>,
+        {
+            self.sort = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `IssueSort` for sort failed".to_string());
+            self
+        }
 
-    #[doc = "Get issue index as JSON\n\nReturns a paginated list of public issues.\n\nSends a `GET` request to `/bugview/index.json`\n\nArguments:\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_issue_index_json()\n    .next_page_token(next_page_token)\n    .sort(sort)\n    .send()\n    .await;\n```"]
-    pub fn get_issue_index_json(&self) -> builder::GetIssueIndexJson<'_> {
-        builder::GetIssueIndexJson::new(self)
-    }
+        pub fn status<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::IssueStatusFilter>,
+        {
+            self.status = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `IssueStatusFilter` for status failed".to_string());
+            self
+        }
 
-    #[doc = "Get issue details as HTML\n\nReturns an HTML view of a single issue with full details.\n\nSends a `GET` request to `/bugview/issue/{key}`\n\nArguments:\n- `key`: Issue key (e.g., \"PROJECT-123\")\n```ignore\nlet response = client.get_issue_html()\n    .key(key)\n    .send()\n    .await;\n```"]
-    pub fn get_issue_html(&self) -> builder::GetIssueHtml<'_> {
-        builder::GetIssueHtml::new(self)
-    }
+        pub fn updated_since<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.updated_since = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for updated_since failed".to_string()
+            });
+            self
+        }
 
-    #[doc = "Get issue summary as JSON (legacy format)\n\nReturns issue key, summary, and web URL. This endpoint maintains backwards compatibility with the original Node.js bugview service.\n\nSends a `GET` request to `/bugview/json/{key}`\n\nArguments:\n- `key`: Issue key (e.g., \"PROJECT-123\")\n```ignore\nlet response = client.get_issue_json()\n    .key(key)\n    .send()\n    .await;\n```"]
-    pub fn get_issue_json(&self) -> builder::GetIssueJson<'_> {
-        builder::GetIssueJson::new(self)
-    }
+        pub fn project<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.project = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for project failed".to_string()
+            });
+            self
+        }
 
-    #[doc = "Get issues for a specific label as HTML\n\nReturns a paginated HTML view of issues with the specified label.\n\nSends a `GET` request to `/bugview/label/{key}`\n\nArguments:\n- `key`: Label key\n- `next_page_token`: Next page token for pagination (token-based, not offset)\n- `sort`: Sort field (key, created, or updated). Defaults to \"updated\" if omitted.\n```ignore\nlet response = client.get_label_index_html()\n    .key(key)\n    .next_page_token(next_page_token)\n    .sort(sort)\n    .send()\n    .await;\n```"]
-    pub fn get_label_index_html(&self) -> builder::GetLabelIndexHtml<'_> {
-        builder::GetLabelIndexHtml::new(self)
+        #[doc = "Sends a `GET` request to `/bugview/index.json`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::IssueListResponse>, Error<types::Error>> {
+            let Self {
+                client,
+                next_page_token,
+                page,
+                sort,
+                status,
+                updated_since,
+                project,
+            } = self;
+            let next_page_token = next_page_token.map_err(Error::InvalidRequest)?;
+            let page = page.map_err(Error::InvalidRequest)?;
+            let sort = sort.map_err(Error::InvalidRequest)?;
+            let status = status.map_err(Error::InvalidRequest)?;
+            let updated_since = updated_since.map_err(Error::InvalidRequest)?;
+            let project = project.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/bugview/index.json", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new(
+                    "next_page_token",
+                    &next_page_token,
+                ))
+                .query(&progenitor_client::QueryParam::new("page", &page))
+                .query(&progenitor_client::QueryParam::new("sort", &sort))
+                .query(&progenitor_client::QueryParam::new("status", &status))
+                .query(&progenitor_client::QueryParam::new(
+                    "updated_since",
+                    &updated_since,
+                ))
+                .query(&progenitor_client::QueryParam::new("project", &project))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "get_issue_index_json",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
-}
 
-#[doc = r" Types for composing operation parameters."]
-#[allow(clippy::all)]
-pub mod builder {
-    use super::types;
-    #[allow(unused_imports)]
-    use super::{
-        ByteStream, ClientHooks, ClientInfo, Error, OperationInfo, RequestBuilderExt,
-        ResponseValue, encode_path,
-    };
-    #[doc = "Builder for [`Client::redirect_bugview_root`]\n\n[`Client::redirect_bugview_root`]: super::Client::redirect_bugview_root"]
+    #[doc = "Builder for [`Client::get_issue_html`]\n\n[`Client::get_issue_html`]: super::Client::get_issue_html"]
     #[derive(Debug, Clone)]
-    pub struct RedirectBugviewRoot<'a> {
+    pub struct GetIssueHtml<'a> {
         client: &'a super::Client,
+        key: Result<::std::string::String, String>,
+        v: Result<Option<u32>, String>,
     }
 
-    impl<'a> RedirectBugviewRoot<'a> {
+    impl<'a> GetIssueHtml<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                key: Err("key was not initialized".to_string()),
+                v: Ok(None),
+            }
         }
 
-        #[doc = "Sends a `GET` request to `/bugview`"]
+        pub fn key<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.key = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for key failed".to_string()
+            });
+            self
+        }
+
+        pub fn v<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.v = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for v failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/bugview/issue/{key}`"]
         pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
-            let Self { client } = self;
-            let url = format!("{}/bugview", client.baseurl,);
+            let Self { client, key, v } = self;
+            let key = key.map_err(Error::InvalidRequest)?;
+            let v = v.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/bugview/issue/{}",
+                client.baseurl,
+                encode_path(&key.to_string()),
+            );
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
                 ::reqwest::header::HeaderName::from_static("api-version"),
                 ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
             );
             #[allow(unused_mut)]
-            let mut request = client.client.get(url).headers(header_map).build()?;
+            let mut request = client
+                .client
+                .get(url)
+                .query(&progenitor_client::QueryParam::new("v", &v))
+                .headers(header_map)
+                .build()?;
             let info = OperationInfo {
-                operation_id: "redirect_bugview_root",
+                operation_id: "get_issue_html",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
@@ -1093,14 +3927,14 @@ pub mod builder {
         }
     }
 
-    #[doc = "Builder for [`Client::get_issue_full_json`]\n\n[`Client::get_issue_full_json`]: super::Client::get_issue_full_json"]
+    #[doc = "Builder for [`Client::get_issue_json`]\n\n[`Client::get_issue_json`]: super::Client::get_issue_json"]
     #[derive(Debug, Clone)]
-    pub struct GetIssueFullJson<'a> {
+    pub struct GetIssueJson<'a> {
         client: &'a super::Client,
         key: Result<::std::string::String, String>,
     }
 
-    impl<'a> GetIssueFullJson<'a> {
+    impl<'a> GetIssueJson<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
@@ -1118,12 +3952,12 @@ pub mod builder {
             self
         }
 
-        #[doc = "Sends a `GET` request to `/bugview/fulljson/{key}`"]
-        pub async fn send(self) -> Result<ResponseValue<types::IssueDetails>, Error<types::Error>> {
+        #[doc = "Sends a `GET` request to `/bugview/json/{key}`"]
+        pub async fn send(self) -> Result<ResponseValue<types::IssueSummary>, Error<types::Error>> {
             let Self { client, key } = self;
             let key = key.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/bugview/fulljson/{}",
+                "{}/bugview/json/{}",
                 client.baseurl,
                 encode_path(&key.to_string()),
             );
@@ -1143,7 +3977,7 @@ pub mod builder {
                 .headers(header_map)
                 .build()?;
             let info = OperationInfo {
-                operation_id: "get_issue_full_json",
+                operation_id: "get_issue_json",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
@@ -1162,23 +3996,37 @@ pub mod builder {
         }
     }
 
-    #[doc = "Builder for [`Client::get_issue_index_html`]\n\n[`Client::get_issue_index_html`]: super::Client::get_issue_index_html"]
+    #[doc = "Builder for [`Client::get_label_index_html`]\n\n[`Client::get_label_index_html`]: super::Client::get_label_index_html"]
     #[derive(Debug, Clone)]
-    pub struct GetIssueIndexHtml<'a> {
+    pub struct GetLabelIndexHtml<'a> {
         client: &'a super::Client,
+        key: Result<::std::string::String, String>,
         next_page_token: Result<Option<::std::string::String>, String>,
+        page: Result<Option<u32>, String>,
         sort: Result<Option<types::IssueSort>, String>,
     }
 
-    impl<'a> GetIssueIndexHtml<'a> {
+    impl<'a> GetLabelIndexHtml<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
+                key: Err("key was not initialized".to_string()),
                 next_page_token: Ok(None),
+                page: Ok(None),
                 sort: Ok(None),
             }
         }
 
+        pub fn key<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.key = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for key failed".to_string()
+            });
+            self
+        }
+
         pub fn next_page_token<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<::std::string::String>,
@@ -1189,6 +4037,17 @@ pub mod builder {
             self
         }
 
+        pub fn page<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.page = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for page failed".to_string());
+            self
+        }
+
         pub fn sort<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<types::IssueSort>,
@@ -1200,16 +4059,24 @@ pub mod builder {
             self
         }
 
-        #[doc = "Sends a `GET` request to `/bugview/index.html`"]
+        #[doc = "Sends a `GET` request to `/bugview/label/{key}`"]
         pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
             let Self {
                 client,
+                key,
                 next_page_token,
+                page,
                 sort,
             } = self;
+            let key = key.map_err(Error::InvalidRequest)?;
             let next_page_token = next_page_token.map_err(Error::InvalidRequest)?;
+            let page = page.map_err(Error::InvalidRequest)?;
             let sort = sort.map_err(Error::InvalidRequest)?;
-            let url = format!("{}/bugview/index.html", client.baseurl,);
+            let url = format!(
+                "{}/bugview/label/{}",
+                client.baseurl,
+                encode_path(&key.to_string()),
+            );
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
                 ::reqwest::header::HeaderName::from_static("api-version"),
@@ -1223,11 +4090,12 @@ pub mod builder {
                     "next_page_token",
                     &next_page_token,
                 ))
+                .query(&progenitor_client::QueryParam::new("page", &page))
                 .query(&progenitor_client::QueryParam::new("sort", &sort))
                 .headers(header_map)
                 .build()?;
             let info = OperationInfo {
-                operation_id: "get_issue_index_html",
+                operation_id: "get_label_index_html",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
@@ -1240,23 +4108,37 @@ pub mod builder {
         }
     }
 
-    #[doc = "Builder for [`Client::get_issue_index_json`]\n\n[`Client::get_issue_index_json`]: super::Client::get_issue_index_json"]
+    #[doc = "Builder for [`Client::get_project_index_html`]\n\n[`Client::get_project_index_html`]: super::Client::get_project_index_html"]
     #[derive(Debug, Clone)]
-    pub struct GetIssueIndexJson<'a> {
+    pub struct GetProjectIndexHtml<'a> {
         client: &'a super::Client,
+        project: Result<::std::string::String, String>,
         next_page_token: Result<Option<::std::string::String>, String>,
+        page: Result<Option<u32>, String>,
         sort: Result<Option<types::IssueSort>, String>,
     }
 
-    impl<'a> GetIssueIndexJson<'a> {
+    impl<'a> GetProjectIndexHtml<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
+                project: Err("project was not initialized".to_string()),
                 next_page_token: Ok(None),
+                page: Ok(None),
                 sort: Ok(None),
             }
         }
 
+        pub fn project<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.project = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for project failed".to_string()
+            });
+            self
+        }
+
         pub fn next_page_token<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<::std::string::String>,
@@ -1267,6 +4149,17 @@ pub mod builder {
             self
         }
 
+        pub fn page<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.page = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for page failed".to_string());
+            self
+        }
+
         pub fn sort<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<types::IssueSort>,
@@ -1278,18 +4171,24 @@ pub mod builder {
             self
         }
 
-        #[doc = "Sends a `GET` request to `/bugview/index.json`"]
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::IssueListResponse>, Error<types::Error>> {
+        #[doc = "Sends a `GET` request to `/bugview/project/{project}/index.html`"]
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
             let Self {
                 client,
+                project,
                 next_page_token,
+                page,
                 sort,
             } = self;
+            let project = project.map_err(Error::InvalidRequest)?;
             let next_page_token = next_page_token.map_err(Error::InvalidRequest)?;
+            let page = page.map_err(Error::InvalidRequest)?;
             let sort = sort.map_err(Error::InvalidRequest)?;
-            let url = format!("{}/bugview/index.json", client.baseurl,);
+            let url = format!(
+                "{}/bugview/project/{}/index.html",
+                client.baseurl,
+                encode_path(&project.to_string()),
+            );
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
                 ::reqwest::header::HeaderName::from_static("api-version"),
@@ -1299,52 +4198,61 @@ pub mod builder {
             let mut request = client
                 .client
                 .get(url)
-                .header(
-                    ::reqwest::header::ACCEPT,
-                    ::reqwest::header::HeaderValue::from_static("application/json"),
-                )
                 .query(&progenitor_client::QueryParam::new(
                     "next_page_token",
                     &next_page_token,
                 ))
+                .query(&progenitor_client::QueryParam::new("page", &page))
                 .query(&progenitor_client::QueryParam::new("sort", &sort))
                 .headers(header_map)
                 .build()?;
             let info = OperationInfo {
-                operation_id: "get_issue_index_json",
+                operation_id: "get_project_index_html",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
             client.post(&result, &info).await?;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                400u16..=499u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                500u16..=599u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                _ => Err(Error::UnexpectedResponse(response)),
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
             }
         }
     }
 
-    #[doc = "Builder for [`Client::get_issue_html`]\n\n[`Client::get_issue_html`]: super::Client::get_issue_html"]
+    #[doc = "Builder for [`Client::get_project_label_index_html`]\n\n[`Client::get_project_label_index_html`]: super::Client::get_project_label_index_html"]
     #[derive(Debug, Clone)]
-    pub struct GetIssueHtml<'a> {
+    pub struct GetProjectLabelIndexHtml<'a> {
         client: &'a super::Client,
+        project: Result<::std::string::String, String>,
         key: Result<::std::string::String, String>,
+        next_page_token: Result<Option<::std::string::String>, String>,
+        page: Result<Option<u32>, String>,
+        sort: Result<Option<types::IssueSort>, String>,
     }
 
-    impl<'a> GetIssueHtml<'a> {
+    impl<'a> GetProjectLabelIndexHtml<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
+                project: Err("project was not initialized".to_string()),
                 key: Err("key was not initialized".to_string()),
+                next_page_token: Ok(None),
+                page: Ok(None),
+                sort: Ok(None),
             }
         }
 
+        pub fn project<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.project = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for project failed".to_string()
+            });
+            self
+        }
+
         pub fn key<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<::std::string::String>,
@@ -1355,13 +4263,57 @@ pub mod builder {
             self
         }
 
-        #[doc = "Sends a `GET` request to `/bugview/issue/{key}`"]
+        pub fn next_page_token<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.next_page_token = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for next_page_token failed".to_string()
+            });
+            self
+        }
+
+        pub fn page<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.page = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for page failed".to_string());
+            self
+        }
+
+        pub fn sort<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::IssueSort>,
+        {
+            self.sort = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `IssueSort` for sort failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/bugview/project/{project}/label/{key}`"]
         pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
-            let Self { client, key } = self;
+            let Self {
+                client,
+                project,
+                key,
+                next_page_token,
+                page,
+                sort,
+            } = self;
+            let project = project.map_err(Error::InvalidRequest)?;
             let key = key.map_err(Error::InvalidRequest)?;
+            let next_page_token = next_page_token.map_err(Error::InvalidRequest)?;
+            let page = page.map_err(Error::InvalidRequest)?;
+            let sort = sort.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/bugview/issue/{}",
+                "{}/bugview/project/{}/label/{}",
                 client.baseurl,
+                encode_path(&project.to_string()),
                 encode_path(&key.to_string()),
             );
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
@@ -1370,9 +4322,19 @@ pub mod builder {
                 ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
             );
             #[allow(unused_mut)]
-            let mut request = client.client.get(url).headers(header_map).build()?;
+            let mut request = client
+                .client
+                .get(url)
+                .query(&progenitor_client::QueryParam::new(
+                    "next_page_token",
+                    &next_page_token,
+                ))
+                .query(&progenitor_client::QueryParam::new("page", &page))
+                .query(&progenitor_client::QueryParam::new("sort", &sort))
+                .headers(header_map)
+                .build()?;
             let info = OperationInfo {
-                operation_id: "get_issue_html",
+                operation_id: "get_project_label_index_html",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
@@ -1385,39 +4347,39 @@ pub mod builder {
         }
     }
 
-    #[doc = "Builder for [`Client::get_issue_json`]\n\n[`Client::get_issue_json`]: super::Client::get_issue_json"]
+    #[doc = "Builder for [`Client::get_static_asset`]\n\n[`Client::get_static_asset`]: super::Client::get_static_asset"]
     #[derive(Debug, Clone)]
-    pub struct GetIssueJson<'a> {
+    pub struct GetStaticAsset<'a> {
         client: &'a super::Client,
-        key: Result<::std::string::String, String>,
+        asset: Result<::std::string::String, String>,
     }
 
-    impl<'a> GetIssueJson<'a> {
+    impl<'a> GetStaticAsset<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                key: Err("key was not initialized".to_string()),
+                asset: Err("asset was not initialized".to_string()),
             }
         }
 
-        pub fn key<V>(mut self, value: V) -> Self
+        pub fn asset<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<::std::string::String>,
         {
-            self.key = value.try_into().map_err(|_| {
-                "conversion to `:: std :: string :: String` for key failed".to_string()
+            self.asset = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for asset failed".to_string()
             });
             self
         }
 
-        #[doc = "Sends a `GET` request to `/bugview/json/{key}`"]
-        pub async fn send(self) -> Result<ResponseValue<types::IssueSummary>, Error<types::Error>> {
-            let Self { client, key } = self;
-            let key = key.map_err(Error::InvalidRequest)?;
+        #[doc = "Sends a `GET` request to `/bugview/static/{asset}`"]
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+            let Self { client, asset } = self;
+            let asset = asset.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/bugview/json/{}",
+                "{}/bugview/static/{}",
                 client.baseurl,
-                encode_path(&key.to_string()),
+                encode_path(&asset.to_string()),
             );
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
@@ -1425,101 +4387,54 @@ pub mod builder {
                 ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
             );
             #[allow(unused_mut)]
-            let mut request = client
-                .client
-                .get(url)
-                .header(
-                    ::reqwest::header::ACCEPT,
-                    ::reqwest::header::HeaderValue::from_static("application/json"),
-                )
-                .headers(header_map)
-                .build()?;
+            let mut request = client.client.get(url).headers(header_map).build()?;
             let info = OperationInfo {
-                operation_id: "get_issue_json",
+                operation_id: "get_static_asset",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
             client.post(&result, &info).await?;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                400u16..=499u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                500u16..=599u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                _ => Err(Error::UnexpectedResponse(response)),
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
             }
         }
     }
 
-    #[doc = "Builder for [`Client::get_label_index_html`]\n\n[`Client::get_label_index_html`]: super::Client::get_label_index_html"]
+    #[doc = "Builder for [`Client::get_stats_json`]\n\n[`Client::get_stats_json`]: super::Client::get_stats_json"]
     #[derive(Debug, Clone)]
-    pub struct GetLabelIndexHtml<'a> {
+    pub struct GetStatsJson<'a> {
         client: &'a super::Client,
-        key: Result<::std::string::String, String>,
-        next_page_token: Result<Option<::std::string::String>, String>,
-        sort: Result<Option<types::IssueSort>, String>,
+        limit: Result<Option<u32>, String>,
     }
 
-    impl<'a> GetLabelIndexHtml<'a> {
+    impl<'a> GetStatsJson<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                key: Err("key was not initialized".to_string()),
-                next_page_token: Ok(None),
-                sort: Ok(None),
+                limit: Ok(None),
             }
         }
 
-        pub fn key<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<::std::string::String>,
-        {
-            self.key = value.try_into().map_err(|_| {
-                "conversion to `:: std :: string :: String` for key failed".to_string()
-            });
-            self
-        }
-
-        pub fn next_page_token<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<::std::string::String>,
-        {
-            self.next_page_token = value.try_into().map(Some).map_err(|_| {
-                "conversion to `:: std :: string :: String` for next_page_token failed".to_string()
-            });
-            self
-        }
-
-        pub fn sort<V>(mut self, value: V) -> Self
+        pub fn limit<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::IssueSort>,
+            V: std::convert::TryInto<u32>,
         {
-            self.sort = value
+            self.limit = value
                 .try_into()
                 .map(Some)
-                .map_err(|_| "conversion to `IssueSort` for sort failed".to_string());
+                .map_err(|_| "conversion to `u32` for limit failed".to_string());
             self
         }
 
-        #[doc = "Sends a `GET` request to `/bugview/label/{key}`"]
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
-            let Self {
-                client,
-                key,
-                next_page_token,
-                sort,
-            } = self;
-            let key = key.map_err(Error::InvalidRequest)?;
-            let next_page_token = next_page_token.map_err(Error::InvalidRequest)?;
-            let sort = sort.map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/bugview/label/{}",
-                client.baseurl,
-                encode_path(&key.to_string()),
-            );
+        #[doc = "Sends a `GET` request to `/bugview/stats.json`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::StatsResponse>, Error<types::Error>> {
+            let Self { client, limit } = self;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/bugview/stats.json", client.baseurl,);
             let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
             header_map.append(
                 ::reqwest::header::HeaderName::from_static("api-version"),
@@ -1529,23 +4444,29 @@ pub mod builder {
             let mut request = client
                 .client
                 .get(url)
-                .query(&progenitor_client::QueryParam::new(
-                    "next_page_token",
-                    &next_page_token,
-                ))
-                .query(&progenitor_client::QueryParam::new("sort", &sort))
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new("limit", &limit))
                 .headers(header_map)
                 .build()?;
             let info = OperationInfo {
-                operation_id: "get_label_index_html",
+                operation_id: "get_stats_json",
             };
             client.pre(&mut request, &info).await?;
             let result = client.exec(request, &info).await;
             client.post(&result, &info).await?;
             let response = result?;
             match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
-                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
@@ -8,8 +8,77 @@
 //!
 //! This module provides the `add_auth_headers` pre-hook function for adding
 //! HTTP Signature authentication headers to Progenitor-generated client requests.
+//!
+//! The headers are produced by a [`Signer`]. By default that is the
+//! client's [`AuthConfig`] itself, which signs with its key source; a
+//! [`ClientAuth`] built with [`ClientAuth::with_signer`] signs with any
+//! other, such as a [`triton_auth::FixedSigner`] in tests. The
+//! [`AuthConfig`] still supplies the account, RBAC roles, `X-Act-As`,
+//! `Accept-Version` and the list of signed headers.
+//!
+//! A configuration with [`KeySource::None`](triton_auth::KeySource::None)
+//! (see [`AuthConfig::unauthenticated`]) sends requests without the `Date`
+//! and `Authorization` headers, for testing against `cloudapi-stub-server`.
+//! Release builds log a warning the first time that happens, since a real
+//! CloudAPI rejects every such request.
+
+use std::sync::Arc;
+
+use triton_auth::{AuthConfig, Signer};
+
+/// How a client authenticates its requests: the configuration, and the
+/// signer that produces the `Date` and `Authorization` headers
+#[derive(Clone)]
+pub struct ClientAuth {
+    config: AuthConfig,
+    /// `None` for an unauthenticated configuration
+    signer: Option<Arc<dyn Signer>>,
+}
+
+impl ClientAuth {
+    /// Sign with the configuration's own key source
+    pub fn new(config: AuthConfig) -> Self {
+        let signer = if config.is_unauthenticated() {
+            None
+        } else {
+            Some(Arc::new(config.clone()) as Arc<dyn Signer>)
+        };
+        Self { config, signer }
+    }
+
+    /// Sign with `signer`; the configuration's key source is not used
+    pub fn with_signer(config: AuthConfig, signer: Arc<dyn Signer>) -> Self {
+        Self {
+            config,
+            signer: Some(signer),
+        }
+    }
+
+    /// The authentication configuration
+    pub fn config(&self) -> &AuthConfig {
+        &self.config
+    }
+
+    /// The signer, or `None` if requests go unsigned
+    pub fn signer(&self) -> Option<&Arc<dyn Signer>> {
+        self.signer.as_ref()
+    }
+}
 
-use triton_auth::AuthConfig;
+impl From<AuthConfig> for ClientAuth {
+    fn from(config: AuthConfig) -> Self {
+        Self::new(config)
+    }
+}
+
+impl std::fmt::Debug for ClientAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientAuth")
+            .field("config", &self.config)
+            .field("signer", &self.signer.as_ref().map(|_| "<Signer>"))
+            .finish()
+    }
+}
 
 /// Add authentication headers to a request
 ///
@@ -17,13 +86,13 @@ use triton_auth::AuthConfig;
 /// It adds the required `Date` and `Authorization` headers for HTTP Signature auth.
 ///
 /// # Arguments
-/// * `auth_config` - Authentication configuration containing account, key_id, and key_source
+/// * `auth` - Authentication configuration and the signer to sign with
 /// * `request` - The mutable request to add headers to
 ///
 /// # Errors
 /// Returns an error if signing fails (key not found, agent unavailable, etc.)
 pub async fn add_auth_headers(
-    auth_config: &AuthConfig,
+    auth: &ClientAuth,
     request: &mut reqwest::Request,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // In emit-payload mode, skip signing — we only need the method/path/body
@@ -32,6 +101,7 @@ pub async fn add_auth_headers(
         return Ok(());
     }
 
+    let auth_config = auth.config();
     let method = request.method().as_str().to_string();
 
     // Add RBAC roles as query parameter BEFORE signing so the signature
@@ -60,30 +130,46 @@ pub async fn add_auth_headers(
         None => url.path().to_string(),
     };
 
-    // Sign the request using triton-auth
-    let (date_header, auth_header) =
-        triton_auth::sign_request(auth_config, &method, &path_and_query).await?;
+    if let Some(signer) = auth.signer() {
+        // Signed headers computed from the request itself (`host`,
+        // `content-md5`) are added before signing, so that the signature
+        // covers the values that are sent
+        let mut signed_headers = request.headers().clone();
+        auth_config.signed_headers.add_computed_headers(
+            &mut signed_headers,
+            &host_header(request.url()),
+            request.body().map_or(Some(&[][..]), |body| body.as_bytes()),
+        )?;
+
+        let (date_header, auth_header) = signer
+            .sign_with_headers(&method, &path_and_query, &signed_headers)
+            .await?;
+
+        let headers = request.headers_mut();
+        *headers = signed_headers;
+        headers.insert(
+            reqwest::header::DATE,
+            date_header.parse().map_err(|e| {
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid date header: {}", e),
+                )) as Box<dyn std::error::Error + Send + Sync>
+            })?,
+        );
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            auth_header.parse().map_err(|e| {
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid authorization header: {}", e),
+                )) as Box<dyn std::error::Error + Send + Sync>
+            })?,
+        );
+    } else {
+        warn_unauthenticated();
+    }
 
-    // Add headers
     let headers = request.headers_mut();
-    headers.insert(
-        reqwest::header::DATE,
-        date_header.parse().map_err(|e| {
-            Box::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Invalid date header: {}", e),
-            )) as Box<dyn std::error::Error + Send + Sync>
-        })?,
-    );
-    headers.insert(
-        reqwest::header::AUTHORIZATION,
-        auth_header.parse().map_err(|e| {
-            Box::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Invalid authorization header: {}", e),
-            )) as Box<dyn std::error::Error + Send + Sync>
-        })?,
-    );
 
     // Add X-Act-As header if present (for operator masquerading)
     if let Some(act_as) = &auth_config.act_as {
@@ -113,3 +199,78 @@ pub async fn add_auth_headers(
 
     Ok(())
 }
+
+/// The `Host` header value for a URL: its host, and its port if not the
+/// scheme's default
+fn host_header(url: &reqwest::Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    }
+}
+
+/// Warn, once per process in release builds, that requests are unsigned
+fn warn_unauthenticated() {
+    #[cfg(not(debug_assertions))]
+    {
+        static WARNED: std::sync::Once = std::sync::Once::new();
+        WARNED.call_once(|| {
+            tracing::warn!(
+                "CloudAPI requests are being sent unsigned (unauthenticated mode); \
+                 this is only meant for testing against a stub server"
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use triton_auth::{FixedSigner, KeySource};
+
+    fn get(url: &str) -> reqwest::Request {
+        reqwest::Request::new(reqwest::Method::GET, url.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_injected_signer() {
+        let config = AuthConfig::new("myaccount", KeySource::None)
+            .with_roles(vec!["admin".to_string()])
+            .with_accept_version("~9");
+        let signer = Arc::new(FixedSigner::new("myaccount", "aa:bb:cc:dd"));
+        let auth = ClientAuth::with_signer(config, signer.clone());
+
+        let mut request = get("https://cloudapi.example.com/myaccount/machines");
+        add_auth_headers(&auth, &mut request).await.unwrap();
+
+        // The signature covers the role parameter added to the query
+        let (date, auth_header) = signer
+            .sign("GET", "/myaccount/machines?as-role=admin")
+            .await
+            .unwrap();
+        let headers = request.headers();
+        assert_eq!(headers[reqwest::header::DATE], date.as_str());
+        assert_eq!(
+            headers[reqwest::header::AUTHORIZATION],
+            auth_header.as_str()
+        );
+        assert_eq!(headers["accept-version"], "~9");
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_config_is_unsigned() {
+        let auth = ClientAuth::from(AuthConfig::unauthenticated("myaccount"));
+        assert!(auth.signer().is_none());
+
+        let mut request = get("https://cloudapi.example.com/myaccount/machines");
+        add_auth_headers(&auth, &mut request).await.unwrap();
+        assert!(
+            request
+                .headers()
+                .get(reqwest::header::AUTHORIZATION)
+                .is_none()
+        );
+        assert!(request.headers().get(reqwest::header::DATE).is_none());
+    }
+}
@@ -0,0 +1,254 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Read-through response cache for rarely-changing resources
+//!
+//! Packages and images change rarely, but CLI-style workloads look them up
+//! over and over (resolving a package name, rendering an instance list).
+//! [`ResponseCache`] keeps decoded response bodies keyed by request URL so
+//! that [`TypedClient`](crate::TypedClient) can answer repeat lookups
+//! without another round trip to CloudAPI.
+//!
+//! Entries live for the configured TTL, shortened by a `Cache-Control:
+//! max-age` on the response. Responses marked `no-store` or `no-cache` are
+//! never stored.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use reqwest::header::{CACHE_CONTROL, HeaderMap};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// A cached response body
+#[derive(Debug)]
+struct CacheEntry {
+    body: serde_json::Value,
+    expires: Instant,
+}
+
+/// URL-keyed cache of decoded response bodies
+#[derive(Debug)]
+pub struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl ResponseCache {
+    /// Create an empty cache whose entries live for at most `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The maximum lifetime of an entry
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+        // A panic while holding the lock leaves the map itself intact
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Look up a fresh entry for `url`
+    ///
+    /// Expired entries are dropped. An entry that no longer decodes as `T`
+    /// is treated as a miss.
+    pub fn get<T: DeserializeOwned>(&self, url: &str) -> Option<T> {
+        let mut entries = self.lock();
+        let entry = entries.get(url)?;
+        if entry.expires <= Instant::now() {
+            entries.remove(url);
+            return None;
+        }
+        serde_json::from_value(entry.body.clone()).ok()
+    }
+
+    /// Store `value` for `url`, honouring the response's `Cache-Control`
+    ///
+    /// Returns whether the value was stored.
+    pub fn insert<T: Serialize>(&self, url: &str, value: &T, headers: &HeaderMap) -> bool {
+        let Some(lifetime) = entry_lifetime(headers, self.ttl) else {
+            self.invalidate(url);
+            return false;
+        };
+        let Ok(body) = serde_json::to_value(value) else {
+            return false;
+        };
+        self.lock().insert(
+            url.to_string(),
+            CacheEntry {
+                body,
+                expires: Instant::now() + lifetime,
+            },
+        );
+        true
+    }
+
+    /// Drop the entry for `url`, if any
+    pub fn invalidate(&self, url: &str) {
+        self.lock().remove(url);
+    }
+
+    /// Drop every entry whose URL starts with `prefix`
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        self.lock().retain(|url, _| !url.starts_with(prefix));
+    }
+
+    /// Drop all entries
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Number of stored entries, including any that have expired but have
+    /// not yet been looked up
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+}
+
+/// How long a response may be cached, or `None` if it must not be
+///
+/// `no-store` and `no-cache` disable caching, and `max-age` shortens (but
+/// never extends) the configured `ttl`.
+fn entry_lifetime(headers: &HeaderMap, ttl: Duration) -> Option<Duration> {
+    let mut lifetime = ttl;
+    for value in headers.get_all(CACHE_CONTROL) {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+        for directive in value.split(',') {
+            let directive = directive.trim();
+            let (name, arg) = match directive.split_once('=') {
+                Some((name, arg)) => (name.trim(), Some(arg.trim().trim_matches('"'))),
+                None => (directive, None),
+            };
+            if name.eq_ignore_ascii_case("no-store") || name.eq_ignore_ascii_case("no-cache") {
+                return None;
+            }
+            if name.eq_ignore_ascii_case("max-age") {
+                // An unparseable max-age is treated as already stale
+                let secs = arg.and_then(|a| a.parse::<u64>().ok()).unwrap_or(0);
+                lifetime = lifetime.min(Duration::from_secs(secs));
+            }
+        }
+    }
+    (!lifetime.is_zero()).then_some(lifetime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    const TTL: Duration = Duration::from_secs(300);
+
+    fn cache_control(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn lifetime_defaults_to_ttl() {
+        assert_eq!(entry_lifetime(&HeaderMap::new(), TTL), Some(TTL));
+        assert_eq!(entry_lifetime(&cache_control("private"), TTL), Some(TTL));
+    }
+
+    #[test]
+    fn lifetime_respects_max_age() {
+        assert_eq!(
+            entry_lifetime(&cache_control("public, max-age=60"), TTL),
+            Some(Duration::from_secs(60))
+        );
+        // max-age never extends the configured TTL
+        assert_eq!(
+            entry_lifetime(&cache_control("max-age=86400"), TTL),
+            Some(TTL)
+        );
+        assert_eq!(entry_lifetime(&cache_control("max-age=0"), TTL), None);
+        assert_eq!(entry_lifetime(&cache_control("max-age=soon"), TTL), None);
+    }
+
+    #[test]
+    fn lifetime_respects_no_store() {
+        assert_eq!(entry_lifetime(&cache_control("no-store"), TTL), None);
+        assert_eq!(entry_lifetime(&cache_control("No-Cache"), TTL), None);
+        assert_eq!(
+            entry_lifetime(&cache_control("max-age=60, no-cache"), TTL),
+            None
+        );
+    }
+
+    #[test]
+    fn insert_and_get() {
+        let cache = ResponseCache::new(TTL);
+        let url = "https://cloudapi.example.com/admin/packages/g1.small";
+
+        assert_eq!(cache.get::<Vec<String>>(url), None);
+        assert!(cache.insert(url, &vec!["a".to_string()], &HeaderMap::new()));
+        assert_eq!(cache.get::<Vec<String>>(url), Some(vec!["a".to_string()]));
+        // A body that no longer decodes as the requested type is a miss
+        assert_eq!(cache.get::<u64>(url), None);
+    }
+
+    #[test]
+    fn uncacheable_response_replaces_entry() {
+        let cache = ResponseCache::new(TTL);
+        let url = "https://cloudapi.example.com/admin/images";
+
+        assert!(cache.insert(url, &1u64, &HeaderMap::new()));
+        assert!(!cache.insert(url, &2u64, &cache_control("no-store")));
+        assert_eq!(cache.get::<u64>(url), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn expired_entries_are_misses() {
+        let cache = ResponseCache::new(TTL);
+        let url = "https://cloudapi.example.com/admin/images";
+
+        cache.lock().insert(
+            url.to_string(),
+            CacheEntry {
+                body: serde_json::json!(1),
+                expires: Instant::now(),
+            },
+        );
+        assert_eq!(cache.get::<u64>(url), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn invalidation() {
+        let cache = ResponseCache::new(TTL);
+        let base = "https://cloudapi.example.com/admin";
+        for path in ["/images", "/images/abc", "/packages", "/packages/g1"] {
+            cache.insert(&format!("{base}{path}"), &0u64, &HeaderMap::new());
+        }
+
+        cache.invalidate(&format!("{base}/packages/g1"));
+        assert_eq!(cache.len(), 3);
+
+        cache.invalidate_prefix(&format!("{base}/images"));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get::<u64>(&format!("{base}/packages")), Some(0));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
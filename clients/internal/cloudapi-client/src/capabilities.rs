@@ -0,0 +1,240 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Server capability probing
+//!
+//! This client is generated from the CloudAPI 9.20 spec, but may be pointed
+//! at an older server that lacks whole families of endpoints. Calling one of
+//! those fails with a bare 404, which reads like a missing resource rather
+//! than a missing feature.
+//!
+//! [`TypedClient::probe_capabilities`](crate::TypedClient::probe_capabilities)
+//! first asks the server for its version through `GET /--version`. A server
+//! at least as recent as [`cloudapi_api::API_VERSION`] has every endpoint the
+//! client knows about. An older one gets a `HEAD` request on the collection
+//! of each optional [`EndpointFamily`]: a route the server does not have
+//! answers 404, while one it has answers anything else (405 for a route
+//! without `HEAD`, 401 for a rejected signature, and so on).
+//!
+//! Once the client has probed, the typed helpers for an unsupported family
+//! fail with [`UnsupportedByServer`] without sending a request.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// A family of endpoints that older CloudAPI servers may lack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EndpointFamily {
+    /// `/{account}/accesskeys`
+    AccessKeys,
+    /// `/{account}/fabrics/default/vlans` and below
+    Fabrics,
+    /// `/{account}/migrations` and machine migration actions
+    Migrations,
+    /// `/{account}/volumes`
+    Volumes,
+}
+
+impl EndpointFamily {
+    /// Every family that is probed
+    pub const ALL: [EndpointFamily; 4] = [
+        EndpointFamily::AccessKeys,
+        EndpointFamily::Fabrics,
+        EndpointFamily::Migrations,
+        EndpointFamily::Volumes,
+    ];
+
+    /// The collection probed for the family, relative to `/{account}/`
+    pub fn probe_path(self) -> &'static str {
+        match self {
+            EndpointFamily::AccessKeys => "accesskeys",
+            EndpointFamily::Fabrics => "fabrics/default/vlans",
+            EndpointFamily::Migrations => "migrations",
+            EndpointFamily::Volumes => "volumes",
+        }
+    }
+}
+
+impl fmt::Display for EndpointFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            EndpointFamily::AccessKeys => "access key",
+            EndpointFamily::Fabrics => "fabric",
+            EndpointFamily::Migrations => "migration",
+            EndpointFamily::Volumes => "volume",
+        })
+    }
+}
+
+/// What a CloudAPI server was found to support
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerCapabilities {
+    /// Version reported by `GET /--version`
+    pub server_version: String,
+    /// API versions the server accepts
+    pub api_versions: Vec<String>,
+    unsupported: BTreeSet<EndpointFamily>,
+}
+
+impl ServerCapabilities {
+    /// Capabilities of a server that has every family
+    pub fn all(server_version: String, api_versions: Vec<String>) -> Self {
+        Self {
+            server_version,
+            api_versions,
+            unsupported: BTreeSet::new(),
+        }
+    }
+
+    /// Capabilities of a server that lacks the given families
+    pub fn without(
+        server_version: String,
+        api_versions: Vec<String>,
+        unsupported: impl IntoIterator<Item = EndpointFamily>,
+    ) -> Self {
+        Self {
+            server_version,
+            api_versions,
+            unsupported: unsupported.into_iter().collect(),
+        }
+    }
+
+    /// Whether the server has the family's endpoints
+    pub fn supports(&self, family: EndpointFamily) -> bool {
+        !self.unsupported.contains(&family)
+    }
+
+    /// The families the server lacks
+    pub fn unsupported(&self) -> impl Iterator<Item = EndpointFamily> + '_ {
+        self.unsupported.iter().copied()
+    }
+
+    /// Fail with [`UnsupportedByServer`] if the server lacks the family
+    pub fn require(&self, family: EndpointFamily) -> Result<(), UnsupportedByServer> {
+        if self.supports(family) {
+            Ok(())
+        } else {
+            Err(UnsupportedByServer {
+                family,
+                server_version: self.server_version.clone(),
+            })
+        }
+    }
+}
+
+/// A typed helper was called for endpoints the server does not have
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{family} endpoints are not supported by CloudAPI {server_version}")]
+pub struct UnsupportedByServer {
+    pub family: EndpointFamily,
+    /// Version reported by the server
+    pub server_version: String,
+}
+
+/// Error type for the `probe_capabilities` method
+#[derive(Debug, thiserror::Error)]
+pub enum ProbeError {
+    /// The version request or a probe failed to complete
+    #[error("{0}")]
+    Request(#[from] reqwest::Error),
+    /// A probe request could not be signed
+    #[error("failed to sign probe request: {0}")]
+    Auth(String),
+}
+
+/// Whether a family exists, judging by the status of a `HEAD` on its
+/// collection
+pub(crate) fn probe_status_supported(status: reqwest::StatusCode) -> bool {
+    status != reqwest::StatusCode::NOT_FOUND
+}
+
+/// Whether `server` is at least version `client`
+///
+/// Versions compare numerically by dotted component, ignoring pre-release
+/// and build suffixes. A version that does not parse is treated as older,
+/// so that the server gets probed.
+pub(crate) fn version_at_least(server: &str, client: &str) -> bool {
+    match (parse_version(server), parse_version(client)) {
+        (Some(server), Some(client)) => server >= client,
+        _ => false,
+    }
+}
+
+fn parse_version(version: &str) -> Option<[u64; 3]> {
+    let core = version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = [0; 3];
+    let mut components = core.split('.');
+    for part in &mut parts {
+        match components.next() {
+            Some(component) => *part = component.parse().ok()?,
+            None => break,
+        }
+    }
+    if components.next().is_some() {
+        return None;
+    }
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_comparison() {
+        assert!(version_at_least("9.20.0", "9.20.0"));
+        assert!(version_at_least("9.21.3", "9.20.0"));
+        assert!(version_at_least("10.0.0", "9.20.0"));
+        assert!(version_at_least("9.20", "9.20.0"));
+        assert!(version_at_least("v9.20.1-pre", "9.20.0"));
+        assert!(!version_at_least("9.3.0", "9.20.0"));
+        assert!(!version_at_least("8.99.99", "9.20.0"));
+        assert!(!version_at_least("unknown", "9.20.0"));
+        assert!(!version_at_least("9.20.0.1", "9.20.0"));
+    }
+
+    #[test]
+    fn probe_status_mapping() {
+        use reqwest::StatusCode;
+
+        assert!(!probe_status_supported(StatusCode::NOT_FOUND));
+        for status in [
+            StatusCode::OK,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::FORBIDDEN,
+            StatusCode::METHOD_NOT_ALLOWED,
+            StatusCode::NOT_IMPLEMENTED,
+        ] {
+            assert!(probe_status_supported(status), "{status}");
+        }
+    }
+
+    #[test]
+    fn require_unsupported_family() {
+        let caps = ServerCapabilities::without(
+            "9.3.0".to_string(),
+            vec!["7.3.0".to_string()],
+            [EndpointFamily::Volumes],
+        );
+        assert!(caps.supports(EndpointFamily::Fabrics));
+        assert!(caps.require(EndpointFamily::Fabrics).is_ok());
+
+        let err = caps.require(EndpointFamily::Volumes).unwrap_err();
+        assert_eq!(err.family, EndpointFamily::Volumes);
+        assert_eq!(
+            err.to_string(),
+            "volume endpoints are not supported by CloudAPI 9.3.0"
+        );
+        assert_eq!(
+            caps.unsupported().collect::<Vec<_>>(),
+            vec![EndpointFamily::Volumes]
+        );
+    }
+}
@@ -55,8 +55,145 @@
 //! // Access underlying client for other operations
 //! let account = client.inner().get_account().account("myaccount").send().await?;
 //! ```
+//!
+//! ### Testing Without Credentials
+//!
+//! Integration tests can point a client at `cloudapi-stub-server`, which does
+//! not check signatures, without any key at all:
+//!
+//! ```ignore
+//! let client = TypedClient::new_unauthenticated("http://127.0.0.1:9091", "test");
+//! let packages = client.list_packages("test").await?;
+//! ```
+//!
+//! Tests that need signed requests can inject a [`Signer`] in place of a
+//! key. [`FixedSigner`] signs every request the same way:
+//!
+//! ```ignore
+//! let signer = Arc::new(FixedSigner::new("test", "aa:bb:cc:dd"));
+//! let client = TypedClient::new_with_signer(
+//!     "http://127.0.0.1:9091",
+//!     AuthConfig::new("test", KeySource::None),
+//!     signer,
+//! );
+//! ```
+//!
+//! ### Proxies, Private CAs and Timeouts
+//!
+//! [`ClientBuilder`] configures the underlying HTTP client for networks
+//! that need it:
+//!
+//! ```ignore
+//! let client = ClientBuilder::new("https://cloudapi.example.com", auth_config)
+//!     .proxy("http://proxy.corp.example.com:3128")
+//!     .no_proxy(".internal.example.com")
+//!     .ca_bundle_file("/etc/pki/corp-root.pem")
+//!     .min_tls_version(TlsVersion::TLS_1_2)
+//!     .connect_timeout(Duration::from_secs(10))
+//!     .build_typed()?;
+//! ```
+//!
+//! ### Response Caching
+//!
+//! Packages and images rarely change. Long-lived tooling that looks them up
+//! repeatedly can enable a read-through cache on the typed lookups:
+//!
+//! ```ignore
+//! let client = TypedClient::new("https://cloudapi.example.com", auth_config)
+//!     .with_response_cache(Duration::from_secs(300));
+//!
+//! let package = client.get_package("myaccount", "g1.small").await?; // fetched
+//! let package = client.get_package("myaccount", "g1.small").await?; // cached
+//!
+//! // Drop stale entries after changes made elsewhere
+//! if let Some(cache) = client.response_cache() {
+//!     cache.clear();
+//! }
+//! ```
+//!
+//! ### Resolving Names
+//!
+//! Images and packages can be looked up by name rather than UUID. The
+//! listings behind these lookups go through the response cache when it is
+//! enabled:
+//!
+//! ```ignore
+//! let image = client.resolve_image("myaccount", "ubuntu-22.04", None).await?;
+//! let image = client
+//!     .resolve_image("myaccount", "ubuntu-22.04", Some("20240101"))
+//!     .await?;
+//! let package = client.resolve_package("myaccount", "g4-highcpu-1G").await?;
+//! ```
+//!
+//! ### Checking Provisioning Limits
+//!
+//! Whether a package and image would fit within the account's provisioning
+//! limits can be checked before asking CloudAPI to provision:
+//!
+//! ```ignore
+//! let exceeded = client
+//!     .exceeded_provisioning_limits("myaccount", "g4-highcpu-1G", Some(&image))
+//!     .await?;
+//! if !exceeded.is_empty() {
+//!     eprintln!("would exceed {} provisioning limit(s)", exceeded.len());
+//! }
+//! ```
+//!
+//! ### Capability Probing
+//!
+//! Older CloudAPI servers lack some endpoint families (volumes, fabrics,
+//! access keys, migrations). After probing, the typed helpers for a family
+//! the server does not have fail with [`UnsupportedByServer`] instead of an
+//! opaque 404:
+//!
+//! ```ignore
+//! let caps = client.probe_capabilities().await?;
+//! if !caps.supports(EndpointFamily::Volumes) {
+//!     eprintln!("CloudAPI {} has no volumes", caps.server_version);
+//! }
+//!
+//! match client.list_volumes("myaccount").await {
+//!     Err(VolumeError::UnsupportedByServer(e)) => eprintln!("{e}"),
+//!     other => { /* ... */ }
+//! }
+//! ```
+//!
+//! See [`capabilities`] for how families are detected.
+//!
+//! ### Exporting Machines
+//!
+//! Large inventories can be exported as CSV or NDJSON straight to a file,
+//! without holding the whole list in memory:
+//!
+//! ```ignore
+//! let mut file = tokio::fs::File::create("machines.csv").await?;
+//! client
+//!     .export_machines(
+//!         "myaccount",
+//!         types::MachineExportFormat::Csv,
+//!         &[MachineColumn::Id, MachineColumn::Name, MachineColumn::State],
+//!         &ListMachinesFilter::default(),
+//!         &mut file,
+//!     )
+//!     .await?;
+//! ```
+//!
+//! ### Request Logging
+//!
+//! Every request and response is logged at `debug` level through `tracing`
+//! (target `cloudapi_client::redact`). Credentials are masked before they
+//! reach the log: the `Authorization` signature, `X-Auth-Token`, cookies and
+//! signature or token query parameters; see [`redact`].
 
 pub mod auth;
+pub mod cache;
+pub mod capabilities;
+pub mod client_builder;
+pub mod redact;
+
+pub use cache::ResponseCache;
+pub use capabilities::{EndpointFamily, ProbeError, ServerCapabilities, UnsupportedByServer};
+pub use client_builder::{ClientBuildError, ClientBuilder, TlsVersion};
 
 /// Re-export of the shared limit/offset pagination helper.
 ///
@@ -76,10 +213,17 @@ use std::sync::OnceLock;
 #[cfg(debug_assertions)]
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use futures_util::TryStreamExt;
 use progenitor_client::{ClientHooks, OperationInfo};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+pub use auth::ClientAuth;
 
 // Re-export triton-auth types for convenience
-pub use triton_auth::{AuthConfig, AuthError, KeySource};
+pub use triton_auth::{AuthConfig, AuthError, FixedSigner, KeySource, Signer};
 
 // Re-export action-dispatch request structs from the Progenitor-generated
 // types module. These structs land in `types::*` because openapi-manager
@@ -111,7 +255,12 @@ pub use cloudapi_api::{
     // Network types
     AddNicRequest,
     AffinityRules,
+    // Health types
+    ApiVersions,
     // Machine types
+    AuditAction,
+    AuditCaller,
+    AuditCallerType,
     AuditEntry,
     // User types
     ChangePasswordRequest,
@@ -161,24 +310,32 @@ pub use cloudapi_api::{
     ImageState,
     ImageType,
     KeyPath,
+    LimitBy,
+    LimitCheck,
     ListImagesQuery,
     ListMachinesQuery,
     Machine,
     MachineAction,
     MachineActionQuery,
+    MachineColumn,
     MachineNic,
     MachinePath,
     MachineState,
+    MachineSummary,
     // Common types
     Metadata,
     MetadataKeyPath,
     MigrateRequest,
     Migration,
     MigrationAction,
+    MigrationActionQuery,
+    MigrationEndEvent,
     MigrationEstimate,
     MigrationEstimateRequest,
     MigrationPhase,
+    MigrationProgressEvent,
     MigrationState,
+    MigrationWatchEvent,
     MountMode,
     Network,
     NetworkIds,
@@ -186,15 +343,18 @@ pub use cloudapi_api::{
     NetworkIpPath,
     NetworkObject,
     NetworkPath,
+    NetworkPool,
     Nic,
     NicPath,
     NicState,
     Package,
     PackagePath,
+    PingResponse,
     Policy,
     PolicyPath,
     PolicyRef,
     PolicyRules,
+    ProvisionCandidate,
     ProvisioningLimit,
     ProvisioningLimits,
     ReplaceRoleTagsRequest,
@@ -227,6 +387,7 @@ pub use cloudapi_api::{
     UserAccessKeyPath,
     UserPath,
     Uuid,
+    VersionResponse,
     // VMAPI types (re-exported through cloudapi-api)
     VmState,
     Volume,
@@ -561,9 +722,14 @@ fn fake_response_body(
                 subnet: None,
                 netmask: None,
                 vlan_id: None,
+                nic_tag: None,
+                mtu: None,
                 suffixes: None,
                 resolvers: None,
                 routes: None,
+                pool: None,
+                networks: None,
+                nic_tags_present: None,
                 role_tag: None,
             };
             serde_json::to_vec(&fake).expect("Network serialization should not fail")
@@ -657,14 +823,15 @@ fn fake_response_body(
 }
 
 // =============================================================================
-// ClientHooks: intercept create_machine to transform body to legacy format
+// ClientHooks: intercept create_machine to transform body to legacy format,
+// and log requests and responses with credentials redacted
 // =============================================================================
 
 /// Override the default (empty) `ClientHooks` impl on `&Client` via auto-ref
 /// specialization: the generated code calls `client.pre(...)` where `client`
 /// is `&Client`, so `&self` resolves to `&Client` (exact match on `Client`)
 /// before `&&Client` (auto-ref match on `&Client`).
-impl ClientHooks<triton_auth::AuthConfig> for Client {
+impl ClientHooks<ClientAuth> for Client {
     async fn pre<E>(
         &self,
         request: &mut reqwest::Request,
@@ -674,6 +841,9 @@ impl ClientHooks<triton_auth::AuthConfig> for Client {
             transform_create_machine_body(request);
         }
 
+        // Auth headers are already set here; see `redact` for what is masked
+        redact::log_request(request, info.operation_id);
+
         // Emit-payload mode: intercept mutations (POST/PUT/DELETE/PATCH) here.
         // GETs/HEADs pass through to the `exec` hook which returns fake responses.
         #[cfg(debug_assertions)]
@@ -689,6 +859,15 @@ impl ClientHooks<triton_auth::AuthConfig> for Client {
         Ok(())
     }
 
+    async fn post<E>(
+        &self,
+        result: &reqwest::Result<reqwest::Response>,
+        info: &OperationInfo,
+    ) -> std::result::Result<(), Error<E>> {
+        redact::log_response(result, info.operation_id);
+        Ok(())
+    }
+
     // Override exec to return fake responses for GETs in emit-payload mode
     #[cfg(debug_assertions)]
     async fn exec(
@@ -830,7 +1009,7 @@ fn transform_create_machine_body(request: &mut reqwest::Request) {
 /// the required Date and Authorization headers.
 pub struct AuthenticatedClient {
     inner: Client,
-    auth_config: AuthConfig,
+    auth: ClientAuth,
 }
 
 impl AuthenticatedClient {
@@ -838,11 +1017,25 @@ impl AuthenticatedClient {
     ///
     /// # Arguments
     /// * `base_url` - CloudAPI base URL (e.g., "https://cloudapi.example.com")
-    /// * `auth_config` - Authentication configuration
-    pub fn new(base_url: &str, auth_config: AuthConfig) -> Self {
+    /// * `auth` - Authentication configuration, optionally with a signer
+    ///   (see [`ClientAuth`])
+    pub fn new(base_url: &str, auth: impl Into<ClientAuth>) -> Self {
+        Self::new_with_http_client(base_url, auth, reqwest::Client::new())
+    }
+
+    /// Create a new authenticated client with a pre-built HTTP client
+    ///
+    /// See [`ClientBuilder`] for building one with proxy, TLS and timeout
+    /// settings.
+    pub fn new_with_http_client(
+        base_url: &str,
+        auth: impl Into<ClientAuth>,
+        http_client: reqwest::Client,
+    ) -> Self {
+        let auth = auth.into();
         Self {
-            inner: Client::new_with_client(base_url, reqwest::Client::new(), auth_config.clone()),
-            auth_config,
+            inner: Client::new_with_client(base_url, http_client, auth.clone()),
+            auth,
         }
     }
 
@@ -853,7 +1046,7 @@ impl AuthenticatedClient {
 
     /// Get the authentication configuration
     pub fn auth_config(&self) -> &AuthConfig {
-        &self.auth_config
+        self.auth.config()
     }
 }
 
@@ -882,6 +1075,32 @@ pub struct ListMachinesFilter {
     pub tag: Option<(String, String)>,
 }
 
+/// Safeguards for [`TypedClient::delete_machine_safe`]
+#[derive(Debug, Default, Clone)]
+pub struct DeleteMachineOptions {
+    /// Tags (key, value) the machine must have. Values are compared with
+    /// the tag's value as a string, so `("count", "3")` matches a numeric
+    /// tag of 3.
+    pub expected_tags: Vec<(String, String)>,
+    /// Disable deletion protection, if it is enabled, instead of refusing to
+    /// delete the machine
+    pub override_deletion_protection: bool,
+    /// Only check the safeguards; do not change or delete anything
+    pub dry_run: bool,
+}
+
+/// Result of [`TypedClient::delete_machine_safe`]
+#[derive(Debug, Clone)]
+pub struct SafeDeletion {
+    /// The machine, as it was when the safeguards were checked
+    pub machine: types::Machine,
+    /// Whether deletion protection had to be disabled (or, in a dry run,
+    /// would have to be) before deleting
+    pub disables_deletion_protection: bool,
+    /// Whether the machine was deleted (false for a dry run)
+    pub deleted: bool,
+}
+
 /// Typed client wrapper for endpoints requiring special handling
 ///
 /// This wrapper provides ergonomic methods for CloudAPI endpoints that need
@@ -890,11 +1109,21 @@ pub struct ListMachinesFilter {
 /// tag query encoding, error recovery, and multi-step operations like
 /// share/unshare.
 ///
-/// This client is authenticated and will automatically sign all requests.
+/// This client is authenticated and will automatically sign all requests,
+/// unless it was created with [`TypedClient::new_unauthenticated`].
+///
+/// Package and image lookups can optionally be served from a read-through
+/// [`ResponseCache`]; see [`TypedClient::with_response_cache`].
+///
+/// Helpers for endpoint families that older servers lack are checked
+/// against the server's capabilities once they have been probed; see
+/// [`TypedClient::probe_capabilities`].
 pub struct TypedClient {
     inner: Client,
-    auth_config: AuthConfig,
+    auth: ClientAuth,
     http_client: reqwest::Client,
+    cache: Option<ResponseCache>,
+    capabilities: RwLock<Option<ServerCapabilities>>,
 }
 
 impl TypedClient {
@@ -902,58 +1131,80 @@ impl TypedClient {
     ///
     /// # Arguments
     /// * `base_url` - CloudAPI base URL (e.g., "https://cloudapi.example.com")
-    /// * `auth_config` - Authentication configuration
-    pub fn new(base_url: &str, auth_config: AuthConfig) -> Self {
-        let http_client = reqwest::Client::new();
-        Self {
-            inner: Client::new_with_client(base_url, http_client.clone(), auth_config.clone()),
-            auth_config,
-            http_client,
-        }
+    /// * `auth` - Authentication configuration, optionally with a signer
+    ///   (see [`ClientAuth`])
+    pub fn new(base_url: &str, auth: impl Into<ClientAuth>) -> Self {
+        Self::new_with_http_client(base_url, auth, reqwest::Client::new())
+    }
+
+    /// Create a new typed client that signs requests with `signer`
+    ///
+    /// `auth_config` still supplies the account, roles and other request
+    /// settings, but not the key. Tests can pass a
+    /// [`FixedSigner`](triton_auth::FixedSigner) to run without one.
+    pub fn new_with_signer(
+        base_url: &str,
+        auth_config: AuthConfig,
+        signer: Arc<dyn Signer>,
+    ) -> Self {
+        Self::new(base_url, ClientAuth::with_signer(auth_config, signer))
+    }
+
+    /// Create a new typed client that does not sign requests
+    ///
+    /// For integration tests against an unauthenticated stub such as
+    /// `cloudapi-stub-server`; a real CloudAPI rejects unsigned requests.
+    /// Release builds log a warning when the first request is sent.
+    ///
+    /// # Arguments
+    /// * `base_url` - Stub server base URL (e.g., "http://127.0.0.1:9091")
+    /// * `account` - Account login name used in request paths
+    pub fn new_unauthenticated(base_url: &str, account: impl Into<String>) -> Self {
+        Self::new(base_url, AuthConfig::unauthenticated(account))
     }
 
     /// Create a new typed client with optional TLS certificate validation bypass
     ///
     /// # Arguments
     /// * `base_url` - CloudAPI base URL (e.g., "https://cloudapi.example.com")
-    /// * `auth_config` - Authentication configuration
+    /// * `auth` - Authentication configuration, optionally with a signer
     /// * `insecure` - If true, skip TLS certificate validation (use with caution)
     ///
     /// # Errors
     /// Returns an error if the HTTP client cannot be built.
     pub fn new_with_insecure(
         base_url: &str,
-        auth_config: AuthConfig,
+        auth: impl Into<ClientAuth>,
         insecure: bool,
     ) -> Result<Self, reqwest::Error> {
         let http_client = reqwest::Client::builder()
             .danger_accept_invalid_certs(insecure)
             .build()?;
-        Ok(Self {
-            inner: Client::new_with_client(base_url, http_client.clone(), auth_config.clone()),
-            auth_config,
-            http_client,
-        })
+        Ok(Self::new_with_http_client(base_url, auth, http_client))
     }
 
     /// Create a new typed client with a pre-built HTTP client
     ///
     /// This allows the caller to control how the `reqwest::Client` is built,
-    /// including custom TLS configuration or certificate loading.
+    /// including custom TLS configuration or certificate loading. For the
+    /// common proxy, CA and timeout settings, see [`ClientBuilder`].
     ///
     /// # Arguments
     /// * `base_url` - CloudAPI base URL (e.g., "https://cloudapi.example.com")
-    /// * `auth_config` - Authentication configuration
+    /// * `auth` - Authentication configuration, optionally with a signer
     /// * `http_client` - Pre-built reqwest HTTP client
     pub fn new_with_http_client(
         base_url: &str,
-        auth_config: AuthConfig,
+        auth: impl Into<ClientAuth>,
         http_client: reqwest::Client,
     ) -> Self {
+        let auth = auth.into();
         Self {
-            inner: Client::new_with_client(base_url, http_client.clone(), auth_config.clone()),
-            auth_config,
+            inner: Client::new_with_client(base_url, http_client.clone(), auth.clone()),
+            auth,
             http_client,
+            cache: None,
+            capabilities: RwLock::new(None),
         }
     }
 
@@ -964,7 +1215,7 @@ impl TypedClient {
 
     /// Get the authentication configuration
     pub fn auth_config(&self) -> &AuthConfig {
-        &self.auth_config
+        self.auth.config()
     }
 
     /// Return the account to use in URL paths.
@@ -972,7 +1223,7 @@ impl TypedClient {
     /// When `--act-as` is active, this returns the target account so that
     /// URL segments use `/:target_account/` instead of the signing account.
     pub fn effective_account(&self) -> &str {
-        self.auth_config.effective_account()
+        self.auth.config().effective_account()
     }
 
     /// Access the underlying reqwest HTTP client
@@ -989,6 +1240,357 @@ impl TypedClient {
         self.inner.baseurl()
     }
 
+    /// Enable the read-through response cache for packages and images
+    ///
+    /// Cached entries live for at most `ttl`, or less if CloudAPI sends a
+    /// shorter `Cache-Control: max-age`. Responses marked `no-store` or
+    /// `no-cache` are never cached.
+    pub fn with_response_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(ResponseCache::new(ttl));
+        self
+    }
+
+    /// Access the response cache, if enabled
+    ///
+    /// Use this to invalidate entries after changes made outside this
+    /// client (e.g. through [`TypedClient::inner`]).
+    pub fn response_cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+
+    /// The response cache to read through, if any
+    ///
+    /// Emit-payload mode bypasses the cache so that every lookup still
+    /// shows up in the emitted request log.
+    fn active_cache(&self) -> Option<&ResponseCache> {
+        #[cfg(debug_assertions)]
+        if is_emit_payload_mode() {
+            return None;
+        }
+        self.cache.as_ref()
+    }
+
+    /// Cache key for an account-scoped collection or resource
+    fn cache_url(&self, account: &str, path: &str) -> String {
+        format!(
+            "{}/{}/{path}",
+            self.baseurl(),
+            progenitor_client::encode_path(account)
+        )
+    }
+
+    /// Drop cached image lookups for an account after an image mutation
+    fn invalidate_cached_images(&self, account: &str) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate_prefix(&self.cache_url(account, "images"));
+        }
+    }
+
+    // ========================================================================
+    // Service Health
+    // ========================================================================
+
+    /// Ping CloudAPI through the unauthenticated `GET /--ping` endpoint
+    ///
+    /// The response lists the API versions the server supports.
+    pub async fn ping(&self) -> Result<PingResponse, reqwest::Error> {
+        self.get_health("--ping").await
+    }
+
+    /// Get the CloudAPI server version through `GET /--version`
+    pub async fn version(&self) -> Result<VersionResponse, reqwest::Error> {
+        self.get_health("--version").await
+    }
+
+    /// GET a health endpoint, which lives outside the `/{account}` namespace
+    /// and needs no request signing
+    async fn get_health<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<T, reqwest::Error> {
+        let url = format!("{}/{path}", self.baseurl().trim_end_matches('/'));
+        self.http_client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+
+    // ========================================================================
+    // Capabilities
+    // ========================================================================
+
+    /// Probe which optional endpoint families the server supports
+    ///
+    /// Servers reporting a version at least [`cloudapi_api::API_VERSION`]
+    /// support every family. Older servers get one `HEAD` request per
+    /// family. The result is kept, and from then on the typed helpers for
+    /// an unsupported family fail with [`UnsupportedByServer`].
+    pub async fn probe_capabilities(&self) -> Result<ServerCapabilities, ProbeError> {
+        let version = self.version().await?;
+        let capabilities =
+            if capabilities::version_at_least(&version.version, cloudapi_api::API_VERSION) {
+                ServerCapabilities::all(version.version, version.versions)
+            } else {
+                let mut unsupported = Vec::new();
+                for family in EndpointFamily::ALL {
+                    if !self.probe_family(family).await? {
+                        unsupported.push(family);
+                    }
+                }
+                ServerCapabilities::without(version.version, version.versions, unsupported)
+            };
+        *self
+            .capabilities
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// The capabilities found by the last probe, if any
+    pub fn capabilities(&self) -> Option<ServerCapabilities> {
+        self.capabilities
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Whether the server has a family's endpoints, judging by a signed
+    /// `HEAD` on its collection
+    async fn probe_family(&self, family: EndpointFamily) -> Result<bool, ProbeError> {
+        let url = format!(
+            "{}/{}/{}",
+            self.baseurl().trim_end_matches('/'),
+            progenitor_client::encode_path(self.effective_account()),
+            family.probe_path()
+        );
+        let mut request = self.http_client.head(url).build()?;
+        auth::add_auth_headers(&self.auth, &mut request)
+            .await
+            .map_err(|e| ProbeError::Auth(e.to_string()))?;
+        let response = self.http_client.execute(request).await?;
+        Ok(capabilities::probe_status_supported(response.status()))
+    }
+
+    /// Fail if a probe found the server lacks the family
+    ///
+    /// Without a probe every family is assumed to be supported.
+    fn require(&self, family: EndpointFamily) -> Result<(), UnsupportedByServer> {
+        match &*self
+            .capabilities
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+        {
+            Some(capabilities) => capabilities.require(family),
+            None => Ok(()),
+        }
+    }
+
+    // ========================================================================
+    // Cached Lookups (packages, images)
+    // ========================================================================
+
+    /// List packages, reading through the response cache if enabled
+    pub async fn list_packages(
+        &self,
+        account: &str,
+    ) -> Result<Vec<types::Package>, Error<types::Error>> {
+        let url = self.cache_url(account, "packages");
+        if let Some(packages) = self.active_cache().and_then(|c| c.get(&url)) {
+            return Ok(packages);
+        }
+        let rv = self.inner.list_packages().account(account).send().await?;
+        if let Some(cache) = self.active_cache() {
+            cache.insert(&url, &*rv, rv.headers());
+        }
+        Ok(rv.into_inner())
+    }
+
+    /// Get a package by name or UUID, reading through the response cache if
+    /// enabled
+    pub async fn get_package(
+        &self,
+        account: &str,
+        package: &str,
+    ) -> Result<types::Package, Error<types::Error>> {
+        let url = self.cache_url(
+            account,
+            &format!("packages/{}", progenitor_client::encode_path(package)),
+        );
+        if let Some(package) = self.active_cache().and_then(|c| c.get(&url)) {
+            return Ok(package);
+        }
+        let rv = self
+            .inner
+            .get_package()
+            .account(account)
+            .package(package)
+            .send()
+            .await?;
+        if let Some(cache) = self.active_cache() {
+            cache.insert(&url, &*rv, rv.headers());
+        }
+        Ok(rv.into_inner())
+    }
+
+    /// List images, reading through the response cache if enabled
+    pub async fn list_images(
+        &self,
+        account: &str,
+    ) -> Result<Vec<types::Image>, Error<types::Error>> {
+        let url = self.cache_url(account, "images");
+        if let Some(images) = self.active_cache().and_then(|c| c.get(&url)) {
+            return Ok(images);
+        }
+        let rv = self.inner.list_images().account(account).send().await?;
+        if let Some(cache) = self.active_cache() {
+            cache.insert(&url, &*rv, rv.headers());
+        }
+        Ok(rv.into_inner())
+    }
+
+    /// Get an image by UUID, reading through the response cache if enabled
+    pub async fn get_image(
+        &self,
+        account: &str,
+        dataset: &Uuid,
+    ) -> Result<types::Image, Error<types::Error>> {
+        let url = self.cache_url(account, &format!("images/{dataset}"));
+        if let Some(image) = self.active_cache().and_then(|c| c.get(&url)) {
+            return Ok(image);
+        }
+        let rv = self
+            .inner
+            .get_image()
+            .account(account)
+            .dataset(*dataset)
+            .send()
+            .await?;
+        if let Some(cache) = self.active_cache() {
+            cache.insert(&url, &*rv, rv.headers());
+        }
+        Ok(rv.into_inner())
+    }
+
+    // ========================================================================
+    // Name Resolution (images, packages)
+    // ========================================================================
+
+    /// Resolve an image name, short ID or UUID to the image's UUID
+    ///
+    /// A UUID is checked to exist with [`TypedClient::get_image`], and
+    /// `version` is ignored. Otherwise the account's images are listed with
+    /// [`TypedClient::list_images`] and matched by name, and by `version`
+    /// when given. Without a version, the most recently published image of
+    /// that name wins. If no name matches, `name_or_uuid` is tried as the
+    /// leading part of an image UUID.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::NotFound`] if no image matches, and
+    /// [`ResolveError::Ambiguous`] if a name and version, or a short ID,
+    /// match more than one image.
+    pub async fn resolve_image(
+        &self,
+        account: &str,
+        name_or_uuid: &str,
+        version: Option<&str>,
+    ) -> Result<Uuid, ResolveError> {
+        if let Ok(id) = Uuid::parse_str(name_or_uuid) {
+            return match self.get_image(account, &id).await {
+                Ok(image) => Ok(image.id),
+                Err(Error::ErrorResponse(rv)) if rv.status() == reqwest::StatusCode::NOT_FOUND => {
+                    Err(ResolveError::NotFound {
+                        kind: "image",
+                        name: name_or_uuid.to_string(),
+                    })
+                }
+                Err(e) => Err(ResolveError::Client(e.to_string())),
+            };
+        }
+
+        let images = self
+            .list_images(account)
+            .await
+            .map_err(|e| ResolveError::Client(e.to_string()))?;
+        match_image(&images, name_or_uuid, version)
+    }
+
+    /// Resolve a package name, short ID or UUID to the package's UUID
+    ///
+    /// A UUID is checked to exist with [`TypedClient::get_package`].
+    /// Otherwise the account's packages are listed with
+    /// [`TypedClient::list_packages`] and matched by name, then as the
+    /// leading part of a package UUID.
+    ///
+    /// # Errors
+    /// Returns [`ResolveError::NotFound`] if no package matches, and
+    /// [`ResolveError::Ambiguous`] if the name or short ID matches more than
+    /// one package.
+    pub async fn resolve_package(
+        &self,
+        account: &str,
+        name_or_uuid: &str,
+    ) -> Result<Uuid, ResolveError> {
+        if let Ok(id) = Uuid::parse_str(name_or_uuid) {
+            return match self.get_package(account, &id.to_string()).await {
+                Ok(package) => Ok(package.id),
+                Err(Error::ErrorResponse(rv)) if rv.status() == reqwest::StatusCode::NOT_FOUND => {
+                    Err(ResolveError::NotFound {
+                        kind: "package",
+                        name: name_or_uuid.to_string(),
+                    })
+                }
+                Err(e) => Err(ResolveError::Client(e.to_string())),
+            };
+        }
+
+        let packages = self
+            .list_packages(account)
+            .await
+            .map_err(|e| ResolveError::Client(e.to_string()))?;
+        match_package(&packages, name_or_uuid)
+    }
+
+    // ========================================================================
+    // Provisioning Limits
+    // ========================================================================
+
+    /// Check locally whether a package, and optionally an image, can be
+    /// provisioned within the account's limits
+    ///
+    /// Fetches the account's provisioning limits along with the package and
+    /// image (through the response cache if enabled), and returns the limits
+    /// the provision would go over; an empty list means it fits. See
+    /// [`ProvisioningLimits::exceeded_by`] for the caveats of a local check.
+    pub async fn exceeded_provisioning_limits(
+        &self,
+        account: &str,
+        package: &str,
+        image: Option<&Uuid>,
+    ) -> Result<Vec<ProvisioningLimit>, Error<types::Error>> {
+        let limits = self
+            .inner
+            .get_provisioning_limits()
+            .account(account)
+            .send()
+            .await?
+            .into_inner();
+        let package = self.get_package(account, package).await?;
+        let image = match image {
+            Some(id) => Some(self.get_image(account, id).await?),
+            None => None,
+        };
+
+        let candidate = provision_candidate(&package, image.as_ref());
+        Ok(limits
+            .exceeded_by(&candidate)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
     // ========================================================================
     // Machine Creation (body transformation handled by ClientHooks pre-hook)
     // ========================================================================
@@ -1072,6 +1674,69 @@ impl TypedClient {
         builder.send().await.map(|r| r.into_inner())
     }
 
+    /// Export machines as CSV or NDJSON, writing the response to `out` as it
+    /// arrives
+    ///
+    /// Only one chunk of the response is held in memory at a time, so this
+    /// suits accounts with more machines than a `list_machines` response
+    /// comfortably holds. An empty `columns` exports the server's default
+    /// columns. The filter's `offset` and `limit` are ignored, as an export
+    /// covers every matching machine.
+    ///
+    /// Returns the number of bytes written. `out` is flushed, but not shut
+    /// down.
+    pub async fn export_machines<W>(
+        &self,
+        account: &str,
+        format: types::MachineExportFormat,
+        columns: &[MachineColumn],
+        filter: &ListMachinesFilter,
+        out: &mut W,
+    ) -> Result<u64, ExportError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut builder = self.inner.export_machines().account(account).format(format);
+
+        if !columns.is_empty() {
+            let names: Vec<&str> = columns.iter().map(|c| c.name()).collect();
+            builder = builder.columns(names.join(","));
+        }
+        if let Some(name) = &filter.name {
+            builder = builder.name(name.clone());
+        }
+        if let Some(state) = &filter.state {
+            builder = builder.state(*state);
+        }
+        if let Some(image) = &filter.image {
+            builder = builder.image(*image);
+        }
+        if let Some(memory) = filter.memory {
+            builder = builder.memory(memory);
+        }
+        if let Some(machine_type) = &filter.machine_type {
+            builder = builder.type_(*machine_type);
+        }
+        if let Some(brand) = &filter.brand {
+            builder = builder.brand(*brand);
+        }
+        if let Some(docker) = filter.docker {
+            builder = builder.docker(docker);
+        }
+        if let Some((key, value)) = &filter.tag {
+            builder = builder.tag(format!("{key}={value}"));
+        }
+
+        let mut stream = builder.send().await?.into_inner().into_inner();
+        let mut written = 0u64;
+        while let Some(chunk) = stream.try_next().await.map_err(ExportError::Read)? {
+            out.write_all(&chunk).await.map_err(ExportError::Write)?;
+            written += chunk.len() as u64;
+        }
+        out.flush().await.map_err(ExportError::Write)?;
+        Ok(written)
+    }
+
     // ========================================================================
     // Machine Retrieval (with 410 handling)
     // ========================================================================
@@ -1373,7 +2038,106 @@ impl TypedClient {
     }
 
     // ========================================================================
-    // Image Actions
+    // Machine Deletion (with safeguards)
+    // ========================================================================
+
+    /// Delete a machine only if it is the one the caller expects
+    ///
+    /// Fetches the machine and refuses to delete it unless its name is
+    /// `expected_name`, it has every tag in `options.expected_tags`, and
+    /// deletion protection is disabled. With
+    /// `options.override_deletion_protection`, protection is disabled first
+    /// instead (waiting for that to take effect). With `options.dry_run`,
+    /// nothing is changed and the result describes what would be deleted.
+    ///
+    /// # Arguments
+    /// * `account` - Account login name
+    /// * `machine` - Machine UUID
+    /// * `expected_name` - Name (alias) the machine must have
+    /// * `options` - Further safeguards, override and dry run
+    pub async fn delete_machine_safe(
+        &self,
+        account: &str,
+        machine: &Uuid,
+        expected_name: &str,
+        options: &DeleteMachineOptions,
+    ) -> Result<SafeDeletion, DeleteMachineError> {
+        let current = self
+            .get_machine(account, machine)
+            .await
+            .map_err(|e| match e {
+                GetMachineError::NotFound => DeleteMachineError::NotFound { machine: *machine },
+                e => DeleteMachineError::Client(e.to_string()),
+            })?;
+        let disables_deletion_protection = check_deletion(&current, expected_name, options)?;
+
+        if options.dry_run {
+            return Ok(SafeDeletion {
+                machine: current,
+                disables_deletion_protection,
+                deleted: false,
+            });
+        }
+
+        if disables_deletion_protection {
+            self.disable_deletion_protection(
+                account,
+                machine,
+                &DisableDeletionProtectionRequest::default(),
+            )
+            .await
+            .map_err(|e| DeleteMachineError::Client(e.to_string()))?;
+            self.wait_for_deletion_protection_disabled(account, machine)
+                .await?;
+        }
+
+        self.inner
+            .delete_machine()
+            .account(account)
+            .machine(*machine)
+            .send()
+            .await
+            .map_err(|e| DeleteMachineError::Client(e.to_string()))?;
+
+        Ok(SafeDeletion {
+            machine: current,
+            disables_deletion_protection,
+            deleted: true,
+        })
+    }
+
+    /// Poll a machine until deletion protection is reported disabled
+    ///
+    /// Disabling protection runs as a job, and CloudAPI refuses to delete a
+    /// machine until it has finished.
+    async fn wait_for_deletion_protection_disabled(
+        &self,
+        account: &str,
+        machine: &Uuid,
+    ) -> Result<(), DeleteMachineError> {
+        let start = Instant::now();
+
+        loop {
+            let current = self
+                .get_machine(account, machine)
+                .await
+                .map_err(|e| DeleteMachineError::Client(e.to_string()))?;
+            if current.deletion_protection != Some(true) {
+                return Ok(());
+            }
+            if start.elapsed() >= DELETION_PROTECTION_WAIT_TIMEOUT {
+                return Err(DeleteMachineError::Timeout {
+                    machine: *machine,
+                    timeout: DELETION_PROTECTION_WAIT_TIMEOUT,
+                });
+            }
+
+            tokio::time::sleep(DELETION_PROTECTION_POLL_INTERVAL).await;
+        }
+    }
+
+    // ========================================================================
+    // Image Actions
     // ========================================================================
 
     /// Update image metadata
@@ -1391,7 +2155,8 @@ impl TypedClient {
         dataset: &Uuid,
         request: &UpdateImageRequest,
     ) -> Result<types::Image, Error<types::Error>> {
-        self.inner
+        let result = self
+            .inner
             .update_image()
             .account(account)
             .dataset(dataset.to_string())
@@ -1399,7 +2164,9 @@ impl TypedClient {
             .body(to_json_value(request))
             .send()
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        self.invalidate_cached_images(account);
+        result
     }
 
     /// Export image to Manta
@@ -1421,14 +2188,17 @@ impl TypedClient {
             action: ImageAction::Export,
             body: request,
         };
-        self.inner
+        let result = self
+            .inner
             .update_image()
             .account(account)
             .dataset(dataset.to_string())
             .body(to_json_value(&body))
             .send()
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        self.invalidate_cached_images(account);
+        result
     }
 
     /// Clone image to account
@@ -1444,7 +2214,8 @@ impl TypedClient {
         account: &str,
         dataset: &Uuid,
     ) -> Result<types::Image, Error<types::Error>> {
-        self.inner
+        let result = self
+            .inner
             .update_image()
             .account(account)
             .dataset(dataset.to_string())
@@ -1452,7 +2223,9 @@ impl TypedClient {
             .body(serde_json::json!({}))
             .send()
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        self.invalidate_cached_images(account);
+        result
     }
 
     /// Import image from another datacenter
@@ -1470,7 +2243,8 @@ impl TypedClient {
         datacenter: &str,
         id: Uuid,
     ) -> Result<types::Image, Error<types::Error>> {
-        self.inner
+        let result = self
+            .inner
             .create_or_import_image()
             .account(account)
             .action(types::ImageAction::ImportFromDatacenter)
@@ -1479,7 +2253,9 @@ impl TypedClient {
             .body(serde_json::json!({}))
             .send()
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        self.invalidate_cached_images(account);
+        result
     }
 
     /// Share image with another account
@@ -1565,11 +2341,8 @@ impl TypedClient {
     // ========================================================================
 
     /// Get a volume by UUID
-    pub async fn get_volume(
-        &self,
-        account: &str,
-        id: &str,
-    ) -> Result<types::Volume, Error<types::Error>> {
+    pub async fn get_volume(&self, account: &str, id: &str) -> Result<types::Volume, VolumeError> {
+        self.require(EndpointFamily::Volumes)?;
         Ok(self
             .inner
             .get_volume()
@@ -1581,10 +2354,8 @@ impl TypedClient {
     }
 
     /// List volumes
-    pub async fn list_volumes(
-        &self,
-        account: &str,
-    ) -> Result<Vec<types::Volume>, Error<types::Error>> {
+    pub async fn list_volumes(&self, account: &str) -> Result<Vec<types::Volume>, VolumeError> {
+        self.require(EndpointFamily::Volumes)?;
         Ok(self
             .inner
             .list_volumes()
@@ -1599,7 +2370,8 @@ impl TypedClient {
         &self,
         account: &str,
         request: types::CreateVolumeRequest,
-    ) -> Result<types::Volume, Error<types::Error>> {
+    ) -> Result<types::Volume, VolumeError> {
+        self.require(EndpointFamily::Volumes)?;
         Ok(self
             .inner
             .create_volume()
@@ -1621,19 +2393,21 @@ impl TypedClient {
         account: &str,
         id: &Uuid,
         request: &UpdateVolumeRequest,
-    ) -> Result<types::Volume, Error<types::Error>> {
+    ) -> Result<types::Volume, VolumeError> {
+        self.require(EndpointFamily::Volumes)?;
         let body = ActionBody {
             action: VolumeAction::Update,
             body: request,
         };
-        self.inner
+        Ok(self
+            .inner
             .update_volume()
             .account(account)
             .id(id.to_string())
             .body(to_json_value(&body))
             .send()
-            .await
-            .map(|r| r.into_inner())
+            .await?
+            .into_inner())
     }
 
     // ========================================================================
@@ -1668,6 +2442,397 @@ impl TypedClient {
             .await
             .map(|r| r.into_inner())
     }
+
+    // ========================================================================
+    // Disk Lifecycle (wait for the disk job to complete)
+    // ========================================================================
+
+    /// Add a disk to a flexible-disk bhyve machine and wait until it is ready
+    ///
+    /// CloudAPI returns the new disk in the `creating` state while a job
+    /// provisions it. This polls `get_machine_disk` until the disk is
+    /// `running`.
+    ///
+    /// # Arguments
+    /// * `account` - Account login name
+    /// * `machine` - Machine UUID
+    /// * `request` - Disk creation request
+    /// * `timeout` - How long to wait for the disk job
+    ///   (see [`DEFAULT_DISK_WAIT_TIMEOUT`])
+    pub async fn add_disk_and_wait(
+        &self,
+        account: &str,
+        machine: &Uuid,
+        request: &types::CreateDiskRequest,
+        timeout: Duration,
+    ) -> Result<types::Disk, DiskWaitError> {
+        let disk = self
+            .inner
+            .create_machine_disk()
+            .account(account)
+            .machine(*machine)
+            .body(request.clone())
+            .send()
+            .await
+            .map_err(|e| DiskWaitError::Client(e.to_string()))?
+            .into_inner();
+
+        self.wait_for_disk(account, machine, &disk.id, timeout, |disk| {
+            disk.is_some_and(disk_settled)
+        })
+        .await?
+        .ok_or(DiskWaitError::NotFound { disk: disk.id })
+    }
+
+    /// Resize a machine disk and wait until the new size is reflected
+    ///
+    /// Polls `get_machine_disk` until the disk reports the requested size and
+    /// is no longer `resizing`.
+    ///
+    /// # Arguments
+    /// * `account` - Account login name
+    /// * `machine` - Machine UUID
+    /// * `disk` - Disk UUID
+    /// * `request` - Disk resize request with new size
+    /// * `timeout` - How long to wait for the disk job
+    ///   (see [`DEFAULT_DISK_WAIT_TIMEOUT`])
+    pub async fn resize_disk_and_wait(
+        &self,
+        account: &str,
+        machine: &Uuid,
+        disk: &Uuid,
+        request: &ResizeDiskRequest,
+        timeout: Duration,
+    ) -> Result<types::Disk, DiskWaitError> {
+        self.resize_disk(account, machine, disk, request)
+            .await
+            .map_err(|e| DiskWaitError::Client(e.to_string()))?;
+
+        self.wait_for_disk(account, machine, disk, timeout, |current| {
+            current.is_some_and(|d| d.size == request.size && disk_settled(d))
+        })
+        .await?
+        .ok_or(DiskWaitError::NotFound { disk: *disk })
+    }
+
+    /// Delete a machine disk and wait until it is gone
+    ///
+    /// Polls `get_machine_disk` until the disk is no longer found or reports
+    /// the `deleted` state.
+    ///
+    /// # Arguments
+    /// * `account` - Account login name
+    /// * `machine` - Machine UUID
+    /// * `disk` - Disk UUID
+    /// * `timeout` - How long to wait for the disk job
+    ///   (see [`DEFAULT_DISK_WAIT_TIMEOUT`])
+    pub async fn delete_disk_and_wait(
+        &self,
+        account: &str,
+        machine: &Uuid,
+        disk: &Uuid,
+        timeout: Duration,
+    ) -> Result<(), DiskWaitError> {
+        self.inner
+            .delete_machine_disk()
+            .account(account)
+            .machine(*machine)
+            .disk(*disk)
+            .send()
+            .await
+            .map_err(|e| DiskWaitError::Client(e.to_string()))?;
+
+        self.wait_for_disk(account, machine, disk, timeout, |current| {
+            current.is_none_or(|d| d.state == Some(types::DiskState::Deleted))
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Poll a disk until `done` accepts it
+    ///
+    /// `done` is given `None` once the disk is no longer found. Returns the
+    /// last disk seen. Fails early if the disk enters the `failed` state, or
+    /// disappears while `done` still expects it.
+    async fn wait_for_disk<F>(
+        &self,
+        account: &str,
+        machine: &Uuid,
+        disk: &Uuid,
+        timeout: Duration,
+        done: F,
+    ) -> Result<Option<types::Disk>, DiskWaitError>
+    where
+        F: Fn(Option<&types::Disk>) -> bool,
+    {
+        let start = Instant::now();
+
+        loop {
+            let current = match self
+                .inner
+                .get_machine_disk()
+                .account(account)
+                .machine(*machine)
+                .disk(*disk)
+                .send()
+                .await
+            {
+                Ok(rv) => Some(rv.into_inner()),
+                Err(Error::ErrorResponse(rv)) if rv.status() == reqwest::StatusCode::NOT_FOUND => {
+                    None
+                }
+                Err(e) => return Err(DiskWaitError::Client(e.to_string())),
+            };
+
+            if done(current.as_ref()) {
+                return Ok(current);
+            }
+
+            let Some(current) = current else {
+                return Err(DiskWaitError::NotFound { disk: *disk });
+            };
+            if current.state == Some(types::DiskState::Failed) {
+                return Err(DiskWaitError::Failed { disk: *disk });
+            }
+            if start.elapsed() >= timeout {
+                return Err(DiskWaitError::Timeout {
+                    disk: *disk,
+                    timeout,
+                    state: current.state,
+                });
+            }
+
+            tokio::time::sleep(DISK_POLL_INTERVAL).await;
+        }
+    }
+}
+
+// =============================================================================
+// Disk lifecycle helpers
+// =============================================================================
+
+/// Default timeout for the `*_disk_and_wait` methods, matching the
+/// `--wait-timeout` default of `triton instance disk add`.
+pub const DEFAULT_DISK_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Delay between `get_machine_disk` polls.
+const DISK_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// True once no disk job is running against `disk`. Disks from CloudAPI
+/// versions that do not report a state are treated as settled.
+fn disk_settled(disk: &types::Disk) -> bool {
+    matches!(disk.state, None | Some(types::DiskState::Running))
+}
+
+// =============================================================================
+// Machine deletion helpers
+// =============================================================================
+
+/// How long `delete_machine_safe` waits for deletion protection to be
+/// disabled before giving up.
+const DELETION_PROTECTION_WAIT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Delay between `get_machine` polls while deletion protection is disabled.
+const DELETION_PROTECTION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Check `machine` against the safeguards of `delete_machine_safe`
+///
+/// Returns whether deletion protection has to be disabled first.
+fn check_deletion(
+    machine: &types::Machine,
+    expected_name: &str,
+    options: &DeleteMachineOptions,
+) -> Result<bool, DeleteMachineError> {
+    if machine.state == types::MachineState::Deleted {
+        return Err(DeleteMachineError::AlreadyDeleted {
+            machine: machine.id,
+        });
+    }
+
+    if machine.name != expected_name {
+        return Err(DeleteMachineError::NameMismatch {
+            machine: machine.id,
+            expected: expected_name.to_string(),
+            actual: machine.name.clone(),
+        });
+    }
+
+    for (key, expected) in &options.expected_tags {
+        let actual = machine.tags.get(key).map(|value| match value {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        });
+        if actual.as_deref() != Some(expected.as_str()) {
+            return Err(DeleteMachineError::TagMismatch {
+                machine: machine.id,
+                key: key.clone(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+    }
+
+    let protected = machine.deletion_protection == Some(true);
+    if protected && !options.override_deletion_protection {
+        return Err(DeleteMachineError::DeletionProtected {
+            machine: machine.id,
+        });
+    }
+    Ok(protected)
+}
+
+// =============================================================================
+// Name resolution helpers
+// =============================================================================
+
+/// Pick the image named `name` (and `version`, if given) from `images`,
+/// falling back to a short ID match
+fn match_image(
+    images: &[types::Image],
+    name: &str,
+    version: Option<&str>,
+) -> Result<Uuid, ResolveError> {
+    let named: Vec<&types::Image> = images
+        .iter()
+        .filter(|image| image.name == name && version.is_none_or(|v| image.version == v))
+        .collect();
+
+    if let Some(version) = version {
+        return match named.as_slice() {
+            [] => Err(ResolveError::NotFound {
+                kind: "image",
+                name: format!("{name}@{version}"),
+            }),
+            [image] => Ok(image.id),
+            _ => Err(ResolveError::Ambiguous {
+                kind: "image",
+                name: format!("{name}@{version}"),
+                matches: named.iter().map(|image| image.id).collect(),
+            }),
+        };
+    }
+
+    // Successive versions of an image share its name
+    if let Some(latest) = named.iter().max_by_key(|image| image.published_at) {
+        return Ok(latest.id);
+    }
+
+    match_short_id("image", name, images.iter().map(|image| image.id))
+}
+
+/// Pick the package named `name` from `packages`, falling back to a short ID
+/// match
+fn match_package(packages: &[types::Package], name: &str) -> Result<Uuid, ResolveError> {
+    let named: Vec<Uuid> = packages
+        .iter()
+        .filter(|package| package.name == name)
+        .map(|package| package.id)
+        .collect();
+
+    match named.as_slice() {
+        [] => match_short_id("package", name, packages.iter().map(|package| package.id)),
+        [id] => Ok(*id),
+        _ => Err(ResolveError::Ambiguous {
+            kind: "package",
+            name: name.to_string(),
+            matches: named,
+        }),
+    }
+}
+
+/// Find the single UUID in `ids` that starts with `prefix`
+fn match_short_id(
+    kind: &'static str,
+    prefix: &str,
+    ids: impl Iterator<Item = Uuid>,
+) -> Result<Uuid, ResolveError> {
+    let lowered = prefix.to_ascii_lowercase();
+    let matches: Vec<Uuid> = if lowered.is_empty() {
+        Vec::new()
+    } else {
+        ids.filter(|id| id.to_string().starts_with(&lowered))
+            .collect()
+    };
+
+    match matches.as_slice() {
+        [] => Err(ResolveError::NotFound {
+            kind,
+            name: prefix.to_string(),
+        }),
+        [id] => Ok(*id),
+        _ => Err(ResolveError::Ambiguous {
+            kind,
+            name: prefix.to_string(),
+            matches,
+        }),
+    }
+}
+
+// =============================================================================
+// Provisioning limit helpers
+// =============================================================================
+
+/// Describe provisioning `package`, with `image` if known, for evaluation
+/// against [`ProvisioningLimits`]
+///
+/// The brand is the package's, falling back to the brand the image
+/// requires. Without an image, limits filtered by image or OS never match.
+pub fn provision_candidate(
+    package: &types::Package,
+    image: Option<&types::Image>,
+) -> ProvisionCandidate {
+    let brand = package
+        .brand
+        .or_else(|| image.and_then(|image| image.requirements.brand));
+
+    ProvisionCandidate {
+        brand: brand.map(|brand| brand.to_string()),
+        image: image.map(|image| image.name.clone()),
+        os: image.map(|image| image.os.clone()),
+        ram_mib: package.memory,
+        disk_mib: package.disk,
+    }
+}
+
+// =============================================================================
+// Audit log helpers
+// =============================================================================
+
+impl types::AuditEntry {
+    /// The action as an [`AuditAction`], `Unknown` for unrecognized names
+    pub fn action_kind(&self) -> AuditAction {
+        AuditAction::from(self.action.as_str())
+    }
+
+    /// Whether the action is recorded as having succeeded
+    pub fn succeeded(&self) -> bool {
+        self.success == Some(types::AuditSuccess::Yes)
+    }
+
+    /// How the caller authenticated, if recorded
+    pub fn caller_type(&self) -> Option<types::AuditCallerType> {
+        self.caller.as_ref().and_then(|caller| caller.type_)
+    }
+
+    /// Client IP address the action came from, if recorded
+    pub fn caller_ip(&self) -> Option<&str> {
+        self.caller.as_ref().and_then(|caller| caller.ip.as_deref())
+    }
+
+    /// Signing key used for the action (`/<login>/keys/<fingerprint>`), if
+    /// recorded
+    pub fn key_id(&self) -> Option<&str> {
+        self.caller
+            .as_ref()
+            .and_then(|caller| caller.key_id.as_deref())
+    }
+
+    /// Fingerprint of the signing key, taken from the end of the key ID
+    pub fn key_fingerprint(&self) -> Option<&str> {
+        self.key_id()
+            .and_then(|key_id| key_id.rsplit_once("/keys/"))
+            .map(|(_, fingerprint)| fingerprint)
+    }
 }
 
 // =============================================================================
@@ -1695,6 +2860,111 @@ pub enum GetMachineError {
     NotFound,
 }
 
+/// Error type for the `delete_machine_safe` method
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteMachineError {
+    /// Progenitor client error (auth, transport, server errors)
+    #[error("{0}")]
+    Client(String),
+    /// The machine does not exist
+    #[error("machine {machine} not found")]
+    NotFound { machine: Uuid },
+    /// The machine has already been deleted
+    #[error("machine {machine} is already deleted")]
+    AlreadyDeleted { machine: Uuid },
+    /// The machine's name is not the expected one
+    #[error("machine {machine} is named \"{actual}\", not \"{expected}\"")]
+    NameMismatch {
+        machine: Uuid,
+        expected: String,
+        actual: String,
+    },
+    /// A tag is missing or has another value
+    #[error(
+        "machine {machine} tag \"{key}\" is {}, not \"{expected}\"",
+        actual.as_ref().map_or_else(|| "not set".to_string(), |v| format!("\"{v}\""))
+    )]
+    TagMismatch {
+        machine: Uuid,
+        key: String,
+        expected: String,
+        /// The tag's value, as a string (None if the tag is not set)
+        actual: Option<String>,
+    },
+    /// Deletion protection is enabled and was not overridden
+    #[error("machine {machine} has deletion protection enabled")]
+    DeletionProtected { machine: Uuid },
+    /// Deletion protection was not disabled in time
+    #[error("timed out after {}s waiting for deletion protection on machine {machine} to be disabled", timeout.as_secs())]
+    Timeout { machine: Uuid, timeout: Duration },
+}
+
+/// Error type for the `*_disk_and_wait` methods
+#[derive(Debug, thiserror::Error)]
+pub enum DiskWaitError {
+    /// Progenitor client error (auth, transport, server errors)
+    #[error("{0}")]
+    Client(String),
+    /// The disk job failed
+    #[error("disk {disk} entered failed state")]
+    Failed { disk: Uuid },
+    /// The disk disappeared while waiting for it to become ready
+    #[error("disk {disk} not found")]
+    NotFound { disk: Uuid },
+    /// The disk job did not complete in time
+    #[error("timed out after {}s waiting for disk {disk}", timeout.as_secs())]
+    Timeout {
+        disk: Uuid,
+        timeout: Duration,
+        /// Last state reported for the disk
+        state: Option<types::DiskState>,
+    },
+}
+
+/// Error type for the `resolve_*` methods
+#[derive(Debug, thiserror::Error)]
+pub enum ResolveError {
+    /// Progenitor client error (auth, transport, server errors)
+    #[error("{0}")]
+    Client(String),
+    /// No resource has the given name, short ID or UUID
+    #[error("no {kind} found matching \"{name}\"")]
+    NotFound { kind: &'static str, name: String },
+    /// More than one resource matches the given name or short ID
+    #[error("{kind} \"{name}\" is ambiguous: {} matches", matches.len())]
+    Ambiguous {
+        kind: &'static str,
+        name: String,
+        /// UUIDs of all matching resources
+        matches: Vec<Uuid>,
+    },
+}
+
+/// Error type for [`TypedClient::export_machines`]
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// Progenitor client error (auth, transport, server errors)
+    #[error("{0}")]
+    Client(#[from] Error<ByteStream>),
+    /// The response failed part way through
+    #[error("failed to read machine export: {0}")]
+    Read(#[source] reqwest::Error),
+    /// The export could not be written out
+    #[error("failed to write machine export: {0}")]
+    Write(#[source] std::io::Error),
+}
+
+/// Error type for the volume methods
+#[derive(Debug, thiserror::Error)]
+pub enum VolumeError {
+    /// The server has no volume endpoints
+    #[error(transparent)]
+    UnsupportedByServer(#[from] UnsupportedByServer),
+    /// Progenitor client error (auth, transport, server errors)
+    #[error("{0}")]
+    Client(#[from] Error<types::Error>),
+}
+
 // =============================================================================
 // Serialization helper
 // =============================================================================
@@ -1723,6 +2993,273 @@ struct ActionBody<A: serde::Serialize, B: serde::Serialize> {
 mod tests {
     use super::*;
 
+    fn disk_with_state(state: Option<types::DiskState>) -> types::Disk {
+        types::Disk {
+            id: Uuid::nil(),
+            size: 10240,
+            block_size: None,
+            pci_slot: None,
+            boot: Some(false),
+            state,
+        }
+    }
+
+    #[test]
+    fn disk_settled_states() {
+        assert!(disk_settled(&disk_with_state(None)));
+        assert!(disk_settled(&disk_with_state(Some(
+            types::DiskState::Running
+        ))));
+        for state in [
+            types::DiskState::Creating,
+            types::DiskState::Resizing,
+            types::DiskState::Failed,
+            types::DiskState::Deleted,
+        ] {
+            assert!(!disk_settled(&disk_with_state(Some(state))), "{state}");
+        }
+    }
+
+    fn machine(name: &str, deletion_protection: Option<bool>) -> types::Machine {
+        serde_json::from_value(serde_json::json!({
+            "id": "aaaaaaaa-bbbb-cccc-dddd-eeeeeeeeeeee",
+            "name": name,
+            "type": "smartmachine",
+            "brand": "joyent",
+            "state": "running",
+            "image": "2f1dc911-6401-4fa4-8e9d-67ea2e39c271",
+            "memory": 1024,
+            "disk": 25600,
+            "metadata": {},
+            "tags": {"role": "web", "replicas": 3},
+            "created": "2026-01-01T00:00:00.000Z",
+            "updated": "2026-01-01T00:00:00.000Z",
+            "ips": [],
+            "networks": [],
+            "package": "g4-highcpu-1G",
+            "deletion_protection": deletion_protection
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn check_deletion_safeguards() {
+        let options = DeleteMachineOptions {
+            expected_tags: vec![
+                ("role".to_string(), "web".to_string()),
+                ("replicas".to_string(), "3".to_string()),
+            ],
+            ..Default::default()
+        };
+
+        assert!(!check_deletion(&machine("web0", None), "web0", &options).unwrap());
+        assert!(!check_deletion(&machine("web0", Some(false)), "web0", &options).unwrap());
+
+        assert!(matches!(
+            check_deletion(&machine("web1", None), "web0", &options),
+            Err(DeleteMachineError::NameMismatch { actual, .. }) if actual == "web1"
+        ));
+
+        let mut wrong_tag = options.clone();
+        wrong_tag
+            .expected_tags
+            .push(("env".to_string(), "prod".to_string()));
+        let err = check_deletion(&machine("web0", None), "web0", &wrong_tag).unwrap_err();
+        assert!(matches!(
+            &err,
+            DeleteMachineError::TagMismatch { key, actual: None, .. } if key == "env"
+        ));
+        assert!(err.to_string().contains("\"env\" is not set"));
+
+        // Protection refuses unless overridden
+        assert!(matches!(
+            check_deletion(&machine("web0", Some(true)), "web0", &options),
+            Err(DeleteMachineError::DeletionProtected { .. })
+        ));
+        let override_protection = DeleteMachineOptions {
+            override_deletion_protection: true,
+            ..options.clone()
+        };
+        assert!(
+            check_deletion(&machine("web0", Some(true)), "web0", &override_protection).unwrap()
+        );
+
+        let mut deleted = machine("web0", None);
+        deleted.state = types::MachineState::Deleted;
+        assert!(matches!(
+            check_deletion(&deleted, "web0", &options),
+            Err(DeleteMachineError::AlreadyDeleted { .. })
+        ));
+    }
+
+    #[test]
+    fn audit_entry_accessors() {
+        let entry: types::AuditEntry = serde_json::from_value(serde_json::json!({
+            "action": "rollback_snapshot",
+            "success": "yes",
+            "time": "2026-03-02T18:04:11.221Z",
+            "caller": {
+                "type": "signature",
+                "ip": "203.0.113.7",
+                "keyId": "/admin/keys/aa:bb:cc:dd"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(entry.action_kind(), AuditAction::RollbackSnapshot);
+        assert!(entry.succeeded());
+        assert_eq!(entry.caller_type(), Some(types::AuditCallerType::Signature));
+        assert_eq!(entry.caller_ip(), Some("203.0.113.7"));
+        assert_eq!(entry.key_id(), Some("/admin/keys/aa:bb:cc:dd"));
+        assert_eq!(entry.key_fingerprint(), Some("aa:bb:cc:dd"));
+
+        let entry: types::AuditEntry = serde_json::from_value(serde_json::json!({
+            "action": "something_new",
+            "time": "2026-03-02T18:04:11.221Z"
+        }))
+        .unwrap();
+        assert_eq!(entry.action_kind(), AuditAction::Unknown);
+        assert!(!entry.succeeded());
+        assert_eq!(entry.caller_ip(), None);
+        assert_eq!(entry.key_fingerprint(), None);
+    }
+
+    fn image(id: &str, name: &str, version: &str, published_at: &str) -> types::Image {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "version": version,
+            "os": "linux",
+            "type": "zone-dataset",
+            "published_at": published_at
+        }))
+        .unwrap()
+    }
+
+    fn package(id: &str, name: &str) -> types::Package {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "memory": 1024,
+            "disk": 25600,
+            "swap": 4096
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn match_image_by_name_and_version() {
+        let images = [
+            image(
+                "2f1dc911-6401-4fa4-8e9d-67ea2e39c271",
+                "ubuntu-22.04",
+                "20240101",
+                "2024-01-01T00:00:00Z",
+            ),
+            image(
+                "2f1dc911-9c2e-4c3b-a5c4-0b8d4a6c1a02",
+                "ubuntu-22.04",
+                "20240601",
+                "2024-06-01T00:00:00Z",
+            ),
+            image(
+                "63d6e664-3f1f-11e8-aef6-a3120cf8dd9d",
+                "base-64-lts",
+                "20240601",
+                "2024-06-01T00:00:00Z",
+            ),
+        ];
+
+        // The latest version wins unless one is asked for
+        assert_eq!(
+            match_image(&images, "ubuntu-22.04", None).unwrap(),
+            images[1].id
+        );
+        assert_eq!(
+            match_image(&images, "ubuntu-22.04", Some("20240101")).unwrap(),
+            images[0].id
+        );
+        assert!(matches!(
+            match_image(&images, "ubuntu-22.04", Some("19700101")),
+            Err(ResolveError::NotFound { .. })
+        ));
+
+        // Short IDs
+        assert_eq!(
+            match_image(&images, "63d6e664", None).unwrap(),
+            images[2].id
+        );
+        match match_image(&images, "2f1dc911", None) {
+            Err(ResolveError::Ambiguous { matches, .. }) => assert_eq!(matches.len(), 2),
+            other => panic!("expected ambiguous short id, got {other:?}"),
+        }
+        assert!(matches!(
+            match_image(&images, "centos-7", None),
+            Err(ResolveError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn match_package_by_name() {
+        let packages = [
+            package("7b17343c-94af-6266-e0e8-893a3b9993d0", "g4-highcpu-1G"),
+            package("7b17343c-1b2c-4d5e-8f90-a1b2c3d4e5f6", "g4-highcpu-2G"),
+            package("0f4d8f3a-2b3c-4f5e-9a1b-2c3d4e5f6a7b", "shared"),
+            package("1a2b3c4d-2b3c-4f5e-9a1b-2c3d4e5f6a7b", "shared"),
+        ];
+
+        assert_eq!(
+            match_package(&packages, "g4-highcpu-1G").unwrap(),
+            packages[0].id
+        );
+        assert_eq!(
+            match_package(&packages, "0F4D8F3A").unwrap(),
+            packages[2].id
+        );
+        assert!(matches!(
+            match_package(&packages, "shared"),
+            Err(ResolveError::Ambiguous { .. })
+        ));
+        assert!(matches!(
+            match_package(&packages, "7b17343c"),
+            Err(ResolveError::Ambiguous { .. })
+        ));
+        assert!(matches!(
+            match_package(&packages, ""),
+            Err(ResolveError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn provision_candidate_from_package_and_image() {
+        let package = package("7b17343c-94af-6266-e0e8-893a3b9993d0", "g4-highcpu-1G");
+        let mut image = image(
+            "63d6e664-3f1f-11e8-aef6-a3120cf8dd9d",
+            "ubuntu-22.04",
+            "20240601",
+            "2024-06-01T00:00:00Z",
+        );
+        image.requirements.brand = Some(types::VmBrand::Bhyve);
+
+        let candidate = provision_candidate(&package, Some(&image));
+        assert_eq!(candidate.brand.as_deref(), Some("bhyve"));
+        assert_eq!(candidate.image.as_deref(), Some("ubuntu-22.04"));
+        assert_eq!(candidate.os.as_deref(), Some("linux"));
+        assert_eq!(candidate.ram_mib, 1024);
+        assert_eq!(candidate.disk_mib, 25600);
+
+        let candidate = provision_candidate(&package, None);
+        assert_eq!(candidate.brand, None);
+        assert_eq!(candidate.image, None);
+
+        let limits: ProvisioningLimits = serde_json::from_value(serde_json::json!([
+            {"value": 1, "used": 1, "check": "brand", "brand": "bhyve"}
+        ]))
+        .unwrap();
+        assert!(!limits.allow(&provision_candidate(&package, Some(&image))));
+        assert!(limits.allow(&provision_candidate(&package, None)));
+    }
+
     /// Validate that the shared emit-payload fixture file deserializes into the
     /// expected Rust types. This catches drift between the fixture JSON and the
     /// API type definitions at `cargo test` time.
@@ -0,0 +1,2148 @@
+// Generated by client-generator from openapi-specs/patched/fwapi-api.json. Do not edit.
+
+#[allow(unused_imports)]
+pub use progenitor_client::{ByteStream, ClientInfo, Error, ResponseValue};
+#[allow(unused_imports)]
+use progenitor_client::{ClientHooks, OperationInfo, RequestBuilderExt, encode_path};
+#[doc = r" Types used as operation parameters and responses."]
+#[allow(clippy::all)]
+pub mod types {
+    #[doc = r" Error types."]
+    pub mod error {
+        #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+        pub struct ConversionError(::std::borrow::Cow<'static, str>);
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+                ::std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(value.into())
+            }
+        }
+    }
+
+    #[doc = "Request body for creating a rule."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Request body for creating a rule.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"rule\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"description\": {"]
+    #[doc = "      \"description\": \"Human-readable description.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"enabled\": {"]
+    #[doc = "      \"description\": \"Whether the rule is enabled (defaults to false).\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"global\": {"]
+    #[doc = "      \"description\": \"Create a global rule applying to VMs of all accounts.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"log\": {"]
+    #[doc = "      \"description\": \"Whether to log TCP connections matching the rule (defaults to false).\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"owner_uuid\": {"]
+    #[doc = "      \"description\": \"Account that owns the rule (required unless `global` is set).\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"rule\": {"]
+    #[doc = "      \"description\": \"Rule text in the fwrule language.\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"uuid\": {"]
+    #[doc = "      \"description\": \"UUID for the new rule (generated if absent).\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct CreateRuleRequest {
+        #[doc = "Human-readable description."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub description: ::std::option::Option<::std::string::String>,
+        #[doc = "Whether the rule is enabled (defaults to false)."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub enabled: ::std::option::Option<bool>,
+        #[doc = "Create a global rule applying to VMs of all accounts."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub global: ::std::option::Option<bool>,
+        #[doc = "Whether to log TCP connections matching the rule (defaults to false)."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub log: ::std::option::Option<bool>,
+        #[doc = "Account that owns the rule (required unless `global` is set)."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub owner_uuid: ::std::option::Option<::uuid::Uuid>,
+        #[doc = "Rule text in the fwrule language."]
+        pub rule: ::std::string::String,
+        #[doc = "UUID for the new rule (generated if absent)."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub uuid: ::std::option::Option<::uuid::Uuid>,
+    }
+
+    impl CreateRuleRequest {
+        pub fn builder() -> builder::CreateRuleRequest {
+            Default::default()
+        }
+    }
+
+    #[doc = "Error response from a Node.js Triton service"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Error response from a Node.js Triton service\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"code\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"code\": {"]
+    #[doc = "      \"description\": \"Error code (e.g., \\\"ResourceNotFound\\\", \\\"InvalidArgument\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"message\": {"]
+    #[doc = "      \"description\": \"Human-readable error message\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"request_id\": {"]
+    #[doc = "      \"description\": \"Request ID for tracing (optional, not always present)\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct Error {
+        #[doc = "Error code (e.g., \"ResourceNotFound\", \"InvalidArgument\")"]
+        pub code: ::std::string::String,
+        #[doc = "Human-readable error message"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub message: ::std::option::Option<::std::string::String>,
+        #[doc = "Request ID for tracing (optional, not always present)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub request_id: ::std::option::Option<::std::string::String>,
+    }
+
+    impl Error {
+        pub fn builder() -> builder::Error {
+            Default::default()
+        }
+    }
+
+    #[doc = "A firewall rule.\n\n`rule` is the rule text in the fwrule language, for example `FROM any TO tag \"www\" ALLOW tcp PORT 80`. FWAPI parses and validates it; clients pass it through as a string."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"A firewall rule.\\n\\n`rule` is the rule text in the fwrule language, for example `FROM any TO tag \\\"www\\\" ALLOW tcp PORT 80`. FWAPI parses and validates it; clients pass it through as a string.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"enabled\","]
+    #[doc = "    \"rule\","]
+    #[doc = "    \"uuid\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"created_by\": {"]
+    #[doc = "      \"description\": \"Service or user that created the rule (e.g. \\\"fwadm\\\").\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"description\": {"]
+    #[doc = "      \"description\": \"Human-readable description.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"enabled\": {"]
+    #[doc = "      \"description\": \"Whether the rule is enabled.\","]
+    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    },"]
+    #[doc = "    \"global\": {"]
+    #[doc = "      \"description\": \"Whether the rule applies to VMs of all accounts.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"log\": {"]
+    #[doc = "      \"default\": false,"]
+    #[doc = "      \"description\": \"Whether TCP connections matching the rule are logged.\","]
+    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    },"]
+    #[doc = "    \"owner_uuid\": {"]
+    #[doc = "      \"description\": \"Account that owns the rule (absent for global rules).\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"rule\": {"]
+    #[doc = "      \"description\": \"Rule text in the fwrule language.\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"uuid\": {"]
+    #[doc = "      \"description\": \"Rule UUID.\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"version\": {"]
+    #[doc = "      \"description\": \"Rule version, changed on every update.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct FirewallRule {
+        #[doc = "Service or user that created the rule (e.g. \"fwadm\")."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub created_by: ::std::option::Option<::std::string::String>,
+        #[doc = "Human-readable description."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub description: ::std::option::Option<::std::string::String>,
+        #[doc = "Whether the rule is enabled."]
+        pub enabled: bool,
+        #[doc = "Whether the rule applies to VMs of all accounts."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub global: ::std::option::Option<bool>,
+        #[doc = "Whether TCP connections matching the rule are logged."]
+        #[serde(default)]
+        pub log: bool,
+        #[doc = "Account that owns the rule (absent for global rules)."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub owner_uuid: ::std::option::Option<::uuid::Uuid>,
+        #[doc = "Rule text in the fwrule language."]
+        pub rule: ::std::string::String,
+        #[doc = "Rule UUID."]
+        pub uuid: ::uuid::Uuid,
+        #[doc = "Rule version, changed on every update."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub version: ::std::option::Option<::std::string::String>,
+    }
+
+    impl FirewallRule {
+        pub fn builder() -> builder::FirewallRule {
+            Default::default()
+        }
+    }
+
+    #[doc = "Response from the /ping health check endpoint."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Response from the /ping health check endpoint.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"healthy\","]
+    #[doc = "    \"status\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"healthy\": {"]
+    #[doc = "      \"description\": \"Whether FWAPI and all of its backend services are healthy.\","]
+    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    },"]
+    #[doc = "    \"services\": {"]
+    #[doc = "      \"additionalProperties\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      },"]
+    #[doc = "      \"description\": \"Status of each backend service (e.g. \\\"moray\\\": \\\"online\\\").\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"object\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"status\": {"]
+    #[doc = "      \"description\": \"Overall status (\\\"OK\\\" when healthy).\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct PingResponse {
+        #[doc = "Whether FWAPI and all of its backend services are healthy."]
+        pub healthy: bool,
+        #[doc = "Status of each backend service (e.g. \"moray\": \"online\")."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub services: ::std::option::Option<
+            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        >,
+        #[doc = "Overall status (\"OK\" when healthy)."]
+        pub status: ::std::string::String,
+    }
+
+    impl PingResponse {
+        pub fn builder() -> builder::PingResponse {
+            Default::default()
+        }
+    }
+
+    #[doc = "Request body for resolving the rules that apply to a set of VMs and tags."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Request body for resolving the rules that apply to a set of VMs and tags.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"owner_uuid\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"owner_uuid\": {"]
+    #[doc = "      \"description\": \"Account whose rules to resolve.\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"tags\": {"]
+    #[doc = "      \"additionalProperties\": {},"]
+    #[doc = "      \"default\": {},"]
+    #[doc = "      \"description\": \"Tags to resolve rules for, mapped to a value or `true` for any value.\","]
+    #[doc = "      \"type\": \"object\""]
+    #[doc = "    },"]
+    #[doc = "    \"vms\": {"]
+    #[doc = "      \"default\": [],"]
+    #[doc = "      \"description\": \"VMs to resolve rules for.\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"format\": \"uuid\","]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      },"]
+    #[doc = "      \"type\": \"array\""]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct ResolveRequest {
+        #[doc = "Account whose rules to resolve."]
+        pub owner_uuid: ::uuid::Uuid,
+        #[doc = "Tags to resolve rules for, mapped to a value or `true` for any value."]
+        #[serde(default, skip_serializing_if = "::serde_json::Map::is_empty")]
+        pub tags: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        #[doc = "VMs to resolve rules for."]
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub vms: ::std::vec::Vec<::uuid::Uuid>,
+    }
+
+    impl ResolveRequest {
+        pub fn builder() -> builder::ResolveRequest {
+            Default::default()
+        }
+    }
+
+    #[doc = "The rules that apply to a set of VMs and tags, and what they refer to."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"The rules that apply to a set of VMs and tags, and what they refer to.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"allVMs\","]
+    #[doc = "    \"owner_uuid\","]
+    #[doc = "    \"rules\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"allVMs\": {"]
+    #[doc = "      \"description\": \"Whether any rule refers to \\\"all vms\\\", so every VM of the account is affected.\","]
+    #[doc = "      \"type\": \"boolean\""]
+    #[doc = "    },"]
+    #[doc = "    \"owner_uuid\": {"]
+    #[doc = "      \"description\": \"Account the rules were resolved for.\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"rules\": {"]
+    #[doc = "      \"description\": \"Rules that apply.\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/FirewallRule\""]
+    #[doc = "      },"]
+    #[doc = "      \"type\": \"array\""]
+    #[doc = "    },"]
+    #[doc = "    \"tags\": {"]
+    #[doc = "      \"additionalProperties\": {},"]
+    #[doc = "      \"default\": {},"]
+    #[doc = "      \"description\": \"Tags the rules refer to, mapped to the values they match (or `true` for any value).\","]
+    #[doc = "      \"type\": \"object\""]
+    #[doc = "    },"]
+    #[doc = "    \"vms\": {"]
+    #[doc = "      \"default\": [],"]
+    #[doc = "      \"description\": \"VMs the rules refer to.\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"format\": \"uuid\","]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      },"]
+    #[doc = "      \"type\": \"array\""]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct ResolveResponse {
+        #[doc = "Whether any rule refers to \"all vms\", so every VM of the account is affected."]
+        #[serde(rename = "allVMs")]
+        pub all_v_ms: bool,
+        #[doc = "Account the rules were resolved for."]
+        pub owner_uuid: ::uuid::Uuid,
+        #[doc = "Rules that apply."]
+        pub rules: ::std::vec::Vec<FirewallRule>,
+        #[doc = "Tags the rules refer to, mapped to the values they match (or `true` for any value)."]
+        #[serde(default, skip_serializing_if = "::serde_json::Map::is_empty")]
+        pub tags: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        #[doc = "VMs the rules refer to."]
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub vms: ::std::vec::Vec<::uuid::Uuid>,
+    }
+
+    impl ResolveResponse {
+        pub fn builder() -> builder::ResolveResponse {
+            Default::default()
+        }
+    }
+
+    #[doc = "Request body for updating a rule. Absent fields are left unchanged."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Request body for updating a rule. Absent fields are left unchanged.\","]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"description\": {"]
+    #[doc = "      \"description\": \"Human-readable description.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"enabled\": {"]
+    #[doc = "      \"description\": \"Whether the rule is enabled.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"log\": {"]
+    #[doc = "      \"description\": \"Whether to log TCP connections matching the rule.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"owner_uuid\": {"]
+    #[doc = "      \"description\": \"Account that owns the rule; must match the existing owner.\","]
+    #[doc = "      \"format\": \"uuid\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"rule\": {"]
+    #[doc = "      \"description\": \"Rule text in the fwrule language.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  },"]
+    #[doc = "  \"type\": \"object\""]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct UpdateRuleRequest {
+        #[doc = "Human-readable description."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub description: ::std::option::Option<::std::string::String>,
+        #[doc = "Whether the rule is enabled."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub enabled: ::std::option::Option<bool>,
+        #[doc = "Whether to log TCP connections matching the rule."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub log: ::std::option::Option<bool>,
+        #[doc = "Account that owns the rule; must match the existing owner."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub owner_uuid: ::std::option::Option<::uuid::Uuid>,
+        #[doc = "Rule text in the fwrule language."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub rule: ::std::option::Option<::std::string::String>,
+    }
+
+    impl ::std::default::Default for UpdateRuleRequest {
+        fn default() -> Self {
+            Self {
+                description: Default::default(),
+                enabled: Default::default(),
+                log: Default::default(),
+                owner_uuid: Default::default(),
+                rule: Default::default(),
+            }
+        }
+    }
+
+    impl UpdateRuleRequest {
+        pub fn builder() -> builder::UpdateRuleRequest {
+            Default::default()
+        }
+    }
+
+    #[doc = r" Types for composing complex structures."]
+    pub mod builder {
+        #[derive(Clone, Debug)]
+        pub struct CreateRuleRequest {
+            description: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            enabled: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            global: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            log: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            owner_uuid:
+                ::std::result::Result<::std::option::Option<::uuid::Uuid>, ::std::string::String>,
+            rule: ::std::result::Result<::std::string::String, ::std::string::String>,
+            uuid: ::std::result::Result<::std::option::Option<::uuid::Uuid>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for CreateRuleRequest {
+            fn default() -> Self {
+                Self {
+                    description: Ok(Default::default()),
+                    enabled: Ok(Default::default()),
+                    global: Ok(Default::default()),
+                    log: Ok(Default::default()),
+                    owner_uuid: Ok(Default::default()),
+                    rule: Err("no value supplied for rule".to_string()),
+                    uuid: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl CreateRuleRequest {
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {e}"));
+                self
+            }
+            pub fn enabled<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.enabled = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for enabled: {e}"));
+                self
+            }
+            pub fn global<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.global = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for global: {e}"));
+                self
+            }
+            pub fn log<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.log = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for log: {e}"));
+                self
+            }
+            pub fn owner_uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::uuid::Uuid>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.owner_uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for owner_uuid: {e}"));
+                self
+            }
+            pub fn rule<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.rule = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rule: {e}"));
+                self
+            }
+            pub fn uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::uuid::Uuid>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for uuid: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<CreateRuleRequest> for super::CreateRuleRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: CreateRuleRequest,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    description: value.description?,
+                    enabled: value.enabled?,
+                    global: value.global?,
+                    log: value.log?,
+                    owner_uuid: value.owner_uuid?,
+                    rule: value.rule?,
+                    uuid: value.uuid?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::CreateRuleRequest> for CreateRuleRequest {
+            fn from(value: super::CreateRuleRequest) -> Self {
+                Self {
+                    description: Ok(value.description),
+                    enabled: Ok(value.enabled),
+                    global: Ok(value.global),
+                    log: Ok(value.log),
+                    owner_uuid: Ok(value.owner_uuid),
+                    rule: Ok(value.rule),
+                    uuid: Ok(value.uuid),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Error {
+            code: ::std::result::Result<::std::string::String, ::std::string::String>,
+            message: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            request_id: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for Error {
+            fn default() -> Self {
+                Self {
+                    code: Err("no value supplied for code".to_string()),
+                    message: Ok(Default::default()),
+                    request_id: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl Error {
+            pub fn code<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.code = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for code: {e}"));
+                self
+            }
+            pub fn message<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {e}"));
+                self
+            }
+            pub fn request_id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.request_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for request_id: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<Error> for super::Error {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: Error,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    code: value.code?,
+                    message: value.message?,
+                    request_id: value.request_id?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::Error> for Error {
+            fn from(value: super::Error) -> Self {
+                Self {
+                    code: Ok(value.code),
+                    message: Ok(value.message),
+                    request_id: Ok(value.request_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct FirewallRule {
+            created_by: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            description: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            enabled: ::std::result::Result<bool, ::std::string::String>,
+            global: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            log: ::std::result::Result<bool, ::std::string::String>,
+            owner_uuid:
+                ::std::result::Result<::std::option::Option<::uuid::Uuid>, ::std::string::String>,
+            rule: ::std::result::Result<::std::string::String, ::std::string::String>,
+            uuid: ::std::result::Result<::uuid::Uuid, ::std::string::String>,
+            version: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for FirewallRule {
+            fn default() -> Self {
+                Self {
+                    created_by: Ok(Default::default()),
+                    description: Ok(Default::default()),
+                    enabled: Err("no value supplied for enabled".to_string()),
+                    global: Ok(Default::default()),
+                    log: Ok(Default::default()),
+                    owner_uuid: Ok(Default::default()),
+                    rule: Err("no value supplied for rule".to_string()),
+                    uuid: Err("no value supplied for uuid".to_string()),
+                    version: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl FirewallRule {
+            pub fn created_by<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created_by = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_by: {e}"));
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {e}"));
+                self
+            }
+            pub fn enabled<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.enabled = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for enabled: {e}"));
+                self
+            }
+            pub fn global<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.global = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for global: {e}"));
+                self
+            }
+            pub fn log<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.log = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for log: {e}"));
+                self
+            }
+            pub fn owner_uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::uuid::Uuid>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.owner_uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for owner_uuid: {e}"));
+                self
+            }
+            pub fn rule<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.rule = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rule: {e}"));
+                self
+            }
+            pub fn uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::uuid::Uuid>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for uuid: {e}"));
+                self
+            }
+            pub fn version<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.version = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for version: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<FirewallRule> for super::FirewallRule {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: FirewallRule,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_by: value.created_by?,
+                    description: value.description?,
+                    enabled: value.enabled?,
+                    global: value.global?,
+                    log: value.log?,
+                    owner_uuid: value.owner_uuid?,
+                    rule: value.rule?,
+                    uuid: value.uuid?,
+                    version: value.version?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::FirewallRule> for FirewallRule {
+            fn from(value: super::FirewallRule) -> Self {
+                Self {
+                    created_by: Ok(value.created_by),
+                    description: Ok(value.description),
+                    enabled: Ok(value.enabled),
+                    global: Ok(value.global),
+                    log: Ok(value.log),
+                    owner_uuid: Ok(value.owner_uuid),
+                    rule: Ok(value.rule),
+                    uuid: Ok(value.uuid),
+                    version: Ok(value.version),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct PingResponse {
+            healthy: ::std::result::Result<bool, ::std::string::String>,
+            services: ::std::result::Result<
+                ::std::option::Option<
+                    ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+                >,
+                ::std::string::String,
+            >,
+            status: ::std::result::Result<::std::string::String, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for PingResponse {
+            fn default() -> Self {
+                Self {
+                    healthy: Err("no value supplied for healthy".to_string()),
+                    services: Ok(Default::default()),
+                    status: Err("no value supplied for status".to_string()),
+                }
+            }
+        }
+
+        impl PingResponse {
+            pub fn healthy<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.healthy = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for healthy: {e}"));
+                self
+            }
+            pub fn services<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<
+                        ::std::option::Option<
+                            ::std::collections::HashMap<
+                                ::std::string::String,
+                                ::std::string::String,
+                            >,
+                        >,
+                    >,
+                T::Error: ::std::fmt::Display,
+            {
+                self.services = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for services: {e}"));
+                self
+            }
+            pub fn status<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.status = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for status: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<PingResponse> for super::PingResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: PingResponse,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    healthy: value.healthy?,
+                    services: value.services?,
+                    status: value.status?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::PingResponse> for PingResponse {
+            fn from(value: super::PingResponse) -> Self {
+                Self {
+                    healthy: Ok(value.healthy),
+                    services: Ok(value.services),
+                    status: Ok(value.status),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ResolveRequest {
+            owner_uuid: ::std::result::Result<::uuid::Uuid, ::std::string::String>,
+            tags: ::std::result::Result<
+                ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+                ::std::string::String,
+            >,
+            vms: ::std::result::Result<::std::vec::Vec<::uuid::Uuid>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for ResolveRequest {
+            fn default() -> Self {
+                Self {
+                    owner_uuid: Err("no value supplied for owner_uuid".to_string()),
+                    tags: Ok(Default::default()),
+                    vms: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl ResolveRequest {
+            pub fn owner_uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::uuid::Uuid>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.owner_uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for owner_uuid: {e}"));
+                self
+            }
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<
+                        ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+                    >,
+                T::Error: ::std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {e}"));
+                self
+            }
+            pub fn vms<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<::uuid::Uuid>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.vms = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for vms: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<ResolveRequest> for super::ResolveRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ResolveRequest,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    owner_uuid: value.owner_uuid?,
+                    tags: value.tags?,
+                    vms: value.vms?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::ResolveRequest> for ResolveRequest {
+            fn from(value: super::ResolveRequest) -> Self {
+                Self {
+                    owner_uuid: Ok(value.owner_uuid),
+                    tags: Ok(value.tags),
+                    vms: Ok(value.vms),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ResolveResponse {
+            all_v_ms: ::std::result::Result<bool, ::std::string::String>,
+            owner_uuid: ::std::result::Result<::uuid::Uuid, ::std::string::String>,
+            rules:
+                ::std::result::Result<::std::vec::Vec<super::FirewallRule>, ::std::string::String>,
+            tags: ::std::result::Result<
+                ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+                ::std::string::String,
+            >,
+            vms: ::std::result::Result<::std::vec::Vec<::uuid::Uuid>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for ResolveResponse {
+            fn default() -> Self {
+                Self {
+                    all_v_ms: Err("no value supplied for all_v_ms".to_string()),
+                    owner_uuid: Err("no value supplied for owner_uuid".to_string()),
+                    rules: Err("no value supplied for rules".to_string()),
+                    tags: Ok(Default::default()),
+                    vms: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl ResolveResponse {
+            pub fn all_v_ms<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<bool>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.all_v_ms = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for all_v_ms: {e}"));
+                self
+            }
+            pub fn owner_uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::uuid::Uuid>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.owner_uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for owner_uuid: {e}"));
+                self
+            }
+            pub fn rules<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::FirewallRule>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.rules = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rules: {e}"));
+                self
+            }
+            pub fn tags<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<
+                        ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+                    >,
+                T::Error: ::std::fmt::Display,
+            {
+                self.tags = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for tags: {e}"));
+                self
+            }
+            pub fn vms<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<::uuid::Uuid>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.vms = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for vms: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<ResolveResponse> for super::ResolveResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ResolveResponse,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    all_v_ms: value.all_v_ms?,
+                    owner_uuid: value.owner_uuid?,
+                    rules: value.rules?,
+                    tags: value.tags?,
+                    vms: value.vms?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::ResolveResponse> for ResolveResponse {
+            fn from(value: super::ResolveResponse) -> Self {
+                Self {
+                    all_v_ms: Ok(value.all_v_ms),
+                    owner_uuid: Ok(value.owner_uuid),
+                    rules: Ok(value.rules),
+                    tags: Ok(value.tags),
+                    vms: Ok(value.vms),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct UpdateRuleRequest {
+            description: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            enabled: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            log: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            owner_uuid:
+                ::std::result::Result<::std::option::Option<::uuid::Uuid>, ::std::string::String>,
+            rule: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for UpdateRuleRequest {
+            fn default() -> Self {
+                Self {
+                    description: Ok(Default::default()),
+                    enabled: Ok(Default::default()),
+                    log: Ok(Default::default()),
+                    owner_uuid: Ok(Default::default()),
+                    rule: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl UpdateRuleRequest {
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {e}"));
+                self
+            }
+            pub fn enabled<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.enabled = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for enabled: {e}"));
+                self
+            }
+            pub fn log<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.log = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for log: {e}"));
+                self
+            }
+            pub fn owner_uuid<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::uuid::Uuid>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.owner_uuid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for owner_uuid: {e}"));
+                self
+            }
+            pub fn rule<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.rule = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rule: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<UpdateRuleRequest> for super::UpdateRuleRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: UpdateRuleRequest,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    description: value.description?,
+                    enabled: value.enabled?,
+                    log: value.log?,
+                    owner_uuid: value.owner_uuid?,
+                    rule: value.rule?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::UpdateRuleRequest> for UpdateRuleRequest {
+            fn from(value: super::UpdateRuleRequest) -> Self {
+                Self {
+                    description: Ok(value.description),
+                    enabled: Ok(value.enabled),
+                    log: Ok(value.log),
+                    owner_uuid: Ok(value.owner_uuid),
+                    rule: Ok(value.rule),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[doc = "Client for Triton FWAPI\n\nTriton FWAPI - internal HTTP API for managing firewall rules, including global rules, and resolving the rules that apply to VMs\n\nVersion: 1.0.0"]
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+}
+
+impl Client {
+    #[doc = r" Create a new client."]
+    #[doc = r""]
+    #[doc = r" `baseurl` is the base URL provided to the internal"]
+    #[doc = r" `reqwest::Client`, and should include a scheme and hostname,"]
+    #[doc = r" as well as port and a path stem if applicable."]
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = ::std::time::Duration::from_secs(15u64);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    #[doc = r" Construct a new client with an existing `reqwest::Client`,"]
+    #[doc = r" allowing more control over its configuration."]
+    #[doc = r""]
+    #[doc = r" `baseurl` is the base URL provided to the internal"]
+    #[doc = r" `reqwest::Client`, and should include a scheme and hostname,"]
+    #[doc = r" as well as port and a path stem if applicable."]
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+        }
+    }
+}
+
+impl ClientInfo<()> for Client {
+    fn api_version() -> &'static str {
+        "1.0.0"
+    }
+
+    fn baseurl(&self) -> &str {
+        self.baseurl.as_str()
+    }
+
+    fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn inner(&self) -> &() {
+        &()
+    }
+}
+
+impl ClientHooks<()> for &Client {}
+impl Client {
+    #[doc = "List the rules applying to a VM\n\nReturns every rule that affects the VM, including global rules and rules that match it by tag or \"all vms\".\n\nSends a `GET` request to `/firewalls/vms/{uuid}`\n\nArguments:\n- `uuid`: VM UUID.\n- `owner_uuid`: Account the rule or VM must belong to.\n```ignore\nlet response = client.list_vm_rules()\n    .uuid(uuid)\n    .owner_uuid(owner_uuid)\n    .send()\n    .await;\n```"]
+    pub fn list_vm_rules(&self) -> builder::ListVmRules<'_> {
+        builder::ListVmRules::new(self)
+    }
+
+    #[doc = "Ping the FWAPI service\n\nReturns the health of FWAPI and its backend services.\n\nSends a `GET` request to `/ping`\n\n```ignore\nlet response = client.ping()\n    .send()\n    .await;\n```"]
+    pub fn ping(&self) -> builder::Ping<'_> {
+        builder::Ping::new(self)
+    }
+
+    #[doc = "Resolve rules\n\nReturns the rules of an account that apply to the given VMs and tags, along with the VMs and tags those rules refer to. Compute nodes use this to find the remote VMs they need to know about.\n\nSends a `POST` request to `/resolve`\n\n```ignore\nlet response = client.resolve()\n    .body(body)\n    .send()\n    .await;\n```"]
+    pub fn resolve(&self) -> builder::Resolve<'_> {
+        builder::Resolve::new(self)
+    }
+
+    #[doc = "List rules\n\nReturns the rules matching the query filters. Filtering by `owner_uuid` also returns global rules.\n\nSends a `GET` request to `/rules`\n\nArguments:\n- `global`: Only return global (true) or non-global (false) rules.\n- `limit`: Maximum number of rules to return.\n- `offset`: Skip this many rules.\n- `owner_uuid`: Only return rules owned by this account (global rules are included).\n```ignore\nlet response = client.list_rules()\n    .global(global)\n    .limit(limit)\n    .offset(offset)\n    .owner_uuid(owner_uuid)\n    .send()\n    .await;\n```"]
+    pub fn list_rules(&self) -> builder::ListRules<'_> {
+        builder::ListRules::new(self)
+    }
+
+    #[doc = "Create a rule\n\nValidates and stores the rule, then pushes it to the compute nodes of the VMs it affects. The push happens asynchronously.\n\nSends a `POST` request to `/rules`\n\n```ignore\nlet response = client.create_rule()\n    .body(body)\n    .send()\n    .await;\n```"]
+    pub fn create_rule(&self) -> builder::CreateRule<'_> {
+        builder::CreateRule::new(self)
+    }
+
+    #[doc = "Get a rule by UUID\n\nSends a `GET` request to `/rules/{uuid}`\n\nArguments:\n- `uuid`: Rule UUID.\n- `owner_uuid`: Account the rule or VM must belong to.\n```ignore\nlet response = client.get_rule()\n    .uuid(uuid)\n    .owner_uuid(owner_uuid)\n    .send()\n    .await;\n```"]
+    pub fn get_rule(&self) -> builder::GetRule<'_> {
+        builder::GetRule::new(self)
+    }
+
+    #[doc = "Update a rule\n\nUpdates the given fields and pushes the new rule to the compute nodes of the VMs affected before or after the update. The push happens asynchronously.\n\nSends a `PUT` request to `/rules/{uuid}`\n\nArguments:\n- `uuid`: Rule UUID.\n- `body`\n```ignore\nlet response = client.update_rule()\n    .uuid(uuid)\n    .body(body)\n    .send()\n    .await;\n```"]
+    pub fn update_rule(&self) -> builder::UpdateRule<'_> {
+        builder::UpdateRule::new(self)
+    }
+
+    #[doc = "Delete a rule\n\nRemoves the rule from FWAPI and from the compute nodes of the VMs it affects.\n\nSends a `DELETE` request to `/rules/{uuid}`\n\nArguments:\n- `uuid`: Rule UUID.\n- `owner_uuid`: Account the rule or VM must belong to.\n```ignore\nlet response = client.delete_rule()\n    .uuid(uuid)\n    .owner_uuid(owner_uuid)\n    .send()\n    .await;\n```"]
+    pub fn delete_rule(&self) -> builder::DeleteRule<'_> {
+        builder::DeleteRule::new(self)
+    }
+}
+
+#[doc = r" Types for composing operation parameters."]
+#[allow(clippy::all)]
+pub mod builder {
+    use super::types;
+    #[allow(unused_imports)]
+    use super::{
+        ByteStream, ClientHooks, ClientInfo, Error, OperationInfo, RequestBuilderExt,
+        ResponseValue, encode_path,
+    };
+    #[doc = "Builder for [`Client::list_vm_rules`]\n\n[`Client::list_vm_rules`]: super::Client::list_vm_rules"]
+    #[derive(Debug, Clone)]
+    pub struct ListVmRules<'a> {
+        client: &'a super::Client,
+        uuid: Result<::uuid::Uuid, String>,
+        owner_uuid: Result<Option<::uuid::Uuid>, String>,
+    }
+
+    impl<'a> ListVmRules<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                uuid: Err("uuid was not initialized".to_string()),
+                owner_uuid: Ok(None),
+            }
+        }
+
+        pub fn uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.uuid = value
+                .try_into()
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for uuid failed".to_string());
+            self
+        }
+
+        pub fn owner_uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.owner_uuid = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for owner_uuid failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/firewalls/vms/{uuid}`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<::std::vec::Vec<types::FirewallRule>>, Error<types::Error>>
+        {
+            let Self {
+                client,
+                uuid,
+                owner_uuid,
+            } = self;
+            let uuid = uuid.map_err(Error::InvalidRequest)?;
+            let owner_uuid = owner_uuid.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/firewalls/vms/{}",
+                client.baseurl,
+                encode_path(&uuid.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new(
+                    "owner_uuid",
+                    &owner_uuid,
+                ))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "list_vm_rules",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::ping`]\n\n[`Client::ping`]: super::Client::ping"]
+    #[derive(Debug, Clone)]
+    pub struct Ping<'a> {
+        client: &'a super::Client,
+    }
+
+    impl<'a> Ping<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+
+        #[doc = "Sends a `GET` request to `/ping`"]
+        pub async fn send(self) -> Result<ResponseValue<types::PingResponse>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/ping", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "ping",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::resolve`]\n\n[`Client::resolve`]: super::Client::resolve"]
+    #[derive(Debug, Clone)]
+    pub struct Resolve<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::ResolveRequest, String>,
+    }
+
+    impl<'a> Resolve<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(::std::default::Default::default()),
+            }
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::ResolveRequest>,
+            <V as std::convert::TryInto<types::ResolveRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `ResolveRequest` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::ResolveRequest) -> types::builder::ResolveRequest,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        #[doc = "Sends a `POST` request to `/resolve`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ResolveResponse>, Error<types::Error>> {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::ResolveRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/resolve", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "resolve",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::list_rules`]\n\n[`Client::list_rules`]: super::Client::list_rules"]
+    #[derive(Debug, Clone)]
+    pub struct ListRules<'a> {
+        client: &'a super::Client,
+        global: Result<Option<bool>, String>,
+        limit: Result<Option<u64>, String>,
+        offset: Result<Option<u64>, String>,
+        owner_uuid: Result<Option<::uuid::Uuid>, String>,
+    }
+
+    impl<'a> ListRules<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                global: Ok(None),
+                limit: Ok(None),
+                offset: Ok(None),
+                owner_uuid: Ok(None),
+            }
+        }
+
+        pub fn global<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<bool>,
+        {
+            self.global = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `bool` for global failed".to_string());
+            self
+        }
+
+        pub fn limit<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u64>,
+        {
+            self.limit = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u64` for limit failed".to_string());
+            self
+        }
+
+        pub fn offset<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u64>,
+        {
+            self.offset = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u64` for offset failed".to_string());
+            self
+        }
+
+        pub fn owner_uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.owner_uuid = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for owner_uuid failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/rules`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<::std::vec::Vec<types::FirewallRule>>, Error<types::Error>>
+        {
+            let Self {
+                client,
+                global,
+                limit,
+                offset,
+                owner_uuid,
+            } = self;
+            let global = global.map_err(Error::InvalidRequest)?;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let offset = offset.map_err(Error::InvalidRequest)?;
+            let owner_uuid = owner_uuid.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/rules", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new("global", &global))
+                .query(&progenitor_client::QueryParam::new("limit", &limit))
+                .query(&progenitor_client::QueryParam::new("offset", &offset))
+                .query(&progenitor_client::QueryParam::new(
+                    "owner_uuid",
+                    &owner_uuid,
+                ))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "list_rules",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::create_rule`]\n\n[`Client::create_rule`]: super::Client::create_rule"]
+    #[derive(Debug, Clone)]
+    pub struct CreateRule<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::CreateRuleRequest, String>,
+    }
+
+    impl<'a> CreateRule<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(::std::default::Default::default()),
+            }
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::CreateRuleRequest>,
+            <V as std::convert::TryInto<types::CreateRuleRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `CreateRuleRequest` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                    types::builder::CreateRuleRequest,
+                ) -> types::builder::CreateRuleRequest,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        #[doc = "Sends a `POST` request to `/rules`"]
+        pub async fn send(self) -> Result<ResponseValue<types::FirewallRule>, Error<types::Error>> {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::CreateRuleRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/rules", client.baseurl,);
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "create_rule",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                202u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::get_rule`]\n\n[`Client::get_rule`]: super::Client::get_rule"]
+    #[derive(Debug, Clone)]
+    pub struct GetRule<'a> {
+        client: &'a super::Client,
+        uuid: Result<::uuid::Uuid, String>,
+        owner_uuid: Result<Option<::uuid::Uuid>, String>,
+    }
+
+    impl<'a> GetRule<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                uuid: Err("uuid was not initialized".to_string()),
+                owner_uuid: Ok(None),
+            }
+        }
+
+        pub fn uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.uuid = value
+                .try_into()
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for uuid failed".to_string());
+            self
+        }
+
+        pub fn owner_uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.owner_uuid = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for owner_uuid failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/rules/{uuid}`"]
+        pub async fn send(self) -> Result<ResponseValue<types::FirewallRule>, Error<types::Error>> {
+            let Self {
+                client,
+                uuid,
+                owner_uuid,
+            } = self;
+            let uuid = uuid.map_err(Error::InvalidRequest)?;
+            let owner_uuid = owner_uuid.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rules/{}",
+                client.baseurl,
+                encode_path(&uuid.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new(
+                    "owner_uuid",
+                    &owner_uuid,
+                ))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "get_rule",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::update_rule`]\n\n[`Client::update_rule`]: super::Client::update_rule"]
+    #[derive(Debug, Clone)]
+    pub struct UpdateRule<'a> {
+        client: &'a super::Client,
+        uuid: Result<::uuid::Uuid, String>,
+        body: Result<types::builder::UpdateRuleRequest, String>,
+    }
+
+    impl<'a> UpdateRule<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                uuid: Err("uuid was not initialized".to_string()),
+                body: Ok(::std::default::Default::default()),
+            }
+        }
+
+        pub fn uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.uuid = value
+                .try_into()
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for uuid failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::UpdateRuleRequest>,
+            <V as std::convert::TryInto<types::UpdateRuleRequest>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `UpdateRuleRequest` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                    types::builder::UpdateRuleRequest,
+                ) -> types::builder::UpdateRuleRequest,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        #[doc = "Sends a `PUT` request to `/rules/{uuid}`"]
+        pub async fn send(self) -> Result<ResponseValue<types::FirewallRule>, Error<types::Error>> {
+            let Self { client, uuid, body } = self;
+            let uuid = uuid.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::UpdateRuleRequest::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rules/{}",
+                client.baseurl,
+                encode_path(&uuid.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .put(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "update_rule",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                202u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    #[doc = "Builder for [`Client::delete_rule`]\n\n[`Client::delete_rule`]: super::Client::delete_rule"]
+    #[derive(Debug, Clone)]
+    pub struct DeleteRule<'a> {
+        client: &'a super::Client,
+        uuid: Result<::uuid::Uuid, String>,
+        owner_uuid: Result<Option<::uuid::Uuid>, String>,
+    }
+
+    impl<'a> DeleteRule<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                uuid: Err("uuid was not initialized".to_string()),
+                owner_uuid: Ok(None),
+            }
+        }
+
+        pub fn uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.uuid = value
+                .try_into()
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for uuid failed".to_string());
+            self
+        }
+
+        pub fn owner_uuid<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.owner_uuid = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for owner_uuid failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `DELETE` request to `/rules/{uuid}`"]
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
+            let Self {
+                client,
+                uuid,
+                owner_uuid,
+            } = self;
+            let uuid = uuid.map_err(Error::InvalidRequest)?;
+            let owner_uuid = owner_uuid.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rules/{}",
+                client.baseurl,
+                encode_path(&uuid.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .query(&progenitor_client::QueryParam::new(
+                    "owner_uuid",
+                    &owner_uuid,
+                ))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "delete_rule",
+            };
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                204u16 => Ok(ResponseValue::empty(response)),
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+}
+
+#[doc = r" Items consumers will typically use such as the Client."]
+pub mod prelude {
+    pub use self::super::Client;
+}
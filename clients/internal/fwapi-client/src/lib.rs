@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! FWAPI Client Library
+//!
+//! This client provides typed access to the Triton FWAPI service
+//! (Firewall API).
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use fwapi_client::Client;
+//!
+//! let client = Client::new("http://fwapi.my-dc.my-cloud.local");
+//!
+//! // List an account's rules (global rules are included)
+//! let rules = client.list_rules().owner_uuid(owner_uuid).send().await?;
+//!
+//! // Create a rule
+//! use fwapi_client::CreateRuleRequest;
+//! let req = CreateRuleRequest { .. };
+//! client.create_rule().body(req).send().await?;
+//!
+//! // List the rules applying to a VM
+//! let vm_rules = client.list_vm_rules().uuid(vm_uuid).send().await?;
+//! ```
+
+// Allow unwrap in generated code - Progenitor uses it in Client::new()
+#[allow(clippy::unwrap_used)]
+mod generated;
+pub use generated::*;
+
+// Re-export types from the API crate for convenience.
+pub use fwapi_api::{
+    // Structs
+    CreateRuleRequest,
+    FirewallRule,
+    ListRulesQuery,
+    OwnerQuery,
+    PingResponse,
+    ResolveRequest,
+    ResolveResponse,
+    RulePath,
+    UpdateRuleRequest,
+    // Common types
+    Uuid,
+    VmPath,
+};
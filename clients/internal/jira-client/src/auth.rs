@@ -0,0 +1,175 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! JIRA credentials
+//!
+//! [`JiraAuth`] describes how requests authenticate to JIRA. The client
+//! constructors in this module install the matching `Authorization` header as
+//! a default header on the underlying `reqwest::Client`, so every request made
+//! through the generated client carries it.
+
+use std::fmt;
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+
+use crate::Client;
+
+/// Request timeout used by [`Client::new_with_auth`], matching `Client::new`
+const DEFAULT_TIMEOUT_SECS: u64 = 15;
+
+/// Credentials used to authenticate to JIRA
+///
+/// The `Debug` output never includes passwords or tokens.
+#[derive(Clone)]
+pub enum JiraAuth {
+    /// HTTP Basic authentication. For JIRA Cloud the username is the account
+    /// email address and the password is an API token.
+    Basic { username: String, password: String },
+    /// An OAuth 2.0 access token, sent as a bearer token
+    BearerToken(String),
+    /// A JIRA Data Center personal access token, sent as a bearer token
+    Pat(String),
+}
+
+impl fmt::Debug for JiraAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JiraAuth::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"[REDACTED]")
+                .finish(),
+            JiraAuth::BearerToken(_) => f.debug_tuple("BearerToken").field(&"[REDACTED]").finish(),
+            JiraAuth::Pat(_) => f.debug_tuple("Pat").field(&"[REDACTED]").finish(),
+        }
+    }
+}
+
+impl JiraAuth {
+    /// HTTP Basic authentication with a username and password (or API token)
+    pub fn basic(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Bearer token authentication with an OAuth 2.0 access token
+    pub fn bearer_token(token: impl Into<String>) -> Self {
+        Self::BearerToken(token.into())
+    }
+
+    /// Personal access token authentication
+    pub fn pat(token: impl Into<String>) -> Self {
+        Self::Pat(token.into())
+    }
+
+    /// The `Authorization` header value for these credentials
+    ///
+    /// The value is marked sensitive so HTTP-level logging does not print it.
+    pub fn header_value(&self) -> Result<HeaderValue, JiraAuthError> {
+        let value = match self {
+            JiraAuth::Basic { username, password } => {
+                let encoded = STANDARD.encode(format!("{}:{}", username, password));
+                format!("Basic {}", encoded)
+            }
+            JiraAuth::BearerToken(token) | JiraAuth::Pat(token) => format!("Bearer {}", token),
+        };
+
+        let mut value =
+            HeaderValue::from_str(&value).map_err(|_| JiraAuthError::InvalidCredentials)?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+}
+
+/// Errors from building an authenticated JIRA client
+#[derive(Debug, thiserror::Error)]
+pub enum JiraAuthError {
+    /// The credentials contain characters that cannot appear in an HTTP header
+    #[error("JIRA credentials contain characters not allowed in an HTTP header")]
+    InvalidCredentials,
+    /// The underlying HTTP client could not be built
+    #[error("failed to create HTTP client: {0}")]
+    Client(#[from] reqwest::Error),
+}
+
+impl Client {
+    /// Construct a client that authenticates every request with `auth`
+    ///
+    /// Uses the same timeouts as `Client::new`.
+    pub fn new_with_auth(baseurl: &str, auth: JiraAuth) -> Result<Self, JiraAuthError> {
+        let timeout = Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        let builder = reqwest::Client::builder()
+            .connect_timeout(timeout)
+            .timeout(timeout);
+        Self::new_with_auth_and_builder(baseurl, auth, builder)
+    }
+
+    /// Construct a client that authenticates every request with `auth`,
+    /// starting from a caller-configured `reqwest::ClientBuilder`
+    ///
+    /// Use this to set timeouts, a user agent or TLS options. Any default
+    /// `Authorization` header already on the builder is replaced.
+    pub fn new_with_auth_and_builder(
+        baseurl: &str,
+        auth: JiraAuth,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<Self, JiraAuthError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, auth.header_value()?);
+
+        let http_client = builder.default_headers(headers).build()?;
+        Ok(Self::new_with_client(baseurl, http_client))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_values() {
+        let basic = JiraAuth::basic("bot@example.com", "api-token");
+        // base64("bot@example.com:api-token")
+        assert_eq!(
+            basic.header_value().unwrap(),
+            "Basic Ym90QGV4YW1wbGUuY29tOmFwaS10b2tlbg=="
+        );
+        assert!(basic.header_value().unwrap().is_sensitive());
+
+        assert_eq!(
+            JiraAuth::bearer_token("oauth").header_value().unwrap(),
+            "Bearer oauth"
+        );
+        assert_eq!(JiraAuth::pat("pat").header_value().unwrap(), "Bearer pat");
+    }
+
+    #[test]
+    fn test_invalid_credentials() {
+        let auth = JiraAuth::pat("bad\ntoken");
+        assert!(matches!(
+            auth.header_value(),
+            Err(JiraAuthError::InvalidCredentials)
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let basic = format!("{:?}", JiraAuth::basic("bot", "hunter2"));
+        assert!(basic.contains("bot"));
+        assert!(!basic.contains("hunter2"));
+
+        let bearer = format!("{:?}", JiraAuth::bearer_token("oauth-secret"));
+        assert!(!bearer.contains("oauth-secret"));
+
+        let pat = format!("{:?}", JiraAuth::pat("pat-secret"));
+        assert!(!pat.contains("pat-secret"));
+    }
+}
@@ -0,0 +1,60 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Bulk issue fetch
+//!
+//! `POST /rest/api/3/issue/bulkfetch` is defined by `jira_api::JiraBulkApi`
+//! rather than `JiraApi`, because Dropshot cannot route it alongside
+//! `/rest/api/3/issue/{issue_id_or_key}`. It is therefore not part of the
+//! generated client, and is sent from here through the same HTTP client, so
+//! it carries the same authentication and timeouts.
+
+use jira_api::{BULK_FETCH_MAX_ISSUES, BulkFetchRequest, BulkFetchResponse};
+
+use crate::{Client, ClientInfo};
+
+/// Errors from a bulk issue fetch
+#[derive(Debug, thiserror::Error)]
+pub enum BulkFetchError {
+    /// More issues were requested than JIRA returns at once
+    #[error("cannot fetch {0} issues at once (at most {max})", max = BULK_FETCH_MAX_ISSUES)]
+    TooManyIssues(usize),
+    /// The request failed or JIRA returned an error status
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+}
+
+impl Client {
+    /// Fetch up to [`BULK_FETCH_MAX_ISSUES`] issues in one request
+    ///
+    /// Issues that do not exist or cannot be viewed are listed in the
+    /// response's `issue_errors` rather than failing the request.
+    pub async fn bulk_fetch_issues(
+        &self,
+        request: &BulkFetchRequest,
+    ) -> Result<BulkFetchResponse, BulkFetchError> {
+        if request.issue_ids_or_keys.len() > BULK_FETCH_MAX_ISSUES {
+            return Err(BulkFetchError::TooManyIssues(
+                request.issue_ids_or_keys.len(),
+            ));
+        }
+
+        let url = format!(
+            "{}/rest/api/3/issue/bulkfetch",
+            self.baseurl().trim_end_matches('/')
+        );
+        let response = self
+            .client()
+            .post(url)
+            .json(request)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(response)
+    }
+}
@@ -37,6 +37,181 @@ pub mod types {
         }
     }
 
+    #[doc = "Issue change history\n\nJIRA includes at most the 100 most recent history entries when the changelog is expanded on a single issue."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Issue change history\\n\\nJIRA includes at most the 100 most recent history entries when the changelog is expanded on a single issue.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"histories\": {"]
+    #[doc = "      \"description\": \"History entries, oldest first\","]
+    #[doc = "      \"default\": [],"]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/ChangelogHistory\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"total\": {"]
+    #[doc = "      \"description\": \"Total number of history entries on the issue\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"integer\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"format\": \"uint32\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct Changelog {
+        #[doc = "History entries, oldest first"]
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub histories: ::std::vec::Vec<ChangelogHistory>,
+        #[doc = "Total number of history entries on the issue"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub total: ::std::option::Option<u32>,
+    }
+
+    impl ::std::default::Default for Changelog {
+        fn default() -> Self {
+            Self {
+                histories: Default::default(),
+                total: Default::default(),
+            }
+        }
+    }
+
+    impl Changelog {
+        pub fn builder() -> builder::Changelog {
+            Default::default()
+        }
+    }
+
+    #[doc = "A single change to an issue, which may touch several fields\n\nJIRA also reports the author of each change; it is deliberately not modeled here."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"A single change to an issue, which may touch several fields\\n\\nJIRA also reports the author of each change; it is deliberately not modeled here.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"created\","]
+    #[doc = "    \"id\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"created\": {"]
+    #[doc = "      \"description\": \"When the change was made\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"id\": {"]
+    #[doc = "      \"description\": \"History entry ID\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"items\": {"]
+    #[doc = "      \"description\": \"Individual field changes\","]
+    #[doc = "      \"default\": [],"]
+    #[doc = "      \"type\": \"array\","]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"$ref\": \"#/components/schemas/ChangelogItem\""]
+    #[doc = "      }"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct ChangelogHistory {
+        #[doc = "When the change was made"]
+        pub created: ::std::string::String,
+        #[doc = "History entry ID"]
+        pub id: ::std::string::String,
+        #[doc = "Individual field changes"]
+        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+        pub items: ::std::vec::Vec<ChangelogItem>,
+    }
+
+    impl ChangelogHistory {
+        pub fn builder() -> builder::ChangelogHistory {
+            Default::default()
+        }
+    }
+
+    #[doc = "A change to a single field"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"A change to a single field\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"field\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"field\": {"]
+    #[doc = "      \"description\": \"Field name (e.g., \\\"status\\\")\","]
+    #[doc = "      \"type\": \"string\""]
+    #[doc = "    },"]
+    #[doc = "    \"fieldtype\": {"]
+    #[doc = "      \"description\": \"Field type (\\\"jira\\\" for system fields, \\\"custom\\\" for custom fields)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"fromString\": {"]
+    #[doc = "      \"description\": \"Display value before the change\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"toString\": {"]
+    #[doc = "      \"description\": \"Display value after the change\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    pub struct ChangelogItem {
+        #[doc = "Field name (e.g., \"status\")"]
+        pub field: ::std::string::String,
+        #[doc = "Field type (\"jira\" for system fields, \"custom\" for custom fields)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub fieldtype: ::std::option::Option<::std::string::String>,
+        #[doc = "Display value before the change"]
+        #[serde(
+            rename = "fromString",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub from_string: ::std::option::Option<::std::string::String>,
+        #[doc = "Display value after the change"]
+        #[serde(
+            rename = "toString",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub to_string: ::std::option::Option<::std::string::String>,
+    }
+
+    impl ChangelogItem {
+        pub fn builder() -> builder::ChangelogItem {
+            Default::default()
+        }
+    }
+
     #[doc = "Error information from a response."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -91,6 +266,21 @@ pub mod types {
     #[doc = "    \"key\""]
     #[doc = "  ],"]
     #[doc = "  \"properties\": {"]
+    #[doc = "    \"changelog\": {"]
+    #[doc = "      \"description\": \"Change history when expand=changelog is used\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/Changelog\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    },"]
     #[doc = "    \"fields\": {"]
     #[doc = "      \"description\": \"Issue fields as a dynamic JSON object\","]
     #[doc = "      \"type\": \"object\","]
@@ -118,6 +308,9 @@ pub mod types {
     #[doc = r" </details>"]
     #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
     pub struct Issue {
+        #[doc = "Change history when expand=changelog is used"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub changelog: ::std::option::Option<Changelog>,
         #[doc = "Issue fields as a dynamic JSON object"]
         pub fields: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
         #[doc = "Issue ID (numeric)"]
@@ -296,6 +489,238 @@ pub mod types {
 
     #[doc = r" Types for composing complex structures."]
     pub mod builder {
+        #[derive(Clone, Debug)]
+        pub struct Changelog {
+            histories: ::std::result::Result<
+                ::std::vec::Vec<super::ChangelogHistory>,
+                ::std::string::String,
+            >,
+            total: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for Changelog {
+            fn default() -> Self {
+                Self {
+                    histories: Ok(Default::default()),
+                    total: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl Changelog {
+            pub fn histories<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::ChangelogHistory>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.histories = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for histories: {e}"));
+                self
+            }
+            pub fn total<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<u32>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.total = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for total: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<Changelog> for super::Changelog {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: Changelog,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    histories: value.histories?,
+                    total: value.total?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::Changelog> for Changelog {
+            fn from(value: super::Changelog) -> Self {
+                Self {
+                    histories: Ok(value.histories),
+                    total: Ok(value.total),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ChangelogHistory {
+            created: ::std::result::Result<::std::string::String, ::std::string::String>,
+            id: ::std::result::Result<::std::string::String, ::std::string::String>,
+            items:
+                ::std::result::Result<::std::vec::Vec<super::ChangelogItem>, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for ChangelogHistory {
+            fn default() -> Self {
+                Self {
+                    created: Err("no value supplied for created".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    items: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl ChangelogHistory {
+            pub fn created<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.created = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created: {e}"));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {e}"));
+                self
+            }
+            pub fn items<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::vec::Vec<super::ChangelogItem>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.items = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for items: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<ChangelogHistory> for super::ChangelogHistory {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ChangelogHistory,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created: value.created?,
+                    id: value.id?,
+                    items: value.items?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::ChangelogHistory> for ChangelogHistory {
+            fn from(value: super::ChangelogHistory) -> Self {
+                Self {
+                    created: Ok(value.created),
+                    id: Ok(value.id),
+                    items: Ok(value.items),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ChangelogItem {
+            field: ::std::result::Result<::std::string::String, ::std::string::String>,
+            fieldtype: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            from_string: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            to_string: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for ChangelogItem {
+            fn default() -> Self {
+                Self {
+                    field: Err("no value supplied for field".to_string()),
+                    fieldtype: Ok(Default::default()),
+                    from_string: Ok(Default::default()),
+                    to_string: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl ChangelogItem {
+            pub fn field<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::string::String>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.field = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for field: {e}"));
+                self
+            }
+            pub fn fieldtype<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.fieldtype = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for fieldtype: {e}"));
+                self
+            }
+            pub fn from_string<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.from_string = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for from_string: {e}"));
+                self
+            }
+            pub fn to_string<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.to_string = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for to_string: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<ChangelogItem> for super::ChangelogItem {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ChangelogItem,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    field: value.field?,
+                    fieldtype: value.fieldtype?,
+                    from_string: value.from_string?,
+                    to_string: value.to_string?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::ChangelogItem> for ChangelogItem {
+            fn from(value: super::ChangelogItem) -> Self {
+                Self {
+                    field: Ok(value.field),
+                    fieldtype: Ok(value.fieldtype),
+                    from_string: Ok(value.from_string),
+                    to_string: Ok(value.to_string),
+                }
+            }
+        }
+
         #[derive(Clone, Debug)]
         pub struct Error {
             error_code: ::std::result::Result<
@@ -374,6 +799,10 @@ pub mod types {
 
         #[derive(Clone, Debug)]
         pub struct Issue {
+            changelog: ::std::result::Result<
+                ::std::option::Option<super::Changelog>,
+                ::std::string::String,
+            >,
             fields: ::std::result::Result<
                 ::serde_json::Map<::std::string::String, ::serde_json::Value>,
                 ::std::string::String,
@@ -391,6 +820,7 @@ pub mod types {
         impl ::std::default::Default for Issue {
             fn default() -> Self {
                 Self {
+                    changelog: Ok(Default::default()),
                     fields: Err("no value supplied for fields".to_string()),
                     id: Err("no value supplied for id".to_string()),
                     key: Err("no value supplied for key".to_string()),
@@ -400,6 +830,16 @@ pub mod types {
         }
 
         impl Issue {
+            pub fn changelog<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<super::Changelog>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.changelog = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for changelog: {e}"));
+                self
+            }
             pub fn fields<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<
@@ -454,6 +894,7 @@ pub mod types {
                 value: Issue,
             ) -> ::std::result::Result<Self, super::error::ConversionError> {
                 Ok(Self {
+                    changelog: value.changelog?,
                     fields: value.fields?,
                     id: value.id?,
                     key: value.key?,
@@ -465,6 +906,7 @@ pub mod types {
         impl ::std::convert::From<super::Issue> for Issue {
             fn from(value: super::Issue) -> Self {
                 Self {
+                    changelog: Ok(value.changelog),
                     fields: Ok(value.fields),
                     id: Ok(value.id),
                     key: Ok(value.key),
@@ -732,7 +1174,7 @@ impl ClientInfo<()> for Client {
 
 impl ClientHooks<()> for &Client {}
 impl Client {
-    #[doc = "Get a single issue by key\n\nRetrieves full details for a specific issue. Use the expand parameter to request additional data like renderedFields (HTML-rendered field values).\n\n**JIRA API Reference**: GET /rest/api/3/issue/{issueIdOrKey}\n\nSends a `GET` request to `/rest/api/3/issue/{issue_id_or_key}`\n\nArguments:\n- `issue_id_or_key`: Issue ID (some opaque number) or Key (e.g., \"PROJECT-123\")\n- `expand`: Comma-separated list of expansions (e.g., \"renderedFields\")\n```ignore\nlet response = client.get_issue()\n    .issue_id_or_key(issue_id_or_key)\n    .expand(expand)\n    .send()\n    .await;\n```"]
+    #[doc = "Get a single issue by key\n\nRetrieves full details for a specific issue. Use the expand parameter to request additional data like renderedFields (HTML-rendered field values).\n\n**JIRA API Reference**: GET /rest/api/3/issue/{issueIdOrKey}\n\nSends a `GET` request to `/rest/api/3/issue/{issue_id_or_key}`\n\nArguments:\n- `issue_id_or_key`: Issue ID (some opaque number) or Key (e.g., \"PROJECT-123\")\n- `expand`: Comma-separated list of expansions (e.g., \"renderedFields,changelog\")\n```ignore\nlet response = client.get_issue()\n    .issue_id_or_key(issue_id_or_key)\n    .expand(expand)\n    .send()\n    .await;\n```"]
     pub fn get_issue(&self) -> builder::GetIssue<'_> {
         builder::GetIssue::new(self)
     }
@@ -14,12 +14,22 @@
 //! - Search issues using JQL
 //! - Get issue details
 //! - Get remote links for an issue
+//! - Get several issues at once ([`Client::bulk_fetch_issues`], which is
+//!   written by hand; see the `bulk` module)
 //!
 //! The generated client provides a type-safe, async interface to these endpoints.
+//! Use [`Client::new_with_auth`] with a [`JiraAuth`] to get a client that
+//! authenticates every request.
 
 // Re-export IssueKey from jira-api for type-safe usage
 pub use jira_api::IssueKey;
 
+mod auth;
+pub use auth::{JiraAuth, JiraAuthError};
+
+mod bulk;
+pub use bulk::BulkFetchError;
+
 // Allow unwrap in generated code - Progenitor uses it in Client::new()
 #[allow(clippy::unwrap_used)]
 mod generated;
@@ -33,6 +43,34 @@ impl From<types::Issue> for jira_api::Issue {
             id: issue.id,
             fields: issue.fields.into_iter().collect(),
             rendered_fields: issue.rendered_fields.map(|m| m.into_iter().collect()),
+            changelog: issue.changelog.map(Into::into),
+        }
+    }
+}
+
+// Add a conversion from generated Changelog to jira_api::Changelog
+impl From<types::Changelog> for jira_api::Changelog {
+    fn from(changelog: types::Changelog) -> Self {
+        jira_api::Changelog {
+            histories: changelog
+                .histories
+                .into_iter()
+                .map(|history| jira_api::ChangelogHistory {
+                    id: history.id,
+                    created: history.created,
+                    items: history
+                        .items
+                        .into_iter()
+                        .map(|item| jira_api::ChangelogItem {
+                            field: item.field,
+                            fieldtype: item.fieldtype,
+                            from_string: item.from_string,
+                            to_string: item.to_string,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            total: changelog.total,
         }
     }
 }
@@ -239,6 +239,16 @@ async fn stamp_bearer(
     Ok(())
 }
 
+/// The `Host` header value for a URL: its host, and its port if not the
+/// scheme's default.
+fn host_header(url: &reqwest::Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+
 /// Stamp Date + Authorization for the SSH HTTP Signature fallback path.
 ///
 /// This mirrors `cloudapi-client`'s `add_auth_headers` (same crate, different
@@ -262,12 +272,30 @@ async fn stamp_ssh(
         None => url.path().to_string(),
     };
 
-    let (date_header, auth_header) =
-        triton_auth::sign_request(auth_config, &method, &path_and_query)
-            .await
-            .map_err(|e| -> HookError { Box::new(e) })?;
+    // Signed headers computed from the request itself (`host`,
+    // `content-md5`) are added before signing, so that the signature covers
+    // the values that are sent.
+    let mut signed_headers = request.headers().clone();
+    auth_config
+        .signed_headers
+        .add_computed_headers(
+            &mut signed_headers,
+            &host_header(request.url()),
+            request.body().map_or(Some(&[][..]), |body| body.as_bytes()),
+        )
+        .map_err(|e| -> HookError { Box::new(e) })?;
+
+    let (date_header, auth_header) = triton_auth::sign_request_with_headers(
+        auth_config,
+        &method,
+        &path_and_query,
+        &signed_headers,
+    )
+    .await
+    .map_err(|e| -> HookError { Box::new(e) })?;
 
     let headers = request.headers_mut();
+    *headers = signed_headers;
     headers.insert(
         reqwest::header::DATE,
         date_header.parse().map_err(|e| {
@@ -85,6 +85,14 @@ pub mod types {
     #[doc = "      ],"]
     #[doc = "      \"format\": \"date-time\""]
     #[doc = "    },"]
+    #[doc = "    \"lastused\": {"]
+    #[doc = "      \"description\": \"Time the key was last used to authenticate a request (absent if it has never been used)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"format\": \"date-time\""]
+    #[doc = "    },"]
     #[doc = "    \"status\": {"]
     #[doc = "      \"description\": \"Status\","]
     #[doc = "      \"allOf\": ["]
@@ -118,6 +126,9 @@ pub mod types {
         #[doc = "Expiration timestamp (null for permanent keys)"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub expiration: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+        #[doc = "Time the key was last used to authenticate a request (absent if it has never been used)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub lastused: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
         #[doc = "Status"]
         pub status: AccessKeyStatus,
         #[doc = "Last updated timestamp"]
@@ -626,13 +637,200 @@ pub mod types {
         }
     }
 
-    #[doc = "Audit entry for a machine"]
+    #[doc = "Caller of an audited action\n\nCloudAPI only includes the fields that apply to the caller type: `keyId` for signature callers, `ip` for requests that came in over HTTP."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Caller of an audited action\\n\\nCloudAPI only includes the fields that apply to the caller type: `keyId` for signature callers, `ip` for requests that came in over HTTP.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"ip\": {"]
+    #[doc = "      \"description\": \"Client IP address\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"keyId\": {"]
+    #[doc = "      \"description\": \"Signing key, as `/<login>/keys/<fingerprint>`\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"login\": {"]
+    #[doc = "      \"description\": \"Login of the caller, when CloudAPI records it\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"type\": {"]
+    #[doc = "      \"description\": \"Authentication type\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/AuditCallerType\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct AuditCaller {
+        #[doc = "Client IP address"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub ip: ::std::option::Option<::std::string::String>,
+        #[doc = "Signing key, as `/<login>/keys/<fingerprint>`"]
+        #[serde(
+            rename = "keyId",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub key_id: ::std::option::Option<::std::string::String>,
+        #[doc = "Login of the caller, when CloudAPI records it"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub login: ::std::option::Option<::std::string::String>,
+        #[doc = "Authentication type"]
+        #[serde(
+            rename = "type",
+            default,
+            skip_serializing_if = "::std::option::Option::is_none"
+        )]
+        pub type_: ::std::option::Option<AuditCallerType>,
+    }
+
+    impl ::std::default::Default for AuditCaller {
+        fn default() -> Self {
+            Self {
+                ip: Default::default(),
+                key_id: Default::default(),
+                login: Default::default(),
+                type_: Default::default(),
+            }
+        }
+    }
+
+    impl AuditCaller {
+        pub fn builder() -> builder::AuditCaller {
+            Default::default()
+        }
+    }
+
+    #[doc = "How the caller of an audited action authenticated\n\n`signature` for HTTP Signature (SSH key) requests, `basic` for HTTP Basic, `token` for trusted services such as the portal, and `operator` for changes made through the internal APIs."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"How the caller of an audited action authenticated\\n\\n`signature` for HTTP Signature (SSH key) requests, `basic` for HTTP Basic, `token` for trusted services such as the portal, and `operator` for changes made through the internal APIs.\","]
+    #[doc = "  \"type\": \"string\","]
+    #[doc = "  \"enum\": ["]
+    #[doc = "    \"signature\","]
+    #[doc = "    \"basic\","]
+    #[doc = "    \"token\","]
+    #[doc = "    \"operator\","]
+    #[doc = "    \"unknown\""]
+    #[doc = "  ]"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        schemars :: JsonSchema,
+    )]
+    pub enum AuditCallerType {
+        #[serde(rename = "signature")]
+        Signature,
+        #[serde(rename = "basic")]
+        Basic,
+        #[serde(rename = "token")]
+        Token,
+        #[serde(rename = "operator")]
+        Operator,
+        #[serde(rename = "unknown")]
+        Unknown,
+    }
+
+    impl ::std::fmt::Display for AuditCallerType {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Signature => f.write_str("signature"),
+                Self::Basic => f.write_str("basic"),
+                Self::Token => f.write_str("token"),
+                Self::Operator => f.write_str("operator"),
+                Self::Unknown => f.write_str("unknown"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for AuditCallerType {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "signature" => Ok(Self::Signature),
+                "basic" => Ok(Self::Basic),
+                "token" => Ok(Self::Token),
+                "operator" => Ok(Self::Operator),
+                "unknown" => Ok(Self::Unknown),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for AuditCallerType {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for AuditCallerType {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for AuditCallerType {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    #[doc = "Audit entry for a machine\n\nMatches the records returned by Node.js CloudAPI's `MachineAudit`."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"Audit entry for a machine\","]
+    #[doc = "  \"description\": \"Audit entry for a machine\\n\\nMatches the records returned by Node.js CloudAPI's `MachineAudit`.\","]
     #[doc = "  \"type\": \"object\","]
     #[doc = "  \"required\": ["]
     #[doc = "    \"action\","]
@@ -640,11 +838,23 @@ pub mod types {
     #[doc = "  ],"]
     #[doc = "  \"properties\": {"]
     #[doc = "    \"action\": {"]
-    #[doc = "      \"description\": \"Action performed\","]
+    #[doc = "      \"description\": \"Action performed (e.g. \\\"start\\\"); see [`AuditAction`] for known values\","]
     #[doc = "      \"type\": \"string\""]
     #[doc = "    },"]
     #[doc = "    \"caller\": {"]
-    #[doc = "      \"description\": \"Caller information\""]
+    #[doc = "      \"description\": \"Caller information\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/AuditCaller\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
     #[doc = "    },"]
     #[doc = "    \"success\": {"]
     #[doc = "      \"description\": \"Success status\","]
@@ -674,11 +884,11 @@ pub mod types {
         :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
     )]
     pub struct AuditEntry {
-        #[doc = "Action performed"]
+        #[doc = "Action performed (e.g. \"start\"); see [`AuditAction`] for known values"]
         pub action: ::std::string::String,
         #[doc = "Caller information"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        pub caller: ::std::option::Option<::serde_json::Value>,
+        pub caller: ::std::option::Option<AuditCaller>,
         #[doc = "Success status"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub success: ::std::option::Option<AuditSuccess>,
@@ -2240,56 +2450,6 @@ pub mod types {
         }
     }
 
-    #[doc = "Datacenter map: name -> URL\n\nThe CloudAPI returns datacenters as a map where keys are datacenter names and values are their URLs. Example: ```json {\"us-central-1\": \"https://us-central-1.api.mnx.io\"} ```"]
-    #[doc = r""]
-    #[doc = r" <details><summary>JSON schema</summary>"]
-    #[doc = r""]
-    #[doc = r" ```json"]
-    #[doc = "{"]
-    #[doc = "  \"description\": \"Datacenter map: name -> URL\\n\\nThe CloudAPI returns datacenters as a map where keys are datacenter names and values are their URLs. Example: ```json {\\\"us-central-1\\\": \\\"https://us-central-1.api.mnx.io\\\"} ```\","]
-    #[doc = "  \"type\": \"object\","]
-    #[doc = "  \"additionalProperties\": {"]
-    #[doc = "    \"type\": \"string\""]
-    #[doc = "  }"]
-    #[doc = "}"]
-    #[doc = r" ```"]
-    #[doc = r" </details>"]
-    #[derive(
-        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
-    )]
-    #[serde(transparent)]
-    pub struct Datacenters(
-        pub ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-    );
-    impl ::std::ops::Deref for Datacenters {
-        type Target = ::std::collections::HashMap<::std::string::String, ::std::string::String>;
-        fn deref(
-            &self,
-        ) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
-            &self.0
-        }
-    }
-
-    impl ::std::convert::From<Datacenters>
-        for ::std::collections::HashMap<::std::string::String, ::std::string::String>
-    {
-        fn from(value: Datacenters) -> Self {
-            value.0
-        }
-    }
-
-    impl
-        ::std::convert::From<
-            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-        > for Datacenters
-    {
-        fn from(
-            value: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-        ) -> Self {
-            Self(value)
-        }
-    }
-
     #[doc = "Request to disable deletion protection"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -4095,6 +4255,186 @@ pub mod types {
         }
     }
 
+    #[doc = "Dimension a provisioning limit counts\n\nUnits for a limit's `value` and `used`: - `machines` → count of VMs - `ram` → MiB - `quota` → GiB"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Dimension a provisioning limit counts\\n\\nUnits for a limit's `value` and `used`: - `machines` → count of VMs - `ram` → MiB - `quota` → GiB\","]
+    #[doc = "  \"type\": \"string\","]
+    #[doc = "  \"enum\": ["]
+    #[doc = "    \"machines\","]
+    #[doc = "    \"ram\","]
+    #[doc = "    \"quota\","]
+    #[doc = "    \"unknown\""]
+    #[doc = "  ]"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        schemars :: JsonSchema,
+    )]
+    pub enum LimitBy {
+        #[serde(rename = "machines")]
+        Machines,
+        #[serde(rename = "ram")]
+        Ram,
+        #[serde(rename = "quota")]
+        Quota,
+        #[serde(rename = "unknown")]
+        Unknown,
+    }
+
+    impl ::std::fmt::Display for LimitBy {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Machines => f.write_str("machines"),
+                Self::Ram => f.write_str("ram"),
+                Self::Quota => f.write_str("quota"),
+                Self::Unknown => f.write_str("unknown"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for LimitBy {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "machines" => Ok(Self::Machines),
+                "ram" => Ok(Self::Ram),
+                "quota" => Ok(Self::Quota),
+                "unknown" => Ok(Self::Unknown),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for LimitBy {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for LimitBy {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for LimitBy {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    #[doc = "Kind of filter restricting which provisions a limit applies to"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Kind of filter restricting which provisions a limit applies to\","]
+    #[doc = "  \"type\": \"string\","]
+    #[doc = "  \"enum\": ["]
+    #[doc = "    \"brand\","]
+    #[doc = "    \"image\","]
+    #[doc = "    \"os\","]
+    #[doc = "    \"unknown\""]
+    #[doc = "  ]"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        schemars :: JsonSchema,
+    )]
+    pub enum LimitCheck {
+        #[serde(rename = "brand")]
+        Brand,
+        #[serde(rename = "image")]
+        Image,
+        #[serde(rename = "os")]
+        Os,
+        #[serde(rename = "unknown")]
+        Unknown,
+    }
+
+    impl ::std::fmt::Display for LimitCheck {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Brand => f.write_str("brand"),
+                Self::Image => f.write_str("image"),
+                Self::Os => f.write_str("os"),
+                Self::Unknown => f.write_str("unknown"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for LimitCheck {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "brand" => Ok(Self::Brand),
+                "image" => Ok(Self::Image),
+                "os" => Ok(Self::Os),
+                "unknown" => Ok(Self::Unknown),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for LimitCheck {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for LimitCheck {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for LimitCheck {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
     #[doc = "Outcome of `POST /v1/auth/login`.\n\nTagged on the wire by the `outcome` field. The common case is `complete`: the password verified, no second factor is enrolled, and the response carries the same fields a non-2FA `LoginResponse` always has. Users enrolled in 2FA receive `challenge_required`, must read a code from their authenticator, and post it together with the `challenge_token` to `/v1/auth/login/verify` to obtain a `LoginResponse`."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -4906,6 +5246,100 @@ pub mod types {
         }
     }
 
+    #[doc = "Output format of a machine export"]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Output format of a machine export\","]
+    #[doc = "  \"oneOf\": ["]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Comma-separated values, starting with a header row\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"csv\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Newline-delimited JSON, one object per machine\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"ndjson\""]
+    #[doc = "      ]"]
+    #[doc = "    }"]
+    #[doc = "  ]"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize,
+        :: serde :: Serialize,
+        Clone,
+        Copy,
+        Debug,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        clap :: ValueEnum,
+        schemars :: JsonSchema,
+    )]
+    pub enum MachineExportFormat {
+        #[doc = "Comma-separated values, starting with a header row"]
+        #[serde(rename = "csv")]
+        Csv,
+        #[doc = "Newline-delimited JSON, one object per machine"]
+        #[serde(rename = "ndjson")]
+        Ndjson,
+    }
+
+    impl ::std::fmt::Display for MachineExportFormat {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match *self {
+                Self::Csv => f.write_str("csv"),
+                Self::Ndjson => f.write_str("ndjson"),
+            }
+        }
+    }
+
+    impl ::std::str::FromStr for MachineExportFormat {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            match value {
+                "csv" => Ok(Self::Csv),
+                "ndjson" => Ok(Self::Ndjson),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl ::std::convert::TryFrom<&str> for MachineExportFormat {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<&::std::string::String> for MachineExportFormat {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: &::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl ::std::convert::TryFrom<::std::string::String> for MachineExportFormat {
+        type Error = self::error::ConversionError;
+        fn try_from(
+            value: ::std::string::String,
+        ) -> ::std::result::Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
     #[doc = "Network interface on a machine"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -5095,6 +5529,78 @@ pub mod types {
         }
     }
 
+    #[doc = "Counts of an account's machines by state, brand and package\n\nReturned by `GET /{account}/machinesummary`, so that dashboards can show how many machines there are without listing them all. Each map only has entries for values that at least one machine has. Deleted machines are not counted."]
+    #[doc = r""]
+    #[doc = r" <details><summary>JSON schema</summary>"]
+    #[doc = r""]
+    #[doc = r" ```json"]
+    #[doc = "{"]
+    #[doc = "  \"description\": \"Counts of an account's machines by state, brand and package\\n\\nReturned by `GET /{account}/machinesummary`, so that dashboards can show how many machines there are without listing them all. Each map only has entries for values that at least one machine has. Deleted machines are not counted.\","]
+    #[doc = "  \"type\": \"object\","]
+    #[doc = "  \"required\": ["]
+    #[doc = "    \"brands\","]
+    #[doc = "    \"packages\","]
+    #[doc = "    \"states\","]
+    #[doc = "    \"total\""]
+    #[doc = "  ],"]
+    #[doc = "  \"properties\": {"]
+    #[doc = "    \"brands\": {"]
+    #[doc = "      \"description\": \"Number of machines of each brand\","]
+    #[doc = "      \"type\": \"object\","]
+    #[doc = "      \"additionalProperties\": {"]
+    #[doc = "        \"type\": \"integer\","]
+    #[doc = "        \"format\": \"uint64\","]
+    #[doc = "        \"minimum\": 0.0"]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"packages\": {"]
+    #[doc = "      \"description\": \"Number of machines using each package, by package name\","]
+    #[doc = "      \"type\": \"object\","]
+    #[doc = "      \"additionalProperties\": {"]
+    #[doc = "        \"type\": \"integer\","]
+    #[doc = "        \"format\": \"uint64\","]
+    #[doc = "        \"minimum\": 0.0"]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"states\": {"]
+    #[doc = "      \"description\": \"Number of machines in each state\","]
+    #[doc = "      \"type\": \"object\","]
+    #[doc = "      \"additionalProperties\": {"]
+    #[doc = "        \"type\": \"integer\","]
+    #[doc = "        \"format\": \"uint64\","]
+    #[doc = "        \"minimum\": 0.0"]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"total\": {"]
+    #[doc = "      \"description\": \"Number of machines\","]
+    #[doc = "      \"type\": \"integer\","]
+    #[doc = "      \"format\": \"uint64\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    }"]
+    #[doc = "  }"]
+    #[doc = "}"]
+    #[doc = r" ```"]
+    #[doc = r" </details>"]
+    #[derive(
+        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
+    )]
+    pub struct MachineSummary {
+        #[doc = "Number of machines of each brand"]
+        pub brands: ::std::collections::HashMap<::std::string::String, u64>,
+        #[doc = "Number of machines using each package, by package name"]
+        pub packages: ::std::collections::HashMap<::std::string::String, u64>,
+        #[doc = "Number of machines in each state"]
+        pub states: ::std::collections::HashMap<::std::string::String, u64>,
+        #[doc = "Number of machines"]
+        pub total: u64,
+    }
+
+    impl MachineSummary {
+        pub fn builder() -> builder::MachineSummary {
+            Default::default()
+        }
+    }
+
     #[doc = "Machine type (virtualization category)"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -5367,23 +5873,27 @@ pub mod types {
         }
     }
 
-    #[doc = "Migration request\n\nUsed to perform migration actions on an instance. The `action` field specifies which migration operation to perform.\n\n# Examples\n\nStart a new migration: ```json {\"action\": \"begin\"} ```\n\nStart migration with affinity rules: ```json {\"action\": \"begin\", \"affinity\": [\"instance!=web-*\"]} ```\n\nSwitch to the new server: ```json {\"action\": \"switch\"} ```\n\nAbort an in-progress migration: ```json {\"action\": \"abort\"} ```"]
+    #[doc = "Migration request\n\nUsed to perform migration actions on an instance. The `action` field specifies which migration operation to perform. It may be omitted when the action is passed as a query parameter (`?action=begin`) instead.\n\n# Examples\n\nStart a new migration: ```json {\"action\": \"begin\"} ```\n\nStart migration with affinity rules: ```json {\"action\": \"begin\", \"affinity\": [\"instance!=web-*\"]} ```\n\nSwitch to the new server: ```json {\"action\": \"switch\"} ```\n\nAbort an in-progress migration: ```json {\"action\": \"abort\"} ```"]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"Migration request\\n\\nUsed to perform migration actions on an instance. The `action` field specifies which migration operation to perform.\\n\\n# Examples\\n\\nStart a new migration: ```json {\\\"action\\\": \\\"begin\\\"} ```\\n\\nStart migration with affinity rules: ```json {\\\"action\\\": \\\"begin\\\", \\\"affinity\\\": [\\\"instance!=web-*\\\"]} ```\\n\\nSwitch to the new server: ```json {\\\"action\\\": \\\"switch\\\"} ```\\n\\nAbort an in-progress migration: ```json {\\\"action\\\": \\\"abort\\\"} ```\","]
+    #[doc = "  \"description\": \"Migration request\\n\\nUsed to perform migration actions on an instance. The `action` field specifies which migration operation to perform. It may be omitted when the action is passed as a query parameter (`?action=begin`) instead.\\n\\n# Examples\\n\\nStart a new migration: ```json {\\\"action\\\": \\\"begin\\\"} ```\\n\\nStart migration with affinity rules: ```json {\\\"action\\\": \\\"begin\\\", \\\"affinity\\\": [\\\"instance!=web-*\\\"]} ```\\n\\nSwitch to the new server: ```json {\\\"action\\\": \\\"switch\\\"} ```\\n\\nAbort an in-progress migration: ```json {\\\"action\\\": \\\"abort\\\"} ```\","]
     #[doc = "  \"type\": \"object\","]
-    #[doc = "  \"required\": ["]
-    #[doc = "    \"action\""]
-    #[doc = "  ],"]
     #[doc = "  \"properties\": {"]
     #[doc = "    \"action\": {"]
-    #[doc = "      \"description\": \"Migration action to perform\","]
-    #[doc = "      \"allOf\": ["]
+    #[doc = "      \"description\": \"Migration action to perform (takes precedence over `?action=`)\","]
+    #[doc = "      \"oneOf\": ["]
     #[doc = "        {"]
-    #[doc = "          \"$ref\": \"#/components/schemas/MigrationAction\""]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/MigrationAction\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
     #[doc = "        }"]
     #[doc = "      ]"]
     #[doc = "    },"]
@@ -5410,13 +5920,23 @@ pub mod types {
         :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
     )]
     pub struct MigrateRequest {
-        #[doc = "Migration action to perform"]
-        pub action: MigrationAction,
+        #[doc = "Migration action to perform (takes precedence over `?action=`)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub action: ::std::option::Option<MigrationAction>,
         #[doc = "Affinity rules (only valid for \"begin\" and \"automatic\" actions)\n\nThese rules influence which server the instance will be migrated to."]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub affinity: ::std::option::Option<cloudapi_api::AffinityRules>,
     }
 
+    impl ::std::default::Default for MigrateRequest {
+        fn default() -> Self {
+            Self {
+                action: Default::default(),
+                affinity: Default::default(),
+            }
+        }
+    }
+
     impl MigrateRequest {
         pub fn builder() -> builder::MigrateRequest {
             Default::default()
@@ -5600,6 +6120,13 @@ pub mod types {
     #[doc = "      \"enum\": ["]
     #[doc = "        \"finalize\""]
     #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    {"]
+    #[doc = "      \"description\": \"Unknown action (forward compatibility)\","]
+    #[doc = "      \"type\": \"string\","]
+    #[doc = "      \"enum\": ["]
+    #[doc = "        \"unknown\""]
+    #[doc = "      ]"]
     #[doc = "    }"]
     #[doc = "  ]"]
     #[doc = "}"]
@@ -5641,6 +6168,9 @@ pub mod types {
         #[doc = "Clean up after switch"]
         #[serde(rename = "finalize")]
         Finalize,
+        #[doc = "Unknown action (forward compatibility)"]
+        #[serde(rename = "unknown")]
+        Unknown,
     }
 
     impl ::std::fmt::Display for MigrationAction {
@@ -5653,6 +6183,7 @@ pub mod types {
                 Self::Abort => f.write_str("abort"),
                 Self::Pause => f.write_str("pause"),
                 Self::Finalize => f.write_str("finalize"),
+                Self::Unknown => f.write_str("unknown"),
             }
         }
     }
@@ -5668,6 +6199,7 @@ pub mod types {
                 "abort" => Ok(Self::Abort),
                 "pause" => Ok(Self::Pause),
                 "finalize" => Ok(Self::Finalize),
+                "unknown" => Ok(Self::Unknown),
                 _ => Err("invalid value".into()),
             }
         }
@@ -6204,6 +6736,15 @@ pub mod types {
     #[doc = "        \"null\""]
     #[doc = "      ]"]
     #[doc = "    },"]
+    #[doc = "    \"mtu\": {"]
+    #[doc = "      \"description\": \"MTU\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"integer\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"format\": \"uint32\","]
+    #[doc = "      \"minimum\": 0.0"]
+    #[doc = "    },"]
     #[doc = "    \"name\": {"]
     #[doc = "      \"description\": \"Network name\","]
     #[doc = "      \"type\": \"string\""]
@@ -6215,6 +6756,45 @@ pub mod types {
     #[doc = "        \"null\""]
     #[doc = "      ]"]
     #[doc = "    },"]
+    #[doc = "    \"networks\": {"]
+    #[doc = "      \"description\": \"Networks in the pool (pools only)\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/NetworkIds\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"nic_tag\": {"]
+    #[doc = "      \"description\": \"NIC tag the network is on. A machine can only be given this network on a server that provides the tag.\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"string\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
+    #[doc = "    \"nic_tags_present\": {"]
+    #[doc = "      \"description\": \"NIC tags of the networks in the pool (pools only)\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"array\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ],"]
+    #[doc = "      \"items\": {"]
+    #[doc = "        \"type\": \"string\""]
+    #[doc = "      }"]
+    #[doc = "    },"]
+    #[doc = "    \"pool\": {"]
+    #[doc = "      \"description\": \"Network pool: provisioning picks one of the pool's networks\","]
+    #[doc = "      \"type\": ["]
+    #[doc = "        \"boolean\","]
+    #[doc = "        \"null\""]
+    #[doc = "      ]"]
+    #[doc = "    },"]
     #[doc = "    \"provision_end_ip\": {"]
     #[doc = "      \"description\": \"Provision end IP\","]
     #[doc = "      \"type\": ["]
@@ -6314,11 +6894,26 @@ pub mod types {
         #[doc = "Internet NAT"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub internet_nat: ::std::option::Option<bool>,
+        #[doc = "MTU"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub mtu: ::std::option::Option<u32>,
         #[doc = "Network name"]
         pub name: ::std::string::String,
         #[doc = "Netmask"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub netmask: ::std::option::Option<::std::string::String>,
+        #[doc = "Networks in the pool (pools only)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub networks: ::std::option::Option<cloudapi_api::NetworkIds>,
+        #[doc = "NIC tag the network is on. A machine can only be given this network on a server that provides the tag."]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub nic_tag: ::std::option::Option<::std::string::String>,
+        #[doc = "NIC tags of the networks in the pool (pools only)"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub nic_tags_present: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+        #[doc = "Network pool: provisioning picks one of the pool's networks"]
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        pub pool: ::std::option::Option<bool>,
         #[doc = "Provision end IP"]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub provision_end_ip: ::std::option::Option<::std::string::String>,
@@ -7120,13 +7715,13 @@ pub mod types {
         }
     }
 
-    #[doc = "A single provisioning limit entry.\n\nEach limit constrains a specific dimension (VM count, RAM, or disk quota), optionally filtered by brand, image, or OS. A `value` of `-1` blocks all matching provisions; `0` means unlimited (filtered out before the response reaches the client).\n\nUnits for `value` and `used` depend on `by`: - absent / `\"machines\"` → count of VMs - `\"ram\"` → MiB - `\"quota\"` → GiB"]
+    #[doc = "A single provisioning limit entry.\n\nEach limit constrains a specific dimension (VM count, RAM, or disk quota), optionally filtered by brand, image, or OS. A `value` of `-1` blocks all matching provisions; `0` means unlimited (filtered out before the response reaches the client).\n\nUnits for `value` and `used` depend on `by`, see [`LimitBy`]."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
     #[doc = r""]
     #[doc = r" ```json"]
     #[doc = "{"]
-    #[doc = "  \"description\": \"A single provisioning limit entry.\\n\\nEach limit constrains a specific dimension (VM count, RAM, or disk quota), optionally filtered by brand, image, or OS. A `value` of `-1` blocks all matching provisions; `0` means unlimited (filtered out before the response reaches the client).\\n\\nUnits for `value` and `used` depend on `by`: - absent / `\\\"machines\\\"` → count of VMs - `\\\"ram\\\"` → MiB - `\\\"quota\\\"` → GiB\","]
+    #[doc = "  \"description\": \"A single provisioning limit entry.\\n\\nEach limit constrains a specific dimension (VM count, RAM, or disk quota), optionally filtered by brand, image, or OS. A `value` of `-1` blocks all matching provisions; `0` means unlimited (filtered out before the response reaches the client).\\n\\nUnits for `value` and `used` depend on `by`, see [`LimitBy`].\","]
     #[doc = "  \"type\": \"object\","]
     #[doc = "  \"required\": ["]
     #[doc = "    \"value\""]
@@ -7140,17 +7735,33 @@ pub mod types {
     #[doc = "      ]"]
     #[doc = "    },"]
     #[doc = "    \"by\": {"]
-    #[doc = "      \"description\": \"What dimension the limit counts: `\\\"ram\\\"`, `\\\"quota\\\"`, or `\\\"machines\\\"`. When absent, defaults to counting VMs.\","]
-    #[doc = "      \"type\": ["]
-    #[doc = "        \"string\","]
-    #[doc = "        \"null\""]
+    #[doc = "      \"description\": \"What dimension the limit counts. When absent, defaults to counting VMs.\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/LimitBy\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
     #[doc = "      ]"]
     #[doc = "    },"]
     #[doc = "    \"check\": {"]
-    #[doc = "      \"description\": \"Type of filter applied: `\\\"brand\\\"`, `\\\"image\\\"`, or `\\\"os\\\"`.\","]
-    #[doc = "      \"type\": ["]
-    #[doc = "        \"string\","]
-    #[doc = "        \"null\""]
+    #[doc = "      \"description\": \"Type of filter applied. When absent, the limit applies to every provision.\","]
+    #[doc = "      \"oneOf\": ["]
+    #[doc = "        {"]
+    #[doc = "          \"type\": \"null\""]
+    #[doc = "        },"]
+    #[doc = "        {"]
+    #[doc = "          \"allOf\": ["]
+    #[doc = "            {"]
+    #[doc = "              \"$ref\": \"#/components/schemas/LimitCheck\""]
+    #[doc = "            }"]
+    #[doc = "          ]"]
+    #[doc = "        }"]
     #[doc = "      ]"]
     #[doc = "    },"]
     #[doc = "    \"image\": {"]
@@ -7191,12 +7802,12 @@ pub mod types {
         #[doc = "Brand filter value (when `check` is `\"brand\"`)."]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub brand: ::std::option::Option<::std::string::String>,
-        #[doc = "What dimension the limit counts: `\"ram\"`, `\"quota\"`, or `\"machines\"`. When absent, defaults to counting VMs."]
+        #[doc = "What dimension the limit counts. When absent, defaults to counting VMs."]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        pub by: ::std::option::Option<::std::string::String>,
-        #[doc = "Type of filter applied: `\"brand\"`, `\"image\"`, or `\"os\"`."]
+        pub by: ::std::option::Option<LimitBy>,
+        #[doc = "Type of filter applied. When absent, the limit applies to every provision."]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        pub check: ::std::option::Option<::std::string::String>,
+        pub check: ::std::option::Option<LimitCheck>,
         #[doc = "Image filter value (when `check` is `\"image\"`)."]
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub image: ::std::option::Option<::std::string::String>,
@@ -7657,56 +8268,6 @@ pub mod types {
         }
     }
 
-    #[doc = "Services map: name -> URL\n\nThe CloudAPI returns services as a map where keys are service names and values are their URLs. Example: ```json {\"cmon\": \"https://cmon.example.com:9163\", \"docker\": \"tcp://docker.example.com:2376\"} ```"]
-    #[doc = r""]
-    #[doc = r" <details><summary>JSON schema</summary>"]
-    #[doc = r""]
-    #[doc = r" ```json"]
-    #[doc = "{"]
-    #[doc = "  \"description\": \"Services map: name -> URL\\n\\nThe CloudAPI returns services as a map where keys are service names and values are their URLs. Example: ```json {\\\"cmon\\\": \\\"https://cmon.example.com:9163\\\", \\\"docker\\\": \\\"tcp://docker.example.com:2376\\\"} ```\","]
-    #[doc = "  \"type\": \"object\","]
-    #[doc = "  \"additionalProperties\": {"]
-    #[doc = "    \"type\": \"string\""]
-    #[doc = "  }"]
-    #[doc = "}"]
-    #[doc = r" ```"]
-    #[doc = r" </details>"]
-    #[derive(
-        :: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema,
-    )]
-    #[serde(transparent)]
-    pub struct Services(
-        pub ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-    );
-    impl ::std::ops::Deref for Services {
-        type Target = ::std::collections::HashMap<::std::string::String, ::std::string::String>;
-        fn deref(
-            &self,
-        ) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
-            &self.0
-        }
-    }
-
-    impl ::std::convert::From<Services>
-        for ::std::collections::HashMap<::std::string::String, ::std::string::String>
-    {
-        fn from(value: Services) -> Self {
-            value.0
-        }
-    }
-
-    impl
-        ::std::convert::From<
-            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-        > for Services
-    {
-        fn from(
-            value: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-        ) -> Self {
-            Self(value)
-        }
-    }
-
     #[doc = "`GET /v1/auth/session` response body."]
     #[doc = r""]
     #[doc = r" <details><summary>JSON schema</summary>"]
@@ -10031,6 +10592,10 @@ pub mod types {
                 ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
                 ::std::string::String,
             >,
+            lastused: ::std::result::Result<
+                ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+                ::std::string::String,
+            >,
             status: ::std::result::Result<super::AccessKeyStatus, ::std::string::String>,
             updated: ::std::result::Result<
                 ::chrono::DateTime<::chrono::offset::Utc>,
@@ -10046,6 +10611,7 @@ pub mod types {
                     credentialtype: Err("no value supplied for credentialtype".to_string()),
                     description: Ok(Default::default()),
                     expiration: Ok(Default::default()),
+                    lastused: Ok(Default::default()),
                     status: Err("no value supplied for status".to_string()),
                     updated: Err("no value supplied for updated".to_string()),
                 }
@@ -10105,6 +10671,18 @@ pub mod types {
                     .map_err(|e| format!("error converting supplied value for expiration: {e}"));
                 self
             }
+            pub fn lastused<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<
+                        ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+                    >,
+                T::Error: ::std::fmt::Display,
+            {
+                self.lastused = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for lastused: {e}"));
+                self
+            }
             pub fn status<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<super::AccessKeyStatus>,
@@ -10138,6 +10716,7 @@ pub mod types {
                     credentialtype: value.credentialtype?,
                     description: value.description?,
                     expiration: value.expiration?,
+                    lastused: value.lastused?,
                     status: value.status?,
                     updated: value.updated?,
                 })
@@ -10152,6 +10731,7 @@ pub mod types {
                     credentialtype: Ok(value.credentialtype),
                     description: Ok(value.description),
                     expiration: Ok(value.expiration),
+                    lastused: Ok(value.lastused),
                     status: Ok(value.status),
                     updated: Ok(value.updated),
                 }
@@ -10708,11 +11288,110 @@ pub mod types {
             }
         }
 
+        #[derive(Clone, Debug)]
+        pub struct AuditCaller {
+            ip: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            key_id: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            login: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            type_: ::std::result::Result<
+                ::std::option::Option<super::AuditCallerType>,
+                ::std::string::String,
+            >,
+        }
+
+        impl ::std::default::Default for AuditCaller {
+            fn default() -> Self {
+                Self {
+                    ip: Ok(Default::default()),
+                    key_id: Ok(Default::default()),
+                    login: Ok(Default::default()),
+                    type_: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl AuditCaller {
+            pub fn ip<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.ip = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for ip: {e}"));
+                self
+            }
+            pub fn key_id<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.key_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key_id: {e}"));
+                self
+            }
+            pub fn login<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.login = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for login: {e}"));
+                self
+            }
+            pub fn type_<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<super::AuditCallerType>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.type_ = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for type_: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<AuditCaller> for super::AuditCaller {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: AuditCaller,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    ip: value.ip?,
+                    key_id: value.key_id?,
+                    login: value.login?,
+                    type_: value.type_?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::AuditCaller> for AuditCaller {
+            fn from(value: super::AuditCaller) -> Self {
+                Self {
+                    ip: Ok(value.ip),
+                    key_id: Ok(value.key_id),
+                    login: Ok(value.login),
+                    type_: Ok(value.type_),
+                }
+            }
+        }
+
         #[derive(Clone, Debug)]
         pub struct AuditEntry {
             action: ::std::result::Result<::std::string::String, ::std::string::String>,
             caller: ::std::result::Result<
-                ::std::option::Option<::serde_json::Value>,
+                ::std::option::Option<super::AuditCaller>,
                 ::std::string::String,
             >,
             success: ::std::result::Result<
@@ -10749,7 +11428,7 @@ pub mod types {
             }
             pub fn caller<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::option::Option<::serde_json::Value>>,
+                T: ::std::convert::TryInto<::std::option::Option<super::AuditCaller>>,
                 T::Error: ::std::fmt::Display,
             {
                 self.caller = value
@@ -14841,6 +15520,102 @@ pub mod types {
             }
         }
 
+        #[derive(Clone, Debug)]
+        pub struct MachineSummary {
+            brands: ::std::result::Result<
+                ::std::collections::HashMap<::std::string::String, u64>,
+                ::std::string::String,
+            >,
+            packages: ::std::result::Result<
+                ::std::collections::HashMap<::std::string::String, u64>,
+                ::std::string::String,
+            >,
+            states: ::std::result::Result<
+                ::std::collections::HashMap<::std::string::String, u64>,
+                ::std::string::String,
+            >,
+            total: ::std::result::Result<u64, ::std::string::String>,
+        }
+
+        impl ::std::default::Default for MachineSummary {
+            fn default() -> Self {
+                Self {
+                    brands: Err("no value supplied for brands".to_string()),
+                    packages: Err("no value supplied for packages".to_string()),
+                    states: Err("no value supplied for states".to_string()),
+                    total: Err("no value supplied for total".to_string()),
+                }
+            }
+        }
+
+        impl MachineSummary {
+            pub fn brands<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::collections::HashMap<::std::string::String, u64>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.brands = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for brands: {e}"));
+                self
+            }
+            pub fn packages<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::collections::HashMap<::std::string::String, u64>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.packages = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for packages: {e}"));
+                self
+            }
+            pub fn states<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::collections::HashMap<::std::string::String, u64>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.states = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for states: {e}"));
+                self
+            }
+            pub fn total<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<u64>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.total = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for total: {e}"));
+                self
+            }
+        }
+
+        impl ::std::convert::TryFrom<MachineSummary> for super::MachineSummary {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: MachineSummary,
+            ) -> ::std::result::Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    brands: value.brands?,
+                    packages: value.packages?,
+                    states: value.states?,
+                    total: value.total?,
+                })
+            }
+        }
+
+        impl ::std::convert::From<super::MachineSummary> for MachineSummary {
+            fn from(value: super::MachineSummary) -> Self {
+                Self {
+                    brands: Ok(value.brands),
+                    packages: Ok(value.packages),
+                    states: Ok(value.states),
+                    total: Ok(value.total),
+                }
+            }
+        }
+
         #[derive(Clone, Debug)]
         pub struct MemberRef {
             default: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
@@ -14933,7 +15708,10 @@ pub mod types {
 
         #[derive(Clone, Debug)]
         pub struct MigrateRequest {
-            action: ::std::result::Result<super::MigrationAction, ::std::string::String>,
+            action: ::std::result::Result<
+                ::std::option::Option<super::MigrationAction>,
+                ::std::string::String,
+            >,
             affinity: ::std::result::Result<
                 ::std::option::Option<cloudapi_api::AffinityRules>,
                 ::std::string::String,
@@ -14943,7 +15721,7 @@ pub mod types {
         impl ::std::default::Default for MigrateRequest {
             fn default() -> Self {
                 Self {
-                    action: Err("no value supplied for action".to_string()),
+                    action: Ok(Default::default()),
                     affinity: Ok(Default::default()),
                 }
             }
@@ -14952,7 +15730,7 @@ pub mod types {
         impl MigrateRequest {
             pub fn action<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<super::MigrationAction>,
+                T: ::std::convert::TryInto<::std::option::Option<super::MigrationAction>>,
                 T::Error: ::std::fmt::Display,
             {
                 self.action = value
@@ -15383,11 +16161,25 @@ pub mod types {
             >,
             id: ::std::result::Result<::uuid::Uuid, ::std::string::String>,
             internet_nat: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
+            mtu: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
             name: ::std::result::Result<::std::string::String, ::std::string::String>,
             netmask: ::std::result::Result<
                 ::std::option::Option<::std::string::String>,
                 ::std::string::String,
             >,
+            networks: ::std::result::Result<
+                ::std::option::Option<cloudapi_api::NetworkIds>,
+                ::std::string::String,
+            >,
+            nic_tag: ::std::result::Result<
+                ::std::option::Option<::std::string::String>,
+                ::std::string::String,
+            >,
+            nic_tags_present: ::std::result::Result<
+                ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                ::std::string::String,
+            >,
+            pool: ::std::result::Result<::std::option::Option<bool>, ::std::string::String>,
             provision_end_ip: ::std::result::Result<
                 ::std::option::Option<::std::string::String>,
                 ::std::string::String,
@@ -15428,8 +16220,13 @@ pub mod types {
                     gateway: Ok(Default::default()),
                     id: Err("no value supplied for id".to_string()),
                     internet_nat: Ok(Default::default()),
+                    mtu: Ok(Default::default()),
                     name: Err("no value supplied for name".to_string()),
                     netmask: Ok(Default::default()),
+                    networks: Ok(Default::default()),
+                    nic_tag: Ok(Default::default()),
+                    nic_tags_present: Ok(Default::default()),
+                    pool: Ok(Default::default()),
                     provision_end_ip: Ok(Default::default()),
                     provision_start_ip: Ok(Default::default()),
                     public: Err("no value supplied for public".to_string()),
@@ -15494,6 +16291,16 @@ pub mod types {
                     .map_err(|e| format!("error converting supplied value for internet_nat: {e}"));
                 self
             }
+            pub fn mtu<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<u32>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.mtu = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for mtu: {e}"));
+                self
+            }
             pub fn name<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::string::String>,
@@ -15514,6 +16321,48 @@ pub mod types {
                     .map_err(|e| format!("error converting supplied value for netmask: {e}"));
                 self
             }
+            pub fn networks<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<cloudapi_api::NetworkIds>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.networks = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for networks: {e}"));
+                self
+            }
+            pub fn nic_tag<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.nic_tag = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for nic_tag: {e}"));
+                self
+            }
+            pub fn nic_tags_present<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<
+                        ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    >,
+                T::Error: ::std::fmt::Display,
+            {
+                self.nic_tags_present = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for nic_tags_present: {e}")
+                });
+                self
+            }
+            pub fn pool<T>(mut self, value: T) -> Self
+            where
+                T: ::std::convert::TryInto<::std::option::Option<bool>>,
+                T::Error: ::std::fmt::Display,
+            {
+                self.pool = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for pool: {e}"));
+                self
+            }
             pub fn provision_end_ip<T>(mut self, value: T) -> Self
             where
                 T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
@@ -15619,8 +16468,13 @@ pub mod types {
                     gateway: value.gateway?,
                     id: value.id?,
                     internet_nat: value.internet_nat?,
+                    mtu: value.mtu?,
                     name: value.name?,
                     netmask: value.netmask?,
+                    networks: value.networks?,
+                    nic_tag: value.nic_tag?,
+                    nic_tags_present: value.nic_tags_present?,
+                    pool: value.pool?,
                     provision_end_ip: value.provision_end_ip?,
                     provision_start_ip: value.provision_start_ip?,
                     public: value.public?,
@@ -15642,8 +16496,13 @@ pub mod types {
                     gateway: Ok(value.gateway),
                     id: Ok(value.id),
                     internet_nat: Ok(value.internet_nat),
+                    mtu: Ok(value.mtu),
                     name: Ok(value.name),
                     netmask: Ok(value.netmask),
+                    networks: Ok(value.networks),
+                    nic_tag: Ok(value.nic_tag),
+                    nic_tags_present: Ok(value.nic_tags_present),
+                    pool: Ok(value.pool),
                     provision_end_ip: Ok(value.provision_end_ip),
                     provision_start_ip: Ok(value.provision_start_ip),
                     public: Ok(value.public),
@@ -16544,12 +17403,9 @@ pub mod types {
                 ::std::option::Option<::std::string::String>,
                 ::std::string::String,
             >,
-            by: ::std::result::Result<
-                ::std::option::Option<::std::string::String>,
-                ::std::string::String,
-            >,
+            by: ::std::result::Result<::std::option::Option<super::LimitBy>, ::std::string::String>,
             check: ::std::result::Result<
-                ::std::option::Option<::std::string::String>,
+                ::std::option::Option<super::LimitCheck>,
                 ::std::string::String,
             >,
             image: ::std::result::Result<
@@ -16591,7 +17447,7 @@ pub mod types {
             }
             pub fn by<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T: ::std::convert::TryInto<::std::option::Option<super::LimitBy>>,
                 T::Error: ::std::fmt::Display,
             {
                 self.by = value
@@ -16601,7 +17457,7 @@ pub mod types {
             }
             pub fn check<T>(mut self, value: T) -> Self
             where
-                T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+                T: ::std::convert::TryInto<::std::option::Option<super::LimitCheck>>,
                 T::Error: ::std::fmt::Display,
             {
                 self.check = value
@@ -19845,7 +20701,7 @@ impl Client {
         builder::HeadFirewallRuleMachines::new(self)
     }
 
-    #[doc = "List images\n\nSends a `GET` request to `/{account}/images`\n\nArguments:\n- `account`: Account login name\n- `name`: Filter by image name\n- `os`: Filter by OS\n- `owner`: Filter by owner\n- `public`: Filter by public/private\n- `state`: Filter by state\n- `type_`: Filter by type\n- `version`: Filter by version\n```ignore\nlet response = client.list_images()\n    .account(account)\n    .name(name)\n    .os(os)\n    .owner(owner)\n    .public(public)\n    .state(state)\n    .type_(type_)\n    .version(version)\n    .send()\n    .await;\n```"]
+    #[doc = "List images\n\nSends a `GET` request to `/{account}/images`\n\nArguments:\n- `account`: Account login name\n- `limit`: Maximum number of images to return\n- `marker`: Pagination marker: return the images listed after this image UUID\n- `name`: Filter by image name\n- `os`: Filter by OS\n- `owner`: Filter by owner\n- `public`: Filter by public/private\n- `state`: Filter by state\n- `type_`: Filter by type\n- `version`: Filter by version\n```ignore\nlet response = client.list_images()\n    .account(account)\n    .limit(limit)\n    .marker(marker)\n    .name(name)\n    .os(os)\n    .owner(owner)\n    .public(public)\n    .state(state)\n    .type_(type_)\n    .version(version)\n    .send()\n    .await;\n```"]
     pub fn list_images(&self) -> builder::ListImages<'_> {
         builder::ListImages::new(self)
     }
@@ -19862,7 +20718,7 @@ impl Client {
         builder::CreateOrImportImage::new(self)
     }
 
-    #[doc = "Head images\n\nSends a `HEAD` request to `/{account}/images`\n\nArguments:\n- `account`: Account login name\n- `name`: Filter by image name\n- `os`: Filter by OS\n- `owner`: Filter by owner\n- `public`: Filter by public/private\n- `state`: Filter by state\n- `type_`: Filter by type\n- `version`: Filter by version\n```ignore\nlet response = client.head_images()\n    .account(account)\n    .name(name)\n    .os(os)\n    .owner(owner)\n    .public(public)\n    .state(state)\n    .type_(type_)\n    .version(version)\n    .send()\n    .await;\n```"]
+    #[doc = "Head images\n\nSends a `HEAD` request to `/{account}/images`\n\nArguments:\n- `account`: Account login name\n- `limit`: Maximum number of images to return\n- `marker`: Pagination marker: return the images listed after this image UUID\n- `name`: Filter by image name\n- `os`: Filter by OS\n- `owner`: Filter by owner\n- `public`: Filter by public/private\n- `state`: Filter by state\n- `type_`: Filter by type\n- `version`: Filter by version\n```ignore\nlet response = client.head_images()\n    .account(account)\n    .limit(limit)\n    .marker(marker)\n    .name(name)\n    .os(os)\n    .owner(owner)\n    .public(public)\n    .state(state)\n    .type_(type_)\n    .version(version)\n    .send()\n    .await;\n```"]
     pub fn head_images(&self) -> builder::HeadImages<'_> {
         builder::HeadImages::new(self)
     }
@@ -19937,6 +20793,11 @@ impl Client {
         builder::GetProvisioningLimits::new(self)
     }
 
+    #[doc = "Export machines\n\nStreams the account's machines as CSV or newline-delimited JSON with the requested columns, for inventories too large to list as one JSON array. The `Content-Type` is `text/csv` or `application/x-ndjson`. Like `machinesummary`, this is not a CloudAPI endpoint, and is not under `/{account}/machines/` for the same reason.\n\nSends a `GET` request to `/{account}/machineexport`\n\nArguments:\n- `account`: Account login name\n- `brand`: Filter by brand (accepts any brand including internal-only brands)\n- `columns`: Comma-separated columns to export, in order (default `id,name,state,brand,package,memory,disk,primaryIp,created`)\n- `docker`: Filter by docker flag\n- `format`: Output format (default `csv`)\n- `image`: Filter by image UUID\n- `memory`: Filter by memory (MB)\n- `name`: Filter by machine name\n- `state`: Filter by state\n- `tag`: Filter by tag (key=value)\n- `tombstone`: Include destroyed/tombstone machines\n- `type_`: Filter by machine type (smartmachine or virtualmachine)\n```ignore\nlet response = client.export_machines()\n    .account(account)\n    .brand(brand)\n    .columns(columns)\n    .docker(docker)\n    .format(format)\n    .image(image)\n    .memory(memory)\n    .name(name)\n    .state(state)\n    .tag(tag)\n    .tombstone(tombstone)\n    .type_(type_)\n    .send()\n    .await;\n```"]
+    pub fn export_machines(&self) -> builder::ExportMachines<'_> {
+        builder::ExportMachines::new(self)
+    }
+
     #[doc = "List machines\n\nSends a `GET` request to `/{account}/machines`\n\nArguments:\n- `account`: Account login name\n- `brand`: Filter by brand (accepts any brand including internal-only brands)\n- `credentials`: Include generated credentials in response\n- `docker`: Filter by docker flag (added in CloudAPI 8.0.0)\n- `image`: Filter by image UUID\n- `limit`: Pagination limit\n- `memory`: Filter by memory (MB)\n- `name`: Filter by machine name\n- `offset`: Pagination offset\n- `state`: Filter by state\n- `tag`: Filter by tag (modern format: key=value)\n- `tombstone`: Include destroyed/tombstone machines\n- `type_`: Filter by machine type (smartmachine or virtualmachine)\n```ignore\nlet response = client.list_machines()\n    .account(account)\n    .brand(brand)\n    .credentials(credentials)\n    .docker(docker)\n    .image(image)\n    .limit(limit)\n    .memory(memory)\n    .name(name)\n    .offset(offset)\n    .state(state)\n    .tag(tag)\n    .tombstone(tombstone)\n    .type_(type_)\n    .send()\n    .await;\n```"]
     pub fn list_machines(&self) -> builder::ListMachines<'_> {
         builder::ListMachines::new(self)
@@ -20072,7 +20933,7 @@ impl Client {
         builder::MigrateMachineEstimate::new(self)
     }
 
-    #[doc = "Perform migration action\n\nPerforms a migration action on a machine. The `action` field in the request body specifies the operation:\n\n- `begin`: Start a new migration. Optionally accepts `affinity` rules. - `sync`: Sync data to the target server. - `switch`: Switch the instance to the new server (finalize migration). - `automatic`: Perform automatic migration (begin + sync + switch). - `abort`: Cancel the migration and clean up. - `pause`: Pause an in-progress migration. - `finalize`: Clean up after a successful switch.\n\nReturns the updated migration object (except for `finalize` which returns an empty response on success).\n\nSends a `POST` request to `/{account}/machines/{machine}/migrate`\n\nArguments:\n- `account`: Account login name\n- `machine`: Machine UUID\n- `body`\n```ignore\nlet response = client.migrate()\n    .account(account)\n    .machine(machine)\n    .body(body)\n    .send()\n    .await;\n```"]
+    #[doc = "Perform migration action\n\nPerforms a migration action on a machine. The action can be specified either in the request body (`{\"action\": \"begin\"}`) or as a query parameter (`?action=begin`). Body takes precedence over the query parameter. The action is one of:\n\n- `begin`: Start a new migration. Optionally accepts `affinity` rules. - `sync`: Sync data to the target server. - `switch`: Switch the instance to the new server (finalize migration). - `automatic`: Perform automatic migration (begin + sync + switch). - `abort`: Cancel the migration and clean up. - `pause`: Pause an in-progress migration. - `finalize`: Clean up after a successful switch.\n\nReturns the updated migration object (except for `finalize` which returns an empty response on success).\n\nSends a `POST` request to `/{account}/machines/{machine}/migrate`\n\nArguments:\n- `account`: Account login name\n- `machine`: Machine UUID\n- `action`: Migration action to perform. Optional in the query string because clients may send it in the request body instead. Body takes precedence over the query parameter.\n- `body`\n```ignore\nlet response = client.migrate()\n    .account(account)\n    .machine(machine)\n    .action(action)\n    .body(body)\n    .send()\n    .await;\n```"]
     pub fn migrate(&self) -> builder::Migrate<'_> {
         builder::Migrate::new(self)
     }
@@ -20187,6 +21048,11 @@ impl Client {
         builder::GetMachineVnc::new(self)
     }
 
+    #[doc = "Summarize machines\n\nCounts the account's machines by state, brand and package, for dashboards that do not need the machines themselves. This is not a CloudAPI endpoint, and would be `/{account}/machines/summary`, but Dropshot does not allow a literal segment at the same path depth as `/{account}/machines/{machine}`.\n\nSends a `GET` request to `/{account}/machinesummary`\n\nArguments:\n- `account`: Account login name\n```ignore\nlet response = client.get_machine_summary()\n    .account(account)\n    .send()\n    .await;\n```"]
+    pub fn get_machine_summary(&self) -> builder::GetMachineSummary<'_> {
+        builder::GetMachineSummary::new(self)
+    }
+
     #[doc = "List migrations\n\nReturns a list of all migrations for the account.\n\nSends a `GET` request to `/{account}/migrations`\n\nArguments:\n- `account`: Account login name\n```ignore\nlet response = client.list_migrations()\n    .account(account)\n    .send()\n    .await;\n```"]
     pub fn list_migrations(&self) -> builder::ListMigrations<'_> {
         builder::ListMigrations::new(self)
@@ -20197,7 +21063,7 @@ impl Client {
         builder::GetMigration::new(self)
     }
 
-    #[doc = "Watch migration progress\n\nStreams real-time migration progress updates via WebSocket. Returns a stream of JSON objects with progress information.\n\nSends a `GET` request to `/{account}/migrations/{machine}/watch`\n\nArguments:\n- `account`: Account login name\n- `machine`: Machine UUID\n```ignore\nlet response = client.watch_migration()\n    .account(account)\n    .machine(machine)\n    .send()\n    .await;\n```"]
+    #[doc = "Watch migration progress\n\nStreams real-time migration progress updates via WebSocket. Each message is a JSON-encoded `MigrationWatchEvent`: `progress` events while the current action runs, then a final `end` event.\n\nSends a `GET` request to `/{account}/migrations/{machine}/watch`\n\nArguments:\n- `account`: Account login name\n- `machine`: Machine UUID\n```ignore\nlet response = client.watch_migration()\n    .account(account)\n    .machine(machine)\n    .send()\n    .await;\n```"]
     pub fn watch_migration(&self) -> builder::WatchMigration<'_> {
         builder::WatchMigration::new(self)
     }
@@ -22389,7 +23255,9 @@ pub mod builder {
         }
 
         #[doc = "Sends a `GET` request to `/{account}/datacenters`"]
-        pub async fn send(self) -> Result<ResponseValue<types::Datacenters>, Error<types::Error>> {
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<cloudapi_api::Datacenters>, Error<types::Error>> {
             let Self { client, account } = self;
             let account = account.map_err(Error::InvalidRequest)?;
             let url = format!(
@@ -24020,7 +24888,9 @@ pub mod builder {
         }
 
         #[doc = "Sends a `GET` request to `/{account}/foreigndatacenters`"]
-        pub async fn send(self) -> Result<ResponseValue<types::Datacenters>, Error<types::Error>> {
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<cloudapi_api::Datacenters>, Error<types::Error>> {
             let Self { client, account } = self;
             let account = account.map_err(Error::InvalidRequest)?;
             let url = format!(
@@ -25396,6 +26266,8 @@ pub mod builder {
     pub struct ListImages<'a> {
         client: &'a super::Client,
         account: Result<::std::string::String, String>,
+        limit: Result<Option<u64>, String>,
+        marker: Result<Option<::uuid::Uuid>, String>,
         name: Result<Option<::std::string::String>, String>,
         os: Result<Option<::std::string::String>, String>,
         owner: Result<Option<::uuid::Uuid>, String>,
@@ -25410,6 +26282,8 @@ pub mod builder {
             Self {
                 client: client,
                 account: Err("account was not initialized".to_string()),
+                limit: Ok(None),
+                marker: Ok(None),
                 name: Ok(None),
                 os: Ok(None),
                 owner: Ok(None),
@@ -25430,6 +26304,28 @@ pub mod builder {
             self
         }
 
+        pub fn limit<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u64>,
+        {
+            self.limit = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u64` for limit failed".to_string());
+            self
+        }
+
+        pub fn marker<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.marker = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for marker failed".to_string());
+            self
+        }
+
         pub fn name<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<::std::string::String>,
@@ -25511,6 +26407,8 @@ pub mod builder {
             let Self {
                 client,
                 account,
+                limit,
+                marker,
                 name,
                 os,
                 owner,
@@ -25520,6 +26418,8 @@ pub mod builder {
                 version,
             } = self;
             let account = account.map_err(Error::InvalidRequest)?;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let marker = marker.map_err(Error::InvalidRequest)?;
             let name = name.map_err(Error::InvalidRequest)?;
             let os = os.map_err(Error::InvalidRequest)?;
             let owner = owner.map_err(Error::InvalidRequest)?;
@@ -25545,6 +26445,8 @@ pub mod builder {
                     ::reqwest::header::ACCEPT,
                     ::reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .query(&progenitor_client::QueryParam::new("limit", &limit))
+                .query(&progenitor_client::QueryParam::new("marker", &marker))
                 .query(&progenitor_client::QueryParam::new("name", &name))
                 .query(&progenitor_client::QueryParam::new("os", &os))
                 .query(&progenitor_client::QueryParam::new("owner", &owner))
@@ -25833,6 +26735,8 @@ pub mod builder {
     pub struct HeadImages<'a> {
         client: &'a super::Client,
         account: Result<::std::string::String, String>,
+        limit: Result<Option<u64>, String>,
+        marker: Result<Option<::uuid::Uuid>, String>,
         name: Result<Option<::std::string::String>, String>,
         os: Result<Option<::std::string::String>, String>,
         owner: Result<Option<::uuid::Uuid>, String>,
@@ -25847,6 +26751,8 @@ pub mod builder {
             Self {
                 client: client,
                 account: Err("account was not initialized".to_string()),
+                limit: Ok(None),
+                marker: Ok(None),
                 name: Ok(None),
                 os: Ok(None),
                 owner: Ok(None),
@@ -25867,6 +26773,28 @@ pub mod builder {
             self
         }
 
+        pub fn limit<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u64>,
+        {
+            self.limit = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u64` for limit failed".to_string());
+            self
+        }
+
+        pub fn marker<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.marker = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for marker failed".to_string());
+            self
+        }
+
         pub fn name<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<::std::string::String>,
@@ -25946,6 +26874,8 @@ pub mod builder {
             let Self {
                 client,
                 account,
+                limit,
+                marker,
                 name,
                 os,
                 owner,
@@ -25955,6 +26885,8 @@ pub mod builder {
                 version,
             } = self;
             let account = account.map_err(Error::InvalidRequest)?;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let marker = marker.map_err(Error::InvalidRequest)?;
             let name = name.map_err(Error::InvalidRequest)?;
             let os = os.map_err(Error::InvalidRequest)?;
             let owner = owner.map_err(Error::InvalidRequest)?;
@@ -25976,6 +26908,8 @@ pub mod builder {
             let mut request = client
                 .client
                 .head(url)
+                .query(&progenitor_client::QueryParam::new("limit", &limit))
+                .query(&progenitor_client::QueryParam::new("marker", &marker))
                 .query(&progenitor_client::QueryParam::new("name", &name))
                 .query(&progenitor_client::QueryParam::new("os", &os))
                 .query(&progenitor_client::QueryParam::new("owner", &owner))
@@ -27314,6 +28248,245 @@ pub mod builder {
         }
     }
 
+    #[doc = "Builder for [`Client::export_machines`]\n\n[`Client::export_machines`]: super::Client::export_machines"]
+    #[derive(Debug, Clone)]
+    pub struct ExportMachines<'a> {
+        client: &'a super::Client,
+        account: Result<::std::string::String, String>,
+        brand: Result<Option<types::VmBrand>, String>,
+        columns: Result<Option<::std::string::String>, String>,
+        docker: Result<Option<bool>, String>,
+        format: Result<Option<types::MachineExportFormat>, String>,
+        image: Result<Option<::uuid::Uuid>, String>,
+        memory: Result<Option<u64>, String>,
+        name: Result<Option<::std::string::String>, String>,
+        state: Result<Option<types::MachineState>, String>,
+        tag: Result<Option<::std::string::String>, String>,
+        tombstone: Result<Option<bool>, String>,
+        type_: Result<Option<types::MachineType>, String>,
+    }
+
+    impl<'a> ExportMachines<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                account: Err("account was not initialized".to_string()),
+                brand: Ok(None),
+                columns: Ok(None),
+                docker: Ok(None),
+                format: Ok(None),
+                image: Ok(None),
+                memory: Ok(None),
+                name: Ok(None),
+                state: Ok(None),
+                tag: Ok(None),
+                tombstone: Ok(None),
+                type_: Ok(None),
+            }
+        }
+
+        pub fn account<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.account = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for account failed".to_string()
+            });
+            self
+        }
+
+        pub fn brand<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::VmBrand>,
+        {
+            self.brand = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `VmBrand` for brand failed".to_string());
+            self
+        }
+
+        pub fn columns<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.columns = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for columns failed".to_string()
+            });
+            self
+        }
+
+        pub fn docker<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<bool>,
+        {
+            self.docker = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `bool` for docker failed".to_string());
+            self
+        }
+
+        pub fn format<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::MachineExportFormat>,
+        {
+            self.format = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `MachineExportFormat` for format failed".to_string());
+            self
+        }
+
+        pub fn image<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::uuid::Uuid>,
+        {
+            self.image = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `:: uuid :: Uuid` for image failed".to_string());
+            self
+        }
+
+        pub fn memory<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u64>,
+        {
+            self.memory = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u64` for memory failed".to_string());
+            self
+        }
+
+        pub fn name<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.name = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for name failed".to_string()
+            });
+            self
+        }
+
+        pub fn state<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::MachineState>,
+        {
+            self.state = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `MachineState` for state failed".to_string());
+            self
+        }
+
+        pub fn tag<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.tag = value.try_into().map(Some).map_err(|_| {
+                "conversion to `:: std :: string :: String` for tag failed".to_string()
+            });
+            self
+        }
+
+        pub fn tombstone<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<bool>,
+        {
+            self.tombstone = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `bool` for tombstone failed".to_string());
+            self
+        }
+
+        pub fn type_<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::MachineType>,
+        {
+            self.type_ = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `MachineType` for type_ failed".to_string());
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/{account}/machineexport`"]
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+            let Self {
+                client,
+                account,
+                brand,
+                columns,
+                docker,
+                format,
+                image,
+                memory,
+                name,
+                state,
+                tag,
+                tombstone,
+                type_,
+            } = self;
+            let account = account.map_err(Error::InvalidRequest)?;
+            let brand = brand.map_err(Error::InvalidRequest)?;
+            let columns = columns.map_err(Error::InvalidRequest)?;
+            let docker = docker.map_err(Error::InvalidRequest)?;
+            let format = format.map_err(Error::InvalidRequest)?;
+            let image = image.map_err(Error::InvalidRequest)?;
+            let memory = memory.map_err(Error::InvalidRequest)?;
+            let name = name.map_err(Error::InvalidRequest)?;
+            let state = state.map_err(Error::InvalidRequest)?;
+            let tag = tag.map_err(Error::InvalidRequest)?;
+            let tombstone = tombstone.map_err(Error::InvalidRequest)?;
+            let type_ = type_.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/{}/machineexport",
+                client.baseurl,
+                encode_path(&account.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .query(&progenitor_client::QueryParam::new("brand", &brand))
+                .query(&progenitor_client::QueryParam::new("columns", &columns))
+                .query(&progenitor_client::QueryParam::new("docker", &docker))
+                .query(&progenitor_client::QueryParam::new("format", &format))
+                .query(&progenitor_client::QueryParam::new("image", &image))
+                .query(&progenitor_client::QueryParam::new("memory", &memory))
+                .query(&progenitor_client::QueryParam::new("name", &name))
+                .query(&progenitor_client::QueryParam::new("state", &state))
+                .query(&progenitor_client::QueryParam::new("tag", &tag))
+                .query(&progenitor_client::QueryParam::new("tombstone", &tombstone))
+                .query(&progenitor_client::QueryParam::new("type", &type_))
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "export_machines",
+            };
+            match (crate::auth::add_auth_headers)(&client.inner, &mut request).await {
+                Ok(_) => (),
+                Err(e) => return Err(Error::Custom(e.to_string())),
+            }
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+            }
+        }
+    }
+
     #[doc = "Builder for [`Client::list_machines`]\n\n[`Client::list_machines`]: super::Client::list_machines"]
     #[derive(Debug, Clone)]
     pub struct ListMachines<'a> {
@@ -30303,6 +31476,7 @@ pub mod builder {
         client: &'a super::Client,
         account: Result<::std::string::String, String>,
         machine: Result<::uuid::Uuid, String>,
+        action: Result<Option<types::MigrationAction>, String>,
         body: Result<types::builder::MigrateRequest, String>,
     }
 
@@ -30312,6 +31486,7 @@ pub mod builder {
                 client: client,
                 account: Err("account was not initialized".to_string()),
                 machine: Err("machine was not initialized".to_string()),
+                action: Ok(None),
                 body: Ok(::std::default::Default::default()),
             }
         }
@@ -30336,6 +31511,17 @@ pub mod builder {
             self
         }
 
+        pub fn action<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::MigrationAction>,
+        {
+            self.action = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `MigrationAction` for action failed".to_string());
+            self
+        }
+
         pub fn body<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<types::MigrateRequest>,
@@ -30362,10 +31548,12 @@ pub mod builder {
                 client,
                 account,
                 machine,
+                action,
                 body,
             } = self;
             let account = account.map_err(Error::InvalidRequest)?;
             let machine = machine.map_err(Error::InvalidRequest)?;
+            let action = action.map_err(Error::InvalidRequest)?;
             let body = body
                 .and_then(|v| types::MigrateRequest::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
@@ -30389,6 +31577,7 @@ pub mod builder {
                     ::reqwest::header::HeaderValue::from_static("application/json"),
                 )
                 .json(&body)
+                .query(&progenitor_client::QueryParam::new("action", &action))
                 .headers(header_map)
                 .build()?;
             let info = OperationInfo {
@@ -32580,6 +33769,81 @@ pub mod builder {
         }
     }
 
+    #[doc = "Builder for [`Client::get_machine_summary`]\n\n[`Client::get_machine_summary`]: super::Client::get_machine_summary"]
+    #[derive(Debug, Clone)]
+    pub struct GetMachineSummary<'a> {
+        client: &'a super::Client,
+        account: Result<::std::string::String, String>,
+    }
+
+    impl<'a> GetMachineSummary<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                account: Err("account was not initialized".to_string()),
+            }
+        }
+
+        pub fn account<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<::std::string::String>,
+        {
+            self.account = value.try_into().map_err(|_| {
+                "conversion to `:: std :: string :: String` for account failed".to_string()
+            });
+            self
+        }
+
+        #[doc = "Sends a `GET` request to `/{account}/machinesummary`"]
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::MachineSummary>, Error<types::Error>> {
+            let Self { client, account } = self;
+            let account = account.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/{}/machinesummary",
+                client.baseurl,
+                encode_path(&account.to_string()),
+            );
+            let mut header_map = ::reqwest::header::HeaderMap::with_capacity(1usize);
+            header_map.append(
+                ::reqwest::header::HeaderName::from_static("api-version"),
+                ::reqwest::header::HeaderValue::from_static(super::Client::api_version()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    ::reqwest::header::ACCEPT,
+                    ::reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .headers(header_map)
+                .build()?;
+            let info = OperationInfo {
+                operation_id: "get_machine_summary",
+            };
+            match (crate::auth::add_auth_headers)(&client.inner, &mut request).await {
+                Ok(_) => (),
+                Err(e) => return Err(Error::Custom(e.to_string())),
+            }
+            client.pre(&mut request, &info).await?;
+            let result = client.exec(request, &info).await;
+            client.post(&result, &info).await?;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
     #[doc = "Builder for [`Client::list_migrations`]\n\n[`Client::list_migrations`]: super::Client::list_migrations"]
     #[derive(Debug, Clone)]
     pub struct ListMigrations<'a> {
@@ -36471,7 +37735,9 @@ pub mod builder {
         }
 
         #[doc = "Sends a `GET` request to `/{account}/services`"]
-        pub async fn send(self) -> Result<ResponseValue<types::Services>, Error<types::Error>> {
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<cloudapi_api::Services>, Error<types::Error>> {
             let Self { client, account } = self;
             let account = account.map_err(Error::InvalidRequest)?;
             let url = format!(
@@ -110,23 +110,25 @@ pub use types::{
 // which client they target).
 pub use cloudapi_api::{
     AccessKey, AccessKeyPath, AccessKeyStatus, Account, AccountPath, AddForeignDatacenterRequest,
-    AddMetadataRequest, AddNicRequest, AffinityRules, AuditEntry, ChangePasswordRequest,
-    ChangefeedChangeKind, ChangefeedMessage, ChangefeedResource, ChangefeedSubResource,
-    ChangefeedSubscription, Config, CreateAccessKeyRequest, CreateAccessKeyResponse,
-    CreateDiskRequest, CreateFabricNetworkRequest, CreateFabricVlanRequest,
-    CreateFirewallRuleRequest, CreateImageRequest, CreateMachineRequest, CreatePolicyRequest,
-    CreateRoleRequest, CreateSnapshotRequest, CreateSshKeyRequest, CreateUserRequest,
-    CreateVolumeRequest, CredentialType, Datacenter, Datacenters, Disk, DiskAction,
-    DiskActionQuery, DiskPath, DiskSpec, DiskState, FabricNetworkPath, FabricVlan, FabricVlanPath,
-    FirewallRule, FirewallRulePath, Image, ImageAcl, ImageAction, ImageActionQuery,
-    ImageCollectionActionQuery, ImagePath, ImageState, ImageType, KeyPath, ListImagesQuery,
-    ListMachinesQuery, Machine, MachineAction, MachineActionQuery, MachineNic, MachinePath,
-    MachineState, Metadata, MetadataKeyPath, MigrateRequest, Migration, MigrationAction,
-    MigrationEstimate, MigrationEstimateRequest, MigrationPhase, MigrationState, MountMode,
-    Network, NetworkIds, NetworkIp, NetworkIpPath, NetworkObject, NetworkPath, Nic, NicPath,
-    NicState, Package, PackagePath, Policy, PolicyPath, PolicyRef, PolicyRules, ProvisioningLimit,
-    ProvisioningLimits, ReplaceRoleTagsRequest, Resolvers, Role, RolePath, RoleTags, Service,
-    Services, Snapshot, SnapshotPath, SnapshotState, SshKey, TagPath, Tags, TagsRequest, Timestamp,
+    AddMetadataRequest, AddNicRequest, AffinityRules, AuditAction, AuditCaller, AuditCallerType,
+    AuditEntry, ChangePasswordRequest, ChangefeedChangeKind, ChangefeedMessage, ChangefeedResource,
+    ChangefeedSubResource, ChangefeedSubscription, Config, CreateAccessKeyRequest,
+    CreateAccessKeyResponse, CreateDiskRequest, CreateFabricNetworkRequest,
+    CreateFabricVlanRequest, CreateFirewallRuleRequest, CreateImageRequest, CreateMachineRequest,
+    CreatePolicyRequest, CreateRoleRequest, CreateSnapshotRequest, CreateSshKeyRequest,
+    CreateUserRequest, CreateVolumeRequest, CredentialType, Datacenter, Datacenters, Disk,
+    DiskAction, DiskActionQuery, DiskPath, DiskSpec, DiskState, FabricNetworkPath, FabricVlan,
+    FabricVlanPath, FirewallRule, FirewallRulePath, Image, ImageAcl, ImageAction, ImageActionQuery,
+    ImageCollectionActionQuery, ImagePath, ImageState, ImageType, KeyPath, LimitBy, LimitCheck,
+    ListImagesQuery, ListMachinesQuery, Machine, MachineAction, MachineActionQuery, MachineNic,
+    MachinePath, MachineState, Metadata, MetadataKeyPath, MigrateRequest, Migration,
+    MigrationAction, MigrationActionQuery, MigrationEndEvent, MigrationEstimate,
+    MigrationEstimateRequest, MigrationPhase, MigrationProgressEvent, MigrationState,
+    MigrationWatchEvent, MountMode, Network, NetworkIds, NetworkIp, NetworkIpPath, NetworkObject,
+    NetworkPath, NetworkPool, Nic, NicPath, NicState, Package, PackagePath, Policy, PolicyPath,
+    PolicyRef, PolicyRules, ProvisionCandidate, ProvisioningLimit, ProvisioningLimits,
+    ReplaceRoleTagsRequest, Resolvers, Role, RolePath, RoleTags, Service, Services, Snapshot,
+    SnapshotPath, SnapshotState, SshKey, TagPath, Tags, TagsRequest, Timestamp,
     UpdateAccessKeyRequest, UpdateAccountRequest, UpdateConfigRequest, UpdateFabricNetworkRequest,
     UpdateFabricVlanRequest, UpdateFirewallRuleRequest, UpdateNetworkIpRequest,
     UpdatePolicyRequest, UpdateRoleRequest, UpdateUserRequest, User, UserAccessKeyPath, UserPath,
@@ -580,9 +582,14 @@ fn fake_response_body(
                 subnet: None,
                 netmask: None,
                 vlan_id: None,
+                nic_tag: None,
+                mtu: None,
                 suffixes: None,
                 resolvers: None,
                 routes: None,
+                pool: None,
+                networks: None,
+                nic_tags_present: None,
                 role_tag: None,
             };
             serde_json::to_vec(&fake).expect("Network serialization should not fail")
@@ -885,6 +892,47 @@ fn to_json_value<T: serde::Serialize>(value: &T) -> serde_json::Value {
     serde_json::to_value(value).expect("request serialization should not fail")
 }
 
+// =============================================================================
+// Audit log helpers
+// =============================================================================
+
+impl types::AuditEntry {
+    /// The action as an [`AuditAction`], `Unknown` for unrecognized names
+    pub fn action_kind(&self) -> AuditAction {
+        AuditAction::from(self.action.as_str())
+    }
+
+    /// Whether the action is recorded as having succeeded
+    pub fn succeeded(&self) -> bool {
+        self.success == Some(types::AuditSuccess::Yes)
+    }
+
+    /// How the caller authenticated, if recorded
+    pub fn caller_type(&self) -> Option<types::AuditCallerType> {
+        self.caller.as_ref().and_then(|caller| caller.type_)
+    }
+
+    /// Client IP address the action came from, if recorded
+    pub fn caller_ip(&self) -> Option<&str> {
+        self.caller.as_ref().and_then(|caller| caller.ip.as_deref())
+    }
+
+    /// Signing key used for the action (`/<login>/keys/<fingerprint>`), if
+    /// recorded
+    pub fn key_id(&self) -> Option<&str> {
+        self.caller
+            .as_ref()
+            .and_then(|caller| caller.key_id.as_deref())
+    }
+
+    /// Fingerprint of the signing key, taken from the end of the key ID
+    pub fn key_fingerprint(&self) -> Option<&str> {
+        self.key_id()
+            .and_then(|key_id| key_id.rsplit_once("/keys/"))
+            .map(|(_, fingerprint)| fingerprint)
+    }
+}
+
 impl TypedClient {
     // ========================================================================
     // Machine Creation (body transformation handled by ClientHooks pre-hook)
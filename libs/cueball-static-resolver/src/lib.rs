@@ -1,9 +1,22 @@
 // Copyright 2019 Joyent, Inc.
+// Copyright 2026 Edgecast Cloud LLC.
 
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::net::{IpAddr, ToSocketAddrs};
+use std::str::FromStr;
 use std::sync::mpsc::Sender;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
 
 use cueball::backend::*;
-use cueball::resolver::{BackendAddedMsg, BackendMsg, Resolver};
+use cueball::resolver::{
+    BackendAddedMsg, BackendMsg, BackendRemovedMsg, Resolver,
+};
+
+/// The default interval at which [`StaticResolver`] re-resolves hostnames.
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
 
 pub struct StaticIpResolver {
     backends: Vec<(BackendAddress, BackendPort)>,
@@ -38,3 +51,434 @@ impl Resolver for StaticIpResolver {
         }
     }
 }
+
+/// A backend host in a [`StaticResolver`] backend list: either a literal IP
+/// address or a hostname to be resolved.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BackendHost {
+    Address(BackendAddress),
+    Hostname(String),
+}
+
+impl From<BackendAddress> for BackendHost {
+    fn from(address: BackendAddress) -> Self {
+        BackendHost::Address(address)
+    }
+}
+
+impl FromStr for BackendHost {
+    type Err = std::convert::Infallible;
+
+    /// Parse an IP address literal, treating anything else as a hostname.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<IpAddr>() {
+            Ok(address) => BackendHost::Address(address),
+            Err(_) => BackendHost::Hostname(s.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for BackendHost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackendHost::Address(address) => write!(f, "{}", address),
+            BackendHost::Hostname(hostname) => write!(f, "{}", hostname),
+        }
+    }
+}
+
+/// Function used to resolve a hostname to its set of addresses.
+pub type LookupFn =
+    Box<dyn Fn(&str, BackendPort) -> io::Result<Vec<IpAddr>> + Send>;
+
+/// A static backend list that may contain hostnames as well as IP addresses.
+///
+/// Hostnames are resolved with the system resolver when the resolver starts
+/// and again every refresh interval. Each time the resolved set of backends
+/// changes the pool is sent an `AddedMsg` for every new address and a
+/// `RemovedMsg` for every address that went away. If a lookup fails, the
+/// addresses last resolved for that hostname are kept rather than removed, so
+/// a transient DNS outage does not drain the pool.
+///
+/// A backend list made up only of IP addresses behaves exactly like
+/// [`StaticIpResolver`]: the backends are added once and `run` returns.
+///
+/// The backend list can also be changed while the resolver runs, through a
+/// [`StaticResolverHandle`]. As long as a handle exists the resolver keeps
+/// running, and applies each change as soon as it is made.
+pub struct StaticResolver {
+    shared: Arc<SharedBackends>,
+    refresh_interval: Duration,
+    lookup: LookupFn,
+}
+
+// The backend list, shared with the resolver's handles
+struct SharedBackends {
+    list: Mutex<BackendList>,
+    changed: Condvar,
+}
+
+struct BackendList {
+    backends: Vec<(BackendHost, BackendPort)>,
+    // Set when a handle changes the list, and cleared once the resolver has
+    // seen the change
+    changed: bool,
+}
+
+impl StaticResolver {
+    pub fn new(backends: Vec<(BackendHost, BackendPort)>) -> Self {
+        StaticResolver {
+            shared: Arc::new(SharedBackends {
+                list: Mutex::new(BackendList {
+                    backends,
+                    changed: false,
+                }),
+                changed: Condvar::new(),
+            }),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            lookup: Box::new(system_lookup),
+        }
+    }
+
+    /// Return a handle that changes the backend list while the resolver
+    /// runs. Take the handle before the resolver is given to the connection
+    /// pool: a resolver with no handle and no hostnames returns from `run`
+    /// once its backends are added.
+    pub fn handle(&self) -> StaticResolverHandle {
+        StaticResolverHandle(Arc::clone(&self.shared))
+    }
+
+    /// Set how often hostnames are re-resolved.
+    pub fn refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Replace the function used to resolve hostnames.
+    pub fn lookup(mut self, lookup: LookupFn) -> Self {
+        self.lookup = lookup;
+        self
+    }
+
+    fn backends(&self) -> Vec<(BackendHost, BackendPort)> {
+        self.shared.list.lock().unwrap().backends.clone()
+    }
+
+    // Whether the resolver has to keep running after the backends are added:
+    // hostnames need to be re-resolved, and handles may change the list.
+    fn is_dynamic(&self) -> bool {
+        Arc::strong_count(&self.shared) > 1
+            || self
+                .backends()
+                .iter()
+                .any(|(host, _)| matches!(host, BackendHost::Hostname(_)))
+    }
+
+    // Wait until a handle changes the backend list, or for the refresh
+    // interval if none does.
+    fn wait_for_change(&self) {
+        let list = self.shared.list.lock().unwrap();
+        let (mut list, _) = self
+            .shared
+            .changed
+            .wait_timeout_while(list, self.refresh_interval, |list| {
+                !list.changed
+            })
+            .unwrap();
+        list.changed = false;
+    }
+
+    // Resolve every entry in the backend list.  `last` holds the backends
+    // from the previous pass, and is used for any hostname whose lookup
+    // fails.
+    fn resolve(
+        &self,
+        last: &HashMap<(BackendHost, BackendPort), Vec<Backend>>,
+    ) -> HashMap<(BackendHost, BackendPort), Vec<Backend>> {
+        self.backends()
+            .into_iter()
+            .map(|(host, port)| {
+                let backends = match &host {
+                    BackendHost::Address(address) => {
+                        vec![Backend::new(address, port)]
+                    }
+                    BackendHost::Hostname(hostname) => {
+                        match (self.lookup)(hostname, port) {
+                            Ok(addresses) => addresses
+                                .iter()
+                                .map(|address| Backend::new(address, port))
+                                .collect(),
+                            Err(_) => last
+                                .get(&(host.clone(), port))
+                                .cloned()
+                                .unwrap_or_default(),
+                        }
+                    }
+                };
+                ((host, port), backends)
+            })
+            .collect()
+    }
+}
+
+/// Changes the backend list of a running [`StaticResolver`].
+///
+/// The pool is sent an `AddedMsg` for every backend added to the list and a
+/// `RemovedMsg` for every backend removed from it, exactly as when a hostname
+/// resolves to a different set of addresses. Handles can be cloned and used
+/// from any thread.
+#[derive(Clone)]
+pub struct StaticResolverHandle(Arc<SharedBackends>);
+
+impl StaticResolverHandle {
+    /// Replace the backend list.
+    pub fn set_backends(&self, backends: Vec<(BackendHost, BackendPort)>) {
+        self.update(|list| *list = backends);
+    }
+
+    /// Add a backend to the list, if it is not there already.
+    pub fn add_backend(&self, host: BackendHost, port: BackendPort) {
+        self.update(|list| {
+            if !list.contains(&(host.clone(), port)) {
+                list.push((host, port));
+            }
+        });
+    }
+
+    /// Remove a backend from the list.
+    pub fn remove_backend(&self, host: &BackendHost, port: BackendPort) {
+        self.update(|list| list.retain(|(h, p)| !(h == host && *p == port)));
+    }
+
+    fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&mut Vec<(BackendHost, BackendPort)>),
+    {
+        let mut list = self.0.list.lock().unwrap();
+        f(&mut list.backends);
+        list.changed = true;
+        self.0.changed.notify_one();
+    }
+}
+
+impl Resolver for StaticResolver {
+    fn run(&mut self, s: Sender<BackendMsg>) {
+        let mut last = HashMap::new();
+        let mut current: HashMap<BackendKey, Backend> = HashMap::new();
+
+        loop {
+            let resolved = self.resolve(&last);
+            let next: HashMap<BackendKey, Backend> = resolved
+                .values()
+                .flatten()
+                .map(|backend| (srv_key(backend), backend.clone()))
+                .collect();
+
+            for msg in backend_changes(&current, &next) {
+                if s.send(msg).is_err() {
+                    return;
+                }
+            }
+
+            last = resolved;
+            current = next;
+
+            if !self.is_dynamic() {
+                return;
+            }
+
+            self.wait_for_change();
+
+            // Nothing is sent when the backend set is unchanged, so check
+            // that the pool is still listening before resolving again.
+            if s.send(BackendMsg::HeartbeatMsg).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// Messages taking the pool from the `current` set of backends to `next`.
+// Removals are ordered before additions.
+fn backend_changes(
+    current: &HashMap<BackendKey, Backend>,
+    next: &HashMap<BackendKey, Backend>,
+) -> Vec<BackendMsg> {
+    let removed = current
+        .keys()
+        .filter(|key| !next.contains_key(key))
+        .map(|key| BackendMsg::RemovedMsg(BackendRemovedMsg(key.clone())));
+
+    let added = next
+        .iter()
+        .filter(|(key, _)| !current.contains_key(key))
+        .map(|(key, backend)| {
+            BackendMsg::AddedMsg(BackendAddedMsg {
+                key: key.clone(),
+                backend: backend.clone(),
+            })
+        });
+
+    removed.chain(added).collect()
+}
+
+fn system_lookup(hostname: &str, port: BackendPort) -> io::Result<Vec<IpAddr>> {
+    let mut addresses: Vec<IpAddr> = (hostname, port)
+        .to_socket_addrs()?
+        .map(|addr| addr.ip())
+        .collect();
+    addresses.sort();
+    addresses.dedup();
+    Ok(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::thread;
+
+    fn ip(last: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, last))
+    }
+
+    fn added(rx: &Receiver<BackendMsg>) -> Vec<IpAddr> {
+        let mut addresses: Vec<IpAddr> = rx
+            .try_iter()
+            .filter_map(|msg| match msg {
+                BackendMsg::AddedMsg(m) => Some(m.backend.address),
+                _ => None,
+            })
+            .collect();
+        addresses.sort();
+        addresses
+    }
+
+    #[test]
+    fn backend_host_from_str() {
+        assert_eq!(
+            "10.0.0.1".parse::<BackendHost>().unwrap(),
+            BackendHost::Address(ip(1))
+        );
+        assert_eq!(
+            "moray.example.com".parse::<BackendHost>().unwrap(),
+            BackendHost::Hostname("moray.example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn ip_only_backends_added_once() {
+        let (tx, rx) = channel();
+        let mut resolver = StaticResolver::new(vec![
+            (ip(1).into(), 2020),
+            (ip(2).into(), 2020),
+        ]);
+
+        // Returns without sleeping since there is nothing to re-resolve.
+        resolver.run(tx);
+        assert_eq!(added(&rx), vec![ip(1), ip(2)]);
+    }
+
+    #[test]
+    fn hostname_changes_emit_added_and_removed() {
+        let answers = Arc::new(Mutex::new(vec![
+            Ok(vec![ip(1), ip(2)]),
+            Err(io::Error::new(io::ErrorKind::Other, "SERVFAIL")),
+            Ok(vec![ip(2), ip(3)]),
+        ]));
+        let answers_clone = Arc::clone(&answers);
+        let mut resolver = StaticResolver::new(vec![(
+            BackendHost::Hostname("moray.example.com".into()),
+            2020,
+        )])
+        .refresh_interval(Duration::from_millis(1))
+        .lookup(Box::new(move |_, _| {
+            let mut answers = answers_clone.lock().unwrap();
+            if answers.is_empty() {
+                Ok(vec![ip(2), ip(3)])
+            } else {
+                answers.remove(0)
+            }
+        }));
+
+        let (tx, rx) = channel();
+        let handle = thread::spawn(move || resolver.run(tx));
+
+        let mut msgs = Vec::new();
+        while msgs.len() < 4 {
+            match rx.recv().unwrap() {
+                BackendMsg::HeartbeatMsg => (),
+                msg => msgs.push(msg),
+            }
+        }
+        drop(rx);
+        handle.join().unwrap();
+
+        let mut first: Vec<IpAddr> = msgs[..2]
+            .iter()
+            .map(|msg| match msg {
+                BackendMsg::AddedMsg(m) => m.backend.address,
+                _ => panic!("expected AddedMsg"),
+            })
+            .collect();
+        first.sort();
+        assert_eq!(first, vec![ip(1), ip(2)]);
+
+        // The failed lookup keeps 10.0.0.1 and 10.0.0.2; the next one
+        // replaces 10.0.0.1 with 10.0.0.3.
+        let removed_key = srv_key(&Backend::new(&ip(1), 2020));
+        assert!(
+            msgs[2] == BackendMsg::RemovedMsg(BackendRemovedMsg(removed_key))
+        );
+        match &msgs[3] {
+            BackendMsg::AddedMsg(m) => assert_eq!(m.backend.address, ip(3)),
+            _ => panic!("expected AddedMsg"),
+        }
+    }
+
+    #[test]
+    fn handle_updates_backends() {
+        let mut resolver = StaticResolver::new(vec![
+            (ip(1).into(), 2020),
+            (ip(2).into(), 2020),
+        ])
+        .refresh_interval(Duration::from_millis(1));
+        let handle = resolver.handle();
+
+        let (tx, rx) = channel();
+        let resolver_thread = thread::spawn(move || resolver.run(tx));
+
+        let recv = || loop {
+            match rx.recv().unwrap() {
+                BackendMsg::HeartbeatMsg => (),
+                msg => return msg,
+            }
+        };
+        for _ in 0..2 {
+            assert!(matches!(recv(), BackendMsg::AddedMsg(_)));
+        }
+
+        handle.remove_backend(&ip(1).into(), 2020);
+        let removed_key = srv_key(&Backend::new(&ip(1), 2020));
+        assert!(
+            recv() == BackendMsg::RemovedMsg(BackendRemovedMsg(removed_key))
+        );
+
+        // Adding a backend that is already in the list changes nothing
+        handle.add_backend(ip(2).into(), 2020);
+        handle.add_backend(ip(3).into(), 2020);
+        match recv() {
+            BackendMsg::AddedMsg(m) => assert_eq!(m.backend.address, ip(3)),
+            _ => panic!("expected AddedMsg"),
+        }
+
+        // The resolver returns once the handle is gone and the pool stops
+        // listening
+        drop(handle);
+        drop(recv);
+        drop(rx);
+        resolver_thread.join().unwrap();
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Connection pool draining and rebalancing as the backend list of a running
+//! `StaticResolver` is changed through its handle.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cueball::backend::{Backend, BackendPort};
+use cueball::connection::Connection;
+use cueball::connection_pool::types::ConnectionPoolOptions;
+use cueball::connection_pool::ConnectionPool;
+use cueball::error::Error;
+use cueball_static_resolver::{BackendHost, StaticResolver};
+
+const PORT_A: BackendPort = 55555;
+const PORT_B: BackendPort = 55556;
+const PORT_C: BackendPort = 55557;
+
+// The number of open connections to each backend, by port
+type OpenConnections = Arc<Mutex<HashMap<BackendPort, u32>>>;
+
+#[derive(Debug)]
+pub struct CountedConnection {
+    port: BackendPort,
+    open: OpenConnections,
+}
+
+impl Connection for CountedConnection {
+    type Error = Error;
+
+    fn connect(&mut self) -> Result<(), Error> {
+        *self.open.lock().unwrap().entry(self.port).or_insert(0) += 1;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), Error> {
+        let mut open = self.open.lock().unwrap();
+        if let Some(count) = open.get_mut(&self.port) {
+            *count -= 1;
+            if *count == 0 {
+                open.remove(&self.port);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn host() -> BackendHost {
+    IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)).into()
+}
+
+fn pool_options(drain_grace_period: Option<u64>) -> ConnectionPoolOptions {
+    ConnectionPoolOptions {
+        max_connections: Some(4),
+        claim_timeout: Some(1000),
+        log: None,
+        rebalancer_action_delay: Some(10),
+        decoherence_interval: None,
+        connection_check_interval: None,
+        drain_grace_period,
+    }
+}
+
+fn connection_fn(
+    open: &OpenConnections,
+) -> impl FnMut(&Backend) -> CountedConnection + Send + 'static {
+    let open = Arc::clone(open);
+    move |b| CountedConnection {
+        port: b.port,
+        open: Arc::clone(&open),
+    }
+}
+
+fn expected(counts: &[(BackendPort, u32)]) -> HashMap<BackendPort, u32> {
+    counts.iter().cloned().collect()
+}
+
+// Wait up to ten seconds for `done` to return true
+fn wait_for<F>(what: &str, mut done: F)
+where
+    F: FnMut() -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !done() {
+        assert!(Instant::now() < deadline, "timed out waiting for {}", what);
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn removed_backend_drained_and_added_backend_balanced() {
+    let open = OpenConnections::default();
+    let resolver =
+        StaticResolver::new(vec![(host(), PORT_A), (host(), PORT_B)]);
+    let handle = resolver.handle();
+    let pool =
+        ConnectionPool::new(pool_options(None), resolver, connection_fn(&open));
+
+    wait_for("initial connections", || {
+        *open.lock().unwrap() == expected(&[(PORT_A, 2), (PORT_B, 2)])
+    });
+
+    handle.set_backends(vec![(host(), PORT_B), (host(), PORT_C)]);
+
+    // The idle connections to A are closed without waiting for a claim, and
+    // C gets its share of the connections.
+    wait_for("rebalance onto C", || {
+        *open.lock().unwrap() == expected(&[(PORT_B, 2), (PORT_C, 2)])
+    });
+    wait_for("pool stats", || {
+        let stats = pool.get_stats().unwrap();
+        stats.total_connections == 4.into()
+            && stats.idle_connections == 4.into()
+    });
+}
+
+#[test]
+fn claimed_connection_closed_when_returned() {
+    let open = OpenConnections::default();
+    let resolver =
+        StaticResolver::new(vec![(host(), PORT_A), (host(), PORT_B)]);
+    let handle = resolver.handle();
+    let pool = ConnectionPool::new(
+        pool_options(Some(60_000)),
+        resolver,
+        connection_fn(&open),
+    );
+
+    wait_for("initial connections", || {
+        *open.lock().unwrap() == expected(&[(PORT_A, 2), (PORT_B, 2)])
+    });
+
+    let mut claimed: Vec<_> = (0..4).map(|_| pool.claim().unwrap()).collect();
+    let i = claimed.iter().position(|conn| conn.port == PORT_A).unwrap();
+    let held = claimed.remove(i);
+    drop(claimed);
+
+    handle.remove_backend(&host(), PORT_A);
+
+    // The claimed connection is left open while B takes over the others
+    wait_for("drain of idle connections", || {
+        *open.lock().unwrap() == expected(&[(PORT_A, 1), (PORT_B, 4)])
+    });
+    assert_eq!(pool.get_stats().unwrap().total_connections, 5.into());
+
+    // and is closed, not returned to the pool, once it is released
+    drop(held);
+    wait_for("drain of claimed connection", || {
+        *open.lock().unwrap() == expected(&[(PORT_B, 4)])
+    });
+    let stats = pool.get_stats().unwrap();
+    assert_eq!(stats.total_connections, 4.into());
+    assert_eq!(stats.idle_connections, 4.into());
+}
+
+#[test]
+fn claimed_connection_written_off_after_grace_period() {
+    let open = OpenConnections::default();
+    let resolver =
+        StaticResolver::new(vec![(host(), PORT_A), (host(), PORT_B)]);
+    let handle = resolver.handle();
+    let pool = ConnectionPool::new(
+        pool_options(Some(100)),
+        resolver,
+        connection_fn(&open),
+    );
+
+    wait_for("initial connections", || {
+        *open.lock().unwrap() == expected(&[(PORT_A, 2), (PORT_B, 2)])
+    });
+
+    let mut claimed: Vec<_> = (0..4).map(|_| pool.claim().unwrap()).collect();
+    let i = claimed.iter().position(|conn| conn.port == PORT_A).unwrap();
+    let held = claimed.remove(i);
+    drop(claimed);
+
+    handle.remove_backend(&host(), PORT_A);
+
+    // Once the grace period has elapsed the pool stops counting the claimed
+    // connection
+    wait_for("grace period", || {
+        pool.get_stats().unwrap().total_connections == 4.into()
+    });
+    assert_eq!(*open.lock().unwrap(), expected(&[(PORT_A, 1), (PORT_B, 4)]));
+
+    drop(held);
+    wait_for("close of claimed connection", || {
+        *open.lock().unwrap() == expected(&[(PORT_B, 4)])
+    });
+    let stats = pool.get_stats().unwrap();
+    assert_eq!(stats.total_connections, 4.into());
+    assert_eq!(stats.idle_connections, 4.into());
+}
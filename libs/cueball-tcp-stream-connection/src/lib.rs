@@ -1,41 +1,104 @@
 // Copyright 2019 Joyent, Inc.
 
 use std::io::Error as IOError;
-use std::net::{SocketAddr, TcpStream};
+use std::net::{Shutdown, SocketAddr, TcpStream};
 use std::ops::{Deref, DerefMut};
+use std::time::Duration;
 
 use cueball::backend::Backend;
 use cueball::connection::Connection;
+use socket2::{SockRef, TcpKeepalive};
+
+/// Socket options applied to each connection made by a `TcpStreamWrapper`.
+///
+/// The default leaves every option at the operating system default, which
+/// means `connect` can block for minutes against a backend that silently
+/// drops packets. Pools that need to notice dead backends promptly should
+/// set at least a connect timeout and keepalive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConnectionOptions {
+    /// Maximum time to wait for the TCP handshake to complete.
+    pub connect_timeout: Option<Duration>,
+    /// Idle time before TCP keepalive probes are sent.  Keepalive is left
+    /// disabled when this is `None`.
+    pub keepalive: Option<Duration>,
+    /// Disable Nagle's algorithm (`TCP_NODELAY`).
+    pub nodelay: bool,
+    /// Timeout for blocking reads on the established stream.
+    pub read_timeout: Option<Duration>,
+    /// Timeout for blocking writes on the established stream.
+    pub write_timeout: Option<Duration>,
+}
+
+impl ConnectionOptions {
+    fn apply(&self, stream: &TcpStream) -> Result<(), IOError> {
+        if let Some(idle) = self.keepalive {
+            let keepalive = TcpKeepalive::new().with_time(idle);
+            SockRef::from(stream).set_tcp_keepalive(&keepalive)?;
+        }
+        stream.set_nodelay(self.nodelay)?;
+        stream.set_read_timeout(self.read_timeout)?;
+        stream.set_write_timeout(self.write_timeout)?;
+        Ok(())
+    }
+}
 
 #[derive(Debug)]
 pub struct TcpStreamWrapper {
     pub stream: Option<TcpStream>,
     addr: SocketAddr,
+    options: ConnectionOptions,
     connected: bool,
+    broken: bool,
 }
 
 impl TcpStreamWrapper {
-    pub fn new(b: &Backend) -> Self {
+    pub fn new(b: &Backend, options: ConnectionOptions) -> Self {
         let addr = SocketAddr::from((b.address, b.port));
 
         TcpStreamWrapper {
             stream: None,
             addr,
+            options,
             connected: false,
+            broken: false,
         }
     }
+
+    /// Shut the stream down and have the pool discard this connection
+    /// instead of handing it out again.
+    ///
+    /// Use this when a request was abandoned part way through, leaving its
+    /// response unread on the stream where the next user would find it.
+    pub fn mark_broken(&mut self) {
+        if let Some(stream) = &self.stream {
+            // Nothing more can be done if the shutdown fails; the pool
+            // drops the connection either way
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+        self.broken = true;
+    }
 }
 
 impl Connection for TcpStreamWrapper {
     type Error = IOError;
 
     fn connect(&mut self) -> Result<(), Self::Error> {
-        let stream = TcpStream::connect(&self.addr)?;
+        let stream = match self.options.connect_timeout {
+            Some(timeout) => TcpStream::connect_timeout(&self.addr, timeout)?,
+            None => TcpStream::connect(&self.addr)?,
+        };
+        self.options.apply(&stream)?;
         self.stream = Some(stream);
         self.connected = true;
+        self.broken = false;
         Ok(())
     }
 
+    fn has_broken(&self) -> bool {
+        self.broken
+    }
+
     fn close(&mut self) -> Result<(), Self::Error> {
         self.stream = None;
         self.connected = false;
@@ -56,3 +119,74 @@ impl DerefMut for TcpStreamWrapper {
         self.stream.as_mut().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Read;
+    use std::net::{IpAddr, Ipv4Addr, TcpListener};
+
+    fn listener_backend() -> (TcpListener, Backend) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let backend =
+            Backend::new(&IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), port);
+        (listener, backend)
+    }
+
+    #[test]
+    fn default_options() {
+        let (_listener, backend) = listener_backend();
+        let mut conn =
+            TcpStreamWrapper::new(&backend, ConnectionOptions::default());
+        conn.connect().unwrap();
+
+        assert!(!conn.nodelay().unwrap());
+        assert_eq!(conn.read_timeout().unwrap(), None);
+        assert_eq!(conn.write_timeout().unwrap(), None);
+        assert!(!SockRef::from(&*conn).keepalive().unwrap());
+    }
+
+    #[test]
+    fn options_applied_on_connect() {
+        let (_listener, backend) = listener_backend();
+        let options = ConnectionOptions {
+            connect_timeout: Some(Duration::from_secs(1)),
+            keepalive: Some(Duration::from_secs(30)),
+            nodelay: true,
+            read_timeout: Some(Duration::from_secs(5)),
+            write_timeout: Some(Duration::from_secs(7)),
+        };
+        let mut conn = TcpStreamWrapper::new(&backend, options);
+        conn.connect().unwrap();
+
+        assert!(conn.nodelay().unwrap());
+        assert_eq!(conn.read_timeout().unwrap(), Some(Duration::from_secs(5)));
+        assert_eq!(conn.write_timeout().unwrap(), Some(Duration::from_secs(7)));
+        assert!(SockRef::from(&*conn).keepalive().unwrap());
+
+        conn.close().unwrap();
+        assert!(conn.stream.is_none());
+    }
+
+    #[test]
+    fn mark_broken() {
+        let (listener, backend) = listener_backend();
+        let mut conn =
+            TcpStreamWrapper::new(&backend, ConnectionOptions::default());
+        conn.connect().unwrap();
+        let (mut server_side, _) = listener.accept().unwrap();
+        assert!(!conn.has_broken());
+
+        conn.mark_broken();
+        assert!(conn.has_broken());
+
+        // The peer sees the connection closed
+        let mut buf = [0; 1];
+        assert_eq!(server_side.read(&mut buf).unwrap(), 0);
+
+        conn.connect().unwrap();
+        assert!(!conn.has_broken());
+    }
+}
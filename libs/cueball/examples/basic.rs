@@ -121,6 +121,7 @@ fn main() {
         rebalancer_action_delay: None,
         decoherence_interval: None,
         connection_check_interval: None,
+        drain_grace_period: None,
     };
 
     let pool = ConnectionPool::new(pool_opts, resolver, DummyConnection::new);
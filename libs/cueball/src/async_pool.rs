@@ -0,0 +1,329 @@
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Adapters for using cueball from async code running on tokio
+//!
+//! The [`ConnectionPool`](../connection_pool/struct.ConnectionPool.html) is
+//! synchronous: it runs the resolver, establishes connections and checks their
+//! health on threads it owns, and `claim` blocks the calling thread until a
+//! connection becomes available or the claim timeout expires. Calling it
+//! directly from a tokio task stalls an executor thread for as long as the
+//! claim takes.
+//!
+//! This module provides async counterparts of the cueball traits,
+//! [`AsyncConnection`](trait.AsyncConnection.html) and
+//! [`AsyncResolver`](trait.AsyncResolver.html), together with an
+//! [`AsyncConnectionPool`](struct.AsyncConnectionPool.html) that manages them.
+//! The pool still does its work on its own threads; those threads drive the
+//! async connection and resolver futures to completion with the
+//! [`Handle`](https://docs.rs/tokio/1/tokio/runtime/struct.Handle.html) of the
+//! runtime the pool was created in. A claim that cannot be satisfied
+//! immediately waits on tokio's blocking thread pool rather than on an
+//! executor thread.
+//!
+//! Because the connection and resolver futures are driven from outside the
+//! runtime, the runtime must be able to make progress on its own. Use the
+//! multi-threaded runtime; a `current_thread` runtime only drives IO and timers
+//! while its own `block_on` call is running.
+//!
+//! This module is only available when the `tokio` feature is enabled.
+
+use std::error;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::mpsc::Sender;
+
+use tokio::runtime::Handle;
+use tokio::sync::mpsc;
+
+use crate::backend::Backend;
+use crate::connection::Connection;
+use crate::connection_pool::types::{
+    ConnectionPoolOptions, ConnectionPoolStats,
+};
+use crate::connection_pool::{ConnectionPool, PoolConnection};
+use crate::error::Error;
+use crate::resolver::{BackendMsg, Resolver};
+
+/// Async cueball connection
+///
+/// The async counterpart of the [`Connection`](../connection/trait.Connection.html)
+/// trait. The methods have the same meaning as their synchronous equivalents,
+/// but `connect`, `is_valid` and `close` return futures so that
+/// implementations can use async IO.
+pub trait AsyncConnection: Send + Sized + 'static {
+    /// The error type returned by the `connect` or `close` functions.
+    type Error: error::Error;
+
+    /// Attempt to establish the connection to a backend.
+    fn connect(
+        &mut self,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Check to see if the connection is still up and working.
+    fn is_valid(&mut self) -> impl Future<Output = bool> + Send {
+        async { true }
+    }
+
+    /// Check to see if the connection has closed or is not operational. This
+    /// is called as a connection is returned to the pool and must not block.
+    fn has_broken(&self) -> bool {
+        false
+    }
+
+    /// Close the connection to the backend
+    fn close(&mut self)
+        -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Async cueball backend resolver
+///
+/// The async counterpart of the [`Resolver`](../resolver/trait.Resolver.html)
+/// trait. The future returned by `run` should notify the connection pool of
+/// backends using the provided sender and complete once the receiving end of
+/// the channel is closed or the resolver encounters an unrecoverable error.
+/// The future is also dropped once the connection pool has stopped and a
+/// message sent to it can no longer be delivered.
+pub trait AsyncResolver: Send + 'static {
+    /// Start the operation of the resolver.
+    fn run(
+        &mut self,
+        s: mpsc::UnboundedSender<BackendMsg>,
+    ) -> impl Future<Output = ()> + Send;
+}
+
+/// Wraps an [`AsyncConnection`](trait.AsyncConnection.html) so that it can be
+/// managed by a [`ConnectionPool`](../connection_pool/struct.ConnectionPool.html)
+///
+/// The [`Connection`](../connection/trait.Connection.html) methods block the
+/// calling thread on the runtime handle until the async operation completes.
+/// The connection pool only calls them from threads it owns, so they never run
+/// on an executor thread.
+pub struct BlockingConnection<C> {
+    inner: C,
+    handle: Handle,
+}
+
+impl<C> BlockingConnection<C>
+where
+    C: AsyncConnection,
+{
+    /// Wrap `inner`, driving its futures with `handle`
+    pub fn new(inner: C, handle: Handle) -> Self {
+        BlockingConnection { inner, handle }
+    }
+
+    /// Return a reference to the wrapped connection
+    pub fn get_ref(&self) -> &C {
+        &self.inner
+    }
+
+    /// Return a mutable reference to the wrapped connection
+    pub fn get_mut(&mut self) -> &mut C {
+        &mut self.inner
+    }
+
+    /// Consume the wrapper, returning the wrapped connection
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C> Connection for BlockingConnection<C>
+where
+    C: AsyncConnection,
+{
+    type Error = C::Error;
+
+    fn connect(&mut self) -> Result<(), Self::Error> {
+        self.handle.block_on(self.inner.connect())
+    }
+
+    fn is_valid(&mut self) -> bool {
+        self.handle.block_on(self.inner.is_valid())
+    }
+
+    fn has_broken(&self) -> bool {
+        self.inner.has_broken()
+    }
+
+    fn close(&mut self) -> Result<(), Self::Error> {
+        self.handle.block_on(self.inner.close())
+    }
+}
+
+impl<C> Deref for BlockingConnection<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C> DerefMut for BlockingConnection<C> {
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.inner
+    }
+}
+
+/// Wraps an [`AsyncResolver`](trait.AsyncResolver.html) so that it can be run
+/// by a [`ConnectionPool`](../connection_pool/struct.ConnectionPool.html)
+///
+/// Messages sent by the async resolver are forwarded to the connection pool.
+/// When the pool stops listening the forwarding fails and the resolver future
+/// is dropped, which ends `run`.
+pub struct BlockingResolver<R> {
+    inner: R,
+    handle: Handle,
+}
+
+impl<R> BlockingResolver<R>
+where
+    R: AsyncResolver,
+{
+    /// Wrap `inner`, driving its future with `handle`
+    pub fn new(inner: R, handle: Handle) -> Self {
+        BlockingResolver { inner, handle }
+    }
+}
+
+impl<R> Resolver for BlockingResolver<R>
+where
+    R: AsyncResolver,
+{
+    fn run(&mut self, s: Sender<BackendMsg>) {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let inner = &mut self.inner;
+
+        self.handle.block_on(async move {
+            let forward = async {
+                while let Some(msg) = rx.recv().await {
+                    if s.send(msg).is_err() {
+                        break;
+                    }
+                }
+            };
+
+            tokio::select! {
+                _ = inner.run(tx) => (),
+                _ = forward => (),
+            }
+        });
+    }
+}
+
+type ConnectionFn<C> =
+    Box<dyn FnMut(&Backend) -> BlockingConnection<C> + Send + 'static>;
+
+/// A connection claimed from an
+/// [`AsyncConnectionPool`](struct.AsyncConnectionPool.html). It dereferences
+/// to the [`BlockingConnection`](struct.BlockingConnection.html) wrapper, which
+/// in turn dereferences to the async connection. The connection is returned to
+/// the pool when it falls out of scope.
+pub type AsyncPoolConnection<C, R> =
+    PoolConnection<BlockingConnection<C>, BlockingResolver<R>, ConnectionFn<C>>;
+
+/// A cueball connection pool for async connections and resolvers
+///
+/// The pool must be created from within a tokio runtime. Dropping the pool (or
+/// calling `stop`) waits for the pool's threads to exit and blocks the calling
+/// thread, so a pool that is shut down from async code should be stopped with
+/// [`spawn_blocking`](https://docs.rs/tokio/1/tokio/task/fn.spawn_blocking.html).
+pub struct AsyncConnectionPool<C, R>
+where
+    C: AsyncConnection,
+    R: AsyncResolver,
+{
+    pool: ConnectionPool<
+        BlockingConnection<C>,
+        BlockingResolver<R>,
+        ConnectionFn<C>,
+    >,
+}
+
+impl<C, R> Clone for AsyncConnectionPool<C, R>
+where
+    C: AsyncConnection,
+    R: AsyncResolver,
+{
+    fn clone(&self) -> Self {
+        AsyncConnectionPool {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl<C, R> AsyncConnectionPool<C, R>
+where
+    C: AsyncConnection,
+    R: AsyncResolver,
+{
+    /// Create a new connection pool. `create_connection` has the same role as
+    /// the function passed to `ConnectionPool::new`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn new<F>(
+        cpo: ConnectionPoolOptions,
+        resolver: R,
+        mut create_connection: F,
+    ) -> Self
+    where
+        F: FnMut(&Backend) -> C + Send + 'static,
+    {
+        let handle = Handle::current();
+        let resolver = BlockingResolver::new(resolver, handle.clone());
+        let create_connection: ConnectionFn<C> = Box::new(move |b| {
+            BlockingConnection::new(create_connection(b), handle.clone())
+        });
+
+        AsyncConnectionPool {
+            pool: ConnectionPool::new(cpo, resolver, create_connection),
+        }
+    }
+
+    /// Claim a connection from the pool, waiting up to the configured claim
+    /// timeout for one to become available.
+    ///
+    /// If no connection is idle the wait happens on tokio's blocking thread
+    /// pool. Dropping the returned future does not cancel that wait; a
+    /// connection it claims is returned to the pool straight away.
+    pub fn claim(
+        &self,
+    ) -> impl Future<Output = Result<AsyncPoolConnection<C, R>, Error>> + Send
+    {
+        let immediate = self.pool.try_claim();
+        let pool = self.pool.clone();
+
+        async move {
+            if let Some(conn) = immediate {
+                return Ok(conn);
+            }
+
+            tokio::task::spawn_blocking(move || pool.claim())
+                .await
+                .unwrap_or(Err(Error::ConnectionRetrievalFailure))
+        }
+    }
+
+    /// Claim a connection from the pool if one is idle. This never waits.
+    pub fn try_claim(&self) -> Option<AsyncPoolConnection<C, R>> {
+        self.pool.try_claim()
+    }
+
+    /// Stop the connection pool. See `ConnectionPool::stop`; this blocks the
+    /// calling thread.
+    pub fn stop(&mut self) -> Result<(), Error> {
+        self.pool.stop()
+    }
+
+    /// Return the connection counts of the pool
+    pub fn get_stats(&self) -> Option<ConnectionPoolStats> {
+        self.pool.get_stats()
+    }
+
+    /// Return the state of the pool
+    pub fn get_state(&self) -> String {
+        self.pool.get_state()
+    }
+}
@@ -39,6 +39,8 @@ const DEFAULT_REBALANCE_ACTION_DELAY: u64 = 100;
 const DEFAULT_DECOHERENCE_INTERVAL: u64 = 300;
 // Connection health check interval in seconds
 const DEFAULT_CONNECTION_CHECK_INTERVAL: u64 = 30;
+// Drain grace period for removed backends in milliseconds
+const DEFAULT_DRAIN_GRACE_PERIOD: u64 = 30000;
 
 /// A pool of connections to a multi-node service
 pub struct ConnectionPool<C, R, F>
@@ -203,11 +205,15 @@ where
         let rebalancer_clone = rebalancer_check.clone();
 
         let resolver_log_clone = logger.clone();
+        let drain_grace_period = time::Duration::from_millis(
+            cpo.drain_grace_period.unwrap_or(DEFAULT_DRAIN_GRACE_PERIOD),
+        );
         let resolver_rx_thread = thread::spawn(move || {
             resolver_recv_loop::<C>(
                 resolver_rx,
                 protected_data_clone,
                 rebalancer_clone,
+                drain_grace_period,
                 resolver_log_clone,
             )
         });
@@ -420,7 +426,10 @@ where
                 }
             }
 
+            // Connections still claimed are closed as they are returned
             connection_data.stats.total_connections = 0.into();
+            connection_data.unwanted_connection_counts.clear();
+            connection_data.draining.clear();
 
             // Move state to Stopped
             self.state = ConnectionPoolState::Stopped;
@@ -438,16 +447,11 @@ where
         let mut waiting_for_connection = true;
         let mut result = Err(Error::DummyError);
 
-        let mut unwanted_connection_counts: HashMap<
-            BackendKey,
-            ConnectionCount,
-        > = connection_data.unwanted_connection_counts.drain().collect();
-
         while waiting_for_connection {
             if connection_data.stats.idle_connections > 0.into() {
                 match connection_data.connections.pop_front() {
                     Some(ConnectionKeyPair((key, Some(conn)))) => {
-                        if unwanted_connection_counts.contains_key(&key) {
+                        if is_unwanted(connection_data, &key) {
                             // This connection is unwanted so close it and try
                             // to claim the next one in the queue. Spawn a
                             // separate thread to close this connection. The
@@ -461,22 +465,11 @@ where
                             });
 
                             connection_data.stats.idle_connections -= 1.into();
-                            unwanted_connection_counts
-                                .entry(key.clone())
-                                .and_modify(|e| *e -= 1u32.into());
-                            if let Some(updated_count) =
-                                unwanted_connection_counts.get(&key)
-                            {
-                                info!(
-                                    self.log,
-                                    "Updated unwanted count for backend {}: {}",
-                                    &key,
-                                    updated_count
-                                );
-                                if *updated_count <= 0u32.into() {
-                                    unwanted_connection_counts.remove(&key);
-                                }
-                            }
+                            discard_connection(
+                                connection_data,
+                                &key,
+                                &self.log,
+                            );
                         } else {
                             info!(
                                 self.log,
@@ -519,8 +512,6 @@ where
             }
         }
 
-        connection_data.unwanted_connection_counts = unwanted_connection_counts;
-
         result
     }
 
@@ -531,16 +522,11 @@ where
         let mut waiting_for_connection = true;
         let mut result: Option<PoolConnection<C, R, F>> = None;
 
-        let mut unwanted_connection_counts: HashMap<
-            BackendKey,
-            ConnectionCount,
-        > = connection_data.unwanted_connection_counts.drain().collect();
-
         while waiting_for_connection {
             if connection_data.stats.idle_connections > 0.into() {
                 match connection_data.connections.pop_front() {
                     Some(ConnectionKeyPair((key, Some(conn)))) => {
-                        if unwanted_connection_counts.contains_key(&key) {
+                        if is_unwanted(connection_data, &key) {
                             // This connection is unwanted so close it and try
                             // to claim the next one in the queue. Spawn a
                             // separate thread to close this connection. The
@@ -554,23 +540,11 @@ where
                             });
 
                             connection_data.stats.idle_connections -= 1.into();
-
-                            unwanted_connection_counts
-                                .entry(key.clone())
-                                .and_modify(|e| *e -= 1u32.into());
-                            if let Some(updated_count) =
-                                unwanted_connection_counts.get(&key)
-                            {
-                                info!(
-                                    self.log,
-                                    "Updated unwanted count for backend {}: {}",
-                                    &key,
-                                    updated_count
-                                );
-                                if *updated_count <= 0u32.into() {
-                                    unwanted_connection_counts.remove(&key);
-                                }
-                            }
+                            discard_connection(
+                                connection_data,
+                                &key,
+                                &self.log,
+                            );
                         } else {
                             info!(
                                 self.log,
@@ -608,8 +582,6 @@ where
             }
         }
 
-        connection_data.unwanted_connection_counts = unwanted_connection_counts;
-
         result
     }
 
@@ -639,6 +611,16 @@ where
                 if conn.has_broken() {
                     warn!(self.log, "Found an invalid connection, not returning to the pool");
                     connection_data.stats.total_connections -= 1.into();
+                } else if is_unwanted(&connection_data, &key) {
+                    // The backend has been removed or has more connections
+                    // than its share, so close the connection instead of
+                    // returning it to the pool
+                    let close_log = self.log.clone();
+                    let close_key = key.clone();
+                    let _close_thread = thread::spawn(|| {
+                        close_connection(close_log, close_key, conn)
+                    });
+                    discard_connection(&mut connection_data, &key, &self.log);
                 } else {
                     connection_data.connections.push_back((key, conn).into());
                     connection_data.stats.idle_connections += 1.into();
@@ -745,6 +727,125 @@ where
     }
 }
 
+// Whether a connection to the backend `key` should be closed rather than
+// kept in the pool: either the backend has been removed, or the rebalancer has
+// found that it has more connections than its share.
+fn is_unwanted<C>(connection_data: &ConnectionData<C>, key: &BackendKey) -> bool
+where
+    C: Connection,
+{
+    !connection_data.backends.contains_key(key)
+        || connection_data
+            .unwanted_connection_counts
+            .get(key)
+            .map_or(false, |count| *count > 0.into())
+}
+
+// Account for an unwanted connection to the backend `key` being closed. A
+// connection to a backend that was removed since the last rebalance is still
+// counted in the connection distribution, while one that was claimed when its
+// drain grace period elapsed is no longer counted at all.
+fn discard_connection<C>(
+    connection_data: &mut ConnectionData<C>,
+    key: &BackendKey,
+    log: &Logger,
+) where
+    C: Connection,
+{
+    let counted = |counts: &HashMap<BackendKey, ConnectionCount>| {
+        counts.get(key).map_or(false, |count| *count > 0.into())
+    };
+
+    let counts = if counted(&connection_data.unwanted_connection_counts) {
+        &mut connection_data.unwanted_connection_counts
+    } else if counted(&connection_data.connection_distribution) {
+        &mut connection_data.connection_distribution
+    } else {
+        return;
+    };
+
+    if let Some(count) = counts.get_mut(key) {
+        *count -= 1.into();
+        info!(log, "Updated unwanted count for backend {}: {}", key, count);
+        if *count == 0.into() {
+            counts.remove(key);
+        }
+    }
+    connection_data.stats.total_connections -= 1.into();
+}
+
+// Close the idle connections that are no longer wanted and stop waiting for
+// claimed connections to removed backends once their drain grace period has
+// elapsed. Those are closed whenever they are returned to the pool.
+fn drain_connections<C>(log: &Logger, protected_data: ProtectedData<C>)
+where
+    C: Connection,
+{
+    let mut connection_data = protected_data.connection_data_lock();
+    if connection_data.unwanted_connection_counts.is_empty()
+        && connection_data.draining.is_empty()
+    {
+        return;
+    }
+
+    let queue: Vec<ConnectionKeyPair<C>> =
+        connection_data.connections.drain(..).collect();
+    for pair in queue {
+        match pair {
+            ConnectionKeyPair((key, Some(conn)))
+                if is_unwanted(&connection_data, &key) =>
+            {
+                let close_log = log.clone();
+                let close_key = key.clone();
+                let _close_thread = thread::spawn(|| {
+                    close_connection(close_log, close_key, conn)
+                });
+                connection_data.stats.idle_connections -= 1.into();
+                discard_connection(&mut connection_data, &key, log);
+            }
+            pair => connection_data.connections.push_back(pair),
+        }
+    }
+
+    let now = time::Instant::now();
+    let draining: Vec<(BackendKey, time::Instant)> = connection_data
+        .draining
+        .iter()
+        .map(|(key, deadline)| (key.clone(), *deadline))
+        .collect();
+    for (key, deadline) in draining {
+        let claimed: u32 = [
+            &connection_data.unwanted_connection_counts,
+            &connection_data.connection_distribution,
+        ]
+        .iter()
+        .filter_map(|counts| counts.get(&key))
+        .map(|count| u32::from(*count))
+        .sum();
+
+        if claimed == 0 {
+            debug!(log, "Finished draining backend {}", &key);
+        } else if now >= deadline {
+            warn!(
+                log,
+                "Drain grace period elapsed for backend {} with {} \
+                 connections still claimed",
+                &key,
+                claimed
+            );
+            let total: u32 = connection_data.stats.total_connections.into();
+            connection_data.stats.total_connections =
+                total.saturating_sub(claimed).into();
+        } else {
+            continue;
+        }
+
+        connection_data.unwanted_connection_counts.remove(&key);
+        connection_data.connection_distribution.remove(&key);
+        connection_data.draining.remove(&key);
+    }
+}
+
 fn add_backend<C>(
     msg: BackendAddedMsg,
     protected_data: ProtectedData<C>,
@@ -755,6 +856,10 @@ where
     let mut connection_data = protected_data.connection_data_lock();
 
     if !connection_data.backends.contains_key(&msg.key) {
+        // A backend that comes back before it has been drained stops
+        // draining. Connections the rebalancer has already found unwanted
+        // are still closed.
+        connection_data.draining.remove(&msg.key);
         connection_data
             .backends
             .insert(msg.key.clone(), msg.backend);
@@ -768,6 +873,7 @@ where
 fn remove_backend<C>(
     msg: BackendRemovedMsg,
     protected_data: ProtectedData<C>,
+    drain_grace_period: time::Duration,
     log: &Logger,
 ) -> Option<BackendAction>
 where
@@ -778,6 +884,9 @@ where
     if connection_data.backends.contains_key(&msg.0) {
         debug!(log, "Removing backend with key {}", &msg.0);
         connection_data.backends.remove(&msg.0);
+        connection_data
+            .draining
+            .insert(msg.0, time::Instant::now() + drain_grace_period);
         Some(BackendAction::BackendRemoved)
     } else {
         None
@@ -891,9 +1000,10 @@ where
             Ordering::Less => {
                 let connection_delta =
                     old_connection_count - new_connection_count;
+                connection_distribution.insert(b.clone(), new_connection_count);
                 unwanted_connection_counts
                     .entry(b.clone())
-                    .and_modify(|e| *e = connection_delta)
+                    .and_modify(|e| *e += connection_delta)
                     .or_insert(connection_delta);
             }
             Ordering::Equal => (),
@@ -978,10 +1088,6 @@ fn add_connections<C, F>(
                                 .push_back(connection_key_pair);
                             connection_data.stats.total_connections += 1.into();
                             connection_data.stats.idle_connections += 1.into();
-                            connection_data
-                                .unwanted_connection_counts
-                                .entry(b_key.clone())
-                                .and_modify(|e| *e -= 1u32.into());
                             connection_data.stats.pending_connections -=
                                 1.into();
 
@@ -1019,6 +1125,7 @@ fn resolver_recv_loop<C>(
     rx: Receiver<BackendMsg>,
     protected_data: ProtectedData<C>,
     rebalance_check: RebalanceCheck,
+    drain_grace_period: time::Duration,
     log: Logger,
 ) where
     C: Connection,
@@ -1032,9 +1139,12 @@ fn resolver_recv_loop<C>(
                 info!(log, "Adding backend {}", added_msg.key);
                 add_backend::<C>(added_msg, protected_data.clone())
             }
-            Ok(BackendMsg::RemovedMsg(removed_msg)) => {
-                remove_backend::<C>(removed_msg, protected_data.clone(), &log)
-            }
+            Ok(BackendMsg::RemovedMsg(removed_msg)) => remove_backend::<C>(
+                removed_msg,
+                protected_data.clone(),
+                drain_grace_period,
+                &log,
+            ),
             Ok(BackendMsg::StopMsg) => {
                 done = true;
                 None
@@ -1115,6 +1225,10 @@ fn rebalancer_loop<C, F>(
             *rebalance = false;
         }
 
+        // Runs at least every time the condvar wait times out, so that
+        // drain grace periods are enforced without a rebalance
+        drain_connections(&log, protected_data.clone());
+
         done = stop.load(AtomicOrdering::Relaxed);
     }
     trace!(log, "rebalancer_loop exiting");
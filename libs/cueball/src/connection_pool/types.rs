@@ -4,7 +4,7 @@ use std::cmp::Ordering;
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::sync::{Arc, Condvar, Mutex, MutexGuard};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use derive_more::{Add, AddAssign, Display, From, Into, Sub, SubAssign};
 use slog::Logger;
@@ -67,6 +67,12 @@ pub struct ConnectionPoolOptions {
     /// the period of the pool connection check task. If not specified the default is
     /// 30 seconds.
     pub connection_check_interval: Option<u64>,
+    /// Optional drain grace period in milliseconds. When a backend is removed
+    /// by the resolver its idle connections are closed, and connections that
+    /// are claimed at the time are closed as they are returned. Connections
+    /// still claimed once the grace period has elapsed are no longer counted
+    /// by the pool. If not specified the default is 30 seconds.
+    pub drain_grace_period: Option<u64>,
 }
 
 // This type wraps a pair that associates a `BackendKey` with a connection of
@@ -160,6 +166,7 @@ pub struct ConnectionData<C> {
     pub connections: VecDeque<ConnectionKeyPair<C>>,
     pub connection_distribution: HashMap<BackendKey, ConnectionCount>,
     pub unwanted_connection_counts: HashMap<BackendKey, ConnectionCount>,
+    pub draining: HashMap<BackendKey, Instant>,
     pub stats: ConnectionPoolStats,
 }
 
@@ -174,6 +181,7 @@ where
             connections: VecDeque::with_capacity(max_size),
             connection_distribution: HashMap::with_capacity(max_size),
             unwanted_connection_counts: HashMap::with_capacity(max_size),
+            draining: HashMap::new(),
             stats: ConnectionPoolStats::new(),
         }
     }
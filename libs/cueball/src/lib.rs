@@ -78,6 +78,21 @@
 //! connection count may exceed the maximum until those connections are returned
 //! to the connection pool and discarded.
 //!
+//! ## Draining
+//!
+//! When the [`Resolver`](resolver/trait.Resolver.html) removes a backend the
+//! connection pool drains the connections to it. Idle connections are closed
+//! once the rebalancer has added the connections replacing them on the
+//! remaining backends, and connections that are claimed at the time are closed
+//! as they are returned instead of going back into the pool. The same applies
+//! to the connections a backend has in excess of its share once a new backend
+//! is added.
+//!
+//! The pool waits up to a grace period, `drain_grace_period` (30 seconds by
+//! default), for claimed connections to a removed backend to be returned.
+//! Connections still claimed after that are no longer counted in the pool's
+//! connection statistics, and are closed whenever they are returned.
+//!
 //! ## Decoherence
 //!
 //! Decoherence in cueball is used to mean a periodic random shuffling of the order
@@ -198,9 +213,20 @@
 //!
 //! * [`cueball-tcp-stream-connection`](https://github.com/joyent/rust-cueball-tcp-stream-connection)
 //! * [`cueball-postgres-connection`](https://github.com/joyent/rust-cueball-postgres-connection)
+//!
+//! ## Use with tokio
+//!
+//! With the `tokio` feature enabled, the [`async_pool`](async_pool/index.html)
+//! module provides async variants of the
+//! [`Connection`](connection/trait.Connection.html) and
+//! [`Resolver`](resolver/trait.Resolver.html) traits and a connection pool
+//! that can be claimed from without blocking tokio executor threads.
 
 #![allow(missing_docs)]
 
+#[cfg(feature = "tokio")]
+pub mod async_pool;
+
 pub mod backend;
 pub mod connection;
 pub mod connection_pool;
@@ -0,0 +1,151 @@
+// Copyright 2026 Edgecast Cloud LLC.
+
+#![cfg(feature = "tokio")]
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use cueball::async_pool::{
+    AsyncConnection, AsyncConnectionPool, AsyncResolver,
+};
+use cueball::backend;
+use cueball::backend::{Backend, BackendAddress, BackendPort};
+use cueball::connection_pool::types::ConnectionPoolOptions;
+use cueball::error::Error;
+use cueball::resolver::{BackendAddedMsg, BackendMsg};
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+pub struct DummyAsyncConnection {
+    addr: SocketAddr,
+    connected: bool,
+}
+
+impl DummyAsyncConnection {
+    fn new(b: &Backend) -> Self {
+        let addr = SocketAddr::from((b.address, b.port));
+
+        DummyAsyncConnection {
+            addr,
+            connected: false,
+        }
+    }
+}
+
+impl AsyncConnection for DummyAsyncConnection {
+    type Error = Error;
+
+    async fn connect(&mut self) -> Result<(), Error> {
+        // Make sure the future is really driven by the runtime
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        self.connected = true;
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        self.connected = false;
+        Ok(())
+    }
+}
+
+pub struct FakeAsyncResolver {
+    backends: Vec<(BackendAddress, BackendPort)>,
+}
+
+impl FakeAsyncResolver {
+    pub fn new(backends: Vec<(BackendAddress, BackendPort)>) -> Self {
+        FakeAsyncResolver { backends }
+    }
+}
+
+impl AsyncResolver for FakeAsyncResolver {
+    async fn run(&mut self, s: UnboundedSender<BackendMsg>) {
+        self.backends.iter().for_each(|b| {
+            let backend = Backend::new(&b.0, b.1);
+            let backend_key = backend::srv_key(&backend);
+            let backend_msg = BackendMsg::AddedMsg(BackendAddedMsg {
+                key: backend_key,
+                backend,
+            });
+            s.send(backend_msg).unwrap();
+        });
+
+        while s.send(BackendMsg::HeartbeatMsg).is_ok() {
+            tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn async_connection_pool_claim() {
+    // Only use one backend to keep the test deterministic, as in
+    // basic_test::connection_pool_claim.
+    let be1 = (IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 55555);
+
+    let resolver = FakeAsyncResolver::new(vec![be1]);
+
+    let pool_opts = ConnectionPoolOptions {
+        max_connections: Some(3),
+        claim_timeout: Some(1000),
+        log: None,
+        rebalancer_action_delay: None,
+        decoherence_interval: None,
+        connection_check_interval: None,
+        drain_grace_period: None,
+    };
+
+    let max_connections = pool_opts.max_connections.unwrap();
+
+    let mut pool = AsyncConnectionPool::new(
+        pool_opts,
+        resolver,
+        DummyAsyncConnection::new,
+    );
+
+    // Wait for total_connections to reach the maximum
+    loop {
+        if let Some(stats) = pool.get_stats() {
+            if stats.total_connections == max_connections.into() {
+                break;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let conn1 = pool.claim().await.expect("first claim");
+    let conn2 = pool.claim().await.expect("second claim");
+    let conn3 = pool.claim().await.expect("third claim");
+    assert!(conn1.connected && conn2.connected && conn3.connected);
+    assert_eq!(conn1.addr, SocketAddr::from(be1));
+
+    assert!(pool.try_claim().is_none());
+
+    // Every connection is claimed, so this waits for the claim timeout. The
+    // wait must not tie up the runtime: a task spawned meanwhile still runs.
+    let waiting = pool.claim();
+    let other = tokio::spawn(async { 42 });
+    assert_eq!(other.await.unwrap(), 42);
+    match waiting.await {
+        Err(Error::ClaimFailure) => (),
+        Err(e) => panic!("unexpected claim error: {}", e),
+        Ok(_) => panic!("claim should have timed out"),
+    }
+
+    // A claim waiting when a connection is returned gets that connection
+    let waiting = tokio::spawn(pool.claim());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    drop(conn1);
+    assert!(waiting.await.unwrap().is_ok());
+
+    drop(conn2);
+    drop(conn3);
+    assert!(pool.try_claim().is_some());
+
+    let stopped = tokio::task::spawn_blocking(move || pool.stop())
+        .await
+        .unwrap();
+    assert!(stopped.is_ok());
+}
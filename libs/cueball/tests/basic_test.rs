@@ -115,6 +115,7 @@ fn connection_pool_claim() {
         rebalancer_action_delay: None,
         decoherence_interval: None,
         connection_check_interval: None,
+        drain_grace_period: None,
     };
 
     let max_connections = pool_opts.max_connections.unwrap().clone();
@@ -207,6 +208,7 @@ fn connection_pool_stop() {
         rebalancer_action_delay: None,
         decoherence_interval: None,
         connection_check_interval: None,
+        drain_grace_period: None,
     };
 
     let max_connections = pool_opts.max_connections.unwrap().clone();
@@ -247,6 +249,7 @@ fn connection_pool_accounting() {
         rebalancer_action_delay: None,
         decoherence_interval: None,
         connection_check_interval: None,
+        drain_grace_period: None,
     };
 
     let max_connections: ConnectionCount =
@@ -359,6 +362,7 @@ fn connection_pool_decoherence() {
         rebalancer_action_delay: Some(10000),
         decoherence_interval: Some(5),
         connection_check_interval: None,
+        drain_grace_period: None,
     };
 
     let max_connections: ConnectionCount =
@@ -396,6 +400,7 @@ fn connection_pool_no_backends() {
         rebalancer_action_delay: None,
         decoherence_interval: Some(10000),
         connection_check_interval: Some(1),
+        drain_grace_period: None,
     };
 
     let _pool = ConnectionPool::new(pool_opts, resolver, DummyConnection::new);
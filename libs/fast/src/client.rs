@@ -4,6 +4,9 @@
 
 use std::io::{Error, ErrorKind};
 use std::net::TcpStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use bytes::BytesMut;
 use serde_json::Value;
@@ -15,6 +18,81 @@ use crate::protocol::{
     FastMessageStatus, FastParseError,
 };
 
+/// How often a receive with a cancellation token but no nearer deadline
+/// wakes up to check whether it has been cancelled.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A handle for cancelling in-progress receives from another thread.
+///
+/// Clones share the same state, so one clone can be handed to the receiving
+/// side in `ReceiveOptions` and another kept to call `cancel` on.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Create a token that has not been cancelled.
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Cancel any receive using this token.  Receives notice within
+    /// `CANCEL_POLL_INTERVAL` and fail with `ErrorKind::Interrupted`.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether `cancel` has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Limits on how long `receive_with` may wait for a complete response.
+#[derive(Clone, Debug, Default)]
+pub struct ReceiveOptions {
+    /// Give up with `ErrorKind::TimedOut` once this instant has passed.
+    pub deadline: Option<Instant>,
+    /// Give up with `ErrorKind::Interrupted` once this token is cancelled.
+    pub cancel: Option<CancelToken>,
+}
+
+impl ReceiveOptions {
+    fn is_limited(&self) -> bool {
+        self.deadline.is_some() || self.cancel.is_some()
+    }
+
+    /// How long the next read may block for, or an error if the receive
+    /// should stop now.
+    fn next_wait(&self) -> Result<Duration, Error> {
+        if self
+            .cancel
+            .as_ref()
+            .map_or(false, CancelToken::is_cancelled)
+        {
+            return Err(Error::new(
+                ErrorKind::Interrupted,
+                "Request cancelled before the response was complete",
+            ));
+        }
+
+        let mut wait = CANCEL_POLL_INTERVAL;
+        if let Some(deadline) = self.deadline {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::new(
+                    ErrorKind::TimedOut,
+                    "Request deadline passed before the response was complete",
+                ));
+            }
+            let remaining = deadline - now;
+            if self.cancel.is_none() || remaining < wait {
+                wait = remaining;
+            }
+        }
+        Ok(wait)
+    }
+}
+
 enum BufferAction {
     Keep,
     Trim(usize),
@@ -46,17 +124,57 @@ pub fn send(
 /// `response_handler` on the response.
 pub fn receive<F>(
     stream: &mut TcpStream,
+    response_handler: F,
+) -> Result<usize, Error>
+where
+    F: FnMut(&FastMessage) -> Result<(), Error>,
+{
+    receive_with(stream, &ReceiveOptions::default(), response_handler)
+}
+
+/// Like `receive`, but stop waiting for the rest of the response once the
+/// deadline in `opts` passes or its cancellation token is cancelled.
+///
+/// A receive that stops early leaves the rest of the response unread, so
+/// the stream must not be used for further requests afterwards.  The
+/// stream's read timeout is restored before returning.
+pub fn receive_with<F>(
+    stream: &mut TcpStream,
+    opts: &ReceiveOptions,
     mut response_handler: F,
 ) -> Result<usize, Error>
 where
     F: FnMut(&FastMessage) -> Result<(), Error>,
 {
+    if !opts.is_limited() {
+        return receive_messages(stream, opts, &mut response_handler);
+    }
+
+    let read_timeout = stream.read_timeout()?;
+    let result = receive_messages(stream, opts, &mut response_handler);
+    stream.set_read_timeout(read_timeout)?;
+    result
+}
+
+fn receive_messages<F>(
+    stream: &mut TcpStream,
+    opts: &ReceiveOptions,
+    response_handler: &mut F,
+) -> Result<usize, Error>
+where
+    F: FnMut(&FastMessage) -> Result<(), Error>,
+{
+    let limited = opts.is_limited();
     let mut stream_end = false;
     let mut msg_buf: Vec<u8> = Vec::new();
     let mut total_bytes = 0;
     let mut result = Ok(total_bytes);
 
     while !stream_end {
+        if limited {
+            stream.set_read_timeout(Some(opts.next_wait()?))?;
+        }
+
         let mut read_buf = [0; 128];
         match stream.read(&mut read_buf) {
             Ok(0) => {
@@ -71,7 +189,7 @@ where
                 msg_buf.extend_from_slice(&read_buf[0..byte_count]);
                 match parse_and_handle_messages(
                     msg_buf.as_slice(),
-                    &mut response_handler,
+                    response_handler,
                 ) {
                     Ok(BufferAction::Keep) => (),
                     Ok(BufferAction::Trim(rest_offset)) => {
@@ -87,6 +205,11 @@ where
                     }
                 }
             }
+            // The read timeout we set expired; check the limits again
+            Err(ref err)
+                if limited
+                    && (err.kind() == ErrorKind::WouldBlock
+                        || err.kind() == ErrorKind::TimedOut) => {}
             Err(err) => {
                 result = Err(err);
                 stream_end = true
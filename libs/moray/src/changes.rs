@@ -0,0 +1,360 @@
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+//! Polling a bucket for changed objects.
+//!
+//! Moray has no way of pushing changes to its clients, so consumers that
+//! want to react to metadata changes have to look for them.  A
+//! `ChangeQuery` finds the objects in a bucket that were written after a
+//! `ChangeCheckpoint`, oldest first, and hands back the checkpoint of the
+//! last one.  The caller stores the checkpoint wherever suits it (it is
+//! serializable) and passes it to the next poll, so that a restarted
+//! consumer picks up where it left off.
+//!
+//! Changes are ordered by `_mtime`, with `_id` to order objects written in
+//! the same millisecond.  `_txn_snap` would order them by transaction, but
+//! it is not set on every object, so it is only passed along with each
+//! object for callers that want it.
+//!
+//! Two limitations follow from finding changes with a query:
+//!
+//! * Deleted objects are never seen.
+//! * A write whose transaction commits after a later write has been polled
+//!   has an `_mtime` behind the checkpoint, and would be missed.  Objects
+//!   written within the query's `settle` time are therefore left for a later
+//!   poll; it should be longer than any write transaction is expected to
+//!   take.
+//!
+//! Each change is handed to the caller at least once: if the handler fails
+//! part way through a page, the caller keeps its previous checkpoint and
+//! gets the objects of the page again on the next poll.
+
+use serde::{Deserialize, Serialize};
+use std::io::{Error, ErrorKind};
+use std::net::TcpStream;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::objects::{self, MethodOptions, Methods, MorayObject, SortOrder};
+
+/// Objects that are fetched per page unless `ChangeQuery::page_size` says
+/// otherwise.
+pub const DEFAULT_PAGE_SIZE: u64 = 1000;
+
+/// Time left for write transactions to commit unless
+/// `ChangeQuery::settle` says otherwise.
+pub const DEFAULT_SETTLE: Duration = Duration::from_secs(5);
+
+/// Position in the changes of a bucket: the `_mtime` and `_id` of the last
+/// object that was handed to the caller.  Checkpoints order as changes do,
+/// and the default checkpoint is before every object.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+pub struct ChangeCheckpoint {
+    pub mtime: u64,
+    pub id: u64,
+}
+
+impl ChangeCheckpoint {
+    /// The checkpoint just after `obj`.
+    pub fn of(obj: &MorayObject) -> Self {
+        ChangeCheckpoint {
+            mtime: obj._mtime,
+            id: obj._id,
+        }
+    }
+
+    /// The checkpoint at `time`: only objects written after it are changes.
+    pub fn at(time: SystemTime) -> Self {
+        ChangeCheckpoint {
+            mtime: millis_since_epoch(time),
+            id: u64::max_value(),
+        }
+    }
+}
+
+/// One page of changes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChangePage {
+    /// The checkpoint to poll from next.
+    pub checkpoint: ChangeCheckpoint,
+    /// Number of changed objects in the page.
+    pub count: u64,
+    /// Whether the page was full, so that more changes may follow it right
+    /// away.
+    pub more: bool,
+}
+
+/// Which changes to look for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeQuery {
+    bucket: String,
+    filter: Option<String>,
+    page_size: u64,
+    settle: Duration,
+}
+
+impl ChangeQuery {
+    pub fn new(bucket: &str) -> Self {
+        ChangeQuery {
+            bucket: bucket.to_string(),
+            filter: None,
+            page_size: DEFAULT_PAGE_SIZE,
+            settle: DEFAULT_SETTLE,
+        }
+    }
+
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Only look for changes to objects that match `filter`, e.g.
+    /// `(type=object)`.
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Fetch at most `page_size` objects per request.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Leave objects written within the last `settle` for a later poll.
+    pub fn settle(mut self, settle: Duration) -> Self {
+        self.settle = settle;
+        self
+    }
+
+    /// The findObjects filter for the page after `checkpoint`, polled at
+    /// `now`.
+    pub fn page_filter(
+        &self,
+        checkpoint: &ChangeCheckpoint,
+        now: SystemTime,
+    ) -> String {
+        // Moray filters have no strict comparisons, so "after" is spelled
+        // with `>=` on the next value.
+        let mut after = format!("(_mtime>={})", checkpoint.mtime + 1);
+        if checkpoint.id < u64::max_value() {
+            after = format!(
+                "(|{}(&(_mtime={})(_id>={})))",
+                after,
+                checkpoint.mtime,
+                checkpoint.id + 1
+            );
+        }
+
+        let mut terms = vec![after];
+        if self.settle > Duration::from_millis(0) {
+            let until = now.checked_sub(self.settle).unwrap_or(UNIX_EPOCH);
+            terms.push(format!("(_mtime<={})", millis_since_epoch(until)));
+        }
+        if let Some(filter) = &self.filter {
+            terms.push(filter.clone());
+        }
+
+        format!("(&{})", terms.concat())
+    }
+
+    /// The options for fetching a page, based on `opts`.
+    pub fn page_options(&self, opts: &MethodOptions) -> MethodOptions {
+        let mut opts = opts.clone();
+        opts.set_limit(self.page_size);
+        opts.unset_sort();
+        opts.add_sort("_mtime", SortOrder::Asc);
+        opts.add_sort("_id", SortOrder::Asc);
+        opts
+    }
+}
+
+fn millis_since_epoch(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// Hand a changed object to the caller, and move the page past it.
+fn take_change<F>(
+    page: &mut ChangePage,
+    obj: &MorayObject,
+    change_handler: &mut F,
+) -> Result<(), Error>
+where
+    F: FnMut(&MorayObject) -> Result<(), Error>,
+{
+    let checkpoint = ChangeCheckpoint::of(obj);
+    if checkpoint <= page.checkpoint {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "object '{}' in bucket '{}' is out of order \
+                 (_mtime {}, _id {} after _mtime {}, _id {})",
+                obj.key,
+                obj.bucket,
+                checkpoint.mtime,
+                checkpoint.id,
+                page.checkpoint.mtime,
+                page.checkpoint.id
+            ),
+        ));
+    }
+
+    change_handler(obj)?;
+    page.checkpoint = checkpoint;
+    page.count += 1;
+    Ok(())
+}
+
+/// Find the next page of changes after `checkpoint`, calling
+/// `change_handler` with each changed object, oldest first.
+pub fn find_changes<F>(
+    stream: &mut TcpStream,
+    query: &ChangeQuery,
+    checkpoint: &ChangeCheckpoint,
+    opts: &MethodOptions,
+    mut change_handler: F,
+) -> Result<ChangePage, Error>
+where
+    F: FnMut(&MorayObject) -> Result<(), Error>,
+{
+    let filter = query.page_filter(checkpoint, SystemTime::now());
+    let opts = query.page_options(opts);
+    let mut page = ChangePage {
+        checkpoint: *checkpoint,
+        count: 0,
+        more: false,
+    };
+
+    objects::get_find_objects(
+        stream,
+        &query.bucket,
+        &filter,
+        &opts,
+        Methods::Find,
+        |obj| take_change(&mut page, obj, &mut change_handler),
+    )?;
+
+    page.more = page.count >= query.page_size;
+    Ok(page)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn object(mtime: u64, id: u64) -> MorayObject {
+        MorayObject {
+            bucket: String::from("foo bucket"),
+            _count: 0,
+            _etag: String::from("F00DCAFE"),
+            _id: id,
+            _mtime: mtime,
+            _txn_snap: None,
+            key: format!("key-{}", id),
+            value: json!({}),
+        }
+    }
+
+    #[test]
+    fn page_filter_test() {
+        let now = UNIX_EPOCH + Duration::from_millis(10_000);
+        let query = ChangeQuery::new("foo bucket");
+        let checkpoint = ChangeCheckpoint { mtime: 100, id: 7 };
+
+        assert_eq!(
+            query.page_filter(&checkpoint, now),
+            "(&(|(_mtime>=101)(&(_mtime=100)(_id>=8)))(_mtime<=5000))"
+        );
+
+        let query = query
+            .filter("(type=object)")
+            .settle(Duration::from_millis(0));
+        assert_eq!(
+            query.page_filter(&checkpoint, now),
+            "(&(|(_mtime>=101)(&(_mtime=100)(_id>=8)))(type=object))"
+        );
+
+        // Nothing written in the same millisecond as a time checkpoint
+        // counts as a change.
+        let checkpoint = ChangeCheckpoint::at(now);
+        assert_eq!(
+            query.page_filter(&checkpoint, now),
+            "(&(_mtime>=10001)(type=object))"
+        );
+    }
+
+    #[test]
+    fn page_options_test() {
+        let query = ChangeQuery::new("foo bucket").page_size(50);
+        let mut opts = MethodOptions::default();
+        opts.add_sort("name", SortOrder::Desc);
+
+        let serialized = serde_json::to_value(query.page_options(&opts))
+            .expect("page options");
+        assert_eq!(serialized["limit"], 50);
+        assert_eq!(
+            serialized["sort"],
+            json!([
+                {"attribute": "_mtime", "order": "ASC"},
+                {"attribute": "_id", "order": "ASC"}
+            ])
+        );
+    }
+
+    #[test]
+    fn take_change_test() {
+        let mut page = ChangePage {
+            checkpoint: ChangeCheckpoint { mtime: 100, id: 7 },
+            count: 0,
+            more: false,
+        };
+        let mut seen = vec![];
+        let mut handler = |obj: &MorayObject| {
+            if obj._id == 3 {
+                return Err(Error::new(ErrorKind::Other, "handler failed"));
+            }
+            seen.push(obj._id);
+            Ok(())
+        };
+
+        take_change(&mut page, &object(100, 9), &mut handler).unwrap();
+        take_change(&mut page, &object(101, 2), &mut handler).unwrap();
+        assert_eq!(page.checkpoint, ChangeCheckpoint { mtime: 101, id: 2 });
+        assert_eq!(page.count, 2);
+
+        // An object at or before the checkpoint is not taken.
+        let err =
+            take_change(&mut page, &object(100, 20), &mut handler).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // Nor is one the handler fails on.
+        assert!(take_change(&mut page, &object(102, 3), &mut handler).is_err());
+        assert_eq!(page.checkpoint, ChangeCheckpoint { mtime: 101, id: 2 });
+        assert_eq!(page.count, 2);
+        assert_eq!(seen, vec![9, 2]);
+    }
+
+    #[test]
+    fn checkpoint_serialization_test() {
+        let checkpoint = ChangeCheckpoint { mtime: 100, id: 7 };
+        let serialized = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(serialized, r#"{"mtime":100,"id":7}"#);
+        let parsed: ChangeCheckpoint =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed, checkpoint);
+        assert!(ChangeCheckpoint::default() < checkpoint);
+    }
+}
@@ -6,22 +6,44 @@ use cueball::backend::Backend;
 use cueball::connection_pool::types::ConnectionPoolOptions;
 use cueball::connection_pool::ConnectionPool;
 use cueball_static_resolver::StaticIpResolver;
-use cueball_tcp_stream_connection::TcpStreamWrapper;
+use cueball_tcp_stream_connection::{ConnectionOptions, TcpStreamWrapper};
 
-use slog::Logger;
+use slog::{warn, Logger};
 use std::ops::DerefMut;
+use std::time::{Duration, Instant};
 
 use std::str::FromStr;
 
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::{self, Value};
 use std::io::{Error, ErrorKind};
 
 use std::net::{IpAddr, SocketAddr};
 
 use super::buckets;
+use super::changes;
 use super::meta;
+use super::metrics::Metrics;
 use super::objects;
 
+// A request that timed out or was cancelled (see `objects::MethodOptions`)
+// leaves the rest of its response unread on the connection.  Make sure the
+// pool does not hand that connection out again, where the next request would
+// read the stale response.
+fn discard_if_abandoned<T>(
+    conn: &mut TcpStreamWrapper,
+    result: Result<T, Error>,
+) -> Result<T, Error> {
+    if let Err(e) = &result {
+        if e.kind() == ErrorKind::TimedOut || e.kind() == ErrorKind::Interrupted
+        {
+            conn.mark_broken();
+        }
+    }
+    result
+}
+
 #[derive(Clone)]
 pub struct MorayClient {
     connection_pool: ConnectionPool<
@@ -29,6 +51,9 @@ pub struct MorayClient {
         StaticIpResolver,
         fn(&Backend) -> TcpStreamWrapper,
     >,
+    log: Logger,
+    metrics: Metrics,
+    slow_query_threshold: Option<Duration>,
 }
 
 ///
@@ -47,10 +72,11 @@ impl MorayClient {
             None => ConnectionPoolOptions {
                 max_connections: Some(2),
                 claim_timeout: Some(5000),
-                log: Some(log),
+                log: Some(log.clone()),
                 rebalancer_action_delay: None, // Default 100ms
                 decoherence_interval: None,    // Default 300s
                 connection_check_interval: None, // Default 30s
+                drain_grace_period: None,      // Default 30s
             },
             Some(opts) => opts,
         };
@@ -59,13 +85,58 @@ impl MorayClient {
             TcpStreamWrapper,
             StaticIpResolver,
             fn(&Backend) -> TcpStreamWrapper,
-        >::new(pool_opts, resolver, TcpStreamWrapper::new);
+        >::new(pool_opts, resolver, |b| {
+            TcpStreamWrapper::new(b, ConnectionOptions::default())
+        });
 
         Ok(MorayClient {
             connection_pool: pool,
+            log,
+            metrics: Metrics::new(),
+            slow_query_threshold: None,
         })
     }
 
+    /// Per-RPC metrics for this client (and its clones).  Use
+    /// `Metrics::set_exporter` to also feed them to the host service's
+    /// metrics registry.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Log a warning, with the bucket and key or filter, for every request
+    /// that takes at least `threshold`.  `None` (the default) turns slow
+    /// query logging off.
+    pub fn set_slow_query_threshold(&mut self, threshold: Option<Duration>) {
+        self.slow_query_threshold = threshold;
+    }
+
+    // Record an RPC that started at `start` in the metrics, and log it if it
+    // was slow.  `query` is the key, filter or statement it was for.  Time
+    // spent waiting to claim a connection is not counted.
+    fn observe<T>(
+        &self,
+        method: &'static str,
+        bucket: &str,
+        query: &str,
+        start: Instant,
+        result: &Result<T, Error>,
+    ) {
+        let elapsed = start.elapsed();
+        self.metrics.observe(method, elapsed, result.is_ok());
+
+        if let Some(threshold) = self.slow_query_threshold {
+            if elapsed >= threshold {
+                warn!(self.log, "slow moray query";
+                    "method" => method,
+                    "bucket" => bucket,
+                    "query" => query,
+                    "elapsed_ms" => elapsed.as_millis() as u64,
+                    "success" => result.is_ok());
+            }
+        }
+    }
+
     pub fn from_parts<I: Into<IpAddr>>(
         ip: I,
         port: u16,
@@ -88,13 +159,16 @@ impl MorayClient {
             .claim()
             .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
 
-        buckets::get_list_buckets(
+        let start = Instant::now();
+        let result = buckets::get_list_buckets(
             &mut (*conn).deref_mut(),
             "",
             opts,
             buckets::Methods::List,
             bucket_handler,
-        )
+        );
+        self.observe("listBuckets", "", "", start, &result);
+        result
     }
 
     pub fn get_bucket<F>(
@@ -111,13 +185,16 @@ impl MorayClient {
             .claim()
             .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
 
-        buckets::get_list_buckets(
+        let start = Instant::now();
+        let result = buckets::get_list_buckets(
             &mut (*conn).deref_mut(),
             name,
             opts,
             buckets::Methods::Get,
             bucket_handler,
-        )
+        );
+        self.observe("getBucket", name, "", start, &result);
+        result
     }
 
     pub fn get_object<F>(
@@ -135,14 +212,38 @@ impl MorayClient {
             .claim()
             .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
 
-        objects::get_find_objects(
+        let start = Instant::now();
+        let result = objects::get_find_objects(
             &mut (*conn).deref_mut(),
             bucket,
             key,
             opts,
             objects::Methods::Get,
             object_handler,
-        )
+        );
+        self.observe("getObject", bucket, key, start, &result);
+        discard_if_abandoned(&mut conn, result)
+    }
+
+    /// Get an object and deserialize its value into `T`.  See
+    /// [`objects::get_object_as`] for how missing keys and schema mismatches
+    /// are reported.
+    pub fn get_object_as<T: DeserializeOwned>(
+        &mut self,
+        bucket: &str,
+        key: &str,
+        opts: &objects::MethodOptions,
+    ) -> Result<objects::TypedObject<T>, Error> {
+        let mut conn = self
+            .connection_pool
+            .claim()
+            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
+
+        let start = Instant::now();
+        let result =
+            objects::get_object_as(&mut (*conn).deref_mut(), bucket, key, opts);
+        self.observe("getObject", bucket, key, start, &result);
+        discard_if_abandoned(&mut conn, result)
     }
 
     pub fn find_objects<F>(
@@ -159,14 +260,83 @@ impl MorayClient {
             .connection_pool
             .claim()
             .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
-        objects::get_find_objects(
+        let start = Instant::now();
+        let result = objects::get_find_objects(
             &mut (*conn).deref_mut(),
             bucket,
             filter,
             opts,
             objects::Methods::Find,
             object_handler,
-        )
+        );
+        self.observe("findObjects", bucket, filter, start, &result);
+        discard_if_abandoned(&mut conn, result)
+    }
+
+    /// Find the next page of changes in a bucket after `checkpoint`.  See
+    /// [`changes::find_changes`].
+    pub fn find_changes<F>(
+        &mut self,
+        query: &changes::ChangeQuery,
+        checkpoint: &changes::ChangeCheckpoint,
+        opts: &objects::MethodOptions,
+        change_handler: F,
+    ) -> Result<changes::ChangePage, Error>
+    where
+        F: FnMut(&objects::MorayObject) -> Result<(), Error>,
+    {
+        let mut conn = self
+            .connection_pool
+            .claim()
+            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
+        let start = Instant::now();
+        let result = changes::find_changes(
+            &mut (*conn).deref_mut(),
+            query,
+            checkpoint,
+            opts,
+            change_handler,
+        );
+        let description = format!(
+            "changes after _mtime {}, _id {}",
+            checkpoint.mtime, checkpoint.id
+        );
+        self.observe(
+            "findObjects",
+            query.bucket(),
+            &description,
+            start,
+            &result,
+        );
+        discard_if_abandoned(&mut conn, result)
+    }
+
+    /// Find every change in a bucket after `checkpoint`, a page at a time,
+    /// and return the checkpoint to poll from next.  The caller persists the
+    /// checkpoint between polls.
+    pub fn poll_changes<F>(
+        &mut self,
+        query: &changes::ChangeQuery,
+        checkpoint: &changes::ChangeCheckpoint,
+        opts: &objects::MethodOptions,
+        mut change_handler: F,
+    ) -> Result<changes::ChangeCheckpoint, Error>
+    where
+        F: FnMut(&objects::MorayObject) -> Result<(), Error>,
+    {
+        let mut checkpoint = *checkpoint;
+        loop {
+            let page = self.find_changes(
+                query,
+                &checkpoint,
+                opts,
+                &mut change_handler,
+            )?;
+            checkpoint = page.checkpoint;
+            if !page.more {
+                return Ok(checkpoint);
+            }
+        }
     }
 
     pub fn put_object<F>(
@@ -184,14 +354,44 @@ impl MorayClient {
             .connection_pool
             .claim()
             .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
-        objects::put_object(
+        let start = Instant::now();
+        let result = objects::put_object(
             &mut (*conn).deref_mut(),
             bucket,
             key,
             value,
             opts,
             object_handler,
-        )
+        );
+        self.observe("putObject", bucket, key, start, &result);
+        discard_if_abandoned(&mut conn, result)
+    }
+
+    /// Put a serializable value, conditional on `etag`, and return the new
+    /// etag.  See [`objects::put_object_typed`].
+    pub fn put_object_typed<T: Serialize>(
+        &mut self,
+        bucket: &str,
+        key: &str,
+        value: &T,
+        etag: objects::Etag,
+        opts: &objects::MethodOptions,
+    ) -> Result<String, Error> {
+        let mut conn = self
+            .connection_pool
+            .claim()
+            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
+        let start = Instant::now();
+        let result = objects::put_object_typed(
+            &mut (*conn).deref_mut(),
+            bucket,
+            key,
+            value,
+            etag,
+            opts,
+        );
+        self.observe("putObject", bucket, key, start, &result);
+        discard_if_abandoned(&mut conn, result)
     }
 
     pub fn create_bucket(
@@ -200,16 +400,19 @@ impl MorayClient {
         config: Value,
         opts: buckets::MethodOptions,
     ) -> Result<(), Error> {
-        buckets::create_bucket(
-            &mut self
-                .connection_pool
-                .claim()
-                .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?
-                .deref_mut(),
+        let mut conn = self
+            .connection_pool
+            .claim()
+            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
+        let start = Instant::now();
+        let result = buckets::create_bucket(
+            &mut (*conn).deref_mut(),
             name,
             config,
             opts,
-        )
+        );
+        self.observe("createBucket", name, "", start, &result);
+        result
     }
 
     pub fn batch<F>(
@@ -225,7 +428,15 @@ impl MorayClient {
             .connection_pool
             .claim()
             .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
-        objects::batch(&mut (*conn).deref_mut(), requests, opts, object_handler)
+        let start = Instant::now();
+        let result = objects::batch(
+            &mut (*conn).deref_mut(),
+            requests,
+            opts,
+            object_handler,
+        );
+        self.observe("batch", "", "", start, &result);
+        discard_if_abandoned(&mut conn, result)
     }
 
     pub fn sql<F, V>(
@@ -239,17 +450,20 @@ impl MorayClient {
         F: FnMut(&Value) -> Result<(), Error>,
         V: Into<Value>,
     {
-        meta::sql(
-            &mut self
-                .connection_pool
-                .claim()
-                .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?
-                .deref_mut(),
+        let mut conn = self
+            .connection_pool
+            .claim()
+            .map_err(|e| Error::new(ErrorKind::Other, e.to_string()))?;
+        let start = Instant::now();
+        let result = meta::sql(
+            &mut (*conn).deref_mut(),
             stmt,
             vals,
             opts,
             query_handler,
-        )
+        );
+        self.observe("sql", "", stmt, start, &result);
+        result
     }
 
     pub fn from_str(
@@ -3,6 +3,8 @@
  */
 
 pub mod buckets;
+pub mod changes;
 pub mod client;
 pub mod meta;
+pub mod metrics;
 pub mod objects;
@@ -0,0 +1,254 @@
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+//! Instrumentation for the RPCs made by a [`MorayClient`].
+//!
+//! Every client keeps per-RPC counters and a coarse duration histogram in a
+//! [`Metrics`] handle, which is shared by clones of the client.  A host
+//! service that already exports metrics can also pass every observation on to
+//! its own registry through a [`MetricsExporter`]; with the `prometheus`
+//! feature, [`PrometheusExporter`] does this for a Prometheus registry.
+//!
+//! [`MorayClient`]: ../client/struct.MorayClient.html
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+/// Upper bounds, in milliseconds, of the duration histogram buckets.  A last,
+/// unbounded bucket counts anything slower.
+pub const DURATION_BUCKETS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1000, 5000];
+
+/// Receives every RPC observation made by a client, for exporting into a
+/// metrics system owned by the host service.
+pub trait MetricsExporter: Send + Sync {
+    /// Record one `method` RPC that took `elapsed` and succeeded or not.
+    fn observe(&self, method: &str, elapsed: Duration, success: bool);
+}
+
+/// Counters for a single RPC method.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RpcStats {
+    /// Number of requests made
+    pub count: u64,
+    /// Number of requests that failed
+    pub errors: u64,
+    /// Total time spent in requests
+    pub total: Duration,
+    /// Longest request
+    pub max: Duration,
+    /// Requests by duration: `buckets[i]` counts requests that took at most
+    /// `DURATION_BUCKETS_MS[i]` (and more than the previous bound); the last
+    /// entry counts the rest.
+    pub buckets: [u64; DURATION_BUCKETS_MS.len() + 1],
+}
+
+impl RpcStats {
+    fn record(&mut self, elapsed: Duration, success: bool) {
+        self.count += 1;
+        if !success {
+            self.errors += 1;
+        }
+        self.total += elapsed;
+        if elapsed > self.max {
+            self.max = elapsed;
+        }
+
+        let ms = elapsed.as_millis();
+        let bucket = DURATION_BUCKETS_MS
+            .iter()
+            .position(|&bound| ms <= u128::from(bound))
+            .unwrap_or(DURATION_BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+    }
+
+    /// Mean request duration, or zero if there have been no requests.
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            return Duration::from_secs(0);
+        }
+        // Averages of longer than u32::MAX seconds are not a concern
+        self.total / self.count as u32
+    }
+}
+
+#[derive(Default)]
+struct MetricsInner {
+    stats: Mutex<HashMap<&'static str, RpcStats>>,
+    exporter: RwLock<Option<Arc<dyn MetricsExporter>>>,
+}
+
+/// Per-RPC metrics for a client, keyed by Fast RPC method name (e.g.
+/// "getObject", "findObjects", "putObject").  Clones share the same
+/// counters and exporter.
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<MetricsInner>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pass every subsequent observation on to `exporter` as well.
+    pub fn set_exporter(&self, exporter: Arc<dyn MetricsExporter>) {
+        if let Ok(mut current) = self.inner.exporter.write() {
+            *current = Some(exporter);
+        }
+    }
+
+    /// Record one `method` RPC.
+    pub fn observe(
+        &self,
+        method: &'static str,
+        elapsed: Duration,
+        success: bool,
+    ) {
+        if let Ok(mut stats) = self.inner.stats.lock() {
+            stats.entry(method).or_default().record(elapsed, success);
+        }
+        if let Ok(exporter) = self.inner.exporter.read() {
+            if let Some(exporter) = exporter.as_ref() {
+                exporter.observe(method, elapsed, success);
+            }
+        }
+    }
+
+    /// Counters for `method`, if any requests have been made with it.
+    pub fn get(&self, method: &str) -> Option<RpcStats> {
+        self.inner
+            .stats
+            .lock()
+            .ok()
+            .and_then(|stats| stats.get(method).cloned())
+    }
+
+    /// Counters for every method used so far.
+    pub fn snapshot(&self) -> HashMap<&'static str, RpcStats> {
+        self.inner
+            .stats
+            .lock()
+            .map(|stats| stats.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Exports RPC observations as Prometheus metrics:
+///
+/// * `moray_rpc_duration_seconds`: histogram of request durations
+/// * `moray_rpc_errors_total`: count of failed requests
+///
+/// both labelled by `method`.
+#[cfg(feature = "prometheus")]
+pub struct PrometheusExporter {
+    durations: prometheus::HistogramVec,
+    errors: prometheus::IntCounterVec,
+}
+
+#[cfg(feature = "prometheus")]
+impl PrometheusExporter {
+    /// Create the metrics and register them with `registry`.  Pass
+    /// `prometheus::default_registry()` to use the global registry.
+    pub fn register(
+        registry: &prometheus::Registry,
+    ) -> Result<Self, prometheus::Error> {
+        let buckets = DURATION_BUCKETS_MS
+            .iter()
+            .map(|&ms| ms as f64 / 1000.0)
+            .collect();
+        let durations = prometheus::HistogramVec::new(
+            prometheus::HistogramOpts::new(
+                "moray_rpc_duration_seconds",
+                "Duration of moray RPCs",
+            )
+            .buckets(buckets),
+            &["method"],
+        )?;
+        let errors = prometheus::IntCounterVec::new(
+            prometheus::Opts::new(
+                "moray_rpc_errors_total",
+                "Number of failed moray RPCs",
+            ),
+            &["method"],
+        )?;
+
+        registry.register(Box::new(durations.clone()))?;
+        registry.register(Box::new(errors.clone()))?;
+
+        Ok(Self { durations, errors })
+    }
+}
+
+#[cfg(feature = "prometheus")]
+impl MetricsExporter for PrometheusExporter {
+    fn observe(&self, method: &str, elapsed: Duration, success: bool) {
+        let secs =
+            elapsed.as_secs() as f64 + f64::from(elapsed.subsec_nanos()) / 1e9;
+        self.durations.with_label_values(&[method]).observe(secs);
+        if !success {
+            self.errors.with_label_values(&[method]).inc();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingExporter {
+        seen: Mutex<Vec<(String, bool)>>,
+    }
+
+    impl MetricsExporter for CountingExporter {
+        fn observe(&self, method: &str, _elapsed: Duration, success: bool) {
+            self.seen
+                .lock()
+                .unwrap()
+                .push((method.to_string(), success));
+        }
+    }
+
+    #[test]
+    fn observe_test() {
+        let metrics = Metrics::new();
+        metrics.observe("getObject", Duration::from_millis(2), true);
+        metrics.observe("getObject", Duration::from_millis(7), false);
+        metrics.observe("getObject", Duration::from_secs(9), true);
+        metrics.observe("findObjects", Duration::from_millis(1), true);
+
+        let get = metrics.get("getObject").unwrap();
+        assert_eq!(get.count, 3);
+        assert_eq!(get.errors, 1);
+        assert_eq!(get.max, Duration::from_secs(9));
+        assert_eq!(get.mean(), Duration::from_millis(3003));
+        assert_eq!(get.buckets, [0, 1, 1, 0, 0, 0, 0, 0, 1]);
+
+        let find = metrics.get("findObjects").unwrap();
+        assert_eq!(find.count, 1);
+        assert_eq!(find.buckets[0], 1);
+
+        assert!(metrics.get("putObject").is_none());
+        assert_eq!(metrics.snapshot().len(), 2);
+        assert_eq!(RpcStats::default().mean(), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn shared_between_clones_test() {
+        let metrics = Metrics::new();
+        let clone = metrics.clone();
+
+        metrics.observe("putObject", Duration::from_millis(2), true);
+        let exporter = Arc::new(CountingExporter::default());
+        clone.set_exporter(exporter.clone());
+        metrics.observe("putObject", Duration::from_millis(2), false);
+
+        assert_eq!(clone.get("putObject").unwrap().count, 2);
+        assert_eq!(
+            *exporter.seen.lock().unwrap(),
+            vec![(String::from("putObject"), false)]
+        );
+    }
+}
@@ -2,14 +2,19 @@
  * Copyright 2020 Joyent, Inc.
  */
 
+use fast_rpc::client::ReceiveOptions;
 use fast_rpc::{client as fast_client, protocol::FastMessageId};
+use serde::de::DeserializeOwned;
 use serde::ser::Serializer;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::{json, Value};
 use std::io::{Error, ErrorKind};
 use std::net::TcpStream;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
+pub use fast_rpc::client::CancelToken;
+
 #[derive(Deserialize, Serialize, PartialEq, Debug, Clone)]
 pub struct MorayObject {
     pub bucket: String,
@@ -23,6 +28,36 @@ pub struct MorayObject {
     pub value: Value, // Bucket schema dependent
 }
 
+impl MorayObject {
+    /// Deserialize the object's value into `T`.
+    ///
+    /// Fails with `ErrorKind::InvalidData` if the value does not match the
+    /// shape of `T`.
+    pub fn value_as<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_value(self.value.clone()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "object '{}' in bucket '{}' does not match the expected \
+                     schema: {}",
+                    self.key, self.bucket, e
+                ),
+            )
+        })
+    }
+}
+
+/// A moray object whose value has been deserialized into `T`.
+///
+/// The etag can be passed back as `Etag::Specified` to make a subsequent
+/// put conditional on the object not having changed in the meantime.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedObject<T> {
+    pub key: String,
+    pub etag: String,
+    pub value: T,
+}
+
 ///
 /// * Undefined: Clobber any object on put
 /// * Nulled: An object with the same key must not exist
@@ -68,10 +103,23 @@ impl Serialize for Etag {
     }
 }
 
+/// Direction of a sort on an attribute.  See `MethodOptions::add_sort`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Sort {
+    pub attribute: String,
+    pub order: SortOrder,
+}
+
 // TODO:
 // * include _value: String = serde_json::to_string(value)
 // * add offset,
-// * add sort
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MethodOptions {
     pub req_id: String, // UUID as String
@@ -84,6 +132,16 @@ pub struct MethodOptions {
     pub no_cache: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sort: Vec<Sort>,
+    // Milliseconds the server may spend on the request, filled in from the
+    // deadline when the request is sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
+    #[serde(skip)]
+    deadline: Option<Instant>,
+    #[serde(skip)]
+    cancel: Option<CancelToken>,
 }
 
 impl Default for MethodOptions {
@@ -96,6 +154,10 @@ impl Default for MethodOptions {
             sql_only: false,
             no_cache: true,
             limit: None,
+            sort: vec![],
+            timeout: None,
+            deadline: None,
+            cancel: None,
         }
     }
 }
@@ -108,6 +170,95 @@ impl MethodOptions {
     pub fn unset_limit(&mut self) {
         self.limit = None;
     }
+
+    /// Sort the results of a find on `attribute`.  Each call adds another
+    /// attribute, which orders results that are equal on the ones before it.
+    /// Internal columns such as `_id` and `_mtime` can be sorted on as well.
+    pub fn add_sort(&mut self, attribute: &str, order: SortOrder) {
+        self.sort.push(Sort {
+            attribute: attribute.to_string(),
+            order,
+        });
+    }
+
+    pub fn unset_sort(&mut self) {
+        self.sort.clear();
+    }
+
+    /// Give up on the request if it has not completed within `timeout`.
+    /// See `set_deadline`.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.set_deadline(Instant::now() + timeout);
+    }
+
+    /// Give up on the request with `ErrorKind::TimedOut` if it has not
+    /// completed by `deadline`.
+    ///
+    /// The time left until the deadline when the request is sent is passed
+    /// to moray as the request's timeout, so the server abandons the query
+    /// as well.
+    pub fn set_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(deadline);
+    }
+
+    pub fn unset_deadline(&mut self) {
+        self.deadline = None;
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Give up on the request with `ErrorKind::Interrupted` once `cancel` is
+    /// cancelled, either from another thread or from a handler called with
+    /// part of the response.
+    pub fn set_cancel_token(&mut self, cancel: CancelToken) {
+        self.cancel = Some(cancel);
+    }
+
+    pub fn unset_cancel_token(&mut self) {
+        self.cancel = None;
+    }
+
+    fn receive_options(&self) -> ReceiveOptions {
+        ReceiveOptions {
+            deadline: self.deadline,
+            cancel: self.cancel.clone(),
+        }
+    }
+
+    // The options to send for a request starting now, with the time left
+    // until the deadline as the server side timeout.  Fails without sending
+    // anything if the deadline has already passed or the request has been
+    // cancelled.
+    fn for_request(&self) -> Result<MethodOptions, Error> {
+        if self
+            .cancel
+            .as_ref()
+            .map_or(false, CancelToken::is_cancelled)
+        {
+            return Err(Error::new(
+                ErrorKind::Interrupted,
+                "Request cancelled before it was sent",
+            ));
+        }
+
+        let mut opts = self.clone();
+        if let Some(deadline) = self.deadline {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::new(
+                    ErrorKind::TimedOut,
+                    "Request deadline passed before it was sent",
+                ));
+            }
+            // Round up so the server never sees a timeout of zero
+            let remaining = deadline - now;
+            let millis = (remaining.as_millis() as u64).max(1);
+            opts.timeout = Some(millis);
+        }
+        Ok(opts)
+    }
 }
 
 /*
@@ -146,6 +297,20 @@ where
     }
 }
 
+// The moray server reports a missing key as an `ObjectNotFoundError`, which
+// fast_rpc hands back as an `ErrorKind::Other` error whose message starts
+// with the error name.  Give it the `NotFound` kind so callers can tell it
+// apart from other failures.
+fn not_found_error(err: Error) -> Error {
+    if err.kind() == ErrorKind::Other
+        && err.to_string().starts_with("ObjectNotFoundError")
+    {
+        Error::new(ErrorKind::NotFound, err.to_string())
+    } else {
+        err
+    }
+}
+
 #[allow(clippy::redundant_closure)]
 fn decode_object<F>(fm_data: &Value, mut cb: F) -> Result<(), Error>
 where
@@ -189,11 +354,12 @@ where
     F: FnMut(&MorayObject) -> Result<(), Error>,
 {
     let obj_method = method.method();
+    let opts = opts.for_request()?;
     let arg = json!([bucket, key_filter, opts]);
     let mut msg_id = FastMessageId::new();
 
     fast_client::send(obj_method, arg, &mut msg_id, stream).and_then(|_| {
-        fast_client::receive(stream, |resp| {
+        fast_client::receive_with(stream, &opts.receive_options(), |resp| {
             decode_object(&resp.data.d, |obj| object_handler(&obj))
         })
     })?;
@@ -212,12 +378,13 @@ pub fn put_object<F>(
 where
     F: FnMut(&str) -> Result<(), Error>,
 {
+    let opts = opts.for_request()?;
     let arg = json!([bucket, key, value, opts]);
     let mut msg_id = FastMessageId::new();
 
     fast_client::send(Methods::Put.method(), arg, &mut msg_id, stream)
         .and_then(|_| {
-        fast_client::receive(stream, |resp| {
+        fast_client::receive_with(stream, &opts.receive_options(), |resp| {
             let arr: Vec<PutObjectReturn> =
                 serde_json::from_value(resp.data.d.clone())?;
             if arr.len() != 1 {
@@ -235,6 +402,64 @@ where
     Ok(())
 }
 
+/// Get a single object and deserialize its value into `T`.
+///
+/// Fails with `ErrorKind::NotFound` if there is no object with the given key
+/// and `ErrorKind::InvalidData` if its value does not match the shape of `T`.
+pub fn get_object_as<T: DeserializeOwned>(
+    stream: &mut TcpStream,
+    bucket: &str,
+    key: &str,
+    opts: &MethodOptions,
+) -> Result<TypedObject<T>, Error> {
+    let mut found = None;
+
+    get_find_objects(stream, bucket, key, opts, Methods::Get, |obj| {
+        found = Some(TypedObject {
+            key: obj.key.clone(),
+            etag: obj._etag.clone(),
+            value: obj.value_as()?,
+        });
+        Ok(())
+    })
+    .map_err(not_found_error)?;
+
+    found.ok_or_else(|| {
+        Error::new(
+            ErrorKind::NotFound,
+            format!("object '{}' not found in bucket '{}'", key, bucket),
+        )
+    })
+}
+
+/// Serialize `value` and put it, returning the object's new etag.
+///
+/// `etag` overrides the etag in `opts`, so the put can be made conditional:
+/// with `Etag::Nulled` it only succeeds if the key does not exist yet, and
+/// with `Etag::Specified` only if the stored object still has that etag.
+/// Otherwise the server rejects it with an `EtagConflictError`.
+pub fn put_object_typed<T: Serialize>(
+    stream: &mut TcpStream,
+    bucket: &str,
+    key: &str,
+    value: &T,
+    etag: Etag,
+    opts: &MethodOptions,
+) -> Result<String, Error> {
+    let value = serde_json::to_value(value)
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+    let mut opts = opts.clone();
+    let mut new_etag = String::new();
+
+    opts.etag = etag;
+    put_object(stream, bucket, key, value, &opts, |etag| {
+        new_etag = etag.to_string();
+        Ok(())
+    })?;
+
+    Ok(new_etag)
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 // This serde macro adds the "operation" field to each variant's structure when
 // it is serialized.
@@ -305,12 +530,13 @@ where
 
     let batch_requests =
         serde_json::to_value(requests.to_owned()).expect("batch requests");
+    let opts = opts.for_request()?;
     let arg = json!([batch_requests, opts]);
     let mut msg_id = FastMessageId::new();
 
     fast_client::send(String::from("batch"), arg, &mut msg_id, stream)
         .and_then(|_| {
-            fast_client::receive(stream, |resp| {
+            fast_client::receive_with(stream, &opts.receive_options(), |resp| {
                 // The response is a Vec<Value>, where each Value can take a
                 // different form depending on the batch operation.  We
                 // assume there are no ordering guarntees, and the Value's
@@ -327,7 +553,13 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use bytes::BytesMut;
+    use fast_rpc::protocol::{FastMessage, FastMessageData, FastRpc};
+    use std::io::{Read, Write};
     use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+    use tokio_io::_tokio_codec::Encoder;
 
     #[test]
     fn batch_unsupported_test() {
@@ -365,6 +597,58 @@ mod test {
         listen_handle.join().unwrap();
     }
 
+    fn object(value: Value) -> MorayObject {
+        MorayObject {
+            bucket: String::from("foo bucket"),
+            _count: 0,
+            _etag: String::from("F00DCAFE"),
+            _id: 1,
+            _mtime: 0,
+            _txn_snap: None,
+            key: String::from("somekey"),
+            value,
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        name: String,
+        count: u64,
+    }
+
+    #[test]
+    fn value_as_test() {
+        let obj = object(json!({"name": "a", "count": 2}));
+        let record: Record = obj.value_as().unwrap();
+        assert_eq!(
+            record,
+            Record {
+                name: String::from("a"),
+                count: 2
+            }
+        );
+
+        let obj = object(json!({"name": "a", "count": "two"}));
+        let err = obj.value_as::<Record>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("somekey"));
+    }
+
+    #[test]
+    fn not_found_error_test() {
+        let err = not_found_error(Error::new(
+            ErrorKind::Other,
+            "ObjectNotFoundError: somekey does not exist",
+        ));
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let err = not_found_error(Error::new(
+            ErrorKind::Other,
+            "EtagConflictError: wanted to put etag F00DCAFE",
+        ));
+        assert_eq!(err.kind(), ErrorKind::Other);
+    }
+
     #[test]
     fn method_options_test() {
         let etag_string = String::from("Some Special Etag");
@@ -389,4 +673,220 @@ mod test {
             serialized.get("etag").expect("get Specified Etag");
         assert_eq!(*specified_etag, Value::String(etag_string));
     }
+
+    #[test]
+    fn sort_options_test() {
+        let mut options = MethodOptions::default();
+        let serialized = serde_json::to_value(options.clone()).unwrap();
+        assert!(serialized.get("sort").is_none());
+
+        options.add_sort("_mtime", SortOrder::Asc);
+        options.add_sort("_id", SortOrder::Desc);
+        let serialized = serde_json::to_value(options.clone()).unwrap();
+        assert_eq!(
+            serialized["sort"],
+            json!([
+                {"attribute": "_mtime", "order": "ASC"},
+                {"attribute": "_id", "order": "DESC"}
+            ])
+        );
+
+        options.unset_sort();
+        let serialized = serde_json::to_value(options).unwrap();
+        assert!(serialized.get("sort").is_none());
+    }
+
+    #[test]
+    fn deadline_propagation_test() {
+        let mut options = MethodOptions::default();
+        let sent = options.for_request().unwrap();
+        let serialized = serde_json::to_value(sent).unwrap();
+        assert!(serialized.get("timeout").is_none());
+
+        options.set_timeout(Duration::from_secs(10));
+        let sent = options.for_request().unwrap();
+        let serialized = serde_json::to_value(sent).unwrap();
+        let timeout = serialized["timeout"].as_u64().expect("timeout");
+        assert!(timeout > 0 && timeout <= 10_000);
+
+        // Nothing is sent once the deadline has passed
+        options.set_deadline(Instant::now());
+        let err = options.for_request().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+        options.unset_deadline();
+        let cancel = CancelToken::new();
+        options.set_cancel_token(cancel.clone());
+        assert!(options.for_request().is_ok());
+        cancel.cancel();
+        let err = options.for_request().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+    }
+
+    // A moray stand-in that reads one request, passes it to `respond` along
+    // with the connection, and then holds the connection open until the test
+    // sends on the returned channel.
+    fn stub_server<F>(
+        respond: F,
+    ) -> (TcpStream, mpsc::Sender<()>, thread::JoinHandle<()>)
+    where
+        F: FnOnce(&FastMessage, &mut TcpStream) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (release_tx, release_rx) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = Vec::new();
+            let request = loop {
+                let mut read_buf = [0; 128];
+                let n = stream.read(&mut read_buf).unwrap();
+                assert!(n > 0, "client closed before sending a request");
+                buf.extend_from_slice(&read_buf[..n]);
+                if let Ok(msg) = FastMessage::parse(&buf) {
+                    break msg;
+                }
+            };
+            respond(&request, &mut stream);
+            let _ = release_rx.recv();
+        });
+
+        (TcpStream::connect(addr).unwrap(), release_tx, handle)
+    }
+
+    fn write_messages(stream: &mut TcpStream, msgs: Vec<FastMessage>) {
+        let mut buf = BytesMut::new();
+        FastRpc.encode(msgs, &mut buf).unwrap();
+        stream.write_all(&buf).unwrap();
+    }
+
+    fn object_message(request: &FastMessage) -> FastMessage {
+        let obj = serde_json::to_value(object(json!({}))).unwrap();
+        FastMessage::data(
+            request.id,
+            FastMessageData::new(request.data.m.name.clone(), json!([obj])),
+        )
+    }
+
+    #[test]
+    fn find_objects_response_test() {
+        let (mut stream, release, server) = stub_server(|request, stream| {
+            let end = FastMessage::end(request.id, request.data.m.name.clone());
+            write_messages(stream, vec![object_message(request), end]);
+        });
+        let mut opts = MethodOptions::default();
+        opts.set_timeout(Duration::from_secs(10));
+
+        let mut count = 0;
+        get_find_objects(
+            &mut stream,
+            "foo bucket",
+            "(key=*)",
+            &opts,
+            Methods::Find,
+            |_| {
+                count += 1;
+                Ok(())
+            },
+        )
+        .unwrap();
+        assert_eq!(count, 1);
+        // The stream's own read timeout is left as it was
+        assert_eq!(stream.read_timeout().unwrap(), None);
+
+        release.send(()).unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn find_objects_timeout_test() {
+        let (tx, rx) = mpsc::channel();
+        // The server never answers
+        let (mut stream, release, server) = stub_server(move |request, _| {
+            tx.send(request.data.d[2]["timeout"].as_u64()).unwrap();
+        });
+        let mut opts = MethodOptions::default();
+        opts.set_timeout(Duration::from_millis(200));
+
+        let start = Instant::now();
+        let err = get_find_objects(
+            &mut stream,
+            "foo bucket",
+            "(key=*)",
+            &opts,
+            Methods::Find,
+            |_| Ok(()),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(stream.read_timeout().unwrap(), None);
+
+        // The server was told how long it had
+        let timeout = rx.recv().unwrap().expect("timeout sent to server");
+        assert!(timeout > 0 && timeout <= 200);
+
+        release.send(()).unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn find_objects_cancel_from_handler_test() {
+        // The server sends one object and then stalls
+        let (mut stream, release, server) = stub_server(|request, stream| {
+            write_messages(stream, vec![object_message(request)]);
+        });
+        let cancel = CancelToken::new();
+        let mut opts = MethodOptions::default();
+        opts.set_cancel_token(cancel.clone());
+
+        let mut count = 0;
+        let err = get_find_objects(
+            &mut stream,
+            "foo bucket",
+            "(key=*)",
+            &opts,
+            Methods::Find,
+            |_| {
+                count += 1;
+                cancel.cancel();
+                Ok(())
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+        assert_eq!(count, 1);
+
+        release.send(()).unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn find_objects_cancel_from_thread_test() {
+        let (mut stream, release, server) = stub_server(|_, _| {});
+        let cancel = CancelToken::new();
+        let mut opts = MethodOptions::default();
+        opts.set_cancel_token(cancel.clone());
+
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            cancel.cancel();
+        });
+
+        let err = get_find_objects(
+            &mut stream,
+            "foo bucket",
+            "(key=*)",
+            &opts,
+            Methods::Find,
+            |_| Ok(()),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+
+        canceller.join().unwrap();
+        release.send(()).unwrap();
+        server.join().unwrap();
+    }
 }
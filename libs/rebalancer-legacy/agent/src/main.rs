@@ -87,7 +87,9 @@ pub mod agenttests {
     use rebalancer::agent_test_util::{
         self, get_progress, send_assignment_impl,
     };
-    use rebalancer::common::{ObjectSkippedReason, Task, TaskStatus};
+    use rebalancer::common::{
+        assignment_hash, ObjectSkippedReason, Task, TaskStatus,
+    };
     use rebalancer::libagent::{
         process_task, router, AgentAssignmentState, AgentConfig, Assignment,
     };
@@ -245,6 +247,10 @@ pub mod agenttests {
                 datacenter: "dc".to_owned(),
                 manta_storage_id: "localhost:8080".to_owned(),
             },
+            size: path.metadata().unwrap().len(),
+            storage_class: None,
+            alternate_sources: Vec::new(),
+            served_by: None,
             status: TaskStatus::Pending,
         }
     }
@@ -278,6 +284,11 @@ pub mod agenttests {
         let uuid = send_assignment(&assignment);
         monitor_assignment(&uuid, TaskStatus::Complete);
 
+        // The agent reports the content hash of the assignment, which is what
+        // the manager compares against when its post is met with a conflict.
+        let progress = get_progress(&uuid, &TEST_SERVER.lock().unwrap());
+        assert_eq!(progress.hash, Some(assignment_hash(&assignment)));
+
         // Send the exact same assignment again.  Note: Even though the contents
         // of this assignment are identical to the previous one, it will be
         // assigned a different uuid so that the agent does not automatically
@@ -289,6 +300,28 @@ pub mod agenttests {
         monitor_assignment(&uuid, TaskStatus::Complete);
     }
 
+    // Test name:    Source failover
+    // Description:  Send an assignment whose tasks name a storage node that
+    //               cannot be reached as their source, with the healthy
+    //               storage node as an alternate source.
+    // Expected:     The agent fails over to the alternate source and
+    //               TaskStatus for all tasks in the assignment should appear
+    //               as "Complete".
+    #[test]
+    fn source_failover() {
+        unit_test_init();
+        let mut assignment = create_assignment(MANTA_SRC_DIR);
+
+        for task in assignment.iter_mut() {
+            let healthy = task.source.clone();
+            task.source.manta_storage_id = "localhost:1".to_string();
+            task.alternate_sources = vec![healthy];
+        }
+
+        let uuid = send_assignment(&assignment);
+        monitor_assignment(&uuid, TaskStatus::Complete);
+    }
+
     // Test name:   Client Error.
     // Description: Attempt to download an object from a storage node where
     //              the object does not reside will cause a client error.
@@ -356,6 +389,11 @@ pub mod agenttests {
         let uuid = send_assignment(&assignment);
         monitor_assignment(&uuid, TaskStatus::Complete);
 
+        // The agent reports the content hash of the assignment, which is what
+        // the manager compares against when its post is met with a conflict.
+        let progress = get_progress(&uuid, &TEST_SERVER.lock().unwrap());
+        assert_eq!(progress.hash, Some(assignment_hash(&assignment)));
+
         // Send the exact same assignment again and send it, although this time,
         // we will reuse our first uuid. We expect to receive a status code of
         // StatusCode::CONFLICT (409) from the server this time.
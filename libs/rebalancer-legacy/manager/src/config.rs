@@ -15,9 +15,11 @@ use std::io::BufReader;
 use std::sync::{Arc, Barrier, Mutex};
 
 use crossbeam_channel::TrySendError;
-use serde::{de, de::Error as de_Error, Deserialize, Deserializer};
+use serde::{de, de::Error as de_Error, Deserialize, Deserializer, Serialize};
 use signal_hook::{self, iterator::Signals};
 
+use crate::jobs::export::ExportConfig;
+use crate::notify::NotificationConfig;
 use rebalancer::error::Error;
 use rebalancer::util;
 use slog::Level;
@@ -60,6 +62,32 @@ static DEFAULT_METADATA_READ_CHUNK_SIZE: usize = 10000;
 // metadata tier.
 static DEFAULT_MAX_METADATA_READ_THREADS: usize = 10;
 
+// The maximum number of metadata updates sent to a single shard in one moray
+// batch RPC when batched updates are enabled.
+static DEFAULT_MD_BATCH_SIZE: usize = 100;
+
+// The maximum amount of time in milliseconds that a metadata update will wait
+// for more updates to join its batch before the batch is sent.
+static DEFAULT_MD_BATCH_FLUSH_INTERVAL: u64 = 500;
+
+// The default number of bytes of object data that the byte budget sizing
+// policy will place in a single assignment (10GiB).
+pub const DEFAULT_MAX_ASSIGNMENT_BYTES: u64 = 10 * 1024 * 1024 * 1024;
+
+// The default upper bound on the number of tasks in a single assignment when
+// the byte budget sizing policy is in use.  This keeps an evacuation of many
+// tiny (or zero length) objects from producing unbounded assignments.
+pub const DEFAULT_BYTE_BUDGET_MAX_TASKS: usize = 1000;
+
+// The default number of other copies of an object that a task lists for the
+// agent to fall back on if its source cannot serve the object.
+pub const DEFAULT_MAX_ALTERNATE_SOURCES: usize = 2;
+
+// The number of seconds an outstanding assignment may go without progress on
+// its agent before its objects are sent to another destination.  0 turns the
+// assignment watchdog off.
+pub const DEFAULT_ASSIGNMENT_STALL_TIMEOUT: u64 = 1800;
+
 pub const MAX_TUNABLE_MD_UPDATE_THREADS: usize = 250;
 
 #[derive(Deserialize, Default, Debug, Clone)]
@@ -79,8 +107,15 @@ pub struct ConfigOptions {
     pub static_queue_depth: usize,
     pub max_assignment_age: u64,
     pub use_batched_updates: bool,
+    pub md_batch_size: usize,
+    pub md_batch_flush_interval: u64,
     pub md_read_chunk_size: usize,
     pub max_md_read_threads: usize,
+    pub assignment_sizing: AssignmentSizing,
+    pub datacenter_spread: DatacenterSpread,
+    pub source_selection: SourceSelection,
+    pub max_alternate_sources: usize,
+    pub assignment_stall_timeout: u64,
 }
 
 impl Default for ConfigOptions {
@@ -93,12 +128,165 @@ impl Default for ConfigOptions {
             static_queue_depth: DEFAULT_STATIC_QUEUE_DEPTH,
             max_assignment_age: DEFAULT_MAX_ASSIGNMENT_AGE,
             use_batched_updates: true,
+            md_batch_size: DEFAULT_MD_BATCH_SIZE,
+            md_batch_flush_interval: DEFAULT_MD_BATCH_FLUSH_INTERVAL,
             md_read_chunk_size: DEFAULT_METADATA_READ_CHUNK_SIZE,
             max_md_read_threads: DEFAULT_MAX_METADATA_READ_THREADS,
+            assignment_sizing: AssignmentSizing::default(),
+            datacenter_spread: DatacenterSpread::default(),
+            source_selection: SourceSelection::default(),
+            max_alternate_sources: DEFAULT_MAX_ALTERNATE_SOURCES,
+            assignment_stall_timeout: DEFAULT_ASSIGNMENT_STALL_TIMEOUT,
         }
     }
 }
 
+/// The heuristic used to decide when an assignment is full and should be
+/// posted to its agent.
+///
+/// In the config file (and in a job's payload) this is an object with a
+/// `policy` tag:
+///
+/// ```json
+/// { "policy": "task_count" }
+/// { "policy": "byte_budget", "max_bytes": 10737418240, "max_tasks": 1000 }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "policy", rename_all = "snake_case")]
+pub enum AssignmentSizing {
+    /// An assignment is full once it holds `max_tasks_per_assignment` tasks,
+    /// regardless of the size of the objects.
+    TaskCount,
+
+    /// An assignment is full once the objects it holds add up to `max_bytes`
+    /// or once it holds `max_tasks` tasks, whichever comes first.  The last
+    /// object added may take an assignment over its byte budget.
+    ByteBudget {
+        #[serde(default = "AssignmentSizing::default_max_bytes")]
+        max_bytes: u64,
+        #[serde(default = "AssignmentSizing::default_max_tasks")]
+        max_tasks: usize,
+    },
+}
+
+impl Default for AssignmentSizing {
+    fn default() -> Self {
+        AssignmentSizing::TaskCount
+    }
+}
+
+impl AssignmentSizing {
+    fn default_max_bytes() -> u64 {
+        DEFAULT_MAX_ASSIGNMENT_BYTES
+    }
+
+    fn default_max_tasks() -> usize {
+        DEFAULT_BYTE_BUDGET_MAX_TASKS
+    }
+
+    /// Reject policies that can never produce a useful assignment.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            AssignmentSizing::TaskCount => Ok(()),
+            AssignmentSizing::ByteBudget {
+                max_bytes,
+                max_tasks,
+            } => {
+                if *max_bytes == 0 {
+                    return Err(String::from(
+                        "Assignment byte budget must be greater than 0",
+                    ));
+                }
+                if *max_tasks == 0 {
+                    return Err(String::from(
+                        "Assignment task limit must be greater than 0",
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The maximum number of tasks a single assignment can hold under this
+    /// policy.
+    pub fn max_tasks(&self, options: &ConfigOptions) -> usize {
+        match self {
+            AssignmentSizing::TaskCount => options.max_tasks_per_assignment,
+            AssignmentSizing::ByteBudget { max_tasks, .. } => *max_tasks,
+        }
+    }
+
+    /// Returns true if an assignment holding `tasks` tasks that add up to
+    /// `bytes` bytes of object data should not have any more tasks added.
+    pub fn is_full(
+        &self,
+        options: &ConfigOptions,
+        tasks: usize,
+        bytes: u64,
+    ) -> bool {
+        if tasks >= self.max_tasks(options) {
+            return true;
+        }
+
+        match self {
+            AssignmentSizing::TaskCount => false,
+            AssignmentSizing::ByteBudget { max_bytes, .. } => {
+                bytes >= *max_bytes
+            }
+        }
+    }
+}
+
+/// What an evacuate job does with an object when every destination with room
+/// for it is in a datacenter that already holds another copy of the object.
+/// Moving the copy there would reduce the number of datacenters the object
+/// is stored in.
+///
+/// In the config file this is a string: `"strict"` or `"best_effort"`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DatacenterSpread {
+    /// Skip the object (with reason `object_already_in_datacenter`) so that
+    /// a later retry job can place it once a suitable destination has room.
+    Strict,
+
+    /// Move the object anyway, preferring destinations that keep the spread
+    /// whenever there are any.
+    BestEffort,
+}
+
+impl Default for DatacenterSpread {
+    fn default() -> Self {
+        DatacenterSpread::Strict
+    }
+}
+
+/// How an evacuate job orders the copies of an object that an agent may
+/// download it from.  The copy on the shark being evacuated is never used.
+/// The first copy becomes the task's source and up to
+/// `max_alternate_sources` of the rest are listed for the agent to fail over
+/// to, in order.
+///
+/// In the config file this is a string: `"metadata_order"` or
+/// `"same_datacenter_first"`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceSelection {
+    /// The order the copies are listed in the object's metadata.
+    MetadataOrder,
+
+    /// Copies in the destination shark's datacenter first, so that the
+    /// download does not cross datacenters when it does not have to, then
+    /// the rest in metadata order.
+    SameDatacenterFirst,
+}
+
+impl Default for SourceSelection {
+    fn default() -> Self {
+        SourceSelection::MetadataOrder
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct Config {
     pub domain_name: String,
@@ -126,6 +314,18 @@ pub struct Config {
         default = "Config::default_log_level"
     )]
     pub log_level: Level,
+
+    #[serde(default)]
+    pub notifications: NotificationConfig,
+
+    #[serde(default)]
+    pub export: ExportConfig,
+
+    /// The base URL at which agents can reach this manager.  If set, agents
+    /// are asked to post each assignment back to the manager once it is
+    /// complete, and are only polled as a fallback.
+    #[serde(default)]
+    pub assignment_callback_url: Option<String>,
 }
 
 impl Default for Config {
@@ -138,6 +338,9 @@ impl Default for Config {
             listen_port: 80,
             max_fill_percentage: 100,
             log_level: Level::Debug,
+            notifications: NotificationConfig::default(),
+            export: ExportConfig::default(),
+            assignment_callback_url: None,
         }
     }
 }
@@ -172,6 +375,18 @@ impl Config {
         util::shard_host2num(self.shards.last().expect("last").host.as_str())
     }
 
+    /// The URL to which agents post the completed assignments of job
+    /// `job_id`, or None if agents are to be polled for them instead.
+    pub fn assignment_callback(&self, job_id: &str) -> Option<String> {
+        self.assignment_callback_url.as_ref().map(|base| {
+            format!(
+                "{}/jobs/{}/assignments",
+                base.trim_end_matches('/'),
+                job_id
+            )
+        })
+    }
+
     fn default_port() -> u16 {
         80
     }
@@ -482,10 +697,171 @@ mod tests {
             config.options.max_assignment_age,
             DEFAULT_MAX_ASSIGNMENT_AGE
         );
+        assert_eq!(config.options.md_batch_size, DEFAULT_MD_BATCH_SIZE);
+        assert_eq!(
+            config.options.md_batch_flush_interval,
+            DEFAULT_MD_BATCH_FLUSH_INTERVAL
+        );
+        assert_eq!(
+            config.options.assignment_sizing,
+            AssignmentSizing::TaskCount
+        );
+        assert_eq!(config.options.datacenter_spread, DatacenterSpread::Strict);
+        assert_eq!(
+            config.options.source_selection,
+            SourceSelection::MetadataOrder
+        );
+        assert_eq!(
+            config.options.max_alternate_sources,
+            DEFAULT_MAX_ALTERNATE_SOURCES
+        );
+        assert_eq!(
+            config.options.assignment_stall_timeout,
+            DEFAULT_ASSIGNMENT_STALL_TIMEOUT
+        );
+
+        config_fini();
+    }
+
+    #[test]
+    fn config_source_selection_test() {
+        unit_test_init();
+
+        let file_contents = r#"{
+                "options": {
+                    "source_selection": "same_datacenter_first",
+                    "max_alternate_sources": 0
+                },
+                "domain_name": "perf1.scloud.host",
+                "shards": [
+                    {
+                        "host": "1.moray.perf1.scloud.host"
+                    }
+                ]
+            }
+        "#;
+
+        std::fs::remove_file(TEST_CONFIG_FILE).unwrap_or(());
+        let config = write_config_file(file_contents.as_bytes());
+
+        assert_eq!(
+            config.options.source_selection,
+            SourceSelection::SameDatacenterFirst
+        );
+        assert_eq!(config.options.max_alternate_sources, 0);
 
         config_fini();
     }
 
+    #[test]
+    fn config_assignment_stall_timeout_test() {
+        unit_test_init();
+
+        let file_contents = r#"{
+                "options": {
+                    "assignment_stall_timeout": 0
+                },
+                "domain_name": "perf1.scloud.host",
+                "shards": [
+                    {
+                        "host": "1.moray.perf1.scloud.host"
+                    }
+                ]
+            }
+        "#;
+
+        std::fs::remove_file(TEST_CONFIG_FILE).unwrap_or(());
+        let config = write_config_file(file_contents.as_bytes());
+
+        assert_eq!(config.options.assignment_stall_timeout, 0);
+
+        config_fini();
+    }
+
+    #[test]
+    fn config_datacenter_spread_test() {
+        unit_test_init();
+
+        let file_contents = r#"{
+                "options": {
+                    "datacenter_spread": "best_effort"
+                },
+                "domain_name": "perf1.scloud.host",
+                "shards": [
+                    {
+                        "host": "1.moray.perf1.scloud.host"
+                    }
+                ]
+            }
+        "#;
+
+        std::fs::remove_file(TEST_CONFIG_FILE).unwrap_or(());
+        let config = write_config_file(file_contents.as_bytes());
+
+        assert_eq!(
+            config.options.datacenter_spread,
+            DatacenterSpread::BestEffort
+        );
+
+        config_fini();
+    }
+
+    #[test]
+    fn config_assignment_sizing_test() {
+        unit_test_init();
+
+        let file_contents = r#"{
+                "options": {
+                    "assignment_sizing": {
+                        "policy": "byte_budget",
+                        "max_bytes": 1048576
+                    }
+                },
+                "domain_name": "perf1.scloud.host",
+                "shards": [
+                    {
+                        "host": "1.moray.perf1.scloud.host"
+                    }
+                ]
+            }
+        "#;
+
+        std::fs::remove_file(TEST_CONFIG_FILE).unwrap_or(());
+        let config = write_config_file(file_contents.as_bytes());
+        let options = config.options;
+        let sizing = options.assignment_sizing;
+
+        assert_eq!(
+            sizing,
+            AssignmentSizing::ByteBudget {
+                max_bytes: 1048576,
+                max_tasks: DEFAULT_BYTE_BUDGET_MAX_TASKS,
+            }
+        );
+        assert_eq!(sizing.max_tasks(&options), DEFAULT_BYTE_BUDGET_MAX_TASKS);
+
+        config_fini();
+    }
+
+    #[test]
+    fn assignment_sizing_is_full() {
+        let mut options = ConfigOptions::default();
+        options.max_tasks_per_assignment = 3;
+
+        let task_count = AssignmentSizing::TaskCount;
+        assert!(!task_count.is_full(&options, 2, u64::max_value()));
+        assert!(task_count.is_full(&options, 3, 0));
+
+        let byte_budget = AssignmentSizing::ByteBudget {
+            max_bytes: 100,
+            max_tasks: 5,
+        };
+        assert_eq!(byte_budget.max_tasks(&options), 5);
+        assert!(!byte_budget.is_full(&options, 4, 99));
+        assert!(byte_budget.is_full(&options, 1, 100));
+        assert!(byte_budget.is_full(&options, 5, 0));
+    }
+
     #[test]
     fn missing_snaplink_cleanup_required() {
         unit_test_init();
@@ -507,6 +883,75 @@ mod tests {
         config_fini();
     }
 
+    #[test]
+    fn config_notification_webhook() {
+        unit_test_init();
+        std::fs::remove_file(TEST_CONFIG_FILE).unwrap_or(());
+
+        let vars = MapBuilder::new()
+            .insert_str("DOMAIN_NAME", "fake.joyent.us")
+            .insert_str(
+                "REBALANCER_NOTIFICATION_WEBHOOK_URL",
+                "https://hooks.example.com/rebalancer?a=1&b=2",
+            )
+            .insert_vec("INDEX_MORAY_SHARDS", |builder| {
+                builder.push_map(|bld| {
+                    bld.insert_str("host", "1.fake.joyent.us")
+                        .insert_bool("last", true)
+                })
+            })
+            .build();
+
+        let config = update_test_config_with_vars(&vars);
+        let webhooks = &config.notifications.webhooks;
+
+        assert_eq!(webhooks.len(), 1);
+        assert_eq!(
+            webhooks[0].url,
+            "https://hooks.example.com/rebalancer?a=1&b=2"
+        );
+        config_fini();
+
+        // Notifications are off unless the tunable is set.
+        let config = config_init();
+        assert!(config.notifications.webhooks.is_empty());
+        config_fini();
+    }
+
+    #[test]
+    fn config_assignment_callback() {
+        unit_test_init();
+        std::fs::remove_file(TEST_CONFIG_FILE).unwrap_or(());
+
+        let vars = MapBuilder::new()
+            .insert_str("DOMAIN_NAME", "fake.joyent.us")
+            .insert_str(
+                "REBALANCER_ASSIGNMENT_CALLBACK_URL",
+                "http://rebalancer.fake.joyent.us/",
+            )
+            .insert_vec("INDEX_MORAY_SHARDS", |builder| {
+                builder.push_map(|bld| {
+                    bld.insert_str("host", "1.fake.joyent.us")
+                        .insert_bool("last", true)
+                })
+            })
+            .build();
+
+        let config = update_test_config_with_vars(&vars);
+        assert_eq!(
+            config.assignment_callback("1234"),
+            Some(String::from(
+                "http://rebalancer.fake.joyent.us/jobs/1234/assignments"
+            ))
+        );
+        config_fini();
+
+        // Agents are polled unless the tunable is set.
+        let config = config_init();
+        assert_eq!(config.assignment_callback("1234"), None);
+        config_fini();
+    }
+
     #[test]
     // 1. Create a config (both file and in memory).
     // 2. Start the config watcher.
@@ -0,0 +1,179 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// Registry of the agents that report to the manager.
+//
+// Agents configured with a `[heartbeat]' section post a summary of their
+// state to `POST /agents' at a regular interval (see `rebalancer::heartbeat').
+// The registry keeps the most recent heartbeat of each agent, by storage id,
+// and `GET /agents' lists them, giving operators a view of the whole fleet
+// rather than only of the agents that a job happens to be using.
+//
+// The registry is only held in memory.  After a restart of the manager it
+// fills up again as agents send their next heartbeat.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use lazy_static::lazy_static;
+use rebalancer::heartbeat::AgentHeartbeat;
+use serde::{Deserialize, Serialize};
+
+// Number of heartbeats that an agent may miss before it is listed as stale.
+pub const MISSED_HEARTBEATS: i64 = 3;
+
+// An agent, as listed by `GET /agents'.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RegisteredAgent {
+    #[serde(flatten)]
+    pub heartbeat: AgentHeartbeat,
+    // When the agent's most recent heartbeat was received.
+    pub last_seen: DateTime<Utc>,
+    // Whether the agent has missed `MISSED_HEARTBEATS' heartbeats in a row.
+    pub stale: bool,
+}
+
+#[derive(Default)]
+pub struct AgentRegistry {
+    agents: Mutex<HashMap<String, (AgentHeartbeat, DateTime<Utc>)>>,
+}
+
+lazy_static! {
+    static ref REGISTRY: AgentRegistry = AgentRegistry::new();
+}
+
+// The registry that the manager's `/agents' endpoint uses.
+pub fn global() -> &'static AgentRegistry {
+    &REGISTRY
+}
+
+impl AgentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Record a heartbeat received now.
+    pub fn record(&self, heartbeat: AgentHeartbeat) -> Result<(), String> {
+        self.record_at(heartbeat, Utc::now())
+    }
+
+    // Record a heartbeat received at `time', replacing the agent's previous
+    // one.
+    pub fn record_at(
+        &self,
+        heartbeat: AgentHeartbeat,
+        time: DateTime<Utc>,
+    ) -> Result<(), String> {
+        if heartbeat.storage_id.trim().is_empty() {
+            return Err(String::from("Heartbeat has no storage_id"));
+        }
+
+        self.agents
+            .lock()
+            .expect("agent registry lock")
+            .insert(heartbeat.storage_id.clone(), (heartbeat, time));
+        Ok(())
+    }
+
+    // Every agent that has sent a heartbeat, in storage id order.
+    pub fn list(&self) -> Vec<RegisteredAgent> {
+        self.list_at(Utc::now())
+    }
+
+    // As `list', with staleness judged as of `now'.
+    pub fn list_at(&self, now: DateTime<Utc>) -> Vec<RegisteredAgent> {
+        let agents = self.agents.lock().expect("agent registry lock");
+        let mut list: Vec<RegisteredAgent> = agents
+            .values()
+            .map(|(heartbeat, last_seen)| {
+                let interval = std::cmp::max(heartbeat.interval_secs, 1);
+                let allowed = Duration::seconds(
+                    (interval as i64).saturating_mul(MISSED_HEARTBEATS),
+                );
+                RegisteredAgent {
+                    heartbeat: heartbeat.clone(),
+                    last_seen: *last_seen,
+                    stale: now.signed_duration_since(*last_seen) > allowed,
+                }
+            })
+            .collect();
+
+        list.sort_by(|a, b| {
+            a.heartbeat.storage_id.cmp(&b.heartbeat.storage_id)
+        });
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heartbeat(
+        storage_id: &str,
+        active_assignments: usize,
+    ) -> AgentHeartbeat {
+        AgentHeartbeat {
+            storage_id: storage_id.to_string(),
+            datacenter: String::from("dc1"),
+            version: String::from("0.1.0"),
+            active_assignments,
+            available_bytes: Some(1 << 30),
+            interval_secs: 60,
+        }
+    }
+
+    #[test]
+    fn registry_test() {
+        let registry = AgentRegistry::new();
+        let start = Utc::now();
+
+        registry.record_at(heartbeat("2.stor", 1), start).unwrap();
+        registry.record_at(heartbeat("1.stor", 0), start).unwrap();
+        assert!(registry.record_at(heartbeat(" ", 0), start).is_err());
+
+        // A newer heartbeat replaces the agent's previous one.
+        let later = start + Duration::seconds(200);
+        registry.record_at(heartbeat("2.stor", 3), later).unwrap();
+
+        let agents = registry.list_at(later);
+        let ids: Vec<&str> = agents
+            .iter()
+            .map(|a| a.heartbeat.storage_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["1.stor", "2.stor"]);
+
+        // 1.stor has missed three heartbeats, 2.stor has just reported.
+        assert!(agents[0].stale);
+        assert!(!agents[1].stale);
+        assert_eq!(agents[1].heartbeat.active_assignments, 3);
+        assert_eq!(agents[1].last_seen, later);
+
+        let agents = registry.list_at(start + Duration::seconds(180));
+        assert!(!agents[0].stale);
+    }
+
+    #[test]
+    fn registered_agent_json_test() {
+        let agent = RegisteredAgent {
+            heartbeat: heartbeat("1.stor", 2),
+            last_seen: Utc::now(),
+            stale: false,
+        };
+        let value = serde_json::to_value(&agent).unwrap();
+        assert_eq!(value["storage_id"], "1.stor");
+        assert_eq!(value["active_assignments"], 2);
+        assert_eq!(value["stale"], false);
+
+        let parsed: RegisteredAgent = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, agent);
+    }
+}
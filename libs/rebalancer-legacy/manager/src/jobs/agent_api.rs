@@ -0,0 +1,159 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// The manager's side of the rebalancer agent API.
+//
+// Evacuate jobs reach the agent on each destination shark through a
+// `RebalancerAgentApi' rather than through an HTTP client of their own.
+// `HttpAgentApi' is what jobs use unless told otherwise.  Tests stand in
+// agents whose behavior they script instead (see `crate::test_harness'), so
+// that the scheduling, retry and watchdog logic of a job can be exercised
+// without real agents on the other end.
+
+use std::fmt;
+
+use rebalancer::common::AssignmentPayload;
+use rebalancer::error::{AgentError, Error, InternalError};
+use rebalancer::libagent::{AgentConfigUpdate, Assignment as AgentAssignment};
+
+/// The port that agents listen on.
+pub const AGENT_PORT: u16 = 7878;
+
+/// Why a call to an agent did not succeed.
+#[derive(Debug)]
+pub enum AgentCallError {
+    /// The agent could not be reached.  It may or may not have acted on the
+    /// request.
+    Unreachable(String),
+
+    /// The agent answered with an error.
+    Agent(AgentError),
+
+    /// The agent answered, but with something that could not be read.
+    BadResponse(String),
+}
+
+impl fmt::Display for AgentCallError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AgentCallError::Unreachable(e) => {
+                write!(f, "agent unreachable: {}", e)
+            }
+            AgentCallError::Agent(e) => write!(f, "{}", e),
+            AgentCallError::BadResponse(e) => {
+                write!(f, "bad response from agent: {}", e)
+            }
+        }
+    }
+}
+
+impl From<AgentCallError> for Error {
+    fn from(error: AgentCallError) -> Self {
+        InternalError::new(None, error.to_string()).into()
+    }
+}
+
+/// The calls that the manager makes to the agent on a storage node.  Each
+/// takes the storage id of the agent's storage node.
+pub trait RebalancerAgentApi: Send + Sync {
+    /// Hand the agent an assignment (POST /assignments).
+    fn post_assignment(
+        &self,
+        storage_id: &str,
+        payload: &AssignmentPayload,
+    ) -> Result<(), AgentCallError>;
+
+    /// Get the agent's progress on an assignment (GET /assignments/<uuid>).
+    fn get_assignment(
+        &self,
+        storage_id: &str,
+        uuid: &str,
+    ) -> Result<AgentAssignment, AgentCallError>;
+
+    /// Change the agent's settings (PUT /config).
+    fn update_config(
+        &self,
+        storage_id: &str,
+        update: &AgentConfigUpdate,
+    ) -> Result<(), AgentCallError>;
+}
+
+/// Reaches agents over HTTP on `AGENT_PORT'.
+pub struct HttpAgentApi {
+    post_client: reqwest::Client,
+    get_client: reqwest::Client,
+}
+
+impl HttpAgentApi {
+    pub fn new() -> Self {
+        HttpAgentApi {
+            post_client: reqwest::Client::new(),
+            get_client: reqwest::Client::new(),
+        }
+    }
+}
+
+impl Default for HttpAgentApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn agent_url(storage_id: &str, path: &str) -> String {
+    format!("http://{}:{}/{}", storage_id, AGENT_PORT, path)
+}
+
+fn check_response(
+    result: Result<reqwest::Response, reqwest::Error>,
+) -> Result<reqwest::Response, AgentCallError> {
+    let mut res =
+        result.map_err(|e| AgentCallError::Unreachable(e.to_string()))?;
+
+    if res.status().is_success() {
+        Ok(res)
+    } else {
+        Err(AgentCallError::Agent(AgentError::from_http_response(
+            &mut res,
+        )))
+    }
+}
+
+impl RebalancerAgentApi for HttpAgentApi {
+    fn post_assignment(
+        &self,
+        storage_id: &str,
+        payload: &AssignmentPayload,
+    ) -> Result<(), AgentCallError> {
+        let url = agent_url(storage_id, "assignments");
+        check_response(self.post_client.post(&url).json(payload).send())
+            .map(|_| ())
+    }
+
+    fn get_assignment(
+        &self,
+        storage_id: &str,
+        uuid: &str,
+    ) -> Result<AgentAssignment, AgentCallError> {
+        let url = agent_url(storage_id, &format!("assignments/{}", uuid));
+        check_response(self.get_client.get(&url).send())?
+            .json::<AgentAssignment>()
+            .map_err(|e| AgentCallError::BadResponse(e.to_string()))
+    }
+
+    fn update_config(
+        &self,
+        storage_id: &str,
+        update: &AgentConfigUpdate,
+    ) -> Result<(), AgentCallError> {
+        let url = agent_url(storage_id, "config");
+        check_response(self.post_client.put(&url).json(update).send())
+            .map(|_| ())
+    }
+}
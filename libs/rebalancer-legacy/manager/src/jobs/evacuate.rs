@@ -10,21 +10,34 @@
 
 use crate::metrics::{
     metrics_error_inc, metrics_gauge_dec, metrics_gauge_inc, metrics_gauge_set,
-    metrics_object_inc_by, metrics_skip_inc, metrics_skip_inc_by,
-    ACTION_EVACUATE, MD_THREAD_GAUGE,
+    metrics_job_assignment_backlog_set, metrics_job_md_thread_dec,
+    metrics_job_md_thread_inc, metrics_job_md_thread_set,
+    metrics_job_object_inc_by, metrics_job_skip_inc_by, metrics_object_inc_by,
+    metrics_skip_inc, metrics_skip_inc_by, metrics_spread_inc, ACTION_EVACUATE,
+    MD_THREAD_GAUGE, SPREAD_REDUCED, SPREAD_SKIPPED,
 };
 use rebalancer::common::{
     self, AssignmentPayload, ObjectId, ObjectSkippedReason, Task, TaskStatus,
 };
 use rebalancer::error::{
-    CrossbeamError, Error, InternalError, InternalErrorCode,
+    AgentErrorCode, CrossbeamError, Error, InternalError, InternalErrorCode,
 };
 use rebalancer::libagent::{
-    AgentAssignmentState, Assignment as AgentAssignment,
+    AgentAssignmentState, AgentConfigUpdate, Assignment as AgentAssignment,
 };
+use rebalancer::throttle::MIN_RATE_BYTES_PER_SEC;
 use rebalancer::util::{MAX_HTTP_STATUS_CODE, MIN_HTTP_STATUS_CODE};
 
-use crate::config::{Config, MAX_TUNABLE_MD_UPDATE_THREADS};
+use crate::config::{
+    AssignmentSizing, Config, ConfigOptions, DatacenterSpread, SourceSelection,
+    MAX_TUNABLE_MD_UPDATE_THREADS,
+};
+use crate::jobs::agent_api::{
+    AgentCallError, HttpAgentApi, RebalancerAgentApi,
+};
+use crate::jobs::watchdog::{
+    AgentEvent, AgentLiveness, AgentState, AssignmentWatchdog,
+};
 use crate::jobs::{
     assignment_cache_usage, Assignment, AssignmentCacheEntry, AssignmentId,
     AssignmentState, JobUpdateMessage, StorageId,
@@ -34,7 +47,7 @@ use crate::pg_db;
 use crate::storinfo::{self as mod_storinfo, SharkSource, StorageNode};
 
 use std::collections::hash_map::Entry::{Occupied, Vacant};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
 use std::error::Error as _Error;
 use std::io::Write;
@@ -43,11 +56,13 @@ use std::string::ToString;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
 use crossbeam_channel as crossbeam;
-use crossbeam_channel::TryRecvError;
+use crossbeam_channel::{RecvTimeoutError, TryRecvError};
 use crossbeam_deque::{Injector, Steal};
+use lazy_static::lazy_static;
 use libmanta::moray::{MantaObject, MantaObjectShark};
 use moray::client::MorayClient;
 use moray::objects::{
@@ -56,7 +71,6 @@ use moray::objects::{
 use quickcheck::{Arbitrary, Gen};
 use quickcheck_helpers::random::string as random_string;
 use rand::seq::SliceRandom;
-use reqwest;
 use serde::{self, Deserialize, Serialize};
 use serde_json::Value;
 use strum::IntoEnumIterator;
@@ -98,10 +112,40 @@ table! {
 }
 
 table! {
-    use diesel::sql_types::{Integer, Jsonb};
+    use diesel::sql_types::{Integer, Jsonb, Nullable, Text};
     config {
         id -> Integer,
         from_shark -> Jsonb,
+        max_objects -> Nullable<Integer>,
+        retry_uuid -> Nullable<Text>,
+        assignment_sizing -> Nullable<Jsonb>,
+        large_objects -> Nullable<Jsonb>,
+        object_filter -> Nullable<Jsonb>,
+    }
+}
+
+// The state of each assignment this job has created.  This is what allows a
+// job interrupted by a manager restart to be resumed.  See
+// EvacuateJob::reconcile().
+table! {
+    use diesel::sql_types::{BigInt, Jsonb, Text};
+    assignments (id) {
+        id -> Text,
+        dest_shark -> Jsonb,
+        total_size -> BigInt,
+        state -> Text,
+    }
+}
+
+// The liveness of each destination agent, as seen by the assignment
+// watchdog.  See jobs::watchdog.
+table! {
+    use diesel::sql_types::{Integer, Nullable, Text, Timestamptz};
+    agents (storage_id) {
+        storage_id -> Text,
+        state -> Text,
+        last_progress -> Nullable<Timestamptz>,
+        stalled_assignments -> Integer,
     }
 }
 
@@ -125,6 +169,20 @@ struct UpdateEvacuateObject<'a> {
 pub struct EvacuateJobDbConfig {
     id: i32,
     pub from_shark: Value,
+    pub max_objects: Option<i32>,
+    pub retry_uuid: Option<String>,
+    pub assignment_sizing: Option<Value>,
+    pub large_objects: Option<Value>,
+    pub object_filter: Option<Value>,
+}
+
+#[derive(Clone, Debug, Insertable, Queryable)]
+#[table_name = "assignments"]
+struct AssignmentDbEntry {
+    id: AssignmentId,
+    dest_shark: Value,
+    total_size: i64,
+    state: AssignmentState,
 }
 
 #[derive(Clone, Debug, Insertable, AsChangeset, Queryable, Serialize)]
@@ -152,6 +210,9 @@ struct MantaObjectEssential {
     #[serde(alias = "contentMD5", default)]
     pub content_md5: String,
 
+    #[serde(alias = "storageClass", default)]
+    pub storage_class: Option<String>,
+
     #[serde(alias = "objectId", default)]
     pub object_id: String,
 
@@ -312,12 +373,71 @@ fn create_table_common(
 fn create_config_table(conn: &PgConnection) -> Result<usize, Error> {
     let create_query = "CREATE TABLE config(
         id Integer PRIMARY KEY,
-        from_shark Jsonb
+        from_shark Jsonb,
+        max_objects Integer,
+        retry_uuid TEXT,
+        assignment_sizing Jsonb,
+        large_objects Jsonb,
+        object_filter Jsonb
     );";
 
     create_table_common(conn, "config", create_query)
 }
 
+// Add the columns to a config table created by an older version of the
+// manager.
+fn upgrade_config_table(conn: &PgConnection) -> Result<usize, Error> {
+    conn.execute(
+        "ALTER TABLE config
+            ADD COLUMN IF NOT EXISTS max_objects Integer,
+            ADD COLUMN IF NOT EXISTS retry_uuid TEXT,
+            ADD COLUMN IF NOT EXISTS assignment_sizing Jsonb,
+            ADD COLUMN IF NOT EXISTS large_objects Jsonb,
+            ADD COLUMN IF NOT EXISTS object_filter Jsonb;",
+    )
+    .map_err(Error::from)
+}
+
+fn assignments_table_query() -> String {
+    let state_strings = AssignmentState::variants();
+    let state_check = format!("'{}'", state_strings.join("', '"));
+
+    format!(
+        "
+            CREATE TABLE IF NOT EXISTS assignments(
+                id TEXT PRIMARY KEY,
+                dest_shark Jsonb,
+                total_size BigInt,
+                state TEXT CHECK(state IN ({})) NOT NULL
+            );",
+        state_check
+    )
+}
+
+fn create_assignments_table(conn: &PgConnection) -> Result<usize, Error> {
+    create_table_common(conn, "assignments", &assignments_table_query())
+}
+
+fn agents_table_query() -> String {
+    let state_strings = AgentState::variants();
+    let state_check = format!("'{}'", state_strings.join("', '"));
+
+    format!(
+        "
+            CREATE TABLE IF NOT EXISTS agents(
+                storage_id TEXT PRIMARY KEY,
+                state TEXT CHECK(state IN ({})) NOT NULL,
+                last_progress TIMESTAMPTZ,
+                stalled_assignments Integer NOT NULL
+            );",
+        state_check
+    )
+}
+
+fn create_agents_table(conn: &PgConnection) -> Result<usize, Error> {
+    create_table_common(conn, "agents", &agents_table_query())
+}
+
 fn create_duplicate_table(conn: &PgConnection) -> Result<usize, Error> {
     let create_query = "CREATE TABLE duplicates(
         id TEXT PRIMARY KEY,
@@ -333,9 +453,18 @@ fn create_duplicate_table(conn: &PgConnection) -> Result<usize, Error> {
 // rebalancer database's jobs table is because this keeps all the
 // information for the evacuate job in a single location.  Doing so makes
 // backing up the database after completion much easier.
+//
+// The object limit, the UUID of the job being retried, the assignment
+// sizing policy, the large object policy and the object filter are stored
+// as well so that the job can be resumed with the same parameters.
 fn update_evacuate_config_impl(
     conn: &PgConnection,
     from_shark: &MantaObjectShark,
+    max_objects: Option<u32>,
+    retry_uuid: Option<&str>,
+    assignment_sizing: &AssignmentSizing,
+    large_objects: &LargeObjectPolicy,
+    object_filter: &ObjectFilter,
 ) -> Result<usize, Error> {
     use self::config::dsl::{config as config_table, id as config_id};
 
@@ -344,6 +473,24 @@ fn update_evacuate_config_impl(
     let value = EvacuateJobDbConfig {
         id: 1,
         from_shark: from_shark_value,
+        max_objects: max_objects.map(|m| m.min(std::i32::MAX as u32) as i32),
+        retry_uuid: retry_uuid.map(String::from),
+        assignment_sizing: Some(
+            serde_json::to_value(assignment_sizing)
+                .expect("AssignmentSizing to Value"),
+        ),
+        large_objects: Some(
+            serde_json::to_value(large_objects)
+                .expect("LargeObjectPolicy to Value"),
+        ),
+        object_filter: if object_filter.is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::to_value(object_filter)
+                    .expect("ObjectFilter to Value"),
+            )
+        },
     };
 
     let updated_records = diesel::insert_into(config_table)
@@ -539,18 +686,51 @@ pub struct EvacuateDestShark {
 /// });
 ///
 /// let deserialized: EvacuateJobUpdateMessage = serde_json::from_value(payload).unwrap();
-/// let EvacuateJobUpdateMessage::SetMetadataThreads(thr_count) = deserialized;
-/// assert_eq!(thr_count, 30);
+/// match deserialized {
+///     EvacuateJobUpdateMessage::SetMetadataThreads(thr_count) => {
+///         assert_eq!(thr_count, 30);
+///     }
+///     _ => panic!("expected set_metadata_threads"),
+/// }
+///
+/// let payload = json!({
+///     "action": "set_large_objects",
+///     "params": { "policy": "skip", "max_bytes": 1073741824 }
+/// });
+///
+/// let deserialized: EvacuateJobUpdateMessage = serde_json::from_value(payload).unwrap();
+/// assert!(deserialized.validate().is_ok());
+///
+/// let payload = json!({
+///     "action": "set_byte_rate_limit",
+///     "params": 52428800
+/// });
+///
+/// let deserialized: EvacuateJobUpdateMessage = serde_json::from_value(payload).unwrap();
+/// assert!(deserialized.validate().is_ok());
 /// ```
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "action", content = "params", rename_all = "snake_case")]
 pub enum EvacuateJobUpdateMessage {
     SetMetadataThreads(usize),
+    SetLargeObjects(LargeObjectPolicy),
+
+    /// Change how new assignments are sized.  Assignments that are already
+    /// being filled are held to the new policy from then on.
+    SetMaxAssignmentSize(AssignmentSizing),
+
+    /// Change the number of objects each destination agent downloads in
+    /// parallel for a single assignment.  This applies to assignments the
+    /// agents start after the change.
+    SetDestinationConcurrency(usize),
+
+    /// Change the aggregate download rate, in bytes/sec, of each
+    /// destination agent.  0 removes the limit.
+    SetByteRateLimit(u64),
 }
 
 impl EvacuateJobUpdateMessage {
     pub fn validate(&self) -> Result<(), String> {
-        #[allow(clippy::single_match)]
         match self {
             EvacuateJobUpdateMessage::SetMetadataThreads(num_threads) => {
                 if *num_threads < 1 {
@@ -571,11 +751,238 @@ impl EvacuateJobUpdateMessage {
                     ));
                 }
             }
+            EvacuateJobUpdateMessage::SetLargeObjects(policy) => {
+                policy.validate()?;
+            }
+            EvacuateJobUpdateMessage::SetMaxAssignmentSize(sizing) => {
+                sizing.validate()?;
+            }
+            EvacuateJobUpdateMessage::SetDestinationConcurrency(workers) => {
+                if *workers < 1 {
+                    return Err(String::from(
+                        "Cannot set destination concurrency below 1",
+                    ));
+                }
+            }
+            EvacuateJobUpdateMessage::SetByteRateLimit(rate) => {
+                // The agents never throttle themselves below this rate, so
+                // neither should an operator.
+                if *rate != 0 && *rate < MIN_RATE_BYTES_PER_SEC {
+                    return Err(format!(
+                        "Cannot set byte rate limit below {} (or 0 for no \
+                         limit)",
+                        MIN_RATE_BYTES_PER_SEC
+                    ));
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// The number of large object assignments a job keeps outstanding at once
+/// under `LargeObjectPolicy::SlowQueue`, unless the policy says otherwise.
+pub const DEFAULT_LARGE_OBJECT_CONCURRENCY: usize = 2;
+
+/// What an evacuate job does with objects larger than `max_bytes`.  A few
+/// very large objects can otherwise dominate an evacuation, holding up the
+/// assignments they are part of long after the rest of their objects have
+/// been moved.
+///
+/// In a job payload or update message this is an object tagged by
+/// `policy`, e.g. `{"policy": "skip", "max_bytes": 10737418240}`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "policy", rename_all = "snake_case")]
+pub enum LargeObjectPolicy {
+    /// Objects are rebalanced alike whatever their size.
+    Include,
+
+    /// Objects larger than `max_bytes` are skipped with reason
+    /// `object_too_large`, and can be moved by a later retry job.
+    Skip { max_bytes: u64 },
+
+    /// Objects larger than `max_bytes` are each moved in an assignment of
+    /// their own.  These are posted from a separate queue which keeps at
+    /// most `concurrency` of them outstanding, so that the remaining
+    /// objects are not held up behind them.
+    SlowQueue {
+        max_bytes: u64,
+        #[serde(default = "LargeObjectPolicy::default_concurrency")]
+        concurrency: usize,
+    },
+}
+
+impl Default for LargeObjectPolicy {
+    fn default() -> Self {
+        LargeObjectPolicy::Include
+    }
+}
+
+impl LargeObjectPolicy {
+    fn default_concurrency() -> usize {
+        DEFAULT_LARGE_OBJECT_CONCURRENCY
+    }
+
+    /// Reject policies that would skip or slow down every object.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            LargeObjectPolicy::Include => Ok(()),
+            LargeObjectPolicy::Skip { max_bytes } => {
+                if *max_bytes == 0 {
+                    return Err(String::from(
+                        "Large object threshold must be greater than 0",
+                    ));
+                }
+                Ok(())
+            }
+            LargeObjectPolicy::SlowQueue {
+                max_bytes,
+                concurrency,
+            } => {
+                if *max_bytes == 0 {
+                    return Err(String::from(
+                        "Large object threshold must be greater than 0",
+                    ));
+                }
+                if *concurrency == 0 {
+                    return Err(String::from(
+                        "Large object concurrency must be greater than 0",
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns true if this policy skips an object of `size` bytes.
+    pub fn skips(&self, size: u64) -> bool {
+        match self {
+            LargeObjectPolicy::Skip { max_bytes } => size > *max_bytes,
+            _ => false,
+        }
+    }
+
+    /// Returns true if this policy sends an object of `size` bytes through
+    /// the slow queue.
+    pub fn slows(&self, size: u64) -> bool {
+        match self {
+            LargeObjectPolicy::SlowQueue { max_bytes, .. } => size > *max_bytes,
+            _ => false,
+        }
+    }
+}
+
+/// Restricts an evacuate job to the objects matching every criterion given,
+/// for partial evacuations.  Objects that do not match are left where they
+/// are and are not recorded in the job's database, so they do not show up
+/// in the job status and a later job can still move them.
+///
+/// In a job payload this is e.g.
+/// `{"owners": [<uuid>], "min_mtime": "2020-01-01T00:00:00Z"}`.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectFilter {
+    /// Only objects owned by one of these account UUIDs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+
+    /// Only objects last modified at or after this time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_mtime: Option<DateTime<Utc>>,
+
+    /// Only objects last modified before this time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_mtime: Option<DateTime<Utc>>,
+
+    /// Only objects of at least this many bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<u64>,
+
+    /// Only objects of at most this many bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u64>,
+}
+
+impl ObjectFilter {
+    /// Reject filters with malformed owners, or with ranges that no object
+    /// could fall in.
+    pub fn validate(&self) -> Result<(), String> {
+        for owner in self.owners.iter() {
+            if Uuid::parse_str(owner).is_err() {
+                return Err(format!("Invalid owner UUID: {}", owner));
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min_mtime, self.max_mtime) {
+            if min >= max {
+                return Err(String::from(
+                    "Filter min_mtime must be before max_mtime",
+                ));
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min_size, self.max_size) {
+            if min > max {
+                return Err(String::from(
+                    "Filter min_size must not be greater than max_size",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if this filter matches every object.
+    pub fn is_empty(&self) -> bool {
+        *self == ObjectFilter::default()
+    }
+
+    /// Returns true if the object metadata `object` matches this filter.
+    /// An object without the owner or mtime a filter asks for does not
+    /// match, while a missing contentLength is taken to be 0 bytes.
+    pub fn matches(&self, object: &Value) -> bool {
+        if !self.owners.is_empty() {
+            let owner = match object.get("owner").and_then(Value::as_str) {
+                Some(o) => o,
+                None => return false,
+            };
+            if !self.owners.iter().any(|o| o.eq_ignore_ascii_case(owner)) {
+                return false;
+            }
+        }
+
+        if self.min_mtime.is_some() || self.max_mtime.is_some() {
+            let mtime = match object.get("mtime").and_then(Value::as_i64) {
+                Some(m) => m,
+                None => return false,
+            };
+            if let Some(min) = self.min_mtime {
+                if mtime < min.timestamp_millis() {
+                    return false;
+                }
+            }
+            if let Some(max) = self.max_mtime {
+                if mtime >= max.timestamp_millis() {
+                    return false;
+                }
+            }
+        }
+
+        let size = object
+            .get("contentLength")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        if self.min_size.map_or(false, |min| size < min) {
+            return false;
+        }
+        if self.max_size.map_or(false, |max| size > max) {
+            return false;
+        }
+
+        true
+    }
+}
+
 enum DyanmicWorkerMsg {
     Data(AssignmentCacheEntry),
     Stop,
@@ -605,9 +1012,8 @@ pub struct EvacuateJob {
 
     pub conn: Mutex<PgConnection>,
 
-    pub post_client: reqwest::Client,
-
-    pub get_client: reqwest::Client,
+    /// How the agents on the destination sharks are reached.
+    pub agent_api: Arc<dyn RebalancerAgentApi>,
 
     pub bytes_transferred: AtomicU64,
 
@@ -622,6 +1028,57 @@ pub struct EvacuateJob {
 
     /// TESTING ONLY
     pub max_objects: Option<u32>,
+
+    /// How the assignment generators decide that an assignment is full.
+    /// Defaults to the policy in the config file, but may be set per job
+    /// and changed while the job is running.
+    assignment_sizing: RwLock<AssignmentSizing>,
+
+    /// Settings pushed to the destination agents by job update messages.
+    /// See EvacuateJob::apply_agent_config().
+    agent_config: Mutex<AgentConfigState>,
+
+    /// How objects above a size threshold are handled.  This may be changed
+    /// while the job is running, see EvacuateJobUpdateMessage.
+    large_objects: RwLock<LargeObjectPolicy>,
+
+    /// Large object assignments that have been posted and not yet
+    /// completed.  See LargeObjectPolicy::SlowQueue.
+    large_assignments: Mutex<HashSet<AssignmentId>>,
+
+    /// Only objects matching this filter are evacuated.  See ObjectFilter.
+    object_filter: ObjectFilter,
+
+    /// This job was interrupted by a restart of the manager and is being
+    /// resumed.  See EvacuateJob::resume().
+    pub resumed: bool,
+
+    /// Objects (and the shard they were found on) that a resumed job has
+    /// already recorded in its local database.  These are not rebalanced a
+    /// second time.
+    known_objects: HashSet<(ObjectId, i32)>,
+
+    /// The liveness of each destination agent, as last recorded in the
+    /// agents table.  See jobs::watchdog.
+    agents: Mutex<HashMap<StorageId, AgentLiveness>>,
+
+    /// Objects from stalled assignments, to be sent to another destination
+    /// by the assignment manager.  The sender is taken once the assignment
+    /// manager stops.  See EvacuateJob::requeue_stalled_assignment().
+    requeue_tx: Mutex<Option<crossbeam::Sender<EvacuateObject>>>,
+    requeue_rx: crossbeam::Receiver<EvacuateObject>,
+}
+
+/// Agent settings requested for the job, and the destination agents that
+/// have been sent them.  An agent that has not, because it was unreachable
+/// or was only chosen as a destination later, is sent them before its next
+/// assignment is posted.
+#[derive(Default)]
+struct AgentConfigState {
+    update: AgentConfigUpdate,
+    // Bumped on every change to `update`.
+    generation: u64,
+    applied: HashMap<StorageId, u64>,
 }
 
 impl TryFrom<SharkspotterMessage> for EvacuateObject {
@@ -663,11 +1120,14 @@ impl EvacuateJob {
         update_rx: Option<crossbeam_channel::Receiver<JobUpdateMessage>>,
         max_objects: Option<u32>,
     ) -> Result<Self, Error> {
-        let mut job = Self::new_common(storage_id, config, db_name, update_rx)?;
-
-        job.max_objects = max_objects;
-
-        Ok(job)
+        Self::new_common(
+            storage_id,
+            config,
+            db_name,
+            update_rx,
+            EvacuateJobType::Initial,
+            max_objects,
+        )
     }
 
     pub fn retry(
@@ -677,10 +1137,90 @@ impl EvacuateJob {
         update_rx: Option<crossbeam_channel::Receiver<JobUpdateMessage>>,
         retry_uuid: &str,
     ) -> Result<Self, Error> {
-        let mut job = Self::new_common(storage_id, config, db_name, update_rx)?;
+        Self::new_common(
+            storage_id,
+            config,
+            db_name,
+            update_rx,
+            EvacuateJobType::Retry(retry_uuid.to_string()),
+            None,
+        )
+    }
 
-        job.evac_type = EvacuateJobType::Retry(retry_uuid.to_string());
-        job.max_objects = None;
+    /// Resume a job that was interrupted by a restart of the manager.  The
+    /// job's existing database is opened rather than re-created, and the
+    /// configuration it was started with is read back from it.  The
+    /// outstanding assignments are reconciled with the agents when the job
+    /// is run.
+    pub fn resume(
+        config: &Config,
+        db_name: &str,
+        update_rx: Option<crossbeam_channel::Receiver<JobUpdateMessage>>,
+    ) -> Result<Self, Error> {
+        use self::config::dsl::config as config_table;
+
+        let conn = pg_db::connect_db(db_name)?;
+
+        // A job started by an older version of the manager has no record of
+        // its assignments.  In that case every object that is not yet
+        // complete is rebalanced again.
+        conn.execute(&assignments_table_query())
+            .map_err(Error::from)?;
+        conn.execute(&agents_table_query()).map_err(Error::from)?;
+        upgrade_config_table(&conn)?;
+
+        let db_config: EvacuateJobDbConfig =
+            config_table.first(&conn).map_err(Error::from)?;
+        let from_shark: MantaObjectShark =
+            serde_json::from_value(db_config.from_shark)?;
+
+        let evac_type = match db_config.retry_uuid {
+            Some(retry_uuid) => EvacuateJobType::Retry(retry_uuid),
+            None => EvacuateJobType::Initial,
+        };
+
+        // Jobs started by an older version of the manager did not record
+        // a sizing policy.  Those use the one from the config file.
+        let assignment_sizing = match db_config.assignment_sizing {
+            Some(value) => Some(serde_json::from_value(value)?),
+            None => None,
+        };
+        let large_objects = match db_config.large_objects {
+            Some(value) => serde_json::from_value(value)?,
+            None => LargeObjectPolicy::default(),
+        };
+        let object_filter = match db_config.object_filter {
+            Some(value) => serde_json::from_value(value)?,
+            None => ObjectFilter::default(),
+        };
+
+        // Agents found stalled before the restart stay that way until they
+        // make progress.
+        let agents: Vec<AgentLiveness> =
+            self::agents::table.load(&conn).map_err(Error::from)?;
+
+        let mut job = Self::from_conn(
+            conn,
+            from_shark,
+            config,
+            db_name,
+            update_rx,
+            evac_type,
+            db_config.max_objects.map(|m| m as u32),
+        );
+
+        job.resumed = true;
+        job.agents = Mutex::new(
+            agents
+                .into_iter()
+                .map(|a| (a.storage_id.clone(), a))
+                .collect(),
+        );
+        if let Some(sizing) = assignment_sizing {
+            job.set_assignment_sizing(sizing);
+        }
+        job.set_large_objects(large_objects);
+        job.set_object_filter(object_filter);
 
         Ok(job)
     }
@@ -690,6 +1230,8 @@ impl EvacuateJob {
         config: &Config,
         db_name: &str,
         update_rx: Option<crossbeam_channel::Receiver<JobUpdateMessage>>,
+        evac_type: EvacuateJobType,
+        max_objects: Option<u32>,
     ) -> Result<Self, Error> {
         let mut from_shark = MantaObjectShark::default();
         let conn = match pg_db::create_and_connect_db(db_name) {
@@ -703,32 +1245,67 @@ impl EvacuateJob {
         create_evacuateobjects_table(&conn)?;
         create_config_table(&conn)?;
         create_duplicate_table(&conn)?;
+        create_assignments_table(&conn)?;
+        create_agents_table(&conn)?;
 
         from_shark.manta_storage_id = storage_id;
 
-        update_evacuate_config_impl(&conn, &from_shark)?;
+        let job = Self::from_conn(
+            conn,
+            from_shark,
+            config,
+            db_name,
+            update_rx,
+            evac_type,
+            max_objects,
+        );
+
+        job.update_evacuate_config()?;
 
-        Ok(Self {
+        Ok(job)
+    }
+
+    fn from_conn(
+        conn: PgConnection,
+        from_shark: MantaObjectShark,
+        config: &Config,
+        db_name: &str,
+        update_rx: Option<crossbeam_channel::Receiver<JobUpdateMessage>>,
+        evac_type: EvacuateJobType,
+        max_objects: Option<u32>,
+    ) -> Self {
+        let (requeue_tx, requeue_rx) = crossbeam::unbounded();
+        Self {
             config: config.to_owned(),
             min_avail_mb: Some(1000), // TODO: config
             dest_shark_hash: RwLock::new(HashMap::new()),
             assignments: RwLock::new(HashMap::new()),
             from_shark,
             conn: Mutex::new(conn),
-            max_objects: Some(10),
-            post_client: reqwest::Client::new(),
-            get_client: reqwest::Client::new(),
+            max_objects,
+            assignment_sizing: RwLock::new(config.options.assignment_sizing),
+            agent_config: Mutex::new(AgentConfigState::default()),
+            large_objects: RwLock::new(LargeObjectPolicy::default()),
+            large_assignments: Mutex::new(HashSet::new()),
+            object_filter: ObjectFilter::default(),
+            agent_api: Arc::new(HttpAgentApi::new()),
             update_rx,
-            evac_type: EvacuateJobType::Initial,
+            evac_type,
             db_name: db_name.to_string(),
             bytes_transferred: AtomicU64::new(0),
             object_movement_start_time: Mutex::new(None),
-        })
+            resumed: false,
+            known_objects: HashSet::new(),
+            agents: Mutex::new(HashMap::new()),
+            requeue_tx: Mutex::new(Some(requeue_tx)),
+            requeue_rx,
+        }
     }
 
     pub fn create_tables(&self) -> Result<usize, Error> {
         let conn = self.conn.lock().expect("DB conn lock");
         create_evacuateobjects_table(&*conn)?;
+        create_assignments_table(&*conn)?;
         create_config_table(&*conn)
     }
 
@@ -747,14 +1324,122 @@ impl EvacuateJob {
 
     fn update_evacuate_config(&self) -> Result<usize, Error> {
         let locked_conn = self.conn.lock().expect("DB conn lock");
+        let retry_uuid = match &self.evac_type {
+            EvacuateJobType::Initial => None,
+            EvacuateJobType::Retry(uuid) => Some(uuid.as_str()),
+        };
 
-        update_evacuate_config_impl(&locked_conn, &self.from_shark)
+        update_evacuate_config_impl(
+            &locked_conn,
+            &self.from_shark,
+            self.max_objects,
+            retry_uuid,
+            &self.assignment_sizing(),
+            &self.large_objects(),
+            &self.object_filter,
+        )
+    }
+
+    /// The job's current assignment sizing policy.
+    pub fn assignment_sizing(&self) -> AssignmentSizing {
+        *self
+            .assignment_sizing
+            .read()
+            .expect("assignment sizing read lock")
+    }
+
+    /// Change how assignments are sized.  Assignments that have already
+    /// been posted are not affected.
+    pub fn set_assignment_sizing(&self, sizing: AssignmentSizing) {
+        *self
+            .assignment_sizing
+            .write()
+            .expect("assignment sizing write lock") = sizing;
+    }
+
+    /// Record agent settings requested by a job update message.  They are
+    /// sent to each destination agent by apply_agent_config().
+    fn update_agent_config(&self, update: AgentConfigUpdate) {
+        let mut state = self.agent_config.lock().expect("agent config lock");
+        if update.throttle.max_bytes_per_sec.is_some() {
+            state.update.throttle.max_bytes_per_sec =
+                update.throttle.max_bytes_per_sec;
+        }
+        if update.workers_per_assignment.is_some() {
+            state.update.workers_per_assignment = update.workers_per_assignment;
+        }
+        state.generation += 1;
+    }
+
+    /// Send the job's agent settings to the agent on `storage_id`, unless
+    /// there are none or it already has them.  Failures are logged rather
+    /// than returned, since an agent that keeps its old settings can still
+    /// process assignments.
+    fn apply_agent_config(&self, storage_id: &str) {
+        let (update, generation) = {
+            let state = self.agent_config.lock().expect("agent config lock");
+            if state.update.is_empty()
+                || state.applied.get(storage_id) == Some(&state.generation)
+            {
+                return;
+            }
+            (state.update.clone(), state.generation)
+        };
+
+        match self.agent_api.update_config(storage_id, &update) {
+            Ok(()) => {
+                info!("Applied {:?} to agent {}", update, storage_id);
+                self.agent_config
+                    .lock()
+                    .expect("agent config lock")
+                    .applied
+                    .insert(storage_id.to_string(), generation);
+            }
+            Err(e) => {
+                warn!(
+                    "Could not apply {:?} to agent {}, will retry before \
+                     its next assignment: {}",
+                    update, storage_id, e
+                );
+            }
+        }
+    }
+
+    /// The job's current large object policy.
+    pub fn large_objects(&self) -> LargeObjectPolicy {
+        *self.large_objects.read().expect("large objects read lock")
+    }
+
+    /// Change how objects above a size threshold are handled.  Objects
+    /// that have already been assigned are not affected.
+    pub fn set_large_objects(&self, policy: LargeObjectPolicy) {
+        *self
+            .large_objects
+            .write()
+            .expect("large objects write lock") = policy;
+    }
+
+    /// The filter objects must match to be evacuated.
+    pub fn object_filter(&self) -> &ObjectFilter {
+        &self.object_filter
+    }
+
+    /// Restrict the job to the objects matching `filter`.  This must be
+    /// done before the job is run.
+    pub fn set_object_filter(&mut self, filter: ObjectFilter) {
+        self.object_filter = filter;
     }
 
     pub fn run(mut self) -> Result<(), Error> {
         self.validate()?;
         self.update_evacuate_config()?;
 
+        let resumed_assignments = if self.resumed {
+            self.reconcile()?
+        } else {
+            vec![]
+        };
+
         let mut ret = Ok(());
 
         // job_action will be shared between threads so create an Arc for it.
@@ -807,7 +1492,11 @@ impl EvacuateJob {
                 );
                 obj_tx = channel.0;
                 obj_rx = channel.1;
-                start_local_db_generator(obj_tx, retry_uuid)?
+                start_local_db_generator(
+                    obj_tx,
+                    Arc::clone(&job_action),
+                    retry_uuid,
+                )?
             }
         };
 
@@ -815,13 +1504,44 @@ impl EvacuateJob {
             start_metadata_update_broker(Arc::clone(&job_action), md_update_rx)
                 .expect("start metadata updater thread");
 
+        // Agents that are given a callback URL post their completed
+        // assignments to the manager, which passes them on to the assignment
+        // checker through this channel.
+        let (completed_tx, completed_rx) = crossbeam::unbounded();
+        COMPLETED_ASSIGNMENT_CHANS
+            .lock()
+            .expect("completed assignment channels lock")
+            .insert(job_action.db_name.clone(), completed_tx);
+
         let assignment_checker_thread = start_assignment_checker(
             Arc::clone(&job_action),
             checker_fini_rx,
-            md_update_tx,
+            completed_rx,
+            md_update_tx.clone(),
         )
         .expect("start assignment checker thread");
 
+        // Assignments that the agents had completed before the job was
+        // interrupted only need their metadata updated.
+        for ace in resumed_assignments.into_iter() {
+            let id = ace.id.clone();
+            if let Err(e) = md_update_tx.send(ace) {
+                error!(
+                    "Error sending resumed assignment {} to the metadata \
+                     broker: {}",
+                    id, e
+                );
+                job_action.mark_assignment_error(
+                    &id,
+                    EvacuateObjectError::InternalError,
+                );
+            }
+        }
+
+        // Drop our sender so that the metadata update broker exits once the
+        // assignment checker is done.
+        drop(md_update_tx);
+
         let post_thread =
             start_assignment_post(full_assignment_rx, Arc::clone(&job_action))?;
 
@@ -888,6 +1608,11 @@ impl EvacuateJob {
                 set_run_error(&mut ret, e);
             });
 
+        COMPLETED_ASSIGNMENT_CHANS
+            .lock()
+            .expect("completed assignment channels lock")
+            .remove(&job_action.db_name);
+
         metadata_update_thread
             .join()
             .expect("MD Update Thread")
@@ -928,41 +1653,293 @@ impl EvacuateJob {
             obj_ids.push(eobj.id);
         }
 
-        debug!("Updated Objects: {:?}", obj_ids);
-        metrics_object_inc_by(Some(ACTION_EVACUATE), obj_ids.len());
-        self.mark_many_objects(obj_ids, EvacuateObjectStatus::Complete);
+        debug!("Updated Objects: {:?}", obj_ids);
+        metrics_object_inc_by(Some(ACTION_EVACUATE), obj_ids.len());
+        metrics_job_object_inc_by(&self.db_name, obj_ids.len());
+        self.mark_many_objects(obj_ids, EvacuateObjectStatus::Complete);
+    }
+
+    fn set_assignment_state(
+        &self,
+        assignment_id: &str,
+        state: AssignmentState,
+    ) -> Result<(), Error> {
+        match self
+            .assignments
+            .write()
+            .expect("assignment write lock")
+            .get_mut(assignment_id)
+        {
+            Some(a) => {
+                a.state = state.clone();
+                info!("Set assignment state to {:?} for {}", a.state, a.id);
+            }
+            None => {
+                let msg = format!(
+                    "Error updating assignment state for: {}",
+                    &assignment_id
+                );
+                error!("{}", &msg);
+
+                return Err(InternalError::new(
+                    Some(InternalErrorCode::AssignmentLookupError),
+                    msg,
+                )
+                .into());
+            }
+        }
+
+        self.persist_assignment_state(assignment_id, state);
+        Ok(())
+    }
+
+    // Record an assignment's state transition in the local database.  The
+    // assignment cache remains the source of truth while the job is
+    // running, so a failure here is logged rather than failing the job.
+    fn persist_assignment_state(
+        &self,
+        assign_id: &str,
+        to_state: AssignmentState,
+    ) {
+        use self::assignments::dsl::{
+            assignments as assignments_table, id, state,
+        };
+
+        let locked_conn = self.conn.lock().expect("DB conn lock");
+
+        if let Err(e) = diesel::update(assignments_table)
+            .filter(id.eq(assign_id))
+            .set(state.eq(to_state.clone()))
+            .execute(&*locked_conn)
+        {
+            error!(
+                "LocalDB: Error setting state of assignment {} to {:?}: {}",
+                assign_id, to_state, e
+            );
+        }
+    }
+
+    // The metadata for the objects in this assignment has been updated.
+    // Remove it from the cache and mark it and its objects complete.
+    fn complete_assignment(
+        &self,
+        assign_id: &str,
+        updated_objects: Vec<EvacuateObject>,
+    ) {
+        info!("Assignment Complete: {}", assign_id);
+
+        self.remove_assignment_from_cache(assign_id);
+        self.mark_objects_complete(updated_objects);
+        self.persist_assignment_state(
+            assign_id,
+            AssignmentState::PostProcessed,
+        );
+    }
+
+    // Returns true if a resumed job has already recorded this object in its
+    // local database.
+    fn already_processed(&self, eobj: &EvacuateObject) -> bool {
+        !self.known_objects.is_empty()
+            && self.known_objects.contains(&(eobj.id.clone(), eobj.shard))
+    }
+
+    // Pick up where an interrupted job left off.
+    //
+    // * Assignments that the agents had completed are put back in the cache
+    //   and returned so that their metadata can be updated.
+    // * Assignments that were posted, or may have been, are looked up on
+    //   their agent.  If the agent has the assignment it is put back in the
+    //   cache and checked on by the assignment checker as usual.  Otherwise
+    //   the assignment is forgotten, along with its objects, so that they
+    //   are found and assigned again.
+    // * Every other object already in the local database has either been
+    //   rebalanced, skipped or marked as an error, and is not rebalanced a
+    //   second time.
+    fn reconcile(&mut self) -> Result<Vec<AssignmentCacheEntry>, Error> {
+        use self::assignments::dsl::{
+            assignments as assignments_table, id as assign_id,
+            state as assign_state,
+        };
+        use self::duplicates::dsl::{duplicates, id as dup_id, shards};
+        use self::evacuateobjects::dsl::{
+            assignment_id, evacuateobjects, id as obj_id, shard, status,
+        };
+
+        let entries: Vec<AssignmentDbEntry> = {
+            let locked_conn = self.conn.lock().expect("DB conn lock");
+            assignments_table
+                .filter(assign_state.eq_any(vec![
+                    AssignmentState::Init,
+                    AssignmentState::Assigned,
+                    AssignmentState::AgentComplete,
+                ]))
+                .load::<AssignmentDbEntry>(&*locked_conn)
+                .map_err(Error::from)?
+        };
+
+        let mut kept: Vec<AssignmentId> = vec![];
+        let mut lost: Vec<AssignmentId> = vec![];
+        let mut agent_complete = vec![];
+
+        for entry in entries.into_iter() {
+            let dest_shark: StorageNode =
+                match serde_json::from_value(entry.dest_shark) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        error!(
+                            "Could not deserialize destination of \
+                             assignment {}: {}",
+                            entry.id, e
+                        );
+                        lost.push(entry.id);
+                        continue;
+                    }
+                };
+
+            let mut ace = AssignmentCacheEntry {
+                id: entry.id,
+                dest_shark,
+                total_size: entry.total_size as u64,
+                state: entry.state,
+            };
+
+            if ace.state == AssignmentState::AgentComplete {
+                agent_complete.push(ace.clone());
+            } else if self.agent_has_assignment(&ace) {
+                ace.state = AssignmentState::Assigned;
+                self.persist_assignment_state(
+                    &ace.id,
+                    AssignmentState::Assigned,
+                );
+                self.reserve_dest_shark(&ace);
+            } else {
+                info!(
+                    "Assignment {} not found on {}, its objects will be \
+                     reassigned",
+                    ace.id, ace.dest_shark.manta_storage_id
+                );
+                lost.push(ace.id);
+                continue;
+            }
+
+            kept.push(ace.id.clone());
+            self.assignments
+                .write()
+                .expect("assignments write lock")
+                .insert(ace.id.clone(), ace);
+        }
+
+        let locked_conn = self.conn.lock().expect("DB conn lock");
+
+        diesel::delete(assignments_table.filter(assign_id.eq_any(lost)))
+            .execute(&*locked_conn)
+            .map_err(Error::from)?;
+
+        // Objects that belong to an assignment which has been forgotten, or
+        // was never recorded, are removed.  Sharkspotter (or the local db
+        // generator for a retry job) will find them again.  Their duplicate
+        // entries are removed too, as those are rebuilt when the objects are
+        // found.
+        let orphans = evacuateobjects
+            .select(obj_id)
+            .filter(status.eq_any(vec![
+                EvacuateObjectStatus::Unprocessed,
+                EvacuateObjectStatus::Assigned,
+                EvacuateObjectStatus::PostProcessing,
+            ]))
+            .filter(assignment_id.ne_all(kept.clone()))
+            .load::<String>(&*locked_conn)
+            .map_err(Error::from)?;
+
+        diesel::delete(duplicates.filter(dup_id.eq_any(orphans.clone())))
+            .execute(&*locked_conn)
+            .map_err(Error::from)?;
+        diesel::delete(evacuateobjects.filter(obj_id.eq_any(orphans.clone())))
+            .execute(&*locked_conn)
+            .map_err(Error::from)?;
+
+        // We hold every remaining object ID in memory, much like the local
+        // db generator does for a retry job.
+        let recorded = evacuateobjects
+            .select((obj_id, shard))
+            .load::<(String, i32)>(&*locked_conn)
+            .map_err(Error::from)?;
+        let duplicate_shards = duplicates
+            .select((dup_id, shards))
+            .load::<(String, Vec<i32>)>(&*locked_conn)
+            .map_err(Error::from)?;
+
+        drop(locked_conn);
+
+        self.known_objects = recorded.into_iter().collect();
+        for (dup, dup_shards) in duplicate_shards.into_iter() {
+            for dup_shard in dup_shards {
+                self.known_objects.insert((dup.clone(), dup_shard));
+            }
+        }
+
+        if let Some(max) = self.max_objects {
+            self.max_objects =
+                Some(max.saturating_sub(self.known_objects.len() as u32));
+        }
+
+        info!(
+            "Resuming job {}: {} outstanding assignments, {} completed \
+             assignments, {} objects to reassign, {} objects already \
+             processed",
+            self.db_name,
+            kept.len() - agent_complete.len(),
+            agent_complete.len(),
+            orphans.len(),
+            self.known_objects.len()
+        );
+
+        Ok(agent_complete)
     }
 
-    fn set_assignment_state(
-        &self,
-        assignment_id: &str,
-        state: AssignmentState,
-    ) -> Result<(), Error> {
+    // Returns false only if the agent reports that it has no such
+    // assignment.  If the agent cannot be reached the assignment is kept
+    // and handled by the assignment checker like any other.
+    fn agent_has_assignment(&self, ace: &AssignmentCacheEntry) -> bool {
         match self
-            .assignments
-            .write()
-            .expect("assignment write lock")
-            .get_mut(assignment_id)
+            .agent_api
+            .get_assignment(&ace.dest_shark.manta_storage_id, &ace.id)
         {
-            Some(a) => {
-                a.state = state;
-                info!("Set assignment state to {:?} for {}", a.state, a.id);
+            Ok(_) => true,
+            Err(AgentCallError::Agent(ref agent_err))
+                if agent_err.code == AgentErrorCode::AssignmentNotFound =>
+            {
+                false
             }
-            None => {
-                let msg = format!(
-                    "Error updating assignment state for: {}",
-                    &assignment_id
+            Err(e) => {
+                warn!(
+                    "Could not look up assignment {} on agent: {}",
+                    ace.id, e
                 );
-                error!("{}", &msg);
-
-                return Err(InternalError::new(
-                    Some(InternalErrorCode::AssignmentLookupError),
-                    msg,
-                )
-                .into());
+                true
             }
         }
-        Ok(())
+    }
+
+    // Account for a resumed assignment that is still outstanding on its
+    // destination shark, as _channel_send_assignment() does for a new one.
+    fn reserve_dest_shark(&self, ace: &AssignmentCacheEntry) {
+        let mut dest_shark_hash = self
+            .dest_shark_hash
+            .write()
+            .expect("dest_shark_hash write lock");
+
+        let dest_shark = dest_shark_hash
+            .entry(ace.dest_shark.manta_storage_id.clone())
+            .or_insert_with(|| EvacuateDestShark {
+                shark: ace.dest_shark.clone(),
+                status: DestSharkStatus::Init,
+                assigned_mb: 0,
+            });
+
+        dest_shark.status = DestSharkStatus::Assigned;
+        dest_shark.assigned_mb =
+            dest_shark.assigned_mb.saturating_add(ace.total_size);
     }
 
     #[allow(clippy::ptr_arg)]
@@ -995,6 +1972,7 @@ impl EvacuateJob {
         );
 
         let entry = assignments.remove(assignment_id);
+        metrics_job_assignment_backlog_set(&self.db_name, assignments.len());
         if entry.is_none() {
             warn!(
                 "Attempt to remove assignment not in cache: {}",
@@ -1015,6 +1993,153 @@ impl EvacuateJob {
         );
     }
 
+    // Record what has been seen of a destination agent.  The agents table is
+    // only written when the agent is first seen or its liveness changes.
+    fn agent_event(&self, storage_id: &str, event: AgentEvent) {
+        let mut agents = self.agents.lock().expect("agents lock");
+        let known = agents.contains_key(storage_id);
+        let agent = agents
+            .entry(storage_id.to_string())
+            .or_insert_with(|| AgentLiveness::new(storage_id));
+
+        if !agent.apply(event, Utc::now()) && known {
+            return;
+        }
+
+        if event == AgentEvent::Stalled {
+            warn!("Agent on {} has stalled", storage_id);
+        }
+
+        let locked_conn = self.conn.lock().expect("DB conn lock");
+        if let Err(e) = diesel::insert_into(self::agents::table)
+            .values(&*agent)
+            .on_conflict(self::agents::storage_id)
+            .do_update()
+            .set(&*agent)
+            .execute(&*locked_conn)
+        {
+            error!(
+                "LocalDB: Error recording liveness of agent {}: {}",
+                storage_id, e
+            );
+        }
+    }
+
+    // Returns true if the assignment watchdog has found this agent stalled
+    // and it has not made progress since.
+    fn agent_stalled(&self, storage_id: &str) -> bool {
+        self.agents
+            .lock()
+            .expect("agents lock")
+            .get(storage_id)
+            .map_or(false, |a| a.state == AgentState::Stalled)
+    }
+
+    // Take an assignment whose agent has stopped making progress away from
+    // it, and hand its objects back to the assignment manager to be sent to
+    // another destination.  Should the agent finish the assignment after
+    // all, its result is ignored, as the assignment is no longer
+    // outstanding.  Returns the number of objects re-queued.
+    fn requeue_stalled_assignment(&self, assign_id: &str) -> usize {
+        use self::evacuateobjects::dsl::{
+            assignment_id, evacuateobjects, status,
+        };
+
+        let ace = match self
+            .assignments
+            .read()
+            .expect("assignments read lock")
+            .get(assign_id)
+        {
+            Some(ace) if ace.state == AssignmentState::Assigned => {
+                ace.to_owned()
+            }
+            _ => return 0,
+        };
+        let dest_shark = &ace.dest_shark.manta_storage_id;
+
+        warn!(
+            "Assignment {} on {} has stalled, sending its objects to \
+             another destination",
+            ace.id, dest_shark
+        );
+
+        self.agent_event(dest_shark, AgentEvent::Stalled);
+        self.persist_assignment_state(
+            &ace.id,
+            AssignmentState::AgentUnavailable,
+        );
+        self.remove_assignment_from_cache(&ace.id);
+        self.mark_dest_shark_ready(dest_shark, ace.total_size);
+
+        let objects = self
+            .load_assignment_objects(&ace.id, EvacuateObjectStatus::Assigned);
+
+        // The objects are inserted again when they are added to their new
+        // assignment.
+        let deleted = diesel::delete(
+            evacuateobjects
+                .filter(assignment_id.eq(&ace.id))
+                .filter(status.eq(EvacuateObjectStatus::Assigned)),
+        )
+        .execute(&*self.conn.lock().expect("DB conn lock"));
+
+        if let Err(e) = deleted {
+            error!(
+                "LocalDB: Error removing objects of stalled assignment {}: {}",
+                ace.id, e
+            );
+            self.mark_assignment_error(
+                &ace.id,
+                EvacuateObjectError::InternalError,
+            );
+            return 0;
+        }
+
+        let mut requeued = 0;
+        let requeue_tx = self.requeue_tx.lock().expect("requeue lock");
+        for mut eobj in objects.into_iter() {
+            // The dest_shark is left in place so that the assignment
+            // manager picks a different one.
+            eobj.status = EvacuateObjectStatus::Unprocessed;
+            eobj.assignment_id = AssignmentId::new();
+
+            let unsent = match &*requeue_tx {
+                Some(tx) => tx.send(eobj).err().map(|e| e.into_inner()),
+                None => Some(eobj),
+            };
+
+            match unsent {
+                Some(mut eobj) => self.skip_object(
+                    &mut eobj,
+                    ObjectSkippedReason::DestinationUnreachable,
+                ),
+                None => requeued += 1,
+            }
+        }
+
+        info!(
+            "Re-queued {} objects from stalled assignment {}",
+            requeued, ace.id
+        );
+        requeued
+    }
+
+    // Called by the assignment manager when it stops.  Objects from stalled
+    // assignments can no longer be sent to another destination, so any it
+    // did not get to, and any from assignments that stall from now on, are
+    // skipped and left for a retry job.
+    fn close_requeue(&self) {
+        self.requeue_tx.lock().expect("requeue lock").take();
+
+        for mut eobj in self.requeue_rx.try_iter() {
+            self.skip_object(
+                &mut eobj,
+                ObjectSkippedReason::DestinationUnreachable,
+            );
+        }
+    }
+
     fn skip_object(
         &self,
         eobj: &mut EvacuateObject,
@@ -1022,6 +2147,7 @@ impl EvacuateJob {
     ) {
         info!("Skipping object {}: {}.", &eobj.id, reason);
         metrics_skip_inc(Some(&reason.to_string()));
+        metrics_job_skip_inc_by(&self.db_name, &reason.to_string(), 1);
 
         eobj.status = EvacuateObjectStatus::Skipped;
         eobj.skipped_reason = Some(reason);
@@ -1152,6 +2278,16 @@ impl EvacuateJob {
                 .map(|v| v.to_owned())
                 .collect();
 
+            // Leave out sharks whose agent has stalled, unless that would
+            // leave none at all.
+            if shark_list
+                .iter()
+                .any(|v| !self.agent_stalled(&v.shark.manta_storage_id))
+            {
+                shark_list
+                    .retain(|v| !self.agent_stalled(&v.shark.manta_storage_id));
+            }
+
             if shark_list.is_empty() {
                 warn!(
                     "Received empty list of sharks, will retry in {}ms",
@@ -1380,6 +2516,27 @@ impl EvacuateJob {
 
         assert_eq!(num_records, obj_list.len());
 
+        // Record the assignment itself so that it can be picked up again if
+        // the job is interrupted.
+        let entry = AssignmentDbEntry {
+            id: assign_id.clone(),
+            dest_shark: serde_json::to_value(&assignment.dest_shark)?,
+            total_size: assignment.total_size as i64,
+            state: AssignmentState::Init,
+        };
+
+        diesel::insert_into(self::assignments::table)
+            .values(&entry)
+            .execute(&*locked_conn)
+            .unwrap_or_else(|e| {
+                let msg = format!(
+                    "Error inserting assignment {} into DB: {}",
+                    assign_id, e
+                );
+                error!("{}", msg);
+                panic!(msg);
+            });
+
         Ok(num_records)
     }
 
@@ -1455,6 +2612,11 @@ impl EvacuateJob {
             skipped_count, assignment_uuid, reason
         );
         metrics_skip_inc_by(Some(&reason.to_string()), skipped_count);
+        metrics_job_skip_inc_by(
+            &self.db_name,
+            &reason.to_string(),
+            skipped_count,
+        );
         skipped_count
     }
 
@@ -1535,6 +2697,11 @@ impl EvacuateJob {
             // the same reason.
             let vec_len = vec_obj_ids.len();
             metrics_skip_inc_by(Some(&reason.to_string()), vec_len);
+            metrics_job_skip_inc_by(
+                &self.db_name,
+                &reason.to_string(),
+                vec_len,
+            );
 
             let rows_updated = diesel::update(evacuateobjects)
                 .filter(id.eq_any(vec_obj_ids))
@@ -1729,6 +2896,13 @@ fn assignment_post_success(
     );
 
     assignment.state = AssignmentState::Assigned;
+    job_action
+        .persist_assignment_state(&assignment.id, AssignmentState::Assigned);
+    job_action.agent_event(
+        &assignment.dest_shark.manta_storage_id,
+        AgentEvent::Assigned,
+    );
+
     let mut assignments = job_action
         .assignments
         .write()
@@ -1768,46 +2942,195 @@ fn assignment_post_fail(
     job_action.skip_assignment(&assignment.id, reason, assignment_state);
 }
 
+// How many times the post of an assignment is retried after a network error,
+// and how long to wait between attempts.  A network error leaves us not
+// knowing whether the agent accepted the assignment, but because posts carry
+// a content hash, a retry that finds the assignment already there can be
+// reconciled instead of being taken as a rejection.
+const ASSIGNMENT_POST_RETRIES: u32 = 2;
+const ASSIGNMENT_POST_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+// Outcome of checking an assignment that an agent says it already has
+// against the one we attempted to post.
+enum ExistingAssignment {
+    // The agent has the same tasks we sent, so the earlier post (whose
+    // response we never saw) succeeded.
+    Matches,
+
+    // The agent has a different assignment under this uuid, or one without a
+    // hash that cannot be compared.
+    Differs(String),
+}
+
+impl EvacuateJob {
+    fn send_assignment(
+        &self,
+        storage_id: &str,
+        payload: &AssignmentPayload,
+    ) -> Result<(), AgentCallError> {
+        let mut attempt = 0;
+        loop {
+            match self.agent_api.post_assignment(storage_id, payload) {
+                Err(AgentCallError::Unreachable(e)) => {
+                    if attempt >= ASSIGNMENT_POST_RETRIES {
+                        return Err(AgentCallError::Unreachable(e));
+                    }
+                    attempt += 1;
+                    warn!(
+                        "Post of assignment {} to {} failed ({}), retrying \
+                         ({}/{})",
+                        payload.id,
+                        storage_id,
+                        e,
+                        attempt,
+                        ASSIGNMENT_POST_RETRIES
+                    );
+                    thread::sleep(ASSIGNMENT_POST_RETRY_DELAY);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // Fetch the assignment that the agent already has under the uuid of
+    // `payload' and compare its hash to ours.
+    fn check_existing_assignment(
+        &self,
+        assignment: &Assignment,
+        payload: &AssignmentPayload,
+    ) -> Result<ExistingAssignment, Error> {
+        let existing = match self.agent_api.get_assignment(
+            &assignment.dest_shark.manta_storage_id,
+            &payload.id,
+        ) {
+            Ok(a) => a,
+            Err(AgentCallError::Agent(agent_err)) => {
+                let msg = format!(
+                    "Could not get existing assignment {} from agent: {}",
+                    payload.id, agent_err
+                );
+                return Err(InternalError::new(
+                    Some(InternalErrorCode::AssignmentGetError),
+                    msg,
+                )
+                .into());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let existing_hash = match existing.hash {
+            Some(h) => h,
+            None => {
+                return Ok(ExistingAssignment::Differs(String::from(
+                    "agent did not report a hash",
+                )))
+            }
+        };
+
+        if payload.hash.as_ref() == Some(&existing_hash) {
+            Ok(ExistingAssignment::Matches)
+        } else {
+            Ok(ExistingAssignment::Differs(format!(
+                "agent has hash {}, expected {}",
+                existing_hash,
+                payload.hash.as_ref().map(String::as_str).unwrap_or("none")
+            )))
+        }
+    }
+}
+
 impl PostAssignment for EvacuateJob {
     fn post(&self, assignment: Assignment) -> Result<(), Error> {
-        let payload = AssignmentPayload {
-            id: assignment.id.clone(),
-            tasks: assignment.tasks.values().map(|t| t.to_owned()).collect(),
-        };
+        if assignment.large {
+            self.large_assignments
+                .lock()
+                .expect("large assignments lock")
+                .insert(assignment.id.clone());
+        }
 
-        let agent_uri = format!(
-            "http://{}:7878/assignments",
-            assignment.dest_shark.manta_storage_id
-        );
+        let payload = AssignmentPayload::new(
+            assignment.id.clone(),
+            assignment.tasks.values().map(|t| t.to_owned()).collect(),
+        )
+        .callback(self.config.assignment_callback(&self.db_name));
+
+        self.apply_agent_config(&assignment.dest_shark.manta_storage_id);
+
+        let storage_id = assignment.dest_shark.manta_storage_id.clone();
+
+        trace!("Sending {:#?} to {}", payload, storage_id);
+        if let Err(e) = self.send_assignment(&storage_id, &payload) {
+            let agent_err = match e {
+                AgentCallError::Agent(agent_err) => agent_err,
+                e => {
+                    assignment_post_fail(
+                        self,
+                        &assignment,
+                        ObjectSkippedReason::DestinationUnreachable,
+                        AssignmentState::AgentUnavailable,
+                    );
+                    return Err(e.into());
+                }
+            };
+
+            // The agent already has an assignment by this uuid.  Most likely
+            // an earlier attempt reached the agent but its response did not
+            // reach us.  If the agent's copy has the same content as ours,
+            // the post succeeded and there is nothing to redo.  Otherwise the
+            // objects are skipped so that they are assigned again.
+            if agent_err.code == AgentErrorCode::AssignmentExists {
+                let reason = match self
+                    .check_existing_assignment(&assignment, &payload)
+                {
+                    Ok(ExistingAssignment::Matches) => {
+                        info!(
+                            "Assignment {} already accepted by {}",
+                            payload.id, assignment.dest_shark.manta_storage_id
+                        );
+                        assignment_post_success(self, assignment);
+                        return Ok(());
+                    }
+                    Ok(ExistingAssignment::Differs(reason)) => reason,
+                    Err(e) => e.to_string(),
+                };
 
-        trace!("Sending {:#?} to {}", payload, agent_uri);
-        let res = match self.post_client.post(&agent_uri).json(&payload).send()
-        {
-            Ok(r) => r,
-            Err(e) => {
                 assignment_post_fail(
                     self,
                     &assignment,
-                    ObjectSkippedReason::DestinationUnreachable,
-                    AssignmentState::AgentUnavailable,
+                    ObjectSkippedReason::AssignmentRejected,
+                    AssignmentState::Rejected,
+                );
+
+                let err = format!(
+                    "Conflicting assignment {} on {} ({})",
+                    payload.id, assignment.dest_shark.manta_storage_id, reason
                 );
-                return Err(e.into());
+
+                return Err(InternalError::new(None, err).into());
             }
-        };
 
-        if !res.status().is_success() {
+            // An agent that is shutting down is only temporarily unable to
+            // take work, which is not the same as rejecting the assignment.
+            // Likewise an agent without room for the objects has not found
+            // anything wrong with them.
+            let reason = match agent_err.code {
+                AgentErrorCode::ShuttingDown => ObjectSkippedReason::AgentBusy,
+                AgentErrorCode::InsufficientSpace => {
+                    ObjectSkippedReason::DestinationInsufficientSpace
+                }
+                _ => ObjectSkippedReason::AssignmentRejected,
+            };
+
             assignment_post_fail(
                 self,
                 &assignment,
-                ObjectSkippedReason::AssignmentRejected,
+                reason,
                 AssignmentState::Rejected,
             );
 
             let err = format!(
                 "Error posting assignment {} to {} ({})",
-                payload.id,
-                assignment.dest_shark.manta_storage_id,
-                res.status()
+                payload.id, assignment.dest_shark.manta_storage_id, agent_err
             );
 
             return Err(InternalError::new(None, err).into());
@@ -1817,6 +3140,27 @@ impl PostAssignment for EvacuateJob {
         assignment_post_success(self, assignment);
         Ok(())
     }
+
+    // Returns true if another large object assignment may be posted under
+    // the current policy.  Assignments that are no longer in the assignment
+    // cache have completed (or failed) and are forgotten.
+    fn large_assignment_slot_available(&self) -> bool {
+        let concurrency = match self.large_objects() {
+            LargeObjectPolicy::SlowQueue { concurrency, .. } => concurrency,
+            // The slow queue was turned off while the job was running, so
+            // drain it as fast as any other assignment.
+            _ => return true,
+        };
+
+        let assignments = self.assignments.read().expect("assignments read");
+        let mut large = self
+            .large_assignments
+            .lock()
+            .expect("large assignments lock");
+
+        large.retain(|id| assignments.contains_key(id));
+        large.len() < concurrency
+    }
 }
 
 impl GetAssignment for EvacuateJob {
@@ -1824,35 +3168,51 @@ impl GetAssignment for EvacuateJob {
         &self,
         ace: &AssignmentCacheEntry,
     ) -> Result<AgentAssignment, Error> {
-        let uri = format!(
-            "http://{}:7878/assignments/{}",
-            ace.dest_shark.manta_storage_id, ace.id
+        debug!(
+            "Getting Assignment {} from {}",
+            ace.id, ace.dest_shark.manta_storage_id
         );
+        match self
+            .agent_api
+            .get_assignment(&ace.dest_shark.manta_storage_id, &ace.id)
+        {
+            Ok(assignment) => {
+                debug!("Assignment Get Response: {:#?}", assignment);
+                Ok(assignment)
+            }
+            Err(AgentCallError::Agent(agent_err)) => {
+                let reason = match agent_err.code {
+                    AgentErrorCode::AssignmentNotFound => {
+                        ObjectSkippedReason::AgentAssignmentNoEnt
+                    }
+                    AgentErrorCode::ShuttingDown => {
+                        ObjectSkippedReason::AgentBusy
+                    }
+                    _ => ObjectSkippedReason::AssignmentError,
+                };
 
-        debug!("Getting Assignment: {:?}", uri);
-        match self.get_client.get(&uri).send() {
-            Ok(mut resp) => {
-                if !resp.status().is_success() {
-                    self.skip_assignment(
-                        &ace.id,
-                        ObjectSkippedReason::AgentAssignmentNoEnt,
-                        AssignmentState::AgentUnavailable,
-                    );
+                self.skip_assignment(
+                    &ace.id,
+                    reason,
+                    AssignmentState::AgentUnavailable,
+                );
 
-                    let msg = format!(
-                        "Could not get assignment from Agent: {:#?}",
-                        resp
-                    );
-                    return Err(InternalError::new(
-                        Some(InternalErrorCode::AssignmentGetError),
-                        msg,
-                    )
-                    .into());
-                }
-                debug!("Assignment Get Response: {:#?}", resp);
-                resp.json::<AgentAssignment>().map_err(Error::from)
+                let msg = format!(
+                    "Could not get assignment {} from Agent: {}",
+                    ace.id, agent_err
+                );
+                Err(InternalError::new(
+                    Some(InternalErrorCode::AssignmentGetError),
+                    msg,
+                )
+                .into())
             }
+            Err(e @ AgentCallError::BadResponse(_)) => Err(e.into()),
             Err(e) => {
+                self.agent_event(
+                    &ace.dest_shark.manta_storage_id,
+                    AgentEvent::Unreachable,
+                );
                 self.skip_assignment(
                     &ace.id,
                     ObjectSkippedReason::NetworkError,
@@ -2051,6 +3411,7 @@ impl ProcessAssignment for EvacuateJob {
                 ace.state = AssignmentState::AgentComplete;
             }
         }
+        self.persist_assignment_state(&ace.id, AssignmentState::AgentComplete);
         self.mark_dest_shark_ready(
             &ace.dest_shark.manta_storage_id,
             ace.total_size,
@@ -2181,6 +3542,7 @@ fn _calculate_available_mb(
 // to consider the trade off if any of inserts.
 fn local_db_generator(
     obj_tx: crossbeam::Sender<EvacuateObject>,
+    job_action: Arc<EvacuateJob>,
     retry_uuid: &str,
 ) -> Result<(), Error> {
     use self::evacuateobjects::dsl::{evacuateobjects, id as obj_id, status};
@@ -2247,6 +3609,10 @@ fn local_db_generator(
             }
         }
 
+        if job_action.already_processed(&obj) {
+            continue;
+        }
+
         // We don't modify the metadata in the database, so what this
         // object has for a sharks array should be the same as what it
         // was when we first found it.
@@ -2265,12 +3631,13 @@ fn local_db_generator(
 
 fn start_local_db_generator(
     obj_tx: crossbeam::Sender<EvacuateObject>,
+    job_action: Arc<EvacuateJob>,
     retry_uuid: &str,
 ) -> Result<thread::JoinHandle<Result<(), Error>>, Error> {
     let db_name = retry_uuid.to_string();
     thread::Builder::new()
         .name(String::from("local_generator"))
-        .spawn(move || local_db_generator(obj_tx, &db_name))
+        .spawn(move || local_db_generator(obj_tx, job_action, &db_name))
         .map_err(Error::from)
 }
 
@@ -2314,11 +3681,25 @@ fn start_sharkspotter(
                                 match EvacuateObject::try_from(ss_msg) {
                                     Ok(o) => o,
                                     Err(e) => {
-                                        job_action.insert_into_db(&e);
+                                        if job_action
+                                            .object_filter()
+                                            .matches(&e.object)
+                                            && !job_action.already_processed(&e)
+                                        {
+                                            job_action.insert_into_db(&e);
+                                        }
                                         continue;
                                     }
                                 };
 
+                            // Objects outside of the filter are not part of
+                            // this job at all, so they are not recorded.
+                            if !job_action.object_filter().matches(&eo.object)
+                                || job_action.already_processed(&eo)
+                            {
+                                continue;
+                            }
+
                             if let Err(e) = obj_tx.send(eo) {
                                 warn!(
                                     "Could not send evacuate object.  Receive \
@@ -2481,8 +3862,9 @@ where
         let mut object_count = 0;
         let max_objects = job_action.max_objects;
         let max_sharks = job_action.config.options.max_sharks;
-        let max_tasks_per_assignment =
-            job_action.config.options.max_tasks_per_assignment;
+        let max_tasks_per_assignment = job_action
+            .assignment_sizing()
+            .max_tasks(&job_action.config.options);
 
         let algo = mod_storinfo::DefaultChooseAlgorithm {
             min_avail_mb: job_action.min_avail_mb,
@@ -2594,8 +3976,13 @@ where
                     }
                 }
 
-                let mut eobj = match obj_rx.recv() {
-                    Ok(obj) => {
+                let next = next_object(&job_action, &obj_rx);
+                let (mut eobj, requeued) = match next {
+                    Ok((obj, true)) => {
+                        trace!("Received re-queued object {:#?}", &obj);
+                        (obj, true)
+                    }
+                    Ok((obj, false)) => {
                         if object_count == 0 {
                             *job_action
                                 .object_movement_start_time
@@ -2606,7 +3993,7 @@ where
                         trace!("Received object {:#?}", &obj);
                         object_count += 1;
 
-                        obj
+                        (obj, false)
                     }
                     Err(e) => {
                         warn!("Didn't receive object. {}\n", e);
@@ -2616,36 +4003,74 @@ where
                     }
                 };
 
-                // Iterate over the list of sharks and get the first
-                // valid one.
-                let mut last_reason = ObjectSkippedReason::AgentBusy;
-                let shark_list_entry: Option<&StorageNode> =
-                    shark_list.iter().find(|shark| {
-                        if let Some(reason) = validate_destination(
-                            &eobj.object,
-                            &job_action.from_shark,
-                            &shark,
-                        ) {
-                            trace!("shark is not valid because: {}", reason);
-                            last_reason = reason;
-                            return false;
-                        }
-                        true
-                    });
+                if job_action.large_objects().skips(object_size(&eobj)) {
+                    job_action.skip_object(
+                        &mut eobj,
+                        ObjectSkippedReason::ObjectTooLarge,
+                    );
+                    continue;
+                }
 
-                // Get the associated shark_hash_entry which holds the
-                // send side of the shark_assignment_generator channel.
-                let shark_hash_entry = match shark_list_entry {
-                    Some(shark) => shark_hash
-                        .get(&shark.manta_storage_id)
-                        .expect("shark not found in hash"),
-                    None => {
+                // An object from a stalled assignment must go somewhere
+                // other than the shark it was stalled on.
+                let requeue_list: Vec<StorageNode>;
+                let destinations = if requeued {
+                    requeue_list = shark_list
+                        .iter()
+                        .filter(|s| s.manta_storage_id != eobj.dest_shark)
+                        .cloned()
+                        .collect();
+                    if requeue_list.is_empty() {
+                        job_action.skip_object(
+                            &mut eobj,
+                            ObjectSkippedReason::DestinationUnreachable,
+                        );
+                        continue;
+                    }
+                    &requeue_list
+                } else {
+                    &shark_list
+                };
+
+                // Pick a destination from the list of sharks, preferring
+                // one that keeps the object's datacenter spread.
+                let shark_list_entry = match select_destination(
+                    &eobj.object,
+                    &job_action.from_shark,
+                    destinations,
+                    job_action.config.options.datacenter_spread,
+                ) {
+                    Ok(Destination::Preserving(shark)) => shark,
+                    Ok(Destination::Reducing(shark)) => {
+                        info!(
+                            "Object {} will be moved to {} which is in a \
+                             datacenter that already has a copy of it",
+                            eobj.id, shark.manta_storage_id
+                        );
+                        metrics_spread_inc(&job_action.db_name, SPREAD_REDUCED);
+                        shark
+                    }
+                    Err(reason) => {
                         warn!("No sharks available");
-                        job_action.skip_object(&mut eobj, last_reason);
+                        if reason
+                            == ObjectSkippedReason::ObjectAlreadyInDatacenter
+                        {
+                            metrics_spread_inc(
+                                &job_action.db_name,
+                                SPREAD_SKIPPED,
+                            );
+                        }
+                        job_action.skip_object(&mut eobj, reason);
                         continue;
                     }
                 };
 
+                // Get the associated shark_hash_entry which holds the
+                // send side of the shark_assignment_generator channel.
+                let shark_hash_entry = shark_hash
+                    .get(&shark_list_entry.manta_storage_id)
+                    .expect("shark not found in hash");
+
                 let shark_id = shark_hash_entry.shark.manta_storage_id.clone();
 
                 debug!("Sending {} to {}", eobj.id, shark_id);
@@ -2690,6 +4115,7 @@ where
         // are all flushing.
         info!("Shutting down all assignment threads");
         _stop_join_drain_assignment_threads(shark_hash);
+        job_action.close_requeue();
 
         info!("Manager: Shutting down assignment checker");
         checker_fini_tx.send(FiniMsg).expect("Fini Msg");
@@ -2697,6 +4123,33 @@ where
     }
 }
 
+// How long the assignment manager waits for a new object before checking
+// for objects from stalled assignments again.
+const REQUEUE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Get the next object to assign, and whether it is one from a stalled
+// assignment (see EvacuateJob::requeue_stalled_assignment()).  Those are
+// taken ahead of new objects.  Returns an error once the object generator
+// has finished.
+fn next_object(
+    job_action: &EvacuateJob,
+    obj_rx: &crossbeam::Receiver<EvacuateObject>,
+) -> Result<(EvacuateObject, bool), crossbeam::RecvError> {
+    loop {
+        if let Ok(obj) = job_action.requeue_rx.try_recv() {
+            return Ok((obj, true));
+        }
+
+        match obj_rx.recv_timeout(REQUEUE_POLL_INTERVAL) {
+            Ok(obj) => return Ok((obj, false)),
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(crossbeam::RecvError)
+            }
+        }
+    }
+}
+
 fn start_assignment_manager<S>(
     full_assignment_tx: crossbeam::Sender<Assignment>,
     checker_fini_tx: crossbeam_channel::Sender<FiniMsg>,
@@ -2734,11 +4187,18 @@ fn _channel_send_assignment(
         let assignment_size = assignment.total_size;
         let dest_shark = assignment.dest_shark.manta_storage_id.clone();
 
-        job_action
-            .assignments
-            .write()
-            .expect("assignments write lock")
-            .insert(assignment_uuid.clone(), assignment.clone().into());
+        {
+            let mut assignments = job_action
+                .assignments
+                .write()
+                .expect("assignments write lock");
+            assignments
+                .insert(assignment_uuid.clone(), assignment.clone().into());
+            metrics_job_assignment_backlog_set(
+                &job_action.db_name,
+                assignments.len(),
+            );
+        }
 
         info!(
             "Sending {}MB Assignment: {}",
@@ -2815,13 +4275,17 @@ fn add_object_to_assignment(
             }
         };
 
-    let source = manta_object
-        .sharks
-        .iter()
-        .find(|s| s.manta_storage_id != from_shark_host);
+    let options = &job_action.config.options;
+    let sources = select_sources(
+        &manta_object.sharks,
+        from_shark_host,
+        &shark.datacenter,
+        options.source_selection,
+        options.max_alternate_sources,
+    );
 
-    let source = match source {
-        Some(src) => src,
+    let (source, alternate_sources) = match sources {
+        Some(sources) => sources,
         None => {
             // The only shark we could find was the one that
             // is being evacuated.
@@ -2832,9 +4296,22 @@ fn add_object_to_assignment(
         }
     };
 
+    let task = Task {
+        object_id: manta_object.object_id.to_owned(),
+        owner: manta_object.owner.to_owned(),
+        md5sum: manta_object.content_md5.to_owned(),
+        source,
+        size: manta_object.content_length,
+        storage_class: manta_object.storage_class.clone(),
+        alternate_sources,
+        served_by: None,
+        status: TaskStatus::Pending,
+    };
+    let size = task.size;
+
     // Make sure there is enough space for this object on the
     // shark.
-    let content_mb = manta_object.content_length / (1024 * 1024);
+    let content_mb = size / (1024 * 1024);
     if content_mb > *available_space {
         job_action.skip_object(
             &mut eobj,
@@ -2846,16 +4323,7 @@ fn add_object_to_assignment(
 
     if assignment
         .tasks
-        .insert(
-            manta_object.object_id.to_owned(),
-            Task {
-                object_id: manta_object.object_id.to_owned(),
-                owner: manta_object.owner.to_owned(),
-                md5sum: manta_object.content_md5.to_owned(),
-                source: source.to_owned(),
-                status: TaskStatus::Pending,
-            },
-        )
+        .insert(manta_object.object_id.to_owned(), task)
         .is_some()
     {
         // We have encountered a duplicate.  We have no way to know which one
@@ -2894,6 +4362,7 @@ fn add_object_to_assignment(
     // panic.  We've already assured that available_space >= content_mb above.
     *available_space -= content_mb;
     assignment.total_size += content_mb;
+    assignment.total_bytes += size;
 
     trace!(
         "{}: Available space: {} | Tasks: {}",
@@ -2908,6 +4377,61 @@ fn add_object_to_assignment(
     Ok(eobj)
 }
 
+// The size in bytes of an evacuate object's manta object, or 0 if it is
+// missing.  Objects without a size are caught by add_object_to_assignment().
+fn object_size(eobj: &EvacuateObject) -> u64 {
+    eobj.object
+        .get("contentLength")
+        .and_then(Value::as_u64)
+        .unwrap_or(0)
+}
+
+// Send a large object to the post thread in an assignment of its own, to be
+// posted through the slow queue.  See LargeObjectPolicy::SlowQueue.
+//
+// The space the object takes up on the shark is also taken off the
+// available_space of the shark's regular assignment, which was worked out
+// before this assignment was made.
+fn send_large_object(
+    job_action: &Arc<EvacuateJob>,
+    eobj: EvacuateObject,
+    shark: &StorageNode,
+    available_space: &mut u64,
+    from_shark_host: &str,
+    full_assignment_tx: &crossbeam::Sender<Assignment>,
+) -> Result<(), Error> {
+    let mut assignment = job_action.new_assignment(shark.to_owned())?;
+    let mut large_space = assignment.max_size / 2;
+    assignment.large = true;
+
+    let eobj = match add_object_to_assignment(
+        job_action,
+        eobj,
+        shark,
+        &mut assignment,
+        &mut large_space,
+        from_shark_host,
+    ) {
+        Ok(eobj) => eobj,
+        // The object has already been skipped or marked as an error.
+        Err(_) => return Ok(()),
+    };
+
+    *available_space = available_space.saturating_sub(assignment.total_size);
+
+    info!(
+        "Sending large object {} to {} through the slow queue",
+        eobj.id, shark.manta_storage_id
+    );
+
+    job_action.insert_assignment_into_db(&mut assignment, &[eobj])?;
+    _channel_send_assignment(
+        Arc::clone(job_action),
+        full_assignment_tx,
+        assignment,
+    )
+}
+
 // This function handles:
 // - inserting all assignment tasks into the database
 // - sending the assignment to the post thread to be sent to the agents
@@ -2953,7 +4477,7 @@ fn shark_assignment_generator(
     full_assignment_tx: crossbeam::Sender<Assignment>,
 ) -> impl Fn() -> Result<(), Error> {
     move || {
-        let max_tasks = job_action.config.options.max_tasks_per_assignment;
+        let options = job_action.config.options;
         let max_age = job_action.config.options.max_assignment_age;
         let from_shark_host = job_action.from_shark.manta_storage_id.clone();
         let mut stop = false;
@@ -3024,6 +4548,19 @@ fn shark_assignment_generator(
                     }
                 }
 
+                AssignmentMsg::Data(data)
+                    if job_action.large_objects().slows(object_size(&data)) =>
+                {
+                    send_large_object(
+                        &job_action,
+                        *data,
+                        &shark,
+                        &mut available_space,
+                        &from_shark_host,
+                        &full_assignment_tx,
+                    )?;
+                }
+
                 AssignmentMsg::Data(data) => {
                     match add_object_to_assignment(
                         &job_action,
@@ -3078,10 +4615,14 @@ fn shark_assignment_generator(
             //  * There are any tasks in the assignment AND:
             //      * We were told to flush or stop
             //        OR
-            //      * We have reached the maximum number of tasks per assignment
+            //      * The job's sizing policy says the assignment is full
             if !assignment.tasks.is_empty() && flush
                 || stop
-                || assignment.tasks.len() >= max_tasks
+                || job_action.assignment_sizing().is_full(
+                    &options,
+                    assignment.tasks.len(),
+                    assignment.total_bytes,
+                )
             {
                 flush = false;
 
@@ -3099,6 +4640,92 @@ fn shark_assignment_generator(
     }
 }
 
+/// A destination chosen by `select_destination()`.
+#[derive(Debug)]
+enum Destination<'a> {
+    /// Moving the object to this shark keeps the number of datacenters the
+    /// object is stored in.
+    Preserving(&'a StorageNode),
+
+    /// This shark is in a datacenter that already holds another copy of the
+    /// object.  Only chosen under `DatacenterSpread::BestEffort`, and only
+    /// when no shark in the list preserves the spread.
+    Reducing(&'a StorageNode),
+}
+
+/// Choose a destination for an object from `sharks`, in order.  The first
+/// shark that preserves the object's datacenter spread is chosen.  Failing
+/// that, the best effort policy settles for the first shark whose only
+/// problem is the spread.
+///
+/// If there is no destination the reason to skip the object is returned.
+/// When any shark was rejected because of the spread this is
+/// `ObjectAlreadyInDatacenter`, as that is what kept the object from moving;
+/// otherwise it is the reason the last shark was rejected.
+fn select_destination<'a>(
+    mobj_value: &Value,
+    evac_shark: &MantaObjectShark,
+    sharks: &'a [StorageNode],
+    policy: DatacenterSpread,
+) -> Result<Destination<'a>, ObjectSkippedReason> {
+    let mut last_reason = ObjectSkippedReason::AgentBusy;
+    let mut reducing: Option<&StorageNode> = None;
+
+    for shark in sharks {
+        match validate_destination(mobj_value, evac_shark, shark) {
+            None => return Ok(Destination::Preserving(shark)),
+            Some(ObjectSkippedReason::ObjectAlreadyInDatacenter) => {
+                trace!("shark would reduce the datacenter spread");
+                if reducing.is_none() {
+                    reducing = Some(shark);
+                }
+            }
+            Some(reason) => {
+                trace!("shark is not valid because: {}", reason);
+                last_reason = reason;
+            }
+        }
+    }
+
+    match (reducing, policy) {
+        (Some(shark), DatacenterSpread::BestEffort) => {
+            Ok(Destination::Reducing(shark))
+        }
+        (Some(_), DatacenterSpread::Strict) => {
+            Err(ObjectSkippedReason::ObjectAlreadyInDatacenter)
+        }
+        (None, _) => Err(last_reason),
+    }
+}
+
+/// Choose the sharks an agent may download an object from: a source, and up
+/// to `max_alternates` others for it to fail over to, in the order they
+/// should be tried.  The shark being evacuated is never used.
+///
+/// Returns None if the only copy of the object is on the shark being
+/// evacuated.
+fn select_sources(
+    sharks: &[MantaObjectShark],
+    from_shark_host: &str,
+    dest_datacenter: &str,
+    policy: SourceSelection,
+    max_alternates: usize,
+) -> Option<(MantaObjectShark, Vec<MantaObjectShark>)> {
+    let mut candidates: Vec<&MantaObjectShark> = sharks
+        .iter()
+        .filter(|s| s.manta_storage_id != from_shark_host)
+        .collect();
+
+    if policy == SourceSelection::SameDatacenterFirst {
+        // A stable sort, so that copies otherwise keep the metadata order.
+        candidates.sort_by_key(|s| s.datacenter != dest_datacenter);
+    }
+
+    let mut candidates = candidates.into_iter().cloned();
+    let source = candidates.next()?;
+    Some((source, candidates.take(max_alternates).collect()))
+}
+
 fn validate_destination(
     mobj_value: &Value,
     evac_shark: &MantaObjectShark,
@@ -3152,16 +4779,65 @@ fn validate_destination(
     None
 }
 
+// How long the slow queue waits before checking again whether another large
+// object assignment may be posted.
+const LARGE_ASSIGNMENT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// Post large object assignments one at a time, waiting whenever the job
+// already has as many outstanding as its policy allows.  See
+// LargeObjectPolicy::SlowQueue.
+fn large_assignment_post<T>(
+    large_rx: crossbeam::Receiver<Assignment>,
+    job_action: Arc<T>,
+) where
+    T: PostAssignment,
+{
+    for assignment in large_rx.iter() {
+        while !job_action.large_assignment_slot_available() {
+            thread::sleep(LARGE_ASSIGNMENT_POLL_INTERVAL);
+        }
+
+        trace!("posting large object assignment: {:#?}", &assignment);
+        if let Err(e) = job_action.post(assignment) {
+            error!("Error posting large object assignment: {}", e);
+        }
+    }
+
+    info!("Large Object Post Thread: Channel closed, exiting.");
+}
+
 fn assignment_post<T>(
     assign_rx: crossbeam::Receiver<Assignment>,
     job_action: Arc<T>,
 ) -> Result<(), Error>
 where
-    T: PostAssignment,
+    T: PostAssignment + 'static,
 {
+    // Large object assignments are handed off to their own thread so that
+    // they do not hold up the others while waiting for their turn.
+    let (large_tx, large_rx) = crossbeam::unbounded();
+    let large_job_action = Arc::clone(&job_action);
+    let large_post_thread = thread::Builder::new()
+        .name(String::from("large_assignment_poster"))
+        .spawn(move || large_assignment_post(large_rx, large_job_action))?;
+
     loop {
         match assign_rx.recv() {
             Ok(assignment) => {
+                // Should the large object queue have gone away the
+                // assignment is posted right here instead.
+                let assignment = if assignment.large {
+                    match large_tx.send(assignment) {
+                        Ok(()) => continue,
+                        Err(e) => {
+                            error!("Large object queue closed, posting now");
+                            e.into_inner()
+                        }
+                    }
+                } else {
+                    assignment
+                };
+
                 {
                     trace!(
                         "posting {} task assignment: {:#?}",
@@ -3186,6 +4862,10 @@ where
             }
         }
     }
+
+    drop(large_tx);
+    large_post_thread.join().expect("Large Object Post Thread");
+
     Ok(())
 }
 
@@ -3202,6 +4882,11 @@ fn start_assignment_post(
 /// Structures implementing this trait are able to post assignments to an agent.
 trait PostAssignment: Sync + Send {
     fn post(&self, assignment: Assignment) -> Result<(), Error>;
+
+    /// Returns true if another large object assignment may be posted.
+    fn large_assignment_slot_available(&self) -> bool {
+        true
+    }
 }
 
 /// Structures implementing this trait are able to process assignments
@@ -3258,10 +4943,126 @@ fn _checker_should_run(checker_fini_rx: &crossbeam::Receiver<FiniMsg>) -> bool {
     }
 }
 
+// When agents post their completed assignments back to us, each outstanding
+// assignment is still polled this often in case its post never arrives (for
+// example, because the agent was restarted before it could deliver it).
+const CALLBACK_FALLBACK_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+// How long the assignment checker waits for an agent to post a completed
+// assignment before checking on the outstanding assignments again.
+const CHECKER_IDLE_INTERVAL: Duration = Duration::from_millis(500);
+
+lazy_static! {
+    // Completed assignments posted to the manager by agents are passed on to
+    // the assignment checker of the job they belong to through these
+    // channels, keyed by job id.
+    static ref COMPLETED_ASSIGNMENT_CHANS: Mutex<
+        HashMap<String, crossbeam::Sender<AgentAssignment>>,
+    > = Mutex::new(HashMap::new());
+}
+
+/// Hand an assignment that an agent posted on completion to the running
+/// evacuate job that it belongs to.  Returns false if there is no such job.
+pub fn report_completed_assignment(
+    job_id: &str,
+    assignment: AgentAssignment,
+) -> bool {
+    let chans = COMPLETED_ASSIGNMENT_CHANS
+        .lock()
+        .expect("completed assignment channels lock");
+
+    match chans.get(job_id) {
+        Some(tx) => tx.send(assignment).is_ok(),
+        None => false,
+    }
+}
+
+// Process an assignment that the agent reports as complete, and pass it to
+// the metadata update broker.  Returns false if the assignment was not
+// outstanding, which is expected when an agent posts an assignment that we
+// have already found by polling (or vice versa).
+fn checker_complete_assignment(
+    job_action: &Arc<EvacuateJob>,
+    ag_assignment: AgentAssignment,
+    md_update_tx: &crossbeam::Sender<AssignmentCacheEntry>,
+) -> Result<bool, Error> {
+    match ag_assignment.stats.state {
+        AgentAssignmentState::Complete(_) => (),
+        _ => return Ok(false),
+    }
+
+    let ace = match job_action
+        .assignments
+        .read()
+        .expect("assignments read lock")
+        .get(&ag_assignment.uuid)
+    {
+        Some(ace) if ace.state == AssignmentState::Assigned => ace.to_owned(),
+        _ => {
+            debug!(
+                "Assignment {} is not outstanding, skipping",
+                ag_assignment.uuid
+            );
+            return Ok(false);
+        }
+    };
+
+    // We don't want to shut the checker down simply because we have issues
+    // handling one assignment.  The process() function should mark the
+    // associated objects appropriately.
+    debug!("Processing Assignment: {:?}", ag_assignment);
+    job_action.process(ag_assignment).unwrap_or_else(|e| {
+        error!("Error Processing Assignment {}", e);
+    });
+
+    if let Err(e) = md_update_tx.send(ace.to_owned()) {
+        job_action
+            .mark_assignment_error(&ace.id, EvacuateObjectError::InternalError);
+        error!(
+            "Assignment Checker: Error sending assignment to the metadata \
+             broker {}",
+            e
+        );
+        return Err(InternalError::new(
+            Some(InternalErrorCode::Crossbeam),
+            CrossbeamError::from(e).description(),
+        )
+        .into());
+    }
+
+    Ok(true)
+}
+
+// Let the assignment watchdog, if the job has one, know how far along an
+// assignment is, and record any progress for its agent.
+fn watchdog_report(
+    job_action: &EvacuateJob,
+    watchdog: &mut Option<AssignmentWatchdog>,
+    ag_assignment: &AgentAssignment,
+) {
+    let watchdog = match watchdog {
+        Some(w) => w,
+        None => return,
+    };
+
+    let stats = &ag_assignment.stats;
+    if let Some(agent) = watchdog.progress(
+        &ag_assignment.uuid,
+        stats.complete + stats.failed,
+        Instant::now(),
+    ) {
+        job_action.agent_event(agent, AgentEvent::Progress);
+    }
+}
+
 /// Responsible for:
 /// 1. periodically checking the Evacuate Job's hash of assignments that have
 /// reached the Assigned state and, if so, querying the associated Agent for an
-/// update on an that Assigned Assignment.
+/// update on an that Assigned Assignment.  If agents post their completed
+/// assignments back to the manager (see `Config::assignment_callback_url`),
+/// the completed assignments arrive on `completed_rx` instead and each
+/// outstanding assignment is only queried every
+/// CALLBACK_FALLBACK_POLL_INTERVAL.
 ///
 /// 2. Upon receipt of a completed assignment from the agent, the assignment is
 /// passed to the process function of the EvacuateJob (which implements the
@@ -3271,14 +5072,29 @@ fn _checker_should_run(checker_fini_rx: &crossbeam::Receiver<FiniMsg>) -> bool {
 /// 3. Finally the assignment is sent to the metadata update broker which will
 /// handle updating the metadata of every object in the assignment in the
 /// Manta Metadata tier.
+///
+/// Unless `assignment_stall_timeout` is 0 the checker also keeps an
+/// AssignmentWatchdog, and re-queues the objects of any assignment that it
+/// finds stalled.  See jobs::watchdog.
 fn start_assignment_checker(
     job_action: Arc<EvacuateJob>,
     checker_fini_rx: crossbeam::Receiver<FiniMsg>,
+    completed_rx: crossbeam::Receiver<AgentAssignment>,
     md_update_tx: crossbeam::Sender<AssignmentCacheEntry>,
 ) -> Result<thread::JoinHandle<Result<(), Error>>, Error> {
     thread::Builder::new()
         .name(String::from("Assignment Checker"))
         .spawn(move || {
+            let callbacks = job_action.config.assignment_callback_url.is_some();
+            let mut last_polled: HashMap<AssignmentId, Instant> =
+                HashMap::new();
+            let mut watchdog =
+                match job_action.config.options.assignment_stall_timeout {
+                    0 => None,
+                    secs => {
+                        Some(AssignmentWatchdog::new(Duration::from_secs(secs)))
+                    }
+                };
             let mut run = true;
             loop {
                 let mut found_assignment_count = 0;
@@ -3286,6 +5102,18 @@ fn start_assignment_checker(
                     run = _checker_should_run(&checker_fini_rx);
                 }
 
+                // Assignments that agents have posted since we last looked.
+                for ag_assignment in completed_rx.try_iter() {
+                    watchdog_report(&job_action, &mut watchdog, &ag_assignment);
+                    if checker_complete_assignment(
+                        &job_action,
+                        ag_assignment,
+                        &md_update_tx,
+                    )? {
+                        found_assignment_count += 1;
+                    }
+                }
+
                 // We'd rather not hold the assignment hash lock here while we
                 // run through all the HTTP GETs and while each completed
                 // assignment is processed.  Furthermore, there's really no need
@@ -3325,12 +5153,50 @@ fn start_assignment_checker(
                     break;
                 }
 
+                last_polled.retain(|id, _| {
+                    assignments
+                        .get(id)
+                        .map_or(false, |a| a.state == AssignmentState::Assigned)
+                });
+
+                if let Some(watchdog) = watchdog.as_mut() {
+                    let now = Instant::now();
+                    watchdog.retain(|id| {
+                        assignments.get(id).map_or(false, |a| {
+                            a.state == AssignmentState::Assigned
+                        })
+                    });
+                    for ace in assignments.values() {
+                        if ace.state == AssignmentState::Assigned {
+                            watchdog.watch(
+                                &ace.id,
+                                &ace.dest_shark.manta_storage_id,
+                                now,
+                            );
+                        }
+                    }
+                }
+
                 for ace in assignments.values() {
                     if ace.state != AssignmentState::Assigned {
                         trace!("Skipping unassigned assignment {:?}", ace);
                         continue;
                     }
 
+                    // The agent will tell us when this assignment is done, so
+                    // only check on it once in a while.
+                    if callbacks {
+                        let now = Instant::now();
+                        if let Some(polled) = last_polled.get(&ace.id) {
+                            if now.duration_since(*polled)
+                                < CALLBACK_FALLBACK_POLL_INTERVAL
+                            {
+                                continue;
+                            }
+                        }
+                        last_polled.insert(ace.id.clone(), now);
+                    }
+
                     debug!(
                         "Assignment Checker, checking: {} | {:?}",
                         ace.id, ace.state
@@ -3353,56 +5219,55 @@ fn start_assignment_checker(
                         "Got Assignment: {} {:?}",
                         ag_assignment.uuid, ag_assignment.stats
                     );
+                    watchdog_report(&job_action, &mut watchdog, &ag_assignment);
                     // If agent assignment is complete, process it and pass
                     // it to the metadata update broker.  Otherwise, continue
                     // to next assignment.
-                    match ag_assignment.stats.state {
-                        AgentAssignmentState::Complete(_) => {
-                            // We don't want to shut this thread down simply
-                            // because we have issues handling one assignment.
-                            // The process() function should mark the
-                            // associated objects appropriately.
-                            debug!(
-                                "Processing Assignment: {:?}",
-                                ag_assignment
-                            );
-                            job_action.process(ag_assignment).unwrap_or_else(
-                                |e| {
-                                    error!("Error Processing Assignment {}", e);
-                                },
-                            );
-                        }
-                        _ => continue,
+                    if checker_complete_assignment(
+                        &job_action,
+                        ag_assignment,
+                        &md_update_tx,
+                    )? {
+                        found_assignment_count += 1;
                     }
+                }
 
-                    found_assignment_count += 1;
-
-                    match md_update_tx.send(ace.to_owned()) {
-                        Ok(()) => (),
-                        Err(e) => {
-                            job_action.mark_assignment_error(
-                                &ace.id,
-                                EvacuateObjectError::InternalError,
-                            );
-                            error!(
-                                "Assignment Checker: Error sending \
-                                 assignment to the metadata \
-                                 broker {}",
-                                e
-                            );
-                            return Err(InternalError::new(
-                                Some(InternalErrorCode::Crossbeam),
-                                CrossbeamError::from(e).description(),
-                            )
-                            .into());
-                        }
+                // Look for stalled assignments only once the agents have
+                // reported on them.  One that completed in this pass is no
+                // longer outstanding, and is left alone by
+                // requeue_stalled_assignment().
+                if let Some(watchdog) = watchdog.as_mut() {
+                    let now = Instant::now();
+                    for id in watchdog.stalled(now) {
+                        job_action.requeue_stalled_assignment(&id);
+                        watchdog.forget(&id);
                     }
                 }
 
                 // TODO: MANTA-5106
                 if found_assignment_count == 0 {
-                    trace!("Found 0 completed assignments, sleeping for 500ms");
-                    thread::sleep(Duration::from_millis(500));
+                    trace!(
+                        "Found 0 completed assignments, waiting up to {:?}",
+                        CHECKER_IDLE_INTERVAL
+                    );
+                    match completed_rx.recv_timeout(CHECKER_IDLE_INTERVAL) {
+                        Ok(ag_assignment) => {
+                            watchdog_report(
+                                &job_action,
+                                &mut watchdog,
+                                &ag_assignment,
+                            );
+                            checker_complete_assignment(
+                                &job_action,
+                                ag_assignment,
+                                &md_update_tx,
+                            )?;
+                        }
+                        Err(RecvTimeoutError::Timeout) => (),
+                        Err(RecvTimeoutError::Disconnected) => {
+                            thread::sleep(CHECKER_IDLE_INTERVAL)
+                        }
+                    }
                     continue;
                 }
 
@@ -3421,6 +5286,80 @@ enum UpdateWorkerMsg {
     Stop,
 }
 
+// How long an idle dynamic metadata update worker with a partially filled
+// batch waits before checking the queue for more assignments.
+const MD_BATCH_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+// Metadata updates waiting to be sent to the metadata tier, collected from
+// one or more assignments.  Each metadata update worker has its own batch so
+// that, like the moray clients, it does not need to be locked.
+//
+// The updates are flushed in per shard batch RPCs once `max_size` updates
+// are pending or the oldest one has waited `flush_interval`, whichever comes
+// first.  An assignment is not marked complete until the batch containing its
+// updates has been flushed.
+struct MetadataUpdateBatch {
+    requests: HashMap<u32, Vec<BatchRequest>>,
+    assignments: Vec<(AssignmentId, Vec<EvacuateObject>)>,
+    num_requests: usize,
+    oldest: Option<Instant>,
+    max_size: usize,
+    flush_interval: Duration,
+}
+
+impl MetadataUpdateBatch {
+    fn new(options: &ConfigOptions) -> Self {
+        MetadataUpdateBatch {
+            requests: HashMap::new(),
+            assignments: vec![],
+            num_requests: 0,
+            oldest: None,
+            max_size: std::cmp::max(options.md_batch_size, 1),
+            flush_interval: Duration::from_millis(
+                options.md_batch_flush_interval,
+            ),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+
+    fn add_assignment(
+        &mut self,
+        id: AssignmentId,
+        objects: Vec<EvacuateObject>,
+    ) {
+        if self.oldest.is_none() {
+            self.oldest = Some(Instant::now());
+        }
+        self.assignments.push((id, objects));
+    }
+
+    fn add_request(&mut self, shard: u32, request: BatchRequest) {
+        self.requests
+            .entry(shard)
+            .or_insert_with(Vec::new)
+            .push(request);
+        self.num_requests += 1;
+    }
+
+    // The time remaining before this batch must be flushed, or None if there
+    // is nothing waiting to be flushed.
+    fn time_to_flush(&self) -> Option<Duration> {
+        self.oldest.map(|oldest| {
+            self.flush_interval
+                .checked_sub(oldest.elapsed())
+                .unwrap_or_else(|| Duration::from_millis(0))
+        })
+    }
+
+    fn should_flush(&self) -> bool {
+        self.num_requests >= self.max_size
+            || self.time_to_flush() == Some(Duration::from_millis(0))
+    }
+}
+
 fn metadata_update_worker_static(
     job_action: Arc<EvacuateJob>,
     static_update_rx: crossbeam::Receiver<UpdateWorkerMsg>,
@@ -3433,6 +5372,7 @@ fn metadata_update_worker_static(
         // number of per-shard connections by simply tuning the number of
         // metadata update worker threads.
         let mut client_hash: HashMap<u32, MorayClient> = HashMap::new();
+        let mut batch = MetadataUpdateBatch::new(&job_action.config.options);
 
         debug!(
             "Started metadata update worker: {:?}",
@@ -3441,7 +5381,17 @@ fn metadata_update_worker_static(
 
         loop {
             let now = std::time::Instant::now();
-            let ace = match static_update_rx.recv() {
+
+            // If there are updates waiting in the batch only wait for the
+            // next assignment until they are due to be flushed.
+            let msg = match batch.time_to_flush() {
+                Some(timeout) => static_update_rx.recv_timeout(timeout),
+                None => static_update_rx
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            let ace = match msg {
                 Ok(msg) => match msg {
                     UpdateWorkerMsg::Data(d) => d,
                     UpdateWorkerMsg::Stop => {
@@ -3453,6 +5403,14 @@ fn metadata_update_worker_static(
                         break;
                     }
                 },
+                Err(RecvTimeoutError::Timeout) => {
+                    flush_metadata_updates(
+                        &job_action,
+                        &mut client_hash,
+                        &mut batch,
+                    );
+                    continue;
+                }
                 Err(e) => {
                     error!("Error receiving UpdateWorkerMessage: {}", e);
                     break;
@@ -3467,9 +5425,16 @@ fn metadata_update_worker_static(
 
             let id = ace.id.clone();
             trace!("Assignment Metadata Update Start: {}", id);
-            metadata_update_assignment(&job_action, ace, &mut client_hash);
+            metadata_update_assignment(
+                &job_action,
+                ace,
+                &mut client_hash,
+                &mut batch,
+            );
             trace!("Assignment Metadata Update Complete: {}", id);
         }
+
+        flush_metadata_updates(&job_action, &mut client_hash, &mut batch);
     }
 }
 
@@ -3488,16 +5453,20 @@ fn metadata_update_worker_dynamic(
         // number of per-shard connections by simply tuning the number of
         // metadata update worker threads.
         let mut client_hash: HashMap<u32, MorayClient> = HashMap::new();
+        let mut batch = MetadataUpdateBatch::new(&job_action.config.options);
 
         metrics_gauge_inc(MD_THREAD_GAUGE);
+        metrics_job_md_thread_inc(&job_action.db_name);
 
         debug!(
             "Started metadata update worker: {:?}",
             thread::current().id()
         );
 
-        // If the queue is empty or we receive a Stop message, then break out
-        // of the loop and return.
+        // If the queue is empty or we receive a Stop message, then flush
+        // any pending updates, break out of the loop and return.  While the
+        // batch is not yet due to be flushed an empty queue is polled
+        // instead, giving more assignments the chance to join the batch.
         //
         // If we get a retry error then, retry.
         //
@@ -3512,13 +5481,30 @@ fn metadata_update_worker_dynamic(
                     }
                 },
                 Steal::Retry => continue,
-                Steal::Empty => break,
+                Steal::Empty => match batch.time_to_flush() {
+                    Some(wait) if wait > Duration::from_millis(0) => {
+                        thread::sleep(std::cmp::min(
+                            wait,
+                            MD_BATCH_POLL_INTERVAL,
+                        ));
+                        continue;
+                    }
+                    _ => break,
+                },
             };
-            metadata_update_assignment(&job_action, ace, &mut client_hash);
+            metadata_update_assignment(
+                &job_action,
+                ace,
+                &mut client_hash,
+                &mut batch,
+            );
         }
 
+        flush_metadata_updates(&job_action, &mut client_hash, &mut batch);
+
         debug!("Exiting metadata update worker.");
         metrics_gauge_dec(MD_THREAD_GAUGE);
+        metrics_job_md_thread_dec(&job_action.db_name);
     }
 }
 
@@ -3596,10 +5582,10 @@ fn metadata_update_one(
     ret
 }
 
-// Attempt to update all objects in this assignment in per shard batches.
-// So given an assignment with objects in two different shards, we will make
-// two calls to moray to update all the objects for this assignment in their
-// respective shards.
+// Attempt to update all objects in per shard batches.  So given objects in
+// two different shards, we will make two calls to moray to update all the
+// objects in their respective shards.  A shard with more than `max_size`
+// objects is split across several batches.
 //
 // If a batch fails this function falls back to updating each object
 // individually for that batch.
@@ -3607,14 +5593,10 @@ fn metadata_update_batch(
     job_action: &Arc<EvacuateJob>,
     client_hash: &mut HashMap<u32, MorayClient>,
     batched_reqs: HashMap<u32, Vec<BatchRequest>>,
+    max_size: usize,
 ) -> Vec<ObjectId> {
     let mut marked_error = vec![];
     for (shard, requests) in batched_reqs.into_iter() {
-        let num_reqs = requests.len();
-        info!(
-            "Updating {} objects for shard {} in a single batch",
-            num_reqs, shard
-        );
         let mclient = match get_client_from_hash(job_action, client_hash, shard)
         {
             Ok(c) => c,
@@ -3640,37 +5622,84 @@ fn metadata_update_batch(
             }
         };
 
-        // If we fail the batch, step through the objects and attempt to
-        // update each one individually. For each object that fails to
-        // update mark it as error, and add it to the marked_error Vec to
-        // be trimmed from our list of successful updates later.
-        let now = std::time::Instant::now();
-        if let Err(e) =
-            mclient.batch(&requests, &ObjectMethodOptions::default(), |_| {
-                // elapsed() gives us a u128, but unfortunately AtomicU128 is
-                // nightly only.
-                let md_update_time = now.elapsed().as_micros();
+        for chunk in requests.chunks(max_size) {
+            let num_reqs = chunk.len();
+            info!(
+                "Updating {} objects for shard {} in a single batch",
+                num_reqs, shard
+            );
 
-                info!(
-                    "Batch updated {} objects in {}us",
-                    num_reqs, md_update_time
+            // If we fail the batch, step through the objects and attempt to
+            // update each one individually. For each object that fails to
+            // update mark it as error, and add it to the marked_error Vec to
+            // be trimmed from our list of successful updates later.
+            let now = std::time::Instant::now();
+            if let Err(e) =
+                mclient.batch(chunk, &ObjectMethodOptions::default(), |_| {
+                    // elapsed() gives us a u128, but unfortunately AtomicU128
+                    // is nightly only.
+                    let md_update_time = now.elapsed().as_micros();
+
+                    info!(
+                        "Batch updated {} objects in {}us",
+                        num_reqs, md_update_time
+                    );
+                    Ok(())
+                })
+            {
+                error!("Batch update failed, retrying individually: {}", e);
+                retry_batch_update(
+                    job_action,
+                    chunk.to_vec(),
+                    shard,
+                    mclient,
+                    &mut marked_error,
                 );
-                Ok(())
-            })
-        {
-            error!("Batch update failed, retrying individually: {}", e);
-            retry_batch_update(
-                job_action,
-                requests,
-                shard,
-                mclient,
-                &mut marked_error,
-            );
+            }
         }
     }
     marked_error
 }
 
+// Send all of the updates waiting in `batch` to the metadata tier, then mark
+// the assignments they came from complete.
+fn flush_metadata_updates(
+    job_action: &Arc<EvacuateJob>,
+    client_hash: &mut HashMap<u32, MorayClient>,
+    batch: &mut MetadataUpdateBatch,
+) {
+    if batch.is_empty() {
+        return;
+    }
+
+    let requests = std::mem::take(&mut batch.requests);
+    let assignments = std::mem::take(&mut batch.assignments);
+
+    debug!(
+        "Flushing {} metadata updates for {} assignments",
+        batch.num_requests,
+        assignments.len()
+    );
+    batch.num_requests = 0;
+    batch.oldest = None;
+
+    let marked_error = metadata_update_batch(
+        job_action,
+        client_hash,
+        requests,
+        batch.max_size,
+    );
+
+    for (id, mut updated_objects) in assignments.into_iter() {
+        // Remove any of the objects that we had to mark as "Error" from the
+        // list of updated objects.
+        updated_objects.retain(|o| !marked_error.contains(&o.id));
+
+        job_action.complete_assignment(&id, updated_objects);
+        // TODO: check for DB insert error
+    }
+}
+
 fn retry_batch_update(
     job_action: &Arc<EvacuateJob>,
     requests: Vec<BatchRequest>,
@@ -3712,7 +5741,7 @@ fn retry_batch_update(
 }
 
 fn batch_add_putobj(
-    batched_reqs: &mut HashMap<u32, Vec<BatchRequest>>,
+    batch: &mut MetadataUpdateBatch,
     object: Value,
     shard: u32,
     etag: String,
@@ -3729,25 +5758,24 @@ fn batch_add_putobj(
         value: object,
     };
 
-    batched_reqs
-        .entry(shard)
-        .and_modify(|ent| ent.push(BatchRequest::Put(put_req.clone())))
-        .or_insert_with(|| vec![BatchRequest::Put(put_req)]);
+    batch.add_request(shard, BatchRequest::Put(put_req));
 
     Ok(())
 }
 
+// Update the metadata for every object in this assignment.  With batched
+// updates the requests are added to `batch`, which is flushed if it is full
+// or due, and the assignment is completed when its batch is flushed.
+// Otherwise each object is updated here and the assignment completed
+// immediately.
 fn metadata_update_assignment(
     job_action: &Arc<EvacuateJob>,
     ace: AssignmentCacheEntry,
     client_hash: &mut HashMap<u32, MorayClient>,
+    batch: &mut MetadataUpdateBatch,
 ) {
     info!("Updating metadata for assignment: {}", ace.id);
 
-    // There is one moray client per shard, so when we collect the requests
-    // into a batch we need to know which moray client this is going to based
-    // on the shard number.
-    let mut batched_reqs: HashMap<u32, Vec<BatchRequest>> = HashMap::new();
     let mut updated_objects = vec![];
     let dest_shark = &ace.dest_shark;
     let objects = job_action
@@ -3783,9 +5811,9 @@ fn metadata_update_assignment(
         match job_action.update_object_shark(mobj, dest_shark) {
             Ok(o) => {
                 if job_action.config.options.use_batched_updates {
-                    if let Err(e) =
-                        batch_add_putobj(&mut batched_reqs, o, shard, etag)
-                    {
+                    // There is one moray client per shard, so the batch
+                    // keeps the requests for each shard together.
+                    if let Err(e) = batch_add_putobj(batch, o, shard, etag) {
                         error!(
                             "Could not add put object operation to batch ({}): \
                             {}",
@@ -3821,37 +5849,122 @@ fn metadata_update_assignment(
             }
         };
 
-        updated_objects.push(eobj);
+        updated_objects.push(eobj);
+    }
+
+    if job_action.config.options.use_batched_updates {
+        batch.add_assignment(ace.id, updated_objects);
+        if batch.should_flush() {
+            flush_metadata_updates(job_action, client_hash, batch);
+        }
+        return;
+    }
+
+    job_action.complete_assignment(&ace.id, updated_objects);
+    // TODO: check for DB insert error
+}
+
+// Apply a job update message received while the job is running.
+fn update_dynamic_job(
+    job_action: &Arc<EvacuateJob>,
+    pool: &mut ThreadPool,
+    queue_back: &Arc<Injector<DyanmicWorkerMsg>>,
+    max_thread_count: &mut usize,
+    msg: JobUpdateMessage,
+) {
+    let JobUpdateMessage::Evacuate(eum) = msg;
+    match eum {
+        EvacuateJobUpdateMessage::SetMetadataThreads(new_worker_count) => {
+            update_dynamic_metadata_threads(
+                pool,
+                queue_back,
+                max_thread_count,
+                new_worker_count,
+            );
+        }
+        EvacuateJobUpdateMessage::SetLargeObjects(policy) => {
+            info!(
+                "Updating large object policy from {:?} to {:?}.",
+                job_action.large_objects(),
+                policy
+            );
+            job_action.set_large_objects(policy);
+
+            // Record the new policy so that it survives a restart of the
+            // manager.
+            if let Err(e) = job_action.update_evacuate_config() {
+                error!("Error recording large object policy: {}", e);
+            }
+        }
+        EvacuateJobUpdateMessage::SetMaxAssignmentSize(sizing) => {
+            info!(
+                "Updating assignment sizing from {:?} to {:?}.",
+                job_action.assignment_sizing(),
+                sizing
+            );
+            job_action.set_assignment_sizing(sizing);
+
+            if let Err(e) = job_action.update_evacuate_config() {
+                error!("Error recording assignment sizing: {}", e);
+            }
+        }
+        EvacuateJobUpdateMessage::SetDestinationConcurrency(workers) => {
+            info!("Updating destination concurrency to {}.", workers);
+            update_dynamic_agent_config(
+                job_action,
+                AgentConfigUpdate {
+                    workers_per_assignment: Some(workers),
+                    ..Default::default()
+                },
+            );
+        }
+        EvacuateJobUpdateMessage::SetByteRateLimit(rate) => {
+            info!("Updating destination byte rate limit to {}.", rate);
+            let mut update = AgentConfigUpdate::default();
+            update.throttle.max_bytes_per_sec = Some(rate);
+            update_dynamic_agent_config(job_action, update);
+        }
     }
+}
 
-    if job_action.config.options.use_batched_updates {
-        let marked_error =
-            metadata_update_batch(job_action, client_hash, batched_reqs);
+// Send new agent settings to every destination chosen so far.  This is done
+// on a thread of its own so that unresponsive agents do not hold up metadata
+// updates.  Destinations chosen later get the settings when their first
+// assignment is posted.
+fn update_dynamic_agent_config(
+    job_action: &Arc<EvacuateJob>,
+    update: AgentConfigUpdate,
+) {
+    job_action.update_agent_config(update);
 
-        // Remove any of the objects that we had to mark as "Error" from the list
-        // of updated objects.
-        updated_objects.retain(|o| !marked_error.contains(&o.id));
-    }
+    let job_action = Arc::clone(job_action);
+    let spawned = thread::Builder::new()
+        .name(String::from("Agent config update"))
+        .spawn(move || {
+            let dest_sharks: Vec<StorageId> = job_action
+                .dest_shark_hash
+                .read()
+                .expect("dest_shark_hash read lock")
+                .keys()
+                .cloned()
+                .collect();
 
-    info!("Assignment Complete: {}", &ace.id);
+            for storage_id in dest_sharks {
+                job_action.apply_agent_config(&storage_id);
+            }
+        });
 
-    job_action.remove_assignment_from_cache(&ace.id);
-    job_action.mark_objects_complete(updated_objects);
-    // TODO: check for DB insert error
+    if let Err(e) = spawned {
+        error!("Error starting agent config update thread: {}", e);
+    }
 }
 
 fn update_dynamic_metadata_threads(
     pool: &mut ThreadPool,
     queue_back: &Arc<Injector<DyanmicWorkerMsg>>,
     max_thread_count: &mut usize,
-    msg: JobUpdateMessage,
+    new_worker_count: usize,
 ) {
-    // Currently there is only one valid message here so we
-    // can use this irrefutable pattern.
-    // If we add any additional messages we have to use match
-    // statements.
-    let JobUpdateMessage::Evacuate(eum) = msg;
-    let EvacuateJobUpdateMessage::SetMetadataThreads(new_worker_count) = eum;
     let difference: i32 = new_worker_count as i32 - *max_thread_count as i32;
 
     info!(
@@ -3932,8 +6045,9 @@ fn metadata_update_broker_dynamic(
         .spawn(move || {
             loop {
                 if let Ok(msg) = update_rx.try_recv() {
-                    debug!("Received metadata update message: {:#?}", msg);
-                    update_dynamic_metadata_threads(
+                    debug!("Received job update message: {:#?}", msg);
+                    update_dynamic_job(
+                        &job_action,
                         &mut pool,
                         &queue,
                         &mut max_thread_count,
@@ -3993,6 +6107,7 @@ fn metadata_update_broker_dynamic(
             }
             pool.join();
             metrics_gauge_set(MD_THREAD_GAUGE, 0);
+            metrics_job_md_thread_set(&job_action.db_name, 0);
             Ok(())
         })
         .map_err(Error::from)
@@ -4030,6 +6145,7 @@ fn _update_broker_static(
     }
 
     metrics_gauge_set(MD_THREAD_GAUGE, num_threads);
+    metrics_job_md_thread_set(&job_action.db_name, num_threads);
 
     loop {
         let ace = match md_update_rx.recv() {
@@ -4068,6 +6184,7 @@ fn _update_broker_static(
     }
 
     metrics_gauge_set(MD_THREAD_GAUGE, 0);
+    metrics_job_md_thread_set(&job_action.db_name, 0);
     Ok(())
 }
 
@@ -4112,6 +6229,7 @@ mod tests {
     use super::*;
     use crate::metrics::metrics_init;
     use crate::storinfo::ChooseAlgorithm;
+    use crate::test_harness::{AgentBehavior, FakeAgents};
     use lazy_static::lazy_static;
     use quickcheck::{Arbitrary, StdThreadGen};
     use quickcheck_helpers::random::string as random_string;
@@ -4801,6 +6919,7 @@ mod tests {
         let assignment_checker_thread = start_assignment_checker(
             Arc::clone(&job_action),
             checker_fini_rx,
+            crossbeam::never(),
             md_update_tx,
         )
         .expect("start assignment checker thread");
@@ -5014,6 +7133,9 @@ mod tests {
         let agent_assignment = AgentAssignment {
             uuid: uuid.clone(),
             stats: agent_assignment_stats,
+            hash: None,
+            kind: Default::default(),
+            callback: None,
             tasks: vec![],
         };
 
@@ -5089,6 +7211,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn metadata_update_batch_size_test() {
+        unit_test_init();
+        let mut g = StdThreadGen::new(10);
+        let options = ConfigOptions {
+            md_batch_size: 2,
+            md_batch_flush_interval: 60_000,
+            ..Default::default()
+        };
+        let mut batch = MetadataUpdateBatch::new(&options);
+
+        assert!(batch.is_empty());
+        assert_eq!(batch.time_to_flush(), None);
+        assert!(!batch.should_flush());
+
+        // One update from one assignment fits in the batch.
+        let eobj = EvacuateObject::arbitrary(&mut g);
+        batch_add_putobj(&mut batch, eobj.object.clone(), 1, eobj.etag.clone())
+            .expect("add first update");
+        batch.add_assignment(Uuid::new_v4().to_string(), vec![eobj]);
+
+        assert!(!batch.is_empty());
+        assert!(batch.time_to_flush().is_some());
+        assert!(!batch.should_flush());
+
+        // A second assignment's update, for another shard, fills it.
+        let eobj = EvacuateObject::arbitrary(&mut g);
+        batch_add_putobj(&mut batch, eobj.object.clone(), 2, eobj.etag.clone())
+            .expect("add second update");
+        batch.add_assignment(Uuid::new_v4().to_string(), vec![eobj]);
+
+        assert!(batch.should_flush());
+        assert_eq!(batch.requests.len(), 2);
+        assert_eq!(batch.assignments.len(), 2);
+    }
+
+    #[test]
+    fn metadata_update_batch_interval_test() {
+        unit_test_init();
+        let options = ConfigOptions {
+            md_batch_size: 100,
+            md_batch_flush_interval: 200,
+            ..Default::default()
+        };
+        let mut batch = MetadataUpdateBatch::new(&options);
+
+        // An assignment whose objects all failed still has to wait for the
+        // batch to be flushed before it is completed.
+        batch.add_assignment(Uuid::new_v4().to_string(), vec![]);
+        assert!(!batch.should_flush());
+
+        thread::sleep(Duration::from_millis(250));
+        assert_eq!(batch.time_to_flush(), Some(Duration::from_millis(0)));
+        assert!(batch.should_flush());
+    }
+
     #[test]
     fn skip_object_test() {
         // TODO: add test that includes skipped objects
@@ -5166,6 +7344,339 @@ mod tests {
         );
     }
 
+    #[test]
+    fn select_destination_test() {
+        unit_test_init();
+        let mut g = StdThreadGen::new(10);
+        let mut obj = MantaObject::arbitrary(&mut g);
+        obj.sharks[0].datacenter = String::from("dc1");
+        obj.sharks[1].datacenter = String::from("dc2");
+
+        let from_shark = obj.sharks[0].clone();
+        let obj_value = serde_json::to_value(obj.clone()).expect("obj value");
+
+        // The other copy of the object is in dc2.
+        let mut reducing = generate_storage_node(false);
+        reducing.manta_storage_id = String::from("1.stor.dc2");
+        reducing.datacenter = String::from("dc2");
+
+        let mut preserving = generate_storage_node(false);
+        preserving.manta_storage_id = String::from("1.stor.dc3");
+        preserving.datacenter = String::from("dc3");
+
+        // Returns whether the spread is preserved, and the chosen shark.
+        let select = |sharks: &[StorageNode], policy| {
+            let dest =
+                select_destination(&obj_value, &from_shark, sharks, policy);
+            dest.map(|d| match d {
+                Destination::Preserving(s) => {
+                    (true, s.manta_storage_id.clone())
+                }
+                Destination::Reducing(s) => (false, s.manta_storage_id.clone()),
+            })
+        };
+
+        // A destination that keeps the spread is preferred under either
+        // policy, even when it is not first in the list.
+        let sharks = vec![reducing.clone(), preserving.clone()];
+        for policy in
+            [DatacenterSpread::Strict, DatacenterSpread::BestEffort].iter()
+        {
+            assert_eq!(
+                select(&sharks, *policy),
+                Ok((true, String::from("1.stor.dc3")))
+            );
+        }
+
+        // Only the best effort policy reduces the spread.
+        let sharks = vec![reducing.clone()];
+        assert_eq!(
+            select(&sharks, DatacenterSpread::Strict),
+            Err(ObjectSkippedReason::ObjectAlreadyInDatacenter)
+        );
+        assert_eq!(
+            select(&sharks, DatacenterSpread::BestEffort),
+            Ok((false, String::from("1.stor.dc2")))
+        );
+
+        // A shark that already has a copy is never chosen.
+        let mut has_copy = generate_storage_node(false);
+        has_copy.manta_storage_id = obj.sharks[1].manta_storage_id.clone();
+        let sharks = vec![has_copy];
+        assert_eq!(
+            select(&sharks, DatacenterSpread::BestEffort),
+            Err(ObjectSkippedReason::ObjectAlreadyOnDestShark)
+        );
+
+        assert_eq!(
+            select(&[], DatacenterSpread::BestEffort),
+            Err(ObjectSkippedReason::AgentBusy)
+        );
+    }
+
+    #[test]
+    fn select_sources_test() {
+        let shark = |id: &str, dc: &str| MantaObjectShark {
+            manta_storage_id: id.to_string(),
+            datacenter: dc.to_string(),
+        };
+        let sharks = vec![
+            shark("1.stor.dc1", "dc1"),
+            shark("2.stor.dc2", "dc2"),
+            shark("3.stor.dc1", "dc1"),
+            shark("4.stor.dc3", "dc3"),
+        ];
+        let ids =
+            |sources: Option<(MantaObjectShark, Vec<MantaObjectShark>)>| {
+                let (source, alternates) = sources.expect("sources");
+                std::iter::once(source)
+                    .chain(alternates)
+                    .map(|s| s.manta_storage_id)
+                    .collect::<Vec<String>>()
+            };
+
+        // The shark being evacuated is skipped, the rest keep their order.
+        assert_eq!(
+            ids(select_sources(
+                &sharks,
+                "1.stor.dc1",
+                "dc3",
+                SourceSelection::MetadataOrder,
+                5,
+            )),
+            vec!["2.stor.dc2", "3.stor.dc1", "4.stor.dc3"]
+        );
+
+        // Copies in the destination's datacenter come first.
+        assert_eq!(
+            ids(select_sources(
+                &sharks,
+                "1.stor.dc1",
+                "dc1",
+                SourceSelection::SameDatacenterFirst,
+                5,
+            )),
+            vec!["3.stor.dc1", "2.stor.dc2", "4.stor.dc3"]
+        );
+
+        // The number of alternates is capped, and can be turned off.
+        assert_eq!(
+            ids(select_sources(
+                &sharks,
+                "9.stor.dc9",
+                "dc9",
+                SourceSelection::MetadataOrder,
+                1,
+            )),
+            vec!["1.stor.dc1", "2.stor.dc2"]
+        );
+        assert_eq!(
+            ids(select_sources(
+                &sharks,
+                "9.stor.dc9",
+                "dc9",
+                SourceSelection::MetadataOrder,
+                0,
+            )),
+            vec!["1.stor.dc1"]
+        );
+
+        let only_evac = vec![shark("1.stor.dc1", "dc1")];
+        assert!(select_sources(
+            &only_evac,
+            "1.stor.dc1",
+            "dc2",
+            SourceSelection::MetadataOrder,
+            2,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn large_object_policy_test() {
+        let policy: LargeObjectPolicy = serde_json::from_str(
+            r#"{"policy": "slow_queue", "max_bytes": 1000}"#,
+        )
+        .expect("slow queue policy");
+
+        assert_eq!(
+            policy,
+            LargeObjectPolicy::SlowQueue {
+                max_bytes: 1000,
+                concurrency: DEFAULT_LARGE_OBJECT_CONCURRENCY,
+            }
+        );
+        assert!(policy.validate().is_ok());
+        assert!(policy.slows(1001));
+        assert!(!policy.slows(1000));
+        assert!(!policy.skips(1001));
+
+        let policy = LargeObjectPolicy::Skip { max_bytes: 1000 };
+        assert!(policy.skips(1001));
+        assert!(!policy.skips(1000));
+        assert!(!policy.slows(1001));
+
+        let policy = LargeObjectPolicy::default();
+        assert!(!policy.skips(std::u64::MAX));
+        assert!(!policy.slows(std::u64::MAX));
+
+        assert!(LargeObjectPolicy::Skip { max_bytes: 0 }.validate().is_err());
+        assert!(LargeObjectPolicy::SlowQueue {
+            max_bytes: 1000,
+            concurrency: 0,
+        }
+        .validate()
+        .is_err());
+
+        let msg = EvacuateJobUpdateMessage::SetLargeObjects(
+            LargeObjectPolicy::Skip { max_bytes: 0 },
+        );
+        assert!(msg.validate().is_err());
+    }
+
+    #[test]
+    fn object_filter_test() {
+        let owner = "930896af-bf8c-48d4-885c-6573a94b1853";
+        let filter: ObjectFilter = serde_json::from_value(serde_json::json!({
+            "owners": [owner],
+            "min_mtime": "2020-01-01T00:00:00Z",
+            "max_mtime": "2020-02-01T00:00:00Z",
+            "min_size": 100,
+            "max_size": 1000,
+        }))
+        .expect("object filter");
+        assert!(filter.validate().is_ok());
+        assert!(!filter.is_empty());
+
+        // 2020-01-15T00:00:00Z
+        let object = serde_json::json!({
+            "owner": owner.to_uppercase(),
+            "mtime": 1_579_046_400_000_i64,
+            "contentLength": 1000,
+        });
+        assert!(filter.matches(&object));
+
+        let mut other_owner = object.clone();
+        other_owner["owner"] = serde_json::json!(Uuid::new_v4().to_string());
+        assert!(!filter.matches(&other_owner));
+
+        // min_mtime is inclusive, max_mtime is not.
+        let mut mtime = object.clone();
+        mtime["mtime"] = serde_json::json!(1_577_836_800_000_i64);
+        assert!(filter.matches(&mtime));
+        mtime["mtime"] = serde_json::json!(1_580_515_200_000_i64);
+        assert!(!filter.matches(&mtime));
+
+        let mut size = object.clone();
+        size["contentLength"] = serde_json::json!(99);
+        assert!(!filter.matches(&size));
+        size["contentLength"] = serde_json::json!(1001);
+        assert!(!filter.matches(&size));
+
+        let mut missing = object.clone();
+        missing.as_object_mut().expect("object").remove("mtime");
+        assert!(!filter.matches(&missing));
+
+        let empty = ObjectFilter::default();
+        assert!(empty.is_empty());
+        assert!(empty.matches(&serde_json::json!({})));
+
+        let invalid = ObjectFilter {
+            owners: vec![String::from("not-a-uuid")],
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+        let invalid = ObjectFilter {
+            min_size: Some(1000),
+            max_size: Some(100),
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+        let invalid = ObjectFilter {
+            min_mtime: filter.max_mtime,
+            max_mtime: filter.min_mtime,
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+
+        assert!(serde_json::from_value::<ObjectFilter>(
+            serde_json::json!({"owner": owner})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn dynamic_update_validate_test() {
+        let msg: EvacuateJobUpdateMessage =
+            serde_json::from_value(serde_json::json!({
+                "action": "set_max_assignment_size",
+                "params": { "policy": "byte_budget", "max_bytes": 1073741824 }
+            }))
+            .expect("set_max_assignment_size");
+        match &msg {
+            EvacuateJobUpdateMessage::SetMaxAssignmentSize(sizing) => {
+                assert_eq!(
+                    *sizing,
+                    AssignmentSizing::ByteBudget {
+                        max_bytes: 1073741824,
+                        max_tasks: 1000,
+                    }
+                );
+            }
+            _ => panic!("expected set_max_assignment_size"),
+        }
+        assert!(msg.validate().is_ok());
+
+        let msg = EvacuateJobUpdateMessage::SetMaxAssignmentSize(
+            AssignmentSizing::ByteBudget {
+                max_bytes: 0,
+                max_tasks: 10,
+            },
+        );
+        assert!(msg.validate().is_err());
+
+        assert!(EvacuateJobUpdateMessage::SetDestinationConcurrency(4)
+            .validate()
+            .is_ok());
+        assert!(EvacuateJobUpdateMessage::SetDestinationConcurrency(0)
+            .validate()
+            .is_err());
+
+        assert!(EvacuateJobUpdateMessage::SetByteRateLimit(0)
+            .validate()
+            .is_ok());
+        assert!(EvacuateJobUpdateMessage::SetByteRateLimit(
+            MIN_RATE_BYTES_PER_SEC
+        )
+        .validate()
+        .is_ok());
+        assert!(EvacuateJobUpdateMessage::SetByteRateLimit(1024)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn agent_config_update_test() {
+        let job_action = create_test_evacuate_job(1);
+        let mut update = AgentConfigUpdate::default();
+        update.throttle.max_bytes_per_sec = Some(MIN_RATE_BYTES_PER_SEC);
+        job_action.update_agent_config(update);
+        job_action.update_agent_config(AgentConfigUpdate {
+            workers_per_assignment: Some(4),
+            ..Default::default()
+        });
+
+        // Later updates add to, rather than replace, earlier ones.
+        let state = job_action.agent_config.lock().unwrap();
+        assert_eq!(
+            state.update.throttle.max_bytes_per_sec,
+            Some(MIN_RATE_BYTES_PER_SEC)
+        );
+        assert_eq!(state.update.workers_per_assignment, Some(4));
+        assert_eq!(state.update.throttle.max_disk_busy_pct, None);
+        assert_eq!(state.generation, 2);
+    }
+
     fn run_full_test(
         test_objects: Vec<MantaObject>,
         md_update_th: Option<
@@ -5224,8 +7735,12 @@ mod tests {
             ),
             EvacuateJobType::Retry(retry_uuid) => {
                 // start local db generator
-                start_local_db_generator(obj_tx, retry_uuid)
-                    .expect("local db generator")
+                start_local_db_generator(
+                    obj_tx,
+                    Arc::clone(&job_action),
+                    retry_uuid,
+                )
+                .expect("local db generator")
             }
         };
 
@@ -5242,6 +7757,7 @@ mod tests {
         let assignment_checker_thread = start_assignment_checker(
             Arc::clone(&job_action),
             checker_fini_rx,
+            crossbeam::never(),
             md_update_tx,
         )
         .expect("start assignment checker thread");
@@ -5483,6 +7999,419 @@ mod tests {
         assert_eq!(bad_moray_client_count, error_count);
     }
 
+    // Generate objects for the given assignment, along with the tasks that
+    // would be sent to the agent for them.
+    fn generate_assignment_objects(
+        g: &mut StdThreadGen,
+        assignment: &mut Assignment,
+        count: usize,
+        status: EvacuateObjectStatus,
+    ) -> Vec<EvacuateObject> {
+        let mut eobjs = vec![];
+
+        for _ in 0..count {
+            let mut eobj = EvacuateObject::arbitrary(g);
+            eobj.assignment_id = assignment.id.clone();
+            eobj.status = status;
+            eobj.skipped_reason = None;
+            eobj.error = None;
+
+            let mut task = Task::arbitrary(g);
+            task.object_id = eobj.id.clone();
+            assignment.tasks.insert(task.object_id.clone(), task);
+
+            eobjs.push(eobj);
+        }
+
+        eobjs
+    }
+
+    #[test]
+    fn resume_reconcile_test() {
+        use super::assignments::dsl::{assignments as assignments_table, id};
+        use super::evacuateobjects::dsl::{assignment_id, evacuateobjects};
+
+        unit_test_init();
+
+        let mut g = StdThreadGen::new(10);
+        let shark = generate_storage_node(true);
+        let mut job_action = create_test_evacuate_job(100);
+        let sizing = AssignmentSizing::ByteBudget {
+            max_bytes: 1024 * 1024,
+            max_tasks: 20,
+        };
+        job_action.set_assignment_sizing(sizing);
+        let large_objects = LargeObjectPolicy::SlowQueue {
+            max_bytes: 1024 * 1024 * 1024,
+            concurrency: 1,
+        };
+        job_action.set_large_objects(large_objects);
+        let filter = ObjectFilter {
+            min_size: Some(1024),
+            ..Default::default()
+        };
+        job_action.set_object_filter(filter.clone());
+        job_action.update_evacuate_config().expect("update config");
+
+        // Posted to the agent before the job was interrupted.
+        let mut posted = Assignment::new(shark.clone());
+        let posted_objs = generate_assignment_objects(
+            &mut g,
+            &mut posted,
+            5,
+            EvacuateObjectStatus::Assigned,
+        );
+        job_action
+            .insert_assignment_into_db(&mut posted, &posted_objs)
+            .expect("insert posted assignment");
+        job_action.post(posted.clone()).expect("post assignment");
+
+        // Created, but interrupted before it was posted.
+        let mut unposted = Assignment::new(shark.clone());
+        let unposted_objs = generate_assignment_objects(
+            &mut g,
+            &mut unposted,
+            5,
+            EvacuateObjectStatus::Assigned,
+        );
+        job_action
+            .insert_assignment_into_db(&mut unposted, &unposted_objs)
+            .expect("insert unposted assignment");
+
+        // Completed by the agent, but its metadata was not updated.
+        let mut agent_complete = Assignment::new(shark);
+        let agent_complete_objs = generate_assignment_objects(
+            &mut g,
+            &mut agent_complete,
+            5,
+            EvacuateObjectStatus::PostProcessing,
+        );
+        job_action
+            .insert_assignment_into_db(
+                &mut agent_complete,
+                &agent_complete_objs,
+            )
+            .expect("insert agent complete assignment");
+        job_action.persist_assignment_state(
+            &agent_complete.id,
+            AssignmentState::AgentComplete,
+        );
+
+        // Assigned to an assignment that was never recorded.
+        let mut orphan = EvacuateObject::arbitrary(&mut g);
+        orphan.status = EvacuateObjectStatus::Assigned;
+        orphan.skipped_reason = None;
+        orphan.error = None;
+        job_action.insert_into_db(&orphan);
+
+        // Already rebalanced.
+        let mut complete = EvacuateObject::arbitrary(&mut g);
+        complete.status = EvacuateObjectStatus::Complete;
+        complete.skipped_reason = None;
+        complete.error = None;
+        job_action.insert_into_db(&complete);
+
+        let mut resumed =
+            EvacuateJob::resume(&job_action.config, &job_action.db_name, None)
+                .expect("resume job");
+        let completed = resumed.reconcile().expect("reconcile");
+
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].id, agent_complete.id);
+
+        {
+            let cache = resumed.assignments.read().expect("assignments");
+            assert_eq!(cache.len(), 2);
+            assert_eq!(
+                cache.get(&posted.id).map(|a| a.state.clone()),
+                Some(AssignmentState::Assigned)
+            );
+            assert!(cache.contains_key(&agent_complete.id));
+        }
+
+        let dest_sharks = resumed.dest_shark_hash.read().expect("dest sharks");
+        assert_eq!(
+            dest_sharks.get("localhost").map(|s| s.status.clone()),
+            Some(DestSharkStatus::Assigned)
+        );
+        drop(dest_sharks);
+
+        // The unposted assignment and the orphaned object are forgotten so
+        // that their objects are found and assigned again.
+        let locked_conn = resumed.conn.lock().expect("DB conn");
+        let unposted_count = evacuateobjects
+            .filter(assignment_id.eq(&unposted.id))
+            .count()
+            .get_result::<i64>(&*locked_conn)
+            .expect("unposted objects");
+        assert_eq!(unposted_count, 0);
+
+        let unposted_entries = assignments_table
+            .filter(id.eq(&unposted.id))
+            .count()
+            .get_result::<i64>(&*locked_conn)
+            .expect("unposted assignment");
+        assert_eq!(unposted_entries, 0);
+        drop(locked_conn);
+
+        for eobj in unposted_objs.iter().chain(std::iter::once(&orphan)) {
+            assert!(!resumed.already_processed(eobj));
+        }
+        for eobj in posted_objs
+            .iter()
+            .chain(agent_complete_objs.iter())
+            .chain(std::iter::once(&complete))
+        {
+            assert!(resumed.already_processed(eobj));
+        }
+
+        assert_eq!(resumed.max_objects, Some(100 - 11));
+        assert_eq!(resumed.assignment_sizing(), sizing);
+        assert_eq!(resumed.large_objects(), large_objects);
+        assert_eq!(resumed.object_filter(), &filter);
+    }
+
+    #[test]
+    fn requeue_stalled_assignment_test() {
+        use super::evacuateobjects::dsl::{
+            assignment_id, evacuateobjects, id, status,
+        };
+
+        unit_test_init();
+
+        let mut g = StdThreadGen::new(10);
+        let shark = generate_storage_node(true);
+        let job_action = create_test_evacuate_job(100);
+
+        let mut stalled = Assignment::new(shark.clone());
+        let stalled_objs = generate_assignment_objects(
+            &mut g,
+            &mut stalled,
+            5,
+            EvacuateObjectStatus::Assigned,
+        );
+        job_action
+            .insert_assignment_into_db(&mut stalled, &stalled_objs)
+            .expect("insert stalled assignment");
+        assignment_post_success(&job_action, stalled.clone());
+
+        assert_eq!(job_action.requeue_stalled_assignment(&stalled.id), 5);
+        assert_eq!(job_action.requeue_stalled_assignment(&stalled.id), 0);
+        assert!(job_action
+            .assignments
+            .read()
+            .expect("assignments")
+            .is_empty());
+        assert!(job_action.agent_stalled(&shark.manta_storage_id));
+
+        // The objects are handed to the assignment manager, and will be
+        // recorded again under their new assignment.
+        let requeued: Vec<EvacuateObject> =
+            job_action.requeue_rx.try_iter().collect();
+        assert_eq!(requeued.len(), 5);
+        for eobj in requeued.iter() {
+            assert_eq!(eobj.status, EvacuateObjectStatus::Unprocessed);
+            assert_eq!(eobj.dest_shark, shark.manta_storage_id);
+        }
+
+        let locked_conn = job_action.conn.lock().expect("DB conn");
+        let stalled_count = evacuateobjects
+            .filter(assignment_id.eq(&stalled.id))
+            .count()
+            .get_result::<i64>(&*locked_conn)
+            .expect("stalled objects");
+        assert_eq!(stalled_count, 0);
+
+        let agent: AgentLiveness = super::agents::table
+            .first(&*locked_conn)
+            .expect("agent liveness");
+        assert_eq!(agent.state, AgentState::Stalled);
+        assert_eq!(agent.stalled_assignments, 1);
+        drop(locked_conn);
+
+        // Once the assignment manager has stopped, the objects of stalled
+        // assignments are skipped instead.
+        job_action.close_requeue();
+
+        let mut late = Assignment::new(shark);
+        let late_objs = generate_assignment_objects(
+            &mut g,
+            &mut late,
+            5,
+            EvacuateObjectStatus::Assigned,
+        );
+        job_action
+            .insert_assignment_into_db(&mut late, &late_objs)
+            .expect("insert late assignment");
+        assignment_post_success(&job_action, late.clone());
+
+        assert_eq!(job_action.requeue_stalled_assignment(&late.id), 0);
+
+        let late_ids: Vec<String> =
+            late_objs.iter().map(|o| o.id.clone()).collect();
+        let locked_conn = job_action.conn.lock().expect("DB conn");
+        let skipped_count = evacuateobjects
+            .filter(id.eq_any(late_ids))
+            .filter(status.eq(EvacuateObjectStatus::Skipped))
+            .count()
+            .get_result::<i64>(&*locked_conn)
+            .expect("skipped objects");
+        assert_eq!(skipped_count, 5);
+    }
+
+    fn assignment_skipped_reasons(
+        job_action: &EvacuateJob,
+        assignment: &Assignment,
+    ) -> Vec<Option<ObjectSkippedReason>> {
+        use super::evacuateobjects::dsl::{assignment_id, evacuateobjects};
+
+        let locked_conn = job_action.conn.lock().expect("DB conn");
+        evacuateobjects
+            .filter(assignment_id.eq(&assignment.id))
+            .load::<EvacuateObject>(&*locked_conn)
+            .expect("assignment objects")
+            .into_iter()
+            .map(|o| o.skipped_reason)
+            .collect()
+    }
+
+    #[test]
+    fn fake_agent_post_test() {
+        unit_test_init();
+
+        let mut g = StdThreadGen::new(10);
+        let agents = Arc::new(
+            FakeAgents::new()
+                .with_agent("1.stor", "dc1", 1000, AgentBehavior::Flaky(2))
+                .with_agent(
+                    "2.stor",
+                    "dc1",
+                    1000,
+                    AgentBehavior::Failing(AgentErrorCode::InsufficientSpace),
+                )
+                .with_agent("3.stor", "dc1", 1000, AgentBehavior::Unreachable),
+        );
+        let mut job_action = create_test_evacuate_job(100);
+        job_action.agent_api = agents.clone();
+
+        let mut assignments = vec![];
+        for storage_id in &["1.stor", "2.stor", "3.stor"] {
+            let mut assignment = Assignment::new(
+                agents.storage_node(storage_id).expect("shark"),
+            );
+            let objs = generate_assignment_objects(
+                &mut g,
+                &mut assignment,
+                3,
+                EvacuateObjectStatus::Assigned,
+            );
+            job_action
+                .insert_assignment_into_db(&mut assignment, &objs)
+                .expect("insert assignment");
+            assignments.push(assignment);
+        }
+
+        // A post that does not reach the agent is retried.
+        job_action.post(assignments[0].clone()).expect("flaky post");
+        assert_eq!(agents.posts("1.stor"), 2);
+        assert_eq!(
+            agents.assignments("1.stor"),
+            vec![assignments[0].id.clone()]
+        );
+        assert!(job_action
+            .assignments
+            .read()
+            .expect("assignments")
+            .contains_key(&assignments[0].id));
+
+        // One the agent rejects is not, and its objects are skipped for the
+        // reason the agent gave.
+        assert!(job_action.post(assignments[1].clone()).is_err());
+        assert_eq!(agents.posts("2.stor"), 1);
+        assert_eq!(
+            assignment_skipped_reasons(&job_action, &assignments[1]),
+            vec![Some(ObjectSkippedReason::DestinationInsufficientSpace); 3]
+        );
+
+        // An agent that stays unreachable is given up on after the retries.
+        assert!(job_action.post(assignments[2].clone()).is_err());
+        assert_eq!(agents.posts("3.stor"), 1 + ASSIGNMENT_POST_RETRIES);
+        assert_eq!(
+            assignment_skipped_reasons(&job_action, &assignments[2]),
+            vec![Some(ObjectSkippedReason::DestinationUnreachable); 3]
+        );
+
+        let assignments = job_action.assignments.read().expect("assignments");
+        assert_eq!(assignments.len(), 1);
+    }
+
+    #[test]
+    fn fake_agent_checker_test() {
+        unit_test_init();
+
+        let mut g = StdThreadGen::new(10);
+        let agents = Arc::new(
+            FakeAgents::new()
+                .with_agent("1.stor", "dc1", 1000, AgentBehavior::Slow)
+                .with_agent("2.stor", "dc1", 1000, AgentBehavior::Stalled),
+        );
+        let mut job_action = create_test_evacuate_job(100);
+        job_action.agent_api = agents.clone();
+        job_action.config.options.assignment_stall_timeout = 1;
+
+        let mut assignments = vec![];
+        for storage_id in &["1.stor", "2.stor"] {
+            let mut assignment = Assignment::new(
+                agents.storage_node(storage_id).expect("shark"),
+            );
+            let objs = generate_assignment_objects(
+                &mut g,
+                &mut assignment,
+                4,
+                EvacuateObjectStatus::Assigned,
+            );
+            job_action
+                .insert_assignment_into_db(&mut assignment, &objs)
+                .expect("insert assignment");
+            job_action
+                .post(assignment.clone())
+                .expect("post assignment");
+            assignments.push(assignment);
+        }
+        let job_action = Arc::new(job_action);
+
+        let (checker_fini_tx, checker_fini_rx) = crossbeam::bounded(1);
+        let (_completed_tx, completed_rx) = crossbeam::unbounded();
+        let (md_update_tx, md_update_rx) = crossbeam::unbounded();
+
+        // The checker runs until neither assignment is outstanding.
+        checker_fini_tx.send(FiniMsg).expect("fini msg");
+        start_assignment_checker(
+            Arc::clone(&job_action),
+            checker_fini_rx,
+            completed_rx,
+            md_update_tx,
+        )
+        .expect("start checker")
+        .join()
+        .expect("checker thread")
+        .expect("checker result");
+
+        // The slow agent kept making progress until it finished, so its
+        // assignment went on to the metadata update.
+        let updated: Vec<AssignmentId> =
+            md_update_rx.try_iter().map(|ace| ace.id).collect();
+        assert_eq!(updated, vec![assignments[0].id.clone()]);
+        assert!(!job_action.agent_stalled("1.stor"));
+
+        // The stalled one was handed back to be sent elsewhere.
+        let requeued: Vec<EvacuateObject> =
+            job_action.requeue_rx.try_iter().collect();
+        assert_eq!(requeued.len(), 4);
+        assert!(requeued.iter().all(|o| o.dest_shark == "2.stor"));
+        assert!(job_action.agent_stalled("2.stor"));
+    }
+
     fn skip_all(
         job_action: Arc<EvacuateJob>,
         md_update_rx: crossbeam::Receiver<AssignmentCacheEntry>,
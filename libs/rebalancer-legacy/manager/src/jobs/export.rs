@@ -0,0 +1,586 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// Job result exports.
+//
+// `GET /jobs/<uuid>/export?format=csv|ndjson` streams one record for every
+// object in a job's `evacuateobjects` table, so that operators do not have to
+// copy results out of the job database by hand.  `status=<status>` limits the
+// report to the objects in that status, e.g. only those that were skipped.  The `export` section of the
+// manager config can also have the same report written out when a job
+// finishes:
+//
+// ```json
+// "export": {
+//     "format": "csv",
+//     "directory": "/var/tmp/rebalancer/exports",
+//     "manta": {
+//         "url": "https://manta.example.com/poseidon/stor/rebalancer",
+//         "headers": { "Authorization": "Bearer abc" },
+//         "timeout": 60
+//     }
+// }
+// ```
+//
+// The report is named `<job uuid>.<format>`.  It is written to `directory`
+// (when set) and `PUT` beneath the Manta `url` (when set).  Like
+// notifications, a failed export is logged and never changes the outcome of
+// the job.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::jobs::evacuate::{EvacuateObject, EvacuateObjectStatus};
+use crate::pg_db;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use rebalancer::error::{Error, InternalError, InternalErrorCode};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+// Number of objects read from the job database at a time.
+static EXPORT_PAGE_SIZE: i64 = 1000;
+
+// How long a Manta upload has to complete before it is abandoned.
+static DEFAULT_UPLOAD_TIMEOUT: u64 = 60;
+
+static CSV_COLUMNS: &[&str] = &[
+    "id",
+    "key",
+    "owner",
+    "content_length",
+    "shard",
+    "assignment_id",
+    "dest_shark",
+    "status",
+    "skipped_reason",
+    "error",
+];
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+}
+
+impl Default for ExportFormat {
+    fn default() -> Self {
+        ExportFormat::Ndjson
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "ndjson" => Ok(ExportFormat::Ndjson),
+            _ => Err(format!("Invalid export format: {}", s)),
+        }
+    }
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    // The line that starts a report, if the format has one.
+    pub fn header(self) -> Option<String> {
+        match self {
+            ExportFormat::Csv => Some(format!("{}\n", CSV_COLUMNS.join(","))),
+            ExportFormat::Ndjson => None,
+        }
+    }
+
+    // A single record as a newline terminated line.
+    pub fn line(self, record: &ExportRecord) -> Result<String, Error> {
+        match self {
+            ExportFormat::Csv => Ok(record.csv_line()),
+            ExportFormat::Ndjson => {
+                Ok(format!("{}\n", serde_json::to_string(record)?))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ExportConfig {
+    pub format: ExportFormat,
+
+    // Local directory to write finished job reports to.
+    pub directory: Option<String>,
+
+    pub manta: Option<MantaExportConfig>,
+}
+
+impl ExportConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.directory.is_some() || self.manta.is_some()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MantaExportConfig {
+    // Manta directory URL that reports are uploaded beneath.
+    pub url: String,
+
+    // Additional request headers, e.g. for an authorization token.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    // Upload timeout in seconds.
+    #[serde(default = "MantaExportConfig::default_timeout")]
+    pub timeout: u64,
+}
+
+impl MantaExportConfig {
+    fn default_timeout() -> u64 {
+        DEFAULT_UPLOAD_TIMEOUT
+    }
+}
+
+/// The exported result for a single object.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExportRecord {
+    pub id: String,
+    pub key: String,
+    pub owner: String,
+    pub content_length: u64,
+    pub shard: i32,
+    pub assignment_id: String,
+    pub dest_shark: String,
+    pub status: String,
+    pub skipped_reason: Option<String>,
+    pub error: Option<String>,
+}
+
+impl From<&EvacuateObject> for ExportRecord {
+    fn from(obj: &EvacuateObject) -> Self {
+        // The stored object may be from an older version of the manager, so
+        // any field that is missing is simply left empty.
+        let field = |names: &[&str]| {
+            names.iter().find_map(|name| obj.object.get(*name))
+        };
+        let string_field = |names: &[&str]| {
+            field(names)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        ExportRecord {
+            id: obj.id.clone(),
+            key: string_field(&["key"]),
+            owner: string_field(&["owner"]),
+            content_length: field(&["contentLength", "content_length"])
+                .and_then(Value::as_u64)
+                .unwrap_or(0),
+            shard: obj.shard,
+            assignment_id: obj.assignment_id.clone(),
+            dest_shark: obj.dest_shark.clone(),
+            status: obj.status.to_string(),
+            skipped_reason: obj.skipped_reason.map(|r| r.to_string()),
+            error: obj.error.map(|e| e.to_string()),
+        }
+    }
+}
+
+// Quote a CSV field if it contains a delimiter, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl ExportRecord {
+    fn csv_line(&self) -> String {
+        let fields = [
+            csv_field(&self.id),
+            csv_field(&self.key),
+            csv_field(&self.owner),
+            self.content_length.to_string(),
+            self.shard.to_string(),
+            csv_field(&self.assignment_id),
+            csv_field(&self.dest_shark),
+            csv_field(&self.status),
+            csv_field(self.skipped_reason.as_deref().unwrap_or("")),
+            csv_field(self.error.as_deref().unwrap_or("")),
+        ];
+        format!("{}\n", fields.join(","))
+    }
+}
+
+/// Reads a job's objects from its database a page at a time, in id order, so
+/// that exporting a large job does not hold every object in memory.
+pub struct ExportPages {
+    conn: PgConnection,
+    status: Option<EvacuateObjectStatus>,
+    last_id: Option<String>,
+    done: bool,
+}
+
+impl ExportPages {
+    pub fn new(job_id: &Uuid) -> Result<Self, Error> {
+        let conn = pg_db::connect_db(&job_id.to_string())?;
+
+        Ok(ExportPages {
+            conn,
+            status: None,
+            last_id: None,
+            done: false,
+        })
+    }
+
+    /// Only read the objects in the given status.
+    pub fn with_status(mut self, status: Option<EvacuateObjectStatus>) -> Self {
+        self.status = status;
+        self
+    }
+
+    fn next_page(&mut self) -> Result<Vec<ExportRecord>, Error> {
+        use crate::jobs::evacuate::evacuateobjects::dsl::{
+            evacuateobjects, id, status,
+        };
+
+        let mut query = evacuateobjects
+            .order(id.asc())
+            .limit(EXPORT_PAGE_SIZE)
+            .into_boxed();
+
+        if let Some(last_id) = self.last_id.take() {
+            query = query.filter(id.gt(last_id));
+        }
+
+        if let Some(object_status) = self.status {
+            query = query.filter(status.eq(object_status));
+        }
+
+        let objects: Vec<EvacuateObject> = query.load(&self.conn)?;
+
+        if (objects.len() as i64) < EXPORT_PAGE_SIZE {
+            self.done = true;
+        }
+        self.last_id = objects.last().map(|obj| obj.id.clone());
+
+        Ok(objects.iter().map(ExportRecord::from).collect())
+    }
+}
+
+impl Iterator for ExportPages {
+    type Item = Result<Vec<ExportRecord>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_page() {
+            Ok(page) if page.is_empty() => None,
+            Ok(page) => Some(Ok(page)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+// Render a page of records in the given format.
+pub fn render_page(
+    format: ExportFormat,
+    records: &[ExportRecord],
+) -> Result<String, Error> {
+    let mut out = String::new();
+    for record in records.iter() {
+        out.push_str(&format.line(record)?);
+    }
+    Ok(out)
+}
+
+/// Write the full report for a job, returning the number of records written.
+pub fn write_export<W: Write>(
+    job_id: &Uuid,
+    format: ExportFormat,
+    writer: &mut W,
+) -> Result<u64, Error> {
+    let mut count = 0;
+
+    if let Some(header) = format.header() {
+        writer.write_all(header.as_bytes())?;
+    }
+
+    for page in ExportPages::new(job_id)? {
+        let page = page?;
+        writer.write_all(render_page(format, &page)?.as_bytes())?;
+        count += page.len() as u64;
+    }
+
+    writer.flush()?;
+    Ok(count)
+}
+
+fn export_error<S: Into<String>>(msg: S) -> Error {
+    InternalError::new(Some(InternalErrorCode::ExportError), msg).into()
+}
+
+fn upload_to_manta(
+    config: &MantaExportConfig,
+    path: &Path,
+    file_name: &str,
+    format: ExportFormat,
+) -> Result<(), Error> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in config.headers.iter() {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| export_error(format!("bad header {}: {}", name, e)))?;
+        let value = HeaderValue::from_str(value).map_err(|e| {
+            export_error(format!("bad value for {}: {}", name, e))
+        })?;
+        headers.insert(name, value);
+    }
+
+    let client = reqwest::Client::builder()
+        .default_headers(headers)
+        .timeout(Duration::from_secs(config.timeout))
+        .build()?;
+
+    let url = format!("{}/{}", config.url.trim_end_matches('/'), file_name);
+    let response = client
+        .put(&url)
+        .header(CONTENT_TYPE, format.content_type())
+        .body(File::open(path)?)
+        .send()?;
+
+    if !response.status().is_success() {
+        return Err(export_error(format!(
+            "upload to {} responded with {}",
+            url,
+            response.status()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Write out and/or upload the report for a finished job, as configured.
+pub fn export_finished_job(
+    config: &ExportConfig,
+    job_id: &Uuid,
+) -> Result<(), Error> {
+    if !config.is_enabled() {
+        return Ok(());
+    }
+
+    let file_name = format!("{}.{}", job_id, config.format.extension());
+
+    // Without a local directory the report only needs to exist long enough
+    // to be uploaded.
+    let (path, temporary): (PathBuf, bool) = match &config.directory {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            (Path::new(dir).join(&file_name), false)
+        }
+        None => (std::env::temp_dir().join(&file_name), true),
+    };
+
+    let written = File::create(&path).map_err(Error::from).and_then(|f| {
+        write_export(job_id, config.format, &mut BufWriter::new(f))
+    });
+
+    let result = written.and_then(|count| {
+        info!("Exported {} objects for job {}", count, job_id);
+        match &config.manta {
+            Some(manta) => {
+                upload_to_manta(manta, &path, &file_name, config.format)
+            }
+            None => Ok(()),
+        }
+    });
+
+    if temporary {
+        if let Err(e) = fs::remove_file(&path) {
+            warn!("Could not remove {}: {}", path.display(), e);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::jobs::evacuate::EvacuateObjectStatus;
+    use crate::jobs::JobBuilder;
+    use quickcheck::{Arbitrary, StdThreadGen};
+    use rebalancer::common::ObjectSkippedReason;
+    use rebalancer::util;
+
+    fn record() -> ExportRecord {
+        ExportRecord {
+            id: String::from("0c5b5b4c-9f2c-4c1a-8d1e-3a2b1c0d9e8f"),
+            key: String::from("/poseidon/stor/a,b \"c\".txt"),
+            owner: String::from("6a1b9c3e-7d2f-4e8a-9b0c-1d2e3f4a5b6c"),
+            content_length: 1024,
+            shard: 2,
+            assignment_id: String::from("5f0e1d2c-3b4a-4958-8776-655443322110"),
+            dest_shark: String::from("3.stor.fake.joyent.us"),
+            status: String::from("skipped"),
+            skipped_reason: Some(String::from("destination_unreachable")),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn parse_export_format() {
+        assert_eq!(ExportFormat::from_str("csv"), Ok(ExportFormat::Csv));
+        assert_eq!(ExportFormat::from_str("NDJSON"), Ok(ExportFormat::Ndjson));
+        assert!(ExportFormat::from_str("xml").is_err());
+        assert_eq!(ExportFormat::default(), ExportFormat::Ndjson);
+    }
+
+    #[test]
+    fn csv_record() {
+        let header = ExportFormat::Csv.header().expect("csv header");
+        assert_eq!(
+            header,
+            "id,key,owner,content_length,shard,assignment_id,dest_shark,\
+             status,skipped_reason,error\n"
+        );
+
+        let line = ExportFormat::Csv.line(&record()).expect("csv line");
+        assert_eq!(
+            line,
+            "0c5b5b4c-9f2c-4c1a-8d1e-3a2b1c0d9e8f,\
+             \"/poseidon/stor/a,b \"\"c\"\".txt\",\
+             6a1b9c3e-7d2f-4e8a-9b0c-1d2e3f4a5b6c,1024,2,\
+             5f0e1d2c-3b4a-4958-8776-655443322110,3.stor.fake.joyent.us,\
+             skipped,destination_unreachable,\n"
+        );
+    }
+
+    #[test]
+    fn ndjson_record() {
+        assert!(ExportFormat::Ndjson.header().is_none());
+
+        let line = ExportFormat::Ndjson.line(&record()).expect("ndjson line");
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.lines().count(), 1);
+
+        let parsed: ExportRecord =
+            serde_json::from_str(line.trim_end()).expect("parse line");
+        assert_eq!(parsed, record());
+    }
+
+    #[test]
+    fn record_from_object() {
+        let mut g = StdThreadGen::new(10);
+        let mut obj = EvacuateObject::arbitrary(&mut g);
+        obj.status = EvacuateObjectStatus::Skipped;
+        obj.skipped_reason = Some(ObjectSkippedReason::SourceOtherError);
+        obj.error = None;
+
+        let rec = ExportRecord::from(&obj);
+        assert_eq!(rec.id, obj.id);
+        assert_eq!(rec.key, obj.object["key"].as_str().expect("key"));
+        assert_eq!(
+            rec.content_length,
+            obj.object["contentLength"].as_u64().expect("contentLength")
+        );
+        assert_eq!(rec.status, "skipped");
+        assert_eq!(rec.skipped_reason.as_deref(), Some("source_other_error"));
+        assert!(rec.error.is_none());
+
+        // Objects missing metadata are still exported.
+        obj.object = serde_json::json!({});
+        let rec = ExportRecord::from(&obj);
+        assert_eq!(rec.key, "");
+        assert_eq!(rec.content_length, 0);
+    }
+
+    #[test]
+    fn export_job_test() {
+        use crate::jobs::evacuate::evacuateobjects::dsl::evacuateobjects;
+
+        let _guard = util::init_global_logger(None);
+        let mut g = StdThreadGen::new(10);
+        let num_objs = EXPORT_PAGE_SIZE as usize + 10;
+
+        let job = JobBuilder::new(Config::default())
+            .evacuate("fake_shark".to_string(), Some(num_objs as u32))
+            .commit()
+            .expect("job builder");
+        let job_id = job.get_id();
+        let conn = pg_db::connect_db(&job_id.to_string()).expect("db connect");
+
+        let objs: Vec<EvacuateObject> = (0..num_objs)
+            .map(|_| EvacuateObject::arbitrary(&mut g))
+            .collect();
+        diesel::insert_into(evacuateobjects)
+            .values(objs)
+            .execute(&conn)
+            .expect("diesel insert");
+
+        let mut out = vec![];
+        let count = write_export(&job_id, ExportFormat::Csv, &mut out)
+            .expect("csv export");
+        assert_eq!(count, num_objs as u64);
+
+        let mut out = vec![];
+        write_export(&job_id, ExportFormat::Ndjson, &mut out)
+            .expect("ndjson export");
+        let out = String::from_utf8(out).expect("utf8");
+        let ids: Vec<String> = out
+            .lines()
+            .map(|l| {
+                serde_json::from_str::<ExportRecord>(l).expect("record").id
+            })
+            .collect();
+        assert_eq!(ids.len(), num_objs);
+
+        // Pages are read in id order, so no object is exported twice.
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        let dir = std::env::temp_dir().join(job_id.to_string());
+        let config = ExportConfig {
+            format: ExportFormat::Ndjson,
+            directory: Some(dir.to_string_lossy().into_owned()),
+            manta: None,
+        };
+        export_finished_job(&config, &job_id).expect("export finished job");
+        let written =
+            fs::read_to_string(dir.join(format!("{}.ndjson", job_id)))
+                .expect("read export");
+        assert_eq!(written, out);
+        fs::remove_dir_all(&dir).expect("remove export dir");
+    }
+}
@@ -8,13 +8,22 @@
  * Copyright 2020 Joyent, Inc.
  */
 
+pub mod agent_api;
 pub mod evacuate;
+pub mod export;
 pub mod status;
+pub mod watchdog;
 
-use crate::config::Config;
+use crate::config::{AssignmentSizing, Config};
+use crate::metrics::{
+    metrics_gauge_dec, metrics_gauge_inc, JOBS_RUNNING_GAUGE,
+};
+use crate::notify::{should_notify, JobNotification, Notifier};
 use crate::pg_db::{connect_or_create_db, REBALANCER_DB};
 use crate::storinfo::StorageNode;
-use evacuate::{EvacuateJob, EvacuateJobUpdateMessage};
+use evacuate::{
+    EvacuateJob, EvacuateJobUpdateMessage, LargeObjectPolicy, ObjectFilter,
+};
 use rebalancer::common::{ObjectId, Task};
 use rebalancer::error::{Error, InternalError, InternalErrorCode};
 
@@ -24,6 +33,7 @@ use std::io::Write;
 use std::str::FromStr;
 
 use crate::jobs::status::JobStatusConfig;
+use chrono::{DateTime, Utc};
 use diesel::deserialize::{self, FromSql};
 use diesel::pg::{Pg, PgValue};
 use diesel::prelude::*;
@@ -56,6 +66,15 @@ pub enum JobPayload {
 pub struct EvacuateJobPayload {
     pub from_shark: String,
     pub max_objects: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignment_sizing: Option<AssignmentSizing>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_objects: Option<LargeObjectPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ObjectFilter>,
+    /// Create the job even if another job is already evacuating the shark.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub force: bool,
 }
 
 #[derive(Debug)]
@@ -68,6 +87,7 @@ pub struct Job {
     action: JobAction,
     state: JobState,
     config: Config,
+    created_at: DateTime<Utc>,
     pub update_tx: Option<crossbeam_channel::Sender<JobUpdateMessage>>,
 }
 
@@ -80,6 +100,8 @@ pub struct JobBuilder {
     action: Option<JobAction>,
     state: JobState,
     config: Config,
+    created_at: DateTime<Utc>,
+    resumed: bool,
     update_tx: Option<crossbeam_channel::Sender<JobUpdateMessage>>,
 }
 
@@ -138,6 +160,51 @@ impl JobBuilder {
         self
     }
 
+    // Override the assignment sizing policy from the config file for this
+    // job.  This must be called after the job action has been added (e.g.
+    // with evacuate()), and is a no-op if `sizing` is None.
+    pub fn assignment_sizing(
+        self,
+        sizing: Option<AssignmentSizing>,
+    ) -> JobBuilder {
+        if let (Some(sizing), Some(JobAction::Evacuate(job))) =
+            (sizing, self.action.as_ref())
+        {
+            job.set_assignment_sizing(sizing);
+        }
+
+        self
+    }
+
+    // Set how this job handles objects above a size threshold.  Like
+    // assignment_sizing() this must be called after the job action has been
+    // added, and is a no-op if `policy` is None.
+    pub fn large_objects(
+        self,
+        policy: Option<LargeObjectPolicy>,
+    ) -> JobBuilder {
+        if let (Some(policy), Some(JobAction::Evacuate(job))) =
+            (policy, self.action.as_ref())
+        {
+            job.set_large_objects(policy);
+        }
+
+        self
+    }
+
+    // Restrict this job to the objects matching `filter`.  Like
+    // large_objects() this must be called after the job action has been
+    // added, and is a no-op if `filter` is None.
+    pub fn object_filter(mut self, filter: Option<ObjectFilter>) -> JobBuilder {
+        if let (Some(filter), Some(JobAction::Evacuate(job))) =
+            (filter, self.action.as_mut())
+        {
+            job.set_object_filter(filter);
+        }
+
+        self
+    }
+
     pub fn retry(mut self, retry_uuid_str: &str) -> Result<JobBuilder, Error> {
         let retry_uuid = Uuid::from_str(retry_uuid_str).map_err(Error::from)?;
         let (tx, rx) = if self.config.options.use_static_md_update_threads {
@@ -185,9 +252,63 @@ impl JobBuilder {
         Ok(self)
     }
 
+    // Resume a job that was interrupted by a restart of the manager.  The
+    // job keeps its UUID, and with it its local database, so that it can
+    // pick up where it left off.  See EvacuateJob::resume().
+    pub fn resume(mut self, job_uuid_str: &str) -> Result<JobBuilder, Error> {
+        let job_uuid = Uuid::from_str(job_uuid_str).map_err(Error::from)?;
+        let (tx, rx) = if self.config.options.use_static_md_update_threads {
+            (None, None)
+        } else {
+            let (tx, rx) = crossbeam_channel::unbounded();
+            (Some(tx), Some(rx))
+        };
+
+        let job_status = status::get_job(job_uuid).map_err(|e| {
+            Error::from(InternalError::new(
+                Some(InternalErrorCode::DbQuery),
+                format!(
+                    "Could not find job with UUID {}: {:#?}",
+                    job_uuid_str, e
+                ),
+            ))
+        })?;
+
+        self.id = job_uuid;
+        self.resumed = true;
+        if let Some(created_at) = job_status.created_at {
+            self.created_at = created_at;
+        }
+
+        match job_status.config {
+            JobStatusConfig::Evacuate(_) => {
+                match EvacuateJob::resume(&self.config, job_uuid_str, rx) {
+                    Ok(j) => {
+                        let action = JobAction::Evacuate(Box::new(j));
+                        self.update_tx = tx;
+                        self.action = Some(action);
+                    }
+                    Err(e) => {
+                        error!("Failed to resume evacuate job: {}", e);
+                        self.state = JobState::Failed;
+
+                        // Don't try to resume this job again.
+                        update_job_db_state(
+                            job_uuid_str.to_string(),
+                            &self.state,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
     // * commit the configuration
     // * set the job state to JobSate::Init
-    // * insert the job into "rebalancer" database in the "jobs" table
+    // * insert the job into "rebalancer" database in the "jobs" table, unless
+    //   it is being resumed and is already there
     pub fn commit(self) -> Result<Job, Error> {
         if self.state != JobState::Init {
             let msg = format!(
@@ -218,10 +339,15 @@ impl JobBuilder {
             action,
             state: JobState::Setup,
             config: self.config,
+            created_at: self.created_at,
             update_tx: self.update_tx,
         };
 
-        job.insert_into_db()?;
+        if self.resumed {
+            job.record_from_shark()?;
+        } else {
+            job.insert_into_db()?;
+        }
 
         Ok(job)
     }
@@ -237,6 +363,11 @@ impl JobBuilder {
 // This approach does not seem ideal, but it is along the lines of what the
 // crate developers recommend.
 // https://github.com/diesel-rs/diesel/issues/860
+//
+// The timestamps are serialized in RFC3339 format.  They are optional
+// because jobs created before these columns were added do not have them.
+// `started_at` is set when the job enters the Running state and
+// `finished_at` when it is Complete or Failed.
 #[derive(
     AsChangeset,
     AsExpression,
@@ -253,14 +384,22 @@ pub struct JobDbEntry {
     pub id: String,
     pub action: JobActionDbEntry,
     pub state: JobState,
+    pub created_at: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub from_shark: Option<String>,
 }
 
 table! {
-    use diesel::sql_types::Text;
+    use diesel::sql_types::{Nullable, Text, Timestamptz};
     jobs (id) {
         id -> Text,
         action -> Text,
         state -> Text,
+        created_at -> Nullable<Timestamptz>,
+        started_at -> Nullable<Timestamptz>,
+        finished_at -> Nullable<Timestamptz>,
+        from_shark -> Nullable<Text>,
     }
 }
 
@@ -315,6 +454,16 @@ impl JobAction {
             _ => JobActionDbEntry::None,
         }
     }
+
+    // The storage id of the shark the action moves objects off of.
+    fn from_shark(&self) -> Option<String> {
+        match self {
+            JobAction::Evacuate(j) => {
+                Some(j.from_shark.manta_storage_id.clone())
+            }
+            _ => None,
+        }
+    }
 }
 
 #[sql_type = "sql_types::Text"]
@@ -370,13 +519,29 @@ impl fmt::Debug for Job {
 
         write!(
             f,
-            "Job {{ id: {}, action: {}, state: {:#?}, config: {:#?} }}",
-            self.id, action_str, self.state, self.config
+            "Job {{ id: {}, action: {}, state: {:#?}, created_at: {}, \
+             config: {:#?} }}",
+            self.id, action_str, self.state, self.created_at, self.config
         )
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+// Assignment states are persisted in the job's local database (see
+// evacuate::assignments) so that an interrupted job can be resumed.
+#[sql_type = "sql_types::Text"]
+#[derive(
+    AsExpression,
+    Clone,
+    Debug,
+    Deserialize,
+    Display,
+    EnumString,
+    EnumVariantNames,
+    FromSqlRow,
+    PartialEq,
+    Serialize,
+)]
+#[strum(serialize_all = "snake_case")]
 enum AssignmentState {
     Init,             // Assignment is in the process of being created.
     Assigned,         // Assignment has been submitted to the Agent.
@@ -387,6 +552,22 @@ enum AssignmentState {
     PostProcessed, // The Assignment has completed all necessary work.
 }
 
+impl ToSql<sql_types::Text, Pg> for AssignmentState {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let state = self.to_string();
+        out.write_all(state.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<sql_types::Text, Pg> for AssignmentState {
+    fn from_sql(bytes: Option<PgValue<'_>>) -> deserialize::Result<Self> {
+        let t: PgValue = not_none!(bytes);
+        let t_str = String::from_utf8_lossy(t.as_bytes());
+        Self::from_str(&t_str).map_err(std::convert::Into::into)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Assignment {
     id: AssignmentId,
@@ -394,7 +575,12 @@ pub struct Assignment {
     tasks: HashMap<ObjectId, Task>,
     max_size: u64,
     total_size: u64,
+    total_bytes: u64,
     state: AssignmentState,
+    // Holds a single large object, to be posted through the slow queue.
+    // See evacuate::LargeObjectPolicy.
+    #[serde(default)]
+    large: bool,
 }
 
 impl Assignment {
@@ -404,8 +590,10 @@ impl Assignment {
             dest_shark,
             max_size: 0,
             total_size: 0,
+            total_bytes: 0,
             tasks: HashMap::new(),
             state: AssignmentState::Init,
+            large: false,
         }
     }
 }
@@ -448,11 +636,14 @@ impl Job {
 
     pub fn run(mut self) -> Result<(), Error> {
         let job_id = self.id.to_string();
+        let action_entry = self.action.to_db_entry();
+        let action = action_entry.to_string();
 
         self.update_state(JobState::Running)?;
         debug!("Starting job {:#?}", &self);
         info!("Starting Job: {}", &job_id);
         let now = std::time::Instant::now();
+        metrics_gauge_inc(JOBS_RUNNING_GAUGE);
 
         let result = match self.action {
             JobAction::Evacuate(job_action) => {
@@ -503,6 +694,9 @@ impl Job {
             _ => Ok(()),
         };
 
+        metrics_gauge_dec(JOBS_RUNNING_GAUGE);
+
+        let error = result.as_ref().err().map(|e| e.to_string());
         let ret = match result {
             Ok(()) => {
                 self.state = JobState::Complete;
@@ -514,7 +708,38 @@ impl Job {
             }
         };
 
-        update_job_db_state(job_id, &self.state)?;
+        let db_result = update_job_db_state(job_id.clone(), &self.state);
+
+        // Let operators know the job has finished, even if its final state
+        // could not be recorded.
+        if should_notify(&JobState::Running, &self.state) {
+            let notifier = Notifier::from_config(&self.config.notifications);
+            if !notifier.is_empty() {
+                notifier.notify(&JobNotification {
+                    job_id,
+                    action,
+                    domain_name: self.config.domain_name.clone(),
+                    previous_state: JobState::Running.to_string(),
+                    state: self.state.to_string(),
+                    elapsed_secs: now.elapsed().as_secs(),
+                    error,
+                });
+            }
+        }
+
+        // Like notifications, a report that cannot be exported is only
+        // logged.
+        if action_entry == JobActionDbEntry::Evacuate
+            && self.config.export.is_enabled()
+        {
+            if let Err(e) =
+                export::export_finished_job(&self.config.export, &self.id)
+            {
+                error!("Failed to export results for job {}: {}", self.id, e);
+            }
+        }
+
+        db_result?;
         ret
     }
 
@@ -523,6 +748,10 @@ impl Job {
             id: self.id.to_string(),
             action: self.action.to_db_entry(),
             state: self.state.clone(),
+            created_at: Some(self.created_at),
+            started_at: None,
+            finished_at: None,
+            from_shark: self.action.from_shark(),
         }
     }
 
@@ -543,6 +772,22 @@ impl Job {
             .map_err(Error::from)
     }
 
+    // A job started by an older version of the manager has no shark
+    // recorded in the jobs table.  Fill it in when the job is resumed so that
+    // the job is found by active_jobs_for_shark().
+    fn record_from_shark(&self) -> Result<usize, Error> {
+        use self::jobs::dsl::*;
+
+        let conn = connect_or_create_db(REBALANCER_DB)?;
+
+        diesel::update(jobs)
+            .filter(id.eq(self.id.to_string()))
+            .filter(from_shark.is_null())
+            .set(from_shark.eq(self.action.from_shark()))
+            .execute(&conn)
+            .map_err(Error::from)
+    }
+
     fn update_state(&mut self, to_state: JobState) -> Result<usize, Error> {
         let result = update_job_db_state(self.id.to_string(), &to_state);
         self.state = to_state;
@@ -569,11 +814,16 @@ impl Default for JobBuilder {
             action: None,
             state: JobState::default(),
             config: Config::default(),
+            created_at: Utc::now(),
+            resumed: false,
             update_tx: None,
         }
     }
 }
 
+// Update the state of a job in the DB, recording the time at which the job
+// started running or finished.  A resumed job keeps the time at which it
+// first started running.
 fn update_job_db_state(
     job_id: String,
     to_state: &JobState,
@@ -587,10 +837,55 @@ fn update_job_db_state(
         }
     };
 
-    diesel::update(jobs)
-        .filter(id.eq(job_id))
-        .set(state.eq(to_state))
-        .execute(&conn)
+    let now = Utc::now();
+    let target = diesel::update(jobs).filter(id.eq(&job_id));
+
+    match to_state {
+        JobState::Running => diesel::update(jobs)
+            .filter(id.eq(&job_id))
+            .filter(started_at.is_null())
+            .set(started_at.eq(now))
+            .execute(&conn)
+            .and_then(|_| target.set(state.eq(to_state)).execute(&conn)),
+        JobState::Complete | JobState::Failed => target
+            .set((state.eq(to_state), finished_at.eq(now)))
+            .execute(&conn),
+        _ => target.set(state.eq(to_state)).execute(&conn),
+    }
+    .map_err(Error::from)
+}
+
+// The UUIDs of the jobs that were set up or running when the manager last
+// exited, oldest first.  These are resumed when the manager starts.
+pub fn interrupted_jobs() -> Result<Vec<String>, Error> {
+    use self::jobs::dsl::*;
+
+    let conn = connect_or_create_db(REBALANCER_DB)?;
+
+    jobs.select(id)
+        .filter(action.eq(JobActionDbEntry::Evacuate))
+        .filter(state.eq_any(vec![JobState::Setup, JobState::Running]))
+        .order(created_at.asc())
+        .load::<String>(&conn)
+        .map_err(Error::from)
+}
+
+// The UUIDs of the jobs moving objects off of the given shark that have not
+// yet reached a terminal state, oldest first.
+pub fn active_jobs_for_shark(shark: &str) -> Result<Vec<String>, Error> {
+    use self::jobs::dsl::*;
+
+    let conn = connect_or_create_db(REBALANCER_DB)?;
+
+    jobs.select(id)
+        .filter(from_shark.eq(shark))
+        .filter(state.eq_any(vec![
+            JobState::Init,
+            JobState::Setup,
+            JobState::Running,
+        ]))
+        .order(created_at.asc())
+        .load::<String>(&conn)
         .map_err(Error::from)
 }
 
@@ -608,23 +903,41 @@ pub fn create_job_database() -> Result<(), Error> {
             CREATE TABLE IF NOT EXISTS jobs(
                 id TEXT PRIMARY KEY,
                 action TEXT CHECK(action IN ({})) NOT NULL,
-                state TEXT CHECK(state IN ({})) NOT NULL
+                state TEXT CHECK(state IN ({})) NOT NULL,
+                created_at TIMESTAMPTZ,
+                started_at TIMESTAMPTZ,
+                finished_at TIMESTAMPTZ,
+                from_shark TEXT
             );
         ",
         action_check, state_check,
     );
 
-    conn.execute(&create_query).map(|_| {}).map_err(Error::from)
+    conn.execute(&create_query).map_err(Error::from)?;
+
+    // Add the timestamp and shark columns to a jobs table created by an
+    // older version of the manager.
+    let alter_query = "
+            ALTER TABLE jobs
+                ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ,
+                ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ,
+                ADD COLUMN IF NOT EXISTS finished_at TIMESTAMPTZ,
+                ADD COLUMN IF NOT EXISTS from_shark TEXT;
+        ";
+
+    conn.execute(alter_query).map(|_| {}).map_err(Error::from)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::metrics::metrics_init;
     use rebalancer::util;
 
     #[test]
     fn basic() {
         let _guard = util::init_global_logger(None);
+        metrics_init(rebalancer::metrics::ConfigMetrics::default());
         let config = Config::parse_config(&Some("src/config.json".to_string()))
             .expect("parse config");
 
@@ -8,9 +8,9 @@
  * Copyright 2020 Joyent, Inc.
  */
 
-use super::evacuate::EvacuateObjectStatus;
+use super::evacuate::{EvacuateObjectStatus, ObjectFilter};
+use super::watchdog::AgentLiveness;
 
-use crate::jobs::evacuate::EvacuateJobDbConfig;
 use crate::jobs::{JobActionDbEntry, JobDbEntry, JobState, REBALANCER_DB};
 use crate::pg_db;
 use rebalancer::error::Error;
@@ -18,6 +18,7 @@ use rebalancer::error::Error;
 use std::collections::HashMap;
 use std::string::ToString;
 
+use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use diesel::result::ConnectionError;
 use diesel::sql_query;
@@ -25,6 +26,7 @@ use diesel::sql_types::{BigInt, Text};
 use inflector::cases::titlecase::to_title_case;
 use libmanta::moray::MantaObjectShark;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use strum::IntoEnumIterator;
 use uuid::Uuid;
 
@@ -34,6 +36,7 @@ static STATUS_COUNT_QUERY: &str = "SELECT status, count(status) \
 #[derive(Debug, EnumString)]
 pub enum StatusError {
     DBExists,
+    InvalidMarker,
     LookupError,
     Unknown,
 }
@@ -63,11 +66,20 @@ pub struct JobStatus {
     pub config: JobStatusConfig,
     pub results: JobStatusResults,
     pub state: JobState,
+    pub created_at: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// The liveness of each destination agent the job has used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<AgentLiveness>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct JobConfigEvacuate {
     pub from_shark: MantaObjectShark,
+    /// The filter the job was restricted to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ObjectFilter>,
 }
 
 type JobStatusResultsEvacuate = HashMap<String, i64>;
@@ -154,17 +166,24 @@ fn get_evacaute_job_status(
 fn get_evacuate_job_config(
     uuid: &Uuid,
 ) -> Result<JobConfigEvacuate, StatusError> {
-    use crate::jobs::evacuate::config::dsl::config as config_table;
+    use crate::jobs::evacuate::config::dsl::{
+        config as config_table, from_shark as from_shark_col,
+        object_filter as object_filter_col,
+    };
     let conn = get_job_db_conn_common(&uuid)?;
 
-    let config: EvacuateJobDbConfig =
-        config_table.first(&conn).map_err(|e| {
+    // Only select the column we need, so that the configuration of jobs
+    // created by older versions of the manager can still be read.
+    let from_shark_value: Value = config_table
+        .select(from_shark_col)
+        .first(&conn)
+        .map_err(|e| {
             error!("Could not find job config ({}): {}", uuid.to_string(), e);
             StatusError::LookupError
         })?;
 
-    let from_shark: MantaObjectShark =
-        serde_json::from_value(config.from_shark).map_err(|e| {
+    let from_shark: MantaObjectShark = serde_json::from_value(from_shark_value)
+        .map_err(|e| {
             error!(
                 "Could not deserialize job config ({}): {}",
                 uuid.to_string(),
@@ -173,7 +192,39 @@ fn get_evacuate_job_config(
             StatusError::Unknown
         })?;
 
-    Ok(JobConfigEvacuate { from_shark })
+    // Jobs created before object filters were added have no such column,
+    // and evacuated every object.
+    let filter = config_table
+        .select(object_filter_col)
+        .first::<Option<Value>>(&conn)
+        .unwrap_or(None)
+        .and_then(|value| match serde_json::from_value(value) {
+            Ok(filter) => Some(filter),
+            Err(e) => {
+                error!("Could not deserialize job filter ({}): {}", uuid, e);
+                None
+            }
+        });
+
+    Ok(JobConfigEvacuate { from_shark, filter })
+}
+
+// Jobs created by an older version of the manager have no agents table, and
+// so no agents to report.
+fn get_evacuate_job_agents(
+    uuid: &Uuid,
+) -> Result<Vec<AgentLiveness>, StatusError> {
+    use crate::jobs::evacuate::agents::dsl::{agents, storage_id};
+
+    let conn = get_job_db_conn_common(&uuid)?;
+
+    match agents.order(storage_id.asc()).load::<AgentLiveness>(&conn) {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            debug!("Could not get agents of job {}: {}", uuid, e);
+            Ok(vec![])
+        }
+    }
 }
 
 pub fn get_job_status(
@@ -204,28 +255,91 @@ pub fn get_job(uuid: Uuid) -> Result<JobStatus, StatusError> {
     let job_entry = get_job_db_entry(&uuid)?;
     let results = get_job_status(&uuid, &job_entry.action)?;
     let config = get_job_config(&uuid, &job_entry.action)?;
+    let agents = match job_entry.action {
+        JobActionDbEntry::Evacuate => get_evacuate_job_agents(&uuid)?,
+        _ => vec![],
+    };
 
     // get job config
     Ok(JobStatus {
         results,
         config,
         state: job_entry.state,
+        created_at: job_entry.created_at,
+        started_at: job_entry.started_at,
+        finished_at: job_entry.finished_at,
+        agents,
     })
 }
 
-pub fn list_jobs() -> Result<Vec<JobDbEntry>, StatusError> {
-    use crate::jobs::jobs::dsl::jobs as jobs_db;
+/// Criteria for `list_jobs`.  Every field is optional; the default lists all
+/// jobs.
+#[derive(Debug, Default)]
+pub struct ListJobsFilter {
+    /// Only list jobs in this state.
+    pub state: Option<JobState>,
+    /// Only list jobs taking this action.
+    pub action: Option<JobActionDbEntry>,
+    /// Return at most this many jobs.
+    pub limit: Option<u32>,
+    /// Return the jobs listed after the job with this id, as given by the
+    /// last entry of the previous page.
+    pub marker: Option<String>,
+}
 
-    let conn = get_rebalancer_db_conn()?;
-    let job_list = match jobs_db.load::<JobDbEntry>(&conn) {
-        Ok(list) => list,
-        Err(e) => {
-            error!("Error listing jobs: {}", e);
-            return Err(StatusError::Unknown);
-        }
+/// List jobs from most to least recently created.  Jobs that predate the
+/// created_at column are listed last, ordered by id.
+pub fn list_jobs(
+    filter: ListJobsFilter,
+) -> Result<Vec<JobDbEntry>, StatusError> {
+    use crate::jobs::jobs::dsl::{
+        action, created_at, id as job_id, jobs as jobs_db, state,
     };
 
-    Ok(job_list)
+    let conn = get_rebalancer_db_conn()?;
+    let mut query = jobs_db
+        .order((created_at.is_null(), created_at.desc(), job_id.asc()))
+        .into_boxed();
+
+    if let Some(job_state) = filter.state {
+        query = query.filter(state.eq(job_state));
+    }
+
+    if let Some(job_action) = filter.action {
+        query = query.filter(action.eq(job_action));
+    }
+
+    // Resume after the marker job in the sort order above.  Jobs created
+    // after the previous page was fetched sort ahead of the marker, so they
+    // do not shift later pages.
+    if let Some(marker) = filter.marker {
+        let marker_job: JobDbEntry = jobs_db
+            .filter(job_id.eq(&marker))
+            .first(&conn)
+            .map_err(|e| {
+                error!("Could not find marker job ({}): {}", marker, e);
+                StatusError::InvalidMarker
+            })?;
+
+        query = match marker_job.created_at {
+            Some(marker_created) => query.filter(
+                created_at
+                    .lt(marker_created)
+                    .or(created_at.eq(marker_created).and(job_id.gt(marker)))
+                    .or(created_at.is_null()),
+            ),
+            None => query.filter(created_at.is_null().and(job_id.gt(marker))),
+        };
+    }
+
+    if let Some(limit) = filter.limit {
+        query = query.limit(i64::from(limit));
+    }
+
+    query.load::<JobDbEntry>(&conn).map_err(|e| {
+        error!("Error listing jobs: {}", e);
+        StatusError::Unknown
+    })
 }
 
 #[cfg(test)]
@@ -242,7 +356,72 @@ mod tests {
 
     #[test]
     fn list_job_test() {
-        assert!(list_jobs().is_ok());
+        assert!(list_jobs(ListJobsFilter::default()).is_ok());
+    }
+
+    #[test]
+    fn list_jobs_order_test() {
+        let _guard = util::init_global_logger(None);
+        for _ in 0..2 {
+            JobBuilder::new(Config::default())
+                .evacuate("fake_shark".to_string(), Some(1))
+                .commit()
+                .expect("job builder");
+        }
+
+        let job_list = list_jobs(ListJobsFilter::default()).expect("list");
+
+        // Newest first, with jobs that have no created_at listed last.
+        for pair in job_list.windows(2) {
+            match (&pair[0].created_at, &pair[1].created_at) {
+                (Some(a), Some(b)) => assert!(a >= b),
+                (None, Some(_)) => {
+                    panic!("job without created_at listed first")
+                }
+                _ => (),
+            }
+        }
+    }
+
+    #[test]
+    fn list_jobs_filter_test() {
+        let _guard = util::init_global_logger(None);
+        for _ in 0..3 {
+            JobBuilder::new(Config::default())
+                .evacuate("fake_shark".to_string(), Some(1))
+                .commit()
+                .expect("job builder");
+        }
+
+        let job_list = list_jobs(ListJobsFilter::default()).expect("list");
+        assert!(job_list.len() >= 3);
+
+        let page = list_jobs(ListJobsFilter {
+            limit: Some(1),
+            marker: Some(job_list[0].id.clone()),
+            ..ListJobsFilter::default()
+        })
+        .expect("list page");
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, job_list[1].id);
+
+        let setup_jobs = list_jobs(ListJobsFilter {
+            state: Some(JobState::Setup),
+            action: Some(JobActionDbEntry::Evacuate),
+            ..ListJobsFilter::default()
+        })
+        .expect("list setup jobs");
+        assert!(setup_jobs.iter().all(|j| j.state == JobState::Setup
+            && j.action == JobActionDbEntry::Evacuate));
+
+        let bad_marker = list_jobs(ListJobsFilter {
+            marker: Some(Uuid::new_v4().to_string()),
+            ..ListJobsFilter::default()
+        });
+        match bad_marker {
+            Err(StatusError::InvalidMarker) => (),
+            other => panic!("expected InvalidMarker, got {:?}", other),
+        }
     }
 
     #[test]
@@ -0,0 +1,346 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// Assignment watchdog and agent liveness.
+//
+// An agent that dies, hangs or loses its disk part way through an assignment
+// may keep answering (or may never answer) without the assignment ever
+// completing, which used to leave the job waiting on it forever.  The
+// assignment checker of an evacuate job keeps an `AssignmentWatchdog` that
+// records when each outstanding assignment last made progress, that is when
+// the agent last reported more of its tasks as done.
+//
+// Agents work through their assignments in turn, so an assignment that is
+// still queued behind others on a busy agent does not progress either.  An
+// assignment is therefore only considered stalled when neither it nor any
+// other assignment on the same agent has progressed for the job's
+// `assignment_stall_timeout`.  The objects of a stalled assignment are handed
+// back to the assignment manager to be sent to another destination, and the
+// agent is not sent new assignments until it makes progress again.
+//
+// The liveness of each destination agent is recorded in the job's `agents`
+// table and reported in the job status.
+
+use super::evacuate::agents;
+use super::{AssignmentId, StorageId};
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types;
+use serde::{Deserialize, Serialize};
+
+// How often the last progress of an agent is recorded while it stays alive.
+// This keeps a busy agent from causing a database write every time one of
+// its assignments is checked on.
+const LAST_PROGRESS_RESOLUTION_SECS: i64 = 60;
+
+#[derive(
+    AsExpression,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumString,
+    EnumVariantNames,
+    FromSqlRow,
+    PartialEq,
+    Serialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[sql_type = "sql_types::Text"]
+pub enum AgentState {
+    Alive,       // The agent is making progress on its assignments.
+    Stalled,     // No progress within the stall timeout.
+    Unreachable, // The agent could not be contacted.
+}
+
+impl ToSql<sql_types::Text, Pg> for AgentState {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let state = self.to_string();
+        out.write_all(state.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<sql_types::Text, Pg> for AgentState {
+    fn from_sql(bytes: Option<PgValue<'_>>) -> deserialize::Result<Self> {
+        let t: PgValue = not_none!(bytes);
+        let t_str = String::from_utf8_lossy(t.as_bytes());
+        Self::from_str(&t_str).map_err(std::convert::Into::into)
+    }
+}
+
+/// The liveness of one destination agent of a job, as reported in the job
+/// status.
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Deserialize,
+    Insertable,
+    PartialEq,
+    Queryable,
+    Serialize,
+)]
+#[table_name = "agents"]
+pub struct AgentLiveness {
+    pub storage_id: StorageId,
+    pub state: AgentState,
+    /// The last time one of the agent's assignments made progress.
+    pub last_progress: Option<DateTime<Utc>>,
+    /// Number of the agent's assignments that stalled and were re-queued.
+    pub stalled_assignments: i32,
+}
+
+/// What the manager has seen of a destination agent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AgentEvent {
+    Assigned,    // An assignment was posted to the agent.
+    Progress,    // One of the agent's assignments made progress.
+    Stalled,     // One of the agent's assignments stalled.
+    Unreachable, // The agent could not be contacted.
+}
+
+impl AgentLiveness {
+    pub fn new(storage_id: &str) -> Self {
+        Self {
+            storage_id: storage_id.to_string(),
+            state: AgentState::Alive,
+            last_progress: None,
+            stalled_assignments: 0,
+        }
+    }
+
+    /// Update the liveness for an event seen at `now`.  Returns true if
+    /// anything changed.  Only progress brings an agent back to life.  The
+    /// last progress of an agent that was already alive is only updated
+    /// once every LAST_PROGRESS_RESOLUTION_SECS.
+    pub fn apply(&mut self, event: AgentEvent, now: DateTime<Utc>) -> bool {
+        match event {
+            AgentEvent::Assigned => false,
+            AgentEvent::Progress => {
+                let resolution =
+                    chrono::Duration::seconds(LAST_PROGRESS_RESOLUTION_SECS);
+                let changed = self.state != AgentState::Alive
+                    || self
+                        .last_progress
+                        .map_or(true, |at| now - at >= resolution);
+
+                self.state = AgentState::Alive;
+                if changed {
+                    self.last_progress = Some(now);
+                }
+                changed
+            }
+            AgentEvent::Stalled => {
+                self.state = AgentState::Stalled;
+                self.stalled_assignments += 1;
+                true
+            }
+            AgentEvent::Unreachable => {
+                let changed = self.state != AgentState::Unreachable;
+                self.state = AgentState::Unreachable;
+                changed
+            }
+        }
+    }
+}
+
+struct AssignmentProgress {
+    agent: StorageId,
+    done: usize,
+    at: Instant,
+}
+
+/// Tracks the last progress of each outstanding assignment.  See the module
+/// comment for when an assignment is considered stalled.
+pub struct AssignmentWatchdog {
+    timeout: Duration,
+    assignments: HashMap<AssignmentId, AssignmentProgress>,
+    agents: HashMap<StorageId, Instant>,
+}
+
+impl AssignmentWatchdog {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            assignments: HashMap::new(),
+            agents: HashMap::new(),
+        }
+    }
+
+    /// Start watching an assignment that has been posted to `agent`.  The
+    /// stall timeout starts now.  Watching an assignment that is already
+    /// watched has no effect.
+    pub fn watch(&mut self, id: &str, agent: &str, now: Instant) {
+        self.assignments.entry(id.to_string()).or_insert_with(|| {
+            AssignmentProgress {
+                agent: agent.to_string(),
+                done: 0,
+                at: now,
+            }
+        });
+    }
+
+    /// Record the number of tasks the agent reports as done (complete or
+    /// failed).  If that is more than it last reported, returns the agent
+    /// the assignment was posted to.
+    pub fn progress(
+        &mut self,
+        id: &str,
+        done: usize,
+        now: Instant,
+    ) -> Option<&str> {
+        let progress = self.assignments.get_mut(id)?;
+
+        if done <= progress.done {
+            return None;
+        }
+
+        progress.done = done;
+        progress.at = now;
+        self.agents.insert(progress.agent.clone(), now);
+        Some(&progress.agent)
+    }
+
+    /// Stop watching an assignment.
+    pub fn forget(&mut self, id: &str) {
+        self.assignments.remove(id);
+    }
+
+    /// Stop watching every assignment for which `keep` returns false.
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.assignments.retain(|id, _| keep(id));
+    }
+
+    /// The assignments that have stalled.
+    pub fn stalled(&self, now: Instant) -> Vec<AssignmentId> {
+        let mut stalled: Vec<AssignmentId> = self
+            .assignments
+            .iter()
+            .filter(|(_, p)| {
+                let last = match self.agents.get(&p.agent) {
+                    Some(agent_at) if *agent_at > p.at => *agent_at,
+                    _ => p.at,
+                };
+                now.saturating_duration_since(last) >= self.timeout
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        stalled.sort();
+        stalled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn agent_liveness_test() {
+        let now = Utc::now();
+        let mut agent = AgentLiveness::new("1.stor");
+
+        assert!(!agent.apply(AgentEvent::Assigned, now));
+        assert_eq!(agent.state, AgentState::Alive);
+
+        assert!(agent.apply(AgentEvent::Stalled, now));
+        assert!(agent.apply(AgentEvent::Stalled, now));
+        assert_eq!(agent.state, AgentState::Stalled);
+        assert_eq!(agent.stalled_assignments, 2);
+
+        assert!(agent.apply(AgentEvent::Unreachable, now));
+        assert!(!agent.apply(AgentEvent::Unreachable, now));
+        assert!(!agent.apply(AgentEvent::Assigned, now));
+        assert_eq!(agent.state, AgentState::Unreachable);
+
+        assert!(agent.apply(AgentEvent::Progress, now));
+        assert_eq!(agent.state, AgentState::Alive);
+        assert_eq!(agent.last_progress, Some(now));
+        assert_eq!(agent.stalled_assignments, 2);
+
+        // Further progress is only recorded once a minute.
+        let later = now + chrono::Duration::seconds(10);
+        assert!(!agent.apply(AgentEvent::Progress, later));
+        assert_eq!(agent.last_progress, Some(now));
+
+        let later = now + chrono::Duration::seconds(60);
+        assert!(agent.apply(AgentEvent::Progress, later));
+        assert_eq!(agent.last_progress, Some(later));
+    }
+
+    #[test]
+    fn stalled_after_timeout_test() {
+        let start = Instant::now();
+        let mut watchdog = AssignmentWatchdog::new(TIMEOUT);
+
+        watchdog.watch("a1", "1.stor", start);
+        watchdog.watch("a2", "2.stor", start);
+        assert!(watchdog.stalled(start + TIMEOUT / 2).is_empty());
+
+        // Progress on a1 restarts its timeout, a2 stalls.
+        assert_eq!(
+            watchdog.progress("a1", 3, start + TIMEOUT / 2),
+            Some("1.stor")
+        );
+        assert_eq!(watchdog.stalled(start + TIMEOUT), vec!["a2".to_string()]);
+
+        // The same count again is not progress.
+        assert_eq!(watchdog.progress("a1", 3, start + TIMEOUT), None);
+        assert_eq!(watchdog.stalled(start + TIMEOUT * 2).len(), 2);
+
+        // Watching again does not reset the timeout.
+        watchdog.watch("a2", "2.stor", start + TIMEOUT);
+        assert_eq!(watchdog.stalled(start + TIMEOUT).len(), 1);
+
+        // Unknown assignments are ignored.
+        assert_eq!(watchdog.progress("a3", 1, start), None);
+    }
+
+    #[test]
+    fn busy_agent_not_stalled_test() {
+        let start = Instant::now();
+        let mut watchdog = AssignmentWatchdog::new(TIMEOUT);
+
+        // a2 is queued behind a1 on the same agent.
+        watchdog.watch("a1", "1.stor", start);
+        watchdog.watch("a2", "1.stor", start);
+
+        assert!(watchdog.progress("a1", 10, start + TIMEOUT / 2).is_some());
+        assert!(watchdog.stalled(start + TIMEOUT).is_empty());
+
+        // a1 completing is progress for the agent too.
+        assert!(watchdog.progress("a1", 20, start + TIMEOUT).is_some());
+        watchdog.forget("a1");
+        assert!(watchdog.stalled(start + TIMEOUT * 3 / 2).is_empty());
+        assert_eq!(
+            watchdog.stalled(start + TIMEOUT * 2),
+            vec!["a2".to_string()]
+        );
+
+        watchdog.retain(|id| id != "a2");
+        assert!(watchdog.stalled(start + TIMEOUT * 3).is_empty());
+    }
+}
@@ -23,11 +23,15 @@ extern crate strum_macros;
 extern crate rebalancer;
 
 pub mod config;
+pub mod fleet;
 pub mod jobs;
 pub mod metrics;
 pub mod moray_client;
+pub mod notify;
 pub mod pg_db;
 pub mod storinfo;
 
+#[cfg(test)]
+pub mod test_harness;
 #[cfg(test)]
 pub mod test_util;
@@ -20,7 +20,9 @@ extern crate rebalancer;
 mod gotham_json_util;
 
 use manager::config::Config;
-use manager::jobs::status::{JobStatus, StatusError};
+use manager::fleet;
+use manager::jobs::export::{self, ExportFormat, ExportPages};
+use manager::jobs::status::{JobStatus, ListJobsFilter, StatusError};
 use manager::jobs::{
     self, JobActionDbEntry, JobBuilder, JobDbEntry, JobPayload, JobState,
     JobUpdateMessage,
@@ -39,7 +41,8 @@ use clap::{App, Arg, ArgMatches};
 use crossbeam_channel;
 use diesel::query_dsl::{QueryDsl, RunQueryDsl};
 use diesel::PgConnection;
-use futures::{future, Future};
+use futures::sync::mpsc;
+use futures::{future, Future, Stream};
 use gotham::handler::{Handler, HandlerFuture, IntoResponse, NewHandler};
 use gotham::helpers::http::response::create_response;
 use gotham::middleware::Middleware;
@@ -48,10 +51,14 @@ use gotham::pipeline::set::{finalize_pipeline_set, new_pipeline_set};
 use gotham::router::builder::{build_router, DefineSingleRoute, DrawRoutes};
 use gotham::router::Router;
 use gotham::state::{FromState, State};
-use hyper::header::HeaderValue;
+use hyper::header::{HeaderValue, CONTENT_DISPOSITION, CONTENT_TYPE};
 use hyper::{Body, Response, StatusCode};
 use lazy_static::lazy_static;
-use manager::jobs::evacuate::EvacuateJobUpdateMessage;
+use manager::jobs::evacuate::{
+    self, EvacuateJobUpdateMessage, EvacuateObjectStatus,
+};
+use rebalancer::heartbeat::AgentHeartbeat;
+use rebalancer::libagent::Assignment as AgentAssignment;
 use threadpool::ThreadPool;
 use uuid::Uuid;
 
@@ -60,6 +67,11 @@ static THREAD_COUNT: usize = 1;
 lazy_static! {
     static ref UPDATE_CHANS: Mutex<HashMap<Uuid, crossbeam_channel::Sender<JobUpdateMessage>>> =
         Mutex::new(HashMap::new());
+
+    // Held from the check for an active job on a shark until the new job is
+    // in the database, so that two requests for the same shark cannot both
+    // pass the check.
+    static ref JOB_CREATE_LOCK: Mutex<()> = Mutex::new(());
 }
 
 #[derive(Deserialize, StateData, StaticResponseExtender)]
@@ -72,6 +84,49 @@ struct JobList {
     jobs: Vec<String>,
 }
 
+#[derive(Deserialize, StateData, StaticResponseExtender)]
+struct ListJobsParams {
+    state: Option<String>,
+    action: Option<String>,
+    limit: Option<u32>,
+    marker: Option<String>,
+}
+
+impl ListJobsParams {
+    // States and actions are matched case-insensitively so that the values
+    // shown in a job listing (e.g. "Running") can be passed back as filters.
+    fn into_filter(self) -> Result<ListJobsFilter, String> {
+        let state = match self.state {
+            Some(s) => Some(
+                JobState::from_str(&s.to_lowercase())
+                    .map_err(|_| format!("Invalid job state: {}", s))?,
+            ),
+            None => None,
+        };
+
+        let action = match self.action {
+            Some(a) => Some(
+                JobActionDbEntry::from_str(&a.to_lowercase())
+                    .map_err(|_| format!("Invalid job action: {}", a))?,
+            ),
+            None => None,
+        };
+
+        Ok(ListJobsFilter {
+            state,
+            action,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
+#[derive(Deserialize, StateData, StaticResponseExtender)]
+struct ExportJobParams {
+    format: Option<String>,
+    status: Option<String>,
+}
+
 #[derive(Deserialize, StateData, StaticResponseExtender)]
 struct UpdateJobParams {
     uuid: String,
@@ -207,7 +262,9 @@ fn get_job(mut state: State) -> Box<HandlerFuture> {
                     // the Job is still initializing its table may not have
                     // been created yet as the tables are unique to the Job
                     // Action.
-                    StatusError::LookupError | StatusError::Unknown => {
+                    StatusError::InvalidMarker
+                    | StatusError::LookupError
+                    | StatusError::Unknown => {
                         ret = invalid_server_error(
                             &state,
                             String::from(
@@ -226,17 +283,24 @@ fn get_job(mut state: State) -> Box<HandlerFuture> {
 type JobListFuture =
     Box<dyn Future<Item = Vec<JobDbEntry>, Error = StatusError> + Send>;
 
-fn get_job_list() -> JobListFuture {
-    Box::new(match jobs::status::list_jobs() {
+fn get_job_list(filter: ListJobsFilter) -> JobListFuture {
+    Box::new(match jobs::status::list_jobs(filter) {
         Ok(list) => future::ok(list),
         Err(e) => future::err(e),
     })
 }
 
-fn list_jobs(state: State) -> Box<HandlerFuture> {
+fn list_jobs(mut state: State) -> Box<HandlerFuture> {
     metrics_request_inc(Some("list_jobs"));
     info!("List Jobs Request");
-    let job_list_future = get_job_list();
+    let filter = match ListJobsParams::take_from(&mut state).into_filter() {
+        Ok(f) => f,
+        Err(msg) => {
+            let ret = bad_request(&state, msg);
+            return Box::new(future::ok((state, ret)));
+        }
+    };
+    let job_list_future = get_job_list(filter);
     Box::new(job_list_future.then(move |result| match result {
         Ok(list) => {
             let jobs = match serde_json::to_string(&list) {
@@ -255,6 +319,10 @@ fn list_jobs(state: State) -> Box<HandlerFuture> {
             );
             future::ok((state, res))
         }
+        Err(StatusError::InvalidMarker) => {
+            let ret = bad_request(&state, String::from("Unknown job marker"));
+            future::ok((state, ret))
+        }
         Err(e) => {
             let msg = format!("Error Getting Job List: {:#?}", e);
             let ret = invalid_server_error(&state, msg);
@@ -352,6 +420,195 @@ fn update_job(mut state: State) -> (State, Response<Body>) {
     (state, res)
 }
 
+// An agent has finished an assignment that it was asked to post back to us.
+// See `Config::assignment_callback_url`.
+fn assignment_complete(mut state: State) -> (State, Response<Body>) {
+    let job_params = GetJobParams::take_from(&mut state);
+    let job_id = match Uuid::from_str(&job_params.uuid) {
+        Ok(id) => id.to_string(),
+        Err(e) => {
+            let res = bad_request(&state, format!("Invalid job id: {}", e));
+            return (state, res);
+        }
+    };
+
+    let assignment = match state.json_body::<AgentAssignment>().wait() {
+        Ok(a) => a,
+        Err(e) => {
+            let msg = format!("Could not parse assignment: {}", e);
+            warn!("{}", msg);
+            let res = create_response(
+                &state,
+                StatusCode::UNPROCESSABLE_ENTITY,
+                mime::APPLICATION_JSON,
+                msg,
+            );
+            return (state, res);
+        }
+    };
+
+    debug!(
+        "Job {}: agent posted completed assignment {}",
+        job_id, assignment.uuid
+    );
+
+    // The agent does not retry a rejected post.  If the job is resumed later
+    // on, it will find the assignment by polling for it.
+    if !evacuate::report_completed_assignment(&job_id, assignment) {
+        let msg = format!("Job {} is not running", job_id);
+        warn!("{}", msg);
+        let res = create_response(
+            &state,
+            StatusCode::NOT_FOUND,
+            mime::APPLICATION_JSON,
+            msg,
+        );
+        return (state, res);
+    }
+
+    let res =
+        create_response(&state, StatusCode::OK, mime::APPLICATION_JSON, "");
+
+    (state, res)
+}
+
+// Send a job's report to the response body a page at a time.  This runs on its
+// own thread so that reading a large job database does not hold up the
+// server.
+fn stream_export(
+    pages: ExportPages,
+    format: ExportFormat,
+    mut tx: mpsc::Sender<Result<String, String>>,
+) {
+    if let Some(header) = format.header() {
+        tx = match tx.send(Ok(header)).wait() {
+            Ok(t) => t,
+            Err(_) => return,
+        };
+    }
+
+    for page in pages {
+        let chunk = page
+            .and_then(|records| export::render_page(format, &records))
+            .map_err(|e| {
+                error!("Error exporting job results: {}", e);
+                e.to_string()
+            });
+        let failed = chunk.is_err();
+
+        tx = match tx.send(chunk).wait() {
+            Ok(t) => t,
+            Err(_) => {
+                warn!("Export request closed before all results were sent");
+                return;
+            }
+        };
+
+        if failed {
+            return;
+        }
+    }
+}
+
+fn export_job(mut state: State) -> (State, Response<Body>) {
+    use crate::jobs::jobs::dsl::jobs as jobs_db;
+
+    metrics_request_inc(Some("export_job"));
+    info!("Export Job Request");
+
+    let db_conn = DBConnMiddlewareData::take_from(&mut state).db_conn;
+    let job_params = GetJobParams::take_from(&mut state);
+    let export_params = ExportJobParams::take_from(&mut state);
+
+    let uuid = match Uuid::parse_str(&job_params.uuid) {
+        Ok(id) => id,
+        Err(e) => {
+            let res = bad_request(&state, format!("Invalid UUID: {}", e));
+            return (state, res);
+        }
+    };
+
+    let format = match export_params.format {
+        Some(f) => match ExportFormat::from_str(&f) {
+            Ok(fmt) => fmt,
+            Err(msg) => {
+                let res = bad_request(&state, msg);
+                return (state, res);
+            }
+        },
+        None => ExportFormat::default(),
+    };
+
+    // Object statuses are matched case-insensitively, like job list filters.
+    let object_status = match export_params.status {
+        Some(s) => match EvacuateObjectStatus::from_str(&s.to_lowercase()) {
+            Ok(status) => Some(status),
+            Err(_) => {
+                let msg = format!("Invalid object status: {}", s);
+                let res = bad_request(&state, msg);
+                return (state, res);
+            }
+        },
+        None => None,
+    };
+
+    let job_db_entry: JobDbEntry =
+        match jobs_db.find(job_params.uuid.as_str()).first(&db_conn) {
+            Ok(jdbe) => jdbe,
+            Err(_) => {
+                let msg = format!("Could not find job {}", uuid);
+                let res = bad_request(&state, msg);
+                return (state, res);
+            }
+        };
+
+    if job_db_entry.action != JobActionDbEntry::Evacuate {
+        let msg = format!("Job {} has no results to export", uuid);
+        let res = bad_request(&state, msg);
+        return (state, res);
+    }
+
+    let pages = match ExportPages::new(&uuid) {
+        Ok(p) => p.with_status(object_status),
+        Err(e) => {
+            let res = invalid_server_error(
+                &state,
+                format!("Could not read results for job {}: {}", uuid, e),
+            );
+            return (state, res);
+        }
+    };
+
+    let (tx, rx) = mpsc::channel(1);
+    std::thread::spawn(move || stream_export(pages, format, tx));
+
+    let body = Body::wrap_stream(
+        rx.map_err(|()| String::from("export ended unexpectedly"))
+            .and_then(|chunk| chunk),
+    );
+
+    let mut res = create_response(
+        &state,
+        StatusCode::OK,
+        mime::APPLICATION_OCTET_STREAM,
+        body,
+    );
+    let headers = res.headers_mut();
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static(format.content_type()),
+    );
+    if let Ok(disposition) = HeaderValue::from_str(&format!(
+        "attachment; filename=\"{}.{}\"",
+        uuid,
+        format.extension()
+    )) {
+        headers.insert(CONTENT_DISPOSITION, disposition);
+    }
+
+    (state, res)
+}
+
 #[derive(Clone)]
 struct JobRetryHandler {
     tx: crossbeam_channel::Sender<jobs::Job>,
@@ -465,8 +722,71 @@ impl Handler for JobCreateHandler {
                     }
                 };
 
+                if let Some(sizing) = &evac_payload.assignment_sizing {
+                    if let Err(msg) = sizing.validate() {
+                        let error = invalid_server_error(&state, msg);
+                        return Box::new(future::ok((state, error)));
+                    }
+                }
+
+                if let Some(policy) = &evac_payload.large_objects {
+                    if let Err(msg) = policy.validate() {
+                        let error = invalid_server_error(&state, msg);
+                        return Box::new(future::ok((state, error)));
+                    }
+                }
+
+                if let Some(filter) = &evac_payload.filter {
+                    if let Err(msg) = filter.validate() {
+                        let error = invalid_server_error(&state, msg);
+                        return Box::new(future::ok((state, error)));
+                    }
+                }
+
+                let _create_guard =
+                    JOB_CREATE_LOCK.lock().expect("job create lock");
+
+                // Only one job at a time may evacuate a given shark, unless
+                // the caller explicitly asks for another.
+                let from_shark = &evac_payload.from_shark;
+                match jobs::active_jobs_for_shark(from_shark) {
+                    Ok(active) => {
+                        if let Some(existing) = active.first() {
+                            if evac_payload.force {
+                                warn!(
+                                    "Forcing a new job for shark {} while \
+                                     job {} is active",
+                                    from_shark, existing
+                                );
+                            } else {
+                                let msg = format!(
+                                    "Job {} is already evacuating shark {}",
+                                    existing, from_shark
+                                );
+                                warn!("{}", msg);
+                                let conflict = create_response(
+                                    &state,
+                                    StatusCode::CONFLICT,
+                                    mime::APPLICATION_JSON,
+                                    msg,
+                                );
+                                return Box::new(future::ok((state, conflict)));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        let msg =
+                            format!("Error checking for active jobs: {}", e);
+                        let error = invalid_server_error(&state, msg);
+                        return Box::new(future::ok((state, error)));
+                    }
+                }
+
                 let job = match job_builder
                     .evacuate(evac_payload.from_shark, max_objects)
+                    .assignment_sizing(evac_payload.assignment_sizing)
+                    .large_objects(evac_payload.large_objects)
+                    .object_filter(evac_payload.filter)
                     .commit()
                 {
                     Ok(j) => j,
@@ -559,16 +879,11 @@ impl Middleware for DBConnMiddleware {
     }
 }
 
-fn router(config: Arc<Mutex<Config>>) -> Router {
+// Start the threads that run jobs, and return the channel on which jobs are
+// sent to them.
+fn start_job_runners() -> crossbeam_channel::Sender<jobs::Job> {
     let (tx, rx) = crossbeam_channel::bounded(5);
 
-    let job_create_handler = JobCreateHandler {
-        tx: tx.clone(),
-        config: Arc::clone(&config),
-    };
-
-    let job_retry_handler = JobRetryHandler { tx, config };
-
     // Start the metrics server.
     metrics_init(rebalancer::metrics::ConfigMetrics::default());
 
@@ -596,6 +911,122 @@ fn router(config: Arc<Mutex<Config>>) -> Router {
         });
     }
 
+    tx
+}
+
+// Resume the jobs that were set up or running when the manager last exited.
+// The jobs are sent to the job runners from a separate thread so that the
+// server does not wait for them to be accepted before it starts.
+fn resume_interrupted_jobs(
+    config: &Arc<Mutex<Config>>,
+    tx: crossbeam_channel::Sender<jobs::Job>,
+) {
+    let job_ids = match jobs::interrupted_jobs() {
+        Ok(ids) => ids,
+        Err(e) => {
+            error!("Could not look up interrupted jobs: {}", e);
+            return;
+        }
+    };
+
+    let mut resumed = vec![];
+    for job_id in job_ids {
+        let job_config = config.lock().expect("lock config").clone();
+        let job = match JobBuilder::new(job_config)
+            .resume(&job_id)
+            .and_then(JobBuilder::commit)
+        {
+            Ok(j) => j,
+            Err(e) => {
+                error!("Could not resume job {}: {}", job_id, e);
+                continue;
+            }
+        };
+
+        info!("Resuming job {}", job_id);
+
+        if let Some(update_tx) = &job.update_tx {
+            add_update_channel(job.get_id(), update_tx.clone());
+        }
+
+        resumed.push(job);
+    }
+
+    if resumed.is_empty() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        for job in resumed {
+            if let Err(e) = tx.send(job) {
+                error!("Could not send resumed job to be run: {}", e);
+                return;
+            }
+        }
+    });
+}
+
+// An agent has sent a heartbeat.  See `rebalancer::heartbeat'.
+fn agent_heartbeat(mut state: State) -> (State, Response<Body>) {
+    let heartbeat = match state.json_body::<AgentHeartbeat>().wait() {
+        Ok(h) => h,
+        Err(e) => {
+            let msg = format!("Could not parse heartbeat: {}", e);
+            warn!("{}", msg);
+            let res = create_response(
+                &state,
+                StatusCode::UNPROCESSABLE_ENTITY,
+                mime::APPLICATION_JSON,
+                msg,
+            );
+            return (state, res);
+        }
+    };
+
+    trace!("Heartbeat from agent on {}", heartbeat.storage_id);
+
+    if let Err(msg) = fleet::global().record(heartbeat) {
+        let res = bad_request(&state, msg);
+        return (state, res);
+    }
+
+    let res =
+        create_response(&state, StatusCode::OK, mime::APPLICATION_JSON, "");
+
+    (state, res)
+}
+
+fn list_agents(state: State) -> (State, Response<Body>) {
+    metrics_request_inc(Some("list_agents"));
+    info!("List Agents Request");
+
+    let res = match serde_json::to_string(&fleet::global().list()) {
+        Ok(agents) => create_response(
+            &state,
+            StatusCode::OK,
+            mime::APPLICATION_JSON,
+            agents,
+        ),
+        Err(e) => invalid_server_error(
+            &state,
+            format!("Error Getting Agent List: {}", e),
+        ),
+    };
+
+    (state, res)
+}
+
+fn router(
+    config: Arc<Mutex<Config>>,
+    tx: crossbeam_channel::Sender<jobs::Job>,
+) -> Router {
+    let job_create_handler = JobCreateHandler {
+        tx: tx.clone(),
+        config: Arc::clone(&config),
+    };
+
+    let job_retry_handler = JobRetryHandler { tx, config };
+
     let ps_builder = new_pipeline_set();
     let (ps_builder, base) =
         ps_builder.add(new_pipeline().add(BaseMiddleware).build());
@@ -620,6 +1051,11 @@ fn router(config: Arc<Mutex<Config>>) -> Router {
                 .put("/jobs/:uuid")
                 .with_path_extractor::<UpdateJobParams>()
                 .to(update_job);
+            route
+                .get("/jobs/:uuid/export")
+                .with_path_extractor::<GetJobParams>()
+                .with_query_string_extractor::<ExportJobParams>()
+                .to(export_job);
         });
         route
             .post("/jobs")
@@ -632,7 +1068,16 @@ fn router(config: Arc<Mutex<Config>>) -> Router {
             .get("/jobs/:uuid")
             .with_path_extractor::<GetJobParams>()
             .to(get_job);
-        route.get("/jobs").to(list_jobs);
+        route
+            .post("/jobs/:uuid/assignments")
+            .with_path_extractor::<GetJobParams>()
+            .to(assignment_complete);
+        route
+            .get("/jobs")
+            .with_query_string_extractor::<ListJobsParams>()
+            .to(list_jobs);
+        route.post("/agents").to(agent_heartbeat);
+        route.get("/agents").to(list_agents);
     });
 
     info!("Rebalancer Online");
@@ -679,7 +1124,14 @@ fn main() {
     let config_watcher_handle =
         Config::start_config_watcher(Arc::clone(&config), config_file);
 
-    gotham::start_with_num_threads(addr, router(Arc::clone(&config)), 1);
+    let job_tx = start_job_runners();
+    resume_interrupted_jobs(&config, job_tx.clone());
+
+    gotham::start_with_num_threads(
+        addr,
+        router(Arc::clone(&config), job_tx),
+        1,
+    );
 
     config_watcher_handle.join().expect("join config watcher");
 }
@@ -691,6 +1143,7 @@ mod tests {
     use lazy_static::lazy_static;
     use manager::jobs::{EvacuateJobPayload, JobPayload};
     use rebalancer::error::{Error, InternalError};
+    use rebalancer::libagent::AgentAssignmentStats;
     use std::sync::Mutex;
     use std::thread;
 
@@ -743,7 +1196,8 @@ mod tests {
         );
         let config = Arc::new(config);
         let test_server =
-            TestServer::new(router(Arc::clone(&config))).expect("test server");
+            TestServer::new(router(Arc::clone(&config), start_job_runners()))
+                .expect("test server");
         (config, test_server)
     }
 
@@ -762,10 +1216,19 @@ mod tests {
             .expect("put update")
     }
 
-    fn create_job(test_server: &TestServer, job_payload: JobPayload) -> String {
-        let payload = serde_json::to_string(&job_payload)
+    // A storage id that no other job uses, so that jobs left unfinished by
+    // this or an earlier test run don't conflict with the job being created.
+    fn fake_shark() -> String {
+        format!("{}.fake_storage_id", Uuid::new_v4())
+    }
+
+    fn post_job(
+        test_server: &TestServer,
+        job_payload: &JobPayload,
+    ) -> TestResponse {
+        let payload = serde_json::to_string(job_payload)
             .expect("serde serialize payload");
-        let response = test_server
+        test_server
             .client()
             .post(
                 "http://localhost:8888/jobs",
@@ -773,7 +1236,11 @@ mod tests {
                 mime::APPLICATION_JSON,
             )
             .perform()
-            .expect("client post");
+            .expect("client post")
+    }
+
+    fn create_job(test_server: &TestServer, job_payload: JobPayload) -> String {
+        let response = post_job(test_server, &job_payload);
 
         assert_eq!(response.status(), StatusCode::OK);
 
@@ -819,19 +1286,186 @@ mod tests {
         println!("{:#?}", pretty_response);
     }
 
+    #[test]
+    fn list_jobs_query() {
+        unit_test_init();
+        let (_, test_server) = test_server_init();
+
+        for _ in 0..2 {
+            JobBuilder::default()
+                .evacuate(String::from("fake_storage_id"), None)
+                .commit()
+                .expect("Failed to create job");
+        }
+
+        let response = test_server
+            .client()
+            .get("http://localhost:8888/jobs?action=Evacuate&limit=1")
+            .perform()
+            .expect("list jobs response");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.read_body().expect("response body");
+        let job_list: Vec<JobDbEntry> =
+            serde_json::from_slice(&body).expect("job list");
+        assert_eq!(job_list.len(), 1);
+        assert_eq!(job_list[0].action, JobActionDbEntry::Evacuate);
+
+        for query in &["state=bogus", "action=bogus", "limit=many"] {
+            let response = test_server
+                .client()
+                .get(format!("http://localhost:8888/jobs?{}", query))
+                .perform()
+                .expect("list jobs response");
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", query);
+        }
+
+        let response = test_server
+            .client()
+            .get(format!(
+                "http://localhost:8888/jobs?marker={}",
+                Uuid::new_v4()
+            ))
+            .perform()
+            .expect("list jobs response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn export_job_query() {
+        unit_test_init();
+        let (_, test_server) = test_server_init();
+        let uuid = Uuid::new_v4();
+
+        for query in &["format=xml", "status=bogus"] {
+            let response = test_server
+                .client()
+                .get(format!(
+                    "http://localhost:8888/jobs/{}/export?{}",
+                    uuid, query
+                ))
+                .perform()
+                .expect("export job response");
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", query);
+        }
+
+        // A valid status is accepted, so the job lookup is what fails.
+        let response = test_server
+            .client()
+            .get(format!(
+                "http://localhost:8888/jobs/{}/export?status=Skipped",
+                uuid
+            ))
+            .perform()
+            .expect("export job response");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response.read_utf8_body().expect("response body");
+        assert_eq!(body, format!("Could not find job {}", uuid));
+    }
+
     #[test]
     fn post_test() {
         unit_test_init();
         let (_, test_server) = test_server_init();
         let job_payload = JobPayload::Evacuate(EvacuateJobPayload {
-            from_shark: String::from("fake_storage_id"),
+            from_shark: fake_shark(),
             max_objects: Some(10),
+            assignment_sizing: None,
+            large_objects: None,
+            filter: None,
+            force: false,
         });
 
         let job_id = create_job(&test_server, job_payload);
         println!("{}", job_id);
     }
 
+    #[test]
+    fn one_active_job_per_shark() {
+        use diesel::ExpressionMethods;
+        use manager::jobs::jobs::dsl::{id, jobs as jobs_db};
+
+        unit_test_init();
+        let (_, test_server) = test_server_init();
+        let from_shark = fake_shark();
+
+        // Stand in for a job that is still evacuating the shark.
+        let active_id = Uuid::new_v4().to_string();
+        let active = JobDbEntry {
+            id: active_id.clone(),
+            action: JobActionDbEntry::Evacuate,
+            state: JobState::Init,
+            created_at: None,
+            started_at: None,
+            finished_at: None,
+            from_shark: Some(from_shark.clone()),
+        };
+        let conn = connect_db(REBALANCER_DB).expect("db connection");
+        diesel::insert_into(jobs_db)
+            .values(&active)
+            .execute(&conn)
+            .expect("insert active job");
+
+        let payload = |force| {
+            JobPayload::Evacuate(EvacuateJobPayload {
+                from_shark: from_shark.clone(),
+                max_objects: Some(10),
+                force,
+                ..Default::default()
+            })
+        };
+
+        let response = post_job(&test_server, &payload(false));
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = response.read_utf8_body().expect("response body");
+        assert!(body.contains(&active_id), "{}", body);
+
+        create_job(&test_server, payload(true));
+
+        diesel::delete(jobs_db.filter(id.eq(&active_id)))
+            .execute(&conn)
+            .expect("delete active job");
+    }
+
+    #[test]
+    fn assignment_callback_requires_running_job() {
+        unit_test_init();
+        let (_, test_server) = test_server_init();
+        let assignment = AgentAssignment {
+            uuid: Uuid::new_v4().to_string(),
+            stats: AgentAssignmentStats::new(0),
+            hash: None,
+            kind: Default::default(),
+            callback: None,
+            tasks: vec![],
+        };
+        let body = serde_json::to_string(&assignment).unwrap();
+
+        let post = |job_id: &str, body: &str| {
+            test_server
+                .client()
+                .post(
+                    format!(
+                        "http://localhost:8888/jobs/{}/assignments",
+                        job_id
+                    ),
+                    body.to_string(),
+                    mime::APPLICATION_JSON,
+                )
+                .perform()
+                .expect("post assignment")
+        };
+
+        let response = post(&Uuid::new_v4().to_string(), &body);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = post("not-a-job", &body);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = post(&Uuid::new_v4().to_string(), "{}");
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[test]
     fn job_dynamic_update() {
         unit_test_init();
@@ -864,8 +1498,12 @@ mod tests {
         // prone.  Some testing will need to be done with this deployed in an
         // actual environment.
         let job_payload = JobPayload::Evacuate(EvacuateJobPayload {
-            from_shark: String::from("fake_storage_id"),
+            from_shark: fake_shark(),
             max_objects: Some(10),
+            assignment_sizing: None,
+            large_objects: None,
+            filter: None,
+            force: false,
         });
         let job_id = create_job(&test_server, job_payload);
         let mut count = 0;
@@ -901,4 +1539,58 @@ mod tests {
 
         assert_eq!(res_body, expected_body);
     }
+
+    #[test]
+    fn agent_heartbeats() {
+        unit_test_init();
+        let (_, test_server) = test_server_init();
+        let storage_id = fake_shark();
+
+        let heartbeat = AgentHeartbeat {
+            storage_id: storage_id.clone(),
+            datacenter: String::from("dc1"),
+            version: String::from("0.1.0"),
+            active_assignments: 2,
+            available_bytes: Some(1 << 30),
+            interval_secs: 60,
+        };
+        let response = test_server
+            .client()
+            .post(
+                "http://localhost:8888/agents",
+                serde_json::to_string(&heartbeat).expect("heartbeat"),
+                mime::APPLICATION_JSON,
+            )
+            .perform()
+            .expect("post heartbeat");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = test_server
+            .client()
+            .post(
+                "http://localhost:8888/agents",
+                "{\"storage_id\": 1}",
+                mime::APPLICATION_JSON,
+            )
+            .perform()
+            .expect("post bad heartbeat");
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = test_server
+            .client()
+            .get("http://localhost:8888/agents")
+            .perform()
+            .expect("list agents");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.read_body().expect("response body");
+        let agents: Vec<fleet::RegisteredAgent> =
+            serde_json::from_slice(&body).expect("agent list");
+        let agent = agents
+            .into_iter()
+            .find(|a| a.heartbeat.storage_id == storage_id)
+            .expect("missing agent");
+        assert_eq!(agent.heartbeat, heartbeat);
+        assert!(!agent.stale);
+    }
 }
@@ -8,14 +8,18 @@
  * Copyright 2020 Joyent, Inc.
  */
 use lazy_static::lazy_static;
-use prometheus::{opts, register_counter_vec, register_gauge};
+use prometheus::{
+    opts, register_counter_vec, register_gauge, register_gauge_vec,
+};
 use rebalancer::metrics::{
-    self, counter_vec_inc_by, gauge_dec, gauge_inc, gauge_set, Metrics,
-    MetricsMap, ERROR_COUNT, OBJECT_COUNT, REQUEST_COUNT,
+    self, counter_vec_inc_by, gauge_dec, gauge_inc, gauge_set, gauge_vec_add,
+    gauge_vec_set, Metrics, MetricsMap, ERROR_COUNT, OBJECT_COUNT,
+    REQUEST_COUNT,
 };
 use std::collections::HashMap;
 use std::sync::Mutex;
 use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 // This is absoutely ridiculous.  Apparently, clippy will complain about the
 // fact that a bool does not need to be wrapped inside of a mutex because we
@@ -54,9 +58,30 @@ pub static ACTION_EVACUATE: &str = "evacuate";
 // why it is defined here instead of where the common labels are.
 pub static SKIP_COUNT: &str = "skip_count";
 
+// Objects whose datacenter spread could not be preserved, labeled by what
+// became of them: skipped under the strict policy, or moved anyway under the
+// best effort policy.
+pub static SPREAD_COUNT: &str = "spread_not_preserved";
+pub static SPREAD_SKIPPED: &str = "skipped";
+pub static SPREAD_REDUCED: &str = "reduced";
+
 // Gauge for tracking the current number of active metadata update threads.
 pub static MD_THREAD_GAUGE: &str = "md_thread_gauge";
 
+// Per-job metrics.  Each of these is a gauge vector labeled by the job uuid so
+// that an operator can watch the progress of an individual job rather than
+// the process-wide totals above.  Label values for a job are left in place
+// once the job completes so that its final values remain scrapable.
+pub static JOB_OBJECT_GAUGE: &str = "job_object_count";
+pub static JOB_SKIP_GAUGE: &str = "job_skip_count";
+pub static JOB_MD_THREAD_GAUGE: &str = "job_md_thread_gauge";
+pub static JOB_ASSIGNMENT_BACKLOG: &str = "job_assignment_backlog";
+pub static JOB_SPREAD_GAUGE: &str = "job_spread_not_preserved";
+
+// Process-wide metrics.
+pub static JOBS_RUNNING_GAUGE: &str = "jobs_running";
+pub static PROCESS_START_TIME: &str = "process_start_time_seconds";
+
 // This method may come in handy if it is necessary to add more metrics to
 // our collector.
 pub fn metrics_get() -> &'static Mutex<Option<MetricsMap>> {
@@ -89,15 +114,114 @@ pub fn metrics_init(cfg: metrics::ConfigMetrics) {
 
     metrics.insert(SKIP_COUNT, Metrics::MetricsCounterVec(skip_counter));
 
+    let spread_counter = register_counter_vec!(
+        opts!(
+            SPREAD_COUNT,
+            "Objects whose datacenter spread was not preserved."
+        )
+        .const_labels(labels.clone()),
+        &["outcome"]
+    )
+    .expect("failed to register spread_not_preserved counter");
+
+    metrics.insert(SPREAD_COUNT, Metrics::MetricsCounterVec(spread_counter));
+
     let md_thread_gauge = register_gauge!(opts!(
         MD_THREAD_GAUGE,
         "Number of currently active metadata threads."
     )
-    .const_labels(labels))
+    .const_labels(labels.clone()))
     .expect("failed to register metadata thread gauge");
 
     metrics.insert(MD_THREAD_GAUGE, Metrics::MetricsGauge(md_thread_gauge));
 
+    let job_object_gauge = register_gauge_vec!(
+        opts!(JOB_OBJECT_GAUGE, "Objects processed, per job.")
+            .const_labels(labels.clone()),
+        &["job"]
+    )
+    .expect("failed to register job object gauge");
+
+    metrics
+        .insert(JOB_OBJECT_GAUGE, Metrics::MetricsGaugeVec(job_object_gauge));
+
+    let job_skip_gauge = register_gauge_vec!(
+        opts!(JOB_SKIP_GAUGE, "Objects skipped, per job and reason.")
+            .const_labels(labels.clone()),
+        &["job", "reason"]
+    )
+    .expect("failed to register job skip gauge");
+
+    metrics.insert(JOB_SKIP_GAUGE, Metrics::MetricsGaugeVec(job_skip_gauge));
+
+    let job_md_thread_gauge = register_gauge_vec!(
+        opts!(
+            JOB_MD_THREAD_GAUGE,
+            "Number of currently active metadata threads, per job."
+        )
+        .const_labels(labels.clone()),
+        &["job"]
+    )
+    .expect("failed to register job metadata thread gauge");
+
+    metrics.insert(
+        JOB_MD_THREAD_GAUGE,
+        Metrics::MetricsGaugeVec(job_md_thread_gauge),
+    );
+
+    let job_assignment_backlog = register_gauge_vec!(
+        opts!(
+            JOB_ASSIGNMENT_BACKLOG,
+            "Assignments created but not yet fully processed, per job."
+        )
+        .const_labels(labels.clone()),
+        &["job"]
+    )
+    .expect("failed to register job assignment backlog gauge");
+
+    metrics.insert(
+        JOB_ASSIGNMENT_BACKLOG,
+        Metrics::MetricsGaugeVec(job_assignment_backlog),
+    );
+
+    let job_spread_gauge = register_gauge_vec!(
+        opts!(
+            JOB_SPREAD_GAUGE,
+            "Objects whose datacenter spread was not preserved, per job."
+        )
+        .const_labels(labels.clone()),
+        &["job", "outcome"]
+    )
+    .expect("failed to register job spread gauge");
+
+    metrics
+        .insert(JOB_SPREAD_GAUGE, Metrics::MetricsGaugeVec(job_spread_gauge));
+
+    let jobs_running = register_gauge!(opts!(
+        JOBS_RUNNING_GAUGE,
+        "Number of jobs currently running."
+    )
+    .const_labels(labels.clone()))
+    .expect("failed to register jobs running gauge");
+
+    metrics.insert(JOBS_RUNNING_GAUGE, Metrics::MetricsGauge(jobs_running));
+
+    // The prometheus crate's process collector only supports Linux, so
+    // record the start time ourselves.  Together with the scrape time this
+    // gives the process uptime, which makes restarts visible on a dashboard.
+    let start_time = register_gauge!(opts!(
+        PROCESS_START_TIME,
+        "Start time of the process since unix epoch in seconds."
+    )
+    .const_labels(labels))
+    .expect("failed to register process start time gauge");
+
+    if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+        start_time.set(now.as_secs() as f64);
+    }
+
+    metrics.insert(PROCESS_START_TIME, Metrics::MetricsGauge(start_time));
+
     // Take the fully formed set of metrics and store it globally.
     let mut global_metrics = METRICS.lock().unwrap();
     *global_metrics = Some(metrics);
@@ -157,6 +281,55 @@ fn metrics_vec_inc_by(key: &str, bucket: Option<&str>, val: usize) {
     counter_vec_inc_by(&metrics.expect("metrics"), key, bucket, val);
 }
 
+// Objects processed by the given job.
+pub fn metrics_job_object_inc_by(job: &str, val: usize) {
+    metrics_job_gauge_add(JOB_OBJECT_GAUGE, &[job], val as f64);
+}
+
+// Objects skipped by the given job, classified by reason.  As with the
+// process-wide skip counter, a "total" bucket is maintained alongside the
+// per-reason buckets.
+pub fn metrics_job_skip_inc_by(job: &str, reason: &str, val: usize) {
+    metrics_job_gauge_add(JOB_SKIP_GAUGE, &[job, "total"], val as f64);
+    metrics_job_gauge_add(JOB_SKIP_GAUGE, &[job, reason], val as f64);
+}
+
+// Objects whose datacenter spread could not be preserved, both process-wide
+// and for the given job.  `outcome` is SPREAD_SKIPPED or SPREAD_REDUCED.
+pub fn metrics_spread_inc(job: &str, outcome: &str) {
+    metrics_vec_inc_by(SPREAD_COUNT, Some(outcome), 1);
+    metrics_job_gauge_add(JOB_SPREAD_GAUGE, &[job, outcome], 1.0);
+}
+
+pub fn metrics_job_md_thread_inc(job: &str) {
+    metrics_job_gauge_add(JOB_MD_THREAD_GAUGE, &[job], 1.0);
+}
+
+pub fn metrics_job_md_thread_dec(job: &str) {
+    metrics_job_gauge_add(JOB_MD_THREAD_GAUGE, &[job], -1.0);
+}
+
+pub fn metrics_job_md_thread_set(job: &str, val: usize) {
+    metrics_job_gauge_set(JOB_MD_THREAD_GAUGE, &[job], val);
+}
+
+// Number of assignments in the given job's assignment cache.  These are the
+// assignments which have been created but have not yet had their metadata
+// updated (or been skipped).
+pub fn metrics_job_assignment_backlog_set(job: &str, val: usize) {
+    metrics_job_gauge_set(JOB_ASSIGNMENT_BACKLOG, &[job], val);
+}
+
+fn metrics_job_gauge_add(key: &str, label_values: &[&str], val: f64) {
+    let metrics = METRICS.lock().unwrap().clone();
+    gauge_vec_add(&metrics.expect("metrics"), key, label_values, val);
+}
+
+fn metrics_job_gauge_set(key: &str, label_values: &[&str], val: usize) {
+    let metrics = METRICS.lock().unwrap().clone();
+    gauge_vec_set(&metrics.expect("metrics"), key, label_values, val);
+}
+
 pub fn metrics_gauge_dec(key: &str) {
     let metrics = METRICS.lock().unwrap().clone();
     gauge_dec(&metrics.expect("metrics"), key);
@@ -0,0 +1,509 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// Job state notifications.
+//
+// Without these, operators only find out that a job has failed (or finished)
+// when they go and check on it.  When a job leaves the Running state for
+// Complete or Failed the manager hands a JobNotification to every sink
+// configured in the `notifications` section of the manager config:
+//
+// ```json
+// "notifications": {
+//     "webhooks": [
+//         { "url": "https://hooks.example.com/rebalancer", "timeout": 10 }
+//     ],
+//     "smtp": {
+//         "host": "localhost",
+//         "from": "rebalancer@example.com",
+//         "to": ["ops@example.com"]
+//     }
+// }
+// ```
+//
+// Webhooks receive the notification as a JSON POST body.  Email is only
+// available when the manager is built with the `smtp` feature.  A sink that
+// cannot be reached is logged and otherwise ignored; it never changes the
+// outcome of the job.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::jobs::JobState;
+use rebalancer::error::{Error, InternalError, InternalErrorCode};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+// How long a webhook has to respond before the notification is abandoned.
+static DEFAULT_WEBHOOK_TIMEOUT: u64 = 10;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct NotificationConfig {
+    pub webhooks: Vec<WebhookConfig>,
+
+    #[cfg(feature = "smtp")]
+    pub smtp: Option<SmtpConfig>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+
+    // Additional request headers, e.g. for an authorization token.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    // Request timeout in seconds.
+    #[serde(default = "WebhookConfig::default_timeout")]
+    pub timeout: u64,
+}
+
+impl WebhookConfig {
+    fn default_timeout() -> u64 {
+        DEFAULT_WEBHOOK_TIMEOUT
+    }
+}
+
+#[cfg(feature = "smtp")]
+#[derive(Deserialize, Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+
+    #[serde(default = "SmtpConfig::default_port")]
+    pub port: u16,
+
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+#[cfg(feature = "smtp")]
+impl SmtpConfig {
+    fn default_port() -> u16 {
+        25
+    }
+}
+
+/// The body of a notification, as POSTed to webhooks.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JobNotification {
+    pub job_id: String,
+    pub action: String,
+    pub domain_name: String,
+    pub previous_state: String,
+    pub state: String,
+    pub elapsed_secs: u64,
+
+    // Why the job failed.  Only present for failed jobs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl JobNotification {
+    pub fn subject(&self) -> String {
+        format!(
+            "rebalancer {} job {} {} ({})",
+            self.action, self.job_id, self.state, self.domain_name
+        )
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = format!(
+            "Job: {}\nAction: {}\nDomain: {}\nState: {} -> {}\n\
+             Elapsed: {} seconds\n",
+            self.job_id,
+            self.action,
+            self.domain_name,
+            self.previous_state,
+            self.state,
+            self.elapsed_secs
+        );
+        if let Some(error) = &self.error {
+            text.push_str(&format!("Error: {}\n", error));
+        }
+        text
+    }
+}
+
+// Only the transitions out of Running into one of the terminal states are
+// worth interrupting an operator for.
+pub fn should_notify(from: &JobState, to: &JobState) -> bool {
+    match (from, to) {
+        (JobState::Running, JobState::Complete)
+        | (JobState::Running, JobState::Failed) => true,
+        _ => false,
+    }
+}
+
+fn notification_error<S: Into<String>>(msg: S) -> Error {
+    InternalError::new(Some(InternalErrorCode::NotificationError), msg).into()
+}
+
+pub trait NotificationSink {
+    // A description of the sink for log messages.
+    fn describe(&self) -> String;
+
+    fn notify(&self, notification: &JobNotification) -> Result<(), Error>;
+}
+
+pub struct WebhookSink {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookSink {
+    pub fn new(config: &WebhookConfig) -> Result<Self, Error> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in config.headers.iter() {
+            let name =
+                HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+                    notification_error(format!("bad header {}: {}", name, e))
+                })?;
+            let value = HeaderValue::from_str(value).map_err(|e| {
+                notification_error(format!("bad value for {}: {}", name, e))
+            })?;
+            headers.insert(name, value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(config.timeout))
+            .build()?;
+
+        Ok(WebhookSink {
+            url: config.url.clone(),
+            client,
+        })
+    }
+}
+
+impl NotificationSink for WebhookSink {
+    fn describe(&self) -> String {
+        format!("webhook {}", self.url)
+    }
+
+    fn notify(&self, notification: &JobNotification) -> Result<(), Error> {
+        let response = self.client.post(&self.url).json(notification).send()?;
+
+        if !response.status().is_success() {
+            return Err(notification_error(format!(
+                "webhook responded with {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "smtp")]
+pub struct SmtpSink {
+    config: SmtpConfig,
+}
+
+#[cfg(feature = "smtp")]
+impl SmtpSink {
+    pub fn new(config: &SmtpConfig) -> Self {
+        SmtpSink {
+            config: config.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "smtp")]
+impl NotificationSink for SmtpSink {
+    fn describe(&self) -> String {
+        format!("smtp {}:{}", self.config.host, self.config.port)
+    }
+
+    fn notify(&self, notification: &JobNotification) -> Result<(), Error> {
+        use lettre::smtp::ClientSecurity;
+        use lettre::{SmtpClient, Transport};
+        use lettre_email::Email;
+
+        let mut builder = Email::builder()
+            .from(self.config.from.as_str())
+            .subject(notification.subject())
+            .text(notification.text());
+        for to in self.config.to.iter() {
+            builder = builder.to(to.as_str());
+        }
+        let email = builder
+            .build()
+            .map_err(|e| notification_error(format!("bad email: {}", e)))?;
+
+        let mut transport = SmtpClient::new(
+            (self.config.host.as_str(), self.config.port),
+            ClientSecurity::None,
+        )
+        .map_err(|e| notification_error(format!("smtp connect: {}", e)))?
+        .transport();
+
+        transport
+            .send(email.into())
+            .map(|_| ())
+            .map_err(|e| notification_error(format!("smtp send: {}", e)))
+    }
+}
+
+#[derive(Default)]
+pub struct Notifier {
+    sinks: Vec<Box<dyn NotificationSink + Send + Sync>>,
+}
+
+impl Notifier {
+    // Build a notifier with a sink for each entry in the configuration.
+    // Sinks that cannot be created are logged and skipped.
+    pub fn from_config(config: &NotificationConfig) -> Self {
+        let mut notifier = Notifier::default();
+
+        for webhook in config.webhooks.iter() {
+            match WebhookSink::new(webhook) {
+                Ok(sink) => notifier.add_sink(Box::new(sink)),
+                Err(e) => error!(
+                    "Ignoring notification webhook {}: {}",
+                    webhook.url, e
+                ),
+            }
+        }
+
+        #[cfg(feature = "smtp")]
+        {
+            if let Some(smtp) = &config.smtp {
+                notifier.add_sink(Box::new(SmtpSink::new(smtp)));
+            }
+        }
+
+        notifier
+    }
+
+    pub fn add_sink(&mut self, sink: Box<dyn NotificationSink + Send + Sync>) {
+        self.sinks.push(sink);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    // Deliver a notification to every sink.  Returns the number of sinks
+    // that accepted it.
+    pub fn notify(&self, notification: &JobNotification) -> usize {
+        let mut delivered = 0;
+
+        for sink in self.sinks.iter() {
+            match sink.notify(notification) {
+                Ok(()) => {
+                    debug!(
+                        "Sent notification for job {} to {}",
+                        notification.job_id,
+                        sink.describe()
+                    );
+                    delivered += 1;
+                }
+                Err(e) => error!(
+                    "Failed to send notification for job {} to {}: {}",
+                    notification.job_id,
+                    sink.describe(),
+                    e
+                ),
+            }
+        }
+
+        delivered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    fn notification(state: JobState, error: Option<&str>) -> JobNotification {
+        JobNotification {
+            job_id: String::from("c2d0a7c2-1d0e-4d44-a0a4-9b6a8d2b1d53"),
+            action: String::from("evacuate"),
+            domain_name: String::from("fake.joyent.us"),
+            previous_state: JobState::Running.to_string(),
+            state: state.to_string(),
+            elapsed_secs: 42,
+            error: error.map(String::from),
+        }
+    }
+
+    // A stub webhook receiver that answers a single request with `status`
+    // and hands the request headers and body back over the channel.
+    fn stub_receiver(
+        status: &'static str,
+    ) -> (String, mpsc::Receiver<(Vec<String>, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let addr = listener.local_addr().expect("local addr");
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("accept");
+            let mut reader = BufReader::new(stream);
+            let mut headers = vec![];
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read header");
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                let lower = line.to_lowercase();
+                if lower.starts_with("content-length:") {
+                    content_length = lower["content-length:".len()..]
+                        .trim()
+                        .parse()
+                        .expect("content length");
+                }
+                headers.push(line);
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).expect("read body");
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                status
+            )
+            .expect("write response");
+
+            tx.send((headers, String::from_utf8(body).expect("utf8")))
+                .expect("send request");
+        });
+
+        (format!("http://{}/notify", addr), rx)
+    }
+
+    #[test]
+    fn notify_transitions() {
+        assert!(should_notify(&JobState::Running, &JobState::Complete));
+        assert!(should_notify(&JobState::Running, &JobState::Failed));
+        assert!(!should_notify(&JobState::Setup, &JobState::Running));
+        assert!(!should_notify(&JobState::Setup, &JobState::Failed));
+        assert!(!should_notify(&JobState::Running, &JobState::Stopped));
+    }
+
+    #[test]
+    fn parse_notification_config() {
+        let config: NotificationConfig = serde_json::from_str(
+            r#"{
+                "webhooks": [
+                    { "url": "http://localhost/a" },
+                    {
+                        "url": "http://localhost/b",
+                        "headers": { "Authorization": "Bearer abc" },
+                        "timeout": 3
+                    }
+                ]
+            }"#,
+        )
+        .expect("parse config");
+
+        assert_eq!(config.webhooks.len(), 2);
+        assert_eq!(config.webhooks[0].timeout, DEFAULT_WEBHOOK_TIMEOUT);
+        assert!(config.webhooks[0].headers.is_empty());
+        assert_eq!(config.webhooks[1].timeout, 3);
+        assert_eq!(
+            config.webhooks[1].headers.get("Authorization"),
+            Some(&String::from("Bearer abc"))
+        );
+
+        let notifier = Notifier::from_config(&config);
+        assert!(!notifier.is_empty());
+        assert!(
+            Notifier::from_config(&NotificationConfig::default()).is_empty()
+        );
+    }
+
+    #[test]
+    fn notification_text() {
+        let n = notification(JobState::Failed, Some("storinfo unavailable"));
+        assert_eq!(
+            n.subject(),
+            "rebalancer evacuate job c2d0a7c2-1d0e-4d44-a0a4-9b6a8d2b1d53 \
+             failed (fake.joyent.us)"
+        );
+        assert!(n.text().contains("State: running -> failed\n"));
+        assert!(n.text().contains("Error: storinfo unavailable\n"));
+
+        let n = notification(JobState::Complete, None);
+        assert!(!n.text().contains("Error:"));
+    }
+
+    #[test]
+    fn webhook_posts_notification() {
+        let (url, rx) = stub_receiver("200 OK");
+        let mut headers = HashMap::new();
+        headers.insert(String::from("X-Rebalancer-Token"), String::from("abc"));
+        let sink = WebhookSink::new(&WebhookConfig {
+            url,
+            headers,
+            timeout: 5,
+        })
+        .expect("webhook sink");
+
+        let mut notifier = Notifier::default();
+        notifier.add_sink(Box::new(sink));
+
+        let sent = notification(JobState::Failed, Some("boom"));
+        assert_eq!(notifier.notify(&sent), 1);
+
+        let (headers, body) = rx.recv().expect("request");
+        assert_eq!(headers[0], "POST /notify HTTP/1.1");
+        assert!(headers
+            .iter()
+            .any(|h| h.to_lowercase() == "x-rebalancer-token: abc"));
+
+        let received: JobNotification =
+            serde_json::from_str(&body).expect("json body");
+        assert_eq!(received, sent);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&body).expect("json")
+                ["state"],
+            "failed"
+        );
+    }
+
+    #[test]
+    fn webhook_error_status() {
+        let (url, rx) = stub_receiver("500 Internal Server Error");
+        let sink = WebhookSink::new(&WebhookConfig {
+            url,
+            headers: HashMap::new(),
+            timeout: 5,
+        })
+        .expect("webhook sink");
+
+        let n = notification(JobState::Complete, None);
+        assert!(sink.notify(&n).is_err());
+        rx.recv().expect("request");
+
+        // A failed sink does not count as delivered.
+        let mut notifier = Notifier::default();
+        notifier.add_sink(Box::new(
+            WebhookSink::new(&WebhookConfig {
+                url: String::from("http://127.0.0.1:1/notify"),
+                headers: HashMap::new(),
+                timeout: 1,
+            })
+            .expect("webhook sink"),
+        ));
+        assert_eq!(notifier.notify(&n), 0);
+    }
+}
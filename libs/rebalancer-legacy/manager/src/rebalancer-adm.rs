@@ -8,17 +8,57 @@
  * Copyright 2020 Joyent, Inc.
  */
 
-use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use chrono::{DateTime, SecondsFormat, Utc};
+use clap::{App, AppSettings, Arg, ArgGroup, ArgMatches, SubCommand};
 use hyper::HeaderMap;
-use manager::jobs::{EvacuateJobPayload, JobPayload};
+use manager::config::{AssignmentSizing, DEFAULT_BYTE_BUDGET_MAX_TASKS};
+use manager::fleet::RegisteredAgent;
+use manager::jobs::evacuate::EvacuateJobUpdateMessage;
+use manager::jobs::status::{JobStatus, JobStatusConfig, JobStatusResults};
+use manager::jobs::{EvacuateJobPayload, JobDbEntry, JobPayload, JobState};
 use reqwest;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::fs::File;
 use std::io::Write;
 use std::result::Result;
+use std::str::FromStr;
+use std::time::Duration;
 
 pub static JOBS_URL: &str = "http://localhost/jobs";
+pub static AGENTS_URL: &str = "http://localhost/agents";
 pub static VERSION: &str = "0.1.0";
 
+// Seconds between status requests of `job watch`, unless told otherwise.
+static DEFAULT_WATCH_INTERVAL: &str = "10";
+
+// How the results of a command are displayed.  Tables are meant for people,
+// JSON (exactly as the manager returned it) for scripts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OutputMode {
+    Table,
+    Json,
+}
+
+impl OutputMode {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        match matches.value_of("output") {
+            Some("json") => OutputMode::Json,
+            _ => OutputMode::Table,
+        }
+    }
+}
+
+fn output_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("output")
+        .short("o")
+        .long("output")
+        .takes_value(true)
+        .possible_values(&["table", "json"])
+        .default_value("table")
+        .help("Output format")
+}
+
 fn output_common(response_headers: HeaderMap, message: String) {
     let version = match response_headers.get("server") {
         Some(v) => v.to_str().unwrap_or("unknown"),
@@ -30,25 +70,48 @@ fn output_common(response_headers: HeaderMap, message: String) {
     println!("{}", message);
 }
 
+// Create a client without a timeout.  We need to make a 'count()' query
+// to get accurate numbers for job status, and exports of large jobs are
+// streamed for as long as they take.  No sense in timing out.  If the user
+// doesn't want to wait, ctrl-c is always an option.
+fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::ClientBuilder::new()
+        .timeout(None)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+// Flag failure if we get a status code of anything other than 200, including
+// the manager's explanation (if any) in the error.
+fn check_response(
+    mut response: reqwest::Response,
+    what: &str,
+) -> Result<reqwest::Response, String> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    match response.text() {
+        Ok(ref body) if !body.trim().is_empty() => {
+            Err(format!("{}: {}: {}", what, status, body.trim()))
+        }
+        _ => Err(format!("{}: {}", what, status)),
+    }
+}
+
 fn post_common<T>(url: &str, body: T) -> Result<(), String>
 where
     T: Into<reqwest::Body>,
 {
-    let client = reqwest::ClientBuilder::new()
-        .timeout(None)
-        .build()
-        .map_err(|e| e.to_string())?;
+    let client = http_client()?;
 
     // Send the request.
-    let mut response = match client.post(url).body(body).send() {
+    let response = match client.post(url).body(body).send() {
         Ok(resp) => resp,
         Err(e) => return Err(format!("Failed to post job: {}", &e)),
     };
-
-    // Flag failure if we get a status code of anything other than 200.
-    if !response.status().is_success() {
-        return Err(format!("Server response: {}", response.status()));
-    }
+    let mut response = check_response(response, "Server response")?;
 
     let headers = response.headers().clone();
 
@@ -64,63 +127,394 @@ where
 }
 
 // Common function used in order to get a list of jobs, or get specific job
-// information.  The contents of the response are evaluated and printed by
-// the caller.
-fn get_common(url: &str) -> Result<(), String> {
-    // Create a client without a timeout.  We need to make a 'count()' query
-    // to get accurate numbers for job status.  This can take a while and no
-    // sense in timing out.  If the user doesn't want to wait, ctrl-c is
-    // always an option.
-    let client = reqwest::ClientBuilder::new()
-        .timeout(None)
-        .build()
-        .map_err(|e| e.to_string())?;
+// information.  The response body is returned as is, to be printed in JSON
+// mode, along with the headers of the response.
+fn get_common(url: &str) -> Result<(HeaderMap, Value), String> {
+    let client = http_client()?;
 
-    let mut response = client
+    let response = client
         .get(url)
         .send()
         .map_err(|e| format!("Request failed: {}", &e))?;
+    let mut response = check_response(response, "Failed to get job")?;
+
+    let headers = response.headers().clone();
 
-    // Flag failure if we get a status code of anything other than 200.
-    if !response.status().is_success() {
-        return Err(format!("Failed to get job: {}", response.status()));
+    match response.json() {
+        Ok(v) => Ok((headers, v)),
+        Err(e) => Err(format!("Failed to parse response body: {}", &e)),
     }
+}
 
-    let headers = response.headers().clone();
+fn parse_value<T: DeserializeOwned>(value: Value) -> Result<T, String> {
+    serde_json::from_value(value)
+        .map_err(|e| format!("Unexpected response from the manager: {}", e))
+}
 
-    let v: Value = match response.json() {
-        Ok(v) => v,
-        Err(e) => return Err(format!("Failed to parse response body: {}", &e)),
+fn to_json(value: &Value, pretty: bool) -> Result<String, String> {
+    let json = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
     };
 
-    let result = match serde_json::to_string_pretty(&v) {
-        Ok(s) => s,
-        Err(e) => return Err(format!("Failed to deserialize: {}", &e)),
+    json.map_err(|e| format!("Failed to deserialize: {}", &e))
+}
+
+fn parse_number<T: FromStr>(matches: &ArgMatches, name: &str) -> Option<T> {
+    // Clap has already checked these with `number_validator`.
+    matches.value_of(name).and_then(|v| v.parse().ok())
+}
+
+fn number_validator(value: String) -> Result<(), String> {
+    value
+        .parse::<u64>()
+        .map(|_| ())
+        .map_err(|e| format!("Numeric value required: {}", e))
+}
+
+// Render rows as left aligned columns, each as wide as its widest value,
+// beneath a header row.
+fn render_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = std::cmp::max(*width, cell.chars().count());
+        }
+    }
+
+    let render_row = |cells: Vec<&str>| -> String {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<1$}", cell, width))
+            .collect();
+        format!("{}\n", line.join("  ").trim_end())
     };
 
-    output_common(headers, result);
-    Ok(())
+    let mut out = render_row(header.to_vec());
+    for row in rows {
+        out.push_str(&render_row(row.iter().map(String::as_str).collect()));
+    }
+    out
+}
+
+fn format_time(time: &Option<DateTime<Utc>>) -> String {
+    match time {
+        Some(t) => t.to_rfc3339_opts(SecondsFormat::Secs, true),
+        None => String::from("-"),
+    }
+}
+
+// Object counts with the total first, then the rest in name order.
+fn status_counts(status: &JobStatus) -> Vec<(String, i64)> {
+    let JobStatusResults::Evacuate(results) = &status.results;
+    let mut counts: Vec<(String, i64)> =
+        results.iter().map(|(k, v)| (k.clone(), *v)).collect();
+    counts.sort_by(|a, b| (a.0 != "Total", &a.0).cmp(&(b.0 != "Total", &b.0)));
+    counts
+}
+
+fn render_job_list(jobs: &[JobDbEntry]) -> String {
+    let rows: Vec<Vec<String>> = jobs
+        .iter()
+        .map(|job| {
+            vec![
+                job.id.clone(),
+                job.action.to_string(),
+                job.state.to_string(),
+                format_time(&job.created_at),
+                format_time(&job.started_at),
+                format_time(&job.finished_at),
+            ]
+        })
+        .collect();
+
+    render_table(
+        &["ID", "ACTION", "STATE", "CREATED", "STARTED", "FINISHED"],
+        &rows,
+    )
+}
+
+fn render_agent_list(agents: &[RegisteredAgent]) -> String {
+    let rows: Vec<Vec<String>> = agents
+        .iter()
+        .map(|agent| {
+            let heartbeat = &agent.heartbeat;
+            vec![
+                heartbeat.storage_id.clone(),
+                heartbeat.datacenter.clone(),
+                heartbeat.version.clone(),
+                heartbeat.active_assignments.to_string(),
+                heartbeat
+                    .available_bytes
+                    .map(|b| (b / (1024 * 1024)).to_string())
+                    .unwrap_or_else(|| String::from("-")),
+                format_time(&Some(agent.last_seen)),
+                String::from(if agent.stale { "stale" } else { "ok" }),
+            ]
+        })
+        .collect();
+
+    render_table(
+        &[
+            "STORAGE_ID",
+            "DATACENTER",
+            "VERSION",
+            "ASSIGNMENTS",
+            "AVAIL_MB",
+            "LAST_SEEN",
+            "STATE",
+        ],
+        &rows,
+    )
+}
+
+fn render_job_status(uuid: &str, status: &JobStatus) -> String {
+    let JobStatusConfig::Evacuate(config) = &status.config;
+    let mut fields = vec![
+        vec![String::from("id"), uuid.to_string()],
+        vec![String::from("action"), String::from("evacuate")],
+        vec![
+            String::from("from_shark"),
+            config.from_shark.manta_storage_id.clone(),
+        ],
+        vec![String::from("state"), status.state.to_string()],
+        vec![String::from("created_at"), format_time(&status.created_at)],
+        vec![String::from("started_at"), format_time(&status.started_at)],
+        vec![
+            String::from("finished_at"),
+            format_time(&status.finished_at),
+        ],
+    ];
+    if let Some(filter) = &config.filter {
+        fields.push(vec![
+            String::from("filter"),
+            serde_json::to_string(filter).expect("filter to JSON"),
+        ]);
+    }
+    let counts: Vec<Vec<String>> = status_counts(status)
+        .into_iter()
+        .map(|(name, count)| vec![name, count.to_string()])
+        .collect();
+
+    let mut out = render_table(&["FIELD", "VALUE"], &fields);
+    out.push('\n');
+    out.push_str(&render_table(&["OBJECTS", "COUNT"], &counts));
+
+    if !status.agents.is_empty() {
+        let agents: Vec<Vec<String>> = status
+            .agents
+            .iter()
+            .map(|agent| {
+                vec![
+                    agent.storage_id.clone(),
+                    agent.state.to_string(),
+                    format_time(&agent.last_progress),
+                    agent.stalled_assignments.to_string(),
+                ]
+            })
+            .collect();
+        out.push('\n');
+        out.push_str(&render_table(
+            &["AGENT", "STATE", "LAST PROGRESS", "STALLED"],
+            &agents,
+        ));
+    }
+
+    out
+}
+
+// A single line summary of a job's progress, for `job watch`.
+fn render_job_progress(status: &JobStatus) -> String {
+    let counts: Vec<String> = status_counts(status)
+        .into_iter()
+        .map(|(name, count)| {
+            format!("{}={}", name.to_lowercase().replace(' ', "_"), count)
+        })
+        .collect();
+
+    format!("{}  {}", status.state, counts.join(" "))
+}
+
+fn is_finished(state: &JobState) -> bool {
+    matches!(
+        state,
+        JobState::Complete | JobState::Failed | JobState::Stopped
+    )
 }
 
 // Given a spcific job id, send a request to the manager for more detailed
 // information.
 fn job_get(matches: &ArgMatches) -> Result<(), String> {
     let uuid = matches.value_of("uuid").expect("get uuid");
+    let output = OutputMode::from_matches(matches);
     let url = format!("{}/{}", JOBS_URL, uuid);
 
-    // Add a delayed notification for job_get on a large job.
+    // Add a delayed notification for job_get on a large job.  This goes to
+    // stderr so that it does not end up in JSON output.
     std::thread::spawn(|| {
         std::thread::sleep(std::time::Duration::from_secs(3));
-        println!("Getting job status counts.  This may take some time...");
+        eprintln!("Getting job status counts.  This may take some time...");
 
         loop {
             std::thread::sleep(std::time::Duration::from_secs(2));
-            print!(".");
-            std::io::stdout().flush().expect("internal flush error");
+            eprint!(".");
+            std::io::stderr().flush().expect("internal flush error");
         }
     });
 
-    get_common(&url)
+    let (headers, value) = get_common(&url)?;
+    match output {
+        OutputMode::Json => println!("{}", to_json(&value, true)?),
+        OutputMode::Table => {
+            let status: JobStatus = parse_value(value)?;
+            output_common(headers, render_job_status(uuid, &status));
+        }
+    }
+
+    Ok(())
+}
+
+// List jobs, passing any filter or pagination options through to the
+// manager as query parameters.
+fn job_list(matches: &ArgMatches) -> Result<(), String> {
+    let output = OutputMode::from_matches(matches);
+    let params: Vec<(&str, &str)> = ["state", "action", "limit", "marker"]
+        .iter()
+        .filter_map(|name| matches.value_of(name).map(|v| (*name, v)))
+        .collect();
+
+    let url = reqwest::Url::parse_with_params(JOBS_URL, &params)
+        .map_err(|e| format!("Invalid job list options: {}", e))?;
+
+    let (headers, value) = get_common(url.as_str())?;
+    match output {
+        OutputMode::Json => println!("{}", to_json(&value, true)?),
+        OutputMode::Table => {
+            let jobs: Vec<JobDbEntry> = parse_value(value)?;
+            output_common(headers, render_job_list(&jobs));
+        }
+    }
+
+    Ok(())
+}
+
+// Poll the status of a job, printing it each time it changes, until the job
+// has finished.  In JSON mode each status is printed on a line of its own.
+fn job_watch(matches: &ArgMatches) -> Result<(), String> {
+    let uuid = matches.value_of("uuid").expect("watch uuid");
+    let output = OutputMode::from_matches(matches);
+    let interval: u64 = parse_number(matches, "interval")
+        .unwrap_or_else(|| DEFAULT_WATCH_INTERVAL.parse().unwrap());
+    let url = format!("{}/{}", JOBS_URL, uuid);
+    let mut last: Option<String> = None;
+
+    loop {
+        let (_, value) = get_common(&url)?;
+        let status: JobStatus = parse_value(value.clone())?;
+
+        let line = match output {
+            OutputMode::Json => to_json(&value, false)?,
+            OutputMode::Table => render_job_progress(&status),
+        };
+
+        if last.as_ref() != Some(&line) {
+            match output {
+                OutputMode::Json => println!("{}", line),
+                OutputMode::Table => println!(
+                    "{}  {}",
+                    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+                    line
+                ),
+            }
+            std::io::stdout().flush().map_err(|e| e.to_string())?;
+            last = Some(line);
+        }
+
+        if is_finished(&status.state) {
+            return Ok(());
+        }
+
+        std::thread::sleep(Duration::from_secs(interval));
+    }
+}
+
+// Change a tunable of a running job.  Only one setting can be changed per
+// request.
+fn job_update(matches: &ArgMatches) -> Result<(), String> {
+    let uuid = matches.value_of("uuid").expect("update uuid");
+    let url = format!("{}/{}", JOBS_URL, uuid);
+
+    let update = if let Some(n) = parse_number(matches, "metadata_threads") {
+        EvacuateJobUpdateMessage::SetMetadataThreads(n)
+    } else if let Some(n) = parse_number(matches, "destination_concurrency") {
+        EvacuateJobUpdateMessage::SetDestinationConcurrency(n)
+    } else if let Some(n) = parse_number(matches, "byte_rate_limit") {
+        EvacuateJobUpdateMessage::SetByteRateLimit(n)
+    } else if let Some(n) = parse_number(matches, "max_assignment_bytes") {
+        EvacuateJobUpdateMessage::SetMaxAssignmentSize(
+            AssignmentSizing::ByteBudget {
+                max_bytes: n,
+                max_tasks: DEFAULT_BYTE_BUDGET_MAX_TASKS,
+            },
+        )
+    } else {
+        // Clap requires one of the settings.
+        unreachable!()
+    };
+
+    // Spare a round trip for a value the manager would refuse.
+    update.validate()?;
+
+    let client = http_client()?;
+    let response = client
+        .put(&url)
+        .json(&update)
+        .send()
+        .map_err(|e| format!("Failed to update job: {}", &e))?;
+    let response = check_response(response, "Failed to update job")?;
+
+    output_common(response.headers().clone(), format!("Updated job {}", uuid));
+    Ok(())
+}
+
+// Write a job's per-object results to a file, or to stdout.  The report is
+// streamed, so exporting a large job does not hold it all in memory.
+fn job_export(matches: &ArgMatches) -> Result<(), String> {
+    let uuid = matches.value_of("uuid").expect("export uuid");
+    let format = matches.value_of("format").unwrap_or("ndjson");
+
+    let mut params = vec![("format", format)];
+    if let Some(status) = matches.value_of("status") {
+        params.push(("status", status));
+    }
+
+    let url = reqwest::Url::parse_with_params(
+        &format!("{}/{}/export", JOBS_URL, uuid),
+        &params,
+    )
+    .map_err(|e| format!("Invalid export options: {}", e))?;
+
+    let client = http_client()?;
+    let response = client
+        .get(url.as_str())
+        .send()
+        .map_err(|e| format!("Request failed: {}", &e))?;
+    let mut response = check_response(response, "Failed to export job")?;
+
+    let written = match matches.value_of("file") {
+        Some(path) => {
+            let mut file = File::create(path)
+                .map_err(|e| format!("Failed to create {}: {}", path, e))?;
+            response.copy_to(&mut file)
+        }
+        None => response.copy_to(&mut std::io::stdout()),
+    };
+
+    written
+        .map(|_| ())
+        .map_err(|e| format!("Failed to read export: {}", e))
 }
 
 fn job_retry(matches: &ArgMatches) -> Result<(), String> {
@@ -160,10 +554,32 @@ fn job_create_evacuate(matches: &ArgMatches) -> Result<(), String> {
         },
     };
 
+    // If a byte budget is given, size this job's assignments by the bytes
+    // they contain rather than by the manager's configured policy.
+    let assignment_sizing = match matches.value_of("max_assignment_bytes") {
+        None => None,
+        Some(b) => match b.parse::<u64>() {
+            Ok(max_bytes) => Some(AssignmentSizing::ByteBudget {
+                max_bytes,
+                max_tasks: DEFAULT_BYTE_BUDGET_MAX_TASKS,
+            }),
+            Err(e) => {
+                return Err(format!(
+                    "Numeric value required for max_assignment_bytes: {}",
+                    e
+                ));
+            }
+        },
+    };
+
     // Form the payload of the request.
     let job_payload = JobPayload::Evacuate(EvacuateJobPayload {
         from_shark: shark.to_owned(),
         max_objects,
+        assignment_sizing,
+        large_objects: None,
+        filter: None,
+        force: matches.is_present("force"),
     });
 
     // Serialize it.
@@ -173,14 +589,39 @@ fn job_create_evacuate(matches: &ArgMatches) -> Result<(), String> {
     post_common(JOBS_URL, payload)
 }
 
-// The `job' subcommand currently requires one of three different primary
+// List the agents that send heartbeats to the manager.
+fn agent_list(matches: &ArgMatches) -> Result<(), String> {
+    let output = OutputMode::from_matches(matches);
+    let (headers, value) = get_common(AGENTS_URL)?;
+    match output {
+        OutputMode::Json => println!("{}", to_json(&value, true)?),
+        OutputMode::Table => {
+            let agents: Vec<RegisteredAgent> = parse_value(value)?;
+            output_common(headers, render_agent_list(&agents));
+        }
+    }
+
+    Ok(())
+}
+
+fn process_subcmd_agent(agent_matches: &ArgMatches) -> Result<(), String> {
+    match agent_matches.subcommand() {
+        ("list", Some(list_matches)) => agent_list(list_matches),
+        _ => unreachable!(),
+    }
+}
+
+// The `job' subcommand requires one of several different primary
 // arguments.  While there are other arguments that might accompany the
 // ones listed below, those are parsed separately depending on which of
 // the pimary arguments are supplied.
 fn process_subcmd_job(job_matches: &ArgMatches) -> Result<(), String> {
     match job_matches.subcommand() {
         ("get", Some(get_matches)) => job_get(get_matches),
-        ("list", Some(_)) => get_common(JOBS_URL),
+        ("list", Some(list_matches)) => job_list(list_matches),
+        ("watch", Some(watch_matches)) => job_watch(watch_matches),
+        ("update", Some(update_matches)) => job_update(update_matches),
+        ("export", Some(export_matches)) => job_export(export_matches),
         ("retry", Some(retry_matches)) => job_retry(retry_matches),
         ("create", Some(create_matches)) => job_create(create_matches),
         _ => unreachable!(),
@@ -204,7 +645,17 @@ fn main() -> Result<(), String> {
                 .long("max_objects")
                 .takes_value(true)
                 .help("Maximum number of objects allowed in the job"),
-        );
+        )
+        .arg(
+            Arg::with_name("max_assignment_bytes")
+                .short("b")
+                .long("max_assignment_bytes")
+                .takes_value(true)
+                .help("Maximum number of object bytes in each assignment"),
+        )
+        .arg(Arg::with_name("force").long("force").help(
+            "Create the job even if the shark is already being evacuated",
+        ));
 
     let matches = App::new("rebalancer-adm")
         .setting(AppSettings::SubcommandRequiredElseHelp)
@@ -217,12 +668,129 @@ fn main() -> Result<(), String> {
                 // Get subcommand
                 .subcommand(
                     App::new("get")
+                        .visible_alias("status")
                         .about("Get information on a specific job")
                         .arg(
                             Arg::with_name("uuid")
                                 .takes_value(true)
                                 .required(true)
                                 .help("Uuid of a job"),
+                        )
+                        .arg(output_arg()),
+                )
+                // Watch subcommand
+                .subcommand(
+                    App::new("watch")
+                        .about("Follow the progress of a job until it finishes")
+                        .arg(
+                            Arg::with_name("uuid")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Uuid of a job"),
+                        )
+                        .arg(
+                            Arg::with_name("interval")
+                                .short("i")
+                                .long("interval")
+                                .takes_value(true)
+                                .default_value(DEFAULT_WATCH_INTERVAL)
+                                .validator(number_validator)
+                                .help("Seconds between status requests"),
+                        )
+                        .arg(output_arg()),
+                )
+                // Update subcommand
+                .subcommand(
+                    App::new("update")
+                        .about("Change a setting of a running job")
+                        .arg(
+                            Arg::with_name("uuid")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Uuid of a running job"),
+                        )
+                        .arg(
+                            Arg::with_name("metadata_threads")
+                                .long("metadata_threads")
+                                .takes_value(true)
+                                .validator(number_validator)
+                                .help("Number of metadata update threads"),
+                        )
+                        .arg(
+                            Arg::with_name("destination_concurrency")
+                                .long("destination_concurrency")
+                                .takes_value(true)
+                                .validator(number_validator)
+                                .help(
+                                    "Number of objects each destination \
+                                     agent downloads in parallel",
+                                ),
+                        )
+                        .arg(
+                            Arg::with_name("byte_rate_limit")
+                                .long("byte_rate_limit")
+                                .takes_value(true)
+                                .validator(number_validator)
+                                .help(
+                                    "Download rate of each destination \
+                                     agent in bytes/sec, 0 for no limit",
+                                ),
+                        )
+                        .arg(
+                            Arg::with_name("max_assignment_bytes")
+                                .long("max_assignment_bytes")
+                                .takes_value(true)
+                                .validator(number_validator)
+                                .help(
+                                    "Maximum number of object bytes in \
+                                     each new assignment",
+                                ),
+                        )
+                        .group(
+                            ArgGroup::with_name("setting")
+                                .args(&[
+                                    "metadata_threads",
+                                    "destination_concurrency",
+                                    "byte_rate_limit",
+                                    "max_assignment_bytes",
+                                ])
+                                .required(true),
+                        ),
+                )
+                // Export subcommand
+                .subcommand(
+                    App::new("export")
+                        .about("Export the per-object results of a job")
+                        .arg(
+                            Arg::with_name("uuid")
+                                .takes_value(true)
+                                .required(true)
+                                .help("Uuid of a job"),
+                        )
+                        .arg(
+                            Arg::with_name("format")
+                                .short("f")
+                                .long("format")
+                                .takes_value(true)
+                                .possible_values(&["csv", "ndjson"])
+                                .default_value("ndjson")
+                                .help("Report format"),
+                        )
+                        .arg(
+                            Arg::with_name("status")
+                                .short("s")
+                                .long("status")
+                                .takes_value(true)
+                                .help(
+                                    "Only export the objects in this \
+                                     status, e.g. skipped",
+                                ),
+                        )
+                        .arg(
+                            Arg::with_name("file")
+                                .long("file")
+                                .takes_value(true)
+                                .help("Write the report here, not to stdout"),
                         ),
                 )
                 // Retry subcommand
@@ -238,7 +806,40 @@ fn main() -> Result<(), String> {
                 )
                 // List subcommand
                 .subcommand(
-                    App::new("list").about("List all known rebalancer jobs"),
+                    App::new("list")
+                        .about("List all known rebalancer jobs")
+                        .arg(
+                            Arg::with_name("state")
+                                .short("s")
+                                .long("state")
+                                .takes_value(true)
+                                .help("Only list jobs in this state"),
+                        )
+                        .arg(
+                            Arg::with_name("action")
+                                .short("a")
+                                .long("action")
+                                .takes_value(true)
+                                .help("Only list jobs taking this action"),
+                        )
+                        .arg(
+                            Arg::with_name("limit")
+                                .short("l")
+                                .long("limit")
+                                .takes_value(true)
+                                .help("Maximum number of jobs to list"),
+                        )
+                        .arg(
+                            Arg::with_name("marker")
+                                .short("m")
+                                .long("marker")
+                                .takes_value(true)
+                                .help(
+                                    "List the jobs after this job uuid, \
+                                     the last one of the previous page",
+                                ),
+                        )
+                        .arg(output_arg()),
                 )
                 // Create subcommand
                 .subcommand(
@@ -249,10 +850,21 @@ fn main() -> Result<(), String> {
                         .subcommand(evacuate_subcommand),
                 ),
         )
+        .subcommand(
+            App::new("agent")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .about("Agent operations")
+                .subcommand(
+                    App::new("list")
+                        .about("List the agents that report to the manager")
+                        .arg(output_arg()),
+                ),
+        )
         .get_matches();
 
     match matches.subcommand() {
         ("job", Some(job_matches)) => process_subcmd_job(job_matches),
+        ("agent", Some(agent_matches)) => process_subcmd_agent(agent_matches),
         _ => unreachable!(),
     }
 }
@@ -277,9 +889,10 @@ mod rebalancer_adm_tests {
                 -V, --version    Prints version information
 
             SUBCOMMANDS:
-                help    Prints this message or the help of the given \
+                agent    Agent operations
+                help     Prints this message or the help of the given \
                 subcommand(s)
-                job     Job operations
+                job      Job operations
             "
         );
 
@@ -301,7 +914,7 @@ mod rebalancer_adm_tests {
             valid in this context
 
             USAGE:
-                rebalancer-adm job list
+                rebalancer-adm job list [OPTIONS]
             "
         );
 
@@ -322,7 +935,7 @@ mod rebalancer_adm_tests {
                 <uuid>
 
             USAGE:
-                rebalancer-adm job get <uuid>
+                rebalancer-adm job get [OPTIONS] <uuid>
             "
         );
 
@@ -384,4 +997,152 @@ mod rebalancer_adm_tests {
             .contains(err_msg)
             .unwrap();
     }
+
+    #[test]
+    fn job_list_invalid_output() {
+        assert_cli::Assert::cargo_binary("rebalancer-adm")
+            .with_args(&["job", "list", "--output", "yaml"])
+            .fails()
+            .and()
+            .stderr()
+            .contains("'yaml' isn't a valid value for '--output <output>'")
+            .unwrap();
+    }
+
+    #[test]
+    fn job_update_no_setting() {
+        let err_msg = indoc!(
+            "
+            error: The following required arguments were not provided:
+            "
+        );
+
+        assert_cli::Assert::cargo_binary("rebalancer-adm")
+            .with_args(&["job", "update", "a-job-uuid"])
+            .fails()
+            .and()
+            .stderr()
+            .contains(err_msg)
+            .unwrap();
+
+        assert_cli::Assert::cargo_binary("rebalancer-adm")
+            .with_args(&[
+                "job",
+                "update",
+                "a-job-uuid",
+                "--metadata_threads",
+                "2",
+                "--byte_rate_limit",
+                "0",
+            ])
+            .fails()
+            .and()
+            .stderr()
+            .contains("cannot be used with")
+            .unwrap();
+    }
+
+    #[test]
+    fn job_update_invalid_value() {
+        assert_cli::Assert::cargo_binary("rebalancer-adm")
+            .with_args(&[
+                "job",
+                "update",
+                "a-job-uuid",
+                "--metadata_threads",
+                "0",
+            ])
+            .fails()
+            .and()
+            .stderr()
+            .contains("Cannot set metadata update threads below 1")
+            .unwrap();
+    }
+
+    #[test]
+    fn render_table_test() {
+        let rows = vec![
+            vec![String::from("a"), String::from("running")],
+            vec![String::from("bbbbbb"), String::from("complete")],
+        ];
+        let expected = indoc!(
+            "
+            ID      STATE
+            a       running
+            bbbbbb  complete
+            "
+        );
+
+        assert_eq!(render_table(&["ID", "STATE"], &rows), expected);
+    }
+
+    #[test]
+    fn render_agent_list_test() {
+        let agents: Vec<RegisteredAgent> =
+            serde_json::from_value(serde_json::json!([
+                {
+                    "storage_id": "1.stor",
+                    "datacenter": "dc1",
+                    "version": "0.1.0",
+                    "active_assignments": 2,
+                    "available_bytes": 3221225472u64,
+                    "interval_secs": 60,
+                    "last_seen": "2026-10-16T12:00:00Z",
+                    "stale": false
+                },
+                {
+                    "storage_id": "2.stor",
+                    "datacenter": "dc2",
+                    "version": "0.1.0",
+                    "active_assignments": 0,
+                    "available_bytes": null,
+                    "interval_secs": 60,
+                    "last_seen": "2026-10-16T11:00:00Z",
+                    "stale": true
+                }
+            ]))
+            .expect("agent list");
+
+        let expected = indoc!(
+            "
+            STORAGE_ID  DATACENTER  VERSION  ASSIGNMENTS  AVAIL_MB  LAST_SEEN             STATE
+            1.stor      dc1         0.1.0    2            3072      2026-10-16T12:00:00Z  ok
+            2.stor      dc2         0.1.0    0            -         2026-10-16T11:00:00Z  stale
+            "
+        );
+
+        assert_eq!(render_agent_list(&agents), expected);
+    }
+
+    #[test]
+    fn status_counts_test() {
+        let status: JobStatus = serde_json::from_value(serde_json::json!({
+            "config": {
+                "action": "Evacuate",
+                "from_shark": {
+                    "manta_storage_id": "1.stor",
+                    "datacenter": "dc"
+                }
+            },
+            "results": {
+                "Complete": 5,
+                "Total": 8,
+                "Error": 1,
+                "Skipped": 2
+            },
+            "state": "Running",
+            "created_at": null,
+            "started_at": null,
+            "finished_at": null
+        }))
+        .expect("job status");
+
+        let names: Vec<String> =
+            status_counts(&status).into_iter().map(|(n, _)| n).collect();
+        assert_eq!(names, vec!["Total", "Complete", "Error", "Skipped"]);
+        assert_eq!(
+            render_job_progress(&status),
+            "running  total=8 complete=5 error=1 skipped=2"
+        );
+    }
 }
@@ -0,0 +1,402 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// In-process stand-ins for rebalancer agents.
+//
+// `FakeAgents' is a set of agents, each on its own storage node, that
+// implements both `RebalancerAgentApi' and `SharkSource'.  Setting it as the
+// `agent_api' of an evacuate job, and handing it to the job as its storinfo,
+// lets a test run the job's scheduling, retry and watchdog logic against
+// agents that behave as the test says, without any real agent or network.
+//
+// The fakes are deterministic: nothing they do depends on the clock.  A
+// "slow" agent takes a set number of polls to finish an assignment rather
+// than a set time, and a "flaky" one fails a set fraction of calls in a fixed
+// pattern.  The job itself still needs the test Postgres database that the
+// rest of the manager tests use, as that is where it keeps its state.
+
+use crate::storinfo::{ChooseAlgorithm, SharkSource, StorageNode};
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use rebalancer::common::{AssignmentPayload, ObjectSkippedReason, TaskStatus};
+use rebalancer::error::{AgentError, AgentErrorCode};
+use rebalancer::libagent::{
+    AgentAssignmentState, AgentAssignmentStats, AgentConfigUpdate,
+    Assignment as AgentAssignment,
+};
+
+use crate::jobs::agent_api::{AgentCallError, RebalancerAgentApi};
+
+/// How a fake agent treats the calls made to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AgentBehavior {
+    /// Accept every assignment and report it complete when first asked.
+    Pass,
+
+    /// Accept every assignment, and complete one more of its tasks each
+    /// time it is asked, reporting it complete once they are all done.
+    Slow,
+
+    /// Accept every assignment and report it complete, with every task
+    /// failed for this reason.
+    FailTasks(ObjectSkippedReason),
+
+    /// Answer every call with this error.
+    Failing(AgentErrorCode),
+
+    /// Accept every assignment, but never make progress on it.
+    Stalled,
+
+    /// Like `Pass', except that calls 1, 1 + n, 1 + 2n, ... cannot reach the
+    /// agent.  The failed calls have no effect on the agent.
+    Flaky(u32),
+
+    /// No call reaches the agent.
+    Unreachable,
+}
+
+struct FakeAssignment {
+    payload: AssignmentPayload,
+    polls: usize,
+}
+
+struct FakeAgent {
+    node: StorageNode,
+    behavior: AgentBehavior,
+    calls: u32,
+    posts: u32,
+    assignments: HashMap<String, FakeAssignment>,
+    order: Vec<String>,
+    config_updates: Vec<AgentConfigUpdate>,
+}
+
+impl FakeAgent {
+    // Count a call, and return an error if this one does not get through.
+    fn call(&mut self) -> Result<(), AgentCallError> {
+        self.calls += 1;
+        match self.behavior {
+            AgentBehavior::Unreachable => Err(AgentCallError::Unreachable(
+                format!("{} is down", self.node.manta_storage_id),
+            )),
+            AgentBehavior::Flaky(n) if (self.calls - 1) % n.max(1) == 0 => {
+                Err(AgentCallError::Unreachable(format!(
+                    "{} dropped call {}",
+                    self.node.manta_storage_id, self.calls
+                )))
+            }
+            AgentBehavior::Failing(code) => Err(AgentCallError::Agent(
+                AgentError::new(code, "scripted failure"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A set of fake agents, keyed by the storage id of their storage node.
+#[derive(Default)]
+pub struct FakeAgents {
+    agents: Mutex<HashMap<String, FakeAgent>>,
+}
+
+impl FakeAgents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an agent on a storage node in `datacenter' with `available_mb' of
+    /// free space.
+    pub fn with_agent(
+        self,
+        storage_id: &str,
+        datacenter: &str,
+        available_mb: u64,
+        behavior: AgentBehavior,
+    ) -> Self {
+        let node = StorageNode {
+            available_mb,
+            percent_used: 0,
+            filesystem: String::from("/manta"),
+            datacenter: datacenter.to_string(),
+            manta_storage_id: storage_id.to_string(),
+            timestamp: 0,
+        };
+
+        self.agents.lock().expect("fake agents lock").insert(
+            storage_id.to_string(),
+            FakeAgent {
+                node,
+                behavior,
+                calls: 0,
+                posts: 0,
+                assignments: HashMap::new(),
+                order: vec![],
+                config_updates: vec![],
+            },
+        );
+        self
+    }
+
+    /// Change how an agent behaves from now on.  Assignments it already has
+    /// are kept.
+    pub fn set_behavior(&self, storage_id: &str, behavior: AgentBehavior) {
+        if let Some(agent) = self
+            .agents
+            .lock()
+            .expect("fake agents lock")
+            .get_mut(storage_id)
+        {
+            agent.behavior = behavior;
+        }
+    }
+
+    /// The storage node of an agent.
+    pub fn storage_node(&self, storage_id: &str) -> Option<StorageNode> {
+        self.agents
+            .lock()
+            .expect("fake agents lock")
+            .get(storage_id)
+            .map(|a| a.node.clone())
+    }
+
+    /// The ids of the assignments an agent has accepted, in the order it
+    /// accepted them.
+    pub fn assignments(&self, storage_id: &str) -> Vec<String> {
+        self.agents
+            .lock()
+            .expect("fake agents lock")
+            .get(storage_id)
+            .map(|a| a.order.clone())
+            .unwrap_or_default()
+    }
+
+    /// The number of times an assignment was posted to an agent, including
+    /// posts that did not get through.
+    pub fn posts(&self, storage_id: &str) -> u32 {
+        self.agents
+            .lock()
+            .expect("fake agents lock")
+            .get(storage_id)
+            .map(|a| a.posts)
+            .unwrap_or(0)
+    }
+
+    /// The settings changes an agent has received, oldest first.
+    pub fn config_updates(&self, storage_id: &str) -> Vec<AgentConfigUpdate> {
+        self.agents
+            .lock()
+            .expect("fake agents lock")
+            .get(storage_id)
+            .map(|a| a.config_updates.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn no_such_agent(storage_id: &str) -> AgentCallError {
+    AgentCallError::Unreachable(format!("no agent on {}", storage_id))
+}
+
+impl RebalancerAgentApi for FakeAgents {
+    fn post_assignment(
+        &self,
+        storage_id: &str,
+        payload: &AssignmentPayload,
+    ) -> Result<(), AgentCallError> {
+        let mut agents = self.agents.lock().expect("fake agents lock");
+        let agent = agents
+            .get_mut(storage_id)
+            .ok_or_else(|| no_such_agent(storage_id))?;
+
+        agent.posts += 1;
+        agent.call()?;
+
+        if agent.assignments.contains_key(&payload.id) {
+            return Err(AgentCallError::Agent(AgentError::new(
+                AgentErrorCode::AssignmentExists,
+                format!("assignment {} already exists", payload.id),
+            )));
+        }
+
+        agent.order.push(payload.id.clone());
+        agent.assignments.insert(
+            payload.id.clone(),
+            FakeAssignment {
+                payload: payload.clone(),
+                polls: 0,
+            },
+        );
+        Ok(())
+    }
+
+    fn get_assignment(
+        &self,
+        storage_id: &str,
+        uuid: &str,
+    ) -> Result<AgentAssignment, AgentCallError> {
+        let mut agents = self.agents.lock().expect("fake agents lock");
+        let agent = agents
+            .get_mut(storage_id)
+            .ok_or_else(|| no_such_agent(storage_id))?;
+
+        agent.call()?;
+        let behavior = agent.behavior;
+
+        let assignment = agent.assignments.get_mut(uuid).ok_or_else(|| {
+            AgentCallError::Agent(AgentError::new(
+                AgentErrorCode::AssignmentNotFound,
+                format!("no assignment {}", uuid),
+            ))
+        })?;
+        assignment.polls += 1;
+
+        let total = assignment.payload.tasks.len();
+        let mut stats = AgentAssignmentStats::new(total);
+        match behavior {
+            AgentBehavior::Stalled => {
+                stats.state = AgentAssignmentState::Running;
+            }
+            AgentBehavior::Slow if assignment.polls < total => {
+                stats.state = AgentAssignmentState::Running;
+                stats.complete = assignment.polls;
+            }
+            AgentBehavior::FailTasks(reason) => {
+                let failed = assignment
+                    .payload
+                    .tasks
+                    .iter()
+                    .cloned()
+                    .map(|mut t| {
+                        t.set_status(TaskStatus::Failed(reason));
+                        t
+                    })
+                    .collect();
+                stats.state = AgentAssignmentState::Complete(Some(failed));
+                stats.failed = total;
+            }
+            _ => {
+                stats.state = AgentAssignmentState::Complete(None);
+                stats.complete = total;
+            }
+        }
+
+        Ok(AgentAssignment {
+            uuid: uuid.to_string(),
+            stats,
+            hash: assignment.payload.hash.clone(),
+            kind: assignment.payload.kind,
+            callback: None,
+            tasks: vec![],
+        })
+    }
+
+    fn update_config(
+        &self,
+        storage_id: &str,
+        update: &AgentConfigUpdate,
+    ) -> Result<(), AgentCallError> {
+        let mut agents = self.agents.lock().expect("fake agents lock");
+        let agent = agents
+            .get_mut(storage_id)
+            .ok_or_else(|| no_such_agent(storage_id))?;
+
+        agent.call()?;
+        agent.config_updates.push(update.clone());
+        Ok(())
+    }
+}
+
+impl SharkSource for FakeAgents {
+    fn choose(&self, _algo: &ChooseAlgorithm) -> Option<Vec<StorageNode>> {
+        let agents = self.agents.lock().expect("fake agents lock");
+        let mut nodes: Vec<StorageNode> =
+            agents.values().map(|a| a.node.clone()).collect();
+        nodes.sort_by(|a, b| a.manta_storage_id.cmp(&b.manta_storage_id));
+        Some(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rebalancer::common::Task;
+
+    fn payload(id: &str, tasks: usize) -> AssignmentPayload {
+        let tasks = (0..tasks)
+            .map(|i| Task {
+                object_id: format!("object-{}", i),
+                ..Default::default()
+            })
+            .collect();
+        AssignmentPayload::new(id.to_string(), tasks)
+    }
+
+    #[test]
+    fn slow_agent_test() {
+        let agents = FakeAgents::new().with_agent(
+            "1.stor",
+            "dc1",
+            100,
+            AgentBehavior::Slow,
+        );
+
+        agents
+            .post_assignment("1.stor", &payload("a1", 3))
+            .expect("post");
+        match agents.post_assignment("1.stor", &payload("a1", 3)) {
+            Err(AgentCallError::Agent(e)) => {
+                assert_eq!(e.code, AgentErrorCode::AssignmentExists)
+            }
+            r => panic!("unexpected {:?}", r),
+        }
+
+        let mut complete = vec![];
+        for _ in 0..3 {
+            let a = agents.get_assignment("1.stor", "a1").expect("get");
+            complete.push(a.stats.complete);
+        }
+        assert_eq!(complete, vec![1, 2, 3]);
+        assert_eq!(agents.assignments("1.stor"), vec!["a1".to_string()]);
+        assert_eq!(agents.posts("1.stor"), 2);
+
+        assert!(agents.get_assignment("2.stor", "a1").is_err());
+    }
+
+    #[test]
+    fn flaky_agent_test() {
+        let agents = FakeAgents::new().with_agent(
+            "1.stor",
+            "dc1",
+            100,
+            AgentBehavior::Flaky(2),
+        );
+
+        let results: Vec<bool> = (0..4)
+            .map(|i| {
+                agents
+                    .post_assignment("1.stor", &payload(&format!("a{}", i), 1))
+                    .is_ok()
+            })
+            .collect();
+        assert_eq!(results, vec![false, true, false, true]);
+        assert_eq!(
+            agents.assignments("1.stor"),
+            vec!["a1".to_string(), "a3".to_string()]
+        );
+
+        agents.set_behavior("1.stor", AgentBehavior::Stalled);
+        let a = agents.get_assignment("1.stor", "a1").expect("get");
+        assert_eq!(a.stats.complete, 0);
+        assert!(match a.stats.state {
+            AgentAssignmentState::Running => true,
+            _ => false,
+        });
+    }
+}
@@ -42,15 +42,141 @@ pub type ObjectId = String; // UUID
 pub struct AssignmentPayload {
     pub id: String,
     pub tasks: Vec<Task>,
+
+    // Content hash of the tasks, see assignment_hash().  Older managers do
+    // not send it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+
+    // URL to which the agent posts the assignment once it is complete, so
+    // that the manager does not have to poll for it.  Older managers do not
+    // send it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback: Option<String>,
+
+    // What the agent is to do with the tasks.  Older managers only send
+    // download assignments, and do not send it.
+    #[serde(default, skip_serializing_if = "AssignmentKind::is_download")]
+    pub kind: AssignmentKind,
+}
+
+impl AssignmentPayload {
+    pub fn new(id: String, tasks: Vec<Task>) -> Self {
+        let hash = Some(assignment_hash(&tasks));
+        AssignmentPayload {
+            id,
+            tasks,
+            hash,
+            callback: None,
+            kind: AssignmentKind::Download,
+        }
+    }
+
+    pub fn callback(mut self, callback: Option<String>) -> Self {
+        self.callback = callback;
+        self
+    }
+
+    // Set the kind of the assignment, rehashing its tasks accordingly.
+    pub fn kind(mut self, kind: AssignmentKind) -> Self {
+        self.kind = kind;
+        self.hash = Some(assignment_kind_hash(kind, &self.tasks));
+        self
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumString,
+    Eq,
+    Hash,
+    PartialEq,
+    Serialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentKind {
+    // Download each object from its source, unless a copy with the right
+    // checksum is already on disk.
+    Download,
+
+    // Download nothing.  Check that each object is on disk with the right
+    // checksum, and fail the tasks of those that are missing
+    // (ObjectMissing) or corrupt (MD5Mismatch).
+    Audit,
+}
+
+impl Default for AssignmentKind {
+    fn default() -> Self {
+        AssignmentKind::Download
+    }
+}
+
+impl AssignmentKind {
+    pub fn is_download(&self) -> bool {
+        *self == AssignmentKind::Download
+    }
 }
 
 impl From<AssignmentPayload> for (String, Vec<Task>) {
     fn from(p: AssignmentPayload) -> (String, Vec<Task>) {
-        let AssignmentPayload { id, tasks } = p;
+        let AssignmentPayload { id, tasks, .. } = p;
         (id, tasks)
     }
 }
 
+/// Compute a content hash for the work described by a list of tasks.
+///
+/// The hash covers each task's object id, owner, checksum and source, but not
+/// its status, and does not depend on the order of the tasks.  This lets the
+/// manager and an agent agree on whether they are talking about the same
+/// assignment regardless of how far along the agent is in processing it.  The
+/// object size, storage class and alternate sources are left out so that
+/// managers and agents from before they were added still compute the same
+/// hash.
+pub fn assignment_hash(tasks: &[Task]) -> String {
+    assignment_kind_hash(AssignmentKind::Download, tasks)
+}
+
+/// Compute the content hash of an assignment of the given kind.
+///
+/// The hash of a download assignment is that of assignment_hash().  The hash
+/// of any other kind also covers the kind, so that an agent which predates
+/// it rejects the assignment as a hash mismatch instead of downloading its
+/// objects.
+pub fn assignment_kind_hash(kind: AssignmentKind, tasks: &[Task]) -> String {
+    let mut keys: Vec<String> = tasks
+        .iter()
+        .map(|t| {
+            format!(
+                "{}\0{}\0{}\0{}\0{}",
+                t.object_id,
+                t.owner,
+                t.md5sum,
+                t.source.manta_storage_id,
+                t.source.datacenter
+            )
+        })
+        .collect();
+    keys.sort();
+
+    let mut hasher = Md5::new();
+    if !kind.is_download() {
+        hasher.input(kind.to_string().as_bytes());
+        hasher.input(b"\n");
+    }
+    for key in keys {
+        hasher.input(key.as_bytes());
+        hasher.input(b"\n");
+    }
+
+    format!("{:x}", hasher.result())
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Task {
     pub object_id: String, // or Uuid
@@ -58,6 +184,27 @@ pub struct Task {
     pub md5sum: String,
     pub source: MantaObjectShark,
 
+    // Size of the object in bytes, from its metadata.  Zero if unknown, which
+    // is the case for tasks sent by managers that predate this field.
+    #[serde(default)]
+    pub size: u64,
+
+    // Storage class (tier) of the object, if its metadata has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<String>,
+
+    // Other copies of the object, in the order the agent should try them if
+    // downloading from `source' fails.  Empty for tasks sent by managers that
+    // predate this field, and when failover is disabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternate_sources: Vec<MantaObjectShark>,
+
+    // The storage id of the shark the agent downloaded the object from, once
+    // it has done so.  This is `source' unless the agent failed over to one
+    // of the alternate sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub served_by: Option<String>,
+
     #[serde(default = "TaskStatus::default")]
     pub status: TaskStatus,
 }
@@ -66,6 +213,21 @@ impl Task {
     pub fn set_status(&mut self, status: TaskStatus) {
         self.status = status;
     }
+
+    /// Every shark the object can be downloaded from, in the order they
+    /// should be tried: `source` first, then the alternates.
+    pub fn sources(&self) -> impl Iterator<Item = &MantaObjectShark> {
+        std::iter::once(&self.source).chain(self.alternate_sources.iter())
+    }
+
+    /// Whether the object was downloaded from one of the alternate sources
+    /// rather than `source`.
+    pub fn failed_over(&self) -> bool {
+        match &self.served_by {
+            Some(id) => *id != self.source.manta_storage_id,
+            None => false,
+        }
+    }
 }
 
 impl Arbitrary for Task {
@@ -75,12 +237,19 @@ impl Arbitrary for Task {
         hasher.input(random_string(g, len).as_bytes());
         let md5checksum = hasher.result();
         let md5sum = base64::encode(&md5checksum);
+        let alternates = (g.next_u32() % 3) as usize;
 
         Task {
             object_id: Uuid::new_v4().to_string(),
             owner: Uuid::new_v4().to_string(),
             md5sum,
             source: MantaObjectShark::arbitrary(g),
+            size: u64::from(g.next_u32()),
+            storage_class: None,
+            alternate_sources: (0..alternates)
+                .map(|_| MantaObjectShark::arbitrary(g))
+                .collect(),
+            served_by: None,
             status: TaskStatus::arbitrary(g),
         }
     }
@@ -159,6 +328,13 @@ pub enum ObjectSkippedReason {
     // MD5 Mismatch between the file on disk and the metadata.
     MD5Mismatch,
 
+    // The download ended before all of the bytes advertised in the source's
+    // Content-Length were received, even after retrying.
+    ObjectTruncated,
+
+    // An audit found no copy of the object on disk.
+    ObjectMissing,
+
     // Catchall for unspecified network errors.
     NetworkError,
 
@@ -177,6 +353,10 @@ pub enum ObjectSkippedReason {
     // The only source available is the shark that is being evacuated.
     SourceIsEvacShark,
 
+    // The object is larger than the size threshold of a job configured to
+    // skip large objects.
+    ObjectTooLarge,
+
     HTTPStatusCode(HttpStatusCode),
 }
 
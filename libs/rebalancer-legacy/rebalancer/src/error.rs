@@ -10,6 +10,9 @@
 
 use std::fmt;
 
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug)]
 pub enum Error {
     Internal(InternalError),
@@ -131,6 +134,8 @@ pub enum InternalErrorCode {
     JobBuilderError,       // Errors building a Job
     MaxObjectsLimit,       // The max_objects limit has been reached
     DbQuery,               // Unexpected result from a database query
+    NotificationError,     // Could not send a job notification
+    ExportError,           // Could not export job results
 }
 
 impl fmt::Display for InternalError {
@@ -194,3 +199,158 @@ impl<T> fmt::Display for CrossbeamError<T> {
         }
     }
 }
+
+// Error codes returned by the agent API.  Every unsuccessful response from the
+// agent carries an `AgentError' in its body so that the manager can tell
+// failures apart by code rather than by HTTP status or message text.
+#[derive(
+    Debug, Copy, Clone, Display, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AgentErrorCode {
+    BadRequest,            // Mal-formed assignment, uuid or config update
+    AssignmentNotFound,    // No assignment by the specified uuid
+    AssignmentNotComplete, // Assignment is scheduled and can not be removed
+    AssignmentExists,      // An assignment by the specified uuid exists
+    MethodNotAllowed,      // Unsupported HTTP method
+    ShuttingDown,          // Agent is shutting down and not taking new work
+    InsufficientSpace,     // Not enough free space for the assignment
+    Internal,              // Unexpected failure on the agent
+}
+
+// The canonical mapping between agent error codes and HTTP status codes.  The
+// agent uses it to pick the status of an error response, and the manager uses
+// it to classify responses from agents that predate `AgentError' bodies.
+static AGENT_ERROR_STATUS: &[(AgentErrorCode, StatusCode)] = &[
+    (AgentErrorCode::BadRequest, StatusCode::BAD_REQUEST),
+    (AgentErrorCode::AssignmentNotFound, StatusCode::NOT_FOUND),
+    (AgentErrorCode::AssignmentNotComplete, StatusCode::FORBIDDEN),
+    (AgentErrorCode::AssignmentExists, StatusCode::CONFLICT),
+    (
+        AgentErrorCode::MethodNotAllowed,
+        StatusCode::METHOD_NOT_ALLOWED,
+    ),
+    (
+        AgentErrorCode::ShuttingDown,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ),
+    (
+        AgentErrorCode::InsufficientSpace,
+        StatusCode::INSUFFICIENT_STORAGE,
+    ),
+    (AgentErrorCode::Internal, StatusCode::INTERNAL_SERVER_ERROR),
+];
+
+impl AgentErrorCode {
+    pub fn status(self) -> StatusCode {
+        AGENT_ERROR_STATUS
+            .iter()
+            .find(|(code, _)| *code == self)
+            .map(|(_, status)| *status)
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    // Any status that is not in the table is treated as an internal error.
+    pub fn from_status(status: StatusCode) -> Self {
+        AGENT_ERROR_STATUS
+            .iter()
+            .find(|(_, s)| *s == status)
+            .map(|(code, _)| *code)
+            .unwrap_or(AgentErrorCode::Internal)
+    }
+}
+
+// The body of an unsuccessful agent API response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentError {
+    pub code: AgentErrorCode,
+    pub message: String,
+}
+
+impl AgentError {
+    pub fn new<S: Into<String>>(code: AgentErrorCode, message: S) -> Self {
+        AgentError {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.code.status()
+    }
+
+    // Interpret an unsuccessful agent response.  If the body is not an
+    // `AgentError' (e.g. the agent is an older version), the code is derived
+    // from the status and the body, if any, becomes the message.
+    pub fn from_response(status: StatusCode, body: &str) -> Self {
+        if let Ok(err) = serde_json::from_str::<AgentError>(body) {
+            return err;
+        }
+
+        let message = if body.is_empty() {
+            status.to_string()
+        } else {
+            body.to_string()
+        };
+
+        AgentError::new(AgentErrorCode::from_status(status), message)
+    }
+
+    // Consume the body of a reqwest response and interpret it as above.
+    pub fn from_http_response(response: &mut reqwest::Response) -> Self {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        AgentError::from_response(status, &body)
+    }
+}
+
+impl fmt::Display for AgentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.code, self.status(), self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agent_error_status_table() {
+        for (code, status) in AGENT_ERROR_STATUS {
+            assert_eq!(code.status(), *status);
+            assert_eq!(AgentErrorCode::from_status(*status), *code);
+        }
+
+        assert_eq!(
+            AgentErrorCode::from_status(StatusCode::BAD_GATEWAY),
+            AgentErrorCode::Internal
+        );
+    }
+
+    #[test]
+    fn agent_error_round_trip() {
+        let err = AgentError::new(
+            AgentErrorCode::AssignmentNotComplete,
+            "Attempt to remove scheduled assignment: abc.",
+        );
+        let body = serde_json::to_string(&err).expect("serialized error");
+
+        assert!(body.contains("\"assignment_not_complete\""));
+        assert_eq!(AgentError::from_response(err.status(), &body), err);
+    }
+
+    #[test]
+    fn agent_error_from_legacy_response() {
+        let err = AgentError::from_response(StatusCode::NOT_FOUND, "");
+        assert_eq!(err.code, AgentErrorCode::AssignmentNotFound);
+        assert_eq!(err.message, StatusCode::NOT_FOUND.to_string());
+
+        let err = AgentError::from_response(
+            StatusCode::BAD_REQUEST,
+            "Mal-formed get request",
+        );
+        assert_eq!(err.code, AgentErrorCode::BadRequest);
+        assert_eq!(err.message, "Mal-formed get request");
+    }
+}
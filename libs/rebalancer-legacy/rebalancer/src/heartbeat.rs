@@ -0,0 +1,225 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// Agent heartbeats.
+//
+// The manager only hears from an agent while one of its jobs has work on
+// the agent's storage node, which leaves operators without a way of telling
+// which agents are up, what they are running, or how much room they have
+// left.  When the `[heartbeat]' section of its configuration names the
+// manager, the agent posts a summary of its state to the manager's `/agents'
+// endpoint every `interval_secs' seconds.  The manager keeps the most recent
+// heartbeat of each agent and lists them (see `GET /agents').
+//
+// A heartbeat that can not be delivered is logged and dropped: the next one
+// takes its place.
+
+use std::thread;
+use std::time::Duration;
+
+use reqwest::Client;
+use serde_derive::{Deserialize, Serialize};
+
+static DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 60;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ConfigHeartbeat {
+    // Base URL of the manager, e.g. `http://<manager>'.  Heartbeats are only
+    // sent when this is set.
+    pub manager_url: Option<String>,
+    // Storage id of the storage node the agent runs on, e.g.
+    // `1.stor.<domain>'.  This is what the manager knows the agent by, so
+    // heartbeats are not sent without it either.
+    pub storage_id: Option<String>,
+    // Number of seconds between heartbeats.
+    pub interval_secs: u64,
+}
+
+impl Default for ConfigHeartbeat {
+    fn default() -> Self {
+        Self {
+            manager_url: None,
+            storage_id: None,
+            interval_secs: DEFAULT_HEARTBEAT_INTERVAL_SECS,
+        }
+    }
+}
+
+impl ConfigHeartbeat {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(std::cmp::max(self.interval_secs, 1))
+    }
+}
+
+// Body of a `POST /agents' request to the manager.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AgentHeartbeat {
+    pub storage_id: String,
+    pub datacenter: String,
+    // Version of the agent.
+    pub version: String,
+    // Number of assignments that are scheduled or being processed.
+    pub active_assignments: usize,
+    // Space available for downloads, in bytes, if the agent could obtain
+    // it.
+    pub available_bytes: Option<u64>,
+    // Number of seconds until the next heartbeat, so that the manager can
+    // tell when one is overdue.
+    pub interval_secs: u64,
+}
+
+// The URL that heartbeats are posted to, given the manager's base URL.
+pub fn heartbeat_url(manager_url: &str) -> String {
+    format!("{}/agents", manager_url.trim_end_matches('/'))
+}
+
+// Post a single heartbeat.
+pub fn send(
+    client: &Client,
+    url: &str,
+    heartbeat: &AgentHeartbeat,
+) -> Result<(), String> {
+    match client.post(url).json(heartbeat).send() {
+        Ok(ref r) if r.status().is_success() => Ok(()),
+        Ok(r) => Err(format!("status {}", r.status())),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// Post the heartbeat returned by `status' to the manager at `manager_url',
+// right away and then every `interval'.
+pub fn start<F>(manager_url: &str, interval: Duration, status: F)
+where
+    F: Fn() -> AgentHeartbeat + Send + 'static,
+{
+    let url = heartbeat_url(manager_url);
+
+    let res = thread::Builder::new()
+        .name(String::from("Rebalancer Heartbeat"))
+        .spawn(move || {
+            let client = Client::new();
+            loop {
+                let heartbeat = status();
+                match send(&client, &url, &heartbeat) {
+                    Ok(()) => debug!("Sent heartbeat to {}", url),
+                    Err(e) => {
+                        warn!("Unable to send heartbeat to {}: {}", url, e)
+                    }
+                }
+                thread::sleep(interval);
+            }
+        });
+
+    if let Err(e) = res {
+        error!("Unable to start heartbeat: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn heartbeat() -> AgentHeartbeat {
+        AgentHeartbeat {
+            storage_id: String::from("1.stor.domain"),
+            datacenter: String::from("dc1"),
+            version: String::from("0.1.0"),
+            active_assignments: 2,
+            available_bytes: Some(1024),
+            interval_secs: 60,
+        }
+    }
+
+    // Whether `request' holds the headers and the whole body of a request.
+    fn request_complete(request: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(request);
+        let end = match text.find("\r\n\r\n") {
+            Some(end) => end + 4,
+            None => return false,
+        };
+        let length = text[..end]
+            .lines()
+            .filter_map(|l| {
+                let l = l.to_lowercase();
+                if l.starts_with("content-length:") {
+                    l["content-length:".len()..].trim().parse::<usize>().ok()
+                } else {
+                    None
+                }
+            })
+            .next()
+            .unwrap_or(0);
+        request.len() >= end + length
+    }
+
+    // Answer a single request with `status', and return the request.
+    fn serve_once(
+        status: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request_complete(&request) {
+                let n = socket.read(&mut buf).unwrap();
+                assert!(n > 0, "connection closed early");
+                request.extend_from_slice(&buf[..n]);
+            }
+            let response = format!(
+                "HTTP/1.1 {}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                status
+            );
+            socket.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn config_test() {
+        let config: ConfigHeartbeat = toml::from_str("").unwrap();
+        assert_eq!(config, ConfigHeartbeat::default());
+        assert_eq!(config.interval(), Duration::from_secs(60));
+
+        let config: ConfigHeartbeat = toml::from_str(
+            "manager_url = \"http://manager\"\ninterval_secs = 0\n",
+        )
+        .unwrap();
+        assert_eq!(config.manager_url, Some(String::from("http://manager")));
+        assert_eq!(config.storage_id, None);
+        assert_eq!(config.interval(), Duration::from_secs(1));
+
+        assert_eq!(heartbeat_url("http://manager"), "http://manager/agents");
+        assert_eq!(heartbeat_url("http://manager/"), "http://manager/agents");
+    }
+
+    #[test]
+    fn send_test() {
+        let client = Client::new();
+
+        let (url, handle) = serve_once("200 OK");
+        send(&client, &heartbeat_url(&url), &heartbeat()).unwrap();
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /agents "), "{}", request);
+        let body = &request[request.find("\r\n\r\n").unwrap() + 4..];
+        let sent: AgentHeartbeat = serde_json::from_str(body).unwrap();
+        assert_eq!(sent, heartbeat());
+
+        let (url, handle) = serve_once("400 Bad Request");
+        let err = send(&client, &heartbeat_url(&url), &heartbeat());
+        handle.join().unwrap();
+        assert_eq!(err, Err(String::from("status 400 Bad Request")));
+    }
+}
@@ -25,4 +25,7 @@ pub mod util;
 pub mod agent_test_util;
 pub mod common;
 pub mod error;
+pub mod heartbeat;
 pub mod libagent;
+pub mod storage;
+pub mod throttle;
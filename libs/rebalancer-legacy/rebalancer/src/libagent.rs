@@ -12,9 +12,13 @@ use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fs;
 use std::fs::File;
+use std::io::{Read, Write};
 use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::thread;
+use std::time::Duration;
 use thread_id;
 
 use futures::future;
@@ -27,16 +31,26 @@ use gotham::router::{builder::*, Router};
 use gotham::state::{FromState, State};
 use gotham_derive::{StateData, StaticResponseExtender};
 
-use hyper::{Body, Chunk, Method};
+use hyper::{Body, Chunk, Method, Response};
 use joyent_rust_utils::file::calculate_md5;
+use lazy_static::lazy_static;
 use libmanta::moray::MantaObjectShark;
-
-use crate::common::{AssignmentPayload, ObjectSkippedReason, Task, TaskStatus};
+use md5::{Digest, Md5};
+
+use crate::common::{
+    assignment_kind_hash, AssignmentKind, AssignmentPayload,
+    ObjectSkippedReason, Task, TaskStatus,
+};
+use crate::error::{AgentError, AgentErrorCode};
+use crate::heartbeat::{self, AgentHeartbeat, ConfigHeartbeat};
 use crate::metrics::{self, *};
+use crate::storage::{self, ConfigStorage, StorageHealth};
+use crate::throttle::{self, ConfigThrottle, ThrottleStatus, ThrottleUpdate};
 
 use reqwest::{Client, StatusCode};
 use rusqlite;
 use serde_derive::{Deserialize, Serialize};
+use signal_hook::{self, iterator::Signals};
 use threadpool::ThreadPool;
 use uuid::Uuid;
 use walkdir::WalkDir;
@@ -47,10 +61,57 @@ static REBALANCER_SCHEDULED_DIR: &str = "/var/tmp/rebalancer/scheduled";
 static REBALANCER_FINISHED_DIR: &str = "/var/tmp/rebalancer/completed";
 static REBALANCER_TEMP_DIR: &str = "/manta/rebalancer";
 
+// Set once the agent has received SIGTERM.  From then on, new assignments are
+// rejected and workers stop picking up tasks, checkpointing the assignments
+// that they are processing so that they can be resumed after a restart.
+static SHUTDOWN: AtomicBool = AtomicBool::new(false);
+
+// How often the shutdown handler checks whether all in-flight assignments
+// have been checkpointed.
+static SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// How many times a download that ended before all of the object's bytes were
+// received is retried before the task is marked as failed.
+static TRUNCATED_DOWNLOAD_RETRIES: u32 = 3;
+
+// Pause between attempts to download a truncated object.
+static TRUNCATED_DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+// How many times a completed assignment is posted again to the manager's
+// callback URL after the first attempt fails.  If none of them succeed, the
+// manager still finds out about the assignment by polling for it.
+static CALLBACK_RETRIES: u32 = 5;
+
+// Pause before the first retry of a failed callback.  The pause is doubled
+// after each attempt, up to CALLBACK_MAX_RETRY_DELAY.
+static CALLBACK_RETRY_DELAY: Duration = Duration::from_secs(1);
+static CALLBACK_MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+lazy_static! {
+    // The download thread pool of each assignment worker.  These are kept
+    // here so that the number of threads per assignment can be changed
+    // through the `/config' endpoint.
+    static ref ASSIGNMENT_POOLS: Mutex<Vec<ThreadPool>> =
+        Mutex::new(Vec::new());
+
+    // How downloaded objects are flushed to disk, from the `[download]'
+    // section of the agent's configuration.
+    static ref FSYNC_POLICY: RwLock<FsyncPolicy> =
+        RwLock::new(FsyncPolicy::default());
+}
+
 #[derive(Clone, Default, Deserialize)]
 pub struct AgentConfig {
     pub server: ConfigServer,
     pub metrics: ConfigMetrics,
+    #[serde(default)]
+    pub throttle: ConfigThrottle,
+    #[serde(default)]
+    pub storage: ConfigStorage,
+    #[serde(default)]
+    pub download: ConfigDownload,
+    #[serde(default)]
+    pub heartbeat: ConfigHeartbeat,
 }
 
 #[derive(Clone, Deserialize)]
@@ -76,6 +137,72 @@ impl Default for ConfigServer {
     }
 }
 
+// When a downloaded object is flushed to stable storage.  The manager treats
+// an object as safely relocated as soon as the agent reports the task
+// complete, so an object that is only in the page cache at that point can be
+// lost by a crash of the storage node.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FsyncPolicy {
+    // Leave flushing to the operating system.
+    Never,
+    // Flush the object's data before it is renamed to its final path.
+    File,
+    // Also flush the object's directory after the rename, so that the rename
+    // itself survives a crash.
+    Full,
+}
+
+impl Default for FsyncPolicy {
+    fn default() -> Self {
+        FsyncPolicy::File
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ConfigDownload {
+    pub fsync: FsyncPolicy,
+}
+
+// Body of a `PUT /config' request.  As with the throttle settings, only the
+// fields that are present are updated.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AgentConfigUpdate {
+    #[serde(flatten)]
+    pub throttle: ThrottleUpdate,
+    // Number of threads used to download the objects of each assignment.
+    // Assignments that are already running keep the number of threads that
+    // they started with.
+    pub workers_per_assignment: Option<usize>,
+}
+
+impl AgentConfigUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.throttle.max_bytes_per_sec.is_none()
+            && self.throttle.max_disk_busy_pct.is_none()
+            && self.workers_per_assignment.is_none()
+    }
+
+    // Check the settings that are not validated by the throttle itself.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.workers_per_assignment == Some(0) {
+            return Err(String::from(
+                "workers_per_assignment must be at least 1",
+            ));
+        }
+        Ok(())
+    }
+}
+
+// Response to requests to the `/config' endpoint.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AgentConfigStatus {
+    #[serde(flatten)]
+    pub throttle: ThrottleStatus,
+    pub workers_per_assignment: Option<usize>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum AgentAssignmentState {
     Scheduled,                   // Haven't even started it yet
@@ -89,6 +216,17 @@ pub struct AgentAssignmentStats {
     pub failed: usize,
     pub complete: usize,
     pub total: usize,
+
+    // Number of tasks whose object was downloaded from one of its alternate
+    // sources because the primary source could not serve it.  Stats saved
+    // by older agents do not have it.
+    #[serde(default)]
+    pub failovers: usize,
+
+    // Health of the assignment's database.  This is only filled in when the
+    // assignment is returned to a client; it is never persisted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<StorageHealth>,
 }
 
 impl AgentAssignmentStats {
@@ -98,6 +236,8 @@ impl AgentAssignmentStats {
             failed: 0,
             complete: 0,
             total,
+            failovers: 0,
+            storage: None,
         }
     }
 }
@@ -197,15 +337,37 @@ pub struct Assignment {
     pub uuid: String,
     pub stats: AgentAssignmentStats,
 
+    // Content hash of the tasks as they were received (see
+    // common::assignment_hash()).  The manager compares this against its own
+    // copy when a post is rejected because the assignment already exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+
+    // Whether the tasks are downloaded or audited.  Only audits say so.
+    #[serde(default, skip_serializing_if = "AssignmentKind::is_download")]
+    pub kind: AssignmentKind,
+
+    // Where to post the assignment once it is complete, if the manager asked
+    // for that.  See `assignment_callback()'.
+    #[serde(skip_serializing, skip_deserializing, default)]
+    pub callback: Option<String>,
+
     #[serde(skip_serializing, skip_deserializing, default)]
     pub tasks: Vec<Task>,
 }
 
 impl Assignment {
     fn new(v: Vec<Task>, uuid: &str) -> Assignment {
+        Assignment::with_kind(v, uuid, AssignmentKind::Download)
+    }
+
+    fn with_kind(v: Vec<Task>, uuid: &str, kind: AssignmentKind) -> Assignment {
         Assignment {
             uuid: uuid.to_string(),
             stats: AgentAssignmentStats::new(v.len()),
+            hash: Some(assignment_kind_hash(kind, &v)),
+            kind,
+            callback: None,
             tasks: v,
         }
     }
@@ -222,14 +384,32 @@ pub enum AssignmentOpErr {
 }
 
 impl AssignmentOpErr {
-    pub fn to_http_status_code(&self) -> StatusCode {
+    pub fn to_agent_error_code(&self) -> AgentErrorCode {
         match self {
-            AssignmentOpErr::DoesNotExist => StatusCode::NOT_FOUND,
-            AssignmentOpErr::InternalError(_) => {
-                StatusCode::INTERNAL_SERVER_ERROR
-            }
+            AssignmentOpErr::DoesNotExist => AgentErrorCode::AssignmentNotFound,
+            AssignmentOpErr::InternalError(_) => AgentErrorCode::Internal,
         }
     }
+
+    pub fn to_http_status_code(&self) -> StatusCode {
+        self.to_agent_error_code().status()
+    }
+}
+
+// Every unsuccessful response from the agent carries an `AgentError' so that
+// the manager does not need to interpret status codes or message text.
+fn agent_error_response<S: Into<String>>(
+    state: &State,
+    code: AgentErrorCode,
+    message: S,
+) -> Response<Body> {
+    let err = AgentError::new(code, message);
+    create_response(
+        state,
+        err.status(),
+        mime::APPLICATION_JSON,
+        serde_json::to_vec(&err).expect("serialized agent error"),
+    )
 }
 
 // Inform the work threads that of an assignment that needs to be processed.
@@ -252,6 +432,17 @@ fn load_saved_assignment(
     match assignment_recall(format!("{}/{}", REBALANCER_SCHEDULED_DIR, &uuid)) {
         Ok(a) => {
             let mut work = assignments.lock().unwrap();
+
+            // This is checked while holding the lock so that the shutdown
+            // handler, which waits for the HashMap to drain, can not miss an
+            // assignment that is just about to start.
+            if shutdown_requested() {
+                return Err(String::from(
+                    "Agent is shutting down, assignment will be resumed \
+                     on restart",
+                ));
+            }
+
             work.insert(uuid.to_string(), a);
             Ok(())
         }
@@ -281,6 +472,13 @@ fn discover_saved_assignments(agent: &Agent) {
         .filter_map(|e| e.ok())
     {
         let uuid = entry.file_name().to_string_lossy();
+
+        // The write-ahead log and shared memory files of a database that was
+        // not closed cleanly are not assignments in their own right.
+        if storage::is_sidecar_file(&uuid) {
+            continue;
+        }
+
         debug!("Discovered unfinished assignment: {}", uuid);
         assignment_signal(&agent, &uuid);
     }
@@ -292,9 +490,9 @@ fn assignment_save(
     assignment: Arc<RwLock<Assignment>>,
 ) {
     let mut conn =
-        match rusqlite::Connection::open(format!("{}/{}", path, uuid)) {
+        match storage::global().connection(&format!("{}/{}", path, uuid)) {
             Ok(conn) => conn,
-            Err(e) => panic!("DB error opening {}/{}: {}", path, uuid, e),
+            Err(e) => panic!("{}", e),
         };
 
     let assn = assignment.read().unwrap();
@@ -302,8 +500,9 @@ fn assignment_save(
     let stats = &assn.stats;
 
     // Create a transaction.  All database operations within this function
-    // will be part of this transaction.  This includes the creation of both
-    // the `tasks' and the `stats' table and the insertion of data in to each.
+    // will be part of this transaction.  This includes the creation of the
+    // `tasks', `stats', `callback' and `kind' tables and the insertion of
+    // data in to each.
     let transaction = conn.transaction().unwrap();
 
     // Create the table for our tasks.
@@ -322,6 +521,21 @@ fn assignment_save(
         Err(e) => panic!("Database creation error: {}", e),
     }
 
+    // Create the table for the alternate sources of our tasks, in the order
+    // they are to be tried.
+    match transaction.execute(
+        "create table if not exists alternate_sources (
+        object_id text not null,
+        position integer not null,
+        datacenter text not null,
+        manta_storage_id text not null
+	)",
+        rusqlite::params![],
+    ) {
+        Ok(_) => (),
+        Err(e) => panic!("Database creation error: {}", e),
+    }
+
     // Create the table for our stats.
     match transaction.execute(
         "create table if not exists stats (stats text not null)",
@@ -351,6 +565,26 @@ fn assignment_save(
                 panic!("Task insertion error on assignment {}: {}", &uuid, e)
             }
         };
+
+        for (position, source) in task.alternate_sources.iter().enumerate() {
+            match transaction.execute(
+                "INSERT INTO alternate_sources
+                (object_id, position, datacenter, manta_storage_id)
+                values (?1, ?2, ?3, ?4)",
+                rusqlite::params![
+                    task.object_id,
+                    position as i64,
+                    source.datacenter,
+                    source.manta_storage_id
+                ],
+            ) {
+                Ok(_) => (),
+                Err(e) => panic!(
+                    "Alternate source insertion error on assignment {}: {}",
+                    &uuid, e
+                ),
+            };
+        }
     }
 
     // Populate the stats table with our stats
@@ -362,6 +596,48 @@ fn assignment_save(
         Err(e) => panic!("Task insertion error on assignment {}: {}", &uuid, e),
     };
 
+    // Create the table for the callback URL.  It holds at most one row, and
+    // none if the manager did not ask to be called back.
+    match transaction.execute(
+        "create table if not exists callback (url text not null)",
+        rusqlite::params![],
+    ) {
+        Ok(_) => (),
+        Err(e) => panic!("Database creation error: {}", e),
+    }
+
+    if let Some(url) = &assn.callback {
+        match transaction
+            .execute("INSERT INTO callback values (?1)", rusqlite::params![url])
+        {
+            Ok(_) => (),
+            Err(e) => panic!(
+                "Callback insertion error on assignment {}: {}",
+                &uuid, e
+            ),
+        };
+    }
+
+    // Create the table for the kind of assignment, which holds exactly one
+    // row.
+    match transaction.execute(
+        "create table if not exists kind (kind text not null)",
+        rusqlite::params![],
+    ) {
+        Ok(_) => (),
+        Err(e) => panic!("Database creation error: {}", e),
+    }
+
+    match transaction.execute(
+        "INSERT INTO kind values (?1)",
+        rusqlite::params![assn.kind.to_string()],
+    ) {
+        Ok(_) => (),
+        Err(e) => {
+            panic!("Kind insertion error on assignment {}: {}", &uuid, e)
+        }
+    };
+
     // Finally, kick off the transaction as a whole.  Up until this point,
     // nothing has been committed to the database.  If this does not complete
     // successfully, we likely have a systemic problem that retrying or
@@ -371,6 +647,59 @@ fn assignment_save(
     }
 }
 
+// Write the progress of an assignment that has already been saved back to its
+// database: the status of each task along with the assignment stats.  Unlike
+// `assignment_save()', errors are returned to the caller rather than causing
+// a panic, since the assignment can still be recovered from whatever state was
+// last saved successfully.
+fn assignment_checkpoint(
+    uuid: &str,
+    path: &str,
+    assignment: &Arc<RwLock<Assignment>>,
+) -> Result<(), String> {
+    let mut conn =
+        match storage::global().connection(&format!("{}/{}", path, uuid)) {
+            Ok(conn) => conn,
+            Err(e) => return Err(e),
+        };
+
+    let assn = assignment.read().unwrap();
+
+    let transaction = match conn.transaction() {
+        Ok(t) => t,
+        Err(e) => return Err(format!("Transaction error: {}", e)),
+    };
+
+    for task in assn.tasks.iter() {
+        if let Err(e) = transaction.execute(
+            "UPDATE tasks SET status = ?1 WHERE object_id = ?2",
+            rusqlite::params![
+                serde_json::to_vec(&task.status).unwrap(),
+                task.object_id
+            ],
+        ) {
+            return Err(format!("Task update error: {}", e));
+        }
+    }
+
+    if let Err(e) =
+        transaction.execute("DELETE FROM stats", rusqlite::params![])
+    {
+        return Err(format!("Stats update error: {}", e));
+    }
+
+    if let Err(e) = transaction.execute(
+        "INSERT INTO stats values (?1)",
+        rusqlite::params![serde_json::to_vec(&assn.stats).unwrap()],
+    ) {
+        return Err(format!("Stats update error: {}", e));
+    }
+
+    transaction
+        .commit()
+        .map_err(|e| format!("Transaction error: {}", e))
+}
+
 // Given the path of a particular assignment, extract its contents from
 // persistent storage.  All assignements on disk are stored in separate
 // files named after their uuid.  The format is an sqlite database.  We
@@ -394,10 +723,7 @@ fn assignment_recall<S: Into<String>>(
         .into_string()
         .unwrap();
 
-    let conn = match rusqlite::Connection::open(path) {
-        Ok(conn) => conn,
-        Err(e) => return Err(format!("DB error {}", e)),
-    };
+    let conn = storage::global().connection(&path)?;
 
     let mut stmt = match conn.prepare(
         "SELECT object_id, owner, md5sum,
@@ -420,11 +746,17 @@ fn assignment_recall<S: Into<String>>(
         let s = String::from_utf8(data).unwrap();
         let status: TaskStatus = serde_json::from_str(&s).unwrap();
 
+        // The size and storage class are only needed when the assignment
+        // is accepted, so they are not saved with the tasks.
         let t = Task {
             object_id: row.get(0)?,
             owner: row.get(1)?,
             md5sum: row.get(2)?,
             source,
+            size: 0,
+            storage_class: None,
+            alternate_sources: Vec::new(),
+            served_by: None,
             status,
         };
         Ok(t)
@@ -441,6 +773,38 @@ fn assignment_recall<S: Into<String>>(
         tasks.push(i.unwrap());
     }
 
+    // Assignments saved by older agents have no `alternate_sources' table;
+    // each of their tasks only has the one source.
+    if let Ok(mut alt_stmt) = conn.prepare(
+        "SELECT object_id, datacenter, manta_storage_id
+        FROM alternate_sources ORDER BY object_id, position",
+    ) {
+        let alt_iter = match alt_stmt.query_map(rusqlite::params![], |row| {
+            let object_id: String = row.get(0)?;
+            let source = MantaObjectShark {
+                datacenter: row.get(1)?,
+                manta_storage_id: row.get(2)?,
+            };
+            Ok((object_id, source))
+        }) {
+            Ok(iter) => iter,
+            Err(e) => return Err(format!("Query execution error: {}", e)),
+        };
+
+        let mut alternates: HashMap<String, Vec<MantaObjectShark>> =
+            HashMap::new();
+        for i in alt_iter {
+            let (object_id, source) = i.unwrap();
+            alternates.entry(object_id).or_default().push(source);
+        }
+
+        for task in tasks.iter_mut() {
+            if let Some(sources) = alternates.remove(&task.object_id) {
+                task.alternate_sources = sources;
+            }
+        }
+    }
+
     stmt = match conn.prepare("SELECT stats FROM stats") {
         Ok(s) => s,
         Err(e) => return Err(format!("Query creation error: {}", e)),
@@ -462,8 +826,33 @@ fn assignment_recall<S: Into<String>>(
         stats.push(i.unwrap());
     }
 
-    let mut assignment = Assignment::new(tasks, &uuid);
+    // Assignments saved by older agents have no `callback' table, and are
+    // treated the same as those for which no callback was requested.
+    let callback: Option<String> = conn
+        .query_row("SELECT url FROM callback", rusqlite::params![], |row| {
+            row.get(0)
+        })
+        .ok();
+
+    // Likewise, assignments saved by older agents have no `kind' table, and
+    // are all downloads.
+    let kind: AssignmentKind = match conn.query_row(
+        "SELECT kind FROM kind",
+        rusqlite::params![],
+        |row| row.get::<_, String>(0),
+    ) {
+        Ok(k) => match k.parse() {
+            Ok(kind) => kind,
+            Err(e) => {
+                return Err(format!("Unknown assignment kind {}: {}", k, e))
+            }
+        },
+        Err(_) => AssignmentKind::Download,
+    };
+
+    let mut assignment = Assignment::with_kind(tasks, &uuid, kind);
     assignment.stats = stats[0].clone();
+    assignment.callback = callback;
 
     Ok(Arc::new(RwLock::new(assignment)))
 }
@@ -472,21 +861,95 @@ fn assignment_recall<S: Into<String>>(
 // out the contents (with updated status for each task) out to a new database
 // file in /var/tmp/rebalancer.  Next, delete the original file from
 // /manta/rebalancer so that we do not process it again on restart of the agent.
-// Finally, remove the assignment from our HashMap.
+// Finally, remove the assignment from our HashMap and, if the manager supplied
+// a callback URL, post the finished assignment to it.
 fn assignment_complete(assignments: Arc<Mutex<Assignments>>, uuid: String) {
     let assn = assignment_get(&assignments, &uuid).unwrap();
 
-    assignment_save(&uuid, REBALANCER_FINISHED_DIR, assn);
+    assignment_save(&uuid, REBALANCER_FINISHED_DIR, Arc::clone(&assn));
     let src = format!("{}/{}", REBALANCER_SCHEDULED_DIR, uuid);
 
-    match fs::remove_file(&src) {
+    match storage::global().remove(&src) {
         Ok(_) => (),
         Err(e) => panic!(format!("Error removing file: {}", e)),
     };
 
     // Remove it from our HashMap of assignments.
-    let mut hm = assignments.lock().unwrap();
-    hm.remove(&uuid);
+    assignments.lock().unwrap().remove(&uuid);
+
+    let finished = assn.read().unwrap().clone();
+    if let Some(url) = finished.callback.clone() {
+        assignment_callback(url, finished);
+    }
+}
+
+// Post a completed assignment to the callback URL supplied by the manager.
+// This is done from its own thread so that the worker does not sit out the
+// retries before moving on to its next assignment.  The callback is only an
+// optimization: if it can not be delivered, the manager will still get the
+// assignment the next time it polls for it.
+fn assignment_callback(url: String, assignment: Assignment) {
+    let res = thread::Builder::new()
+        .name(format!("Callback {}", &assignment.uuid))
+        .spawn(move || {
+            let client = Client::new();
+            match post_callback(&client, &url, &assignment) {
+                Ok(()) => {
+                    debug!("Posted assignment {} to {}.", assignment.uuid, url)
+                }
+                Err(e) => warn!(
+                    "Unable to post assignment {} to {}: {}",
+                    assignment.uuid, url, e
+                ),
+            }
+        });
+
+    if let Err(e) = res {
+        error!("Unable to start callback thread: {}", e);
+    }
+}
+
+// Post the assignment, retrying connection errors and server errors with an
+// exponential backoff.  A client error means that the manager will never take
+// the assignment (e.g. the job is no longer running), so it is not retried.
+fn post_callback(
+    client: &Client,
+    url: &str,
+    assignment: &Assignment,
+) -> Result<(), String> {
+    let mut attempt = 0;
+    loop {
+        let err = match client.post(url).json(assignment).send() {
+            Ok(ref r) if r.status().is_success() => return Ok(()),
+            Ok(ref r) if r.status().is_client_error() => {
+                return Err(format!("rejected with status {}", r.status()));
+            }
+            Ok(r) => format!("status {}", r.status()),
+            Err(e) => e.to_string(),
+        };
+
+        if attempt >= CALLBACK_RETRIES {
+            return Err(err);
+        }
+
+        let delay = callback_retry_delay(attempt);
+        attempt += 1;
+        warn!(
+            "Callback for assignment {} failed ({}), retrying in {:?} ({}/{})",
+            assignment.uuid, err, delay, attempt, CALLBACK_RETRIES
+        );
+        thread::sleep(delay);
+    }
+}
+
+// How long to wait before retrying a callback that has already failed
+// `attempt' + 1 times.
+fn callback_retry_delay(attempt: u32) -> Duration {
+    2u32.checked_pow(attempt)
+        .and_then(|factor| CALLBACK_RETRY_DELAY.checked_mul(factor))
+        .map_or(CALLBACK_MAX_RETRY_DELAY, |delay| {
+            min(delay, CALLBACK_MAX_RETRY_DELAY)
+        })
 }
 
 fn handle_delete_assignment_error(
@@ -494,7 +957,7 @@ fn handle_delete_assignment_error(
     err: AssignmentOpErr,
     uuid: &str,
 ) -> Box<HandlerFuture> {
-    let status = err.to_http_status_code();
+    let code = err.to_agent_error_code();
     let res = match err {
         // The file does not exist in the target path specified by the client,
         // but we need to know if it exists at all.  The answer to that question
@@ -510,19 +973,19 @@ fn handle_delete_assignment_error(
                     uuid
                 );
                 info!("{}", &msg);
-                create_response(
+                agent_error_response(
                     &state,
-                    StatusCode::FORBIDDEN,
-                    mime::APPLICATION_JSON,
+                    AgentErrorCode::AssignmentNotComplete,
                     msg,
                 )
             } else {
-                create_empty_response(&state, status)
+                let msg = format!("Assignment {} not found.", uuid);
+                agent_error_response(&state, code, msg)
             }
         }
         // File exists but deletion was unsuccessful.
         AssignmentOpErr::InternalError(s) => {
-            create_response(&state, status, mime::APPLICATION_JSON, s)
+            agent_error_response(&state, code, s)
         }
     };
     Box::new(future::ok((state, res)))
@@ -543,7 +1006,7 @@ fn delete_assignment_impl(uuid: &str) -> Result<(), AssignmentOpErr> {
         return Err(AssignmentOpErr::DoesNotExist);
     }
 
-    match fs::remove_file(finished) {
+    match storage::global().remove(&finished) {
         Err(e) => {
             let msg = format!("Error deleting assignment: {}", e);
             Err(AssignmentOpErr::InternalError(msg))
@@ -561,12 +1024,8 @@ fn delete_assignment(mut state: State) -> Box<HandlerFuture> {
             // Mal-formed uuid.
             let msg = format!("Mal-formed delete request: {}", e);
             info!("{}", &msg);
-            let res = create_response(
-                &state,
-                StatusCode::BAD_REQUEST,
-                mime::APPLICATION_JSON,
-                msg,
-            );
+            let res =
+                agent_error_response(&state, AgentErrorCode::BadRequest, msg);
             return Box::new(future::ok((state, res)));
         }
     };
@@ -589,29 +1048,49 @@ fn post_assignment_handler(
         .concat2()
         .then(move |full_body| match full_body {
             Ok(valid_body) => {
+                // Do not take on any new work while shutting down.  The
+                // manager will record the objects in this assignment as
+                // skipped so that they can be retried.
+                if shutdown_requested() {
+                    info!("Agent is shutting down, rejecting assignment.");
+                    let res = agent_error_response(
+                        &state,
+                        AgentErrorCode::ShuttingDown,
+                        "Agent is shutting down.",
+                    );
+                    return future::ok((state, res));
+                }
+
                 // Ceremony for parsing the information needed to create an
                 // an assignment out of the message body.
-                let (uuid, v) = match validate_assignment(&valid_body) {
-                    Ok(uv) => uv,
-                    Err(e) => {
-                        let res = create_empty_response(
-                            &state,
-                            StatusCode::BAD_REQUEST,
-                        );
-
-                        if let Some(m) = agent.metrics.lock().unwrap().clone() {
-                            counter_vec_inc(&m, ERROR_COUNT, Some(&e));
+                let (uuid, v, callback, kind) =
+                    match validate_assignment(&valid_body) {
+                        Ok(uv) => uv,
+                        Err(e) => {
+                            let res = agent_error_response(
+                                &state,
+                                AgentErrorCode::BadRequest,
+                                e.clone(),
+                            );
+
+                            if let Some(m) =
+                                agent.metrics.lock().unwrap().clone()
+                            {
+                                counter_vec_inc(&m, ERROR_COUNT, Some(&e));
+                            }
+                            return future::ok((state, res));
                         }
-                        return future::ok((state, res));
-                    }
-                };
+                    };
 
                 // Ensure that an asignment with this uuid is not already
                 // currently in flight.  If there is one, do not allow this
                 // assignment to proceed.
                 if agent.assignment_exists(&uuid) {
-                    let res =
-                        create_empty_response(&state, StatusCode::CONFLICT);
+                    let res = agent_error_response(
+                        &state,
+                        AgentErrorCode::AssignmentExists,
+                        format!("Assignment {} already exists.", uuid),
+                    );
 
                     if let Some(m) = agent.metrics.lock().unwrap().clone() {
                         counter_vec_inc(&m, ERROR_COUNT, Some("conflict"));
@@ -620,8 +1099,31 @@ fn post_assignment_handler(
                     return future::ok((state, res));
                 }
 
-                let assignment =
-                    Arc::new(RwLock::new(Assignment::new(v, &uuid)));
+                // Make sure that the objects will fit before accepting the
+                // assignment, rather than failing each of them once the disk
+                // has filled up.  Audits do not download anything.
+                let preflight = match kind {
+                    AssignmentKind::Download => preflight_disk_space(&v),
+                    AssignmentKind::Audit => Ok(()),
+                };
+
+                if let Err(e) = preflight {
+                    let res = agent_error_response(
+                        &state,
+                        AgentErrorCode::InsufficientSpace,
+                        format!("Assignment {}: {}", uuid, e),
+                    );
+
+                    if let Some(m) = agent.metrics.lock().unwrap().clone() {
+                        counter_vec_inc(&m, ERROR_COUNT, Some("no_space"));
+                    }
+
+                    return future::ok((state, res));
+                }
+
+                let mut assignment = Assignment::with_kind(v, &uuid, kind);
+                assignment.callback = callback;
+                let assignment = Arc::new(RwLock::new(assignment));
 
                 info!("Received assignment {}.", &uuid);
                 debug!("Received assignment: {:#?}", &assignment);
@@ -661,9 +1163,81 @@ fn post_assignment_handler(
     Box::new(f)
 }
 
-fn empty_response(state: State, code: StatusCode) -> Box<HandlerFuture> {
-    let res = create_empty_response(&state, code);
-    Box::new(future::ok((state, res)))
+// Return the current throttle configuration along with the rate that is
+// presently being enforced.
+fn get_config_handler(state: State) -> (State, hyper::Response<Body>) {
+    let status = AgentConfigStatus {
+        throttle: throttle::global().status(),
+        workers_per_assignment: ASSIGNMENT_POOLS
+            .lock()
+            .unwrap()
+            .first()
+            .map(ThreadPool::max_count),
+    };
+    let res = create_response(
+        &state,
+        StatusCode::OK,
+        mime::APPLICATION_JSON,
+        serde_json::to_vec(&status).expect("serialized config status"),
+    );
+    (state, res)
+}
+
+// Apply the parts of a `PUT /config' request that are not throttle settings.
+fn update_workers_per_assignment(update: &AgentConfigUpdate) {
+    if let Some(workers) = update.workers_per_assignment {
+        for pool in ASSIGNMENT_POOLS.lock().unwrap().iter_mut() {
+            pool.set_num_threads(workers);
+        }
+        info!("Workers per assignment updated: {}", workers);
+    }
+}
+
+// Adjust the throttle limits and the number of workers per assignment at
+// runtime.  The message body is a json object containing any subset of the
+// fields in `AgentConfigUpdate'.  On success, the updated configuration is
+// returned to the caller.
+fn put_config_handler(mut state: State) -> Box<HandlerFuture> {
+    let f = Body::take_from(&mut state)
+        .concat2()
+        .then(move |full_body| match full_body {
+            Ok(valid_body) => {
+                let update: AgentConfigUpdate =
+                    match serde_json::from_slice(&valid_body.to_vec()) {
+                        Ok(u) => u,
+                        Err(e) => {
+                            let msg = format!(
+                                "Failed to deserialize config update: {}",
+                                e
+                            );
+                            let res = agent_error_response(
+                                &state,
+                                AgentErrorCode::BadRequest,
+                                msg,
+                            );
+                            return future::ok((state, res));
+                        }
+                    };
+
+                if let Err(msg) = update
+                    .validate()
+                    .and_then(|_| throttle::global().update(&update.throttle))
+                {
+                    let res = agent_error_response(
+                        &state,
+                        AgentErrorCode::BadRequest,
+                        msg,
+                    );
+                    return future::ok((state, res));
+                }
+                update_workers_per_assignment(&update);
+
+                let (state, res) = get_config_handler(state);
+                future::ok((state, res))
+            }
+            Err(e) => future::err((state, e.into_handler_error())),
+        });
+    Box::new(f)
 }
 
 // First check to see if the assignment in question is located in memory.  If
@@ -696,6 +1270,18 @@ fn get_assignment_impl(
     None
 }
 
+// Report the health of the database in which an assignment is stored.  A
+// completed assignment is stored in the "completed" directory, everything
+// else is in the "scheduled" directory.
+fn assignment_storage_health(uuid: &str) -> Option<StorageHealth> {
+    let finished = format!("{}/{}", REBALANCER_FINISHED_DIR, uuid);
+    let scheduled = format!("{}/{}", REBALANCER_SCHEDULED_DIR, uuid);
+
+    storage::global()
+        .health(&finished)
+        .or_else(|| storage::global().health(&scheduled))
+}
+
 fn get_assignment_handler(
     agent: Agent,
     mut state: State,
@@ -709,27 +1295,39 @@ fn get_assignment_handler(
         Err(e) => {
             // Mal-formed uuid.
             let msg = format!("Mal-formed get request: {}", e);
-            let res = create_response(
-                &state,
-                StatusCode::BAD_REQUEST,
-                mime::APPLICATION_JSON,
-                msg,
-            );
+            let res =
+                agent_error_response(&state, AgentErrorCode::BadRequest, msg);
             return Box::new(future::ok((state, res)));
         }
     };
 
     let res = match get_assignment_impl(&agent, &uuid) {
         Some(a) => {
-            let assignment = a.read().unwrap();
+            // The tasks are not serialized, so there is no need to copy them.
+            let mut assignment = {
+                let a = a.read().unwrap();
+                Assignment {
+                    uuid: a.uuid.clone(),
+                    stats: a.stats.clone(),
+                    hash: a.hash.clone(),
+                    kind: a.kind,
+                    callback: None,
+                    tasks: vec![],
+                }
+            };
+            assignment.stats.storage = assignment_storage_health(&uuid);
             create_response(
                 &state,
                 StatusCode::OK,
                 mime::APPLICATION_JSON,
-                serde_json::to_vec(&*assignment).expect("serialized task"),
+                serde_json::to_vec(&assignment).expect("serialized task"),
             )
         }
-        None => create_empty_response(&state, StatusCode::NOT_FOUND),
+        None => agent_error_response(
+            &state,
+            AgentErrorCode::AssignmentNotFound,
+            format!("Assignment {} not found.", uuid),
+        ),
     };
 
     Box::new(future::ok((state, res)))
@@ -744,7 +1342,9 @@ fn get_assignment_handler(
 // go separate ways after this point, so they are separated out here in a tuple
 // to save the caller from the monotony of accessing each (private) member of
 // the structure by hand.
-fn validate_assignment(body: &Chunk) -> Result<(String, Vec<Task>), String> {
+fn validate_assignment(
+    body: &Chunk,
+) -> Result<(String, Vec<Task>, Option<String>, AssignmentKind), String> {
     let payload: AssignmentPayload =
         match serde_json::from_slice(&body.to_vec()) {
             Ok(p) => p,
@@ -753,7 +1353,108 @@ fn validate_assignment(body: &Chunk) -> Result<(String, Vec<Task>), String> {
             }
         };
 
-    Ok(<(String, Vec<Task>)>::from(payload))
+    // Older managers do not send a hash, in which case there is nothing to
+    // check.
+    if let Some(hash) = &payload.hash {
+        let computed = assignment_kind_hash(payload.kind, &payload.tasks);
+        if *hash != computed {
+            return Err(format!(
+                "Assignment hash mismatch: expected {}, computed {}",
+                hash, computed
+            ));
+        }
+    }
+
+    // A callback that could never be delivered is better rejected up front
+    // than retried once the assignment is done.
+    if let Some(callback) = &payload.callback {
+        let url = reqwest::Url::parse(callback)
+            .map_err(|e| format!("Invalid callback URL {}: {}", callback, e))?;
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err(format!("Unsupported callback URL: {}", callback));
+        }
+    }
+
+    let callback = payload.callback.clone();
+    let kind = payload.kind;
+    let (uuid, tasks) = <(String, Vec<Task>)>::from(payload);
+    Ok((uuid, tasks, callback, kind))
+}
+
+// The number of bytes that the pending tasks of an assignment will occupy once
+// downloaded.  Tasks of unknown size count as zero.
+fn assignment_bytes(tasks: &[Task]) -> u64 {
+    tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Pending)
+        .map(|t| t.size)
+        .sum()
+}
+
+// Check that the pending tasks of an assignment fit on the filesystem that
+// objects are downloaded to.  There is nothing to check (and no need to run
+// df) if none of the tasks has a known size.
+fn preflight_disk_space(tasks: &[Task]) -> Result<(), String> {
+    let required = assignment_bytes(tasks);
+    if required == 0 {
+        return Ok(());
+    }
+
+    check_disk_space(required, available_bytes(REBALANCER_TEMP_DIR))
+}
+
+// Determine whether `required' bytes fit in the space available for
+// downloads.  If the available space could not be determined, the
+// assignment is allowed to proceed as it would have before this check
+// existed.
+fn check_disk_space(
+    required: u64,
+    available: Option<u64>,
+) -> Result<(), String> {
+    match available {
+        Some(avail) if required > avail => Err(format!(
+            "{} bytes required but only {} bytes available",
+            required, avail
+        )),
+        _ => Ok(()),
+    }
+}
+
+// Obtain the space available to unprivileged users on the filesystem holding
+// `path' using df(1M).  Returns None if it could not be obtained.
+fn available_bytes(path: &str) -> Option<u64> {
+    let output = match Command::new("df").args(&["-k", path]).output() {
+        Ok(o) => o,
+        Err(e) => {
+            warn!("Unable to run df: {}", e);
+            return None;
+        }
+    };
+
+    if !output.status.success() {
+        warn!("df exited with {}", output.status);
+        return None;
+    }
+
+    parse_df_available(&String::from_utf8_lossy(&output.stdout))
+}
+
+// Parse the available space, in bytes, out of `df -k' output.  The columns are
+// counted from the end of the output since a long filesystem name may push
+// the rest of its line on to the next one: the available kilobytes are
+// followed by the capacity and the mount point.
+fn parse_df_available(output: &str) -> Option<u64> {
+    let fields: Vec<&str> = output
+        .lines()
+        .skip(1)
+        .flat_map(|l| l.split_whitespace())
+        .collect();
+    if fields.len() < 3 {
+        return None;
+    }
+
+    let kb: u64 = fields[fields.len() - 3].parse().ok()?;
+    kb.checked_mul(1024)
 }
 
 impl Handler for Agent {
@@ -769,7 +1470,14 @@ impl Handler for Agent {
         match method.as_str() {
             "POST" => post_assignment_handler(self, state),
             "GET" => get_assignment_handler(self, state),
-            _ => empty_response(state, StatusCode::METHOD_NOT_ALLOWED),
+            _ => {
+                let res = agent_error_response(
+                    &state,
+                    AgentErrorCode::MethodNotAllowed,
+                    format!("Method {} not allowed.", method),
+                );
+                Box::new(future::ok((state, res)))
+            }
         }
     }
 }
@@ -797,21 +1505,39 @@ fn manta_file_path(owner: &str, object: &str) -> String {
     path
 }
 
-fn file_move(src: &str, dst: &str) {
-    let parent = match Path::new(dst).parent() {
-        Some(p) => p,
-        None => panic!("Invalid destination path supplied."),
-    };
+fn fsync_policy() -> FsyncPolicy {
+    *FSYNC_POLICY.read().unwrap()
+}
+
+// Move a verified download from its temporary path to its final path.  Both
+// are on the same filesystem, so the rename is atomic: the object appears at
+// `dst' whole or not at all.  Under the `full' fsync policy the destination
+// directory is flushed afterwards so that the rename is not lost in a crash.
+fn commit_download(
+    src: &str,
+    dst: &str,
+    policy: FsyncPolicy,
+) -> std::io::Result<()> {
+    let parent = Path::new(dst).parent().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{} has no parent directory", dst),
+        )
+    })?;
 
     // Check to see if the destination directory exists.  If it does not, then
     // create it now.
     if !parent.exists() {
-        create_dir(parent.to_str().unwrap());
+        fs::create_dir_all(parent)?;
     }
 
-    if let Err(e) = fs::rename(src, dst) {
-        panic!("Error renaming file {}: {}", src, e);
+    fs::rename(src, dst)?;
+
+    if policy == FsyncPolicy::Full {
+        File::open(parent)?.sync_all()?;
     }
+
+    Ok(())
 }
 
 fn file_remove(file_path: &str) {
@@ -824,19 +1550,111 @@ fn file_remove(file_path: &str) {
     }
 }
 
-fn file_create(file_path: &str) -> File {
-    match File::create(&file_path) {
-        Err(e) => panic!("Error creating file {}", e),
-        Ok(file) => file,
+// A writer that computes the MD5 checksum of everything written through it,
+// so that a download can be verified as soon as its last byte is written
+// instead of by reading the whole object back from disk.
+struct Md5Writer<W: Write> {
+    inner: W,
+    hasher: Md5,
+}
+
+impl<W: Write> Md5Writer<W> {
+    fn new(inner: W) -> Md5Writer<W> {
+        Md5Writer {
+            inner,
+            hasher: Md5::new(),
+        }
+    }
+
+    // Returns the underlying writer along with the base64 encoded checksum of
+    // the bytes written, which is the form used in Manta's object metadata.
+    fn finish(self) -> (W, String) {
+        (self.inner, base64::encode(&self.hasher.result()))
+    }
+}
+
+impl<W: Write> Write for Md5Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = self.inner.write(buf)?;
+        self.hasher.input(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// The ways in which copying an object from the network to disk can fail.
+#[derive(Debug)]
+enum CopyError {
+    // Reading from the source failed after `received` bytes had arrived.
+    Read { received: u64, err: std::io::Error },
+    // Writing to the local file failed.
+    Write(std::io::Error),
+}
+
+// Equivalent to std::io::copy(), except that every chunk is accounted for by
+// the agent's throttle, which may put this thread to sleep in order to keep
+// the aggregate download rate of the agent within its current limit.  Errors
+// reading from the source are reported separately from errors writing to the
+// destination so that the caller can tell a broken download from a local
+// filesystem problem.
+fn throttled_copy<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64, CopyError> {
+    let throttle = throttle::global();
+    let mut buf = vec![0; throttle::THROTTLE_CHUNK_SIZE];
+    let mut total: u64 = 0;
+
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => return Ok(total),
+            Ok(len) => len,
+            Err(ref e) if e.kind() == std::io::ErrorKind::Interrupted => {
+                continue
+            }
+            Err(err) => {
+                return Err(CopyError::Read {
+                    received: total,
+                    err,
+                })
+            }
+        };
+
+        writer.write_all(&buf[..len]).map_err(CopyError::Write)?;
+        total += len as u64;
+        throttle.consume(len);
+    }
+}
+
+// Determine whether a download that ended after `received` bytes delivered
+// the whole object, given the Content-Length advertised by the source (if
+// any).
+fn check_content_length(
+    received: u64,
+    content_length: Option<u64>,
+) -> Result<(), ObjectSkippedReason> {
+    match content_length {
+        Some(expected) if received < expected => {
+            Err(ObjectSkippedReason::ObjectTruncated)
+        }
+        _ => Ok(()),
     }
 }
 
-// TODO: Make this return an actual result.
+// Stream an object from `uri' to its temporary path, one throttle chunk at a
+// time, so that the memory used by a download does not depend on the size of
+// the object.  The checksum is computed along the way, and the file is
+// flushed to disk according to the fsync policy before the download is
+// reported as successful.
 fn download(
     uri: &str,
     owner: &str,
     object: &str,
     csum: &str,
+    size: u64,
     client: &Client,
 ) -> Result<u64, ObjectSkippedReason> {
     let mut response = match client.get(uri).send() {
@@ -856,22 +1674,104 @@ fn download(
 
     trace!("{}", msg);
 
+    // Fall back on the size from the object's metadata, if the manager sent
+    // one, when the source does not advertise a Content-Length.
+    let content_length = response
+        .content_length()
+        .or_else(|| Some(size).filter(|s| *s > 0));
     let tmp_path = manta_tmp_path(owner, object);
-    let mut file = file_create(&tmp_path);
+    let file = match File::create(&tmp_path) {
+        Ok(f) => f,
+        Err(e) => {
+            error!("Unable to create {}: {}", &tmp_path, e);
+            return Err(ObjectSkippedReason::AgentFSError);
+        }
+    };
+    let mut writer = Md5Writer::new(file);
 
-    let bytes = match std::io::copy(&mut response, &mut file) {
+    let bytes = match throttled_copy(&mut response, &mut writer) {
         Ok(b) => b,
-        Err(e) => {
+        Err(CopyError::Read { received, err }) => {
+            // The connection broke off part way through the object.
+            error!(
+                "Download of {} ended after {} of {:?} bytes: {}",
+                uri, received, content_length, err
+            );
+            return Err(ObjectSkippedReason::ObjectTruncated);
+        }
+        Err(CopyError::Write(e)) => {
             error!("Failed to complete object download: {}:{}", uri, e);
             return Err(ObjectSkippedReason::AgentFSError);
         }
     };
 
-    if calculate_md5(&tmp_path) == csum {
-        Ok(bytes)
-    } else {
+    // Only now that we know that every byte the source promised has arrived
+    // can a checksum failure be attributed to corruption.
+    if let Err(reason) = check_content_length(bytes, content_length) {
+        error!(
+            "Download of {} truncated: received {} of {:?} bytes",
+            uri, bytes, content_length
+        );
+        return Err(reason);
+    }
+
+    let (file, md5sum) = writer.finish();
+    if md5sum != csum {
         error!("Checksum failed for {}/{}.", owner, object);
-        Err(ObjectSkippedReason::MD5Mismatch)
+        return Err(ObjectSkippedReason::MD5Mismatch);
+    }
+
+    if fsync_policy() != FsyncPolicy::Never {
+        if let Err(e) = file.sync_data() {
+            error!("Unable to flush {}: {}", &tmp_path, e);
+            return Err(ObjectSkippedReason::AgentFSError);
+        }
+    }
+
+    Ok(bytes)
+}
+
+// Download an object, retrying downloads that were cut short.  Any other
+// failure is returned immediately.  The temporary file of a failed attempt is
+// removed before the next attempt starts.
+fn download_with_retries(
+    uri: &str,
+    owner: &str,
+    object: &str,
+    csum: &str,
+    size: u64,
+    client: &Client,
+) -> Result<u64, ObjectSkippedReason> {
+    let mut attempt = 0;
+
+    loop {
+        match download(uri, owner, object, csum, size, client) {
+            Err(ObjectSkippedReason::ObjectTruncated)
+                if attempt < TRUNCATED_DOWNLOAD_RETRIES =>
+            {
+                attempt += 1;
+                warn!(
+                    "Retrying truncated download of {}/{} ({} of {})",
+                    owner, object, attempt, TRUNCATED_DOWNLOAD_RETRIES
+                );
+                file_remove(&manta_tmp_path(owner, object));
+                thread::sleep(TRUNCATED_DOWNLOAD_RETRY_DELAY);
+            }
+            result => return result,
+        }
+    }
+}
+
+// Whether a download that failed for `reason` might succeed from another copy
+// of the object.  Local problems, such as a broken filesystem, would fail the
+// same way whichever source the object came from.
+fn is_source_failure(reason: ObjectSkippedReason) -> bool {
+    match reason {
+        ObjectSkippedReason::SourceOtherError
+        | ObjectSkippedReason::HTTPStatusCode(_)
+        | ObjectSkippedReason::ObjectTruncated
+        | ObjectSkippedReason::MD5Mismatch => true,
+        _ => false,
     }
 }
 
@@ -896,46 +1796,104 @@ pub fn process_task(
         return;
     }
 
-    // Put it all together.  The format of the url is:
-    // http://<storage id>/<owner id>/<object id>
-    let url = format!(
-        "http://{}/{}/{}",
-        &task.source.manta_storage_id, &task.owner, &task.object_id
-    );
-
     let tmp_path = manta_tmp_path(&task.owner, &task.object_id);
+    let sources: Vec<MantaObjectShark> = task.sources().cloned().collect();
+    let mut status = TaskStatus::Pending;
+
+    // Try each copy of the object in the order the manager listed them,
+    // until one of them can be downloaded.
+    for (i, source) in sources.iter().enumerate() {
+        // Put it all together.  The format of the url is:
+        // http://<storage id>/<owner id>/<object id>
+        let url = format!(
+            "http://{}/{}/{}",
+            &source.manta_storage_id, &task.owner, &task.object_id
+        );
 
-    // Reach out to the storage node to download
-    // the object.
-    let status = match download(
-        &url,
-        &task.owner,
-        &task.object_id,
-        &task.md5sum,
-        client,
-    ) {
-        Ok(bytes) => {
-            if let Some(m) = metrics {
-                counter_inc_by(m, BYTES_COUNT, bytes);
-            }
+        // Reach out to the storage node to download
+        // the object.
+        match download_with_retries(
+            &url,
+            &task.owner,
+            &task.object_id,
+            &task.md5sum,
+            task.size,
+            client,
+        ) {
+            Ok(bytes) => {
+                // Upon successful download, move the temporary object to
+                // its rightful location (i.e. /manta/account/object).
+                let manta_path = manta_file_path(&task.owner, &task.object_id);
+                if let Err(e) =
+                    commit_download(&tmp_path, &manta_path, fsync_policy())
+                {
+                    error!(
+                        "Unable to move {} to {}: {}",
+                        &tmp_path, &manta_path, e
+                    );
+                    file_remove(&tmp_path);
+                    status =
+                        TaskStatus::Failed(ObjectSkippedReason::AgentFSError);
+                    break;
+                }
 
-            info!(
-                "owner: {}, object: {}, bytes: {}",
-                &task.owner, &task.object_id, bytes
-            );
+                if let Some(m) = metrics {
+                    counter_inc_by(m, BYTES_COUNT, bytes);
+                }
+
+                info!(
+                    "owner: {}, object: {}, bytes: {}, source: {}",
+                    &task.owner,
+                    &task.object_id,
+                    bytes,
+                    &source.manta_storage_id
+                );
 
-            // Upon successful download, move the temprorary object to its
-            // rightful location (i.e. /manta/account/object).
-            let manta_path = manta_file_path(&task.owner, &task.object_id);
-            file_move(&tmp_path, &manta_path);
-            TaskStatus::Complete
+                task.served_by = Some(source.manta_storage_id.clone());
+                status = TaskStatus::Complete;
+                break;
+            }
+            Err(e) => {
+                // If we failed to complete the download, remove the
+                // temporary file so that these kinds of things do not pile
+                // up.  It is worth mentioning that in all failure cases
+                // except one there will a partially downloaded object that
+                // requires clean-up.
+                file_remove(&tmp_path);
+                status = TaskStatus::Failed(e);
+
+                if !is_source_failure(e) {
+                    break;
+                }
+
+                if let Some(next) = sources.get(i + 1) {
+                    warn!(
+                        "Download of {}/{} from {} failed ({}), \
+                         failing over to {}",
+                        &task.owner,
+                        &task.object_id,
+                        &source.manta_storage_id,
+                        e,
+                        &next.manta_storage_id
+                    );
+                }
+            }
         }
+    }
+
+    task.set_status(status);
+}
+
+// Check the copy of an object on disk rather than downloading it, for an
+// audit assignment.  The task fails with ObjectMissing if there is no copy,
+// and with MD5Mismatch if the copy is corrupt, that is if its size (when the
+// manager sent it) or its checksum differs from the object's metadata.
+pub fn audit_task(task: &mut Task) {
+    let file_path = manta_file_path(&task.owner, &task.object_id);
+    let status = match audit_file(&file_path, &task.md5sum, task.size) {
+        Ok(()) => TaskStatus::Complete,
         Err(e) => {
-            // If we failed to complete the download, remove the temporary
-            // file so that these kinds of things do not pile up.  It is
-            // worth mentioning that in all failure cases except one there
-            // will a partially downloaded object that requires clean-up.
-            file_remove(&tmp_path);
+            warn!("Audit of {}/{} failed: {}", &task.owner, &task.object_id, e);
             TaskStatus::Failed(e)
         }
     };
@@ -943,6 +1901,57 @@ pub fn process_task(
     task.set_status(status);
 }
 
+// Unlike calculate_md5(), which panics, a file that can not be read is
+// reported as AgentFSError so that the rest of the audit can carry on.
+fn audit_file(
+    file_path: &str,
+    md5sum: &str,
+    size: u64,
+) -> Result<(), ObjectSkippedReason> {
+    let metadata = match fs::metadata(file_path) {
+        Ok(m) => m,
+        Err(ref e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(ObjectSkippedReason::ObjectMissing);
+        }
+        Err(e) => {
+            error!("Unable to stat {}: {}", file_path, e);
+            return Err(ObjectSkippedReason::AgentFSError);
+        }
+    };
+
+    if size != 0 && metadata.len() != size {
+        return Err(ObjectSkippedReason::MD5Mismatch);
+    }
+
+    let mut file = match File::open(file_path) {
+        Ok(f) => f,
+        Err(e) => {
+            error!("Unable to open {}: {}", file_path, e);
+            return Err(ObjectSkippedReason::AgentFSError);
+        }
+    };
+
+    let mut hasher = Md5::new();
+    let mut buf = vec![0u8; 128 * 1024];
+    loop {
+        match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => hasher.input(&buf[..n]),
+            Err(ref e) if e.kind() == std::io::ErrorKind::Interrupted => (),
+            Err(e) => {
+                error!("Unable to read {}: {}", file_path, e);
+                return Err(ObjectSkippedReason::AgentFSError);
+            }
+        }
+    }
+
+    if base64::encode(&hasher.result()) != md5sum {
+        return Err(ObjectSkippedReason::MD5Mismatch);
+    }
+
+    Ok(())
+}
+
 // Searches our HashMap of assignments.  This is not to be confused with the
 // function that searches our on-disk database for assignments if they are
 // no longer in memory.  See `assignment_recall()' for the function that queries
@@ -967,9 +1976,18 @@ fn process_assignment_impl(
     client: &Client,
     next: Arc<Mutex<usize>>,
 ) {
-    let len = assignment.read().unwrap().tasks.len();
+    let (len, kind) = {
+        let a = assignment.read().unwrap();
+        (a.tasks.len(), a.kind)
+    };
 
     loop {
+        // Once shutdown has been requested, leave the remaining tasks to be
+        // processed when the agent restarts.
+        if shutdown_requested() {
+            break;
+        }
+
         // Obtain the index of the next unprocessed task in the vector.  This
         // will allow multiple workers to find the next available task in
         // constant time without having to perform the practically negligible
@@ -988,6 +2006,12 @@ fn process_assignment_impl(
 
         let mut t = assignment.read().unwrap().tasks[index].clone();
 
+        // When resuming a checkpointed assignment, the tasks that were
+        // processed before the agent restarted are already accounted for.
+        if t.status != TaskStatus::Pending {
+            continue;
+        }
+
         trace!(
             "Processing task: assignment: {}, owner: {}, object: {}",
             &uuid,
@@ -996,7 +2020,10 @@ fn process_assignment_impl(
         );
 
         // Process the task.
-        f(&mut t, client, &metrics);
+        match kind {
+            AssignmentKind::Download => f(&mut t, client, &metrics),
+            AssignmentKind::Audit => audit_task(&mut t),
+        }
 
         // Update the total number of objects that have been processed, whether
         // successful or not.
@@ -1013,6 +2040,10 @@ fn process_assignment_impl(
         // Update our stats.
         tmp.stats.complete += 1;
 
+        if t.failed_over() {
+            tmp.stats.failovers += 1;
+        }
+
         if let TaskStatus::Failed(e) = t.status {
             if let Some(m) = metrics.clone() {
                 counter_vec_inc(&m, ERROR_COUNT, Some(&e.to_string()));
@@ -1060,12 +2091,42 @@ fn process_assignment(
     }
 
     let assignment = assignment_get(&assignments, &uuid).unwrap();
+
+    // The assignment was being processed when the agent last exited, but it
+    // was never checkpointed (e.g. the agent crashed or was killed), so there
+    // is no telling which of its tasks were completed.  Rather than guess,
+    // fail whatever is left so that the manager can retry those objects.
+    let interrupted = match assignment.read().unwrap().stats.state {
+        AgentAssignmentState::Running => true,
+        _ => false,
+    };
+
+    if interrupted {
+        warn!(
+            "Assignment {} was interrupted without being checkpointed, \
+             failing its remaining tasks.",
+            &uuid
+        );
+        fail_interrupted_assignment(&mut assignment.write().unwrap());
+        assignment_complete(assignments, uuid);
+        return;
+    }
+
     let len = assignment.read().unwrap().tasks.len();
-    let failures = Arc::new(Mutex::new(Vec::new()));
+    let failures =
+        Arc::new(Mutex::new(failed_tasks(&assignment.read().unwrap())));
     let next = Arc::new(Mutex::new(0));
 
     assignment.write().unwrap().stats.state = AgentAssignmentState::Running;
 
+    // Record on disk that the assignment is running, so that if the agent
+    // exits without checkpointing it, we know not to resume it on restart.
+    if let Err(e) =
+        assignment_checkpoint(&uuid, REBALANCER_SCHEDULED_DIR, &assignment)
+    {
+        error!("Unable to save state of assignment {}: {}", &uuid, e);
+    }
+
     info!("Begin processing assignment {}.", &uuid);
 
     let active_workers = min(len, pool.max_count());
@@ -1085,6 +2146,33 @@ fn process_assignment(
     }
     pool.join();
 
+    // If the workers stopped early because the agent is shutting down, save
+    // the progress made so far.  The assignment is put back in the scheduled
+    // state, which marks it as safe to resume when the agent restarts.
+    if shutdown_requested() && *next.lock().unwrap() < len {
+        assignment.write().unwrap().stats.state =
+            AgentAssignmentState::Scheduled;
+
+        match assignment_checkpoint(
+            &uuid,
+            REBALANCER_SCHEDULED_DIR,
+            &assignment,
+        ) {
+            Ok(()) => info!(
+                "Checkpointed assignment {} after {} of {} tasks.",
+                &uuid,
+                assignment.read().unwrap().stats.complete,
+                len
+            ),
+            Err(e) => {
+                error!("Unable to checkpoint assignment {}: {}", &uuid, e)
+            }
+        }
+
+        assignments.lock().unwrap().remove(&uuid);
+        return;
+    }
+
     let done = start.elapsed().as_secs_f64();
 
     if let Some(m) = metrics.clone() {
@@ -1107,6 +2195,121 @@ fn process_assignment(
     assignment_complete(assignments, uuid);
 }
 
+fn shutdown_requested() -> bool {
+    SHUTDOWN.load(Ordering::SeqCst)
+}
+
+// Tasks in the assignment that have already failed.
+fn failed_tasks(assignment: &Assignment) -> Vec<Task> {
+    assignment
+        .tasks
+        .iter()
+        .filter(|t| match t.status {
+            TaskStatus::Failed(_) => true,
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+// Mark every task that has yet to be processed as failed and complete the
+// assignment.  This is used for assignments that were interrupted without
+// being checkpointed.
+fn fail_interrupted_assignment(assignment: &mut Assignment) {
+    for task in assignment.tasks.iter_mut() {
+        if task.status == TaskStatus::Pending {
+            task.set_status(TaskStatus::Failed(
+                ObjectSkippedReason::AssignmentError,
+            ));
+            assignment.stats.complete += 1;
+            assignment.stats.failed += 1;
+        }
+    }
+
+    let failed = failed_tasks(assignment);
+    assignment.stats.state = if failed.is_empty() {
+        AgentAssignmentState::Complete(None)
+    } else {
+        AgentAssignmentState::Complete(Some(failed))
+    };
+}
+
+// Start a thread that waits for SIGTERM, which SMF sends when the agent is
+// disabled or restarted.  Upon receipt, new assignments are rejected and the
+// workers checkpoint the assignments that they are processing once their
+// current tasks are finished.  When no assignments remain in flight, the
+// agent exits.
+fn start_shutdown_handler(assignments: Arc<Mutex<Assignments>>) {
+    // Register the handler before returning, so that a SIGTERM received at
+    // any point after the agent has started is handled gracefully.
+    let signals = match Signals::new(&[signal_hook::SIGTERM]) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Unable to register SIGTERM handler: {}", e);
+            return;
+        }
+    };
+
+    let res = thread::Builder::new()
+        .name(String::from("Rebalancer Shutdown"))
+        .spawn(move || {
+            if signals.forever().next().is_none() {
+                return;
+            }
+
+            info!("Received SIGTERM, shutting down.");
+            SHUTDOWN.store(true, Ordering::SeqCst);
+
+            let in_flight = assignments.lock().unwrap().len();
+            if in_flight > 0 {
+                info!(
+                    "Waiting for {} assignment(s) to be checkpointed.",
+                    in_flight
+                );
+            }
+
+            while !assignments.lock().unwrap().is_empty() {
+                thread::sleep(SHUTDOWN_POLL_INTERVAL);
+            }
+
+            info!("Shutdown complete.");
+            std::process::exit(0);
+        });
+
+    if let Err(e) = res {
+        error!("Unable to start shutdown handler: {}", e);
+    }
+}
+
+// Periodically report the agent's state to the manager, if the `[heartbeat]'
+// section of the configuration names the manager.
+fn start_heartbeat(config: &AgentConfig, assignments: Arc<Mutex<Assignments>>) {
+    let manager_url = match config.heartbeat.manager_url {
+        Some(ref url) => url.clone(),
+        None => return,
+    };
+
+    let storage_id = match config.heartbeat.storage_id {
+        Some(ref id) => id.clone(),
+        None => {
+            warn!("Heartbeat storage_id is not set, not sending heartbeats.");
+            return;
+        }
+    };
+
+    let datacenter = config.metrics.datacenter.clone();
+    let interval = config.heartbeat.interval();
+
+    heartbeat::start(&manager_url, interval, move || AgentHeartbeat {
+        storage_id: storage_id.clone(),
+        datacenter: datacenter.clone(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        active_assignments: assignments.lock().unwrap().len(),
+        available_bytes: available_bytes(REBALANCER_TEMP_DIR),
+        interval_secs: interval.as_secs(),
+    });
+}
+
 fn agent_start_metrics_server(config: &AgentConfig) -> MetricsMap {
     let agent_metrics = metrics::register_metrics(&config.metrics);
     let metrics_host = config.metrics.host.clone();
@@ -1137,11 +2340,20 @@ pub fn router(
         let mut agent_metrics: Option<MetricsMap> = None;
         let mut workers = 1;
         let mut workers_per_assignment = 1;
+        let mut handle_shutdown = false;
+        let mut heartbeat_config = None;
 
         if let Some(c) = config {
             agent_metrics = Some(agent_start_metrics_server(&c));
             workers = c.server.workers;
             workers_per_assignment = c.server.workers_per_assignment;
+            throttle::global().configure(c.throttle.clone());
+            throttle::start_sampler();
+            storage::global().configure(c.storage.clone());
+            storage::start_maintenance();
+            *FSYNC_POLICY.write().unwrap() = c.download.fsync;
+            handle_shutdown = true;
+            heartbeat_config = Some(c);
         }
 
         assert!(workers > 0 && workers_per_assignment > 0);
@@ -1153,6 +2365,14 @@ pub fn router(
         let agent = Agent::new(tx, Arc::new(Mutex::new(agent_metrics.clone())));
         let pool = ThreadPool::new(workers);
 
+        if handle_shutdown {
+            start_shutdown_handler(Arc::clone(&agent.assignments));
+        }
+
+        if let Some(c) = heartbeat_config {
+            start_heartbeat(&c, Arc::clone(&agent.assignments));
+        }
+
         create_dir(REBALANCER_SCHEDULED_DIR);
         create_dir(REBALANCER_FINISHED_DIR);
 
@@ -1165,12 +2385,16 @@ pub fn router(
 
         create_dir(REBALANCER_TEMP_DIR);
 
+        let mut assignment_pools = ASSIGNMENT_POOLS.lock().unwrap();
+        assignment_pools.clear();
+
         for _ in 0..workers {
             let rx = Arc::clone(&rx);
             let assignments = Arc::clone(&agent.assignments);
             let m = agent_metrics.clone();
             let client = reqwest::Client::new();
             let worker_pool = ThreadPool::new(workers_per_assignment);
+            assignment_pools.push(worker_pool.clone());
 
             pool.execute(move || loop {
                 let uuid = match rx.lock().unwrap().recv() {
@@ -1191,6 +2415,8 @@ pub fn router(
             });
         }
 
+        drop(assignment_pools);
+
         discover_saved_assignments(&agent);
 
         route.scope("/assignments", |route| {
@@ -1209,7 +2435,10 @@ pub fn router(
             });
 
             route.post("").to_new_handler(agent.clone());
-        })
+        });
+
+        route.get("/config").to(get_config_handler);
+        route.put("/config").to(put_config_handler);
     })
 }
 
@@ -1218,3 +2447,510 @@ fn create_dir(dirname: &str) {
         panic!("Error creating directory {}", e);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::assignment_hash;
+
+    fn task(object_id: &str, status: TaskStatus) -> Task {
+        Task {
+            object_id: object_id.to_string(),
+            owner: "rebalancer".to_string(),
+            md5sum: "abc".to_string(),
+            source: MantaObjectShark {
+                datacenter: "dc".to_string(),
+                manta_storage_id: "1.stor.domain".to_string(),
+            },
+            size: 1024,
+            storage_class: None,
+            alternate_sources: Vec::new(),
+            served_by: None,
+            status,
+        }
+    }
+
+    fn partial_assignment(uuid: &str) -> Assignment {
+        let mut assignment = Assignment::new(
+            vec![
+                task("a", TaskStatus::Complete),
+                task("b", TaskStatus::Failed(ObjectSkippedReason::MD5Mismatch)),
+                task("c", TaskStatus::Pending),
+            ],
+            uuid,
+        );
+        assignment.stats.complete = 2;
+        assignment.stats.failed = 1;
+        assignment
+    }
+
+    #[test]
+    fn interrupted_assignment_fails_pending_tasks() {
+        let mut assignment = partial_assignment("interrupted");
+        assignment.stats.state = AgentAssignmentState::Running;
+
+        fail_interrupted_assignment(&mut assignment);
+
+        assert_eq!(assignment.stats.complete, 3);
+        assert_eq!(assignment.stats.failed, 2);
+        assert_eq!(
+            assignment.tasks[2].status,
+            TaskStatus::Failed(ObjectSkippedReason::AssignmentError)
+        );
+        match &assignment.stats.state {
+            AgentAssignmentState::Complete(Some(failed)) => {
+                assert_eq!(failed.len(), 2)
+            }
+            state => panic!("Unexpected assignment state: {:?}", state),
+        }
+    }
+
+    #[test]
+    fn assignment_hash_ignores_order_and_status() {
+        let tasks = vec![
+            task("a", TaskStatus::Pending),
+            task("b", TaskStatus::Pending),
+        ];
+        let reordered = vec![
+            task("b", TaskStatus::Complete),
+            task("a", TaskStatus::Pending),
+        ];
+        assert_eq!(assignment_hash(&tasks), assignment_hash(&reordered));
+
+        let mut changed = tasks.clone();
+        changed[1].md5sum = "def".to_string();
+        assert_ne!(assignment_hash(&tasks), assignment_hash(&changed));
+
+        let assignment = Assignment::new(tasks.clone(), "hashed");
+        assert_eq!(assignment.hash, Some(assignment_hash(&tasks)));
+    }
+
+    #[test]
+    fn validate_assignment_checks_hash() {
+        let tasks = vec![task("a", TaskStatus::Pending)];
+        let payload = AssignmentPayload::new("hashed".to_string(), tasks);
+        let body = Chunk::from(serde_json::to_vec(&payload).unwrap());
+        assert!(validate_assignment(&body).is_ok());
+
+        let mut payload = payload;
+        payload.hash = Some("0".repeat(32));
+        let body = Chunk::from(serde_json::to_vec(&payload).unwrap());
+        assert!(validate_assignment(&body).is_err());
+
+        // Payloads from managers that predate the hash are still accepted.
+        payload.hash = None;
+        let body = Chunk::from(serde_json::to_vec(&payload).unwrap());
+        assert!(validate_assignment(&body).is_ok());
+    }
+
+    #[test]
+    fn audit_assignment_hash() {
+        let tasks = vec![task("a", TaskStatus::Pending)];
+        let payload = AssignmentPayload::new("audit".to_string(), tasks)
+            .kind(AssignmentKind::Audit);
+        let body = Chunk::from(serde_json::to_vec(&payload).unwrap());
+        let (uuid, tasks, _, kind) = validate_assignment(&body).unwrap();
+        assert_eq!(kind, AssignmentKind::Audit);
+
+        // An agent that does not know about audits computes the hash of a
+        // download, and rejects the assignment.
+        assert_ne!(payload.hash, Some(assignment_hash(&tasks)));
+
+        let assignment = Assignment::with_kind(tasks, &uuid, kind);
+        assert_eq!(assignment.hash, payload.hash);
+
+        // Download assignments are sent as they were before audits existed.
+        let payload = payload.kind(AssignmentKind::Download);
+        let json = serde_json::to_value(&payload).unwrap();
+        assert!(json.get("kind").is_none());
+        assert_eq!(payload.hash, Some(assignment_hash(&payload.tasks)));
+    }
+
+    #[test]
+    fn validate_assignment_checks_callback() {
+        let tasks = vec![task("a", TaskStatus::Pending)];
+        let url = "http://rebalancer.domain/jobs/1/assignments";
+        let payload = AssignmentPayload::new("called".to_string(), tasks)
+            .callback(Some(url.to_string()));
+        let body = Chunk::from(serde_json::to_vec(&payload).unwrap());
+        let (_, _, callback, _) = validate_assignment(&body).unwrap();
+        assert_eq!(callback, Some(url.to_string()));
+
+        for bad in &["not a url", "ftp://rebalancer.domain/jobs"] {
+            let payload = payload.clone().callback(Some(bad.to_string()));
+            let body = Chunk::from(serde_json::to_vec(&payload).unwrap());
+            assert!(validate_assignment(&body).is_err());
+        }
+    }
+
+    #[test]
+    fn config_update_parsing() {
+        let update: AgentConfigUpdate = serde_json::from_str(
+            r#"{"max_bytes_per_sec": 52428800, "workers_per_assignment": 4}"#,
+        )
+        .unwrap();
+        assert_eq!(update.throttle.max_bytes_per_sec, Some(52428800));
+        assert_eq!(update.throttle.max_disk_busy_pct, None);
+        assert_eq!(update.workers_per_assignment, Some(4));
+        assert!(update.validate().is_ok());
+        assert!(!update.is_empty());
+
+        // Managers that only know about the throttle are still understood.
+        let update: AgentConfigUpdate =
+            serde_json::from_str(r#"{"max_disk_busy_pct": 70}"#).unwrap();
+        assert_eq!(update.workers_per_assignment, None);
+
+        let update: AgentConfigUpdate =
+            serde_json::from_str(r#"{"workers_per_assignment": 0}"#).unwrap();
+        assert!(update.validate().is_err());
+
+        assert!(AgentConfigUpdate::default().is_empty());
+    }
+
+    #[test]
+    fn callback_retry_backoff() {
+        assert_eq!(callback_retry_delay(0), CALLBACK_RETRY_DELAY);
+        assert_eq!(callback_retry_delay(1), CALLBACK_RETRY_DELAY * 2);
+        assert_eq!(callback_retry_delay(2), CALLBACK_RETRY_DELAY * 4);
+        assert_eq!(callback_retry_delay(10), CALLBACK_MAX_RETRY_DELAY);
+        assert_eq!(callback_retry_delay(64), CALLBACK_MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn callback_saved_with_assignment() {
+        let uuid = Uuid::new_v4().to_string();
+        let dir = std::env::temp_dir().join(format!("rebalancer-{}", &uuid));
+        let dir = dir.to_str().unwrap().to_string();
+        create_dir(&dir);
+
+        let url = "http://rebalancer.domain/jobs/1/assignments".to_string();
+        let mut assignment = partial_assignment(&uuid);
+        assignment.callback = Some(url.clone());
+        assignment_save(&uuid, &dir, Arc::new(RwLock::new(assignment)));
+
+        let recalled = assignment_recall(format!("{}/{}", &dir, &uuid))
+            .unwrap()
+            .read()
+            .unwrap()
+            .clone();
+        storage::global()
+            .remove(&format!("{}/{}", &dir, &uuid))
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(recalled.callback, Some(url));
+
+        // The callback is for the agent's own use, the manager does not need
+        // to see it.
+        let json = serde_json::to_value(&recalled).unwrap();
+        assert!(json.get("callback").is_none());
+    }
+
+    #[test]
+    fn kind_saved_with_assignment() {
+        let uuid = Uuid::new_v4().to_string();
+        let dir = std::env::temp_dir().join(format!("rebalancer-{}", &uuid));
+        let dir = dir.to_str().unwrap().to_string();
+        create_dir(&dir);
+
+        let tasks = partial_assignment(&uuid).tasks;
+        let assignment =
+            Assignment::with_kind(tasks, &uuid, AssignmentKind::Audit);
+        let hash = assignment.hash.clone();
+        assignment_save(&uuid, &dir, Arc::new(RwLock::new(assignment)));
+
+        let recalled = assignment_recall(format!("{}/{}", &dir, &uuid))
+            .unwrap()
+            .read()
+            .unwrap()
+            .clone();
+        storage::global()
+            .remove(&format!("{}/{}", &dir, &uuid))
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(recalled.kind, AssignmentKind::Audit);
+        assert_eq!(recalled.hash, hash);
+    }
+
+    #[test]
+    fn audit_file_checks() {
+        let uuid = Uuid::new_v4().to_string();
+        let dir = std::env::temp_dir().join(format!("rebalancer-{}", &uuid));
+        let dir = dir.to_str().unwrap().to_string();
+        create_dir(&dir);
+
+        let contents = b"audited object";
+        let mut hasher = Md5::new();
+        hasher.input(&contents[..]);
+        let md5sum = base64::encode(&hasher.result());
+
+        let path = format!("{}/object", &dir);
+        File::create(&path).unwrap().write_all(contents).unwrap();
+        let len = contents.len() as u64;
+
+        let intact = audit_file(&path, &md5sum, len);
+        let unknown_size = audit_file(&path, &md5sum, 0);
+        let wrong_size = audit_file(&path, &md5sum, len + 1);
+        let corrupt = audit_file(&path, "AAAAAAAAAAAAAAAAAAAAAA==", len);
+        let missing = audit_file(&format!("{}/missing", &dir), &md5sum, len);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(intact, Ok(()));
+        assert_eq!(unknown_size, Ok(()));
+        assert_eq!(wrong_size, Err(ObjectSkippedReason::MD5Mismatch));
+        assert_eq!(corrupt, Err(ObjectSkippedReason::MD5Mismatch));
+        assert_eq!(missing, Err(ObjectSkippedReason::ObjectMissing));
+    }
+
+    #[test]
+    fn checkpoint_saves_progress() {
+        let uuid = Uuid::new_v4().to_string();
+        let dir = std::env::temp_dir().join(format!("rebalancer-{}", &uuid));
+        let dir = dir.to_str().unwrap().to_string();
+        create_dir(&dir);
+
+        let mut fresh = partial_assignment(&uuid);
+        for t in fresh.tasks.iter_mut() {
+            t.set_status(TaskStatus::Pending);
+        }
+        fresh.stats = AgentAssignmentStats::new(3);
+        assignment_save(&uuid, &dir, Arc::new(RwLock::new(fresh)));
+
+        let progress = Arc::new(RwLock::new(partial_assignment(&uuid)));
+        assignment_checkpoint(&uuid, &dir, &progress).unwrap();
+
+        let recalled = assignment_recall(format!("{}/{}", &dir, &uuid))
+            .unwrap()
+            .read()
+            .unwrap()
+            .clone();
+        storage::global()
+            .remove(&format!("{}/{}", &dir, &uuid))
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(recalled.stats.complete, 2);
+        assert_eq!(recalled.stats.failed, 1);
+        assert_eq!(failed_tasks(&recalled).len(), 1);
+
+        let mut statuses: Vec<(String, TaskStatus)> = recalled
+            .tasks
+            .into_iter()
+            .map(|t| (t.object_id, t.status))
+            .collect();
+        statuses.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            statuses,
+            vec![
+                ("a".to_string(), TaskStatus::Complete),
+                (
+                    "b".to_string(),
+                    TaskStatus::Failed(ObjectSkippedReason::MD5Mismatch)
+                ),
+                ("c".to_string(), TaskStatus::Pending),
+            ]
+        );
+    }
+
+    #[test]
+    fn alternate_sources_saved_with_assignment() {
+        let uuid = Uuid::new_v4().to_string();
+        let dir = std::env::temp_dir().join(format!("rebalancer-{}", &uuid));
+        let dir = dir.to_str().unwrap().to_string();
+        create_dir(&dir);
+
+        let shark = |id: &str| MantaObjectShark {
+            datacenter: "dc2".to_string(),
+            manta_storage_id: id.to_string(),
+        };
+        let mut assignment = partial_assignment(&uuid);
+        assignment.tasks[0].alternate_sources =
+            vec![shark("3.stor.domain"), shark("2.stor.domain")];
+        assignment_save(&uuid, &dir, Arc::new(RwLock::new(assignment)));
+
+        let recalled = assignment_recall(format!("{}/{}", &dir, &uuid))
+            .unwrap()
+            .read()
+            .unwrap()
+            .clone();
+        storage::global()
+            .remove(&format!("{}/{}", &dir, &uuid))
+            .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        for task in recalled.tasks.iter() {
+            let sources: Vec<&str> = task
+                .sources()
+                .map(|s| s.manta_storage_id.as_str())
+                .collect();
+            if task.object_id == "a" {
+                assert_eq!(
+                    sources,
+                    vec!["1.stor.domain", "3.stor.domain", "2.stor.domain"]
+                );
+            } else {
+                assert_eq!(sources, vec!["1.stor.domain"]);
+            }
+        }
+    }
+
+    #[test]
+    fn failover_on_source_failures_only() {
+        assert!(is_source_failure(ObjectSkippedReason::SourceOtherError));
+        assert!(is_source_failure(ObjectSkippedReason::HTTPStatusCode(404)));
+        assert!(is_source_failure(ObjectSkippedReason::ObjectTruncated));
+        assert!(is_source_failure(ObjectSkippedReason::MD5Mismatch));
+        assert!(!is_source_failure(ObjectSkippedReason::AgentFSError));
+
+        let mut t = task("a", TaskStatus::Complete);
+        assert!(!t.failed_over());
+        t.served_by = Some("1.stor.domain".to_string());
+        assert!(!t.failed_over());
+        t.served_by = Some("2.stor.domain".to_string());
+        assert!(t.failed_over());
+
+        // Stats saved by older agents have no failover count.
+        let stats: AgentAssignmentStats = serde_json::from_str(
+            r#"{"state": "Scheduled", "failed": 0, "complete": 0, "total": 3}"#,
+        )
+        .unwrap();
+        assert_eq!(stats.failovers, 0);
+    }
+
+    // A reader that returns `data` and then fails, like a connection that
+    // is closed part way through a response body.
+    struct BrokenReader {
+        data: Vec<u8>,
+        done: bool,
+    }
+
+    impl Read for BrokenReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.done {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::ConnectionAborted,
+                    "connection closed",
+                ));
+            }
+            self.done = true;
+            let len = min(buf.len(), self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn copy_distinguishes_read_errors() {
+        let mut reader = BrokenReader {
+            data: vec![7; 10],
+            done: false,
+        };
+        let mut out = Vec::new();
+
+        match throttled_copy(&mut reader, &mut out) {
+            Err(CopyError::Read { received, .. }) => assert_eq!(received, 10),
+            result => panic!("Unexpected copy result: {:?}", result),
+        }
+        assert_eq!(out.len(), 10);
+    }
+
+    #[test]
+    fn checksum_computed_while_writing() {
+        let contents = b"streamed object";
+        let mut expected = Md5::new();
+        expected.input(&contents[..]);
+
+        let mut reader = &contents[..];
+        let mut writer = Md5Writer::new(Vec::new());
+        let bytes = throttled_copy(&mut reader, &mut writer).unwrap();
+        let (out, md5sum) = writer.finish();
+
+        assert_eq!(bytes, contents.len() as u64);
+        assert_eq!(out, contents.to_vec());
+        assert_eq!(md5sum, base64::encode(&expected.result()));
+    }
+
+    #[test]
+    fn download_committed_to_new_directory() {
+        let uuid = Uuid::new_v4().to_string();
+        let dir = std::env::temp_dir().join(format!("rebalancer-{}", &uuid));
+        let dir = dir.to_str().unwrap().to_string();
+        create_dir(&dir);
+
+        let tmp = format!("{}/object.tmp", &dir);
+        File::create(&tmp).unwrap().write_all(b"object").unwrap();
+
+        let dst = format!("{}/owner/object", &dir);
+        let committed = commit_download(&tmp, &dst, FsyncPolicy::Full);
+        let contents = fs::read(&dst);
+        let tmp_exists = Path::new(&tmp).exists();
+        let missing = commit_download(&tmp, &dst, FsyncPolicy::Never);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(committed.is_ok());
+        assert_eq!(contents.unwrap(), b"object".to_vec());
+        assert!(!tmp_exists);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn download_config_parsing() {
+        let config: ConfigDownload =
+            toml::from_str(r#"fsync = "full""#).unwrap();
+        assert_eq!(config.fsync, FsyncPolicy::Full);
+
+        let config: ConfigDownload = toml::from_str("").unwrap();
+        assert_eq!(config.fsync, FsyncPolicy::File);
+
+        assert!(
+            toml::from_str::<ConfigDownload>(r#"fsync = "always""#).is_err()
+        );
+    }
+
+    #[test]
+    fn short_download_is_truncated() {
+        assert_eq!(
+            check_content_length(10, Some(20)),
+            Err(ObjectSkippedReason::ObjectTruncated)
+        );
+        assert_eq!(check_content_length(20, Some(20)), Ok(()));
+        // Without a Content-Length only the checksum can catch a short read.
+        assert_eq!(check_content_length(10, None), Ok(()));
+    }
+
+    #[test]
+    fn disk_space_preflight() {
+        let tasks = vec![
+            task("a", TaskStatus::Pending),
+            task("b", TaskStatus::Complete),
+            task("c", TaskStatus::Pending),
+        ];
+        assert_eq!(assignment_bytes(&tasks), 2048);
+
+        assert!(check_disk_space(2048, Some(2048)).is_ok());
+        assert!(check_disk_space(2048, Some(2047)).is_err());
+        // Unknown free space does not hold up the assignment.
+        assert!(check_disk_space(2048, None).is_ok());
+    }
+
+    #[test]
+    fn df_output_parsing() {
+        let out =
+            "Filesystem            kbytes    used   avail capacity  Mounted on
+zones/manta        959307264 1234567 958072697     1%    /manta
+";
+        assert_eq!(parse_df_available(out), Some(958072697 * 1024));
+
+        // GNU df wraps the line after a long filesystem name.
+        let wrapped =
+            "Filesystem     1K-blocks      Used Available Use% Mounted on
+/dev/mapper/a-very-long-volume-name
+                959307264   1234567 958072697   1% /manta
+";
+        assert_eq!(parse_df_available(wrapped), Some(958072697 * 1024));
+
+        assert_eq!(parse_df_available(""), None);
+        assert_eq!(parse_df_available("Filesystem kbytes used avail\n"), None);
+    }
+}
@@ -15,7 +15,7 @@ use hyper::{Request, Response};
 use lazy_static::lazy_static;
 use prometheus::{
     opts, register_counter, register_counter_vec, register_histogram, Counter,
-    CounterVec, Encoder, Gauge, Histogram, TextEncoder,
+    CounterVec, Encoder, Gauge, GaugeVec, Histogram, TextEncoder,
 };
 use serde_derive::Deserialize;
 use slog::{error, info, Logger};
@@ -60,6 +60,7 @@ pub enum Metrics {
     MetricsCounterVec(CounterVec),
     MetricsCounter(Counter),
     MetricsGauge(Gauge),
+    MetricsGaugeVec(GaugeVec),
     MetricsHistogram(Histogram),
 }
 
@@ -113,6 +114,44 @@ pub fn gauge_set<S: ::std::hash::BuildHasher>(
     }
 }
 
+// Add to (or, with a negative value, subtract from) the gauge identified by
+// the given label values.  Unlike counter_vec_inc_by() there is no implicit
+// "total" bucket; a gauge vector is typically keyed by something like a job
+// id where a sum across label values is not meaningful.
+pub fn gauge_vec_add<S: ::std::hash::BuildHasher>(
+    metrics: &HashMap<&'static str, Metrics, S>,
+    key: &str,
+    label_values: &[&str],
+    val: f64,
+) {
+    match metrics.get(key) {
+        Some(metric) => {
+            if let Metrics::MetricsGaugeVec(g) = metric {
+                g.with_label_values(label_values).add(val);
+            }
+        }
+        None => error!(slog_scope::logger(), "Invalid metric: {}", key),
+    }
+}
+
+pub fn gauge_vec_set<S: ::std::hash::BuildHasher>(
+    metrics: &HashMap<&'static str, Metrics, S>,
+    key: &str,
+    label_values: &[&str],
+    val: usize,
+) {
+    let num = val as f64;
+
+    match metrics.get(key) {
+        Some(metric) => {
+            if let Metrics::MetricsGaugeVec(g) = metric {
+                g.with_label_values(label_values).set(num);
+            }
+        }
+        None => error!(slog_scope::logger(), "Invalid metric: {}", key),
+    }
+}
+
 #[allow(irrefutable_let_patterns)]
 pub fn counter_vec_inc<S: ::std::hash::BuildHasher>(
     metrics: &HashMap<&'static str, Metrics, S>,
@@ -0,0 +1,521 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// SQLite storage for agent assignments.
+//
+// Every assignment is persisted in its own SQLite database, named after the
+// assignment uuid.  While an assignment is being processed its database is
+// written (checkpointed) and read (by `GET /assignments/:uuid') from several
+// threads, so databases are opened in WAL mode, which lets readers proceed
+// while a writer holds the database, and connections are handed out from a
+// small per-database pool instead of being opened for every operation.
+//
+// WAL mode trades the rollback journal for a write-ahead log that grows until
+// it is checkpointed back in to the database.  A maintenance thread does this
+// periodically for every database with open connections, vacuums databases
+// that have accumulated a significant number of free pages, and closes the
+// connections of databases that have not been used for a while.
+
+use std::collections::HashMap;
+use std::fs;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use lazy_static::lazy_static;
+use rusqlite::{Connection, NO_PARAMS};
+use serde_derive::{Deserialize, Serialize};
+
+static DEFAULT_POOL_SIZE: usize = 4;
+static DEFAULT_BUSY_TIMEOUT_MS: u64 = 5000;
+static DEFAULT_MAINTENANCE_INTERVAL_SECS: u64 = 300;
+
+// Vacuum a database once at least this percentage of its pages are free.
+static VACUUM_FREE_PCT: i64 = 25;
+
+// Files that SQLite keeps alongside a database in WAL mode.
+static SIDECAR_SUFFIXES: [&str; 3] = ["-wal", "-shm", "-journal"];
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ConfigStorage {
+    // Maximum number of idle connections kept open per database.
+    pub pool_size: usize,
+    // How long (in milliseconds) a connection waits for a lock held by
+    // another connection before giving up.
+    pub busy_timeout_ms: u64,
+    // Number of seconds between maintenance passes.
+    pub maintenance_interval_secs: u64,
+}
+
+impl Default for ConfigStorage {
+    fn default() -> Self {
+        Self {
+            pool_size: DEFAULT_POOL_SIZE,
+            busy_timeout_ms: DEFAULT_BUSY_TIMEOUT_MS,
+            maintenance_interval_secs: DEFAULT_MAINTENANCE_INTERVAL_SECS,
+        }
+    }
+}
+
+// Health of an assignment database, reported along with the assignment
+// stats.  Times are in seconds since the epoch.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct StorageHealth {
+    pub journal_mode: String,
+    pub page_count: i64,
+    pub freelist_count: i64,
+    pub idle_connections: usize,
+    // Frames that were in the write-ahead log at the last checkpoint.
+    pub wal_frames: Option<i64>,
+    pub last_checkpoint: Option<u64>,
+    pub last_vacuum: Option<u64>,
+    // The most recent maintenance error, if the last pass failed.
+    pub last_error: Option<String>,
+}
+
+#[derive(Default)]
+struct Maintenance {
+    wal_frames: Option<i64>,
+    last_checkpoint: Option<u64>,
+    last_vacuum: Option<u64>,
+    last_error: Option<String>,
+}
+
+struct DatabasePool {
+    path: String,
+    idle: Mutex<Vec<Connection>>,
+    last_used: Mutex<Instant>,
+    maintenance: Mutex<Maintenance>,
+}
+
+impl DatabasePool {
+    fn new(path: &str) -> DatabasePool {
+        DatabasePool {
+            path: path.to_string(),
+            idle: Mutex::new(Vec::new()),
+            last_used: Mutex::new(Instant::now()),
+            maintenance: Mutex::new(Maintenance::default()),
+        }
+    }
+}
+
+// A connection borrowed from a database pool.  It is returned to the pool
+// when dropped, unless the pool already holds as many idle connections as it
+// is configured to.
+pub struct PooledConnection {
+    conn: Option<Connection>,
+    pool: Arc<DatabasePool>,
+    pool_size: usize,
+}
+
+impl Deref for PooledConnection {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().expect("pooled connection")
+    }
+}
+
+impl DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn.as_mut().expect("pooled connection")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            let mut idle = self.pool.idle.lock().unwrap();
+            if idle.len() < self.pool_size {
+                idle.push(conn);
+            }
+        }
+    }
+}
+
+pub struct Storage {
+    config: Mutex<ConfigStorage>,
+    pools: Mutex<HashMap<String, Arc<DatabasePool>>>,
+}
+
+lazy_static! {
+    static ref STORAGE: Storage = Storage::new(ConfigStorage::default());
+}
+
+// The storage shared by every thread in the agent.
+pub fn global() -> &'static Storage {
+    &STORAGE
+}
+
+impl Storage {
+    pub fn new(config: ConfigStorage) -> Storage {
+        Storage {
+            config: Mutex::new(config),
+            pools: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn configure(&self, config: ConfigStorage) {
+        *self.config.lock().unwrap() = config;
+    }
+
+    pub fn maintenance_interval(&self) -> Duration {
+        let secs = self.config.lock().unwrap().maintenance_interval_secs;
+        Duration::from_secs(std::cmp::max(secs, 1))
+    }
+
+    fn pool(&self, path: &str) -> Arc<DatabasePool> {
+        let mut pools = self.pools.lock().unwrap();
+        Arc::clone(
+            pools
+                .entry(path.to_string())
+                .or_insert_with(|| Arc::new(DatabasePool::new(path))),
+        )
+    }
+
+    // Borrow a connection to the database at `path', creating the database
+    // if it does not yet exist.
+    pub fn connection(&self, path: &str) -> Result<PooledConnection, String> {
+        self.borrow(path, true)
+    }
+
+    // Maintenance borrows connections without marking the database as in
+    // use, so that the connections of idle databases are eventually closed.
+    fn borrow(
+        &self,
+        path: &str,
+        in_use: bool,
+    ) -> Result<PooledConnection, String> {
+        let config = self.config.lock().unwrap().clone();
+        let pool = self.pool(path);
+        if in_use {
+            *pool.last_used.lock().unwrap() = Instant::now();
+        }
+
+        let idle = pool.idle.lock().unwrap().pop();
+        let conn = match idle {
+            Some(conn) => conn,
+            None => open(path, config.busy_timeout_ms)?,
+        };
+
+        Ok(PooledConnection {
+            conn: Some(conn),
+            pool,
+            pool_size: std::cmp::max(config.pool_size, 1),
+        })
+    }
+
+    // Close every pooled connection to the database at `path'.  Connections
+    // that are currently borrowed are closed when they are dropped.  Closing
+    // the last connection to a database removes its write-ahead log.
+    pub fn close(&self, path: &str) {
+        if let Some(pool) = self.pools.lock().unwrap().remove(path) {
+            pool.idle.lock().unwrap().clear();
+        }
+    }
+
+    // Close the database at `path' and remove it, along with any files that
+    // SQLite keeps alongside it.
+    pub fn remove(&self, path: &str) -> std::io::Result<()> {
+        self.close(path);
+        fs::remove_file(path)?;
+
+        for suffix in SIDECAR_SUFFIXES.iter() {
+            let sidecar = format!("{}{}", path, suffix);
+            if Path::new(&sidecar).exists() {
+                fs::remove_file(&sidecar)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Report the health of the database at `path', or None if it does not
+    // exist.
+    pub fn health(&self, path: &str) -> Option<StorageHealth> {
+        if !Path::new(path).exists() {
+            return None;
+        }
+
+        let conn = match self.connection(path) {
+            Ok(conn) => conn,
+            Err(e) => {
+                return Some(StorageHealth {
+                    last_error: Some(e),
+                    ..StorageHealth::default()
+                });
+            }
+        };
+
+        let mut health = StorageHealth::default();
+
+        if let Err(e) = (|| -> rusqlite::Result<()> {
+            health.journal_mode = pragma(&conn, "journal_mode")?;
+            health.page_count = pragma(&conn, "page_count")?;
+            health.freelist_count = pragma(&conn, "freelist_count")?;
+            Ok(())
+        })() {
+            health.last_error = Some(format!("Health query error: {}", e));
+            return Some(health);
+        }
+
+        let pool = Arc::clone(&conn.pool);
+        drop(conn);
+        health.idle_connections = pool.idle.lock().unwrap().len();
+
+        let maintenance = pool.maintenance.lock().unwrap();
+        health.wal_frames = maintenance.wal_frames;
+        health.last_checkpoint = maintenance.last_checkpoint;
+        health.last_vacuum = maintenance.last_vacuum;
+        health.last_error = maintenance.last_error.clone();
+
+        Some(health)
+    }
+
+    // Checkpoint the write-ahead log of the database at `path' in to the
+    // database, returning the number of frames that were in the log.
+    pub fn checkpoint(&self, path: &str) -> Result<i64, String> {
+        let conn = self.borrow(path, false)?;
+        let frames = conn
+            .query_row("PRAGMA wal_checkpoint(TRUNCATE)", NO_PARAMS, |row| {
+                row.get::<_, i64>(1)
+            })
+            .map_err(|e| format!("Checkpoint error: {}", e))?;
+
+        let mut maintenance = conn.pool.maintenance.lock().unwrap();
+        maintenance.wal_frames = Some(frames);
+        maintenance.last_checkpoint = Some(now_secs());
+        Ok(frames)
+    }
+
+    // Vacuum the database at `path' if enough of its pages are free to make
+    // it worthwhile.  Returns whether the database was vacuumed.
+    pub fn vacuum(&self, path: &str) -> Result<bool, String> {
+        let conn = self.borrow(path, false)?;
+        let (pages, free): (i64, i64) = (|| -> rusqlite::Result<_> {
+            Ok((
+                pragma(&conn, "page_count")?,
+                pragma(&conn, "freelist_count")?,
+            ))
+        })()
+        .map_err(|e| format!("Vacuum error: {}", e))?;
+
+        if pages == 0 || free * 100 < pages * VACUUM_FREE_PCT {
+            return Ok(false);
+        }
+
+        conn.execute_batch("VACUUM")
+            .map_err(|e| format!("Vacuum error: {}", e))?;
+
+        conn.pool.maintenance.lock().unwrap().last_vacuum = Some(now_secs());
+        Ok(true)
+    }
+
+    // Perform a maintenance pass over every database with open connections.
+    pub fn maintain(&self) {
+        let interval = self.maintenance_interval();
+        let pools: Vec<Arc<DatabasePool>> =
+            self.pools.lock().unwrap().values().cloned().collect();
+
+        for pool in pools {
+            // The assignment has been completed or deleted.
+            if !Path::new(&pool.path).exists() {
+                self.close(&pool.path);
+                continue;
+            }
+
+            let result = self
+                .checkpoint(&pool.path)
+                .and_then(|_| self.vacuum(&pool.path));
+
+            if let Err(e) = &result {
+                warn!("Maintenance of {} failed: {}", pool.path, e);
+            }
+            pool.maintenance.lock().unwrap().last_error = result.err();
+
+            // Hold on to connections only for databases that are in use.
+            // They are reopened on demand.
+            if pool.last_used.lock().unwrap().elapsed() >= interval {
+                self.close(&pool.path);
+            }
+        }
+    }
+}
+
+// Whether `name' is a file that SQLite keeps alongside a database, rather
+// than a database itself.
+pub fn is_sidecar_file(name: &str) -> bool {
+    SIDECAR_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+fn open(path: &str, busy_timeout_ms: u64) -> Result<Connection, String> {
+    let conn = Connection::open(path)
+        .map_err(|e| format!("DB error opening {}: {}", path, e))?;
+
+    conn.busy_timeout(Duration::from_millis(busy_timeout_ms))
+        .map_err(|e| format!("DB error setting busy timeout: {}", e))?;
+
+    // The journal mode is persistent, but setting it is cheap and covers
+    // databases written before WAL mode was introduced.  NORMAL
+    // synchronization is durable in WAL mode across application crashes,
+    // which is all that checkpointing assignments relies on.
+    let mode: String = pragma(&conn, "journal_mode=WAL")
+        .map_err(|e| format!("DB error enabling WAL mode: {}", e))?;
+    if mode != "wal" {
+        warn!("Database {} is in {} journal mode", path, mode);
+    }
+
+    conn.execute_batch("PRAGMA synchronous=NORMAL")
+        .map_err(|e| format!("DB error setting synchronous mode: {}", e))?;
+
+    Ok(conn)
+}
+
+fn pragma<T: rusqlite::types::FromSql>(
+    conn: &Connection,
+    pragma: &str,
+) -> rusqlite::Result<T> {
+    conn.query_row(&format!("PRAGMA {}", pragma), NO_PARAMS, |row| row.get(0))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// Start a thread that performs storage maintenance periodically.
+pub fn start_maintenance() {
+    let res = thread::Builder::new()
+        .name(String::from("Rebalancer Storage"))
+        .spawn(move || loop {
+            let storage = global();
+            thread::sleep(storage.maintenance_interval());
+            storage.maintain();
+        });
+
+    if let Err(e) = res {
+        error!("Unable to start storage maintenance: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temp_db() -> (String, String) {
+        let dir = std::env::temp_dir()
+            .join(format!("rebalancer-storage-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let dir = dir.to_str().unwrap().to_string();
+        let path = format!("{}/{}", dir, Uuid::new_v4());
+        (dir, path)
+    }
+
+    #[test]
+    fn connections_use_wal_and_are_pooled() {
+        let (dir, path) = temp_db();
+        let storage = Storage::new(ConfigStorage {
+            pool_size: 2,
+            ..ConfigStorage::default()
+        });
+
+        {
+            let a = storage.connection(&path).unwrap();
+            let b = storage.connection(&path).unwrap();
+            let c = storage.connection(&path).unwrap();
+            a.execute_batch("CREATE TABLE t (v INTEGER)").unwrap();
+            b.execute("INSERT INTO t VALUES (1)", NO_PARAMS).unwrap();
+            let n: i64 = c
+                .query_row("SELECT count(*) FROM t", NO_PARAMS, |r| r.get(0))
+                .unwrap();
+            assert_eq!(n, 1);
+        }
+
+        let health = storage.health(&path).unwrap();
+        assert_eq!(health.journal_mode, "wal");
+        assert_eq!(health.idle_connections, 2);
+        assert!(health.page_count > 0);
+        assert!(health.last_checkpoint.is_none());
+
+        assert!(storage.checkpoint(&path).is_ok());
+        assert!(storage.health(&path).unwrap().last_checkpoint.is_some());
+
+        storage.remove(&path).unwrap();
+        assert!(storage.health(&path).is_none());
+        for suffix in SIDECAR_SUFFIXES.iter() {
+            assert!(!Path::new(&format!("{}{}", path, suffix)).exists());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn vacuum_reclaims_free_pages() {
+        let (dir, path) = temp_db();
+        let storage = Storage::new(ConfigStorage::default());
+
+        {
+            let conn = storage.connection(&path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE t (v BLOB);
+                 WITH RECURSIVE n(i) AS
+                     (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 200)
+                 INSERT INTO t SELECT zeroblob(4096) FROM n;",
+            )
+            .unwrap();
+        }
+        assert_eq!(storage.vacuum(&path), Ok(false));
+
+        storage
+            .connection(&path)
+            .unwrap()
+            .execute_batch("DELETE FROM t")
+            .unwrap();
+        storage.checkpoint(&path).unwrap();
+        assert_eq!(storage.vacuum(&path), Ok(true));
+
+        let health = storage.health(&path).unwrap();
+        assert_eq!(health.freelist_count, 0);
+        assert!(health.last_vacuum.is_some());
+
+        storage.remove(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn maintenance_forgets_removed_databases() {
+        let (dir, path) = temp_db();
+        let storage = Storage::new(ConfigStorage::default());
+
+        storage
+            .connection(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE t (v INTEGER)")
+            .unwrap();
+        assert_eq!(storage.pools.lock().unwrap().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+        storage.maintain();
+        assert!(storage.pools.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn sidecar_files() {
+        let uuid = Uuid::new_v4().to_string();
+        assert!(!is_sidecar_file(&uuid));
+        assert!(is_sidecar_file(&format!("{}-wal", uuid)));
+        assert!(is_sidecar_file(&format!("{}-shm", uuid)));
+    }
+}
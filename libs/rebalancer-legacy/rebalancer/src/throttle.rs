@@ -0,0 +1,411 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/*
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+// Adaptive download throttle for the rebalancer agent.
+//
+// An agent processing an evacuation can easily saturate the disks of the
+// storage node it runs on, starving mako of the I/O it needs to serve
+// regular Manta traffic.  The throttle enforces an aggregate bytes/sec budget
+// shared by every download thread on the agent (a simple token bucket) and,
+// optionally, adapts that budget to the observed utilization of the local
+// disks: when the busiest disk exceeds the configured ceiling, the rate is
+// cut in half; while the disks are below the ceiling, the rate is allowed to
+// recover gradually until it reaches the operator supplied ceiling (or until
+// it no longer constrains downloads, in which case the throttle is lifted).
+//
+// The limits can be adjusted at runtime through the agent's `/config`
+// endpoint, so operators do not need to restart the agent (and interrupt any
+// in-flight assignments) in order to slow it down.
+
+use std::process::Command;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+use serde_derive::{Deserialize, Serialize};
+
+// Regardless of how busy the disks are, never throttle below this rate.
+// Stalling downloads entirely would cause assignments to time out on the
+// manager, which is worse than a slow evacuation.
+pub static MIN_RATE_BYTES_PER_SEC: u64 = 1024 * 1024;
+
+// Amount of data that the copy loop reads before consulting the throttle.
+pub static THROTTLE_CHUNK_SIZE: usize = 128 * 1024;
+
+static DEFAULT_SAMPLE_INTERVAL_SECS: u64 = 5;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ConfigThrottle {
+    // Ceiling on the aggregate download rate of the agent in bytes/sec.  A
+    // value of 0 means that there is no fixed ceiling.
+    pub max_bytes_per_sec: u64,
+    // Maximum utilization (percent busy) that the busiest local disk should
+    // reach before the agent starts slowing itself down.  A value of 0
+    // disables adaptive throttling.
+    pub max_disk_busy_pct: u8,
+    // Number of seconds between disk utilization samples.
+    pub sample_interval_secs: u64,
+}
+
+impl Default for ConfigThrottle {
+    fn default() -> Self {
+        Self {
+            max_bytes_per_sec: 0,
+            max_disk_busy_pct: 0,
+            sample_interval_secs: DEFAULT_SAMPLE_INTERVAL_SECS,
+        }
+    }
+}
+
+// Body of a `PUT /config' request.  Only the fields that are present are
+// updated, everything else retains its current value.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ThrottleUpdate {
+    pub max_bytes_per_sec: Option<u64>,
+    pub max_disk_busy_pct: Option<u8>,
+}
+
+// Snapshot of the throttle state returned to clients of the `/config'
+// endpoint.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThrottleStatus {
+    pub config: ConfigThrottle,
+    // The rate currently being enforced in bytes/sec.  0 means unlimited.
+    pub effective_bytes_per_sec: u64,
+    // The most recent utilization sample of the busiest local disk.
+    pub disk_busy_pct: Option<u8>,
+}
+
+struct ThrottleState {
+    config: ConfigThrottle,
+    // The rate currently being enforced.  This will differ from the
+    // configured ceiling when adaptive throttling has kicked in.
+    rate: u64,
+    // Tokens (bytes) currently available.  This is permitted to go negative,
+    // in which case the caller that drove it negative sleeps off the debt.
+    available: f64,
+    last_refill: Instant,
+    // Bytes consumed since the last utilization sample, used to measure the
+    // actual throughput of the agent.
+    window_bytes: u64,
+    window_start: Instant,
+    disk_busy_pct: Option<u8>,
+}
+
+pub struct Throttle {
+    state: Mutex<ThrottleState>,
+}
+
+lazy_static! {
+    static ref THROTTLE: Throttle = Throttle::new(ConfigThrottle::default());
+}
+
+// The throttle shared by all download threads in the agent.
+pub fn global() -> &'static Throttle {
+    &THROTTLE
+}
+
+impl Throttle {
+    pub fn new(config: ConfigThrottle) -> Throttle {
+        let now = Instant::now();
+        Throttle {
+            state: Mutex::new(ThrottleState {
+                rate: config.max_bytes_per_sec,
+                available: config.max_bytes_per_sec as f64,
+                config,
+                last_refill: now,
+                window_bytes: 0,
+                window_start: now,
+                disk_busy_pct: None,
+            }),
+        }
+    }
+
+    // Replace the throttle configuration wholesale.  This is used when the
+    // agent first reads its configuration file.
+    pub fn configure(&self, config: ConfigThrottle) {
+        let mut state = self.state.lock().unwrap();
+        state.rate = config.max_bytes_per_sec;
+        state.available = state.rate as f64;
+        state.config = config;
+    }
+
+    // Apply a partial update received through the `/config' endpoint.
+    pub fn update(&self, update: &ThrottleUpdate) -> Result<(), String> {
+        if let Some(pct) = update.max_disk_busy_pct {
+            if pct > 100 {
+                return Err(format!(
+                    "max_disk_busy_pct must be between 0 and 100: {}",
+                    pct
+                ));
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(pct) = update.max_disk_busy_pct {
+            state.config.max_disk_busy_pct = pct;
+        }
+
+        if let Some(rate) = update.max_bytes_per_sec {
+            state.config.max_bytes_per_sec = rate;
+            // Start over from the new ceiling.  If the disks are still too
+            // busy, the next sample will pull the rate back down.
+            state.rate = rate;
+            state.available = rate as f64;
+        }
+
+        info!("Throttle configuration updated: {:?}", state.config);
+        Ok(())
+    }
+
+    pub fn status(&self) -> ThrottleStatus {
+        let state = self.state.lock().unwrap();
+        ThrottleStatus {
+            config: state.config.clone(),
+            effective_bytes_per_sec: state.rate,
+            disk_busy_pct: state.disk_busy_pct,
+        }
+    }
+
+    pub fn sample_interval(&self) -> Duration {
+        let secs = self.state.lock().unwrap().config.sample_interval_secs;
+        Duration::from_secs(std::cmp::max(secs, 1))
+    }
+
+    // Account for `bytes` having been downloaded, sleeping for as long as is
+    // required to keep the agent at or below the effective rate.
+    pub fn consume(&self, bytes: usize) {
+        let delay = {
+            let mut state = self.state.lock().unwrap();
+            let now = Instant::now();
+
+            state.window_bytes += bytes as u64;
+
+            if state.rate == 0 {
+                state.last_refill = now;
+                return;
+            }
+
+            // Refill the bucket, allowing at most one second worth of burst.
+            let rate = state.rate as f64;
+            let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+            state.available = (state.available + elapsed * rate).min(rate);
+            state.last_refill = now;
+            state.available -= bytes as f64;
+
+            if state.available >= 0.0 {
+                return;
+            }
+
+            Duration::from_secs_f64(-state.available / rate)
+        };
+
+        thread::sleep(delay);
+    }
+
+    // Adjust the effective rate based on a new utilization sample of the
+    // busiest local disk.
+    pub fn adjust(&self, disk_busy_pct: Option<u8>) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let window = now.duration_since(state.window_start).as_secs_f64();
+        let measured = if window > 0.0 {
+            (state.window_bytes as f64 / window) as u64
+        } else {
+            0
+        };
+
+        state.window_bytes = 0;
+        state.window_start = now;
+        state.disk_busy_pct = disk_busy_pct;
+
+        let ceiling = state.config.max_bytes_per_sec;
+        let target = state.config.max_disk_busy_pct;
+
+        let busy = match disk_busy_pct {
+            Some(b) if target > 0 => b,
+            _ => {
+                // Adaptive throttling is disabled, or we were unable to
+                // obtain a sample.  Fall back to the fixed ceiling.
+                state.rate = ceiling;
+                return;
+            }
+        };
+
+        let new_rate = next_rate(state.rate, ceiling, measured, busy, target);
+
+        if new_rate != state.rate {
+            debug!(
+                "Throttle: disk busy {}% (ceiling {}%), measured {} B/s, \
+                 rate {} -> {} B/s",
+                busy, target, measured, state.rate, new_rate
+            );
+            state.rate = new_rate;
+            state.available = state.available.min(new_rate as f64);
+        }
+    }
+}
+
+// Compute the next effective rate.  The rate is cut in half when the disks
+// are busier than the target and recovers by a quarter of its value per
+// sample otherwise.  A rate of 0 means "unlimited".
+fn next_rate(
+    current: u64,
+    ceiling: u64,
+    measured: u64,
+    busy: u8,
+    target: u8,
+) -> u64 {
+    if busy > target {
+        // When we are not currently limited, start from what the agent is
+        // actually achieving, rather than from infinity.
+        let base = match current {
+            0 => measured,
+            c => c,
+        };
+        let halved = std::cmp::max(base / 2, MIN_RATE_BYTES_PER_SEC);
+
+        return match ceiling {
+            0 => halved,
+            c => std::cmp::min(halved, c),
+        };
+    }
+
+    if current == 0 {
+        return ceiling;
+    }
+
+    let grown = current + std::cmp::max(current / 4, MIN_RATE_BYTES_PER_SEC);
+
+    match ceiling {
+        // With no fixed ceiling, lift the throttle entirely once it is
+        // comfortably above what the agent is actually downloading.
+        0 if grown > measured.saturating_mul(2) => 0,
+        0 => grown,
+        c => std::cmp::min(grown, c),
+    }
+}
+
+// Obtain the utilization of the busiest local disk using iostat(1M).  The
+// first report from iostat contains averages since boot, so we ask for two
+// reports and use the second one.  Returns None if the sample could not be
+// obtained.
+pub fn sample_disk_busy(interval: Duration) -> Option<u8> {
+    let secs = std::cmp::max(interval.as_secs(), 1).to_string();
+    let output =
+        match Command::new("iostat").args(&["-xn", &secs, "2"]).output() {
+            Ok(o) => o,
+            Err(e) => {
+                warn!("Unable to run iostat: {}", e);
+                return None;
+            }
+        };
+
+    if !output.status.success() {
+        warn!("iostat exited with {}", output.status);
+        return None;
+    }
+
+    parse_iostat_busy(&String::from_utf8_lossy(&output.stdout))
+}
+
+// Parse the `%b' column of the last report in `iostat -xn' output and return
+// the highest value across all devices.
+fn parse_iostat_busy(output: &str) -> Option<u8> {
+    let last_report = output.rsplit("extended device statistics").next()?;
+    let mut lines = last_report.lines().skip_while(|l| !l.contains("%b"));
+    let header: Vec<&str> = lines.next()?.split_whitespace().collect();
+    let busy_idx = header.iter().position(|c| *c == "%b")?;
+
+    lines
+        .filter_map(|l| l.split_whitespace().nth(busy_idx))
+        .filter_map(|b| b.parse::<u8>().ok())
+        .max()
+}
+
+// Start a thread that periodically samples local disk utilization and adjusts
+// the global throttle accordingly.
+pub fn start_sampler() {
+    let res = thread::Builder::new()
+        .name(String::from("Rebalancer Throttle"))
+        .spawn(move || loop {
+            let throttle = global();
+            let interval = throttle.sample_interval();
+
+            // Sampling is comparatively expensive, skip it when adaptive
+            // throttling is switched off.
+            let busy = if throttle.status().config.max_disk_busy_pct > 0 {
+                sample_disk_busy(interval)
+            } else {
+                thread::sleep(interval);
+                None
+            };
+
+            throttle.adjust(busy);
+        });
+
+    if let Err(e) = res {
+        error!("Unable to start throttle sampler: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MB: u64 = 1024 * 1024;
+
+    #[test]
+    fn next_rate_halves_when_busy() {
+        assert_eq!(next_rate(100 * MB, 100 * MB, 100 * MB, 95, 80), 50 * MB);
+        // Unlimited: start from what was measured.
+        assert_eq!(next_rate(0, 0, 40 * MB, 95, 80), 20 * MB);
+        // Never drop below the floor.
+        assert_eq!(next_rate(MB, 0, MB, 95, 80), MIN_RATE_BYTES_PER_SEC);
+    }
+
+    #[test]
+    fn next_rate_recovers_when_idle() {
+        assert_eq!(next_rate(40 * MB, 100 * MB, 40 * MB, 10, 80), 50 * MB);
+        // Capped at the ceiling.
+        assert_eq!(next_rate(90 * MB, 100 * MB, 90 * MB, 10, 80), 100 * MB);
+        // Lifted entirely once well above the measured rate.
+        assert_eq!(next_rate(40 * MB, 0, 10 * MB, 10, 80), 0);
+        assert_eq!(next_rate(0, 100 * MB, 10 * MB, 10, 80), 100 * MB);
+    }
+
+    #[test]
+    fn update_rejects_bad_percentage() {
+        let throttle = Throttle::new(ConfigThrottle::default());
+        let update = ThrottleUpdate {
+            max_bytes_per_sec: None,
+            max_disk_busy_pct: Some(101),
+        };
+        assert!(throttle.update(&update).is_err());
+    }
+
+    #[test]
+    fn parse_iostat() {
+        let output = "\
+                    extended device statistics
+    r/s    w/s   kr/s   kw/s wait actv wsvc_t asvc_t  %w  %b device
+    1.0    2.0   10.0   20.0  0.0  0.0    0.0    1.0   0  99 c0t0d0
+                    extended device statistics
+    r/s    w/s   kr/s   kw/s wait actv wsvc_t asvc_t  %w  %b device
+    1.0    2.0   10.0   20.0  0.0  0.0    0.0    1.0   0  12 c0t0d0
+    1.0    2.0   10.0   20.0  0.0  0.0    0.0    1.0   0  47 c0t1d0
+";
+        assert_eq!(parse_iostat_busy(output), Some(47));
+        assert_eq!(parse_iostat_busy(""), None);
+    }
+}
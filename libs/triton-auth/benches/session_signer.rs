@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Signing throughput: `sign_request` against a reused `SessionSigner`
+//!
+//! Run with `cargo bench -p triton-auth --bench session_signer`. Each test
+//! key under `tests/keys` is signed with both ways and the mean time per
+//! signature is printed, along with the speedup from reusing the signer.
+//!
+//! Only file keys are measured, so the numbers do not depend on an SSH agent
+//! or token being present. File keys are also where a session signer saves
+//! the most, since `sign_request` re-reads and re-parses the key every call.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use triton_auth::{AuthConfig, KeySource, SessionSigner, sign_request};
+
+const ITERATIONS: u32 = 200;
+
+const KEYS: &[&str] = &["id_rsa", "id_ecdsa", "id_ed25519", "id_dsa"];
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let keys_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/keys");
+
+    println!(
+        "{:<12} {:>16} {:>16} {:>9}",
+        "key", "sign_request", "SessionSigner", "speedup"
+    );
+    for name in KEYS {
+        let config = AuthConfig::new("bench", KeySource::file(keys_dir.join(name)));
+
+        let per_request =
+            time_per_iteration(|| async { sign_request(&config, "GET", "/bench/machines").await })
+                .await?;
+
+        let session = SessionSigner::new(&config).await?;
+        let per_session =
+            time_per_iteration(|| async { session.sign("GET", "/bench/machines").await }).await?;
+
+        println!(
+            "{:<12} {:>16?} {:>16?} {:>8.1}x",
+            name,
+            per_request,
+            per_session,
+            per_request.as_secs_f64() / per_session.as_secs_f64()
+        );
+    }
+    Ok(())
+}
+
+/// Mean time of `f` over [`ITERATIONS`] runs, after one warm-up run
+async fn time_per_iteration<F, Fut, T>(f: F) -> Result<Duration, triton_auth::AuthError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, triton_auth::AuthError>>,
+{
+    f().await?;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(f().await?);
+    }
+    Ok(start.elapsed() / ITERATIONS)
+}
@@ -11,10 +11,16 @@
 //! - Signing data using keys stored in the agent
 //!
 //! The SSH agent is accessed via the `SSH_AUTH_SOCK` environment variable.
+//! On Windows, where that variable is usually unset, the OpenSSH agent
+//! service is reached through its named pipe, `\\.\pipe\openssh-ssh-agent`.
+//!
+//! Keys loaded into the agent as OpenSSH certificates are matched and
+//! returned by the key they certify, so a certificate-bearing identity can be
+//! selected with the fingerprint of the user's ordinary key.
 
 use crate::error::AuthError;
+use crate::fingerprint::public_key_from_blob;
 use crate::ssh_agent::SshAgentClient;
-use ssh_encoding::Decode;
 use ssh_key::PublicKey;
 
 /// Find a key in the SSH agent matching the given fingerprint (MD5 or SHA256)
@@ -66,7 +72,47 @@ fn sign_with_agent_sync(fingerprint: &str, data: &[u8]) -> Result<Vec<u8>, AuthE
     client.sign_data(&identity, data)
 }
 
+/// Sign several pieces of data with the same key from the SSH agent
+///
+/// Unlike calling [`sign_with_agent`] once per item, this makes a single
+/// agent connection and identity lookup for the whole set, so the cost of
+/// reaching the agent (and of any confirmation prompt it shows) is paid once.
+///
+/// # Returns
+/// The public key that signed, and the raw signature bytes for each item in
+/// the order given
+pub async fn sign_all_with_agent(
+    fingerprint: &str,
+    data: Vec<Vec<u8>>,
+) -> Result<(PublicKey, Vec<Vec<u8>>), AuthError> {
+    let fp = fingerprint.to_string();
+
+    tokio::task::spawn_blocking(move || sign_all_with_agent_sync(&fp, &data))
+        .await
+        .map_err(|e| AuthError::AgentError(format!("Task join error: {}", e)))?
+}
+
+/// Synchronous version of sign_all_with_agent
+fn sign_all_with_agent_sync(
+    fingerprint: &str,
+    data: &[Vec<u8>],
+) -> Result<(PublicKey, Vec<Vec<u8>>), AuthError> {
+    let mut client = SshAgentClient::connect_env()?;
+    let identity = client.find_identity(fingerprint)?;
+    let pub_key = parse_public_key_from_bytes(&identity.raw_key)?;
+
+    let signatures = data
+        .iter()
+        .map(|item| client.sign_data(&identity, item))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((pub_key, signatures))
+}
+
 /// List all keys available in the SSH agent
+///
+/// A key that is present both bare and as a certificate (as `ssh-add` loads
+/// it when an `id_*-cert.pub` file is present) is listed once.
 pub async fn list_agent_keys() -> Result<Vec<(PublicKey, String)>, AuthError> {
     tokio::task::spawn_blocking(list_agent_keys_sync)
         .await
@@ -79,7 +125,11 @@ fn list_agent_keys_sync() -> Result<Vec<(PublicKey, String)>, AuthError> {
     let identities = client.list_identities()?;
 
     let mut keys = Vec::new();
+    let mut seen = std::collections::HashSet::new();
     for ident in identities {
+        if !seen.insert(ident.sha256_fp.clone()) {
+            continue;
+        }
         if let Ok(pub_key) = parse_public_key_from_bytes(&ident.raw_key)
             .inspect_err(|e| tracing::debug!("Skipping unparseable agent key: {}", e))
         {
@@ -103,13 +153,9 @@ pub async fn is_agent_available() -> bool {
 
 /// Parse an ssh_key::PublicKey from raw SSH wire format bytes
 fn parse_public_key_from_bytes(bytes: &[u8]) -> Result<PublicKey, AuthError> {
-    use ssh_key::public::KeyData;
-
     // The raw_key is in SSH wire format, which ssh_key can decode
-    let key_data = KeyData::decode(&mut &bytes[..])
-        .map_err(|e| AuthError::KeyLoadError(format!("Failed to parse public key: {}", e)))?;
-
-    Ok(PublicKey::from(key_data))
+    public_key_from_blob(bytes)
+        .map_err(|e| AuthError::KeyLoadError(format!("Failed to parse public key: {}", e)))
 }
 
 #[cfg(test)]
@@ -0,0 +1,306 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Batch request signing
+//!
+//! [`sign_request`](crate::sign_request) makes a round trip to the SSH agent
+//! (two, counting the key lookup) for every request, which dominates the run
+//! time of tooling that issues hundreds of requests. [`sign_batch`] instead
+//! signs a list of `(method, path)` pairs up front against a single `Date`
+//! header, using one agent connection and one key lookup for the lot.
+//!
+//! # Clock skew
+//!
+//! CloudAPI rejects a request whose `Date` header is more than
+//! [`SERVER_CLOCK_SKEW_SECS`] (five minutes) away from the server's clock. Every
+//! signature in a batch covers the same `Date`, so the whole batch goes stale
+//! at once. A [`SignedBatch`] is only handed out while it is younger than
+//! [`BATCH_VALIDITY_SECS`], which keeps a margin for drift between the client and
+//! server clocks; after that [`SignedBatch::requests`] returns
+//! [`AuthError::SignatureExpired`] and the caller should sign a new batch.
+//! Batches should therefore be sized to what can be sent within a few
+//! minutes, not to the full workload.
+
+use chrono::{DateTime, Duration, Utc};
+use std::path::Path;
+
+use crate::error::AuthError;
+use crate::key_loader::{KeyLoader, KeySource};
+use crate::legacy_pem::LegacyPrivateKey;
+use crate::pkcs11::{self, PinSource};
+use crate::signature::{KeyType, RequestSigner, encode_signature};
+use crate::{AuthConfig, agent, convert_agent_sig, create_signer_with_fp, fingerprint};
+
+/// How far (in seconds, either direction) CloudAPI allows a request's `Date`
+/// header to be from its own clock
+pub const SERVER_CLOCK_SKEW_SECS: i64 = 300;
+
+/// How long (in seconds) after signing a [`SignedBatch`] may be used
+///
+/// One minute short of [`SERVER_CLOCK_SKEW_SECS`], leaving room for the
+/// client clock to be behind the server's.
+pub const BATCH_VALIDITY_SECS: i64 = 240;
+
+/// A single request signed as part of a [`SignedBatch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedRequest {
+    /// HTTP method, as given to [`sign_batch`]
+    pub method: String,
+    /// Request path, as given to [`sign_batch`]
+    pub path: String,
+    /// Authorization header value
+    pub authorization: String,
+}
+
+/// Requests signed against a shared `Date` header
+#[derive(Debug, Clone)]
+pub struct SignedBatch {
+    date: String,
+    signed_at: DateTime<Utc>,
+    requests: Vec<SignedRequest>,
+}
+
+impl SignedBatch {
+    /// The `Date` header value that must accompany every request in the batch
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    /// When the batch was signed (the time in the `Date` header)
+    pub fn signed_at(&self) -> DateTime<Utc> {
+        self.signed_at
+    }
+
+    /// When the batch stops being usable
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.signed_at + Duration::seconds(BATCH_VALIDITY_SECS)
+    }
+
+    /// Whether the batch has expired as of `now`
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at()
+    }
+
+    /// Number of requests in the batch
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Whether the batch has no requests
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// The signed requests, in the order they were given to [`sign_batch`]
+    ///
+    /// # Errors
+    /// Returns [`AuthError::SignatureExpired`] once the batch is older than
+    /// [`BATCH_VALIDITY_SECS`], as the server would reject the signatures.
+    pub fn requests(&self) -> Result<&[SignedRequest], AuthError> {
+        self.requests_at(Utc::now())
+    }
+
+    /// The signed requests, checked for expiry against `now`
+    pub fn requests_at(&self, now: DateTime<Utc>) -> Result<&[SignedRequest], AuthError> {
+        if self.is_expired_at(now) {
+            return Err(AuthError::SignatureExpired(format!(
+                "batch signed at {} expired at {}; sign a new batch",
+                self.signed_at.to_rfc3339(),
+                self.expires_at().to_rfc3339()
+            )));
+        }
+        Ok(&self.requests)
+    }
+}
+
+/// Sign a batch of `(method, path)` pairs with a shared `Date` header
+///
+/// Resolves the key from `config.key_source` once, the same way
+/// [`sign_request`](crate::sign_request) does, and signs every request with
+/// it. Agent-held keys are signed over a single agent connection, and
+/// token-held keys in a single PKCS#11 session.
+///
+/// Only `(request-target)` and `date` can be signed this way, as the batch
+/// has no request headers to take other values from.
+///
+/// # Errors
+/// Returns an error if key loading or any signature fails, if
+/// `config.signed_headers` includes other headers, or
+/// [`AuthError::SignatureExpired`] if signing took so long that the batch
+/// expired before it could be returned.
+pub async fn sign_batch(
+    config: &AuthConfig,
+    requests: &[(&str, &str)],
+) -> Result<SignedBatch, AuthError> {
+    let signed_at = Utc::now();
+    let date = RequestSigner::date_header_at(signed_at);
+
+    let no_headers = http::HeaderMap::new();
+    let signing_strings: Vec<Vec<u8>> = requests
+        .iter()
+        .map(|(method, path)| {
+            config
+                .signed_headers
+                .signing_string(method, path, &date, &no_headers)
+                .map(String::into_bytes)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let (signer, signatures) = match &config.key_source {
+        KeySource::None => return Err(AuthError::unsigned()),
+        KeySource::Agent { fingerprint } => {
+            sign_with_agent(config, fingerprint, signing_strings).await?
+        }
+        KeySource::File {
+            path: key_path,
+            passphrase,
+        } => {
+            let key = KeyLoader::load_legacy_from_file(key_path, passphrase.as_deref()).await?;
+            sign_with_key(config, &key, &signing_strings)?
+        }
+        KeySource::Auto { fingerprint } => {
+            match sign_with_agent(config, fingerprint, signing_strings.clone()).await {
+                Ok(signed) => signed,
+                Err(e) => {
+                    tracing::debug!(
+                        "SSH agent batch signing failed, falling back to file: {}",
+                        e
+                    );
+                    let key = KeyLoader::load_legacy_from_common_paths(fingerprint).await?;
+                    sign_with_key(config, &key, &signing_strings)?
+                }
+            }
+        }
+        KeySource::Pkcs11 {
+            module_path,
+            slot,
+            key_label,
+            pin_source,
+        } => {
+            sign_with_token(
+                config,
+                module_path,
+                *slot,
+                key_label.as_deref(),
+                pin_source,
+                signing_strings,
+            )
+            .await?
+        }
+    };
+
+    let batch = SignedBatch {
+        date,
+        signed_at,
+        requests: requests
+            .iter()
+            .zip(signatures)
+            .map(|((method, path), signature_b64)| SignedRequest {
+                method: method.to_string(),
+                path: path.to_string(),
+                authorization: signer.authorization_header(&signature_b64),
+            })
+            .collect(),
+    };
+
+    // A batch that took the whole window to sign is of no use to the caller
+    batch.requests()?;
+    Ok(batch)
+}
+
+/// Sign every signing string with a key from the SSH agent
+async fn sign_with_agent(
+    config: &AuthConfig,
+    fingerprint: &str,
+    signing_strings: Vec<Vec<u8>>,
+) -> Result<(RequestSigner, Vec<String>), AuthError> {
+    let (pub_key, raw_sigs) = agent::sign_all_with_agent(fingerprint, signing_strings).await?;
+    let key_type = KeyType::from_public_key(&pub_key)?;
+    let md5_fp = fingerprint::md5_fingerprint(&pub_key)?;
+
+    let signatures = raw_sigs
+        .iter()
+        .map(|raw_sig| convert_agent_sig(raw_sig, key_type).map(|sig| encode_signature(&sig)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((create_signer_with_fp(config, key_type, &md5_fp), signatures))
+}
+
+/// Sign every signing string with a key on a PKCS#11 token
+async fn sign_with_token(
+    config: &AuthConfig,
+    module_path: &Path,
+    slot: u64,
+    key_label: Option<&str>,
+    pin_source: &PinSource,
+    signing_strings: Vec<Vec<u8>>,
+) -> Result<(RequestSigner, Vec<String>), AuthError> {
+    let (pub_key, sigs) =
+        pkcs11::sign_all_with_token(module_path, slot, key_label, pin_source, signing_strings)
+            .await?;
+    let key_type = KeyType::from_public_key(&pub_key)?;
+    let md5_fp = fingerprint::md5_fingerprint(&pub_key)?;
+    let signatures = sigs.iter().map(|sig| encode_signature(sig)).collect();
+
+    Ok((create_signer_with_fp(config, key_type, &md5_fp), signatures))
+}
+
+/// Sign every signing string with a key loaded from disk
+fn sign_with_key(
+    config: &AuthConfig,
+    key: &LegacyPrivateKey,
+    signing_strings: &[Vec<u8>],
+) -> Result<(RequestSigner, Vec<String>), AuthError> {
+    let key_type = key.key_type()?;
+    let md5_fp = fingerprint::md5_fingerprint_bytes(&key.public_key_blob()?);
+
+    let signatures = signing_strings
+        .iter()
+        .map(|data| key.sign(data).map(|sig| encode_signature(&sig)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((create_signer_with_fp(config, key_type, &md5_fp), signatures))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch_signed_at(signed_at: DateTime<Utc>) -> SignedBatch {
+        SignedBatch {
+            date: RequestSigner::date_header_at(signed_at),
+            signed_at,
+            requests: vec![SignedRequest {
+                method: "GET".to_string(),
+                path: "/test/machines".to_string(),
+                authorization: "Signature ...".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_validity_within_server_skew() {
+        assert!(BATCH_VALIDITY_SECS < SERVER_CLOCK_SKEW_SECS);
+    }
+
+    #[test]
+    fn test_batch_expiry() {
+        let signed_at = Utc::now();
+        let batch = batch_signed_at(signed_at);
+        let validity = Duration::seconds(BATCH_VALIDITY_SECS);
+
+        assert_eq!(batch.expires_at(), signed_at + validity);
+        assert!(batch.requests_at(signed_at).is_ok());
+        assert!(
+            batch
+                .requests_at(signed_at + validity - Duration::seconds(1))
+                .is_ok()
+        );
+
+        let err = batch.requests_at(signed_at + validity).unwrap_err();
+        assert!(matches!(err, AuthError::SignatureExpired(_)));
+    }
+}
@@ -654,6 +654,7 @@ mod tests {
             md5_fp: "".to_string(),
             sha256_fp: "".to_string(),
             raw_key: vec![],
+            certificate: None,
         };
         assert!(SshSigningKey::new(&identity).is_err());
     }
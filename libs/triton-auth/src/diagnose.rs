@@ -0,0 +1,523 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Key discovery diagnostics
+//!
+//! When `KeySource::Auto` cannot find a key, the resulting error only says
+//! that nothing matched. [`diagnose`] repeats the same search (SSH agent
+//! first, then `~/.ssh`) and records every candidate key it looked at, the
+//! MD5 and SHA256 fingerprints it computed for it, and why it was not used.
+//!
+//! The `Display` impl of [`KeyDiagnostics`] formats the report for CLI
+//! output:
+//!
+//! ```text
+//! Looking for key SHA256:Wpa8NxW6Llg34sAhSfudzWRmz2aMMzsGfPoAu0ez768
+//!
+//! SSH agent:
+//!   not available: SSH_AUTH_SOCK environment variable not set. Is ssh-agent running?
+//!
+//! Key files in /home/user/.ssh:
+//!   id_rsa.pub (ssh-rsa)
+//!     MD5:fa:56:a1:6b:cc:04:97:fe:e2:98:54:c4:2e:0d:26:c6
+//!     SHA256:VMEY3GIT7bS01hFFB6kjrLICl1tf2jomkT9JqpsUQmU
+//!     fingerprint does not match
+//! ```
+
+use crate::error::AuthError;
+use crate::fingerprint::{
+    Fingerprint, md5_fingerprint_bytes, parse_public_key_line, public_key_from_blob,
+    sha256_fingerprint_bytes,
+};
+use crate::key_loader::{FALLBACK_KEY_FILES, KeyLoader, private_key_path};
+use crate::ssh_agent::SshAgentClient;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Report of a key discovery attempt
+#[derive(Debug, Clone)]
+pub struct KeyDiagnostics {
+    /// The fingerprint that was searched for, as given
+    pub fingerprint: String,
+    /// Why the fingerprint could not be parsed, if it could not
+    pub fingerprint_error: Option<String>,
+    /// What the SSH agent offered
+    pub agent: AgentReport,
+    /// The directory that was scanned for key files, if one was determined
+    pub ssh_dir: Option<PathBuf>,
+    /// Why the directory could not be scanned, if it could not
+    pub ssh_dir_error: Option<String>,
+    /// Key files found in `ssh_dir`, in the order they were examined
+    pub files: Vec<KeyCandidate>,
+}
+
+/// What the SSH agent offered during key discovery
+#[derive(Debug, Clone)]
+pub enum AgentReport {
+    /// The agent was not consulted
+    NotChecked,
+    /// The agent could not be reached or did not answer
+    Unavailable(String),
+    /// The identities the agent listed
+    Identities(Vec<KeyCandidate>),
+}
+
+/// A key that was considered during discovery
+#[derive(Debug, Clone)]
+pub struct KeyCandidate {
+    /// Where the key came from
+    pub source: CandidateSource,
+    /// SSH key type (e.g. `ssh-ed25519`), when the key could be parsed
+    pub key_type: Option<String>,
+    /// MD5 fingerprint in colon-separated hex format
+    pub md5_fingerprint: Option<String>,
+    /// SHA256 fingerprint in `SHA256:base64` format
+    pub sha256_fingerprint: Option<String>,
+    /// Whether the key matched and, if not, why
+    pub outcome: CandidateOutcome,
+}
+
+/// Where a candidate key came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CandidateSource {
+    /// An identity held by the SSH agent
+    Agent {
+        /// The identity comment (usually the key file path or email)
+        comment: String,
+        /// The agent holds this identity as an OpenSSH certificate
+        certificate: bool,
+    },
+    /// A `.pub` file (public key or OpenSSH certificate)
+    PublicKeyFile(PathBuf),
+    /// A standard `id_*` private key file without a `.pub` companion
+    PrivateKeyFile(PathBuf),
+}
+
+/// Whether a candidate key matched and, if not, why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CandidateOutcome {
+    /// The key matches and can be used for signing
+    Usable,
+    /// The key matches, but its private key is encrypted and needs a
+    /// passphrase
+    Encrypted(PathBuf),
+    /// The key's fingerprint is a different one
+    FingerprintMismatch,
+    /// The public key matches, but there is no private key next to it
+    MissingPrivateKey(PathBuf),
+    /// The public key matches, but its private key could not be loaded
+    PrivateKeyUnusable {
+        /// Path to the private key file
+        path: PathBuf,
+        /// Why the private key could not be loaded
+        reason: String,
+    },
+    /// The private key is encrypted and has no `.pub` companion, so its
+    /// fingerprint cannot be computed without the passphrase
+    EncryptedWithoutPublicKey,
+    /// The file could not be read or parsed as a key
+    Unreadable(String),
+}
+
+impl CandidateOutcome {
+    /// Whether the candidate is the key that was searched for
+    pub fn is_match(&self) -> bool {
+        matches!(self, Self::Usable | Self::Encrypted(_))
+    }
+}
+
+impl KeyDiagnostics {
+    fn new(fingerprint: &str) -> Self {
+        Self {
+            fingerprint: fingerprint.to_string(),
+            fingerprint_error: None,
+            agent: AgentReport::NotChecked,
+            ssh_dir: None,
+            ssh_dir_error: None,
+            files: Vec::new(),
+        }
+    }
+
+    /// The first candidate that matched the fingerprint, agent keys first
+    pub fn matched(&self) -> Option<&KeyCandidate> {
+        let agent_keys = match &self.agent {
+            AgentReport::Identities(keys) => keys.as_slice(),
+            _ => &[],
+        };
+        agent_keys
+            .iter()
+            .chain(self.files.iter())
+            .find(|c| c.outcome.is_match())
+    }
+}
+
+/// Diagnose why the key with the given fingerprint can or cannot be found
+///
+/// Examines the same places as `KeySource::Auto`: the SSH agent, then the
+/// key files in `~/.ssh`. Unlike the lookup itself this never stops at the
+/// first match, so the report covers every candidate.
+pub async fn diagnose(fingerprint: &str) -> KeyDiagnostics {
+    let agent = match tokio::task::spawn_blocking({
+        let fp = fingerprint.to_string();
+        move || agent_candidates(&fp)
+    })
+    .await
+    {
+        Ok(Ok(keys)) => AgentReport::Identities(keys),
+        Ok(Err(e)) => AgentReport::Unavailable(e.to_string()),
+        Err(e) => AgentReport::Unavailable(format!("Task join error: {}", e)),
+    };
+
+    let mut report = match dirs::home_dir() {
+        Some(home) => diagnose_ssh_dir(&home.join(".ssh"), fingerprint).await,
+        None => {
+            let mut report = KeyDiagnostics::new(fingerprint);
+            report.fingerprint_error = Fingerprint::parse(fingerprint).err();
+            report.ssh_dir_error = Some("Could not determine home directory".to_string());
+            report
+        }
+    };
+    report.agent = agent;
+    report
+}
+
+/// Diagnose key discovery in an explicit directory, without the SSH agent
+///
+/// This is the testable core of [`diagnose`], mirroring
+/// `KeyLoader::scan_ssh_dir_for_key`: every `.pub` file is examined, then
+/// the standard `id_*` private keys that have no `.pub` companion.
+pub async fn diagnose_ssh_dir(ssh_dir: &Path, fingerprint: &str) -> KeyDiagnostics {
+    let mut report = KeyDiagnostics::new(fingerprint);
+    report.ssh_dir = Some(ssh_dir.to_path_buf());
+
+    let parsed = match Fingerprint::parse(fingerprint) {
+        Ok(fp) => Some(fp),
+        Err(e) => {
+            report.fingerprint_error = Some(e);
+            None
+        }
+    };
+
+    let mut pub_paths = Vec::new();
+    match tokio::fs::read_dir(ssh_dir).await {
+        Ok(mut dir) => {
+            while let Ok(Some(entry)) = dir.next_entry().await {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) == Some("pub") {
+                    pub_paths.push(path);
+                }
+            }
+        }
+        Err(e) => {
+            report.ssh_dir_error = Some(format!("Failed to read {}: {}", ssh_dir.display(), e));
+            return report;
+        }
+    }
+    pub_paths.sort();
+
+    for path in &pub_paths {
+        report
+            .files
+            .push(public_key_candidate(path, parsed.as_ref()).await);
+    }
+
+    for key_file in FALLBACK_KEY_FILES {
+        let path = ssh_dir.join(key_file);
+        if pub_paths.contains(&ssh_dir.join(format!("{}.pub", key_file)))
+            || !tokio::fs::try_exists(&path).await.unwrap_or(false)
+        {
+            continue;
+        }
+        report
+            .files
+            .push(private_key_candidate(&path, parsed.as_ref()).await);
+    }
+
+    report
+}
+
+/// List the agent's identities and check each against the fingerprint
+fn agent_candidates(fingerprint: &str) -> Result<Vec<KeyCandidate>, AuthError> {
+    let mut client = SshAgentClient::connect_env()?;
+    let identities = client.list_identities()?;
+
+    Ok(identities
+        .into_iter()
+        .map(|ident| {
+            // Match the same way the agent lookup does
+            let outcome = if ident.matches_fingerprint(fingerprint) {
+                CandidateOutcome::Usable
+            } else {
+                CandidateOutcome::FingerprintMismatch
+            };
+            KeyCandidate {
+                source: CandidateSource::Agent {
+                    certificate: ident.is_certificate(),
+                    comment: ident.comment,
+                },
+                key_type: Some(ident.key_type),
+                md5_fingerprint: Some(ident.md5_fp),
+                sha256_fingerprint: Some(ident.sha256_fp),
+                outcome,
+            }
+        })
+        .collect())
+}
+
+/// Examine a `.pub` file and, if it matches, its private key
+async fn public_key_candidate(path: &Path, fingerprint: Option<&Fingerprint>) -> KeyCandidate {
+    let mut candidate = KeyCandidate {
+        source: CandidateSource::PublicKeyFile(path.to_path_buf()),
+        key_type: None,
+        md5_fingerprint: None,
+        sha256_fingerprint: None,
+        outcome: CandidateOutcome::FingerprintMismatch,
+    };
+
+    let pub_data = match tokio::fs::read_to_string(path).await {
+        Ok(data) => data,
+        Err(e) => {
+            candidate.outcome = CandidateOutcome::Unreadable(e.to_string());
+            return candidate;
+        }
+    };
+    let blob = match parse_public_key_line(&pub_data).and_then(|key| key.to_bytes()) {
+        Ok(blob) => blob,
+        Err(e) => {
+            candidate.outcome = CandidateOutcome::Unreadable(e.to_string());
+            return candidate;
+        }
+    };
+    candidate.describe_blob(&blob);
+
+    if !fingerprint.is_some_and(|fp| fp.matches_bytes(&blob)) {
+        return candidate;
+    }
+
+    let priv_path = private_key_path(path);
+    if !tokio::fs::try_exists(&priv_path).await.unwrap_or(false) {
+        candidate.outcome = CandidateOutcome::MissingPrivateKey(priv_path);
+        return candidate;
+    }
+
+    candidate.outcome = match KeyLoader::load_legacy_from_file(&priv_path, None).await {
+        Ok(_) => CandidateOutcome::Usable,
+        Err(e) if is_encrypted_error(&e) => CandidateOutcome::Encrypted(priv_path),
+        Err(e) => CandidateOutcome::PrivateKeyUnusable {
+            path: priv_path,
+            reason: e.to_string(),
+        },
+    };
+    candidate
+}
+
+/// Examine a private key file that has no `.pub` companion
+async fn private_key_candidate(path: &Path, fingerprint: Option<&Fingerprint>) -> KeyCandidate {
+    let mut candidate = KeyCandidate {
+        source: CandidateSource::PrivateKeyFile(path.to_path_buf()),
+        key_type: None,
+        md5_fingerprint: None,
+        sha256_fingerprint: None,
+        outcome: CandidateOutcome::FingerprintMismatch,
+    };
+
+    let blob = match KeyLoader::load_legacy_from_file(path, None)
+        .await
+        .and_then(|key| key.public_key_blob())
+    {
+        Ok(blob) => blob,
+        Err(e) if is_encrypted_error(&e) => {
+            candidate.outcome = CandidateOutcome::EncryptedWithoutPublicKey;
+            return candidate;
+        }
+        Err(e) => {
+            candidate.outcome = CandidateOutcome::Unreadable(e.to_string());
+            return candidate;
+        }
+    };
+    candidate.describe_blob(&blob);
+
+    if fingerprint.is_some_and(|fp| fp.matches_bytes(&blob)) {
+        candidate.outcome = CandidateOutcome::Usable;
+    }
+    candidate
+}
+
+/// Whether a key loading error means the key needs a passphrase
+///
+/// Same heuristic as `KeyLoader::scan_ssh_dir_for_key`: not every format's
+/// parser reports encryption as `KeyEncrypted`.
+fn is_encrypted_error(e: &AuthError) -> bool {
+    if matches!(e, AuthError::KeyEncrypted(_)) {
+        return true;
+    }
+    let msg = e.to_string();
+    msg.contains("encrypted") || msg.contains("Encrypted")
+}
+
+impl KeyCandidate {
+    /// Fill in the key type and fingerprints from a public key blob
+    fn describe_blob(&mut self, blob: &[u8]) {
+        self.key_type = public_key_from_blob(blob)
+            .ok()
+            .map(|key| key.algorithm().as_str().to_string());
+        self.md5_fingerprint = Some(md5_fingerprint_bytes(blob));
+        self.sha256_fingerprint = Some(sha256_fingerprint_bytes(blob));
+    }
+}
+
+impl fmt::Display for CandidateOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usable => write!(f, "matches"),
+            Self::Encrypted(path) => {
+                write!(f, "matches, private key {} is encrypted", path.display())
+            }
+            Self::FingerprintMismatch => write!(f, "fingerprint does not match"),
+            Self::MissingPrivateKey(path) => {
+                write!(
+                    f,
+                    "matches, but private key {} does not exist",
+                    path.display()
+                )
+            }
+            Self::PrivateKeyUnusable { path, reason } => write!(
+                f,
+                "matches, but private key {} could not be loaded: {}",
+                path.display(),
+                reason
+            ),
+            Self::EncryptedWithoutPublicKey => write!(
+                f,
+                "encrypted, and no .pub file to compute the fingerprint from"
+            ),
+            Self::Unreadable(reason) => write!(f, "could not be read: {}", reason),
+        }
+    }
+}
+
+impl fmt::Display for KeyCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            CandidateSource::Agent {
+                comment,
+                certificate,
+            } => {
+                write!(f, "  {}", comment)?;
+                if *certificate {
+                    write!(f, " [certificate]")?;
+                }
+            }
+            CandidateSource::PublicKeyFile(path) | CandidateSource::PrivateKeyFile(path) => {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy())
+                    .unwrap_or_else(|| path.to_string_lossy());
+                write!(f, "  {}", name)?;
+            }
+        }
+        if let Some(key_type) = &self.key_type {
+            write!(f, " ({})", key_type)?;
+        }
+        writeln!(f)?;
+        if let Some(md5) = &self.md5_fingerprint {
+            writeln!(f, "    MD5:{}", md5)?;
+        }
+        if let Some(sha256) = &self.sha256_fingerprint {
+            writeln!(f, "    {}", sha256)?;
+        }
+        writeln!(f, "    {}", self.outcome)
+    }
+}
+
+impl fmt::Display for KeyDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Looking for key {}", self.fingerprint)?;
+        if let Some(e) = &self.fingerprint_error {
+            writeln!(f, "  invalid fingerprint: {}", e)?;
+        }
+
+        match &self.agent {
+            AgentReport::NotChecked => {}
+            AgentReport::Unavailable(reason) => {
+                writeln!(f, "\nSSH agent:\n  not available: {}", reason)?;
+            }
+            AgentReport::Identities(keys) => {
+                writeln!(f, "\nSSH agent:")?;
+                if keys.is_empty() {
+                    writeln!(f, "  no identities")?;
+                }
+                for key in keys {
+                    write!(f, "{}", key)?;
+                }
+            }
+        }
+
+        match &self.ssh_dir {
+            Some(dir) => writeln!(f, "\nKey files in {}:", dir.display())?,
+            None => writeln!(f, "\nKey files:")?,
+        }
+        if let Some(e) = &self.ssh_dir_error {
+            writeln!(f, "  {}", e)?;
+        } else if self.files.is_empty() {
+            writeln!(f, "  no key files found")?;
+        }
+        for file in &self.files {
+            write!(f, "{}", file)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_is_match() {
+        assert!(CandidateOutcome::Usable.is_match());
+        assert!(CandidateOutcome::Encrypted(PathBuf::from("id_rsa")).is_match());
+        assert!(!CandidateOutcome::FingerprintMismatch.is_match());
+        assert!(!CandidateOutcome::MissingPrivateKey(PathBuf::from("id_rsa")).is_match());
+        assert!(!CandidateOutcome::EncryptedWithoutPublicKey.is_match());
+    }
+
+    #[test]
+    fn test_display_agent_unavailable() {
+        let mut report = KeyDiagnostics::new("aa:bb");
+        report.fingerprint_error = Some("bad length".to_string());
+        report.agent = AgentReport::Unavailable("SSH_AUTH_SOCK not set".to_string());
+
+        let text = report.to_string();
+        assert!(text.starts_with("Looking for key aa:bb\n"));
+        assert!(text.contains("invalid fingerprint: bad length"));
+        assert!(text.contains("SSH agent:\n  not available: SSH_AUTH_SOCK not set"));
+        assert!(text.contains("no key files found"));
+    }
+
+    #[test]
+    fn test_display_agent_candidate() {
+        let mut report = KeyDiagnostics::new("SHA256:abc");
+        report.agent = AgentReport::Identities(vec![KeyCandidate {
+            source: CandidateSource::Agent {
+                comment: "me@host".to_string(),
+                certificate: true,
+            },
+            key_type: Some("ssh-ed25519".to_string()),
+            md5_fingerprint: Some("aa:bb".to_string()),
+            sha256_fingerprint: Some("SHA256:xyz".to_string()),
+            outcome: CandidateOutcome::FingerprintMismatch,
+        }]);
+
+        let text = report.to_string();
+        assert!(text.contains(
+            "  me@host [certificate] (ssh-ed25519)\n    MD5:aa:bb\n    SHA256:xyz\n    \
+             fingerprint does not match\n"
+        ));
+        assert!(report.matched().is_none());
+    }
+}
@@ -31,6 +31,14 @@ pub enum AuthError {
     #[error("Signing error: {0}")]
     SigningError(String),
 
+    /// Error talking to a PKCS#11 token or its module
+    #[error("PKCS#11 error: {0}")]
+    Pkcs11Error(String),
+
+    /// Signatures were used outside the server's clock-skew window
+    #[error("Signature expired: {0}")]
+    SignatureExpired(String),
+
     /// Configuration error
     #[error("Configuration error: {0}")]
     ConfigError(String),
@@ -43,3 +51,10 @@ pub enum AuthError {
     #[error("SSH key error: {0}")]
     SshKeyError(#[from] ssh_key::Error),
 }
+
+impl AuthError {
+    /// Error for an attempt to sign with [`KeySource::None`](crate::KeySource::None)
+    pub(crate) fn unsigned() -> Self {
+        AuthError::ConfigError("no signing key configured (key source is None)".into())
+    }
+}
@@ -17,11 +17,34 @@
 //! 1. Parse and understand both formats
 //! 2. Match keys using the provided format
 //! 3. Always use MD5 format in the Authorization header (CloudAPI requirement)
+//!
+//! # OpenSSH certificates
+//!
+//! An OpenSSH certificate (`ssh-*-cert-v01@openssh.com`) wraps the user's
+//! public key together with a CA signature. As with `ssh-keygen -l`, the
+//! fingerprint of a certificate is that of the key it certifies, so the same
+//! fingerprint identifies a key whether it is presented bare or certified.
+//! Use [`public_key_from_blob`] and [`parse_public_key_line`] to get at the
+//! certified key before fingerprinting.
+//!
+//! # Public key files
+//!
+//! [`parse_authorized_keys_line`] accepts a line from an `authorized_keys`
+//! file, including any leading options, and [`find_key_by_fingerprint`]
+//! searches a whole `authorized_keys` or `.pub` file for the key matching a
+//! fingerprint in either format. [`KeyFingerprints`] holds both fingerprints
+//! of a key, as printed by `ssh-keygen -l -E md5` and `ssh-keygen -l`.
 
 use base64::Engine;
 use md5::{Digest, Md5};
 use sha2::Sha256;
-use ssh_key::PublicKey;
+use ssh_encoding::Decode;
+use ssh_key::public::KeyData;
+use ssh_key::{Certificate, PublicKey};
+
+/// Suffix shared by all OpenSSH certificate key types
+/// (e.g. `ssh-ed25519-cert-v01@openssh.com`)
+pub const CERT_KEY_TYPE_SUFFIX: &str = "-cert-v01@openssh.com";
 
 /// A parsed SSH key fingerprint (either MD5 or SHA256)
 ///
@@ -230,6 +253,156 @@ pub fn sha256_fingerprint_bytes(key_bytes: &[u8]) -> String {
     format!("SHA256:{}", b64)
 }
 
+/// Check whether an SSH wire format blob is an OpenSSH certificate
+///
+/// Only the leading key type string is inspected; the rest of the blob is
+/// not validated.
+pub fn is_certificate_blob(blob: &[u8]) -> bool {
+    let Some(len_bytes) = blob.get(..4) else {
+        return false;
+    };
+    let len = u32::from_be_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]);
+    blob.get(4..)
+        .and_then(|rest| rest.get(..len as usize))
+        .is_some_and(|key_type| key_type.ends_with(CERT_KEY_TYPE_SUFFIX.as_bytes()))
+}
+
+/// Parse a public key from an SSH wire format blob
+///
+/// If the blob is an OpenSSH certificate, the certified public key is
+/// returned (not the CA's signing key), so that the result fingerprints the
+/// same as the bare key.
+pub fn public_key_from_blob(blob: &[u8]) -> Result<PublicKey, ssh_key::Error> {
+    if is_certificate_blob(blob) {
+        let cert = Certificate::from_bytes(blob)?;
+        return Ok(PublicKey::from(cert.public_key().clone()));
+    }
+
+    let key_data = KeyData::decode(&mut &blob[..])?;
+    Ok(PublicKey::from(key_data))
+}
+
+/// Parse an OpenSSH public key line, as found in a `.pub` file
+///
+/// Accepts both plain public keys (`id_ed25519.pub`) and certificates
+/// (`id_ed25519-cert.pub`). For a certificate the certified public key is
+/// returned and the certificate's comment is carried over.
+pub fn parse_public_key_line(line: &str) -> Result<PublicKey, ssh_key::Error> {
+    match PublicKey::from_openssh(line) {
+        Ok(key) => Ok(key),
+        Err(key_err) => {
+            let Ok(cert) = Certificate::from_openssh(line) else {
+                return Err(key_err);
+            };
+            let mut key = PublicKey::from(cert.public_key().clone());
+            key.set_comment(cert.comment());
+            Ok(key)
+        }
+    }
+}
+
+/// Both fingerprints of a public key, along with its comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyFingerprints {
+    /// MD5 fingerprint (`aa:bb:cc:...`), the format CloudAPI uses in key IDs
+    pub md5: String,
+    /// SHA256 fingerprint (`SHA256:...`), without padding
+    pub sha256: String,
+    /// Key comment (e.g. `user@host`), empty if the key has none
+    pub comment: String,
+}
+
+impl KeyFingerprints {
+    /// Compute the fingerprints of a public key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the public key cannot be encoded to bytes.
+    pub fn from_key(key: &PublicKey) -> Result<Self, ssh_key::Error> {
+        let key_bytes = key.to_bytes()?;
+        Ok(Self {
+            md5: md5_fingerprint_bytes(&key_bytes),
+            sha256: sha256_fingerprint_bytes(&key_bytes),
+            comment: key.comment().to_string(),
+        })
+    }
+
+    /// Check whether `target` is one of these fingerprints
+    pub fn matches(&self, target: &Fingerprint) -> bool {
+        let target_str = target.to_string_repr();
+        match target {
+            Fingerprint::Md5(_) => target_str == self.md5,
+            Fingerprint::Sha256(_) => target_str == self.sha256,
+        }
+    }
+}
+
+/// Parse a line from an `authorized_keys` file
+///
+/// The key may be preceded by options (e.g.
+/// `command="echo hi",no-pty ssh-ed25519 AAAA... user@host`) and followed by
+/// a comment, which is kept on the returned key. Quoted option values may
+/// contain spaces. Plain `.pub` lines and certificates are accepted too (see
+/// [`parse_public_key_line`]).
+///
+/// Returns `Ok(None)` for blank lines and `#` comments.
+///
+/// # Errors
+///
+/// Returns an error if the line holds no parseable public key.
+pub fn parse_authorized_keys_line(line: &str) -> Result<Option<PublicKey>, ssh_key::Error> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    // As in OpenSSH, first try the line as a bare key and only then as
+    // options followed by a key
+    match parse_public_key_line(line) {
+        Ok(key) => Ok(Some(key)),
+        Err(key_err) => {
+            let Some(rest) = skip_key_options(line) else {
+                return Err(key_err);
+            };
+            parse_public_key_line(rest).map(Some)
+        }
+    }
+}
+
+/// Skip the options field at the start of an `authorized_keys` line,
+/// returning the rest of the line (or None if nothing follows the options)
+fn skip_key_options(line: &str) -> Option<&str> {
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                let rest = line[i..].trim_start();
+                return (!rest.is_empty()).then_some(rest);
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Find the key matching `target` in the contents of an `authorized_keys` or
+/// `.pub` file
+///
+/// Blank lines, comments and lines that cannot be parsed are skipped, so one
+/// unsupported key does not hide the others.
+pub fn find_key_by_fingerprint(contents: &str, target: &Fingerprint) -> Option<PublicKey> {
+    contents
+        .lines()
+        .filter_map(|line| parse_authorized_keys_line(line).ok().flatten())
+        .find(|key| target.matches(key))
+}
+
 /// Parse an MD5 fingerprint string into bytes
 ///
 /// Accepts formats:
@@ -437,6 +610,53 @@ mod tests {
         assert!(matches!(fp, Fingerprint::Sha256(_)));
     }
 
+    #[test]
+    fn test_is_certificate_blob() {
+        let mut blob = Vec::new();
+        let key_type = b"ssh-ed25519-cert-v01@openssh.com";
+        blob.extend_from_slice(&(key_type.len() as u32).to_be_bytes());
+        blob.extend_from_slice(key_type);
+        assert!(is_certificate_blob(&blob));
+
+        let mut blob = Vec::new();
+        let key_type = b"ssh-ed25519";
+        blob.extend_from_slice(&(key_type.len() as u32).to_be_bytes());
+        blob.extend_from_slice(key_type);
+        assert!(!is_certificate_blob(&blob));
+
+        assert!(!is_certificate_blob(&[]));
+        assert!(!is_certificate_blob(&[0, 0, 0, 32, b's']));
+    }
+
+    #[test]
+    fn test_skip_key_options() {
+        assert_eq!(
+            skip_key_options("no-pty ssh-ed25519 AAAA c"),
+            Some("ssh-ed25519 AAAA c")
+        );
+        assert_eq!(
+            skip_key_options(r#"command="echo \"a b\"",no-pty  ssh-rsa AAAA"#),
+            Some("ssh-rsa AAAA")
+        );
+        assert_eq!(skip_key_options("no-pty"), None);
+        assert_eq!(
+            skip_key_options(r#"command="unterminated ssh-rsa AAAA"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_authorized_keys_line_skips_comments() {
+        assert!(parse_authorized_keys_line("").unwrap().is_none());
+        assert!(parse_authorized_keys_line("   ").unwrap().is_none());
+        assert!(
+            parse_authorized_keys_line("# ssh-rsa AAAA")
+                .unwrap()
+                .is_none()
+        );
+        assert!(parse_authorized_keys_line("no-pty not-a-key").is_err());
+    }
+
     #[test]
     fn test_sha256_fingerprint_bytes() {
         let test_data = b"test public key data";
@@ -11,8 +11,11 @@
 //!
 //! - Loading SSH keys from files (RSA, ECDSA, Ed25519, DSA)
 //! - SSH agent integration for secure key access
+//! - Keys on PKCS#11 hardware tokens and HSMs ([`pkcs11`], behind the
+//!   `pkcs11` feature)
 //! - Both MD5 and SHA256 fingerprint formats for key identification
 //! - HTTP Signature generation per the CloudAPI authentication scheme
+//! - Key discovery diagnostics ([`diagnose`]) for when a key cannot be found
 //!
 //! # Fingerprint Format Support
 //!
@@ -33,6 +36,32 @@
 //!    c. Sign the string using the configured key
 //!    d. Construct the Authorization header with keyId, algorithm, and signature
 //!
+//! Tooling that issues many requests can use [`sign_batch`] to sign them all
+//! up front against one Date header and one SSH agent session. The batch is
+//! only usable for [`batch::BATCH_VALIDITY_SECS`], inside the server's
+//! clock-skew window; see the [`batch`] module.
+//!
+//! Long-lived clients that sign requests as they go can instead create a
+//! [`SessionSigner`] once, which loads the key and computes its type and
+//! fingerprint up front, and call [`SessionSigner::sign`] per request; see
+//! the [`session`] module.
+//!
+//! Code that should not care how requests are signed, such as a client that
+//! tests want to run without a key, can take an `Arc<dyn Signer>`. The
+//! [`Signer`] trait is implemented by [`AuthConfig`] and [`SessionSigner`],
+//! and by [`FixedSigner`], which signs deterministically without a key; see
+//! the [`signer`] module.
+//!
+//! # Signed Headers
+//!
+//! By default the signature covers `(request-target)` and `date`, as
+//! node-smartdc-auth does. [`AuthConfig::with_signed_headers`] signs a
+//! different list, for example `(request-target) host date content-md5` to
+//! also bind the signature to the server and the request body. Use
+//! [`sign_request_with_headers`] with the request's headers, after
+//! [`SignedHeaders::add_computed_headers`] has added `host` and
+//! `content-md5` to them.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -73,23 +102,36 @@
 
 pub mod agent;
 pub mod auth_scheme;
+pub mod batch;
 pub mod certgen;
+pub mod diagnose;
 pub mod error;
 pub mod fingerprint;
 pub mod http_sig;
 pub mod key_loader;
 pub mod legacy_pem;
+pub mod pkcs11;
+pub mod session;
 pub mod signature;
+pub mod signer;
 pub mod ssh_agent;
 
+pub use batch::{SignedBatch, SignedRequest, sign_batch};
 pub use certgen::{CertGenerator, CertPurpose, DEFAULT_CERT_LIFETIME_DAYS, GeneratedCert};
+pub use diagnose::{KeyDiagnostics, diagnose};
 pub use error::AuthError;
 pub use fingerprint::{
-    Fingerprint, format_fingerprint, md5_fingerprint, parse_fingerprint, sha256_fingerprint,
+    Fingerprint, KeyFingerprints, find_key_by_fingerprint, format_fingerprint, md5_fingerprint,
+    parse_authorized_keys_line, parse_fingerprint, sha256_fingerprint,
 };
 pub use key_loader::{KeyLoader, KeySource};
 pub use legacy_pem::{LegacyPrivateKey, PemKeyFormat};
-pub use signature::{KeyType, RequestSigner, encode_signature, sign_with_key};
+pub use pkcs11::PinSource;
+pub use session::SessionSigner;
+pub use signature::{
+    KeyType, RequestSigner, SignedHeaders, content_md5, encode_signature, sign_with_key,
+};
+pub use signer::{FixedSigner, Signer};
 use std::path::PathBuf;
 
 /// Authentication configuration for CloudAPI requests
@@ -107,6 +149,8 @@ pub struct AuthConfig {
     pub act_as: Option<String>,
     /// CloudAPI version to request
     pub accept_version: Option<String>,
+    /// Headers covered by the signature, in order
+    pub signed_headers: SignedHeaders,
 }
 
 impl AuthConfig {
@@ -123,9 +167,23 @@ impl AuthConfig {
             roles: None,
             act_as: None,
             accept_version: None,
+            signed_headers: SignedHeaders::default(),
         }
     }
 
+    /// Create an AuthConfig that does not sign requests
+    ///
+    /// Uses [`KeySource::None`]; only for testing against servers that do
+    /// not check signatures.
+    pub fn unauthenticated(account: impl Into<String>) -> Self {
+        Self::new(account, KeySource::None)
+    }
+
+    /// Whether requests made with this configuration go unsigned
+    pub fn is_unauthenticated(&self) -> bool {
+        matches!(self.key_source, KeySource::None)
+    }
+
     /// Set RBAC sub-user for this configuration
     pub fn with_user(mut self, user: impl Into<String>) -> Self {
         self.user = Some(user.into());
@@ -150,6 +208,16 @@ impl AuthConfig {
         self
     }
 
+    /// Set the headers to sign (default `(request-target) date`)
+    ///
+    /// Signing `host` or `content-md5` requires the request's headers, so
+    /// sign with [`sign_request_with_headers`]; the CloudAPI clients do this
+    /// and set both headers themselves.
+    pub fn with_signed_headers(mut self, headers: SignedHeaders) -> Self {
+        self.signed_headers = headers;
+        self
+    }
+
     /// Return the account to use in URL paths.
     ///
     /// When `act_as` is set (operator masquerading), the target account
@@ -178,11 +246,14 @@ pub enum KeyProbeResult {
 /// - For `Agent`: checks if the key is in the agent
 /// - For `File`: checks if the file exists and is loadable
 /// - For `Auto`: tries agent first, then scans `.pub` files in `~/.ssh/`
+/// - For `Pkcs11`: logs in to the token and looks for the signing key
+/// - For `None`: there is no key, so it is always ready
 ///
 /// Returns `Ready` if the key can be used immediately, `Encrypted { path }`
 /// if a passphrase is needed, or an error if the key cannot be found.
 pub async fn probe_key(key_source: &KeySource) -> Result<KeyProbeResult, AuthError> {
     match key_source {
+        KeySource::None => Ok(KeyProbeResult::Ready),
         KeySource::Agent { fingerprint } => {
             agent::find_key_in_agent(fingerprint).await?;
             Ok(KeyProbeResult::Ready)
@@ -199,6 +270,15 @@ pub async fn probe_key(key_source: &KeySource) -> Result<KeyProbeResult, AuthErr
                 Err(e) => Err(e),
             }
         }
+        KeySource::Pkcs11 {
+            module_path,
+            slot,
+            key_label,
+            pin_source,
+        } => {
+            pkcs11::find_key_on_token(module_path, *slot, key_label.as_deref(), pin_source).await?;
+            Ok(KeyProbeResult::Ready)
+        }
         KeySource::Auto { fingerprint } => {
             // Try agent first
             let agent_err = match agent::find_key_in_agent(fingerprint).await {
@@ -257,17 +337,41 @@ fn convert_agent_sig(sig_bytes: &[u8], key_type: KeyType) -> Result<Vec<u8>, Aut
 /// header always uses MD5 format (CloudAPI server requirement).
 ///
 /// # Errors
-/// Returns an error if key loading or signing fails
+/// Returns an error if key loading or signing fails, or if the key source is
+/// [`KeySource::None`]. A configuration that signs headers other than
+/// `(request-target)` and `date` needs [`sign_request_with_headers`].
 pub async fn sign_request(
     config: &AuthConfig,
     method: &str,
     path: &str,
+) -> Result<(String, String), AuthError> {
+    sign_request_with_headers(config, method, path, &http::HeaderMap::new()).await
+}
+
+/// Sign an HTTP request whose other signed headers are in `headers`
+///
+/// Like [`sign_request`], for configurations with
+/// [`AuthConfig::signed_headers`] such as `host` or `content-md5`, whose
+/// values are read from `headers`. See
+/// [`SignedHeaders::add_computed_headers`] for setting them.
+pub async fn sign_request_with_headers(
+    config: &AuthConfig,
+    method: &str,
+    path: &str,
+    headers: &http::HeaderMap,
 ) -> Result<(String, String), AuthError> {
     // Generate the date header
     let date = RequestSigner::date_header();
 
+    // The signing string does not depend on the key, so it is built before
+    // the key has been located
+    let signing_string = config
+        .signed_headers
+        .signing_string(method, path, &date, headers)?;
+
     // Determine key type, get public key for MD5 fingerprint, and sign
     let (key_type, signature_b64, md5_fp) = match &config.key_source {
+        KeySource::None => return Err(AuthError::unsigned()),
         KeySource::Agent { fingerprint } => {
             // Find key in agent to determine type
             let pub_key = agent::find_key_in_agent(fingerprint).await?;
@@ -275,9 +379,6 @@ pub async fn sign_request(
             // Always compute MD5 fingerprint for the Authorization header
             let md5_fp = md5_fingerprint(&pub_key)?;
 
-            // Create signing string using MD5 fingerprint
-            let signer = create_signer_with_fp(config, key_type, &md5_fp);
-            let signing_string = signer.signing_string(method, path, &date);
             let raw_sig = agent::sign_with_agent(fingerprint, signing_string.as_bytes()).await?;
             let sig_bytes = convert_agent_sig(&raw_sig, key_type)?;
             (key_type, encode_signature(&sig_bytes), md5_fp)
@@ -293,8 +394,6 @@ pub async fn sign_request(
             let pub_blob = legacy_key.public_key_blob()?;
             let md5_fp = fingerprint::md5_fingerprint_bytes(&pub_blob);
 
-            let signer = create_signer_with_fp(config, key_type, &md5_fp);
-            let signing_string = signer.signing_string(method, path, &date);
             let sig_bytes = legacy_key.sign(signing_string.as_bytes())?;
             (key_type, encode_signature(&sig_bytes), md5_fp)
         }
@@ -306,8 +405,6 @@ pub async fn sign_request(
                     // Always compute MD5 fingerprint for the Authorization header
                     let md5_fp = md5_fingerprint(&pub_key)?;
 
-                    let signer = create_signer_with_fp(config, key_type, &md5_fp);
-                    let signing_string = signer.signing_string(method, path, &date);
                     let raw_sig =
                         agent::sign_with_agent(fingerprint, signing_string.as_bytes()).await?;
                     let sig_bytes = convert_agent_sig(&raw_sig, key_type)?;
@@ -321,13 +418,34 @@ pub async fn sign_request(
                     let pub_blob = legacy_key.public_key_blob()?;
                     let md5_fp = fingerprint::md5_fingerprint_bytes(&pub_blob);
 
-                    let signer = create_signer_with_fp(config, key_type, &md5_fp);
-                    let signing_string = signer.signing_string(method, path, &date);
                     let sig_bytes = legacy_key.sign(signing_string.as_bytes())?;
                     (key_type, encode_signature(&sig_bytes), md5_fp)
                 }
             }
         }
+        KeySource::Pkcs11 {
+            module_path,
+            slot,
+            key_label,
+            pin_source,
+        } => {
+            // The token can be opened, searched and signed with in one
+            // session
+            let (pub_key, mut sigs) = pkcs11::sign_all_with_token(
+                module_path,
+                *slot,
+                key_label.as_deref(),
+                pin_source,
+                vec![signing_string.into_bytes()],
+            )
+            .await?;
+            let key_type = KeyType::from_public_key(&pub_key)?;
+            let md5_fp = md5_fingerprint(&pub_key)?;
+            let sig_bytes = sigs.pop().ok_or_else(|| {
+                AuthError::SigningError("PKCS#11 token returned no signature".into())
+            })?;
+            (key_type, encode_signature(&sig_bytes), md5_fp)
+        }
     };
 
     // Create the authorization header using MD5 fingerprint
@@ -339,7 +457,8 @@ pub async fn sign_request(
 
 /// Helper to create a RequestSigner from config with explicit MD5 fingerprint
 fn create_signer_with_fp(config: &AuthConfig, key_type: KeyType, md5_fp: &str) -> RequestSigner {
-    let signer = RequestSigner::new(&config.account, md5_fp, key_type);
+    let signer = RequestSigner::new(&config.account, md5_fp, key_type)
+        .with_signed_headers(config.signed_headers.clone());
     if let Some(ref user) = config.user {
         signer.with_subuser(user)
     } else {
@@ -364,4 +483,34 @@ mod tests {
             Some(vec!["admin".to_string(), "operator".to_string()])
         );
     }
+
+    #[tokio::test]
+    async fn test_unauthenticated_config_does_not_sign() {
+        let config = AuthConfig::unauthenticated("myaccount");
+        assert!(config.is_unauthenticated());
+        assert!(!AuthConfig::new("myaccount", KeySource::agent("aa:bb")).is_unauthenticated());
+
+        let err = sign_request(&config, "GET", "/myaccount/machines")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::ConfigError(_)));
+        assert!(matches!(
+            probe_key(&config.key_source).await,
+            Ok(KeyProbeResult::Ready)
+        ));
+    }
+
+    #[test]
+    fn test_auth_config_signed_headers() {
+        let config = AuthConfig::new("myaccount", KeySource::agent("aa:bb:cc:dd"));
+        assert_eq!(config.signed_headers, SignedHeaders::default());
+
+        let config = config.with_signed_headers("(request-target) host date".parse().unwrap());
+        let signer = create_signer_with_fp(&config, KeyType::Rsa, "aa:bb:cc:dd");
+        assert!(
+            signer
+                .authorization_header("c2ln")
+                .contains("headers=\"(request-target) host date\"")
+        );
+    }
 }
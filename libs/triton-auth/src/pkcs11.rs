@@ -0,0 +1,574 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Signing with keys held on PKCS#11 tokens
+//!
+//! [`KeySource::Pkcs11`](crate::KeySource::Pkcs11) signs with a private key
+//! that never leaves a hardware token (YubiKey PIV, smart card, network HSM).
+//! The token is reached through its vendor's PKCS#11 module, e.g.
+//! `libykcs11.so` or `opensc-pkcs11.so`.
+//!
+//! The key is the private key with `CKA_SIGN` set in the configured slot,
+//! narrowed down by label when the token holds several. Its public half is
+//! read from the public key object sharing its `CKA_ID`, which gives the key
+//! type and the MD5 fingerprint used in the `keyId`.
+//!
+//! Talking to the token needs the `pkcs11` cargo feature. Without it, every
+//! signing attempt with this source fails with [`AuthError::ConfigError`];
+//! the PIN and key conversion helpers are always available.
+
+use crate::certgen::{der_encode_integer, der_encode_sequence};
+use crate::error::AuthError;
+use ssh_key::PublicKey;
+use ssh_key::public::{EcdsaPublicKey, Ed25519PublicKey, KeyData, RsaPublicKey};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// DER-encoded `CKA_EC_PARAMS` for NIST P-256 (OID 1.2.840.10045.3.1.7)
+const EC_PARAMS_P256: &[u8] = &[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+/// DER-encoded `CKA_EC_PARAMS` for NIST P-384 (OID 1.3.132.0.34)
+const EC_PARAMS_P384: &[u8] = &[0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x22];
+/// DER-encoded `CKA_EC_PARAMS` for NIST P-521 (OID 1.3.132.0.35)
+const EC_PARAMS_P521: &[u8] = &[0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x23];
+/// DER-encoded `CKA_EC_PARAMS` for Ed25519 (OID 1.3.101.112)
+const EC_PARAMS_ED25519: &[u8] = &[0x06, 0x03, 0x2b, 0x65, 0x70];
+/// `CKA_EC_PARAMS` for Ed25519 as a PrintableString, as older tokens report it
+const EC_PARAMS_ED25519_NAME: &[u8] = b"\x13\x0cedwards25519";
+
+/// Where to get the user PIN for a PKCS#11 token
+#[derive(Clone)]
+pub enum PinSource {
+    /// Read the PIN from an environment variable
+    Env(String),
+    /// Read the PIN from the first line of a file
+    File(PathBuf),
+    /// Use this PIN
+    Value(String),
+    /// Do not log in (tokens whose keys need no PIN, or a protected
+    /// authentication path such as a PIN pad)
+    None,
+}
+
+impl fmt::Debug for PinSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PinSource::Env(var) => f.debug_tuple("Env").field(var).finish(),
+            PinSource::File(path) => f.debug_tuple("File").field(path).finish(),
+            PinSource::Value(_) => f.debug_tuple("Value").field(&"[REDACTED]").finish(),
+            PinSource::None => f.write_str("None"),
+        }
+    }
+}
+
+impl PinSource {
+    /// Read the PIN, or `None` if the token should not be logged in to
+    pub fn resolve(&self) -> Result<Option<String>, AuthError> {
+        match self {
+            PinSource::Env(var) => std::env::var(var).map(Some).map_err(|_| {
+                AuthError::ConfigError(format!("PIN environment variable {} is not set", var))
+            }),
+            PinSource::File(path) => {
+                let contents = std::fs::read_to_string(path).map_err(|e| {
+                    AuthError::KeyLoadError(format!(
+                        "Failed to read PIN file {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+                Ok(Some(contents.lines().next().unwrap_or("").to_string()))
+            }
+            PinSource::Value(pin) => Ok(Some(pin.clone())),
+            PinSource::None => Ok(None),
+        }
+    }
+}
+
+/// Build an SSH public key from a token's RSA `CKA_MODULUS` and
+/// `CKA_PUBLIC_EXPONENT`
+pub fn public_key_from_rsa(modulus: &[u8], exponent: &[u8]) -> Result<PublicKey, AuthError> {
+    let key = RsaPublicKey {
+        e: ssh_key::Mpint::from_positive_bytes(exponent)?,
+        n: ssh_key::Mpint::from_positive_bytes(modulus)?,
+    };
+    Ok(PublicKey::from(KeyData::Rsa(key)))
+}
+
+/// Build an SSH public key from a token's `CKA_EC_PARAMS` and `CKA_EC_POINT`
+///
+/// Handles the NIST curves SSH supports (P-256, P-384, P-521) and Ed25519.
+/// The point may be DER-wrapped in an OCTET STRING, as PKCS#11 specifies, or
+/// raw, as some tokens return it.
+pub fn public_key_from_ec(params: &[u8], point: &[u8]) -> Result<PublicKey, AuthError> {
+    let key_data = match params {
+        EC_PARAMS_P256 | EC_PARAMS_P384 | EC_PARAMS_P521 => {
+            let point_len = match params {
+                EC_PARAMS_P256 => 65,
+                EC_PARAMS_P384 => 97,
+                _ => 133,
+            };
+            let point = unwrap_ec_point(point, point_len)?;
+            KeyData::Ecdsa(EcdsaPublicKey::from_sec1_bytes(point)?)
+        }
+        EC_PARAMS_ED25519 | EC_PARAMS_ED25519_NAME => {
+            let point = unwrap_ec_point(point, Ed25519PublicKey::BYTE_SIZE)?;
+            KeyData::Ed25519(Ed25519PublicKey::try_from(point)?)
+        }
+        _ => {
+            return Err(AuthError::KeyLoadError(
+                "Unsupported elliptic curve on PKCS#11 token".into(),
+            ));
+        }
+    };
+    Ok(PublicKey::from(key_data))
+}
+
+/// Strip the DER OCTET STRING around an EC point, if there is one
+fn unwrap_ec_point(point: &[u8], point_len: usize) -> Result<&[u8], AuthError> {
+    if point.len() == point_len {
+        return Ok(point);
+    }
+    let header_len = point.len().saturating_sub(point_len);
+    let wrapped = match point {
+        [0x04, len, rest @ ..] if header_len == 2 => usize::from(*len) == rest.len(),
+        [0x04, 0x81, len, rest @ ..] if header_len == 3 => usize::from(*len) == rest.len(),
+        _ => false,
+    };
+    if !wrapped {
+        return Err(AuthError::KeyLoadError(format!(
+            "Malformed EC point on PKCS#11 token ({} bytes)",
+            point.len()
+        )));
+    }
+    Ok(&point[header_len..])
+}
+
+/// Convert a raw PKCS#11 ECDSA signature (`r || s`, each half the length of
+/// the curve order) to the ASN.1/DER format CloudAPI expects
+pub fn ecdsa_raw_sig_to_der(sig: &[u8]) -> Result<Vec<u8>, AuthError> {
+    if sig.is_empty() || sig.len() % 2 != 0 {
+        return Err(AuthError::SigningError(format!(
+            "Malformed ECDSA signature from PKCS#11 token ({} bytes)",
+            sig.len()
+        )));
+    }
+    let (r, s) = sig.split_at(sig.len() / 2);
+    let r_der = der_encode_integer(&positive_integer_bytes(r));
+    let s_der = der_encode_integer(&positive_integer_bytes(s));
+    let mut inner = Vec::with_capacity(r_der.len() + s_der.len());
+    inner.extend_from_slice(&r_der);
+    inner.extend_from_slice(&s_der);
+    Ok(der_encode_sequence(&inner))
+}
+
+/// Minimal big-endian encoding of an unsigned integer as a positive DER
+/// INTEGER body: leading zeros stripped, one 0x00 added back if the top bit
+/// is set
+fn positive_integer_bytes(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(bytes.len().saturating_sub(1));
+    let trimmed = &bytes[start..];
+    let mut out = Vec::with_capacity(trimmed.len() + 1);
+    if trimmed.first().is_some_and(|b| b & 0x80 != 0) {
+        out.push(0x00);
+    }
+    out.extend_from_slice(trimmed);
+    out
+}
+
+/// Find the signing key on a token and return its public key
+///
+/// Logs in to the token, so this also checks the PIN.
+pub async fn find_key_on_token(
+    module_path: &Path,
+    slot: u64,
+    key_label: Option<&str>,
+    pin_source: &PinSource,
+) -> Result<PublicKey, AuthError> {
+    let (public_key, _) =
+        sign_all_with_token(module_path, slot, key_label, pin_source, Vec::new()).await?;
+    Ok(public_key)
+}
+
+/// Sign each of `data` with the key on a token, in one session
+///
+/// Returns the key's public key and the signatures in the format HTTP
+/// signatures use (PKCS#1 v1.5 for RSA, DER for ECDSA, raw for Ed25519).
+#[cfg(feature = "pkcs11")]
+pub async fn sign_all_with_token(
+    module_path: &Path,
+    slot: u64,
+    key_label: Option<&str>,
+    pin_source: &PinSource,
+    data: Vec<Vec<u8>>,
+) -> Result<(PublicKey, Vec<Vec<u8>>), AuthError> {
+    let module_path = module_path.to_path_buf();
+    let key_label = key_label.map(str::to_string);
+    let pin_source = pin_source.clone();
+
+    // PKCS#11 calls block, sometimes for seconds while a token is touched
+    tokio::task::spawn_blocking(move || {
+        let key = token::TokenKey::open(&module_path, slot, key_label.as_deref(), &pin_source)?;
+        let signatures = data
+            .iter()
+            .map(|d| key.sign(d))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((key.public_key.clone(), signatures))
+    })
+    .await
+    .map_err(|e| AuthError::Pkcs11Error(format!("Token signing task failed: {}", e)))?
+}
+
+/// Sign each of `data` with the key on a token, in one session
+///
+/// This build has no PKCS#11 support; always returns
+/// [`AuthError::ConfigError`].
+#[cfg(not(feature = "pkcs11"))]
+pub async fn sign_all_with_token(
+    _module_path: &Path,
+    _slot: u64,
+    _key_label: Option<&str>,
+    _pin_source: &PinSource,
+    _data: Vec<Vec<u8>>,
+) -> Result<(PublicKey, Vec<Vec<u8>>), AuthError> {
+    Err(AuthError::ConfigError(
+        "PKCS#11 token support is not enabled; rebuild triton-auth with the `pkcs11` feature"
+            .into(),
+    ))
+}
+
+#[cfg(feature = "pkcs11")]
+mod token {
+    use super::*;
+    use crate::signature::KeyType;
+    use cryptoki::context::{CInitializeArgs, Pkcs11};
+    use cryptoki::mechanism::Mechanism;
+    use cryptoki::mechanism::eddsa::{EddsaParams, EddsaSignatureScheme};
+    use cryptoki::object::{Attribute, AttributeType, ObjectClass, ObjectHandle};
+    use cryptoki::session::{Session, UserType};
+    use cryptoki::types::AuthPin;
+    use sha2::{Digest, Sha256, Sha384, Sha512};
+
+    fn token_error(e: cryptoki::error::Error) -> AuthError {
+        AuthError::Pkcs11Error(e.to_string())
+    }
+
+    /// A private key on a token, with a session open to sign with it
+    pub(super) struct TokenKey {
+        // Fields drop in order: the session closes before the module is
+        // finalized
+        session: Session,
+        _pkcs11: Pkcs11,
+        private_key: ObjectHandle,
+        key_type: KeyType,
+        pub(super) public_key: PublicKey,
+    }
+
+    impl TokenKey {
+        /// Load the module, log in to the token in `slot` and find the key
+        pub(super) fn open(
+            module_path: &Path,
+            slot: u64,
+            key_label: Option<&str>,
+            pin_source: &PinSource,
+        ) -> Result<Self, AuthError> {
+            let pin = pin_source.resolve()?;
+
+            let pkcs11 = Pkcs11::new(module_path).map_err(|e| {
+                AuthError::Pkcs11Error(format!(
+                    "Failed to load module {}: {}",
+                    module_path.display(),
+                    e
+                ))
+            })?;
+            pkcs11
+                .initialize(CInitializeArgs::OsThreads)
+                .map_err(token_error)?;
+
+            let token_slot = pkcs11
+                .get_slots_with_token()
+                .map_err(token_error)?
+                .into_iter()
+                .find(|s| s.id() == slot)
+                .ok_or_else(|| {
+                    AuthError::KeyNotFound(format!("no token in PKCS#11 slot {}", slot))
+                })?;
+            let session = pkcs11.open_ro_session(token_slot).map_err(token_error)?;
+            if let Some(pin) = pin {
+                session
+                    .login(UserType::User, Some(&AuthPin::from(pin)))
+                    .map_err(|e| {
+                        AuthError::Pkcs11Error(format!("Failed to log in to token: {}", e))
+                    })?;
+            }
+
+            let mut template = vec![
+                Attribute::Class(ObjectClass::PRIVATE_KEY),
+                Attribute::Sign(true),
+            ];
+            if let Some(label) = key_label {
+                template.push(Attribute::Label(label.as_bytes().to_vec()));
+            }
+            let private_key = match session.find_objects(&template).map_err(token_error)?[..] {
+                [key] => key,
+                [] => {
+                    return Err(AuthError::KeyNotFound(match key_label {
+                        Some(label) => {
+                            format!("no signing key {:?} in PKCS#11 slot {}", label, slot)
+                        }
+                        None => format!("no signing key in PKCS#11 slot {}", slot),
+                    }));
+                }
+                ref keys => {
+                    return Err(AuthError::ConfigError(format!(
+                        "{} signing keys in PKCS#11 slot {}; select one by label",
+                        keys.len(),
+                        slot
+                    )));
+                }
+            };
+
+            let public_key = Self::public_key(&session, private_key)?;
+            let key_type = KeyType::from_public_key(&public_key)?;
+
+            Ok(Self {
+                session,
+                _pkcs11: pkcs11,
+                private_key,
+                key_type,
+                public_key,
+            })
+        }
+
+        /// Read the public key that shares the private key's `CKA_ID`
+        fn public_key(
+            session: &Session,
+            private_key: ObjectHandle,
+        ) -> Result<PublicKey, AuthError> {
+            let id = match session
+                .get_attributes(private_key, &[AttributeType::Id])
+                .map_err(token_error)?
+                .pop()
+            {
+                Some(Attribute::Id(id)) => id,
+                _ => {
+                    return Err(AuthError::KeyLoadError(
+                        "Signing key on PKCS#11 token has no ID".into(),
+                    ));
+                }
+            };
+
+            let public = session
+                .find_objects(&[Attribute::Class(ObjectClass::PUBLIC_KEY), Attribute::Id(id)])
+                .map_err(token_error)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    AuthError::KeyLoadError(
+                        "No public key on PKCS#11 token matches the signing key".into(),
+                    )
+                })?;
+
+            let (mut modulus, mut exponent, mut params, mut point) = (None, None, None, None);
+            for attr in session
+                .get_attributes(
+                    public,
+                    &[
+                        AttributeType::Modulus,
+                        AttributeType::PublicExponent,
+                        AttributeType::EcParams,
+                        AttributeType::EcPoint,
+                    ],
+                )
+                .map_err(token_error)?
+            {
+                match attr {
+                    Attribute::Modulus(v) => modulus = Some(v),
+                    Attribute::PublicExponent(v) => exponent = Some(v),
+                    Attribute::EcParams(v) => params = Some(v),
+                    Attribute::EcPoint(v) => point = Some(v),
+                    _ => {}
+                }
+            }
+
+            match (modulus, exponent, params, point) {
+                (Some(n), Some(e), _, _) => public_key_from_rsa(&n, &e),
+                (_, _, Some(params), Some(point)) => public_key_from_ec(&params, &point),
+                _ => Err(AuthError::KeyLoadError(
+                    "Unsupported key type on PKCS#11 token".into(),
+                )),
+            }
+        }
+
+        /// Sign `data`, returning the signature as HTTP signatures encode it
+        pub(super) fn sign(&self, data: &[u8]) -> Result<Vec<u8>, AuthError> {
+            let sig = match self.key_type {
+                KeyType::Rsa => {
+                    self.session
+                        .sign(&Mechanism::Sha256RsaPkcs, self.private_key, data)
+                }
+                KeyType::Ecdsa256 => {
+                    self.session
+                        .sign(&Mechanism::Ecdsa, self.private_key, &Sha256::digest(data))
+                }
+                KeyType::Ecdsa384 => {
+                    self.session
+                        .sign(&Mechanism::Ecdsa, self.private_key, &Sha384::digest(data))
+                }
+                KeyType::Ecdsa521 => {
+                    self.session
+                        .sign(&Mechanism::Ecdsa, self.private_key, &Sha512::digest(data))
+                }
+                KeyType::Ed25519 => self.session.sign(
+                    &Mechanism::Eddsa(EddsaParams::new(EddsaSignatureScheme::Pure)),
+                    self.private_key,
+                    data,
+                ),
+                KeyType::Dsa => {
+                    return Err(AuthError::SigningError(
+                        "DSA keys on PKCS#11 tokens are not supported".into(),
+                    ));
+                }
+            }
+            .map_err(|e| AuthError::SigningError(format!("Token failed to sign: {}", e)))?;
+
+            match self.key_type {
+                KeyType::Ecdsa256 | KeyType::Ecdsa384 | KeyType::Ecdsa521 => {
+                    ecdsa_raw_sig_to_der(&sig)
+                }
+                _ => Ok(sig),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fingerprint::md5_fingerprint;
+    use p256::ecdsa::Signature;
+
+    fn test_key(pem: &str) -> PublicKey {
+        let key = crate::legacy_pem::LegacyPrivateKey::from_pem(pem, None).unwrap();
+        PublicKey::from_bytes(&key.public_key_blob().unwrap()).unwrap()
+    }
+
+    /// Wrap bytes in a DER OCTET STRING, as `CKA_EC_POINT` is returned
+    fn octet_string(bytes: &[u8]) -> Vec<u8> {
+        let mut out = vec![0x04];
+        crate::certgen::der_encode_length(&mut out, bytes.len());
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    #[test]
+    fn rsa_public_key_from_token_attributes() {
+        let key = test_key(include_str!("../tests/keys/id_rsa"));
+        let KeyData::Rsa(rsa) = key.key_data() else {
+            panic!("expected RSA key");
+        };
+
+        let rebuilt = public_key_from_rsa(
+            rsa.n.as_positive_bytes().unwrap(),
+            rsa.e.as_positive_bytes().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            md5_fingerprint(&rebuilt).unwrap(),
+            md5_fingerprint(&key).unwrap()
+        );
+    }
+
+    #[test]
+    fn ec_public_key_from_token_attributes() {
+        let keys = [
+            ("id_ecdsa", include_str!("../tests/keys/id_ecdsa")),
+            ("id_ed25519", include_str!("../tests/keys/id_ed25519")),
+        ];
+        for (name, pem) in keys {
+            let key = test_key(pem);
+            let (params, point) = match key.key_data() {
+                KeyData::Ecdsa(ec) => (EC_PARAMS_P256, ec.as_sec1_bytes().to_vec()),
+                KeyData::Ed25519(ed) => (EC_PARAMS_ED25519, ed.0.to_vec()),
+                other => panic!("unexpected key type {:?}", other.algorithm()),
+            };
+
+            // Both the DER-wrapped point PKCS#11 specifies and a raw one
+            for point in [octet_string(&point), point] {
+                let rebuilt = public_key_from_ec(params, &point).unwrap();
+                assert_eq!(
+                    md5_fingerprint(&rebuilt).unwrap(),
+                    md5_fingerprint(&key).unwrap(),
+                    "{}",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ec_public_key_rejects_unknown_curve() {
+        // secp256k1 (OID 1.3.132.0.10)
+        let params = [0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a];
+        assert!(public_key_from_ec(&params, &[0x04; 65]).is_err());
+        assert!(public_key_from_ec(EC_PARAMS_P256, &[0x04; 40]).is_err());
+    }
+
+    #[test]
+    fn ecdsa_raw_sig_matches_der_encoding() {
+        // One half with leading zeros, one with the top bit set
+        let mut raw = vec![0x00, 0x00];
+        raw.extend_from_slice(&[0x01; 30]);
+        raw.extend_from_slice(&[0x80; 32]);
+
+        let expected = Signature::from_slice(&raw).unwrap().to_der();
+        assert_eq!(ecdsa_raw_sig_to_der(&raw).unwrap(), expected.as_bytes());
+        assert!(ecdsa_raw_sig_to_der(&raw[..63]).is_err());
+    }
+
+    #[test]
+    fn pin_sources_resolve_and_redact() {
+        let dir = tempfile::tempdir().unwrap();
+        let pin_file = dir.path().join("pin");
+        std::fs::write(&pin_file, "123456\n").unwrap();
+
+        assert_eq!(
+            PinSource::File(pin_file).resolve().unwrap().as_deref(),
+            Some("123456")
+        );
+        assert_eq!(
+            PinSource::Value("654321".into())
+                .resolve()
+                .unwrap()
+                .as_deref(),
+            Some("654321")
+        );
+        assert_eq!(PinSource::None.resolve().unwrap(), None);
+        assert!(
+            PinSource::Env("TRITON_AUTH_TEST_UNSET_PIN".into())
+                .resolve()
+                .is_err()
+        );
+
+        let debug = format!("{:?}", PinSource::Value("654321".into()));
+        assert!(!debug.contains("654321"));
+    }
+
+    #[cfg(not(feature = "pkcs11"))]
+    #[tokio::test]
+    async fn signing_requires_feature() {
+        let result = find_key_on_token(
+            Path::new("/usr/lib/libykcs11.so"),
+            0,
+            None,
+            &PinSource::None,
+        )
+        .await;
+        assert!(matches!(result, Err(AuthError::ConfigError(_))));
+    }
+}
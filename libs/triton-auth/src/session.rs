@@ -0,0 +1,251 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Reusable request signing
+//!
+//! [`sign_request`](crate::sign_request) resolves the key from scratch on
+//! every call: a key file is read and parsed again, an agent key is looked up
+//! again, and the key type and MD5 fingerprint are recomputed each time. A
+//! [`SessionSigner`] does that work once, when it is created, and then only
+//! signs. Clients that sign many requests over a long period, where
+//! [`sign_batch`](crate::sign_batch) does not fit because the requests are not
+//! known up front, should create one and keep it.
+//!
+//! What is saved per call depends on where the key lives:
+//!
+//! - **File**: the key is kept in memory, so signing needs no I/O at all.
+//! - **SSH agent**: the separate key lookup is skipped; each signature is
+//!   still one round trip to the agent, which holds the private key.
+//! - **PKCS#11 token**: the key's public half is not fetched again, but each
+//!   signature opens a token session, as the token holds the private key.
+//!
+//! A `SessionSigner` does not notice the key changing underneath it (a key
+//! file rewritten, or a key removed from the agent); create a new one if that
+//! matters.
+
+use chrono::{DateTime, Utc};
+use std::path::PathBuf;
+
+use crate::error::AuthError;
+use crate::key_loader::{KeyLoader, KeySource};
+use crate::legacy_pem::LegacyPrivateKey;
+use crate::pkcs11::{self, PinSource};
+use crate::signature::{KeyType, RequestSigner, encode_signature};
+use crate::{AuthConfig, agent, convert_agent_sig, create_signer_with_fp, fingerprint};
+
+/// Where a [`SessionSigner`]'s private key lives
+enum SessionKey {
+    /// Loaded from disk and held in memory
+    File(LegacyPrivateKey),
+    /// Held by the SSH agent, selected by the configured fingerprint
+    Agent { fingerprint: String },
+    /// Held on a PKCS#11 token
+    Pkcs11 {
+        module_path: PathBuf,
+        slot: u64,
+        key_label: Option<String>,
+        pin_source: PinSource,
+    },
+}
+
+/// A request signer with its key resolved once, for signing many requests
+///
+/// See the [module documentation](self) for what is cached.
+pub struct SessionSigner {
+    key: SessionKey,
+    key_type: KeyType,
+    md5_fingerprint: String,
+    signer: RequestSigner,
+}
+
+impl SessionSigner {
+    /// Resolve the key from `config.key_source`, the same way
+    /// [`sign_request`](crate::sign_request) does
+    ///
+    /// # Errors
+    /// Returns an error if the key cannot be found or loaded, or if the key
+    /// source is [`KeySource::None`]
+    pub async fn new(config: &AuthConfig) -> Result<Self, AuthError> {
+        match &config.key_source {
+            KeySource::None => Err(AuthError::unsigned()),
+            KeySource::Agent { fingerprint } => Self::from_agent(config, fingerprint).await,
+            KeySource::File {
+                path: key_path,
+                passphrase,
+            } => {
+                let key = KeyLoader::load_legacy_from_file(key_path, passphrase.as_deref()).await?;
+                Self::from_key(config, key)
+            }
+            KeySource::Auto { fingerprint } => match Self::from_agent(config, fingerprint).await {
+                Ok(signer) => Ok(signer),
+                Err(e) => {
+                    tracing::debug!("SSH agent key lookup failed, falling back to file: {}", e);
+                    let key = KeyLoader::load_legacy_from_common_paths(fingerprint).await?;
+                    Self::from_key(config, key)
+                }
+            },
+            KeySource::Pkcs11 {
+                module_path,
+                slot,
+                key_label,
+                pin_source,
+            } => {
+                let pub_key =
+                    pkcs11::find_key_on_token(module_path, *slot, key_label.as_deref(), pin_source)
+                        .await?;
+                let key_type = KeyType::from_public_key(&pub_key)?;
+                let md5_fp = fingerprint::md5_fingerprint(&pub_key)?;
+                Ok(Self::with_key(
+                    config,
+                    SessionKey::Pkcs11 {
+                        module_path: module_path.clone(),
+                        slot: *slot,
+                        key_label: key_label.clone(),
+                        pin_source: pin_source.clone(),
+                    },
+                    key_type,
+                    md5_fp,
+                ))
+            }
+        }
+    }
+
+    /// Use a key from the SSH agent
+    async fn from_agent(config: &AuthConfig, fingerprint: &str) -> Result<Self, AuthError> {
+        let pub_key = agent::find_key_in_agent(fingerprint).await?;
+        let key_type = KeyType::from_public_key(&pub_key)?;
+        let md5_fp = fingerprint::md5_fingerprint(&pub_key)?;
+        Ok(Self::with_key(
+            config,
+            SessionKey::Agent {
+                fingerprint: fingerprint.to_string(),
+            },
+            key_type,
+            md5_fp,
+        ))
+    }
+
+    /// Use a key loaded from disk
+    fn from_key(config: &AuthConfig, key: LegacyPrivateKey) -> Result<Self, AuthError> {
+        let key_type = key.key_type()?;
+        let md5_fp = fingerprint::md5_fingerprint_bytes(&key.public_key_blob()?);
+        Ok(Self::with_key(
+            config,
+            SessionKey::File(key),
+            key_type,
+            md5_fp,
+        ))
+    }
+
+    fn with_key(
+        config: &AuthConfig,
+        key: SessionKey,
+        key_type: KeyType,
+        md5_fingerprint: String,
+    ) -> Self {
+        Self {
+            signer: create_signer_with_fp(config, key_type, &md5_fingerprint),
+            key,
+            key_type,
+            md5_fingerprint,
+        }
+    }
+
+    /// The type of the signing key
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    /// The MD5 fingerprint of the signing key, as used in the keyId
+    pub fn fingerprint(&self) -> &str {
+        &self.md5_fingerprint
+    }
+
+    /// The keyId that signed requests carry
+    pub fn key_id(&self) -> String {
+        self.signer.key_id_string()
+    }
+
+    /// Sign an HTTP request and return the Date and Authorization headers
+    ///
+    /// Equivalent to [`sign_request`](crate::sign_request) with the
+    /// configuration this signer was created from.
+    pub async fn sign(&self, method: &str, path: &str) -> Result<(String, String), AuthError> {
+        self.sign_at(method, path, Utc::now()).await
+    }
+
+    /// Sign an HTTP request with a `Date` header for the given time
+    pub async fn sign_at(
+        &self,
+        method: &str,
+        path: &str,
+        time: DateTime<Utc>,
+    ) -> Result<(String, String), AuthError> {
+        self.sign_with_headers_at(method, path, &http::HeaderMap::new(), time)
+            .await
+    }
+
+    /// Sign an HTTP request whose other signed headers are in `headers`
+    ///
+    /// Equivalent to
+    /// [`sign_request_with_headers`](crate::sign_request_with_headers).
+    pub async fn sign_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &http::HeaderMap,
+    ) -> Result<(String, String), AuthError> {
+        self.sign_with_headers_at(method, path, headers, Utc::now())
+            .await
+    }
+
+    /// Sign an HTTP request whose other signed headers are in `headers`,
+    /// with a `Date` header for the given time
+    pub async fn sign_with_headers_at(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &http::HeaderMap,
+        time: DateTime<Utc>,
+    ) -> Result<(String, String), AuthError> {
+        let date = RequestSigner::date_header_at(time);
+        let signing_string = self
+            .signer
+            .signing_string_with_headers(method, path, &date, headers)?;
+
+        let sig_bytes = match &self.key {
+            SessionKey::File(key) => key.sign(signing_string.as_bytes())?,
+            SessionKey::Agent { fingerprint } => {
+                let raw_sig =
+                    agent::sign_with_agent(fingerprint, signing_string.as_bytes()).await?;
+                convert_agent_sig(&raw_sig, self.key_type)?
+            }
+            SessionKey::Pkcs11 {
+                module_path,
+                slot,
+                key_label,
+                pin_source,
+            } => {
+                let (_, mut sigs) = pkcs11::sign_all_with_token(
+                    module_path,
+                    *slot,
+                    key_label.as_deref(),
+                    pin_source,
+                    vec![signing_string.into_bytes()],
+                )
+                .await?;
+                sigs.pop().ok_or_else(|| {
+                    AuthError::SigningError("PKCS#11 token returned no signature".into())
+                })?
+            }
+        };
+
+        let auth_header = self
+            .signer
+            .authorization_header(&encode_signature(&sig_bytes));
+        Ok((date, auth_header))
+    }
+}
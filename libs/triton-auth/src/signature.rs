@@ -16,11 +16,191 @@
 //! - `date: <RFC2822 date header value>`
 //! - `\n`
 //! - `(request-target): <method lowercase> <path>`
+//!
+//! Deployments that require more of the request to be signed (for example a
+//! proxy that checks `host`, or `content-md5` to cover the body) can configure
+//! an ordered list of [`SignedHeaders`]. The `headers="..."` parameter of the
+//! Authorization header then lists them in the order they were signed.
 
 use crate::error::AuthError;
 use base64::Engine;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use md5::{Digest, Md5};
 use ssh_key::{HashAlg, PrivateKey};
+use std::str::FromStr;
+
+/// The `(request-target)` pseudo-header: the lowercased method and the path
+pub const REQUEST_TARGET: &str = "(request-target)";
+
+/// The headers an HTTP signature covers, in signing order
+///
+/// The default, `(request-target) date`, is what CloudAPI and node-triton
+/// use. Names are lowercased; `date` is required, as it is what limits how
+/// long a signature can be replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedHeaders(Vec<String>);
+
+impl Default for SignedHeaders {
+    fn default() -> Self {
+        Self(vec![REQUEST_TARGET.to_string(), "date".to_string()])
+    }
+}
+
+impl SignedHeaders {
+    /// Sign the given headers, in the given order
+    ///
+    /// # Errors
+    /// Returns [`AuthError::ConfigError`] if a name is not a valid header
+    /// name (other than `(request-target)`), appears twice, or if `date` is
+    /// missing.
+    pub fn new<I, S>(names: I) -> Result<Self, AuthError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut headers: Vec<String> = Vec::new();
+        for name in names {
+            let name = name.as_ref().trim().to_ascii_lowercase();
+            if name != REQUEST_TARGET && HeaderName::from_bytes(name.as_bytes()).is_err() {
+                return Err(AuthError::ConfigError(format!(
+                    "invalid header name to sign: {:?}",
+                    name
+                )));
+            }
+            if headers.contains(&name) {
+                return Err(AuthError::ConfigError(format!(
+                    "header {} is listed twice in the headers to sign",
+                    name
+                )));
+            }
+            headers.push(name);
+        }
+
+        if !headers.iter().any(|h| h == "date") {
+            return Err(AuthError::ConfigError(
+                "the headers to sign must include date".into(),
+            ));
+        }
+        Ok(Self(headers))
+    }
+
+    /// The header names, in signing order
+    pub fn names(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Whether the (lowercase) header is signed
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|h| h == name)
+    }
+
+    /// The value of the `headers` parameter of the Authorization header
+    pub fn param(&self) -> String {
+        self.0.join(" ")
+    }
+
+    /// Set the signed headers whose values the client computes, unless the
+    /// request already has them: `host` from `host` (the request URL's
+    /// authority) and `content-md5` from the body
+    ///
+    /// `body` is `None` for a streaming body, whose MD5 cannot be known
+    /// before it is sent.
+    ///
+    /// # Errors
+    /// Returns [`AuthError::SigningError`] if `content-md5` is signed but the
+    /// body is streamed, or a value is not a valid header value.
+    pub fn add_computed_headers(
+        &self,
+        headers: &mut HeaderMap,
+        host: &str,
+        body: Option<&[u8]>,
+    ) -> Result<(), AuthError> {
+        if self.contains("host") && !headers.contains_key(http::header::HOST) {
+            let value = HeaderValue::from_str(host)
+                .map_err(|e| AuthError::SigningError(format!("invalid host header: {}", e)))?;
+            headers.insert(http::header::HOST, value);
+        }
+
+        let content_md5_header = HeaderName::from_static("content-md5");
+        if self.contains("content-md5") && !headers.contains_key(&content_md5_header) {
+            let body = body.ok_or_else(|| {
+                AuthError::SigningError(
+                    "content-md5 cannot be computed for a streaming request body".into(),
+                )
+            })?;
+            let value = HeaderValue::from_str(&content_md5(body))
+                .map_err(|e| AuthError::SigningError(format!("invalid content-md5: {}", e)))?;
+            headers.insert(content_md5_header, value);
+        }
+
+        Ok(())
+    }
+
+    /// Build the signing string for a request
+    ///
+    /// `(request-target)` comes from `method` and `path`, and `date` from
+    /// `date`; every other signed header is read from `headers`, with
+    /// repeated values joined by `", "`.
+    ///
+    /// # Errors
+    /// Returns [`AuthError::SigningError`] if a signed header is not set on
+    /// the request.
+    pub fn signing_string(
+        &self,
+        method: &str,
+        path: &str,
+        date: &str,
+        headers: &HeaderMap,
+    ) -> Result<String, AuthError> {
+        let mut lines = Vec::with_capacity(self.0.len());
+        for name in &self.0 {
+            let value = match name.as_str() {
+                REQUEST_TARGET => format!("{} {}", method.to_lowercase(), path),
+                "date" => date.to_string(),
+                _ => {
+                    let values = headers
+                        .get_all(name.as_str())
+                        .iter()
+                        .map(|v| v.to_str().map(str::trim))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| {
+                            AuthError::SigningError(format!(
+                                "header {} to be signed is not text: {}",
+                                name, e
+                            ))
+                        })?;
+                    if values.is_empty() {
+                        return Err(AuthError::SigningError(format!(
+                            "header {} to be signed is not set on the request",
+                            name
+                        )));
+                    }
+                    values.join(", ")
+                }
+            };
+            lines.push(format!("{}: {}", name, value));
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+impl FromStr for SignedHeaders {
+    type Err = AuthError;
+
+    /// Parse a space or comma separated list, e.g. `(request-target) host date`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(
+            s.split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|n| !n.is_empty()),
+        )
+    }
+}
+
+/// The `Content-MD5` header value for a request body: its base64 MD5 digest
+pub fn content_md5(body: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(Md5::digest(body))
+}
 
 /// Key type for algorithm selection in HTTP signatures
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -115,6 +295,7 @@ pub struct RequestSigner {
     subuser: Option<String>,
     fingerprint: String, // MD5 hex format: aa:bb:cc:...
     key_type: KeyType,
+    headers: SignedHeaders,
 }
 
 impl RequestSigner {
@@ -130,6 +311,7 @@ impl RequestSigner {
             subuser: None,
             fingerprint: fingerprint.to_string(),
             key_type,
+            headers: SignedHeaders::default(),
         }
     }
 
@@ -139,6 +321,17 @@ impl RequestSigner {
         self
     }
 
+    /// Set the headers this signer signs (default `(request-target) date`)
+    pub fn with_signed_headers(mut self, headers: SignedHeaders) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// The headers this signer signs, in order
+    pub fn signed_headers(&self) -> &SignedHeaders {
+        &self.headers
+    }
+
     /// Get the algorithm string for the HTTP Signature
     pub fn algorithm(&self) -> &'static str {
         self.key_type.algorithm_string()
@@ -169,20 +362,38 @@ impl RequestSigner {
     ///
     /// This matches the behavior of node-triton and provides protection against
     /// replay attacks by binding the signature to a specific HTTP method and path.
+    ///
+    /// This always covers the default headers; a signer configured with
+    /// other [`SignedHeaders`] must use
+    /// [`signing_string_with_headers`](Self::signing_string_with_headers).
     pub fn signing_string(&self, method: &str, path: &str, date: &str) -> String {
-        format!(
-            "(request-target): {} {}\ndate: {}",
-            method.to_lowercase(),
-            path,
-            date
-        )
+        request_signing_string(method, path, date)
+    }
+
+    /// Generate the signing string for the headers this signer signs
+    ///
+    /// Headers other than `(request-target)` and `date` are read from
+    /// `headers`. See [`SignedHeaders::signing_string`].
+    pub fn signing_string_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        date: &str,
+        headers: &HeaderMap,
+    ) -> Result<String, AuthError> {
+        self.headers.signing_string(method, path, date, headers)
     }
 
     /// Generate a Date header value in RFC 2822 format
     ///
     /// Example: "Mon, 15 Dec 2025 10:30:00 GMT"
     pub fn date_header() -> String {
-        Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+        Self::date_header_at(Utc::now())
+    }
+
+    /// Generate a Date header value in RFC 2822 format for the given time
+    pub fn date_header_at(time: DateTime<Utc>) -> String {
+        time.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
     }
 
     /// Generate the full Authorization header value
@@ -195,16 +406,32 @@ impl RequestSigner {
     /// ```text
     /// Signature keyId="/:account/keys/:fp",algorithm="rsa-sha256",headers="(request-target) date",signature=":sig:"
     /// ```
+    ///
+    /// `headers` lists the signer's [`SignedHeaders`].
     pub fn authorization_header(&self, signature_b64: &str) -> String {
         format!(
-            "Signature keyId=\"{}\",algorithm=\"{}\",headers=\"(request-target) date\",signature=\"{}\"",
+            "Signature keyId=\"{}\",algorithm=\"{}\",headers=\"{}\",signature=\"{}\"",
             self.key_id_string(),
             self.algorithm(),
+            self.headers.param(),
             signature_b64
         )
     }
 }
 
+/// Build the signing string for a request
+///
+/// The signing string does not depend on the key, so it can be built before
+/// the key has been located. See [`RequestSigner::signing_string`].
+pub fn request_signing_string(method: &str, path: &str, date: &str) -> String {
+    format!(
+        "(request-target): {} {}\ndate: {}",
+        method.to_lowercase(),
+        path,
+        date
+    )
+}
+
 /// Sign data with an SSH private key
 ///
 /// # Arguments
@@ -323,6 +550,95 @@ mod tests {
         assert!(auth.contains("signature=\"dGVzdHNpZ25hdHVyZQ==\""));
     }
 
+    #[test]
+    fn test_signed_headers() {
+        let date = "Mon, 15 Dec 2025 10:30:00 GMT";
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::HOST,
+            HeaderValue::from_static("cloudapi.example.com"),
+        );
+
+        let signed: SignedHeaders = "(request-target) Host date".parse().unwrap();
+        assert_eq!(signed.names(), ["(request-target)", "host", "date"]);
+        assert_eq!(
+            signed
+                .signing_string("POST", "/test/machines", date, &headers)
+                .unwrap(),
+            "(request-target): post /test/machines\nhost: cloudapi.example.com\ndate: Mon, 15 Dec 2025 10:30:00 GMT"
+        );
+
+        // The default matches the fixed signing string
+        assert_eq!(
+            SignedHeaders::default()
+                .signing_string("GET", "/test/machines", date, &HeaderMap::new())
+                .unwrap(),
+            request_signing_string("GET", "/test/machines", date)
+        );
+
+        // Signed headers must be on the request
+        let signed = SignedHeaders::new(["date", "content-md5"]).unwrap();
+        assert!(matches!(
+            signed.signing_string("GET", "/", date, &headers),
+            Err(AuthError::SigningError(_))
+        ));
+
+        for invalid in ["host", "date date", "date bad:name", ""] {
+            assert!(
+                matches!(
+                    invalid.parse::<SignedHeaders>(),
+                    Err(AuthError::ConfigError(_))
+                ),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_computed_headers() {
+        let signed =
+            SignedHeaders::new(["(request-target)", "host", "date", "content-md5"]).unwrap();
+        let mut headers = HeaderMap::new();
+        signed
+            .add_computed_headers(&mut headers, "cloudapi.example.com:8443", Some(b"{}"))
+            .unwrap();
+        assert_eq!(headers["host"], "cloudapi.example.com:8443");
+        assert_eq!(headers["content-md5"], "mZFLkyvTelC5g8XnyQrpOw==");
+
+        // Values already on the request are kept
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::HOST,
+            HeaderValue::from_static("other.example.com"),
+        );
+        signed
+            .add_computed_headers(&mut headers, "cloudapi.example.com", Some(b""))
+            .unwrap();
+        assert_eq!(headers["host"], "other.example.com");
+        assert_eq!(headers["content-md5"], "1B2M2Y8AsgTpgAmY7PhCfg==");
+
+        assert!(
+            signed
+                .add_computed_headers(&mut HeaderMap::new(), "cloudapi.example.com", None)
+                .is_err()
+        );
+        // Nothing is computed for headers that are not signed
+        let mut headers = HeaderMap::new();
+        SignedHeaders::default()
+            .add_computed_headers(&mut headers, "cloudapi.example.com", None)
+            .unwrap();
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn test_authorization_header_lists_signed_headers() {
+        let signer = RequestSigner::new("test", "aa:bb:cc:dd", KeyType::Ed25519)
+            .with_signed_headers("(request-target) host date content-md5".parse().unwrap());
+
+        let auth = signer.authorization_header("c2ln");
+        assert!(auth.contains("headers=\"(request-target) host date content-md5\""));
+    }
+
     #[test]
     fn test_algorithm_strings() {
         assert_eq!(KeyType::Rsa.algorithm_string(), "rsa-sha256");
@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Request signing behind a trait
+//!
+//! Code that signs requests can take an `Arc<dyn Signer>` rather than an
+//! [`AuthConfig`], so that tests can swap in a signer that needs no key.
+//!
+//! - [`AuthConfig`] resolves its key source (file, SSH agent, PKCS#11 token)
+//!   on every call, like [`sign_request`](crate::sign_request).
+//! - [`SessionSigner`] resolves the key once and then only signs.
+//! - [`FixedSigner`] needs no key and gives the same headers for the same
+//!   request every time, for tests. Servers reject what it produces.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::error::AuthError;
+use crate::session::SessionSigner;
+use crate::signature::{KeyType, RequestSigner, SignedHeaders, encode_signature};
+use crate::{AuthConfig, sign_request_with_headers};
+
+/// Something that produces the `Date` and `Authorization` headers of a
+/// signed request
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Sign an HTTP request whose other signed headers are in `headers`,
+    /// and return the Date and Authorization headers
+    ///
+    /// See [`sign_request_with_headers`](crate::sign_request_with_headers).
+    async fn sign_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &http::HeaderMap,
+    ) -> Result<(String, String), AuthError>;
+
+    /// Sign an HTTP request and return the Date and Authorization headers
+    ///
+    /// See [`sign_request`](crate::sign_request).
+    async fn sign(&self, method: &str, path: &str) -> Result<(String, String), AuthError> {
+        self.sign_with_headers(method, path, &http::HeaderMap::new())
+            .await
+    }
+}
+
+#[async_trait]
+impl Signer for AuthConfig {
+    async fn sign_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &http::HeaderMap,
+    ) -> Result<(String, String), AuthError> {
+        sign_request_with_headers(self, method, path, headers).await
+    }
+}
+
+#[async_trait]
+impl Signer for SessionSigner {
+    async fn sign_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &http::HeaderMap,
+    ) -> Result<(String, String), AuthError> {
+        SessionSigner::sign_with_headers(self, method, path, headers).await
+    }
+}
+
+/// A signer for tests that needs no key
+///
+/// The `Date` header is always for the same time, and the signature is the
+/// base64 of the signing string itself, so a test can decode it to see what
+/// was signed. The `Authorization` header otherwise has the usual form.
+pub struct FixedSigner {
+    signer: RequestSigner,
+    time: DateTime<Utc>,
+}
+
+impl FixedSigner {
+    /// Sign as `account` with a key of the given MD5 fingerprint, at the
+    /// Unix epoch
+    pub fn new(account: &str, fingerprint: &str) -> Self {
+        Self {
+            signer: RequestSigner::new(account, fingerprint, KeyType::Rsa),
+            time: DateTime::<Utc>::UNIX_EPOCH,
+        }
+    }
+
+    /// Set the time the `Date` header is for
+    pub fn with_time(mut self, time: DateTime<Utc>) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Set the RBAC sub-user in the keyId
+    pub fn with_subuser(mut self, subuser: impl Into<String>) -> Self {
+        self.signer = self.signer.with_subuser(subuser);
+        self
+    }
+
+    /// Set the headers to sign (default `(request-target) date`)
+    pub fn with_signed_headers(mut self, headers: SignedHeaders) -> Self {
+        self.signer = self.signer.with_signed_headers(headers);
+        self
+    }
+
+    /// The keyId that signed requests carry
+    pub fn key_id(&self) -> String {
+        self.signer.key_id_string()
+    }
+}
+
+#[async_trait]
+impl Signer for FixedSigner {
+    async fn sign_with_headers(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &http::HeaderMap,
+    ) -> Result<(String, String), AuthError> {
+        let date = RequestSigner::date_header_at(self.time);
+        let signing_string = self
+            .signer
+            .signing_string_with_headers(method, path, &date, headers)?;
+        let auth_header = self
+            .signer
+            .authorization_header(&encode_signature(signing_string.as_bytes()));
+        Ok((date, auth_header))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_fixed_signer() {
+        let signer: Arc<dyn Signer> =
+            Arc::new(FixedSigner::new("myaccount", "aa:bb:cc:dd").with_subuser("bob"));
+
+        let (date, auth_header) = signer.sign("GET", "/myaccount/machines").await.unwrap();
+        assert_eq!(date, "Thu, 01 Jan 1970 00:00:00 GMT");
+        assert_eq!(
+            auth_header,
+            format!(
+                "Signature keyId=\"/myaccount/users/bob/keys/aa:bb:cc:dd\",\
+                 algorithm=\"rsa-sha256\",headers=\"(request-target) date\",\
+                 signature=\"{}\"",
+                encode_signature(
+                    b"(request-target): get /myaccount/machines\n\
+                      date: Thu, 01 Jan 1970 00:00:00 GMT"
+                )
+            )
+        );
+
+        // The same request signs the same way every time
+        assert_eq!(
+            signer.sign("GET", "/myaccount/machines").await.unwrap(),
+            (date, auth_header)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fixed_signer_headers() {
+        let signer = FixedSigner::new("myaccount", "aa:bb:cc:dd")
+            .with_signed_headers("(request-target) host date".parse().unwrap());
+
+        // A signed header that is missing from the request is an error
+        assert!(signer.sign("GET", "/myaccount/machines").await.is_err());
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::HOST, "cloudapi.example.com".parse().unwrap());
+        let (_, auth_header) = signer
+            .sign_with_headers("GET", "/myaccount/machines", &headers)
+            .await
+            .unwrap();
+        assert!(auth_header.contains("headers=\"(request-target) host date\""));
+    }
+}
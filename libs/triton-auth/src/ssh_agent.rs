@@ -9,18 +9,27 @@
 //! Direct SSH agent protocol implementation
 //!
 //! This module provides a minimal SSH agent client that communicates directly
-//! with the SSH agent via Unix socket. It implements just the operations needed
-//! for HTTP Signature authentication:
+//! with the SSH agent via Unix socket, or on Windows via the named pipe of the
+//! OpenSSH agent service. It implements just the operations needed for HTTP
+//! Signature authentication:
 //!
 //! - List identities (keys) in the agent
 //! - Sign data with a specific key
 //!
+//! Identities may be OpenSSH certificates (`ssh-*-cert-v01@openssh.com`), as
+//! loaded by `ssh-add` when an `id_*-cert.pub` file sits next to the key. Such
+//! identities are reported by the key they certify: the key type and
+//! fingerprints describe the underlying key, while the certificate blob is
+//! retained for sign requests since that is how the agent identifies it.
+//!
 //! This implementation gives us full control over the SSH agent protocol,
 //! including the ability to specify RSA signature algorithm flags (SHA-256 vs SHA-512).
 
 use std::fmt;
 use std::io::prelude::*;
+#[cfg(unix)]
 use std::os::unix::net::UnixStream;
+#[cfg(unix)]
 use std::time::Duration;
 
 use base64::Engine;
@@ -31,6 +40,7 @@ use sha2::Sha256;
 use md5::Digest;
 
 use crate::error::AuthError;
+use crate::fingerprint::{is_certificate_blob, public_key_from_blob};
 
 /// SSH agent protocol message types
 const SSH_AGENTC_REQUEST_IDENTITIES: u8 = 11;
@@ -47,6 +57,18 @@ const SSH_AGENT_RSA_SHA2_256: u32 = 0x02;
 /// by specifying an extremely large length field.
 const MAX_WIRE_LENGTH: usize = 10 * 1024 * 1024;
 
+/// Named pipe of the Windows OpenSSH agent service, used when
+/// `SSH_AUTH_SOCK` is not set
+#[cfg(windows)]
+pub const WINDOWS_AGENT_PIPE: &str = r"\\.\pipe\openssh-ssh-agent";
+
+/// Connection to the agent: a Unix socket, or a named pipe opened as a file
+/// on Windows
+#[cfg(unix)]
+type AgentStream = UnixStream;
+#[cfg(windows)]
+type AgentStream = std::fs::File;
+
 /// Read a big-endian u32 from a buffer at the given offset.
 /// Returns an error if the buffer is too short.
 fn read_u32be(buf: &[u8], offset: usize) -> Result<u32, AuthError> {
@@ -175,7 +197,8 @@ pub fn sha256_fingerprint(bytes: &[u8]) -> String {
 /// An SSH identity (key) from the agent
 #[derive(Clone)]
 pub struct SshIdentity {
-    /// The key type (e.g., "ssh-rsa", "ecdsa-sha2-nistp256", "ssh-ed25519")
+    /// The key type (e.g., "ssh-rsa", "ecdsa-sha2-nistp256", "ssh-ed25519").
+    /// For a certificate this is the type of the certified key.
     pub key_type: String,
     /// The key comment (usually the key file path or email)
     pub comment: String,
@@ -183,33 +206,66 @@ pub struct SshIdentity {
     pub md5_fp: String,
     /// SHA256 fingerprint in "SHA256:base64" format
     pub sha256_fp: String,
-    /// Raw public key bytes in SSH wire format
+    /// Raw public key bytes in SSH wire format.
+    /// For a certificate this is the certified key, not the certificate.
     pub raw_key: Vec<u8>,
+    /// The certificate blob in SSH wire format, if the agent holds this
+    /// identity as an OpenSSH certificate
+    pub certificate: Option<Vec<u8>>,
 }
 
 impl SshIdentity {
     /// Create a new SshIdentity from raw key bytes and comment.
     /// Returns an error if the key bytes are malformed (too short to contain
-    /// the key type string).
+    /// the key type string, or an undecodable certificate).
     pub fn new(bytes: &[u8], comment: &str) -> Result<SshIdentity, AuthError> {
+        // For certificates, fingerprint and describe the certified key
+        let (raw_key, certificate) = if is_certificate_blob(bytes) {
+            let key = public_key_from_blob(bytes)
+                .and_then(|key| key.to_bytes())
+                .map_err(|e| {
+                    AuthError::AgentError(format!(
+                        "SSH agent returned unparseable certificate: {}",
+                        e
+                    ))
+                })?;
+            (key, Some(bytes.to_vec()))
+        } else {
+            (bytes.to_vec(), None)
+        };
+
         // The type of the key is held in the key itself - extract it here
-        let type_len = read_u32be(bytes, 0)? as usize;
+        let type_len = read_u32be(&raw_key, 0)? as usize;
         check_wire_length(type_len, "key type string")?;
-        let key_type = read_string(bytes, 4, type_len)?;
+        let key_type = read_string(&raw_key, 4, type_len)?;
 
         // Generate fingerprints
-        let md5_fp = md5_fingerprint(bytes);
-        let sha256_fp = sha256_fingerprint(bytes);
+        let md5_fp = md5_fingerprint(&raw_key);
+        let sha256_fp = sha256_fingerprint(&raw_key);
 
         Ok(SshIdentity {
-            raw_key: bytes.to_vec(),
+            raw_key,
             key_type,
             comment: comment.to_string(),
             md5_fp,
             sha256_fp,
+            certificate,
         })
     }
 
+    /// The key blob the agent knows this identity by
+    ///
+    /// This is the certificate for certificate identities and the raw public
+    /// key otherwise. Sign requests must use this blob.
+    pub fn agent_key_blob(&self) -> &[u8] {
+        self.certificate.as_deref().unwrap_or(&self.raw_key)
+    }
+
+    /// Check if this identity is an OpenSSH certificate
+    pub fn is_certificate(&self) -> bool {
+        self.certificate.is_some()
+    }
+
     /// Check if this identity matches the given fingerprint
     ///
     /// Supports both MD5 (aa:bb:cc:...) and SHA256 (SHA256:base64) formats
@@ -234,21 +290,23 @@ impl SshIdentity {
 
 impl fmt::Display for SshIdentity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cert = if self.is_certificate() { "-CERT" } else { "" };
         write!(
             f,
-            "SshIdentity: {} {} {}",
-            self.key_type, self.sha256_fp, self.comment
+            "SshIdentity: {}{} {} {}",
+            self.key_type, cert, self.sha256_fp, self.comment
         )
     }
 }
 
 /// SSH agent client for communicating with ssh-agent
 pub struct SshAgentClient {
-    stream: UnixStream,
+    stream: AgentStream,
 }
 
 impl SshAgentClient {
     /// Connect to the SSH agent using the given socket path
+    #[cfg(unix)]
     pub fn connect(socket_path: &str) -> Result<SshAgentClient, AuthError> {
         let stream = UnixStream::connect(socket_path)
             .map_err(|e| AuthError::AgentError(format!("Failed to connect to SSH agent: {}", e)))?;
@@ -264,13 +322,37 @@ impl SshAgentClient {
         Ok(SshAgentClient { stream })
     }
 
+    /// Connect to the SSH agent using the given named pipe path
+    ///
+    /// Unlike the Unix socket, no read or write timeouts are set, since
+    /// `std::fs::File` does not support them.
+    #[cfg(windows)]
+    pub fn connect(pipe_path: &str) -> Result<SshAgentClient, AuthError> {
+        let stream = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(pipe_path)
+            .map_err(|e| AuthError::AgentError(format!("Failed to connect to SSH agent: {}", e)))?;
+
+        Ok(SshAgentClient { stream })
+    }
+
     /// Connect to the SSH agent using SSH_AUTH_SOCK environment variable
+    ///
+    /// On Windows, falls back to the OpenSSH agent service pipe
+    /// (`\\.\pipe\openssh-ssh-agent`) when the variable is not set.
     pub fn connect_env() -> Result<SshAgentClient, AuthError> {
-        let socket_path = std::env::var("SSH_AUTH_SOCK").map_err(|_| {
-            AuthError::AgentError(
-                "SSH_AUTH_SOCK environment variable not set. Is ssh-agent running?".to_string(),
-            )
-        })?;
+        let socket_path = match std::env::var("SSH_AUTH_SOCK") {
+            Ok(path) => path,
+            #[cfg(windows)]
+            Err(_) => WINDOWS_AGENT_PIPE.to_string(),
+            #[cfg(not(windows))]
+            Err(_) => {
+                return Err(AuthError::AgentError(
+                    "SSH_AUTH_SOCK environment variable not set. Is ssh-agent running?".to_string(),
+                ));
+            }
+        };
         Self::connect(&socket_path)
     }
 
@@ -361,8 +443,9 @@ impl SshAgentClient {
     /// For RSA keys, this requests SHA-256 signatures (rsa-sha2-256) which is
     /// required for CloudAPI compatibility.
     pub fn sign_data(&mut self, identity: &SshIdentity, data: &[u8]) -> Result<Vec<u8>, AuthError> {
+        let key_blob = identity.agent_key_blob();
         let mut idx = 0;
-        let mut buf = vec![0; 4 + 1 + 4 + identity.raw_key.len() + 4 + data.len() + 4];
+        let mut buf = vec![0; 4 + 1 + 4 + key_blob.len() + 4 + data.len() + 4];
 
         let len = buf.len() - 4;
         write_u32be(&mut buf, len as u32, idx);
@@ -371,11 +454,11 @@ impl SshAgentClient {
         write_u8(&mut buf, SSH_AGENTC_SIGN_REQUEST, idx);
         idx += 1;
 
-        write_u32be(&mut buf, identity.raw_key.len() as u32, idx);
+        write_u32be(&mut buf, key_blob.len() as u32, idx);
         idx += 4;
 
-        write_bytes(&mut buf, &identity.raw_key, idx);
-        idx += identity.raw_key.len();
+        write_bytes(&mut buf, key_blob, idx);
+        idx += key_blob.len();
 
         write_u32be(&mut buf, data.len() as u32, idx);
         idx += 4;
@@ -479,6 +562,7 @@ mod tests {
             md5_fp: "aa:bb:cc:dd:ee:ff:00:11:22:33:44:55:66:77:88:99".to_string(),
             sha256_fp: "SHA256:abcdefghijklmnop".to_string(),
             raw_key: vec![],
+            certificate: None,
         };
 
         // Test MD5 matching (no prefix)
@@ -503,6 +587,7 @@ mod tests {
             md5_fp: "".to_string(),
             sha256_fp: "".to_string(),
             raw_key: vec![],
+            certificate: None,
         };
         assert!(rsa_ident.is_rsa());
 
@@ -512,6 +597,7 @@ mod tests {
             md5_fp: "".to_string(),
             sha256_fp: "".to_string(),
             raw_key: vec![],
+            certificate: None,
         };
         assert!(!ed25519_ident.is_rsa());
     }
@@ -542,6 +628,51 @@ mod tests {
         assert!(ident.is_rsa());
     }
 
+    #[test]
+    fn test_identity_new_certificate() {
+        use base64::engine::general_purpose::STANDARD;
+
+        let cert_line = include_str!("../tests/keys/id_ed25519-cert.pub");
+        let cert_blob = STANDARD
+            .decode(cert_line.split_whitespace().nth(1).unwrap())
+            .unwrap();
+
+        let ident = SshIdentity::new(&cert_blob, "test cert").unwrap();
+        assert!(ident.is_certificate());
+        assert_eq!(ident.key_type, "ssh-ed25519");
+        assert_eq!(ident.agent_key_blob(), &cert_blob[..]);
+        assert_ne!(ident.raw_key, cert_blob);
+        // Same fingerprint as the bare tests/keys/id_ed25519 key
+        assert_eq!(
+            ident.md5_fp,
+            "4c:2d:7d:ef:1a:f7:37:1a:9e:d8:e8:27:5d:c0:3a:40"
+        );
+        assert_eq!(
+            ident.sha256_fp,
+            "SHA256:Wpa8NxW6Llg34sAhSfudzWRmz2aMMzsGfPoAu0ez768"
+        );
+    }
+
+    #[test]
+    fn test_identity_new_plain_key_has_no_certificate() {
+        let key_type = b"ssh-ed25519";
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(key_type.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(key_type);
+        let ident = SshIdentity::new(&bytes, "").unwrap();
+        assert!(!ident.is_certificate());
+        assert_eq!(ident.agent_key_blob(), &bytes[..]);
+    }
+
+    #[test]
+    fn test_identity_new_rejects_truncated_certificate() {
+        let key_type = b"ssh-ed25519-cert-v01@openssh.com";
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(key_type.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(key_type);
+        assert!(SshIdentity::new(&bytes, "").is_err());
+    }
+
     #[test]
     fn test_read_string_rejects_invalid_utf8() {
         // 4-byte length header pointing to 3 bytes of invalid UTF-8
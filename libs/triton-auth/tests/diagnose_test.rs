@@ -0,0 +1,144 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Key discovery diagnostics tests for triton-auth
+//!
+//! Each test builds a throwaway `.ssh` directory from the keys in
+//! `tests/keys` and checks the report `diagnose_ssh_dir` produces for it.
+
+use std::path::{Path, PathBuf};
+use triton_auth::diagnose::{CandidateOutcome, CandidateSource, diagnose_ssh_dir};
+
+const ID_RSA_MD5: &str = "fa:56:a1:6b:cc:04:97:fe:e2:98:54:c4:2e:0d:26:c6";
+const ID_RSA2_MD5: &str = "9f:cf:50:5b:df:c2:c5:2a:ad:ad:96:38:31:a5:0d:9e";
+const ID_ED25519_MD5: &str = "4c:2d:7d:ef:1a:f7:37:1a:9e:d8:e8:27:5d:c0:3a:40";
+const ID_ED25519_SHA256: &str = "SHA256:Wpa8NxW6Llg34sAhSfudzWRmz2aMMzsGfPoAu0ez768";
+
+fn test_keys_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/keys")
+}
+
+async fn copy_keys(ssh_dir: &Path, names: &[(&str, &str)]) {
+    tokio::fs::create_dir_all(ssh_dir).await.unwrap();
+    for (src, dst) in names {
+        tokio::fs::copy(test_keys_dir().join(src), ssh_dir.join(dst))
+            .await
+            .unwrap();
+    }
+}
+
+/// A matching `.pub` with its private key is reported usable, and the other
+/// keys are reported as fingerprint mismatches with both fingerprints
+#[tokio::test]
+async fn test_diagnose_reports_match_and_mismatches() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ssh_dir = tmp_dir.path().join(".ssh");
+    copy_keys(
+        &ssh_dir,
+        &[
+            ("id_rsa", "id_rsa"),
+            ("id_rsa.pub", "id_rsa.pub"),
+            ("id_ed25519", "id_ed25519"),
+        ],
+    )
+    .await;
+
+    let report = diagnose_ssh_dir(&ssh_dir, ID_ED25519_SHA256).await;
+    assert!(report.fingerprint_error.is_none());
+    assert_eq!(report.files.len(), 2);
+
+    let rsa = &report.files[0];
+    assert_eq!(
+        rsa.source,
+        CandidateSource::PublicKeyFile(ssh_dir.join("id_rsa.pub"))
+    );
+    assert_eq!(rsa.key_type.as_deref(), Some("ssh-rsa"));
+    assert_eq!(rsa.md5_fingerprint.as_deref(), Some(ID_RSA_MD5));
+    assert_eq!(rsa.outcome, CandidateOutcome::FingerprintMismatch);
+
+    let ed25519 = &report.files[1];
+    assert_eq!(
+        ed25519.source,
+        CandidateSource::PrivateKeyFile(ssh_dir.join("id_ed25519"))
+    );
+    assert_eq!(ed25519.md5_fingerprint.as_deref(), Some(ID_ED25519_MD5));
+    assert_eq!(
+        ed25519.sha256_fingerprint.as_deref(),
+        Some(ID_ED25519_SHA256)
+    );
+    assert_eq!(ed25519.outcome, CandidateOutcome::Usable);
+
+    assert_eq!(report.matched().map(|c| &c.source), Some(&ed25519.source));
+}
+
+/// A matching `.pub` without its private key says so
+#[tokio::test]
+async fn test_diagnose_missing_private_key() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ssh_dir = tmp_dir.path().join(".ssh");
+    copy_keys(&ssh_dir, &[("id_rsa.pub", "work.pub")]).await;
+
+    let report = diagnose_ssh_dir(&ssh_dir, ID_RSA_MD5).await;
+    assert_eq!(report.files.len(), 1);
+    assert_eq!(
+        report.files[0].outcome,
+        CandidateOutcome::MissingPrivateKey(ssh_dir.join("work"))
+    );
+    assert!(report.matched().is_none());
+
+    let text = report.to_string();
+    assert!(text.contains("work.pub (ssh-rsa)"));
+    assert!(text.contains("does not exist"));
+}
+
+/// A matching `.pub` whose private key is encrypted is a match that needs a
+/// passphrase
+#[tokio::test]
+async fn test_diagnose_encrypted_private_key() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ssh_dir = tmp_dir.path().join(".ssh");
+    copy_keys(
+        &ssh_dir,
+        &[("id_rsa2", "id_rsa2"), ("id_rsa2.pub", "id_rsa2.pub")],
+    )
+    .await;
+
+    let report = diagnose_ssh_dir(&ssh_dir, ID_RSA2_MD5).await;
+    assert_eq!(
+        report.files[0].outcome,
+        CandidateOutcome::Encrypted(ssh_dir.join("id_rsa2"))
+    );
+    assert!(report.matched().is_some());
+}
+
+/// An unparseable fingerprint is reported, and keys are still listed
+#[tokio::test]
+async fn test_diagnose_invalid_fingerprint() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ssh_dir = tmp_dir.path().join(".ssh");
+    copy_keys(&ssh_dir, &[("id_rsa", "id_rsa")]).await;
+
+    let report = diagnose_ssh_dir(&ssh_dir, "not-a-fingerprint").await;
+    assert!(report.fingerprint_error.is_some());
+    assert_eq!(report.files.len(), 1);
+    assert_eq!(report.files[0].md5_fingerprint.as_deref(), Some(ID_RSA_MD5));
+    assert_eq!(
+        report.files[0].outcome,
+        CandidateOutcome::FingerprintMismatch
+    );
+    assert!(report.to_string().contains("invalid fingerprint"));
+}
+
+/// A missing directory is reported rather than treated as empty
+#[tokio::test]
+async fn test_diagnose_missing_directory() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ssh_dir = tmp_dir.path().join(".ssh");
+
+    let report = diagnose_ssh_dir(&ssh_dir, ID_RSA_MD5).await;
+    assert!(report.ssh_dir_error.is_some());
+    assert!(report.files.is_empty());
+}
@@ -13,7 +13,11 @@
 use std::path::PathBuf;
 use triton_auth::{
     error::AuthError,
-    fingerprint::{md5_fingerprint_bytes, parse_fingerprint},
+    fingerprint::{
+        Fingerprint, KeyFingerprints, find_key_by_fingerprint, md5_fingerprint,
+        md5_fingerprint_bytes, parse_authorized_keys_line, parse_fingerprint,
+        parse_public_key_line, sha256_fingerprint,
+    },
     key_loader::KeyLoader,
     legacy_pem::PemKeyFormat,
     signature::KeyType,
@@ -21,9 +25,11 @@ use triton_auth::{
 
 /// Test key fingerprints for OpenSSH-format ed25519 key
 const ID_ED25519_MD5: &str = "4c:2d:7d:ef:1a:f7:37:1a:9e:d8:e8:27:5d:c0:3a:40";
+const ID_ED25519_SHA256: &str = "SHA256:Wpa8NxW6Llg34sAhSfudzWRmz2aMMzsGfPoAu0ez768";
 
 /// Test key fingerprints from node-smartdc-auth test suite
 const ID_RSA_MD5: &str = "fa:56:a1:6b:cc:04:97:fe:e2:98:54:c4:2e:0d:26:c6";
+const ID_RSA_SHA256: &str = "SHA256:29GY+6bxcBkcNNUzTnEcTdTv1W3d3PN/OxyplcYSoX4";
 const ID_DSA_MD5: &str = "a6:e6:68:d3:28:2b:0a:a0:12:54:da:c4:c0:22:8d:ba";
 const ID_ECDSA_MD5: &str = "00:74:32:ae:0a:24:3c:7a:e7:07:b8:ee:91:c4:c7:27";
 const ID_RSA2_MD5: &str = "9f:cf:50:5b:df:c2:c5:2a:ad:ad:96:38:31:a5:0d:9e";
@@ -347,6 +353,43 @@ async fn test_scan_finds_openssh_ed25519_key() {
     assert!(matches!(key.key_type().unwrap(), KeyType::Ed25519));
 }
 
+/// OpenSSH certificate companion (`<name>-cert.pub`) is matched by the
+/// fingerprint of the certified key and resolves to `<name>`. A non-standard
+/// name keeps the `id_*` fallback from finding the key on its own.
+#[tokio::test]
+async fn test_scan_finds_key_via_certificate() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let ssh_dir = tmp_dir.path().join(".ssh");
+    tokio::fs::create_dir_all(&ssh_dir).await.unwrap();
+
+    let src = test_keys_dir();
+    tokio::fs::copy(src.join("id_ed25519"), ssh_dir.join("work"))
+        .await
+        .unwrap();
+    tokio::fs::copy(
+        src.join("id_ed25519-cert.pub"),
+        ssh_dir.join("work-cert.pub"),
+    )
+    .await
+    .unwrap();
+
+    let key = KeyLoader::scan_ssh_dir_for_key(&ssh_dir, ID_ED25519_SHA256)
+        .await
+        .expect("Should find key via certificate");
+
+    assert!(matches!(key.key_type().unwrap(), KeyType::Ed25519));
+}
+
+/// Certificates fingerprint as the key they certify, matching `ssh-keygen -l`
+#[test]
+fn test_certificate_fingerprint_is_certified_key() {
+    let cert = std::fs::read_to_string(test_keys_dir().join("id_ed25519-cert.pub")).unwrap();
+    let key = parse_public_key_line(&cert).expect("parse certificate");
+
+    assert_eq!(md5_fingerprint(&key).unwrap(), ID_ED25519_MD5);
+    assert_eq!(sha256_fingerprint(&key).unwrap(), ID_ED25519_SHA256);
+}
+
 /// Encrypted key with .pub companion returns KeyEncrypted (not KeyNotFound)
 #[tokio::test]
 async fn test_scan_encrypted_key_with_pub_returns_key_encrypted() {
@@ -458,3 +501,51 @@ async fn test_scan_no_match_returns_error() {
         "Should fail when no key matches fingerprint"
     );
 }
+
+// ============================================================================
+// authorized_keys Parsing
+// ============================================================================
+
+/// Options before the key and the comment after it do not affect fingerprints
+#[test]
+fn test_authorized_keys_line_with_options() {
+    let pub_line = std::fs::read_to_string(test_keys_dir().join("id_rsa.pub")).unwrap();
+    let line = format!(
+        r#"command="echo \"hello world\"",no-port-forwarding {}"#,
+        pub_line.trim()
+    );
+
+    let key = parse_authorized_keys_line(&line)
+        .expect("parse authorized_keys line")
+        .expect("line holds a key");
+    let fps = KeyFingerprints::from_key(&key).unwrap();
+
+    assert_eq!(fps.md5, ID_RSA_MD5);
+    assert_eq!(fps.sha256, ID_RSA_SHA256);
+    assert_eq!(fps.comment, "user@example.com");
+    assert!(fps.matches(&Fingerprint::parse(ID_RSA_MD5).unwrap()));
+    assert!(fps.matches(&Fingerprint::parse(ID_RSA_SHA256).unwrap()));
+    assert!(!fps.matches(&Fingerprint::parse(ID_ED25519_SHA256).unwrap()));
+}
+
+/// Keys are found by either fingerprint format, skipping lines that are not keys
+#[test]
+fn test_find_key_by_fingerprint_in_authorized_keys() {
+    let rsa = std::fs::read_to_string(test_keys_dir().join("id_rsa.pub")).unwrap();
+    let cert = std::fs::read_to_string(test_keys_dir().join("id_ed25519-cert.pub")).unwrap();
+    let contents = format!(
+        "# keys for deploy\n\nno-pty {}\nthis is not a key\nfrom=\"10.0.0.0/8\" {}\n",
+        rsa.trim(),
+        cert.trim()
+    );
+
+    let key = find_key_by_fingerprint(&contents, &Fingerprint::parse(ID_RSA_MD5).unwrap())
+        .expect("RSA key by MD5");
+    assert_eq!(sha256_fingerprint(&key).unwrap(), ID_RSA_SHA256);
+
+    let key = find_key_by_fingerprint(&contents, &Fingerprint::parse(ID_ED25519_SHA256).unwrap())
+        .expect("certified key by SHA256");
+    assert_eq!(md5_fingerprint(&key).unwrap(), ID_ED25519_MD5);
+
+    assert!(find_key_by_fingerprint(&contents, &Fingerprint::parse(ID_DSA_MD5).unwrap()).is_none());
+}
@@ -11,9 +11,14 @@
 //! Tests signature generation, request signing, and known test vectors.
 
 use std::path::PathBuf;
+use std::sync::Arc;
 use triton_auth::{
+    AuthConfig, AuthError, KeySource, SessionSigner, SignedHeaders, Signer,
+    batch::{SERVER_CLOCK_SKEW_SECS, sign_batch},
     fingerprint::md5_fingerprint_bytes,
+    http_sig,
     key_loader::KeyLoader,
+    sign_request_with_headers,
     signature::{KeyType, RequestSigner, encode_signature},
 };
 
@@ -275,6 +280,73 @@ async fn test_full_request_signing_flow() {
     assert!(auth_header.contains("signature=\""));
 }
 
+/// A signature over host and content-md5 is verified by the HTTP Signature
+/// verifier, and covers the body
+#[tokio::test]
+async fn test_sign_request_with_signed_headers() {
+    let key_path = test_keys_dir().join("id_rsa");
+    let signed_headers: SignedHeaders = "(request-target) host date content-md5"
+        .parse()
+        .expect("Failed to parse signed headers");
+    let config = AuthConfig::new("foo", KeySource::file(&key_path))
+        .with_signed_headers(signed_headers.clone());
+
+    let body = br#"{"name":"web0"}"#;
+    let mut headers = http::HeaderMap::new();
+    signed_headers
+        .add_computed_headers(&mut headers, "cloudapi.example.com", Some(body))
+        .expect("Failed to add computed headers");
+    let (date, auth_header) = sign_request_with_headers(&config, "POST", "/foo/machines", &headers)
+        .await
+        .expect("Failed to sign");
+    headers.insert(http::header::DATE, date.parse().unwrap());
+
+    let parsed = http_sig::parse_signature_params(
+        auth_header
+            .strip_prefix("Signature ")
+            .expect("Not a Signature header"),
+    )
+    .expect("Failed to parse Authorization header");
+    assert_eq!(
+        parsed.headers,
+        ["(request-target)", "host", "date", "content-md5"]
+    );
+
+    let public_key = http_sig::parse_public_key_blob(
+        &std::fs::read_to_string(test_keys_dir().join("id_rsa.pub")).unwrap(),
+    )
+    .expect("Failed to parse public key");
+    let signing_string =
+        http_sig::build_signing_string("POST", "/foo/machines", &headers, &parsed.headers)
+            .expect("Failed to build signing string");
+    http_sig::verify_signature(
+        &public_key,
+        &parsed.algorithm,
+        signing_string.as_bytes(),
+        &parsed.signature,
+    )
+    .expect("Signature should verify");
+
+    // A different body no longer matches the signed digest
+    let mut tampered = headers.clone();
+    tampered.remove("content-md5");
+    signed_headers
+        .add_computed_headers(&mut tampered, "cloudapi.example.com", Some(b"{}"))
+        .unwrap();
+    let signing_string =
+        http_sig::build_signing_string("POST", "/foo/machines", &tampered, &parsed.headers)
+            .unwrap();
+    assert!(
+        http_sig::verify_signature(
+            &public_key,
+            &parsed.algorithm,
+            signing_string.as_bytes(),
+            &parsed.signature,
+        )
+        .is_err()
+    );
+}
+
 /// Signing string format test
 #[test]
 fn test_signing_string_format() {
@@ -308,3 +380,159 @@ fn test_authorization_header_format() {
     assert!(auth_header.contains("headers=\"(request-target) date\""));
     assert!(auth_header.contains("signature=\"dGVzdHNpZw==\""));
 }
+
+// ============================================================================
+// Batch Signing
+// ============================================================================
+
+/// Every request in a batch shares one Date header, and each signature is
+/// the one the key would produce for that request on its own
+#[tokio::test]
+async fn test_sign_batch_with_file_key() {
+    let key_path = test_keys_dir().join("id_rsa");
+    let config = AuthConfig::new("foo", KeySource::file(&key_path));
+    let requests = [
+        ("GET", "/foo/machines"),
+        ("DELETE", "/foo/machines/abc"),
+        ("GET", "/foo/images"),
+    ];
+
+    let batch = sign_batch(&config, &requests)
+        .await
+        .expect("Failed to sign batch");
+    assert_eq!(batch.len(), requests.len());
+    assert!(batch.expires_at() > batch.signed_at());
+
+    let key = KeyLoader::load_legacy_from_file(&key_path, None)
+        .await
+        .expect("Failed to load RSA key");
+    let signer = RequestSigner::new("foo", ID_RSA_MD5, KeyType::Rsa);
+    let signed = batch.requests().expect("Batch should not have expired");
+
+    for ((method, path), request) in requests.iter().zip(signed) {
+        assert_eq!(request.method, *method);
+        assert_eq!(request.path, *path);
+
+        let signing_string = signer.signing_string(method, path, batch.date());
+        let sig_bytes = key.sign(signing_string.as_bytes()).expect("Failed to sign");
+        assert_eq!(
+            request.authorization,
+            signer.authorization_header(&encode_signature(&sig_bytes))
+        );
+    }
+}
+
+/// A batch cannot be used once the server would reject its Date header
+#[tokio::test]
+async fn test_sign_batch_expires() {
+    let config = AuthConfig::new("foo", KeySource::file(test_keys_dir().join("id_rsa")));
+
+    let batch = sign_batch(&config, &[("GET", "/foo/machines")])
+        .await
+        .expect("Failed to sign batch");
+
+    let err = batch
+        .requests_at(batch.signed_at() + chrono::Duration::seconds(SERVER_CLOCK_SKEW_SECS))
+        .unwrap_err();
+    assert!(matches!(err, AuthError::SignatureExpired(_)));
+}
+
+// ============================================================================
+// Session Signing
+// ============================================================================
+
+/// A session signer produces the same headers as signing each request alone
+#[tokio::test]
+async fn test_session_signer_matches_sign_request() {
+    let key_path = test_keys_dir().join("id_rsa");
+    let config = AuthConfig::new("foo", KeySource::file(&key_path)).with_user("bar");
+
+    let session = SessionSigner::new(&config)
+        .await
+        .expect("Failed to create session signer");
+    assert_eq!(session.key_type(), KeyType::Rsa);
+    assert_eq!(session.fingerprint(), ID_RSA_MD5);
+    assert_eq!(
+        session.key_id(),
+        format!("/foo/users/bar/keys/{}", ID_RSA_MD5)
+    );
+
+    let key = KeyLoader::load_legacy_from_file(&key_path, None)
+        .await
+        .expect("Failed to load RSA key");
+    let signer = RequestSigner::new("foo", ID_RSA_MD5, KeyType::Rsa).with_subuser("bar");
+
+    for (method, path) in [("GET", "/foo/machines"), ("DELETE", "/foo/machines/abc")] {
+        let (date, auth_header) = session.sign(method, path).await.expect("Failed to sign");
+
+        let signing_string = signer.signing_string(method, path, &date);
+        let sig_bytes = key.sign(signing_string.as_bytes()).expect("Failed to sign");
+        assert_eq!(
+            auth_header,
+            signer.authorization_header(&encode_signature(&sig_bytes))
+        );
+    }
+}
+
+/// Signing at a fixed time gives a fixed Date header, and ECDSA keys are
+/// resolved to the right algorithm
+#[tokio::test]
+async fn test_session_signer_sign_at() {
+    let config = AuthConfig::new("foo", KeySource::file(test_keys_dir().join("id_ecdsa")));
+    let session = SessionSigner::new(&config)
+        .await
+        .expect("Failed to create session signer");
+    assert_eq!(session.fingerprint(), ID_ECDSA_MD5);
+
+    let time = chrono::DateTime::parse_from_rfc3339("2026-01-15T10:30:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let (date, auth_header) = session
+        .sign_at("GET", "/foo/machines", time)
+        .await
+        .expect("Failed to sign");
+    assert_eq!(date, "Thu, 15 Jan 2026 10:30:00 GMT");
+    assert!(auth_header.contains(&format!("keyId=\"/foo/keys/{}\"", ID_ECDSA_MD5)));
+    assert!(auth_header.contains("algorithm=\"ecdsa-sha256\""));
+}
+
+/// There is nothing to resolve for an unauthenticated configuration
+#[tokio::test]
+async fn test_session_signer_unauthenticated() {
+    let err = SessionSigner::new(&AuthConfig::unauthenticated("foo"))
+        .await
+        .err()
+        .expect("Unauthenticated config should not give a signer");
+    assert!(matches!(err, AuthError::ConfigError(_)), "{:?}", err);
+}
+
+/// Configurations and session signers sign the same way behind the
+/// `Signer` trait
+#[tokio::test]
+async fn test_signer_trait_objects() {
+    let key_path = test_keys_dir().join("id_rsa");
+    let config = AuthConfig::new("foo", KeySource::file(&key_path));
+    let session = SessionSigner::new(&config)
+        .await
+        .expect("Failed to create session signer");
+
+    let key = KeyLoader::load_legacy_from_file(&key_path, None)
+        .await
+        .expect("Failed to load RSA key");
+    let request_signer = RequestSigner::new("foo", ID_RSA_MD5, KeyType::Rsa);
+
+    let signers: Vec<Arc<dyn Signer>> = vec![Arc::new(config), Arc::new(session)];
+    for signer in signers {
+        let (date, auth_header) = signer
+            .sign("GET", "/foo/machines")
+            .await
+            .expect("Failed to sign");
+
+        let signing_string = request_signer.signing_string("GET", "/foo/machines", &date);
+        let sig_bytes = key.sign(signing_string.as_bytes()).expect("Failed to sign");
+        assert_eq!(
+            auth_header,
+            request_signer.authorization_header(&encode_signature(&sig_bytes))
+        );
+    }
+}
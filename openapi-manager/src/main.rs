@@ -76,6 +76,34 @@ fn all_apis() -> Result<dropshot_api_manager::ManagedApis> {
             },
             api_description: cloudapi_api::cloud_api_mod::stub_api_description,
         },
+        ManagedApiConfig {
+            ident: "cloudapi-health-api",
+            versions: Versions::Lockstep {
+                version: crate_version("apis/cloudapi-api")?,
+            },
+            title: "Triton CloudAPI Health",
+            metadata: ManagedApiMetadata {
+                description: Some(
+                    "Triton CloudAPI - unauthenticated service health endpoints (/--ping, /--version) used by load balancers",
+                ),
+                ..ManagedApiMetadata::default()
+            },
+            api_description: cloudapi_api::cloud_api_health_mod::stub_api_description,
+        },
+        ManagedApiConfig {
+            ident: "fwapi-api",
+            versions: Versions::Lockstep {
+                version: crate_version("apis/fwapi-api")?,
+            },
+            title: "Triton FWAPI",
+            metadata: ManagedApiMetadata {
+                description: Some(
+                    "Triton FWAPI - internal HTTP API for managing firewall rules, including global rules, and resolving the rules that apply to VMs",
+                ),
+                ..ManagedApiMetadata::default()
+            },
+            api_description: fwapi_api::fwapi_api_mod::stub_api_description,
+        },
         ManagedApiConfig {
             ident: "imgapi-api",
             versions: Versions::Lockstep {
@@ -104,6 +132,20 @@ fn all_apis() -> Result<dropshot_api_manager::ManagedApis> {
             },
             api_description: jira_api::jira_api_mod::stub_api_description,
         },
+        ManagedApiConfig {
+            ident: "jira-bulk-api",
+            versions: Versions::Lockstep {
+                version: crate_version("apis/jira-api")?,
+            },
+            title: "JIRA Bulk Fetch API (Subset)",
+            metadata: ManagedApiMetadata {
+                description: Some(
+                    "JIRA REST API v3 bulk issue fetch used by bugview-service, kept apart from the JIRA API subset because its path conflicts with /rest/api/3/issue/{issue_id_or_key} in Dropshot.",
+                ),
+                ..ManagedApiMetadata::default()
+            },
+            api_description: jira_api::jira_bulk_api_mod::stub_api_description,
+        },
         ManagedApiConfig {
             ident: "mahi-api",
             versions: Versions::Lockstep {
@@ -46,8 +46,8 @@ pub fn apply_transforms(generated_dir: &Utf8Path, patched_dir: &Utf8Path) -> Res
             .context("failed to transform imgapi-api.json")?;
     }
 
-    // NAPI, PAPI, VMAPI all need the same error schema patch
-    for api_name in ["napi-api", "papi-api", "vmapi-api"] {
+    // FWAPI, NAPI, PAPI, VMAPI all need the same error schema patch
+    for api_name in ["fwapi-api", "napi-api", "papi-api", "vmapi-api"] {
         let source = generated_dir.join(format!("{api_name}.json"));
         if source.exists() {
             let dest = patched_dir.join(format!("{api_name}.json"));
@@ -106,7 +106,7 @@ pub fn check_transforms(generated_dir: &Utf8Path, patched_dir: &Utf8Path) -> Res
         apply_all_imgapi_patches,
     )?;
 
-    for api_name in ["napi-api", "papi-api", "vmapi-api"] {
+    for api_name in ["fwapi-api", "napi-api", "papi-api", "vmapi-api"] {
         all_fresh &= check_one_transform(
             generated_dir,
             patched_dir,
@@ -474,7 +474,7 @@ fn patch_sapi_create_status_codes(spec: &mut Value) -> Result<()> {
 
 /// Patch the Error schema to match Node.js Triton services' actual error format.
 ///
-/// All Node.js Triton services (IMGAPI, NAPI, PAPI, VMAPI, etc.) return errors as
+/// All Node.js Triton services (FWAPI, IMGAPI, NAPI, PAPI, VMAPI, etc.) return errors as
 /// `{"code": "ResourceNotFound", "message": "..."}`. Dropshot generates an Error
 /// schema with `error_code` and a required `request_id` which these services don't
 /// include. This patch makes `request_id` optional and uses `code` instead.
@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Static assets for the HTML pages
+//!
+//! The stylesheet and script are compiled into the binary, so the service has
+//! no runtime file dependencies and the pages load nothing from third-party
+//! hosts (see the CSP in `main.rs`).
+
+/// `Cache-Control` header value for static assets
+///
+/// Asset URLs are not versioned, so keep the lifetime short enough that a
+/// deploy is picked up within the hour.
+pub(crate) const CACHE_CONTROL: &str = "public, max-age=3600";
+
+/// An embedded static asset
+pub(crate) struct StaticAsset {
+    pub(crate) content_type: &'static str,
+    pub(crate) content: &'static [u8],
+}
+
+/// All assets served under `/bugview/static/`
+const ASSETS: &[(&str, StaticAsset)] = &[
+    (
+        "bugview.css",
+        StaticAsset {
+            content_type: "text/css; charset=utf-8",
+            content: include_bytes!("../static/bugview.css"),
+        },
+    ),
+    (
+        "bugview.js",
+        StaticAsset {
+            content_type: "text/javascript; charset=utf-8",
+            content: include_bytes!("../static/bugview.js"),
+        },
+    ),
+];
+
+/// Look up an asset by file name
+pub(crate) fn lookup(name: &str) -> Option<&'static StaticAsset> {
+    ASSETS
+        .iter()
+        .find(|(asset_name, _)| *asset_name == name)
+        .map(|(_, asset)| asset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_assets_are_embedded() {
+        let css = lookup("bugview.css").expect("css");
+        assert!(css.content_type.starts_with("text/css"));
+        assert!(!css.content.is_empty());
+
+        let js = lookup("bugview.js").expect("js");
+        assert!(js.content_type.starts_with("text/javascript"));
+        assert!(!js.content.is_empty());
+    }
+
+    #[test]
+    fn unknown_assets_are_not_found() {
+        assert!(lookup("missing.css").is_none());
+        assert!(lookup("../Cargo.toml").is_none());
+        assert!(lookup("").is_none());
+    }
+}
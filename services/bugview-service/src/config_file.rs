@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Settings file for the reloadable configuration.
+//!
+//! The environment of a running process cannot be changed, so settings that
+//! should be changeable without a restart can also be put in the file named
+//! by `BUGVIEW_CONFIG_FILE`. It is read at startup and again on every
+//! `POST /bugview/admin/reload`, and its settings take precedence over the
+//! environment.
+//!
+//! The file uses the same names as the environment variables, one
+//! `NAME=value` per line, in the format of a shell `.env` file: blank lines
+//! and lines starting with `#` are ignored, a leading `export` is allowed,
+//! and values may be wrapped in single or double quotes.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Read the settings in the file at `path`.
+pub(crate) fn read(path: &Path) -> Result<HashMap<String, String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    parse(&contents).with_context(|| format!("invalid settings file {}", path.display()))
+}
+
+/// Parse the settings in `contents`. A setting given twice takes its last
+/// value.
+pub(crate) fn parse(contents: &str) -> Result<HashMap<String, String>> {
+    let mut settings = HashMap::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+
+        let Some((name, value)) = line.split_once('=') else {
+            anyhow::bail!("line {}: expected NAME=value", number + 1);
+        };
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            anyhow::bail!("line {}: invalid setting name {:?}", number + 1, name);
+        }
+
+        settings.insert(name.to_string(), unquote(value.trim()).to_string());
+    }
+    Ok(settings)
+}
+
+/// Strip one pair of matching quotes from around `value`.
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_settings() {
+        let settings = parse(
+            r#"
+# Public labels
+JIRA_DEFAULT_LABEL=public
+export JIRA_ALLOWED_LABELS = "smartos,illumos"
+JIRA_ALLOWED_DOMAINS='github.com'
+BUGVIEW_STATS_TOKEN=
+JIRA_DEFAULT_LABEL=os-public
+"#,
+        )
+        .expect("valid settings");
+
+        assert_eq!(settings.len(), 4);
+        assert_eq!(settings["JIRA_DEFAULT_LABEL"], "os-public");
+        assert_eq!(settings["JIRA_ALLOWED_LABELS"], "smartos,illumos");
+        assert_eq!(settings["JIRA_ALLOWED_DOMAINS"], "github.com");
+        assert_eq!(settings["BUGVIEW_STATS_TOKEN"], "");
+    }
+
+    #[test]
+    fn test_parse_invalid_settings() {
+        for contents in ["JIRA_DEFAULT_LABEL", "=public", "JIRA DEFAULT=public"] {
+            assert!(parse(contents).is_err(), "{}", contents);
+        }
+        assert!(read(Path::new("/nonexistent/bugview.env")).is_err());
+    }
+}
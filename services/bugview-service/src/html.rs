@@ -8,7 +8,7 @@
 //!
 //! This module renders JIRA issue content by converting Atlassian Document Format (ADF)
 //! to HTML. ADF is the structured JSON format JIRA uses in `fields.description` and
-//! comment bodies.
+//! comment bodies; it is parsed by `bugview_api::adf` and written out by `HtmlWriter`.
 //!
 //! # Security
 //!
@@ -16,30 +16,61 @@
 //! produce safe HTML. Key security measures:
 //! - All text content is HTML-escaped via `html_escape()` before rendering
 //! - The `HtmlWriter` implementation escapes text in `write_text()` and URLs in `start_link()`
+//! - Attachments are never inlined; only external `http`/`https` media URLs become links
 //! - Link URLs are escaped but not validated (URL scheme validation happens earlier in `search.rs`)
 
 use anyhow::Result;
 use askama::Template;
-use bugview_api::{IssueListItem, IssueSort};
+use bugview_api::adf::{self, AdfNode, AdfWriter, PanelType, TextStyle};
+use bugview_api::{IssueHistoryEntry, IssueListItem, IssueSort, Theme};
+
+use crate::search::IssuePage;
+use crate::suggest::RelatedIssue;
+
+/// Longest title in an issue page's link preview, in characters
+const PREVIEW_TITLE_CHARS: usize = 120;
+
+/// Longest description in an issue page's link preview, in characters
+const PREVIEW_DESCRIPTION_CHARS: usize = 200;
 
 /// Issue index page template
 #[derive(Template)]
 #[template(path = "issue_index.html")]
 struct IssueIndexTemplate<'a> {
+    theme: Theme,
     title: &'a str,
+    current_project: Option<&'a str>,
     current_label: Option<&'a str>,
     allowed_labels: &'a [String],
+    projects: &'a [String],
+    /// Path that this index's label pages are under
+    base_path: &'a str,
     page_path: &'a str,
     sort: IssueSort,
     next_page_token: Option<&'a str>,
     is_last: bool,
+    page: Option<u32>,
+    prev_page: Option<u32>,
+    next_page: Option<u32>,
     issues: &'a [IssueListItem],
 }
 
+/// Where an index page links to, other than its own pages
+#[derive(Debug, Default)]
+pub struct IndexNav<'a> {
+    /// Project the index is limited to, if any
+    pub project: Option<&'a str>,
+    /// Labels the index can be filtered by
+    pub allowed_labels: &'a [String],
+    /// Projects with public labels of their own, each with its own index
+    pub projects: &'a [String],
+}
+
 /// Single issue page template
 #[derive(Template)]
 #[template(path = "issue.html")]
 struct IssueTemplate<'a> {
+    theme: Theme,
     title: &'a str,
     key: &'a str,
     summary: &'a str,
@@ -52,6 +83,43 @@ struct IssueTemplate<'a> {
     remote_links: &'a [RemoteLinkView],
     /// True if remote links could not be fetched (show warning to user)
     remote_links_error: bool,
+    history: &'a [IssueHistoryEntry],
+    /// True if the issue is a cached copy that could not be refreshed
+    stale: bool,
+    preview: &'a LinkPreview,
+}
+
+/// Open Graph and Twitter card metadata, for links to an issue page posted
+/// to chat and social media. Values are plain text; the template escapes
+/// them.
+pub struct LinkPreview {
+    /// Issue key and summary
+    pub title: String,
+    /// Start of the description, empty if there is none
+    pub description: String,
+    /// Canonical URL of the issue page
+    pub url: String,
+}
+
+impl LinkPreview {
+    /// Preview for `issue`, whose page is at `url`
+    pub fn new(issue: &crate::jira_client::Issue, summary: &str, url: &str) -> Self {
+        let description = issue
+            .fields
+            .get("description")
+            .map(|d| {
+                let mut writer = TextWriter::default();
+                adf::render(&adf::parse_content(d), &mut writer);
+                writer.output
+            })
+            .unwrap_or_default();
+
+        Self {
+            title: truncate_text(&format!("{}: {}", issue.key, summary), PREVIEW_TITLE_CHARS),
+            description: truncate_text(&description, PREVIEW_DESCRIPTION_CHARS),
+            url: url.to_string(),
+        }
+    }
 }
 
 /// Comment data for template rendering
@@ -72,9 +140,11 @@ pub struct RemoteLinkView {
 #[derive(Template)]
 #[template(path = "error.html")]
 struct ErrorTemplate<'a> {
+    theme: Theme,
     title: &'a str,
     status_code: u16,
     message: &'a str,
+    related: &'a [RelatedIssue],
 }
 
 /// HTML template renderer
@@ -90,37 +160,49 @@ impl HtmlRenderer {
     /// Render the issue index page
     pub fn render_issue_index(
         &self,
-        issues: &[IssueListItem],
-        next_page_token: Option<String>,
-        is_last: bool,
-        sort: IssueSort,
+        page: &IssuePage,
         label: Option<&str>,
-        allowed_labels: &[String],
+        nav: &IndexNav<'_>,
+        theme: Theme,
     ) -> Result<String> {
+        // Project indexes are namespaced under /bugview/project/{project}
+        let base_path = match nav.project {
+            Some(p) => format!("/bugview/project/{}", urlencoding::encode(p)),
+            None => "/bugview".to_string(),
+        };
+
         // Build page path for pagination links
         let page_path = if let Some(l) = label {
             let enc = urlencoding::encode(l);
-            format!("/bugview/label/{}", enc)
+            format!("{}/label/{}", base_path, enc)
         } else {
-            "/bugview/index.html".to_string()
+            format!("{}/index.html", base_path)
         };
 
-        let title_string = if let Some(l) = label {
-            format!("Public Issues: {}", l)
-        } else {
+        let scope: Vec<&str> = nav.project.into_iter().chain(label).collect();
+        let title_string = if scope.is_empty() {
             "Public Issues Index".to_string()
+        } else {
+            format!("Public Issues: {}", scope.join(" / "))
         };
 
         // Render the issue_index template
         let index_template = IssueIndexTemplate {
+            theme,
             title: &title_string,
+            current_project: nav.project,
             current_label: label,
-            allowed_labels,
+            allowed_labels: nav.allowed_labels,
+            projects: nav.projects,
+            base_path: &base_path,
             page_path: &page_path,
-            sort,
-            next_page_token: next_page_token.as_deref(),
-            is_last,
-            issues,
+            sort: page.sort,
+            next_page_token: page.next_page_token.as_deref(),
+            is_last: page.is_last,
+            page: page.page,
+            prev_page: page.prev_page(),
+            next_page: page.next_page(),
+            issues: &page.issues,
         };
         index_template
             .render()
@@ -131,11 +213,19 @@ impl HtmlRenderer {
     ///
     /// If `remote_links_error` is true, displays a warning that links could not be loaded
     /// instead of showing an empty list (which would misleadingly suggest no links exist).
+    /// `history` must already be filtered down to public changes. If `stale`
+    /// is true, displays a banner noting the issue may be out of date.
+    /// `canonical_url` is the public URL of the page, for link previews.
+    #[allow(clippy::too_many_arguments)]
     pub fn render_issue(
         &self,
         issue: &crate::jira_client::Issue,
+        canonical_url: &str,
         remote_links: &[crate::jira_client::RemoteLink],
         remote_links_error: bool,
+        history: &[IssueHistoryEntry],
+        stale: bool,
+        theme: Theme,
     ) -> Result<String> {
         // Extract key fields with logging for missing data
         let summary = issue
@@ -181,86 +271,8 @@ impl HtmlRenderer {
                 ""
             });
 
-        // Render description from ADF
-        let description = issue
-            .fields
-            .get("description")
-            .and_then(|adf| adf.get("content"))
-            .map(adf_to_html)
-            .unwrap_or_default();
-
-        // Extract and render comments, filtering out restricted comments
-        // (defense in depth: JSON path also filters via strip_restricted_comments)
-        let comments: Vec<CommentView> = issue
-            .fields
-            .get("comment")
-            .and_then(|c| c.get("comments"))
-            .and_then(|c| c.as_array())
-            .map(|comments| {
-                comments
-                    .iter()
-                    .filter(|comment| !crate::search::comment_has_visibility(comment))
-                    .map(|comment| {
-                        let author = comment
-                            .get("author")
-                            .and_then(|a| a.get("displayName"))
-                            .and_then(|d| d.as_str())
-                            .unwrap_or("Unknown")
-                            .to_string();
-
-                        let created = comment
-                            .get("created")
-                            .and_then(|c| c.as_str())
-                            .unwrap_or("")
-                            .to_string();
-
-                        let updated = comment
-                            .get("updated")
-                            .and_then(|u| u.as_str())
-                            .unwrap_or("");
-
-                        let edited = if created != updated {
-                            Some(updated.to_string())
-                        } else {
-                            None
-                        };
-
-                        let body = comment
-                            .get("body")
-                            .map(|body| {
-                                if let Some(body_str) = body.as_str() {
-                                    // Plain text fallback
-                                    format!("<p>{}</p>", html_escape(body_str))
-                                } else if let Some(body_content) = body.get("content") {
-                                    // ADF format
-                                    adf_to_html(body_content)
-                                } else {
-                                    tracing::warn!(
-                                        issue_key = %issue.key,
-                                        "Comment body has unexpected format (not string or ADF)"
-                                    );
-                                    "<p><em>(Comment body could not be displayed)</em></p>"
-                                        .to_string()
-                                }
-                            })
-                            .unwrap_or_else(|| {
-                                tracing::warn!(
-                                    issue_key = %issue.key,
-                                    "Comment missing body field"
-                                );
-                                String::new()
-                            });
-
-                        CommentView {
-                            author,
-                            created,
-                            edited,
-                            body,
-                        }
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+        let description = description_html(issue).unwrap_or_default();
+        let comments = issue_comments(issue);
 
         // Extract remote links
         let link_views: Vec<RemoteLinkView> = remote_links
@@ -273,9 +285,12 @@ impl HtmlRenderer {
             })
             .collect();
 
+        let preview = LinkPreview::new(issue, summary, canonical_url);
+
         // Render issue template
         let title = format!("{} - Bugview", issue.key);
         let issue_template = IssueTemplate {
+            theme,
             title: &title,
             key: issue.key.as_str(),
             summary,
@@ -287,14 +302,23 @@ impl HtmlRenderer {
             comments: &comments,
             remote_links: &link_views,
             remote_links_error,
+            history,
+            stale,
+            preview: &preview,
         };
         issue_template
             .render()
             .map_err(|e| anyhow::anyhow!("Failed to render page: {}", e))
     }
 
-    /// Render an error page
-    pub fn render_error(&self, status_code: u16, message: &str) -> Result<String> {
+    /// Render an error page, listing `related` public issues if there are any
+    pub fn render_error(
+        &self,
+        status_code: u16,
+        message: &str,
+        related: &[RelatedIssue],
+        theme: Theme,
+    ) -> Result<String> {
         let title = match status_code {
             404 => "Not Found",
             500 => "Internal Server Error",
@@ -302,9 +326,11 @@ impl HtmlRenderer {
         };
 
         let template = ErrorTemplate {
+            theme,
             title,
             status_code,
             message,
+            related,
         };
         template
             .render()
@@ -312,216 +338,370 @@ impl HtmlRenderer {
     }
 }
 
-/// Convert ADF (Atlassian Document Format) to HTML
+/// Render the description of an issue from ADF to HTML
+///
+/// Returns None if the issue has no description.
+pub fn description_html(issue: &crate::jira_client::Issue) -> Option<String> {
+    issue
+        .fields
+        .get("description")
+        .and_then(|adf| adf.get("content"))
+        .map(adf_to_html)
+}
+
+/// Extract the public comments of an issue, oldest first, with their bodies
+/// rendered to HTML
+pub fn issue_comments(issue: &crate::jira_client::Issue) -> Vec<CommentView> {
+    // Extract and render comments, filtering out restricted comments
+    // (defense in depth: JSON path also filters via strip_restricted_comments)
+    issue
+        .fields
+        .get("comment")
+        .and_then(|c| c.get("comments"))
+        .and_then(|c| c.as_array())
+        .map(|comments| {
+            comments
+                .iter()
+                .filter(|comment| !crate::search::comment_has_visibility(comment))
+                .map(|comment| {
+                    let author = comment
+                        .get("author")
+                        .and_then(|a| a.get("displayName"))
+                        .and_then(|d| d.as_str())
+                        .unwrap_or("Unknown")
+                        .to_string();
+
+                    let created = comment
+                        .get("created")
+                        .and_then(|c| c.as_str())
+                        .unwrap_or("")
+                        .to_string();
+
+                    let updated = comment
+                        .get("updated")
+                        .and_then(|u| u.as_str())
+                        .unwrap_or("");
+
+                    let edited = if created != updated {
+                        Some(updated.to_string())
+                    } else {
+                        None
+                    };
+
+                    let body = comment
+                        .get("body")
+                        .map(|body| {
+                            if let Some(body_str) = body.as_str() {
+                                // Plain text fallback
+                                format!("<p>{}</p>", html_escape(body_str))
+                            } else if let Some(body_content) = body.get("content") {
+                                // ADF format
+                                adf_to_html(body_content)
+                            } else {
+                                tracing::warn!(
+                                    issue_key = %issue.key,
+                                    "Comment body has unexpected format (not string or ADF)"
+                                );
+                                "<p><em>(Comment body could not be displayed)</em></p>".to_string()
+                            }
+                        })
+                        .unwrap_or_else(|| {
+                            tracing::warn!(
+                                issue_key = %issue.key,
+                                "Comment missing body field"
+                            );
+                            String::new()
+                        });
+
+                    CommentView {
+                        author,
+                        created,
+                        edited,
+                        body,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Convert ADF (Atlassian Document Format) content to HTML
 ///
 /// # Security
 ///
 /// This function is a trust boundary - it receives untrusted content from JIRA and must
 /// produce safe HTML. All text content is HTML-escaped via `html_escape()` before rendering.
 fn adf_to_html(nodes: &serde_json::Value) -> String {
-    let mut output = String::new();
-    render_adf_to_html(nodes, &mut output);
-    output
+    let mut writer = HtmlWriter::default();
+    adf::render(&adf::parse_content(nodes), &mut writer);
+    writer.output
 }
 
-fn render_adf_to_html(nodes: &serde_json::Value, output: &mut String) {
-    let Some(nodes_array) = nodes.as_array() else {
-        return;
-    };
+/// [`AdfWriter`] that produces HTML
+#[derive(Default)]
+struct HtmlWriter {
+    output: String,
+}
 
-    for node in nodes_array {
-        let Some(node_obj) = node.as_object() else {
-            continue;
-        };
-        let node_type = node_obj.get("type").and_then(|t| t.as_str()).unwrap_or("");
+impl HtmlWriter {
+    fn start_link(&mut self, href: &str) {
+        self.output.push_str(&format!(
+            r#"<a href="{}" rel="noopener noreferrer" target="_blank">"#,
+            html_escape(href)
+        ));
+    }
 
-        match node_type {
-            "paragraph" => {
-                output.push_str("<p>");
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_html(content, output);
-                }
-                output.push_str("</p>\n");
-            }
+    fn write_card(&mut self, url: &str) {
+        self.start_link(url);
+        self.output.push_str(&html_escape(adf::card_label(url)));
+        self.output.push_str("</a>");
+    }
+}
 
-            "text" => {
-                if let Some(text) = node_obj.get("text").and_then(|t| t.as_str()) {
-                    let marks = node_obj
-                        .get("marks")
-                        .and_then(|m| m.as_array())
-                        .map(|m| m.as_slice())
-                        .unwrap_or(&[]);
-
-                    let mut has_strong = false;
-                    let mut has_em = false;
-                    let mut has_code = false;
-                    let mut has_strike = false;
-                    let mut link_href: Option<&str> = None;
-
-                    for mark in marks {
-                        if let Some(mark_type) = mark.get("type").and_then(|t| t.as_str()) {
-                            match mark_type {
-                                "strong" => has_strong = true,
-                                "em" => has_em = true,
-                                "code" => has_code = true,
-                                "strike" => has_strike = true,
-                                "link" => {
-                                    link_href = mark
-                                        .get("attrs")
-                                        .and_then(|a| a.get("href"))
-                                        .and_then(|h| h.as_str());
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
+impl AdfWriter for HtmlWriter {
+    fn write_text(&mut self, text: &str, style: TextStyle<'_>) {
+        if let Some(href) = style.link {
+            self.start_link(href);
+        }
+        if style.strong {
+            self.output.push_str("<strong>");
+        }
+        if style.em {
+            self.output.push_str("<em>");
+        }
+        if style.underline {
+            self.output.push_str("<u>");
+        }
+        if style.code {
+            self.output.push_str("<code>");
+        }
+        if style.strike {
+            self.output.push_str("<del>");
+        }
 
-                    if let Some(href) = link_href {
-                        output.push_str(&format!(
-                            r#"<a href="{}" rel="noopener noreferrer" target="_blank">"#,
-                            html_escape(href)
-                        ));
-                    }
-                    if has_strong {
-                        output.push_str("<strong>");
-                    }
-                    if has_em {
-                        output.push_str("<em>");
-                    }
-                    if has_code {
-                        output.push_str("<code>");
-                    }
-                    if has_strike {
-                        output.push_str("<del>");
-                    }
+        self.output.push_str(&html_escape(text));
 
-                    output.push_str(&html_escape(text));
+        if style.strike {
+            self.output.push_str("</del>");
+        }
+        if style.code {
+            self.output.push_str("</code>");
+        }
+        if style.underline {
+            self.output.push_str("</u>");
+        }
+        if style.em {
+            self.output.push_str("</em>");
+        }
+        if style.strong {
+            self.output.push_str("</strong>");
+        }
+        if style.link.is_some() {
+            self.output.push_str("</a>");
+        }
+    }
 
-                    if has_strike {
-                        output.push_str("</del>");
-                    }
-                    if has_code {
-                        output.push_str("</code>");
-                    }
-                    if has_em {
-                        output.push_str("</em>");
-                    }
-                    if has_strong {
-                        output.push_str("</strong>");
-                    }
-                    if link_href.is_some() {
-                        output.push_str("</a>");
+    fn start_node(&mut self, node: &AdfNode) {
+        match node {
+            AdfNode::Paragraph(_) => self.output.push_str("<p>"),
+            AdfNode::HardBreak => self.output.push_str("<br>\n"),
+            AdfNode::Rule => self.output.push_str("<hr>\n"),
+            AdfNode::Heading { level, .. } => self.output.push_str(&format!("<h{}>", level)),
+            AdfNode::BulletList(_) => self.output.push_str("<ul>\n"),
+            AdfNode::OrderedList { start: 1, .. } => self.output.push_str("<ol>\n"),
+            AdfNode::OrderedList { start, .. } => {
+                self.output.push_str(&format!("<ol start=\"{}\">\n", start))
+            }
+            AdfNode::ListItem(_) => self.output.push_str("<li>"),
+            AdfNode::CodeBlock { language, .. } => match language {
+                Some(lang) => self.output.push_str(&format!(
+                    r#"<pre><code class="language-{}">"#,
+                    html_escape(lang)
+                )),
+                None => self.output.push_str("<pre><code>"),
+            },
+            AdfNode::Blockquote(_) => self.output.push_str("<blockquote>\n"),
+            AdfNode::Panel { panel_type, .. } => {
+                let class = match panel_type {
+                    PanelType::Success | PanelType::Tip => "alert-success",
+                    PanelType::Warning => "alert-warning",
+                    PanelType::Error => "alert-danger",
+                    PanelType::Note => "alert-secondary",
+                    PanelType::Info | PanelType::Unknown => "alert-info",
+                };
+                self.output
+                    .push_str(&format!(r#"<div class="alert {}">"#, class));
+            }
+            AdfNode::Expand { title, .. } => {
+                self.output.push_str(&format!(
+                    "<details>\n<summary>{}</summary>\n",
+                    html_escape(title.as_deref().unwrap_or("Details"))
+                ));
+            }
+            AdfNode::Table(_) => self
+                .output
+                .push_str("<table class=\"table table-bordered\">\n"),
+            AdfNode::TableRow(_) => self.output.push_str("<tr>"),
+            AdfNode::TableCell { header: true, .. } => self.output.push_str("<th>"),
+            AdfNode::TableCell { header: false, .. } => self.output.push_str("<td>"),
+            AdfNode::Media(media) => {
+                // Attachments are only reachable with JIRA credentials, so
+                // only external media can be linked; neither is inlined
+                // because the CSP restricts images to our own origin.
+                let external = media
+                    .url
+                    .as_deref()
+                    .filter(|url| url.starts_with("https://") || url.starts_with("http://"));
+                match external {
+                    Some(url) => {
+                        self.start_link(url);
+                        self.output.push_str(&html_escape(media.label()));
+                        self.output.push_str("</a>");
                     }
+                    None => self.output.push_str(&format!(
+                        "<em>[attachment: {}]</em>",
+                        html_escape(media.label())
+                    )),
                 }
             }
-
-            "inlineCard" => {
-                if let Some(attrs) = node_obj.get("attrs")
-                    && let Some(url) = attrs.get("url").and_then(|u| u.as_str())
-                {
-                    let display = url.rsplit('/').next().unwrap_or(url);
-                    output.push_str(&format!(
-                        r#"<a href="{}" rel="noopener noreferrer" target="_blank">{}</a>"#,
-                        html_escape(url),
-                        html_escape(display)
-                    ));
-                }
+            AdfNode::Mention { name, .. } => {
+                self.output
+                    .push_str(&format!("<strong>@{}</strong>", html_escape(name)));
             }
-
-            "codeBlock" => {
-                output.push_str("<pre><code>");
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_html(content, output);
-                }
-                output.push_str("</code></pre>\n");
+            AdfNode::Emoji { short_name, text } => {
+                self.output
+                    .push_str(&html_escape(text.as_deref().unwrap_or(short_name)));
             }
-
-            "hardBreak" => {
-                output.push_str("<br>\n");
+            AdfNode::InlineCard { url } => self.write_card(url),
+            AdfNode::BlockCard { url } => {
+                self.output.push_str("<p>");
+                self.write_card(url);
+                self.output.push_str("</p>\n");
             }
-
-            "mention" => {
-                if let Some(attrs) = node_obj.get("attrs") {
-                    let display = attrs
-                        .get("text")
-                        .and_then(|t| t.as_str())
-                        .map(|t| t.strip_prefix('@').unwrap_or(t))
-                        .or_else(|| attrs.get("id").and_then(|i| i.as_str()));
-                    if let Some(d) = display {
-                        output.push_str(&format!("<strong>@{}</strong>", html_escape(d)));
-                    }
-                }
+            AdfNode::Status { text } => {
+                self.output.push_str(&format!(
+                    r#"<span class="badge bg-secondary">{}</span>"#,
+                    html_escape(&text.to_uppercase())
+                ));
             }
-
-            "bulletList" => {
-                output.push_str("<ul>\n");
-                if let Some(content) = node_obj.get("content")
-                    && let Some(items) = content.as_array()
-                {
-                    for item in items {
-                        output.push_str("<li>");
-                        if let Some(item_content) = item.get("content") {
-                            render_adf_to_html(item_content, output);
-                        }
-                        output.push_str("</li>\n");
-                    }
-                }
-                output.push_str("</ul>\n");
+            AdfNode::Date { timestamp } => {
+                self.output
+                    .push_str(&html_escape(&format_adf_date(timestamp)));
             }
+            AdfNode::Text { .. } | AdfNode::MediaGroup(_) | AdfNode::Unknown { .. } => {}
+        }
+    }
 
-            "orderedList" => {
-                output.push_str("<ol>\n");
-                if let Some(content) = node_obj.get("content")
-                    && let Some(items) = content.as_array()
-                {
-                    for item in items {
-                        output.push_str("<li>");
-                        if let Some(item_content) = item.get("content") {
-                            render_adf_to_html(item_content, output);
-                        }
-                        output.push_str("</li>\n");
-                    }
-                }
-                output.push_str("</ol>\n");
-            }
+    fn end_node(&mut self, node: &AdfNode) {
+        match node {
+            AdfNode::Paragraph(_) => self.output.push_str("</p>\n"),
+            AdfNode::Heading { level, .. } => self.output.push_str(&format!("</h{}>\n", level)),
+            AdfNode::BulletList(_) => self.output.push_str("</ul>\n"),
+            AdfNode::OrderedList { .. } => self.output.push_str("</ol>\n"),
+            AdfNode::ListItem(_) => self.output.push_str("</li>\n"),
+            AdfNode::CodeBlock { .. } => self.output.push_str("</code></pre>\n"),
+            AdfNode::Blockquote(_) => self.output.push_str("</blockquote>\n"),
+            AdfNode::Panel { .. } => self.output.push_str("</div>\n"),
+            AdfNode::Expand { .. } => self.output.push_str("</details>\n"),
+            AdfNode::Table(_) => self.output.push_str("</table>\n"),
+            AdfNode::TableRow(_) => self.output.push_str("</tr>\n"),
+            AdfNode::TableCell { header: true, .. } => self.output.push_str("</th>"),
+            AdfNode::TableCell { header: false, .. } => self.output.push_str("</td>"),
+            _ => {}
+        }
+    }
+}
 
-            "listItem" => {
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_html(content, output);
-                }
-            }
+/// [`AdfWriter`] that produces plain text on a single line, for link previews
+///
+/// Blocks are separated by a space, and media and other non-text nodes are
+/// left out.
+#[derive(Default)]
+struct TextWriter {
+    output: String,
+}
 
-            "heading" => {
-                let level = node_obj
-                    .get("attrs")
-                    .and_then(|a| a.get("level"))
-                    .and_then(|l| l.as_u64())
-                    .unwrap_or(1)
-                    .min(6) as u8;
-                output.push_str(&format!("<h{}>", level));
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_html(content, output);
-                }
-                output.push_str(&format!("</h{}>\n", level));
-            }
+impl TextWriter {
+    /// Separate what comes next from the text so far
+    fn push_break(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with(' ') {
+            self.output.push(' ');
+        }
+    }
+}
 
-            "panel" => {
-                output.push_str(r#"<div class="alert alert-info" style="margin: 10px 0;">"#);
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_html(content, output);
-                }
-                output.push_str("</div>\n");
-            }
+impl AdfWriter for TextWriter {
+    fn write_text(&mut self, text: &str, _style: TextStyle<'_>) {
+        // Differently styled runs can split a word, so only the whitespace
+        // in the text itself separates words
+        if text.starts_with(char::is_whitespace) {
+            self.push_break();
+        }
+        self.output
+            .push_str(&text.split_whitespace().collect::<Vec<_>>().join(" "));
+        if text.ends_with(char::is_whitespace) {
+            self.push_break();
+        }
+    }
 
-            _ => {
-                if let Some(content) = node_obj.get("content") {
-                    render_adf_to_html(content, output);
-                }
+    fn start_node(&mut self, node: &AdfNode) {
+        match node {
+            AdfNode::Mention { name, .. } => self.output.push_str(&format!("@{}", name)),
+            AdfNode::Emoji { short_name, text } => {
+                self.output.push_str(text.as_deref().unwrap_or(short_name))
             }
+            AdfNode::InlineCard { url } => self.output.push_str(adf::card_label(url)),
+            AdfNode::Status { text } => self.output.push_str(&text.to_uppercase()),
+            AdfNode::Date { timestamp } => self.output.push_str(&format_adf_date(timestamp)),
+            AdfNode::BlockCard { url } => {
+                self.push_break();
+                self.output.push_str(adf::card_label(url));
+            }
+            AdfNode::Text { .. } => {}
+            _ => self.push_break(),
+        }
+    }
+
+    fn end_node(&mut self, node: &AdfNode) {
+        match node {
+            AdfNode::Mention { .. }
+            | AdfNode::Emoji { .. }
+            | AdfNode::InlineCard { .. }
+            | AdfNode::Status { .. }
+            | AdfNode::Date { .. }
+            | AdfNode::Text { .. } => {}
+            _ => self.push_break(),
         }
     }
 }
 
+/// Shorten `text` to at most `max` characters, breaking at a word boundary
+/// where possible and marking the cut with an ellipsis
+fn truncate_text(text: &str, max: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+
+    let cut: String = text.chars().take(max.saturating_sub(1)).collect();
+    let cut = match cut.rfind(' ') {
+        Some(i) if i > 0 => &cut[..i],
+        _ => cut.as_str(),
+    };
+    format!("{}\u{2026}", cut.trim_end())
+}
+
+/// Format an ADF `date` node (milliseconds since the epoch) as `YYYY-MM-DD`,
+/// falling back to the raw value if it cannot be parsed.
+fn format_adf_date(timestamp: &str) -> String {
+    timestamp
+        .parse::<i64>()
+        .ok()
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
 /// Simple HTML escape function
 fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -538,15 +718,15 @@ mod tests {
     #[test]
     fn label_links_are_url_encoded() {
         let renderer = HtmlRenderer::new();
-        let issues: Vec<IssueListItem> = vec![];
         let html = renderer
             .render_issue_index(
-                &issues,
-                None,
-                true,
-                IssueSort::Updated,
+                &index_page(Some(1), true),
                 None,
-                &["needs triage".to_string()],
+                &IndexNav {
+                    allowed_labels: &["needs triage".to_string()],
+                    ..Default::default()
+                },
+                Theme::Auto,
             )
             .expect("render");
         assert!(html.contains("/bugview/label/needs%20triage"));
@@ -555,20 +735,319 @@ mod tests {
     #[test]
     fn pagination_path_for_label_is_encoded() {
         let renderer = HtmlRenderer::new();
-        let issues: Vec<IssueListItem> = vec![];
         let html = renderer
             .render_issue_index(
-                &issues,
-                None,
-                true,
-                IssueSort::Updated,
+                &index_page(Some(1), true),
                 Some("needs triage"),
-                &["needs triage".to_string()],
+                &IndexNav {
+                    allowed_labels: &["needs triage".to_string()],
+                    ..Default::default()
+                },
+                Theme::Auto,
             )
             .expect("render");
         assert!(html.contains("/bugview/label/needs%20triage?sort=updated"));
     }
 
+    #[test]
+    fn project_index_links_are_namespaced() {
+        let renderer = HtmlRenderer::new();
+        let projects = ["OS".to_string(), "TRITON".to_string()];
+        let nav = IndexNav {
+            project: Some("OS"),
+            allowed_labels: &["smartos".to_string()],
+            projects: &projects,
+        };
+
+        let html = renderer
+            .render_issue_index(&index_page(Some(2), false), None, &nav, Theme::Auto)
+            .expect("render");
+        assert!(html.contains("Public Issues: OS"));
+        assert!(html.contains(r#"<a href="/bugview/project/OS/label/smartos">smartos</a>"#));
+        assert!(html.contains("/bugview/project/OS/index.html?page=3&amp;sort=updated"));
+        assert!(html.contains("<b>OS</b>"));
+        assert!(html.contains(r#"<a href="/bugview/project/TRITON/index.html">TRITON</a>"#));
+
+        let html = renderer
+            .render_issue_index(
+                &index_page(Some(1), true),
+                Some("smartos"),
+                &nav,
+                Theme::Auto,
+            )
+            .expect("render");
+        assert!(html.contains("Public Issues: OS / smartos"));
+        assert!(html.contains("/bugview/project/OS/label/smartos?sort=updated"));
+
+        // Without per-project labels there is no project list
+        let html = renderer
+            .render_issue_index(
+                &index_page(Some(1), true),
+                None,
+                &IndexNav::default(),
+                Theme::Auto,
+            )
+            .expect("render");
+        assert!(!html.contains("Projects:"));
+    }
+
+    #[test]
+    fn pagination_links_use_page_numbers() {
+        let renderer = HtmlRenderer::new();
+        let html = renderer
+            .render_issue_index(
+                &index_page(Some(3), false),
+                None,
+                &IndexNav::default(),
+                Theme::Auto,
+            )
+            .expect("render");
+        assert!(html.contains("Page 3"));
+        assert!(html.contains("/bugview/index.html?page=2&amp;sort=updated\">Previous Page"));
+        assert!(html.contains("/bugview/index.html?page=4&amp;sort=updated\">Next Page"));
+        assert!(!html.contains("next_page_token="));
+    }
+
+    #[test]
+    fn pagination_first_and_last_pages_omit_links() {
+        let renderer = HtmlRenderer::new();
+        let html = renderer
+            .render_issue_index(
+                &index_page(Some(1), true),
+                None,
+                &IndexNav::default(),
+                Theme::Auto,
+            )
+            .expect("render");
+        assert!(html.contains("First Page"));
+        assert!(!html.contains("Previous Page"));
+        assert!(!html.contains("Next Page"));
+    }
+
+    #[test]
+    fn pagination_by_token_links_next_token() {
+        let renderer = HtmlRenderer::new();
+        let html = renderer
+            .render_issue_index(
+                &index_page(None, false),
+                None,
+                &IndexNav::default(),
+                Theme::Auto,
+            )
+            .expect("render");
+        assert!(!html.contains("Previous Page"));
+        assert!(html.contains("?next_page_token=abc123&amp;sort=updated\">Next Page"));
+    }
+
+    #[test]
+    fn theme_is_rendered_on_html_element() {
+        let renderer = HtmlRenderer::new();
+        let html = renderer
+            .render_issue_index(
+                &index_page(Some(1), true),
+                None,
+                &IndexNav::default(),
+                Theme::Dark,
+            )
+            .expect("render");
+        assert!(html.contains(r#"<html lang="en" data-theme="dark">"#));
+
+        let html = renderer
+            .render_error(404, "missing", &[], Theme::Auto)
+            .expect("render");
+        assert!(html.contains(r#"<html lang="en" data-theme="auto">"#));
+    }
+
+    fn preview_issue(summary: &str, description: serde_json::Value) -> crate::jira_client::Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": "OS-1",
+            "id": "1",
+            "fields": { "summary": summary, "description": description }
+        }))
+        .expect("issue")
+    }
+
+    #[test]
+    fn link_preview_is_plain_text() {
+        let description = serde_json::json!({
+            "type": "doc",
+            "content": [
+                {"type": "paragraph", "content": [
+                    {"type": "text", "text": "Panics in "},
+                    {"type": "text", "text": "zfs", "marks": [{"type": "code"}]},
+                    {"type": "text", "text": "_send when  asked"}
+                ]},
+                {"type": "paragraph", "content": [
+                    {"type": "text", "text": "nicely."}
+                ]}
+            ]
+        });
+        let issue = preview_issue("zfs send panics", description);
+        let preview = LinkPreview::new(&issue, "zfs send panics", "https://x/bugview/issue/OS-1");
+        assert_eq!(preview.title, "OS-1: zfs send panics");
+        assert_eq!(preview.description, "Panics in zfs_send when asked nicely.");
+        assert_eq!(preview.url, "https://x/bugview/issue/OS-1");
+
+        let preview = LinkPreview::new(&preview_issue("s", serde_json::Value::Null), "s", "u");
+        assert_eq!(preview.description, "");
+    }
+
+    #[test]
+    fn truncates_on_word_boundaries() {
+        assert_eq!(truncate_text("  short  ", 10), "short");
+        assert_eq!(truncate_text("exactly 10", 10), "exactly 10");
+        assert_eq!(truncate_text("one two three", 10), "one two\u{2026}");
+        assert_eq!(truncate_text("abcdefghijkl", 5), "abcd\u{2026}");
+        // Multi-byte characters are counted, not split
+        assert_eq!(truncate_text("ééééééé", 4), "ééé\u{2026}");
+    }
+
+    #[test]
+    fn issue_page_has_escaped_link_preview() {
+        let description = serde_json::json!({
+            "type": "doc",
+            "content": [{"type": "paragraph", "content": [
+                {"type": "text", "text": "Quote \" and <tag> & 'apostrophe'"}
+            ]}]
+        });
+        let summary = "Summary with \"quotes\" & <b>markup</b>";
+        let issue = preview_issue(summary, description);
+        let html = HtmlRenderer::new()
+            .render_issue(
+                &issue,
+                "https://bugs.example.com/bugview/issue/OS-1",
+                &[],
+                false,
+                &[],
+                false,
+                Theme::Auto,
+            )
+            .expect("render");
+
+        assert!(html.contains(
+            r#"<link rel="canonical" href="https://bugs.example.com/bugview/issue/OS-1">"#
+        ));
+        assert!(html.contains(r#"<meta name="twitter:card" content="summary">"#));
+        assert!(!html.contains("<b>markup"));
+        assert!(!html.contains("<tag>"));
+        for (property, value) in [
+            (
+                "og:title",
+                "OS-1: Summary with &#34;quotes&#34; &#38; &#60;b&#62;markup&#60;/b&#62;",
+            ),
+            (
+                "og:description",
+                "Quote &#34; and &#60;tag&#62; &#38; &#39;apostrophe&#39;",
+            ),
+        ] {
+            assert!(
+                html.contains(&format!(
+                    r#"<meta property="{}" content="{}">"#,
+                    property, value
+                )),
+                "missing {}: {}",
+                property,
+                html
+            );
+        }
+    }
+
+    #[test]
+    fn error_page_lists_related_issues() {
+        let renderer = HtmlRenderer::new();
+        let html = renderer
+            .render_error(404, "missing", &[], Theme::Auto)
+            .expect("render");
+        assert!(!html.contains("Related public issues"));
+
+        let related = [RelatedIssue {
+            key: "OS-99".to_string(),
+            summary: "<b>bold</b> summary".to_string(),
+        }];
+        let html = renderer
+            .render_error(404, "missing", &related, Theme::Auto)
+            .expect("render");
+        assert!(html.contains("Related public issues"));
+        assert!(html.contains(r#"<a href="/bugview/issue/OS-99">OS-99</a>"#));
+        assert!(html.contains("&#60;b&#62;bold&#60;/b&#62; summary"));
+    }
+
+    #[test]
+    fn issue_index_shows_relative_updated_time() {
+        let renderer = HtmlRenderer::new();
+        let item = |key: &str, updated_display| IssueListItem {
+            key: jira_api::IssueKey::new_unchecked(key),
+            summary: "summary".to_string(),
+            status: "Open".to_string(),
+            resolution: None,
+            updated: "not a timestamp".to_string(),
+            created: String::new(),
+            updated_display,
+            created_display: None,
+            labels: vec![],
+            issue_type: None,
+            priority: None,
+        };
+        let mut page = index_page(Some(1), true);
+        page.issues = vec![
+            item(
+                "PROJ-1",
+                Some(bugview_api::DisplayTimestamp {
+                    iso: "2023-10-05T11:27:22+09:00".to_string(),
+                    date: "2023-10-05".to_string(),
+                    relative: "2 days ago".to_string(),
+                }),
+            ),
+            // Without display text the raw JIRA value is shown
+            item("PROJ-2", None),
+        ];
+
+        let html = renderer
+            .render_issue_index(&page, None, &IndexNav::default(), Theme::Auto)
+            .expect("render");
+        assert!(html.contains(r#"<time datetime="2023-10-05T11:27:22+09:00""#));
+        assert!(html.contains(">2 days ago</time>"));
+        assert!(html.contains("<td>not a timestamp</td>"));
+    }
+
+    #[test]
+    fn issue_index_shows_badges() {
+        let renderer = HtmlRenderer::new();
+        let mut page = index_page(Some(1), true);
+        page.issues = vec![IssueListItem {
+            key: jira_api::IssueKey::new_unchecked("PROJ-1"),
+            summary: "summary".to_string(),
+            status: "Open".to_string(),
+            resolution: None,
+            updated: String::new(),
+            created: String::new(),
+            updated_display: None,
+            created_display: None,
+            labels: vec!["public".to_string(), "kernel".to_string()],
+            issue_type: Some("Bug".to_string()),
+            priority: Some("2 - Critical".to_string()),
+        }];
+
+        let html = renderer
+            .render_issue_index(&page, None, &IndexNav::default(), Theme::Auto)
+            .expect("render");
+        assert!(html.contains(r#"<span class="badge bg-secondary">Bug</span> "#));
+        assert!(html.contains(r#"<span class="badge bg-primary">2 - Critical</span> summary"#));
+        assert!(html.contains(
+            r#"summary <span class="badge bg-info">public</span> <span class="badge bg-info">kernel</span>"#
+        ));
+    }
+
+    fn index_page(page: Option<u32>, is_last: bool) -> IssuePage {
+        IssuePage {
+            issues: vec![],
+            next_page_token: (!is_last).then(|| "abc123".to_string()),
+            is_last,
+            sort: IssueSort::Updated,
+            page,
+        }
+    }
+
     #[test]
     fn adf_inline_card_renders_anchor_with_last_segment() {
         let input = serde_json::json!([
@@ -583,6 +1062,78 @@ mod tests {
         assert!(html.contains(">ABC-123<"));
     }
 
+    #[test]
+    fn adf_text_marks_nest_inside_link() {
+        let input = serde_json::json!([{
+            "type": "text",
+            "text": "<bold>",
+            "marks": [
+                {"type": "strong"},
+                {"type": "link", "attrs": {"href": "https://example.com/?a=1&b=2"}}
+            ]
+        }]);
+        assert_eq!(
+            super::adf_to_html(&input),
+            r#"<a href="https://example.com/?a=1&amp;b=2" rel="noopener noreferrer" target="_blank"><strong>&lt;bold&gt;</strong></a>"#
+        );
+    }
+
+    #[test]
+    fn adf_table_renders_header_and_data_cells() {
+        let input = serde_json::json!([{
+            "type": "table",
+            "content": [
+                {"type": "tableRow", "content": [
+                    {"type": "tableHeader", "content": [{"type": "text", "text": "Name"}]}
+                ]},
+                {"type": "tableRow", "content": [
+                    {"type": "tableCell", "content": [{"type": "text", "text": "value"}]}
+                ]}
+            ]
+        }]);
+        let html = super::adf_to_html(&input);
+        assert!(html.contains("<th>Name</th>"), "html: {}", html);
+        assert!(html.contains("<td>value</td>"), "html: {}", html);
+    }
+
+    #[test]
+    fn adf_panel_type_selects_alert_class() {
+        let input = serde_json::json!([
+            {"type": "panel", "attrs": {"panelType": "error"}, "content": []}
+        ]);
+        assert!(super::adf_to_html(&input).contains("alert-danger"));
+    }
+
+    #[test]
+    fn adf_media_is_not_inlined() {
+        let input = serde_json::json!([
+            {"type": "mediaSingle", "content": [
+                {"type": "media", "attrs": {"type": "file", "id": "abc", "alt": "<core>.txt"}}
+            ]},
+            {"type": "mediaSingle", "content": [
+                {"type": "media", "attrs": {"type": "external", "url": "javascript:alert(1)"}}
+            ]}
+        ]);
+        let html = super::adf_to_html(&input);
+        assert!(
+            html.contains("[attachment: &lt;core&gt;.txt]"),
+            "html: {}",
+            html
+        );
+        assert!(!html.contains("<img"), "html: {}", html);
+        assert!(!html.contains("href=\"javascript:"), "html: {}", html);
+    }
+
+    #[test]
+    fn adf_unknown_node_renders_children() {
+        let input = serde_json::json!([
+            {"type": "layoutSection", "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "kept"}]}
+            ]}
+        ]);
+        assert_eq!(super::adf_to_html(&input), "<p>kept</p>\n");
+    }
+
     #[test]
     fn html_escape_handles_ampersand() {
         assert_eq!(html_escape("foo & bar"), "foo &amp; bar");
@@ -0,0 +1,540 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Cache of issue index searches, warmed in the background.
+//!
+//! Every index page is a JIRA search, and reaching a numbered page can take
+//! several, so the first visitor after a deploy used to wait on a series of
+//! JIRA calls. [`IndexCache`] wraps the JIRA client and caches searches for
+//! the issue cache TTL, and a warmer task preloads the first pages of the
+//! public indexes, and the most recently updated issues listed on them, at
+//! startup and then on an interval.
+//!
+//! Warmed searches are kept until the next round has replaced them, so an
+//! index page may be up to the warming interval out of date. Page tokens
+//! found while warming are stored like those found while serving, so
+//! `?page=N` links to warmed pages resolve without walking.
+
+use crate::issue_cache::IssueCache;
+use crate::jira_client::{
+    Issue, IssueKey, JiraClientTrait, RemoteLink, SearchFilter, SearchResponse,
+};
+use crate::search::{IssueScope, page_query_key, parse_jira_timestamp};
+use crate::token_cache::TokenCache;
+use anyhow::Result;
+use async_trait::async_trait;
+use bugview_api::IssueSort;
+use indexmap::IndexMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Default number of index pages warmed per index.
+pub(crate) const DEFAULT_WARM_PAGES: u32 = 3;
+
+/// Default number of most recently updated issues warmed.
+pub(crate) const DEFAULT_WARM_ISSUES: usize = 25;
+
+/// Default time between warming rounds (seconds).
+pub(crate) const DEFAULT_WARM_INTERVAL_SECS: u64 = 5 * 60;
+
+/// Maximum number of cached searches to retain.
+const INDEX_CACHE_MAX_ENTRIES: usize = 500;
+
+struct CachedSearch {
+    response: SearchResponse,
+    expires_at: Instant,
+}
+
+/// JIRA client that caches searches. Issues and remote links are fetched
+/// straight from the wrapped client.
+pub(crate) struct IndexCache {
+    jira: Arc<dyn JiraClientTrait>,
+    /// Searches in the order they were cached, oldest first
+    searches: Mutex<IndexMap<String, CachedSearch>>,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl IndexCache {
+    /// Cache the searches made through `jira` for `ttl`.
+    pub(crate) fn new(jira: Arc<dyn JiraClientTrait>, ttl: Duration) -> Self {
+        Self {
+            jira,
+            searches: Mutex::new(IndexMap::new()),
+            ttl,
+            max_entries: INDEX_CACHE_MAX_ENTRIES,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, IndexMap<String, CachedSearch>> {
+        self.searches.lock().unwrap_or_else(|poisoned| {
+            tracing::error!("Index cache mutex was poisoned, recovering");
+            poisoned.into_inner()
+        })
+    }
+
+    fn cached(&self, key: &str) -> Option<SearchResponse> {
+        let mut searches = self.lock();
+        match searches.get(key) {
+            Some(search) if Instant::now() < search.expires_at => Some(search.response.clone()),
+            Some(_) => {
+                searches.shift_remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, key: String, response: SearchResponse, ttl: Duration) {
+        let mut searches = self.lock();
+
+        // Re-insert so the entries stay ordered by fetch time
+        searches.shift_remove(&key);
+        while searches.len() >= self.max_entries {
+            searches.shift_remove_index(0);
+        }
+
+        searches.insert(
+            key,
+            CachedSearch {
+                response,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+
+    /// Drop every cached search, returning how many there were.
+    pub(crate) fn purge(&self) -> u64 {
+        let mut searches = self.lock();
+        let purged = searches.len() as u64;
+        searches.clear();
+        purged
+    }
+
+    /// Search JIRA without looking at the cache, and cache the result for
+    /// `ttl`.
+    async fn refresh(
+        &self,
+        labels: &[String],
+        filter: &SearchFilter,
+        page_token: Option<&str>,
+        sort: &str,
+        ttl: Duration,
+    ) -> Result<SearchResponse> {
+        let response = self
+            .jira
+            .search_issues(labels, filter, page_token, sort)
+            .await?;
+        self.store(
+            search_key(labels, filter, page_token, sort),
+            response.clone(),
+            ttl,
+        );
+        Ok(response)
+    }
+}
+
+/// Identify a search: its query, sort order and page.
+fn search_key(
+    labels: &[String],
+    filter: &SearchFilter,
+    page_token: Option<&str>,
+    sort: &str,
+) -> String {
+    format!(
+        "{}:{}:{}:{}",
+        sort,
+        labels.join(","),
+        filter.jql_clauses().join(" AND "),
+        page_token.unwrap_or_default()
+    )
+}
+
+#[async_trait]
+impl JiraClientTrait for IndexCache {
+    async fn search_issues(
+        &self,
+        labels: &[String],
+        filter: &SearchFilter,
+        page_token: Option<&str>,
+        sort: &str,
+    ) -> Result<SearchResponse> {
+        if let Some(response) = self.cached(&search_key(labels, filter, page_token, sort)) {
+            return Ok(response);
+        }
+        self.refresh(labels, filter, page_token, sort, self.ttl)
+            .await
+    }
+
+    async fn get_issue(&self, key: &IssueKey) -> Result<Issue> {
+        self.jira.get_issue(key).await
+    }
+
+    async fn get_issues(&self, keys: &[IssueKey]) -> Result<Vec<Issue>> {
+        self.jira.get_issues(keys).await
+    }
+
+    async fn get_remote_links(&self, issue_id: &str) -> Result<Vec<RemoteLink>> {
+        self.jira.get_remote_links(issue_id).await
+    }
+}
+
+/// What the warmer preloads, and how often.
+#[derive(Debug, Clone)]
+pub(crate) struct WarmConfig {
+    /// Index pages preloaded per index; 0 disables warming
+    pub(crate) pages: u32,
+    /// Most recently updated issues preloaded into the issue cache
+    pub(crate) issues: usize,
+    /// Time between warming rounds
+    pub(crate) interval: Duration,
+}
+
+/// What one warming round preloaded.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct WarmSummary {
+    pub(crate) pages: u32,
+    pub(crate) issues: usize,
+}
+
+/// Preload the first pages of each index in `scopes`, in the default sort
+/// order, then the most recently updated issues listed on them. The issues
+/// are fetched together, in as few JIRA requests as possible.
+///
+/// Failures are logged and skipped: a round warms what it can.
+pub(crate) async fn warm(
+    index_cache: &IndexCache,
+    token_cache: &TokenCache,
+    issue_cache: &Arc<IssueCache>,
+    scopes: &[IssueScope],
+    config: &WarmConfig,
+) -> WarmSummary {
+    // Warmed searches must outlive the interval, or the index would go cold
+    // between rounds
+    let ttl = config.interval + index_cache.ttl;
+    let sort = IssueSort::default();
+    let mut summary = WarmSummary::default();
+    let mut listed: Vec<(Option<chrono::DateTime<chrono::Utc>>, IssueKey)> = Vec::new();
+
+    for scope in scopes {
+        let filter = scope.restrict(SearchFilter::default());
+        let page_query = page_query_key(&scope.labels, &filter, sort);
+        let mut page_token: Option<String> = None;
+
+        for page in 1..=config.pages {
+            let response = match index_cache
+                .refresh(
+                    &scope.labels,
+                    &filter,
+                    page_token.as_deref(),
+                    sort.as_str(),
+                    ttl,
+                )
+                .await
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    tracing::warn!(page, labels = ?scope.labels, error = %e, "Failed to warm index page");
+                    break;
+                }
+            };
+            summary.pages += 1;
+
+            listed.extend(response.issues.iter().map(|issue| {
+                let updated = issue
+                    .fields
+                    .get("updated")
+                    .and_then(|v| v.as_str())
+                    .and_then(parse_jira_timestamp);
+                (updated, issue.key.clone())
+            }));
+
+            let Some(next_token) = response.next_page_token else {
+                break;
+            };
+            token_cache.store_page(&page_query, page + 1, next_token.clone());
+            page_token = Some(next_token);
+        }
+    }
+
+    // Most recently updated first; issues without a timestamp last
+    listed.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut keys: Vec<IssueKey> = Vec::new();
+    for (_, key) in listed {
+        if keys.len() == config.issues {
+            break;
+        }
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    match issue_cache.prefetch(&index_cache.jira, &keys).await {
+        Ok(cached) => summary.issues = cached,
+        Err(e) => tracing::warn!(issues = keys.len(), error = %e, "Failed to warm issues"),
+    }
+
+    summary
+}
+
+/// Warm the caches now, and then every `config.interval`, until the process
+/// exits. The indexes to warm are looked up with `scopes` at the start of
+/// every round.
+pub(crate) fn spawn_warmer(
+    index_cache: Arc<IndexCache>,
+    token_cache: TokenCache,
+    issue_cache: Arc<IssueCache>,
+    scopes: impl Fn() -> Vec<IssueScope> + Send + 'static,
+    config: WarmConfig,
+) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(config.interval);
+        // A slow round is not followed by a burst of catch-up rounds
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            // The first tick completes immediately, warming at startup
+            ticker.tick().await;
+            let scopes = scopes();
+            let summary = warm(&index_cache, &token_cache, &issue_cache, &scopes, &config).await;
+            tracing::info!(
+                pages = summary.pages,
+                issues = summary.issues,
+                "Warmed index and issue caches"
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::fetch_issues_for_html;
+    use crate::timefmt::DisplayTimezone;
+    use bugview_api::IssueListQuery;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// JIRA client with three pages of two issues each, OS-6 to OS-1,
+    /// counting calls. Issue N was updated on day N of January, so the
+    /// pages list the most recently updated first.
+    #[derive(Default)]
+    struct PagedJira {
+        searches: AtomicUsize,
+        issues: AtomicUsize,
+        bulk_fetches: AtomicUsize,
+    }
+
+    fn issue(n: u32) -> Issue {
+        Issue {
+            key: IssueKey::new_unchecked(format!("OS-{}", n)),
+            id: n.to_string(),
+            fields: [
+                (
+                    "summary".to_string(),
+                    serde_json::json!(format!("Issue {}", n)),
+                ),
+                (
+                    "updated".to_string(),
+                    serde_json::json!(format!("2026-01-{:02}T10:00:00.000+0000", n)),
+                ),
+                ("labels".to_string(), serde_json::json!(["public"])),
+            ]
+            .into(),
+            rendered_fields: None,
+            changelog: None,
+        }
+    }
+
+    #[async_trait]
+    impl JiraClientTrait for PagedJira {
+        async fn search_issues(
+            &self,
+            _labels: &[String],
+            _filter: &SearchFilter,
+            page_token: Option<&str>,
+            _sort: &str,
+        ) -> Result<SearchResponse> {
+            self.searches.fetch_add(1, Ordering::SeqCst);
+            let page: u32 = match page_token {
+                None => 1,
+                Some(token) => token.trim_start_matches("page-").parse()?,
+            };
+            let first = 8 - page * 2;
+            Ok(SearchResponse {
+                issues: vec![issue(first), issue(first - 1)],
+                is_last: Some(page == 3),
+                next_page_token: (page < 3).then(|| format!("page-{}", page + 1)),
+            })
+        }
+
+        async fn get_issue(&self, key: &IssueKey) -> Result<Issue> {
+            self.issues.fetch_add(1, Ordering::SeqCst);
+            let n = key.as_str().trim_start_matches("OS-").parse()?;
+            Ok(issue(n))
+        }
+
+        async fn get_issues(&self, keys: &[IssueKey]) -> Result<Vec<Issue>> {
+            self.bulk_fetches.fetch_add(1, Ordering::SeqCst);
+            keys.iter()
+                .map(|key| Ok(issue(key.as_str().trim_start_matches("OS-").parse()?)))
+                .collect()
+        }
+
+        async fn get_remote_links(&self, _issue_id: &str) -> Result<Vec<RemoteLink>> {
+            Ok(vec![])
+        }
+    }
+
+    fn scope() -> IssueScope {
+        IssueScope {
+            labels: vec!["public".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn page_query(page: u32) -> IssueListQuery {
+        IssueListQuery {
+            next_page_token: None,
+            sort: None,
+            page: Some(page),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_searches_cached() {
+        let paged = Arc::new(PagedJira::default());
+        let cache = IndexCache::new(
+            Arc::clone(&paged) as Arc<dyn JiraClientTrait>,
+            Duration::from_secs(60),
+        );
+        let labels = vec!["public".to_string()];
+        let filter = SearchFilter::default();
+
+        let first = cache
+            .search_issues(&labels, &filter, None, "updated")
+            .await
+            .unwrap();
+        let again = cache
+            .search_issues(&labels, &filter, None, "updated")
+            .await
+            .unwrap();
+        assert_eq!(paged.searches.load(Ordering::SeqCst), 1);
+        assert_eq!(first.next_page_token, again.next_page_token);
+
+        // Other pages and sort orders are searches of their own
+        cache
+            .search_issues(&labels, &filter, Some("page-2"), "updated")
+            .await
+            .unwrap();
+        cache
+            .search_issues(&labels, &filter, None, "key")
+            .await
+            .unwrap();
+        assert_eq!(paged.searches.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_expired_search_refetched() {
+        let paged = Arc::new(PagedJira::default());
+        let cache = IndexCache::new(
+            Arc::clone(&paged) as Arc<dyn JiraClientTrait>,
+            Duration::ZERO,
+        );
+        let labels = vec!["public".to_string()];
+
+        for _ in 0..2 {
+            cache
+                .search_issues(&labels, &SearchFilter::default(), None, "updated")
+                .await
+                .unwrap();
+        }
+        assert_eq!(paged.searches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_warm() {
+        let paged = Arc::new(PagedJira::default());
+        let index_cache = IndexCache::new(
+            Arc::clone(&paged) as Arc<dyn JiraClientTrait>,
+            Duration::ZERO,
+        );
+        let token_cache = TokenCache::new();
+        let issue_cache = Arc::new(IssueCache::default());
+        let config = WarmConfig {
+            pages: 2,
+            issues: 3,
+            interval: Duration::from_secs(300),
+        };
+
+        let summary = warm(
+            &index_cache,
+            &token_cache,
+            &issue_cache,
+            &[scope()],
+            &config,
+        )
+        .await;
+        assert_eq!(
+            summary,
+            WarmSummary {
+                pages: 2,
+                issues: 3
+            }
+        );
+        assert_eq!(paged.searches.load(Ordering::SeqCst), 2);
+        // The issues were fetched in one request
+        assert_eq!(paged.bulk_fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(paged.issues.load(Ordering::SeqCst), 0);
+        assert_eq!(issue_cache.stats().entries, 3);
+
+        // Warmed pages are served without searching, even with a zero TTL,
+        // and page 2 is reached without walking
+        for page in 1..=2 {
+            let served = fetch_issues_for_html(
+                &index_cache,
+                &token_cache,
+                scope(),
+                page_query(page),
+                DisplayTimezone::default(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(served.page, Some(page));
+        }
+        assert_eq!(paged.searches.load(Ordering::SeqCst), 2);
+
+        // The most recently updated issues were warmed
+        for n in [6, 5, 4] {
+            let key = IssueKey::new_unchecked(format!("OS-{}", n));
+            issue_cache.get(&index_cache.jira, &key).await.unwrap();
+        }
+        assert_eq!(paged.issues.load(Ordering::SeqCst), 0);
+
+        // Issues still fresh in the cache are not fetched again
+        let summary = warm(
+            &index_cache,
+            &token_cache,
+            &issue_cache,
+            &[scope()],
+            &config,
+        )
+        .await;
+        assert_eq!(summary.issues, 3);
+        assert_eq!(paged.bulk_fetches.load(Ordering::SeqCst), 1);
+        let searches = paged.searches.load(Ordering::SeqCst);
+
+        // Page 3 was not warmed
+        fetch_issues_for_html(
+            &index_cache,
+            &token_cache,
+            scope(),
+            page_query(3),
+            DisplayTimezone::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(paged.searches.load(Ordering::SeqCst), searches + 1);
+    }
+}
@@ -0,0 +1,229 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Issue assembly shared by the issue endpoints
+//!
+//! `/bugview/issue/{key}` (as HTML, or as JSON when negotiated),
+//! `/bugview/json/{key}` and `/bugview/fulljson/{key}` all look an issue up
+//! the same way: validate the key, fetch the issue through the cache, check
+//! that it is public and count the view. The full JSON response is also built
+//! here, so the issue page and `fulljson` cannot drift apart.
+
+use crate::ApiContext;
+use crate::issue_cache::{self, CachedIssue};
+use crate::search::{
+    convert_to_details_v2, filter_remote_links, issue_has_public_label, issue_validators,
+    public_history, strip_restricted_comments,
+};
+use bugview_api::{
+    HttpResponseConditional, IssueDetails, IssueFullQuery, IssueFullResponse, RemoteLink,
+};
+use dropshot::HttpError;
+use http::HeaderMap;
+
+/// Message for an issue that could not be fetched from JIRA
+const UNAVAILABLE_MESSAGE: &str = "Failed to retrieve issue. Please try again later.";
+
+/// Why an issue could not be served
+pub(crate) enum IssueLookupError {
+    /// The key is not a valid issue key
+    InvalidKey(String),
+    /// The issue does not exist or is not public. Both are reported as not
+    /// found, with related public issues suggested in its place.
+    NotFound {
+        key: jira_api::IssueKey,
+        message: String,
+    },
+    /// JIRA could not be reached; the error has been logged
+    Unavailable,
+}
+
+impl IssueLookupError {
+    /// HTTP status code for the error
+    pub(crate) fn status(&self) -> u16 {
+        match self {
+            IssueLookupError::InvalidKey(_) => 400,
+            IssueLookupError::NotFound { .. } => 404,
+            IssueLookupError::Unavailable => 500,
+        }
+    }
+
+    /// Message that is safe to show the client
+    pub(crate) fn message(&self) -> &str {
+        match self {
+            IssueLookupError::InvalidKey(message) => message,
+            IssueLookupError::NotFound { message, .. } => message,
+            IssueLookupError::Unavailable => UNAVAILABLE_MESSAGE,
+        }
+    }
+
+    /// The error for a JSON endpoint
+    pub(crate) async fn into_http_error(self, ctx: &ApiContext) -> HttpError {
+        match self {
+            IssueLookupError::InvalidKey(message) => HttpError::for_bad_request(None, message),
+            IssueLookupError::NotFound { key, message } => ctx.issue_not_found(&key, message).await,
+            IssueLookupError::Unavailable => {
+                HttpError::for_internal_error(UNAVAILABLE_MESSAGE.to_string())
+            }
+        }
+    }
+}
+
+/// Look up a public issue by key, counting the view
+pub(crate) async fn lookup_public_issue(
+    ctx: &ApiContext,
+    key: &str,
+) -> Result<CachedIssue, IssueLookupError> {
+    let key =
+        jira_api::IssueKey::new(key).map_err(|e| IssueLookupError::InvalidKey(format!("{}", e)))?;
+
+    let cached = match ctx.issue_cache.get(&ctx.jira, &key).await {
+        Ok(cached) => cached,
+        Err(e) if issue_cache::is_not_found(&e) => {
+            // Safe to expose - user is asking for an issue that doesn't exist
+            let message = format!("Issue {} not found", key);
+            return Err(IssueLookupError::NotFound { key, message });
+        }
+        Err(e) => {
+            // Log full error but return generic message to avoid exposing internals
+            tracing::error!(issue_key = %key, error = %e, "Failed to get issue from JIRA");
+            return Err(IssueLookupError::Unavailable);
+        }
+    };
+
+    // Check if issue has the required label
+    if !issue_has_public_label(&cached.issue, ctx.config().default_label_for(&key)) {
+        let message = format!("Issue {} is not public", key);
+        return Err(IssueLookupError::NotFound { key, message });
+    }
+    ctx.record_view(&cached.issue);
+
+    Ok(cached)
+}
+
+/// The issue's remote links to allowed domains, and whether fetching them
+/// failed (in which case there are none)
+pub(crate) async fn public_remote_links(
+    ctx: &ApiContext,
+    issue: &jira_api::Issue,
+) -> (Vec<jira_api::RemoteLink>, bool) {
+    match ctx.jira.get_remote_links(&issue.id).await {
+        Ok(links) => (filter_remote_links(&links, &ctx.config()), false),
+        Err(e) => {
+            let err_str = e.to_string();
+            // Escalate to error level for auth failures and rate limiting
+            // as these indicate operational issues that need attention
+            if err_str.contains("401")
+                || err_str.contains("403")
+                || err_str.to_lowercase().contains("unauthorized")
+                || err_str.to_lowercase().contains("forbidden")
+            {
+                tracing::error!(
+                    issue_id = %issue.id,
+                    issue_key = %issue.key,
+                    error = %e,
+                    "Authentication/authorization failure fetching remote links - check JIRA credentials"
+                );
+            } else if err_str.contains("429")
+                || err_str.to_lowercase().contains("too many requests")
+            {
+                tracing::error!(
+                    issue_id = %issue.id,
+                    issue_key = %issue.key,
+                    error = %e,
+                    "Rate limited by JIRA when fetching remote links"
+                );
+            } else {
+                tracing::warn!(
+                    issue_id = %issue.id,
+                    issue_key = %issue.key,
+                    error = %e,
+                    "Failed to fetch remote links"
+                );
+            }
+            (Vec::new(), true)
+        }
+    }
+}
+
+/// The response format version requested for full issue details
+pub(crate) fn full_response_version(query: &IssueFullQuery) -> Result<u32, HttpError> {
+    let version = query.v.unwrap_or(1);
+    if !(1..=2).contains(&version) {
+        return Err(HttpError::for_bad_request(
+            None,
+            format!("Unsupported response format version {}", version),
+        ));
+    }
+    Ok(version)
+}
+
+/// Full issue details in format `version`, checked against the request's
+/// conditional headers
+pub(crate) async fn full_issue_response(
+    ctx: &ApiContext,
+    request_headers: &HeaderMap,
+    issue: jira_api::Issue,
+    version: u32,
+) -> Result<HttpResponseConditional<IssueFullResponse>, HttpError> {
+    let (filtered_links, _) = public_remote_links(ctx, &issue).await;
+
+    // Convert to API response format
+    let remotelinks: Vec<RemoteLink> = filtered_links
+        .iter()
+        .filter_map(|link| {
+            link.object.as_ref().map(|obj| RemoteLink {
+                url: obj.url.clone(),
+                title: obj.title.clone(),
+            })
+        })
+        .collect();
+
+    // History changes bump `updated`, and its display timestamps are left
+    // out of the validators like the index's are
+    let config = ctx.config();
+    let history = public_history(&issue, &config, chrono::Utc::now());
+    let validators = issue_validators(&issue, &remotelinks)?;
+
+    if version == 2 {
+        return Ok(HttpResponseConditional::new(
+            request_headers,
+            IssueFullResponse::IssueDetailsV2(convert_to_details_v2(
+                &issue,
+                &config,
+                remotelinks,
+                history,
+            )),
+            validators,
+        ));
+    }
+
+    // Strip restricted comments before serializing to prevent leaking
+    // comments with visibility restrictions (role/group-restricted)
+    let mut fields_map = issue.fields;
+    strip_restricted_comments(&mut fields_map);
+
+    let fields = serde_json::to_value(fields_map).map_err(|e| {
+        tracing::error!(
+            issue_key = %issue.key,
+            error = %e,
+            "Failed to serialize issue fields to JSON"
+        );
+        HttpError::for_internal_error(format!("Failed to serialize issue fields: {}", e))
+    })?;
+
+    Ok(HttpResponseConditional::new(
+        request_headers,
+        IssueFullResponse::IssueDetails(IssueDetails {
+            id: issue.id,
+            key: issue.key,
+            fields,
+            remotelinks,
+            history,
+        }),
+        validators,
+    ))
+}
@@ -0,0 +1,493 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Stale-while-revalidate cache of issues fetched from JIRA.
+//!
+//! Without a cache every issue request goes to JIRA, so a JIRA outage takes
+//! every issue page down with it. Issues are cached by key:
+//!
+//! - A copy younger than the TTL is served without contacting JIRA.
+//! - An older copy is still served straight away, and the issue is refreshed
+//!   in the background. Only one refresh per issue runs at a time.
+//! - A copy older than the maximum staleness is dropped, and the issue is
+//!   fetched before responding, as it is when it is not cached at all.
+//!
+//! While JIRA is unreachable, refreshes fail and the last good copy keeps
+//! being served (flagged as stale so the page can say so) until it reaches
+//! the maximum staleness. An issue that JIRA reports as not found is dropped
+//! from the cache. Public-label checks are applied to cached copies like
+//! fresh ones, so an issue made private stops being served once a refresh
+//! succeeds.
+
+use crate::jira_client::{Issue, IssueKey, JiraClientTrait};
+use anyhow::Result;
+use bugview_api::IssueCacheStats;
+use indexmap::IndexMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Default time a cached issue is served without being refreshed (seconds).
+pub(crate) const DEFAULT_TTL_SECS: u64 = 60;
+
+/// Default longest time a cached issue is served while JIRA cannot be
+/// reached (seconds).
+pub(crate) const DEFAULT_MAX_STALE_SECS: u64 = 24 * 60 * 60;
+
+/// Maximum number of cached issues to retain.
+const ISSUE_CACHE_MAX_ENTRIES: usize = 1000;
+
+/// Whether a JIRA error means the issue does not exist.
+pub(crate) fn is_not_found(error: &anyhow::Error) -> bool {
+    let msg = error.to_string();
+    msg.contains("Issue not found") || msg.contains("404")
+}
+
+struct CacheEntry {
+    issue: Issue,
+    fetched_at: Instant,
+    /// A background refresh is in flight
+    refreshing: bool,
+    /// The most recent refresh failed
+    refresh_failed: bool,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Entries in the order they were fetched, oldest first
+    entries: IndexMap<String, CacheEntry>,
+    stats: IssueCacheStats,
+}
+
+/// An issue as served from the cache
+pub(crate) struct CachedIssue {
+    pub(crate) issue: Issue,
+    /// JIRA could not be reached to refresh this copy, so it may be out of
+    /// date
+    pub(crate) stale: bool,
+}
+
+/// Thread-safe stale-while-revalidate issue cache.
+pub(crate) struct IssueCache {
+    state: Mutex<CacheState>,
+    ttl: Duration,
+    max_stale: Duration,
+    max_entries: usize,
+}
+
+impl Default for IssueCache {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_secs(DEFAULT_TTL_SECS),
+            Duration::from_secs(DEFAULT_MAX_STALE_SECS),
+        )
+    }
+}
+
+enum Lookup {
+    Fresh(Issue),
+    Stale {
+        issue: Issue,
+        refresh: bool,
+        refresh_failed: bool,
+    },
+    Miss,
+}
+
+impl IssueCache {
+    /// Create an issue cache serving copies without refreshing them for
+    /// `ttl`, and while JIRA is unreachable for up to `max_stale`.
+    pub(crate) fn new(ttl: Duration, max_stale: Duration) -> Self {
+        Self {
+            state: Mutex::new(CacheState::default()),
+            ttl,
+            max_stale: max_stale.max(ttl),
+            max_entries: ISSUE_CACHE_MAX_ENTRIES,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|poisoned| {
+            tracing::error!("Issue cache mutex was poisoned, recovering");
+            poisoned.into_inner()
+        })
+    }
+
+    /// Get an issue, from the cache if possible.
+    ///
+    /// Returns the error from JIRA only when there is no usable cached copy.
+    pub(crate) async fn get(
+        self: &Arc<Self>,
+        jira: &Arc<dyn JiraClientTrait>,
+        key: &IssueKey,
+    ) -> Result<CachedIssue> {
+        match self.lookup(key) {
+            Lookup::Fresh(issue) => Ok(CachedIssue {
+                issue,
+                stale: false,
+            }),
+            Lookup::Stale {
+                issue,
+                refresh,
+                refresh_failed,
+            } => {
+                if refresh {
+                    self.spawn_refresh(Arc::clone(jira), key.clone());
+                }
+                Ok(CachedIssue {
+                    issue,
+                    stale: refresh_failed,
+                })
+            }
+            Lookup::Miss => {
+                let issue = self.store(key, jira.get_issue(key).await?);
+                Ok(CachedIssue {
+                    issue,
+                    stale: false,
+                })
+            }
+        }
+    }
+
+    /// Fetch the issues among `keys` without a fresh cached copy, together
+    /// in as few JIRA requests as possible, and cache them.
+    ///
+    /// Returns how many of `keys` are fresh in the cache afterwards. Unlike
+    /// [`IssueCache::get`], this does not count towards the cache statistics.
+    pub(crate) async fn prefetch(
+        &self,
+        jira: &Arc<dyn JiraClientTrait>,
+        keys: &[IssueKey],
+    ) -> Result<usize> {
+        let missing: Vec<IssueKey> = {
+            let state = self.lock();
+            let now = Instant::now();
+            keys.iter()
+                .filter(|key| {
+                    !state
+                        .entries
+                        .get(key.as_str())
+                        .is_some_and(|entry| now.duration_since(entry.fetched_at) <= self.ttl)
+                })
+                .cloned()
+                .collect()
+        };
+        if missing.is_empty() {
+            return Ok(keys.len());
+        }
+
+        let issues = jira.get_issues(&missing).await?;
+        let fetched = issues.len();
+        for issue in issues {
+            let key = issue.key.clone();
+            self.store(&key, issue);
+        }
+        Ok(keys.len() - missing.len() + fetched)
+    }
+
+    fn lookup(&self, key: &IssueKey) -> Lookup {
+        let mut state = self.lock();
+        let now = Instant::now();
+
+        let lookup = match state.entries.get_mut(key.as_str()) {
+            Some(entry) if now.duration_since(entry.fetched_at) <= self.ttl => {
+                Lookup::Fresh(entry.issue.clone())
+            }
+            Some(entry) if now.duration_since(entry.fetched_at) <= self.max_stale => {
+                let refresh = !entry.refreshing;
+                entry.refreshing = true;
+                Lookup::Stale {
+                    issue: entry.issue.clone(),
+                    refresh,
+                    refresh_failed: entry.refresh_failed,
+                }
+            }
+            Some(_) => {
+                state.entries.shift_remove(key.as_str());
+                Lookup::Miss
+            }
+            None => Lookup::Miss,
+        };
+
+        match lookup {
+            Lookup::Fresh(_) => state.stats.hits += 1,
+            Lookup::Stale { .. } => state.stats.stale += 1,
+            Lookup::Miss => state.stats.misses += 1,
+        }
+        lookup
+    }
+
+    /// Cache a freshly fetched issue, evicting the oldest entries if the
+    /// cache is full.
+    ///
+    /// User details are scrubbed before the issue is cached, so neither the
+    /// JSON nor the HTML output can expose them. Returns the scrubbed issue.
+    fn store(&self, key: &IssueKey, mut issue: Issue) -> Issue {
+        jira_api::sanitize::scrub_issue(&mut issue);
+        let mut state = self.lock();
+
+        // Re-insert so the entries stay ordered by fetch time
+        state.entries.shift_remove(key.as_str());
+        while state.entries.len() >= self.max_entries {
+            state.entries.shift_remove_index(0);
+        }
+
+        state.entries.insert(
+            key.as_str().to_string(),
+            CacheEntry {
+                issue: issue.clone(),
+                fetched_at: Instant::now(),
+                refreshing: false,
+                refresh_failed: false,
+            },
+        );
+        issue
+    }
+
+    fn spawn_refresh(self: &Arc<Self>, jira: Arc<dyn JiraClientTrait>, key: IssueKey) {
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            let result = jira.get_issue(&key).await;
+            cache.finish_refresh(&key, result);
+        });
+    }
+
+    fn finish_refresh(&self, key: &IssueKey, result: Result<Issue>) {
+        match result {
+            Ok(issue) => {
+                self.store(key, issue);
+            }
+            Err(e) if is_not_found(&e) => {
+                tracing::info!(issue_key = %key, "Dropping cached issue that no longer exists");
+                self.lock().entries.shift_remove(key.as_str());
+            }
+            Err(e) => {
+                tracing::warn!(
+                    issue_key = %key,
+                    error = %e,
+                    "Failed to refresh cached issue, serving stale copy"
+                );
+                let mut state = self.lock();
+                state.stats.refresh_failures += 1;
+                if let Some(entry) = state.entries.get_mut(key.as_str()) {
+                    entry.refreshing = false;
+                    entry.refresh_failed = true;
+                }
+            }
+        }
+    }
+
+    /// Cache counters since the service started.
+    pub(crate) fn stats(&self) -> IssueCacheStats {
+        let state = self.lock();
+        IssueCacheStats {
+            entries: state.entries.len() as u64,
+            ..state.stats.clone()
+        }
+    }
+
+    /// Drop every cached issue, returning how many there were. The counters
+    /// are kept.
+    ///
+    /// A refresh already in flight still caches the issue it fetches, which
+    /// is then as fresh as a miss would have made it.
+    pub(crate) fn purge(&self) -> u64 {
+        let mut state = self.lock();
+        let purged = state.entries.len() as u64;
+        state.entries.clear();
+        purged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira_client::{RemoteLink, SearchFilter, SearchResponse};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// JIRA client whose `get_issue` can be switched between succeeding,
+    /// failing and reporting the issue as missing.
+    #[derive(Default)]
+    struct FlakyJira {
+        calls: AtomicUsize,
+        down: AtomicBool,
+        missing: AtomicBool,
+    }
+
+    #[async_trait]
+    impl JiraClientTrait for FlakyJira {
+        async fn search_issues(
+            &self,
+            _labels: &[String],
+            _filter: &SearchFilter,
+            _page_token: Option<&str>,
+            _sort: &str,
+        ) -> Result<SearchResponse> {
+            anyhow::bail!("not used")
+        }
+
+        async fn get_issue(&self, key: &IssueKey) -> Result<Issue> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if self.missing.load(Ordering::SeqCst) {
+                anyhow::bail!("Issue not found: {}", key);
+            }
+            if self.down.load(Ordering::SeqCst) {
+                anyhow::bail!("JIRA API error: 503 Service Unavailable");
+            }
+            Ok(Issue {
+                key: key.clone(),
+                id: call.to_string(),
+                fields: [(
+                    "assignee".to_string(),
+                    serde_json::json!({
+                        "accountId": "5b10a2844c20165700ede21g",
+                        "emailAddress": "jane@example.com",
+                        "displayName": "Jane"
+                    }),
+                )]
+                .into(),
+                rendered_fields: None,
+                changelog: None,
+            })
+        }
+
+        async fn get_remote_links(&self, _issue_id: &str) -> Result<Vec<RemoteLink>> {
+            Ok(vec![])
+        }
+    }
+
+    fn setup(ttl: Duration) -> (Arc<IssueCache>, Arc<FlakyJira>, Arc<dyn JiraClientTrait>) {
+        let cache = Arc::new(IssueCache::new(ttl, Duration::from_secs(3600)));
+        let flaky = Arc::new(FlakyJira::default());
+        let jira: Arc<dyn JiraClientTrait> = Arc::clone(&flaky) as Arc<dyn JiraClientTrait>;
+        (cache, flaky, jira)
+    }
+
+    /// Wait for any background refresh of `key` to finish.
+    async fn settle(cache: &IssueCache, key: &IssueKey) {
+        for _ in 0..100 {
+            let refreshing = cache
+                .lock()
+                .entries
+                .get(key.as_str())
+                .is_some_and(|e| e.refreshing);
+            if !refreshing {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("refresh of {} did not finish", key);
+    }
+
+    #[tokio::test]
+    async fn test_fresh_hit() {
+        let (cache, flaky, jira) = setup(Duration::from_secs(60));
+        let key = IssueKey::new_unchecked("OS-1");
+
+        let first = cache.get(&jira, &key).await.unwrap();
+        let second = cache.get(&jira, &key).await.unwrap();
+        assert!(!second.stale);
+        assert_eq!(first.issue.id, second.issue.id);
+        assert_eq!(flaky.calls.load(Ordering::SeqCst), 1);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.stale), (1, 1, 0));
+        assert_eq!(stats.entries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate() {
+        let (cache, flaky, jira) = setup(Duration::ZERO);
+        let key = IssueKey::new_unchecked("OS-1");
+
+        assert_eq!(cache.get(&jira, &key).await.unwrap().issue.id, "1");
+
+        // The old copy is served while the refresh runs
+        let served = cache.get(&jira, &key).await.unwrap();
+        assert_eq!(served.issue.id, "1");
+        assert!(!served.stale);
+        settle(&cache, &key).await;
+        assert_eq!(flaky.calls.load(Ordering::SeqCst), 2);
+
+        // ...and the refreshed copy afterwards
+        assert_eq!(cache.get(&jira, &key).await.unwrap().issue.id, "2");
+        settle(&cache, &key).await;
+        assert_eq!(cache.stats().stale, 2);
+    }
+
+    #[tokio::test]
+    async fn test_jira_outage_serves_stale_copy() {
+        let (cache, flaky, jira) = setup(Duration::ZERO);
+        let key = IssueKey::new_unchecked("OS-1");
+        cache.get(&jira, &key).await.unwrap();
+
+        flaky.down.store(true, Ordering::SeqCst);
+        cache.get(&jira, &key).await.unwrap();
+        settle(&cache, &key).await;
+
+        // Once a refresh has failed the copy is flagged as stale
+        let served = cache.get(&jira, &key).await.unwrap();
+        assert_eq!(served.issue.id, "1");
+        assert!(served.stale);
+        settle(&cache, &key).await;
+        assert_eq!(cache.stats().refresh_failures, 2);
+
+        // Uncached issues still fail
+        let other = IssueKey::new_unchecked("OS-2");
+        assert!(cache.get(&jira, &other).await.is_err());
+
+        // Recovery clears the flag
+        flaky.down.store(false, Ordering::SeqCst);
+        cache.get(&jira, &key).await.unwrap();
+        settle(&cache, &key).await;
+        assert!(!cache.get(&jira, &key).await.unwrap().stale);
+    }
+
+    #[tokio::test]
+    async fn test_missing_issue_dropped() {
+        let (cache, flaky, jira) = setup(Duration::ZERO);
+        let key = IssueKey::new_unchecked("OS-1");
+        cache.get(&jira, &key).await.unwrap();
+
+        flaky.missing.store(true, Ordering::SeqCst);
+        cache.get(&jira, &key).await.unwrap();
+        settle(&cache, &key).await;
+
+        assert_eq!(cache.stats().entries, 0);
+        let err = cache.get(&jira, &key).await.err().expect("not found");
+        assert!(is_not_found(&err));
+    }
+
+    #[tokio::test]
+    async fn test_max_stale() {
+        let cache = Arc::new(IssueCache::new(Duration::ZERO, Duration::ZERO));
+        let flaky = Arc::new(FlakyJira::default());
+        let jira: Arc<dyn JiraClientTrait> = Arc::clone(&flaky) as Arc<dyn JiraClientTrait>;
+        let key = IssueKey::new_unchecked("OS-1");
+        cache.get(&jira, &key).await.unwrap();
+
+        // Too old to serve, so JIRA's error is returned
+        flaky.down.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert!(cache.get(&jira, &key).await.is_err());
+        assert_eq!(cache.stats().misses, 2);
+    }
+
+    #[tokio::test]
+    async fn test_cached_issue_scrubbed() {
+        let (cache, _flaky, jira) = setup(Duration::from_secs(60));
+        let key = IssueKey::new_unchecked("OS-1");
+
+        let fetched = cache.get(&jira, &key).await.unwrap();
+        let cached = cache.get(&jira, &key).await.unwrap();
+        for issue in [fetched.issue, cached.issue] {
+            assert_eq!(
+                issue.fields["assignee"],
+                serde_json::json!({"displayName": "Jane"})
+            );
+        }
+    }
+}
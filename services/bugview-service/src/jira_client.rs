@@ -11,6 +11,12 @@
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use bugview_api::IssueStatusFilter;
+use chrono::NaiveDate;
+use jira_api::{BULK_FETCH_MAX_ISSUES, BulkFetchRequest};
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 // Retry/backoff and HTTP configuration
@@ -24,6 +30,7 @@ const JIRA_SEARCH_MAX_RESULTS: u32 = 50;
 
 // Re-export types from jira-api for consistency
 pub use jira_api::{Issue, IssueKey, RemoteLink};
+pub use jira_client::JiraAuth;
 
 // Custom SearchResponse that uses jira_api::Issue instead of generated Issue
 #[derive(Debug, Clone)]
@@ -43,55 +50,124 @@ impl From<jira_client::types::SearchResponse> for SearchResponse {
     }
 }
 
+/// Restrictions on an issue search beyond the required labels
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilter {
+    /// Only open (unresolved) or resolved issues
+    pub status: Option<IssueStatusFilter>,
+    /// Only issues updated on or after this date
+    pub updated_since: Option<NaiveDate>,
+    /// Only issues from this project key (already validated)
+    pub project: Option<String>,
+    /// Only issues whose key number is in this range; ignored unless
+    /// `project` is set
+    pub key_numbers: Option<RangeInclusive<u64>>,
+    /// Leave out issues from these project keys (already validated)
+    pub exclude_projects: Vec<String>,
+}
+
+impl SearchFilter {
+    /// JQL clauses for the filter, to be joined with AND
+    pub fn jql_clauses(&self) -> Vec<String> {
+        let mut clauses = Vec::new();
+        match self.status {
+            Some(IssueStatusFilter::Open) => clauses.push("resolution is EMPTY".to_string()),
+            Some(IssueStatusFilter::Resolved) => {
+                clauses.push("resolution is not EMPTY".to_string())
+            }
+            None => {}
+        }
+        if let Some(date) = self.updated_since {
+            clauses.push(format!("updated >= \"{}\"", date.format("%Y-%m-%d")));
+        }
+        if let Some(ref project) = self.project {
+            clauses.push(format!("project = \"{}\"", project));
+            if let Some(ref numbers) = self.key_numbers {
+                clauses.push(format!("issuekey >= \"{}-{}\"", project, numbers.start()));
+                clauses.push(format!("issuekey <= \"{}-{}\"", project, numbers.end()));
+            }
+        }
+        if !self.exclude_projects.is_empty() {
+            let projects: Vec<String> = self
+                .exclude_projects
+                .iter()
+                .map(|p| format!("\"{}\"", p))
+                .collect();
+            clauses.push(format!("project not in ({})", projects.join(", ")));
+        }
+        clauses
+    }
+}
+
 /// Trait abstraction for the JIRA client used by the service.
 #[async_trait]
 pub trait JiraClientTrait: Send + Sync {
     async fn search_issues(
         &self,
         labels: &[String],
+        filter: &SearchFilter,
         page_token: Option<&str>,
         sort: &str,
     ) -> Result<SearchResponse>;
 
     async fn get_issue(&self, key: &IssueKey) -> Result<Issue>;
 
+    /// Get several issues, in no particular order. Issues that do not exist
+    /// are left out.
+    ///
+    /// By default they are fetched one at a time.
+    async fn get_issues(&self, keys: &[IssueKey]) -> Result<Vec<Issue>> {
+        get_issues_one_by_one(self, keys).await
+    }
+
     async fn get_remote_links(&self, issue_id: &str) -> Result<Vec<RemoteLink>>;
 }
 
+/// Get each of `keys` with its own request, leaving out those not found.
+async fn get_issues_one_by_one<J: JiraClientTrait + ?Sized>(
+    jira: &J,
+    keys: &[IssueKey],
+) -> Result<Vec<Issue>> {
+    let mut issues = Vec::with_capacity(keys.len());
+    for key in keys {
+        match jira.get_issue(key).await {
+            Ok(issue) => issues.push(issue),
+            Err(e) if crate::issue_cache::is_not_found(&e) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(issues)
+}
+
+/// Whether a bulk fetch error means JIRA has no bulk fetch endpoint (older
+/// JIRA releases, or the stub server).
+fn bulk_fetch_unsupported(error: &anyhow::Error) -> bool {
+    let msg = error.to_string();
+    msg.contains("404") || msg.contains("405")
+}
+
 /// Concrete JIRA client wrapper backed by the Progenitor-generated client.
 #[derive(Clone)]
 pub struct JiraClient {
     client: jira_client::Client,
+    /// Cleared once JIRA turns out not to support bulk fetches, after which
+    /// issues are fetched one at a time
+    bulk_fetch: Arc<AtomicBool>,
 }
 
 impl JiraClient {
-    /// Create a new JIRA client
-    pub fn new(base_url: String, username: String, password: String) -> Result<Self> {
-        use base64::Engine;
-        use base64::engine::general_purpose::STANDARD;
-        use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
-
-        // Create Basic Auth header
-        let mut headers = HeaderMap::new();
-        let credentials = format!("{}:{}", username, password);
-        let encoded = STANDARD.encode(credentials.as_bytes());
-        let auth_value = format!("Basic {}", encoded);
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&auth_value).context("Failed to create auth header")?,
-        );
-
-        // Create authenticated HTTP client
-        let http_client = reqwest::Client::builder()
+    /// Create a new JIRA client authenticating with `auth`
+    pub fn new(base_url: String, auth: JiraAuth) -> Result<Self> {
+        let builder = reqwest::Client::builder()
             .timeout(std::time::Duration::from_secs(HTTP_TIMEOUT_SECS))
-            .user_agent(USER_AGENT)
-            .default_headers(headers)
-            .build()
-            .context("Failed to create HTTP client")?;
-
-        let client = jira_client::Client::new_with_client(&base_url, http_client);
-
-        Ok(Self { client })
+            .user_agent(USER_AGENT);
+        let client = jira_client::Client::new_with_auth_and_builder(&base_url, auth, builder)
+            .context("Failed to create JIRA client")?;
+
+        Ok(Self {
+            client,
+            bulk_fetch: Arc::new(AtomicBool::new(true)),
+        })
     }
 }
 
@@ -142,17 +218,19 @@ impl JiraClientTrait for JiraClient {
     async fn search_issues(
         &self,
         labels: &[String],
+        filter: &SearchFilter,
         page_token: Option<&str>,
         sort: &str,
     ) -> Result<SearchResponse> {
         let max_results = JIRA_SEARCH_MAX_RESULTS;
 
         // Build JQL query
-        let label_clauses: Vec<String> = labels
+        let mut clauses: Vec<String> = labels
             .iter()
             .map(|label| format!("labels in (\"{}\")", label))
             .collect();
-        let mut jql = label_clauses.join(" AND ");
+        clauses.extend(filter.jql_clauses());
+        let mut jql = clauses.join(" AND ");
 
         // Add sort clause
         if sort == "created" || sort == "updated" {
@@ -170,7 +248,10 @@ impl JiraClientTrait for JiraClient {
             || async {
                 let mut request = self.client.search_issues().jql(jql_owned.clone());
                 request = request.max_results(max_results);
-                request = request.fields("summary,status,resolution,updated,created".to_string());
+                request = request.fields(
+                    "summary,status,resolution,updated,created,labels,issuetype,priority"
+                        .to_string(),
+                );
                 if let Some(ref token) = token_owned {
                     request = request.next_page_token(token.clone());
                 }
@@ -198,6 +279,7 @@ impl JiraClientTrait for JiraClient {
                     .client
                     .get_issue()
                     .issue_id_or_key(&k)
+                    .expand("changelog")
                     .send()
                     .await
                     .map_err(|e| {
@@ -223,6 +305,62 @@ impl JiraClientTrait for JiraClient {
         .await
     }
 
+    async fn get_issues(&self, keys: &[IssueKey]) -> Result<Vec<Issue>> {
+        let mut issues = Vec::with_capacity(keys.len());
+
+        for (n, chunk) in keys.chunks(BULK_FETCH_MAX_ISSUES).enumerate() {
+            if !self.bulk_fetch.load(Ordering::Relaxed) {
+                let rest = &keys[n * BULK_FETCH_MAX_ISSUES..];
+                issues.extend(get_issues_one_by_one(self, rest).await?);
+                break;
+            }
+
+            // The same fields and changelog as get_issue, so that issues
+            // fetched either way can be cached alike
+            let request = BulkFetchRequest {
+                issue_ids_or_keys: chunk.iter().map(|key| key.to_string()).collect(),
+                fields: Some(vec!["*all".to_string()]),
+                expand: Some(vec!["changelog".to_string()]),
+            };
+            let result = with_retries(
+                || async {
+                    self.client
+                        .bulk_fetch_issues(&request)
+                        .await
+                        .map_err(|e| anyhow::anyhow!("Failed to bulk fetch issues: {}", e))
+                },
+                "jira.bulk_fetch_issues",
+            )
+            .await;
+
+            match result {
+                Ok(response) => {
+                    for error in &response.issue_errors {
+                        tracing::debug!(
+                            issue = %error.id,
+                            error = %error.error_message,
+                            "Issue left out of bulk fetch"
+                        );
+                    }
+                    issues.extend(response.issues);
+                }
+                Err(e) if bulk_fetch_unsupported(&e) => {
+                    tracing::warn!(
+                        error = %e,
+                        "JIRA does not support bulk fetches, fetching issues one at a time"
+                    );
+                    self.bulk_fetch.store(false, Ordering::Relaxed);
+                    let rest = &keys[n * BULK_FETCH_MAX_ISSUES..];
+                    issues.extend(get_issues_one_by_one(self, rest).await?);
+                    break;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(issues)
+    }
+
     async fn get_remote_links(&self, issue_id: &str) -> Result<Vec<RemoteLink>> {
         if issue_id.contains('-') {
             anyhow::bail!("Issue ID must be numeric, not a key: {}", issue_id);
@@ -4,28 +4,45 @@
 //
 // Copyright 2026 Edgecast Cloud LLC.
 
+mod assets;
+mod config_file;
 mod html;
+mod index_cache;
+mod issue;
+mod issue_cache;
 mod jira_client;
 mod search;
+mod stats;
+mod suggest;
+mod timefmt;
 mod token_cache;
 
 use anyhow::{Context, Result};
+use arc_swap::ArcSwap;
 use bugview_api::{
-    BugviewApi, IssueDetails, IssueListQuery, IssueListResponse, IssuePath, IssueSummary,
-    LabelPath, RemoteLink,
+    BugviewApi, CachePurgeResponse, ConfigReloadResponse, HttpResponseConditional, IssueFullQuery,
+    IssueFullResponse, IssueIndexQuery, IssueListQuery, IssueListResponse, IssuePath, IssueSummary,
+    LabelPath, ProjectLabelPath, ProjectPath, ResponseFormat, StaticAssetPath, StatsQuery,
+    StatsResponse, Theme, VARY_NEGOTIATED,
 };
 use dropshot::{
-    Body, ConfigDropshot, ConfigLogging, ConfigLoggingLevel, HttpError, HttpResponseOk,
-    HttpServerStarter, Path, Query, RequestContext,
+    Body, ClientErrorStatusCode, ConfigDropshot, ConfigLogging, ConfigLoggingLevel, HttpError,
+    HttpResponseOk, HttpServerStarter, Path, Query, RequestContext,
 };
-use html::HtmlRenderer;
+use html::{HtmlRenderer, IndexNav};
 use http::Response;
-use jira_client::{JiraClient, JiraClientTrait};
+use index_cache::{IndexCache, WarmConfig};
+use issue_cache::IssueCache;
+use jira_client::{JiraAuth, JiraClient, JiraClientTrait};
 use search::{
-    fetch_issues_for_html, filter_remote_links, issue_has_public_label, search_issues,
-    strip_restricted_comments,
+    IssueScope, fetch_issues_for_html, index_validators, is_project_key, issue_validators,
+    public_history, search_issues,
 };
+use stats::AccessStats;
+use std::path::PathBuf;
 use std::sync::Arc;
+use suggest::RelatedIssue;
+use timefmt::DisplayTimezone;
 use token_cache::TokenCache;
 use tracing::info;
 
@@ -39,17 +56,41 @@ const DEFAULT_BIND_ADDRESS: &str = "127.0.0.1:8080";
 /// Default public base URL for constructing web_url in legacy JSON responses.
 const DEFAULT_PUBLIC_BASE_URL: &str = "https://smartos.org";
 
+/// A JIRA project whose issues are made public by labels of its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProjectConfig {
+    /// Project key (e.g. "OS")
+    pub(crate) key: String,
+    /// Label for public issues in this project
+    pub(crate) default_label: String,
+    /// Additional allowed labels in this project
+    pub(crate) allowed_labels: Vec<String>,
+}
+
 /// Service configuration
-#[derive(Clone)]
+///
+/// All of it can be changed without a restart, through
+/// `POST /bugview/admin/reload`; see [`read_config`].
+#[derive(Clone, PartialEq)]
 pub(crate) struct Config {
     /// Default label for public issues
     pub(crate) default_label: String,
     /// Additional allowed labels
     pub(crate) allowed_labels: Vec<String>,
+    /// Projects with labels of their own. Issues in other projects use
+    /// `default_label` and `allowed_labels`.
+    pub(crate) projects: Vec<ProjectConfig>,
     /// Allowed domains for remote links (security: prevents exposing signed URLs)
     allowed_domains: Vec<String>,
     /// Public base URL for constructing web_url in legacy JSON responses
     pub(crate) public_base_url: String,
+    /// Bearer token required by the stats endpoint (endpoint disabled if None)
+    stats_token: Option<String>,
+    /// Bearer token required by the admin endpoints (endpoints disabled if
+    /// None)
+    admin_token: Option<String>,
+    /// Timezone that issue list timestamps are displayed in
+    pub(crate) display_timezone: DisplayTimezone,
 }
 
 impl Config {
@@ -60,24 +101,162 @@ impl Config {
     pub(crate) fn is_allowed_domain(&self, domain: &str) -> bool {
         self.allowed_domains.iter().any(|d| d == domain)
     }
+
+    /// Whether `label` may be shown publicly
+    pub(crate) fn is_public_label(&self, label: &str) -> bool {
+        label == self.default_label
+            || self.is_allowed_label(label)
+            || self
+                .projects
+                .iter()
+                .any(|p| label == p.default_label || p.allowed_labels.iter().any(|l| l == label))
+    }
+
+    /// The configuration of project `key`, if it has labels of its own
+    pub(crate) fn project(&self, key: &str) -> Option<&ProjectConfig> {
+        self.projects.iter().find(|p| p.key == key)
+    }
+
+    /// The label that marks issue `key` as public
+    pub(crate) fn default_label_for(&self, key: &jira_api::IssueKey) -> &str {
+        key.as_str()
+            .rsplit_once('-')
+            .and_then(|(project, _)| self.project(project))
+            .map_or(&self.default_label, |p| &p.default_label)
+    }
+
+    /// The labels project `project` (or, with None, every project without
+    /// labels of its own) can be filtered by
+    pub(crate) fn allowed_labels_for(&self, project: Option<&str>) -> &[String] {
+        project
+            .and_then(|key| self.project(key))
+            .map_or(&self.allowed_labels, |p| &p.allowed_labels)
+    }
+
+    /// Keys of the projects with labels of their own
+    fn project_keys(&self) -> Vec<String> {
+        self.projects.iter().map(|p| p.key.clone()).collect()
+    }
+
+    /// The indexes preloaded by the cache warmer: the main index, and those
+    /// of the projects with labels of their own
+    fn warm_scopes(&self) -> Vec<IssueScope> {
+        std::iter::once(self.index_scope(None, None))
+            .chain(
+                self.projects
+                    .iter()
+                    .map(|p| self.index_scope(Some(&p.key), None)),
+            )
+            .collect()
+    }
+
+    /// The public issues listed by the index of `project` (already
+    /// validated), optionally narrowed to those with `label`.
+    ///
+    /// The index of all projects, with `project` None, only lists issues of
+    /// projects without labels of their own; the others have their own
+    /// indexes. Listed issues show only the public labels of the project.
+    fn index_scope(&self, project: Option<&str>, label: Option<&str>) -> IssueScope {
+        let default_label = match project.and_then(|key| self.project(key)) {
+            Some(p) => &p.default_label,
+            None => &self.default_label,
+        };
+        IssueScope {
+            labels: std::iter::once(default_label.as_str())
+                .chain(label)
+                .map(String::from)
+                .collect(),
+            project: project.map(String::from),
+            exclude_projects: match project {
+                Some(_) => Vec::new(),
+                None => self.project_keys(),
+            },
+            public_labels: std::iter::once(default_label)
+                .chain(
+                    self.allowed_labels_for(project)
+                        .iter()
+                        .filter(|l| *l != default_label),
+                )
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Labels of `issue` that are public, i.e. safe to report in statistics.
+    fn public_labels(&self, issue: &jira_api::Issue) -> Vec<String> {
+        issue
+            .fields
+            .get("labels")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|l| l.as_str())
+            .filter(|l| self.is_public_label(l))
+            .map(|l| l.to_string())
+            .collect()
+    }
 }
 
 /// Context for API handlers
 struct ApiContext {
     jira: Arc<dyn JiraClientTrait>,
-    config: Config,
+    /// Current configuration, replaced by `POST /bugview/admin/reload`
+    config: Arc<ArcSwap<Config>>,
+    /// Settings file the configuration is reloaded from, if any
+    config_file: Option<PathBuf>,
     html: HtmlRenderer,
     token_cache: TokenCache,
+    issue_cache: Arc<IssueCache>,
+    /// Cached index searches (None if searches are not cached)
+    index_cache: Option<Arc<IndexCache>>,
+    stats: Arc<AccessStats>,
+}
+
+impl ApiContext {
+    /// The current configuration.
+    ///
+    /// Handlers that consult it more than once should take it once, so that
+    /// a reload part way through a request cannot mix two configurations.
+    fn config(&self) -> Arc<Config> {
+        self.config.load_full()
+    }
+
+    /// Count a view of a public issue.
+    fn record_view(&self, issue: &jira_api::Issue) {
+        self.stats
+            .record_issue_view(issue.key.as_str(), &self.config().public_labels(issue));
+    }
+
+    /// Public issues to suggest in place of `key`, which does not exist or
+    /// is not public.
+    async fn related_issues(&self, key: &jira_api::IssueKey) -> Vec<RelatedIssue> {
+        let config = self.config();
+        suggest::related_issues(self.jira.as_ref(), config.default_label_for(key), key).await
+    }
+
+    /// A 404 error for a JSON endpoint. Dropshot errors only carry a message,
+    /// so any related public issues are listed at the end of it.
+    async fn issue_not_found(&self, key: &jira_api::IssueKey, message: String) -> HttpError {
+        let related = self.related_issues(key).await;
+        if related.is_empty() {
+            return HttpError::for_not_found(None, message);
+        }
+
+        let keys: Vec<&str> = related.iter().map(|r| r.key.as_str()).collect();
+        HttpError::for_not_found(
+            None,
+            format!("{}; related public issues: {}", message, keys.join(", ")),
+        )
+    }
 }
 
 /// Content-Security-Policy header value for HTML responses
 /// Allows:
-/// - Scripts from self, unsafe-inline (for theme detection), and cdn.jsdelivr.net (Bootstrap JS)
-/// - Styles from self, unsafe-inline (for inline styles), and cdn.jsdelivr.net (Bootstrap CSS)
+/// - Scripts and styles from self only (the embedded assets; no inline code)
 /// - Images from self and data: URIs
-/// - Fonts from self and cdn.jsdelivr.net
+/// - Fonts from self
 /// - Default to self for everything else
-const CSP_HEADER: &str = "default-src 'self'; script-src 'self' 'unsafe-inline' cdn.jsdelivr.net; style-src 'self' 'unsafe-inline' cdn.jsdelivr.net; img-src 'self' data:; font-src 'self' cdn.jsdelivr.net";
+const CSP_HEADER: &str = "default-src 'self'; script-src 'self'; style-src 'self'; img-src 'self' data:; font-src 'self'";
 
 /// Helper function to build HTML responses with security headers
 fn build_html_response(status: u16, html: String) -> Result<Response<Body>, HttpError> {
@@ -89,6 +268,133 @@ fn build_html_response(status: u16, html: String) -> Result<Response<Body>, Http
         .map_err(|e| HttpError::for_internal_error(format!("Failed to build response: {}", e)))
 }
 
+/// Mark a content-negotiated response as varying with the `Accept` header
+fn vary_on_accept(mut response: Response<Body>) -> Response<Body> {
+    response.headers_mut().insert(
+        http::header::VARY,
+        http::HeaderValue::from_static(VARY_NEGOTIATED),
+    );
+    response
+}
+
+/// Theme preference sent by the browser in the `Cookie` header
+fn request_theme(rqctx: &RequestContext<ApiContext>) -> Theme {
+    rqctx
+        .request
+        .headers()
+        .get(http::header::COOKIE)
+        .and_then(|v| v.to_str().ok())
+        .map(Theme::from_cookie_header)
+        .unwrap_or_default()
+}
+
+/// Compare two byte strings without exiting early on the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Check that the request carries `token` as its bearer token.
+///
+/// Without a configured token the endpoint does not exist, so that it is
+/// never open by accident: the request fails with `disabled_message` as a
+/// 404.
+fn require_bearer_token(
+    rqctx: &RequestContext<ApiContext>,
+    token: Option<&str>,
+    disabled_message: &str,
+    unauthorized_message: &str,
+) -> Result<(), HttpError> {
+    let Some(token) = token else {
+        return Err(HttpError::for_not_found(None, disabled_message.to_string()));
+    };
+
+    let authorized = rqctx
+        .request
+        .headers()
+        .get(http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()));
+    if !authorized {
+        return Err(HttpError::for_client_error(
+            None,
+            ClientErrorStatusCode::UNAUTHORIZED,
+            unauthorized_message.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Check the bearer token of a request to an admin endpoint
+fn require_admin_token(rqctx: &RequestContext<ApiContext>) -> Result<(), HttpError> {
+    require_bearer_token(
+        rqctx,
+        rqctx.context().config().admin_token.as_deref(),
+        "Admin endpoints are not enabled",
+        "A valid admin bearer token is required",
+    )
+}
+
+/// Render an HTML issue index: of every project without labels of its own,
+/// or of `project`, optionally narrowed to the issues with `label`.
+async fn render_index_html(
+    rqctx: &RequestContext<ApiContext>,
+    project: Option<&str>,
+    label: Option<&str>,
+    query: IssueListQuery,
+) -> Result<Response<Body>, HttpError> {
+    let ctx = rqctx.context();
+    let config = ctx.config();
+
+    // The project key is interpolated into JQL
+    if let Some(project) = project
+        && !is_project_key(project)
+    {
+        return Err(HttpError::for_bad_request(
+            None,
+            format!(
+                "Invalid project {:?}: expected a project key such as OS",
+                project
+            ),
+        ));
+    }
+
+    // Validate label is allowed
+    let allowed_labels = config.allowed_labels_for(project);
+    if let Some(label) = label
+        && !allowed_labels.iter().any(|l| l == label)
+    {
+        return Err(HttpError::for_bad_request(
+            None,
+            format!("Label '{}' is not public", label),
+        ));
+    }
+
+    // Get issues
+    let page = fetch_issues_for_html(
+        ctx.jira.as_ref(),
+        &ctx.token_cache,
+        config.index_scope(project, label),
+        query,
+        config.display_timezone,
+    )
+    .await?;
+
+    // Render HTML
+    let projects = config.project_keys();
+    let nav = IndexNav {
+        project,
+        allowed_labels,
+        projects: &projects,
+    };
+    let html = ctx
+        .html
+        .render_issue_index(&page, label, &nav, request_theme(rqctx))
+        .map_err(|e| HttpError::for_internal_error(format!("Failed to render HTML: {}", e)))?;
+
+    build_html_response(200, html)
+}
+
 /// Bugview service implementation
 enum BugviewServiceImpl {}
 
@@ -97,49 +403,48 @@ impl BugviewApi for BugviewServiceImpl {
 
     async fn get_issue_index_json(
         rqctx: RequestContext<Self::Context>,
-        query: Query<IssueListQuery>,
-    ) -> Result<HttpResponseOk<IssueListResponse>, HttpError> {
+        query: Query<IssueIndexQuery>,
+    ) -> Result<HttpResponseConditional<IssueListResponse>, HttpError> {
         let ctx = rqctx.context();
+        let config = ctx.config();
         let query = query.into_inner();
 
-        // Use the default label
-        let labels = vec![ctx.config.default_label.clone()];
-
-        search_issues(ctx.jira.as_ref(), &ctx.token_cache, labels, query).await
+        // A project with labels of its own is searched with them. Any other
+        // project filter is validated along with the rest of the query.
+        let project = query
+            .project
+            .as_deref()
+            .filter(|key| config.project(key).is_some());
+        let scope = config.index_scope(project, None);
+
+        let HttpResponseOk(response) = search_issues(
+            ctx.jira.as_ref(),
+            &ctx.token_cache,
+            scope,
+            query,
+            config.display_timezone,
+        )
+        .await?;
+
+        let validators = index_validators(&response)?;
+        Ok(HttpResponseConditional::new(
+            rqctx.request.headers(),
+            response,
+            validators,
+        ))
     }
 
     async fn get_issue_json(
         rqctx: RequestContext<Self::Context>,
         path: Path<IssuePath>,
-    ) -> Result<HttpResponseOk<IssueSummary>, HttpError> {
+    ) -> Result<HttpResponseConditional<IssueSummary>, HttpError> {
         let ctx = rqctx.context();
-        let key_str = path.into_inner().key;
-
-        // Validate issue key format
-        let key = jira_api::IssueKey::new(&key_str)
-            .map_err(|e| HttpError::for_bad_request(None, format!("{}", e)))?;
-
-        let issue = ctx.jira.get_issue(&key).await.map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("Issue not found") || msg.contains("404") {
-                // Safe to expose - user is asking for an issue that doesn't exist
-                HttpError::for_not_found(None, format!("Issue {} not found", key))
-            } else {
-                // Log full error but return generic message to avoid exposing internals
-                tracing::error!(issue_key = %key, error = %e, "Failed to get issue from JIRA");
-                HttpError::for_internal_error(
-                    "Failed to retrieve issue. Please try again later.".to_string(),
-                )
-            }
-        })?;
+        let key = path.into_inner().key;
 
-        // Check if issue has the required label
-        if !issue_has_public_label(&issue, &ctx.config.default_label) {
-            return Err(HttpError::for_not_found(
-                None,
-                format!("Issue {} is not public", key),
-            ));
-        }
+        let issue = match issue::lookup_public_issue(ctx, &key).await {
+            Ok(cached) => cached.issue,
+            Err(e) => return Err(e.into_http_error(ctx).await),
+        };
 
         let summary = issue
             .fields
@@ -154,121 +459,110 @@ impl BugviewApi for BugviewServiceImpl {
                 "(No summary)".to_string()
             });
 
-        Ok(HttpResponseOk(IssueSummary {
+        let body = IssueSummary {
             id: issue.key.to_string(),
             summary,
-            web_url: format!("{}/bugview/{}", ctx.config.public_base_url, issue.key),
-        }))
+            web_url: format!("{}/bugview/{}", ctx.config().public_base_url, issue.key),
+        };
+
+        let validators = issue_validators(&issue, &body)?;
+        Ok(HttpResponseConditional::new(
+            rqctx.request.headers(),
+            body,
+            validators,
+        ))
     }
 
     async fn get_issue_full_json(
         rqctx: RequestContext<Self::Context>,
         path: Path<IssuePath>,
-    ) -> Result<HttpResponseOk<IssueDetails>, HttpError> {
+        query: Query<IssueFullQuery>,
+    ) -> Result<HttpResponseConditional<IssueFullResponse>, HttpError> {
         let ctx = rqctx.context();
-        let key_str = path.into_inner().key;
-
-        // Validate issue key format
-        let key = jira_api::IssueKey::new(&key_str)
-            .map_err(|e| HttpError::for_bad_request(None, format!("{}", e)))?;
-
-        let issue = ctx.jira.get_issue(&key).await.map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("Issue not found") || msg.contains("404") {
-                // Safe to expose - user is asking for an issue that doesn't exist
-                HttpError::for_not_found(None, format!("Issue {} not found", key))
-            } else {
-                // Log full error but return generic message to avoid exposing internals
-                tracing::error!(issue_key = %key, error = %e, "Failed to get issue from JIRA");
-                HttpError::for_internal_error(
-                    "Failed to retrieve issue. Please try again later.".to_string(),
-                )
-            }
-        })?;
+        let key = path.into_inner().key;
+        let version = issue::full_response_version(&query.into_inner())?;
 
-        // Check if issue has the required label
-        if !issue_has_public_label(&issue, &ctx.config.default_label) {
-            return Err(HttpError::for_not_found(
-                None,
-                format!("Issue {} is not public", key),
-            ));
-        }
+        let issue = match issue::lookup_public_issue(ctx, &key).await {
+            Ok(cached) => cached.issue,
+            Err(e) => return Err(e.into_http_error(ctx).await),
+        };
 
-        // Fetch remote links and filter by allowed domains
-        let jira_remote_links = ctx
-            .jira
-            .get_remote_links(&issue.id)
-            .await
-            .unwrap_or_else(|e| {
-                let err_str = e.to_string();
-                // Escalate to error level for auth failures and rate limiting
-                // as these indicate operational issues that need attention
-                if err_str.contains("401")
-                    || err_str.contains("403")
-                    || err_str.to_lowercase().contains("unauthorized")
-                    || err_str.to_lowercase().contains("forbidden")
-                {
-                    tracing::error!(
-                        issue_id = %issue.id,
-                        issue_key = %issue.key,
-                        error = %e,
-                        "Authentication/authorization failure fetching remote links - check JIRA credentials"
-                    );
-                } else if err_str.contains("429")
-                    || err_str.to_lowercase().contains("too many requests")
-                {
-                    tracing::error!(
-                        issue_id = %issue.id,
-                        issue_key = %issue.key,
-                        error = %e,
-                        "Rate limited by JIRA when fetching remote links"
-                    );
-                } else {
-                    tracing::warn!(
-                        issue_id = %issue.id,
-                        issue_key = %issue.key,
-                        error = %e,
-                        "Failed to fetch remote links, returning empty list"
-                    );
-                }
-                Vec::new()
-            });
+        issue::full_issue_response(ctx, rqctx.request.headers(), issue, version).await
+    }
 
-        let filtered_links = filter_remote_links(&jira_remote_links, &ctx.config);
+    // ========================================================================
+    // Admin Endpoints
+    // ========================================================================
 
-        // Convert to API response format
-        let remotelinks: Vec<RemoteLink> = filtered_links
-            .iter()
-            .filter_map(|link| {
-                link.object.as_ref().map(|obj| RemoteLink {
-                    url: obj.url.clone(),
-                    title: obj.title.clone(),
-                })
-            })
-            .collect();
-
-        // Strip restricted comments before serializing to prevent leaking
-        // comments with visibility restrictions (role/group-restricted)
-        let mut fields_map = issue.fields;
-        strip_restricted_comments(&mut fields_map);
-
-        let fields = serde_json::to_value(fields_map).map_err(|e| {
-            tracing::error!(
-                issue_key = %issue.key,
-                error = %e,
-                "Failed to serialize issue fields to JSON"
-            );
-            HttpError::for_internal_error(format!("Failed to serialize issue fields: {}", e))
-        })?;
+    async fn get_stats_json(
+        rqctx: RequestContext<Self::Context>,
+        query: Query<StatsQuery>,
+    ) -> Result<HttpResponseOk<StatsResponse>, HttpError> {
+        let ctx = rqctx.context();
 
-        Ok(HttpResponseOk(IssueDetails {
-            id: issue.id,
-            key: issue.key,
-            fields,
-            remotelinks,
+        // View counts are never public by accident: without a token the
+        // endpoint does not exist
+        require_bearer_token(
+            &rqctx,
+            ctx.config().stats_token.as_deref(),
+            "Statistics are not enabled",
+            "A valid stats bearer token is required",
+        )?;
+
+        let limit = query
+            .into_inner()
+            .limit
+            .unwrap_or(stats::DEFAULT_STATS_LIMIT);
+        Ok(HttpResponseOk(
+            ctx.stats.summary(limit, ctx.issue_cache.stats()),
+        ))
+    }
+
+    async fn reload_config(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<ConfigReloadResponse>, HttpError> {
+        let ctx = rqctx.context();
+        require_admin_token(&rqctx)?;
+
+        // The running configuration is kept if the new one is invalid
+        let config = read_config(ctx.config_file.as_deref()).map_err(|e| {
+            tracing::warn!(error = %format!("{:#}", e), "Failed to reload configuration");
+            HttpError::for_bad_request(None, format!("Invalid configuration: {:#}", e))
+        })?;
+        let config = Arc::new(config);
+        let previous = ctx.config.swap(Arc::clone(&config));
+        let changed = *previous != *config;
+        info!(changed, "Reloaded configuration");
+
+        Ok(HttpResponseOk(ConfigReloadResponse {
+            changed,
+            default_label: config.default_label.clone(),
+            allowed_labels: config.allowed_labels.clone(),
+            projects: config.project_keys(),
+            allowed_domains: config.allowed_domains.clone(),
         }))
     }
 
+    async fn purge_caches(
+        rqctx: RequestContext<Self::Context>,
+    ) -> Result<HttpResponseOk<CachePurgeResponse>, HttpError> {
+        let ctx = rqctx.context();
+        require_admin_token(&rqctx)?;
+
+        // Page tokens are left alone, so that visitors part way through an
+        // index can carry on paging
+        let response = CachePurgeResponse {
+            issues: ctx.issue_cache.purge(),
+            searches: ctx.index_cache.as_ref().map_or(0, |cache| cache.purge()),
+        };
+        info!(
+            issues = response.issues,
+            searches = response.searches,
+            "Purged issue and index caches"
+        );
+        Ok(HttpResponseOk(response))
+    }
+
     // ========================================================================
     // HTML Endpoints
     // ========================================================================
@@ -277,30 +571,7 @@ impl BugviewApi for BugviewServiceImpl {
         rqctx: RequestContext<Self::Context>,
         query: Query<IssueListQuery>,
     ) -> Result<Response<Body>, HttpError> {
-        let ctx = rqctx.context();
-        let query = query.into_inner();
-
-        // Use the default label
-        let labels = vec![ctx.config.default_label.clone()];
-
-        // Get issues
-        let (issues, next_page_token, is_last, sort) =
-            fetch_issues_for_html(ctx.jira.as_ref(), &ctx.token_cache, labels, query).await?;
-
-        // Render HTML
-        let html = ctx
-            .html
-            .render_issue_index(
-                &issues,
-                next_page_token,
-                is_last,
-                sort,
-                None,
-                &ctx.config.allowed_labels,
-            )
-            .map_err(|e| HttpError::for_internal_error(format!("Failed to render HTML: {}", e)))?;
-
-        build_html_response(200, html)
+        render_index_html(&rqctx, None, None, query.into_inner()).await
     }
 
     async fn get_label_index_html(
@@ -308,169 +579,123 @@ impl BugviewApi for BugviewServiceImpl {
         path: Path<LabelPath>,
         query: Query<IssueListQuery>,
     ) -> Result<Response<Body>, HttpError> {
-        let ctx = rqctx.context();
         let label = path.into_inner().key;
-        let query = query.into_inner();
-
-        // Validate label is allowed
-        if !ctx.config.is_allowed_label(&label) {
-            return Err(HttpError::for_bad_request(
-                None,
-                format!("Label '{}' is not public", label),
-            ));
-        }
-
-        // Combine default label with requested label
-        let labels = vec![ctx.config.default_label.clone(), label.clone()];
-
-        // Get issues
-        let (issues, next_page_token, is_last, sort) =
-            fetch_issues_for_html(ctx.jira.as_ref(), &ctx.token_cache, labels, query).await?;
+        render_index_html(&rqctx, None, Some(&label), query.into_inner()).await
+    }
 
-        // Render HTML
-        let html = ctx
-            .html
-            .render_issue_index(
-                &issues,
-                next_page_token,
-                is_last,
-                sort,
-                Some(&label),
-                &ctx.config.allowed_labels,
-            )
-            .map_err(|e| HttpError::for_internal_error(format!("Failed to render HTML: {}", e)))?;
+    async fn get_project_index_html(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<ProjectPath>,
+        query: Query<IssueListQuery>,
+    ) -> Result<Response<Body>, HttpError> {
+        let project = path.into_inner().project;
+        render_index_html(&rqctx, Some(&project), None, query.into_inner()).await
+    }
 
-        build_html_response(200, html)
+    async fn get_project_label_index_html(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<ProjectLabelPath>,
+        query: Query<IssueListQuery>,
+    ) -> Result<Response<Body>, HttpError> {
+        let path = path.into_inner();
+        render_index_html(
+            &rqctx,
+            Some(&path.project),
+            Some(&path.key),
+            query.into_inner(),
+        )
+        .await
     }
 
     async fn get_issue_html(
         rqctx: RequestContext<Self::Context>,
         path: Path<IssuePath>,
+        query: Query<IssueFullQuery>,
     ) -> Result<Response<Body>, HttpError> {
         let ctx = rqctx.context();
-        let key_str = path.into_inner().key;
-
-        // Validate issue key format
-        let key = match jira_api::IssueKey::new(&key_str) {
-            Ok(k) => k,
-            Err(e) => {
-                let error_message = format!("{}", e);
-                let html =
-                    ctx.html
-                        .render_error(400, &error_message)
-                        .unwrap_or_else(|template_err| {
-                            tracing::error!(
-                                error = %template_err,
-                                "Failed to render error page template"
-                            );
-                            format!("Error 400: {}", error_message)
-                        });
-
-                return build_html_response(400, html);
-            }
-        };
+        let key = path.into_inner().key;
+
+        // Clients asking for JSON get the full issue details, with JSON
+        // errors
+        if ResponseFormat::from_accept(rqctx.request.headers()) == ResponseFormat::Json {
+            let version = issue::full_response_version(&query.into_inner())?;
+            let issue = match issue::lookup_public_issue(ctx, &key).await {
+                Ok(cached) => cached.issue,
+                Err(e) => return Err(e.into_http_error(ctx).await),
+            };
+            let response: Result<Response<Body>, HttpError> =
+                issue::full_issue_response(ctx, rqctx.request.headers(), issue, version)
+                    .await?
+                    .into();
+            return response.map(vary_on_accept);
+        }
 
-        // Try to get the issue
-        let issue = match ctx.jira.get_issue(&key).await {
-            Ok(issue) => issue,
+        let theme = request_theme(&rqctx);
+        let cached = match issue::lookup_public_issue(ctx, &key).await {
+            Ok(cached) => cached,
             Err(e) => {
-                let msg = e.to_string();
-                let (status_code, error_message) = if msg.contains("Issue not found")
-                    || msg.contains("404")
-                {
-                    // Safe to expose - user is asking for an issue that doesn't exist
-                    (404, format!("Issue {} not found", key))
-                } else {
-                    // Log full error but return generic message to avoid exposing internals
-                    tracing::error!(issue_key = %key, error = %e, "Failed to get issue from JIRA");
-                    (
-                        500,
-                        "Failed to retrieve issue. Please try again later.".to_string(),
-                    )
+                let related = match &e {
+                    issue::IssueLookupError::NotFound { key, .. } => ctx.related_issues(key).await,
+                    _ => Vec::new(),
                 };
-
+                let status_code = e.status();
                 let html = ctx
                     .html
-                    .render_error(status_code, &error_message)
+                    .render_error(status_code, e.message(), &related, theme)
                     .unwrap_or_else(|template_err| {
                         tracing::error!(
                             error = %template_err,
                             status_code = status_code,
                             "Failed to render error page template"
                         );
-                        format!("Error {}: {}", status_code, error_message)
+                        format!("Error {}: {}", status_code, e.message())
                     });
 
-                return build_html_response(status_code, html);
-            }
-        };
-
-        // Check if issue has the required label
-        if !issue_has_public_label(&issue, &ctx.config.default_label) {
-            let error_message = format!("Issue {} is not public", key);
-            let html = ctx
-                .html
-                .render_error(404, &error_message)
-                .unwrap_or_else(|template_err| {
-                    tracing::error!(
-                        error = %template_err,
-                        "Failed to render error page template"
-                    );
-                    format!("Error 404: {}", error_message)
-                });
-
-            return build_html_response(404, html);
-        }
-
-        // Fetch remote links and filter by allowed_domains
-        // Track whether fetch failed so we can show a warning to users
-        let (remote_links, remote_links_error) = match ctx.jira.get_remote_links(&issue.id).await {
-            Ok(links) => (links, false),
-            Err(e) => {
-                let err_str = e.to_string();
-                // Escalate to error level for auth failures and rate limiting
-                // as these indicate operational issues that need attention
-                if err_str.contains("401")
-                    || err_str.contains("403")
-                    || err_str.to_lowercase().contains("unauthorized")
-                    || err_str.to_lowercase().contains("forbidden")
-                {
-                    tracing::error!(
-                        issue_id = %issue.id,
-                        issue_key = %issue.key,
-                        error = %e,
-                        "Authentication/authorization failure fetching remote links - check JIRA credentials"
-                    );
-                } else if err_str.contains("429")
-                    || err_str.to_lowercase().contains("too many requests")
-                {
-                    tracing::error!(
-                        issue_id = %issue.id,
-                        issue_key = %issue.key,
-                        error = %e,
-                        "Rate limited by JIRA when fetching remote links"
-                    );
-                } else {
-                    tracing::warn!(
-                        issue_id = %issue.id,
-                        issue_key = %issue.key,
-                        error = %e,
-                        "Failed to fetch remote links, will show warning to user"
-                    );
-                }
-                (Vec::new(), true)
+                return build_html_response(status_code, html).map(vary_on_accept);
             }
         };
+        let issue = cached.issue;
+        let config = ctx.config();
 
-        let filtered_links = filter_remote_links(&remote_links, &ctx.config);
+        // Fetch remote links and filter by allowed_domains. Track whether
+        // the fetch failed so we can show a warning to users.
+        let (filtered_links, remote_links_error) = issue::public_remote_links(ctx, &issue).await;
+        let history = public_history(&issue, &config, chrono::Utc::now());
 
-        // Render HTML (pass error flag to show warning if links couldn't be loaded)
+        // Render HTML (pass error flags to show warnings if links couldn't be
+        // loaded or the issue itself could not be refreshed)
         let html = ctx
             .html
-            .render_issue(&issue, &filtered_links, remote_links_error)
+            .render_issue(
+                &issue,
+                &format!("{}/bugview/issue/{}", config.public_base_url, issue.key),
+                &filtered_links,
+                remote_links_error,
+                &history,
+                cached.stale,
+                theme,
+            )
             .map_err(|e| HttpError::for_internal_error(format!("Failed to render HTML: {}", e)))?;
 
-        build_html_response(200, html)
+        build_html_response(200, html).map(vary_on_accept)
+    }
+
+    async fn get_static_asset(
+        _rqctx: RequestContext<Self::Context>,
+        path: Path<StaticAssetPath>,
+    ) -> Result<Response<Body>, HttpError> {
+        let name = path.into_inner().asset;
+        let asset = assets::lookup(&name).ok_or_else(|| {
+            HttpError::for_not_found(None, format!("Static asset '{}' not found", name))
+        })?;
+
+        Response::builder()
+            .status(200)
+            .header("Content-Type", asset.content_type)
+            .header("Cache-Control", assets::CACHE_CONTROL)
+            .header("X-Content-Type-Options", "nosniff")
+            .body(asset.content.into())
+            .map_err(|e| HttpError::for_internal_error(format!("Failed to build response: {}", e)))
     }
 
     // ========================================================================
@@ -488,6 +713,122 @@ impl BugviewApi for BugviewServiceImpl {
     }
 }
 
+/// Read a number of seconds from an environment variable, or `default` if
+/// it is unset or empty.
+fn env_secs(name: &str, default: u64) -> Result<u64> {
+    match std::env::var(name) {
+        Ok(v) if !v.is_empty() => v
+            .parse()
+            .map_err(|e| anyhow::anyhow!("{}: invalid number of seconds: {}", name, e)),
+        _ => Ok(default),
+    }
+}
+
+/// Read a count from an environment variable, or `default` if it is unset
+/// or empty.
+fn env_count<T>(name: &str, default: T) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match std::env::var(name) {
+        Ok(v) if !v.is_empty() => v
+            .parse()
+            .map_err(|e| anyhow::anyhow!("{}: invalid count: {}", name, e)),
+        _ => Ok(default),
+    }
+}
+
+/// Split a comma-separated list, dropping empty entries.
+fn comma_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Read the projects with labels of their own.
+///
+/// `BUGVIEW_PROJECTS` lists their keys. Each project's public label is read
+/// from `BUGVIEW_PROJECT_<KEY>_LABEL` (default: `default_label`) and its
+/// additional allowed labels from `BUGVIEW_PROJECT_<KEY>_ALLOWED_LABELS`.
+/// `var` looks up an environment variable.
+fn project_configs(
+    var: impl Fn(&str) -> Option<String>,
+    default_label: &str,
+) -> Result<Vec<ProjectConfig>> {
+    let mut projects: Vec<ProjectConfig> = Vec::new();
+    for key in comma_list(&var("BUGVIEW_PROJECTS").unwrap_or_default()) {
+        // Project keys end up in JQL
+        if !is_project_key(&key) {
+            anyhow::bail!("BUGVIEW_PROJECTS: invalid project key {:?}", key);
+        }
+        if projects.iter().any(|p| p.key == key) {
+            anyhow::bail!("BUGVIEW_PROJECTS: project {} is listed twice", key);
+        }
+
+        let default_label = var(&format!("BUGVIEW_PROJECT_{}_LABEL", key))
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| default_label.to_string());
+        let allowed_labels = comma_list(
+            &var(&format!("BUGVIEW_PROJECT_{}_ALLOWED_LABELS", key)).unwrap_or_default(),
+        );
+        projects.push(ProjectConfig {
+            key,
+            default_label,
+            allowed_labels,
+        });
+    }
+    Ok(projects)
+}
+
+/// Read the reloadable configuration. `var` looks up a setting by the name
+/// of its environment variable.
+fn load_config(var: impl Fn(&str) -> Option<String>) -> Result<Config> {
+    let default_label = var("JIRA_DEFAULT_LABEL").unwrap_or_else(|| "public".to_string());
+    let allowed_labels = comma_list(&var("JIRA_ALLOWED_LABELS").unwrap_or_default());
+    let projects = project_configs(&var, &default_label)?;
+    let allowed_domains = comma_list(&var("JIRA_ALLOWED_DOMAINS").unwrap_or_default());
+    let public_base_url =
+        var("PUBLIC_BASE_URL").unwrap_or_else(|| DEFAULT_PUBLIC_BASE_URL.to_string());
+    let stats_token = var("BUGVIEW_STATS_TOKEN").filter(|s| !s.is_empty());
+    let admin_token = var("BUGVIEW_ADMIN_TOKEN").filter(|s| !s.is_empty());
+    let display_timezone = match var("BUGVIEW_TIMEZONE") {
+        Some(tz) if !tz.is_empty() => tz
+            .parse()
+            .map_err(|e| anyhow::anyhow!("BUGVIEW_TIMEZONE: {}", e))?,
+        _ => DisplayTimezone::default(),
+    };
+
+    Ok(Config {
+        default_label,
+        allowed_labels,
+        projects,
+        allowed_domains,
+        public_base_url,
+        stats_token,
+        admin_token,
+        display_timezone,
+    })
+}
+
+/// Read the reloadable configuration from the environment, with the
+/// settings in `file` (see [`config_file`]) taking precedence.
+fn read_config(file: Option<&std::path::Path>) -> Result<Config> {
+    let settings = match file {
+        Some(path) => config_file::read(path)?,
+        None => Default::default(),
+    };
+    load_config(|name| {
+        settings
+            .get(name)
+            .cloned()
+            .or_else(|| std::env::var(name).ok())
+    })
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Install the rustls crypto provider before any reqwest or rustls
@@ -506,47 +847,107 @@ async fn main() -> Result<()> {
     // Required credentials - fail fast if not set rather than starting with invalid config
     let jira_url =
         std::env::var("JIRA_URL").context("JIRA_URL environment variable is required")?;
-    let jira_username =
-        std::env::var("JIRA_USERNAME").context("JIRA_USERNAME environment variable is required")?;
-    let jira_password =
-        std::env::var("JIRA_PASSWORD").context("JIRA_PASSWORD environment variable is required")?;
-    let default_label =
-        std::env::var("JIRA_DEFAULT_LABEL").unwrap_or_else(|_| "public".to_string());
-    let allowed_labels = std::env::var("JIRA_ALLOWED_LABELS")
-        .unwrap_or_default()
-        .split(',')
-        .filter(|s| !s.is_empty())
-        .map(|s| s.trim().to_string())
-        .collect();
+    // A personal access token takes precedence over username/password
+    let jira_auth = match std::env::var("JIRA_TOKEN") {
+        Ok(token) => JiraAuth::pat(token),
+        Err(_) => JiraAuth::basic(
+            std::env::var("JIRA_USERNAME").context(
+                "JIRA_USERNAME environment variable is required when JIRA_TOKEN is not set",
+            )?,
+            std::env::var("JIRA_PASSWORD").context(
+                "JIRA_PASSWORD environment variable is required when JIRA_TOKEN is not set",
+            )?,
+        ),
+    };
+
+    // Everything else can be reloaded at runtime, from the environment and
+    // an optional settings file
+    let config_file = std::env::var_os("BUGVIEW_CONFIG_FILE").map(PathBuf::from);
+    let config = read_config(config_file.as_deref())?;
+    for project in &config.projects {
+        info!(
+            "Project {} uses public label {:?}",
+            project.key, project.default_label
+        );
+    }
+    info!("Displaying timestamps in {}", config.display_timezone);
+    let config = Arc::new(ArcSwap::from_pointee(config));
 
     info!("Initializing JIRA client");
-    let jira_client = JiraClient::new(jira_url, jira_username, jira_password)?;
+    let jira_client = JiraClient::new(jira_url, jira_auth)?;
 
     info!("Initializing HTML renderer");
     let html_renderer = HtmlRenderer::new();
 
-    let allowed_domains = std::env::var("JIRA_ALLOWED_DOMAINS")
-        .unwrap_or_default()
-        .split(',')
-        .filter(|s| !s.is_empty())
-        .map(|s| s.trim().to_string())
-        .collect();
-
-    let public_base_url =
-        std::env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| DEFAULT_PUBLIC_BASE_URL.to_string());
-
-    let config = Config {
-        default_label,
-        allowed_labels,
-        allowed_domains,
-        public_base_url,
+    // Access statistics are kept in memory, and flushed to a file if one is
+    // configured
+    let stats_path = std::env::var_os("BUGVIEW_STATS_PATH").map(std::path::PathBuf::from);
+    let stats = Arc::new(AccessStats::new(stats_path));
+    stats::spawn_flusher(
+        Arc::clone(&stats),
+        std::time::Duration::from_secs(stats::DEFAULT_FLUSH_INTERVAL_SECS),
+    );
+
+    // Issues are served stale-while-revalidate so pages survive JIRA outages
+    let cache_ttl = env_secs("BUGVIEW_CACHE_TTL_SECS", issue_cache::DEFAULT_TTL_SECS)?;
+    let cache_max_stale = env_secs(
+        "BUGVIEW_CACHE_MAX_STALE_SECS",
+        issue_cache::DEFAULT_MAX_STALE_SECS,
+    )?;
+    info!(
+        "Caching issues for {}s, serving stale copies for up to {}s",
+        cache_ttl, cache_max_stale
+    );
+    let issue_cache = Arc::new(IssueCache::new(
+        std::time::Duration::from_secs(cache_ttl),
+        std::time::Duration::from_secs(cache_max_stale),
+    ));
+
+    // Index searches are cached for as long as issues are, and the first
+    // index pages and the issues on them are preloaded in the background
+    let index_cache = Arc::new(IndexCache::new(
+        Arc::new(jira_client) as Arc<dyn JiraClientTrait>,
+        std::time::Duration::from_secs(cache_ttl),
+    ));
+    let token_cache = TokenCache::new();
+    let warm_config = WarmConfig {
+        pages: env_count("BUGVIEW_WARM_PAGES", index_cache::DEFAULT_WARM_PAGES)?,
+        issues: env_count("BUGVIEW_WARM_ISSUES", index_cache::DEFAULT_WARM_ISSUES)?,
+        interval: std::time::Duration::from_secs(env_secs(
+            "BUGVIEW_WARM_INTERVAL_SECS",
+            index_cache::DEFAULT_WARM_INTERVAL_SECS,
+        )?),
     };
+    if warm_config.pages > 0 {
+        if warm_config.interval.is_zero() {
+            anyhow::bail!("BUGVIEW_WARM_INTERVAL_SECS: must be at least 1");
+        }
+        info!(
+            "Warming {} index pages and {} issues every {}s",
+            warm_config.pages,
+            warm_config.issues,
+            warm_config.interval.as_secs()
+        );
+        // Scopes are looked up each round, so the warmer follows reloads
+        let warm_scopes = Arc::clone(&config);
+        index_cache::spawn_warmer(
+            Arc::clone(&index_cache),
+            token_cache.clone(),
+            Arc::clone(&issue_cache),
+            move || warm_scopes.load().warm_scopes(),
+            warm_config,
+        );
+    }
 
     let api_context = ApiContext {
-        jira: Arc::new(jira_client) as Arc<dyn JiraClientTrait>,
+        jira: Arc::clone(&index_cache) as Arc<dyn JiraClientTrait>,
         config,
+        config_file,
         html: html_renderer,
-        token_cache: TokenCache::new(),
+        token_cache,
+        issue_cache,
+        index_cache: Some(index_cache),
+        stats: Arc::clone(&stats),
     };
 
     // Get API description from the trait implementation
@@ -580,19 +981,27 @@ async fn main() -> Result<()> {
 
     info!("Bugview service running on http://{}", bind_address);
 
-    server
+    let result = server
         .await
-        .map_err(|error| anyhow::anyhow!("server failed: {}", error))
+        .map_err(|error| anyhow::anyhow!("server failed: {}", error));
+
+    if let Err(e) = stats.flush().await {
+        tracing::error!(error = %e, "Failed to flush access statistics");
+    }
+
+    result
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::jira_client::SearchResponse;
+    use crate::jira_client::{SearchFilter, SearchResponse};
+    use crate::search::{filter_remote_links, issue_has_public_label};
     use async_trait::async_trait;
-    use bugview_api::IssueDetails;
+    use bugview_api::{IssueDetails, IssueFieldChange, IssueStatusFilter};
     use http::StatusCode;
     use jira_api::{Issue, RemoteLink};
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     /// Start a test server with the given context.
     ///
@@ -641,6 +1050,7 @@ mod tests {
         async fn search_issues(
             &self,
             _labels: &[String],
+            _filter: &SearchFilter,
             _page_token: Option<&str>,
             _sort: &str,
         ) -> anyhow::Result<SearchResponse> {
@@ -656,6 +1066,8 @@ mod tests {
                 serde_json::Value::String("2023-10-02T00:00:00.000-0400".into()),
             );
             issue1.insert("labels".into(), serde_json::json!(["public"]));
+            issue1.insert("issuetype".into(), serde_json::json!({"name": "Bug"}));
+            issue1.insert("priority".into(), serde_json::json!({"name": "3 - Major"}));
 
             let mut issue2 = serde_json::Map::new();
             issue2.insert("summary".into(), serde_json::Value::String("Beta".into()));
@@ -667,7 +1079,11 @@ mod tests {
                 "updated".into(),
                 serde_json::Value::String("2023-10-04T00:00:00.000-0400".into()),
             );
-            issue2.insert("labels".into(), serde_json::json!(["public"]));
+            // Private labels must not be shown in the index
+            issue2.insert(
+                "labels".into(),
+                serde_json::json!(["public", "customer-acme", "bug"]),
+            );
 
             let issues = vec![
                 Issue {
@@ -675,12 +1091,14 @@ mod tests {
                     id: "1".into(),
                     fields: issue1.into_iter().collect(),
                     rendered_fields: None,
+                    changelog: None,
                 },
                 Issue {
                     key: jira_api::IssueKey::new_unchecked("PROJ-2"),
                     id: "2".into(),
                     fields: issue2.into_iter().collect(),
                     rendered_fields: None,
+                    changelog: None,
                 },
             ];
 
@@ -708,11 +1126,57 @@ mod tests {
             );
             fields.insert("labels".to_string(), serde_json::json!(["public"]));
 
+            // A changelog mixing public and private changes
+            let changelog = serde_json::from_value(serde_json::json!({
+                "histories": [
+                    {
+                        "id": "1",
+                        "created": "2023-10-04T11:00:00.000-0400",
+                        "items": [
+                            {"field": "status", "fieldtype": "jira",
+                             "fromString": "Open", "toString": "In Progress"},
+                            {"field": "assignee", "fieldtype": "jira",
+                             "fromString": null, "toString": "Jane Engineer"}
+                        ]
+                    },
+                    {
+                        "id": "2",
+                        "created": "2023-10-04T12:00:00.000-0400",
+                        "items": [
+                            {"field": "labels", "fieldtype": "jira",
+                             "fromString": "internal", "toString": "internal public"}
+                        ]
+                    },
+                    {
+                        "id": "3",
+                        "created": "2023-10-04T13:00:00.000-0400",
+                        "items": [
+                            {"field": "labels", "fieldtype": "jira",
+                             "fromString": "internal public", "toString": "internal public secret"},
+                            {"field": "status", "fieldtype": "custom",
+                             "fromString": "Triage", "toString": "Escalated"}
+                        ]
+                    },
+                    {
+                        "id": "4",
+                        "created": "2023-10-05T10:27:22.826-0400",
+                        "items": [
+                            {"field": "resolution", "fieldtype": "jira",
+                             "fromString": null, "toString": "Fixed"},
+                            {"field": "status", "fieldtype": "jira",
+                             "fromString": "In Progress", "toString": "Resolved"}
+                        ]
+                    }
+                ],
+                "total": 4
+            }))?;
+
             Ok(Issue {
                 key: key.clone(),
                 id: "12345".to_string(),
                 fields: fields.into_iter().collect(),
                 rendered_fields: None,
+                changelog: Some(changelog),
             })
         }
 
@@ -736,6 +1200,7 @@ mod tests {
         async fn search_issues(
             &self,
             _labels: &[String],
+            _filter: &SearchFilter,
             _page_token: Option<&str>,
             _sort: &str,
         ) -> anyhow::Result<SearchResponse> {
@@ -760,6 +1225,7 @@ mod tests {
                 id: "1".into(),
                 fields: issue1.into_iter().collect(),
                 rendered_fields: None,
+                changelog: None,
             }];
 
             Ok(SearchResponse {
@@ -791,6 +1257,7 @@ mod tests {
                 id: "12345".to_string(),
                 fields: fields.into_iter().collect(),
                 rendered_fields: None,
+                changelog: None,
             })
         }
 
@@ -805,32 +1272,55 @@ mod tests {
         let config = Config {
             default_label: "public".to_string(),
             allowed_labels: vec!["public".to_string(), "bug".to_string()],
+            projects: vec![],
             allowed_domains: vec!["example.com".to_string()],
             public_base_url: "https://test.example.com".to_string(),
+            stats_token: None,
+            admin_token: None,
+            display_timezone: DisplayTimezone::default(),
         };
 
         ApiContext {
             jira: Arc::new(MockJiraClient) as Arc<dyn JiraClientTrait>,
-            config,
+            config: Arc::new(ArcSwap::from_pointee(config)),
+            config_file: None,
             html: HtmlRenderer::new(),
             token_cache: TokenCache::new(),
+            issue_cache: Arc::new(IssueCache::default()),
+            index_cache: None,
+            stats: Arc::new(AccessStats::new(None)),
         }
     }
 
+    // Change the configuration of a test context, as a reload would
+    fn update_config(ctx: &ApiContext, update: impl FnOnce(&mut Config)) {
+        let mut config = Config::clone(&ctx.config());
+        update(&mut config);
+        ctx.config.store(Arc::new(config));
+    }
+
     // Build a test ApiContext with the non-public mock client
     fn non_public_test_context() -> ApiContext {
         let config = Config {
             default_label: "public".to_string(),
             allowed_labels: vec!["public".to_string(), "bug".to_string()],
+            projects: vec![],
             allowed_domains: vec!["example.com".to_string()],
             public_base_url: "https://test.example.com".to_string(),
+            stats_token: None,
+            admin_token: None,
+            display_timezone: DisplayTimezone::default(),
         };
 
         ApiContext {
             jira: Arc::new(NonPublicMockJiraClient) as Arc<dyn JiraClientTrait>,
-            config,
+            config: Arc::new(ArcSwap::from_pointee(config)),
+            config_file: None,
             html: HtmlRenderer::new(),
             token_cache: TokenCache::new(),
+            issue_cache: Arc::new(IssueCache::default()),
+            index_cache: None,
+            stats: Arc::new(AccessStats::new(None)),
         }
     }
 
@@ -842,13 +1332,15 @@ mod tests {
         let issue = ctx.jira.get_issue(&key).await.expect("mock get_issue");
 
         // Verify label gate
-        assert!(issue_has_public_label(&issue, &ctx.config.default_label));
+        assert!(issue_has_public_label(&issue, &ctx.config().default_label));
 
+        let history = public_history(&issue, &ctx.config(), chrono::Utc::now());
         let details = IssueDetails {
             id: issue.id,
             key: issue.key,
             fields: serde_json::to_value(issue.fields).unwrap(),
             remotelinks: vec![],
+            history,
         };
 
         // Spot-check a couple fields
@@ -857,6 +1349,62 @@ mod tests {
             fields.get("summary").and_then(|v| v.as_str()),
             Some("Test summary")
         );
+
+        let json = serde_json::to_value(&details).unwrap();
+        let history = json["history"].as_array().unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0]["created"], "2023-10-04T11:00:00.000-0400");
+        assert_eq!(history[0]["created_display"]["date"], "2023-10-04");
+    }
+
+    #[tokio::test]
+    async fn test_public_history_filters_private_changes() {
+        let ctx = test_context();
+        let key = jira_api::IssueKey::new("PROJ-1").expect("valid key");
+        let issue = ctx.jira.get_issue(&key).await.unwrap();
+
+        let history = public_history(&issue, &ctx.config(), chrono::Utc::now());
+        let change = |field: &str, from: Option<&str>, to: Option<&str>| IssueFieldChange {
+            field: field.to_string(),
+            from: from.map(String::from),
+            to: to.map(String::from),
+        };
+
+        // The assignee change is dropped, leaving only the status change
+        assert_eq!(
+            history[0].changes,
+            vec![change("status", Some("Open"), Some("In Progress"))]
+        );
+        // Internal labels are removed from label changes
+        assert_eq!(
+            history[1].changes,
+            vec![change("labels", None, Some("public"))]
+        );
+        // Entry 3 only added a private label and changed a custom field, so
+        // nothing of it is left
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            history[2].changes,
+            vec![
+                change("resolution", None, Some("Fixed")),
+                change("status", Some("In Progress"), Some("Resolved")),
+            ]
+        );
+
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(!json.contains("Jane Engineer"));
+        assert!(!json.contains("internal"));
+        assert!(!json.contains("secret"));
+        assert!(!json.contains("Escalated"));
+    }
+
+    #[tokio::test]
+    async fn test_public_history_without_changelog() {
+        let ctx = non_public_test_context();
+        let key = jira_api::IssueKey::new("PROJ-1").expect("valid key");
+        let issue = ctx.jira.get_issue(&key).await.unwrap();
+
+        assert!(public_history(&issue, &ctx.config(), chrono::Utc::now()).is_empty());
     }
 
     #[tokio::test]
@@ -872,16 +1420,49 @@ mod tests {
         .unwrap();
         let links = vec![link];
 
-        let filtered = super::filter_remote_links(&links, &ctx.config);
+        let filtered = filter_remote_links(&links, &ctx.config());
         assert_eq!(filtered.len(), 1, "allowed domain should pass");
 
+        let history = public_history(&issue, &ctx.config(), chrono::Utc::now());
+
         let html = ctx
             .html
-            .render_issue(&issue, &filtered, false)
+            .render_issue(
+                &issue,
+                "https://test.example.com/bugview/issue/PROJ-2",
+                &filtered,
+                false,
+                &history,
+                false,
+                Theme::Auto,
+            )
             .expect("render html");
         assert!(html.contains("Test summary"));
         assert!(html.contains("Related Links"));
         assert!(html.contains("example.com"));
+        assert!(html.contains("History"));
+        assert!(html.contains("In Progress"));
+        assert!(!html.contains("Jane Engineer"));
+        assert!(!html.contains("secret"));
+        assert!(!html.contains("may be out of date"));
+        assert!(html.contains(
+            r#"<meta property="og:url" content="https://test.example.com/bugview/issue/PROJ-2">"#
+        ));
+        assert!(html.contains(r#"<meta property="og:title" content="PROJ-2: Test summary">"#));
+
+        let stale_html = ctx
+            .html
+            .render_issue(
+                &issue,
+                "https://test.example.com/bugview/issue/PROJ-2",
+                &filtered,
+                false,
+                &history,
+                true,
+                Theme::Auto,
+            )
+            .expect("render stale html");
+        assert!(stale_html.contains("may be out of date"));
     }
 
     #[tokio::test]
@@ -889,8 +1470,12 @@ mod tests {
         let config = Config {
             default_label: "public".into(),
             allowed_labels: vec![],
+            projects: vec![],
             allowed_domains: vec!["safe.example.com".into()],
             public_base_url: "https://test.example.com".into(),
+            stats_token: None,
+            admin_token: None,
+            display_timezone: DisplayTimezone::default(),
         };
 
         // Create links from mixed domains - some allowed, some not
@@ -920,8 +1505,12 @@ mod tests {
         let config = Config {
             default_label: "public".into(),
             allowed_labels: vec![],
+            projects: vec![],
             allowed_domains: vec!["example.com".into()],
             public_base_url: "https://test.example.com".into(),
+            stats_token: None,
+            admin_token: None,
+            display_timezone: DisplayTimezone::default(),
         };
 
         // Create links with dangerous schemes that could enable XSS
@@ -983,18 +1572,403 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_http_index_json_with_mock_server() {
+    async fn test_http_issue_route_negotiates_json() {
         let Some(server) = start_test_server(test_context()).await else {
             return;
         };
-        let url = format!("http://{}/bugview/index.json", server.local_addr());
+        let url = format!("http://{}/bugview/issue/PROJ-1", server.local_addr());
+        let client = reqwest::Client::new();
 
-        let resp = reqwest::get(&url).await.expect("request");
+        let resp = client
+            .get(&url)
+            .header("Accept", "text/html,application/xhtml+xml,*/*;q=0.8")
+            .send()
+            .await
+            .expect("request");
         assert_eq!(resp.status(), StatusCode::OK);
-        let body = resp.text().await.expect("body");
-        assert!(body.contains("\"issues\""));
-        assert!(body.contains("PROJ-1") && body.contains("PROJ-2"));
-    }
+        assert_eq!(
+            resp.headers().get("Vary").and_then(|v| v.to_str().ok()),
+            Some("Accept")
+        );
+        assert!(
+            resp.headers()
+                .get("Content-Type")
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|v| v.starts_with("text/html"))
+        );
+
+        // Same body as fulljson, in either version
+        let fulljson = format!("http://{}/bugview/fulljson/PROJ-1", server.local_addr());
+        for query in ["", "?v=2"] {
+            let resp = client
+                .get(format!("{}{}", url, query))
+                .header("Accept", "application/json")
+                .send()
+                .await
+                .expect("request");
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(
+                resp.headers().get("Vary").and_then(|v| v.to_str().ok()),
+                Some("Accept")
+            );
+            let etag = resp.headers().get("ETag").cloned().expect("ETag header");
+            let negotiated: serde_json::Value = resp.json().await.expect("json");
+
+            let resp = client
+                .get(format!("{}{}", fulljson, query))
+                .send()
+                .await
+                .expect("request");
+            assert_eq!(resp.headers().get("ETag"), Some(&etag));
+            let direct: serde_json::Value = resp.json().await.expect("json");
+            assert_eq!(negotiated, direct);
+
+            // Conditional requests work on the negotiated representation
+            let resp = client
+                .get(format!("{}{}", url, query))
+                .header("Accept", "application/json")
+                .header("If-None-Match", etag)
+                .send()
+                .await
+                .expect("request");
+            assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        }
+
+        // Errors are JSON too
+        let resp = client
+            .get(format!(
+                "http://{}/bugview/issue/invalid",
+                server.local_addr()
+            ))
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let error: serde_json::Value = resp.json().await.expect("json");
+        assert!(error["message"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_http_fulljson_versions() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let base = format!("http://{}/bugview/fulljson/PROJ-1", server.local_addr());
+
+        let v1: serde_json::Value = reqwest::get(&base)
+            .await
+            .expect("request")
+            .json()
+            .await
+            .expect("json");
+        assert_eq!(v1["fields"]["summary"], "Test summary");
+        assert!(v1.get("version").is_none());
+
+        let v2: serde_json::Value = reqwest::get(format!("{}?v=2", base))
+            .await
+            .expect("request")
+            .json()
+            .await
+            .expect("json");
+        assert_eq!(v2["version"], 2);
+        assert_eq!(v2["key"], "PROJ-1");
+        assert_eq!(v2["summary"], "Test summary");
+        assert_eq!(v2["labels"], serde_json::json!(["public"]));
+        assert_eq!(v2["history"].as_array().map(Vec::len), Some(3));
+        assert!(v2.get("fields").is_none());
+
+        let resp = reqwest::get(format!("{}?v=3", base))
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_http_stats_json_requires_token() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let url = format!("http://{}/bugview/stats.json", server.local_addr());
+
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "stats should be disabled without a configured token"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_stats_json_counts_views() {
+        let ctx = test_context();
+        update_config(&ctx, |config| {
+            config.stats_token = Some("s3cret".to_string())
+        });
+        let Some(server) = start_test_server(ctx).await else {
+            return;
+        };
+        let addr = server.local_addr();
+        let client = reqwest::Client::new();
+
+        for path in ["issue/PROJ-1", "json/PROJ-1", "json/PROJ-2"] {
+            let url = format!("http://{}/bugview/{}", addr, path);
+            let resp = reqwest::get(&url).await.expect("request");
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+
+        let url = format!("http://{}/bugview/stats.json", addr);
+        let resp = client
+            .get(&url)
+            .bearer_auth("wrong")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = client
+            .get(format!("{}?limit=1", url))
+            .bearer_auth("s3cret")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let stats: StatsResponse = resp.json().await.expect("stats json");
+        let day = &stats.windows[0];
+        assert_eq!(day.window, "24h");
+        assert_eq!(day.total_views, 3);
+        assert_eq!(day.issues.len(), 1);
+        assert_eq!(day.issues[0].key, "PROJ-1");
+        assert_eq!(day.issues[0].views, 2);
+        assert_eq!(day.labels[0].label, "public");
+        assert_eq!(day.labels[0].views, 3);
+    }
+
+    #[tokio::test]
+    async fn test_http_admin_requires_token() {
+        let ctx = test_context();
+        let config = ctx.config.clone();
+        let Some(server) = start_test_server(ctx).await else {
+            return;
+        };
+        let addr = server.local_addr();
+        let client = reqwest::Client::new();
+
+        for path in ["reload", "cache/purge"] {
+            let url = format!("http://{}/bugview/admin/{}", addr, path);
+            let resp = client.post(&url).send().await.expect("request");
+            assert_eq!(
+                resp.status(),
+                StatusCode::NOT_FOUND,
+                "admin endpoints should be disabled without a configured token"
+            );
+        }
+
+        let mut enabled = Config::clone(&config.load());
+        enabled.admin_token = Some("s3cret".to_string());
+        config.store(Arc::new(enabled));
+        for path in ["reload", "cache/purge"] {
+            let url = format!("http://{}/bugview/admin/{}", addr, path);
+            let resp = client
+                .post(&url)
+                .bearer_auth("wrong")
+                .send()
+                .await
+                .expect("request");
+            assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_admin_reload_and_purge() {
+        let dir = std::env::temp_dir().join(format!("bugview-admin-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_file = dir.join("bugview.env");
+        std::fs::write(
+            &config_file,
+            "BUGVIEW_ADMIN_TOKEN=s3cret\nJIRA_ALLOWED_LABELS=public,bug,zfs\n",
+        )
+        .unwrap();
+
+        let mut ctx = test_context();
+        ctx.config_file = Some(config_file.clone());
+        update_config(&ctx, |config| {
+            config.admin_token = Some("s3cret".to_string())
+        });
+        let config = ctx.config.clone();
+        let issue_cache = Arc::clone(&ctx.issue_cache);
+        let Some(server) = start_test_server(ctx).await else {
+            return;
+        };
+        let addr = server.local_addr();
+        let client = reqwest::Client::new();
+
+        // Viewing an issue caches it
+        let resp = reqwest::get(format!("http://{}/bugview/json/PROJ-1", addr))
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(issue_cache.stats().entries, 1);
+
+        let resp = client
+            .post(format!("http://{}/bugview/admin/reload", addr))
+            .bearer_auth("s3cret")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let reload: ConfigReloadResponse = resp.json().await.expect("reload json");
+        assert!(reload.changed);
+        assert_eq!(reload.allowed_labels, ["public", "bug", "zfs"]);
+        assert!(config.load().is_public_label("zfs"));
+
+        // An invalid configuration is rejected and the running one kept
+        std::fs::write(
+            &config_file,
+            "BUGVIEW_ADMIN_TOKEN=s3cret\nBUGVIEW_PROJECTS=os\n",
+        )
+        .unwrap();
+        let resp = client
+            .post(format!("http://{}/bugview/admin/reload", addr))
+            .bearer_auth("s3cret")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert!(config.load().is_public_label("zfs"));
+
+        let resp = client
+            .post(format!("http://{}/bugview/admin/cache/purge", addr))
+            .bearer_auth("s3cret")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let purge: CachePurgeResponse = resp.json().await.expect("purge json");
+        assert_eq!(
+            purge,
+            CachePurgeResponse {
+                issues: 1,
+                searches: 0,
+            }
+        );
+        assert_eq!(issue_cache.stats().entries, 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_http_index_json_with_mock_server() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let url = format!("http://{}/bugview/index.json", server.local_addr());
+
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = resp.json().await.expect("body");
+        let issues = body["issues"].as_array().expect("issues");
+        assert_eq!(issues[0]["key"], "PROJ-1");
+        assert_eq!(issues[0]["issue_type"], "Bug");
+        assert_eq!(issues[0]["priority"], "3 - Major");
+        assert_eq!(issues[1]["key"], "PROJ-2");
+        assert_eq!(issues[1]["labels"], serde_json::json!(["public", "bug"]));
+        assert!(issues[1]["issue_type"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_http_index_json_conditional_get() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let url = format!("http://{}/bugview/index.json", server.local_addr());
+        let client = reqwest::Client::new();
+
+        let resp = client.get(&url).send().await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let etag = resp
+            .headers()
+            .get("ETag")
+            .and_then(|v| v.to_str().ok())
+            .expect("ETag header")
+            .to_string();
+        let last_modified = resp
+            .headers()
+            .get("Last-Modified")
+            .and_then(|v| v.to_str().ok())
+            .expect("Last-Modified header")
+            .to_string();
+        // Newest "updated" among the mock issues, converted to GMT
+        assert_eq!(last_modified, "Wed, 04 Oct 2023 04:00:00 GMT");
+
+        // Matching ETag yields 304 with no body
+        let resp = client
+            .get(&url)
+            .header("If-None-Match", &etag)
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(
+            resp.headers().get("ETag").and_then(|v| v.to_str().ok()),
+            Some(etag.as_str())
+        );
+        assert!(resp.bytes().await.expect("body").is_empty());
+
+        // Matching Last-Modified also yields 304
+        let resp = client
+            .get(&url)
+            .header("If-Modified-Since", &last_modified)
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+
+        // Stale ETag yields the full response
+        let resp = client
+            .get(&url)
+            .header("If-None-Match", "\"stale\"")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.text().await.expect("body").contains("PROJ-1"));
+    }
+
+    #[tokio::test]
+    async fn test_http_issue_json_conditional_get() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let client = reqwest::Client::new();
+
+        for path in ["json", "fulljson"] {
+            let url = format!("http://{}/bugview/{}/PROJ-1", server.local_addr(), path);
+
+            let resp = client.get(&url).send().await.expect("request");
+            assert_eq!(resp.status(), StatusCode::OK);
+            let etag = resp
+                .headers()
+                .get("ETag")
+                .and_then(|v| v.to_str().ok())
+                .expect("ETag header")
+                .to_string();
+
+            let resp = client
+                .get(&url)
+                .header("If-None-Match", &etag)
+                .send()
+                .await
+                .expect("request");
+            assert_eq!(
+                resp.status(),
+                StatusCode::NOT_MODIFIED,
+                "{} should honor If-None-Match",
+                path
+            );
+        }
+    }
 
     #[tokio::test]
     async fn test_http_index_html_with_mock_server() {
@@ -1020,6 +1994,122 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
     }
 
+    // Build a test ApiContext where project PROJ has labels of its own
+    fn project_test_context() -> ApiContext {
+        let ctx = test_context();
+        update_config(&ctx, |config| {
+            config.projects = vec![ProjectConfig {
+                key: "PROJ".to_string(),
+                default_label: "proj-public".to_string(),
+                allowed_labels: vec!["kernel".to_string()],
+            }]
+        });
+        ctx
+    }
+
+    #[test]
+    fn test_project_configs_from_env() {
+        let env: std::collections::HashMap<&str, &str> = [
+            ("BUGVIEW_PROJECTS", "OS, TRITON,"),
+            ("BUGVIEW_PROJECT_OS_LABEL", "os-public"),
+            ("BUGVIEW_PROJECT_OS_ALLOWED_LABELS", "kernel,zfs"),
+        ]
+        .into_iter()
+        .collect();
+        let var = |name: &str| env.get(name).map(|v| v.to_string());
+
+        let projects = project_configs(var, "public").expect("valid config");
+        assert_eq!(
+            projects,
+            vec![
+                ProjectConfig {
+                    key: "OS".to_string(),
+                    default_label: "os-public".to_string(),
+                    allowed_labels: vec!["kernel".to_string(), "zfs".to_string()],
+                },
+                ProjectConfig {
+                    key: "TRITON".to_string(),
+                    default_label: "public".to_string(),
+                    allowed_labels: vec![],
+                },
+            ]
+        );
+
+        assert!(project_configs(|_| None, "public").unwrap().is_empty());
+        for projects in ["os", "OS,OS", "OS) OR (labels"] {
+            let var = |name: &str| (name == "BUGVIEW_PROJECTS").then(|| projects.to_string());
+            assert!(project_configs(var, "public").is_err(), "{}", projects);
+        }
+    }
+
+    #[test]
+    fn test_project_labels() {
+        let config = project_test_context().config();
+
+        let key = jira_api::IssueKey::new("PROJ-1").expect("valid key");
+        assert_eq!(config.default_label_for(&key), "proj-public");
+        let key = jira_api::IssueKey::new("OTHER-1").expect("valid key");
+        assert_eq!(config.default_label_for(&key), "public");
+
+        assert!(config.is_public_label("kernel"));
+        assert!(config.is_public_label("proj-public"));
+        assert!(!config.is_public_label("internal"));
+        assert_eq!(config.allowed_labels_for(Some("PROJ")), ["kernel"]);
+        assert_eq!(config.allowed_labels_for(Some("OTHER")), ["public", "bug"]);
+        assert_eq!(config.allowed_labels_for(None), ["public", "bug"]);
+
+        assert_eq!(
+            config.index_scope(Some("PROJ"), Some("kernel")),
+            IssueScope {
+                labels: vec!["proj-public".to_string(), "kernel".to_string()],
+                project: Some("PROJ".to_string()),
+                exclude_projects: vec![],
+                public_labels: vec!["proj-public".to_string(), "kernel".to_string()],
+            }
+        );
+        assert_eq!(
+            config.index_scope(None, None),
+            IssueScope {
+                labels: vec!["public".to_string()],
+                project: None,
+                exclude_projects: vec!["PROJ".to_string()],
+                public_labels: vec!["public".to_string(), "bug".to_string()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_project_index_html() {
+        let Some(server) = start_test_server(project_test_context()).await else {
+            return;
+        };
+        let addr = server.local_addr();
+
+        let url = format!("http://{}/bugview/project/PROJ/index.html", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.text().await.expect("body");
+        assert!(body.contains(r#"href="/bugview/project/PROJ/label/kernel""#));
+        assert!(body.contains(r#"href="/bugview/index.html""#));
+
+        // Labels are those of the project, if it has its own
+        let url = format!("http://{}/bugview/project/PROJ/label/kernel", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let url = format!("http://{}/bugview/project/PROJ/label/bug", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let url = format!("http://{}/bugview/project/OTHER/label/bug", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        for project in ["proj", "PROJ%22%20OR%20x"] {
+            let url = format!("http://{}/bugview/project/{}/index.html", addr, project);
+            let resp = reqwest::get(&url).await.expect("request");
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{}", project);
+        }
+    }
+
     #[tokio::test]
     async fn test_http_redirect_bugview_root() {
         let Some(server) = start_test_server(test_context()).await else {
@@ -1091,6 +2181,69 @@ mod tests {
         assert_eq!(csp_header.unwrap(), CSP_HEADER);
     }
 
+    #[tokio::test]
+    async fn test_http_static_assets() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let addr = server.local_addr();
+
+        let url = format!("http://{}/bugview/static/bugview.css", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let content_type = resp
+            .headers()
+            .get("Content-Type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+        assert!(content_type.starts_with("text/css"));
+        assert!(resp.headers().get("Cache-Control").is_some());
+        let body = resp.text().await.expect("body");
+        assert!(body.contains("data-theme"));
+
+        let url = format!("http://{}/bugview/static/bugview.js", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let url = format!("http://{}/bugview/static/missing.js", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_http_html_uses_theme_cookie() {
+        let Some(server) = start_test_server(test_context()).await else {
+            return;
+        };
+        let addr = server.local_addr();
+        let client = reqwest::Client::new();
+
+        let url = format!("http://{}/bugview/index.html", addr);
+        let body = client
+            .get(&url)
+            .header("Cookie", "other=1; bugview_theme=dark")
+            .send()
+            .await
+            .expect("request")
+            .text()
+            .await
+            .expect("body");
+        assert!(body.contains(r#"data-theme="dark""#));
+        assert!(body.contains("/bugview/static/bugview.css"));
+        assert!(!body.contains("cdn.jsdelivr.net"));
+
+        // No cookie: follow the browser preference
+        let body = client
+            .get(&url)
+            .send()
+            .await
+            .expect("request")
+            .text()
+            .await
+            .expect("body");
+        assert!(body.contains(r#"data-theme="auto""#));
+    }
+
     #[tokio::test]
     async fn test_http_non_public_issue_returns_404() {
         // Test the security boundary: issues without the public label should return 404
@@ -1182,6 +2335,234 @@ mod tests {
         );
     }
 
+    // Mock JIRA client serving three pages of one issue each, chained by
+    // JIRA-style tokens, and counting the searches it receives
+    #[derive(Clone, Default)]
+    struct PagedMockJiraClient {
+        searches: Arc<AtomicUsize>,
+        filters: Arc<std::sync::Mutex<Vec<SearchFilter>>>,
+    }
+
+    #[async_trait]
+    impl JiraClientTrait for PagedMockJiraClient {
+        async fn search_issues(
+            &self,
+            _labels: &[String],
+            filter: &SearchFilter,
+            page_token: Option<&str>,
+            _sort: &str,
+        ) -> anyhow::Result<SearchResponse> {
+            self.searches.fetch_add(1, Ordering::SeqCst);
+            self.filters.lock().unwrap().push(filter.clone());
+
+            let page = match page_token {
+                None => 1,
+                Some("jira-token-2") => 2,
+                Some("jira-token-3") => 3,
+                Some(other) => anyhow::bail!("unexpected token {}", other),
+            };
+
+            let mut fields = serde_json::Map::new();
+            fields.insert(
+                "summary".into(),
+                serde_json::json!(format!("Page {}", page)),
+            );
+            fields.insert("labels".into(), serde_json::json!(["public"]));
+
+            Ok(SearchResponse {
+                issues: vec![Issue {
+                    key: jira_api::IssueKey::new_unchecked(format!("PROJ-{}", page)),
+                    id: page.to_string(),
+                    fields: fields.into_iter().collect(),
+                    rendered_fields: None,
+                    changelog: None,
+                }],
+                is_last: Some(page == 3),
+                next_page_token: (page < 3).then(|| format!("jira-token-{}", page + 1)),
+            })
+        }
+
+        async fn get_issue(&self, key: &jira_api::IssueKey) -> anyhow::Result<Issue> {
+            anyhow::bail!("Issue not found: {}", key)
+        }
+
+        async fn get_remote_links(&self, _issue_id: &str) -> anyhow::Result<Vec<RemoteLink>> {
+            Ok(vec![])
+        }
+    }
+
+    fn paged_test_context(jira: PagedMockJiraClient) -> ApiContext {
+        ApiContext {
+            jira: Arc::new(jira) as Arc<dyn JiraClientTrait>,
+            ..test_context()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_issues_by_page_number() {
+        let jira = PagedMockJiraClient::default();
+        let ctx = paged_test_context(jira.clone());
+        let query = |page| IssueIndexQuery {
+            page: Some(page),
+            ..Default::default()
+        };
+        let tz = DisplayTimezone::default();
+
+        // Page 2 is not cached yet, so page 1 is fetched to find its token
+        let HttpResponseOk(response) = search_issues(
+            ctx.jira.as_ref(),
+            &ctx.token_cache,
+            IssueScope::default(),
+            query(2),
+            tz,
+        )
+        .await
+        .expect("page 2");
+        assert_eq!(response.issues[0].key.as_str(), "PROJ-2");
+        assert_eq!(response.page, Some(2));
+        assert_eq!(response.prev_page, Some(1));
+        assert_eq!(response.next_page, Some(3));
+        assert_eq!(jira.searches.load(Ordering::SeqCst), 2);
+
+        // Page 3's token was cached while serving page 2
+        let HttpResponseOk(response) = search_issues(
+            ctx.jira.as_ref(),
+            &ctx.token_cache,
+            IssueScope::default(),
+            query(3),
+            tz,
+        )
+        .await
+        .expect("page 3");
+        assert_eq!(response.issues[0].key.as_str(), "PROJ-3");
+        assert_eq!(response.prev_page, Some(2));
+        assert_eq!(response.next_page, None);
+        assert!(response.is_last);
+        assert_eq!(jira.searches.load(Ordering::SeqCst), 3);
+
+        // Past the end
+        let err = search_issues(
+            ctx.jira.as_ref(),
+            &ctx.token_cache,
+            IssueScope::default(),
+            query(4),
+            tz,
+        )
+        .await
+        .expect_err("page 4");
+        assert_eq!(err.status_code.as_u16(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_search_issues_filters() {
+        let jira = PagedMockJiraClient::default();
+        let ctx = paged_test_context(jira.clone());
+        let tz = DisplayTimezone::default();
+
+        let query = IssueIndexQuery {
+            status: Some(IssueStatusFilter::Resolved),
+            updated_since: Some("2025-01-31".to_string()),
+            project: Some("OS".to_string()),
+            ..Default::default()
+        };
+        search_issues(
+            ctx.jira.as_ref(),
+            &ctx.token_cache,
+            IssueScope::default(),
+            query,
+            tz,
+        )
+        .await
+        .expect("filtered search");
+        assert_eq!(
+            jira.filters.lock().unwrap()[0].jql_clauses(),
+            vec![
+                "resolution is not EMPTY",
+                "updated >= \"2025-01-31\"",
+                "project = \"OS\"",
+            ]
+        );
+
+        // Invalid values are rejected before anything is sent to JIRA
+        let invalid = [
+            (Some("2025-1-31"), None),
+            (Some("2025-02-30"), None),
+            (Some("yesterday"), None),
+            (None, Some("os")),
+            (None, Some("O")),
+            (None, Some("OS\" OR project = \"SECRET")),
+        ];
+        for (updated_since, project) in invalid {
+            let query = IssueIndexQuery {
+                updated_since: updated_since.map(String::from),
+                project: project.map(String::from),
+                ..Default::default()
+            };
+            let err = search_issues(
+                ctx.jira.as_ref(),
+                &ctx.token_cache,
+                IssueScope::default(),
+                query,
+                tz,
+            )
+            .await
+            .expect_err("invalid filter");
+            assert_eq!(err.status_code.as_u16(), 400);
+        }
+        assert_eq!(jira.searches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_http_index_json_filters() {
+        let Some(server) =
+            start_test_server(paged_test_context(PagedMockJiraClient::default())).await
+        else {
+            return;
+        };
+
+        let url = format!(
+            "http://{}/bugview/index.json?status=open&updated_since=2025-01-31&project=OS",
+            server.local_addr()
+        );
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let url = format!(
+            "http://{}/bugview/index.json?status=closed",
+            server.local_addr()
+        );
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_http_index_html_page_links() {
+        let Some(server) =
+            start_test_server(paged_test_context(PagedMockJiraClient::default())).await
+        else {
+            return;
+        };
+
+        let url = format!(
+            "http://{}/bugview/index.html?page=2&sort=created",
+            server.local_addr()
+        );
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.text().await.expect("body");
+        assert!(body.contains("PROJ-2"));
+        assert!(body.contains("/bugview/index.html?page=1&amp;sort=created"));
+        assert!(body.contains("/bugview/index.html?page=3&amp;sort=created"));
+
+        // An unreachable page falls back to the first page
+        let url = format!("http://{}/bugview/index.html?page=99", server.local_addr());
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.text().await.expect("body");
+        assert!(body.contains("PROJ-1"));
+        assert!(!body.contains("Previous Page"));
+    }
+
     // Mock JIRA client that returns "Issue not found" error
     #[derive(Clone, Default)]
     struct NotFoundMockJiraClient;
@@ -1191,6 +2572,7 @@ mod tests {
         async fn search_issues(
             &self,
             _labels: &[String],
+            _filter: &SearchFilter,
             _page_token: Option<&str>,
             _sort: &str,
         ) -> anyhow::Result<SearchResponse> {
@@ -1214,15 +2596,23 @@ mod tests {
         let config = Config {
             default_label: "public".to_string(),
             allowed_labels: vec!["public".to_string()],
+            projects: vec![],
             allowed_domains: vec![],
             public_base_url: "https://test.example.com".to_string(),
+            stats_token: None,
+            admin_token: None,
+            display_timezone: DisplayTimezone::default(),
         };
 
         ApiContext {
             jira: Arc::new(NotFoundMockJiraClient) as Arc<dyn JiraClientTrait>,
-            config,
+            config: Arc::new(ArcSwap::from_pointee(config)),
+            config_file: None,
             html: HtmlRenderer::new(),
             token_cache: TokenCache::new(),
+            issue_cache: Arc::new(IssueCache::default()),
+            index_cache: None,
+            stats: Arc::new(AccessStats::new(None)),
         }
     }
 
@@ -1262,6 +2652,88 @@ mod tests {
         );
     }
 
+    // Mock JIRA client for which every issue is missing except a few public
+    // neighbours of PROJ-10 that come back from searches
+    #[derive(Clone, Default)]
+    struct NearbyMockJiraClient;
+
+    #[async_trait]
+    impl JiraClientTrait for NearbyMockJiraClient {
+        async fn search_issues(
+            &self,
+            _labels: &[String],
+            filter: &SearchFilter,
+            _page_token: Option<&str>,
+            _sort: &str,
+        ) -> anyhow::Result<SearchResponse> {
+            assert_eq!(filter.project.as_deref(), Some("PROJ"));
+            let issues = ["PROJ-9", "PROJ-12"]
+                .into_iter()
+                .map(|key| Issue {
+                    key: jira_api::IssueKey::new_unchecked(key),
+                    id: "1".into(),
+                    fields: [
+                        (
+                            "summary".to_string(),
+                            serde_json::json!(format!("Neighbour {}", key)),
+                        ),
+                        ("labels".to_string(), serde_json::json!(["public"])),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    rendered_fields: None,
+                    changelog: None,
+                })
+                .collect();
+            Ok(SearchResponse {
+                issues,
+                is_last: Some(true),
+                next_page_token: None,
+            })
+        }
+
+        async fn get_issue(&self, key: &jira_api::IssueKey) -> anyhow::Result<Issue> {
+            anyhow::bail!("Issue not found: {}", key)
+        }
+
+        async fn get_remote_links(&self, _issue_id: &str) -> anyhow::Result<Vec<RemoteLink>> {
+            Ok(vec![])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_issue_not_found_suggests_related_issues() {
+        let mut ctx = not_found_test_context();
+        ctx.jira = Arc::new(NearbyMockJiraClient) as Arc<dyn JiraClientTrait>;
+        let Some(server) = start_test_server(ctx).await else {
+            return;
+        };
+        let addr = server.local_addr();
+
+        let url = format!("http://{}/bugview/issue/PROJ-10", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body = resp.text().await.expect("body");
+        assert!(body.contains("Related public issues"));
+        let nearest = body
+            .find(r#"href="/bugview/issue/PROJ-9""#)
+            .expect("PROJ-9");
+        let next = body
+            .find(r#"href="/bugview/issue/PROJ-12""#)
+            .expect("PROJ-12");
+        assert!(nearest < next, "closest key should be listed first");
+        assert!(body.contains("Neighbour PROJ-9"));
+
+        let url = format!("http://{}/bugview/json/PROJ-10", addr);
+        let resp = reqwest::get(&url).await.expect("request");
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body: serde_json::Value = resp.json().await.expect("json body");
+        assert_eq!(
+            body["message"],
+            "Issue PROJ-10 not found; related public issues: PROJ-9, PROJ-12"
+        );
+    }
+
     #[test]
     fn test_strip_restricted_comments_removes_visibility() {
         use crate::search::strip_restricted_comments;
@@ -9,97 +9,402 @@
 //! This module contains functions for searching JIRA issues, converting
 //! between JIRA and bugview API types, and filtering remote links.
 
-use bugview_api::{IssueListItem, IssueListQuery, IssueListResponse, IssueSort};
+use bugview_api::{
+    CacheValidators, IssueComment, IssueDetailsV2, IssueFieldChange, IssueHistoryEntry,
+    IssueIndexQuery, IssueListItem, IssueListQuery, IssueListResponse, IssueSort, RemoteLink,
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use dropshot::{HttpError, HttpResponseOk};
+use serde::Serialize;
 
 use crate::Config;
-use crate::jira_client::JiraClientTrait;
+use crate::html;
+use crate::jira_client::{JiraClientTrait, SearchFilter};
+use crate::timefmt::DisplayTimezone;
 use crate::token_cache::TokenCache;
 
+/// Maximum number of JIRA pages fetched to reach a numbered page whose token
+/// is no longer cached (e.g. a bookmark older than the token TTL).
+const MAX_PAGE_WALK: u32 = 20;
+
+/// Changelog fields that may be shown publicly. Everything else (assignee,
+/// description edits, custom fields, ...) is left out of issue history.
+const PUBLIC_HISTORY_FIELDS: &[&str] = &["status", "resolution", "labels"];
+
+/// Which public issues an index lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueScope {
+    /// Labels every listed issue must have
+    pub labels: Vec<String>,
+    /// Only issues from this project key (already validated)
+    pub project: Option<String>,
+    /// Leave out issues from these project keys (already validated)
+    pub exclude_projects: Vec<String>,
+    /// Labels that may be shown on listed issues; any others are left out
+    pub public_labels: Vec<String>,
+}
+
+impl IssueScope {
+    /// Restrict `filter` to this scope. A project filter already on
+    /// `filter` is kept.
+    pub(crate) fn restrict(&self, mut filter: SearchFilter) -> SearchFilter {
+        if filter.project.is_none() {
+            filter.project = self.project.clone();
+        }
+        filter.exclude_projects = self.exclude_projects.clone();
+        filter
+    }
+}
+
+/// One page of the issue index, along with the state needed to link to its
+/// neighbours.
+#[derive(Debug)]
+pub struct IssuePage {
+    pub issues: Vec<IssueListItem>,
+    /// Short ID for the next page's JIRA token (None on the last page)
+    pub next_page_token: Option<String>,
+    pub is_last: bool,
+    pub sort: IssueSort,
+    /// 1-based page number, or None when the page was requested by token
+    pub page: Option<u32>,
+}
+
+impl IssuePage {
+    /// Number of the previous page, if there is one.
+    pub fn prev_page(&self) -> Option<u32> {
+        self.page.and_then(|p| p.checked_sub(1)).filter(|&p| p > 0)
+    }
+
+    /// Number of the next page, if there is one.
+    pub fn next_page(&self) -> Option<u32> {
+        self.page
+            .filter(|_| !self.is_last)
+            .and_then(|p| p.checked_add(1))
+    }
+}
+
 /// Helper function to fetch issues for HTML rendering.
 ///
-/// This variant gracefully falls back to the first page on invalid/expired tokens,
-/// which provides a better UX for HTML pages where users might have stale bookmarks.
+/// This variant gracefully falls back to the first page on invalid/expired tokens
+/// and unreachable page numbers, which provides a better UX for HTML pages where
+/// users might have stale bookmarks.
 pub async fn fetch_issues_for_html(
+    jira: &dyn JiraClientTrait,
+    token_cache: &TokenCache,
+    scope: IssueScope,
+    query: IssueListQuery,
+    timezone: DisplayTimezone,
+) -> Result<IssuePage, HttpError> {
+    let filter = scope.restrict(SearchFilter::default());
+    fetch_issue_page(
+        jira,
+        token_cache,
+        scope.labels,
+        &scope.public_labels,
+        &filter,
+        query,
+        timezone,
+        false,
+    )
+    .await
+}
+
+/// Helper function to search issues for JSON API responses.
+///
+/// This variant returns an error for invalid filters, invalid/expired tokens
+/// and unreachable page numbers, which is the correct behavior for
+/// programmatic API clients that should handle errors.
+pub async fn search_issues(
+    jira: &dyn JiraClientTrait,
+    token_cache: &TokenCache,
+    scope: IssueScope,
+    query: IssueIndexQuery,
+    timezone: DisplayTimezone,
+) -> Result<HttpResponseOk<IssueListResponse>, HttpError> {
+    let filter = scope.restrict(search_filter(&query)?);
+    let page = fetch_issue_page(
+        jira,
+        token_cache,
+        scope.labels,
+        &scope.public_labels,
+        &filter,
+        query.list_query(),
+        timezone,
+        true,
+    )
+    .await?;
+
+    // Use constructor to ensure is_last and next_page_token are consistent
+    Ok(HttpResponseOk(
+        IssueListResponse::new(page.issues, page.next_page_token).with_page(page.page),
+    ))
+}
+
+/// Validate the filter parameters of an index query.
+///
+/// `updated_since` must be a calendar date (`YYYY-MM-DD`) and `project` a
+/// JIRA project key; anything else is a 400 error. The project key is
+/// interpolated into JQL, so it must never be passed through unchecked.
+pub fn search_filter(query: &IssueIndexQuery) -> Result<SearchFilter, HttpError> {
+    let updated_since = query
+        .updated_since
+        .as_deref()
+        .map(|date| {
+            // NaiveDate's parser tolerates unpadded fields; require the
+            // canonical form so equivalent queries share cached page tokens
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .filter(|parsed| parsed.format("%Y-%m-%d").to_string() == date)
+                .ok_or_else(|| {
+                    HttpError::for_bad_request(
+                        None,
+                        format!("Invalid updated_since {:?}: expected YYYY-MM-DD", date),
+                    )
+                })
+        })
+        .transpose()?;
+
+    let project = query
+        .project
+        .as_deref()
+        .map(|project| {
+            if is_project_key(project) {
+                Ok(project.to_string())
+            } else {
+                Err(HttpError::for_bad_request(
+                    None,
+                    format!(
+                        "Invalid project {:?}: expected a project key such as OS",
+                        project
+                    ),
+                ))
+            }
+        })
+        .transpose()?;
+
+    Ok(SearchFilter {
+        status: query.status,
+        updated_since,
+        project,
+        key_numbers: None,
+        exclude_projects: Vec::new(),
+    })
+}
+
+/// Whether `s` looks like a JIRA project key: an uppercase ASCII letter
+/// followed by at least one uppercase letter, digit or underscore.
+pub(crate) fn is_project_key(s: &str) -> bool {
+    let mut chars = s.chars();
+    s.len() >= 2
+        && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Fetch one page of the index, addressed either by short token ID or by
+/// page number.
+///
+/// When `strict` is false, an unknown token or unreachable page number falls
+/// back to the first page; otherwise it is a 400 error. Timestamps are
+/// formatted for display in `timezone`, and only `public_labels` are shown
+/// on the issues.
+#[allow(clippy::too_many_arguments)]
+async fn fetch_issue_page(
     jira: &dyn JiraClientTrait,
     token_cache: &TokenCache,
     labels: Vec<String>,
+    public_labels: &[String],
+    filter: &SearchFilter,
     query: IssueListQuery,
-) -> Result<(Vec<IssueListItem>, Option<String>, bool, IssueSort), HttpError> {
+    timezone: DisplayTimezone,
+    strict: bool,
+) -> Result<IssuePage, HttpError> {
     let sort = query.sort.unwrap_or_default();
+    let page_query = page_query_key(&labels, filter, sort);
 
-    // Resolve the short token ID to the real JIRA token
-    // For HTML endpoints, we gracefully fall back to first page on bad tokens
-    let jira_token = if let Some(short_id) = &query.next_page_token {
-        token_cache.get(short_id)
-    } else {
-        None
+    // Resolve the short token ID or page number to the real JIRA token
+    let (jira_token, page) = match (&query.next_page_token, query.page) {
+        (Some(short_id), _) => match token_cache.get(short_id) {
+            Some(jira_token) => (Some(jira_token), None),
+            None if strict => {
+                return Err(HttpError::for_bad_request(
+                    None,
+                    "Invalid or expired pagination token".to_string(),
+                ));
+            }
+            None => (None, Some(1)),
+        },
+        (None, None | Some(1)) => (None, Some(1)),
+        (None, Some(page)) => {
+            match page_token(jira, token_cache, &labels, filter, sort, &page_query, page).await? {
+                Some(jira_token) => (Some(jira_token), Some(page)),
+                None if strict => {
+                    return Err(HttpError::for_bad_request(
+                        None,
+                        format!("Page {} does not exist", page),
+                    ));
+                }
+                None => (None, Some(1)),
+            }
+        }
     };
 
     let search_result = jira
-        .search_issues(&labels, jira_token.as_deref(), sort.as_str())
+        .search_issues(&labels, filter, jira_token.as_deref(), sort.as_str())
         .await
         .map_err(|e| HttpError::for_internal_error(format!("Failed to search issues: {}", e)))?;
 
+    let now = Utc::now();
     let issues: Vec<IssueListItem> = search_result
         .issues
         .into_iter()
-        .map(convert_to_list_item)
+        .map(|issue| convert_to_list_item(issue, public_labels, timezone, now))
         .collect();
 
     // Extract pagination info from JIRA response
     let is_last = search_result.is_last.unwrap_or(false);
 
+    // Remember the token for the following page number so the "next" link
+    // resolves without walking
+    if let (Some(next), Some(jira_token)) = (
+        page.and_then(|p| p.checked_add(1)),
+        &search_result.next_page_token,
+    ) {
+        token_cache.store_page(&page_query, next, jira_token.clone());
+    }
+
     // Store JIRA's token in cache and return short ID instead
     let next_page_token = search_result
         .next_page_token
         .map(|jira_token| token_cache.store(jira_token));
 
-    Ok((issues, next_page_token, is_last, sort))
+    Ok(IssuePage {
+        issues,
+        next_page_token,
+        is_last,
+        sort,
+        page,
+    })
 }
 
-/// Helper function to search issues for JSON API responses.
+/// Identify the query whose page tokens are cached: the same page number
+/// means different things for different labels, filters and sort orders.
+pub(crate) fn page_query_key(labels: &[String], filter: &SearchFilter, sort: IssueSort) -> String {
+    format!(
+        "{}:{}:{}",
+        sort.as_str(),
+        labels.join(","),
+        filter.jql_clauses().join(" AND ")
+    )
+}
+
+/// Find the JIRA token for page `page` (2 or later).
 ///
-/// This variant returns an error for invalid/expired tokens, which is the
-/// correct behavior for programmatic API clients that should handle errors.
-pub async fn search_issues(
+/// If the token is not cached, walk forward from the nearest earlier page
+/// that is, caching each token on the way. Returns `None` if the page is
+/// past the end of the results or more than [`MAX_PAGE_WALK`] pages beyond
+/// anything cached.
+async fn page_token(
     jira: &dyn JiraClientTrait,
     token_cache: &TokenCache,
-    labels: Vec<String>,
-    query: IssueListQuery,
-) -> Result<HttpResponseOk<IssueListResponse>, HttpError> {
-    let sort = query.sort.unwrap_or_default();
+    labels: &[String],
+    filter: &SearchFilter,
+    sort: IssueSort,
+    page_query: &str,
+    page: u32,
+) -> Result<Option<String>, HttpError> {
+    if let Some(jira_token) = token_cache.get_page(page_query, page) {
+        return Ok(Some(jira_token));
+    }
 
-    // Resolve the short token ID to the real JIRA token
-    let jira_token = if let Some(short_id) = &query.next_page_token {
-        Some(token_cache.get(short_id).ok_or_else(|| {
-            HttpError::for_bad_request(None, "Invalid or expired pagination token".to_string())
-        })?)
-    } else {
-        None
+    let floor = page.saturating_sub(MAX_PAGE_WALK).max(1);
+    let start = (floor + 1..page).rev().find_map(|p| {
+        token_cache
+            .get_page(page_query, p)
+            .map(|jira_token| (p, Some(jira_token)))
+    });
+    let (mut current, mut jira_token) = match start {
+        Some(start) => start,
+        None if floor == 1 && page > 1 => (1, None),
+        None => return Ok(None),
     };
 
-    let search_result = jira
-        .search_issues(&labels, jira_token.as_deref(), sort.as_str())
-        .await
-        .map_err(|e| HttpError::for_internal_error(format!("Failed to search issues: {}", e)))?;
+    while current < page {
+        let search_result = jira
+            .search_issues(labels, filter, jira_token.as_deref(), sort.as_str())
+            .await
+            .map_err(|e| {
+                HttpError::for_internal_error(format!("Failed to search issues: {}", e))
+            })?;
 
-    let issues: Vec<IssueListItem> = search_result
+        let Some(next_token) = search_result.next_page_token else {
+            return Ok(None);
+        };
+        current += 1;
+        token_cache.store_page(page_query, current, next_token.clone());
+        jira_token = Some(next_token);
+    }
+
+    Ok(jira_token)
+}
+
+/// Parse a JIRA timestamp such as `2023-10-04T10:27:22.826-0400`.
+pub fn parse_jira_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Compute cache validators for a page of the issue index.
+///
+/// The pagination token is deliberately excluded from the ETag: a fresh
+/// short ID is minted for every request, so including it would make every
+/// response look modified. Likewise the display timestamps are left out,
+/// since their relative text changes as time passes even though the issues
+/// do not. `Last-Modified` is the newest `updated` time on the page.
+pub fn index_validators(response: &IssueListResponse) -> Result<CacheValidators, HttpError> {
+    let last_modified = response
         .issues
-        .into_iter()
-        .map(convert_to_list_item)
+        .iter()
+        .filter_map(|issue| parse_jira_timestamp(&issue.updated))
+        .max();
+
+    let issues: Vec<_> = response
+        .issues
+        .iter()
+        .map(|issue| {
+            (
+                &issue.key,
+                &issue.summary,
+                &issue.status,
+                &issue.resolution,
+                &issue.updated,
+                &issue.created,
+                &issue.labels,
+                &issue.issue_type,
+                &issue.priority,
+            )
+        })
         .collect();
 
-    // Store JIRA's token in cache and return short ID instead
-    let next_page_token = search_result
-        .next_page_token
-        .map(|jira_token| token_cache.store(jira_token));
+    CacheValidators::from_content(&(&issues, response.is_last), last_modified)
+        .map_err(|e| HttpError::for_internal_error(format!("Failed to compute ETag: {}", e)))
+}
 
-    // Use constructor to ensure is_last and next_page_token are consistent
-    Ok(HttpResponseOk(IssueListResponse::new(
-        issues,
-        next_page_token,
-    )))
+/// Compute cache validators for a single-issue response.
+///
+/// The raw JIRA fields are not hashed directly since their serialized
+/// ordering is not guaranteed to be stable; instead `content` should hold the
+/// parts of the response that can change without JIRA bumping `updated`
+/// (e.g. filtered remote links).
+pub fn issue_validators<C: Serialize + ?Sized>(
+    issue: &jira_api::Issue,
+    content: &C,
+) -> Result<CacheValidators, HttpError> {
+    let updated = issue.fields.get("updated").and_then(|v| v.as_str());
+    let last_modified = updated.and_then(parse_jira_timestamp);
+
+    CacheValidators::from_content(&(&issue.key, updated, content), last_modified)
+        .map_err(|e| HttpError::for_internal_error(format!("Failed to compute ETag: {}", e)))
 }
 
 /// Check if an issue has the required public label.
@@ -113,7 +418,15 @@ pub fn issue_has_public_label(issue: &jira_api::Issue, required_label: &str) ->
 }
 
 /// Convert a full JIRA issue to a list item for the index.
-pub fn convert_to_list_item(issue: jira_api::Issue) -> IssueListItem {
+///
+/// Only the labels in `public_labels` are kept. Timestamps are formatted
+/// for display in `timezone`, relative to `now`.
+pub fn convert_to_list_item(
+    issue: jira_api::Issue,
+    public_labels: &[String],
+    timezone: DisplayTimezone,
+    now: DateTime<Utc>,
+) -> IssueListItem {
     let summary = issue
         .fields
         .get("summary")
@@ -162,13 +475,104 @@ pub fn convert_to_list_item(issue: jira_api::Issue) -> IssueListItem {
         .unwrap_or("")
         .to_string();
 
+    let labels = issue
+        .fields
+        .get("labels")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|l| l.as_str())
+        .filter(|l| public_labels.iter().any(|p| p == l))
+        .map(|l| l.to_string())
+        .collect();
+
+    let field_name = |name: &str| {
+        issue
+            .fields
+            .get(name)
+            .and_then(|v| v.get("name"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+    let issue_type = field_name("issuetype");
+    let priority = field_name("priority");
+
     IssueListItem {
         key: issue.key,
         summary,
         status,
         resolution,
+        updated_display: timezone.format_jira(&updated, now),
+        created_display: timezone.format_jira(&created, now),
         updated,
         created,
+        labels,
+        issue_type,
+        priority,
+    }
+}
+
+/// Convert a public JIRA issue to the version 2 full issue format.
+///
+/// `links` and `history` must already be filtered for public display.
+/// Restricted comments are left out, and only public labels are reported.
+pub fn convert_to_details_v2(
+    issue: &jira_api::Issue,
+    config: &Config,
+    links: Vec<RemoteLink>,
+    history: Vec<IssueHistoryEntry>,
+) -> IssueDetailsV2 {
+    let field_str = |name: &str| {
+        issue
+            .fields
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+    let field_name = |name: &str| {
+        issue
+            .fields
+            .get(name)
+            .and_then(|v| v.get("name"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+
+    let fix_versions = issue
+        .fields
+        .get("fixVersions")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.get("name").and_then(|n| n.as_str()))
+        .map(|n| n.to_string())
+        .collect();
+
+    let comments = html::issue_comments(issue)
+        .into_iter()
+        .map(|comment| IssueComment {
+            author: comment.author,
+            created: comment.created,
+            edited: comment.edited,
+            body_html: comment.body,
+        })
+        .collect();
+
+    IssueDetailsV2 {
+        version: 2,
+        id: issue.id.clone(),
+        key: issue.key.clone(),
+        summary: field_str("summary").unwrap_or_else(|| "(No summary)".to_string()),
+        description_html: html::description_html(issue),
+        status: field_name("status").unwrap_or_else(|| "Unknown".to_string()),
+        resolution: field_name("resolution"),
+        labels: config.public_labels(issue),
+        fix_versions,
+        created: field_str("created").unwrap_or_default(),
+        updated: field_str("updated").unwrap_or_default(),
+        links,
+        comments,
+        history,
     }
 }
 
@@ -217,6 +621,76 @@ pub fn strip_restricted_comments(
     }
 }
 
+/// Build the public change history of an issue from its JIRA changelog.
+///
+/// # Security
+///
+/// Only changes to [`PUBLIC_HISTORY_FIELDS`] are kept, and label changes are
+/// reduced to the labels that are themselves public (the default label and
+/// the allowed labels) so that internal labels are never revealed. History
+/// entries with nothing left to show are dropped. JIRA's order (oldest
+/// first) is preserved.
+pub fn public_history(
+    issue: &jira_api::Issue,
+    config: &Config,
+    now: DateTime<Utc>,
+) -> Vec<IssueHistoryEntry> {
+    let Some(changelog) = &issue.changelog else {
+        return Vec::new();
+    };
+
+    changelog
+        .histories
+        .iter()
+        .filter_map(|history| {
+            let changes: Vec<IssueFieldChange> = history
+                .items
+                .iter()
+                .filter(|item| item.fieldtype.as_deref() != Some("custom"))
+                .filter(|item| PUBLIC_HISTORY_FIELDS.contains(&item.field.as_str()))
+                .filter_map(|item| {
+                    if item.field == "labels" {
+                        let from = public_label_list(item.from_string.as_deref(), config);
+                        let to = public_label_list(item.to_string.as_deref(), config);
+                        (from != to).then(|| IssueFieldChange {
+                            field: item.field.clone(),
+                            from,
+                            to,
+                        })
+                    } else {
+                        Some(IssueFieldChange {
+                            field: item.field.clone(),
+                            from: item.from_string.clone(),
+                            to: item.to_string.clone(),
+                        })
+                    }
+                })
+                .collect();
+
+            if changes.is_empty() {
+                return None;
+            }
+
+            Some(IssueHistoryEntry {
+                created_display: config.display_timezone.format_jira(&history.created, now),
+                created: history.created.clone(),
+                changes,
+            })
+        })
+        .collect()
+}
+
+/// The public labels in a space-separated JIRA changelog label value, or
+/// None if there are none.
+fn public_label_list(labels: Option<&str>, config: &Config) -> Option<String> {
+    let public: Vec<&str> = labels
+        .unwrap_or("")
+        .split_whitespace()
+        .filter(|l| config.is_public_label(l))
+        .collect();
+    (!public.is_empty()).then(|| public.join(" "))
+}
+
 /// Filter remote links by allowed domains and safe URL schemes.
 ///
 /// # Security
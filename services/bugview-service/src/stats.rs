@@ -0,0 +1,339 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Per-issue and per-label access statistics.
+//!
+//! Issue views are counted in hourly buckets held in memory. When a stats
+//! file is configured, the buckets are written to it periodically (and loaded
+//! from it at startup) so counts survive restarts. The file is a small JSON
+//! document; with hourly buckets and a 30-day retention it stays bounded by
+//! the number of distinct issues viewed.
+//!
+//! Labels are counted per view of an issue carrying them, and only labels
+//! that are already public (the default label and `JIRA_ALLOWED_LABELS`) are
+//! recorded, so the statistics never reveal internal labels.
+
+use anyhow::{Context, Result};
+use bugview_api::{IssueCacheStats, IssueViews, LabelViews, StatsResponse, StatsWindow};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+/// Length of a counting bucket, in seconds.
+const BUCKET_SECS: i64 = 60 * 60;
+
+/// How long buckets are kept. Matches the longest reported window.
+const RETENTION_HOURS: i64 = 30 * 24;
+
+/// Reported windows: name and length in hours, shortest first.
+const WINDOWS: &[(&str, i64)] = &[("24h", 24), ("7d", 7 * 24), ("30d", 30 * 24)];
+
+/// Default number of issues and labels listed per window.
+pub(crate) const DEFAULT_STATS_LIMIT: u32 = 20;
+
+/// Default interval between flushes of the stats file (seconds).
+pub(crate) const DEFAULT_FLUSH_INTERVAL_SECS: u64 = 60;
+
+/// View counts for one hour.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Bucket {
+    issues: HashMap<String, u64>,
+    labels: HashMap<String, u64>,
+}
+
+/// Persisted form of the statistics, keyed by bucket start (Unix seconds).
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StatsStore {
+    buckets: BTreeMap<i64, Bucket>,
+}
+
+struct StatsState {
+    store: StatsStore,
+    /// True when there are views not yet written to the stats file.
+    dirty: bool,
+}
+
+/// In-process access counter, optionally backed by a stats file.
+pub(crate) struct AccessStats {
+    state: Mutex<StatsState>,
+    path: Option<PathBuf>,
+}
+
+impl AccessStats {
+    /// Create an access counter.
+    ///
+    /// If `path` names an existing stats file its counts are loaded; an
+    /// unreadable file is logged and replaced on the next flush.
+    pub(crate) fn new(path: Option<PathBuf>) -> Self {
+        let store = path
+            .as_ref()
+            .and_then(|p| match std::fs::read(p) {
+                Ok(bytes) => serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        tracing::warn!(
+                            path = %p.display(),
+                            error = %e,
+                            "Ignoring unreadable stats file"
+                        );
+                    })
+                    .ok(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    tracing::warn!(path = %p.display(), error = %e, "Failed to read stats file");
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            state: Mutex::new(StatsState {
+                store,
+                dirty: false,
+            }),
+            path,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StatsState> {
+        self.state.lock().unwrap_or_else(|poisoned| {
+            tracing::error!("Stats mutex was poisoned, recovering");
+            poisoned.into_inner()
+        })
+    }
+
+    /// Record a view of `key`, counting it against each of `labels`.
+    pub(crate) fn record_issue_view(&self, key: &str, labels: &[String]) {
+        self.record_at(Utc::now(), key, labels);
+    }
+
+    fn record_at(&self, now: DateTime<Utc>, key: &str, labels: &[String]) {
+        let mut state = self.lock();
+        let bucket = state.store.buckets.entry(bucket_start(now)).or_default();
+
+        *bucket.issues.entry(key.to_string()).or_default() += 1;
+        for label in labels {
+            *bucket.labels.entry(label.clone()).or_default() += 1;
+        }
+        state.dirty = true;
+    }
+
+    /// Summarize views over each reported window, listing at most `limit`
+    /// issues and labels per window, alongside the issue cache counters.
+    pub(crate) fn summary(&self, limit: u32, cache: IssueCacheStats) -> StatsResponse {
+        self.summary_at(Utc::now(), limit, cache)
+    }
+
+    fn summary_at(&self, now: DateTime<Utc>, limit: u32, cache: IssueCacheStats) -> StatsResponse {
+        let state = self.lock();
+        let current = bucket_start(now);
+
+        let windows = WINDOWS
+            .iter()
+            .map(|&(name, hours)| {
+                let since = current - (hours - 1) * BUCKET_SECS;
+                let mut issues: HashMap<&str, u64> = HashMap::new();
+                let mut labels: HashMap<&str, u64> = HashMap::new();
+
+                for (_, bucket) in state.store.buckets.range(since..) {
+                    for (key, views) in &bucket.issues {
+                        *issues.entry(key.as_str()).or_default() += views;
+                    }
+                    for (label, views) in &bucket.labels {
+                        *labels.entry(label.as_str()).or_default() += views;
+                    }
+                }
+
+                StatsWindow {
+                    window: name.to_string(),
+                    since: format_time(since),
+                    total_views: issues.values().sum(),
+                    issues: top(issues, limit)
+                        .into_iter()
+                        .map(|(key, views)| IssueViews {
+                            key: key.to_string(),
+                            views,
+                        })
+                        .collect(),
+                    labels: top(labels, limit)
+                        .into_iter()
+                        .map(|(label, views)| LabelViews {
+                            label: label.to_string(),
+                            views,
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        StatsResponse {
+            generated: now.to_rfc3339(),
+            windows,
+            cache,
+        }
+    }
+
+    /// Drop expired buckets and, if there are unsaved views, write the
+    /// statistics to the stats file.
+    pub(crate) async fn flush(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let bytes = {
+            let mut state = self.lock();
+            let cutoff = bucket_start(Utc::now()) - (RETENTION_HOURS - 1) * BUCKET_SECS;
+            state.store.buckets = state.store.buckets.split_off(&cutoff);
+
+            if !state.dirty {
+                return Ok(());
+            }
+            state.dirty = false;
+            serde_json::to_vec(&state.store).context("Failed to serialize stats")?
+        };
+
+        // Write to a temporary file and rename it into place so a crash
+        // mid-write never leaves a truncated stats file behind.
+        let tmp = path.with_extension("tmp");
+        let result = async {
+            tokio::fs::write(&tmp, &bytes)
+                .await
+                .with_context(|| format!("Failed to write {}", tmp.display()))?;
+            tokio::fs::rename(&tmp, path)
+                .await
+                .with_context(|| format!("Failed to rename {} into place", tmp.display()))
+        }
+        .await;
+
+        if result.is_err() {
+            // Try again on the next flush.
+            self.lock().dirty = true;
+        }
+        result
+    }
+}
+
+/// Start of the bucket containing `time`, in Unix seconds.
+fn bucket_start(time: DateTime<Utc>) -> i64 {
+    time.timestamp().div_euclid(BUCKET_SECS) * BUCKET_SECS
+}
+
+fn format_time(secs: i64) -> String {
+    Utc.timestamp_opt(secs, 0)
+        .single()
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// The `limit` entries with the most views, ties broken by name.
+fn top(counts: HashMap<&str, u64>, limit: u32) -> Vec<(&str, u64)> {
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    counts.truncate(limit as usize);
+    counts
+}
+
+/// Periodically flush `stats` until the process exits.
+pub(crate) fn spawn_flusher(stats: std::sync::Arc<AccessStats>, interval: std::time::Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately; there is nothing to flush yet.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            if let Err(e) = stats.flush().await {
+                tracing::error!(error = %e, "Failed to flush access statistics");
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn time(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s)
+            .expect("valid time")
+            .with_timezone(&Utc)
+    }
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_summary_windows() {
+        let stats = AccessStats::new(None);
+        let now = time("2026-03-31T12:30:00Z");
+
+        stats.record_at(now, "OS-1", &labels(&["public", "smartos"]));
+        stats.record_at(now, "OS-1", &labels(&["public", "smartos"]));
+        stats.record_at(now, "OS-2", &labels(&["public"]));
+        // Two days ago: outside 24h, inside 7d.
+        stats.record_at(now - Duration::days(2), "OS-2", &labels(&["public"]));
+        stats.record_at(now - Duration::days(2), "OS-2", &labels(&["public"]));
+        // Ten days ago: only inside 30d.
+        stats.record_at(now - Duration::days(10), "OS-3", &labels(&["public"]));
+
+        let summary = stats.summary_at(now, 10, IssueCacheStats::default());
+        let windows: Vec<&str> = summary.windows.iter().map(|w| w.window.as_str()).collect();
+        assert_eq!(windows, vec!["24h", "7d", "30d"]);
+
+        let day = &summary.windows[0];
+        assert_eq!(day.since, "2026-03-30T13:00:00+00:00");
+        assert_eq!(day.total_views, 3);
+        assert_eq!(day.issues[0].key, "OS-1");
+        assert_eq!(day.issues[0].views, 2);
+        assert_eq!(day.labels[0].label, "public");
+        assert_eq!(day.labels[0].views, 3);
+        assert_eq!(day.labels[1].label, "smartos");
+
+        let week = &summary.windows[1];
+        assert_eq!(week.total_views, 5);
+        assert_eq!(week.issues[0].key, "OS-2");
+        assert_eq!(week.issues[0].views, 3);
+
+        let month = &summary.windows[2];
+        assert_eq!(month.total_views, 6);
+        assert_eq!(month.issues.len(), 3);
+    }
+
+    #[test]
+    fn test_summary_limit() {
+        let stats = AccessStats::new(None);
+        let now = time("2026-03-31T12:30:00Z");
+        for key in ["OS-1", "OS-2", "OS-3"] {
+            stats.record_at(now, key, &[]);
+        }
+
+        let summary = stats.summary_at(now, 2, IssueCacheStats::default());
+        assert_eq!(summary.windows[0].issues.len(), 2);
+        assert_eq!(summary.windows[0].total_views, 3);
+        // Ties are listed by key.
+        assert_eq!(summary.windows[0].issues[0].key, "OS-1");
+    }
+
+    #[tokio::test]
+    async fn test_flush_and_reload() {
+        let dir = std::env::temp_dir().join(format!("bugview-stats-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("stats.json");
+
+        let stats = AccessStats::new(Some(path.clone()));
+        stats.record_issue_view("OS-1", &labels(&["public"]));
+        stats.flush().await.expect("flush");
+
+        let reloaded = AccessStats::new(Some(path));
+        let summary = reloaded.summary(DEFAULT_STATS_LIMIT, IssueCacheStats::default());
+        assert_eq!(summary.windows[0].total_views, 1);
+        assert_eq!(summary.windows[0].issues[0].key, "OS-1");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,226 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Related issue suggestions for error pages.
+//!
+//! When an issue does not exist or is not public, the 404 response lists a
+//! few public issues from the same project whose key numbers are close to
+//! the one requested, to help with mistyped or mis-remembered keys.
+//!
+//! Suggestions are found by key alone. The summary of a non-public issue is
+//! deliberately not used to look for similar issues, since which public
+//! issues came back would reveal something about its text.
+
+use bugview_api::IssueSort;
+use jira_api::IssueKey;
+
+use crate::jira_client::{JiraClientTrait, SearchFilter};
+use crate::search::is_project_key;
+
+/// Maximum number of related issues shown on an error page
+pub const MAX_SUGGESTIONS: usize = 5;
+
+/// How far either side of the requested key number to look
+const KEY_NUMBER_RADIUS: u64 = 10;
+
+/// A public issue suggested in place of one that could not be shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelatedIssue {
+    pub key: String,
+    pub summary: String,
+}
+
+/// Find up to [`MAX_SUGGESTIONS`] public issues related to `key`, closest
+/// key number first.
+///
+/// Suggestions are a nicety, so a key that cannot be split into a project
+/// and number, or a failed JIRA search, just yields no suggestions.
+pub async fn related_issues(
+    jira: &dyn JiraClientTrait,
+    label: &str,
+    key: &IssueKey,
+) -> Vec<RelatedIssue> {
+    let Some((project, number)) = split_key(key.as_str()) else {
+        return Vec::new();
+    };
+
+    let filter = SearchFilter {
+        project: Some(project.to_string()),
+        key_numbers: Some(
+            number.saturating_sub(KEY_NUMBER_RADIUS).max(1)
+                ..=number.saturating_add(KEY_NUMBER_RADIUS),
+        ),
+        ..Default::default()
+    };
+    let labels = [label.to_string()];
+
+    let response = match jira
+        .search_issues(&labels, &filter, None, IssueSort::Key.as_str())
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!(issue_key = %key, error = %e, "Failed to search for related issues");
+            return Vec::new();
+        }
+    };
+
+    let mut related: Vec<(u64, u64, RelatedIssue)> = response
+        .issues
+        .into_iter()
+        .filter(|issue| issue.key.as_str() != key.as_str())
+        .filter_map(|issue| {
+            let (issue_project, issue_number) = split_key(issue.key.as_str())?;
+            let distance = issue_number.abs_diff(number);
+            if issue_project != project || distance > KEY_NUMBER_RADIUS {
+                return None;
+            }
+            let summary = issue
+                .fields
+                .get("summary")
+                .and_then(|v| v.as_str())
+                .unwrap_or("(No summary)")
+                .to_string();
+            Some((
+                distance,
+                issue_number,
+                RelatedIssue {
+                    key: issue.key.to_string(),
+                    summary,
+                },
+            ))
+        })
+        .collect();
+
+    related.sort_by_key(|&(distance, issue_number, _)| (distance, issue_number));
+    related
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, _, issue)| issue)
+        .collect()
+}
+
+/// Split an issue key such as `OS-1234` into its project key and number.
+///
+/// The project key ends up in JQL, so keys whose project part is not a
+/// plain JIRA project key are rejected.
+fn split_key(key: &str) -> Option<(&str, u64)> {
+    let (project, number) = key.rsplit_once('-')?;
+    if !is_project_key(project) {
+        return None;
+    }
+    Some((project, number.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira_client::SearchResponse;
+    use async_trait::async_trait;
+    use jira_api::{Issue, RemoteLink};
+    use std::sync::Mutex;
+
+    /// Mock JIRA client that returns a fixed set of issues from searches and
+    /// remembers the filter it was given
+    struct SearchMockJiraClient {
+        keys: Vec<&'static str>,
+        filter: Mutex<Option<SearchFilter>>,
+    }
+
+    #[async_trait]
+    impl JiraClientTrait for SearchMockJiraClient {
+        async fn search_issues(
+            &self,
+            _labels: &[String],
+            filter: &SearchFilter,
+            _page_token: Option<&str>,
+            _sort: &str,
+        ) -> anyhow::Result<SearchResponse> {
+            *self.filter.lock().unwrap() = Some(filter.clone());
+            let issues = self
+                .keys
+                .iter()
+                .map(|key| Issue {
+                    key: IssueKey::new_unchecked(*key),
+                    id: "1".into(),
+                    fields: [(
+                        "summary".to_string(),
+                        serde_json::json!(format!("Summary of {}", key)),
+                    )]
+                    .into_iter()
+                    .collect(),
+                    rendered_fields: None,
+                    changelog: None,
+                })
+                .collect();
+            Ok(SearchResponse {
+                issues,
+                is_last: Some(true),
+                next_page_token: None,
+            })
+        }
+
+        async fn get_issue(&self, key: &IssueKey) -> anyhow::Result<Issue> {
+            anyhow::bail!("Issue not found: {}", key)
+        }
+
+        async fn get_remote_links(&self, _issue_id: &str) -> anyhow::Result<Vec<RemoteLink>> {
+            Ok(vec![])
+        }
+    }
+
+    fn mock(keys: Vec<&'static str>) -> SearchMockJiraClient {
+        SearchMockJiraClient {
+            keys,
+            filter: Mutex::new(None),
+        }
+    }
+
+    #[tokio::test]
+    async fn related_issues_closest_first() {
+        let jira = mock(vec![
+            "OS-90", "OS-95", "OS-99", "OS-100", "OS-101", "OS-104", "OS-111", "OS-98",
+        ]);
+        let key = IssueKey::new("OS-100").unwrap();
+
+        let related = related_issues(&jira, "public", &key).await;
+        let keys: Vec<&str> = related.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["OS-99", "OS-101", "OS-98", "OS-104", "OS-95"]);
+        assert_eq!(related[0].summary, "Summary of OS-99");
+
+        let filter = jira.filter.lock().unwrap().clone().unwrap();
+        assert_eq!(
+            filter.jql_clauses(),
+            vec![
+                r#"project = "OS""#,
+                r#"issuekey >= "OS-90""#,
+                r#"issuekey <= "OS-110""#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn related_issues_low_key_numbers() {
+        let jira = mock(vec!["OS-1", "OS-2"]);
+        let key = IssueKey::new("OS-3").unwrap();
+
+        let related = related_issues(&jira, "public", &key).await;
+        assert_eq!(related.len(), 2);
+
+        let filter = jira.filter.lock().unwrap().clone().unwrap();
+        assert_eq!(filter.key_numbers, Some(1..=13));
+    }
+
+    #[tokio::test]
+    async fn related_issues_rejects_odd_keys() {
+        let jira = mock(vec!["OS-1"]);
+        for key in ["os-1", "OS-abc", "A-1"] {
+            let key = IssueKey::new_unchecked(key);
+            assert!(related_issues(&jira, "public", &key).await.is_empty());
+        }
+        assert!(jira.filter.lock().unwrap().is_none());
+    }
+}
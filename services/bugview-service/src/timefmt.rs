@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Display formatting for JIRA timestamps
+//!
+//! Issue lists show each timestamp as an ISO date along with relative text
+//! such as "3 days ago". Both are rendered in the display timezone configured
+//! with `BUGVIEW_TIMEZONE`, which is `UTC` or a fixed offset like `-04:00`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use bugview_api::DisplayTimestamp;
+use chrono::{DateTime, FixedOffset, Offset, SecondsFormat, Utc};
+
+use crate::search::parse_jira_timestamp;
+
+/// Timezone that timestamps are displayed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayTimezone(FixedOffset);
+
+impl Default for DisplayTimezone {
+    fn default() -> Self {
+        Self(Utc.fix())
+    }
+}
+
+impl FromStr for DisplayTimezone {
+    type Err = String;
+
+    /// Parse `UTC`, `Z`, or an offset in the form `+HH:MM`, `+HHMM` or `+HH`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("utc") || s.eq_ignore_ascii_case("z") {
+            return Ok(Self::default());
+        }
+
+        let invalid = || {
+            format!(
+                "invalid timezone '{}': expected UTC or an offset like -04:00",
+                s
+            )
+        };
+
+        let (sign, offset) = match s.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => return Err(invalid()),
+        };
+        let (hours, minutes) = match offset.split_once(':') {
+            Some((h, m)) => (h, m),
+            None if offset.len() == 4 => offset.split_at(2),
+            None => (offset, "00"),
+        };
+        if hours.len() != 2 || minutes.len() != 2 {
+            return Err(invalid());
+        }
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if minutes >= 60 {
+            return Err(invalid());
+        }
+
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for DisplayTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.local_minus_utc() == 0 {
+            write!(f, "UTC")
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl DisplayTimezone {
+    /// Format `time` for display, with relative text measured from `now`
+    pub fn format(&self, time: DateTime<Utc>, now: DateTime<Utc>) -> DisplayTimestamp {
+        let local = time.with_timezone(&self.0);
+        DisplayTimestamp {
+            iso: local.to_rfc3339_opts(SecondsFormat::Secs, false),
+            date: local.format("%Y-%m-%d").to_string(),
+            relative: relative_time(time, now),
+        }
+    }
+
+    /// Format a raw JIRA timestamp, or None if it cannot be parsed
+    pub fn format_jira(&self, raw: &str, now: DateTime<Utc>) -> Option<DisplayTimestamp> {
+        parse_jira_timestamp(raw).map(|time| self.format(time, now))
+    }
+}
+
+/// Describe how long before `now` the given time was, e.g. "3 days ago"
+///
+/// Times in the future (clock skew between JIRA and bugview) and times less
+/// than a minute ago are "just now".
+fn relative_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(time);
+    let minutes = elapsed.num_minutes();
+    let hours = elapsed.num_hours();
+    let days = elapsed.num_days();
+
+    if minutes < 1 {
+        return "just now".to_string();
+    }
+
+    let (count, unit) = if minutes < 60 {
+        (minutes, "minute")
+    } else if hours < 24 {
+        (hours, "hour")
+    } else if days < 30 {
+        (days, "day")
+    } else if days < 365 {
+        (days / 30, "month")
+    } else {
+        (days / 365, "year")
+    };
+
+    if count == 1 {
+        format!("1 {} ago", unit)
+    } else {
+        format!("{} {}s ago", count, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s)
+            .expect("valid timestamp")
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parse_timezone() {
+        let parse = |s: &str| {
+            s.parse::<DisplayTimezone>()
+                .map(|tz| tz.0.local_minus_utc())
+        };
+
+        assert_eq!(parse("UTC"), Ok(0));
+        assert_eq!(parse("z"), Ok(0));
+        assert_eq!(parse("+09:00"), Ok(9 * 3600));
+        assert_eq!(parse("-0430"), Ok(-(4 * 3600 + 30 * 60)));
+        assert_eq!(parse("-04"), Ok(-4 * 3600));
+
+        for invalid in [
+            "",
+            "EST",
+            "America/New_York",
+            "+9",
+            "+09:60",
+            "+25:00",
+            "09:00",
+        ] {
+            assert!(parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn display_timezone() {
+        assert_eq!(DisplayTimezone::default().to_string(), "UTC");
+        let tz: DisplayTimezone = "-04:00".parse().expect("offset");
+        assert_eq!(tz.to_string(), "-04:00");
+    }
+
+    #[test]
+    fn relative_time_units() {
+        let now = at("2024-06-15T12:00:00Z");
+        let ago = |s: &str| relative_time(at(s), now);
+
+        assert_eq!(ago("2024-06-15T12:00:30Z"), "just now");
+        assert_eq!(ago("2024-06-15T11:59:30Z"), "just now");
+        assert_eq!(ago("2024-06-15T11:59:00Z"), "1 minute ago");
+        assert_eq!(ago("2024-06-15T11:15:00Z"), "45 minutes ago");
+        assert_eq!(ago("2024-06-15T09:00:00Z"), "3 hours ago");
+        assert_eq!(ago("2024-06-14T12:00:00Z"), "1 day ago");
+        assert_eq!(ago("2024-06-12T11:00:00Z"), "3 days ago");
+        assert_eq!(ago("2024-04-01T12:00:00Z"), "2 months ago");
+        assert_eq!(ago("2022-01-01T12:00:00Z"), "2 years ago");
+    }
+
+    #[test]
+    fn format_in_display_timezone() {
+        let now = at("2023-10-07T12:00:00Z");
+        let tz: DisplayTimezone = "+09:00".parse().expect("offset");
+
+        // 22:27 at -04:00 is already the next day at +09:00
+        let ts = tz
+            .format_jira("2023-10-04T22:27:22.826-0400", now)
+            .expect("parsed");
+        assert_eq!(ts.iso, "2023-10-05T11:27:22+09:00");
+        assert_eq!(ts.date, "2023-10-05");
+        assert_eq!(ts.relative, "2 days ago");
+
+        let ts = DisplayTimezone::default().format(at("2023-10-04T00:00:00Z"), now);
+        assert_eq!(ts.iso, "2023-10-04T00:00:00+00:00");
+        assert_eq!(ts.date, "2023-10-04");
+
+        assert!(tz.format_jira("", now).is_none());
+        assert!(tz.format_jira("yesterday", now).is_none());
+    }
+}
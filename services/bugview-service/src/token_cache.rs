@@ -22,9 +22,13 @@
 //! - Cryptographically random (using thread_rng)
 //! - Time-limited (TTL-based expiration)
 //! - Capacity-limited (LRU eviction)
+//!
+//! The same cache also backs page-number navigation: the JIRA token for page
+//! N of a given query is stored under a key derived from the query and page
+//! number, so `?page=N` URLs stay stable even though JIRA tokens are not.
 
 use indexmap::IndexMap;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 
 /// Length of the short, URL-safe pagination token IDs we expose publicly.
@@ -81,25 +85,46 @@ impl TokenCache {
         }
     }
 
-    /// Store a JIRA token and return a short random ID.
-    pub fn store(&self, jira_token: String) -> String {
-        use rand::Rng;
-
-        let mut rng = rand::rng();
+    /// Lock the cache and drop expired entries.
+    fn lock(&self) -> MutexGuard<'_, IndexMap<String, TokenCacheEntry>> {
         let mut cache = self.cache.lock().unwrap_or_else(|poisoned| {
             tracing::error!("Token cache mutex was poisoned, recovering");
             poisoned.into_inner()
         });
 
-        // Cleanup expired entries
         let now = Instant::now();
         cache.retain(|_, entry| entry.expires_at > now);
+        cache
+    }
 
+    /// Insert an entry, evicting the oldest entries if the cache is full.
+    fn insert(
+        &self,
+        cache: &mut IndexMap<String, TokenCacheEntry>,
+        key: String,
+        jira_token: String,
+    ) {
         // Enforce capacity by evicting oldest entries (O(1) with IndexMap swap_remove_index)
-        while cache.len() >= self.max_entries {
+        while cache.len() >= self.max_entries && !cache.contains_key(&key) {
             let _ = cache.swap_remove_index(0);
         }
 
+        cache.insert(
+            key,
+            TokenCacheEntry {
+                jira_token,
+                expires_at: Instant::now() + self.ttl,
+            },
+        );
+    }
+
+    /// Store a JIRA token and return a short random ID.
+    pub fn store(&self, jira_token: String) -> String {
+        use rand::Rng;
+
+        let mut rng = rand::rng();
+        let mut cache = self.lock();
+
         // Generate ID, checking for collisions (unlikely but possible)
         // With 62^12 possible IDs (~3.2e21) and max 1000 entries, collision
         // probability is astronomically low, but we add a limit for safety
@@ -136,33 +161,39 @@ impl TokenCache {
                     .collect()
             });
 
-        cache.insert(
-            id.clone(),
-            TokenCacheEntry {
-                jira_token,
-                expires_at: now + self.ttl,
-            },
-        );
+        self.insert(&mut cache, id.clone(), jira_token);
 
         id
     }
 
     /// Retrieve a JIRA token by ID, cleaning up expired entries.
     pub fn get(&self, id: &str) -> Option<String> {
-        let mut cache = self.cache.lock().unwrap_or_else(|poisoned| {
-            tracing::error!("Token cache mutex was poisoned, recovering");
-            poisoned.into_inner()
-        });
+        // Get the token if it exists and hasn't expired
+        self.lock().get(id).map(|entry| entry.jira_token.clone())
+    }
 
-        // Clean up expired entries
-        let now = Instant::now();
-        cache.retain(|_, entry| entry.expires_at > now);
+    /// Store the JIRA token for page `page` of the query identified by `query`.
+    pub fn store_page(&self, query: &str, page: u32, jira_token: String) {
+        let mut cache = self.lock();
+        self.insert(&mut cache, page_key(query, page), jira_token);
+    }
 
-        // Get the token if it exists and hasn't expired
-        cache.get(id).map(|entry| entry.jira_token.clone())
+    /// Retrieve the JIRA token for page `page` of the query identified by `query`.
+    pub fn get_page(&self, query: &str, page: u32) -> Option<String> {
+        self.lock()
+            .get(&page_key(query, page))
+            .map(|entry| entry.jira_token.clone())
     }
 }
 
+/// Cache key for a numbered page.
+///
+/// The `page:` prefix contains a character outside the short ID alphabet, so
+/// these keys can never collide with IDs handed out by [`TokenCache::store`].
+fn page_key(query: &str, page: u32) -> String {
+    format!("page:{}:{}", page, query)
+}
+
 impl Default for TokenCache {
     fn default() -> Self {
         Self::new()
@@ -248,4 +279,26 @@ mod tests {
         sorted.dedup();
         assert_eq!(sorted.len(), ids.len());
     }
+
+    #[test]
+    fn test_token_cache_page_tokens() {
+        let cache = TokenCache::new();
+
+        cache.store_page("updated:public", 2, "jira-page-2".to_string());
+
+        assert_eq!(
+            cache.get_page("updated:public", 2),
+            Some("jira-page-2".to_string())
+        );
+        assert_eq!(cache.get_page("updated:public", 3), None);
+        assert_eq!(cache.get_page("created:public", 2), None);
+
+        // Re-storing a page replaces its token rather than adding an entry
+        cache.store_page("updated:public", 2, "jira-page-2b".to_string());
+        assert_eq!(
+            cache.get_page("updated:public", 2),
+            Some("jira-page-2b".to_string())
+        );
+        assert_eq!(cache.lock().len(), 1);
+    }
 }
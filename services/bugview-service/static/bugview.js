@@ -0,0 +1,56 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ *
+ * Copyright 2026 Edgecast Cloud LLC.
+ */
+
+/*
+ * Theme toggle. The server renders the theme from the bugview_theme cookie
+ * into the data-theme attribute of <html>, so the page is drawn in the right
+ * colors before this script runs. This script only keeps the toggle icon in
+ * sync and stores a new choice in the cookie.
+ */
+(function () {
+  "use strict";
+
+  var COOKIE = "bugview_theme";
+  var ONE_YEAR = 365 * 24 * 60 * 60;
+  var root = document.documentElement;
+  var media = window.matchMedia("(prefers-color-scheme: dark)");
+
+  // The theme currently shown, resolving "auto" to the browser preference
+  function effectiveTheme() {
+    var theme = root.getAttribute("data-theme");
+    if (theme === "dark" || theme === "light") {
+      return theme;
+    }
+    return media.matches ? "dark" : "light";
+  }
+
+  function updateIcon() {
+    var icon = document.getElementById("theme-icon");
+    if (icon) {
+      icon.textContent = effectiveTheme() === "dark" ? "☀" : "☽";
+    }
+  }
+
+  function toggleTheme() {
+    var next = effectiveTheme() === "dark" ? "light" : "dark";
+    root.setAttribute("data-theme", next);
+    document.cookie = COOKIE + "=" + next + "; Path=/bugview; Max-Age=" +
+      ONE_YEAR + "; SameSite=Lax";
+    updateIcon();
+  }
+
+  document.addEventListener("DOMContentLoaded", function () {
+    var button = document.getElementById("theme-toggle");
+    if (button) {
+      button.addEventListener("click", toggleTheme);
+    }
+    updateIcon();
+  });
+
+  media.addEventListener("change", updateIcon);
+})();
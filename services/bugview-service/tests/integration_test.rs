@@ -227,6 +227,378 @@ async fn test_stub_jira_label_filtering() {
     jira_server.close().await.expect("shutdown");
 }
 
+/// Test that the stub server pages through search results with real tokens
+#[tokio::test]
+async fn test_stub_jira_search_pagination() {
+    triton_tls::install_default_crypto_provider();
+
+    let jira_fixtures_dir =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../jira-stub-server/fixtures");
+
+    let jira_context =
+        Arc::new(jira_stub_server::StubContext::from_fixtures(&jira_fixtures_dir).unwrap());
+
+    let jira_api = jira_stub_server::api_description().expect("jira api description");
+
+    let jira_config = dropshot::ConfigDropshot {
+        bind_address: "127.0.0.1:0".parse().unwrap(),
+        default_request_body_max_bytes: 1024 * 1024,
+        default_handler_task_mode: dropshot::HandlerTaskMode::Detached,
+        ..Default::default()
+    };
+
+    let jira_log = dropshot::ConfigLogging::StderrTerminal {
+        level: dropshot::ConfigLoggingLevel::Warn,
+    }
+    .to_logger("jira-stub-pagination-test")
+    .expect("jira logger");
+
+    let jira_server =
+        match dropshot::HttpServerStarter::new(&jira_config, jira_api, jira_context, &jira_log) {
+            Ok(starter) => starter.start(),
+            Err(e) => {
+                if std::env::var("CI").is_ok() {
+                    panic!("Failed to start JIRA stub in CI: {}", e);
+                }
+                eprintln!("SKIPPING: pagination test: {} (set CI=1 to fail)", e);
+                return;
+            }
+        };
+
+    let jira_addr = jira_server.local_addr();
+    let jira_base_url = format!("http://{}", jira_addr);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let client = jira_client::Client::new(&jira_base_url);
+
+    let all = client
+        .search_issues()
+        .jql("labels IN (public)")
+        .send()
+        .await
+        .expect("unpaged search");
+    let all_keys: Vec<String> = all.issues.iter().map(|i| i.key.clone()).collect();
+    assert!(
+        all_keys.len() > 1,
+        "need several public issues to page through"
+    );
+    assert_eq!(all.is_last, Some(true));
+    assert_eq!(all.next_page_token, None);
+
+    // Walk the same results one issue per page by following the tokens
+    let mut paged_keys: Vec<String> = Vec::new();
+    let mut token: Option<String> = None;
+    loop {
+        let mut request = client
+            .search_issues()
+            .jql("labels IN (public)")
+            .max_results(1u32);
+        if let Some(t) = token.take() {
+            request = request.next_page_token(t);
+        }
+        let page = request.send().await.expect("paged search");
+
+        assert_eq!(page.issues.len(), 1, "each page should hold one issue");
+        paged_keys.extend(page.issues.iter().map(|i| i.key.clone()));
+        assert!(
+            paged_keys.len() <= all_keys.len(),
+            "pagination should stop at the last issue"
+        );
+
+        match &page.next_page_token {
+            Some(next) => {
+                assert_eq!(page.is_last, Some(false));
+                token = Some(next.clone());
+            }
+            None => {
+                assert_eq!(page.is_last, Some(true));
+                break;
+            }
+        }
+    }
+    assert_eq!(
+        paged_keys, all_keys,
+        "pages should cover every issue in order"
+    );
+
+    // Tokens the stub did not hand out are rejected
+    let bogus = client
+        .search_issues()
+        .jql("labels IN (public)")
+        .next_page_token("not-a-stub-token")
+        .send()
+        .await;
+    assert!(bogus.is_err(), "unknown token should be rejected");
+
+    jira_server.close().await.expect("shutdown");
+}
+
+/// Test that remote links can be changed at runtime through the stub's admin
+/// API, and that they are served when looked up by issue id (as bugview does)
+#[tokio::test]
+async fn test_stub_jira_remote_link_admin() {
+    triton_tls::install_default_crypto_provider();
+
+    let jira_fixtures_dir =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../jira-stub-server/fixtures");
+
+    let jira_context =
+        Arc::new(jira_stub_server::StubContext::from_fixtures(&jira_fixtures_dir).unwrap());
+
+    let jira_api = jira_stub_server::api_description().expect("jira api description");
+
+    let jira_config = dropshot::ConfigDropshot {
+        bind_address: "127.0.0.1:0".parse().unwrap(),
+        default_request_body_max_bytes: 1024 * 1024,
+        default_handler_task_mode: dropshot::HandlerTaskMode::Detached,
+        ..Default::default()
+    };
+
+    let jira_log = dropshot::ConfigLogging::StderrTerminal {
+        level: dropshot::ConfigLoggingLevel::Warn,
+    }
+    .to_logger("jira-stub-remote-link-test")
+    .expect("jira logger");
+
+    let jira_server =
+        match dropshot::HttpServerStarter::new(&jira_config, jira_api, jira_context, &jira_log) {
+            Ok(starter) => starter.start(),
+            Err(e) => {
+                if std::env::var("CI").is_ok() {
+                    panic!("Failed to start JIRA stub in CI: {}", e);
+                }
+                eprintln!("SKIPPING: remote link admin test: {} (set CI=1 to fail)", e);
+                return;
+            }
+        };
+
+    let jira_addr = jira_server.local_addr();
+    let jira_base_url = format!("http://{}", jira_addr);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let client = jira_client::Client::new(&jira_base_url);
+    let http = reqwest::Client::new();
+    let admin_url = format!("{}/_stub/issue/TRITON-2520/remotelink", jira_base_url);
+
+    // Links from remote_links/<KEY>.json fixture files are served
+    let fixture_links = client
+        .get_remote_links()
+        .issue_id_or_key("TRITON-1813")
+        .send()
+        .await
+        .expect("fixture remote links");
+    assert_eq!(fixture_links.len(), 2);
+
+    let created = http
+        .post(&admin_url)
+        .json(&serde_json::json!({
+            "id": 500,
+            "url": "https://github.com/TritonDataCenter/triton/issues/1",
+            "title": "GitHub issue"
+        }))
+        .send()
+        .await
+        .expect("add remote link");
+    assert_eq!(created.status(), 201);
+
+    // A second link with the same id is a conflict
+    let duplicate = http
+        .post(&admin_url)
+        .json(&serde_json::json!({
+            "id": 500,
+            "url": "https://example.com/",
+            "title": "Duplicate"
+        }))
+        .send()
+        .await
+        .expect("add duplicate remote link");
+    assert_eq!(duplicate.status(), 409);
+
+    // Looked up by issue id, as bugview-service does
+    let links = client
+        .get_remote_links()
+        .issue_id_or_key("57781")
+        .send()
+        .await
+        .expect("remote links by id");
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].id, 500);
+    assert_eq!(
+        links[0].object.as_ref().map(|o| o.url.as_str()),
+        Some("https://github.com/TritonDataCenter/triton/issues/1")
+    );
+
+    let deleted = http
+        .delete(format!("{}/500", admin_url))
+        .send()
+        .await
+        .expect("remove remote link");
+    assert_eq!(deleted.status(), 204);
+
+    let links = client
+        .get_remote_links()
+        .issue_id_or_key("TRITON-2520")
+        .send()
+        .await
+        .expect("remote links after delete");
+    assert!(links.is_empty());
+
+    // Unknown issues are rejected
+    let unknown = http
+        .post(format!("{}/_stub/issue/NOPE-1/remotelink", jira_base_url))
+        .json(&serde_json::json!({"url": "https://example.com/", "title": "x"}))
+        .send()
+        .await
+        .expect("add link to unknown issue");
+    assert_eq!(unknown.status(), 404);
+
+    jira_server.close().await.expect("shutdown");
+}
+
+/// A client builder that trusts only the stub's self-signed certificate
+fn trusting_stub_cert(cert: &jira_stub_server::SelfSignedCert) -> reqwest::ClientBuilder {
+    let mut roots = rustls::RootCertStore::empty();
+    for der in rustls_pemfile::certs(&mut cert.cert_pem().as_bytes()) {
+        roots
+            .add(der.expect("parse stub certificate"))
+            .expect("trust stub certificate");
+    }
+    let tls = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    reqwest::Client::builder().use_preconfigured_tls(tls)
+}
+
+/// Test that the client's credentials and TLS configuration work against a
+/// stub that, like production JIRA, requires basic auth over HTTPS
+#[tokio::test]
+async fn test_stub_jira_basic_auth_over_tls() {
+    triton_tls::install_default_crypto_provider();
+
+    let jira_fixtures_dir =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../jira-stub-server/fixtures");
+
+    let jira_context = Arc::new(
+        jira_stub_server::StubContext::from_fixtures(&jira_fixtures_dir)
+            .unwrap()
+            .with_basic_auth("bugview", "s3cret"),
+    );
+
+    let jira_api = jira_stub_server::api_description().expect("jira api description");
+    let cert = jira_stub_server::SelfSignedCert::localhost().expect("self-signed cert");
+
+    let jira_config = dropshot::ConfigDropshot {
+        bind_address: "127.0.0.1:0".parse().unwrap(),
+        default_request_body_max_bytes: 1024 * 1024,
+        default_handler_task_mode: dropshot::HandlerTaskMode::Detached,
+        ..Default::default()
+    };
+
+    let jira_log = dropshot::ConfigLogging::StderrTerminal {
+        level: dropshot::ConfigLoggingLevel::Warn,
+    }
+    .to_logger("jira-stub-auth-test")
+    .expect("jira logger");
+
+    let jira_server = match dropshot::HttpServerStarter::new_with_tls(
+        &jira_config,
+        jira_api,
+        jira_context,
+        &jira_log,
+        Some(cert.tls_config()),
+    ) {
+        Ok(starter) => starter.start(),
+        Err(e) => {
+            if std::env::var("CI").is_ok() {
+                panic!("Failed to start JIRA stub in CI: {}", e);
+            }
+            eprintln!(
+                "SKIPPING: basic auth over TLS test: {} (set CI=1 to fail)",
+                e
+            );
+            return;
+        }
+    };
+
+    let jira_addr = jira_server.local_addr();
+    let jira_base_url = format!("https://{}", jira_addr);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    // The right credentials over a trusted connection are accepted
+    let client = jira_client::Client::new_with_auth_and_builder(
+        &jira_base_url,
+        jira_client::JiraAuth::basic("bugview", "s3cret"),
+        trusting_stub_cert(&cert),
+    )
+    .expect("authenticated client");
+    let issue = client
+        .get_issue()
+        .issue_id_or_key("TRITON-2520")
+        .send()
+        .await
+        .expect("get issue with credentials");
+    assert_eq!(issue.key, "TRITON-2520");
+
+    // Wrong or missing credentials are rejected with 401
+    let wrong_password = jira_client::Client::new_with_auth_and_builder(
+        &jira_base_url,
+        jira_client::JiraAuth::basic("bugview", "wrong"),
+        trusting_stub_cert(&cert),
+    )
+    .expect("client with wrong password");
+    let err = wrong_password
+        .search_issues()
+        .jql("labels IN (public)")
+        .send()
+        .await
+        .expect_err("wrong password should be rejected");
+    assert_eq!(err.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+
+    let anonymous = jira_client::Client::new_with_client(
+        &jira_base_url,
+        trusting_stub_cert(&cert).build().expect("anonymous client"),
+    );
+    let err = anonymous
+        .get_remote_links()
+        .issue_id_or_key("TRITON-2520")
+        .send()
+        .await
+        .expect_err("missing credentials should be rejected");
+    assert_eq!(err.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+
+    // A client that does not trust the certificate cannot connect at all
+    let untrusting = jira_client::Client::new_with_auth(
+        &jira_base_url,
+        jira_client::JiraAuth::basic("bugview", "s3cret"),
+    )
+    .expect("untrusting client");
+    let err = untrusting
+        .get_issue()
+        .issue_id_or_key("TRITON-2520")
+        .send()
+        .await
+        .expect_err("self-signed certificate should not be trusted");
+    assert_eq!(err.status(), None);
+
+    // The stub's admin endpoints do not need credentials
+    let admin = trusting_stub_cert(&cert)
+        .build()
+        .expect("admin client")
+        .delete(format!(
+            "{}/_stub/issue/TRITON-2520/remotelink",
+            jira_base_url
+        ))
+        .send()
+        .await
+        .expect("clear remote links");
+    assert_eq!(admin.status(), 204);
+
+    jira_server.close().await.expect("shutdown");
+}
+
 /// Test that non-public issues (FAKE-PRIVATE-*) are correctly filtered out
 ///
 /// This test verifies that:
@@ -0,0 +1,1707 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Stub CloudAPI server for testing
+//!
+//! This crate provides a Dropshot-based HTTP server that implements the
+//! CloudAPI trait with in-memory state. It can be used for:
+//!
+//! - Testing client helpers (state waiters, pagination, bulk operations)
+//!   without a real Triton datacenter
+//! - Local development of triton-cli and triton-gateway
+//!
+//! Images and packages are loaded from fixture files at startup. Machines and
+//! SSH keys start out empty and are created through the API. All other
+//! endpoints return a `NotImplemented` error.
+//!
+//! # State transitions
+//!
+//! Machine lifecycle changes in CloudAPI are asynchronous: provisioning,
+//! starting, stopping, rebooting and deleting a machine each pass through an
+//! intermediate state before settling. The stub models this deterministically
+//! by counting reads instead of using wall-clock time. After a change the
+//! machine reports its intermediate state, and settles in the target state on
+//! the Nth read (GET, HEAD or list) where N is the number of transition polls
+//! configured with [`StubContext::with_transition_polls`].
+
+use anyhow::{Context, Result};
+use cloudapi_api::*;
+use dropshot::{
+    Body, ClientErrorStatusCode, HttpError, HttpResponseAccepted, HttpResponseCreated,
+    HttpResponseDeleted, HttpResponseOk, Path, Query, RequestContext, TypedBody,
+    WebsocketChannelResult, WebsocketConnection,
+};
+use http::Response;
+use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Number of reads it takes a machine to settle after a state change
+pub const DEFAULT_TRANSITION_POLLS: u32 = 2;
+
+/// Page size used when listing machines without a `limit`, matching CloudAPI
+const DEFAULT_MACHINE_LIMIT: u64 = 1000;
+
+/// First address handed out to stub machines (10.88.0.2)
+const FIRST_MACHINE_IP: u32 = 0x0a58_0002;
+
+// ============================================================================
+// Server State
+// ============================================================================
+
+/// A pending change of machine state
+#[derive(Debug)]
+struct Transition {
+    /// Audit action recorded when the transition completes
+    action: &'static str,
+    /// State the machine settles in
+    target: MachineState,
+    /// Reads left before the machine settles
+    remaining: u32,
+}
+
+/// An entry in a stub machine's audit log
+#[derive(Debug)]
+struct StubAudit {
+    action: &'static str,
+    time: Timestamp,
+}
+
+/// A machine and the bookkeeping needed to drive its state transitions
+#[derive(Debug)]
+struct StubMachine {
+    machine: Machine,
+    transition: Option<Transition>,
+    audit: Vec<StubAudit>,
+}
+
+impl StubMachine {
+    /// Move to `intermediate` now, settling in `target` after `polls` reads
+    fn begin(
+        &mut self,
+        action: &'static str,
+        intermediate: MachineState,
+        target: MachineState,
+        polls: u32,
+    ) {
+        self.machine.state = intermediate;
+        self.machine.updated = Timestamp::now();
+        self.transition = Some(Transition {
+            action,
+            target,
+            remaining: polls,
+        });
+        if polls == 0 {
+            self.settle();
+        }
+    }
+
+    /// Record a read of the machine, completing any transition that is due
+    fn observe(&mut self) {
+        if let Some(transition) = &mut self.transition {
+            transition.remaining = transition.remaining.saturating_sub(1);
+            if transition.remaining == 0 {
+                self.settle();
+            }
+        }
+    }
+
+    /// Complete the pending transition, if any
+    fn settle(&mut self) {
+        if let Some(transition) = self.transition.take() {
+            self.machine.state = transition.target;
+            self.record(transition.action);
+        }
+    }
+
+    /// Append a successful action to the audit log
+    fn record(&mut self, action: &'static str) {
+        let time = Timestamp::now();
+        self.machine.updated = time;
+        self.audit.push(StubAudit { action, time });
+    }
+
+    fn is_deleted(&self) -> bool {
+        self.machine.state == MachineState::Deleted
+    }
+
+    /// Fail unless the machine is in `state` with no transition pending
+    fn require_state(&self, state: MachineState) -> Result<(), HttpError> {
+        if self.transition.is_some() || self.machine.state != state {
+            return Err(invalid_argument(format!(
+                "instance {} is {}, not {}",
+                self.machine.id, self.machine.state, state
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Mutable state shared by all requests
+#[derive(Debug, Default)]
+struct StubState {
+    /// Machines in creation order, including deleted ones
+    machines: Vec<StubMachine>,
+    /// SSH keys in creation order
+    keys: Vec<SshKey>,
+    /// Host part of the next machine IP address
+    next_ip: u32,
+}
+
+impl StubState {
+    fn machine_mut(&mut self, id: Uuid) -> Result<&mut StubMachine, HttpError> {
+        self.machines
+            .iter_mut()
+            .find(|m| m.machine.id == id)
+            .ok_or_else(|| not_found("instance", id))
+    }
+
+    /// Look up a machine that can still be acted on
+    fn live_machine_mut(&mut self, id: Uuid) -> Result<&mut StubMachine, HttpError> {
+        let machine = self.machine_mut(id)?;
+        if machine.is_deleted() {
+            return Err(HttpError::for_client_error(
+                Some(ErrorCode::ResourceNotFound.to_string()),
+                ClientErrorStatusCode::GONE,
+                format!("instance {} has been deleted", id),
+            ));
+        }
+        Ok(machine)
+    }
+
+    fn allocate_ip(&mut self) -> String {
+        let ip = Ipv4Addr::from(FIRST_MACHINE_IP.wrapping_add(self.next_ip));
+        self.next_ip += 1;
+        ip.to_string()
+    }
+}
+
+// ============================================================================
+// Server Context
+// ============================================================================
+
+/// Context for the stub CloudAPI server
+///
+/// The stub serves a single set of resources regardless of the account in
+/// the request path.
+#[derive(Debug)]
+pub struct StubContext {
+    images: Vec<Image>,
+    packages: Vec<Package>,
+    transition_polls: u32,
+    state: Mutex<StubState>,
+}
+
+impl StubContext {
+    /// Create a stub context serving the given images and packages
+    pub fn new(images: Vec<Image>, packages: Vec<Package>) -> Self {
+        Self {
+            images,
+            packages,
+            transition_polls: DEFAULT_TRANSITION_POLLS,
+            state: Mutex::new(StubState::default()),
+        }
+    }
+
+    /// Create a stub context by loading `images.json` and `packages.json`
+    ///
+    /// Each file holds a JSON array in the CloudAPI wire format, as returned
+    /// by `GET /:account/images` and `GET /:account/packages`.
+    pub fn from_fixtures(fixtures_dir: &std::path::Path) -> Result<Self> {
+        let images = load_fixture(&fixtures_dir.join("images.json"))?;
+        let packages = load_fixture(&fixtures_dir.join("packages.json"))?;
+        Ok(Self::new(images, packages))
+    }
+
+    /// Set how many reads it takes a machine to settle after a state change
+    ///
+    /// With `0`, state changes complete before the request that made them
+    /// returns.
+    pub fn with_transition_polls(mut self, polls: u32) -> Self {
+        self.transition_polls = polls;
+        self
+    }
+
+    /// Number of images loaded from fixtures
+    pub fn image_count(&self) -> usize {
+        self.images.len()
+    }
+
+    /// Number of packages loaded from fixtures
+    pub fn package_count(&self) -> usize {
+        self.packages.len()
+    }
+
+    fn state(&self) -> Result<MutexGuard<'_, StubState>, HttpError> {
+        self.state
+            .lock()
+            .map_err(|_| HttpError::for_internal_error("stub state lock poisoned".to_string()))
+    }
+
+    // ------------------------------------------------------------------------
+    // Machines
+    // ------------------------------------------------------------------------
+
+    /// Provision a machine, which starts out `provisioning`
+    pub fn create_machine(&self, req: CreateMachineRequest) -> Result<Machine, HttpError> {
+        let image = self
+            .images
+            .iter()
+            .find(|i| i.id == req.image)
+            .ok_or_else(|| not_found("image", req.image))?;
+        let package = self
+            .find_package(&req.package)
+            .ok_or_else(|| not_found("package", &req.package))?;
+
+        let brand = match req.brand {
+            Some(brand) => vmapi_brand(brand),
+            None => image
+                .requirements
+                .brand
+                .or(package.brand)
+                .unwrap_or(match image.image_type {
+                    ImageType::Zvol => VmapiBrand::Bhyve,
+                    ImageType::LxDataset => VmapiBrand::Lx,
+                    _ => VmapiBrand::Joyent,
+                }),
+        };
+        let machine_type = match brand {
+            VmapiBrand::Bhyve | VmapiBrand::Kvm => MachineType::Virtualmachine,
+            _ => MachineType::Smartmachine,
+        };
+
+        let id = Uuid::new_v4();
+        let now = Timestamp::now();
+        let mut state = self.state()?;
+        let ip = state.allocate_ip();
+
+        let machine = Machine {
+            id,
+            name: req
+                .name
+                .clone()
+                .unwrap_or_else(|| id.to_string().chars().take(8).collect()),
+            machine_type,
+            brand,
+            state: MachineState::Provisioning,
+            image: image.id,
+            package: package.name.clone(),
+            memory: Some(package.memory),
+            disk: package.disk,
+            ips: vec![ip.clone()],
+            metadata: req.metadata(),
+            tags: req.tags(),
+            created: now,
+            updated: now,
+            networks: None,
+            primary_ip: Some(ip),
+            nics: Vec::new(),
+            docker: None,
+            firewall_enabled: Some(req.firewall_enabled.unwrap_or(false)),
+            deletion_protection: Some(req.deletion_protection.unwrap_or(false)),
+            compute_node: None,
+            dns_names: None,
+            free_space: None,
+            disks: None,
+            encrypted: req.encrypted,
+            flexible: None,
+            delegate_dataset: req.delegate_dataset,
+            role_tag: None,
+        };
+
+        let mut stub = StubMachine {
+            machine,
+            transition: None,
+            audit: Vec::new(),
+        };
+        stub.begin(
+            "provision",
+            MachineState::Provisioning,
+            MachineState::Running,
+            self.transition_polls,
+        );
+        let machine = stub.machine.clone();
+        state.machines.push(stub);
+        Ok(machine)
+    }
+
+    /// Get a machine, advancing any pending transition by one read
+    ///
+    /// Deleted machines are still returned, in the `deleted` state.
+    pub fn get_machine(&self, id: Uuid) -> Result<Machine, HttpError> {
+        let mut state = self.state()?;
+        let machine = state.machine_mut(id)?;
+        machine.observe();
+        Ok(machine.machine.clone())
+    }
+
+    /// List machines matching `query`, advancing every pending transition
+    /// by one read
+    pub fn list_machines(&self, query: &ListMachinesQuery) -> Result<Vec<Machine>, HttpError> {
+        let mut state = self.state()?;
+        let tag_filters = query.tag_filters();
+        let offset = query.offset.unwrap_or(0) as usize;
+        let limit = query.limit.unwrap_or(DEFAULT_MACHINE_LIMIT) as usize;
+
+        for machine in &mut state.machines {
+            machine.observe();
+        }
+
+        Ok(state
+            .machines
+            .iter()
+            .map(|m| &m.machine)
+            .filter(|m| match query.state {
+                Some(s) => m.state == s,
+                None => query.tombstone == Some(true) || m.state != MachineState::Deleted,
+            })
+            .filter(|m| query.name.as_ref().is_none_or(|name| &m.name == name))
+            .filter(|m| query.image.is_none_or(|image| m.image == image))
+            .filter(|m| query.memory.is_none_or(|memory| m.memory == Some(memory)))
+            .filter(|m| query.machine_type.is_none_or(|t| m.machine_type == t))
+            .filter(|m| query.brand.is_none_or(|brand| m.brand == brand))
+            .filter(|m| {
+                query
+                    .docker
+                    .is_none_or(|docker| m.docker.unwrap_or(false) == docker)
+            })
+            .filter(|m| {
+                tag_filters.iter().all(|(key, expected)| {
+                    m.tags
+                        .get(key)
+                        .is_some_and(|value| tag_matches(value, expected))
+                })
+            })
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    /// Apply a machine action
+    ///
+    /// `body` is the action request body; its `action` field takes precedence
+    /// over `query_action`, as in CloudAPI.
+    pub fn update_machine(
+        &self,
+        id: Uuid,
+        query_action: Option<MachineAction>,
+        body: &serde_json::Value,
+    ) -> Result<(), HttpError> {
+        let action = match body.get("action") {
+            Some(value) => serde_json::from_value(value.clone())
+                .map_err(|e| invalid_argument(format!("invalid action: {}", e)))?,
+            None => query_action.ok_or_else(|| missing_parameter("action"))?,
+        };
+
+        let polls = self.transition_polls;
+        let mut state = self.state()?;
+        let stub = state.live_machine_mut(id)?;
+
+        match action {
+            MachineAction::Start => {
+                stub.require_state(MachineState::Stopped)?;
+                stub.begin("start", MachineState::Ready, MachineState::Running, polls);
+            }
+            MachineAction::Stop => {
+                stub.require_state(MachineState::Running)?;
+                stub.begin("stop", MachineState::Stopping, MachineState::Stopped, polls);
+            }
+            MachineAction::Reboot => {
+                stub.require_state(MachineState::Running)?;
+                stub.begin("reboot", MachineState::Ready, MachineState::Running, polls);
+            }
+            MachineAction::Resize => {
+                let name = body_str(body, "package")?;
+                let package = self
+                    .find_package(name)
+                    .ok_or_else(|| not_found("package", name))?;
+                stub.machine.package = package.name.clone();
+                stub.machine.memory = Some(package.memory);
+                stub.machine.disk = package.disk;
+                stub.record("resize");
+            }
+            MachineAction::Rename => {
+                stub.machine.name = body_str(body, "name")?.to_string();
+                stub.record("rename");
+            }
+            MachineAction::EnableFirewall => {
+                stub.machine.firewall_enabled = Some(true);
+                stub.record("enable_firewall");
+            }
+            MachineAction::DisableFirewall => {
+                stub.machine.firewall_enabled = Some(false);
+                stub.record("disable_firewall");
+            }
+            MachineAction::EnableDeletionProtection => {
+                stub.machine.deletion_protection = Some(true);
+                stub.record("enable_deletion_protection");
+            }
+            MachineAction::DisableDeletionProtection => {
+                stub.machine.deletion_protection = Some(false);
+                stub.record("disable_deletion_protection");
+            }
+            MachineAction::Unknown => {
+                return Err(invalid_argument("unsupported action".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete a machine, which passes through `stopping` to `deleted`
+    pub fn delete_machine(&self, id: Uuid) -> Result<(), HttpError> {
+        let polls = self.transition_polls;
+        let mut state = self.state()?;
+        let stub = state.live_machine_mut(id)?;
+
+        if stub.machine.deletion_protection == Some(true) {
+            return Err(invalid_argument(format!(
+                "instance {} has deletion_protection enabled",
+                id
+            )));
+        }
+
+        stub.begin(
+            "destroy",
+            MachineState::Stopping,
+            MachineState::Deleted,
+            polls,
+        );
+        Ok(())
+    }
+
+    /// Counts of the machines by state, brand and package, advancing every
+    /// pending transition by one read like a list does
+    pub fn machine_summary(&self) -> Result<MachineSummary, HttpError> {
+        let mut state = self.state()?;
+        for machine in &mut state.machines {
+            machine.observe();
+        }
+        Ok(state.machines.iter().map(|m| &m.machine).collect())
+    }
+
+    /// Render every machine matching `query` in the requested format
+    pub fn export_machines(&self, query: &MachineExportQuery) -> Result<String, HttpError> {
+        let format = query.format();
+        let columns = query.columns().map_err(invalid_argument)?;
+        let mut list_query = query.list_query();
+        list_query.limit = Some(u64::MAX);
+
+        let mut body = format.header(&columns).unwrap_or_default();
+        for machine in self.list_machines(&list_query)? {
+            body.push_str(&format.row(&machine, &columns));
+        }
+        Ok(body)
+    }
+
+    /// Audit log of completed actions on a machine, newest first
+    pub fn machine_audit(&self, id: Uuid) -> Result<Vec<AuditEntry>, HttpError> {
+        let mut state = self.state()?;
+        let stub = state.machine_mut(id)?;
+        Ok(stub
+            .audit
+            .iter()
+            .rev()
+            .map(|entry| AuditEntry {
+                action: entry.action.to_string(),
+                time: entry.time,
+                caller: None,
+                success: Some(AuditSuccess::Yes),
+            })
+            .collect())
+    }
+
+    // ------------------------------------------------------------------------
+    // Images and packages
+    // ------------------------------------------------------------------------
+
+    /// List images matching `query`
+    ///
+    /// As in CloudAPI, only active images are listed unless a `state` is
+    /// given. A `marker` resumes the listing after that image.
+    pub fn list_images(&self, query: &ListImagesQuery) -> Vec<Image> {
+        let images: Vec<&Image> = self
+            .images
+            .iter()
+            .filter(|i| match query.state {
+                None => i.state.is_none_or(|s| s == ImageState::Active),
+                Some(ImageState::All) => true,
+                Some(state) => i.state == Some(state),
+            })
+            .filter(|i| query.name.as_ref().is_none_or(|name| &i.name == name))
+            .filter(|i| query.os.as_ref().is_none_or(|os| &i.os == os))
+            .filter(|i| query.version.as_ref().is_none_or(|v| &i.version == v))
+            .filter(|i| query.public.is_none_or(|public| i.public == Some(public)))
+            .filter(|i| query.owner.is_none_or(|owner| i.owner == Some(owner)))
+            .filter(|i| query.image_type.is_none_or(|t| i.image_type == t))
+            .collect();
+        let start = query.marker.map_or(0, |marker| {
+            images
+                .iter()
+                .position(|i| i.id == marker)
+                .map_or(images.len(), |pos| pos + 1)
+        });
+
+        images
+            .into_iter()
+            .skip(start)
+            .take(query.limit.map_or(usize::MAX, |limit| limit as usize))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_image(&self, id: Uuid) -> Result<Image, HttpError> {
+        self.images
+            .iter()
+            .find(|i| i.id == id)
+            .cloned()
+            .ok_or_else(|| not_found("image", id))
+    }
+
+    /// List packages matching `query`
+    pub fn list_packages(&self, query: &ListPackagesQuery) -> Vec<Package> {
+        self.packages
+            .iter()
+            .filter(|p| query.name.as_ref().is_none_or(|name| &p.name == name))
+            .filter(|p| query.memory.is_none_or(|memory| p.memory == memory))
+            .filter(|p| query.disk.is_none_or(|disk| p.disk == disk))
+            .filter(|p| query.swap.is_none_or(|swap| p.swap == swap))
+            .filter(|p| query.lwps.is_none_or(|lwps| p.lwps == Some(lwps)))
+            .filter(|p| query.vcpus.is_none_or(|vcpus| p.vcpus == vcpus))
+            .filter(|p| {
+                query
+                    .version
+                    .as_ref()
+                    .is_none_or(|v| p.version.as_ref() == Some(v))
+            })
+            .filter(|p| {
+                query
+                    .group
+                    .as_ref()
+                    .is_none_or(|g| p.group.as_ref() == Some(g))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Get a package by name or UUID
+    pub fn get_package(&self, name_or_id: &str) -> Result<Package, HttpError> {
+        self.find_package(name_or_id)
+            .cloned()
+            .ok_or_else(|| not_found("package", name_or_id))
+    }
+
+    fn find_package(&self, name_or_id: &str) -> Option<&Package> {
+        self.packages
+            .iter()
+            .find(|p| p.name == name_or_id || p.id.to_string() == name_or_id)
+    }
+
+    // ------------------------------------------------------------------------
+    // SSH keys
+    // ------------------------------------------------------------------------
+
+    /// Add an SSH key, computing its MD5 fingerprint like CloudAPI does
+    pub fn create_key(&self, req: CreateSshKeyRequest) -> Result<SshKey, HttpError> {
+        let fingerprint = triton_auth::fingerprint::parse_public_key_line(&req.key)
+            .and_then(|key| triton_auth::md5_fingerprint(&key))
+            .map_err(|e| invalid_argument(format!("key is invalid: {}", e)))?;
+
+        let mut state = self.state()?;
+        if state
+            .keys
+            .iter()
+            .any(|k| k.name == req.name || k.fingerprint == fingerprint)
+        {
+            return Err(invalid_argument(format!(
+                "key {} ({}) already exists",
+                req.name, fingerprint
+            )));
+        }
+
+        let key = SshKey {
+            name: req.name,
+            key: req.key,
+            fingerprint,
+            created: Some(Timestamp::now()),
+            role_tag: None,
+        };
+        state.keys.push(key.clone());
+        Ok(key)
+    }
+
+    pub fn list_keys(&self) -> Result<Vec<SshKey>, HttpError> {
+        Ok(self.state()?.keys.clone())
+    }
+
+    /// Get an SSH key by name or fingerprint
+    pub fn get_key(&self, name_or_fingerprint: &str) -> Result<SshKey, HttpError> {
+        self.state()?
+            .keys
+            .iter()
+            .find(|k| k.name == name_or_fingerprint || k.fingerprint == name_or_fingerprint)
+            .cloned()
+            .ok_or_else(|| not_found("key", name_or_fingerprint))
+    }
+
+    /// Delete an SSH key by name or fingerprint
+    pub fn delete_key(&self, name_or_fingerprint: &str) -> Result<(), HttpError> {
+        let mut state = self.state()?;
+        let before = state.keys.len();
+        state
+            .keys
+            .retain(|k| k.name != name_or_fingerprint && k.fingerprint != name_or_fingerprint);
+        if state.keys.len() == before {
+            return Err(not_found("key", name_or_fingerprint));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// API Implementation
+// ============================================================================
+
+/// Implement trait endpoints that the stub does not back with any state
+///
+/// Each listed endpoint fails with a `NotImplemented` error naming the
+/// endpoint, so a test that strays outside the stub's coverage fails loudly.
+macro_rules! not_implemented {
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)*) => {
+        $(
+            async fn $name(
+                _rqctx: RequestContext<Self::Context>,
+                $($arg: $ty),*
+            ) -> Result<$ret, HttpError> {
+                Err(not_implemented(stringify!($name)))
+            }
+        )*
+    };
+}
+
+/// Marker type for the stub CloudAPI implementation
+pub enum StubCloudApi {}
+
+impl CloudApi for StubCloudApi {
+    type Context = Arc<StubContext>;
+
+    // ========================================================================
+    // Machines
+    // ========================================================================
+
+    async fn create_machine(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        body: TypedBody<CreateMachineRequest>,
+    ) -> Result<HttpResponseCreated<Machine>, HttpError> {
+        let machine = rqctx.context().create_machine(body.into_inner())?;
+        Ok(HttpResponseCreated(machine))
+    }
+
+    async fn list_machines(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<ListMachinesQuery>,
+    ) -> Result<HttpResponseOk<Vec<Machine>>, HttpError> {
+        let machines = rqctx.context().list_machines(&query.into_inner())?;
+        Ok(HttpResponseOk(machines))
+    }
+
+    async fn head_machines(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<ListMachinesQuery>,
+    ) -> Result<Response<Body>, HttpError> {
+        let machines = rqctx.context().list_machines(&query.into_inner())?;
+        head_response(Some(machines.len()))
+    }
+
+    async fn get_machine_summary(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+    ) -> Result<HttpResponseOk<MachineSummary>, HttpError> {
+        let summary = rqctx.context().machine_summary()?;
+        Ok(HttpResponseOk(summary))
+    }
+
+    async fn export_machines(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<MachineExportQuery>,
+    ) -> Result<Response<Body>, HttpError> {
+        let query = query.into_inner();
+        let body = rqctx.context().export_machines(&query)?;
+        Response::builder()
+            .status(http::StatusCode::OK)
+            .header(http::header::CONTENT_TYPE, query.format().content_type())
+            .body(Body::with_content(body))
+            .map_err(|e| HttpError::for_internal_error(e.to_string()))
+    }
+
+    async fn get_machine(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<MachinePath>,
+    ) -> Result<HttpResponseOk<Machine>, HttpError> {
+        let machine = rqctx.context().get_machine(path.into_inner().machine)?;
+        Ok(HttpResponseOk(machine))
+    }
+
+    async fn head_machine(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<MachinePath>,
+    ) -> Result<Response<Body>, HttpError> {
+        rqctx.context().get_machine(path.into_inner().machine)?;
+        head_response(None)
+    }
+
+    async fn update_machine(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<MachinePath>,
+        query: Query<MachineActionQuery>,
+        body: TypedBody<serde_json::Value>,
+    ) -> Result<HttpResponseAccepted<()>, HttpError> {
+        rqctx.context().update_machine(
+            path.into_inner().machine,
+            query.into_inner().action,
+            &body.into_inner(),
+        )?;
+        Ok(HttpResponseAccepted(()))
+    }
+
+    async fn delete_machine(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<MachinePath>,
+    ) -> Result<HttpResponseDeleted, HttpError> {
+        rqctx.context().delete_machine(path.into_inner().machine)?;
+        Ok(HttpResponseDeleted())
+    }
+
+    async fn machine_audit(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<MachinePath>,
+    ) -> Result<HttpResponseOk<Vec<AuditEntry>>, HttpError> {
+        let audit = rqctx.context().machine_audit(path.into_inner().machine)?;
+        Ok(HttpResponseOk(audit))
+    }
+
+    // ========================================================================
+    // Images
+    // ========================================================================
+
+    async fn list_images(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<ListImagesQuery>,
+    ) -> Result<HttpResponseOk<Vec<Image>>, HttpError> {
+        Ok(HttpResponseOk(
+            rqctx.context().list_images(&query.into_inner()),
+        ))
+    }
+
+    async fn head_images(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<ListImagesQuery>,
+    ) -> Result<Response<Body>, HttpError> {
+        let images = rqctx.context().list_images(&query.into_inner());
+        head_response(Some(images.len()))
+    }
+
+    async fn get_image(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<ImagePath>,
+    ) -> Result<HttpResponseOk<Image>, HttpError> {
+        let image = rqctx.context().get_image(path.into_inner().dataset)?;
+        Ok(HttpResponseOk(image))
+    }
+
+    async fn head_image(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<ImagePath>,
+    ) -> Result<Response<Body>, HttpError> {
+        rqctx.context().get_image(path.into_inner().dataset)?;
+        head_response(None)
+    }
+
+    // ========================================================================
+    // Packages
+    // ========================================================================
+
+    async fn list_packages(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<ListPackagesQuery>,
+    ) -> Result<HttpResponseOk<Vec<Package>>, HttpError> {
+        Ok(HttpResponseOk(
+            rqctx.context().list_packages(&query.into_inner()),
+        ))
+    }
+
+    async fn head_packages(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        query: Query<ListPackagesQuery>,
+    ) -> Result<Response<Body>, HttpError> {
+        let packages = rqctx.context().list_packages(&query.into_inner());
+        head_response(Some(packages.len()))
+    }
+
+    async fn get_package(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<PackagePath>,
+    ) -> Result<HttpResponseOk<Package>, HttpError> {
+        let package = rqctx.context().get_package(&path.into_inner().package)?;
+        Ok(HttpResponseOk(package))
+    }
+
+    async fn head_package(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<PackagePath>,
+    ) -> Result<Response<Body>, HttpError> {
+        rqctx.context().get_package(&path.into_inner().package)?;
+        head_response(None)
+    }
+
+    // ========================================================================
+    // SSH Keys
+    // ========================================================================
+
+    async fn create_key(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        body: TypedBody<CreateSshKeyRequest>,
+    ) -> Result<HttpResponseCreated<SshKey>, HttpError> {
+        let key = rqctx.context().create_key(body.into_inner())?;
+        Ok(HttpResponseCreated(key))
+    }
+
+    async fn list_keys(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+    ) -> Result<HttpResponseOk<Vec<SshKey>>, HttpError> {
+        Ok(HttpResponseOk(rqctx.context().list_keys()?))
+    }
+
+    async fn head_keys(
+        rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+    ) -> Result<Response<Body>, HttpError> {
+        let keys = rqctx.context().list_keys()?;
+        head_response(Some(keys.len()))
+    }
+
+    async fn get_key(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<KeyPath>,
+    ) -> Result<HttpResponseOk<SshKey>, HttpError> {
+        let key = rqctx.context().get_key(&path.into_inner().name)?;
+        Ok(HttpResponseOk(key))
+    }
+
+    async fn head_key(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<KeyPath>,
+    ) -> Result<Response<Body>, HttpError> {
+        rqctx.context().get_key(&path.into_inner().name)?;
+        head_response(None)
+    }
+
+    async fn delete_key(
+        rqctx: RequestContext<Self::Context>,
+        path: Path<KeyPath>,
+    ) -> Result<HttpResponseDeleted, HttpError> {
+        rqctx.context().delete_key(&path.into_inner().name)?;
+        Ok(HttpResponseDeleted())
+    }
+
+    // ========================================================================
+    // WebSocket Channels
+    // ========================================================================
+
+    async fn watch_migration(
+        _rqctx: RequestContext<Self::Context>,
+        _path: Path<MachinePath>,
+        _upgraded: WebsocketConnection,
+    ) -> WebsocketChannelResult {
+        Err("watch_migration is not implemented by the stub server".into())
+    }
+
+    async fn get_changefeed(
+        _rqctx: RequestContext<Self::Context>,
+        _path: Path<AccountPath>,
+        _upgraded: WebsocketConnection,
+    ) -> WebsocketChannelResult {
+        Err("get_changefeed is not implemented by the stub server".into())
+    }
+
+    async fn get_machine_vnc(
+        _rqctx: RequestContext<Self::Context>,
+        _path: Path<MachinePath>,
+        _upgraded: WebsocketConnection,
+    ) -> WebsocketChannelResult {
+        Err("get_machine_vnc is not implemented by the stub server".into())
+    }
+
+    // ========================================================================
+    // Unimplemented Endpoints
+    // ========================================================================
+
+    not_implemented! {
+        get_account(_path: Path<AccountPath>) -> HttpResponseOk<Account>;
+        head_account(_path: Path<AccountPath>) -> Response<Body>;
+        update_account(
+            _path: Path<AccountPath>,
+            _body: TypedBody<UpdateAccountRequest>,
+        ) -> HttpResponseOk<Account>;
+        get_provisioning_limits(_path: Path<AccountPath>) -> HttpResponseOk<ProvisioningLimits>;
+        head_audit(_path: Path<MachinePath>) -> Response<Body>;
+        add_machine_metadata(
+            _path: Path<MachinePath>,
+            _body: TypedBody<AddMetadataRequest>,
+        ) -> HttpResponseOk<Metadata>;
+        list_machine_metadata(_path: Path<MachinePath>) -> HttpResponseOk<Metadata>;
+        head_machine_metadata(_path: Path<MachinePath>) -> Response<Body>;
+        get_machine_metadata(_path: Path<MetadataKeyPath>) -> HttpResponseOk<String>;
+        head_machine_metadata_key(_path: Path<MetadataKeyPath>) -> Response<Body>;
+        delete_all_machine_metadata(_path: Path<MachinePath>) -> HttpResponseDeleted;
+        delete_machine_metadata(_path: Path<MetadataKeyPath>) -> HttpResponseDeleted;
+        add_machine_tags(
+            _path: Path<MachinePath>,
+            _body: TypedBody<TagsRequest>,
+        ) -> HttpResponseOk<Tags>;
+        replace_machine_tags(
+            _path: Path<MachinePath>,
+            _body: TypedBody<TagsRequest>,
+        ) -> HttpResponseOk<Tags>;
+        list_machine_tags(_path: Path<MachinePath>) -> HttpResponseOk<Tags>;
+        head_machine_tags(_path: Path<MachinePath>) -> Response<Body>;
+        get_machine_tag(_path: Path<TagPath>) -> HttpResponseOk<String>;
+        head_machine_tag(_path: Path<TagPath>) -> Response<Body>;
+        delete_machine_tags(_path: Path<MachinePath>) -> HttpResponseDeleted;
+        delete_machine_tag(_path: Path<TagPath>) -> HttpResponseDeleted;
+        create_machine_snapshot(
+            _path: Path<MachinePath>,
+            _body: TypedBody<CreateSnapshotRequest>,
+        ) -> HttpResponseCreated<Snapshot>;
+        start_machine_from_snapshot(_path: Path<SnapshotPath>) -> HttpResponseAccepted<()>;
+        list_machine_snapshots(_path: Path<MachinePath>) -> HttpResponseOk<Vec<Snapshot>>;
+        head_machine_snapshots(_path: Path<MachinePath>) -> Response<Body>;
+        get_machine_snapshot(_path: Path<SnapshotPath>) -> HttpResponseOk<Snapshot>;
+        head_machine_snapshot(_path: Path<SnapshotPath>) -> Response<Body>;
+        delete_machine_snapshot(_path: Path<SnapshotPath>) -> HttpResponseDeleted;
+        add_nic(
+            _path: Path<MachinePath>,
+            _body: TypedBody<AddNicRequest>,
+        ) -> HttpResponseCreated<Nic>;
+        list_nics(_path: Path<MachinePath>) -> HttpResponseOk<Vec<Nic>>;
+        head_nics(_path: Path<MachinePath>) -> Response<Body>;
+        get_nic(_path: Path<NicPath>) -> HttpResponseOk<Nic>;
+        head_nic(_path: Path<NicPath>) -> Response<Body>;
+        remove_nic(_path: Path<NicPath>) -> HttpResponseDeleted;
+        create_machine_disk(
+            _path: Path<MachinePath>,
+            _body: TypedBody<CreateDiskRequest>,
+        ) -> HttpResponseCreated<Disk>;
+        list_machine_disks(_path: Path<MachinePath>) -> HttpResponseOk<Vec<Disk>>;
+        head_machine_disks(_path: Path<MachinePath>) -> Response<Body>;
+        get_machine_disk(_path: Path<DiskPath>) -> HttpResponseOk<Disk>;
+        head_machine_disk(_path: Path<DiskPath>) -> Response<Body>;
+        resize_machine_disk(
+            _path: Path<DiskPath>,
+            _query: Query<DiskActionQuery>,
+            _body: TypedBody<serde_json::Value>,
+        ) -> HttpResponseOk<Disk>;
+        delete_machine_disk(_path: Path<DiskPath>) -> HttpResponseDeleted;
+        create_or_import_image(
+            _path: Path<AccountPath>,
+            _query: Query<ImageCollectionActionQuery>,
+            _body: TypedBody<serde_json::Value>,
+        ) -> HttpResponseCreated<Image>;
+        update_image(
+            _path: Path<ImagePath>,
+            _query: Query<ImageActionQuery>,
+            _body: TypedBody<serde_json::Value>,
+        ) -> HttpResponseOk<Image>;
+        delete_image(_path: Path<ImagePath>) -> HttpResponseDeleted;
+        list_networks(_path: Path<AccountPath>) -> HttpResponseOk<Vec<Network>>;
+        head_networks(_path: Path<AccountPath>) -> Response<Body>;
+        get_network(_path: Path<NetworkPath>) -> HttpResponseOk<Network>;
+        head_network(_path: Path<NetworkPath>) -> Response<Body>;
+        list_network_ips(_path: Path<NetworkPath>) -> HttpResponseOk<Vec<NetworkIp>>;
+        head_network_ips(_path: Path<NetworkPath>) -> Response<Body>;
+        get_network_ip(_path: Path<NetworkIpPath>) -> HttpResponseOk<NetworkIp>;
+        head_network_ip(_path: Path<NetworkIpPath>) -> Response<Body>;
+        update_network_ip(
+            _path: Path<NetworkIpPath>,
+            _body: TypedBody<UpdateNetworkIpRequest>,
+        ) -> HttpResponseOk<NetworkIp>;
+        list_fabric_vlans(_path: Path<AccountPath>) -> HttpResponseOk<Vec<FabricVlan>>;
+        head_fabric_vlans(_path: Path<AccountPath>) -> Response<Body>;
+        create_fabric_vlan(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreateFabricVlanRequest>,
+        ) -> HttpResponseCreated<FabricVlan>;
+        get_fabric_vlan(_path: Path<FabricVlanPath>) -> HttpResponseOk<FabricVlan>;
+        head_fabric_vlan(_path: Path<FabricVlanPath>) -> Response<Body>;
+        update_fabric_vlan(
+            _path: Path<FabricVlanPath>,
+            _body: TypedBody<UpdateFabricVlanRequest>,
+        ) -> HttpResponseAccepted<FabricVlan>;
+        delete_fabric_vlan(_path: Path<FabricVlanPath>) -> HttpResponseDeleted;
+        list_fabric_networks(_path: Path<FabricVlanPath>) -> HttpResponseOk<Vec<Network>>;
+        head_fabric_networks(_path: Path<FabricVlanPath>) -> Response<Body>;
+        create_fabric_network(
+            _path: Path<FabricVlanPath>,
+            _body: TypedBody<CreateFabricNetworkRequest>,
+        ) -> HttpResponseCreated<Network>;
+        get_fabric_network(_path: Path<FabricNetworkPath>) -> HttpResponseOk<Network>;
+        head_fabric_network(_path: Path<FabricNetworkPath>) -> Response<Body>;
+        update_fabric_network(
+            _path: Path<FabricNetworkPath>,
+            _body: TypedBody<UpdateFabricNetworkRequest>,
+        ) -> HttpResponseOk<Network>;
+        delete_fabric_network(_path: Path<FabricNetworkPath>) -> HttpResponseDeleted;
+        create_firewall_rule(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreateFirewallRuleRequest>,
+        ) -> HttpResponseCreated<FirewallRule>;
+        list_firewall_rules(_path: Path<AccountPath>) -> HttpResponseOk<Vec<FirewallRule>>;
+        head_firewall_rules(_path: Path<AccountPath>) -> Response<Body>;
+        get_firewall_rule(_path: Path<FirewallRulePath>) -> HttpResponseOk<FirewallRule>;
+        head_firewall_rule(_path: Path<FirewallRulePath>) -> Response<Body>;
+        update_firewall_rule(
+            _path: Path<FirewallRulePath>,
+            _body: TypedBody<UpdateFirewallRuleRequest>,
+        ) -> HttpResponseOk<FirewallRule>;
+        enable_firewall_rule(_path: Path<FirewallRulePath>) -> HttpResponseOk<FirewallRule>;
+        disable_firewall_rule(_path: Path<FirewallRulePath>) -> HttpResponseOk<FirewallRule>;
+        delete_firewall_rule(_path: Path<FirewallRulePath>) -> HttpResponseDeleted;
+        list_firewall_rule_machines(_path: Path<FirewallRulePath>) -> HttpResponseOk<Vec<Machine>>;
+        head_firewall_rule_machines(_path: Path<FirewallRulePath>) -> Response<Body>;
+        list_machine_firewall_rules(_path: Path<MachinePath>) -> HttpResponseOk<Vec<FirewallRule>>;
+        head_machine_firewall_rules(_path: Path<MachinePath>) -> Response<Body>;
+        create_user(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreateUserRequest>,
+        ) -> HttpResponseCreated<User>;
+        list_users(_path: Path<AccountPath>) -> HttpResponseOk<Vec<User>>;
+        head_users(_path: Path<AccountPath>) -> Response<Body>;
+        get_user(_path: Path<UserPath>) -> HttpResponseOk<User>;
+        head_user(_path: Path<UserPath>) -> Response<Body>;
+        update_user(
+            _path: Path<UserPath>,
+            _body: TypedBody<UpdateUserRequest>,
+        ) -> HttpResponseOk<User>;
+        change_user_password(
+            _path: Path<UserPath>,
+            _body: TypedBody<ChangePasswordRequest>,
+        ) -> HttpResponseOk<User>;
+        delete_user(_path: Path<UserPath>) -> HttpResponseDeleted;
+        create_role(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreateRoleRequest>,
+        ) -> HttpResponseCreated<Role>;
+        list_roles(_path: Path<AccountPath>) -> HttpResponseOk<Vec<Role>>;
+        head_roles(_path: Path<AccountPath>) -> Response<Body>;
+        get_role(_path: Path<RolePath>) -> HttpResponseOk<Role>;
+        head_role(_path: Path<RolePath>) -> Response<Body>;
+        update_role(
+            _path: Path<RolePath>,
+            _body: TypedBody<UpdateRoleRequest>,
+        ) -> HttpResponseOk<Role>;
+        delete_role(_path: Path<RolePath>) -> HttpResponseDeleted;
+        create_policy(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreatePolicyRequest>,
+        ) -> HttpResponseCreated<Policy>;
+        list_policies(_path: Path<AccountPath>) -> HttpResponseOk<Vec<Policy>>;
+        head_policies(_path: Path<AccountPath>) -> Response<Body>;
+        get_policy(_path: Path<PolicyPath>) -> HttpResponseOk<Policy>;
+        head_policy(_path: Path<PolicyPath>) -> Response<Body>;
+        update_policy(
+            _path: Path<PolicyPath>,
+            _body: TypedBody<UpdatePolicyRequest>,
+        ) -> HttpResponseOk<Policy>;
+        delete_policy(_path: Path<PolicyPath>) -> HttpResponseDeleted;
+        create_user_key(
+            _path: Path<UserPath>,
+            _body: TypedBody<CreateSshKeyRequest>,
+        ) -> HttpResponseCreated<SshKey>;
+        list_user_keys(_path: Path<UserPath>) -> HttpResponseOk<Vec<SshKey>>;
+        head_user_keys(_path: Path<UserPath>) -> Response<Body>;
+        get_user_key(_path: Path<UserKeyPath>) -> HttpResponseOk<SshKey>;
+        head_user_key(_path: Path<UserKeyPath>) -> Response<Body>;
+        delete_user_key(_path: Path<UserKeyPath>) -> HttpResponseDeleted;
+        create_access_key(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreateAccessKeyRequest>,
+        ) -> HttpResponseCreated<CreateAccessKeyResponse>;
+        list_access_keys(_path: Path<AccountPath>) -> HttpResponseOk<Vec<AccessKey>>;
+        head_access_keys(_path: Path<AccountPath>) -> Response<Body>;
+        get_access_key(_path: Path<AccessKeyPath>) -> HttpResponseOk<AccessKey>;
+        head_access_key(_path: Path<AccessKeyPath>) -> Response<Body>;
+        update_access_key(
+            _path: Path<AccessKeyPath>,
+            _body: TypedBody<UpdateAccessKeyRequest>,
+        ) -> HttpResponseOk<AccessKey>;
+        delete_access_key(_path: Path<AccessKeyPath>) -> HttpResponseDeleted;
+        create_user_access_key(
+            _path: Path<UserPath>,
+            _body: TypedBody<CreateAccessKeyRequest>,
+        ) -> HttpResponseCreated<CreateAccessKeyResponse>;
+        list_user_access_keys(_path: Path<UserPath>) -> HttpResponseOk<Vec<AccessKey>>;
+        head_user_access_keys(_path: Path<UserPath>) -> Response<Body>;
+        get_user_access_key(_path: Path<UserAccessKeyPath>) -> HttpResponseOk<AccessKey>;
+        head_user_access_key(_path: Path<UserAccessKeyPath>) -> Response<Body>;
+        update_user_access_key(
+            _path: Path<UserAccessKeyPath>,
+            _body: TypedBody<UpdateAccessKeyRequest>,
+        ) -> HttpResponseOk<AccessKey>;
+        delete_user_access_key(_path: Path<UserAccessKeyPath>) -> HttpResponseDeleted;
+        get_config(_path: Path<AccountPath>) -> HttpResponseOk<Config>;
+        head_config(_path: Path<AccountPath>) -> Response<Body>;
+        update_config(
+            _path: Path<AccountPath>,
+            _body: TypedBody<UpdateConfigRequest>,
+        ) -> HttpResponseOk<Config>;
+        list_datacenters(_path: Path<AccountPath>) -> HttpResponseOk<Datacenters>;
+        get_datacenter(_path: Path<DatacenterPath>) -> HttpResponseFound;
+        list_foreign_datacenters(_path: Path<AccountPath>) -> HttpResponseOk<Datacenters>;
+        add_foreign_datacenter(
+            _path: Path<AccountPath>,
+            _body: TypedBody<AddForeignDatacenterRequest>,
+        ) -> HttpResponseCreated<Datacenter>;
+        list_services(_path: Path<AccountPath>) -> HttpResponseOk<Services>;
+        list_volume_sizes(_path: Path<AccountPath>) -> HttpResponseOk<Vec<VolumeSize>>;
+        get_volume(_path: Path<VolumePath>) -> HttpResponseOk<Volume>;
+        list_volumes(
+            _path: Path<AccountPath>,
+            _query: Query<ListVolumesQuery>,
+        ) -> HttpResponseOk<Vec<Volume>>;
+        create_volume(
+            _path: Path<AccountPath>,
+            _body: TypedBody<CreateVolumeRequest>,
+        ) -> HttpResponseCreated<Volume>;
+        delete_volume(_path: Path<VolumePath>) -> HttpResponseDeleted;
+        update_volume(
+            _path: Path<VolumePath>,
+            _query: Query<VolumeActionQuery>,
+            _body: TypedBody<serde_json::Value>,
+        ) -> HttpResponseOk<Volume>;
+        list_migrations(_path: Path<AccountPath>) -> HttpResponseOk<Vec<Migration>>;
+        get_migration(_path: Path<MachinePath>) -> HttpResponseOk<Migration>;
+        migrate_machine_estimate(_path: Path<MachinePath>) -> HttpResponseOk<MigrationEstimate>;
+        migrate(
+            _path: Path<MachinePath>,
+            _query: Query<MigrationActionQuery>,
+            _body: TypedBody<MigrateRequest>,
+        ) -> HttpResponseOk<Migration>;
+        replace_account_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_machine_role_tags(
+            _path: Path<MachinePath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_users_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_roles_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_packages_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_images_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_policies_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_keys_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_datacenters_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_fwrules_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_networks_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_services_collection_role_tags(
+            _path: Path<AccountPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_user_role_tags(
+            _path: Path<UserPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_role_role_tags(
+            _path: Path<RolePath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_package_role_tags(
+            _path: Path<PackagePath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_image_role_tags(
+            _path: Path<ImagePath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_policy_role_tags(
+            _path: Path<PolicyPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_key_role_tags(
+            _path: Path<KeyPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_fwrule_role_tags(
+            _path: Path<FirewallRulePath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_network_role_tags(
+            _path: Path<NetworkPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_user_keys_collection_role_tags(
+            _path: Path<UserPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+        replace_user_key_role_tags(
+            _path: Path<UserKeyPath>,
+            _body: TypedBody<ReplaceRoleTagsRequest>,
+        ) -> HttpResponseOk<RoleTagsResponse>;
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Load a JSON array fixture
+fn load_fixture<T: serde::de::DeserializeOwned>(path: &std::path::Path) -> Result<Vec<T>> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Map a provisioning brand onto the brand reported for machines
+fn vmapi_brand(brand: Brand) -> VmapiBrand {
+    match brand {
+        Brand::Bhyve => VmapiBrand::Bhyve,
+        Brand::Joyent => VmapiBrand::Joyent,
+        Brand::JoyentMinimal => VmapiBrand::JoyentMinimal,
+        Brand::Kvm => VmapiBrand::Kvm,
+        Brand::Lx => VmapiBrand::Lx,
+    }
+}
+
+/// Compare a tag value against a `tag.KEY=VALUE` filter
+fn tag_matches(value: &serde_json::Value, expected: &str) -> bool {
+    match value {
+        serde_json::Value::String(s) => s == expected,
+        other => other.to_string() == expected,
+    }
+}
+
+/// Get a required string field from an action request body
+fn body_str<'a>(body: &'a serde_json::Value, field: &str) -> Result<&'a str, HttpError> {
+    body.get(field)
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| missing_parameter(field))
+}
+
+/// Build the empty response for a HEAD request, with the `x-resource-count`
+/// header CloudAPI sends for collections
+fn head_response(count: Option<usize>) -> Result<Response<Body>, HttpError> {
+    let mut builder = Response::builder().status(http::StatusCode::OK);
+    if let Some(count) = count {
+        builder = builder.header("x-resource-count", count);
+    }
+    builder
+        .body(Body::empty())
+        .map_err(|e| HttpError::for_internal_error(e.to_string()))
+}
+
+fn not_found(kind: &str, id: impl std::fmt::Display) -> HttpError {
+    HttpError::for_client_error(
+        Some(ErrorCode::ResourceNotFound.to_string()),
+        ClientErrorStatusCode::NOT_FOUND,
+        format!("{} {} not found", kind, id),
+    )
+}
+
+fn invalid_argument(message: String) -> HttpError {
+    HttpError::for_client_error(
+        Some(ErrorCode::InvalidArgument.to_string()),
+        ClientErrorStatusCode::CONFLICT,
+        message,
+    )
+}
+
+fn missing_parameter(name: &str) -> HttpError {
+    HttpError::for_client_error(
+        Some(ErrorCode::MissingParameter.to_string()),
+        ClientErrorStatusCode::CONFLICT,
+        format!("{} is required", name),
+    )
+}
+
+fn not_implemented(endpoint: &str) -> HttpError {
+    HttpError::for_client_error(
+        Some("NotImplemented".to_string()),
+        ClientErrorStatusCode::NOT_FOUND,
+        format!("{} is not implemented by the stub server", endpoint),
+    )
+}
+
+/// Create the Dropshot API description for the stub server
+pub fn api_description() -> Result<dropshot::ApiDescription<Arc<StubContext>>, String> {
+    cloudapi_api::cloud_api_mod::api_description::<StubCloudApi>().map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_64_LTS: &str = "2f1dc911-6401-4fa4-8e9d-67ea2e39c271";
+    const UBUNTU: &str = "a3a4d5a6-3c52-4b6b-9f0a-2c1e8f3b7d41";
+
+    const TEST_KEY: &str = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAINZEtXYPlEJl8TMYMVYaJsWRpeMb/M7wLJZ+7cVV075J stub@example.com";
+    const TEST_KEY_FP: &str = "ee:de:b7:fa:5a:43:b8:e4:6a:e6:ff:77:ee:a8:f3:d9";
+
+    fn context() -> StubContext {
+        let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        StubContext::from_fixtures(&fixtures_dir).expect("Failed to load fixtures")
+    }
+
+    fn create(ctx: &StubContext, body: serde_json::Value) -> Machine {
+        let req: CreateMachineRequest = serde_json::from_value(body).unwrap();
+        ctx.create_machine(req).unwrap()
+    }
+
+    fn create_base(ctx: &StubContext, name: &str) -> Machine {
+        create(
+            ctx,
+            serde_json::json!({
+                "name": name,
+                "image": BASE_64_LTS,
+                "package": "g1.small",
+            }),
+        )
+    }
+
+    fn action(ctx: &StubContext, id: Uuid, action: &str) -> Result<(), HttpError> {
+        ctx.update_machine(id, None, &serde_json::json!({ "action": action }))
+    }
+
+    fn list(ctx: &StubContext, query: serde_json::Value) -> Vec<Machine> {
+        let query: ListMachinesQuery = serde_json::from_value(query).unwrap();
+        ctx.list_machines(&query).unwrap()
+    }
+
+    #[test]
+    fn test_load_fixtures() {
+        let ctx = context();
+        assert_eq!(ctx.image_count(), 3);
+        assert_eq!(ctx.package_count(), 3);
+        assert!(ctx.get_package("g1.small").is_ok());
+        assert!(ctx.get_image(UBUNTU.parse().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_provision_settles_after_polls() {
+        let ctx = context();
+        let machine = create_base(&ctx, "web0");
+        assert_eq!(machine.state, MachineState::Provisioning);
+        assert_eq!(machine.brand, VmapiBrand::Joyent);
+        assert_eq!(machine.memory, Some(1024));
+        assert_eq!(machine.primary_ip.as_deref(), Some("10.88.0.2"));
+
+        let first = ctx.get_machine(machine.id).unwrap();
+        assert_eq!(first.state, MachineState::Provisioning);
+        let second = ctx.get_machine(machine.id).unwrap();
+        assert_eq!(second.state, MachineState::Running);
+
+        let audit = ctx.machine_audit(machine.id).unwrap();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].action, "provision");
+    }
+
+    #[test]
+    fn test_machine_summary() {
+        let ctx = context().with_transition_polls(0);
+        let web0 = create_base(&ctx, "web0");
+        create_base(&ctx, "web1");
+        let db0 = create_base(&ctx, "db0");
+        action(&ctx, web0.id, "stop").unwrap();
+        ctx.delete_machine(db0.id).unwrap();
+
+        let summary = ctx.machine_summary().unwrap();
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.states.get("running"), Some(&1));
+        assert_eq!(summary.states.get("stopped"), Some(&1));
+        assert!(!summary.states.contains_key("deleted"));
+        assert_eq!(summary.brands.get("joyent"), Some(&2));
+        assert_eq!(summary.packages.get("g1.small"), Some(&2));
+    }
+
+    #[test]
+    fn test_zero_polls_settles_immediately() {
+        let ctx = context().with_transition_polls(0);
+        let machine = create_base(&ctx, "web0");
+        assert_eq!(machine.state, MachineState::Running);
+
+        action(&ctx, machine.id, "stop").unwrap();
+        assert_eq!(
+            ctx.get_machine(machine.id).unwrap().state,
+            MachineState::Stopped
+        );
+    }
+
+    #[test]
+    fn test_brand_from_image() {
+        let ctx = context();
+        let machine = create(
+            &ctx,
+            serde_json::json!({ "image": UBUNTU, "package": "b1.medium" }),
+        );
+        assert_eq!(machine.brand, VmapiBrand::Bhyve);
+        assert_eq!(machine.machine_type, MachineType::Virtualmachine);
+        // Unnamed machines get the short form of their UUID
+        assert_eq!(machine.name, machine.id.to_string()[..8]);
+    }
+
+    #[test]
+    fn test_lifecycle_actions() {
+        let ctx = context().with_transition_polls(1);
+        let id = create_base(&ctx, "web0").id;
+        ctx.get_machine(id).unwrap();
+
+        // Cannot start a running machine
+        assert!(action(&ctx, id, "start").is_err());
+
+        action(&ctx, id, "stop").unwrap();
+        // Cannot act on a machine mid-transition
+        assert!(action(&ctx, id, "start").is_err());
+        assert_eq!(ctx.get_machine(id).unwrap().state, MachineState::Stopped);
+
+        // The query parameter is used when the body has no action
+        ctx.update_machine(id, Some(MachineAction::Start), &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(ctx.get_machine(id).unwrap().state, MachineState::Running);
+
+        action(&ctx, id, "reboot").unwrap();
+        assert_eq!(ctx.get_machine(id).unwrap().state, MachineState::Running);
+
+        let actions: Vec<String> = ctx
+            .machine_audit(id)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.action)
+            .collect();
+        assert_eq!(actions, vec!["reboot", "start", "stop", "provision"]);
+    }
+
+    #[test]
+    fn test_missing_action() {
+        let ctx = context();
+        let id = create_base(&ctx, "web0").id;
+        assert!(
+            ctx.update_machine(id, None, &serde_json::json!({}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_immediate_actions() {
+        let ctx = context();
+        let id = create_base(&ctx, "web0").id;
+
+        ctx.update_machine(
+            id,
+            None,
+            &serde_json::json!({ "action": "resize", "package": "g1.medium" }),
+        )
+        .unwrap();
+        ctx.update_machine(
+            id,
+            None,
+            &serde_json::json!({ "action": "rename", "name": "web1" }),
+        )
+        .unwrap();
+        action(&ctx, id, "enable_firewall").unwrap();
+
+        let machine = ctx.get_machine(id).unwrap();
+        assert_eq!(machine.package, "g1.medium");
+        assert_eq!(machine.memory, Some(4096));
+        assert_eq!(machine.name, "web1");
+        assert_eq!(machine.firewall_enabled, Some(true));
+    }
+
+    #[test]
+    fn test_delete() {
+        let ctx = context().with_transition_polls(1);
+        let id = create(
+            &ctx,
+            serde_json::json!({
+                "image": BASE_64_LTS,
+                "package": "g1.small",
+                "deletion_protection": true,
+            }),
+        )
+        .id;
+
+        assert!(ctx.delete_machine(id).is_err());
+        action(&ctx, id, "disable_deletion_protection").unwrap();
+        ctx.delete_machine(id).unwrap();
+
+        // Deleted machines can still be fetched, but not listed or acted on
+        assert_eq!(ctx.get_machine(id).unwrap().state, MachineState::Deleted);
+        assert!(list(&ctx, serde_json::json!({})).is_empty());
+        assert_eq!(
+            list(&ctx, serde_json::json!({ "tombstone": true })).len(),
+            1
+        );
+        assert!(ctx.delete_machine(id).is_err());
+        assert!(action(&ctx, id, "start").is_err());
+    }
+
+    #[test]
+    fn test_list_filters_and_pagination() {
+        let ctx = context();
+        for i in 0..5 {
+            create(
+                &ctx,
+                serde_json::json!({
+                    "name": format!("web{}", i),
+                    "image": BASE_64_LTS,
+                    "package": "g1.small",
+                    "tags": { "role": if i % 2 == 0 { "web" } else { "db" } },
+                }),
+            );
+        }
+
+        let names = |machines: Vec<Machine>| -> Vec<String> {
+            machines.into_iter().map(|m| m.name).collect()
+        };
+
+        assert_eq!(
+            names(list(&ctx, serde_json::json!({ "offset": 1, "limit": 2 }))),
+            vec!["web1", "web2"]
+        );
+        assert_eq!(
+            names(list(&ctx, serde_json::json!({ "tag": "role=db" }))),
+            vec!["web1", "web3"]
+        );
+        assert_eq!(
+            names(list(
+                &ctx,
+                serde_json::json!({ "tag.role": "web", "limit": 2 })
+            )),
+            vec!["web0", "web2"]
+        );
+
+        // Each list is a read of every machine, so by now all have settled
+        assert_eq!(
+            list(&ctx, serde_json::json!({ "state": "running" })).len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_unknown_image_or_package() {
+        let ctx = context();
+        let req: CreateMachineRequest = serde_json::from_value(serde_json::json!({
+            "image": "00000000-0000-0000-0000-000000000001",
+            "package": "g1.small",
+        }))
+        .unwrap();
+        assert!(ctx.create_machine(req).is_err());
+
+        let req: CreateMachineRequest = serde_json::from_value(serde_json::json!({
+            "image": BASE_64_LTS,
+            "package": "nope",
+        }))
+        .unwrap();
+        assert!(ctx.create_machine(req).is_err());
+    }
+
+    #[test]
+    fn test_list_images() {
+        let ctx = context();
+        let query: ListImagesQuery =
+            serde_json::from_value(serde_json::json!({ "os": "linux" })).unwrap();
+        let images = ctx.list_images(&query);
+        assert_eq!(images.len(), 2);
+        assert!(images.iter().all(|i| i.os == "linux"));
+
+        let first: ListImagesQuery =
+            serde_json::from_value(serde_json::json!({ "os": "linux", "limit": 1 })).unwrap();
+        let page = ctx.list_images(&first);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, images[0].id);
+
+        let next: ListImagesQuery = serde_json::from_value(serde_json::json!({
+            "os": "linux",
+            "limit": 1,
+            "marker": page[0].id,
+        }))
+        .unwrap();
+        let page = ctx.list_images(&next);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, images[1].id);
+    }
+
+    #[test]
+    fn test_keys() {
+        let ctx = context();
+        let key = ctx
+            .create_key(CreateSshKeyRequest {
+                name: "laptop".to_string(),
+                key: TEST_KEY.to_string(),
+            })
+            .unwrap();
+        assert_eq!(key.fingerprint, TEST_KEY_FP);
+
+        assert_eq!(ctx.get_key(TEST_KEY_FP).unwrap().name, "laptop");
+        assert!(
+            ctx.create_key(CreateSshKeyRequest {
+                name: "laptop".to_string(),
+                key: TEST_KEY.to_string(),
+            })
+            .is_err()
+        );
+        assert!(
+            ctx.create_key(CreateSshKeyRequest {
+                name: "bogus".to_string(),
+                key: "ssh-ed25519 not-base64".to_string(),
+            })
+            .is_err()
+        );
+
+        ctx.delete_key("laptop").unwrap();
+        assert!(ctx.list_keys().unwrap().is_empty());
+        assert!(ctx.delete_key("laptop").is_err());
+    }
+}
@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Standalone stub CloudAPI server for testing and development
+//!
+//! Run with:
+//! ```bash
+//! cargo run -p cloudapi-stub-server
+//! ```
+//!
+//! Then point a client at it:
+//! ```bash
+//! curl -s http://localhost:9091/my/images | jq .
+//! ```
+
+use anyhow::Result;
+use dropshot::{ConfigDropshot, ConfigLogging, ConfigLoggingLevel, HttpServerStarter};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use cloudapi_stub_server::{StubContext, api_description};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize logging
+    let log_config = ConfigLogging::StderrTerminal {
+        level: ConfigLoggingLevel::Info,
+    };
+    let log = log_config.to_logger("cloudapi-stub-server")?;
+
+    // Load fixture data
+    let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let context = Arc::new(StubContext::from_fixtures(&fixtures_dir)?);
+
+    tracing::info!(
+        "Loaded {} images and {} packages from fixtures",
+        context.image_count(),
+        context.package_count()
+    );
+
+    // Configure the server
+    let config = ConfigDropshot {
+        bind_address: SocketAddr::from((Ipv4Addr::LOCALHOST, 9091)),
+        default_request_body_max_bytes: 1024 * 1024,
+        default_handler_task_mode: dropshot::HandlerTaskMode::Detached,
+        ..Default::default()
+    };
+
+    // Create and start the server
+    let api = api_description().map_err(|e| anyhow::anyhow!(e))?;
+    let server = HttpServerStarter::new(&config, api, context, &log)
+        .map_err(|e| anyhow::anyhow!("Failed to create server: {}", e))?
+        .start();
+
+    tracing::info!("Stub CloudAPI server listening on http://localhost:9091");
+
+    server
+        .await
+        .map_err(|e| anyhow::anyhow!("Server error: {}", e))
+}
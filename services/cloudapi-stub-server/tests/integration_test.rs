@@ -0,0 +1,274 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Integration tests for cloudapi-stub-server
+//!
+//! These tests run the stub over HTTP to check that the Dropshot wiring
+//! matches the CloudAPI wire format that clients expect.
+
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+
+/// Start the stub server on an ephemeral port, returning its base URL
+fn start_server() -> Option<(
+    dropshot::HttpServer<Arc<cloudapi_stub_server::StubContext>>,
+    String,
+)> {
+    let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let context = Arc::new(
+        cloudapi_stub_server::StubContext::from_fixtures(&fixtures_dir)
+            .unwrap()
+            .with_transition_polls(2),
+    );
+    let api = cloudapi_stub_server::api_description().expect("cloudapi api description");
+
+    let config = dropshot::ConfigDropshot {
+        bind_address: "127.0.0.1:0".parse().unwrap(),
+        default_request_body_max_bytes: 1024 * 1024,
+        default_handler_task_mode: dropshot::HandlerTaskMode::Detached,
+        ..Default::default()
+    };
+    let log = dropshot::ConfigLogging::StderrTerminal {
+        level: dropshot::ConfigLoggingLevel::Warn,
+    }
+    .to_logger("cloudapi-stub-test")
+    .expect("logger");
+
+    match dropshot::HttpServerStarter::new(&config, api, context, &log) {
+        Ok(starter) => {
+            let server = starter.start();
+            let base_url = format!("http://{}", server.local_addr());
+            Some((server, base_url))
+        }
+        Err(e) => {
+            if std::env::var("CI").is_ok() {
+                panic!("Failed to start CloudAPI stub in CI: {}", e);
+            }
+            eprintln!(
+                "SKIPPING: failed to start cloudapi stub: {} (set CI=1 to fail)",
+                e
+            );
+            None
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_machine_lifecycle_over_http() {
+    triton_tls::install_default_crypto_provider();
+    let Some((server, base_url)) = start_server() else {
+        return;
+    };
+    let http = reqwest::Client::new();
+
+    // Provision a machine
+    let resp = http
+        .post(format!("{}/stub/machines", base_url))
+        .json(&json!({
+            "name": "web0",
+            "image": "2f1dc911-6401-4fa4-8e9d-67ea2e39c271",
+            "package": "g1.small",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+    let machine: Value = resp.json().await.unwrap();
+    assert_eq!(machine["state"], "provisioning");
+    let machine_url = format!(
+        "{}/stub/machines/{}",
+        base_url,
+        machine["id"].as_str().unwrap()
+    );
+
+    // The second poll sees the machine running
+    let mut states = Vec::new();
+    for _ in 0..2 {
+        let machine: Value = http
+            .get(&machine_url)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        states.push(machine["state"].clone());
+    }
+    assert_eq!(states, vec![json!("provisioning"), json!("running")]);
+
+    // Stop it with the action in the query string
+    let resp = http
+        .post(format!("{}?action=stop", machine_url))
+        .json(&json!({}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::ACCEPTED);
+
+    // Listing counts as a read, and honors limit/offset
+    let page: Vec<Value> = http
+        .get(format!("{}/stub/machines?limit=1&offset=0", base_url))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0]["state"], "stopping");
+
+    let resp = http
+        .head(format!("{}/stub/machines", base_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get("x-resource-count")
+            .and_then(|v| v.to_str().ok()),
+        Some("1")
+    );
+
+    let machine: Value = http
+        .get(&machine_url)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(machine["state"], "stopped");
+
+    server.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_errors_over_http() {
+    triton_tls::install_default_crypto_provider();
+    let Some((server, base_url)) = start_server() else {
+        return;
+    };
+    let http = reqwest::Client::new();
+
+    let resp = http
+        .get(format!(
+            "{}/stub/images/00000000-0000-0000-0000-000000000001",
+            base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::NOT_FOUND);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["error_code"], "ResourceNotFound");
+
+    // Endpoints outside the stub's coverage say so
+    let resp = http
+        .get(format!("{}/stub/networks", base_url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::NOT_FOUND);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["error_code"], "NotImplemented");
+
+    server.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_unauthenticated_typed_client() {
+    triton_tls::install_default_crypto_provider();
+    let Some((server, base_url)) = start_server() else {
+        return;
+    };
+    let client = cloudapi_client::TypedClient::new_unauthenticated(&base_url, "stub");
+
+    let packages = client.list_packages("stub").await.unwrap();
+    assert_eq!(packages.len(), 3);
+    let package = client.get_package("stub", "g1.small").await.unwrap();
+    assert_eq!(package.name, "g1.small");
+
+    let image = client
+        .resolve_image("stub", "base-64-lts", None)
+        .await
+        .unwrap();
+    assert_eq!(image.to_string(), "2f1dc911-6401-4fa4-8e9d-67ea2e39c271");
+
+    server.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_export_machines_typed_client() {
+    use cloudapi_client::{ListMachinesFilter, MachineColumn, types};
+
+    triton_tls::install_default_crypto_provider();
+    let Some((server, base_url)) = start_server() else {
+        return;
+    };
+    let http = reqwest::Client::new();
+    for name in ["web0", "db,primary"] {
+        let resp = http
+            .post(format!("{}/stub/machines", base_url))
+            .json(&json!({
+                "name": name,
+                "image": "2f1dc911-6401-4fa4-8e9d-67ea2e39c271",
+                "package": "g1.small",
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+    }
+    let client = cloudapi_client::TypedClient::new_unauthenticated(&base_url, "stub");
+
+    let mut csv = Vec::new();
+    let written = client
+        .export_machines(
+            "stub",
+            types::MachineExportFormat::Csv,
+            &[MachineColumn::Name, MachineColumn::Package],
+            &ListMachinesFilter::default(),
+            &mut csv,
+        )
+        .await
+        .unwrap();
+    assert_eq!(written, csv.len() as u64);
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "name,package\nweb0,g1.small\n\"db,primary\",g1.small\n"
+    );
+
+    let mut ndjson = Vec::new();
+    client
+        .export_machines(
+            "stub",
+            types::MachineExportFormat::Ndjson,
+            &[MachineColumn::Name],
+            &ListMachinesFilter {
+                name: Some("web0".to_string()),
+                ..Default::default()
+            },
+            &mut ndjson,
+        )
+        .await
+        .unwrap();
+    assert_eq!(String::from_utf8(ndjson).unwrap(), "{\"name\":\"web0\"}\n");
+
+    // Unknown columns are rejected before anything is written
+    let resp = http
+        .get(format!(
+            "{}/stub/machineexport?columns=name,bogus",
+            base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_client_error());
+
+    server.close().await.unwrap();
+}
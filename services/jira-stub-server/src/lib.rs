@@ -15,13 +15,47 @@
 //!
 //! The server loads fixture data from JSON files at startup and serves it
 //! via the standard JIRA REST API endpoints.
+//!
+//! Remote links can also be changed at runtime through admin endpoints under
+//! `/_stub/`, which are not part of the JIRA API. Tests use these to set up
+//! exactly the links they need (e.g. for bugview's domain filtering).
+//!
+//! Fixtures can be checked without starting a server with
+//! [`validate_fixtures`], which the binary runs for `--validate`.
+//!
+//! Like production JIRA, the stub can require HTTP Basic authentication
+//! ([`StubContext::with_basic_auth`]) and be served over HTTPS with a
+//! [`SelfSignedCert`], so that a client's credentials and TLS configuration
+//! are exercised too.
 
 use anyhow::{Context, Result};
-use dropshot::{HttpError, HttpResponseOk, Path, Query, RequestContext};
-use jira_api::{Issue, IssueIdOrKey, IssueQuery, RemoteLink, SearchQuery, SearchResponse};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use dropshot::{
+    ClientErrorStatusCode, HttpError, HttpResponseCreated, HttpResponseDeleted, HttpResponseOk,
+    Path, Query, RequestContext, TypedBody, endpoint,
+};
+use jira_api::{
+    Issue, IssueIdOrKey, IssueQuery, RemoteLink, RemoteLinkObject, SearchQuery, SearchResponse,
+};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+mod tls;
+mod validate;
+
+pub use tls::SelfSignedCert;
+pub use validate::{FixtureProblem, FixtureReport, validate_fixtures};
+
+/// Page size used when a search does not specify `maxResults`
+const DEFAULT_MAX_RESULTS: u32 = 50;
+
+/// Prefix of the `nextPageToken` values handed out by the stub. The rest of
+/// the token is the offset of the next page in the sorted results.
+const PAGE_TOKEN_PREFIX: &str = "stub-offset-";
 
 // ============================================================================
 // Fixture Data Types
@@ -35,6 +69,8 @@ struct FixtureIssue {
     fields: HashMap<String, serde_json::Value>,
     #[serde(default, rename = "renderedFields")]
     rendered_fields: Option<HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    changelog: Option<jira_api::Changelog>,
     // Additional fields from raw JIRA responses (ignored but allowed for compatibility)
     #[allow(dead_code)]
     #[serde(default)]
@@ -51,6 +87,7 @@ impl From<FixtureIssue> for Issue {
             id: f.id,
             fields: f.fields,
             rendered_fields: f.rendered_fields,
+            changelog: f.changelog,
         }
     }
 }
@@ -59,13 +96,31 @@ impl From<FixtureIssue> for Issue {
 // Server Context
 // ============================================================================
 
+/// Credentials the JIRA API endpoints require
+#[derive(Clone)]
+struct BasicAuth {
+    username: String,
+    password: String,
+}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"[REDACTED]")
+            .finish()
+    }
+}
+
 /// Context for the stub JIRA server containing all test data
 #[derive(Debug)]
 pub struct StubContext {
     /// Issues indexed by key (e.g., "OS-8627")
     issues: HashMap<String, FixtureIssue>,
-    /// Remote links indexed by issue key
-    remote_links: HashMap<String, Vec<RemoteLink>>,
+    /// Remote links indexed by issue key; changed through the admin API
+    remote_links: Mutex<HashMap<String, Vec<RemoteLink>>>,
+    /// Credentials required on the JIRA API endpoints, if any
+    basic_auth: Option<BasicAuth>,
 }
 
 impl StubContext {
@@ -73,9 +128,14 @@ impl StubContext {
     ///
     /// Loads issues from individual `*.json` files containing raw JIRA API responses.
     /// Each file should be a single issue response from the JIRA REST API.
-    /// Remote links are loaded from `remote_links.json` if present.
+    ///
+    /// Remote links are loaded from `remote_links.json` (a map of issue key
+    /// to links) if present, and then from `remote_links/<KEY>.json` files,
+    /// each holding the list of links for one issue. A per-issue file
+    /// replaces any links `remote_links.json` has for that issue.
     pub fn from_fixtures(fixtures_dir: &std::path::Path) -> Result<Self> {
         let links_path = fixtures_dir.join("remote_links.json");
+        let links_dir = fixtures_dir.join("remote_links");
 
         let mut issues: HashMap<String, FixtureIssue> = HashMap::new();
 
@@ -112,7 +172,7 @@ impl StubContext {
             }
         }
 
-        let remote_links: HashMap<String, Vec<RemoteLink>> = if links_path.exists() {
+        let mut remote_links: HashMap<String, Vec<RemoteLink>> = if links_path.exists() {
             let links_json = std::fs::read_to_string(&links_path)
                 .with_context(|| format!("Failed to read {}", links_path.display()))?;
             serde_json::from_str(&links_json)
@@ -121,16 +181,209 @@ impl StubContext {
             HashMap::new()
         };
 
+        if links_dir.is_dir() {
+            remote_links.extend(load_remote_link_files(&links_dir)?);
+        }
+
         Ok(Self {
             issues,
-            remote_links,
+            remote_links: Mutex::new(remote_links),
+            basic_auth: None,
         })
     }
 
+    /// Like [`from_fixtures`](Self::from_fixtures), but fail if any fixture
+    /// is invalid instead of skipping it
+    ///
+    /// The fixtures are first checked with [`validate_fixtures`], and the
+    /// error lists every problem found.
+    pub fn from_fixtures_strict(fixtures_dir: &std::path::Path) -> Result<Self> {
+        let report = validate_fixtures(fixtures_dir)?;
+        if !report.is_ok() {
+            let problems: Vec<String> = report.problems.iter().map(|p| p.to_string()).collect();
+            anyhow::bail!(
+                "Invalid fixtures in {}:\n  {}",
+                fixtures_dir.display(),
+                problems.join("\n  ")
+            );
+        }
+        Self::from_fixtures(fixtures_dir)
+    }
+
+    /// Require HTTP Basic authentication with these credentials on the JIRA
+    /// API endpoints
+    ///
+    /// Requests without them are rejected with 401, as JIRA does. The admin
+    /// endpoints under `/_stub/` stay open.
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.basic_auth = Some(BasicAuth {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Check the `Authorization` header of a JIRA API request against the
+    /// required credentials
+    fn authorize(&self, authorization: Option<&str>) -> Result<(), HttpError> {
+        let Some(required) = &self.basic_auth else {
+            return Ok(());
+        };
+        let message = match authorization.and_then(parse_basic_auth) {
+            Some((username, password))
+                if username == required.username && password == required.password =>
+            {
+                return Ok(());
+            }
+            Some(_) => "Invalid username or password",
+            None => "Basic authentication required",
+        };
+        Err(HttpError::for_client_error(
+            None,
+            ClientErrorStatusCode::UNAUTHORIZED,
+            message.to_string(),
+        ))
+    }
+
     /// Get all issue keys
     pub fn issue_keys(&self) -> Vec<&str> {
         self.issues.keys().map(|s| s.as_str()).collect()
     }
+
+    /// Resolve an issue id or key to the key of a known issue
+    fn issue_key(&self, id_or_key: &str) -> Option<&str> {
+        if let Some((key, _)) = self.issues.get_key_value(id_or_key) {
+            return Some(key);
+        }
+        self.issues
+            .values()
+            .find(|i| i.id == id_or_key)
+            .map(|i| i.key.as_str())
+    }
+
+    /// Like [`issue_key`](Self::issue_key), but with a 404 for unknown issues
+    fn require_issue_key(&self, id_or_key: &str) -> Result<String, HttpError> {
+        self.issue_key(id_or_key)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                HttpError::for_not_found(None, format!("Issue not found: {}", id_or_key))
+            })
+    }
+
+    fn lock_remote_links(&self) -> MutexGuard<'_, HashMap<String, Vec<RemoteLink>>> {
+        // A panic while holding the lock leaves the map itself intact
+        self.remote_links
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Remote links of an issue, by issue key
+    pub fn remote_links(&self, issue_key: &str) -> Vec<RemoteLink> {
+        self.lock_remote_links()
+            .get(issue_key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Add a remote link to an issue
+    ///
+    /// Without an `id` the link gets one more than the highest id in use on
+    /// any issue. Returns `None` if the issue already has a link with the
+    /// requested id.
+    pub fn add_remote_link(
+        &self,
+        issue_key: &str,
+        id: Option<u64>,
+        object: RemoteLinkObject,
+    ) -> Option<RemoteLink> {
+        let mut all_links = self.lock_remote_links();
+        let id = id.unwrap_or_else(|| {
+            all_links
+                .values()
+                .flatten()
+                .map(|l| l.id)
+                .max()
+                .map_or(1, |max| max + 1)
+        });
+
+        let links = all_links.entry(issue_key.to_string()).or_default();
+        if links.iter().any(|l| l.id == id) {
+            return None;
+        }
+        let link = RemoteLink {
+            id,
+            object: Some(object),
+        };
+        links.push(link.clone());
+        Some(link)
+    }
+
+    /// Replace the object of an existing remote link
+    ///
+    /// Returns `None` if the issue has no link with this id.
+    pub fn update_remote_link(
+        &self,
+        issue_key: &str,
+        id: u64,
+        object: RemoteLinkObject,
+    ) -> Option<RemoteLink> {
+        let mut all_links = self.lock_remote_links();
+        let link = all_links
+            .get_mut(issue_key)?
+            .iter_mut()
+            .find(|l| l.id == id)?;
+        link.object = Some(object);
+        Some(link.clone())
+    }
+
+    /// Remove a remote link from an issue
+    ///
+    /// Returns `false` if the issue has no link with this id.
+    pub fn remove_remote_link(&self, issue_key: &str, id: u64) -> bool {
+        let mut all_links = self.lock_remote_links();
+        let Some(links) = all_links.get_mut(issue_key) else {
+            return false;
+        };
+        let before = links.len();
+        links.retain(|l| l.id != id);
+        links.len() != before
+    }
+
+    /// Remove all remote links from an issue
+    pub fn clear_remote_links(&self, issue_key: &str) {
+        self.lock_remote_links().remove(issue_key);
+    }
+}
+
+/// Load `<KEY>.json` files of per-issue remote links from a directory
+fn load_remote_link_files(links_dir: &std::path::Path) -> Result<HashMap<String, Vec<RemoteLink>>> {
+    let mut remote_links = HashMap::new();
+
+    for entry in std::fs::read_dir(links_dir)
+        .with_context(|| format!("Failed to read {}", links_dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(issue_key) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+
+        let links_json = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let links: Vec<RemoteLink> = serde_json::from_str(&links_json)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        tracing::info!("Loaded {} remote links for {}", links.len(), issue_key);
+        remote_links.insert(issue_key.to_string(), links);
+    }
+
+    Ok(remote_links)
 }
 
 // ============================================================================
@@ -147,6 +400,7 @@ impl jira_api::JiraApi for StubJiraApi {
         rqctx: RequestContext<Self::Context>,
         query: Query<SearchQuery>,
     ) -> Result<HttpResponseOk<SearchResponse>, HttpError> {
+        authorize(&rqctx)?;
         let ctx = rqctx.context();
         let query = query.into_inner();
 
@@ -173,9 +427,15 @@ impl jira_api::JiraApi for StubJiraApi {
             })
             .cloned()
             .map(Issue::from)
+            // JIRA only returns the changelog from search when expanded
+            .map(|issue| Issue {
+                changelog: None,
+                ..issue
+            })
             .collect();
 
-        // Sort by updated date descending (default JIRA behavior)
+        // Sort by updated date descending (default JIRA behavior), breaking
+        // ties by key so that pages are stable across requests
         matching_issues.sort_by(|a, b| {
             let a_updated = a
                 .fields
@@ -187,25 +447,26 @@ impl jira_api::JiraApi for StubJiraApi {
                 .get("updated")
                 .and_then(|v| v.as_str())
                 .unwrap_or("");
-            b_updated.cmp(a_updated)
+            b_updated
+                .cmp(a_updated)
+                .then_with(|| a.key.as_str().cmp(b.key.as_str()))
         });
 
-        // Apply pagination
-        let max_results = query.max_results.unwrap_or(50) as usize;
-        let start = 0; // Simple implementation - no cursor support yet
-        let end = std::cmp::min(start + max_results, matching_issues.len());
-        let page_issues = matching_issues[start..end].to_vec();
-        let is_last = end >= matching_issues.len();
+        // Apply pagination: the token carries the offset of the page, while
+        // the page size comes from each request's maxResults
+        let start = match &query.next_page_token {
+            Some(token) => parse_page_token(token).ok_or_else(|| {
+                HttpError::for_bad_request(None, format!("Invalid nextPageToken: {}", token))
+            })?,
+            None => 0,
+        };
+        let max_results = query.max_results.unwrap_or(DEFAULT_MAX_RESULTS).max(1) as usize;
 
-        Ok(HttpResponseOk(SearchResponse {
-            issues: page_issues,
-            is_last: Some(is_last),
-            next_page_token: if is_last {
-                None
-            } else {
-                Some("stub_token".to_string())
-            },
-        }))
+        Ok(HttpResponseOk(paginate(
+            matching_issues,
+            start,
+            max_results,
+        )))
     }
 
     async fn get_issue(
@@ -213,6 +474,7 @@ impl jira_api::JiraApi for StubJiraApi {
         path: Path<IssueIdOrKey>,
         query: Query<IssueQuery>,
     ) -> Result<HttpResponseOk<Issue>, HttpError> {
+        authorize(&rqctx)?;
         let ctx = rqctx.context();
         let path = path.into_inner();
         let query = query.into_inner();
@@ -228,14 +490,14 @@ impl jira_api::JiraApi for StubJiraApi {
 
         let mut result: Issue = issue.clone().into();
 
-        // Only include renderedFields if expand=renderedFields is requested
-        if let Some(expand) = &query.expand {
-            if !expand.contains("renderedFields") {
-                result.rendered_fields = None;
-            }
-        } else {
+        // Only include renderedFields and changelog when they are expanded
+        let expand = query.expand.as_deref().unwrap_or("");
+        if !expand.contains("renderedFields") {
             result.rendered_fields = None;
         }
+        if !expand.contains("changelog") {
+            result.changelog = None;
+        }
 
         Ok(HttpResponseOk(result))
     }
@@ -244,29 +506,145 @@ impl jira_api::JiraApi for StubJiraApi {
         rqctx: RequestContext<Self::Context>,
         path: Path<IssueIdOrKey>,
     ) -> Result<HttpResponseOk<Vec<RemoteLink>>, HttpError> {
+        authorize(&rqctx)?;
         let ctx = rqctx.context();
         let path = path.into_inner();
 
         // First verify the issue exists
-        if !ctx.issues.contains_key(&path.issue_id_or_key)
-            && !ctx.issues.values().any(|i| i.id == path.issue_id_or_key)
-        {
-            return Err(HttpError::for_not_found(
+        let issue_key = ctx.require_issue_key(&path.issue_id_or_key)?;
+
+        Ok(HttpResponseOk(ctx.remote_links(&issue_key)))
+    }
+}
+
+/// Reject a JIRA API request that lacks the credentials the stub requires
+fn authorize(rqctx: &RequestContext<Arc<StubContext>>) -> Result<(), HttpError> {
+    let authorization = rqctx
+        .request
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok());
+    rqctx.context().authorize(authorization)
+}
+
+// ============================================================================
+// Admin API
+// ============================================================================
+
+/// Path parameters for a single remote link
+#[derive(Debug, Deserialize, JsonSchema)]
+struct RemoteLinkPath {
+    issue_id_or_key: String,
+    link_id: u64,
+}
+
+/// Request body for adding a remote link
+#[derive(Debug, Deserialize, JsonSchema)]
+struct NewRemoteLink {
+    /// Link id; allocated by the stub if omitted
+    #[serde(default)]
+    id: Option<u64>,
+    /// URL of the remote resource
+    url: String,
+    /// Title of the remote resource
+    title: String,
+}
+
+/// Add a remote link to an issue
+#[endpoint {
+    method = POST,
+    path = "/_stub/issue/{issue_id_or_key}/remotelink",
+    tags = ["stub-admin"],
+}]
+async fn admin_add_remote_link(
+    rqctx: RequestContext<Arc<StubContext>>,
+    path: Path<IssueIdOrKey>,
+    body: TypedBody<NewRemoteLink>,
+) -> Result<HttpResponseCreated<RemoteLink>, HttpError> {
+    let ctx = rqctx.context();
+    let issue_key = ctx.require_issue_key(&path.into_inner().issue_id_or_key)?;
+    let body = body.into_inner();
+    let object = RemoteLinkObject {
+        url: body.url,
+        title: body.title,
+    };
+
+    ctx.add_remote_link(&issue_key, body.id, object)
+        .map(HttpResponseCreated)
+        .ok_or_else(|| {
+            HttpError::for_client_error(
                 None,
-                format!("Issue not found: {}", path.issue_id_or_key),
-            ));
-        }
+                ClientErrorStatusCode::CONFLICT,
+                format!("Issue {} already has a remote link with that id", issue_key),
+            )
+        })
+}
 
-        let links = ctx
-            .remote_links
-            .get(&path.issue_id_or_key)
-            .cloned()
-            .unwrap_or_default();
+/// Replace the URL and title of a remote link
+#[endpoint {
+    method = PUT,
+    path = "/_stub/issue/{issue_id_or_key}/remotelink/{link_id}",
+    tags = ["stub-admin"],
+}]
+async fn admin_update_remote_link(
+    rqctx: RequestContext<Arc<StubContext>>,
+    path: Path<RemoteLinkPath>,
+    body: TypedBody<RemoteLinkObject>,
+) -> Result<HttpResponseOk<RemoteLink>, HttpError> {
+    let ctx = rqctx.context();
+    let path = path.into_inner();
+    let issue_key = ctx.require_issue_key(&path.issue_id_or_key)?;
+
+    ctx.update_remote_link(&issue_key, path.link_id, body.into_inner())
+        .map(HttpResponseOk)
+        .ok_or_else(|| remote_link_not_found(&issue_key, path.link_id))
+}
 
-        Ok(HttpResponseOk(links))
+/// Remove a remote link from an issue
+#[endpoint {
+    method = DELETE,
+    path = "/_stub/issue/{issue_id_or_key}/remotelink/{link_id}",
+    tags = ["stub-admin"],
+}]
+async fn admin_remove_remote_link(
+    rqctx: RequestContext<Arc<StubContext>>,
+    path: Path<RemoteLinkPath>,
+) -> Result<HttpResponseDeleted, HttpError> {
+    let ctx = rqctx.context();
+    let path = path.into_inner();
+    let issue_key = ctx.require_issue_key(&path.issue_id_or_key)?;
+
+    if ctx.remove_remote_link(&issue_key, path.link_id) {
+        Ok(HttpResponseDeleted())
+    } else {
+        Err(remote_link_not_found(&issue_key, path.link_id))
     }
 }
 
+/// Remove all remote links from an issue
+#[endpoint {
+    method = DELETE,
+    path = "/_stub/issue/{issue_id_or_key}/remotelink",
+    tags = ["stub-admin"],
+}]
+async fn admin_clear_remote_links(
+    rqctx: RequestContext<Arc<StubContext>>,
+    path: Path<IssueIdOrKey>,
+) -> Result<HttpResponseDeleted, HttpError> {
+    let ctx = rqctx.context();
+    let issue_key = ctx.require_issue_key(&path.into_inner().issue_id_or_key)?;
+
+    ctx.clear_remote_links(&issue_key);
+    Ok(HttpResponseDeleted())
+}
+
+fn remote_link_not_found(issue_key: &str, link_id: u64) -> HttpError {
+    HttpError::for_not_found(
+        None,
+        format!("Issue {} has no remote link {}", issue_key, link_id),
+    )
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -290,9 +668,57 @@ fn parse_jql_labels(jql: &str) -> Vec<String> {
     Vec::new()
 }
 
+/// Decode the username and password of a `Basic` `Authorization` header
+fn parse_basic_auth(header: &str) -> Option<(String, String)> {
+    let (scheme, encoded) = header.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (username, password) = decoded.split_once(':')?;
+    Some((username.to_string(), password.to_string()))
+}
+
+/// Take the page of `issues` starting at offset `start`, with a token for the
+/// following page if there are more results
+fn paginate(issues: Vec<Issue>, start: usize, max_results: usize) -> SearchResponse {
+    let total = issues.len();
+    let start = start.min(total);
+    let end = start.saturating_add(max_results).min(total);
+    let is_last = end >= total;
+
+    SearchResponse {
+        issues: issues.into_iter().skip(start).take(end - start).collect(),
+        is_last: Some(is_last),
+        next_page_token: (!is_last).then(|| page_token(end)),
+    }
+}
+
+/// Encode the offset of a page as a `nextPageToken`
+fn page_token(offset: usize) -> String {
+    format!("{}{}", PAGE_TOKEN_PREFIX, offset)
+}
+
+/// Decode a `nextPageToken` produced by [`page_token`]
+fn parse_page_token(token: &str) -> Option<usize> {
+    token.strip_prefix(PAGE_TOKEN_PREFIX)?.parse().ok()
+}
+
 /// Create the Dropshot API description for the stub server
+///
+/// This is the JIRA API plus the stub's admin endpoints.
 pub fn api_description() -> Result<dropshot::ApiDescription<Arc<StubContext>>, String> {
-    jira_api::jira_api_mod::api_description::<StubJiraApi>().map_err(|e| e.to_string())
+    let mut api =
+        jira_api::jira_api_mod::api_description::<StubJiraApi>().map_err(|e| e.to_string())?;
+    api.register(admin_add_remote_link)
+        .map_err(|e| e.to_string())?;
+    api.register(admin_update_remote_link)
+        .map_err(|e| e.to_string())?;
+    api.register(admin_remove_remote_link)
+        .map_err(|e| e.to_string())?;
+    api.register(admin_clear_remote_links)
+        .map_err(|e| e.to_string())?;
+    Ok(api)
 }
 
 // ============================================================================
@@ -331,6 +757,58 @@ mod tests {
         assert!(labels.is_empty());
     }
 
+    fn issues(keys: &[&str]) -> Vec<Issue> {
+        keys.iter()
+            .map(|key| Issue {
+                key: jira_api::IssueKey::new_unchecked(*key),
+                id: key.to_string(),
+                fields: HashMap::new(),
+                rendered_fields: None,
+                changelog: None,
+            })
+            .collect()
+    }
+
+    fn page_keys(page: &SearchResponse) -> Vec<&str> {
+        page.issues.iter().map(|i| i.key.as_str()).collect()
+    }
+
+    #[test]
+    fn test_page_token_round_trip() {
+        assert_eq!(parse_page_token(&page_token(0)), Some(0));
+        assert_eq!(parse_page_token(&page_token(42)), Some(42));
+        assert_eq!(parse_page_token("stub_token"), None);
+        assert_eq!(parse_page_token("stub-offset-abc"), None);
+    }
+
+    #[test]
+    fn test_paginate_follows_tokens() {
+        let all = issues(&["A-1", "A-2", "A-3", "A-4", "A-5"]);
+
+        let first = paginate(all.clone(), 0, 2);
+        assert_eq!(page_keys(&first), vec!["A-1", "A-2"]);
+        assert_eq!(first.is_last, Some(false));
+
+        let start = parse_page_token(first.next_page_token.as_deref().unwrap()).unwrap();
+        let second = paginate(all.clone(), start, 2);
+        assert_eq!(page_keys(&second), vec!["A-3", "A-4"]);
+
+        // A later request may use a different page size
+        let start = parse_page_token(second.next_page_token.as_deref().unwrap()).unwrap();
+        let last = paginate(all.clone(), start, 10);
+        assert_eq!(page_keys(&last), vec!["A-5"]);
+        assert_eq!(last.is_last, Some(true));
+        assert_eq!(last.next_page_token, None);
+    }
+
+    #[test]
+    fn test_paginate_past_end() {
+        let page = paginate(issues(&["A-1"]), 5, 2);
+        assert!(page.issues.is_empty());
+        assert_eq!(page.is_last, Some(true));
+        assert_eq!(page.next_page_token, None);
+    }
+
     #[test]
     fn test_load_fixtures() {
         let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
@@ -343,4 +821,139 @@ mod tests {
         assert_eq!(issue.id, "57781");
         // renderedFields is optional - bugview-service does its own ADF→HTML conversion
     }
+
+    #[test]
+    fn test_strict_fixture_loading() {
+        let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let ctx =
+            StubContext::from_fixtures_strict(&fixtures_dir).expect("Failed to load fixtures");
+        assert_eq!(ctx.issue_keys().len(), 5);
+
+        // An issue without labels is loaded normally, but fails strict loading
+        let dir = tempfile::tempdir().unwrap();
+        let issue = serde_json::json!({
+            "key": "OS-1",
+            "id": "1",
+            "fields": {"summary": "No labels", "created": "x", "updated": "x"}
+        });
+        std::fs::write(dir.path().join("OS-1.json"), issue.to_string()).unwrap();
+
+        assert_eq!(
+            StubContext::from_fixtures(dir.path()).unwrap().issue_keys(),
+            vec!["OS-1"]
+        );
+        let err = StubContext::from_fixtures_strict(dir.path())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("OS-1.json: missing required field labels"),
+            "{}",
+            err
+        );
+    }
+
+    fn fixtures_context() -> StubContext {
+        let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        StubContext::from_fixtures(&fixtures_dir).expect("Failed to load fixtures")
+    }
+
+    fn link_object(url: &str) -> RemoteLinkObject {
+        RemoteLinkObject {
+            url: url.to_string(),
+            title: "link".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_basic_auth() {
+        // base64("bot@example.com:api:token")
+        assert_eq!(
+            parse_basic_auth("Basic Ym90QGV4YW1wbGUuY29tOmFwaTp0b2tlbg=="),
+            Some(("bot@example.com".to_string(), "api:token".to_string()))
+        );
+        assert_eq!(
+            parse_basic_auth("basic Ym90OnB3"),
+            Some(("bot".to_string(), "pw".to_string()))
+        );
+        assert_eq!(parse_basic_auth("Bearer Ym90OnB3"), None);
+        assert_eq!(parse_basic_auth("Basic not-base64!"), None);
+        // base64("no-colon")
+        assert_eq!(parse_basic_auth("Basic bm8tY29sb24="), None);
+    }
+
+    #[test]
+    fn test_authorize() {
+        // Without credentials configured anything is accepted
+        let ctx = fixtures_context();
+        assert!(ctx.authorize(None).is_ok());
+        assert!(ctx.authorize(Some("Bearer token")).is_ok());
+
+        let ctx = fixtures_context().with_basic_auth("bot", "pw");
+        assert!(ctx.authorize(Some("Basic Ym90OnB3")).is_ok());
+        for header in [None, Some("Bearer Ym90OnB3"), Some("Basic Ym90Ondyb25n")] {
+            let err = ctx.authorize(header).unwrap_err();
+            assert_eq!(err.status_code.as_u16(), 401, "{:?}", header);
+        }
+        assert!(!format!("{:?}", ctx).contains("\"pw\""));
+    }
+
+    #[test]
+    fn test_load_per_issue_remote_links() {
+        let ctx = fixtures_context();
+
+        let links = ctx.remote_links("TRITON-1813");
+        let ids: Vec<u64> = links.iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![10100, 10101]);
+        assert!(ctx.remote_links("TRITON-2520").is_empty());
+    }
+
+    #[test]
+    fn test_issue_key_resolves_id() {
+        let ctx = fixtures_context();
+
+        assert_eq!(ctx.issue_key("TRITON-2520"), Some("TRITON-2520"));
+        assert_eq!(ctx.issue_key("57781"), Some("TRITON-2520"));
+        assert_eq!(ctx.issue_key("NOPE-1"), None);
+    }
+
+    #[test]
+    fn test_remote_link_crud() {
+        let ctx = fixtures_context();
+
+        // Allocated ids follow the highest id in use on any issue
+        let added = ctx
+            .add_remote_link("TRITON-2520", None, link_object("https://github.com/a"))
+            .expect("add");
+        assert_eq!(added.id, 10102);
+
+        let explicit = ctx
+            .add_remote_link("TRITON-2520", Some(7), link_object("https://github.com/b"))
+            .expect("add with id");
+        assert_eq!(explicit.id, 7);
+        assert!(
+            ctx.add_remote_link("TRITON-2520", Some(7), link_object("https://github.com/c"))
+                .is_none()
+        );
+
+        let updated = ctx
+            .update_remote_link("TRITON-2520", 7, link_object("https://smartos.org/b"))
+            .expect("update");
+        assert_eq!(updated.object.unwrap().url, "https://smartos.org/b");
+        assert!(
+            ctx.update_remote_link("TRITON-2520", 99, link_object("https://x"))
+                .is_none()
+        );
+
+        assert!(ctx.remove_remote_link("TRITON-2520", 10102));
+        assert!(!ctx.remove_remote_link("TRITON-2520", 10102));
+        let urls: Vec<String> = ctx
+            .remote_links("TRITON-2520")
+            .into_iter()
+            .filter_map(|l| l.object.map(|o| o.url))
+            .collect();
+        assert_eq!(urls, vec!["https://smartos.org/b"]);
+
+        ctx.clear_remote_links("TRITON-2520");
+        assert!(ctx.remote_links("TRITON-2520").is_empty());
+    }
 }
@@ -15,16 +15,70 @@
 //! ```bash
 //! JIRA_URL=http://localhost:9090 cargo run -p bugview-service
 //! ```
+//!
+//! Check the fixtures without starting the server:
+//! ```bash
+//! cargo run -p jira-stub-server -- --validate
+//! ```
+//!
+//! Require credentials and serve HTTPS, like production JIRA:
+//! ```bash
+//! cargo run -p jira-stub-server -- --username bot --password secret \
+//!     --tls --tls-cert-out /tmp/jira-stub.pem
+//! ```
 
 use anyhow::Result;
+use clap::Parser;
 use dropshot::{ConfigDropshot, ConfigLogging, ConfigLoggingLevel, HttpServerStarter};
 use std::net::{Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 
-use jira_stub_server::{StubContext, api_description};
+use jira_stub_server::{SelfSignedCert, StubContext, api_description, validate_fixtures};
+
+#[derive(Parser)]
+#[command(name = "jira-stub-server")]
+#[command(about = "Stub JIRA server for testing bugview-service", long_about = None)]
+struct Cli {
+    /// Directory of fixture files
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"))]
+    fixtures: PathBuf,
+
+    /// Refuse to start if any fixture is invalid, instead of skipping it
+    #[arg(long)]
+    strict: bool,
+
+    /// Check the fixtures, report every problem found and exit
+    #[arg(long)]
+    validate: bool,
+
+    /// Require HTTP Basic authentication with this username on the JIRA API
+    /// endpoints
+    #[arg(long, requires = "password")]
+    username: Option<String>,
+
+    /// Password required with --username
+    #[arg(long, env = "JIRA_STUB_PASSWORD")]
+    password: Option<String>,
+
+    /// Serve HTTPS with a self-signed certificate for localhost
+    #[arg(long)]
+    tls: bool,
+
+    /// Write the self-signed certificate to this file as PEM, for clients
+    /// to trust
+    #[arg(long, requires = "tls")]
+    tls_cert_out: Option<PathBuf>,
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    if cli.validate {
+        return validate(&cli.fixtures);
+    }
+
     // Initialize logging
     let log_config = ConfigLogging::StderrTerminal {
         level: ConfigLoggingLevel::Info,
@@ -32,11 +86,32 @@ async fn main() -> Result<()> {
     let log = log_config.to_logger("jira-stub-server")?;
 
     // Load fixture data
-    let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
-    let context = Arc::new(StubContext::from_fixtures(&fixtures_dir)?);
+    let mut context = if cli.strict {
+        StubContext::from_fixtures_strict(&cli.fixtures)?
+    } else {
+        StubContext::from_fixtures(&cli.fixtures)?
+    };
+    if let (Some(username), Some(password)) = (cli.username, cli.password) {
+        tracing::info!("Requiring basic authentication as {}", username);
+        context = context.with_basic_auth(username, password);
+    }
+    let context = Arc::new(context);
 
     tracing::info!("Loaded {} issues from fixtures", context.issue_keys().len());
 
+    let tls = if cli.tls {
+        let cert = SelfSignedCert::localhost()?;
+        if let Some(path) = &cli.tls_cert_out {
+            std::fs::write(path, cert.cert_pem())
+                .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))?;
+            tracing::info!("Wrote self-signed certificate to {}", path.display());
+        }
+        Some(cert.tls_config())
+    } else {
+        None
+    };
+    let scheme = if tls.is_some() { "https" } else { "http" };
+
     // Configure the server
     let config = ConfigDropshot {
         bind_address: SocketAddr::from((Ipv4Addr::LOCALHOST, 9090)),
@@ -47,17 +122,41 @@ async fn main() -> Result<()> {
 
     // Create and start the server
     let api = api_description().map_err(|e| anyhow::anyhow!(e))?;
-    let server = HttpServerStarter::new(&config, api, context, &log)
+    let server = HttpServerStarter::new_with_tls(&config, api, context, &log, tls)
         .map_err(|e| anyhow::anyhow!("Failed to create server: {}", e))?
         .start();
 
-    tracing::info!("Stub JIRA server listening on http://localhost:9090");
+    tracing::info!("Stub JIRA server listening on {}://localhost:9090", scheme);
     tracing::info!("Available endpoints:");
     tracing::info!("  GET /rest/api/3/search/jql?jql=...");
     tracing::info!("  GET /rest/api/3/issue/{{issueIdOrKey}}");
     tracing::info!("  GET /rest/api/3/issue/{{issueIdOrKey}}/remotelink");
+    tracing::info!("Admin endpoints:");
+    tracing::info!("  POST   /_stub/issue/{{issueIdOrKey}}/remotelink");
+    tracing::info!("  PUT    /_stub/issue/{{issueIdOrKey}}/remotelink/{{linkId}}");
+    tracing::info!("  DELETE /_stub/issue/{{issueIdOrKey}}/remotelink/{{linkId}}");
+    tracing::info!("  DELETE /_stub/issue/{{issueIdOrKey}}/remotelink");
 
     server
         .await
         .map_err(|e| anyhow::anyhow!("Server error: {}", e))
 }
+
+/// Lint the fixtures directory, failing if any fixture is invalid
+fn validate(fixtures_dir: &std::path::Path) -> Result<()> {
+    let report = validate_fixtures(fixtures_dir)?;
+    for problem in &report.problems {
+        println!("{}", problem);
+    }
+    println!(
+        "Checked {} issue files and {} remote link files in {}",
+        report.issue_files,
+        report.remote_link_files,
+        fixtures_dir.display()
+    );
+
+    if !report.is_ok() {
+        anyhow::bail!("{} problems found", report.problems.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,357 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Copyright 2026 Edgecast Cloud LLC.
+
+//! Fixture validation
+//!
+//! [`StubContext::from_fixtures`](crate::StubContext::from_fixtures) skips
+//! issue files it cannot parse with only a warning, and loads issues that
+//! parse but lack fields bugview relies on. [`validate_fixtures`] instead
+//! checks every fixture against the `jira-api` types and reports each
+//! problem it finds, for the `--validate` command and for strict startup.
+
+use anyhow::{Context, Result};
+use jira_api::{Issue, RemoteLink};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Issue fields bugview needs, with the JSON type each must have
+const REQUIRED_FIELDS: &[(&str, FieldType)] = &[
+    ("summary", FieldType::String),
+    ("labels", FieldType::StringArray),
+    ("created", FieldType::String),
+    ("updated", FieldType::String),
+];
+
+#[derive(Debug, Clone, Copy)]
+enum FieldType {
+    String,
+    StringArray,
+}
+
+impl FieldType {
+    fn matches(self, value: &serde_json::Value) -> bool {
+        match self {
+            FieldType::String => value.is_string(),
+            FieldType::StringArray => value
+                .as_array()
+                .is_some_and(|values| values.iter().all(|v| v.is_string())),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            FieldType::String => "a string",
+            FieldType::StringArray => "an array of strings",
+        }
+    }
+}
+
+/// A problem with one fixture file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureProblem {
+    /// The file with the problem
+    pub path: PathBuf,
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl fmt::Display for FixtureProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Result of checking a fixtures directory
+#[derive(Debug, Default)]
+pub struct FixtureReport {
+    /// Number of issue files checked
+    pub issue_files: usize,
+    /// Number of remote link files checked
+    pub remote_link_files: usize,
+    /// Every problem found, in file name order
+    pub problems: Vec<FixtureProblem>,
+}
+
+impl FixtureReport {
+    /// Whether no problems were found
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    fn problem(&mut self, path: &Path, message: impl Into<String>) {
+        self.problems.push(FixtureProblem {
+            path: path.to_path_buf(),
+            message: message.into(),
+        });
+    }
+}
+
+/// Check every fixture in `fixtures_dir`
+///
+/// Issue files must deserialize as a [`jira_api::Issue`] (so keys must be in
+/// `PROJECT-123` form), have a numeric id, and have the `summary`, `labels`,
+/// `created` and `updated` fields bugview relies on. No two files may hold
+/// the same issue. Remote link files must deserialize as
+/// [`jira_api::RemoteLink`]s and name an issue that has a fixture.
+///
+/// # Errors
+/// Only if the directory itself cannot be read; problems with individual
+/// files are collected in the report.
+pub fn validate_fixtures(fixtures_dir: &Path) -> Result<FixtureReport> {
+    let mut report = FixtureReport::default();
+    let mut keys: HashMap<String, PathBuf> = HashMap::new();
+
+    for path in json_files(fixtures_dir)? {
+        if path.file_name().and_then(|s| s.to_str()) == Some("remote_links.json") {
+            continue;
+        }
+        report.issue_files += 1;
+
+        let Some(value) = read_json(&path, &mut report) else {
+            continue;
+        };
+        for message in check_issue(&value) {
+            report.problem(&path, message);
+        }
+        if let Some(key) = value.get("key").and_then(|v| v.as_str()) {
+            if let Some(first) = keys.get(key) {
+                report.problem(
+                    &path,
+                    format!("duplicate issue {} (also in {})", key, first.display()),
+                );
+            } else {
+                keys.insert(key.to_string(), path.clone());
+            }
+        }
+    }
+
+    let known: HashSet<&str> = keys.keys().map(String::as_str).collect();
+
+    let links_path = fixtures_dir.join("remote_links.json");
+    if links_path.exists() {
+        report.remote_link_files += 1;
+        if let Some(value) = read_json(&links_path, &mut report) {
+            match serde_json::from_value::<HashMap<String, Vec<RemoteLink>>>(value) {
+                Ok(links) => {
+                    let mut issue_keys: Vec<&String> = links.keys().collect();
+                    issue_keys.sort();
+                    for issue_key in issue_keys {
+                        if !known.contains(issue_key.as_str()) {
+                            report.problem(
+                                &links_path,
+                                format!("remote links for unknown issue {}", issue_key),
+                            );
+                        }
+                    }
+                }
+                Err(e) => report.problem(&links_path, format!("invalid remote links: {}", e)),
+            }
+        }
+    }
+
+    let links_dir = fixtures_dir.join("remote_links");
+    if links_dir.is_dir() {
+        for path in json_files(&links_dir)? {
+            report.remote_link_files += 1;
+            let Some(value) = read_json(&path, &mut report) else {
+                continue;
+            };
+            if let Err(e) = serde_json::from_value::<Vec<RemoteLink>>(value) {
+                report.problem(&path, format!("invalid remote links: {}", e));
+            }
+            let issue_key = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+            if !known.contains(issue_key) {
+                report.problem(
+                    &path,
+                    format!("remote links for unknown issue {}", issue_key),
+                );
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Problems with one issue fixture
+fn check_issue(value: &serde_json::Value) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if let Err(e) = serde_json::from_value::<Issue>(value.clone()) {
+        problems.push(format!("not a valid JIRA issue: {}", e));
+    }
+
+    if let Some(id) = value.get("id").and_then(|v| v.as_str())
+        && (id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()))
+    {
+        problems.push(format!("issue id {:?} is not numeric", id));
+    }
+
+    let Some(fields) = value.get("fields").and_then(|v| v.as_object()) else {
+        // Already reported as an invalid issue
+        return problems;
+    };
+    for (name, field_type) in REQUIRED_FIELDS {
+        match fields.get(*name) {
+            None | Some(serde_json::Value::Null) => {
+                problems.push(format!("missing required field {}", name));
+            }
+            Some(field) if !field_type.matches(field) => {
+                problems.push(format!("field {} must be {}", name, field_type.describe()));
+            }
+            Some(_) => {}
+        }
+    }
+    if let Some(status) = fields.get("status")
+        && !status.get("name").is_some_and(|name| name.is_string())
+    {
+        problems.push("field status must be an object with a name".to_string());
+    }
+
+    problems
+}
+
+/// Parse a file as JSON, reporting it if that fails
+fn read_json(path: &Path, report: &mut FixtureReport) -> Option<serde_json::Value> {
+    let result = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read file: {}", e))
+        .and_then(|s| serde_json::from_str(&s).map_err(|e| format!("invalid JSON: {}", e)));
+    match result {
+        Ok(value) => Some(value),
+        Err(message) => {
+            report.problem(path, message);
+            None
+        }
+    }
+}
+
+/// The `*.json` files in a directory, sorted by name
+fn json_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_json(key: &str) -> serde_json::Value {
+        serde_json::json!({
+            "key": key,
+            "id": "10001",
+            "fields": {
+                "summary": "An issue",
+                "status": {"name": "Open"},
+                "labels": ["public"],
+                "created": "2025-01-01T00:00:00.000-0500",
+                "updated": "2025-01-02T00:00:00.000-0500"
+            }
+        })
+    }
+
+    fn write(dir: &Path, name: &str, value: &serde_json::Value) {
+        std::fs::write(dir.join(name), value.to_string()).unwrap();
+    }
+
+    fn messages(report: &FixtureReport) -> Vec<String> {
+        report.problems.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_shipped_fixtures_are_valid() {
+        let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let report = validate_fixtures(&fixtures_dir).unwrap();
+
+        assert!(report.is_ok(), "{:?}", messages(&report));
+        assert_eq!(report.issue_files, 5);
+        assert_eq!(report.remote_link_files, 1);
+    }
+
+    #[test]
+    fn test_check_issue() {
+        assert!(check_issue(&issue_json("OS-1")).is_empty());
+
+        let mut issue = issue_json("not a key");
+        issue["id"] = serde_json::json!("abc");
+        let fields = issue["fields"].as_object_mut().unwrap();
+        fields.remove("updated");
+        fields.insert("labels".into(), serde_json::json!("public"));
+        fields.insert("status".into(), serde_json::json!("Open"));
+
+        let problems = check_issue(&issue);
+        assert_eq!(problems.len(), 5, "{:?}", problems);
+        assert!(problems[0].starts_with("not a valid JIRA issue"));
+        assert_eq!(problems[1], r#"issue id "abc" is not numeric"#);
+        assert_eq!(problems[2], "field labels must be an array of strings");
+        assert_eq!(problems[3], "missing required field updated");
+        assert_eq!(problems[4], "field status must be an object with a name");
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "OS-1.json", &issue_json("OS-1"));
+        write(dir.path(), "OS-1-copy.json", &issue_json("OS-1"));
+        std::fs::write(dir.path().join("broken.json"), "{ not json").unwrap();
+        std::fs::write(dir.path().join("README.txt"), "not a fixture").unwrap();
+        write(
+            dir.path(),
+            "remote_links.json",
+            &serde_json::json!({"OS-2": [{"id": 1, "object": null}]}),
+        );
+        std::fs::create_dir(dir.path().join("remote_links")).unwrap();
+        write(
+            &dir.path().join("remote_links"),
+            "OS-1.json",
+            &serde_json::json!([{"object": null}]),
+        );
+
+        let report = validate_fixtures(dir.path()).unwrap();
+        assert_eq!(report.issue_files, 3);
+        assert_eq!(report.remote_link_files, 2);
+
+        let paths: Vec<&str> = report
+            .problems
+            .iter()
+            .map(|p| p.path.strip_prefix(dir.path()).unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "OS-1.json",
+                "broken.json",
+                "remote_links.json",
+                "remote_links/OS-1.json",
+            ],
+            "{:?}",
+            messages(&report)
+        );
+        assert!(
+            report.problems[0]
+                .message
+                .starts_with("duplicate issue OS-1")
+        );
+        assert!(report.problems[1].message.starts_with("invalid JSON"));
+        assert_eq!(
+            report.problems[2].message,
+            "remote links for unknown issue OS-2"
+        );
+        assert!(
+            report.problems[3]
+                .message
+                .starts_with("invalid remote links")
+        );
+    }
+}